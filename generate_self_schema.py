@@ -10,6 +10,8 @@ import importlib.util
 import re
 from collections.abc import Callable
 from datetime import date, datetime, time, timedelta
+from decimal import Decimal
+from fractions import Fraction
 from pathlib import Path
 from typing import TYPE_CHECKING, Any, Dict, ForwardRef, List, Set, Type, Union
 
@@ -48,6 +50,10 @@ def get_schema(obj) -> core_schema.CoreSchema:
         return {'type': obj}
     elif obj in (datetime, timedelta, date, time, bool, int, float, str):
         return {'type': obj.__name__}
+    elif obj is Fraction:
+        return {'type': 'fraction'}
+    elif obj is Decimal:
+        return {'type': 'decimal'}
     elif is_typeddict(obj):
         return type_dict_schema(obj)
     elif obj == Any or obj == type:
@@ -75,6 +81,9 @@ def get_schema(obj) -> core_schema.CoreSchema:
             'keys_schema': get_schema(obj.__args__[0]),
             'values_schema': get_schema(obj.__args__[1]),
         }
+    elif origin is re.Pattern:
+        # a str or a compiled pattern, which is checked when the str validator is built
+        return {'type': 'any'}
     elif issubclass(origin, Type):
         # can't really use 'is-instance' since this is used for the class_ parameter of 'is-instance' validators
         return {'type': 'any'}
@@ -127,6 +136,22 @@ def type_dict_schema(typed_dict) -> dict[str, Any]:  # noqa: C901
                     schema = schema_ref_validator
                 elif fr_arg == 'List[CoreSchema]':
                     schema = {'type': 'list', 'items_schema': schema_ref_validator}
+                elif fr_arg == 'List[Union[CoreSchema, UnionChoice]]':
+                    schema = {
+                        'type': 'list',
+                        'items_schema': {
+                            'type': 'union',
+                            'choices': [schema_ref_validator, type_dict_schema(core_schema.UnionChoice)],
+                        },
+                    }
+                elif fr_arg == 'List[Union[CoreSchema, ChainStep]]':
+                    schema = {
+                        'type': 'list',
+                        'items_schema': {
+                            'type': 'union',
+                            'choices': [schema_ref_validator, type_dict_schema(core_schema.ChainStep)],
+                        },
+                    }
                 elif fr_arg == 'Dict[str, CoreSchema]':
                     schema = {'type': 'dict', 'keys_schema': {'type': 'str'}, 'values_schema': schema_ref_validator}
                 elif fr_arg == 'Dict[Union[str, int], Union[str, int, CoreSchema]]':
@@ -161,6 +186,9 @@ def type_dict_schema(typed_dict) -> dict[str, Any]:  # noqa: C901
         # now_utc_offset is an int that must be in the range -24 hours to +24 hours, we manually add a constraint here
         if field_name == 'now_utc_offset':
             schema.update(gt=-86_400, lt=86_400)
+        # likewise for the UTC offset form of tz_constraint
+        elif field_name == 'tz_constraint':
+            next(c for c in schema['choices'] if c['type'] == 'int').update(gt=-86_400, lt=86_400)
         fields[field_name] = {'schema': schema, 'required': required}
 
     return {'type': 'typed-dict', 'fields': fields, 'extra_behavior': 'forbid'}
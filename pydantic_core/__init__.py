@@ -12,6 +12,7 @@ from ._pydantic_core import (
     SchemaError,
     SchemaSerializer,
     SchemaValidator,
+    Some,
     Url,
     ValidationError,
     __version__,
@@ -37,6 +38,7 @@ __all__ = (
     'CoreSchemaType',
     'SchemaValidator',
     'SchemaSerializer',
+    'Some',
     'Url',
     'MultiHostUrl',
     'ArgsKwargs',
@@ -58,7 +60,7 @@ class ErrorDetails(_TypedDict):
     type: str
     loc: 'tuple[int | str, ...]'
     msg: str
-    input: _Any
+    input: _NotRequired[_Any]
     ctx: _NotRequired['dict[str, str | int | float]']
 
 
@@ -67,3 +69,5 @@ class InitErrorDetails(_TypedDict):
     loc: _NotRequired['tuple[int | str, ...]']
     input: _Any
     ctx: _NotRequired['dict[str, str | int | float]']
+    # only used with unknown `type` strings, which are custom errors using `msg` as their message template
+    msg: _NotRequired[str]
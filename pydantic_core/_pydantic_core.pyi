@@ -1,6 +1,6 @@
 import decimal
 import sys
-from typing import Any, Callable
+from typing import Any, Callable, Generic, TypeVar
 
 from pydantic_core import ErrorDetails, InitErrorDetails
 from pydantic_core.core_schema import CoreConfig, CoreSchema, ErrorType
@@ -20,6 +20,7 @@ __all__ = (
     'build_profile',
     'SchemaValidator',
     'SchemaSerializer',
+    'Some',
     'Url',
     'MultiHostUrl',
     'SchemaError',
@@ -33,27 +34,86 @@ __all__ = (
 __version__: str
 build_profile: str
 
+_T = TypeVar('_T', covariant=True)
+
+class Some(Generic[_T]):
+    """
+    The default returned by `SchemaValidator.get_default_value()`, so a default of `None` can be told apart from
+    a schema without a default.
+    """
+
+    __match_args__ = ('value',)
+    def __init__(self, value: _T) -> None: ...
+    @property
+    def value(self) -> _T: ...
+
 class SchemaValidator:
     @property
     def title(self) -> str: ...
     def __init__(self, schema: CoreSchema, config: 'CoreConfig | None' = None) -> None: ...
     def validate_python(
-        self, input: Any, *, strict: 'bool | None' = None, context: Any = None, self_instance: 'Any | None' = None
+        self,
+        input: Any,
+        *,
+        strict: 'bool | None' = None,
+        context: Any = None,
+        self_instance: 'Any | None' = None,
+        max_steps: 'int | None' = None,
+        allow_partial: bool = False,
+        recursion_limit: 'int | None' = None,
+        max_errors: 'int | None' = None,
     ) -> Any: ...
+    # only available if pydantic-core is built with the `timings` feature
+    def validate_python_timings(
+        self, input: Any, *, strict: 'bool | None' = None, context: Any = None
+    ) -> 'tuple[Any, dict[str, ValidatorTiming]]': ...
+    def validate_python_with_report(
+        self, input: Any, *, strict: 'bool | None' = None, context: Any = None, report_defaults: bool = False
+    ) -> 'tuple[Any, list[Coercion | DefaultApplied]]': ...
+    def validate_python_with_warnings(
+        self, input: Any, *, strict: 'bool | None' = None, context: Any = None
+    ) -> 'tuple[Any, list[ValidationWarning]]': ...
+    def validate_python_with_raw(
+        self, input: Any, *, strict: 'bool | None' = None, context: Any = None, record_nodes: bool = False
+    ) -> 'tuple[Any, Any]': ...
     def isinstance_python(
         self, input: Any, *, strict: 'bool | None' = None, context: Any = None, self_instance: 'Any | None' = None
     ) -> bool: ...
     def validate_json(
         self,
-        input: 'str | bytes | bytearray',
+        input: 'str | bytes | bytearray | memoryview',
         *,
         strict: 'bool | None' = None,
         context: Any = None,
         self_instance: 'Any | None' = None,
+        max_steps: 'int | None' = None,
+        allow_partial: bool = False,
+        recursion_limit: 'int | None' = None,
+        max_errors: 'int | None' = None,
     ) -> Any: ...
+    def validate_strings(self, input: Any, *, strict: 'bool | None' = None, context: Any = None) -> Any: ...
+    def validate_json_bytes(
+        self,
+        input: bytes,
+        *,
+        strict: 'bool | None' = None,
+        context: Any = None,
+        self_instance: 'Any | None' = None,
+    ) -> Any: ...
+    def validate_json_with_raw(
+        self,
+        input: 'str | bytes | bytearray | memoryview',
+        *,
+        strict: 'bool | None' = None,
+        context: Any = None,
+        record_nodes: bool = False,
+    ) -> 'tuple[Any, Any]': ...
+    def validate_json_with_warnings(
+        self, input: 'str | bytes | bytearray | memoryview', *, strict: 'bool | None' = None, context: Any = None
+    ) -> 'tuple[Any, list[ValidationWarning]]': ...
     def isinstance_json(
         self,
-        input: 'str | bytes | bytearray',
+        input: 'str | bytes | bytearray | memoryview',
         *,
         strict: 'bool | None' = None,
         context: Any = None,
@@ -62,8 +122,84 @@ class SchemaValidator:
     def validate_assignment(
         self, obj: Any, field: str, input: Any, *, strict: 'bool | None' = None, context: Any = None
     ) -> 'dict[str, Any]': ...
+    def definitions_summary(self) -> 'list[DefinitionSummary]': ...
+    def memory_report(self) -> MemoryReport: ...
+    def describe(self) -> 'ValidatorDescription': ...
+    def get_default_value(self, *, strict: 'bool | None' = None, context: Any = None) -> 'Some[Any] | None': ...
+    def sub_validator(self, path: 'tuple[str | int, ...]') -> 'SchemaValidator': ...
+    def with_strict(self, strict: bool) -> 'SchemaValidator': ...
+    def __copy__(self) -> 'SchemaValidator': ...
+    def __deepcopy__(self, memo: Any) -> 'SchemaValidator': ...
+
+class ValidatorTiming(TypedDict):
+    # cumulative time in seconds
+    total: float
+    calls: int
+
+class Coercion(TypedDict):
+    # e.g. 'int_from_str', or 'any_from_int' for the type of inputs recorded by `any_schema(record_types=True)`
+    type: str
+    loc: 'tuple[int | str, ...]'
+    input_type: str
+    target_type: str
+
+class DefaultApplied(TypedDict):
+    # only included with `report_defaults=True`
+    type: Literal['default', 'default_factory']
+    loc: 'tuple[int | str, ...]'
+
+class ValidationWarning(TypedDict):
+    # the code passed to `info.warn()`, 'warning' by default
+    type: str
+    loc: 'tuple[int | str, ...]'
+    msg: str
+
+class MemoryReport(TypedDict):
+    slots: int
+    # schemas walked, each definition is counted once however many times it's referenced
+    nodes: int
+    # number of schemas of each type, e.g. {'str': 3, 'union': 1}
+    kinds: 'dict[str, int]'
+    # python objects retained, e.g. functions, classes and defaults
+    py_refs: int
+    # estimate of the memory used by the validator or serializer itself, excluding `py_refs`
+    estimated_bytes: int
+
+class DescriptionAccepts(TypedDict):
+    lax: 'list[str]'
+    strict: 'list[str]'
+
+class DescriptionNode(TypedDict):
+    # schema type, e.g. 'int', 'typed-dict'
+    kind: str
+    # for 'definition-ref' nodes, the key of the definition in `ValidatorDescription.definitions`
+    ref: 'str | None'
+    # default strictness, None if the validator has no strict setting
+    strict: 'bool | None'
+    has_default: bool
+    # input categories, e.g. 'str', 'mapping', 'opaque' if a function decides, None if decided by children or values
+    accepts: "DescriptionAccepts | Literal['opaque'] | None"
+    # only constraints which are set
+    constraints: 'dict[str, Any]'
+    # keyed by schema key e.g. 'items_schema', field or parameter name, tag or index
+    children: 'dict[str | int, DescriptionNode]'
+
+class ValidatorDescription(TypedDict):
+    validator: DescriptionNode
+    definitions: 'dict[str, DescriptionNode]'
+
+class DefinitionSummary(TypedDict):
+    ref: str
+    kind: Literal['slot', 'reusable', 'unused']
+    slot_id: 'int | None'
+    name: str
+    references: int
 
 IncEx: TypeAlias = 'set[int] | set[str] | dict[int, IncEx] | dict[str, IncEx] | None'
+# dotted paths for `include_paths` and `exclude_paths`, e.g. `'user.password'` or `'items.*.internal_id'`,
+# `*` matches any key or index, segments made of digits are indices, and `\` escapes the following character:
+# `'a\\.b'` is the key `'a.b'`, `'\\*'` is the key `'*'` and `'\\1'` is the string key `'1'`
+IncExPaths: TypeAlias = 'list[str] | tuple[str, ...] | set[str] | None'
 
 class SchemaSerializer:
     def __init__(self, schema: CoreSchema, config: 'CoreConfig | None' = None) -> None: ...
@@ -74,13 +210,19 @@ class SchemaSerializer:
         mode: str | None = None,
         include: IncEx = None,
         exclude: IncEx = None,
+        include_paths: IncExPaths = None,
+        exclude_paths: IncExPaths = None,
         by_alias: bool = True,
         exclude_unset: bool = False,
         exclude_defaults: bool = False,
         exclude_none: bool = False,
         round_trip: bool = False,
-        warnings: bool = True,
+        warnings: bool | Literal['none', 'warn', 'error'] | None = None,
         fallback: 'Callable[[Any], Any] | None' = None,
+        serialize_as_any: bool = False,
+        context: Any = None,
+        copy: Literal['none', 'shallow', 'deep'] | None = None,
+        round_trip_check: bool = False,
     ) -> Any: ...
     def to_json(
         self,
@@ -89,14 +231,45 @@ class SchemaSerializer:
         indent: int | None = None,
         include: IncEx = None,
         exclude: IncEx = None,
+        include_paths: IncExPaths = None,
+        exclude_paths: IncExPaths = None,
         by_alias: bool = True,
         exclude_unset: bool = False,
         exclude_defaults: bool = False,
         exclude_none: bool = False,
         round_trip: bool = False,
-        warnings: bool = True,
+        warnings: bool | Literal['none', 'warn', 'error'] | None = None,
         fallback: 'Callable[[Any], Any] | None' = None,
+        serialize_as_any: bool = False,
+        context: Any = None,
+        round_trip_check: bool = False,
     ) -> bytes: ...
+    # appends JSON to a bytearray, or passes it in chunks to `buffer.write(bytes)`, returning the number of bytes
+    # written, if an error is raised part of the output may already have been written to `buffer`
+    def to_json_into(
+        self,
+        value: Any,
+        buffer: Any,
+        *,
+        indent: int | None = None,
+        include: IncEx = None,
+        exclude: IncEx = None,
+        include_paths: IncExPaths = None,
+        exclude_paths: IncExPaths = None,
+        by_alias: bool = True,
+        exclude_unset: bool = False,
+        exclude_defaults: bool = False,
+        exclude_none: bool = False,
+        round_trip: bool = False,
+        warnings: bool | Literal['none', 'warn', 'error'] | None = None,
+        fallback: 'Callable[[Any], Any] | None' = None,
+        serialize_as_any: bool = False,
+        context: Any = None,
+    ) -> int: ...
+    def sub_serializer(self, path: 'tuple[str | int, ...]') -> 'SchemaSerializer': ...
+    def memory_report(self) -> MemoryReport: ...
+    def __copy__(self) -> 'SchemaSerializer': ...
+    def __deepcopy__(self, memo: Any) -> 'SchemaSerializer': ...
 
 def to_json(
     value: Any,
@@ -104,11 +277,14 @@ def to_json(
     indent: int | None = None,
     include: IncEx = None,
     exclude: IncEx = None,
+    include_paths: IncExPaths = None,
+    exclude_paths: IncExPaths = None,
     by_alias: bool = True,
     exclude_none: bool = False,
     round_trip: bool = False,
-    timedelta_mode: Literal['iso8601', 'float'] = 'iso8601',
-    bytes_mode: Literal['utf8', 'base64'] = 'utf8',
+    timedelta_mode: Literal['iso8601', 'seconds_float', 'float'] = 'iso8601',
+    bytes_mode: Literal['utf8', 'base64', 'base64url', 'hex'] = 'utf8',
+    inf_nan_mode: Literal['null', 'constants', 'strings'] = 'null',
     serialize_unknown: bool = False,
     fallback: 'Callable[[Any], Any] | None' = None,
 ) -> bytes: ...
@@ -117,11 +293,14 @@ def to_jsonable_python(
     *,
     include: IncEx = None,
     exclude: IncEx = None,
+    include_paths: IncExPaths = None,
+    exclude_paths: IncExPaths = None,
     by_alias: bool = True,
     exclude_none: bool = False,
     round_trip: bool = False,
-    timedelta_mode: Literal['iso8601', 'float'] = 'iso8601',
-    bytes_mode: Literal['utf8', 'base64'] = 'utf8',
+    timedelta_mode: Literal['iso8601', 'seconds_float', 'float'] = 'iso8601',
+    bytes_mode: Literal['utf8', 'base64', 'base64url', 'hex'] = 'utf8',
+    inf_nan_mode: Literal['null', 'constants', 'strings'] = 'null',
     serialize_unknown: bool = False,
     fallback: 'Callable[[Any], Any] | None' = None,
 ) -> Any: ...
@@ -149,6 +328,14 @@ class Url:
     def unicode_string(self) -> str: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: Url) -> bool: ...
+    def __le__(self, other: Url) -> bool: ...
+    def __gt__(self, other: Url) -> bool: ...
+    def __ge__(self, other: Url) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __copy__(self) -> Url: ...
+    def __deepcopy__(self, memo: Any) -> Url: ...
 
 class MultiHostHost(TypedDict):
     scheme: str
@@ -171,6 +358,14 @@ class MultiHostUrl:
     def unicode_string(self) -> str: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: MultiHostUrl) -> bool: ...
+    def __le__(self, other: MultiHostUrl) -> bool: ...
+    def __gt__(self, other: MultiHostUrl) -> bool: ...
+    def __ge__(self, other: MultiHostUrl) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __copy__(self) -> MultiHostUrl: ...
+    def __deepcopy__(self, memo: Any) -> MultiHostUrl: ...
 
 class SchemaError(Exception):
     def error_count(self) -> int: ...
@@ -180,11 +375,32 @@ class ValidationError(ValueError):
     def __init__(
         self, title: str, errors: 'list[InitErrorDetails]', error_mode: Literal['python', 'json'] = 'python'
     ) -> None: ...
+    @classmethod
+    def from_exception_data(
+        cls,
+        title: str,
+        line_errors: 'list[InitErrorDetails]',
+        *,
+        input_type: Literal['python', 'json'] = 'python',
+        hide_input: bool = False,
+    ) -> ValidationError: ...
     @property
     def title(self) -> str: ...
+    @property
+    def truncated(self) -> bool: ...
     def error_count(self) -> int: ...
-    def errors(self, include_context: bool = True) -> 'list[ErrorDetails]': ...
-    def json(self, indent: 'int | None' = None, include_context: bool = False) -> str: ...
+    def errors(
+        self,
+        include_context: bool = True,
+        *,
+        include_input: bool = True,
+        sort_errors: Literal['input', 'loc'] = 'input',
+        context_mode: Literal['python', 'json'] = 'python',
+        by_alias: 'bool | None' = None,
+    ) -> 'list[ErrorDetails]': ...
+    def json(
+        self, indent: 'int | None' = None, include_context: bool = True, *, include_input: bool = True
+    ) -> str: ...
 
 class PydanticCustomError(ValueError):
     def __init__(self, error_type: str, message_template: str, context: 'dict[str, Any] | None' = None) -> None: ...
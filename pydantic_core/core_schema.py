@@ -2,7 +2,9 @@ from __future__ import annotations as _annotations
 
 import sys
 from datetime import date, datetime, time, timedelta
-from typing import Any, Callable, Dict, List, Optional, Set, Type, Union
+from decimal import Decimal
+from fractions import Fraction
+from typing import TYPE_CHECKING, Any, Callable, Dict, Iterable, List, Optional, Pattern, Set, Type, Union
 
 if sys.version_info < (3, 11):
     from typing_extensions import Protocol, Required, TypeAlias
@@ -14,6 +16,9 @@ if sys.version_info < (3, 9):
 else:
     from typing import Literal, TypedDict
 
+if TYPE_CHECKING:
+    from pydantic_core import ValidationError
+
 
 def dict_not_none(**kwargs: Any) -> Any:
     return {k: v for k, v in kwargs.items() if v is not None}
@@ -24,6 +29,12 @@ ExtraBehavior = Literal['allow', 'forbid', 'ignore']
 
 class CoreConfig(TypedDict, total=False):
     title: str
+    # strictness of each schema is decided by, highest precedence first:
+    # 1. `strict` given for the validation call, e.g. `validate_python(..., strict=True)`, unless the schema or one
+    #    containing it sets `strict`
+    # 2. `strict` set on the schema itself, it isn't inherited from containers, e.g. a lax list can have strict items
+    # 3. `strict` in config
+    # 4. lax by default
     strict: bool
     # higher priority configs take precedence of over lower, if priority matches the two configs are merged, default 0
     config_choose_priority: int
@@ -43,17 +54,101 @@ class CoreConfig(TypedDict, total=False):
     validate_default: bool
     # used on typed-dicts and arguments
     populate_by_name: bool  # replaces `allow_population_by_field_name` in pydantic v1
+    # used on typed-dicts, fields with an alias can't be populated by name even with `populate_by_name`
+    require_alias: bool
+    # used on typed-dicts, keys which don't match a field exactly are matched case-insensitively
+    case_insensitive_fields: bool
     # fields related to string fields only
     str_max_length: int
     str_min_length: int
     str_strip_whitespace: bool
     str_to_lower: bool
     str_to_upper: bool
+    # the engine used for the `pattern` of str fields, 'rust-regex' is faster but doesn't support lookarounds or
+    # backreferences, 'python-re' uses `re.Pattern.match` so patterns must match at the start of the string,
+    # default 'rust-regex' unless `pattern` is a compiled `re.Pattern`
+    regex_engine: Literal['rust-regex', 'python-re']
     # fields related to float fields only
-    allow_inf_nan: bool  # default: True
+    allow_inf_nan: bool  # default: True, except for decimals where the default is False, also used by complex fields
+    # whether leaf validators call `__pydantic_core_coerce__(target)` on inputs they reject in lax mode
+    coerce_hook: bool  # default: True
+    # whether int fields reject JSON numbers with a fractional part or exponent (e.g. `1.0`) in lax mode too
+    json_strict_numbers: bool  # default: False
+    # how ints and floats are interpreted as unix timestamps by date and datetime fields, 'infer' treats values
+    # larger than 2e10 in either direction as milliseconds and other values as seconds
+    val_temporal_unit: Literal['seconds', 'milliseconds', 'infer']  # default: 'infer'
+    # whether fractions of a second with more than 6 digits in strings are truncated or raise an error, used by
+    # datetime, time and timedelta fields
+    microseconds_precision: Literal['truncate', 'error']  # default: 'truncate'
+    # the maximum length in bytes of any string (including keys) when parsing JSON, strings over the limit stop
+    # parsing before they're copied, default unlimited
+    max_str_bytes: int
+    # which python strings made when validating JSON are reused for repeated strings of up to 64 bytes, 'keys' only
+    # reuses object keys, 'none' makes a new string every time
+    cache_strings: Literal['all', 'keys', 'none']  # default: 'all'
+    # which value is kept when a key is repeated in a JSON object, keys are compared after unescaping, 'error' raises
+    # a `json_duplicate_key` error
+    json_duplicate_keys: Literal['last', 'first', 'error']  # default: 'last'
+    # how deeply definitions can recurse when validating, and arrays and objects can be nested when parsing JSON,
+    # deeper input is a `recursion_loop` error or invalid JSON, default 255 (123 on PyPy and WASM), at most 500
+    # (250 on PyPy and WASM) since deeper recursion could overflow the stack
+    recursion_limit: int
+    # whether the input is left out of `str()` and `repr()` of validation errors, it's still in `errors()` unless
+    # `include_input=False` is passed
+    hide_input_in_errors: bool  # default: False
+    # replacement message templates keyed by built-in error type, e.g. `{'string_too_short': 'at least {min_length}'}`,
+    # only `msg` changes, `type` and `ctx` don't, and `custom_error` schemas and `error_overrides` take precedence
+    custom_error_messages: Dict[str, str]
+    # the most errors collected by one validation, once reached lists, tuples, sets and dicts stop validating items
+    # and the `ValidationError` has `truncated=True`, default unlimited
+    max_errors: int
+    # how many expected values literal and tagged union errors show before "…and N more", default 20
+    error_expected_max_items: int
+    # whether literal and tagged union errors include every expected value as `expected_full` in their context
+    error_expected_full: bool  # default: False
+    # whether errors in typed dict and dataclass fields with a `title` include it as `field_title` in their context
+    error_field_title: bool  # default: True
+    # diagnostic only: when the choice selected by a tagged union's discriminator fails, also try every other choice
+    # in strict mode and add a `union_tag_other_matches` error listing the tags which would have accepted the input,
+    # validation still fails with the selected choice's errors
+    tagged_union_probe_all: bool  # default: False
+    # strings which nullable schemas validate as `None` in lax mode, e.g. `['', 'null', 'NULL']` for data from
+    # spreadsheets, whitespace is stripped before matching with `str_strip_whitespace`
+    none_strings: List[str]
+    # whether `none_strings` also applies to nullable `str` schemas, where the strings may be legitimate values
+    none_strings_apply_to_str: bool  # default: False
+    # by default subclasses of dict, list and tuple are read with the mapping and iterator protocols so overridden
+    # methods are used, with this they're read directly like the exact types which is faster
+    force_exact_container_path: bool  # default: False
+    # whether list, set, frozenset and tuple fields stop at the first item which fails validation instead of collecting
+    # the errors of every item
+    fail_fast: bool  # default: False
+    # whether validators are simplified when they're built: adjacent chain steps which only check constraints of the
+    # same `str` or `int` schema are merged into one, and `nullable` inside `nullable` or `default` schemas with no
+    # default are removed, accepted values and error types are unchanged
+    merge_validators: bool  # default: True
     # the config options are used to customise serialization to JSON
-    ser_json_timedelta: Literal['iso8601', 'float']  # default: 'iso8601'
-    ser_json_bytes: Literal['utf8', 'base64']  # default: 'utf8'
+    # 'seconds_float' writes the total number of seconds, e.g. `12.5`, which the timedelta validator reads back in lax
+    # mode, exact to the microsecond for durations up to 2**33 seconds (about 270 years), 'float' is an alias
+    ser_json_timedelta: Literal['iso8601', 'seconds_float', 'float']  # default: 'iso8601'
+    ser_json_bytes: Literal['utf8', 'base64', 'base64url', 'hex']  # default: 'utf8'
+    # 'str' writes complex numbers as strings like `"1.5+2j"`, 'pair' as `[real, imag]` arrays, both are accepted by
+    # the complex validator, infinite and NaN components are `null` in pairs as with floats
+    ser_json_complex: Literal['str', 'pair']  # default: 'str'
+    # how infinite and NaN floats and decimals are written by `to_json` and `to_python(mode='json')`: 'null' as `null`,
+    # 'constants' as `Infinity`, `-Infinity` and `NaN` like python's `json` module (not valid JSON), 'strings' as
+    # `"inf"`, `"-inf"` and `"nan"` which the float and decimal validators read back with `allow_inf_nan`
+    ser_json_inf_nan: Literal['null', 'constants', 'strings']  # default: 'null'
+    # whether `exclude_defaults` calls a field's `default_factory` to compare the value with, if false fields with a
+    # factory are never excluded, for factories which are expensive or have side effects
+    ser_call_default_factory: bool  # default: True
+    # how values which don't match their serializer's schema are reported, 'warn' emits a `UserWarning` after
+    # serializing them by inference, 'none' serializes them silently and 'error' raises `PydanticSerializationError`,
+    # overridden by `warnings` when serializing
+    ser_warnings: Literal['none', 'warn', 'error']  # default: 'warn'
+    # how the bytes validators decode JSON strings, before `min_length` and `max_length` are checked, 'base64' accepts
+    # the standard and URL-safe alphabets with or without padding, python inputs are unaffected
+    val_json_bytes: Literal['utf8', 'base64', 'base64url', 'hex']  # default: 'utf8'
 
 
 IncExCall: TypeAlias = 'set[int | str] | dict[int | str, IncExCall] | None'
@@ -92,6 +187,14 @@ class SerializationInfo(Protocol):
     def round_trip(self) -> bool:
         ...
 
+    @property
+    def context(self) -> Any:
+        """
+        The `context` passed to `to_python` or `to_json`, `None` if it wasn't set, it's only available to function
+        serializers and doesn't change how other values are serialized.
+        """
+        ...
+
     def mode_is_json(self) -> bool:
         ...
 
@@ -123,6 +226,21 @@ class ValidationInfo(Protocol):
         """The CoreConfig that applies to this validation."""
         ...
 
+    @property
+    def field_name(self) -> str | None:
+        """
+        The name of the typed dict, model or dataclass field being validated, or `None` if
+        this validator isn't run inside a field.
+        """
+        ...
+
+    def warn(self, message: str, *, code: str = 'warning') -> None:
+        """
+        Emit a warning about the value being validated, it doesn't affect validation and is only returned by
+        `validate_python_with_warnings` and `validate_json_with_warnings`.
+        """
+        ...
+
 
 class FieldValidationInfo(ValidationInfo, Protocol):
     """
@@ -143,6 +261,17 @@ class FieldValidationInfo(ValidationInfo, Protocol):
         ...
 
 
+class ModelValidationInfo(ValidationInfo, Protocol):
+    """
+    Argument passed to wrap validation functions which wrap a model schema.
+    """
+
+    @property
+    def model_class(self) -> Type[Any]:
+        """The class of the model being validated."""
+        ...
+
+
 ExpectedSerializationTypes = Literal[
     'none',
     'int',
@@ -226,6 +355,8 @@ JsonReturnTypes = Literal[
     'model',
     'enum',
     'path',
+    'uuid',
+    'pattern',
 ]
 
 WhenUsed = Literal['always', 'unless-none', 'json', 'json-unless-none']
@@ -388,17 +519,21 @@ class ModelSerSchema(TypedDict, total=False):
     type: Required[Literal['model']]
     cls: Required[Type[Any]]
     schema: Required[CoreSchema]
+    computed_fields: List[ComputedField]
 
 
-def model_ser_schema(cls: Type[Any], schema: CoreSchema) -> ModelSerSchema:
+def model_ser_schema(
+    cls: Type[Any], schema: CoreSchema, *, computed_fields: list[ComputedField] | None = None
+) -> ModelSerSchema:
     """
     Returns a schema for serialization using a model.
 
     Args:
         cls: The expected class type, used to generate warnings if the wrong type is passed
         schema: Internal schema to use to serialize the model dict
+        computed_fields: Properties of the model included after its fields, see `computed_field`
     """
-    return ModelSerSchema(type='model', cls=cls, schema=schema)
+    return dict_not_none(type='model', cls=cls, schema=schema, computed_fields=computed_fields)
 
 
 SerSchema = Union[
@@ -413,12 +548,19 @@ SerSchema = Union[
 
 class AnySchema(TypedDict, total=False):
     type: Required[Literal['any']]
+    record_types: bool  # default: False
     ref: str
     metadata: Any
     serialization: SerSchema
 
 
-def any_schema(*, ref: str | None = None, metadata: Any = None, serialization: SerSchema | None = None) -> AnySchema:
+def any_schema(
+    *,
+    record_types: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> AnySchema:
     """
     Returns a schema that matches any value, e.g.:
 
@@ -431,11 +573,15 @@ def any_schema(*, ref: str | None = None, metadata: Any = None, serialization: S
     ```
 
     Args:
+        record_types: Whether to record the type of every input in the report from `validate_python_with_report`,
+            as a coercion with `target_type='any'`, the input is never changed
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
-    return dict_not_none(type='any', ref=ref, metadata=metadata, serialization=serialization)
+    return dict_not_none(
+        type='any', record_types=record_types, ref=ref, metadata=metadata, serialization=serialization
+    )
 
 
 class NoneSchema(TypedDict, total=False):
@@ -468,13 +614,20 @@ def none_schema(*, ref: str | None = None, metadata: Any = None, serialization:
 class BoolSchema(TypedDict, total=False):
     type: Required[Literal['bool']]
     strict: bool
+    coerce: Callable[[Any], Optional[bool]]
+    numeric_mode: Literal['strict01', 'truthy']  # default: 'strict01'
     ref: str
     metadata: Any
     serialization: SerSchema
 
 
 def bool_schema(
-    strict: bool | None = None, ref: str | None = None, metadata: Any = None, serialization: SerSchema | None = None
+    strict: bool | None = None,
+    coerce: Callable[[Any], Optional[bool]] | None = None,
+    numeric_mode: Literal['strict01', 'truthy'] | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
 ) -> BoolSchema:
     """
     Returns a schema that matches a bool value, e.g.:
@@ -489,21 +642,46 @@ def bool_schema(
 
     Args:
         strict: Whether the value should be a bool or a value that can be converted to a bool
+        coerce: A function called with the input in lax mode before the default rules, it should return
+            `True` or `False`, or `None` to fall through to the default rules; exceptions raised by the function
+            become `bool_parsing` errors
+        numeric_mode: How numbers are handled in lax mode, `'strict01'` only accepts `0` and `1`, `'truthy'`
+            accepts any int or float with nonzero values being `True`, NaN is never valid
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
-    return dict_not_none(type='bool', strict=strict, ref=ref, metadata=metadata, serialization=serialization)
+    return dict_not_none(
+        type='bool',
+        strict=strict,
+        coerce=coerce,
+        numeric_mode=numeric_mode,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class ErrorOverride(TypedDict):
+    type: str
+    message: str  # may use the context of the original error, e.g. '{min_length}'
+
+
+MultipleOfMode = Literal['error', 'round', 'floor', 'ceil']
 
 
 class IntSchema(TypedDict, total=False):
     type: Required[Literal['int']]
     multiple_of: int
+    multiple_of_mode: MultipleOfMode  # default: 'error'
     le: int
     ge: int
     lt: int
     gt: int
     strict: bool
+    parse_radix_prefixes: bool  # default: False
+    scale: int
+    error_overrides: Dict[str, ErrorOverride]
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -512,11 +690,15 @@ class IntSchema(TypedDict, total=False):
 def int_schema(
     *,
     multiple_of: int | None = None,
+    multiple_of_mode: MultipleOfMode | None = None,
     le: int | None = None,
     ge: int | None = None,
     lt: int | None = None,
     gt: int | None = None,
     strict: bool | None = None,
+    parse_radix_prefixes: bool | None = None,
+    scale: int | None = None,
+    error_overrides: Dict[str, ErrorOverride] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -534,11 +716,20 @@ def int_schema(
 
     Args:
         multiple_of: The value must be a multiple of this number
+        multiple_of_mode: Whether a value which isn't a multiple of `multiple_of` raises an error (the default),
+            or is adjusted to the nearest multiple (`'round'`), the multiple below (`'floor'`) or above (`'ceil'`),
+            adjusting is exact, inputs must fit in a 64-bit integer like all ints but the adjusted value needn't
         le: The value must be less than or equal to this number
         ge: The value must be greater than or equal to this number
         lt: The value must be strictly less than this number
         gt: The value must be strictly greater than this number
         strict: Whether the value should be a int or a value that can be converted to a int
+        parse_radix_prefixes: Whether strings are parsed like `int(x, 0)` in lax mode, allowing `0x`, `0o` and `0b`
+            prefixes and underscores between digits, e.g. `'0xFF'` or `'1_000'`
+        scale: Validate decimals as an integer number of minor units with this many decimal places, e.g. with
+            `scale=2` `'12.34'` is validated as `1234`, inputs with more decimal places are invalid,
+            constraints apply to the number of minor units
+        error_overrides: Replacement `type` and `message` for errors raised by this schema, keyed by error type
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -546,26 +737,38 @@ def int_schema(
     return dict_not_none(
         type='int',
         multiple_of=multiple_of,
+        multiple_of_mode=multiple_of_mode,
         le=le,
         ge=ge,
         lt=lt,
         gt=gt,
         strict=strict,
+        parse_radix_prefixes=parse_radix_prefixes,
+        scale=scale,
+        error_overrides=error_overrides,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
     )
 
 
+class NumberFormat(TypedDict, total=False):
+    thousands_sep: str  # default: ','
+    decimal_sep: str  # default: '.'
+
+
 class FloatSchema(TypedDict, total=False):
     type: Required[Literal['float']]
     allow_inf_nan: bool  # whether 'NaN', '+inf', '-inf' should be forbidden. default: True
+    number_format: NumberFormat
     multiple_of: float
+    multiple_of_mode: MultipleOfMode  # default: 'error'
     le: float
     ge: float
     lt: float
     gt: float
     strict: bool
+    error_overrides: Dict[str, ErrorOverride]
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -574,12 +777,15 @@ class FloatSchema(TypedDict, total=False):
 def float_schema(
     *,
     allow_inf_nan: bool | None = None,
+    number_format: NumberFormat | None = None,
     multiple_of: float | None = None,
+    multiple_of_mode: MultipleOfMode | None = None,
     le: float | None = None,
     ge: float | None = None,
     lt: float | None = None,
     gt: float | None = None,
     strict: bool | None = None,
+    error_overrides: Dict[str, ErrorOverride] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -597,12 +803,17 @@ def float_schema(
 
     Args:
         allow_inf_nan: Whether to allow inf and nan values
+        number_format: Separators used to parse strings in lax mode, e.g. `{'thousands_sep': '.', 'decimal_sep': ','}`
+            validates `'1.234,5'` as `1234.5`, a separator in an invalid position is a parsing error
         multiple_of: The value must be a multiple of this number
+        multiple_of_mode: Whether a value which isn't a multiple of `multiple_of` raises an error (the default),
+            or is adjusted to the nearest multiple (`'round'`), the multiple below (`'floor'`) or above (`'ceil'`)
         le: The value must be less than or equal to this number
         ge: The value must be greater than or equal to this number
         lt: The value must be strictly less than this number
         gt: The value must be strictly greater than this number
         strict: Whether the value should be a float or a value that can be converted to a float
+        error_overrides: Replacement `type` and `message` for errors raised by this schema, keyed by error type
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -610,12 +821,15 @@ def float_schema(
     return dict_not_none(
         type='float',
         allow_inf_nan=allow_inf_nan,
+        number_format=number_format,
         multiple_of=multiple_of,
+        multiple_of_mode=multiple_of_mode,
         le=le,
         ge=ge,
         lt=lt,
         gt=gt,
         strict=strict,
+        error_overrides=error_overrides,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -624,13 +838,16 @@ def float_schema(
 
 class StringSchema(TypedDict, total=False):
     type: Required[Literal['str']]
-    pattern: str
+    pattern: Union[str, Pattern[str]]
+    regex_engine: Literal['rust-regex', 'python-re']
     max_length: int
     min_length: int
     strip_whitespace: bool
     to_lower: bool
     to_upper: bool
+    post_validator: Callable[[str], str]
     strict: bool
+    error_overrides: Dict[str, ErrorOverride]
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -638,13 +855,16 @@ class StringSchema(TypedDict, total=False):
 
 def str_schema(
     *,
-    pattern: str | None = None,
+    pattern: str | Pattern[str] | None = None,
+    regex_engine: Literal['rust-regex', 'python-re'] | None = None,
     max_length: int | None = None,
     min_length: int | None = None,
     strip_whitespace: bool | None = None,
     to_lower: bool | None = None,
     to_upper: bool | None = None,
+    post_validator: Callable[[str], str] | None = None,
     strict: bool | None = None,
+    error_overrides: Dict[str, ErrorOverride] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -661,13 +881,18 @@ def str_schema(
     ```
 
     Args:
-        pattern: A regex pattern that the value must match
+        pattern: A regex pattern that the value must match, or a compiled `re.Pattern` with `regex_engine='python-re'`
+        regex_engine: The engine used for `pattern`, `'rust-regex'` is faster but doesn't support lookarounds or
+            backreferences, `'python-re'` uses `re.Pattern.match` so the pattern must match at the start of the value,
+            defaults to `'rust-regex'` unless `pattern` is a compiled `re.Pattern`
         max_length: The value must be at most this length
         min_length: The value must be at least this length
         strip_whitespace: Whether to strip whitespace from the value
         to_lower: Whether to convert the value to lowercase
         to_upper: Whether to convert the value to uppercase
+        post_validator: A function called with the validated string after all other checks, returning the final value
         strict: Whether the value should be a string or a value that can be converted to a string
+        error_overrides: Replacement `type` and `message` for errors raised by this schema, keyed by error type
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -675,12 +900,15 @@ def str_schema(
     return dict_not_none(
         type='str',
         pattern=pattern,
+        regex_engine=regex_engine,
         max_length=max_length,
         min_length=min_length,
         strip_whitespace=strip_whitespace,
         to_lower=to_lower,
         to_upper=to_upper,
+        post_validator=post_validator,
         strict=strict,
+        error_overrides=error_overrides,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -692,6 +920,8 @@ class BytesSchema(TypedDict, total=False):
     max_length: int
     min_length: int
     strict: bool
+    output: Literal['bytes', 'bytearray']  # default: 'bytes'
+    ser_json_bytes: Literal['utf8', 'base64', 'base64url', 'hex']  # default: from config
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -702,6 +932,8 @@ def bytes_schema(
     max_length: int | None = None,
     min_length: int | None = None,
     strict: bool | None = None,
+    output: Literal['bytes', 'bytearray'] | None = None,
+    ser_json_bytes: Literal['utf8', 'base64', 'base64url', 'hex'] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -721,6 +953,8 @@ def bytes_schema(
         max_length: The value must be at most this length
         min_length: The value must be at least this length
         strict: Whether the value should be a bytes or a value that can be converted to a bytes
+        output: Whether to return a `bytes` or a mutable `bytearray`, in strict mode only this type is accepted
+        ser_json_bytes: How the value is serialized to JSON, overriding `ser_json_bytes` from config
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -730,6 +964,8 @@ def bytes_schema(
         max_length=max_length,
         min_length=min_length,
         strict=strict,
+        output=output,
+        ser_json_bytes=ser_json_bytes,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -747,6 +983,7 @@ class DateSchema(TypedDict, total=False):
     # defaults to current local utc offset from `time.localtime().tm_gmtoff`
     # value is restricted to -86_400 < offset < 86_400 by bounds in generate_self_schema.py
     now_utc_offset: int
+    val_temporal_unit: Literal['seconds', 'milliseconds', 'infer']  # default: 'infer'
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -761,6 +998,7 @@ def date_schema(
     gt: date | None = None,
     now_op: Literal['past', 'future'] | None = None,
     now_utc_offset: int | None = None,
+    val_temporal_unit: Literal['seconds', 'milliseconds', 'infer'] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -785,6 +1023,8 @@ def date_schema(
         gt: The value must be strictly greater than this date
         now_op: The value must be in the past or future relative to the current date
         now_utc_offset: The value must be in the past or future relative to the current date with this utc offset
+        val_temporal_unit: Whether numbers are unix timestamps in `'seconds'` or `'milliseconds'`, by default
+            (`'infer'`) values larger than 2e10 in either direction are milliseconds and other values seconds
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -798,6 +1038,7 @@ def date_schema(
         gt=gt,
         now_op=now_op,
         now_utc_offset=now_utc_offset,
+        val_temporal_unit=val_temporal_unit,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -811,6 +1052,7 @@ class TimeSchema(TypedDict, total=False):
     ge: time
     lt: time
     gt: time
+    microseconds_precision: Literal['truncate', 'error']  # default: 'truncate'
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -823,6 +1065,7 @@ def time_schema(
     ge: time | None = None,
     lt: time | None = None,
     gt: time | None = None,
+    microseconds_precision: Literal['truncate', 'error'] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -845,12 +1088,23 @@ def time_schema(
         ge: The value must be greater than or equal to this time
         lt: The value must be strictly less than this time
         gt: The value must be strictly greater than this time
+        microseconds_precision: Whether fractions of a second with more than 6 digits in strings are truncated
+            to microseconds (`'truncate'`, they are never rounded) or raise a `datetime_microseconds_precision` error
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
     return dict_not_none(
-        type='time', strict=strict, le=le, ge=ge, lt=lt, gt=gt, ref=ref, metadata=metadata, serialization=serialization
+        type='time',
+        strict=strict,
+        le=le,
+        ge=ge,
+        lt=lt,
+        gt=gt,
+        microseconds_precision=microseconds_precision,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
     )
 
 
@@ -862,10 +1116,16 @@ class DatetimeSchema(TypedDict, total=False):
     lt: datetime
     gt: datetime
     now_op: Literal['past', 'future']
-    tz_constraint: Literal['aware', 'naive']
+    # 'aware', 'naive' or the exact UTC offset in seconds, also restricted to -86_400 < offset < 86_400
+    tz_constraint: Union[Literal['aware', 'naive'], int]
     # defaults to current local utc offset from `time.localtime().tm_gmtoff`
     # value is restricted to -86_400 < offset < 86_400 by bounds in generate_self_schema.py
     now_utc_offset: int
+    strict_rfc3339: bool  # default: False
+    tz_names: bool  # default: False
+    dst_policy: Literal['earlier', 'later', 'error']  # default: 'error'
+    val_temporal_unit: Literal['seconds', 'milliseconds', 'infer']  # default: 'infer'
+    microseconds_precision: Literal['truncate', 'error']  # default: 'truncate'
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -879,8 +1139,13 @@ def datetime_schema(
     lt: datetime | None = None,
     gt: datetime | None = None,
     now_op: Literal['past', 'future'] | None = None,
-    tz_constraint: Literal['aware', 'naive'] | None = None,
+    tz_constraint: Literal['aware', 'naive'] | int | None = None,
     now_utc_offset: int | None = None,
+    strict_rfc3339: bool | None = None,
+    tz_names: bool | None = None,
+    dst_policy: Literal['earlier', 'later', 'error'] | None = None,
+    val_temporal_unit: Literal['seconds', 'milliseconds', 'infer'] | None = None,
+    microseconds_precision: Literal['truncate', 'error'] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -905,8 +1170,21 @@ def datetime_schema(
         lt: The value must be strictly less than this datetime
         gt: The value must be strictly greater than this datetime
         now_op: The value must be in the past or future relative to the current datetime
-        tz_constraint: The value must be timezone aware or naive
+        tz_constraint: The value must be timezone aware or naive, or aware with exactly this UTC offset in seconds,
+            timestamps are treated as UTC and returned as aware datetimes when this is set
         now_utc_offset: The value must be in the past or future relative to the current datetime with this utc offset
+        strict_rfc3339: Whether inputs other than datetime objects must be RFC 3339 strings,
+            e.g. `2022-06-08T12:30:00Z`
+        tz_names: Whether strings may end with a space and an IANA time zone name, e.g.
+            `2024-03-10 02:30:00 America/New_York`, the zone is looked up with `zoneinfo` and attached to the
+            output as a `zoneinfo.ZoneInfo`, the rest of the string must not include a UTC offset
+        dst_policy: How times in a named zone which don't exist or are ambiguous because of a DST transition are
+            handled, `'earlier'` or `'later'` use the earlier or later of the possible instants,
+            `'error'` raises a `datetime_nonexistent` or `datetime_ambiguous` error
+        val_temporal_unit: Whether numbers are unix timestamps in `'seconds'` or `'milliseconds'`, by default
+            (`'infer'`) values larger than 2e10 in either direction are milliseconds and other values seconds
+        microseconds_precision: Whether fractions of a second with more than 6 digits in strings are truncated
+            to microseconds (`'truncate'`, they are never rounded) or raise a `datetime_microseconds_precision` error
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -921,6 +1199,11 @@ def datetime_schema(
         now_op=now_op,
         tz_constraint=tz_constraint,
         now_utc_offset=now_utc_offset,
+        strict_rfc3339=strict_rfc3339,
+        tz_names=tz_names,
+        dst_policy=dst_policy,
+        val_temporal_unit=val_temporal_unit,
+        microseconds_precision=microseconds_precision,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -934,6 +1217,8 @@ class TimedeltaSchema(TypedDict, total=False):
     ge: timedelta
     lt: timedelta
     gt: timedelta
+    multiple_of: timedelta
+    microseconds_precision: Literal['truncate', 'error']  # default: 'truncate'
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -942,10 +1227,12 @@ class TimedeltaSchema(TypedDict, total=False):
 def timedelta_schema(
     *,
     strict: bool | None = None,
-    le: timedelta | None = None,
-    ge: timedelta | None = None,
-    lt: timedelta | None = None,
-    gt: timedelta | None = None,
+    le: timedelta | str | float | None = None,
+    ge: timedelta | str | float | None = None,
+    lt: timedelta | str | float | None = None,
+    gt: timedelta | str | float | None = None,
+    multiple_of: timedelta | str | float | None = None,
+    microseconds_precision: Literal['truncate', 'error'] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -968,9 +1255,14 @@ def timedelta_schema(
         ge: The value must be greater than or equal to this timedelta
         lt: The value must be strictly less than this timedelta
         gt: The value must be strictly greater than this timedelta
+        multiple_of: The value must be a whole multiple of this timedelta, e.g. `timedelta(minutes=1)`
+        microseconds_precision: Whether fractions of a second with more than 6 digits in strings are truncated
+            to microseconds (`'truncate'`, they are never rounded) or raise a `datetime_microseconds_precision` error
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
+
+    Constraints may also be given as an ISO 8601 duration string or a number of seconds.
     """
     return dict_not_none(
         type='timedelta',
@@ -979,6 +1271,8 @@ def timedelta_schema(
         ge=ge,
         lt=lt,
         gt=gt,
+        multiple_of=multiple_of,
+        microseconds_precision=microseconds_precision,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -987,14 +1281,20 @@ def timedelta_schema(
 
 class LiteralSchema(TypedDict, total=False):
     type: Required[Literal['literal']]
-    expected: Required[List[Any]]
+    expected: List[Any]
+    allowed_values_factory: Callable[[], Any]  # should return an iterable of the allowed values
     ref: str
     metadata: Any
     serialization: SerSchema
 
 
 def literal_schema(
-    expected: list[Any], *, ref: str | None = None, metadata: Any = None, serialization: SerSchema | None = None
+    expected: list[Any] | None = None,
+    *,
+    allowed_values_factory: Callable[[], Iterable[Any]] | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
 ) -> LiteralSchema:
     """
     Returns a schema that matches a literal value, e.g.:
@@ -1007,13 +1307,99 @@ def literal_schema(
     assert v.validate_python('hello') == 'hello'
     ```
 
+    Exactly one of `expected` or `allowed_values_factory` must be provided.
+
+    Args:
+        expected: The value must be one of these values, enum members are returned when the member is matched,
+            or in lax mode when its value is
+        allowed_values_factory: A function returning an iterable of the values currently allowed, called each time
+            the schema is validated, errors show the values allowed at the time
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return dict_not_none(
+        type='literal',
+        expected=expected,
+        allowed_values_factory=allowed_values_factory,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class EnumSchema(TypedDict, total=False):
+    type: Required[Literal['enum']]
+    cls: Required[Any]
+    members: List[Any]
+    by_name: bool  # default: False
+    by_name_case_insensitive: bool  # default: False
+    missing: Callable[[Any], Any]
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def enum_schema(
+    cls: Any,
+    members: list[Any] | None = None,
+    *,
+    by_name: bool | None = None,
+    by_name_case_insensitive: bool | None = None,
+    missing: Callable[[Any], Any] | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> EnumSchema:
+    """
+    Returns a schema that matches an enum member, e.g.:
+
+    ```py
+    from enum import Enum
+    from pydantic_core import SchemaValidator, core_schema
+
+    class Color(Enum):
+        RED = 'red'
+        GREEN = 'green'
+
+    schema = core_schema.enum_schema(Color)
+    v = SchemaValidator(schema)
+    assert v.validate_python('red') is Color.RED
+    assert v.validate_python(Color.GREEN) is Color.GREEN
+    ```
+
+    Members are matched by identity, so members of other enums are rejected. In lax mode members are also
+    validated from their values, values of `int` and `str` enums are validated as ints and strings, e.g. `'1'`
+    matches an `IntEnum` member with the value `1`, values of other enums are compared by equality as `Enum(value)`
+    does. JSON has no enum type, so values are accepted from JSON in strict mode too.
+
     Args:
-        expected: The value must be one of these values
+        cls: The enum class
+        members: The members to accept, defaults to all members of `cls`
+        by_name: Whether strings are also matched to member names, e.g. `'RED'` to `Color.RED`, values are tried
+            first, names are accepted wherever values are
+        by_name_case_insensitive: Whether names are matched ignoring case, requires `by_name`
+        missing: Called with the input when it doesn't match any member, like `Enum._missing_`, should return
+            a member or `None` to fail validation
+        strict: Whether only members are accepted from python
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
-    return dict_not_none(type='literal', expected=expected, ref=ref, metadata=metadata, serialization=serialization)
+    return dict_not_none(
+        type='enum',
+        cls=cls,
+        members=members,
+        by_name=by_name,
+        by_name_case_insensitive=by_name_case_insensitive,
+        missing=missing,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
 
 
 # must match input/parse_json.rs::JsonType::try_from
@@ -1171,7 +1557,12 @@ class ListSchema(TypedDict, total=False):
     min_length: int
     max_length: int
     strict: bool
+    fail_fast: bool  # default: False
     allow_any_iter: bool
+    unique_items: bool
+    nan_equality: Literal['ieee', 'identity']  # default: 'ieee'
+    output_preserve_subclass: bool  # default: False
+    error_overrides: Dict[str, ErrorOverride]
     ref: str
     metadata: Any
     serialization: IncExSeqOrElseSerSchema
@@ -1183,7 +1574,12 @@ def list_schema(
     min_length: int | None = None,
     max_length: int | None = None,
     strict: bool | None = None,
+    fail_fast: bool | None = None,
     allow_any_iter: bool | None = None,
+    unique_items: bool | None = None,
+    nan_equality: Literal['ieee', 'identity'] | None = None,
+    output_preserve_subclass: bool | None = None,
+    error_overrides: Dict[str, ErrorOverride] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: IncExSeqOrElseSerSchema | None = None,
@@ -1204,7 +1600,16 @@ def list_schema(
         min_length: The value must be a list with at least this many items
         max_length: The value must be a list with at most this many items
         strict: The value must be a list with exactly this many items
+        fail_fast: Whether to stop at the first item which fails validation instead of collecting the errors of
+            every item, defaults to the config value
         allow_any_iter: Whether the value can be any iterable
+        unique_items: Whether the items must be unique after validation, items are compared with `==`
+        nan_equality: How NaN floats are compared when checking `unique_items`, with `'ieee'` NaN is never equal
+            to anything so NaN items are always unique, with `'identity'` NaN is equal to itself so
+            only one NaN item is allowed
+        output_preserve_subclass: Whether a list subclass given as input is returned as an instance of that subclass,
+            built by calling it with the validated items, otherwise the output is always a plain `list`
+        error_overrides: Replacement `type` and `message` for errors raised by this schema, keyed by error type
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1215,7 +1620,12 @@ def list_schema(
         min_length=min_length,
         max_length=max_length,
         strict=strict,
+        fail_fast=fail_fast,
         allow_any_iter=allow_any_iter,
+        unique_items=unique_items,
+        nan_equality=nan_equality,
+        output_preserve_subclass=output_preserve_subclass,
+        error_overrides=error_overrides,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1226,7 +1636,10 @@ class TuplePositionalSchema(TypedDict, total=False):
     type: Required[Literal['tuple-positional']]
     items_schema: Required[List[CoreSchema]]
     extra_schema: CoreSchema
+    fill_missing: bool  # default: True
     strict: bool
+    fail_fast: bool  # default: False
+    error_overrides: Dict[str, ErrorOverride]
     ref: str
     metadata: Any
     serialization: IncExSeqOrElseSerSchema
@@ -1236,7 +1649,10 @@ def tuple_positional_schema(
     items_schema: list[CoreSchema],
     *,
     extra_schema: CoreSchema | None = None,
+    fill_missing: bool | None = None,
     strict: bool | None = None,
+    fail_fast: bool | None = None,
+    error_overrides: Dict[str, ErrorOverride] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: IncExSeqOrElseSerSchema | None = None,
@@ -1260,7 +1676,12 @@ def tuple_positional_schema(
             This was inspired by JSON schema's `prefixItems` and `items` fields.
             In python's `typing.Tuple`, you can't specify a type for "extra" items -- they must all be the same type
             if the length is variable. So this field won't be set from a `typing.Tuple` annotation on a pydantic model.
+        fill_missing: Whether missing trailing items are filled from the defaults of their `with_default_schema`
+            schemas (the default), if `False` a tuple with fewer items than `items_schema` is always an error
         strict: The value must be a tuple with exactly this many items
+        fail_fast: Whether to stop at the first item which fails validation instead of collecting the errors of
+            every item, defaults to the config value
+        error_overrides: Replacement `type` and `message` for errors raised by this schema, keyed by error type
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1269,7 +1690,10 @@ def tuple_positional_schema(
         type='tuple-positional',
         items_schema=items_schema,
         extra_schema=extra_schema,
+        fill_missing=fill_missing,
         strict=strict,
+        fail_fast=fail_fast,
+        error_overrides=error_overrides,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1282,6 +1706,8 @@ class TupleVariableSchema(TypedDict, total=False):
     min_length: int
     max_length: int
     strict: bool
+    fail_fast: bool  # default: False
+    error_overrides: Dict[str, ErrorOverride]
     ref: str
     metadata: Any
     serialization: IncExSeqOrElseSerSchema
@@ -1293,6 +1719,8 @@ def tuple_variable_schema(
     min_length: int | None = None,
     max_length: int | None = None,
     strict: bool | None = None,
+    fail_fast: bool | None = None,
+    error_overrides: Dict[str, ErrorOverride] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: IncExSeqOrElseSerSchema | None = None,
@@ -1315,6 +1743,9 @@ def tuple_variable_schema(
         min_length: The value must be a tuple with at least this many items
         max_length: The value must be a tuple with at most this many items
         strict: The value must be a tuple with exactly this many items
+        fail_fast: Whether to stop at the first item which fails validation instead of collecting the errors of
+            every item, defaults to the config value
+        error_overrides: Replacement `type` and `message` for errors raised by this schema, keyed by error type
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1325,6 +1756,8 @@ def tuple_variable_schema(
         min_length=min_length,
         max_length=max_length,
         strict=strict,
+        fail_fast=fail_fast,
+        error_overrides=error_overrides,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1338,6 +1771,8 @@ class SetSchema(TypedDict, total=False):
     max_length: int
     generator_max_length: int
     strict: bool
+    fail_fast: bool  # default: False
+    error_overrides: Dict[str, ErrorOverride]
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -1350,6 +1785,8 @@ def set_schema(
     max_length: int | None = None,
     generator_max_length: int | None = None,
     strict: bool | None = None,
+    fail_fast: bool | None = None,
+    error_overrides: Dict[str, ErrorOverride] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -1370,11 +1807,15 @@ def set_schema(
     Args:
         items_schema: The value must be a set with items that match this schema
         min_length: The value must be a set with at least this many items
-        max_length: The value must be a set with at most this many items
+        max_length: The value must be a set with at most this many items, for generators and other iterables this
+            is checked as items are added so reading stops once there are more distinct items
         generator_max_length: At most this many items will be read from a generator before failing validation
             This is important because generators can be infinite, and even with a `max_length` on the set,
             an infinite generator could run forever without producing more than `max_length` distinct items.
         strict: The value must be a set with exactly this many items
+        fail_fast: Whether to stop at the first item which fails validation instead of collecting the errors of
+            every item, defaults to the config value
+        error_overrides: Replacement `type` and `message` for errors raised by this schema, keyed by error type
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1386,6 +1827,8 @@ def set_schema(
         max_length=max_length,
         generator_max_length=generator_max_length,
         strict=strict,
+        fail_fast=fail_fast,
+        error_overrides=error_overrides,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1399,6 +1842,8 @@ class FrozenSetSchema(TypedDict, total=False):
     max_length: int
     generator_max_length: int
     strict: bool
+    fail_fast: bool  # default: False
+    error_overrides: Dict[str, ErrorOverride]
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -1411,6 +1856,8 @@ def frozenset_schema(
     max_length: int | None = None,
     generator_max_length: int | None = None,
     strict: bool | None = None,
+    fail_fast: bool | None = None,
+    error_overrides: Dict[str, ErrorOverride] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -1431,9 +1878,13 @@ def frozenset_schema(
     Args:
         items_schema: The value must be a frozenset with items that match this schema
         min_length: The value must be a frozenset with at least this many items
-        max_length: The value must be a frozenset with at most this many items
+        max_length: The value must be a frozenset with at most this many items, for generators and other iterables this
+            is checked as items are added so reading stops once there are more distinct items
         generator_max_length: The value must generate a frozenset with at most this many items
         strict: The value must be a frozenset with exactly this many items
+        fail_fast: Whether to stop at the first item which fails validation instead of collecting the errors of
+            every item, defaults to the config value
+        error_overrides: Replacement `type` and `message` for errors raised by this schema, keyed by error type
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1445,6 +1896,8 @@ def frozenset_schema(
         max_length=max_length,
         generator_max_length=generator_max_length,
         strict=strict,
+        fail_fast=fail_fast,
+        error_overrides=error_overrides,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1530,7 +1983,13 @@ class DictSchema(TypedDict, total=False):
     values_schema: CoreSchema  # default: AnySchema
     min_length: int
     max_length: int
+    required_keys: List[str]
     strict: bool
+    output_type: Literal['dict', 'ordered_dict', 'defaultdict']  # default: 'dict'
+    default_factory: Callable[[], Any]
+    defaultdict_strict: bool  # default: False
+    output_preserve_subclass: bool  # default: False
+    error_overrides: Dict[str, ErrorOverride]
     ref: str
     metadata: Any
     serialization: IncExDictOrElseSerSchema
@@ -1542,7 +2001,13 @@ def dict_schema(
     *,
     min_length: int | None = None,
     max_length: int | None = None,
+    required_keys: List[str] | None = None,
     strict: bool | None = None,
+    output_type: Literal['dict', 'ordered_dict', 'defaultdict'] | None = None,
+    default_factory: Callable[[], Any] | None = None,
+    defaultdict_strict: bool | None = None,
+    output_preserve_subclass: bool | None = None,
+    error_overrides: Dict[str, ErrorOverride] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -1565,7 +2030,18 @@ def dict_schema(
         values_schema: The value must be a dict with values that match this schema
         min_length: The value must be a dict with at least this many items
         max_length: The value must be a dict with at most this many items
+        required_keys: String keys which must be present in the dict, each absent key is reported as `missing`
         strict: Whether the keys and values should be validated with strict mode
+        output_type: The type of the output, `'ordered_dict'` returns a `collections.OrderedDict` in input order,
+            `'defaultdict'` returns a `collections.defaultdict`
+        default_factory: The `default_factory` of the `defaultdict` returned with `output_type='defaultdict'`,
+            if omitted the `default_factory` of a `defaultdict` input is used, otherwise it's `None`
+        defaultdict_strict: With `output_type='defaultdict'`, whether strict mode only accepts `defaultdict`
+            instances as python input, by default any dict is accepted, JSON objects are always accepted
+        output_preserve_subclass: Whether a dict subclass given as input is returned as an instance of that subclass,
+            built by calling it with the validated dict, a `defaultdict` keeps its `default_factory`, this takes
+            precedence over `output_type`
+        error_overrides: Replacement `type` and `message` for errors raised by this schema, keyed by error type
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1576,7 +2052,13 @@ def dict_schema(
         values_schema=values_schema,
         min_length=min_length,
         max_length=max_length,
+        required_keys=required_keys,
         strict=strict,
+        output_type=output_type,
+        default_factory=default_factory,
+        defaultdict_strict=defaultdict_strict,
+        output_preserve_subclass=output_preserve_subclass,
+        error_overrides=error_overrides,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1621,14 +2103,22 @@ class _ValidatorFunctionSchema(TypedDict, total=False):
     serialization: SerSchema
 
 
+# (__loc: tuple[int | str, ...]) -> tuple[int | str, ...]
+LocModeFunction = Callable[[Any], Any]
+
+LocMode = Union[Literal['inner', 'outer'], LocModeFunction]
+
+
 class BeforeValidatorFunctionSchema(_ValidatorFunctionSchema, total=False):
     type: Required[Literal['function-before']]
+    loc_mode: LocMode  # default: 'inner'
 
 
 def no_info_before_validator_function(
     function: NoInfoValidatorFunction,
     schema: CoreSchema,
     *,
+    loc_mode: LocMode | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -1654,6 +2144,9 @@ def no_info_before_validator_function(
     Args:
         function: The validator function to call
         schema: The schema to validate the output of the validator function
+        loc_mode: How the locations of errors from `schema` are reported, `'inner'` keeps them relative to the
+            output of the function, `'outer'` reports them at the location of this validator, a callable is given
+            the inner location tuple and returns a replacement
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1662,6 +2155,7 @@ def no_info_before_validator_function(
         type='function-before',
         function={'type': 'no-info', 'function': function},
         schema=schema,
+        loc_mode=loc_mode,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1672,6 +2166,7 @@ def field_before_validator_function(
     function: FieldValidatorFunction,
     schema: CoreSchema,
     *,
+    loc_mode: LocMode | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -1700,6 +2195,9 @@ def field_before_validator_function(
     Args:
         function: The validator function to call
         schema: The schema to validate the output of the validator function
+        loc_mode: How the locations of errors from `schema` are reported, `'inner'` keeps them relative to the
+            output of the function, `'outer'` reports them at the location of this validator, a callable is given
+            the inner location tuple and returns a replacement
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1708,6 +2206,7 @@ def field_before_validator_function(
         type='function-before',
         function={'type': 'field', 'function': function},
         schema=schema,
+        loc_mode=loc_mode,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1718,6 +2217,7 @@ def general_before_validator_function(
     function: GeneralValidatorFunction,
     schema: CoreSchema,
     *,
+    loc_mode: LocMode | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -1744,6 +2244,9 @@ def general_before_validator_function(
     Args:
         function: The validator function to call
         schema: The schema to validate the output of the validator function
+        loc_mode: How the locations of errors from `schema` are reported, `'inner'` keeps them relative to the
+            output of the function, `'outer'` reports them at the location of this validator, a callable is given
+            the inner location tuple and returns a replacement
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1752,6 +2255,7 @@ def general_before_validator_function(
         type='function-before',
         function={'type': 'general', 'function': function},
         schema=schema,
+        loc_mode=loc_mode,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -2205,12 +2709,19 @@ def field_plain_validator_function(
     )
 
 
+class OnErrorCallFunction(Protocol):
+    def __call__(self, input_value: Any, validation_error: ValidationError) -> Any:  # pragma: no cover
+        ...
+
+
 class WithDefaultSchema(TypedDict, total=False):
     type: Required[Literal['default']]
     schema: Required[CoreSchema]
     default: Any
-    default_factory: Callable[[], Any]
-    on_error: Literal['raise', 'omit', 'default']  # default: 'raise'
+    default_factory: Union[Callable[[], Any], Callable[[Dict[str, Any]], Any]]
+    default_factory_takes_data: bool  # default: False
+    on_error: Literal['raise', 'omit', 'default', 'call']  # default: 'raise'
+    on_error_call: OnErrorCallFunction
     validate_default: bool  # default: False
     strict: bool
     ref: str
@@ -2225,8 +2736,10 @@ def with_default_schema(
     schema: CoreSchema,
     *,
     default: Any = Omitted,
-    default_factory: Callable[[], Any] | None = None,
-    on_error: Literal['raise', 'omit', 'default'] | None = None,
+    default_factory: Union[Callable[[], Any], Callable[[Dict[str, Any]], Any], None] = None,
+    default_factory_takes_data: bool | None = None,
+    on_error: Literal['raise', 'omit', 'default', 'call'] | None = None,
+    on_error_call: OnErrorCallFunction | None = None,
     validate_default: bool | None = None,
     strict: bool | None = None,
     ref: str | None = None,
@@ -2251,8 +2764,13 @@ def with_default_schema(
         schema: The schema to add a default value to
         default: The default value to use
         default_factory: A function that returns the default value to use
-        on_error: What to do if the schema validation fails. One of 'raise', 'omit', 'default'
-        validate_default: Whether the default value should be validated
+        default_factory_takes_data: Whether `default_factory` is called with a read-only mapping of the fields
+            validated before this one, only possible for fields of a typed dict, model or dataclass
+        on_error: What to do if the schema validation fails. One of 'raise', 'omit', 'default', 'call'
+        on_error_call: With `on_error='call'`, called with the input and the `ValidationError` if the schema
+            validation fails, the value it returns is used instead, exceptions it raises aren't caught
+        validate_default: Whether the default value should be validated, a static `default` is validated once
+            when the schema is built if `schema` only returns immutable values, e.g. `int_schema()`
         strict: Whether the underlying schema should be validated with strict mode
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -2262,7 +2780,9 @@ def with_default_schema(
         type='default',
         schema=schema,
         default_factory=default_factory,
+        default_factory_takes_data=default_factory_takes_data,
         on_error=on_error,
+        on_error_call=on_error_call,
         validate_default=validate_default,
         strict=strict,
         ref=ref,
@@ -2314,11 +2834,42 @@ def nullable_schema(
     )
 
 
+class UnionChoice(TypedDict, total=False):
+    schema: Required[CoreSchema]
+    priority: int  # default: 0
+    strict: bool  # default: False
+
+
+def union_choice(schema: CoreSchema, *, priority: int | None = None, strict: bool | None = None) -> UnionChoice:
+    """
+    Returns a union choice with options, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.union_schema(
+        [core_schema.float_schema(), core_schema.union_choice(core_schema.int_schema(), priority=1)]
+    )
+    v = SchemaValidator(schema)
+    assert v.validate_python('1') == 1
+    ```
+
+    Args:
+        schema: The schema of the choice
+        priority: Choices with a higher priority are tried first, choices with the same priority are tried
+            in the order they're declared, default 0
+        strict: Whether the choice is always validated in strict mode, even if the union is validated in lax mode
+    """
+    return dict_not_none(schema=schema, priority=priority, strict=strict)
+
+
 class UnionSchema(TypedDict, total=False):
     type: Required[Literal['union']]
-    choices: Required[List[CoreSchema]]
+    choices: Required[List[Union[CoreSchema, UnionChoice]]]
     # default true, whether to automatically collapse unions with one element to the inner validator
     auto_collapse: bool
+    mode: Literal['smart', 'left_to_right', 'isinstance']  # default: 'smart'
+    short_circuit_on_strict: bool  # default: False
     custom_error_type: str
     custom_error_message: str
     custom_error_context: Dict[str, Union[str, int, float]]
@@ -2329,9 +2880,11 @@ class UnionSchema(TypedDict, total=False):
 
 
 def union_schema(
-    choices: list[CoreSchema],
+    choices: list[CoreSchema | UnionChoice],
     *,
     auto_collapse: bool | None = None,
+    mode: Literal['smart', 'left_to_right', 'isinstance'] | None = None,
+    short_circuit_on_strict: bool | None = None,
     custom_error_type: str | None = None,
     custom_error_message: str | None = None,
     custom_error_context: dict[str, str | int] | None = None,
@@ -2353,8 +2906,19 @@ def union_schema(
     ```
 
     Args:
-        choices: The schemas to match
+        choices: The schemas to match, use `union_choice` to set the priority or strictness of a choice,
+            errors are always reported in the order choices are declared
         auto_collapse: whether to automatically collapse unions with one element to the inner validator, default true
+        mode: How to choose between choices, `'smart'` tries each choice once and returns the closest match,
+            an exact type match is returned straight away, then a strict match, then a lax coercion, the first
+            choice tried wins a tie,
+            `'left_to_right'` tries each choice once in order and returns the first success,
+            `'isinstance'` validates with the first model, dataclass or is-instance choice the input is an instance of
+            and falls back to `'smart'` if there isn't one
+        short_circuit_on_strict: With `'smart'` mode, return the first choice tried which matches in strict mode
+            instead of also trying the remaining choices for an exact type match, this is faster for wide unions
+            but e.g. `union_schema([float_schema(), int_schema()], short_circuit_on_strict=True)` validates `1`
+            as `1.0`, while without it `1` is returned since `int_schema()` matches exactly
         custom_error_type: The custom error type to use if the validation fails
         custom_error_message: The custom error message to use if the validation fails
         custom_error_context: The custom error context to use if the validation fails
@@ -2367,6 +2931,8 @@ def union_schema(
         type='union',
         choices=choices,
         auto_collapse=auto_collapse,
+        mode=mode,
+        short_circuit_on_strict=short_circuit_on_strict,
         custom_error_type=custom_error_type,
         custom_error_message=custom_error_message,
         custom_error_context=custom_error_context,
@@ -2381,13 +2947,19 @@ class TaggedUnionSchema(TypedDict, total=False):
     type: Required[Literal['tagged-union']]
     choices: Required[Dict[Union[str, int], Union[str, int, CoreSchema]]]
     discriminator: Required[
-        Union[str, List[Union[str, int]], List[List[Union[str, int]]], Callable[[Any], Optional[Union[str, int]]]]
+        Union[
+            str,
+            List[Union[str, int]],
+            List[Union[str, List[Union[str, int]]]],
+            Callable[[Any], Optional[Union[str, int]]],
+        ]
     ]
     custom_error_type: str
     custom_error_message: str
     custom_error_context: Dict[str, Union[str, int, float]]
     strict: bool
     from_attributes: bool  # default: True
+    tagged_union_probe_all: bool  # default: False
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -2395,13 +2967,14 @@ class TaggedUnionSchema(TypedDict, total=False):
 
 def tagged_union_schema(
     choices: Dict[Union[int, str], int | str | CoreSchema],
-    discriminator: str | list[str | int] | list[list[str | int]] | Callable[[Any], str | int | None],
+    discriminator: str | list[str | int] | list[str | list[str | int]] | Callable[[Any], str | int | None],
     *,
     custom_error_type: str | None = None,
     custom_error_message: str | None = None,
     custom_error_context: dict[str, int | str | float] | None = None,
     strict: bool | None = None,
     from_attributes: bool | None = None,
+    tagged_union_probe_all: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -2450,13 +3023,19 @@ def tagged_union_schema(
             * If `discriminator` is a str, it is the name of the attribute to use as the discriminator
             * If `discriminator` is a list of int/str, it should be used as a "path" to access the discriminator
             * If `discriminator` is a list of lists, each inner list is a path, and the first path that exists is used
+              a value found at a path which isn't a str or int fails with `union_tag_invalid`
             * If `discriminator` is a callable, it should return the discriminator when called on the value to validate;
-              the callable can return `None` to indicate that there is no matching discriminator present on the input
+              the callable can return `None` to indicate that there is no matching discriminator present on the input,
+              JSON input is passed as the python objects it's loaded as, a `ValueError` or `AssertionError` raised
+              by the callable fails validation as it would in a function validator, other exceptions mean no tag
+              was found
         custom_error_type: The custom error type to use if the validation fails
         custom_error_message: The custom error message to use if the validation fails
         custom_error_context: The custom error context to use if the validation fails
         strict: Whether the underlying schemas should be validated with strict mode
         from_attributes: Whether to use the attributes of the object to retrieve the discriminator value
+        tagged_union_probe_all: Diagnostic only, when the selected choice fails, also try the other choices in strict
+            mode and add an error listing the tags which would have accepted the input, defaults to the config value
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -2470,22 +3049,125 @@ def tagged_union_schema(
         custom_error_context=custom_error_context,
         strict=strict,
         from_attributes=from_attributes,
+        tagged_union_probe_all=tagged_union_probe_all,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class MergeSchema(TypedDict, total=False):
+    type: Required[Literal['merge']]
+    schemas: Required[List[CoreSchema]]
+    conflict: Literal['first', 'last', 'error']  # default: 'last'
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def merge_schema(
+    schemas: list[CoreSchema],
+    *,
+    conflict: Literal['first', 'last', 'error'] | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> MergeSchema:
+    """
+    Returns a schema that validates the input against every schema in `schemas` and merges the outputs
+    into a single dict, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    name_schema = core_schema.typed_dict_schema({'name': core_schema.typed_dict_field(core_schema.str_schema())})
+    age_schema = core_schema.typed_dict_schema({'age': core_schema.typed_dict_field(core_schema.int_schema())})
+    schema = core_schema.merge_schema([name_schema, age_schema])
+    v = SchemaValidator(schema)
+    assert v.validate_python({'name': 'Alice', 'age': '42'}) == {'name': 'Alice', 'age': 42}
+    ```
+
+    Unlike unions, the input must be valid against all schemas, errors from each schema are collected
+    with the name of the schema as the first item of their location.
+
+    Args:
+        schemas: The schemas to validate against, each must return a dict, a tuple starting with a dict
+            (e.g. a typed dict schema with `return_fields_set`) or an object with a `__dict__`
+        conflict: How to handle a key returned by more than one schema with different values:
+            * `'first'` keeps the value from the first schema to return the key
+            * `'last'` keeps the value from the last schema to return the key, this is the default
+            * `'error'` raises a `merge_conflict` validation error
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return dict_not_none(
+        type='merge',
+        schemas=schemas,
+        conflict=conflict,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
     )
 
 
+class ChainStep(TypedDict, total=False):
+    schema: Required[CoreSchema]
+    label: str
+    validate_only: bool  # default: False
+
+
+def chain_step(schema: CoreSchema, *, label: str | None = None, validate_only: bool | None = None) -> ChainStep:
+    """
+    Returns a chain step with options, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    def check_positive(v: int, info: core_schema.ValidationInfo) -> int:
+        assert v > 0, 'must be positive'
+        return v
+
+    schema = core_schema.chain_schema(
+        [
+            core_schema.int_schema(),
+            core_schema.chain_step(
+                core_schema.general_plain_validator_function(check_positive), label='positive', validate_only=True
+            ),
+        ]
+    )
+    v = SchemaValidator(schema)
+    assert v.validate_python('1') == 1
+    ```
+
+    Args:
+        schema: The schema of the step
+        label: Used instead of the step's index as `chain_step` in the context of errors from the step,
+            and instead of the step's name in the chain's name
+        validate_only: Whether the step only checks the value, if so its output is ignored and the next step
+            is given the same value, default False
+    """
+    return dict_not_none(schema=schema, label=label, validate_only=validate_only)
+
+
 class ChainSchema(TypedDict, total=False):
     type: Required[Literal['chain']]
-    steps: Required[List[CoreSchema]]
+    steps: Required[List[Union[CoreSchema, ChainStep]]]
+    short_circuit: bool  # default: True
+    error_input_type: bool  # default: False
     ref: str
     metadata: Any
     serialization: SerSchema
 
 
 def chain_schema(
-    steps: list[CoreSchema], *, ref: str | None = None, metadata: Any = None, serialization: SerSchema | None = None
+    steps: list[CoreSchema | ChainStep],
+    *,
+    short_circuit: bool | None = None,
+    error_input_type: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
 ) -> ChainSchema:
     """
     Returns a schema that chains the provided validation schemas, e.g.:
@@ -2505,13 +3187,28 @@ def chain_schema(
     assert v.validate_python('hello') == 'hello world world world'
     ```
 
+    Errors from a step include the step's label, or its index if it has none, as `chain_step` in their context.
+
     Args:
-        steps: The schemas to chain
+        steps: The schemas to chain, either schemas or steps with options from `chain_step`
+        short_circuit: Whether validation stops at the first step which fails, if False, after a `validate_only`
+            step fails the following `validate_only` steps still run and all their errors are raised together,
+            steps which transform the value never run after a step has failed, default True
+        error_input_type: Whether to include the type of the value given to the failing step
+            as `chain_step_input_type` in the error context, default False
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
-    return dict_not_none(type='chain', steps=steps, ref=ref, metadata=metadata, serialization=serialization)
+    return dict_not_none(
+        type='chain',
+        steps=steps,
+        short_circuit=short_circuit,
+        error_input_type=error_input_type,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
 
 
 class LaxOrStrictSchema(TypedDict, total=False):
@@ -2578,14 +3275,81 @@ def lax_or_strict_schema(
     )
 
 
+class JsonOrPythonSchema(TypedDict, total=False):
+    type: Required[Literal['json-or-python']]
+    json_schema: Required[CoreSchema]
+    python_schema: Required[CoreSchema]
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def json_or_python_schema(
+    json_schema: CoreSchema,
+    python_schema: CoreSchema,
+    *,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> JsonOrPythonSchema:
+    """
+    Returns a schema that uses the JSON or Python schema depending on the input, e.g.:
+
+    ```py
+    from enum import Enum
+
+    from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+    class Color(Enum):
+        red = 'r'
+
+    schema = core_schema.json_or_python_schema(
+        json_schema=core_schema.no_info_after_validator_function(Color, core_schema.str_schema()),
+        python_schema=core_schema.is_instance_schema(Color),
+    )
+    v = SchemaValidator(schema)
+    assert v.validate_json('"r"') is Color.red
+    assert v.validate_python(Color.red) is Color.red
+    try:
+        v.validate_python('r')
+    except ValidationError:
+        pass
+    else:
+        raise AssertionError('python input should be an instance')
+    ```
+
+    The JSON schema is used for `validate_json` and for JSON object keys, the Python schema is used for
+    `validate_python` and `validate_assignment`. Serialization uses the Python schema.
+
+    Args:
+        json_schema: The schema to use for JSON input
+        python_schema: The schema to use for Python input
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return dict_not_none(
+        type='json-or-python',
+        json_schema=json_schema,
+        python_schema=python_schema,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
 class TypedDictField(TypedDict, total=False):
     type: Required[Literal['typed-dict-field']]
     schema: Required[CoreSchema]
     required: bool
-    validation_alias: Union[str, List[Union[str, int]], List[List[Union[str, int]]]]
+    validation_alias: Union[str, List[Union[str, int]], List[Union[str, List[Union[str, int]]]]]
+    populate_by_name: bool
     serialization_alias: str
     serialization_exclude: bool  # default: False
+    serialize_as_any: bool  # default: False
     frozen: bool
+    title: str
+    depends_on: List[str]
     metadata: Any
 
 
@@ -2593,10 +3357,14 @@ def typed_dict_field(
     schema: CoreSchema,
     *,
     required: bool | None = None,
-    validation_alias: str | list[str | int] | list[list[str | int]] | None = None,
+    validation_alias: str | list[str | int] | list[str | list[str | int]] | None = None,
+    populate_by_name: bool | None = None,
     serialization_alias: str | None = None,
     serialization_exclude: bool | None = None,
+    serialize_as_any: bool | None = None,
     frozen: bool | None = None,
+    title: str | None = None,
+    depends_on: List[str] | None = None,
     metadata: Any = None,
 ) -> TypedDictField:
     """
@@ -2611,10 +3379,19 @@ def typed_dict_field(
     Args:
         schema: The schema to use for the field
         required: Whether the field is required
-        validation_alias: The alias(es) to use to find the field in the validation data
+        validation_alias: The alias(es) to use to find the field in the validation data, either a key, a path of
+            string keys and int indices, or a list of alternatives, each a key or a path, which are tried in order
+        populate_by_name: Whether the field can also be populated by its name, overriding `populate_by_name` on the
+            schema or config for this field only, the alias is used if both are present
         serialization_alias: The alias to use as a key when serializing
         serialization_exclude: Whether to exclude the field when serializing
+        serialize_as_any: Whether models and dataclasses in the field are serialized according to their runtime
+            type rather than this schema, like the `serialize_as_any` serialization option for this field only
         frozen: Whether the field is frozen
+        title: The human readable title of the field, included as `field_title` in the context of its errors
+        depends_on: Names of fields which are validated before this field, so their validated values are available
+            from `info.data` in this field's validators, if one is missing or fails validation this field isn't
+            validated and has a `field_dependency_failed` error instead
         metadata: Any other information you want to include with the schema, not used by pydantic-core
     """
     return dict_not_none(
@@ -2622,23 +3399,62 @@ def typed_dict_field(
         schema=schema,
         required=required,
         validation_alias=validation_alias,
+        populate_by_name=populate_by_name,
         serialization_alias=serialization_alias,
         serialization_exclude=serialization_exclude,
+        serialize_as_any=serialize_as_any,
         frozen=frozen,
+        title=title,
+        depends_on=depends_on,
         metadata=metadata,
     )
 
 
+class FieldGroup(TypedDict):
+    fields: List[str]
+    mode: Literal['required_together', 'mutually_exclusive', 'at_least_one']
+
+
+def field_group(
+    fields: list[str], mode: Literal['required_together', 'mutually_exclusive', 'at_least_one']
+) -> FieldGroup:
+    """
+    Returns a constraint on which fields of a typed dict are provided in the input, for use with
+    `typed_dict_schema`, e.g.:
+
+    ```py
+    from pydantic_core import core_schema
+
+    group = core_schema.field_group(['lat', 'lon'], 'required_together')
+    ```
+
+    Args:
+        fields: The names of the fields in the group, not their aliases
+        mode: Whether the fields must all be provided or all be omitted (`'required_together'`), at most one may be
+            provided (`'mutually_exclusive'`) or at least one must be provided (`'at_least_one'`), fields filled
+            from their default don't count as provided
+    """
+    return FieldGroup(fields=fields, mode=mode)
+
+
 class TypedDictSchema(TypedDict, total=False):
     type: Required[Literal['typed-dict']]
     fields: Required[Dict[str, TypedDictField]]
+    field_groups: List[FieldGroup]
     strict: bool
-    extra_validator: CoreSchema
+    extras_schema: CoreSchema
+    extra_validator: CoreSchema  # older name for `extras_schema`
     return_fields_set: bool
+    return_extra: bool
+    attributes_mode: Literal['getattr', 'dict', 'slots']  # default: 'getattr'
+    from_attributes_mode: Literal['getattr', 'dir', 'explicit']  # default: 'getattr'
+    readable_attributes: List[str]
     # all these values can be set via config, equivalent fields have `typed_dict_` prefix
     extra_behavior: ExtraBehavior
     total: bool  # default: True
     populate_by_name: bool  # replaces `allow_population_by_field_name` in pydantic v1
+    require_alias: bool  # default: False
+    case_insensitive_fields: bool  # default: False
     from_attributes: bool
     ref: str
     metadata: Any
@@ -2648,13 +3464,21 @@ class TypedDictSchema(TypedDict, total=False):
 def typed_dict_schema(
     fields: Dict[str, TypedDictField],
     *,
+    field_groups: List[FieldGroup] | None = None,
     strict: bool | None = None,
+    extras_schema: CoreSchema | None = None,
     extra_validator: CoreSchema | None = None,
     return_fields_set: bool | None = None,
+    return_extra: bool | None = None,
     extra_behavior: ExtraBehavior | None = None,
     total: bool | None = None,
     populate_by_name: bool | None = None,
+    require_alias: bool | None = None,
+    case_insensitive_fields: bool | None = None,
     from_attributes: bool | None = None,
+    attributes_mode: Literal['getattr', 'dict', 'slots'] | None = None,
+    from_attributes_mode: Literal['getattr', 'dir', 'explicit'] | None = None,
+    readable_attributes: List[str] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -2674,55 +3498,171 @@ def typed_dict_schema(
 
     Args:
         fields: The fields to use for the typed dict
+        field_groups: Constraints on which fields are provided together, see `field_group`, each group which isn't
+            satisfied adds an error after the fields are validated
         strict: Whether the typed dict is strict
-        extra_validator: The extra validator to use for the typed dict
+        extras_schema: The schema used to validate extra values with `extra_behavior='allow'`
+        extra_validator: The older name for `extras_schema`
         return_fields_set: Whether the typed dict should return a fields set
+        return_extra: Whether extra values are returned separately from the fields rather than added to them, `None`
+            unless `extra_behavior='allow'`, the model validator sets them as `__pydantic_extra__`
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         extra_behavior: The extra behavior to use for the typed dict
         total: Whether the typed dict is total
         populate_by_name: Whether the typed dict should populate by name
+        require_alias: Whether fields with an alias can only be populated by the alias, keys matching the field
+            name are treated as extra keys, even with `populate_by_name`
+        case_insensitive_fields: Whether keys which don't exactly match a field's name or alias are matched
+            case-insensitively, an exact match is always used first, otherwise the first matching key in the input
+            is used, other keys matching the field are ignored rather than treated as extra keys, only aliases
+            which aren't paths of more than one item are matched case-insensitively
         from_attributes: Whether the typed dict should be populated from attributes
+        attributes_mode: How attributes are read with `from_attributes`, `'getattr'` uses `getattr` for each field,
+            `'dict'` reads the object's `__dict__` directly and `'slots'` reads `__slots__` with the class's member
+            descriptors, resolved once per class; both fall back to `getattr` for attributes they don't find so
+            properties and other descriptors still work
+        from_attributes_mode: Which attributes may be read with `from_attributes`, `'getattr'` reads any attribute,
+            `'dir'` only reads names in `dir(obj)`, computed once per validation, and `'explicit'` only reads names
+            in `readable_attributes`; attributes which can't be read are treated as missing without being accessed,
+            useful for objects with a `__getattr__` which returns a value for any name
+        readable_attributes: The attributes which may be read with `from_attributes_mode='explicit'`
         serialization: Custom serialization schema
     """
     return dict_not_none(
         type='typed-dict',
         fields=fields,
+        field_groups=field_groups,
         strict=strict,
+        extras_schema=extras_schema,
         extra_validator=extra_validator,
         return_fields_set=return_fields_set,
+        return_extra=return_extra,
         extra_behavior=extra_behavior,
         total=total,
         populate_by_name=populate_by_name,
+        require_alias=require_alias,
+        case_insensitive_fields=case_insensitive_fields,
         from_attributes=from_attributes,
+        attributes_mode=attributes_mode,
+        from_attributes_mode=from_attributes_mode,
+        readable_attributes=readable_attributes,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
     )
 
 
-class ModelSchema(TypedDict, total=False):
-    type: Required[Literal['model']]
-    cls: Required[Type[Any]]
-    schema: Required[CoreSchema]
-    post_init: str
-    revalidate_instances: Literal['always', 'never', 'subclass-instances']  # default: 'never'
-    strict: bool
-    frozen: bool
-    config: CoreConfig
-    ref: str
-    metadata: Any
-    serialization: SerSchema
+class ExclusiveGroup(TypedDict, total=False):
+    fields: Required[List[str]]
+    mode: Literal['at_most_one', 'exactly_one']  # default: 'at_most_one'
+    name: str
 
 
-def model_schema(
-    cls: Type[Any],
-    schema: CoreSchema,
+def exclusive_group(
+    fields: list[str], *, mode: Literal['at_most_one', 'exactly_one'] | None = None, name: str | None = None
+) -> ExclusiveGroup:
+    """
+    Returns a group of mutually exclusive fields for use with `model_schema`, e.g.:
+
+    ```py
+    from pydantic_core import core_schema
+
+    group = core_schema.exclusive_group(['email', 'phone'], mode='exactly_one', name='contact')
+    ```
+
+    Args:
+        fields: The names of the fields in the group
+        mode: Whether at most one or exactly one of the fields must be set, defaults to 'at_most_one'
+        name: The name of the group used in errors, defaults to the field names
+    """
+    return dict_not_none(fields=fields, mode=mode, name=name)
+
+
+class ComputedField(TypedDict, total=False):
+    type: Required[Literal['computed-field']]
+    property_name: Required[str]
+    return_schema: Required[CoreSchema]
+    alias: str
+    metadata: Any
+
+
+def computed_field(
+    property_name: str, return_schema: CoreSchema, *, alias: str | None = None, metadata: Any = None
+) -> ComputedField:
+    """
+    ComputedFields are properties of a model or dataclass which are included when serializing it, they have no
+    effect on validation.
+
+    Example:
+
+    ```py
+    from pydantic_core import SchemaSerializer, core_schema
+
+    class Rect:
+        def __init__(self, width, height):
+            self.width = width
+            self.height = height
+
+        @property
+        def area(self):
+            return self.width * self.height
+
+    schema = core_schema.model_schema(
+        Rect,
+        core_schema.typed_dict_schema(
+            {
+                'width': core_schema.typed_dict_field(core_schema.int_schema()),
+                'height': core_schema.typed_dict_field(core_schema.int_schema()),
+            }
+        ),
+        computed_fields=[core_schema.computed_field('area', core_schema.int_schema())],
+    )
+    s = SchemaSerializer(schema)
+    assert s.to_python(Rect(2, 3)) == {'width': 2, 'height': 3, 'area': 6}
+    ```
+
+    Args:
+        property_name: The name of the property or attribute on the model or dataclass instance
+        return_schema: The schema used to serialize the value of the property
+        alias: The key used in the output when serializing with `by_alias`
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+    """
+    return dict_not_none(
+        type='computed-field', property_name=property_name, return_schema=return_schema, alias=alias, metadata=metadata
+    )
+
+
+class ModelSchema(TypedDict, total=False):
+    type: Required[Literal['model']]
+    cls: Required[Type[Any]]
+    schema: Required[CoreSchema]
+    post_init: str
+    revalidate_instances: Literal['always', 'never', 'subclass-instances']  # default: 'never'
+    strict: bool
+    frozen: bool
+    exclusive_groups: List[ExclusiveGroup]
+    computed_fields: List[ComputedField]
+    alias_generator: Callable[[str], str]
+    alias_generator_serialization: bool  # default: False
+    config: CoreConfig
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def model_schema(
+    cls: Type[Any],
+    schema: CoreSchema,
     *,
     post_init: str | None = None,
     revalidate_instances: Literal['always', 'never', 'subclass-instances'] | None = None,
     strict: bool | None = None,
     frozen: bool | None = None,
+    exclusive_groups: list[ExclusiveGroup] | None = None,
+    computed_fields: list[ComputedField] | None = None,
+    alias_generator: Callable[[str], str] | None = None,
+    alias_generator_serialization: bool | None = None,
     config: CoreConfig | None = None,
     ref: str | None = None,
     metadata: Any = None,
@@ -2762,6 +3702,15 @@ def model_schema(
           should re-validate defaults to config.revalidate_instances, else 'never'
         strict: Whether the model is strict
         frozen: Whether the model is frozen
+        exclusive_groups: Groups of fields where at most one (or exactly one) may be set, checked against
+          the fields set after validation, requires the schema to return the fields set
+        computed_fields: Properties of the model included after its fields when serializing, `schema` must then be
+          a typed dict schema
+        alias_generator: Called with the name of each field of `schema`, which must be a typed dict schema, without a
+          `validation_alias` when the schema is built, the string it returns is used as the field's alias, aliases it
+          generates must be unique
+        alias_generator_serialization: Whether the generated aliases are also used as the `serialization_alias` of
+          fields without one
         config: The config to use for the model
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -2775,6 +3724,10 @@ def model_schema(
         revalidate_instances=revalidate_instances,
         strict=strict,
         frozen=frozen,
+        exclusive_groups=exclusive_groups,
+        computed_fields=computed_fields,
+        alias_generator=alias_generator,
+        alias_generator_serialization=alias_generator_serialization,
         config=config,
         ref=ref,
         metadata=metadata,
@@ -2789,9 +3742,12 @@ class DataclassField(TypedDict, total=False):
     kw_only: bool  # default: True
     init_only: bool  # default: False
     frozen: bool  # default: False
-    validation_alias: Union[str, List[Union[str, int]], List[List[Union[str, int]]]]
+    validation_alias: Union[str, List[Union[str, int]], List[Union[str, List[Union[str, int]]]]]
+    populate_by_name: bool
     serialization_alias: str
     serialization_exclude: bool  # default: False
+    serialize_as_any: bool  # default: False
+    title: str
     metadata: Any
 
 
@@ -2801,11 +3757,14 @@ def dataclass_field(
     *,
     kw_only: bool | None = None,
     init_only: bool | None = None,
-    validation_alias: str | list[str | int] | list[list[str | int]] | None = None,
+    validation_alias: str | list[str | int] | list[str | list[str | int]] | None = None,
+    populate_by_name: bool | None = None,
     serialization_alias: str | None = None,
     serialization_exclude: bool | None = None,
+    serialize_as_any: bool | None = None,
     metadata: Any = None,
     frozen: bool | None = None,
+    title: str | None = None,
 ) -> DataclassField:
     """
     Returns a schema for a dataclass field, e.g.:
@@ -2826,11 +3785,17 @@ def dataclass_field(
         schema: The schema to use for the argument parameter
         kw_only: Whether the field can be set with a positional argument as well as a keyword argument
         init_only: Whether the field should be omitted  from `__dict__` and passed to `__post_init__`
-        validation_alias: The alias(es) to use to find the field in the validation data
+        validation_alias: The alias(es) to use to find the field in the validation data, either a key, a path of
+            string keys and int indices, or a list of alternatives, each a key or a path, which are tried in order
+        populate_by_name: Whether the field can also be populated by its name, overriding `populate_by_name` on the
+            schema or config for this field only, the alias is used if both are present
         serialization_alias: The alias to use as a key when serializing
         serialization_exclude: Whether to exclude the field when serializing
+        serialize_as_any: Whether models and dataclasses in the field are serialized according to their runtime
+            type rather than this schema, like the `serialize_as_any` serialization option for this field only
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         frozen: Whether the field is frozen
+        title: The human readable title of the field, included as `field_title` in the context of its errors
     """
     return dict_not_none(
         type='dataclass-field',
@@ -2839,10 +3804,13 @@ def dataclass_field(
         kw_only=kw_only,
         init_only=init_only,
         validation_alias=validation_alias,
+        populate_by_name=populate_by_name,
         serialization_alias=serialization_alias,
         serialization_exclude=serialization_exclude,
+        serialize_as_any=serialize_as_any,
         metadata=metadata,
         frozen=frozen,
+        title=title,
     )
 
 
@@ -2917,6 +3885,8 @@ class DataclassSchema(TypedDict, total=False):
     revalidate_instances: Literal['always', 'never', 'subclass-instances']  # default: 'never'
     strict: bool  # default: False
     frozen: bool  # default False
+    slots: bool  # default False
+    computed_fields: List[ComputedField]
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -2933,6 +3903,8 @@ def dataclass_schema(
     metadata: Any = None,
     serialization: SerSchema | None = None,
     frozen: bool | None = None,
+    slots: bool | None = None,
+    computed_fields: list[ComputedField] | None = None,
 ) -> DataclassSchema:
     """
     Returns a schema for a dataclass. As with `ModelSchema`, this schema can only be used as a field within
@@ -2949,6 +3921,10 @@ def dataclass_schema(
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
         frozen: Whether the dataclass is frozen
+        slots: Whether the dataclass uses `__slots__` rather than `__dict__`, fields are set and read as attributes,
+            `schema` must then be a `dataclass_args_schema`
+        computed_fields: Properties of the dataclass included after its fields when serializing, `schema` must then
+            be a `dataclass_args_schema`
     """
     return dict_not_none(
         type='dataclass',
@@ -2961,6 +3937,8 @@ def dataclass_schema(
         metadata=metadata,
         serialization=serialization,
         frozen=frozen,
+        slots=slots,
+        computed_fields=computed_fields,
     )
 
 
@@ -2968,7 +3946,10 @@ class ArgumentsParameter(TypedDict, total=False):
     name: Required[str]
     schema: Required[CoreSchema]
     mode: Literal['positional_only', 'positional_or_keyword', 'keyword_only']  # default positional_or_keyword
-    alias: Union[str, List[Union[str, int]], List[List[Union[str, int]]]]
+    alias: Union[str, List[Union[str, int]], List[Union[str, List[Union[str, int]]]]]
+    default: Any
+    default_factory: Callable[[], Any]
+    validate_default: bool  # default: False
 
 
 def arguments_parameter(
@@ -2976,7 +3957,10 @@ def arguments_parameter(
     schema: CoreSchema,
     *,
     mode: Literal['positional_only', 'positional_or_keyword', 'keyword_only'] | None = None,
-    alias: str | list[str | int] | list[list[str | int]] | None = None,
+    alias: str | list[str | int] | list[str | list[str | int]] | None = None,
+    default: Any = Omitted,
+    default_factory: Callable[[], Any] | None = None,
+    validate_default: bool | None = None,
 ) -> ArgumentsParameter:
     """
     Returns a schema that matches an argument parameter, e.g.:
@@ -2997,8 +3981,21 @@ def arguments_parameter(
         schema: The schema to use for the argument parameter
         mode: The mode to use for the argument parameter
         alias: The alias to use for the argument parameter
+        default: The value to use if the argument is not provided
+        default_factory: A function called to get the value if the argument is not provided
+        validate_default: Whether the default value should be validated
     """
-    return dict_not_none(name=name, schema=schema, mode=mode, alias=alias)
+    s = dict_not_none(
+        name=name,
+        schema=schema,
+        mode=mode,
+        alias=alias,
+        default_factory=default_factory,
+        validate_default=validate_default,
+    )
+    if default is not Omitted:
+        s['default'] = default
+    return s
 
 
 class ArgumentsSchema(TypedDict, total=False):
@@ -3007,6 +4004,7 @@ class ArgumentsSchema(TypedDict, total=False):
     populate_by_name: bool
     var_args_schema: CoreSchema
     var_kwargs_schema: CoreSchema
+    var_kwargs_mode: Literal['uniform', 'unpacked-typed-dict']  # default: 'uniform'
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -3018,6 +4016,7 @@ def arguments_schema(
     populate_by_name: bool | None = None,
     var_args_schema: CoreSchema | None = None,
     var_kwargs_schema: CoreSchema | None = None,
+    var_kwargs_mode: Literal['uniform', 'unpacked-typed-dict'] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -3044,6 +4043,9 @@ def arguments_schema(
         populate_by_name: Whether to populate by name
         var_args_schema: The variable args schema to use for the arguments schema
         var_kwargs_schema: The variable kwargs schema to use for the arguments schema
+        var_kwargs_mode: How keyword arguments which don't match a parameter are validated, `'uniform'` validates
+            each value with `var_kwargs_schema`, `'unpacked-typed-dict'` validates them together with
+            `var_kwargs_schema`, which must be a typed dict schema, like `**kwargs: Unpack[TypedDict]`
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -3054,6 +4056,7 @@ def arguments_schema(
         populate_by_name=populate_by_name,
         var_args_schema=var_args_schema,
         var_kwargs_schema=var_kwargs_schema,
+        var_kwargs_mode=var_kwargs_mode,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -3359,6 +4362,589 @@ def multi_host_url_schema(
     )
 
 
+class PathSchema(TypedDict, total=False):
+    type: Required[Literal['path']]
+    flavor: Literal['native', 'posix', 'windows']  # default: 'native'
+    absolute: bool  # default: False
+    suffix_in: List[str]
+    max_parts: int
+    must_exist: Literal['file', 'dir', 'any']
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def path_schema(
+    *,
+    flavor: Literal['native', 'posix', 'windows'] | None = None,
+    absolute: bool | None = None,
+    suffix_in: list[str] | None = None,
+    max_parts: int | None = None,
+    must_exist: Literal['file', 'dir', 'any'] | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> PathSchema:
+    """
+    Returns a schema that matches a filesystem path, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.path_schema(flavor='posix', suffix_in=['.txt'])
+    v = SchemaValidator(schema)
+    print(repr(v.validate_python('/tmp/foo.txt')))
+    #> PurePosixPath('/tmp/foo.txt')
+    ```
+
+    Args:
+        flavor: Whether to return a `pathlib.Path` (`'native'`), `pathlib.PurePosixPath` (`'posix'`)
+            or `pathlib.PureWindowsPath` (`'windows'`)
+        absolute: Whether the path must be absolute
+        suffix_in: The allowed path suffixes, e.g. `['.txt', '.csv']`
+        max_parts: The maximum number of parts in the path
+        must_exist: Whether the path must exist as a file, a directory or either,
+            note this performs I/O during validation
+        strict: Whether only `pathlib.PurePath` instances are accepted
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return dict_not_none(
+        type='path',
+        flavor=flavor,
+        absolute=absolute,
+        suffix_in=suffix_in,
+        max_parts=max_parts,
+        must_exist=must_exist,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class TimezoneSchema(TypedDict, total=False):
+    type: Required[Literal['timezone']]
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def timezone_schema(
+    *, strict: bool | None = None, ref: str | None = None, metadata: Any = None, serialization: SerSchema | None = None
+) -> TimezoneSchema:
+    """
+    Returns a schema that matches a timezone, either a `datetime.tzinfo` instance or an IANA timezone name
+    which is converted to a `zoneinfo.ZoneInfo`, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.timezone_schema()
+    v = SchemaValidator(schema)
+    print(repr(v.validate_python('America/New_York')))
+    #> zoneinfo.ZoneInfo(key='America/New_York')
+    ```
+
+    Args:
+        strict: Whether only `datetime.tzinfo` instances are accepted
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return dict_not_none(type='timezone', strict=strict, ref=ref, metadata=metadata, serialization=serialization)
+
+
+class FractionSchema(TypedDict, total=False):
+    type: Required[Literal['fraction']]
+    le: Fraction
+    ge: Fraction
+    lt: Fraction
+    gt: Fraction
+    float_conversion: Literal['exact', 'repr']  # default: 'exact'
+    limit_denominator: int
+    number_format: NumberFormat
+    ser_json_fraction: Literal['str', 'pair']  # default: 'str'
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def fraction_schema(
+    *,
+    le: Fraction | int | str | None = None,
+    ge: Fraction | int | str | None = None,
+    lt: Fraction | int | str | None = None,
+    gt: Fraction | int | str | None = None,
+    float_conversion: Literal['exact', 'repr'] | None = None,
+    limit_denominator: int | None = None,
+    number_format: NumberFormat | None = None,
+    ser_json_fraction: Literal['str', 'pair'] | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> FractionSchema:
+    """
+    Returns a schema that matches a `fractions.Fraction`, e.g.:
+
+    ```py
+    from fractions import Fraction
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.fraction_schema(le=1)
+    v = SchemaValidator(schema)
+    assert v.validate_python('3/4') == Fraction(3, 4)
+    assert v.validate_python(0.5) == Fraction(1, 2)
+    ```
+
+    In lax mode fractions are also validated from ints, strings like `'3/4'` or `'1.5'`, `Decimal`s and floats,
+    all of which are converted exactly, unless `float_conversion` or `limit_denominator` are set for floats.
+
+    Args:
+        le: The value must be less than or equal to this number, compared exactly
+        ge: The value must be greater than or equal to this number, compared exactly
+        lt: The value must be strictly less than this number, compared exactly
+        gt: The value must be strictly greater than this number, compared exactly
+        float_conversion: Whether floats are converted from their exact binary value (`'exact'`), e.g. `0.1` is
+            validated as `Fraction(3602879701896397, 36028797018963968)`, or from their repr (`'repr'`),
+            e.g. `0.1` is validated as `Fraction(1, 10)`
+        limit_denominator: The largest denominator of fractions converted from floats, e.g. with `1000`, `3.14159265`
+            is validated as `Fraction(355, 113)`
+        number_format: Separators used to parse strings, e.g. `{'thousands_sep': '.', 'decimal_sep': ','}` validates
+            `'1.234,5'` as `Fraction(2469, 2)`, a separator in an invalid position is a parsing error
+        ser_json_fraction: Whether fractions are serialized to JSON as `"numerator/denominator"` strings (`'str'`),
+            or `[numerator, denominator]` pairs (`'pair'`), dict keys are always strings
+        strict: Whether only `fractions.Fraction` instances are accepted
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return dict_not_none(
+        type='fraction',
+        le=le,
+        ge=ge,
+        lt=lt,
+        gt=gt,
+        float_conversion=float_conversion,
+        limit_denominator=limit_denominator,
+        number_format=number_format,
+        ser_json_fraction=ser_json_fraction,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class DecimalSchema(TypedDict, total=False):
+    type: Required[Literal['decimal']]
+    allow_inf_nan: bool  # default: False
+    multiple_of: Decimal
+    le: Decimal
+    ge: Decimal
+    lt: Decimal
+    gt: Decimal
+    max_digits: int
+    decimal_places: int
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def decimal_schema(
+    *,
+    allow_inf_nan: bool | None = None,
+    multiple_of: Decimal | int | str | None = None,
+    le: Decimal | int | str | None = None,
+    ge: Decimal | int | str | None = None,
+    lt: Decimal | int | str | None = None,
+    gt: Decimal | int | str | None = None,
+    max_digits: int | None = None,
+    decimal_places: int | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> DecimalSchema:
+    """
+    Returns a schema that matches a `decimal.Decimal`, e.g.:
+
+    ```py
+    from decimal import Decimal
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.decimal_schema(max_digits=5, decimal_places=2)
+    v = SchemaValidator(schema)
+    assert v.validate_python('123.45') == Decimal('123.45')
+    assert v.validate_json('0.1') == Decimal('0.1')
+    ```
+
+    In lax mode decimals are also validated from ints, strings and floats, floats are converted from their repr,
+    e.g. `0.1` is validated as `Decimal('0.1')`. JSON has no decimal type, so JSON numbers and strings are accepted
+    in strict mode too. The result is always a `decimal.Decimal`, subclass instances are converted.
+
+    Args:
+        allow_inf_nan: Whether `NaN` and infinite values are accepted, signalling NaNs are always rejected
+        multiple_of: The value must be an exact multiple of this number
+        le: The value must be less than or equal to this number, compared exactly
+        ge: The value must be greater than or equal to this number, compared exactly
+        lt: The value must be strictly less than this number, compared exactly
+        gt: The value must be strictly greater than this number, compared exactly
+        max_digits: The maximum number of digits, trailing zeros after the decimal point aren't counted
+        decimal_places: The maximum number of digits after the decimal point, trailing zeros aren't counted,
+            with `max_digits` this also limits the digits before the decimal point to `max_digits - decimal_places`
+        strict: Whether only `decimal.Decimal` instances are accepted from python
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return dict_not_none(
+        type='decimal',
+        allow_inf_nan=allow_inf_nan,
+        multiple_of=multiple_of,
+        le=le,
+        ge=ge,
+        lt=lt,
+        gt=gt,
+        max_digits=max_digits,
+        decimal_places=decimal_places,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class UuidSchema(TypedDict, total=False):
+    type: Required[Literal['uuid']]
+    version: Literal[1, 2, 3, 4, 5]
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def uuid_schema(
+    *,
+    version: Literal[1, 2, 3, 4, 5] | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> UuidSchema:
+    """
+    Returns a schema that matches a `uuid.UUID`, e.g.:
+
+    ```py
+    from uuid import UUID
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.uuid_schema(version=4)
+    v = SchemaValidator(schema)
+    assert v.validate_python('12345678-1234-4678-9234-567812345678') == UUID('12345678-1234-4678-9234-567812345678')
+    ```
+
+    In lax mode UUIDs are also validated from strings in the hyphenated form, as 32 hex digits, or in the URN form
+    `'urn:uuid:<hyphenated>'`, and from 16 bytes. JSON has no UUID type, so in strict mode the hyphenated form is
+    accepted from JSON.
+
+    Args:
+        version: The version the UUID must have, UUIDs which aren't RFC 4122 UUIDs have no version
+        strict: Whether only `uuid.UUID` instances are accepted from python
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return dict_not_none(
+        type='uuid', version=version, strict=strict, ref=ref, metadata=metadata, serialization=serialization
+    )
+
+
+class ComplexSchema(TypedDict, total=False):
+    type: Required[Literal['complex']]
+    allow_inf_nan: bool  # default: True
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def complex_schema(
+    *,
+    allow_inf_nan: bool | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> ComplexSchema:
+    """
+    Returns a schema that matches a `complex` value, e.g.:
+
+    ```py
+    from pydantic_core import SchemaSerializer, SchemaValidator, core_schema
+
+    schema = core_schema.complex_schema()
+    v = SchemaValidator(schema)
+    assert v.validate_python('1.5+2j') == complex(1.5, 2)
+    assert v.validate_python(3) == complex(3, 0)
+    assert SchemaSerializer(schema).to_json(complex(1.5, 2)) == b'"1.5+2j"'
+    ```
+
+    In lax mode complex numbers are also validated from ints, floats, and strings parsed by the same rules as
+    `complex()`, e.g. `'1+2j'` or `'(1.5-2J)'`. JSON has no complex type, so JSON numbers, strings and `[real, imag]`
+    pairs are accepted in strict mode too. The result is always a `complex`, subclass instances are converted.
+
+    Complex numbers are serialized to JSON as strings like `"1.5+2j"`, or as `[real, imag]` pairs with the
+    `ser_json_complex` config.
+
+    Args:
+        allow_inf_nan: Whether infinite and `NaN` components are accepted
+        strict: Whether only `complex` instances are accepted from python
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return dict_not_none(
+        type='complex',
+        allow_inf_nan=allow_inf_nan,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class IpV4AddressSchema(TypedDict, total=False):
+    type: Required[Literal['ip-v4-address']]
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def ip_v4_address_schema(
+    *, strict: bool | None = None, ref: str | None = None, metadata: Any = None, serialization: SerSchema | None = None
+) -> IpV4AddressSchema:
+    """
+    Returns a schema that matches an `ipaddress.IPv4Address`, e.g.:
+
+    ```py
+    from ipaddress import IPv4Address
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.ip_v4_address_schema()
+    v = SchemaValidator(schema)
+    assert v.validate_python('192.168.0.1') == IPv4Address('192.168.0.1')
+    ```
+
+    In lax mode addresses are also validated from strings, ints, and packed bytes of length 4. JSON has no address
+    type, so strings are accepted from JSON in strict mode too, JSON ints only in lax mode.
+
+    They're serialized to JSON as strings in the compressed form `str()` gives.
+
+    Args:
+        strict: Whether only `ipaddress.IPv4Address` instances are accepted from python
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return dict_not_none(type='ip-v4-address', strict=strict, ref=ref, metadata=metadata, serialization=serialization)
+
+
+class IpV6AddressSchema(TypedDict, total=False):
+    type: Required[Literal['ip-v6-address']]
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def ip_v6_address_schema(
+    *, strict: bool | None = None, ref: str | None = None, metadata: Any = None, serialization: SerSchema | None = None
+) -> IpV6AddressSchema:
+    """
+    Returns a schema that matches an `ipaddress.IPv6Address`, e.g.:
+
+    ```py
+    from ipaddress import IPv6Address
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.ip_v6_address_schema()
+    v = SchemaValidator(schema)
+    assert v.validate_python('2001:db8::1') == IPv6Address('2001:db8::1')
+    ```
+
+    In lax mode addresses are also validated from strings, ints, and packed bytes of length 16. JSON has no address
+    type, so strings are accepted from JSON in strict mode too, JSON ints only in lax mode and up to `2**63 - 1`.
+
+    They're serialized to JSON as strings in the compressed form `str()` gives.
+
+    Args:
+        strict: Whether only `ipaddress.IPv6Address` instances are accepted from python
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return dict_not_none(type='ip-v6-address', strict=strict, ref=ref, metadata=metadata, serialization=serialization)
+
+
+class IpV4NetworkSchema(TypedDict, total=False):
+    type: Required[Literal['ip-v4-network']]
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def ip_v4_network_schema(
+    *, strict: bool | None = None, ref: str | None = None, metadata: Any = None, serialization: SerSchema | None = None
+) -> IpV4NetworkSchema:
+    """
+    Returns a schema that matches an `ipaddress.IPv4Network`, e.g.:
+
+    ```py
+    from ipaddress import IPv4Network
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.ip_v4_network_schema()
+    v = SchemaValidator(schema)
+    assert v.validate_python('192.168.0.0/24') == IPv4Network('192.168.0.0/24')
+    ```
+
+    In lax mode networks are also validated from strings with a prefix length, netmask or hostmask, from
+    `IPv4Address`es, ints and packed bytes, which are networks of a single address. As with `ipaddress`, host bits
+    set in the input are an error in strict mode, e.g. from JSON, and are masked in lax mode.
+
+    They're serialized to JSON as strings in the compressed form `str()` gives.
+
+    Args:
+        strict: Whether only `ipaddress.IPv4Network` instances are accepted from python
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return dict_not_none(type='ip-v4-network', strict=strict, ref=ref, metadata=metadata, serialization=serialization)
+
+
+class IpV6NetworkSchema(TypedDict, total=False):
+    type: Required[Literal['ip-v6-network']]
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def ip_v6_network_schema(
+    *, strict: bool | None = None, ref: str | None = None, metadata: Any = None, serialization: SerSchema | None = None
+) -> IpV6NetworkSchema:
+    """
+    Returns a schema that matches an `ipaddress.IPv6Network`, e.g.:
+
+    ```py
+    from ipaddress import IPv6Network
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.ip_v6_network_schema()
+    v = SchemaValidator(schema)
+    assert v.validate_python('2001:db8::/32') == IPv6Network('2001:db8::/32')
+    ```
+
+    In lax mode networks are also validated from strings with a prefix length, from `IPv6Address`es, ints and
+    packed bytes, which are networks of a single address. As with `ipaddress`, host bits set in the input are an
+    error in strict mode, e.g. from JSON, and are masked in lax mode.
+
+    They're serialized to JSON as strings in the compressed form `str()` gives.
+
+    Args:
+        strict: Whether only `ipaddress.IPv6Network` instances are accepted from python
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return dict_not_none(type='ip-v6-network', strict=strict, ref=ref, metadata=metadata, serialization=serialization)
+
+
+class IpV4InterfaceSchema(TypedDict, total=False):
+    type: Required[Literal['ip-v4-interface']]
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def ip_v4_interface_schema(
+    *, strict: bool | None = None, ref: str | None = None, metadata: Any = None, serialization: SerSchema | None = None
+) -> IpV4InterfaceSchema:
+    """
+    Returns a schema that matches an `ipaddress.IPv4Interface`, e.g.:
+
+    ```py
+    from ipaddress import IPv4Interface
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.ip_v4_interface_schema()
+    v = SchemaValidator(schema)
+    assert v.validate_python('192.168.0.1/24') == IPv4Interface('192.168.0.1/24')
+    ```
+
+    In lax mode interfaces are also validated from strings with a prefix length, netmask or hostmask, from
+    `IPv4Address`es, ints and packed bytes, which have a prefix length of 32.
+
+    They're serialized to JSON as strings in the compressed form `str()` gives.
+
+    Args:
+        strict: Whether only `ipaddress.IPv4Interface` instances are accepted from python
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return dict_not_none(type='ip-v4-interface', strict=strict, ref=ref, metadata=metadata, serialization=serialization)
+
+
+class IpV6InterfaceSchema(TypedDict, total=False):
+    type: Required[Literal['ip-v6-interface']]
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def ip_v6_interface_schema(
+    *, strict: bool | None = None, ref: str | None = None, metadata: Any = None, serialization: SerSchema | None = None
+) -> IpV6InterfaceSchema:
+    """
+    Returns a schema that matches an `ipaddress.IPv6Interface`, e.g.:
+
+    ```py
+    from ipaddress import IPv6Interface
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.ip_v6_interface_schema()
+    v = SchemaValidator(schema)
+    assert v.validate_python('2001:db8::1/32') == IPv6Interface('2001:db8::1/32')
+    ```
+
+    In lax mode interfaces are also validated from strings with a prefix length, from `IPv6Address`es, ints and
+    packed bytes, which have a prefix length of 128.
+
+    They're serialized to JSON as strings in the compressed form `str()` gives.
+
+    Args:
+        strict: Whether only `ipaddress.IPv6Interface` instances are accepted from python
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return dict_not_none(type='ip-v6-interface', strict=strict, ref=ref, metadata=metadata, serialization=serialization)
+
+
 class DefinitionsSchema(TypedDict, total=False):
     type: Required[Literal['definitions']]
     schema: Required[CoreSchema]
@@ -3421,6 +5007,75 @@ def definition_reference_schema(
     return dict_not_none(type='definition-ref', schema_ref=schema_ref, metadata=metadata, serialization=serialization)
 
 
+class TypeVarSchema(TypedDict, total=False):
+    type: Required[Literal['type-var']]
+    name: Required[str]
+    metadata: Any
+    serialization: SerSchema
+
+
+def type_var_schema(name: str, metadata: Any = None, serialization: SerSchema | None = None) -> TypeVarSchema:
+    """
+    Returns a schema that is a placeholder for a type variable, it's replaced by the schema substituted for
+    the type variable by an enclosing `parametrized_schema` when building, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    box_schema = core_schema.typed_dict_schema(
+        {'content': core_schema.typed_dict_field(core_schema.type_var_schema('T'))}
+    )
+    schema = core_schema.parametrized_schema(box_schema, {'T': core_schema.int_schema()})
+    v = SchemaValidator(schema)
+    assert v.validate_python({'content': '1'}) == {'content': 1}
+    ```
+
+    Args:
+        name: The name of the type variable
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return dict_not_none(type='type-var', name=name, metadata=metadata, serialization=serialization)
+
+
+class ParametrizedSchema(TypedDict, total=False):
+    type: Required[Literal['parametrized']]
+    schema: Required[CoreSchema]
+    type_vars: Required[Dict[str, CoreSchema]]
+    metadata: Any
+    serialization: SerSchema
+
+
+def parametrized_schema(
+    schema: CoreSchema,
+    type_vars: Dict[str, CoreSchema],
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> ParametrizedSchema:
+    """
+    Returns a schema that builds a generic schema with its type variables substituted, this allows a generic
+    schema to be defined once and specialized for different parameters, e.g. `Box[int]` and `Box[str]`:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    box_schema = core_schema.list_schema(core_schema.type_var_schema('T'))
+    v = SchemaValidator(core_schema.parametrized_schema(box_schema, {'T': core_schema.int_schema()}))
+    assert v.validate_python(['1', 2]) == [1, 2]
+    ```
+
+    Args:
+        schema: The generic schema, which may contain `type_var_schema`s
+        type_vars: Mapping of type variable names to the schemas substituted for them, substituted schemas
+            may themselves contain type variables from enclosing `parametrized_schema`s
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return dict_not_none(
+        type='parametrized', schema=schema, type_vars=type_vars, metadata=metadata, serialization=serialization
+    )
+
+
 MYPY = False
 # See https://github.com/python/mypy/issues/14034 for details, in summary mypy is extremely slow to process this
 # union which kills performance not just for pydantic, but even for code using pydantic
@@ -3438,6 +5093,7 @@ if not MYPY:
         DatetimeSchema,
         TimedeltaSchema,
         LiteralSchema,
+        EnumSchema,
         IsInstanceSchema,
         IsSubclassSchema,
         CallableSchema,
@@ -3456,8 +5112,10 @@ if not MYPY:
         NullableSchema,
         UnionSchema,
         TaggedUnionSchema,
+        MergeSchema,
         ChainSchema,
         LaxOrStrictSchema,
+        JsonOrPythonSchema,
         TypedDictSchema,
         ModelSchema,
         DataclassArgsSchema,
@@ -3468,8 +5126,22 @@ if not MYPY:
         JsonSchema,
         UrlSchema,
         MultiHostUrlSchema,
+        PathSchema,
+        TimezoneSchema,
+        FractionSchema,
+        DecimalSchema,
+        UuidSchema,
+        ComplexSchema,
+        IpV4AddressSchema,
+        IpV6AddressSchema,
+        IpV4NetworkSchema,
+        IpV6NetworkSchema,
+        IpV4InterfaceSchema,
+        IpV6InterfaceSchema,
         DefinitionsSchema,
         DefinitionReferenceSchema,
+        TypeVarSchema,
+        ParametrizedSchema,
     ]
 elif False:
     CoreSchema: TypeAlias = Dict[str, Any]
@@ -3489,6 +5161,7 @@ CoreSchemaType = Literal[
     'datetime',
     'timedelta',
     'literal',
+    'enum',
     'is-instance',
     'is-subclass',
     'callable',
@@ -3507,8 +5180,10 @@ CoreSchemaType = Literal[
     'nullable',
     'union',
     'tagged-union',
+    'merge',
     'chain',
     'lax-or-strict',
+    'json-or-python',
     'typed-dict',
     'model',
     'dataclass-args',
@@ -3519,8 +5194,22 @@ CoreSchemaType = Literal[
     'json',
     'url',
     'multi-host-url',
+    'path',
+    'timezone',
+    'fraction',
+    'decimal',
+    'uuid',
+    'complex',
+    'ip-v4-address',
+    'ip-v6-address',
+    'ip-v4-network',
+    'ip-v6-network',
+    'ip-v4-interface',
+    'ip-v6-interface',
     'definitions',
     'definition-ref',
+    'type-var',
+    'parametrized',
 ]
 
 
@@ -3530,7 +5219,10 @@ ErrorType = Literal[
     'no_such_attribute',
     'json_invalid',
     'json_type',
+    'json_string_too_long',
+    'json_duplicate_key',
     'recursion_loop',
+    'validation_budget_exceeded',
     'dict_attributes_type',
     'missing',
     'frozen_field',
@@ -3538,7 +5230,14 @@ ErrorType = Literal[
     'extra_forbidden',
     'invalid_key',
     'get_attribute_error',
+    'default_factory_error',
     'model_class_type',
+    'exclusive_group_conflict',
+    'exclusive_group_missing',
+    'field_dependency_failed',
+    'group_required_together',
+    'group_mutually_exclusive',
+    'group_at_least_one',
     'none_required',
     'bool',
     'greater_than',
@@ -3547,6 +5246,7 @@ ErrorType = Literal[
     'less_than_equal',
     'multiple_of',
     'finite_number',
+    'decimal_places',
     'too_short',
     'too_long',
     'iterable_type',
@@ -3559,22 +5259,31 @@ ErrorType = Literal[
     'string_pattern_mismatch',
     'dict_type',
     'mapping_type',
+    'dict_duplicate_key',
+    'default_dict_type',
     'list_type',
+    'list_unique_items',
     'tuple_type',
     'set_type',
+    'unhashable',
     'bool_type',
     'bool_parsing',
     'int_type',
     'int_parsing',
+    'int_parsing_size',
     'int_from_float',
     'float_type',
     'float_parsing',
     'bytes_type',
     'bytes_too_short',
     'bytes_too_long',
+    'bytes_invalid_encoding',
+    'bytearray_type',
+    'bytes_buffer_invalid',
     'value_error',
     'assertion_error',
     'literal_error',
+    'enum',
     'date_type',
     'date_parsing',
     'date_from_datetime_parsing',
@@ -3588,8 +5297,13 @@ ErrorType = Literal[
     'datetime_object_invalid',
     'datetime_past',
     'datetime_future',
-    'datetime_aware',
-    'datetime_naive',
+    'timezone_aware',
+    'timezone_naive',
+    'timezone_offset',
+    'datetime_unknown_zone',
+    'datetime_nonexistent',
+    'datetime_ambiguous',
+    'datetime_microseconds_precision',
     'time_delta_type',
     'time_delta_parsing',
     'frozen_set_type',
@@ -3598,6 +5312,8 @@ ErrorType = Literal[
     'callable_type',
     'union_tag_invalid',
     'union_tag_not_found',
+    'union_tag_other_matches',
+    'merge_conflict',
     'arguments_type',
     'missing_argument',
     'unexpected_keyword_argument',
@@ -3611,4 +5327,34 @@ ErrorType = Literal[
     'url_syntax_violation',
     'url_too_long',
     'url_scheme',
+    'path_type',
+    'path_not_absolute',
+    'path_suffix',
+    'path_too_many_parts',
+    'path_not_exists',
+    'path_not_file',
+    'path_not_directory',
+    'timezone_type',
+    'timezone_name',
+    'fraction_type',
+    'fraction_parsing',
+    'fraction_zero_denominator',
+    'fraction_too_many_digits',
+    'decimal_type',
+    'decimal_parsing',
+    'decimal_max_digits',
+    'decimal_max_places',
+    'decimal_whole_digits',
+    'uuid_type',
+    'uuid_parsing',
+    'uuid_version',
+    'complex_type',
+    'complex_str_parsing',
+    'ip_v4_address',
+    'ip_v6_address',
+    'ip_v4_network',
+    'ip_v6_network',
+    'ip_v4_interface',
+    'ip_v6_interface',
+    'ip_network_host_bits',
 ]
@@ -1,8 +1,9 @@
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList};
+use pyo3::types::{PyDict, PyList, PyString};
 
-use ahash::{AHashMap, AHashSet};
+use ahash::AHashMap;
+use regex::Regex;
 
 use crate::build_tools::{py_err, py_error_type, SchemaDict};
 use crate::questions::Answers;
@@ -21,45 +22,117 @@ pub enum ThingOrId<T> {
     Id(usize),
 }
 
+/// How a schema with a `ref` was built
+#[derive(Clone, Debug)]
+pub enum DefinitionKind {
+    /// the ref is used within itself, so the validator is stored in a slot
+    Slot(usize),
+    /// the ref is only used outside itself, so the validator is cloned wherever it's used
+    Reusable,
+    /// the ref isn't used anywhere, so the schema is built as if it had no ref
+    Unused,
+}
+
+/// Summary of a schema with a `ref`, recorded while building so definitions can be inspected afterwards
+#[derive(Clone, Debug)]
+pub struct Definition {
+    ref_: String,
+    kind: DefinitionKind,
+    name: String,
+    references: usize,
+}
+
+impl Definition {
+    /// the definition's ref and slot id if it's stored in a slot
+    pub fn slot(&self) -> Option<(&str, usize)> {
+        match self.kind {
+            DefinitionKind::Slot(slot_id) => Some((&self.ref_, slot_id)),
+            _ => None,
+        }
+    }
+
+    pub fn to_py_dict<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        let (kind, slot_id) = match self.kind {
+            DefinitionKind::Slot(slot_id) => ("slot", Some(slot_id)),
+            DefinitionKind::Reusable => ("reusable", None),
+            DefinitionKind::Unused => ("unused", None),
+        };
+        let dict = PyDict::new(py);
+        dict.set_item(intern!(py, "ref"), &self.ref_)?;
+        dict.set_item(intern!(py, "kind"), PyString::new(py, kind))?;
+        dict.set_item(intern!(py, "slot_id"), slot_id)?;
+        dict.set_item(intern!(py, "name"), &self.name)?;
+        dict.set_item(intern!(py, "references"), self.references)?;
+        Ok(dict)
+    }
+}
+
 /// `BuildContext` is used to store extra information while building validators and type_serializers
 #[derive(Clone, Debug)]
 pub struct BuildContext<T> {
-    /// set of used refs, useful to see if a `ref` is actually used elsewhere in the schema
-    used_refs: AHashSet<String>,
+    /// how many times each ref is used in the schema, useful to see if a `ref` is actually used elsewhere
+    used_refs: AHashMap<String, usize>,
     /// holds validators/type_serializers which reference themselves and therefore can't be cloned and owned
     /// in one or multiple places.
     slots: Vec<Slot<T>>,
     /// holds validators/type_serializers which need to be accessed from multiple other validators/type_serializers
     /// and therefore can't be owned by them directly.
     reusable: AHashMap<String, T>,
+    /// schemas with a `ref` in the order they were built, only used for introspection
+    definitions: Vec<Definition>,
+    /// how many times each ref was looked up by a `definition-ref` schema
+    reference_counts: AHashMap<String, usize>,
+    /// schemas substituted for type variables by enclosing `parametrized` schemas, innermost last
+    type_vars: Vec<AHashMap<String, Py<PyAny>>>,
+    /// regexes compiled while building, keyed by their pattern so identical patterns share one compiled regex
+    regexes: AHashMap<String, Regex>,
 }
 
 impl<T: Clone + std::fmt::Debug> BuildContext<T> {
     pub fn new(schema: &PyAny) -> PyResult<Self> {
-        let mut used_refs = AHashSet::new();
+        let mut used_refs = AHashMap::new();
         extract_used_refs(schema, &mut used_refs)?;
         Ok(Self {
             used_refs,
             slots: Vec::new(),
             reusable: AHashMap::new(),
+            definitions: Vec::new(),
+            reference_counts: AHashMap::new(),
+            type_vars: Vec::new(),
+            regexes: AHashMap::new(),
         })
     }
 
     pub fn for_self_schema() -> Self {
-        let mut used_refs = AHashSet::with_capacity(3);
+        let mut used_refs = AHashMap::with_capacity(3);
         // NOTE: we don't call `extract_used_refs` for performance reasons, if more recursive references
         // are used, they would need to be manually added here.
         // we use `2` as count to avoid `find_slot` pulling the validator out of slots and returning it directly
-        used_refs.insert("root-schema".to_string());
-        used_refs.insert("ser-schema".to_string());
-        used_refs.insert("inc-ex-type".to_string());
+        used_refs.insert("root-schema".to_string(), 2);
+        used_refs.insert("ser-schema".to_string(), 2);
+        used_refs.insert("inc-ex-type".to_string(), 2);
         Self {
             used_refs,
             slots: Vec::new(),
             reusable: AHashMap::new(),
+            definitions: Vec::new(),
+            reference_counts: AHashMap::new(),
+            type_vars: Vec::new(),
+            regexes: AHashMap::new(),
         }
     }
 
+    /// compile `pattern`, or if it's already been compiled return a clone of that regex,
+    /// clones share the compiled program
+    pub fn compile_regex(&mut self, pattern: &str) -> Result<Regex, regex::Error> {
+        if let Some(regex) = self.regexes.get(pattern) {
+            return Ok(regex.clone());
+        }
+        let regex = Regex::new(pattern)?;
+        self.regexes.insert(pattern.to_string(), regex.clone());
+        Ok(regex)
+    }
+
     /// Check whether a ref is already in `reusable` or `slots`, we shouldn't allow repeated refs
     pub fn ref_already_used(&self, ref_: &str) -> bool {
         self.reusable.contains_key(ref_) || self.slots.iter().any(|slot| slot.slot_ref == ref_)
@@ -67,7 +140,7 @@ impl<T: Clone + std::fmt::Debug> BuildContext<T> {
 
     /// check if a ref is used elsewhere in the schema
     pub fn ref_used(&self, ref_: &str) -> bool {
-        self.used_refs.contains(ref_)
+        self.used_refs.contains_key(ref_)
     }
 
     /// check if a ref is used within a given schema
@@ -75,6 +148,24 @@ impl<T: Clone + std::fmt::Debug> BuildContext<T> {
         check_ref_used(schema_dict, ref_)
     }
 
+    /// A schema with a `ref` is built once and shared wherever the ref is used, so if it contains type variables
+    /// every use would get the substitution in place when it was built. Such a schema may only be referenced
+    /// from within itself, outside any `parametrized` schema nested in it.
+    pub fn check_generic_ref(&self, schema_dict: &PyAny, ref_: &str) -> PyResult<()> {
+        let mut bound = Vec::new();
+        if let Some(name) = find_free_type_var(schema_dict, &mut bound)? {
+            let uses = self.used_refs.get(ref_).copied().unwrap_or(0);
+            if count_ref_uses(schema_dict, ref_)? < uses {
+                return py_err!(
+                    "Schema with ref `{}` contains type variable `{}`, it can only be referenced within itself",
+                    ref_,
+                    name
+                );
+            }
+        }
+        Ok(())
+    }
+
     /// add a validator/serializer to `reusable` so it can be cloned and used again elsewhere
     pub fn store_reusable(&mut self, ref_: String, val_ser: T) {
         self.reusable.insert(ref_, val_ser);
@@ -112,6 +203,7 @@ impl<T: Clone + std::fmt::Debug> BuildContext<T> {
     /// find validator/serializer by `ref`, if the `ref` is in `resuable` return a clone of the validator/serializer,
     /// otherwise return the id of the slot.
     pub fn find(&mut self, ref_: &str) -> PyResult<ThingOrId<T>> {
+        *self.reference_counts.entry(ref_.to_string()).or_insert(0) += 1;
         if let Some(val_ser) = self.reusable.get(ref_) {
             Ok(ThingOrId::Thing(val_ser.clone()))
         } else {
@@ -131,6 +223,62 @@ impl<T: Clone + std::fmt::Debug> BuildContext<T> {
         }
     }
 
+    /// build a `parametrized` schema's inner schema with `type_vars` substituted for `type-var` schemas within it
+    pub fn with_type_vars(&mut self, type_vars: &PyDict, build: impl FnOnce(&mut Self) -> PyResult<T>) -> PyResult<T> {
+        let type_vars = type_vars
+            .iter()
+            .map(|(name, schema)| Ok((name.extract()?, schema.into())))
+            .collect::<PyResult<_>>()?;
+        self.type_vars.push(type_vars);
+        let result = build(self);
+        self.type_vars.pop();
+        result
+    }
+
+    /// build the schema substituted for the type variable `name`, the substituted schema is built outside the
+    /// `parametrized` schema which provided it, so type variables within it refer to enclosing substitutions
+    pub fn build_type_var<'py>(
+        &mut self,
+        py: Python<'py>,
+        name: &str,
+        build: impl FnOnce(&'py PyAny, &mut Self) -> PyResult<T>,
+    ) -> PyResult<T> {
+        let position = match self
+            .type_vars
+            .iter()
+            .rposition(|type_vars| type_vars.contains_key(name))
+        {
+            Some(position) => position,
+            None => return py_err!("Type variable `{}` has not been substituted", name),
+        };
+        let schema = self.type_vars[position][name].clone_ref(py).into_ref(py);
+        let inner_type_vars = self.type_vars.split_off(position);
+        let result = build(schema, self);
+        self.type_vars.extend(inner_type_vars);
+        result
+    }
+
+    /// record how a schema with a `ref` was built, see `definitions`
+    pub fn record_definition(&mut self, ref_: String, kind: DefinitionKind, name: &str) {
+        self.definitions.push(Definition {
+            ref_,
+            kind,
+            name: name.to_string(),
+            references: 0,
+        });
+    }
+
+    /// summary of every schema with a `ref`, including how many times each was referenced
+    pub fn definitions(&self) -> Vec<Definition> {
+        self.definitions
+            .iter()
+            .map(|definition| Definition {
+                references: self.reference_counts.get(&definition.ref_).copied().unwrap_or(0),
+                ..definition.clone()
+            })
+            .collect()
+    }
+
     /// find a validator/serializer by `slot_id` - this used in `Validator.complete`,
     /// specifically `DefinitionRefValidator` to set its name
     pub fn find_validator(&self, slot_id: usize) -> PyResult<&T> {
@@ -175,10 +323,12 @@ impl BuildContext<CombinedSerializer> {
     }
 }
 
-fn extract_used_refs(schema: &PyAny, refs: &mut AHashSet<String>) -> PyResult<()> {
+fn extract_used_refs(schema: &PyAny, refs: &mut AHashMap<String, usize>) -> PyResult<()> {
     if let Ok(dict) = schema.downcast::<PyDict>() {
         if is_definition_ref(dict)? {
-            refs.insert(dict.get_as_req(intern!(schema.py(), "schema_ref"))?);
+            *refs
+                .entry(dict.get_as_req(intern!(schema.py(), "schema_ref"))?)
+                .or_insert(0) += 1;
         } else {
             for (key, value) in dict.iter() {
                 if !key.eq(intern!(schema.py(), "metadata"))? {
@@ -216,6 +366,78 @@ fn check_ref_used(schema: &PyAny, ref_: &str) -> PyResult<bool> {
     Ok(false)
 }
 
+/// count uses of `ref_` within `schema`, ignoring `parametrized` schemas since uses within them
+/// could have a different substitution
+fn count_ref_uses(schema: &PyAny, ref_: &str) -> PyResult<usize> {
+    let mut count = 0;
+    if let Ok(dict) = schema.downcast::<PyDict>() {
+        if is_definition_ref(dict)? {
+            let value: &str = dict.get_as_req(intern!(schema.py(), "schema_ref"))?;
+            return Ok(usize::from(value == ref_));
+        } else if !is_schema_type(dict, "parametrized")? {
+            for (key, value) in dict.iter() {
+                if !key.eq(intern!(schema.py(), "metadata"))? {
+                    count += count_ref_uses(value, ref_)?;
+                }
+            }
+        }
+    } else if let Ok(list) = schema.downcast::<PyList>() {
+        for item in list.iter() {
+            count += count_ref_uses(item, ref_)?;
+        }
+    }
+    Ok(count)
+}
+
+/// find a type variable within `schema` which isn't substituted by a `parametrized` schema also within it,
+/// `bound` holds the names substituted by enclosing `parametrized` schemas
+fn find_free_type_var(schema: &PyAny, bound: &mut Vec<String>) -> PyResult<Option<String>> {
+    let py = schema.py();
+    if let Ok(dict) = schema.downcast::<PyDict>() {
+        if is_schema_type(dict, "type-var")? {
+            let name: String = dict.get_as_req(intern!(py, "name"))?;
+            return Ok(if bound.contains(&name) { None } else { Some(name) });
+        } else if is_schema_type(dict, "parametrized")? {
+            // substitutions are built outside the `parametrized` schema, so only the inner schema sees its names
+            let type_vars: &PyDict = dict.get_as_req(intern!(py, "type_vars"))?;
+            for value in type_vars.values() {
+                if let Some(name) = find_free_type_var(value, bound)? {
+                    return Ok(Some(name));
+                }
+            }
+            let outer_len = bound.len();
+            for name in type_vars.keys() {
+                bound.push(name.extract()?);
+            }
+            let result = find_free_type_var(dict.get_as_req(intern!(py, "schema"))?, bound);
+            bound.truncate(outer_len);
+            return result;
+        } else {
+            for (key, value) in dict.iter() {
+                if !key.eq(intern!(py, "metadata"))? {
+                    if let Some(name) = find_free_type_var(value, bound)? {
+                        return Ok(Some(name));
+                    }
+                }
+            }
+        }
+    } else if let Ok(list) = schema.downcast::<PyList>() {
+        for item in list.iter() {
+            if let Some(name) = find_free_type_var(item, bound)? {
+                return Ok(Some(name));
+            }
+        }
+    }
+    Ok(None)
+}
+
+fn is_schema_type(dict: &PyDict, schema_type: &str) -> PyResult<bool> {
+    match dict.get_item(intern!(dict.py(), "type")) {
+        Some(type_value) => type_value.eq(schema_type),
+        None => Ok(false),
+    }
+}
+
 fn is_definition_ref(dict: &PyDict) -> PyResult<bool> {
     match dict.get_item(intern!(dict.py(), "type")) {
         Some(type_value) => type_value.eq(intern!(dict.py(), "definition-ref")),
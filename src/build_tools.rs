@@ -4,9 +4,11 @@ use std::fmt;
 
 use pyo3::exceptions::{PyException, PyKeyError};
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList, PyString};
+use pyo3::types::{PyBool, PyBytes, PyDict, PyFloat, PyInt, PyList, PyModule, PyString, PyTuple};
 use pyo3::{intern, FromPyObject, PyErrArguments};
 
+use ahash::AHashMap;
+
 use crate::errors::{ErrorMode, ValError};
 use crate::ValidationError;
 
@@ -99,6 +101,117 @@ pub fn is_strict(schema: &PyDict, config: Option<&PyDict>) -> PyResult<bool> {
     Ok(schema_or_config_same(schema, config, intern!(py, "strict"))?.unwrap_or(false))
 }
 
+/// whether leaf validators should call `__pydantic_core_coerce__` on inputs they reject in lax mode
+pub fn coerce_hook_enabled(config: Option<&PyDict>) -> PyResult<bool> {
+    match config {
+        Some(config) => Ok(config.get_as(intern!(config.py(), "coerce_hook"))?.unwrap_or(true)),
+        None => Ok(true),
+    }
+}
+
+/// whether validators are simplified at build time, merging constraint-only chain steps and removing wrappers
+/// which have no effect
+pub fn merge_validators_enabled(config: Option<&PyDict>) -> PyResult<bool> {
+    match config {
+        Some(config) => Ok(config.get_as(intern!(config.py(), "merge_validators"))?.unwrap_or(true)),
+        None => Ok(true),
+    }
+}
+
+/// whether dict, list and tuple subclasses are read directly like the exact types, ignoring overridden methods,
+/// rather than with the mapping and iterator protocols
+pub fn force_exact_container_path(config: Option<&PyDict>) -> PyResult<bool> {
+    match config {
+        Some(config) => Ok(config
+            .get_as(intern!(config.py(), "force_exact_container_path"))?
+            .unwrap_or(false)),
+        None => Ok(false),
+    }
+}
+
+/// a model's typed dict `schema`, copied with aliases from the model's `alias_generator` added to the fields without
+/// one, the generator is called once for each of those fields, generated aliases mustn't collide with other aliases
+pub fn apply_alias_generator<'py>(model_schema: &'py PyDict, schema: &'py PyDict) -> PyResult<&'py PyDict> {
+    let py = model_schema.py();
+    let alias_generator: &PyAny = match model_schema.get_as(intern!(py, "alias_generator"))? {
+        Some(alias_generator) => alias_generator,
+        None => return Ok(schema),
+    };
+    if schema.get_as::<&str>(intern!(py, "type"))? != Some("typed-dict") {
+        return py_err!("`alias_generator` requires `schema` to be a typed-dict schema");
+    }
+    let serialization: bool = model_schema
+        .get_as(intern!(py, "alias_generator_serialization"))?
+        .unwrap_or(false);
+    let validation_alias_key = intern!(py, "validation_alias");
+    let serialization_alias_key = intern!(py, "serialization_alias");
+
+    let fields: &PyDict = schema.get_as_req(intern!(py, "fields"))?;
+    let new_fields = PyDict::new(py);
+    let mut validation_aliases = AliasCollisions::default();
+    let mut serialization_aliases = AliasCollisions::default();
+    for (name, field) in fields {
+        let name: &str = name.extract()?;
+        let field = field.downcast::<PyDict>()?.copy()?;
+        let needs_validation_alias = field.get_item(validation_alias_key).is_none();
+        let needs_serialization_alias = serialization && field.get_item(serialization_alias_key).is_none();
+        if needs_validation_alias || needs_serialization_alias {
+            let alias = alias_generator.call1((name,))?;
+            if alias.downcast::<PyString>().is_err() {
+                return py_err!(
+                    "`alias_generator` must return a str, got `{}` for field '{}'",
+                    alias.get_type().name()?,
+                    name
+                );
+            }
+            if needs_validation_alias {
+                field.set_item(validation_alias_key, alias)?;
+            }
+            if needs_serialization_alias {
+                field.set_item(serialization_alias_key, alias)?;
+            }
+        }
+        // aliases which are paths can't collide with a generated alias
+        if let Some(alias) = field
+            .get_item(validation_alias_key)
+            .and_then(|a| a.extract::<String>().ok())
+        {
+            validation_aliases.add(alias, name, needs_validation_alias)?;
+        }
+        if let Some(alias) = field.get_as::<String>(serialization_alias_key)? {
+            serialization_aliases.add(alias, name, needs_serialization_alias)?;
+        }
+        new_fields.set_item(name, field)?;
+    }
+    let new_schema = schema.copy()?;
+    new_schema.set_item(intern!(py, "fields"), new_fields)?;
+    Ok(new_schema)
+}
+
+/// the aliases of the fields seen so far, with the field and whether the alias was generated
+#[derive(Default)]
+struct AliasCollisions(AHashMap<String, (String, bool)>);
+
+impl AliasCollisions {
+    fn add(&mut self, alias: String, field: &str, generated: bool) -> PyResult<()> {
+        match self.0.get(&alias) {
+            // collisions between explicit aliases are left to the typed dict
+            Some((other_field, other_generated)) if generated || *other_generated => py_err!(
+                "Alias '{}' of field '{}' is also the alias of field '{}', {}",
+                alias,
+                field,
+                other_field,
+                "aliases from `alias_generator` must be unique"
+            ),
+            Some(_) => Ok(()),
+            None => {
+                self.0.insert(alias, (field.to_string(), generated));
+                Ok(())
+            }
+        }
+    }
+}
+
 enum SchemaErrorEnum {
     Message(String),
     ValidationError(ValidationError),
@@ -140,7 +253,14 @@ impl SchemaError {
         match error {
             ValError::LineErrors(raw_errors) => {
                 let line_errors = raw_errors.into_iter().map(|e| e.into_py(py)).collect();
-                let validation_error = ValidationError::new(line_errors, "Schema".to_object(py), ErrorMode::Python);
+                let validation_error = ValidationError::new(
+                    line_errors,
+                    "Schema".to_object(py),
+                    ErrorMode::Python,
+                    false,
+                    None,
+                    false,
+                );
                 let schema_error = SchemaError(SchemaErrorEnum::ValidationError(validation_error));
                 match Py::new(py, schema_error) {
                     Ok(err) => PyErr::from_value(err.into_ref(py)),
@@ -177,7 +297,7 @@ impl SchemaError {
     fn errors(&self, py: Python) -> PyResult<Py<PyList>> {
         match &self.0 {
             SchemaErrorEnum::Message(_) => Ok(PyList::empty(py).into_py(py)),
-            SchemaErrorEnum::ValidationError(error) => error.errors(py, None),
+            SchemaErrorEnum::ValidationError(error) => error.errors(py, None, None, None, None, None),
         }
     }
 
@@ -249,6 +369,69 @@ pub fn safe_repr(v: &PyAny) -> Cow<str> {
     }
 }
 
+/// check every value in `schema` and `config` other than plain containers and scalars, e.g. functions and default
+/// values, can be pickled, so pickling a validator or serializer with one which can't fails with an error naming
+/// where it is in the schema rather than pickle's own error
+pub fn check_picklable(py: Python, owner: &str, schema: &PyAny, config: Option<&PyDict>) -> PyResult<()> {
+    let pickle = py.import(intern!(py, "pickle"))?;
+    let mut path = String::from("schema");
+    check_picklable_value(pickle, owner, schema, None, &mut path)?;
+    if let Some(config) = config {
+        let mut path = String::from("config");
+        check_picklable_value(pickle, owner, config, None, &mut path)?;
+    }
+    Ok(())
+}
+
+fn check_picklable_value(
+    pickle: &PyModule,
+    owner: &str,
+    value: &PyAny,
+    schema_type: Option<&str>,
+    path: &mut String,
+) -> PyResult<()> {
+    let py = value.py();
+    let path_len = path.len();
+    if let Ok(dict) = value.downcast::<PyDict>() {
+        // `{'type': 'general', 'function': ...}` describes how a function is called rather than being a schema,
+        // so errors inside it name the schema holding it
+        let function_spec = path.ends_with("['function']");
+        let schema_type = match dict.get_item(intern!(py, "type")) {
+            Some(schema_type) if !function_spec => schema_type.extract().ok(),
+            _ => schema_type,
+        };
+        for (key, item) in dict {
+            path.push_str(&format!("[{}]", safe_repr(key)));
+            check_picklable_value(pickle, owner, item, schema_type, path)?;
+            path.truncate(path_len);
+        }
+    } else if value.downcast::<PyList>().is_ok() || value.downcast::<PyTuple>().is_ok() {
+        for (index, item) in value.iter()?.enumerate() {
+            path.push_str(&format!("[{index}]"));
+            check_picklable_value(pickle, owner, item?, schema_type, path)?;
+            path.truncate(path_len);
+        }
+    } else if !(value.is_none()
+        || value.is_instance_of::<PyString>()?
+        || value.is_instance_of::<PyBool>()?
+        || value.is_instance_of::<PyInt>()?
+        || value.is_instance_of::<PyFloat>()?
+        || value.is_instance_of::<PyBytes>()?)
+    {
+        if let Err(err) = pickle.getattr(intern!(py, "dumps"))?.call1((value,)) {
+            let node = match schema_type {
+                Some(schema_type) => format!("in the '{schema_type}' schema "),
+                None => String::new(),
+            };
+            let message = format!("Can't pickle {owner}, {path} {node}can't be pickled: {err}");
+            let pickling_error = PyErr::from_value(pickle.getattr(intern!(py, "PicklingError"))?.call1((message,))?);
+            pickling_error.set_cause(py, Some(err));
+            return Err(pickling_error);
+        }
+    }
+    Ok(())
+}
+
 #[derive(Debug, Clone)]
 pub(crate) enum ExtraBehavior {
     Allow,
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use ahash::AHashMap;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::build_tools::SchemaDict;
+
+use super::ErrorType;
+
+/// Replacement message templates for built-in error types from the `custom_error_messages` config, they're only
+/// used to render `msg`, the error's `type` and `ctx` are unchanged
+#[derive(Debug, Clone)]
+pub struct CustomErrorMessages(Arc<AHashMap<String, String>>);
+
+impl CustomErrorMessages {
+    pub fn from_config(py: Python, config: Option<&PyDict>) -> PyResult<Option<Self>> {
+        let messages_dict: &PyDict = match config.get_as(intern!(py, "custom_error_messages"))? {
+            Some(messages_dict) => messages_dict,
+            None => return Ok(None),
+        };
+        let mut messages = AHashMap::with_capacity(messages_dict.len());
+        for (key, value) in messages_dict {
+            let error_type: String = key.extract()?;
+            let template: String = value.extract()?;
+            ErrorType::check_message_template(py, &error_type, &template)?;
+            messages.insert(error_type, template);
+        }
+        Ok(Some(Self(Arc::new(messages))))
+    }
+
+    /// the error's message from its replacement template, `None` if it has none, custom errors keep their own
+    /// message so `custom_error` schemas and `error_overrides` take precedence
+    pub fn render(&self, py: Python, error_type: &ErrorType) -> Option<PyResult<String>> {
+        if matches!(error_type, ErrorType::CustomError { .. }) {
+            return None;
+        }
+        self.0
+            .get(&error_type.to_string())
+            .map(|template| error_type.render_template(py, template))
+    }
+}
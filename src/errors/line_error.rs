@@ -60,6 +60,19 @@ impl<'a> ValError<'a> {
         }
     }
 
+    /// helper function to call with_field_title on line items if applicable
+    pub fn with_field_title(self, field_title: Option<&str>) -> Self {
+        match self {
+            Self::LineErrors(line_errors) => Self::LineErrors(
+                line_errors
+                    .into_iter()
+                    .map(|line_error| line_error.with_field_title(field_title))
+                    .collect(),
+            ),
+            other => other,
+        }
+    }
+
     /// a bit like clone but change the lifetime to match py
     pub fn duplicate<'py>(&self, py: Python<'py>) -> ValError<'py> {
         match self {
@@ -79,6 +92,23 @@ pub struct ValLineError<'a> {
     // location is reversed so that adding an "outer" location item is pushing, it's reversed before showing to the user
     pub location: Location,
     pub input_value: InputValue<'a>,
+    // title of the innermost field the error occurred in, included as `field_title` in the error context
+    pub field_title: Option<String>,
+    // step of the innermost chain the error occurred in, included as `chain_step` in the error context
+    pub chain_step: Option<ChainStep>,
+    // the exception which caused the error, set as `__cause__` of the raised `ValidationError`
+    pub cause: Option<PyObject>,
+}
+
+/// The step of a `chain` schema an error occurred in
+#[derive(Clone)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub struct ChainStep {
+    /// the step's label, or its index if it has none
+    pub step: LocItem,
+    /// with `error_input_type` set on the chain, the type of the value given to the step,
+    /// included as `chain_step_input_type` in the error context
+    pub input_type: Option<String>,
 }
 
 impl<'a> ValLineError<'a> {
@@ -87,6 +117,9 @@ impl<'a> ValLineError<'a> {
             error_type,
             input_value: input.as_error_value(),
             location: Location::default(),
+            field_title: None,
+            chain_step: None,
+            cause: None,
         }
     }
 
@@ -95,6 +128,9 @@ impl<'a> ValLineError<'a> {
             error_type,
             input_value: input.as_error_value(),
             location: Location::new_some(loc.into()),
+            field_title: None,
+            chain_step: None,
+            cause: None,
         }
     }
 
@@ -103,6 +139,9 @@ impl<'a> ValLineError<'a> {
             error_type,
             input_value: input.as_error_value(),
             location,
+            field_title: None,
+            chain_step: None,
+            cause: None,
         }
     }
 
@@ -111,6 +150,9 @@ impl<'a> ValLineError<'a> {
             error_type,
             input_value,
             location: Location::default(),
+            field_title: None,
+            chain_step: None,
+            cause: None,
         }
     }
 
@@ -121,6 +163,27 @@ impl<'a> ValLineError<'a> {
         self
     }
 
+    /// set the title of the field the error occurred in, unless an inner field has already set one
+    pub fn with_field_title(mut self, field_title: Option<&str>) -> Self {
+        if self.field_title.is_none() {
+            self.field_title = field_title.map(ToString::to_string);
+        }
+        self
+    }
+
+    /// set the chain step the error occurred in, unless an inner chain has already set one
+    pub fn with_chain_step(mut self, chain_step: &ChainStep) -> Self {
+        if self.chain_step.is_none() {
+            self.chain_step = Some(chain_step.clone());
+        }
+        self
+    }
+
+    pub fn with_cause(mut self, cause: PyObject) -> Self {
+        self.cause = Some(cause);
+        self
+    }
+
     // change the error_type on a error in place
     pub fn with_type(mut self, error_type: ErrorType) -> Self {
         self.error_type = error_type;
@@ -133,6 +196,9 @@ impl<'a> ValLineError<'a> {
             error_type: self.error_type.clone(),
             input_value: InputValue::<'py>::from(self.input_value.to_object(py)),
             location: self.location.clone(),
+            field_title: self.field_title.clone(),
+            chain_step: self.chain_step.clone(),
+            cause: self.cause.clone(),
         }
     }
 }
@@ -1,5 +1,6 @@
 use pyo3::exceptions::PyTypeError;
 use pyo3::once_cell::GILOnceCell;
+use std::cmp::Ordering;
 use std::fmt;
 
 use pyo3::prelude::*;
@@ -7,7 +8,7 @@ use pyo3::types::{PyList, PyString, PyTuple};
 use serde::ser::SerializeSeq;
 use serde::{Serialize, Serializer};
 
-use crate::lookup_key::{LookupPath, PathItem};
+use crate::lookup_key::PathItem;
 
 /// Used to store individual items of the error location, e.g. a string for key/field names
 /// or a number for array indices.
@@ -22,6 +23,20 @@ pub enum LocItem {
     ///   * dict with int keys `Dict[int, ...]` (python only)
     ///   * with integer keys in tagged unions
     I(i64),
+    /// a field with an alias, which can be shown either as its alias path or its name,
+    /// see `ValidationError.errors(by_alias=...)`
+    Field(Box<FieldLoc>),
+}
+
+#[derive(Clone)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub struct FieldLoc {
+    // always `LocItem::S`, stored as an item so it can be returned as a slice like `alias`
+    name: LocItem,
+    // the alias path which matched the input, or the first one if the field was missing
+    alias: Vec<LocItem>,
+    // whether the field is shown by alias unless overridden, from the `loc_by_alias` config
+    by_alias: bool,
 }
 
 impl fmt::Display for LocItem {
@@ -30,6 +45,43 @@ impl fmt::Display for LocItem {
             Self::S(s) if s.contains('.') => write!(f, "`{s}`"),
             Self::S(s) => write!(f, "{s}"),
             Self::I(i) => write!(f, "{i}"),
+            Self::Field(_) => {
+                let items = self.items(None).iter().map(ToString::to_string).collect::<Vec<_>>();
+                write!(f, "{}", items.join("."))
+            }
+        }
+    }
+}
+
+impl LocItem {
+    pub fn field(name: &str, alias: Vec<LocItem>, by_alias: bool) -> Self {
+        Self::Field(Box::new(FieldLoc {
+            name: name.into(),
+            alias,
+            by_alias,
+        }))
+    }
+
+    /// the plain string and int items this item is shown as, `by_alias` overrides the field's `by_alias`
+    fn items(&self, by_alias: Option<bool>) -> &[LocItem] {
+        match self {
+            Self::Field(field) => match by_alias.unwrap_or(field.by_alias) {
+                true => &field.alias,
+                false => std::slice::from_ref(&field.name),
+            },
+            _ => std::slice::from_ref(self),
+        }
+    }
+
+    /// order used when sorting errors by location, ints are ordered numerically before strings
+    fn sort_cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::I(a), Self::I(b)) => a.cmp(b),
+            (Self::S(a), Self::S(b)) => a.cmp(b),
+            (Self::I(_), Self::S(_)) => Ordering::Less,
+            (Self::S(_), Self::I(_)) => Ordering::Greater,
+            // fields are expanded into their items before sorting, see `Location::sort_cmp`
+            _ => self.to_string().cmp(&other.to_string()),
         }
     }
 }
@@ -77,6 +129,10 @@ impl ToPyObject for LocItem {
         match self {
             Self::S(val) => val.to_object(py),
             Self::I(val) => val.to_object(py),
+            Self::Field(_) => match self.items(None) {
+                [item] => item.to_object(py),
+                items => PyTuple::new(py, items).to_object(py),
+            },
         }
     }
 }
@@ -104,6 +160,10 @@ impl Serialize for LocItem {
         match self {
             Self::S(s) => serializer.serialize_str(s.as_str()),
             Self::I(loc) => serializer.serialize_i64(*loc),
+            Self::Field(_) => match self.items(None) {
+                [item] => item.serialize(serializer),
+                items => items.serialize(serializer),
+            },
         }
     }
 }
@@ -134,31 +194,15 @@ static EMPTY_TUPLE: GILOnceCell<PyObject> = GILOnceCell::new();
 
 impl ToPyObject for Location {
     fn to_object(&self, py: Python<'_>) -> PyObject {
-        match self {
-            Self::List(loc) => PyTuple::new(py, loc.iter().rev()).to_object(py),
-            Self::Empty => EMPTY_TUPLE
-                .get_or_init(py, || PyTuple::empty(py).to_object(py))
-                .clone_ref(py),
-        }
-    }
-}
-
-impl From<&LookupPath> for Location {
-    fn from(lookup_path: &LookupPath) -> Self {
-        let v = lookup_path
-            .iter()
-            .rev()
-            .map(|path_item| path_item.clone().into())
-            .collect();
-        Self::List(v)
+        self.to_object_by_alias(py, None)
     }
 }
 
 impl fmt::Display for Location {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::List(loc) => {
-                let loc_str = loc.iter().rev().map(|i| i.to_string()).collect::<Vec<_>>();
+            Self::List(_) => {
+                let loc_str = self.items(None).iter().map(|i| i.to_string()).collect::<Vec<_>>();
                 writeln!(f, "{}", loc_str.join("."))
             }
             Self::Empty => Ok(()),
@@ -174,6 +218,30 @@ impl Location {
         Self::List(loc)
     }
 
+    /// the location as a tuple, `by_alias` overrides whether fields with an alias are shown by alias or name
+    pub fn to_object_by_alias(&self, py: Python, by_alias: Option<bool>) -> PyObject {
+        match self {
+            Self::List(_) => PyTuple::new(py, self.items(by_alias)).to_object(py),
+            Self::Empty => EMPTY_TUPLE
+                .get_or_init(py, || PyTuple::empty(py).to_object(py))
+                .clone_ref(py),
+        }
+    }
+
+    /// the plain items of the location from the outermost, with fields expanded, see `LocItem::items`
+    fn items(&self, by_alias: Option<bool>) -> Vec<&LocItem> {
+        match self {
+            // location is reversed, so iterate from the end
+            Self::List(loc) => loc.iter().rev().flat_map(|item| item.items(by_alias)).collect(),
+            Self::Empty => Vec::new(),
+        }
+    }
+
+    /// whether the location is a single item, e.g. a key of the outermost dict
+    pub fn is_single(&self) -> bool {
+        matches!(self, Self::List(loc) if loc.len() == 1)
+    }
+
     pub fn with_outer(&mut self, loc_item: LocItem) {
         match self {
             Self::List(ref mut loc) => loc.push(loc_item),
@@ -182,6 +250,36 @@ impl Location {
             }
         };
     }
+
+    /// compare locations item by item from the outermost, a location is ordered before locations it's a prefix of
+    pub fn sort_cmp(&self, other: &Self, by_alias: Option<bool>) -> Ordering {
+        match (self, other) {
+            (Self::Empty, Self::Empty) => Ordering::Equal,
+            (Self::Empty, Self::List(_)) => Ordering::Less,
+            (Self::List(_), Self::Empty) => Ordering::Greater,
+            (Self::List(_), Self::List(_)) => {
+                let (a, b) = (self.items(by_alias), other.items(by_alias));
+                for (a_item, b_item) in a.iter().zip(b.iter()) {
+                    match a_item.sort_cmp(b_item) {
+                        Ordering::Equal => continue,
+                        ordering => return ordering,
+                    }
+                }
+                a.len().cmp(&b.len())
+            }
+        }
+    }
+
+    /// keep only the outermost `max_length` items, with a `...` marker in place of the inner items
+    pub fn truncate(&mut self, max_length: usize) {
+        if let Self::List(ref mut loc) = self {
+            if loc.len() > max_length {
+                // location is reversed, so the outermost items are at the end
+                loc.drain(..loc.len() - max_length);
+                loc.insert(0, "...".into());
+            }
+        }
+    }
 }
 
 impl Serialize for Location {
@@ -191,9 +289,10 @@ impl Serialize for Location {
     {
         match self {
             Self::Empty => serializer.serialize_seq(Some(0))?.end(),
-            Self::List(loc) => {
-                let mut seq = serializer.serialize_seq(Some(loc.len()))?;
-                for e in loc.iter().rev() {
+            Self::List(_) => {
+                let items = self.items(None);
+                let mut seq = serializer.serialize_seq(Some(items.len()))?;
+                for e in items {
                     seq.serialize_element(e)?;
                 }
                 seq.end()
@@ -207,7 +306,8 @@ impl TryFrom<Option<&PyAny>> for Location {
 
     fn try_from(location: Option<&PyAny>) -> PyResult<Self> {
         if let Some(location) = location {
-            let loc_vec: Vec<LocItem> = if let Ok(tuple) = location.downcast::<PyTuple>() {
+            // the location is given from the outermost item, but stored reversed
+            let mut loc_vec: Vec<LocItem> = if let Ok(tuple) = location.downcast::<PyTuple>() {
                 tuple.iter().map(LocItem::try_from).collect::<PyResult<_>>()?
             } else if let Ok(list) = location.downcast::<PyList>() {
                 list.iter().map(LocItem::try_from).collect::<PyResult<_>>()?
@@ -219,6 +319,7 @@ impl TryFrom<Option<&PyAny>> for Location {
             if loc_vec.is_empty() {
                 Ok(Self::Empty)
             } else {
+                loc_vec.reverse();
                 Ok(Self::List(loc_vec))
             }
         } else {
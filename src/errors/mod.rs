@@ -1,14 +1,16 @@
 use pyo3::prelude::*;
 
+mod custom_messages;
 mod line_error;
 mod location;
 mod types;
 mod validation_exception;
 mod value_exception;
 
-pub use self::line_error::{InputValue, ValError, ValLineError, ValResult};
-pub use self::location::LocItem;
-pub use self::types::{list_all_errors, ErrorMode, ErrorType};
+pub use self::custom_messages::CustomErrorMessages;
+pub use self::line_error::{ChainStep, InputValue, ValError, ValLineError, ValResult};
+pub use self::location::{LocItem, Location};
+pub use self::types::{list_all_errors, ErrorMode, ErrorType, Number};
 pub use self::validation_exception::ValidationError;
 pub use self::value_exception::{PydanticCustomError, PydanticKnownError, PydanticOmit};
 
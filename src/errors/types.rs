@@ -72,13 +72,33 @@ pub enum ErrorType {
     },
     // ---------------------
     // JSON errors
+    // `position` is the byte offset of the failure, `line` and `column` are as in `error`, starting at 1, columns
+    // count bytes from the start of the line, even for multi-byte UTF-8 characters
     JsonInvalid {
         error: String,
+        position: Option<usize>,
+        line: Option<usize>,
+        column: Option<usize>,
+        expected: Option<String>,
     },
     JsonType,
+    JsonStringTooLong {
+        max_length: usize,
+        position: usize,
+    },
+    // `position` is the byte offset of the opening quote of the repeated key
+    JsonDuplicateKey {
+        key: String,
+        position: usize,
+    },
     // ---------------------
     // recursion error
-    RecursionLoop,
+    RecursionLoop {
+        // the definition which was reused within itself, `None` when the error is raised directly by users
+        schema_ref: Option<String>,
+    },
+    // `max_steps` given for the validation call was used up
+    ValidationBudgetExceeded,
     // ---------------------
     // typed dict specific errors
     DictAttributesType,
@@ -91,10 +111,34 @@ pub enum ErrorType {
         error: String,
     },
     // ---------------------
+    // default specific errors
+    DefaultFactoryError {
+        error: String,
+    },
+    // ---------------------
     // model class specific errors
     ModelClassType {
         class_name: String,
     },
+    ExclusiveGroupConflict {
+        group: String,
+        fields: String,
+    },
+    ExclusiveGroupMissing {
+        group: String,
+    },
+    FieldDependencyFailed {
+        dependency: String,
+    },
+    GroupRequiredTogether {
+        fields: String,
+    },
+    GroupMutuallyExclusive {
+        fields: String,
+    },
+    GroupAtLeastOne {
+        fields: String,
+    },
     // ---------------------
     // None errors
     NoneRequired,
@@ -119,6 +163,9 @@ pub enum ErrorType {
         multiple_of: Number,
     },
     FiniteNumber,
+    DecimalPlaces {
+        decimal_places: usize,
+    },
     // ---------------------
     // generic length errors - used for everything with a length except strings and bytes which need custom messages
     TooShort {
@@ -157,15 +204,20 @@ pub enum ErrorType {
     MappingType {
         error: Cow<'static, str>,
     },
+    DictDuplicateKey,
+    DefaultDictType,
     // ---------------------
     // list errors
     ListType,
+    ListUniqueItems,
     // ---------------------
     // tuple errors
     TupleType,
     // ---------------------
     // set errors
     SetType,
+    // set items and dict keys
+    Unhashable,
     // ---------------------
     // bool errors
     BoolType,
@@ -174,6 +226,7 @@ pub enum ErrorType {
     // int errors
     IntType,
     IntParsing,
+    IntParsingSize,
     IntFromFloat,
     // ---------------------
     // float errors
@@ -188,6 +241,12 @@ pub enum ErrorType {
     BytesTooLong {
         max_length: usize,
     },
+    BytesInvalidEncoding {
+        encoding: String,
+        encoding_error: String,
+    },
+    BytearrayType,
+    BytesBufferInvalid,
     // ---------------------
     // python errors from functions
     ValueError {
@@ -204,6 +263,11 @@ pub enum ErrorType {
     // literals
     LiteralError {
         expected: String,
+        expected_full: Option<String>,
+    },
+    Enum {
+        expected: String,
+        expected_full: Option<String>,
     },
     // ---------------------
     // date errors
@@ -234,8 +298,28 @@ pub enum ErrorType {
     },
     DatetimePast,
     DatetimeFuture,
-    DatetimeAware,
-    DatetimeNaive,
+    TimezoneAware {
+        tz_constraint: String,
+    },
+    TimezoneNaive {
+        tz_constraint: String,
+    },
+    TimezoneOffset {
+        tz_expected: i32,
+        tz_actual: i32,
+    },
+    DatetimeUnknownZone {
+        zone: String,
+    },
+    DatetimeNonexistent {
+        zone: String,
+    },
+    DatetimeAmbiguous {
+        zone: String,
+    },
+    DatetimeMicrosecondsPrecision {
+        precision: usize,
+    },
     // ---------------------
     // timedelta errors
     TimeDeltaType,
@@ -260,10 +344,20 @@ pub enum ErrorType {
         discriminator: String,
         tag: String,
         expected_tags: String,
+        expected_full: Option<String>,
     },
     UnionTagNotFound {
         discriminator: String,
     },
+    UnionTagOtherMatches {
+        tag: String,
+        matched_other_tags: Vec<String>,
+    },
+    // ---------------------
+    // merge errors
+    MergeConflict {
+        key: String,
+    },
     // ---------------------
     // argument errors
     ArgumentsType,
@@ -294,6 +388,68 @@ pub enum ErrorType {
     UrlScheme {
         expected_schemes: String,
     },
+    // ---------------------
+    // path errors
+    PathType,
+    PathNotAbsolute,
+    PathSuffix {
+        expected_suffixes: String,
+    },
+    PathTooManyParts {
+        max_parts: usize,
+    },
+    PathNotExists,
+    PathNotFile,
+    PathNotDirectory,
+    // ---------------------
+    // timezone errors
+    TimezoneType,
+    TimezoneName {
+        name: String,
+    },
+    // ---------------------
+    // fraction errors
+    FractionType,
+    FractionParsing,
+    FractionZeroDenominator,
+    FractionTooManyDigits {
+        max_digits: usize,
+    },
+    // ---------------------
+    // decimal errors
+    DecimalType,
+    DecimalParsing,
+    DecimalMaxDigits {
+        max_digits: usize,
+    },
+    DecimalMaxPlaces {
+        decimal_places: usize,
+    },
+    DecimalWholeDigits {
+        whole_digits: usize,
+    },
+    // ---------------------
+    // uuid errors
+    UuidType,
+    UuidParsing {
+        error: String,
+    },
+    UuidVersion {
+        expected_version: usize,
+    },
+    // ---------------------
+    // complex errors
+    ComplexType,
+    ComplexStrParsing,
+    // ---------------------
+    // ip address errors
+    IpV4Address,
+    IpV6Address,
+    IpV4Network,
+    IpV6Network,
+    IpV4Interface,
+    IpV6Interface,
+    IpNetworkHostBits,
 }
 
 macro_rules! render {
@@ -332,11 +488,15 @@ fn do_nothing<T>(v: T) -> T {
     v
 }
 
+/// keys after `;` are optional and must be `Option`s, they're `None` if missing from the context
 macro_rules! extract_context {
-    ($type:ident, $context:ident, $($key:ident: $type_:ty),* $(,)?) => {
-        extract_context!(do_nothing, $type, $context, $($key: $type_,)*)
+    ($type:ident, $context:ident, $($key:ident: $type_:ty),* $(,)? $(; $($opt_key:ident: $opt_type:ty),* $(,)?)?) => {
+        extract_context!(do_nothing, $type, $context, $($key: $type_,)* $(; $($opt_key: $opt_type,)*)?)
     };
-    ($function:path, $type:ident, $context:ident, $($key:ident: $type_:ty),* $(,)?) => {{
+    (
+        $function:path, $type:ident, $context:ident, $($key:ident: $type_:ty),* $(,)?
+        $(; $($opt_key:ident: $opt_type:ty),* $(,)?)?
+    ) => {{
         let context = match $context {
             Some(context) => context,
             None => {
@@ -354,6 +514,14 @@ macro_rules! extract_context {
                     .map_err(|_| py_error_type!(PyTypeError; "{}: '{}' context value must be a {}", stringify!($type), stringify!($key), stringify!($type_)))?
                 ),
             )*
+            $($(
+                $opt_key: match context.get_item(stringify!($opt_key)) {
+                    Some(value) => value
+                        .extract::<$opt_type>()
+                        .map_err(|_| py_error_type!(PyTypeError; "{}: '{}' context value must be a {}", stringify!($type), stringify!($opt_key), stringify!($opt_type)))?,
+                    None => None,
+                },
+            )*)?
         })
     }};
 }
@@ -379,14 +547,36 @@ impl ErrorType {
         };
         match error_type {
             Self::NoSuchAttribute { .. } => extract_context!(NoSuchAttribute, ctx, attribute: String),
-            Self::JsonInvalid { .. } => extract_context!(JsonInvalid, ctx, error: String),
+            Self::JsonInvalid { .. } => extract_context!(
+                JsonInvalid,
+                ctx,
+                error: String;
+                position: Option<usize>,
+                line: Option<usize>,
+                column: Option<usize>,
+                expected: Option<String>,
+            ),
+            Self::JsonStringTooLong { .. } => {
+                extract_context!(JsonStringTooLong, ctx, max_length: usize, position: usize)
+            }
+            Self::JsonDuplicateKey { .. } => extract_context!(JsonDuplicateKey, ctx, key: String, position: usize),
             Self::GetAttributeError { .. } => extract_context!(GetAttributeError, ctx, error: String),
+            Self::DefaultFactoryError { .. } => extract_context!(DefaultFactoryError, ctx, error: String),
             Self::ModelClassType { .. } => extract_context!(ModelClassType, ctx, class_name: String),
+            Self::ExclusiveGroupConflict { .. } => {
+                extract_context!(ExclusiveGroupConflict, ctx, group: String, fields: String)
+            }
+            Self::ExclusiveGroupMissing { .. } => extract_context!(ExclusiveGroupMissing, ctx, group: String),
+            Self::FieldDependencyFailed { .. } => extract_context!(FieldDependencyFailed, ctx, dependency: String),
+            Self::GroupRequiredTogether { .. } => extract_context!(GroupRequiredTogether, ctx, fields: String),
+            Self::GroupMutuallyExclusive { .. } => extract_context!(GroupMutuallyExclusive, ctx, fields: String),
+            Self::GroupAtLeastOne { .. } => extract_context!(GroupAtLeastOne, ctx, fields: String),
             Self::GreaterThan { .. } => extract_context!(GreaterThan, ctx, gt: Number),
             Self::GreaterThanEqual { .. } => extract_context!(GreaterThanEqual, ctx, ge: Number),
             Self::LessThan { .. } => extract_context!(LessThan, ctx, lt: Number),
             Self::LessThanEqual { .. } => extract_context!(LessThanEqual, ctx, le: Number),
             Self::MultipleOf { .. } => extract_context!(MultipleOf, ctx, multiple_of: Number),
+            Self::DecimalPlaces { .. } => extract_context!(DecimalPlaces, ctx, decimal_places: usize),
             Self::TooShort { .. } => extract_context!(
                 TooShort,
                 ctx,
@@ -408,14 +598,29 @@ impl ErrorType {
             Self::MappingType { .. } => extract_context!(Cow::Owned, MappingType, ctx, error: String),
             Self::BytesTooShort { .. } => extract_context!(BytesTooShort, ctx, min_length: usize),
             Self::BytesTooLong { .. } => extract_context!(BytesTooLong, ctx, max_length: usize),
+            Self::BytesInvalidEncoding { .. } => {
+                extract_context!(BytesInvalidEncoding, ctx, encoding: String, encoding_error: String)
+            }
             Self::ValueError { .. } => extract_context!(ValueError, ctx, error: String),
             Self::AssertionError { .. } => extract_context!(AssertionError, ctx, error: String),
-            Self::LiteralError { .. } => extract_context!(LiteralError, ctx, expected: String),
+            Self::LiteralError { .. } => {
+                extract_context!(LiteralError, ctx, expected: String; expected_full: Option<String>)
+            }
+            Self::Enum { .. } => extract_context!(Enum, ctx, expected: String; expected_full: Option<String>),
             Self::DateParsing { .. } => extract_context!(Cow::Owned, DateParsing, ctx, error: String),
             Self::DateFromDatetimeParsing { .. } => extract_context!(DateFromDatetimeParsing, ctx, error: String),
             Self::TimeParsing { .. } => extract_context!(Cow::Owned, TimeParsing, ctx, error: String),
             Self::DatetimeParsing { .. } => extract_context!(Cow::Owned, DatetimeParsing, ctx, error: String),
             Self::DatetimeObjectInvalid { .. } => extract_context!(DatetimeObjectInvalid, ctx, error: String),
+            Self::TimezoneAware { .. } => extract_context!(TimezoneAware, ctx, tz_constraint: String),
+            Self::TimezoneNaive { .. } => extract_context!(TimezoneNaive, ctx, tz_constraint: String),
+            Self::TimezoneOffset { .. } => extract_context!(TimezoneOffset, ctx, tz_expected: i32, tz_actual: i32),
+            Self::DatetimeUnknownZone { .. } => extract_context!(DatetimeUnknownZone, ctx, zone: String),
+            Self::DatetimeNonexistent { .. } => extract_context!(DatetimeNonexistent, ctx, zone: String),
+            Self::DatetimeAmbiguous { .. } => extract_context!(DatetimeAmbiguous, ctx, zone: String),
+            Self::DatetimeMicrosecondsPrecision { .. } => {
+                extract_context!(DatetimeMicrosecondsPrecision, ctx, precision: usize)
+            }
             Self::TimeDeltaParsing { .. } => extract_context!(Cow::Owned, TimeDeltaParsing, ctx, error: String),
             Self::IsInstanceOf { .. } => extract_context!(IsInstanceOf, ctx, class: String),
             Self::IsSubclassOf { .. } => extract_context!(IsSubclassOf, ctx, class: String),
@@ -424,14 +629,38 @@ impl ErrorType {
                 ctx,
                 discriminator: String,
                 tag: String,
-                expected_tags: String
+                expected_tags: String;
+                expected_full: Option<String>
             ),
             Self::UnionTagNotFound { .. } => extract_context!(UnionTagNotFound, ctx, discriminator: String),
+            Self::UnionTagOtherMatches { .. } => {
+                extract_context!(UnionTagOtherMatches, ctx, tag: String, matched_other_tags: Vec<String>)
+            }
+            Self::MergeConflict { .. } => extract_context!(MergeConflict, ctx, key: String),
             Self::DataclassType { .. } => extract_context!(DataclassType, ctx, dataclass_name: String),
             Self::UrlParsing { .. } => extract_context!(UrlParsing, ctx, error: String),
             Self::UrlSyntaxViolation { .. } => extract_context!(Cow::Owned, UrlSyntaxViolation, ctx, error: String),
             Self::UrlTooLong { .. } => extract_context!(UrlTooLong, ctx, max_length: usize),
             Self::UrlScheme { .. } => extract_context!(UrlScheme, ctx, expected_schemes: String),
+            Self::PathSuffix { .. } => extract_context!(PathSuffix, ctx, expected_suffixes: String),
+            Self::PathTooManyParts { .. } => extract_context!(PathTooManyParts, ctx, max_parts: usize),
+            Self::TimezoneName { .. } => extract_context!(TimezoneName, ctx, name: String),
+            Self::FractionTooManyDigits { .. } => extract_context!(FractionTooManyDigits, ctx, max_digits: usize),
+            Self::DecimalMaxDigits { .. } => extract_context!(DecimalMaxDigits, ctx, max_digits: usize),
+            Self::DecimalMaxPlaces { .. } => extract_context!(DecimalMaxPlaces, ctx, decimal_places: usize),
+            Self::DecimalWholeDigits { .. } => extract_context!(DecimalWholeDigits, ctx, whole_digits: usize),
+            Self::UuidParsing { .. } => extract_context!(UuidParsing, ctx, error: String),
+            Self::UuidVersion { .. } => extract_context!(UuidVersion, ctx, expected_version: usize),
+            // context is optional since users can raise recursion errors without a ref
+            Self::RecursionLoop { .. } => {
+                let schema_ref = match ctx.and_then(|ctx| ctx.get_item("ref")) {
+                    Some(schema_ref) => Some(schema_ref.extract().map_err(
+                        |_| py_error_type!(PyTypeError; "RecursionLoop: 'ref' context value must be a String"),
+                    )?),
+                    None => None,
+                };
+                Ok(Self::RecursionLoop { schema_ref })
+            }
             _ => {
                 if ctx.is_some() {
                     py_err!(PyTypeError; "'{}' errors do not require context", value)
@@ -451,7 +680,10 @@ impl ErrorType {
             Self::NoSuchAttribute {..} => "Object has no attribute '{attribute}'",
             Self::JsonInvalid {..} => "Invalid JSON: {error}",
             Self::JsonType => "JSON input should be string, bytes or bytearray",
-            Self::RecursionLoop => "Recursion error - cyclic reference detected",
+            Self::JsonStringTooLong {..} => "JSON string at byte {position} should have at most {max_length} bytes",
+            Self::JsonDuplicateKey {..} => "Duplicate key '{key}' in JSON object at byte {position}",
+            Self::RecursionLoop { .. } => "Recursion error - cyclic reference detected",
+            Self::ValidationBudgetExceeded => "Validation exceeded the maximum number of steps",
             Self::DictAttributesType => "Input should be a valid dictionary or instance to extract fields from",
             Self::Missing => "Field required",
             Self::FrozenField => "Field is frozen",
@@ -459,7 +691,14 @@ impl ErrorType {
             Self::ExtraForbidden => "Extra inputs are not permitted",
             Self::InvalidKey => "Keys should be strings",
             Self::GetAttributeError {..} => "Error extracting attribute: {error}",
+            Self::DefaultFactoryError {..} => "Error calling default_factory: {error}",
             Self::ModelClassType {..} => "Input should be an instance of {class_name}",
+            Self::ExclusiveGroupConflict {..} => "At most one field of group '{group}' should be set, got {fields}",
+            Self::ExclusiveGroupMissing {..} => "Exactly one field of group '{group}' should be set, got none",
+            Self::FieldDependencyFailed {..} => "Field depends on '{dependency}', which is missing or failed validation",
+            Self::GroupRequiredTogether {..} => "Fields {fields} should either all be provided or all be omitted",
+            Self::GroupMutuallyExclusive {..} => "At most one of fields {fields} should be provided",
+            Self::GroupAtLeastOne {..} => "At least one of fields {fields} should be provided",
             Self::NoneRequired => "Input should be None",
             Self::Bool => "Input should be a valid boolean",
             Self::GreaterThan {..} => "Input should be greater than {gt}",
@@ -468,6 +707,7 @@ impl ErrorType {
             Self::LessThanEqual {..} => "Input should be less than or equal to {le}",
             Self::MultipleOf {..} => "Input should be a multiple of {multiple_of}",
             Self::FiniteNumber => "Input should be a finite number",
+            Self::DecimalPlaces {..} => "Input should have no more than {decimal_places} decimal place{expected_plural}",
             Self::TooShort {..} => "{field_type} should have at least {min_length} item{expected_plural} after validation, not {actual_length}",
             Self::TooLong {..} => "{field_type} should have at most {max_length} item{expected_plural} after validation, not {actual_length}",
             Self::IterableType => "Input should be iterable",
@@ -481,22 +721,31 @@ impl ErrorType {
             Self::DictType => "Input should be a valid dictionary",
             Self::MappingType {..} => "Input should be a valid mapping, error: {error}",
             Self::ListType => "Input should be a valid list",
+            Self::ListUniqueItems => "List should have unique items",
+            Self::DictDuplicateKey => "Dictionary keys should be unique after validation",
+            Self::DefaultDictType => "Input should be a valid defaultdict",
             Self::TupleType => "Input should be a valid tuple",
             Self::SetType => "Input should be a valid set",
+            Self::Unhashable => "Input should be hashable",
             Self::BoolType => "Input should be a valid boolean",
             Self::BoolParsing => "Input should be a valid boolean, unable to interpret input",
             Self::IntType => "Input should be a valid integer",
             Self::IntParsing => "Input should be a valid integer, unable to parse string as an integer",
+            Self::IntParsingSize => "Unable to parse input as an integer, exceeded maximum size",
             Self::IntFromFloat => "Input should be a valid integer, got a number with a fractional part",
             Self::FloatType => "Input should be a valid number",
             Self::FloatParsing => "Input should be a valid number, unable to parse string as an number",
             Self::BytesType => "Input should be a valid bytes",
             Self::BytesTooShort {..} => "Data should have at least {min_length} bytes",
             Self::BytesTooLong {..} => "Data should have at most {max_length} bytes",
+            Self::BytesInvalidEncoding {..} => "Data should be valid {encoding}: {encoding_error}",
+            Self::BytearrayType => "Input should be a valid bytearray",
+            Self::BytesBufferInvalid => "Input should be a contiguous one-dimensional buffer of bytes",
             Self::ValueError {..} => "Value error, {error}",
             Self::AssertionError {..} => "Assertion failed, {error}",
             Self::CustomError {..} => "",  // custom errors are handled separately
             Self::LiteralError {..} => "Input should be {expected}",
+            Self::Enum {..} => "Input should be {expected}",
             Self::DateType => "Input should be a valid date",
             Self::DateParsing {..} => "Input should be a valid date in the format YYYY-MM-DD, {error}",
             Self::DateFromDatetimeParsing {..} => "Input should be a valid date or datetime, {error}",
@@ -510,8 +759,13 @@ impl ErrorType {
             Self::DatetimeObjectInvalid {..} => "Invalid datetime object, got {error}",
             Self::DatetimePast => "Datetime should be in the past",
             Self::DatetimeFuture => "Datetime should be in the future",
-            Self::DatetimeAware => "Datetime should have timezone info",
-            Self::DatetimeNaive => "Datetime should not have timezone info",
+            Self::TimezoneAware {..} => "Input should have timezone info",
+            Self::TimezoneNaive {..} => "Input should not have timezone info",
+            Self::TimezoneOffset {..} => "Timezone offset of {tz_expected} required, got {tz_actual}",
+            Self::DatetimeUnknownZone {..} => "Input should have a valid IANA time zone name, unknown time zone '{zone}'",
+            Self::DatetimeNonexistent {..} => "Datetime does not exist in time zone '{zone}', it falls in a DST gap",
+            Self::DatetimeAmbiguous {..} => "Datetime is ambiguous in time zone '{zone}', it falls in a DST overlap",
+            Self::DatetimeMicrosecondsPrecision {..} => "Input should have at most microsecond precision, got {precision} fractional second digits",
             Self::TimeDeltaType => "Input should be a valid timedelta",
             Self::TimeDeltaParsing {..} => "Input should be a valid timedelta, {error}",
             Self::FrozenSetType => "Input should be a valid frozenset",
@@ -520,6 +774,8 @@ impl ErrorType {
             Self::CallableType => "Input should be callable",
             Self::UnionTagInvalid {..} => "Input tag '{tag}' found using {discriminator} does not match any of the expected tags: {expected_tags}",
             Self::UnionTagNotFound {..} => "Unable to extract tag using discriminator {discriminator}",
+            Self::UnionTagOtherMatches {..} => "Input is invalid for tag '{tag}' but valid for other tags: {matched_other_tags}",
+            Self::MergeConflict {..} => "Merged schemas returned different values for key '{key}'",
             Self::ArgumentsType => "Arguments must be a tuple, list or a dictionary",
             Self::MissingArgument => "Missing required argument",
             Self::UnexpectedKeywordArgument => "Unexpected keyword argument",
@@ -533,6 +789,36 @@ impl ErrorType {
             Self::UrlSyntaxViolation {..} => "Input violated strict URL syntax rules, {error}",
             Self::UrlTooLong {..} => "URL should have at most {max_length} characters",
             Self::UrlScheme {..} => "URL scheme should be {expected_schemes}",
+            Self::PathType => "Input should be a valid path",
+            Self::PathNotAbsolute => "Path should be absolute",
+            Self::PathSuffix {..} => "Path suffix should be {expected_suffixes}",
+            Self::PathTooManyParts {..} => "Path should have at most {max_parts} parts",
+            Self::PathNotExists => "Path does not exist",
+            Self::PathNotFile => "Path does not point to a file",
+            Self::PathNotDirectory => "Path does not point to a directory",
+            Self::TimezoneType => "Input should be a valid timezone",
+            Self::TimezoneName {..} => "Unknown timezone name '{name}'",
+            Self::FractionType => "Input should be a valid fraction",
+            Self::FractionParsing => "Input should be a valid fraction, unable to parse string as a fraction",
+            Self::FractionZeroDenominator => "Fraction denominator should not be zero",
+            Self::FractionTooManyDigits {..} => "Fraction should have at most {max_digits} digits",
+            Self::DecimalType => "Decimal input should be an integer, float, string or Decimal object",
+            Self::DecimalParsing => "Input should be a valid decimal",
+            Self::DecimalMaxDigits {..} => "Decimal input should have no more than {max_digits} digit{expected_plural} in total",
+            Self::DecimalMaxPlaces {..} => "Decimal input should have no more than {decimal_places} decimal place{expected_plural}",
+            Self::DecimalWholeDigits {..} => "Decimal input should have no more than {whole_digits} digit{expected_plural} before the decimal point",
+            Self::UuidType => "UUID input should be a string, bytes or UUID object",
+            Self::UuidParsing {..} => "Input should be a valid UUID, {error}",
+            Self::UuidVersion {..} => "UUID version {expected_version} expected",
+            Self::ComplexType => "Input should be a valid python complex object, a number, or a valid complex string following the rules at https://docs.python.org/3/library/functions.html#complex",
+            Self::ComplexStrParsing => "Input should be a valid complex string following the rules at https://docs.python.org/3/library/functions.html#complex",
+            Self::IpV4Address => "Input is not a valid IPv4 address",
+            Self::IpV6Address => "Input is not a valid IPv6 address",
+            Self::IpV4Network => "Input is not a valid IPv4 network",
+            Self::IpV6Network => "Input is not a valid IPv6 network",
+            Self::IpV4Interface => "Input is not a valid IPv4 interface",
+            Self::IpV6Interface => "Input is not a valid IPv6 interface",
+            Self::IpNetworkHostBits => "Input network should not have host bits set",
         }
     }
 
@@ -550,6 +836,43 @@ impl ErrorType {
         lookup.contains_key(error_type)
     }
 
+    /// check a replacement message template for `error_type` only uses placeholders which are rendered for it
+    pub fn check_message_template(py: Python, error_type: &str, template: &str) -> PyResult<()> {
+        let lookup = ERROR_TYPE_LOOKUP.get_or_init(py, Self::build_lookup);
+        let example = match lookup.get(error_type) {
+            Some(example) => example,
+            None => return py_err!("Invalid error type in custom_error_messages: '{}'", error_type),
+        };
+        let mut rest = template;
+        while let Some(start) = rest.find('{') {
+            rest = &rest[start + 1..];
+            let end = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            let (name, after) = rest.split_at(end);
+            if !name.is_empty() && after.starts_with('}') {
+                // a placeholder is rendered if the example error replaces it
+                let placeholder = format!("{{{name}}}");
+                if example.render_template(py, &placeholder)? == placeholder {
+                    return py_err!(
+                        "Invalid placeholder '{}' in custom_error_messages for '{}', it isn't in the error's context",
+                        placeholder,
+                        error_type
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// every known error type string, sorted
+    pub fn all_type_strings(py: Python<'_>) -> Vec<&str> {
+        let lookup = ERROR_TYPE_LOOKUP.get_or_init(py, Self::build_lookup);
+        let mut type_strings: Vec<&str> = lookup.keys().map(String::as_str).collect();
+        type_strings.sort_unstable();
+        type_strings
+    }
+
     fn build_lookup() -> AHashMap<String, Self> {
         let mut lookup = AHashMap::new();
         for error_type in Self::iter() {
@@ -574,16 +897,36 @@ impl ErrorType {
             ErrorMode::Python => self.message_template_python(),
             ErrorMode::Json => self.message_template_json(),
         };
+        self.render_template(py, tmpl)
+    }
+
+    /// render `tmpl` with the error's context, either one of its own templates or a replacement
+    /// from `custom_error_messages`
+    pub fn render_template(&self, py: Python, tmpl: &str) -> PyResult<String> {
         match self {
             Self::NoSuchAttribute { attribute } => render!(tmpl, attribute),
-            Self::JsonInvalid { error } => render!(tmpl, error),
+            Self::JsonInvalid { error, .. } => render!(tmpl, error),
+            Self::JsonStringTooLong { max_length, position } => to_string_render!(tmpl, max_length, position),
+            // `key` is replaced last, so braces in the key aren't treated as placeholders
+            Self::JsonDuplicateKey { key, position } => to_string_render!(tmpl, position, key),
             Self::GetAttributeError { error } => render!(tmpl, error),
+            Self::DefaultFactoryError { error } => render!(tmpl, error),
             Self::ModelClassType { class_name } => render!(tmpl, class_name),
+            Self::ExclusiveGroupConflict { group, fields } => render!(tmpl, group, fields),
+            Self::ExclusiveGroupMissing { group } => render!(tmpl, group),
+            Self::FieldDependencyFailed { dependency } => render!(tmpl, dependency),
+            Self::GroupRequiredTogether { fields } => render!(tmpl, fields),
+            Self::GroupMutuallyExclusive { fields } => render!(tmpl, fields),
+            Self::GroupAtLeastOne { fields } => render!(tmpl, fields),
             Self::GreaterThan { gt } => to_string_render!(tmpl, gt),
             Self::GreaterThanEqual { ge } => to_string_render!(tmpl, ge),
             Self::LessThan { lt } => to_string_render!(tmpl, lt),
             Self::LessThanEqual { le } => to_string_render!(tmpl, le),
             Self::MultipleOf { multiple_of } => to_string_render!(tmpl, multiple_of),
+            Self::DecimalPlaces { decimal_places } => {
+                let expected_plural = plural_s(decimal_places);
+                to_string_render!(tmpl, decimal_places, expected_plural)
+            }
             Self::TooShort {
                 field_type,
                 min_length,
@@ -607,17 +950,27 @@ impl ErrorType {
             Self::MappingType { error } => render!(tmpl, error),
             Self::BytesTooShort { min_length } => to_string_render!(tmpl, min_length),
             Self::BytesTooLong { max_length } => to_string_render!(tmpl, max_length),
+            Self::BytesInvalidEncoding {
+                encoding,
+                encoding_error,
+            } => render!(tmpl, encoding, encoding_error),
             Self::ValueError { error } => render!(tmpl, error),
             Self::AssertionError { error } => render!(tmpl, error),
             Self::CustomError {
                 custom_error: value_error,
             } => value_error.message(py),
-            Self::LiteralError { expected } => render!(tmpl, expected),
+            Self::LiteralError { expected, .. } => render!(tmpl, expected),
+            Self::Enum { expected, .. } => render!(tmpl, expected),
             Self::DateParsing { error } => render!(tmpl, error),
             Self::DateFromDatetimeParsing { error } => render!(tmpl, error),
             Self::TimeParsing { error } => render!(tmpl, error),
             Self::DatetimeParsing { error } => render!(tmpl, error),
             Self::DatetimeObjectInvalid { error } => render!(tmpl, error),
+            Self::TimezoneOffset { tz_expected, tz_actual } => to_string_render!(tmpl, tz_expected, tz_actual),
+            Self::DatetimeUnknownZone { zone } => render!(tmpl, zone),
+            Self::DatetimeNonexistent { zone } => render!(tmpl, zone),
+            Self::DatetimeAmbiguous { zone } => render!(tmpl, zone),
+            Self::DatetimeMicrosecondsPrecision { precision } => to_string_render!(tmpl, precision),
             Self::TimeDeltaParsing { error } => render!(tmpl, error),
             Self::IsInstanceOf { class } => render!(tmpl, class),
             Self::IsSubclassOf { class } => render!(tmpl, class),
@@ -625,13 +978,44 @@ impl ErrorType {
                 discriminator,
                 tag,
                 expected_tags,
+                ..
             } => render!(tmpl, discriminator, tag, expected_tags),
             Self::UnionTagNotFound { discriminator } => render!(tmpl, discriminator),
+            Self::UnionTagOtherMatches {
+                tag,
+                matched_other_tags,
+            } => {
+                let matched_other_tags = &matched_other_tags
+                    .iter()
+                    .map(|t| format!("'{t}'"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                render!(tmpl, tag, matched_other_tags)
+            }
+            Self::MergeConflict { key } => render!(tmpl, key),
             Self::DataclassType { dataclass_name } => render!(tmpl, dataclass_name),
             Self::UrlParsing { error } => render!(tmpl, error),
             Self::UrlSyntaxViolation { error } => render!(tmpl, error),
             Self::UrlTooLong { max_length } => to_string_render!(tmpl, max_length),
             Self::UrlScheme { expected_schemes } => render!(tmpl, expected_schemes),
+            Self::PathSuffix { expected_suffixes } => render!(tmpl, expected_suffixes),
+            Self::PathTooManyParts { max_parts } => to_string_render!(tmpl, max_parts),
+            Self::TimezoneName { name } => render!(tmpl, name),
+            Self::FractionTooManyDigits { max_digits } => to_string_render!(tmpl, max_digits),
+            Self::DecimalMaxDigits { max_digits } => {
+                let expected_plural = plural_s(max_digits);
+                to_string_render!(tmpl, max_digits, expected_plural)
+            }
+            Self::DecimalMaxPlaces { decimal_places } => {
+                let expected_plural = plural_s(decimal_places);
+                to_string_render!(tmpl, decimal_places, expected_plural)
+            }
+            Self::DecimalWholeDigits { whole_digits } => {
+                let expected_plural = plural_s(whole_digits);
+                to_string_render!(tmpl, whole_digits, expected_plural)
+            }
+            Self::UuidParsing { error } => render!(tmpl, error),
+            Self::UuidVersion { expected_version } => to_string_render!(tmpl, expected_version),
             _ => Ok(tmpl.to_string()),
         }
     }
@@ -639,14 +1023,42 @@ impl ErrorType {
     pub fn py_dict(&self, py: Python) -> PyResult<Option<Py<PyDict>>> {
         match self {
             Self::NoSuchAttribute { attribute } => py_dict!(py, attribute),
-            Self::JsonInvalid { error } => py_dict!(py, error),
+            Self::JsonInvalid {
+                error,
+                position,
+                line,
+                column,
+                expected,
+            } => {
+                let dict = PyDict::new(py);
+                dict.set_item("error", error)?;
+                for (key, value) in [("position", position), ("line", line), ("column", column)] {
+                    if let Some(value) = value {
+                        dict.set_item(key, value)?;
+                    }
+                }
+                if let Some(expected) = expected {
+                    dict.set_item("expected", expected)?;
+                }
+                Ok(Some(dict.into_py(py)))
+            }
+            Self::JsonStringTooLong { max_length, position } => py_dict!(py, max_length, position),
+            Self::JsonDuplicateKey { key, position } => py_dict!(py, key, position),
             Self::GetAttributeError { error } => py_dict!(py, error),
+            Self::DefaultFactoryError { error } => py_dict!(py, error),
             Self::ModelClassType { class_name } => py_dict!(py, class_name),
+            Self::ExclusiveGroupConflict { group, fields } => py_dict!(py, group, fields),
+            Self::ExclusiveGroupMissing { group } => py_dict!(py, group),
+            Self::FieldDependencyFailed { dependency } => py_dict!(py, dependency),
+            Self::GroupRequiredTogether { fields } => py_dict!(py, fields),
+            Self::GroupMutuallyExclusive { fields } => py_dict!(py, fields),
+            Self::GroupAtLeastOne { fields } => py_dict!(py, fields),
             Self::GreaterThan { gt } => py_dict!(py, gt),
             Self::GreaterThanEqual { ge } => py_dict!(py, ge),
             Self::LessThan { lt } => py_dict!(py, lt),
             Self::LessThanEqual { le } => py_dict!(py, le),
             Self::MultipleOf { multiple_of } => py_dict!(py, multiple_of),
+            Self::DecimalPlaces { decimal_places } => py_dict!(py, decimal_places),
             Self::TooShort {
                 field_type,
                 min_length,
@@ -664,17 +1076,43 @@ impl ErrorType {
             Self::MappingType { error } => py_dict!(py, error),
             Self::BytesTooShort { min_length } => py_dict!(py, min_length),
             Self::BytesTooLong { max_length } => py_dict!(py, max_length),
+            Self::BytesInvalidEncoding {
+                encoding,
+                encoding_error,
+            } => py_dict!(py, encoding, encoding_error),
             Self::ValueError { error } => py_dict!(py, error),
             Self::AssertionError { error } => py_dict!(py, error),
             Self::CustomError {
                 custom_error: value_error,
             } => Ok(value_error.context(py)),
-            Self::LiteralError { expected } => py_dict!(py, expected),
+            Self::LiteralError {
+                expected,
+                expected_full: None,
+            } => py_dict!(py, expected),
+            Self::LiteralError {
+                expected,
+                expected_full: Some(expected_full),
+            } => py_dict!(py, expected, expected_full),
+            Self::Enum {
+                expected,
+                expected_full: None,
+            } => py_dict!(py, expected),
+            Self::Enum {
+                expected,
+                expected_full: Some(expected_full),
+            } => py_dict!(py, expected, expected_full),
             Self::DateParsing { error } => py_dict!(py, error),
             Self::DateFromDatetimeParsing { error } => py_dict!(py, error),
             Self::TimeParsing { error } => py_dict!(py, error),
             Self::DatetimeParsing { error } => py_dict!(py, error),
             Self::DatetimeObjectInvalid { error } => py_dict!(py, error),
+            Self::TimezoneAware { tz_constraint } => py_dict!(py, tz_constraint),
+            Self::TimezoneNaive { tz_constraint } => py_dict!(py, tz_constraint),
+            Self::TimezoneOffset { tz_expected, tz_actual } => py_dict!(py, tz_expected, tz_actual),
+            Self::DatetimeUnknownZone { zone } => py_dict!(py, zone),
+            Self::DatetimeNonexistent { zone } => py_dict!(py, zone),
+            Self::DatetimeAmbiguous { zone } => py_dict!(py, zone),
+            Self::DatetimeMicrosecondsPrecision { precision } => py_dict!(py, precision),
             Self::TimeDeltaParsing { error } => py_dict!(py, error),
             Self::IsInstanceOf { class } => py_dict!(py, class),
             Self::IsSubclassOf { class } => py_dict!(py, class),
@@ -682,13 +1120,41 @@ impl ErrorType {
                 discriminator,
                 tag,
                 expected_tags,
+                expected_full: None,
             } => py_dict!(py, discriminator, tag, expected_tags),
+            Self::UnionTagInvalid {
+                discriminator,
+                tag,
+                expected_tags,
+                expected_full: Some(expected_full),
+            } => py_dict!(py, discriminator, tag, expected_tags, expected_full),
             Self::UnionTagNotFound { discriminator } => py_dict!(py, discriminator),
+            Self::UnionTagOtherMatches {
+                tag,
+                matched_other_tags,
+            } => py_dict!(py, tag, matched_other_tags),
+            Self::MergeConflict { key } => py_dict!(py, key),
             Self::DataclassType { dataclass_name } => py_dict!(py, dataclass_name),
             Self::UrlParsing { error } => py_dict!(py, error),
             Self::UrlSyntaxViolation { error } => py_dict!(py, error),
             Self::UrlTooLong { max_length } => py_dict!(py, max_length),
             Self::UrlScheme { expected_schemes } => py_dict!(py, expected_schemes),
+            Self::PathSuffix { expected_suffixes } => py_dict!(py, expected_suffixes),
+            Self::PathTooManyParts { max_parts } => py_dict!(py, max_parts),
+            Self::TimezoneName { name } => py_dict!(py, name),
+            Self::FractionTooManyDigits { max_digits } => py_dict!(py, max_digits),
+            Self::DecimalMaxDigits { max_digits } => py_dict!(py, max_digits),
+            Self::DecimalMaxPlaces { decimal_places } => py_dict!(py, decimal_places),
+            Self::DecimalWholeDigits { whole_digits } => py_dict!(py, whole_digits),
+            Self::UuidParsing { error } => py_dict!(py, error),
+            Self::UuidVersion { expected_version } => py_dict!(py, expected_version),
+            Self::RecursionLoop {
+                schema_ref: Some(schema_ref),
+            } => {
+                let dict = PyDict::new(py);
+                dict.set_item("ref", schema_ref)?;
+                Ok(Some(dict.into_py(py)))
+            }
             _ => Ok(None),
         }
     }
@@ -6,19 +6,21 @@ use crate::errors::LocItem;
 use pyo3::exceptions::{PyKeyError, PyTypeError, PyValueError};
 use pyo3::ffi::Py_ssize_t;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList, PyString};
+use pyo3::types::{PyDict, PyList, PyString, PyType};
 use pyo3::{ffi, intern};
 use serde::ser::{Error, SerializeMap, SerializeSeq};
 use serde::{Serialize, Serializer};
 use serde_json::ser::PrettyFormatter;
 
-use crate::build_tools::{py_error_type, safe_repr, SchemaDict};
-use crate::serializers::{SerMode, SerializationState};
+use crate::build_tools::{py_err, py_error_type, safe_repr, SchemaDict};
+use crate::serializers::{infer_to_python, Extra, SerMode, SerializationState};
+use crate::validators::ErrorLimit;
 use crate::PydanticCustomError;
 
-use super::line_error::ValLineError;
+use super::line_error::{ChainStep, ValLineError};
 use super::location::Location;
 use super::types::{ErrorMode, ErrorType};
+use super::CustomErrorMessages;
 use super::ValError;
 
 #[pyclass(extends=PyValueError, module="pydantic_core._pydantic_core")]
@@ -28,26 +30,53 @@ pub struct ValidationError {
     line_errors: Vec<PyLineError>,
     error_mode: ErrorMode,
     title: PyObject,
+    // from the `hide_input_in_errors` config, omits input values from `__str__` and `__repr__` but not `errors()`
+    hide_input: bool,
+    // from the `custom_error_messages` config, replaces the messages of built-in errors wherever they're rendered
+    custom_messages: Option<CustomErrorMessages>,
+    // whether `max_errors` errors were collected, so validation stopped and there may be more
+    truncated: bool,
 }
 
 impl ValidationError {
-    pub fn new(line_errors: Vec<PyLineError>, title: PyObject, error_mode: ErrorMode) -> Self {
+    pub fn new(
+        line_errors: Vec<PyLineError>,
+        title: PyObject,
+        error_mode: ErrorMode,
+        hide_input: bool,
+        custom_messages: Option<CustomErrorMessages>,
+        truncated: bool,
+    ) -> Self {
         Self {
             line_errors,
             title,
             error_mode,
+            hide_input,
+            custom_messages,
+            truncated,
         }
     }
 
+    /// `error_limit` cuts the errors to `max_errors`, see `ErrorLimit`
+    #[allow(clippy::too_many_arguments)]
     pub fn from_val_error(
         py: Python,
         title: PyObject,
         error_mode: ErrorMode,
         error: ValError,
         outer_location: Option<LocItem>,
+        hide_input: bool,
+        custom_messages: Option<CustomErrorMessages>,
+        error_limit: Option<&ErrorLimit>,
     ) -> PyErr {
         match error {
-            ValError::LineErrors(raw_errors) => {
+            ValError::LineErrors(mut raw_errors) => {
+                // truncated if errors are cut here, or weren't collected because a collection stopped early
+                let truncated =
+                    error_limit.is_some_and(|limit| raw_errors.len() > limit.max_errors() || limit.stopped_early());
+                if let Some(limit) = error_limit {
+                    raw_errors.truncate(limit.max_errors());
+                }
                 let line_errors: Vec<PyLineError> = match outer_location {
                     Some(outer_location) => raw_errors
                         .into_iter()
@@ -55,9 +84,20 @@ impl ValidationError {
                         .collect(),
                     None => raw_errors.into_iter().map(|e| e.into_py(py)).collect(),
                 };
-                let validation_error = Self::new(line_errors, title, error_mode);
+                // only one exception can be the cause, the first error's is used
+                let cause = line_errors
+                    .iter()
+                    .find_map(|e| e.cause.as_ref().map(|c| c.clone_ref(py)));
+                let validation_error =
+                    Self::new(line_errors, title, error_mode, hide_input, custom_messages, truncated);
                 match Py::new(py, validation_error) {
-                    Ok(err) => PyErr::from_value(err.into_ref(py)),
+                    Ok(err) => {
+                        let err = PyErr::from_value(err.into_ref(py));
+                        if let Some(cause) = cause {
+                            err.set_cause(py, Some(PyErr::from_value(cause.as_ref(py))));
+                        }
+                        err
+                    }
                     Err(err) => err,
                 }
             }
@@ -67,14 +107,24 @@ impl ValidationError {
     }
 
     pub fn display(&self, py: Python, prefix_override: Option<&'static str>) -> String {
-        let line_errors = pretty_py_line_errors(py, &self.error_mode, self.line_errors.iter());
+        let line_errors = pretty_py_line_errors(
+            py,
+            &self.error_mode,
+            self.custom_messages.as_ref(),
+            self.line_errors.iter(),
+            self.hide_input,
+        );
         if let Some(prefix) = prefix_override {
             format!("{prefix}\n{line_errors}")
         } else {
             let count = self.line_errors.len();
             let plural = if count == 1 { "" } else { "s" };
             let title: &str = self.title.extract(py).unwrap();
-            format!("{count} validation error{plural} for {title}\n{line_errors}")
+            let truncated = match self.truncated {
+                true => "\n[validation stopped at max_errors, there may be more errors]",
+                false => "",
+            };
+            format!("{count} validation error{plural} for {title}\n{line_errors}{truncated}")
         }
     }
 
@@ -102,19 +152,81 @@ impl ValidationError {
             line_errors: line_errors.iter().map(PyLineError::try_from).collect::<PyResult<_>>()?,
             title,
             error_mode: ErrorMode::try_from(error_mode)?,
+            hide_input: false,
+            custom_messages: None,
+            truncated: false,
         })
     }
 
+    /// Build a `ValidationError` which renders exactly like one raised during validation, `line_errors` are
+    /// dicts as returned by `errors()`, so `ValidationError.from_exception_data(e.title, e.errors())` reproduces `e`.
+    #[classmethod]
+    #[pyo3(signature = (title, line_errors, *, input_type="python", hide_input=false))]
+    fn from_exception_data(
+        cls: &PyType,
+        title: PyObject,
+        line_errors: &PyList,
+        input_type: &str,
+        hide_input: bool,
+    ) -> PyResult<Py<Self>> {
+        let py = cls.py();
+        let validation_error = Self::new(
+            line_errors.iter().map(PyLineError::try_from).collect::<PyResult<_>>()?,
+            title,
+            ErrorMode::try_from(Some(input_type))?,
+            hide_input,
+            None,
+            false,
+        );
+        Py::new(py, validation_error)
+    }
+
     #[getter]
     fn title(&self, py: Python) -> PyObject {
         self.title.clone_ref(py)
     }
 
+    /// the number of errors in `errors()`, with `max_errors` that's at most `max_errors` even if there were more,
+    /// see `truncated`
     pub fn error_count(&self) -> usize {
         self.line_errors.len()
     }
 
-    pub fn errors(&self, py: Python, include_context: Option<bool>) -> PyResult<Py<PyList>> {
+    /// whether validation collected `max_errors` errors and stopped, so there may be more than `error_count()`
+    #[getter]
+    fn truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// `include_context` and `include_input` drop `ctx` and `input` from every error when false, regardless of
+    /// `hide_input_in_errors` which only affects how the error is displayed
+    #[pyo3(signature = (include_context=None, *, include_input=None, sort_errors=None, context_mode=None, by_alias=None))]
+    pub fn errors(
+        &self,
+        py: Python,
+        include_context: Option<bool>,
+        include_input: Option<bool>,
+        sort_errors: Option<&str>,
+        context_mode: Option<&str>,
+        by_alias: Option<bool>,
+    ) -> PyResult<Py<PyList>> {
+        // errors are in the order they're found in the input (the default), or sorted by location,
+        // the sort is stable so errors with the same location stay in input order
+        let mut line_errors: Vec<&PyLineError> = self.line_errors.iter().collect();
+        match sort_errors {
+            None | Some("input") => (),
+            Some("loc") => line_errors.sort_by(|a, b| a.location.sort_cmp(&b.location, by_alias)),
+            Some(s) => return py_err!(PyValueError; "Invalid sort_errors: `{}`, expected 'input' or 'loc'", s),
+        }
+        // with `context_mode='json'`, context values are converted as they are by `json()`, e.g. datetimes
+        // become ISO strings and patterns their source string, rather than returning the objects as they are
+        let state = SerializationState::new(py, None, None, None)?;
+        let json_extra = match context_mode {
+            None | Some("python") => None,
+            Some("json") => Some(state.extra(py, &SerMode::Json, true, false, false, true, None)),
+            Some(s) => return py_err!(PyValueError; "Invalid context_mode: `{}`, expected 'python' or 'json'", s),
+        };
+
         // taken approximately from the pyo3, but modified to return the error during iteration
         // https://github.com/PyO3/pyo3/blob/a3edbf4fcd595f0e234c87d4705eb600a9779130/src/types/list.rs#L27-L55
         unsafe {
@@ -125,8 +237,16 @@ impl ValidationError {
             // - its Drop cleans up the list if user code or the asserts panic.
             let list: Py<PyList> = Py::from_owned_ptr(py, ptr);
 
-            for (index, line_error) in (0_isize..).zip(&self.line_errors) {
-                let item = line_error.as_dict(py, include_context, &self.error_mode)?;
+            for (index, line_error) in (0_isize..).zip(line_errors) {
+                let item = line_error.as_dict(
+                    py,
+                    include_context,
+                    include_input,
+                    &self.error_mode,
+                    self.custom_messages.as_ref(),
+                    json_extra.as_ref(),
+                    by_alias,
+                )?;
                 ffi::PyList_SET_ITEM(ptr, index, item.into_ptr());
             }
 
@@ -134,20 +254,29 @@ impl ValidationError {
         }
     }
 
+    /// Serialize the errors directly to a JSON string, `indent` matches `json.dumps(..., indent=indent)`.
+    ///
+    /// Inputs and context values are serialized as `to_json` would with `bytes_mode='utf8'`: dates and times become
+    /// ISO 8601 strings, `Decimal` and `UUID` strings, `nan` and `inf` `null`, and unknown objects their `repr()`;
+    /// an input which still can't be serialized, e.g. bytes which aren't valid UTF-8, becomes its `repr()`.
+    #[pyo3(signature = (indent=None, include_context=None, *, include_input=None))]
     pub fn json<'py>(
         &self,
         py: Python<'py>,
         indent: Option<usize>,
         include_context: Option<bool>,
+        include_input: Option<bool>,
     ) -> PyResult<&'py PyString> {
-        let state = SerializationState::new(None, None);
+        let state = SerializationState::new(py, None, None, None)?;
         let extra = state.extra(py, &SerMode::Json, true, false, false, true, None);
         let serializer = ValidationErrorSerializer {
             py,
             line_errors: &self.line_errors,
             include_context: include_context.unwrap_or(true),
+            include_input: include_input.unwrap_or(true),
             extra: &extra,
             error_mode: &self.error_mode,
+            custom_messages: self.custom_messages.as_ref(),
         };
 
         let writer: Vec<u8> = Vec::with_capacity(self.line_errors.len() * 200);
@@ -196,10 +325,12 @@ macro_rules! truncate_input_value {
 pub fn pretty_py_line_errors<'a>(
     py: Python,
     error_mode: &ErrorMode,
+    custom_messages: Option<&CustomErrorMessages>,
     line_errors_iter: impl Iterator<Item = &'a PyLineError>,
+    hide_input: bool,
 ) -> String {
     line_errors_iter
-        .map(|i| i.pretty(py, error_mode))
+        .map(|i| i.pretty(py, error_mode, custom_messages, hide_input))
         .collect::<Result<Vec<_>, _>>()
         .unwrap_or_else(|err| vec![format!("[error formatting line errors: {err}]")])
         .join("\n")
@@ -213,6 +344,9 @@ pub struct PyLineError {
     error_type: ErrorType,
     location: Location,
     input_value: PyObject,
+    field_title: Option<String>,
+    chain_step: Option<ChainStep>,
+    cause: Option<PyObject>,
 }
 
 impl<'a> IntoPy<PyLineError> for ValLineError<'a> {
@@ -221,6 +355,9 @@ impl<'a> IntoPy<PyLineError> for ValLineError<'a> {
             error_type: self.error_type,
             location: self.location,
             input_value: self.input_value.to_object(py),
+            field_title: self.field_title,
+            chain_step: self.chain_step,
+            cause: self.cause,
         }
     }
 }
@@ -232,6 +369,9 @@ impl<'a> IntoPy<ValLineError<'a>> for PyLineError {
             error_type: self.error_type,
             location: self.location,
             input_value: self.input_value.into(),
+            field_title: self.field_title,
+            chain_step: self.chain_step,
+            cause: self.cause,
         }
     }
 }
@@ -247,9 +387,44 @@ impl TryFrom<&PyAny> for PyLineError {
             .get_item(intern!(py, "type"))
             .ok_or_else(|| PyKeyError::new_err("type"))?;
 
+        let mut context: Option<&PyDict> = dict.get_as(intern!(py, "ctx"))?;
+        let mut field_title: Option<String> = None;
+        let mut chain_step: Option<ChainStep> = None;
+        if let Some(ctx) = context {
+            field_title = ctx.get_as(intern!(py, "field_title"))?;
+            if let Some(step) = ctx.get_item(intern!(py, "chain_step")) {
+                chain_step = Some(ChainStep {
+                    step: LocItem::try_from(step)?,
+                    input_type: ctx.get_as(intern!(py, "chain_step_input_type"))?,
+                });
+            }
+            if field_title.is_some() || chain_step.is_some() {
+                // `field_title` and `chain_step` aren't part of the error type's own context
+                let ctx = ctx.copy()?;
+                for key in [
+                    intern!(py, "field_title"),
+                    intern!(py, "chain_step"),
+                    intern!(py, "chain_step_input_type"),
+                ] {
+                    if ctx.contains(key)? {
+                        ctx.del_item(key)?;
+                    }
+                }
+                context = if ctx.is_empty() { None } else { Some(ctx) };
+            }
+        }
+
         let error_type = if let Ok(type_str) = type_raw.downcast::<PyString>() {
-            let context: Option<&PyDict> = dict.get_as(intern!(py, "ctx"))?;
-            ErrorType::new(py, type_str.to_str()?, context)?
+            let type_str = type_str.to_str()?;
+            if ErrorType::valid_type(py, type_str) {
+                // known types always render their own message template, so any `msg` is ignored
+                ErrorType::new(py, type_str, context)?
+            } else if let Some(msg) = dict.get_as::<String>(intern!(py, "msg"))? {
+                // unknown types with a message are custom errors, e.g. from `errors()` of a `PydanticCustomError`
+                ErrorType::new_custom_error(PydanticCustomError::py_new(py, type_str.to_string(), msg, context))
+            } else {
+                return Err(invalid_error_type(py, type_str));
+            }
         } else if let Ok(custom_error) = type_raw.extract::<PydanticCustomError>() {
             ErrorType::new_custom_error(custom_error)
         } else {
@@ -269,34 +444,117 @@ impl TryFrom<&PyAny> for PyLineError {
             error_type,
             location,
             input_value,
+            field_title,
+            chain_step,
+            cause: None,
         })
     }
 }
 
+/// error for an unknown error type without a `msg`, suggesting known types with similar names
+fn invalid_error_type(py: Python, type_str: &str) -> PyErr {
+    let close_matches: Option<Vec<String>> = py
+        .import(intern!(py, "difflib"))
+        .and_then(|difflib| {
+            difflib
+                .getattr(intern!(py, "get_close_matches"))?
+                .call1((type_str, ErrorType::all_type_strings(py)))?
+                .extract()
+        })
+        .ok();
+    let mut message = format!("Invalid error type: '{type_str}', custom error types require a `msg`");
+    if let Some(close_matches) = close_matches.filter(|m| !m.is_empty()) {
+        write!(message, ", did you mean '{}'?", close_matches.join("', '")).ok();
+    }
+    PyKeyError::new_err(message)
+}
+
 impl PyLineError {
-    pub fn as_dict(&self, py: Python, include_context: Option<bool>, error_mode: &ErrorMode) -> PyResult<PyObject> {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn as_dict(
+        &self,
+        py: Python,
+        include_context: Option<bool>,
+        include_input: Option<bool>,
+        error_mode: &ErrorMode,
+        custom_messages: Option<&CustomErrorMessages>,
+        json_extra: Option<&Extra>,
+        by_alias: Option<bool>,
+    ) -> PyResult<PyObject> {
         let dict = PyDict::new(py);
         dict.set_item("type", self.error_type.type_string())?;
-        dict.set_item("loc", self.location.to_object(py))?;
-        dict.set_item("msg", self.error_type.render_message(py, error_mode)?)?;
-        dict.set_item("input", &self.input_value)?;
+        dict.set_item("loc", self.location.to_object_by_alias(py, by_alias))?;
+        dict.set_item("msg", self.message(py, error_mode, custom_messages)?)?;
+        if include_input.unwrap_or(true) {
+            dict.set_item("input", &self.input_value)?;
+        }
         if include_context.unwrap_or(true) {
-            if let Some(context) = self.error_type.py_dict(py)? {
-                dict.set_item("ctx", context)?;
+            if let Some(context) = self.context(py)? {
+                match json_extra {
+                    Some(extra) => dict.set_item("ctx", infer_to_python(context.as_ref(py), None, None, extra)?)?,
+                    None => dict.set_item("ctx", context)?,
+                }
             }
         }
         Ok(dict.into_py(py))
     }
 
-    fn pretty(&self, py: Python, error_mode: &ErrorMode) -> Result<String, fmt::Error> {
+    /// the error type's context, with `field_title` added if the error occurred in a field with a title,
+    /// and `chain_step` if it occurred in a step of a chain
+    fn context(&self, py: Python) -> PyResult<Option<Py<PyDict>>> {
+        let context = self.error_type.py_dict(py)?;
+        if self.field_title.is_none() && self.chain_step.is_none() {
+            return Ok(context);
+        }
+        // copy since custom errors share their context dict
+        let context = match context {
+            Some(context) => context.into_ref(py).copy()?,
+            None => PyDict::new(py),
+        };
+        if let Some(ref field_title) = self.field_title {
+            context.set_item(intern!(py, "field_title"), field_title)?;
+        }
+        if let Some(ref chain_step) = self.chain_step {
+            context.set_item(intern!(py, "chain_step"), chain_step.step.to_object(py))?;
+            if let Some(ref input_type) = chain_step.input_type {
+                context.set_item(intern!(py, "chain_step_input_type"), input_type)?;
+            }
+        }
+        Ok(Some(context.into()))
+    }
+
+    /// the error's message, from its replacement template in `custom_messages` if it has one
+    fn message(
+        &self,
+        py: Python,
+        error_mode: &ErrorMode,
+        custom_messages: Option<&CustomErrorMessages>,
+    ) -> PyResult<String> {
+        match custom_messages.and_then(|messages| messages.render(py, &self.error_type)) {
+            Some(message) => message,
+            None => self.error_type.render_message(py, error_mode),
+        }
+    }
+
+    fn pretty(
+        &self,
+        py: Python,
+        error_mode: &ErrorMode,
+        custom_messages: Option<&CustomErrorMessages>,
+        hide_input: bool,
+    ) -> Result<String, fmt::Error> {
         let mut output = String::with_capacity(200);
         write!(output, "{}", self.location)?;
 
-        let message = match self.error_type.render_message(py, error_mode) {
+        let message = match self.message(py, error_mode, custom_messages) {
             Ok(message) => message,
             Err(err) => format!("(error rendering message: {err})"),
         };
         write!(output, "  {message} [type={}", self.error_type.type_string())?;
+        if hide_input {
+            output.push(']');
+            return Ok(output);
+        }
 
         let input_value = self.input_value.as_ref(py);
         let input_str = safe_repr(input_value);
@@ -325,8 +583,10 @@ struct ValidationErrorSerializer<'py> {
     py: Python<'py>,
     line_errors: &'py [PyLineError],
     include_context: bool,
+    include_input: bool,
     extra: &'py crate::serializers::Extra<'py>,
     error_mode: &'py ErrorMode,
+    custom_messages: Option<&'py CustomErrorMessages>,
 }
 
 impl<'py> Serialize for ValidationErrorSerializer<'py> {
@@ -340,8 +600,10 @@ impl<'py> Serialize for ValidationErrorSerializer<'py> {
                 py: self.py,
                 line_error,
                 include_context: self.include_context,
+                include_input: self.include_input,
                 extra: self.extra,
                 error_mode: self.error_mode,
+                custom_messages: self.custom_messages,
             };
             seq.serialize_element(&line_s)?;
         }
@@ -353,8 +615,10 @@ struct PyLineErrorSerializer<'py> {
     py: Python<'py>,
     line_error: &'py PyLineError,
     include_context: bool,
+    include_input: bool,
     extra: &'py crate::serializers::Extra<'py>,
     error_mode: &'py ErrorMode,
+    custom_messages: Option<&'py CustomErrorMessages>,
 }
 
 impl<'py> Serialize for PyLineErrorSerializer<'py> {
@@ -363,7 +627,7 @@ impl<'py> Serialize for PyLineErrorSerializer<'py> {
         S: Serializer,
     {
         let py = self.py;
-        let size = if self.include_context { 5 } else { 4 };
+        let size = 3 + usize::from(self.include_context) + usize::from(self.include_input);
         let mut map = serializer.serialize_map(Some(size))?;
 
         map.serialize_entry("type", &self.line_error.error_type.type_string())?;
@@ -372,18 +636,21 @@ impl<'py> Serialize for PyLineErrorSerializer<'py> {
 
         let msg = self
             .line_error
-            .error_type
-            .render_message(py, self.error_mode)
+            .message(py, self.error_mode, self.custom_messages)
             .map_err(py_err_json::<S>)?;
         map.serialize_entry("msg", &msg)?;
 
-        map.serialize_entry(
-            "input",
-            &self.extra.serialize_infer(self.line_error.input_value.as_ref(py)),
-        )?;
+        if self.include_input {
+            let input_value = self.line_error.input_value.as_ref(py);
+            // serialized on its own first so an input which can't be represented in JSON, e.g. bytes which
+            // aren't valid UTF-8, falls back to its repr rather than failing the whole error
+            let input_json = serde_json::to_value(self.extra.serialize_infer(input_value))
+                .unwrap_or_else(|_| serde_json::Value::String(safe_repr(input_value).to_string()));
+            map.serialize_entry("input", &input_json)?;
+        }
 
         if self.include_context {
-            if let Some(context) = self.line_error.error_type.py_dict(py).map_err(py_err_json::<S>)? {
+            if let Some(context) = self.line_error.context(py).map_err(py_err_json::<S>)? {
                 map.serialize_entry("ctx", &self.extra.serialize_infer(context.as_ref(py)))?;
             }
         }
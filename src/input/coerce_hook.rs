@@ -0,0 +1,93 @@
+use std::os::raw::c_uint;
+use std::sync::Mutex;
+
+use ahash::AHashMap;
+use pyo3::intern;
+use pyo3::once_cell::GILOnceCell;
+use pyo3::prelude::*;
+use pyo3::types::{PyString, PyType};
+use pyo3::AsPyPointer;
+
+use crate::errors::{ValError, ValResult};
+
+use super::Input;
+
+/// Whether types have a `__pydantic_core_coerce__` method, keyed by the address of the type and stored with the
+/// type's version tag. CPython gives a type a new version tag whenever it or one of its bases is modified, so a
+/// hook added or removed later is noticed, as is another type reusing the address once a type is freed.
+/// The types themselves aren't kept alive, instead the cache is cleared once it holds `HOOK_CACHE_SIZE` types.
+type HookCache = Mutex<AHashMap<usize, (c_uint, bool)>>;
+
+static HOOK_CACHE: GILOnceCell<HookCache> = GILOnceCell::new();
+
+const HOOK_CACHE_SIZE: usize = 1024;
+
+/// Call `__pydantic_core_coerce__(target)` on `input` if its type defines it, returning the coerced value,
+/// `None` is returned if the type doesn't define the hook or the hook returns `NotImplemented`
+pub fn coerce_hook<'a>(input: &'a PyAny, target: &str) -> PyResult<Option<&'a PyAny>> {
+    let py = input.py();
+    let hook_name = intern!(py, "__pydantic_core_coerce__");
+    if !type_has_hook(input.get_type(), hook_name)? {
+        return Ok(None);
+    }
+    let coerced = input.call_method1(hook_name, (target,))?;
+    if coerced.is(&py.NotImplemented()) {
+        Ok(None)
+    } else {
+        Ok(Some(coerced))
+    }
+}
+
+fn type_has_hook(py_type: &PyType, hook_name: &PyString) -> PyResult<bool> {
+    let py = py_type.py();
+    let cache = HOOK_CACHE.get_or_init(py, || Mutex::new(AHashMap::new()));
+    let key = py_type.as_ptr() as usize;
+    // the GIL is held so there's no contention on the lock
+    if let Some(version_tag) = version_tag(py_type) {
+        if let Some(&(cached_version_tag, has_hook)) = cache.lock().unwrap().get(&key) {
+            if cached_version_tag == version_tag {
+                return Ok(has_hook);
+            }
+        }
+    }
+    let has_hook = py_type.hasattr(hook_name)?;
+    // looking up the hook gives the type a version tag if it didn't have one
+    if let Some(version_tag) = version_tag(py_type) {
+        let mut cache = cache.lock().unwrap();
+        if cache.len() >= HOOK_CACHE_SIZE && !cache.contains_key(&key) {
+            cache.clear();
+        }
+        cache.insert(key, (version_tag, has_hook));
+    }
+    Ok(has_hook)
+}
+
+/// the type's version tag, `None` if it doesn't have a valid one, in which case the hook isn't cached
+fn version_tag(py_type: &PyType) -> Option<c_uint> {
+    let type_ptr = py_type.as_type_ptr();
+    unsafe {
+        match pyo3::ffi::PyType_HasFeature(type_ptr, pyo3::ffi::Py_TPFLAGS_VALID_VERSION_TAG) {
+            0 => None,
+            _ => Some((*type_ptr).tp_version_tag),
+        }
+    }
+}
+
+/// Used by leaf validators in lax mode: if `result` is a validation error and `input` provides a value
+/// for `target` via `__pydantic_core_coerce__`, that value is validated with `validate` instead,
+/// otherwise the original result is returned unchanged
+pub fn with_coerce_hook<'a, T>(
+    input: &'a impl Input<'a>,
+    target: &str,
+    enabled: bool,
+    result: ValResult<'a, T>,
+    validate: impl FnOnce(&'a PyAny) -> ValResult<'a, T>,
+) -> ValResult<'a, T> {
+    match result {
+        Err(ValError::LineErrors(line_errors)) if enabled => match input.input_coerce_hook(target)? {
+            Some(coerced) => validate(coerced),
+            None => Err(ValError::LineErrors(line_errors)),
+        },
+        result => result,
+    }
+}
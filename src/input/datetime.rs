@@ -1,10 +1,11 @@
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDate, PyDateTime, PyDelta, PyDeltaAccess, PyTime, PyTzInfo};
+use pyo3::types::{PyDate, PyDateTime, PyDelta, PyDeltaAccess, PyDict, PyTime, PyTzInfo};
 use speedate::{Date, DateTime, Duration, ParseError, Time};
 use std::borrow::Cow;
 use strum::EnumMessage;
 
+use crate::build_tools::{py_err, schema_or_config_same};
 use crate::errors::{ErrorType, ValError, ValResult};
 
 use super::Input;
@@ -265,9 +266,70 @@ pub fn bytes_as_date<'a>(input: &'a impl Input<'a>, bytes: &[u8]) -> ValResult<'
     }
 }
 
-pub fn bytes_as_time<'a>(input: &'a impl Input<'a>, bytes: &[u8]) -> ValResult<'a, EitherTime<'a>> {
-    match Time::parse_bytes(bytes) {
-        Ok(date) => Ok(date.into()),
+/// How fractions of a second with more digits than microseconds are handled when parsing strings
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MicrosecondsPrecision {
+    /// digits after the sixth are dropped, never rounded
+    #[default]
+    Truncate,
+    Error,
+}
+
+impl MicrosecondsPrecision {
+    pub fn from_py(schema: &PyDict, config: Option<&PyDict>) -> PyResult<Self> {
+        match schema_or_config_same::<&str>(schema, config, intern!(schema.py(), "microseconds_precision"))? {
+            None | Some("truncate") => Ok(Self::Truncate),
+            Some("error") => Ok(Self::Error),
+            Some(s) => py_err!("Invalid microseconds_precision: {}", s),
+        }
+    }
+}
+
+/// Truncates a fraction of a second longer than 6 digits, returning the number of digits it had. speedate would
+/// otherwise reject the fraction in times and datetimes but round it in ISO 8601 durations.
+fn truncate_microseconds(bytes: &[u8]) -> (Cow<'_, [u8]>, Option<usize>) {
+    for (index, byte) in bytes.iter().enumerate() {
+        if *byte != b'.' && *byte != b',' {
+            continue;
+        }
+        let start = index + 1;
+        let digits = bytes[start..].iter().take_while(|b| b.is_ascii_digit()).count();
+        let end = start + digits;
+        // only the fraction of seconds, either after `HH:MM:SS` or before `S` in an ISO 8601 duration
+        let is_seconds = (index >= 3 && bytes[index - 3] == b':') || matches!(bytes.get(end), Some(b'S' | b's'));
+        if digits > 6 && is_seconds {
+            return (Cow::Owned([&bytes[..start + 6], &bytes[end..]].concat()), Some(digits));
+        }
+    }
+    (Cow::Borrowed(bytes), None)
+}
+
+/// Called once the truncated input has been parsed successfully, so other parsing errors take priority
+fn check_microseconds_precision<'a>(
+    input: &'a impl Input<'a>,
+    digits: Option<usize>,
+    precision: MicrosecondsPrecision,
+) -> ValResult<'a, ()> {
+    match (digits, precision) {
+        (Some(precision), MicrosecondsPrecision::Error) => Err(ValError::new(
+            ErrorType::DatetimeMicrosecondsPrecision { precision },
+            input,
+        )),
+        _ => Ok(()),
+    }
+}
+
+pub fn bytes_as_time<'a>(
+    input: &'a impl Input<'a>,
+    bytes: &[u8],
+    precision: MicrosecondsPrecision,
+) -> ValResult<'a, EitherTime<'a>> {
+    let (bytes, digits) = truncate_microseconds(bytes);
+    match Time::parse_bytes(&bytes) {
+        Ok(time) => {
+            check_microseconds_precision(input, digits, precision)?;
+            Ok(time.into())
+        }
         Err(err) => Err(ValError::new(
             ErrorType::TimeParsing {
                 error: Cow::Borrowed(err.get_documentation().unwrap_or_default()),
@@ -277,9 +339,17 @@ pub fn bytes_as_time<'a>(input: &'a impl Input<'a>, bytes: &[u8]) -> ValResult<'
     }
 }
 
-pub fn bytes_as_datetime<'a, 'b>(input: &'a impl Input<'a>, bytes: &'b [u8]) -> ValResult<'a, EitherDateTime<'a>> {
-    match DateTime::parse_bytes(bytes) {
-        Ok(dt) => Ok(dt.into()),
+pub fn bytes_as_datetime<'a, 'b>(
+    input: &'a impl Input<'a>,
+    bytes: &'b [u8],
+    precision: MicrosecondsPrecision,
+) -> ValResult<'a, EitherDateTime<'a>> {
+    let (bytes, digits) = truncate_microseconds(bytes);
+    match DateTime::parse_bytes(&bytes) {
+        Ok(dt) => {
+            check_microseconds_precision(input, digits, precision)?;
+            Ok(dt.into())
+        }
         Err(err) => Err(ValError::new(
             ErrorType::DatetimeParsing {
                 error: Cow::Borrowed(err.get_documentation().unwrap_or_default()),
@@ -289,19 +359,110 @@ pub fn bytes_as_datetime<'a, 'b>(input: &'a impl Input<'a>, bytes: &'b [u8]) ->
     }
 }
 
+/// How numbers are interpreted as unix timestamps when validating dates and datetimes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TemporalUnit {
+    Seconds,
+    Milliseconds,
+    /// seconds, unless the value is larger than 2e10 in either direction in which case milliseconds
+    #[default]
+    Infer,
+}
+
+impl TemporalUnit {
+    pub fn from_py(schema: &PyDict, config: Option<&PyDict>) -> PyResult<Self> {
+        match schema_or_config_same::<&str>(schema, config, intern!(schema.py(), "val_temporal_unit"))? {
+            None | Some("infer") => Ok(Self::Infer),
+            Some("seconds") => Ok(Self::Seconds),
+            Some("milliseconds") => Ok(Self::Milliseconds),
+            Some(s) => py_err!("Invalid val_temporal_unit: {}", s),
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Seconds => "seconds",
+            Self::Milliseconds => "milliseconds",
+            Self::Infer => "infer",
+        }
+    }
+}
+
+// unix timestamps of 1600-01-01T00:00:00 and 9999-12-31T23:59:59, the range supported by speedate
+const UNIX_1600: i64 = -11_676_096_000;
+const UNIX_9999: i64 = 253_402_300_799;
+
+/// Like `DateTime::from_timestamp`, but `seconds` is never reinterpreted as milliseconds
+fn datetime_from_unix_seconds(seconds: i64, microsecond: u32) -> Result<DateTime, ParseError> {
+    let seconds = seconds
+        .checked_add(i64::from(microsecond / 1_000_000))
+        .ok_or(ParseError::DateTooLarge)?;
+    if seconds < UNIX_1600 {
+        return Err(ParseError::DateTooSmall);
+    } else if seconds > UNIX_9999 {
+        return Err(ParseError::DateTooLarge);
+    }
+    let second_of_day = seconds.rem_euclid(86_400) as u32;
+    Ok(DateTime {
+        date: date_from_unix_days(seconds.div_euclid(86_400)),
+        time: Time {
+            hour: (second_of_day / 3_600) as u8,
+            minute: (second_of_day % 3_600 / 60) as u8,
+            second: (second_of_day % 60) as u8,
+            microsecond: microsecond % 1_000_000,
+        },
+        offset: None,
+    })
+}
+
+/// Proleptic gregorian date from days since the unix epoch,
+/// see http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn date_from_unix_days(days: i64) -> Date {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    Date {
+        year: year as u16,
+        month: month as u8,
+        day: day as u8,
+    }
+}
+
 pub fn int_as_datetime<'a>(
     input: &'a impl Input<'a>,
     timestamp: i64,
     timestamp_microseconds: u32,
+    unit: TemporalUnit,
 ) -> ValResult<EitherDateTime> {
-    match DateTime::from_timestamp(timestamp, timestamp_microseconds) {
+    let result = match unit {
+        TemporalUnit::Infer => DateTime::from_timestamp(timestamp, timestamp_microseconds),
+        TemporalUnit::Seconds => datetime_from_unix_seconds(timestamp, timestamp_microseconds),
+        TemporalUnit::Milliseconds => datetime_from_unix_seconds(
+            timestamp.div_euclid(1_000),
+            timestamp_microseconds + timestamp.rem_euclid(1_000) as u32 * 1_000,
+        ),
+    };
+    match result {
         Ok(dt) => Ok(dt.into()),
-        Err(err) => Err(ValError::new(
-            ErrorType::DatetimeParsing {
-                error: Cow::Borrowed(err.get_documentation().unwrap_or_default()),
-            },
-            input,
-        )),
+        Err(err) => {
+            let doc = err.get_documentation().unwrap_or_default();
+            let error = match unit {
+                TemporalUnit::Infer => Cow::Borrowed(doc),
+                // with an explicit unit the only errors are dates out of range, say which unit was used
+                unit => Cow::Owned(format!("{doc} in {}", unit.as_str())),
+            };
+            Err(ValError::new(ErrorType::DatetimeParsing { error }, input))
+        }
     }
 }
 
@@ -318,12 +479,26 @@ macro_rules! nan_check {
     };
 }
 
-pub fn float_as_datetime<'a>(input: &'a impl Input<'a>, timestamp: f64) -> ValResult<EitherDateTime> {
+pub fn float_as_datetime<'a>(
+    input: &'a impl Input<'a>,
+    timestamp: f64,
+    unit: TemporalUnit,
+) -> ValResult<EitherDateTime> {
     nan_check!(input, timestamp, DatetimeParsing);
-    let microseconds = timestamp.fract().abs() * 1_000_000.0;
+    // milliseconds are converted to seconds so any fraction is still kept as microseconds
+    let (timestamp, unit) = match unit {
+        TemporalUnit::Milliseconds => (timestamp / 1_000.0, TemporalUnit::Seconds),
+        unit => (timestamp, unit),
+    };
+    let seconds = timestamp.floor();
+    let microseconds = match unit {
+        TemporalUnit::Infer => timestamp.fract().abs() * 1_000_000.0,
+        // measured up from `seconds` so negative timestamps with a fraction are correct
+        _ => (timestamp - seconds) * 1_000_000.0,
+    };
     // checking for extra digits in microseconds is unreliable with large floats,
     // so we just round to the nearest microsecond
-    int_as_datetime(input, timestamp.floor() as i64, microseconds.round() as u32)
+    int_as_datetime(input, seconds as i64, microseconds.round() as u32, unit)
 }
 
 pub fn date_as_datetime(date: &PyDate) -> PyResult<EitherDateTime> {
@@ -390,9 +565,17 @@ fn map_timedelta_err<'a>(input: &'a impl Input<'a>, err: ParseError) -> ValError
     )
 }
 
-pub fn bytes_as_timedelta<'a, 'b>(input: &'a impl Input<'a>, bytes: &'b [u8]) -> ValResult<'a, EitherTimedelta<'a>> {
-    match Duration::parse_bytes(bytes) {
-        Ok(dt) => Ok(dt.into()),
+pub fn bytes_as_timedelta<'a, 'b>(
+    input: &'a impl Input<'a>,
+    bytes: &'b [u8],
+    precision: MicrosecondsPrecision,
+) -> ValResult<'a, EitherTimedelta<'a>> {
+    let (bytes, digits) = truncate_microseconds(bytes);
+    match Duration::parse_bytes(&bytes) {
+        Ok(dt) => {
+            check_microseconds_precision(input, digits, precision)?;
+            Ok(dt.into())
+        }
         Err(err) => Err(map_timedelta_err(input, err)),
     }
 }
@@ -408,6 +591,14 @@ pub fn int_as_duration<'a>(input: &'a impl Input<'a>, total_seconds: i64) -> Val
 
 pub fn float_as_duration<'a>(input: &'a impl Input<'a>, total_seconds: f64) -> ValResult<Duration> {
     nan_check!(input, total_seconds, TimeDeltaParsing);
+    if total_seconds.is_infinite() {
+        return Err(ValError::new(
+            ErrorType::TimeDeltaParsing {
+                error: Cow::Borrowed("infinite values not permitted"),
+            },
+            input,
+        ));
+    }
     let positive = total_seconds >= 0_f64;
     let total_seconds = total_seconds.abs();
     let microsecond = total_seconds.fract() * 1_000_000.0;
@@ -1,14 +1,19 @@
+use std::cell::Cell;
 use std::fmt;
 
 use pyo3::prelude::*;
 use pyo3::types::{PyString, PyType};
 
 use crate::errors::{InputValue, LocItem, ValResult};
+use crate::serializers::BytesMode;
 use crate::{PyMultiHostUrl, PyUrl};
 
-use super::datetime::{EitherDate, EitherDateTime, EitherTime, EitherTimedelta};
+use super::datetime::{EitherDate, EitherDateTime, EitherTime, EitherTimedelta, MicrosecondsPrecision, TemporalUnit};
 use super::return_enums::{EitherBytes, EitherString};
-use super::{GenericArguments, GenericCollection, GenericIterator, GenericMapping, JsonInput};
+use super::{
+    DuplicateKeys, GenericArguments, GenericCollection, GenericIterator, GenericMapping, JsonInput, PrimitiveItems,
+    StringCache,
+};
 
 pub enum InputType {
     Python,
@@ -39,6 +44,28 @@ pub trait Input<'a>: fmt::Debug + ToPyObject {
 
     fn is_none(&self) -> bool;
 
+    /// like `to_object`, with the strings of JSON inputs looked up in `string_cache`
+    fn to_object_cached(&self, py: Python, _string_cache: &StringCache) -> PyObject {
+        self.to_object(py)
+    }
+
+    /// the output of validating this item with `primitive` if it already has exactly the output type,
+    /// `None` if it needs validating, see `PrimitiveItems`
+    fn exact_primitive(
+        &self,
+        _py: Python,
+        _primitive: PrimitiveItems,
+        _string_cache: Option<&StringCache>,
+    ) -> Option<PyObject> {
+        None
+    }
+
+    /// a JSON number which isn't an `i64` as it's written, e.g. `1.000`, so it can be read without going through
+    /// a float
+    fn number_lexeme(&self) -> Option<&str> {
+        None
+    }
+
     #[cfg_attr(has_no_coverage, no_coverage)]
     fn input_get_attr(&self, _name: &PyString) -> Option<PyResult<&PyAny>> {
         None
@@ -51,6 +78,12 @@ pub trait Input<'a>: fmt::Debug + ToPyObject {
         false
     }
 
+    /// whether `output` has exactly the type of this input, JSON values have the type they're loaded as,
+    /// used by smart unions to tell exact matches from strict ones
+    fn is_exact_type_of(&self, _output: &PyAny) -> bool {
+        false
+    }
+
     fn is_python(&self) -> bool {
         false
     }
@@ -71,11 +104,25 @@ pub trait Input<'a>: fmt::Debug + ToPyObject {
         false
     }
 
+    /// the value returned by `__pydantic_core_coerce__(target)` if the input's type defines it,
+    /// only python objects can define the hook
+    fn input_coerce_hook(&'a self, _target: &str) -> PyResult<Option<&'a PyAny>> {
+        Ok(None)
+    }
+
     fn validate_args(&'a self) -> ValResult<'a, GenericArguments<'a>>;
 
     fn validate_dataclass_args(&'a self, dataclass_name: &str) -> ValResult<'a, GenericArguments<'a>>;
 
-    fn parse_json(&'a self) -> ValResult<'a, JsonInput>;
+    /// `max_str_bytes` limits the length of strings in the JSON, `duplicate_keys` decides what happens to repeated
+    /// keys, `max_depth` limits nesting and `steps_left` limits the number of values and keys, see `parse_json_str`
+    fn parse_json(
+        &'a self,
+        max_str_bytes: Option<usize>,
+        duplicate_keys: DuplicateKeys,
+        max_depth: u16,
+        steps_left: &Cell<usize>,
+    ) -> ValResult<'a, JsonInput>;
 
     fn validate_str(&'a self, strict: bool) -> ValResult<EitherString<'a>> {
         if strict {
@@ -90,19 +137,30 @@ pub trait Input<'a>: fmt::Debug + ToPyObject {
         self.strict_str()
     }
 
-    fn validate_bytes(&'a self, strict: bool) -> ValResult<EitherBytes<'a>> {
+    /// `mode` is how JSON strings are decoded, it's ignored for python inputs
+    fn validate_bytes(&'a self, strict: bool, mode: BytesMode) -> ValResult<EitherBytes<'a>> {
         if strict {
-            self.strict_bytes()
+            self.strict_bytes(mode)
         } else {
-            self.lax_bytes()
+            self.lax_bytes(mode)
         }
     }
-    fn strict_bytes(&'a self) -> ValResult<EitherBytes<'a>>;
+    fn strict_bytes(&'a self, mode: BytesMode) -> ValResult<EitherBytes<'a>>;
     #[cfg_attr(has_no_coverage, no_coverage)]
-    fn lax_bytes(&'a self) -> ValResult<EitherBytes<'a>> {
-        self.strict_bytes()
+    fn lax_bytes(&'a self, mode: BytesMode) -> ValResult<EitherBytes<'a>> {
+        self.strict_bytes(mode)
     }
 
+    /// lax mode is the same as `lax_bytes`, only strict mode differs since it requires a `bytearray`
+    fn validate_bytearray(&'a self, strict: bool, mode: BytesMode) -> ValResult<EitherBytes<'a>> {
+        if strict {
+            self.strict_bytearray(mode)
+        } else {
+            self.lax_bytes(mode)
+        }
+    }
+    fn strict_bytearray(&'a self, mode: BytesMode) -> ValResult<EitherBytes<'a>>;
+
     fn validate_bool(&self, strict: bool) -> ValResult<bool> {
         if strict {
             self.strict_bool()
@@ -128,6 +186,11 @@ pub trait Input<'a>: fmt::Debug + ToPyObject {
     fn lax_int(&self) -> ValResult<i64> {
         self.strict_int()
     }
+    /// used in place of `lax_int` with the `json_strict_numbers` config, JSON numbers written with
+    /// a fractional part or exponent aren't ints even if their value is integral, other inputs are unaffected
+    fn lax_int_strict_numbers(&self) -> ValResult<i64> {
+        self.lax_int()
+    }
 
     fn validate_float(&self, strict: bool) -> ValResult<f64> {
         if strict {
@@ -226,42 +289,47 @@ pub trait Input<'a>: fmt::Debug + ToPyObject {
         self.strict_date()
     }
 
-    fn validate_time(&self, strict: bool) -> ValResult<EitherTime> {
+    fn validate_time(&self, strict: bool, precision: MicrosecondsPrecision) -> ValResult<EitherTime> {
         if strict {
-            self.strict_time()
+            self.strict_time(precision)
         } else {
-            self.lax_time()
+            self.lax_time(precision)
         }
     }
-    fn strict_time(&self) -> ValResult<EitherTime>;
+    fn strict_time(&self, precision: MicrosecondsPrecision) -> ValResult<EitherTime>;
     #[cfg_attr(has_no_coverage, no_coverage)]
-    fn lax_time(&self) -> ValResult<EitherTime> {
-        self.strict_time()
+    fn lax_time(&self, precision: MicrosecondsPrecision) -> ValResult<EitherTime> {
+        self.strict_time(precision)
     }
 
-    fn validate_datetime(&self, strict: bool) -> ValResult<EitherDateTime> {
+    fn validate_datetime(
+        &self,
+        strict: bool,
+        unit: TemporalUnit,
+        precision: MicrosecondsPrecision,
+    ) -> ValResult<EitherDateTime> {
         if strict {
-            self.strict_datetime()
+            self.strict_datetime(precision)
         } else {
-            self.lax_datetime()
+            self.lax_datetime(unit, precision)
         }
     }
-    fn strict_datetime(&self) -> ValResult<EitherDateTime>;
+    fn strict_datetime(&self, precision: MicrosecondsPrecision) -> ValResult<EitherDateTime>;
     #[cfg_attr(has_no_coverage, no_coverage)]
-    fn lax_datetime(&self) -> ValResult<EitherDateTime> {
-        self.strict_datetime()
+    fn lax_datetime(&self, _unit: TemporalUnit, precision: MicrosecondsPrecision) -> ValResult<EitherDateTime> {
+        self.strict_datetime(precision)
     }
 
-    fn validate_timedelta(&self, strict: bool) -> ValResult<EitherTimedelta> {
+    fn validate_timedelta(&self, strict: bool, precision: MicrosecondsPrecision) -> ValResult<EitherTimedelta> {
         if strict {
-            self.strict_timedelta()
+            self.strict_timedelta(precision)
         } else {
-            self.lax_timedelta()
+            self.lax_timedelta(precision)
         }
     }
-    fn strict_timedelta(&self) -> ValResult<EitherTimedelta>;
+    fn strict_timedelta(&self, precision: MicrosecondsPrecision) -> ValResult<EitherTimedelta>;
     #[cfg_attr(has_no_coverage, no_coverage)]
-    fn lax_timedelta(&self) -> ValResult<EitherTimedelta> {
-        self.strict_timedelta()
+    fn lax_timedelta(&self, precision: MicrosecondsPrecision) -> ValResult<EitherTimedelta> {
+        self.strict_timedelta(precision)
     }
 }
@@ -1,17 +1,23 @@
+use std::cell::Cell;
+
 use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyDict, PyFloat, PyList, PyLong, PyString};
+use pyo3::PyTypeInfo;
 
 use crate::errors::{ErrorType, InputValue, LocItem, ValError, ValResult};
+use crate::serializers::BytesMode;
 
 use super::datetime::{
     bytes_as_date, bytes_as_datetime, bytes_as_time, bytes_as_timedelta, float_as_datetime, float_as_duration,
     float_as_time, int_as_datetime, int_as_duration, int_as_time, EitherDate, EitherDateTime, EitherTime,
+    MicrosecondsPrecision, TemporalUnit,
 };
 use super::input_abstract::InputType;
-use super::parse_json::JsonArray;
-use super::shared::{float_as_int, int_as_bool, map_json_err, str_as_bool, str_as_int};
+use super::parse_json::{parse_json_str, DuplicateKeys, JsonArray};
+use super::shared::{float_as_int, int_as_bool, str_as_bool, str_as_int};
 use super::{
     EitherBytes, EitherString, EitherTimedelta, GenericArguments, GenericCollection, GenericIterator, GenericMapping,
-    Input, JsonArgs, JsonInput, JsonType,
+    Input, JsonArgs, JsonInput, JsonType, PrimitiveItems, StringCache,
 };
 
 impl<'a> Input<'a> for JsonInput {
@@ -33,10 +39,68 @@ impl<'a> Input<'a> for JsonInput {
         InputValue::JsonInput(self)
     }
 
+    fn is_exact_type_of(&self, output: &PyAny) -> bool {
+        match self {
+            JsonInput::Null => output.is_none(),
+            JsonInput::Bool(_) => is_exact::<PyBool>(output),
+            JsonInput::Int(_) | JsonInput::BigInt(_) => is_exact::<PyLong>(output),
+            JsonInput::Float(..) => is_exact::<PyFloat>(output),
+            JsonInput::String(_) => is_exact::<PyString>(output),
+            JsonInput::Array(_) => is_exact::<PyList>(output),
+            JsonInput::Object(_) => is_exact::<PyDict>(output),
+        }
+    }
+
     fn is_none(&self) -> bool {
         matches!(self, JsonInput::Null)
     }
 
+    fn to_object_cached(&self, py: Python, string_cache: &StringCache) -> PyObject {
+        match self {
+            JsonInput::String(s) => string_cache.get(py, s, false).into_py(py),
+            JsonInput::Array(array) => {
+                PyList::new(py, array.iter().map(|item| item.to_object_cached(py, string_cache))).into_py(py)
+            }
+            JsonInput::Object(object) => {
+                let dict = PyDict::new(py);
+                for (key, value) in object {
+                    let key = string_cache.get(py, key, true);
+                    dict.set_item(key, value.to_object_cached(py, string_cache)).unwrap();
+                }
+                dict.into_py(py)
+            }
+            _ => self.to_object(py),
+        }
+    }
+
+    fn exact_primitive(
+        &self,
+        py: Python,
+        primitive: PrimitiveItems,
+        string_cache: Option<&StringCache>,
+    ) -> Option<PyObject> {
+        match (primitive, self) {
+            (PrimitiveItems::Int, JsonInput::Int(i)) => Some(i.into_py(py)),
+            (PrimitiveItems::Float { allow_inf_nan }, JsonInput::Float(f, _)) if allow_inf_nan || f.is_finite() => {
+                Some(f.into_py(py))
+            }
+            (PrimitiveItems::Float { .. }, JsonInput::Int(i)) => Some((*i as f64).into_py(py)),
+            (PrimitiveItems::Str, JsonInput::String(s)) => match string_cache {
+                Some(string_cache) => Some(string_cache.get(py, s, false).into_py(py)),
+                None => Some(PyString::new(py, s).into_py(py)),
+            },
+            (PrimitiveItems::Bool, JsonInput::Bool(b)) => Some(b.into_py(py)),
+            _ => None,
+        }
+    }
+
+    fn number_lexeme(&self) -> Option<&str> {
+        match self {
+            JsonInput::BigInt(lexeme) | JsonInput::Float(_, lexeme) => Some(lexeme),
+            _ => None,
+        }
+    }
+
     fn input_is_instance(&self, _class: &PyAny, json_mask: u8) -> PyResult<bool> {
         if json_mask == 0 {
             Ok(false)
@@ -44,8 +108,8 @@ impl<'a> Input<'a> for JsonInput {
             let json_type: JsonType = match self {
                 JsonInput::Null => JsonType::Null,
                 JsonInput::Bool(_) => JsonType::Bool,
-                JsonInput::Int(_) => JsonType::Int,
-                JsonInput::Float(_) => JsonType::Float,
+                JsonInput::Int(_) | JsonInput::BigInt(_) => JsonType::Int,
+                JsonInput::Float(..) => JsonType::Float,
                 JsonInput::String(_) => JsonType::String,
                 JsonInput::Array(_) => JsonType::Array,
                 JsonInput::Object(_) => JsonType::Object,
@@ -72,9 +136,16 @@ impl<'a> Input<'a> for JsonInput {
         }
     }
 
-    fn parse_json(&'a self) -> ValResult<'a, JsonInput> {
+    fn parse_json(
+        &'a self,
+        max_str_bytes: Option<usize>,
+        duplicate_keys: DuplicateKeys,
+        max_depth: u16,
+        steps_left: &Cell<usize>,
+    ) -> ValResult<'a, JsonInput> {
         match self {
-            JsonInput::String(s) => serde_json::from_str(s.as_str()).map_err(|e| map_json_err(self, e)),
+            JsonInput::String(s) => parse_json_str(s.as_str(), max_str_bytes, duplicate_keys, max_depth, steps_left)
+                .map_err(|e| ValError::new(e, self)),
             _ => Err(ValError::new(ErrorType::JsonType, self)),
         }
     }
@@ -92,15 +163,22 @@ impl<'a> Input<'a> for JsonInput {
         }
     }
 
-    fn validate_bytes(&'a self, _strict: bool) -> ValResult<EitherBytes<'a>> {
+    fn validate_bytes(&'a self, _strict: bool, mode: BytesMode) -> ValResult<EitherBytes<'a>> {
         match self {
-            JsonInput::String(s) => Ok(s.as_bytes().into()),
+            JsonInput::String(s) => match mode.deserialize_string(s) {
+                Ok(b) => Ok(EitherBytes::Cow(b)),
+                Err(error_type) => Err(ValError::new(error_type, self)),
+            },
             _ => Err(ValError::new(ErrorType::BytesType, self)),
         }
     }
     #[cfg_attr(has_no_coverage, no_coverage)]
-    fn strict_bytes(&'a self) -> ValResult<EitherBytes<'a>> {
-        self.validate_bytes(false)
+    fn strict_bytes(&'a self, mode: BytesMode) -> ValResult<EitherBytes<'a>> {
+        self.validate_bytes(false, mode)
+    }
+
+    fn strict_bytearray(&'a self, mode: BytesMode) -> ValResult<EitherBytes<'a>> {
+        self.validate_bytes(false, mode)
     }
 
     fn strict_bool(&self) -> ValResult<bool> {
@@ -114,10 +192,12 @@ impl<'a> Input<'a> for JsonInput {
             JsonInput::Bool(b) => Ok(*b),
             JsonInput::String(s) => str_as_bool(self, s),
             JsonInput::Int(int) => int_as_bool(self, *int),
-            JsonInput::Float(float) => match float_as_int(self, *float) {
+            JsonInput::Float(float, _) => match float_as_int(self, *float) {
                 Ok(int) => int_as_bool(self, int),
                 _ => Err(ValError::new(ErrorType::BoolType, self)),
             },
+            // an int too big for an `i64` can't be `0` or `1`
+            JsonInput::BigInt(_) => Err(ValError::new(ErrorType::BoolType, self)),
             _ => Err(ValError::new(ErrorType::BoolType, self)),
         }
     }
@@ -125,6 +205,7 @@ impl<'a> Input<'a> for JsonInput {
     fn strict_int(&self) -> ValResult<i64> {
         match self {
             JsonInput::Int(i) => Ok(*i),
+            JsonInput::BigInt(_) => Err(ValError::new(ErrorType::IntParsingSize, self)),
             _ => Err(ValError::new(ErrorType::IntType, self)),
         }
     }
@@ -135,16 +216,26 @@ impl<'a> Input<'a> for JsonInput {
                 false => Ok(0),
             },
             JsonInput::Int(i) => Ok(*i),
-            JsonInput::Float(f) => float_as_int(self, *f),
+            JsonInput::BigInt(_) => Err(ValError::new(ErrorType::IntParsingSize, self)),
+            JsonInput::Float(f, _) => float_as_int(self, *f),
             JsonInput::String(str) => str_as_int(self, str),
             _ => Err(ValError::new(ErrorType::IntType, self)),
         }
     }
+    fn lax_int_strict_numbers(&self) -> ValResult<i64> {
+        match self {
+            // `JsonInput::Float` means the number had a fractional part or exponent, e.g. `1.0` or `1e3`
+            JsonInput::Float(f, _) if f % 1.0 != 0.0 => Err(ValError::new(ErrorType::IntFromFloat, self)),
+            JsonInput::Float(..) => Err(ValError::new(ErrorType::IntType, self)),
+            _ => self.lax_int(),
+        }
+    }
 
     fn strict_float(&self) -> ValResult<f64> {
         match self {
-            JsonInput::Float(f) => Ok(*f),
+            JsonInput::Float(f, _) => Ok(*f),
             JsonInput::Int(i) => Ok(*i as f64),
+            JsonInput::BigInt(digits) => Ok(big_int_as_float(digits)),
             _ => Err(ValError::new(ErrorType::FloatType, self)),
         }
     }
@@ -154,8 +245,9 @@ impl<'a> Input<'a> for JsonInput {
                 true => Ok(1.0),
                 false => Ok(0.0),
             },
-            JsonInput::Float(f) => Ok(*f),
+            JsonInput::Float(f, _) => Ok(*f),
             JsonInput::Int(i) => Ok(*i as f64),
+            JsonInput::BigInt(digits) => Ok(big_int_as_float(digits)),
             JsonInput::String(str) => match str.parse::<f64>() {
                 Ok(i) => Ok(i),
                 Err(_) => Err(ValError::new(ErrorType::FloatParsing, self)),
@@ -248,47 +340,50 @@ impl<'a> Input<'a> for JsonInput {
         self.validate_date(false)
     }
 
-    fn strict_time(&self) -> ValResult<EitherTime> {
+    fn strict_time(&self, precision: MicrosecondsPrecision) -> ValResult<EitherTime> {
         match self {
-            JsonInput::String(v) => bytes_as_time(self, v.as_bytes()),
+            JsonInput::String(v) => bytes_as_time(self, v.as_bytes(), precision),
             _ => Err(ValError::new(ErrorType::TimeType, self)),
         }
     }
-    fn lax_time(&self) -> ValResult<EitherTime> {
+    fn lax_time(&self, precision: MicrosecondsPrecision) -> ValResult<EitherTime> {
         match self {
-            JsonInput::String(v) => bytes_as_time(self, v.as_bytes()),
+            JsonInput::String(v) => bytes_as_time(self, v.as_bytes(), precision),
             JsonInput::Int(v) => int_as_time(self, *v, 0),
-            JsonInput::Float(v) => float_as_time(self, *v),
+            JsonInput::Float(v, _) => float_as_time(self, *v),
+            JsonInput::BigInt(digits) => float_as_time(self, big_int_as_float(digits)),
             _ => Err(ValError::new(ErrorType::TimeType, self)),
         }
     }
 
-    fn strict_datetime(&self) -> ValResult<EitherDateTime> {
+    fn strict_datetime(&self, precision: MicrosecondsPrecision) -> ValResult<EitherDateTime> {
         match self {
-            JsonInput::String(v) => bytes_as_datetime(self, v.as_bytes()),
+            JsonInput::String(v) => bytes_as_datetime(self, v.as_bytes(), precision),
             _ => Err(ValError::new(ErrorType::DatetimeType, self)),
         }
     }
-    fn lax_datetime(&self) -> ValResult<EitherDateTime> {
+    fn lax_datetime(&self, unit: TemporalUnit, precision: MicrosecondsPrecision) -> ValResult<EitherDateTime> {
         match self {
-            JsonInput::String(v) => bytes_as_datetime(self, v.as_bytes()),
-            JsonInput::Int(v) => int_as_datetime(self, *v, 0),
-            JsonInput::Float(v) => float_as_datetime(self, *v),
+            JsonInput::String(v) => bytes_as_datetime(self, v.as_bytes(), precision),
+            JsonInput::Int(v) => int_as_datetime(self, *v, 0, unit),
+            JsonInput::Float(v, _) => float_as_datetime(self, *v, unit),
+            JsonInput::BigInt(digits) => float_as_datetime(self, big_int_as_float(digits), unit),
             _ => Err(ValError::new(ErrorType::DatetimeType, self)),
         }
     }
 
-    fn strict_timedelta(&self) -> ValResult<EitherTimedelta> {
+    fn strict_timedelta(&self, precision: MicrosecondsPrecision) -> ValResult<EitherTimedelta> {
         match self {
-            JsonInput::String(v) => bytes_as_timedelta(self, v.as_bytes()),
+            JsonInput::String(v) => bytes_as_timedelta(self, v.as_bytes(), precision),
             _ => Err(ValError::new(ErrorType::TimeDeltaType, self)),
         }
     }
-    fn lax_timedelta(&self) -> ValResult<EitherTimedelta> {
+    fn lax_timedelta(&self, precision: MicrosecondsPrecision) -> ValResult<EitherTimedelta> {
         match self {
-            JsonInput::String(v) => bytes_as_timedelta(self, v.as_bytes()),
+            JsonInput::String(v) => bytes_as_timedelta(self, v.as_bytes(), precision),
             JsonInput::Int(v) => Ok(int_as_duration(self, *v)?.into()),
-            JsonInput::Float(v) => Ok(float_as_duration(self, *v)?.into()),
+            JsonInput::Float(v, _) => Ok(float_as_duration(self, *v)?.into()),
+            JsonInput::BigInt(digits) => Ok(float_as_duration(self, big_int_as_float(digits))?.into()),
             _ => Err(ValError::new(ErrorType::TimeDeltaType, self)),
         }
     }
@@ -308,11 +403,19 @@ impl<'a> Input<'a> for String {
         InputValue::String(self)
     }
 
+    fn is_exact_type_of(&self, output: &PyAny) -> bool {
+        is_exact::<PyString>(output)
+    }
+
     #[cfg_attr(has_no_coverage, no_coverage)]
     fn is_none(&self) -> bool {
         false
     }
 
+    fn to_object_cached(&self, py: Python, string_cache: &StringCache) -> PyObject {
+        string_cache.get(py, self, true).into_py(py)
+    }
+
     fn input_is_instance(&self, _class: &PyAny, json_mask: u8) -> PyResult<bool> {
         if json_mask == 0 {
             Ok(false)
@@ -332,8 +435,15 @@ impl<'a> Input<'a> for String {
         Err(ValError::new(ErrorType::DataclassType { dataclass_name }, self))
     }
 
-    fn parse_json(&'a self) -> ValResult<'a, JsonInput> {
-        serde_json::from_str(self.as_str()).map_err(|e| map_json_err(self, e))
+    fn parse_json(
+        &'a self,
+        max_str_bytes: Option<usize>,
+        duplicate_keys: DuplicateKeys,
+        max_depth: u16,
+        steps_left: &Cell<usize>,
+    ) -> ValResult<'a, JsonInput> {
+        parse_json_str(self.as_str(), max_str_bytes, duplicate_keys, max_depth, steps_left)
+            .map_err(|e| ValError::new(e, self))
     }
 
     fn validate_str(&'a self, _strict: bool) -> ValResult<EitherString<'a>> {
@@ -343,12 +453,19 @@ impl<'a> Input<'a> for String {
         self.validate_str(false)
     }
 
-    fn validate_bytes(&'a self, _strict: bool) -> ValResult<EitherBytes<'a>> {
-        Ok(self.as_bytes().into())
+    fn validate_bytes(&'a self, _strict: bool, mode: BytesMode) -> ValResult<EitherBytes<'a>> {
+        match mode.deserialize_string(self) {
+            Ok(b) => Ok(EitherBytes::Cow(b)),
+            Err(error_type) => Err(ValError::new(error_type, self)),
+        }
     }
     #[cfg_attr(has_no_coverage, no_coverage)]
-    fn strict_bytes(&'a self) -> ValResult<EitherBytes<'a>> {
-        self.validate_bytes(false)
+    fn strict_bytes(&'a self, mode: BytesMode) -> ValResult<EitherBytes<'a>> {
+        self.validate_bytes(false, mode)
+    }
+
+    fn strict_bytearray(&'a self, mode: BytesMode) -> ValResult<EitherBytes<'a>> {
+        self.validate_bytes(false, mode)
     }
 
     fn strict_bool(&self) -> ValResult<bool> {
@@ -436,31 +553,45 @@ impl<'a> Input<'a> for String {
         self.validate_date(false)
     }
 
-    fn validate_time(&self, _strict: bool) -> ValResult<EitherTime> {
-        bytes_as_time(self, self.as_bytes())
+    fn validate_time(&self, _strict: bool, precision: MicrosecondsPrecision) -> ValResult<EitherTime> {
+        bytes_as_time(self, self.as_bytes(), precision)
     }
     #[cfg_attr(has_no_coverage, no_coverage)]
-    fn strict_time(&self) -> ValResult<EitherTime> {
-        self.validate_time(false)
+    fn strict_time(&self, precision: MicrosecondsPrecision) -> ValResult<EitherTime> {
+        self.validate_time(false, precision)
     }
 
-    fn validate_datetime(&self, _strict: bool) -> ValResult<EitherDateTime> {
-        bytes_as_datetime(self, self.as_bytes())
+    fn validate_datetime(
+        &self,
+        _strict: bool,
+        _unit: TemporalUnit,
+        precision: MicrosecondsPrecision,
+    ) -> ValResult<EitherDateTime> {
+        bytes_as_datetime(self, self.as_bytes(), precision)
     }
     #[cfg_attr(has_no_coverage, no_coverage)]
-    fn strict_datetime(&self) -> ValResult<EitherDateTime> {
-        self.validate_datetime(false)
+    fn strict_datetime(&self, precision: MicrosecondsPrecision) -> ValResult<EitherDateTime> {
+        self.validate_datetime(false, TemporalUnit::default(), precision)
     }
 
-    fn validate_timedelta(&self, _strict: bool) -> ValResult<EitherTimedelta> {
-        bytes_as_timedelta(self, self.as_bytes())
+    fn validate_timedelta(&self, _strict: bool, precision: MicrosecondsPrecision) -> ValResult<EitherTimedelta> {
+        bytes_as_timedelta(self, self.as_bytes(), precision)
     }
     #[cfg_attr(has_no_coverage, no_coverage)]
-    fn strict_timedelta(&self) -> ValResult<EitherTimedelta> {
-        self.validate_timedelta(false)
+    fn strict_timedelta(&self, precision: MicrosecondsPrecision) -> ValResult<EitherTimedelta> {
+        self.validate_timedelta(false, precision)
     }
 }
 
 fn string_to_vec(s: &str) -> JsonArray {
     s.chars().map(|c| JsonInput::String(c.to_string())).collect()
 }
+
+fn is_exact<T: PyTypeInfo>(output: &PyAny) -> bool {
+    output.get_type().is(T::type_object(output.py()))
+}
+
+/// the digits of an int too big for an `i64` are always a valid float, though it might be rounded or infinite
+fn big_int_as_float(digits: &str) -> f64 {
+    digits.parse().unwrap_or(f64::NAN)
+}
@@ -1,39 +1,47 @@
 use std::borrow::Cow;
+use std::cell::Cell;
 use std::str::from_utf8;
 
+use pyo3::buffer::PyBuffer;
 use pyo3::once_cell::GILOnceCell;
 use pyo3::prelude::*;
 use pyo3::types::{
-    PyBool, PyByteArray, PyBytes, PyDate, PyDateTime, PyDelta, PyDict, PyFrozenSet, PyIterator, PyList, PyMapping,
-    PySet, PyString, PyTime, PyTuple, PyType,
+    PyBool, PyByteArray, PyBytes, PyDate, PyDateTime, PyDelta, PyDict, PyFloat, PyFrozenSet, PyIterator, PyList,
+    PyLong, PyMapping, PySet, PyString, PyTime, PyTuple, PyType,
 };
 #[cfg(not(PyPy))]
 use pyo3::types::{PyDictItems, PyDictKeys, PyDictValues};
 use pyo3::{ffi, intern, AsPyPointer, PyTypeInfo};
 
 use crate::build_tools::safe_repr;
-use crate::errors::{ErrorType, InputValue, LocItem, ValError, ValResult};
+use crate::errors::{ErrorType, InputValue, LocItem, ValError, ValLineError, ValResult};
+use crate::serializers::BytesMode;
 use crate::{ArgsKwargs, PyMultiHostUrl, PyUrl};
 
+use super::coerce_hook::coerce_hook;
 use super::datetime::{
     bytes_as_date, bytes_as_datetime, bytes_as_time, bytes_as_timedelta, date_as_datetime, float_as_datetime,
     float_as_duration, float_as_time, int_as_datetime, int_as_duration, int_as_time, EitherDate, EitherDateTime,
-    EitherTime,
+    EitherTime, MicrosecondsPrecision, TemporalUnit,
 };
 use super::input_abstract::InputType;
-use super::shared::{float_as_int, int_as_bool, map_json_err, str_as_bool, str_as_int};
+use super::parse_json::{parse_json_bytes, parse_json_partial, parse_json_str, DuplicateKeys};
+use super::shared::{float_as_int, int_as_bool, str_as_bool, str_as_int};
 use super::{
-    py_error_on_minusone, py_string_str, EitherBytes, EitherString, EitherTimedelta, GenericArguments,
-    GenericCollection, GenericIterator, GenericMapping, Input, JsonInput, PyArgs,
+    is_range, py_error_on_minusone, py_string_str, EitherBytes, EitherString, EitherTimedelta, GenericArguments,
+    GenericCollection, GenericIterator, GenericMapping, Input, JsonInput, JsonObject, PrimitiveItems, PyArgs,
+    StringCache,
 };
 
-/// Extract generators and deques into a `GenericCollection`
+/// Extract generators, deques and ranges into a `GenericCollection`
 macro_rules! extract_shared_iter {
     ($type:ty, $obj:ident) => {
         if $obj.downcast::<PyIterator>().is_ok() {
             Some($obj.into())
         } else if is_deque($obj) {
             Some($obj.into())
+        } else if is_range($obj) {
+            Some($obj.into())
         } else {
             None
         }
@@ -114,6 +122,29 @@ impl<'a> Input<'a> for PyAny {
         self.get_type().is(class)
     }
 
+    fn is_exact_type_of(&self, output: &PyAny) -> bool {
+        self.get_type().is(output.get_type())
+    }
+
+    fn exact_primitive(
+        &self,
+        py: Python,
+        primitive: PrimitiveItems,
+        _string_cache: Option<&StringCache>,
+    ) -> Option<PyObject> {
+        let exact = match primitive {
+            // ints are validated as `i64`, larger ints fail
+            PrimitiveItems::Int => PyLong::is_exact_type_of(self) && self.extract::<i64>().is_ok(),
+            PrimitiveItems::Float { allow_inf_nan } => match self.downcast::<PyFloat>() {
+                Ok(float) if PyFloat::is_exact_type_of(self) => allow_inf_nan || float.value().is_finite(),
+                _ => false,
+            },
+            PrimitiveItems::Str => PyString::is_exact_type_of(self),
+            PrimitiveItems::Bool => PyBool::is_type_of(self),
+        };
+        exact.then(|| self.into_py(py))
+    }
+
     fn is_python(&self) -> bool {
         true
     }
@@ -137,6 +168,10 @@ impl<'a> Input<'a> for PyAny {
         self.is_callable()
     }
 
+    fn input_coerce_hook(&'a self, target: &str) -> PyResult<Option<&'a PyAny>> {
+        coerce_hook(self, target)
+    }
+
     fn validate_args(&'a self) -> ValResult<'a, GenericArguments<'a>> {
         if let Ok(dict) = self.downcast::<PyDict>() {
             Ok(PyArgs::new(None, Some(dict)).into())
@@ -167,16 +202,30 @@ impl<'a> Input<'a> for PyAny {
         }
     }
 
-    fn parse_json(&'a self) -> ValResult<'a, JsonInput> {
+    fn parse_json(
+        &'a self,
+        max_str_bytes: Option<usize>,
+        duplicate_keys: DuplicateKeys,
+        max_depth: u16,
+        steps_left: &Cell<usize>,
+    ) -> ValResult<'a, JsonInput> {
         if let Ok(py_bytes) = self.downcast::<PyBytes>() {
-            serde_json::from_slice(py_bytes.as_bytes()).map_err(|e| map_json_err(self, e))
+            parse_json_bytes(
+                py_bytes.as_bytes(),
+                max_str_bytes,
+                duplicate_keys,
+                max_depth,
+                steps_left,
+            )
+            .map_err(|e| ValError::new(e, self))
         } else if let Ok(py_str) = self.downcast::<PyString>() {
             let str = py_str.to_str()?;
-            serde_json::from_str(str).map_err(|e| map_json_err(self, e))
-        } else if let Ok(py_byte_array) = self.downcast::<PyByteArray>() {
-            serde_json::from_slice(unsafe { py_byte_array.as_bytes() }).map_err(|e| map_json_err(self, e))
+            parse_json_str(str, max_str_bytes, duplicate_keys, max_depth, steps_left)
+                .map_err(|e| ValError::new(e, self))
         } else {
-            Err(ValError::new(ErrorType::JsonType, self))
+            parse_json_buffer(self, |json| {
+                parse_json_bytes(json, max_str_bytes, duplicate_keys, max_depth, steps_left)
+            })
         }
     }
 
@@ -220,7 +269,7 @@ impl<'a> Input<'a> for PyAny {
         }
     }
 
-    fn strict_bytes(&'a self) -> ValResult<EitherBytes<'a>> {
+    fn strict_bytes(&'a self, _mode: BytesMode) -> ValResult<EitherBytes<'a>> {
         if let Ok(py_bytes) = self.downcast::<PyBytes>() {
             Ok(py_bytes.into())
         } else {
@@ -228,7 +277,15 @@ impl<'a> Input<'a> for PyAny {
         }
     }
 
-    fn lax_bytes(&'a self) -> ValResult<EitherBytes<'a>> {
+    fn strict_bytearray(&'a self, _mode: BytesMode) -> ValResult<EitherBytes<'a>> {
+        if let Ok(py_byte_array) = self.downcast::<PyByteArray>() {
+            Ok(py_byte_array.to_vec().into())
+        } else {
+            Err(ValError::new(ErrorType::BytearrayType, self))
+        }
+    }
+
+    fn lax_bytes(&'a self, _mode: BytesMode) -> ValResult<EitherBytes<'a>> {
         if let Ok(py_bytes) = self.downcast::<PyBytes>() {
             Ok(py_bytes.into())
         } else if let Ok(py_str) = self.downcast::<PyString>() {
@@ -236,6 +293,8 @@ impl<'a> Input<'a> for PyAny {
             Ok(str.as_bytes().into())
         } else if let Ok(py_byte_array) = self.downcast::<PyByteArray>() {
             Ok(py_byte_array.to_vec().into())
+        } else if unsafe { ffi::PyObject_CheckBuffer(self.as_ptr()) } == 1 {
+            buffer_as_bytes(self)
         } else {
             Err(ValError::new(ErrorType::BytesType, self))
         }
@@ -278,10 +337,11 @@ impl<'a> Input<'a> for PyAny {
     }
 
     fn lax_int(&self) -> ValResult<i64> {
-        if let Ok(int) = self.extract::<i64>() {
-            Ok(int)
-        } else if let Some(cow_str) = maybe_as_string(self, ErrorType::IntParsing)? {
+        // strings are checked first since failing to extract a number from them raises a python error
+        if let Some(cow_str) = maybe_as_string(self, ErrorType::IntParsing)? {
             str_as_int(self, &cow_str)
+        } else if let Ok(int) = self.extract::<i64>() {
+            Ok(int)
         } else if let Ok(float) = self.extract::<f64>() {
             float_as_int(self, float)
         } else {
@@ -300,13 +360,14 @@ impl<'a> Input<'a> for PyAny {
     }
 
     fn lax_float(&self) -> ValResult<f64> {
-        if let Ok(float) = self.extract::<f64>() {
-            Ok(float)
-        } else if let Some(cow_str) = maybe_as_string(self, ErrorType::FloatParsing)? {
+        // as with `lax_int`, strings are checked first
+        if let Some(cow_str) = maybe_as_string(self, ErrorType::FloatParsing)? {
             match cow_str.as_ref().parse::<f64>() {
                 Ok(i) => Ok(i),
                 Err(_) => Err(ValError::new(ErrorType::FloatParsing, self)),
             }
+        } else if let Ok(float) = self.extract::<f64>() {
+            Ok(float)
         } else {
             Err(ValError::new(ErrorType::FloatType, self))
         }
@@ -494,7 +555,7 @@ impl<'a> Input<'a> for PyAny {
         }
     }
 
-    fn strict_time(&self) -> ValResult<EitherTime> {
+    fn strict_time(&self, _precision: MicrosecondsPrecision) -> ValResult<EitherTime> {
         if let Ok(time) = self.downcast::<PyTime>() {
             Ok(time.into())
         } else {
@@ -502,14 +563,14 @@ impl<'a> Input<'a> for PyAny {
         }
     }
 
-    fn lax_time(&self) -> ValResult<EitherTime> {
+    fn lax_time(&self, precision: MicrosecondsPrecision) -> ValResult<EitherTime> {
         if let Ok(time) = self.downcast::<PyTime>() {
             Ok(time.into())
         } else if let Ok(py_str) = self.downcast::<PyString>() {
             let str = py_string_str(py_str)?;
-            bytes_as_time(self, str.as_bytes())
+            bytes_as_time(self, str.as_bytes(), precision)
         } else if let Ok(py_bytes) = self.downcast::<PyBytes>() {
-            bytes_as_time(self, py_bytes.as_bytes())
+            bytes_as_time(self, py_bytes.as_bytes(), precision)
         } else if self.downcast::<PyBool>().is_ok() {
             Err(ValError::new(ErrorType::TimeType, self))
         } else if let Ok(int) = self.extract::<i64>() {
@@ -521,7 +582,7 @@ impl<'a> Input<'a> for PyAny {
         }
     }
 
-    fn strict_datetime(&self) -> ValResult<EitherDateTime> {
+    fn strict_datetime(&self, _precision: MicrosecondsPrecision) -> ValResult<EitherDateTime> {
         if let Ok(dt) = self.downcast::<PyDateTime>() {
             Ok(dt.into())
         } else {
@@ -529,20 +590,20 @@ impl<'a> Input<'a> for PyAny {
         }
     }
 
-    fn lax_datetime(&self) -> ValResult<EitherDateTime> {
+    fn lax_datetime(&self, unit: TemporalUnit, precision: MicrosecondsPrecision) -> ValResult<EitherDateTime> {
         if let Ok(dt) = self.downcast::<PyDateTime>() {
             Ok(dt.into())
         } else if let Ok(py_str) = self.downcast::<PyString>() {
             let str = py_string_str(py_str)?;
-            bytes_as_datetime(self, str.as_bytes())
+            bytes_as_datetime(self, str.as_bytes(), precision)
         } else if let Ok(py_bytes) = self.downcast::<PyBytes>() {
-            bytes_as_datetime(self, py_bytes.as_bytes())
+            bytes_as_datetime(self, py_bytes.as_bytes(), precision)
         } else if self.downcast::<PyBool>().is_ok() {
             Err(ValError::new(ErrorType::DatetimeType, self))
         } else if let Ok(int) = self.extract::<i64>() {
-            int_as_datetime(self, int, 0)
+            int_as_datetime(self, int, 0, unit)
         } else if let Ok(float) = self.extract::<f64>() {
-            float_as_datetime(self, float)
+            float_as_datetime(self, float, unit)
         } else if let Ok(date) = self.downcast::<PyDate>() {
             Ok(date_as_datetime(date)?)
         } else {
@@ -550,7 +611,7 @@ impl<'a> Input<'a> for PyAny {
         }
     }
 
-    fn strict_timedelta(&self) -> ValResult<EitherTimedelta> {
+    fn strict_timedelta(&self, _precision: MicrosecondsPrecision) -> ValResult<EitherTimedelta> {
         if let Ok(dt) = self.downcast::<PyDelta>() {
             Ok(dt.into())
         } else {
@@ -558,14 +619,14 @@ impl<'a> Input<'a> for PyAny {
         }
     }
 
-    fn lax_timedelta(&self) -> ValResult<EitherTimedelta> {
+    fn lax_timedelta(&self, precision: MicrosecondsPrecision) -> ValResult<EitherTimedelta> {
         if let Ok(dt) = self.downcast::<PyDelta>() {
             Ok(dt.into())
         } else if let Ok(py_str) = self.downcast::<PyString>() {
             let str = py_string_str(py_str)?;
-            bytes_as_timedelta(self, str.as_bytes())
+            bytes_as_timedelta(self, str.as_bytes(), precision)
         } else if let Ok(py_bytes) = self.downcast::<PyBytes>() {
-            bytes_as_timedelta(self, py_bytes.as_bytes())
+            bytes_as_timedelta(self, py_bytes.as_bytes(), precision)
         } else if let Ok(int) = self.extract::<i64>() {
             Ok(int_as_duration(self, int)?.into())
         } else if let Ok(float) = self.extract::<f64>() {
@@ -623,6 +684,18 @@ fn import_type(py: Python, module: &str, attr: &str) -> PyResult<Py<PyType>> {
     Ok(obj.downcast::<PyType>()?.into())
 }
 
+/// copy the contents of an object supporting the buffer protocol, e.g. a `memoryview`, straight into a new `bytes`
+/// so length checks use it without another copy, only contiguous one-dimensional buffers of bytes are supported
+fn buffer_as_bytes(obj: &PyAny) -> ValResult<'_, EitherBytes<'_>> {
+    let buffer = match PyBuffer::<u8>::get(obj) {
+        Ok(buffer) if buffer.is_c_contiguous() && buffer.dimensions() <= 1 => buffer,
+        _ => return Err(ValError::new(ErrorType::BytesBufferInvalid, obj)),
+    };
+    // safety: the buffer is contiguous and holds `len_bytes` bytes, it's only read while `buffer` holds it
+    let data = unsafe { std::slice::from_raw_parts(buffer.buf_ptr() as *const u8, buffer.len_bytes()) };
+    Ok(PyBytes::new(obj.py(), data).into())
+}
+
 fn is_builtin_str(py_str: &PyString) -> bool {
     py_str.get_type().is(PyString::type_object(py_str.py()))
 }
@@ -681,6 +754,106 @@ fn is_dict_items_type(v: &PyAny) -> bool {
     v.is_instance(items_type).unwrap_or(false)
 }
 
+/// like `Input::parse_json` for JSON which may have been cut off, see `parse_json_partial`
+pub fn parse_partial_json<'a>(
+    input: &'a PyAny,
+    max_str_bytes: Option<usize>,
+    duplicate_keys: DuplicateKeys,
+    max_depth: u16,
+    steps_left: &Cell<usize>,
+) -> ValResult<'a, (JsonInput, usize)> {
+    let json = if let Ok(py_bytes) = input.downcast::<PyBytes>() {
+        py_bytes.as_bytes()
+    } else if let Ok(py_str) = input.downcast::<PyString>() {
+        py_str.to_str()?.as_bytes()
+    } else {
+        return parse_json_buffer(input, |json| {
+            parse_json_partial(json, max_str_bytes, duplicate_keys, max_depth, steps_left)
+        });
+    };
+    parse_json_partial(json, max_str_bytes, duplicate_keys, max_depth, steps_left).map_err(|e| ValError::new(e, input))
+}
+
+/// parse JSON from an object supporting the buffer protocol, e.g. a `bytearray` or `memoryview`, in place without
+/// copying it, the buffer is held until `parse` returns so a `bytearray` can't be resized or freed in the meantime,
+/// and since the result owns its data, no python code (e.g. a validator function) runs while the buffer is read
+fn parse_json_buffer<'a, T>(input: &'a PyAny, parse: impl FnOnce(&[u8]) -> Result<T, ErrorType>) -> ValResult<'a, T> {
+    let buffer = match PyBuffer::<u8>::get(input) {
+        Ok(buffer) => buffer,
+        Err(_) => return Err(ValError::new(ErrorType::JsonType, input)),
+    };
+    if !buffer.is_c_contiguous() || buffer.dimensions() > 1 {
+        let error_type = ErrorType::JsonInvalid {
+            error: "buffer should be contiguous UTF-8 encoded bytes".to_string(),
+            position: None,
+            line: None,
+            column: None,
+            expected: None,
+        };
+        return Err(ValError::new(error_type, input));
+    }
+    // safety: the buffer is contiguous and holds `len_bytes` bytes, it's only read while `buffer` holds it
+    let json = unsafe { std::slice::from_raw_parts(buffer.buf_ptr() as *const u8, buffer.len_bytes()) };
+    parse(json).map_err(|e| ValError::new(e, input))
+}
+
+/// a `str`, or a dict, list or tuple whose leaves are all `str`, as `JsonInput` so it's validated the way JSON
+/// strings are, non-`str` leaves and keys are `string_type` errors at their location
+pub fn strings_as_json<'a>(input: &'a PyAny) -> ValResult<'a, JsonInput> {
+    if let Ok(py_str) = input.downcast::<PyString>() {
+        Ok(JsonInput::String(py_str.to_str()?.to_string()))
+    } else if let Ok(dict) = input.downcast::<PyDict>() {
+        let mut object = JsonObject::with_capacity(dict.len());
+        let mut errors: Vec<ValLineError> = Vec::new();
+        for (key, value) in dict {
+            let key = match key.downcast::<PyString>() {
+                Ok(py_key) => py_key.to_str()?.to_string(),
+                Err(_) => {
+                    let err = ValLineError::new_with_loc(ErrorType::StringType, key, "[key]");
+                    errors.push(err.with_outer_location(key.as_loc_item()));
+                    continue;
+                }
+            };
+            match strings_as_json(value) {
+                Ok(value) => {
+                    object.insert(key, value);
+                }
+                Err(ValError::LineErrors(line_errors)) => {
+                    let loc_item: LocItem = key.into();
+                    errors.extend(
+                        line_errors
+                            .into_iter()
+                            .map(|err| err.with_outer_location(loc_item.clone())),
+                    );
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        match errors.is_empty() {
+            true => Ok(JsonInput::Object(object)),
+            false => Err(ValError::LineErrors(errors)),
+        }
+    } else if input.downcast::<PyList>().is_ok() || input.downcast::<PyTuple>().is_ok() {
+        let mut array = Vec::with_capacity(input.len()?);
+        let mut errors: Vec<ValLineError> = Vec::new();
+        for (index, item) in input.iter()?.enumerate() {
+            match strings_as_json(item?) {
+                Ok(item) => array.push(item),
+                Err(ValError::LineErrors(line_errors)) => {
+                    errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index.into())));
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        match errors.is_empty() {
+            true => Ok(JsonInput::Array(array)),
+            false => Err(ValError::LineErrors(errors)),
+        }
+    } else {
+        Err(ValError::new(ErrorType::StringType, input))
+    }
+}
+
 pub fn list_as_tuple(list: &PyList) -> &PyTuple {
     let py_tuple: Py<PyTuple> = unsafe {
         let ptr = list.as_ptr();
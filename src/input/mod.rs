@@ -1,7 +1,9 @@
 use std::os::raw::c_int;
 
 use pyo3::prelude::*;
+use pyo3::{ffi, AsPyPointer};
 
+mod coerce_hook;
 mod datetime;
 mod input_abstract;
 mod input_json;
@@ -9,18 +11,22 @@ mod input_python;
 mod parse_json;
 mod return_enums;
 mod shared;
+mod string_cache;
 
+pub(crate) use coerce_hook::with_coerce_hook;
 pub(crate) use datetime::{
-    pydate_as_date, pydatetime_as_datetime, pytime_as_time, pytimedelta_as_duration, EitherDate, EitherDateTime,
-    EitherTime, EitherTimedelta,
+    bytes_as_datetime, pydate_as_date, pydatetime_as_datetime, pytime_as_time, pytimedelta_as_duration, EitherDate,
+    EitherDateTime, EitherTime, EitherTimedelta, MicrosecondsPrecision, TemporalUnit,
 };
-pub(crate) use input_abstract::Input;
-pub(crate) use parse_json::{JsonInput, JsonObject, JsonType};
+pub(crate) use input_abstract::{Input, InputType};
+pub(crate) use input_python::{parse_partial_json, strings_as_json};
+pub(crate) use parse_json::{parse_json_utf8, DuplicateKeys, JsonInput, JsonObject, JsonType};
 pub(crate) use return_enums::{
     py_string_str, AttributesGenericIterator, DictGenericIterator, EitherBytes, EitherString, GenericArguments,
     GenericCollection, GenericIterator, GenericMapping, JsonArgs, JsonObjectGenericIterator, MappingGenericIterator,
-    PyArgs,
+    PrimitiveItems, PyArgs,
 };
+pub(crate) use string_cache::StringCache;
 
 // Defined here as it's not exported by pyo3
 pub fn py_error_on_minusone(py: Python<'_>, result: c_int) -> PyResult<()> {
@@ -30,3 +36,8 @@ pub fn py_error_on_minusone(py: Python<'_>, result: c_int) -> PyResult<()> {
         Err(PyErr::fetch(py))
     }
 }
+
+// also not exported by pyo3, `range` can't be subclassed so only the exact type is checked
+pub fn is_range(obj: &PyAny) -> bool {
+    unsafe { ffi::PyRange_Check(obj.as_ptr()) == 1 }
+}
@@ -1,11 +1,15 @@
+use std::cell::{Cell, RefCell};
 use std::fmt;
 
 use indexmap::IndexMap;
+use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList, PySet};
-use serde::de::{Deserialize, DeserializeSeed, Error as SerdeError, MapAccess, SeqAccess, Visitor};
+use pyo3::types::{PyDict, PyList, PyLong, PySet};
+use serde::de::value::{MapAccessDeserializer, SeqAccessDeserializer};
+use serde::de::{Deserialize, DeserializeSeed, Error as SerdeError, IntoDeserializer, MapAccess, SeqAccess, Visitor};
 
-use crate::build_tools::py_err;
+use crate::build_tools::{py_err, SchemaDict};
+use crate::errors::ErrorType;
 
 #[derive(Copy, Clone, Debug)]
 pub enum JsonType {
@@ -46,13 +50,45 @@ impl TryFrom<&PyAny> for JsonType {
     }
 }
 
-/// similar to serde `Value` but with int and float split
+/// what to do with a key which appears more than once in a JSON object, from the `json_duplicate_keys` config
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DuplicateKeys {
+    /// the last value is kept, like `json.loads`
+    #[default]
+    Last,
+    First,
+    Error,
+}
+
+impl DuplicateKeys {
+    pub fn from_config(config: Option<&PyDict>) -> PyResult<Self> {
+        let raw_mode: Option<&str> = match config {
+            Some(c) => c.get_as::<&str>(intern!(c.py(), "json_duplicate_keys"))?,
+            None => None,
+        };
+        match raw_mode {
+            None | Some("last") => Ok(Self::Last),
+            Some("first") => Ok(Self::First),
+            Some("error") => Ok(Self::Error),
+            Some(s) => py_err!(
+                "Invalid json_duplicate_keys: `{}`, expected `last`, `first` or `error`",
+                s
+            ),
+        }
+    }
+}
+
+/// similar to serde `Value` but with int and float split, `Float` is used for any number written with a fractional
+/// part or exponent, so e.g. `1` and `1.0` can be distinguished when validating
 #[derive(Clone, Debug)]
 pub enum JsonInput {
     Null,
     Bool(bool),
     Int(i64),
-    Float(f64),
+    // an int too big for an `i64`, as it's written in the JSON
+    BigInt(String),
+    // the value and the number as it's written, so e.g. decimals can be read exactly
+    Float(f64, String),
     String(String),
     Array(JsonArray),
     Object(JsonObject),
@@ -66,7 +102,8 @@ impl ToPyObject for JsonInput {
             Self::Null => py.None(),
             Self::Bool(b) => b.into_py(py),
             Self::Int(i) => i.into_py(py),
-            Self::Float(f) => f.into_py(py),
+            Self::BigInt(digits) => big_int_to_object(py, digits),
+            Self::Float(f, _) => f.into_py(py),
             Self::String(s) => s.into_py(py),
             Self::Array(v) => PyList::new(py, v.iter().map(|v| v.to_object(py))).into_py(py),
             Self::Object(o) => {
@@ -80,14 +117,454 @@ impl ToPyObject for JsonInput {
     }
 }
 
+/// `int()` of a str is limited in length, `int()` of a decimal isn't, the digits have been checked by
+/// `number_from_str` so `Decimal` accepts them
+fn big_int_to_object(py: Python, digits: &str) -> PyObject {
+    let decimal = py
+        .import(intern!(py, "decimal"))
+        .and_then(|decimal| decimal.getattr(intern!(py, "Decimal")))
+        .and_then(|decimal_class| decimal_class.call1((digits,)))
+        .unwrap();
+    py.get_type::<PyLong>().call1((decimal,)).unwrap().into_py(py)
+}
+
+/// Parse a JSON string, strings (including keys) longer than `max_str_bytes` stop parsing
+/// before they're copied into the output, arrays and objects nested more than `max_depth` deep are
+/// invalid, each value and key parsed uses one of `steps_left`,
+/// parsing stops with `ValidationBudgetExceeded` once there are none left
+pub fn parse_json_str(
+    json: &str,
+    max_str_bytes: Option<usize>,
+    duplicate_keys: DuplicateKeys,
+    max_depth: u16,
+    steps_left: &Cell<usize>,
+) -> Result<JsonInput, ErrorType> {
+    parse_json(
+        serde_json::Deserializer::from_str(json),
+        json.as_bytes(),
+        max_str_bytes,
+        duplicate_keys,
+        max_depth,
+        steps_left,
+    )
+    .map_err(|error| error.error_type)
+}
+
+/// Parse JSON bytes, see `parse_json_str`
+pub fn parse_json_bytes(
+    json: &[u8],
+    max_str_bytes: Option<usize>,
+    duplicate_keys: DuplicateKeys,
+    max_depth: u16,
+    steps_left: &Cell<usize>,
+) -> Result<JsonInput, ErrorType> {
+    parse_json(
+        serde_json::Deserializer::from_slice(json),
+        json,
+        max_str_bytes,
+        duplicate_keys,
+        max_depth,
+        steps_left,
+    )
+    .map_err(|error| error.error_type)
+}
+
+/// Parse UTF-8 encoded JSON bytes, UTF-8 is validated as strings are parsed rather than decoding the input first
+pub fn parse_json_utf8(
+    json: &[u8],
+    max_str_bytes: Option<usize>,
+    duplicate_keys: DuplicateKeys,
+    max_depth: u16,
+    steps_left: &Cell<usize>,
+) -> Result<JsonInput, ErrorType> {
+    parse_json(
+        serde_json::Deserializer::from_slice(json),
+        json,
+        max_str_bytes,
+        duplicate_keys,
+        max_depth,
+        steps_left,
+    )
+    .map_err(|error| error.error_type)
+}
+
+/// Parse JSON bytes which may have been cut off, for `allow_partial`: if `json` ends before the JSON is complete,
+/// it's completed with `complete_truncated` and parsed again. Returns the JSON and how many of its trailing arrays
+/// and objects were left open, and so may be incomplete, `0` if `json` is complete
+pub fn parse_json_partial(
+    json: &[u8],
+    max_str_bytes: Option<usize>,
+    duplicate_keys: DuplicateKeys,
+    max_depth: u16,
+    steps_left: &Cell<usize>,
+) -> Result<(JsonInput, usize), ErrorType> {
+    let steps = steps_left.get();
+    let error = match parse_json(
+        serde_json::Deserializer::from_slice(json),
+        json,
+        max_str_bytes,
+        duplicate_keys,
+        max_depth,
+        steps_left,
+    ) {
+        Ok(value) => return Ok((value, 0)),
+        Err(error) if error.eof => error,
+        Err(error) => return Err(error.error_type),
+    };
+    let (completed, open) = match complete_truncated(json) {
+        Some(completed) => completed,
+        None => return Err(error.error_type),
+    };
+    // steps used parsing the truncated JSON aren't counted twice
+    steps_left.set(steps);
+    match parse_json(
+        serde_json::Deserializer::from_slice(&completed),
+        &completed,
+        max_str_bytes,
+        duplicate_keys,
+        max_depth,
+        steps_left,
+    ) {
+        Ok(value) => Ok((value, open)),
+        // the JSON was invalid before it was cut off
+        Err(ParseError {
+            error_type: ErrorType::JsonInvalid { .. },
+            ..
+        }) => Err(error.error_type),
+        Err(completed_error) => Err(completed_error.error_type),
+    }
+}
+
+/// Complete JSON which was cut off at the end of `json`, by dropping the value which was cut off, unless it's an
+/// array or object, then closing the arrays and objects left open. Strings and numbers at the end of an array or
+/// object may be incomplete, so they're dropped too. `json` must be valid up to where it ends.
+/// Returns the completed JSON and how many arrays and objects were left open, or `None` if there's nothing left,
+/// e.g. if `json` is a single string which was cut off
+fn complete_truncated(json: &[u8]) -> Option<(Vec<u8>, usize)> {
+    struct Open {
+        is_object: bool,
+        // whether the next string in an object is a key
+        expect_key: bool,
+        // the offset after the last complete item, or after the opening bracket
+        end: usize,
+    }
+
+    fn item_complete(open: &mut [Open], end: usize) {
+        if let Some(container) = open.last_mut() {
+            container.end = end;
+        }
+    }
+
+    let mut open: Vec<Open> = Vec::new();
+    let mut index = 0;
+    while index < json.len() {
+        match json[index] {
+            bracket @ (b'{' | b'[') => {
+                index += 1;
+                open.push(Open {
+                    is_object: bracket == b'{',
+                    expect_key: bracket == b'{',
+                    end: index,
+                });
+            }
+            b'}' | b']' => {
+                index += 1;
+                open.pop();
+                item_complete(&mut open, index);
+            }
+            b':' => {
+                index += 1;
+                if let Some(container) = open.last_mut() {
+                    container.expect_key = false;
+                }
+            }
+            b',' => {
+                index += 1;
+                if let Some(container) = open.last_mut() {
+                    container.expect_key = container.is_object;
+                }
+            }
+            b'"' => {
+                index += 1;
+                loop {
+                    match json.get(index) {
+                        Some(b'"') => break,
+                        Some(b'\\') => index += 2,
+                        Some(_) => index += 1,
+                        None => break,
+                    }
+                }
+                if index >= json.len() {
+                    // the string was cut off
+                    break;
+                }
+                index += 1;
+                match open.last() {
+                    Some(container) if container.expect_key => (),
+                    _ => item_complete(&mut open, index),
+                }
+            }
+            b' ' | b'\t' | b'\n' | b'\r' => index += 1,
+            _ => {
+                // numbers, `true`, `false` and `null`, a number which ends at the end of `json` may be incomplete
+                let start = index;
+                while index < json.len() && !b"{}[]:,\" \t\n\r".contains(&json[index]) {
+                    index += 1;
+                }
+                if index >= json.len() && !matches!(&json[start..], b"true" | b"false" | b"null") {
+                    break;
+                }
+                item_complete(&mut open, index);
+            }
+        }
+    }
+    let end = open.last()?.end;
+    let mut completed = json[..end].to_vec();
+    completed.extend(open.iter().rev().map(|container| match container.is_object {
+        true => b'}',
+        false => b']',
+    }));
+    Some((completed, open.len()))
+}
+
+/// why parsing failed, and whether it failed because `json` ended
+struct ParseError {
+    error_type: ErrorType,
+    eof: bool,
+}
+
+fn parse_json<'de, R: serde_json::de::Read<'de>>(
+    mut deserializer: serde_json::Deserializer<R>,
+    json: &[u8],
+    max_str_bytes: Option<usize>,
+    duplicate_keys: DuplicateKeys,
+    max_depth: u16,
+    steps_left: &Cell<usize>,
+) -> Result<JsonInput, ParseError> {
+    let str_too_long = Cell::new(false);
+    let budget_exceeded = Cell::new(false);
+    let duplicate_key = RefCell::new(None);
+    let seed = JsonSeed {
+        max_str_bytes,
+        str_too_long: &str_too_long,
+        duplicate_keys,
+        duplicate_key: &duplicate_key,
+        depth_left: max_depth,
+        steps_left,
+        budget_exceeded: &budget_exceeded,
+    };
+    // `JsonSeed` limits nesting to `max_depth` instead
+    deserializer.disable_recursion_limit();
+    match seed.deserialize(&mut deserializer).and_then(|value| {
+        deserializer.end()?;
+        Ok(value)
+    }) {
+        Ok(value) => Ok(value),
+        Err(_) if budget_exceeded.get() => Err(ParseError {
+            error_type: ErrorType::ValidationBudgetExceeded,
+            eof: false,
+        }),
+        // this is only reachable if `max_str_bytes` is set
+        Err(error) if str_too_long.get() => Err(ParseError {
+            error_type: ErrorType::JsonStringTooLong {
+                max_length: max_str_bytes.unwrap_or_default(),
+                position: string_start(json, error.line(), error.column()),
+            },
+            eof: false,
+        }),
+        // this is only reachable if `duplicate_keys` is `Error`
+        Err(error) if duplicate_key.borrow().is_some() => Err(ParseError {
+            error_type: ErrorType::JsonDuplicateKey {
+                key: duplicate_key.take().unwrap_or_default(),
+                position: string_start(json, error.line(), error.column()),
+            },
+            eof: false,
+        }),
+        Err(error) => {
+            let message = error.to_string();
+            // serde reports line 0 for errors which aren't about the input, e.g. from `Deserialize` impls
+            let (position, line, column) = match error.line() {
+                0 => (None, None, None),
+                _ if error.is_eof() => (Some(json.len()), Some(error.line()), Some(error.column())),
+                line => (
+                    Some(byte_offset(json, line, error.column())),
+                    Some(line),
+                    Some(error.column()),
+                ),
+            };
+            let error_type = ErrorType::JsonInvalid {
+                expected: expected(&message),
+                error: message,
+                position,
+                line,
+                column,
+            };
+            Err(ParseError {
+                error_type,
+                eof: error.is_eof(),
+            })
+        }
+    }
+}
+
+/// What the parser expected where it failed, from serde's `message`, `None` if the input was wrong rather than
+/// missing something, e.g. an invalid escape or number
+fn expected(message: &str) -> Option<String> {
+    let code = match message.rsplit_once(" at line ") {
+        Some((code, _)) => code,
+        None => message,
+    };
+    let expected = match code {
+        code if code.starts_with("expected ") => code,
+        "EOF while parsing a value" | "trailing comma" => "expected value",
+        "EOF while parsing a list" => "expected the rest of the array",
+        "EOF while parsing an object" => "expected the rest of the object",
+        "EOF while parsing a string" | "key must be a string" => "expected `\"`",
+        "trailing characters" => "expected end of input",
+        _ => return None,
+    };
+    Some(expected.to_string())
+}
+
+/// Find the byte offset of the start of `line`, serde's lines start at 1
+fn line_start(json: &[u8], line: usize) -> usize {
+    match line {
+        0 | 1 => 0,
+        _ => json
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| **b == b'\n')
+            .nth(line - 2)
+            .map_or(0, |(index, _)| index + 1),
+    }
+}
+
+/// Find the byte offset of `line` and `column`, serde's columns count bytes from the start of the line and
+/// point at the last byte read, invalid UTF-8 is only reported once the string containing it has been read
+/// so the offset of the invalid byte is found by checking the input up to that point
+fn byte_offset(json: &[u8], line: usize, column: usize) -> usize {
+    let offset = (line_start(json, line) + column).saturating_sub(1).min(json.len());
+    match std::str::from_utf8(&json[..offset]) {
+        Err(error) if error.error_len().is_some() => error.valid_up_to(),
+        _ => offset,
+    }
+}
+
+/// Find the byte offset of the opening quote of the string which ends just before `line` and `column`,
+/// serde's columns count bytes from the start of the line
+fn string_start(json: &[u8], line: usize, column: usize) -> usize {
+    // `end` is the index of the closing quote
+    let mut end = (line_start(json, line) + column).saturating_sub(1);
+    while let Some(index) = json[..end].iter().rposition(|b| *b == b'"') {
+        let backslashes = json[..index].iter().rev().take_while(|b| **b == b'\\').count();
+        if backslashes % 2 == 0 {
+            return index;
+        }
+        end = index;
+    }
+    0
+}
+
 impl<'de> Deserialize<'de> for JsonInput {
     fn deserialize<D>(deserializer: D) -> Result<JsonInput, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        struct JsonVisitor;
+        let str_too_long = Cell::new(false);
+        let duplicate_key = RefCell::new(None);
+        let steps_left = Cell::new(usize::MAX);
+        let budget_exceeded = Cell::new(false);
+        let seed = JsonSeed {
+            max_str_bytes: None,
+            str_too_long: &str_too_long,
+            duplicate_keys: DuplicateKeys::Last,
+            duplicate_key: &duplicate_key,
+            // the deserializer limits nesting itself unless its recursion limit is disabled
+            depth_left: u16::MAX,
+            steps_left: &steps_left,
+            budget_exceeded: &budget_exceeded,
+        };
+        seed.deserialize(deserializer)
+    }
+}
+
+/// Deserializes a `JsonInput`, keeping track of whether parsing was stopped because a string was too long,
+/// a key was repeated or the step budget was used up
+#[derive(Clone, Copy)]
+struct JsonSeed<'c> {
+    max_str_bytes: Option<usize>,
+    str_too_long: &'c Cell<bool>,
+    duplicate_keys: DuplicateKeys,
+    duplicate_key: &'c RefCell<Option<String>>,
+    // how many more arrays and objects can be nested inside the value being deserialized
+    depth_left: u16,
+    steps_left: &'c Cell<usize>,
+    budget_exceeded: &'c Cell<bool>,
+}
+
+impl<'c> JsonSeed<'c> {
+    fn check_str<E: SerdeError>(&self, value: &str) -> Result<(), E> {
+        match self.max_str_bytes {
+            Some(max_str_bytes) if value.len() > max_str_bytes => {
+                self.str_too_long.set(true);
+                Err(E::custom(format!("string longer than {max_str_bytes} bytes")))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Whether the value for `key` should be inserted into `values`, keys are compared after unescaping
+    fn keep_value<E: SerdeError>(&self, values: &JsonObject, key: &str) -> Result<bool, E> {
+        if !values.contains_key(key) {
+            return Ok(true);
+        }
+        match self.duplicate_keys {
+            DuplicateKeys::Last => Ok(true),
+            DuplicateKeys::First => Ok(false),
+            DuplicateKeys::Error => {
+                *self.duplicate_key.borrow_mut() = Some(key.to_string());
+                Err(E::custom(format!("duplicate key `{key}`")))
+            }
+        }
+    }
+
+    /// The seed for the items of an array or object, reported like serde's own recursion limit when nesting is
+    /// deeper than `max_depth`
+    fn nested<E: SerdeError>(&self) -> Result<Self, E> {
+        match self.depth_left {
+            0 => Err(E::custom("recursion limit exceeded")),
+            depth_left => Ok(Self {
+                depth_left: depth_left - 1,
+                ..*self
+            }),
+        }
+    }
+
+    fn step<E: SerdeError>(&self) -> Result<(), E> {
+        match self.steps_left.get() {
+            0 => {
+                self.budget_exceeded.set(true);
+                Err(E::custom("validation budget exceeded"))
+            }
+            steps_left => {
+                self.steps_left.set(steps_left - 1);
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<'de, 'c> DeserializeSeed<'de> for JsonSeed<'c> {
+    type Value = JsonInput;
 
-        impl<'de> Visitor<'de> for JsonVisitor {
+    fn deserialize<D>(self, deserializer: D) -> Result<JsonInput, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        self.step()?;
+        struct JsonVisitor<'c>(JsonSeed<'c>);
+
+        impl<'de, 'c> Visitor<'de> for JsonVisitor<'c> {
             type Value = JsonInput;
 
             #[cfg_attr(has_no_coverage, no_coverage)]
@@ -104,17 +581,23 @@ impl<'de> Deserialize<'de> for JsonInput {
             }
 
             fn visit_u64<E>(self, value: u64) -> Result<JsonInput, E> {
-                Ok(JsonInput::Int(value as i64))
+                // values too big for an i64 would wrap around to negative numbers
+                match i64::try_from(value) {
+                    Ok(int) => Ok(JsonInput::Int(int)),
+                    Err(_) => Ok(JsonInput::BigInt(value.to_string())),
+                }
             }
 
-            fn visit_f64<E>(self, value: f64) -> Result<JsonInput, E> {
-                Ok(JsonInput::Float(value))
+            fn visit_f64<E>(self, _: f64) -> Result<JsonInput, E> {
+                // with `arbitrary_precision` floats are passed to `visit_map` as they're written
+                unreachable!()
             }
 
             fn visit_str<E>(self, value: &str) -> Result<JsonInput, E>
             where
                 E: SerdeError,
             {
+                self.0.check_str(value)?;
                 Ok(JsonInput::String(value.to_string()))
             }
 
@@ -144,9 +627,10 @@ impl<'de> Deserialize<'de> for JsonInput {
             where
                 V: SeqAccess<'de>,
             {
+                let seed = self.0.nested()?;
                 let mut vec = Vec::new();
 
-                while let Some(elem) = visitor.next_element()? {
+                while let Some(elem) = visitor.next_element_seed(seed)? {
                     vec.push(elem);
                 }
 
@@ -157,39 +641,195 @@ impl<'de> Deserialize<'de> for JsonInput {
             where
                 V: MapAccess<'de>,
             {
-                match visitor.next_key_seed(KeyDeserializer)? {
-                    Some(first_key) => {
-                        let mut values = IndexMap::new();
-
-                        values.insert(first_key, visitor.next_value()?);
-                        while let Some((key, value)) = visitor.next_entry()? {
-                            values.insert(key, value);
+                let mut values = IndexMap::new();
+                let first_key = match visitor.next_key_seed(FirstKeyDeserializer(self.0))? {
+                    Some(FirstKey::Number) => match visitor.next_value_seed(NumberSeed(self.0))? {
+                        NumberOrValue::Number(number) => return Ok(number),
+                        // an object which happens to use the same key, its first value has been parsed already
+                        NumberOrValue::Value(value) => {
+                            self.0.step()?;
+                            self.0.check_str(NUMBER_TOKEN)?;
+                            values.insert(NUMBER_TOKEN.to_string(), value);
+                            visitor.next_key_seed(KeyDeserializer(self.0.nested()?))?
                         }
-                        Ok(JsonInput::Object(values))
+                    },
+                    Some(FirstKey::Key(key)) => Some(key),
+                    None => None,
+                };
+                let seed = self.0.nested()?;
+                let key_seed = KeyDeserializer(seed);
+
+                let mut next_key = first_key;
+                while let Some(key) = next_key {
+                    // the check happens before the value is parsed, so errors point at the key
+                    let keep = seed.keep_value(&values, &key)?;
+                    let value = visitor.next_value_seed(seed)?;
+                    if keep {
+                        values.insert(key, value);
                     }
-                    None => Ok(JsonInput::Object(IndexMap::new())),
+                    next_key = visitor.next_key_seed(key_seed)?;
                 }
+                Ok(JsonInput::Object(values))
             }
         }
 
-        deserializer.deserialize_any(JsonVisitor)
+        deserializer.deserialize_any(JsonVisitor(self))
+    }
+}
+
+/// with serde_json's `arbitrary_precision` feature, numbers which aren't an `i64` or `u64` are passed to
+/// `visit_map` as a map with this key and the number as it's written as the value, a JSON object can have the
+/// same key, so the value decides which it is, see `NumberSeed`
+const NUMBER_TOKEN: &str = "$serde_json::private::Number";
+
+/// the text of a number passed as a map by serde_json, read through `serde_json::Number` so anything which isn't
+/// a JSON number is invalid rather than reaching `big_int_to_object` or `Decimal`
+fn number_from_str<E: SerdeError>(number: String) -> Result<JsonInput, E> {
+    if number.parse::<serde_json::Number>().is_err() {
+        return Err(E::custom("invalid number"));
+    }
+    if !number.contains(['.', 'e', 'E']) {
+        return Ok(JsonInput::BigInt(number));
+    }
+    // numbers too big for a float are infinite, they're still valid decimals
+    match number.parse::<f64>() {
+        Ok(float) => Ok(JsonInput::Float(float, number)),
+        Err(_) => Err(E::custom("invalid number")),
+    }
+}
+
+enum NumberOrValue {
+    Number(JsonInput),
+    Value(JsonInput),
+}
+
+/// The value after a `NUMBER_TOKEN` key: serde_json gives a number's text as an owned `String`, which it never
+/// does for the strings in the JSON, any other value is the value of an object's key
+#[derive(Clone, Copy)]
+struct NumberSeed<'c>(JsonSeed<'c>);
+
+impl<'c> NumberSeed<'c> {
+    /// the value of the object's key, parsed like any other value of the object
+    fn value<'de, D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<NumberOrValue, D::Error> {
+        self.0.nested()?.deserialize(deserializer).map(NumberOrValue::Value)
+    }
+}
+
+impl<'de, 'c> DeserializeSeed<'de> for NumberSeed<'c> {
+    type Value = NumberOrValue;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de, 'c> Visitor<'de> for NumberSeed<'c> {
+    type Value = NumberOrValue;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("any valid JSON value")
+    }
+
+    fn visit_string<E: SerdeError>(self, number: String) -> Result<Self::Value, E> {
+        number_from_str(number).map(NumberOrValue::Number)
+    }
+
+    fn visit_bool<E: SerdeError>(self, value: bool) -> Result<Self::Value, E> {
+        self.value(value.into_deserializer())
+    }
+
+    fn visit_i64<E: SerdeError>(self, value: i64) -> Result<Self::Value, E> {
+        self.value(value.into_deserializer())
+    }
+
+    fn visit_u64<E: SerdeError>(self, value: u64) -> Result<Self::Value, E> {
+        self.value(value.into_deserializer())
+    }
+
+    fn visit_str<E: SerdeError>(self, value: &str) -> Result<Self::Value, E> {
+        self.value(value.into_deserializer())
+    }
+
+    fn visit_unit<E: SerdeError>(self) -> Result<Self::Value, E> {
+        self.value(().into_deserializer())
+    }
+
+    fn visit_seq<V: SeqAccess<'de>>(self, seq: V) -> Result<Self::Value, V::Error> {
+        self.value(SeqAccessDeserializer::new(seq))
+    }
+
+    fn visit_map<V: MapAccess<'de>>(self, map: V) -> Result<Self::Value, V::Error> {
+        self.value(MapAccessDeserializer::new(map))
+    }
+}
+
+enum FirstKey {
+    Number,
+    Key(String),
+}
+
+/// The first key of a map, which is `NUMBER_TOKEN` if the map is really a number
+#[derive(Clone, Copy)]
+struct FirstKeyDeserializer<'c>(JsonSeed<'c>);
+
+impl<'de, 'c> DeserializeSeed<'de> for FirstKeyDeserializer<'c> {
+    type Value = FirstKey;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'de, 'c> Visitor<'de> for FirstKeyDeserializer<'c> {
+    type Value = FirstKey;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a string key")
+    }
+
+    fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        if s == NUMBER_TOKEN {
+            return Ok(FirstKey::Number);
+        }
+        // the key of an actual map, checked the way `KeyDeserializer` checks keys
+        self.0.step()?;
+        self.0.check_str(s)?;
+        Ok(FirstKey::Key(s.to_string()))
+    }
+
+    fn visit_string<E>(self, _: String) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        unreachable!()
     }
 }
 
-struct KeyDeserializer;
+#[derive(Clone, Copy)]
+struct KeyDeserializer<'c>(JsonSeed<'c>);
 
-impl<'de> DeserializeSeed<'de> for KeyDeserializer {
+impl<'de, 'c> DeserializeSeed<'de> for KeyDeserializer<'c> {
     type Value = String;
 
     fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
+        self.0.step()?;
         deserializer.deserialize_str(self)
     }
 }
 
-impl<'de> Visitor<'de> for KeyDeserializer {
+impl<'de, 'c> Visitor<'de> for KeyDeserializer<'c> {
     type Value = String;
 
     #[cfg_attr(has_no_coverage, no_coverage)]
@@ -201,6 +841,7 @@ impl<'de> Visitor<'de> for KeyDeserializer {
     where
         E: serde::de::Error,
     {
+        self.0.check_str(s)?;
         Ok(s.to_string())
     }
 
@@ -3,11 +3,10 @@ use std::borrow::Cow;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::iter::PyDictIterator;
-use pyo3::types::{PyBytes, PyDict, PyFrozenSet, PyIterator, PyList, PyMapping, PySet, PyString, PyTuple};
+use pyo3::types::{PyByteArray, PyBytes, PyDict, PyFrozenSet, PyIterator, PyList, PyMapping, PySet, PyString, PyTuple};
 
 #[cfg(not(PyPy))]
 use pyo3::types::PyFunction;
-#[cfg(not(PyPy))]
 use pyo3::PyTypeInfo;
 
 use indexmap::map::Iter;
@@ -17,7 +16,7 @@ use crate::recursion_guard::RecursionGuard;
 use crate::validators::{CombinedValidator, Extra, Validator};
 
 use super::parse_json::{JsonArray, JsonInput, JsonObject};
-use super::Input;
+use super::{is_range, Input, StringCache};
 
 /// Container for all the collections (sized iterable containers) types, which
 /// can mostly be converted to each other in lax mode.
@@ -49,10 +48,25 @@ derive_from!(GenericCollection, PyAny, PyAny);
 derive_from!(GenericCollection, JsonArray, JsonArray);
 derive_from!(GenericCollection, JsonArray, [JsonInput]);
 
+/// A bare `int`, `float`, `str` or `bool` item validator, items which already have exactly its output type would be
+/// returned unchanged by it, so they're taken as they are with `Input::exact_primitive` rather than validated
+#[derive(Debug, Clone, Copy)]
+pub enum PrimitiveItems {
+    Int,
+    Float { allow_inf_nan: bool },
+    Str,
+    Bool,
+}
+
+/// with `primitive`, only items which `exact_primitive` doesn't accept are validated, so errors and their locations
+/// are the same as validating every item
+#[allow(clippy::too_many_arguments)]
 fn validate_iter_to_vec<'a, 's>(
     py: Python<'a>,
     iter: impl Iterator<Item = &'a (impl Input<'a> + 'a)>,
     capacity: usize,
+    fail_fast: bool,
+    primitive: Option<PrimitiveItems>,
     validator: &'s CombinedValidator,
     extra: &Extra,
     slots: &'a [CombinedValidator],
@@ -60,11 +74,39 @@ fn validate_iter_to_vec<'a, 's>(
 ) -> ValResult<'a, Vec<PyObject>> {
     let mut output: Vec<PyObject> = Vec::with_capacity(capacity);
     let mut errors: Vec<ValLineError> = Vec::new();
-    for (index, item) in iter.enumerate() {
-        match validator.validate(py, item, extra, slots, recursion_guard) {
+    let mut iter = iter.enumerate().peekable();
+    while let Some((index, item)) = iter.next() {
+        if !recursion_guard.step() {
+            errors.push(ValLineError::new_with_loc(
+                ErrorType::ValidationBudgetExceeded,
+                item,
+                index,
+            ));
+            return Err(ValError::LineErrors(errors));
+        }
+        let _loc = extra.coercion_loc(|| [index.into()]);
+        extra.report_raw_input(py, item);
+        if let Some(output_item) = primitive.and_then(|p| item.exact_primitive(py, p, extra.string_cache)) {
+            output.push(output_item);
+            continue;
+        }
+        let partial_extra;
+        let item_extra = match extra.is_partial() {
+            true => {
+                partial_extra = extra.partial_item(iter.peek().is_none());
+                &partial_extra
+            }
+            false => extra,
+        };
+        let mark = extra.error_mark();
+        match validator.validate(py, item, item_extra, slots, recursion_guard) {
             Ok(item) => output.push(item),
             Err(ValError::LineErrors(line_errors)) => {
+                let stop = extra.stop_collecting(mark, line_errors.len(), || iter.peek().is_some());
                 errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index.into())));
+                if fail_fast || stop {
+                    return Err(ValError::LineErrors(errors));
+                }
             }
             Err(ValError::Omit) => (),
             Err(err) => return Err(err),
@@ -111,6 +153,16 @@ macro_rules! generator_too_long {
 static DEFAULT_CAPACITY: usize = 10;
 
 impl<'a> GenericCollection<'a> {
+    /// subclasses of list and tuple are iterated with the iterator protocol so overridden methods like `__iter__`
+    /// are used, unless `force_exact` is set, then they're read directly like exact lists and tuples
+    pub fn with_subclass_path(self, force_exact: bool) -> Self {
+        match self {
+            Self::List(list) if !force_exact && !PyList::is_exact_type_of(list) => Self::PyAny(list),
+            Self::Tuple(tuple) if !force_exact && !PyTuple::is_exact_type_of(tuple) => Self::PyAny(tuple),
+            _ => self,
+        }
+    }
+
     pub fn generic_len(&self) -> PyResult<usize> {
         match self {
             Self::List(v) => Ok(v.len()),
@@ -122,6 +174,34 @@ impl<'a> GenericCollection<'a> {
         }
     }
 
+    /// the length of a range is known without iterating it, so a range longer than `max_length` is rejected
+    /// before any of it is validated or copied
+    pub fn check_range_length(
+        &self,
+        input: &'a impl Input<'a>,
+        max_length: Option<usize>,
+        field_type: &str,
+    ) -> ValResult<'a, ()> {
+        if let (Self::PyAny(collection), Some(max_length)) = (self, max_length) {
+            if is_range(collection) {
+                let actual_length = collection.len()?;
+                if actual_length > max_length {
+                    return Err(ValError::new(
+                        ErrorType::TooLong {
+                            field_type: field_type.to_string(),
+                            max_length,
+                            actual_length,
+                        },
+                        input,
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// with `fail_fast`, iteration stops at the first item which fails and only its errors are returned,
+    /// `primitive` is ignored when coercions or exactness are being reported since they're reported by the validator
     #[allow(clippy::too_many_arguments)]
     pub fn validate_to_vec<'s>(
         &'s self,
@@ -130,11 +210,15 @@ impl<'a> GenericCollection<'a> {
         max_length: Option<usize>,
         field_type: &'static str,
         generator_max_length: Option<usize>,
+        fail_fast: bool,
+        primitive: Option<PrimitiveItems>,
         validator: &'s CombinedValidator,
         extra: &Extra,
         slots: &'a [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'a, Vec<PyObject>> {
+        self.check_range_length(input, max_length, field_type)?;
+        let primitive = primitive.filter(|_| extra.coercions.is_none() && extra.exactness.is_none());
         let capacity = self
             .generic_len()
             .unwrap_or_else(|_| max_length.unwrap_or(DEFAULT_CAPACITY));
@@ -143,6 +227,8 @@ impl<'a> GenericCollection<'a> {
                 py,
                 collection.iter(),
                 capacity,
+                fail_fast,
+                primitive,
                 validator,
                 extra,
                 slots,
@@ -152,6 +238,8 @@ impl<'a> GenericCollection<'a> {
                 py,
                 collection.iter(),
                 capacity,
+                fail_fast,
+                primitive,
                 validator,
                 extra,
                 slots,
@@ -161,6 +249,8 @@ impl<'a> GenericCollection<'a> {
                 py,
                 collection.iter(),
                 capacity,
+                fail_fast,
+                primitive,
                 validator,
                 extra,
                 slots,
@@ -170,24 +260,41 @@ impl<'a> GenericCollection<'a> {
                 py,
                 collection.iter(),
                 capacity,
+                fail_fast,
+                primitive,
                 validator,
                 extra,
                 slots,
                 recursion_guard,
             ),
             Self::PyAny(collection) => {
-                let iter = collection.iter()?;
+                let mut iter = collection.iter()?.enumerate().peekable();
                 let mut output: Vec<PyObject> = Vec::with_capacity(capacity);
                 let mut errors: Vec<ValLineError> = Vec::new();
-                for (index, item_result) in iter.enumerate() {
+                while let Some((index, item_result)) = iter.next() {
                     let item = item_result.map_err(|e| any_next_error!(collection.py(), e, input, index))?;
+                    if !recursion_guard.step() {
+                        errors.push(ValLineError::new_with_loc(
+                            ErrorType::ValidationBudgetExceeded,
+                            item,
+                            index,
+                        ));
+                        return Err(ValError::LineErrors(errors));
+                    }
+                    let _loc = extra.coercion_loc(|| [index.into()]);
+                    extra.report_raw_input(py, item);
+                    let mark = extra.error_mark();
                     match validator.validate(py, item, extra, slots, recursion_guard) {
                         Ok(item) => {
                             generator_too_long!(input, index, generator_max_length, field_type);
                             output.push(item);
                         }
                         Err(ValError::LineErrors(line_errors)) => {
+                            let stop = extra.stop_collecting(mark, line_errors.len(), || iter.peek().is_some());
                             errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index.into())));
+                            if fail_fast || stop {
+                                return Err(ValError::LineErrors(errors));
+                            }
                         }
                         Err(ValError::Omit) => (),
                         Err(err) => return Err(err),
@@ -205,6 +312,8 @@ impl<'a> GenericCollection<'a> {
                 py,
                 collection.iter(),
                 capacity,
+                fail_fast,
+                primitive,
                 validator,
                 extra,
                 slots,
@@ -213,13 +322,18 @@ impl<'a> GenericCollection<'a> {
         }
     }
 
+    /// strings in JSON arrays are looked up in `string_cache` if it's set
+    #[allow(clippy::too_many_arguments)]
     pub fn to_vec<'s>(
         &'s self,
         py: Python<'a>,
         input: &'a impl Input<'a>,
+        max_length: Option<usize>,
         field_type: &'static str,
         generator_max_length: Option<usize>,
+        string_cache: Option<&StringCache>,
     ) -> ValResult<'a, Vec<PyObject>> {
+        self.check_range_length(input, max_length, field_type)?;
         match self {
             Self::List(collection) => Ok(collection.iter().map(|i| i.to_object(py)).collect()),
             Self::Tuple(collection) => Ok(collection.iter().map(|i| i.to_object(py)).collect()),
@@ -234,7 +348,13 @@ impl<'a> GenericCollection<'a> {
                     Ok(item.to_object(py))
                 })
                 .collect(),
-            Self::JsonArray(collection) => Ok(collection.iter().map(|i| i.to_object(py)).collect()),
+            Self::JsonArray(collection) => match string_cache {
+                Some(string_cache) => Ok(collection
+                    .iter()
+                    .map(|i| i.to_object_cached(py, string_cache))
+                    .collect()),
+                None => Ok(collection.iter().map(|i| i.to_object(py)).collect()),
+            },
         }
     }
 }
@@ -252,6 +372,26 @@ derive_from!(GenericMapping, PyMapping, PyMapping);
 derive_from!(GenericMapping, PyGetAttr, PyAny, None);
 derive_from!(GenericMapping, JsonObject, JsonObject);
 
+impl<'a> GenericMapping<'a> {
+    /// subclasses of dict are read with the mapping protocol so overridden methods like `__getitem__` and `items`
+    /// are used, unless `force_exact` is set, then they're read directly like exact dicts
+    pub fn with_subclass_path(self, force_exact: bool) -> Self {
+        match self {
+            Self::PyDict(dict) if !force_exact && !PyDict::is_exact_type_of(dict) => Self::PyMapping(dict.as_mapping()),
+            _ => self,
+        }
+    }
+
+    /// the last key of a dict or JSON object, if it's a string, with `allow_partial` only its value may be incomplete
+    pub fn last_key(&self) -> Option<&'a str> {
+        match self {
+            Self::PyDict(dict) => dict.iter().last().and_then(|(key, _)| key.extract().ok()),
+            Self::JsonObject(object) => object.keys().last().map(String::as_str),
+            _ => None,
+        }
+    }
+}
+
 pub struct DictGenericIterator<'py> {
     dict_iter: PyDictIterator<'py>,
 }
@@ -289,8 +429,10 @@ impl<'py> MappingGenericIterator<'py> {
     pub fn new(mapping: &'py PyMapping) -> ValResult<'py, Self> {
         let py = mapping.py();
         let input: &PyAny = mapping;
+        // `items()` is iterated directly rather than with `PyMapping::items` which copies it to a list first,
+        // so large or endless mappings can be read lazily
         let iter = mapping
-            .items()
+            .call_method0(intern!(py, "items"))
             .map_err(|e| mapping_err(e, py, input))?
             .iter()
             .map_err(|e| mapping_err(e, py, input))?;
@@ -355,6 +497,15 @@ impl<'py> AttributesGenericIterator<'py> {
             index: 0,
         })
     }
+
+    /// iterate over `attributes` instead of `dir(py_any)`
+    pub fn with_attributes(py_any: &'py PyAny, attributes: &'py PyList) -> Self {
+        Self {
+            object: py_any,
+            attributes,
+            index: 0,
+        }
+    }
 }
 
 impl<'py> Iterator for AttributesGenericIterator<'py> {
@@ -631,6 +782,15 @@ impl<'a> EitherBytes<'a> {
     }
 }
 
+impl<'a> EitherBytes<'a> {
+    pub fn into_py_bytearray(self, py: Python) -> PyObject {
+        match self {
+            EitherBytes::Cow(bytes) => PyByteArray::new(py, &bytes).into_py(py),
+            EitherBytes::Py(py_bytes) => PyByteArray::new(py, py_bytes.as_bytes()).into_py(py),
+        }
+    }
+}
+
 impl<'a> IntoPy<PyObject> for EitherBytes<'a> {
     fn into_py(self, py: Python<'_>) -> PyObject {
         match self {
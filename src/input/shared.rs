@@ -2,15 +2,6 @@ use crate::errors::{ErrorType, ValError, ValResult};
 
 use super::Input;
 
-pub fn map_json_err<'a>(input: &'a impl Input<'a>, error: serde_json::Error) -> ValError<'a> {
-    ValError::new(
-        ErrorType::JsonInvalid {
-            error: error.to_string(),
-        },
-        input,
-    )
-}
-
 pub fn str_as_bool<'a>(input: &'a impl Input<'a>, str: &str) -> ValResult<'a, bool> {
     if str == "0"
         || str.eq_ignore_ascii_case("f")
@@ -58,6 +49,9 @@ pub fn float_as_int<'a>(input: &'a impl Input<'a>, float: f64) -> ValResult<'a,
         Err(ValError::new(ErrorType::FiniteNumber, input))
     } else if float % 1.0 != 0.0 {
         Err(ValError::new(ErrorType::IntFromFloat, input))
+    } else if !(-9_223_372_036_854_775_808.0..9_223_372_036_854_775_808.0).contains(&float) {
+        // `as` would saturate floats outside the range of an i64
+        Err(ValError::new(ErrorType::IntParsingSize, input))
     } else {
         Ok(float as i64)
     }
@@ -0,0 +1,92 @@
+use std::sync::Mutex;
+
+use ahash::RandomState;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString};
+
+use crate::build_tools::{py_err, SchemaDict};
+
+// strings longer than this are rarely repeated, so they're never cached
+const MAX_CACHED_BYTES: usize = 64;
+const CACHE_SLOTS: usize = 16_384;
+
+/// which strings made from JSON are looked up in the cache, from the `cache_strings` config
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CacheStrings {
+    All,
+    Keys,
+    None,
+}
+
+/// Reuses the python strings made for short JSON strings which are repeated, e.g. the keys of a list of objects.
+/// There are a fixed number of slots, each holding the last string whose hash picked it, so the cache never grows
+/// past `CACHE_SLOTS` strings, the slots are only allocated once a string is cached.
+#[derive(Debug)]
+pub struct StringCache {
+    mode: CacheStrings,
+    hasher: RandomState,
+    // only locked with the GIL held, so the lock is never contended
+    slots: Mutex<Vec<Option<Py<PyString>>>>,
+}
+
+impl Clone for StringCache {
+    // copies of a validator start with an empty cache
+    fn clone(&self) -> Self {
+        Self {
+            mode: self.mode,
+            hasher: RandomState::new(),
+            slots: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+impl StringCache {
+    pub fn from_config(config: Option<&PyDict>) -> PyResult<Self> {
+        let raw_mode: Option<&str> = match config {
+            Some(c) => c.get_as::<&str>(intern!(c.py(), "cache_strings"))?,
+            None => None,
+        };
+        let mode = match raw_mode {
+            None | Some("all") => CacheStrings::All,
+            Some("keys") => CacheStrings::Keys,
+            Some("none") => CacheStrings::None,
+            Some(s) => return py_err!("Invalid cache_strings: `{}`, expected `all`, `keys` or `none`", s),
+        };
+        Ok(Self {
+            mode,
+            hasher: RandomState::new(),
+            slots: Mutex::new(Vec::new()),
+        })
+    }
+
+    /// `s` as a python string, reusing the cached one if `s` has been seen before, `is_key` for JSON object keys
+    pub fn get<'py>(&self, py: Python<'py>, s: &str, is_key: bool) -> &'py PyString {
+        let cached = match self.mode {
+            CacheStrings::All => true,
+            CacheStrings::Keys => is_key,
+            CacheStrings::None => false,
+        };
+        if !cached || s.len() > MAX_CACHED_BYTES {
+            return PyString::new(py, s);
+        }
+
+        let index = self.hasher.hash_one(s) as usize % CACHE_SLOTS;
+
+        let mut slots = match self.slots.try_lock() {
+            Ok(slots) => slots,
+            Err(_) => return PyString::new(py, s),
+        };
+        if slots.is_empty() {
+            slots.resize_with(CACHE_SLOTS, || None);
+        }
+        if let Some(ref py_string) = slots[index] {
+            if py_string.as_ref(py).to_str().is_ok_and(|cached| cached == s) {
+                return py_string.clone_ref(py).into_ref(py);
+            }
+        }
+        let py_string = PyString::new(py, s);
+        slots[index] = Some(py_string.into_py(py));
+        py_string
+    }
+}
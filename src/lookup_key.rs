@@ -1,13 +1,18 @@
 use core::slice::Iter;
+use std::borrow::Cow;
 use std::fmt;
 
+use ahash::{AHashMap, AHashSet};
 use pyo3::exceptions::{PyAttributeError, PyTypeError};
+use pyo3::once_cell::GILOnceCell;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList, PyMapping, PyString};
+use pyo3::types::{PyDict, PyList, PyMapping, PyString, PyTuple, PyType};
+use pyo3::{ffi, intern, AsPyPointer, PyTypeInfo};
 
-use crate::build_tools::py_err;
-use crate::errors::{ErrorType, ValLineError};
-use crate::input::{Input, JsonInput, JsonObject};
+use crate::build_tools::{py_err, SchemaDict};
+use crate::errors::ValResult;
+use crate::errors::{ErrorType, LocItem, ValLineError};
+use crate::input::{AttributesGenericIterator, Input, JsonInput, JsonObject};
 
 /// Used got getting items from python dicts, python objects, or JSON objects, in different ways
 #[derive(Debug, Clone)]
@@ -75,11 +80,19 @@ impl LookupKey {
                 Ok(v) => v,
                 Err(_) => return py_err!("Lookup paths should have at least one element"),
             };
-            let mut locs: Vec<LookupPath> = if first.downcast::<PyString>().is_ok() {
-                // list of strings rather than list of lists
+            let is_path =
+                first.downcast::<PyString>().is_ok() && !list.iter().any(|item| item.downcast::<PyList>().is_ok());
+            let mut locs: Vec<LookupPath> = if is_path {
+                // list of strings and ints rather than a list of alternatives
                 vec![LookupPath::from_list(list)?]
             } else {
-                list.iter().map(LookupPath::from_list).collect::<PyResult<_>>()?
+                // alternatives are either paths or single keys
+                list.iter()
+                    .map(|item| match item.downcast::<PyString>() {
+                        Ok(key) => Ok(LookupPath::from_str(py, key.to_str()?, Some(key))),
+                        Err(_) => LookupPath::from_list(item),
+                    })
+                    .collect::<PyResult<_>>()?
             };
 
             if let Some(alt_alias) = alt_alias {
@@ -129,9 +142,11 @@ impl LookupKey {
             },
             Self::PathChoices(path_choices) => {
                 for path in path_choices {
-                    // iterate over the path and plug each value into the py_any from the last step, starting with dict
-                    // this could just be a loop but should be somewhat faster with a functional design
-                    if let Some(v) = path.iter().try_fold(dict as &PyAny, |d, loc| loc.py_get_item(d)) {
+                    // like simple keys, the first item is read from the dict directly, then iterate over the rest
+                    // of the path and plug each value into the py_any from the last step
+                    let mut path_iter = path.iter();
+                    let first = path_iter.next().and_then(|loc| dict.get_item(loc));
+                    if let Some(v) = first.and_then(|first| path_iter.try_fold(first, |d, loc| loc.py_get_item(d))) {
                         // Successfully found an item, return it
                         return Ok(Some((path, v)));
                     }
@@ -147,9 +162,9 @@ impl LookupKey {
         dict: &'data PyMapping,
     ) -> PyResult<Option<(&'s LookupPath, &'data PyAny)>> {
         match self {
-            Self::Simple { py_key, path, .. } => match dict.get_item(py_key) {
-                Ok(value) => Ok(Some((path, value))),
-                _ => Ok(None),
+            Self::Simple { py_key, path, .. } => match py_get_item_no_missing(dict, py_key) {
+                Some(value) => Ok(Some((path, value))),
+                None => Ok(None),
             },
             Self::Choice {
                 py_key1,
@@ -157,11 +172,11 @@ impl LookupKey {
                 py_key2,
                 path2,
                 ..
-            } => match dict.get_item(py_key1) {
-                Ok(value) => Ok(Some((path1, value))),
-                _ => match dict.get_item(py_key2) {
-                    Ok(value) => Ok(Some((path2, value))),
-                    _ => Ok(None),
+            } => match py_get_item_no_missing(dict, py_key1) {
+                Some(value) => Ok(Some((path1, value))),
+                None => match py_get_item_no_missing(dict, py_key2) {
+                    Some(value) => Ok(Some((path2, value))),
+                    None => Ok(None),
                 },
             },
             Self::PathChoices(path_choices) => {
@@ -183,6 +198,16 @@ impl LookupKey {
         &'s self,
         obj: &'data PyAny,
         kwargs: Option<&'data PyDict>,
+    ) -> PyResult<Option<(&'s LookupPath, &'data PyAny)>> {
+        self.py_read_attr(obj, kwargs, &AttributesReader::GETATTR)
+    }
+
+    /// like `py_get_attr` but top level attributes of simple and choice keys are read with `reader`
+    pub fn py_read_attr<'data, 's>(
+        &'s self,
+        obj: &'data PyAny,
+        kwargs: Option<&'data PyDict>,
+        reader: &AttributesReader<'_, 'data>,
     ) -> PyResult<Option<(&'s LookupPath, &'data PyAny)>> {
         if let Some(dict) = kwargs {
             if let Ok(Some(item)) = self.py_get_dict_item(dict) {
@@ -191,25 +216,30 @@ impl LookupKey {
         }
 
         match self {
-            Self::Simple { py_key, path, .. } => match py_get_attrs(obj, py_key)? {
+            Self::Simple { key, py_key, path } => match reader.get(obj, key, py_key)? {
                 Some(value) => Ok(Some((path, value))),
                 None => Ok(None),
             },
             Self::Choice {
+                key1,
                 py_key1,
                 path1,
+                key2,
                 py_key2,
                 path2,
-                ..
-            } => match py_get_attrs(obj, py_key1)? {
+            } => match reader.get(obj, key1, py_key1)? {
                 Some(value) => Ok(Some((path1, value))),
-                None => match py_get_attrs(obj, py_key2)? {
+                None => match reader.get(obj, key2, py_key2)? {
                     Some(value) => Ok(Some((path2, value))),
                     None => Ok(None),
                 },
             },
             Self::PathChoices(path_choices) => {
                 'outer: for path in path_choices {
+                    // paths starting with an attribute which can't be read are skipped without probing the object
+                    if !reader.can_read(path.first_key()) {
+                        continue;
+                    }
                     // similar to above, but using `py_get_attrs`, we can't use try_fold because of the extra Err
                     // so we have to loop manually
                     let mut v = obj;
@@ -278,6 +308,19 @@ impl LookupKey {
         }
     }
 
+    /// keys which are looked up directly in the input, rather than by a path of more than one item
+    pub fn top_level_keys(&self) -> Vec<&str> {
+        match self {
+            Self::Simple { key, .. } => vec![key],
+            Self::Choice { key1, key2, .. } => vec![key1, key2],
+            Self::PathChoices(paths) => paths
+                .iter()
+                .filter(|path| path.0.len() == 1)
+                .map(|path| path.first_key())
+                .collect(),
+        }
+    }
+
     pub fn error<'d>(
         &self,
         error_type: ErrorType,
@@ -285,16 +328,19 @@ impl LookupKey {
         loc_by_alias: bool,
         field_name: &str,
     ) -> ValLineError<'d> {
-        if loc_by_alias {
-            let lookup_path = match self {
-                Self::Simple { path, .. } => path,
-                Self::Choice { path1, .. } => path1,
-                Self::PathChoices(paths) => paths.first().unwrap(),
-            };
-            ValLineError::new_with_full_loc(error_type, input, lookup_path.into())
-        } else {
-            ValLineError::new_with_loc(error_type, input, field_name.to_string())
-        }
+        // if the field can be populated by its name, that's where it's missing, otherwise at the first alias
+        let lookup_path = match self {
+            Self::Simple { path, .. } => path,
+            Self::Choice { path1, path2, .. } => match path2.is_key(field_name) {
+                true => path2,
+                false => path1,
+            },
+            Self::PathChoices(paths) => paths
+                .iter()
+                .find(|path| path.is_key(field_name))
+                .unwrap_or_else(|| paths.first().unwrap()),
+        };
+        ValLineError::new_with_loc(error_type, input, lookup_path.field_loc_item(loc_by_alias, field_name))
     }
 }
 
@@ -314,7 +360,7 @@ impl fmt::Display for LookupPath {
 }
 
 impl LookupPath {
-    fn from_str(py: Python, key: &str, py_key: Option<&PyString>) -> Self {
+    pub fn from_str(py: Python, key: &str, py_key: Option<&PyString>) -> Self {
         let py_key = match py_key {
             Some(py_key) => py_key.into_py(py),
             None => py_string!(py, key),
@@ -339,20 +385,40 @@ impl LookupPath {
 
     pub fn apply_error_loc<'a>(
         &self,
-        mut line_error: ValLineError<'a>,
+        line_error: ValLineError<'a>,
         loc_by_alias: bool,
         field_name: &str,
     ) -> ValLineError<'a> {
+        line_error.with_outer_location(self.field_loc_item(loc_by_alias, field_name))
+    }
+
+    /// the location of a field found by this path, which remembers both the path and `field_name`
+    /// unless the path is just `field_name`
+    fn field_loc_item(&self, loc_by_alias: bool, field_name: &str) -> LocItem {
+        match self.0.as_slice() {
+            [PathItem::S(key, _)] if key == field_name => field_name.into(),
+            _ => LocItem::field(
+                field_name,
+                self.iter().map(|path_item| path_item.clone().into()).collect(),
+                loc_by_alias,
+            ),
+        }
+    }
+
+    /// the location `apply_error_loc` would prepend to an error
+    pub fn loc_items(&self, loc_by_alias: bool, field_name: &str) -> Vec<LocItem> {
         if loc_by_alias {
-            for path_item in self.iter().rev() {
-                line_error = line_error.with_outer_location(path_item.clone().into());
-            }
-            line_error
+            self.iter().map(|path_item| path_item.clone().into()).collect()
         } else {
-            line_error.with_outer_location(field_name.to_string().into())
+            vec![field_name.into()]
         }
     }
 
+    /// whether the path is just `key`
+    fn is_key(&self, key: &str) -> bool {
+        matches!(self.0.as_slice(), [PathItem::S(k, _)] if k == key)
+    }
+
     pub fn iter(&self) -> Iter<PathItem> {
         self.0.iter()
     }
@@ -425,7 +491,7 @@ impl PathItem {
             None
         } else {
             // otherwise, blindly try getitem on v since no better logic is realistic
-            py_any.get_item(self).ok()
+            py_get_item_no_missing(py_any, self)
         }
     }
 
@@ -477,6 +543,208 @@ impl PathItem {
     }
 }
 
+/// How attributes are read from objects validated with `from_attributes`, set with `attributes_mode`
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum AttributesMode {
+    Getattr,
+    Dict,
+    Slots,
+}
+
+impl AttributesMode {
+    pub fn from_schema(schema: &PyDict) -> PyResult<Self> {
+        match schema.get_as::<&str>(intern!(schema.py(), "attributes_mode"))? {
+            None | Some("getattr") => Ok(Self::Getattr),
+            Some("dict") => Ok(Self::Dict),
+            Some("slots") => Ok(Self::Slots),
+            Some(s) => py_err!(
+                "Invalid attributes_mode: `{}`, expected `getattr`, `dict` or `slots`",
+                s
+            ),
+        }
+    }
+}
+
+/// Which attributes may be read from objects validated with `from_attributes`, set with `from_attributes_mode`
+#[derive(Debug, Clone)]
+pub(crate) enum FromAttributesMode {
+    Getattr,
+    /// only names in `dir(obj)`, computed once per validation
+    Dir,
+    /// only names in `readable_attributes`, kept as a list too for iterating over
+    Explicit {
+        names: AHashSet<String>,
+        names_list: Py<PyList>,
+    },
+}
+
+impl FromAttributesMode {
+    pub fn from_schema(schema: &PyDict) -> PyResult<Self> {
+        let py = schema.py();
+        let readable_attributes: Option<Vec<String>> = schema.get_as(intern!(py, "readable_attributes"))?;
+        let mode = match schema.get_as::<&str>(intern!(py, "from_attributes_mode"))? {
+            None | Some("getattr") => Self::Getattr,
+            Some("dir") => Self::Dir,
+            Some("explicit") => match readable_attributes {
+                Some(names) => {
+                    return Ok(Self::Explicit {
+                        names_list: PyList::new(py, &names).into(),
+                        names: names.into_iter().collect(),
+                    })
+                }
+                None => return py_err!("`readable_attributes` is required with `from_attributes_mode='explicit'`"),
+            },
+            Some(s) => {
+                return py_err!(
+                    "Invalid from_attributes_mode: `{}`, expected `getattr`, `dir` or `explicit`",
+                    s
+                )
+            }
+        };
+        match readable_attributes {
+            Some(_) => py_err!("`readable_attributes` can only be used with `from_attributes_mode='explicit'`"),
+            None => Ok(mode),
+        }
+    }
+}
+
+/// Reads attributes for `LookupKey::py_read_attr`, attributes which aren't readable are treated as missing
+pub(crate) struct AttributesReader<'r, 'data> {
+    source: AttributesSource<'r, 'data>,
+    /// names which may be read, with the same names as a list to iterate over, `None` if any attribute may be read
+    readable: Option<(Cow<'r, AHashSet<String>>, &'data PyList)>,
+}
+
+impl<'r, 'data> AttributesReader<'r, 'data> {
+    pub const GETATTR: Self = Self {
+        source: AttributesSource::Getattr,
+        readable: None,
+    };
+
+    pub fn new(obj: &'data PyAny, source: AttributesSource<'r, 'data>, mode: &'r FromAttributesMode) -> Self {
+        let readable = match mode {
+            FromAttributesMode::Getattr => None,
+            FromAttributesMode::Dir => {
+                let names = obj.dir();
+                let names_set = names.iter().filter_map(|name| name.extract().ok()).collect();
+                Some((Cow::Owned(names_set), names))
+            }
+            FromAttributesMode::Explicit { names, names_list } => {
+                Some((Cow::Borrowed(names), names_list.clone_ref(obj.py()).into_ref(obj.py())))
+            }
+        };
+        Self { source, readable }
+    }
+
+    fn can_read(&self, key: &str) -> bool {
+        match self.readable {
+            Some((ref names, _)) => names.contains(key),
+            None => true,
+        }
+    }
+
+    /// iterate over the readable attributes of `obj`, used for case-insensitive matching
+    pub fn attributes(&self, obj: &'data PyAny) -> ValResult<'data, AttributesGenericIterator<'data>> {
+        match self.readable {
+            Some((_, names)) => Ok(AttributesGenericIterator::with_attributes(obj, names)),
+            None => AttributesGenericIterator::new(obj),
+        }
+    }
+
+    fn get(&self, obj: &'data PyAny, key: &str, py_key: &Py<PyString>) -> PyResult<Option<&'data PyAny>> {
+        if !self.can_read(key) {
+            return Ok(None);
+        }
+        self.source.get(obj, key, py_key)
+    }
+}
+
+/// Where `AttributesReader` reads attributes from, the fast paths fall back to `getattr` for attributes they
+/// don't find so properties, descriptors and `__getattr__` still work
+pub(crate) enum AttributesSource<'r, 'data> {
+    Getattr,
+    /// the object's `__dict__`
+    Dict(&'data PyDict),
+    /// member descriptors from `__slots__` of the object's class, by attribute name
+    Slots(&'r AHashMap<String, PyObject>),
+}
+
+impl<'r, 'data> AttributesSource<'r, 'data> {
+    fn get(&self, obj: &'data PyAny, key: &str, py_key: &Py<PyString>) -> PyResult<Option<&'data PyAny>> {
+        match self {
+            Self::Getattr => (),
+            Self::Dict(dict) => {
+                if let Some(value) = dict.get_item(py_key.as_ref(obj.py())) {
+                    return Ok(Some(value));
+                }
+            }
+            Self::Slots(descriptors) => {
+                if let Some(descriptor) = descriptors.get(key) {
+                    if let Some(value) = member_get(descriptor.as_ref(obj.py()), obj)? {
+                        return Ok(Some(value));
+                    }
+                }
+            }
+        }
+        py_get_attrs(obj, py_key)
+    }
+}
+
+/// Member descriptors for the `__slots__` of `cls` and its bases, attributes shadowed by other class attributes
+/// earlier in the MRO are omitted
+pub(crate) fn slot_descriptors(cls: &PyType) -> PyResult<AHashMap<String, PyObject>> {
+    let py = cls.py();
+    let member_descriptor_type = py
+        .import(intern!(py, "types"))?
+        .getattr(intern!(py, "MemberDescriptorType"))?;
+    let mut seen: AHashSet<String> = AHashSet::new();
+    let mut descriptors = AHashMap::new();
+    let mro: &PyTuple = cls.getattr(intern!(py, "__mro__"))?.downcast()?;
+    for base in mro {
+        let base_dict: &PyMapping = base.getattr(intern!(py, "__dict__"))?.downcast()?;
+        for item in base_dict.items()?.iter()? {
+            let (name, value): (String, &PyAny) = item?.extract()?;
+            if seen.insert(name.clone()) && value.get_type().is(member_descriptor_type) {
+                descriptors.insert(name, value.into_py(py));
+            }
+        }
+    }
+    Ok(descriptors)
+}
+
+/// the object's `__dict__`, `None` for objects without one, checked on the type to avoid an `AttributeError`
+pub(crate) fn instance_dict(obj: &PyAny) -> Option<&PyDict> {
+    let dict_offset = unsafe { (*ffi::Py_TYPE(obj.as_ptr())).tp_dictoffset };
+    if dict_offset == 0 {
+        return None;
+    }
+    match obj.getattr(intern!(obj.py(), "__dict__")) {
+        Ok(dict) => dict.downcast::<PyDict>().ok(),
+        Err(_) => None,
+    }
+}
+
+/// read a slot with its member descriptor, `Ok(None)` if the slot isn't set
+fn member_get<'data>(descriptor: &PyAny, obj: &'data PyAny) -> PyResult<Option<&'data PyAny>> {
+    let py = obj.py();
+    // calling `tp_descr_get` directly skips the attribute lookup on the class which `getattr` would do
+    let result = unsafe {
+        match (*ffi::Py_TYPE(descriptor.as_ptr())).tp_descr_get {
+            Some(descr_get) => descr_get(
+                descriptor.as_ptr(),
+                obj.as_ptr(),
+                ffi::Py_TYPE(obj.as_ptr()) as *mut ffi::PyObject,
+            ),
+            None => return Ok(None),
+        }
+    };
+    match unsafe { py.from_owned_ptr_or_err::<PyAny>(result) } {
+        Ok(value) => Ok(Some(value)),
+        Err(err) if err.is_instance_of::<PyAttributeError>(py) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
 /// wrapper around `getattr` that returns `Ok(None)` for attribute errors, but returns other errors
 /// We don't check `try_from_attributes` because that check was performed on the top level object before we got here
 fn py_get_attrs<'a>(obj: &'a PyAny, attr_name: &Py<PyString>) -> PyResult<Option<&'a PyAny>> {
@@ -491,3 +759,30 @@ fn py_get_attrs<'a>(obj: &'a PyAny, attr_name: &Py<PyString>) -> PyResult<Option
         }
     }
 }
+
+static DICT_GETITEM: GILOnceCell<PyObject> = GILOnceCell::new();
+
+/// `obj[key]` or `None` if that fails, dicts and dict subclasses which don't override `__getitem__` are read
+/// directly so a key which isn't there never calls `__missing__`, e.g. on `defaultdict` which would insert the key
+fn py_get_item_no_missing(obj: &PyAny, key: impl ToPyObject) -> Option<&PyAny> {
+    if let Ok(dict) = obj.downcast::<PyDict>() {
+        if PyDict::is_exact_type_of(obj) || !overrides_getitem(dict) {
+            return dict.get_item(key);
+        }
+    }
+    obj.get_item(key).ok()
+}
+
+fn overrides_getitem(dict: &PyDict) -> bool {
+    let py = dict.py();
+    let dict_getitem = DICT_GETITEM.get_or_init(py, || {
+        py.get_type::<PyDict>()
+            .getattr(intern!(py, "__getitem__"))
+            .unwrap()
+            .into_py(py)
+    });
+    match dict.get_type().getattr(intern!(py, "__getitem__")) {
+        Ok(getitem) => !getitem.is(dict_getitem),
+        Err(_) => true,
+    }
+}
@@ -0,0 +1,102 @@
+use std::collections::BTreeMap;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyDict, PyFloat, PyList, PyLong, PyString, PyTuple};
+use pyo3::AsPyPointer;
+
+use ahash::AHashSet;
+
+/// Summary of what a validator or serializer holds onto, for `memory_report()`, found by walking the schema it
+/// was built from rather than the built tree, so it's as cheap as a walk over the schema's dicts and lists.
+///
+/// Each schema (a dict with a `type`) is counted as one node of size `node_size`, strings are assumed to be copied
+/// into rust, other python objects (functions, classes, defaults and so on) are counted as retained references.
+/// Definitions are walked once where they're defined, `definition-ref` schemas are nodes of their own, and dicts
+/// seen before aren't walked again, so recursive schemas can't loop.
+#[derive(Default)]
+struct MemoryReport {
+    nodes: usize,
+    kinds: BTreeMap<String, usize>,
+    py_refs: usize,
+    heap_bytes: usize,
+    seen: AHashSet<usize>,
+}
+
+pub fn memory_report(py: Python, schema: &PyDict, slots: usize, node_size: usize) -> PyResult<Py<PyDict>> {
+    let mut report = MemoryReport::default();
+    report.walk_dict(schema)?;
+
+    let dict = PyDict::new(py);
+    dict.set_item(intern!(py, "slots"), slots)?;
+    dict.set_item(intern!(py, "nodes"), report.nodes)?;
+    dict.set_item(intern!(py, "kinds"), report.kinds.into_py(py))?;
+    dict.set_item(intern!(py, "py_refs"), report.py_refs)?;
+    dict.set_item(
+        intern!(py, "estimated_bytes"),
+        report.nodes * node_size + report.heap_bytes,
+    )?;
+    Ok(dict.into())
+}
+
+impl MemoryReport {
+    fn walk_dict(&mut self, dict: &PyDict) -> PyResult<()> {
+        if !self.seen.insert(dict.as_ptr() as usize) {
+            return Ok(());
+        }
+        let schema_type = match dict.get_item(intern!(dict.py(), "type")) {
+            Some(schema_type) => schema_type.downcast::<PyString>().ok(),
+            None => None,
+        };
+        if let Some(schema_type) = schema_type {
+            self.nodes += 1;
+            *self.kinds.entry(schema_type.to_str()?.to_string()).or_default() += 1;
+        }
+        for (key, value) in dict {
+            match (key.downcast::<PyString>(), schema_type) {
+                (Ok(key), Some(_)) => match key.to_str()? {
+                    // metadata is only for the user, it's not used to build validators or serializers
+                    "type" | "metadata" => continue,
+                    // defaults are kept as python objects, whatever their type
+                    "default" => self.py_refs += 1,
+                    // `{'type': 'with-info', 'function': ...}` isn't a schema, so only its contents are walked
+                    "function" => match value.downcast::<PyDict>() {
+                        Ok(function) => function.values().iter().try_for_each(|v| self.walk(v))?,
+                        Err(_) => self.walk(value)?,
+                    },
+                    _ => self.walk(value)?,
+                },
+                // keys of other dicts are things like field names, which are copied
+                (Ok(key), None) => {
+                    self.heap_bytes += key.to_str()?.len();
+                    self.walk(value)?;
+                }
+                (Err(_), _) => self.walk(value)?,
+            }
+        }
+        Ok(())
+    }
+
+    fn walk(&mut self, value: &PyAny) -> PyResult<()> {
+        if let Ok(dict) = value.downcast::<PyDict>() {
+            self.walk_dict(dict)?;
+        } else if let Ok(list) = value.downcast::<PyList>() {
+            for item in list {
+                self.walk(item)?;
+            }
+        } else if let Ok(tuple) = value.downcast::<PyTuple>() {
+            for item in tuple {
+                self.walk(item)?;
+            }
+        } else if let Ok(py_str) = value.downcast::<PyString>() {
+            self.heap_bytes += py_str.to_str()?.len();
+        } else if !(value.is_none()
+            || value.is_instance_of::<PyBool>()?
+            || value.is_instance_of::<PyLong>()?
+            || value.is_instance_of::<PyFloat>()?)
+        {
+            self.py_refs += 1;
+        }
+        Ok(())
+    }
+}
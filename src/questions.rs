@@ -7,23 +7,43 @@ use crate::build_tools::SchemaDict;
 #[derive(Debug, PartialEq, Eq)]
 pub enum Question {
     ReturnFieldsSet,
+    /// whether the validator returns extra values separately from fields, set with `return_extra`
+    ReturnExtra,
+    /// whether the validator provides a default value, validators without a default (the answer is `false`)
+    /// make a field required
+    HasDefault,
+    /// whether every value the validator returns is immutable, so a validated value can safely be reused
+    ReturnsImmutable,
 }
 
 #[derive(Debug, Clone)]
 pub struct Answers {
     return_fields_set: bool,
+    return_extra: bool,
+    has_default: bool,
 }
 
 impl Answers {
     pub fn new(schema: &PyDict) -> PyResult<Self> {
-        let key = intern!(schema.py(), "return_fields_set");
-        let return_fields_set = schema.get_as(key)?.unwrap_or(false);
-        Ok(Self { return_fields_set })
+        let py = schema.py();
+        let return_fields_set = schema.get_as(intern!(py, "return_fields_set"))?.unwrap_or(false);
+        let return_extra = schema.get_as(intern!(py, "return_extra"))?.unwrap_or(false);
+        let has_default = schema.get_as::<&str>(intern!(py, "type"))? == Some("default")
+            && (schema.contains(intern!(py, "default"))? || schema.contains(intern!(py, "default_factory"))?);
+        Ok(Self {
+            return_fields_set,
+            return_extra,
+            has_default,
+        })
     }
 
     pub fn ask(&self, question: &Question) -> bool {
         match question {
             Question::ReturnFieldsSet => self.return_fields_set,
+            Question::ReturnExtra => self.return_extra,
+            Question::HasDefault => self.has_default,
+            // validators behind a definition reference can only be validated with slots, so they're never reused
+            Question::ReturnsImmutable => false,
         }
     }
 }
@@ -1,17 +1,76 @@
 use ahash::AHashSet;
 
+// the stack is smaller on PyPy and WASM, so recursion is limited further
+const SMALL_STACK: bool = cfg!(PyPy) || cfg!(target_family = "wasm");
+
+/// the default `recursion_limit`, see #143 and `validators/definition` for why depth is limited as well as
+/// checking identity
+pub const DEFAULT_RECURSION_LIMIT: u16 = if SMALL_STACK { 123 } else { 255 };
+
+/// the highest `recursion_limit` allowed, each level of recursion uses the stack so a higher limit could overflow it
+/// and abort the process rather than raising an error
+pub const MAX_RECURSION_LIMIT: u16 = if SMALL_STACK { 250 } else { 500 };
+
 /// This is used to avoid cyclic references in input data causing recursive validation and a nasty segmentation fault.
 /// It's used in `validators/definition` to detect when a reference is reused within itself.
-#[derive(Debug, Clone, Default)]
+/// It also carries the `max_steps` budget of a validation call, see `step`.
+#[derive(Debug, Clone)]
 pub struct RecursionGuard {
     ids: Option<AHashSet<usize>>,
-    // see validators/definition::BACKUP_GUARD_LIMIT for details
     // depth could be a hashmap {validator_id => depth} but for simplicity and performance it's easier to just
     // use one number for all validators
     depth: u16,
+    // from `recursion_limit`, deeper recursion is an error regardless of `ids`, it also limits nesting when
+    // parsing JSON
+    max_depth: u16,
+    // steps left before the budget is exceeded, `usize::MAX` (effectively unlimited) without `max_steps`
+    steps_left: usize,
+    budget_exceeded: bool,
+}
+
+impl Default for RecursionGuard {
+    fn default() -> Self {
+        Self::new(None, DEFAULT_RECURSION_LIMIT)
+    }
 }
 
 impl RecursionGuard {
+    pub fn new(max_steps: Option<usize>, max_depth: u16) -> Self {
+        Self {
+            ids: None,
+            depth: 0,
+            max_depth,
+            steps_left: max_steps.unwrap_or(usize::MAX),
+            budget_exceeded: false,
+        }
+    }
+
+    /// count one step against the budget, called by containers before validating each item and by unions
+    /// before trying each choice, returns false once the budget is exceeded, the caller should then stop
+    /// validating and return a `validation_budget_exceeded` error along with any errors collected so far
+    pub fn step(&mut self) -> bool {
+        if self.steps_left == 0 {
+            self.budget_exceeded = true;
+            false
+        } else {
+            self.steps_left -= 1;
+            true
+        }
+    }
+
+    pub fn steps_left(&self) -> usize {
+        self.steps_left
+    }
+
+    /// update the steps left after work done outside validators, e.g. parsing JSON
+    pub fn set_steps_left(&mut self, steps_left: usize) {
+        self.steps_left = steps_left;
+    }
+
+    pub fn budget_exceeded(&self) -> bool {
+        self.budget_exceeded
+    }
+
     // insert a new id into the set, return whether the set already had the id in it
     pub fn contains_or_insert(&mut self, id: usize) -> bool {
         match self.ids {
@@ -27,12 +86,21 @@ impl RecursionGuard {
         }
     }
 
-    // see #143 this is used as a backup in case the identity check recursion guard fails
-    pub fn incr_depth(&mut self) -> u16 {
+    // see #143 this is used as a backup in case the identity check recursion guard fails, and to limit recursion
+    // for inputs without an identity, returns false if the depth is now over `max_depth`
+    pub fn incr_depth(&mut self) -> bool {
         self.depth += 1;
+        self.depth <= self.max_depth
+    }
+
+    pub fn depth(&self) -> u16 {
         self.depth
     }
 
+    pub fn max_depth(&self) -> u16 {
+        self.max_depth
+    }
+
     pub fn decr_depth(&mut self) {
         self.depth -= 1;
     }
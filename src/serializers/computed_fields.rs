@@ -0,0 +1,167 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyString};
+
+use serde::ser::{Error, SerializeMap};
+
+use crate::build_context::BuildContext;
+use crate::build_tools::{py_error_type, SchemaDict};
+
+use super::errors::{py_err_se_err, PydanticSerializationError};
+use super::filter::SchemaFilter;
+use super::shared::{BuildSerializer, CombinedSerializer, PydanticSerializer, TypeSerializer};
+use super::Extra;
+
+/// `computed_fields` from a model or dataclass schema, read from the instance's attributes and added after its
+/// fields, they're filtered by `include` and `exclude` with their property names like fields
+#[derive(Debug, Clone)]
+pub(super) struct ComputedFields(Vec<ComputedField>);
+
+impl ComputedFields {
+    pub fn new(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext<CombinedSerializer>,
+    ) -> PyResult<Option<Self>> {
+        let py = schema.py();
+        match schema.get_as::<&PyList>(intern!(py, "computed_fields"))? {
+            Some(computed_fields) => {
+                let computed_fields = computed_fields
+                    .iter()
+                    .map(|field| ComputedField::new(field.downcast()?, config, build_context))
+                    .collect::<PyResult<Vec<_>>>()?;
+                Ok(Some(Self(computed_fields)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn to_python(
+        &self,
+        model: &PyAny,
+        output_dict: &PyDict,
+        filter: &SchemaFilter<isize>,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> PyResult<()> {
+        let py = model.py();
+        for field in &self.0 {
+            let property_name_py = field.property_name_py.as_ref(py);
+            if let Some((next_include, next_exclude)) = filter.key_filter(property_name_py, include, exclude)? {
+                let value = field.get_value(model)?;
+                if extra.exclude_none && value.is_none() {
+                    continue;
+                }
+                let extra = Extra {
+                    field_name: Some(&field.property_name),
+                    ..*extra
+                };
+                let value = field.serializer.to_python(value, next_include, next_exclude, &extra)?;
+                output_dict.set_item(field.get_key_py(py, &extra), value)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn serde_serialize<S: SerializeMap>(
+        &self,
+        model: &PyAny,
+        map: &mut S,
+        filter: &SchemaFilter<isize>,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> Result<(), S::Error> {
+        let py = model.py();
+        for field in &self.0 {
+            let property_name_py = field.property_name_py.as_ref(py);
+            if let Some((next_include, next_exclude)) = filter
+                .key_filter(property_name_py, include, exclude)
+                .map_err(py_err_se_err)?
+            {
+                let value = model.getattr(property_name_py).map_err(|err| {
+                    S::Error::custom(format!(
+                        "Error getting computed field `{}`: {}",
+                        field.property_name, err
+                    ))
+                })?;
+                if extra.exclude_none && value.is_none() {
+                    continue;
+                }
+                let extra = Extra {
+                    field_name: Some(&field.property_name),
+                    ..*extra
+                };
+                let s = PydanticSerializer::new(value, &field.serializer, next_include, next_exclude, &extra);
+                map.serialize_entry(field.get_key_json(&extra), &s)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ComputedField {
+    property_name: String,
+    property_name_py: Py<PyString>,
+    serializer: CombinedSerializer,
+    alias: Option<String>,
+    alias_py: Option<Py<PyString>>,
+}
+
+impl ComputedField {
+    fn new(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext<CombinedSerializer>,
+    ) -> PyResult<Self> {
+        let py = schema.py();
+        let property_name: String = schema.get_as_req(intern!(py, "property_name"))?;
+        let return_schema = schema.get_as_req(intern!(py, "return_schema"))?;
+        let serializer = CombinedSerializer::build(return_schema, config, build_context)
+            .map_err(|e| py_error_type!("Computed field `{}`:\n  {}", property_name, e))?;
+        let alias: Option<String> = schema.get_as(intern!(py, "alias"))?;
+        Ok(Self {
+            property_name_py: PyString::intern(py, &property_name).into_py(py),
+            property_name,
+            serializer,
+            alias_py: alias.as_ref().map(|alias| PyString::new(py, alias).into()),
+            alias,
+        })
+    }
+
+    /// read the property, an exception is raised as a `PydanticSerializationError` rather than the field
+    /// being skipped
+    fn get_value<'py>(&self, model: &'py PyAny) -> PyResult<&'py PyAny> {
+        let py = model.py();
+        model.getattr(self.property_name_py.as_ref(py)).map_err(|err| {
+            let new_err = py_error_type!(
+                PydanticSerializationError;
+                "Error getting computed field `{}`: {}",
+                self.property_name,
+                err
+            );
+            new_err.set_cause(py, Some(err));
+            new_err
+        })
+    }
+
+    fn get_key_py<'py>(&'py self, py: Python<'py>, extra: &Extra) -> &'py PyAny {
+        match (extra.by_alias, &self.alias_py) {
+            (true, Some(alias_py)) => alias_py.as_ref(py),
+            _ => self.property_name_py.as_ref(py),
+        }
+    }
+
+    fn get_key_json(&self, extra: &Extra) -> &str {
+        match (extra.by_alias, &self.alias) {
+            (true, Some(alias)) => alias,
+            _ => &self.property_name,
+        }
+    }
+}
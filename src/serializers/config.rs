@@ -1,13 +1,15 @@
 use std::borrow::Cow;
 use std::str::{from_utf8, Utf8Error};
 
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::types::{PyDelta, PyDict};
+use pyo3::types::{PyBool, PyDelta, PyDict};
 use pyo3::{intern, PyNativeType};
 
-use serde::ser::Error;
+use serde::ser::{Error, SerializeStruct};
 
 use crate::build_tools::{py_err, SchemaDict};
+use crate::errors::ErrorType;
 use crate::input::pytimedelta_as_duration;
 
 use super::errors::py_err_se_err;
@@ -16,28 +18,86 @@ use super::errors::py_err_se_err;
 pub(crate) struct SerializationConfig {
     pub timedelta_mode: TimedeltaMode,
     pub bytes_mode: BytesMode,
+    pub inf_nan_mode: InfNanMode,
+    pub warnings_mode: WarningsMode,
 }
 
 impl SerializationConfig {
     pub fn from_config(config: Option<&PyDict>) -> PyResult<Self> {
         let timedelta_mode = TimedeltaMode::from_config(config)?;
         let bytes_mode = BytesMode::from_config(config)?;
+        let inf_nan_mode = InfNanMode::from_config(config)?;
+        let warnings_mode = WarningsMode::from_config(config)?;
         Ok(Self {
             timedelta_mode,
             bytes_mode,
+            inf_nan_mode,
+            warnings_mode,
         })
     }
 
-    pub fn from_args(timedelta_mode: Option<&str>, bytes_mode: Option<&str>) -> PyResult<Self> {
+    pub fn from_args(
+        timedelta_mode: Option<&str>,
+        bytes_mode: Option<&str>,
+        inf_nan_mode: Option<&str>,
+    ) -> PyResult<Self> {
         let timedelta_mode = TimedeltaMode::from_str(timedelta_mode)?;
         let bytes_mode = BytesMode::from_str(bytes_mode)?;
+        let inf_nan_mode = InfNanMode::from_str(inf_nan_mode)?;
         Ok(Self {
             timedelta_mode,
             bytes_mode,
+            inf_nan_mode,
+            warnings_mode: WarningsMode::Warn,
         })
     }
 }
 
+/// How values which don't match their serializer's schema are reported, they're always serialized by inference
+/// unless this is `Error`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum WarningsMode {
+    None,
+    Warn,
+    Error,
+}
+
+impl WarningsMode {
+    pub fn from_config(config: Option<&PyDict>) -> PyResult<Self> {
+        let raw_mode: Option<&str> = match config {
+            Some(c) => c.get_as::<&str>(intern!(c.py(), "ser_warnings"))?,
+            None => None,
+        };
+        match raw_mode {
+            Some("warn") | None => Ok(Self::Warn),
+            Some("none") => Ok(Self::None),
+            Some("error") => Ok(Self::Error),
+            Some(s) => py_err!("Invalid warnings mode: `{}`, expected `none`, `warn` or `error`", s),
+        }
+    }
+
+    /// `warnings` passed to `to_python` or `to_json`, `True` and `False` are the same as `'warn'` and `'none'`,
+    /// `None` uses the mode from config
+    pub fn from_arg(warnings: Option<&PyAny>, default: Self) -> PyResult<Self> {
+        let Some(warnings) = warnings else {
+            return Ok(default);
+        };
+        if let Ok(b) = warnings.downcast::<PyBool>() {
+            return Ok(if b.is_true() { Self::Warn } else { Self::None });
+        }
+        match warnings.extract::<&str>() {
+            Ok("warn") => Ok(Self::Warn),
+            Ok("none") => Ok(Self::None),
+            Ok("error") => Ok(Self::Error),
+            _ => py_err!(
+                PyValueError;
+                "Invalid warnings: {}, expected a bool, `'none'`, `'warn'` or `'error'`",
+                warnings.repr()?
+            ),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(crate) enum TimedeltaMode {
     Iso8601,
@@ -56,9 +116,10 @@ impl TimedeltaMode {
     pub fn from_str(s: Option<&str>) -> PyResult<Self> {
         match s {
             Some("iso8601") => Ok(Self::Iso8601),
-            Some("float") => Ok(Self::Float),
+            // `float` is the original name of `seconds_float`
+            Some("seconds_float" | "float") => Ok(Self::Float),
             Some(s) => py_err!(
-                "Invalid timedelta serialization mode: `{}`, expected `iso8601` or `float`",
+                "Invalid timedelta serialization mode: `{}`, expected `iso8601` or `seconds_float`",
                 s
             ),
             None => Ok(Self::Iso8601),
@@ -115,11 +176,17 @@ impl TimedeltaMode {
     }
 }
 
-#[derive(Debug, Clone)]
-pub(crate) struct BytesMode {
-    base64_config: Option<base64::Config>,
+#[derive(Debug, Clone, Copy)]
+pub enum BytesMode {
+    Utf8,
+    Base64,
+    // URL-safe base64 without padding, as used in JWTs and URLs
+    Base64Url,
+    Hex,
 }
 
+const BASE64_CONFIG: base64::Config = base64::Config::new(base64::CharacterSet::UrlSafe, true);
+
 impl BytesMode {
     pub fn from_config(config: Option<&PyDict>) -> PyResult<Self> {
         let raw_mode: Option<&str> = match config {
@@ -130,35 +197,213 @@ impl BytesMode {
     }
 
     pub fn from_str(s: Option<&str>) -> PyResult<Self> {
-        let base64_config = match s {
-            Some("utf8") => None,
-            Some("base64") => Some(base64::Config::new(base64::CharacterSet::UrlSafe, true)),
-            Some(s) => return py_err!("Invalid bytes serialization mode: `{}`, expected `utf8` or `base64`", s),
+        match s {
+            Some("utf8") => Ok(Self::Utf8),
+            Some("base64") => Ok(Self::Base64),
+            Some("base64url") => Ok(Self::Base64Url),
+            Some("hex") => Ok(Self::Hex),
+            Some(s) => py_err!(
+                "Invalid bytes serialization mode: `{}`, expected `utf8`, `base64`, `base64url` or `hex`",
+                s
+            ),
+            None => Ok(Self::Utf8),
+        }
+    }
+
+    /// How the bytes validators decode JSON strings, from `val_json_bytes` so it can match `ser_json_bytes`
+    pub fn from_val_config(config: Option<&PyDict>) -> PyResult<Self> {
+        let raw_mode: Option<&str> = match config {
+            Some(c) => c.get_as::<&str>(intern!(c.py(), "val_json_bytes"))?,
             None => None,
         };
-        Ok(Self { base64_config })
+        match raw_mode {
+            Some("utf8") | None => Ok(Self::Utf8),
+            Some("base64") => Ok(Self::Base64),
+            Some("base64url") => Ok(Self::Base64Url),
+            Some("hex") => Ok(Self::Hex),
+            Some(s) => py_err!(
+                "Invalid bytes validation mode: `{}`, expected `utf8`, `base64`, `base64url` or `hex`",
+                s
+            ),
+        }
+    }
+
+    pub fn deserialize_string<'a>(self, s: &'a str) -> Result<Cow<'a, [u8]>, ErrorType> {
+        let encoding_error = |encoding: &str, encoding_error: String| ErrorType::BytesInvalidEncoding {
+            encoding: encoding.to_string(),
+            encoding_error,
+        };
+        match self {
+            Self::Utf8 => Ok(Cow::Borrowed(s.as_bytes())),
+            Self::Base64 => {
+                // both the standard and URL-safe alphabets are accepted, padding is optional
+                let config = match s.contains(['+', '/']) {
+                    true => base64::STANDARD,
+                    false => base64::URL_SAFE,
+                };
+                base64::decode_config(s, config)
+                    .map(Cow::Owned)
+                    .map_err(|err| encoding_error("base64", err.to_string()))
+            }
+            // only the URL-safe alphabet, padding is optional
+            Self::Base64Url => base64::decode_config(s, base64::URL_SAFE_NO_PAD)
+                .map(Cow::Owned)
+                .map_err(|err| encoding_error("base64url", err.to_string())),
+            Self::Hex => hex_decode(s).map(Cow::Owned).map_err(|err| encoding_error("hex", err)),
+        }
     }
 
     pub fn bytes_to_string<'py>(&self, py: Python, bytes: &'py [u8]) -> PyResult<Cow<'py, str>> {
-        if let Some(config) = self.base64_config {
-            Ok(Cow::Owned(base64::encode_config(bytes, config)))
-        } else {
-            from_utf8(bytes)
+        match self {
+            Self::Utf8 => from_utf8(bytes)
                 .map_err(|err| utf8_py_error(py, err, bytes))
-                .map(Cow::Borrowed)
+                .map(Cow::Borrowed),
+            Self::Base64 => Ok(Cow::Owned(base64::encode_config(bytes, BASE64_CONFIG))),
+            Self::Base64Url => Ok(Cow::Owned(base64::encode_config(bytes, base64::URL_SAFE_NO_PAD))),
+            Self::Hex => Ok(Cow::Owned(hex_encode(bytes))),
         }
     }
 
     pub fn serialize_bytes<S: serde::ser::Serializer>(&self, bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
-        if let Some(config) = self.base64_config {
-            serializer.serialize_str(&base64::encode_config(bytes, config))
-        } else {
-            match from_utf8(bytes) {
+        match self {
+            Self::Utf8 => match from_utf8(bytes) {
                 Ok(s) => serializer.serialize_str(s),
                 Err(e) => Err(Error::custom(e.to_string())),
+            },
+            Self::Base64 => serializer.serialize_str(&base64::encode_config(bytes, BASE64_CONFIG)),
+            Self::Base64Url => serializer.serialize_str(&base64::encode_config(bytes, base64::URL_SAFE_NO_PAD)),
+            Self::Hex => serializer.serialize_str(&hex_encode(bytes)),
+        }
+    }
+}
+
+/// How infinite and NaN floats and decimals are written in JSON, where they aren't valid numbers
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum InfNanMode {
+    // `null`
+    Null,
+    // `Infinity`, `-Infinity` and `NaN` like python's `json` module, which isn't standard JSON
+    Constants,
+    // the strings `"inf"`, `"-inf"` and `"nan"`, which the float and decimal validators accept with `allow_inf_nan`
+    Strings,
+}
+
+// serde_json writes a string serialized as a field of a struct with this name as it is, it's how `RawValue` is
+// serialized, it's the only way to write `Infinity` since serde_json writes infinite floats as `null`
+const RAW_VALUE_TOKEN: &str = "$serde_json::private::RawValue";
+
+impl InfNanMode {
+    pub fn from_config(config: Option<&PyDict>) -> PyResult<Self> {
+        let raw_mode: Option<&str> = match config {
+            Some(c) => c.get_as::<&str>(intern!(c.py(), "ser_json_inf_nan"))?,
+            None => None,
+        };
+        Self::from_str(raw_mode)
+    }
+
+    pub fn from_str(s: Option<&str>) -> PyResult<Self> {
+        match s {
+            Some("null") | None => Ok(Self::Null),
+            Some("constants") => Ok(Self::Constants),
+            Some("strings") => Ok(Self::Strings),
+            Some(s) => py_err!(
+                "Invalid inf_nan serialization mode: `{}`, expected `null`, `constants` or `strings`",
+                s
+            ),
+        }
+    }
+
+    /// a float for `to_python` in JSON mode, `None` if it's finite and can be used unchanged
+    pub fn float_to_python(self, py: Python, value: f64) -> Option<PyObject> {
+        if value.is_finite() {
+            return None;
+        }
+        match self {
+            Self::Null => Some(py.None()),
+            // `json.dumps` writes these as `Infinity` and `NaN`
+            Self::Constants => Some(value.into_py(py)),
+            Self::Strings => Some(non_finite_str(value).into_py(py)),
+        }
+    }
+
+    pub fn serialize_float<S: serde::ser::Serializer>(self, value: f64, serializer: S) -> Result<S::Ok, S::Error> {
+        if value.is_finite() {
+            return serializer.serialize_f64(value);
+        }
+        match self {
+            Self::Null => serializer.serialize_none(),
+            Self::Constants => {
+                let constant = match value.is_nan() {
+                    true => "NaN",
+                    false if value.is_sign_positive() => "Infinity",
+                    false => "-Infinity",
+                };
+                let mut raw = serializer.serialize_struct(RAW_VALUE_TOKEN, 1)?;
+                raw.serialize_field(RAW_VALUE_TOKEN, constant)?;
+                raw.end()
+            }
+            Self::Strings => serializer.serialize_str(non_finite_str(value)),
+        }
+    }
+
+    /// the float equivalent of a decimal's string, e.g. `-Infinity` or `NaN`, if it isn't finite
+    pub fn non_finite_decimal(decimal_str: &str) -> Option<f64> {
+        if decimal_str.ends_with("NaN") {
+            Some(f64::NAN)
+        } else if decimal_str.ends_with("Infinity") {
+            match decimal_str.starts_with('-') {
+                true => Some(f64::NEG_INFINITY),
+                false => Some(f64::INFINITY),
+            }
+        } else {
+            None
+        }
+    }
+}
+
+fn non_finite_str(value: f64) -> &'static str {
+    match value.is_nan() {
+        true => "nan",
+        false if value.is_sign_positive() => "inf",
+        false => "-inf",
+    }
+}
+
+/// lowercase hex, matching `bytes.hex()`
+fn hex_encode(bytes: &[u8]) -> String {
+    const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut s = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        s.push(HEX_DIGITS[(byte >> 4) as usize] as char);
+        s.push(HEX_DIGITS[(byte & 0xf) as usize] as char);
+    }
+    s
+}
+
+/// upper or lowercase hex, like `bytes.fromhex` but without whitespace
+fn hex_decode(s: &str) -> Result<Vec<u8>, String> {
+    let digit = |index: usize| {
+        let byte = s.as_bytes()[index];
+        match byte {
+            b'0'..=b'9' => Ok(byte - b'0'),
+            b'a'..=b'f' => Ok(byte - b'a' + 10),
+            b'A'..=b'F' => Ok(byte - b'A' + 10),
+            _ => {
+                let c = String::from_utf8_lossy(&s.as_bytes()[index..])
+                    .chars()
+                    .next()
+                    .unwrap_or_default();
+                Err(format!("Invalid character {c:?} at position {index}"))
             }
         }
+    };
+    if s.len() % 2 == 1 {
+        return Err("Odd number of digits".to_string());
     }
+    (0..s.len())
+        .step_by(2)
+        .map(|index| Ok(digit(index)? << 4 | digit(index + 1)?))
+        .collect()
 }
 
 pub fn utf8_py_error(py: Python, err: Utf8Error, data: &[u8]) -> PyErr {
@@ -25,6 +25,9 @@ pub(super) fn se_err_py_err(error: serde_json::Error) -> PyErr {
         }
     } else if let Some(msg) = s.strip_prefix(SERIALIZATION_ERR_MARKER) {
         PydanticSerializationError::new_err(msg.to_string())
+    } else if let Some(msg) = s.strip_prefix("PydanticSerializationError: ") {
+        // a `PydanticSerializationError` from a serializer's `to_python` or `json_key`, via `py_err_se_err`
+        PydanticSerializationError::new_err(msg.to_string())
     } else {
         let msg = format!("Error serializing to JSON: {s}");
         PydanticSerializationError::new_err(msg)
@@ -3,13 +3,19 @@ use std::fmt;
 
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::{intern, AsPyPointer};
+use pyo3::types::{PyDict, PyList, PySet};
+use pyo3::{intern, AsPyPointer, PyTypeInfo};
 
 use ahash::AHashSet;
 use serde::ser::Error;
 
-use super::config::SerializationConfig;
-use super::errors::{PydanticSerializationUnexpectedValue, UNEXPECTED_TYPE_SER_MARKER};
+use crate::build_tools::py_err;
+
+use super::config::{SerializationConfig, WarningsMode};
+use super::errors::{
+    PydanticSerializationError, PydanticSerializationUnexpectedValue, SERIALIZATION_ERR_MARKER,
+    UNEXPECTED_TYPE_SER_MARKER,
+};
 use super::ob_type::ObTypeLookup;
 use super::shared::CombinedSerializer;
 
@@ -22,15 +28,22 @@ pub(crate) struct SerializationState {
 }
 
 impl SerializationState {
-    pub fn new(timedelta_mode: Option<&str>, bytes_mode: Option<&str>) -> Self {
-        let warnings = CollectWarnings::new(false);
+    pub fn new(
+        py: Python,
+        timedelta_mode: Option<&str>,
+        bytes_mode: Option<&str>,
+        inf_nan_mode: Option<&str>,
+    ) -> PyResult<Self> {
+        let warnings = CollectWarnings::new(WarningsMode::None);
         let rec_guard = SerRecursionGuard::default();
-        let config = SerializationConfig::from_args(timedelta_mode, bytes_mode).unwrap();
-        Self {
+        // the modes are function arguments here rather than schema config, so an invalid mode is a `ValueError`
+        let config = SerializationConfig::from_args(timedelta_mode, bytes_mode, inf_nan_mode)
+            .map_err(|err| PyValueError::new_err(err.value(py).to_string()))?;
+        Ok(Self {
             warnings,
             rec_guard,
             config,
-        }
+        })
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -90,6 +103,11 @@ pub(crate) struct Extra<'a> {
     pub field_name: Option<&'a str>,
     pub serialize_unknown: bool,
     pub fallback: Option<&'a PyAny>,
+    pub copy: CopyMode,
+    // models and dataclasses are serialized according to their runtime type rather than the schema
+    pub serialize_as_any: bool,
+    // passed to function serializers as `info.context`, it's not used by other serializers
+    pub context: Option<&'a PyAny>,
 }
 
 impl<'a> Extra<'a> {
@@ -126,12 +144,57 @@ impl<'a> Extra<'a> {
             field_name: None,
             serialize_unknown,
             fallback,
+            copy: CopyMode::Rebuild,
+            serialize_as_any: false,
+            context: None,
         }
     }
 
     pub fn serialize_infer<'py>(&'py self, value: &'py PyAny) -> super::infer::SerializeInfer<'py> {
         super::infer::SerializeInfer::new(value, None, None, self)
     }
+
+    /// with `copy='none'` or `'shallow'`, a container which can be returned instead of building a new one,
+    /// `exact_type` should be true if the container has the type of the output, `unchanged` if every item serialized
+    /// to itself and none were excluded
+    pub fn share_container(&self, container: &PyAny, exact_type: bool, unchanged: bool) -> Option<PyObject> {
+        match (self.copy, self.mode) {
+            (_, SerMode::Json) => None,
+            (CopyMode::None | CopyMode::Shallow, _) if exact_type && unchanged => {
+                Some(container.into_py(container.py()))
+            }
+            _ => None,
+        }
+    }
+
+    /// with `copy='shallow'`, `output` copied if it's the mutable container `value` returned as is, so only the
+    /// top level of the output is copied and nested values are shared with the input like with `copy='none'`
+    pub fn copy_top_level(&self, value: &PyAny, output: PyObject) -> PyResult<PyObject> {
+        let py = value.py();
+        match self.copy {
+            CopyMode::Shallow
+                if output.is(value)
+                    && (PyList::is_exact_type_of(value)
+                        || PyDict::is_exact_type_of(value)
+                        || PySet::is_exact_type_of(value)) =>
+            {
+                Ok(value.call_method0(intern!(py, "copy"))?.into_py(py))
+            }
+            _ => Ok(output),
+        }
+    }
+
+    /// a value included in the output of `to_python` as is, deep copied with `copy='deep'`
+    pub fn pass_through(&self, value: &PyAny) -> PyResult<PyObject> {
+        let py = value.py();
+        match self.copy {
+            CopyMode::Deep => Ok(py
+                .import(intern!(py, "copy"))?
+                .call_method1(intern!(py, "deepcopy"), (value,))?
+                .into_py(py)),
+            _ => Ok(value.into_py(py)),
+        }
+    }
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -169,6 +232,9 @@ pub(crate) struct ExtraOwned {
     field_name: Option<String>,
     serialize_unknown: bool,
     fallback: Option<PyObject>,
+    copy: CopyMode,
+    serialize_as_any: bool,
+    context: Option<PyObject>,
 }
 
 impl ExtraOwned {
@@ -189,6 +255,9 @@ impl ExtraOwned {
             field_name: extra.field_name.map(|v| v.to_string()),
             serialize_unknown: extra.serialize_unknown,
             fallback: extra.fallback.map(|v| v.into()),
+            copy: extra.copy,
+            serialize_as_any: extra.serialize_as_any,
+            context: extra.context.map(|v| v.into()),
         }
     }
 
@@ -210,6 +279,36 @@ impl ExtraOwned {
             field_name: self.field_name.as_ref().map(|n| n.as_ref()),
             serialize_unknown: self.serialize_unknown,
             fallback: self.fallback.as_ref().map(|m| m.as_ref(py)),
+            copy: self.copy,
+            serialize_as_any: self.serialize_as_any,
+            context: self.context.as_ref().map(|c| c.as_ref(py)),
+        }
+    }
+}
+
+/// How much of the output of `to_python` is shared with the input value, set with `copy`
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub(crate) enum CopyMode {
+    /// containers are returned as is when none of their items change or are excluded
+    None,
+    /// like `None`, but the top level of the output is never the input's list, dict or set, immutable containers
+    /// can still be shared
+    Shallow,
+    /// the default, containers are always rebuilt, other values are shared with the input
+    Rebuild,
+    /// like `Rebuild`, but values which would be shared with the input are copied with `copy.deepcopy`
+    Deep,
+}
+
+impl CopyMode {
+    pub fn from_str(s: Option<&str>) -> PyResult<Self> {
+        match s {
+            Some("none") => Ok(Self::None),
+            Some("shallow") => Ok(Self::Shallow),
+            None => Ok(Self::Rebuild),
+            Some("deep") => Ok(Self::Deep),
+            Some(s) => py_err!(PyValueError; "Invalid copy mode: `{}`, expected `none`, `shallow` or `deep`", s),
         }
     }
 }
@@ -262,22 +361,26 @@ impl ToPyObject for SerMode {
 #[derive(Clone)]
 #[cfg_attr(debug_assertions, derive(Debug))]
 pub(crate) struct CollectWarnings {
-    active: bool,
+    mode: WarningsMode,
     warnings: RefCell<Option<Vec<String>>>,
 }
 
 impl CollectWarnings {
-    pub(crate) fn new(active: bool) -> Self {
+    pub(crate) fn new(mode: WarningsMode) -> Self {
         Self {
-            active,
+            mode,
             warnings: RefCell::new(None),
         }
     }
 
-    pub fn custom_warning(&self, warning: String) {
-        if self.active {
-            self.add_warning(warning);
-        }
+    /// with `WarningsMode::Error`, the warning is raised as a `PydanticSerializationError`
+    pub fn custom_warning(&self, warning: String) -> PyResult<()> {
+        self.report(warning).map_err(PydanticSerializationError::new_err)
+    }
+
+    pub fn custom_warning_ser<S: serde::ser::Serializer>(&self, warning: String) -> Result<(), S::Error> {
+        self.report(warning)
+            .map_err(|msg| S::Error::custom(format!("{SERIALIZATION_ERR_MARKER}{msg}")))
     }
 
     pub fn on_fallback_py(&self, field_type: &str, value: &PyAny, extra: &Extra) -> PyResult<()> {
@@ -287,8 +390,8 @@ impl CollectWarnings {
         } else if extra.check.enabled() {
             Err(PydanticSerializationUnexpectedValue::new_err(None))
         } else {
-            self.fallback_warning(field_type, value);
-            Ok(())
+            self.fallback_warning(field_type, value, extra)
+                .map_err(PydanticSerializationError::new_err)
         }
     }
 
@@ -307,17 +410,41 @@ impl CollectWarnings {
             // in particular, in future we could allow errors instead of warnings on fallback
             Err(S::Error::custom(UNEXPECTED_TYPE_SER_MARKER))
         } else {
-            self.fallback_warning(field_type, value);
-            Ok(())
+            self.fallback_warning(field_type, value, extra)
+                .map_err(|msg| S::Error::custom(format!("{SERIALIZATION_ERR_MARKER}{msg}")))
+        }
+    }
+
+    /// the message of the error to raise with `WarningsMode::Error`, which includes the field name
+    fn fallback_warning(&self, field_type: &str, value: &PyAny, extra: &Extra) -> Result<(), String> {
+        let type_name = || value.get_type().name().unwrap_or("<unknown python object>");
+        match self.mode {
+            WarningsMode::None => Ok(()),
+            WarningsMode::Warn => {
+                self.add_warning(format!(
+                    "Expected `{field_type}` but got `{}` - serialized value may not be as expected",
+                    type_name()
+                ));
+                Ok(())
+            }
+            WarningsMode::Error => match extra.field_name {
+                Some(field_name) => Err(format!(
+                    "Expected `{field_type}` but got `{}` in field `{field_name}`",
+                    type_name()
+                )),
+                None => Err(format!("Expected `{field_type}` but got `{}`", type_name())),
+            },
         }
     }
 
-    fn fallback_warning(&self, field_type: &str, value: &PyAny) {
-        if self.active {
-            let type_name = value.get_type().name().unwrap_or("<unknown python object>");
-            self.add_warning(format!(
-                "Expected `{field_type}` but got `{type_name}` - serialized value may not be as expected"
-            ));
+    fn report(&self, message: String) -> Result<(), String> {
+        match self.mode {
+            WarningsMode::None => Ok(()),
+            WarningsMode::Warn => {
+                self.add_warning(message);
+                Ok(())
+            }
+            WarningsMode::Error => Err(message),
         }
     }
 
@@ -331,7 +458,7 @@ impl CollectWarnings {
     }
 
     pub fn final_check(&self, py: Python) -> PyResult<()> {
-        if self.active {
+        if self.mode == WarningsMode::Warn {
             match *self.warnings.borrow() {
                 Some(ref warnings) => {
                     let warnings = warnings.iter().map(|w| w.as_str()).collect::<Vec<_>>();
@@ -366,18 +493,39 @@ pub struct SerRecursionGuard {
 impl SerRecursionGuard {
     const MAX_DEPTH: u16 = 200;
 
-    pub fn add(&self, value: &PyAny) -> PyResult<usize> {
+    /// `schema_ref` is the definition being serialized, if any, it's included in errors
+    pub fn add(&self, value: &PyAny, schema_ref: Option<&str>) -> PyResult<usize> {
+        self.add_id(value.as_ptr() as usize, schema_ref)
+    }
+
+    /// used when a model is serialized with the serializer of its runtime type because of `serialize_as_any`,
+    /// the value has usually just been added by a definition reference, so it's tracked separately with the
+    /// lowest bit of its id set, which is otherwise always zero since objects are aligned
+    pub fn add_as_any(&self, value: &PyAny, schema_ref: &str) -> PyResult<usize> {
+        self.add_id(value.as_ptr() as usize | 1, Some(schema_ref))
+    }
+
+    fn add_id(&self, id: usize, schema_ref: Option<&str>) -> PyResult<usize> {
         // https://doc.rust-lang.org/std/collections/struct.HashSet.html#method.insert
         // "If the set did not have this value present, `true` is returned."
-        let id = value.as_ptr() as usize;
         let mut info = self.info.borrow_mut();
-        if !info.ids.insert(id) {
-            Err(PyValueError::new_err("Circular reference detected (id repeated)"))
+        let reason = if !info.ids.insert(id) {
+            "id repeated"
         } else if info.depth > Self::MAX_DEPTH {
-            Err(PyValueError::new_err("Circular reference detected (depth exceeded)"))
+            "depth exceeded"
         } else {
             info.depth += 1;
-            Ok(id)
+            return Ok(id);
+        };
+        // a `PydanticSerializationError` so its message isn't prefixed when serializing to JSON
+        match schema_ref {
+            Some(schema_ref) => py_err!(
+                PydanticSerializationError;
+                "Circular reference detected ({}) in '{}'",
+                reason,
+                schema_ref
+            ),
+            None => py_err!(PydanticSerializationError; "Circular reference detected ({})", reason),
         }
     }
 
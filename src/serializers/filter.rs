@@ -1,7 +1,7 @@
 use ahash::AHashSet;
 use std::hash::Hash;
 
-use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyBool, PyDict, PySet, PyString};
@@ -359,3 +359,106 @@ fn merge_dicts<'py>(item_dict: &'py PyDict, all_value: &'py PyAny) -> PyResult<&
     }
     Ok(item_dict)
 }
+
+/// Use `include_paths`/`exclude_paths` in place of `include`/`exclude` if they're provided, using both is an error.
+/// Paths are compiled once per call into the nested dict structure used for `include` and `exclude`.
+pub(crate) fn filter_with_paths<'py>(
+    py: Python<'py>,
+    filter: Option<&'py PyAny>,
+    paths: Option<&'py PyAny>,
+    filter_name: &str,
+) -> PyResult<Option<&'py PyAny>> {
+    match paths {
+        Some(paths) => {
+            if filter.is_some() {
+                return Err(PyTypeError::new_err(format!(
+                    "`{filter_name}` and `{filter_name}_paths` cannot be used together"
+                )));
+            }
+            Ok(Some(paths_to_filter(py, paths, filter_name)?))
+        }
+        None => Ok(filter),
+    }
+}
+
+fn paths_to_filter<'py>(py: Python<'py>, paths: &'py PyAny, filter_name: &str) -> PyResult<&'py PyAny> {
+    if paths.downcast::<PyString>().is_ok() {
+        return Err(PyTypeError::new_err(format!(
+            "`{filter_name}_paths` must be a collection of strings, not a single string"
+        )));
+    }
+    let filter = PyDict::new(py);
+    for path in paths.iter()? {
+        let path: &str = path?.extract()?;
+        let segments = parse_path(py, path)
+            .map_err(|msg| PyValueError::new_err(format!("Invalid path in `{filter_name}_paths`: '{path}', {msg}")))?;
+        insert_path(filter, &segments)?;
+    }
+    Ok(filter)
+}
+
+/// Split a dotted path like `items.*.internal_id` into keys: `*` becomes `__all__` to match any key or index,
+/// segments made of digits become int indices, and `\` escapes the following character so
+/// `a\.b` is the key `a.b`, `\*` is the key `*` and `\1` is the string key `1`.
+fn parse_path(py: Python, path: &str) -> Result<Vec<PyObject>, &'static str> {
+    let mut segments = Vec::new();
+    let mut segment = String::new();
+    let mut escaped = false;
+    let mut chars = path.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(c) => {
+                    segment.push(c);
+                    escaped = true;
+                }
+                None => return Err("`\\` must be followed by the character to escape"),
+            },
+            '.' => {
+                segments.push(segment_key(py, &segment, escaped)?);
+                segment.clear();
+                escaped = false;
+            }
+            c => segment.push(c),
+        }
+    }
+    segments.push(segment_key(py, &segment, escaped)?);
+    Ok(segments)
+}
+
+fn segment_key(py: Python, segment: &str, escaped: bool) -> Result<PyObject, &'static str> {
+    if escaped {
+        return Ok(segment.into_py(py));
+    }
+    if segment.is_empty() {
+        return Err("segments must not be empty");
+    }
+    if segment == "*" {
+        return Ok(intern!(py, "__all__").into_py(py));
+    }
+    if segment.bytes().all(|b| b.is_ascii_digit()) {
+        if let Ok(index) = segment.parse::<usize>() {
+            return Ok(index.into_py(py));
+        }
+    }
+    Ok(segment.into_py(py))
+}
+
+/// add a path to the filter, selecting a whole item takes precedence over selecting items within it
+fn insert_path(filter: &PyDict, segments: &[PyObject]) -> PyResult<()> {
+    let py = filter.py();
+    let (key, rest) = match segments.split_first() {
+        Some(split) => split,
+        None => return Ok(()),
+    };
+    match filter.get_item(key) {
+        Some(existing) if existing.is_ellipsis() => Ok(()),
+        _ if rest.is_empty() => filter.set_item(key, py.Ellipsis()),
+        Some(existing) => insert_path(existing.downcast()?, rest),
+        None => {
+            let sub_filter = PyDict::new(py);
+            filter.set_item(key, sub_filter)?;
+            insert_path(sub_filter, rest)
+        }
+    }
+}
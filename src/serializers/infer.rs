@@ -7,6 +7,7 @@ use pyo3::types::{
     PyByteArray, PyBytes, PyDate, PyDateTime, PyDelta, PyDict, PyFrozenSet, PyIterator, PyList, PySet, PyString,
     PyTime, PyTuple,
 };
+use pyo3::PyTypeInfo;
 
 use serde::ser::{Error, Serialize, SerializeMap, SerializeSeq, Serializer};
 
@@ -16,6 +17,7 @@ use crate::serializers::filter::SchemaFilter;
 use crate::serializers::{shared::TypeSerializer, SchemaSerializer};
 use crate::url::{PyMultiHostUrl, PyUrl};
 
+use super::config::InfNanMode;
 use super::errors::{py_err_se_err, PydanticSerializationError};
 use super::extra::{Extra, SerMode};
 use super::filter::AnyFilter;
@@ -39,7 +41,7 @@ pub(crate) fn infer_to_python_known(
     extra: &Extra,
 ) -> PyResult<PyObject> {
     let py = value.py();
-    let value_id = match extra.rec_guard.add(value) {
+    let value_id = match extra.rec_guard.add(value, None) {
         Ok(id) => id,
         Err(e) => {
             return match extra.mode {
@@ -76,6 +78,19 @@ pub(crate) fn infer_to_python_known(
         }};
     }
 
+    // with `copy='none'`, the container is returned rather than `$items` if they're unchanged and none were
+    // excluded, subclasses are still converted to `$t`
+    macro_rules! share_or {
+        ($t:ty, $items:ident, $new:expr) => {{
+            let py_seq: &$t = value.downcast()?;
+            let unchanged = $items.len() == py_seq.len() && $items.iter().zip(py_seq.iter()).all(|(a, b)| a.is(b));
+            match extra.share_container(value, <$t>::is_exact_type_of(value), unchanged) {
+                Some(shared) => shared,
+                None => $new,
+            }
+        }};
+    }
+
     let serialize_dict = |dict: &PyDict| {
         let new_dict = PyDict::new(py);
         let filter = AnyFilter::new();
@@ -93,10 +108,12 @@ pub(crate) fn infer_to_python_known(
     };
 
     let serialize_with_serializer = |value: &PyAny, is_model: bool| {
-        if let Ok(py_serializer) = value.getattr(intern!(py, "__pydantic_serializer__")) {
-            if let Ok(serializer) = py_serializer.extract::<SchemaSerializer>() {
-                return serializer.serializer.to_python(value, include, exclude, extra);
-            }
+        if let Some(schema_serializer) = get_pydantic_serializer(value) {
+            let extra = Extra {
+                slots: &schema_serializer.slots,
+                ..extra.clone()
+            };
+            return schema_serializer.serializer.to_python(value, include, exclude, &extra);
         }
         // Fallback to dict serialization if `__pydantic_serializer__` is not set.else
         // This is currently only relevant to non-pydantic dataclasses.
@@ -107,11 +124,24 @@ pub(crate) fn infer_to_python_known(
     let value = match extra.mode {
         SerMode::Json => match ob_type {
             // `bool` and `None` can't be subclasses, `ObType::Int`, `ObType::Float`, `ObType::Str` refer to exact types
-            ObType::None | ObType::Bool | ObType::Int | ObType::Float | ObType::Str => value.into_py(py),
+            ObType::None | ObType::Bool | ObType::Int | ObType::Str => value.into_py(py),
             // have to do this to make sure subclasses of for example str are upcast to `str`
             ObType::IntSubclass => value.extract::<i64>()?.into_py(py),
-            ObType::FloatSubclass => value.extract::<f64>()?.into_py(py),
-            ObType::Decimal => value.to_string().into_py(py),
+            ObType::Float | ObType::FloatSubclass => {
+                let float: f64 = value.extract()?;
+                match extra.config.inf_nan_mode.float_to_python(py, float) {
+                    Some(non_finite) => non_finite,
+                    None if matches!(ob_type, ObType::Float) => value.into_py(py),
+                    None => float.into_py(py),
+                }
+            }
+            ObType::Decimal => {
+                let decimal_str = value.to_string();
+                match InfNanMode::non_finite_decimal(&decimal_str) {
+                    Some(float) => extra.config.inf_nan_mode.float_to_python(py, float).unwrap(),
+                    None => decimal_str.into_py(py),
+                }
+            }
             ObType::StrSubclass => value.extract::<&str>()?.into_py(py),
             ObType::Bytes => extra
                 .config
@@ -194,6 +224,11 @@ pub(crate) fn infer_to_python_known(
                 PyList::new(py, items).into_py(py)
             }
             ObType::Path => value.str()?.into_py(py),
+            ObType::Uuid => value.str()?.into_py(py),
+            ObType::Pattern => {
+                let v = value.getattr(intern!(py, "pattern"))?;
+                infer_to_python(v, include, exclude, extra)?.into_py(py)
+            }
             ObType::Unknown => {
                 if let Some(fallback) = extra.fallback {
                     let next_value = fallback.call1((value,))?;
@@ -210,34 +245,41 @@ pub(crate) fn infer_to_python_known(
         _ => match ob_type {
             ObType::Tuple => {
                 let elements = serialize_seq_filter!(PyTuple);
-                PyTuple::new(py, elements).into_py(py)
+                share_or!(PyTuple, elements, PyTuple::new(py, elements).into_py(py))
             }
             ObType::List => {
                 let elements = serialize_seq_filter!(PyList);
-                PyList::new(py, elements).into_py(py)
+                share_or!(PyList, elements, PyList::new(py, elements).into_py(py))
             }
             ObType::Set => {
                 let elements = serialize_seq!(PySet);
-                PySet::new(py, &elements)?.into_py(py)
+                share_or!(PySet, elements, PySet::new(py, &elements)?.into_py(py))
             }
             ObType::Frozenset => {
                 let elements = serialize_seq!(PyFrozenSet);
-                PyFrozenSet::new(py, &elements)?.into_py(py)
+                share_or!(PyFrozenSet, elements, PyFrozenSet::new(py, &elements)?.into_py(py))
             }
             ObType::Dict => {
                 // different logic for keys from above
                 let dict: &PyDict = value.downcast()?;
                 let new_dict = PyDict::new(py);
                 let filter = AnyFilter::new();
+                let mut unchanged = true;
 
                 for (k, v) in dict {
                     let op_next = filter.key_filter(k, include, exclude)?;
                     if let Some((next_include, next_exclude)) = op_next {
-                        let v = infer_to_python(v, next_include, next_exclude, extra)?;
-                        new_dict.set_item(k, v)?;
+                        let new_v = infer_to_python(v, next_include, next_exclude, extra)?;
+                        unchanged &= new_v.is(v);
+                        new_dict.set_item(k, new_v)?;
+                    } else {
+                        unchanged = false;
                     }
                 }
-                new_dict.into_py(py)
+                match extra.share_container(value, PyDict::is_exact_type_of(value), unchanged) {
+                    Some(shared) => shared,
+                    None => new_dict.into_py(py),
+                }
             }
             ObType::Dataclass => serialize_dict(object_to_dict(value, false, extra)?)?,
             ObType::Model => serialize_dict(object_to_dict(value, true, extra)?)?,
@@ -259,10 +301,10 @@ pub(crate) fn infer_to_python_known(
                     extra.rec_guard.pop(value_id);
                     return next_result;
                 } else {
-                    value.into_py(py)
+                    extra.pass_through(value)?
                 }
             }
-            _ => value.into_py(py),
+            _ => extra.pass_through(value)?,
         },
     };
     extra.rec_guard.pop(value_id);
@@ -324,7 +366,7 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
     exclude: Option<&PyAny>,
     extra: &Extra,
 ) -> Result<S::Ok, S::Error> {
-    let value_id = match extra.rec_guard.add(value).map_err(py_err_se_err) {
+    let value_id = match extra.rec_guard.add(value, None).map_err(py_err_se_err) {
         Ok(v) => v,
         Err(e) => {
             return if extra.serialize_unknown {
@@ -392,8 +434,17 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
         ObType::None => serializer.serialize_none(),
         ObType::Int | ObType::IntSubclass => serialize!(i64),
         ObType::Bool => serialize!(bool),
-        ObType::Float | ObType::FloatSubclass => serialize!(f64),
-        ObType::Decimal => value.to_string().serialize(serializer),
+        ObType::Float | ObType::FloatSubclass => {
+            let float: f64 = value.extract().map_err(py_err_se_err)?;
+            extra.config.inf_nan_mode.serialize_float(float, serializer)
+        }
+        ObType::Decimal => {
+            let decimal_str = value.to_string();
+            match InfNanMode::non_finite_decimal(&decimal_str) {
+                Some(float) => extra.config.inf_nan_mode.serialize_float(float, serializer),
+                None => decimal_str.serialize(serializer),
+            }
+        }
         ObType::Str | ObType::StrSubclass => {
             let py_str: &PyString = value.downcast().map_err(py_err_se_err)?;
             super::type_serializers::string::serialize_py_str(py_str, serializer)
@@ -442,8 +493,22 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
             let py_url: PyMultiHostUrl = value.extract().map_err(py_err_se_err)?;
             serializer.serialize_str(&py_url.__str__())
         }
-        ObType::Dataclass => serialize_dict!(object_to_dict(value, false, extra).map_err(py_err_se_err)?),
-        ObType::Model => serialize_dict!(object_to_dict(value, true, extra).map_err(py_err_se_err)?),
+        ObType::Dataclass | ObType::Model => match get_pydantic_serializer(value) {
+            // use the object's own serializer so the output matches `to_python(mode='json')`
+            Some(schema_serializer) => {
+                let extra = Extra {
+                    slots: &schema_serializer.slots,
+                    ..extra.clone()
+                };
+                schema_serializer
+                    .serializer
+                    .serde_serialize(value, serializer, include, exclude, &extra)
+            }
+            None => {
+                let is_model = matches!(ob_type, ObType::Model);
+                serialize_dict!(object_to_dict(value, is_model, extra).map_err(py_err_se_err)?)
+            }
+        },
         ObType::Enum => {
             let v = value.getattr(intern!(value.py(), "value")).map_err(py_err_se_err)?;
             infer_serialize(v, serializer, include, exclude, extra)
@@ -466,6 +531,14 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
             let s = value.str().map_err(py_err_se_err)?.to_str().map_err(py_err_se_err)?;
             serializer.serialize_str(s)
         }
+        ObType::Uuid => {
+            let s = value.str().map_err(py_err_se_err)?.to_str().map_err(py_err_se_err)?;
+            serializer.serialize_str(s)
+        }
+        ObType::Pattern => {
+            let v = value.getattr(intern!(value.py(), "pattern")).map_err(py_err_se_err)?;
+            infer_serialize(v, serializer, include, exclude, extra)
+        }
         ObType::Unknown => {
             if let Some(fallback) = extra.fallback {
                 let next_value = fallback.call1((value,)).map_err(py_err_se_err)?;
@@ -488,6 +561,15 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
     ser_result
 }
 
+/// Get the `SchemaSerializer` from `__pydantic_serializer__` if it's set on the model or dataclass.
+fn get_pydantic_serializer(value: &PyAny) -> Option<SchemaSerializer> {
+    value
+        .getattr(intern!(value.py(), "__pydantic_serializer__"))
+        .ok()?
+        .extract::<SchemaSerializer>()
+        .ok()
+}
+
 fn unknown_type_error(value: &PyAny) -> PyErr {
     PydanticSerializationError::new_err(format!("Unable to serialize unknown type: {}", safe_repr(value)))
 }
@@ -576,6 +658,11 @@ pub(crate) fn infer_json_key_known<'py>(ob_type: &ObType, key: &'py PyAny, extra
             infer_json_key(k, extra)
         }
         ObType::Path => Ok(key.str()?.to_string_lossy()),
+        ObType::Uuid => Ok(Cow::Owned(key.str()?.to_string())),
+        ObType::Pattern => {
+            let k = key.getattr(intern!(key.py(), "pattern"))?;
+            infer_json_key(k, extra)
+        }
         ObType::Unknown => {
             if let Some(fallback) = extra.fallback {
                 let next_key = fallback.call1((key,))?;
@@ -1,35 +1,52 @@
 use std::fmt::Debug;
 
 use pyo3::prelude::*;
-use pyo3::types::{PyBytes, PyDict};
+use pyo3::types::{PyDict, PyTuple};
 use pyo3::{PyTraverseError, PyVisit};
 
 use crate::build_context::BuildContext;
+use crate::build_tools::check_picklable;
+use crate::memory_report::memory_report;
+use crate::sub_schema::find_sub_schema;
 use crate::validators::SelfValidator;
 
-use config::SerializationConfig;
+pub(crate) use config::BytesMode;
+use config::{SerializationConfig, WarningsMode};
 pub use errors::{PydanticSerializationError, PydanticSerializationUnexpectedValue};
-use extra::{CollectWarnings, SerRecursionGuard};
+use extra::{CollectWarnings, CopyMode, SerRecursionGuard};
 pub(crate) use extra::{Extra, SerMode, SerializationState};
+use filter::filter_with_paths;
+pub(crate) use infer::infer_to_python;
+use round_trip::RoundTripSchema;
 pub use shared::CombinedSerializer;
-use shared::{to_json_bytes, BuildSerializer, TypeSerializer};
+use shared::{to_json_bytes, to_json_into, to_json_py_bytes, BuildSerializer, TypeSerializer, JSON_CHUNK_SIZE};
 
+mod computed_fields;
 mod config;
 mod errors;
 mod extra;
 mod filter;
 mod infer;
 mod ob_type;
+mod round_trip;
 mod shared;
 mod type_serializers;
 
+/// the most memory kept between calls for JSON output, so one huge value doesn't pin a huge buffer
+const MAX_JSON_BUFFER_SIZE: usize = 1024 * 1024;
+
 #[pyclass(module = "pydantic_core._pydantic_core")]
 #[derive(Debug, Clone)]
 pub struct SchemaSerializer {
     serializer: CombinedSerializer,
     slots: Vec<CombinedSerializer>,
+    // the size of the last `to_json` output, used as the initial size of the next one
     json_size: usize,
+    // reused by `to_json_into` to collect chunks
+    json_buffer: Vec<u8>,
     config: SerializationConfig,
+    // `None` once cleared by the garbage collector
+    round_trip_schema: Option<RoundTripSchema>,
 }
 
 #[pymethods]
@@ -45,14 +62,17 @@ impl SchemaSerializer {
             serializer,
             slots: build_context.into_slots_ser()?,
             json_size: 1024,
+            json_buffer: Vec::new(),
             config: SerializationConfig::from_config(config)?,
+            round_trip_schema: Some(RoundTripSchema::new(schema.downcast()?, config)),
         })
     }
 
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (value, *, mode = None, include = None, exclude = None, by_alias = true,
-        exclude_unset = false, exclude_defaults = false, exclude_none = false, round_trip = false, warnings = true,
-        fallback = None))]
+    #[pyo3(signature = (value, *, mode = None, include = None, exclude = None, include_paths = None,
+        exclude_paths = None, by_alias = true, exclude_unset = false, exclude_defaults = false, exclude_none = false,
+        round_trip = false, warnings = None, fallback = None, serialize_as_any = false, context = None,
+        copy = None, round_trip_check = false))]
     pub fn to_python(
         &self,
         py: Python,
@@ -60,41 +80,62 @@ impl SchemaSerializer {
         mode: Option<&str>,
         include: Option<&PyAny>,
         exclude: Option<&PyAny>,
+        include_paths: Option<&PyAny>,
+        exclude_paths: Option<&PyAny>,
         by_alias: bool,
         exclude_unset: bool,
         exclude_defaults: bool,
         exclude_none: bool,
         round_trip: bool,
-        warnings: bool,
+        warnings: Option<&PyAny>,
         fallback: Option<&PyAny>,
+        serialize_as_any: bool,
+        context: Option<&PyAny>,
+        copy: Option<&str>,
+        round_trip_check: bool,
     ) -> PyResult<PyObject> {
+        let include = filter_with_paths(py, include, include_paths, "include")?;
+        let exclude = filter_with_paths(py, exclude, exclude_paths, "exclude")?;
         let mode: SerMode = mode.into();
-        let warnings = CollectWarnings::new(warnings);
+        let copy = CopyMode::from_str(copy)?;
+        let warnings = CollectWarnings::new(WarningsMode::from_arg(warnings, self.config.warnings_mode)?);
         let rec_guard = SerRecursionGuard::default();
-        let extra = Extra::new(
-            py,
-            &mode,
-            &self.slots,
-            by_alias,
-            &warnings,
-            exclude_unset,
-            exclude_defaults,
-            exclude_none,
-            round_trip,
-            &self.config,
-            &rec_guard,
-            false,
-            fallback,
-        );
+        let extra = Extra {
+            copy,
+            serialize_as_any,
+            context,
+            ..Extra::new(
+                py,
+                &mode,
+                &self.slots,
+                by_alias,
+                &warnings,
+                exclude_unset,
+                exclude_defaults,
+                exclude_none,
+                round_trip,
+                &self.config,
+                &rec_guard,
+                false,
+                fallback,
+            )
+        };
         let v = self.serializer.to_python(value, include, exclude, &extra)?;
+        let v = extra.copy_top_level(value, v)?;
+        if round_trip_check {
+            self.round_trip_schema()?.check_python(py, v.as_ref(py), |validated| {
+                self.serializer.to_python(validated, include, exclude, &extra)
+            })?;
+        }
         warnings.final_check(py)?;
         Ok(v)
     }
 
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (value, *, indent = None, include = None, exclude = None, by_alias = true,
-        exclude_unset = false, exclude_defaults = false, exclude_none = false, round_trip = false, warnings = true,
-        fallback = None))]
+    #[pyo3(signature = (value, *, indent = None, include = None, exclude = None, include_paths = None,
+        exclude_paths = None, by_alias = true, exclude_unset = false, exclude_defaults = false, exclude_none = false,
+        round_trip = false, warnings = None, fallback = None, serialize_as_any = false, context = None,
+        round_trip_check = false))]
     pub fn to_json(
         &mut self,
         py: Python,
@@ -102,32 +143,44 @@ impl SchemaSerializer {
         indent: Option<usize>,
         include: Option<&PyAny>,
         exclude: Option<&PyAny>,
+        include_paths: Option<&PyAny>,
+        exclude_paths: Option<&PyAny>,
         by_alias: bool,
         exclude_unset: bool,
         exclude_defaults: bool,
         exclude_none: bool,
         round_trip: bool,
-        warnings: bool,
+        warnings: Option<&PyAny>,
         fallback: Option<&PyAny>,
+        serialize_as_any: bool,
+        context: Option<&PyAny>,
+        round_trip_check: bool,
     ) -> PyResult<PyObject> {
-        let warnings = CollectWarnings::new(warnings);
+        let include = filter_with_paths(py, include, include_paths, "include")?;
+        let exclude = filter_with_paths(py, exclude, exclude_paths, "exclude")?;
+        let warnings = CollectWarnings::new(WarningsMode::from_arg(warnings, self.config.warnings_mode)?);
         let rec_guard = SerRecursionGuard::default();
-        let extra = Extra::new(
+        let extra = Extra {
+            serialize_as_any,
+            context,
+            ..Extra::new(
+                py,
+                &SerMode::Json,
+                &self.slots,
+                by_alias,
+                &warnings,
+                exclude_unset,
+                exclude_defaults,
+                exclude_none,
+                round_trip,
+                &self.config,
+                &rec_guard,
+                false,
+                fallback,
+            )
+        };
+        let py_bytes = to_json_py_bytes(
             py,
-            &SerMode::Json,
-            &self.slots,
-            by_alias,
-            &warnings,
-            exclude_unset,
-            exclude_defaults,
-            exclude_none,
-            round_trip,
-            &self.config,
-            &rec_guard,
-            false,
-            fallback,
-        );
-        let bytes = to_json_bytes(
             value,
             &self.serializer,
             include,
@@ -136,14 +189,94 @@ impl SchemaSerializer {
             indent,
             self.json_size,
         )?;
+        let bytes = py_bytes.as_bytes();
+        if round_trip_check {
+            self.round_trip_schema()?.check_json(py, bytes, |validated| {
+                to_json_bytes(
+                    validated,
+                    &self.serializer,
+                    include,
+                    exclude,
+                    &extra,
+                    indent,
+                    bytes.len(),
+                )
+            })?;
+        }
 
         warnings.final_check(py)?;
 
-        self.json_size = bytes.len();
-        let py_bytes = PyBytes::new(py, &bytes);
+        self.json_size = bytes.len().min(MAX_JSON_BUFFER_SIZE);
         Ok(py_bytes.into())
     }
 
+    /// Like `to_json` but writes the JSON to `buffer`, see `shared::to_json_into`
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (value, buffer, *, indent = None, include = None, exclude = None, include_paths = None,
+        exclude_paths = None, by_alias = true, exclude_unset = false, exclude_defaults = false, exclude_none = false,
+        round_trip = false, warnings = None, fallback = None, serialize_as_any = false, context = None))]
+    pub fn to_json_into(
+        &mut self,
+        py: Python,
+        value: &PyAny,
+        buffer: &PyAny,
+        indent: Option<usize>,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        include_paths: Option<&PyAny>,
+        exclude_paths: Option<&PyAny>,
+        by_alias: bool,
+        exclude_unset: bool,
+        exclude_defaults: bool,
+        exclude_none: bool,
+        round_trip: bool,
+        warnings: Option<&PyAny>,
+        fallback: Option<&PyAny>,
+        serialize_as_any: bool,
+        context: Option<&PyAny>,
+    ) -> PyResult<usize> {
+        let include = filter_with_paths(py, include, include_paths, "include")?;
+        let exclude = filter_with_paths(py, exclude, exclude_paths, "exclude")?;
+        let warnings = CollectWarnings::new(WarningsMode::from_arg(warnings, self.config.warnings_mode)?);
+        let rec_guard = SerRecursionGuard::default();
+        let extra = Extra {
+            serialize_as_any,
+            context,
+            ..Extra::new(
+                py,
+                &SerMode::Json,
+                &self.slots,
+                by_alias,
+                &warnings,
+                exclude_unset,
+                exclude_defaults,
+                exclude_none,
+                round_trip,
+                &self.config,
+                &rec_guard,
+                false,
+                fallback,
+            )
+        };
+        let result = to_json_into(
+            value,
+            &self.serializer,
+            include,
+            exclude,
+            &extra,
+            indent,
+            buffer,
+            &mut self.json_buffer,
+        );
+        // a single long string can grow the buffer well past the chunk size, don't keep all of that
+        if self.json_buffer.capacity() > MAX_JSON_BUFFER_SIZE {
+            self.json_buffer = Vec::with_capacity(JSON_CHUNK_SIZE);
+        }
+        let written = result?;
+        warnings.final_check(py)?;
+        Ok(written)
+    }
+
     pub fn __repr__(&self) -> String {
         format!(
             "SchemaSerializer(serializer={:#?}, slots={:#?})",
@@ -151,11 +284,48 @@ impl SchemaSerializer {
         )
     }
 
+    /// like `SchemaValidator.__reduce__`, pickling rebuilds the serializer from its schema and config
+    pub fn __reduce__(&self, py: Python) -> PyResult<PyObject> {
+        let schema = self.round_trip_schema()?;
+        check_picklable(py, "SchemaSerializer", schema.schema(py), schema.config(py))?;
+        let args = (schema.schema(py), schema.config(py));
+        Ok((py.get_type::<Self>(), args).into_py(py))
+    }
+
+    fn __copy__(slf: &PyCell<Self>) -> &PyCell<Self> {
+        slf
+    }
+
+    fn __deepcopy__<'py>(slf: &'py PyCell<Self>, _memo: &PyAny) -> &'py PyCell<Self> {
+        slf
+    }
+
+    /// like `SchemaValidator.memory_report`, with the size of serializers
+    pub fn memory_report(&self, py: Python) -> PyResult<Py<PyDict>> {
+        memory_report(
+            py,
+            self.round_trip_schema()?.schema(py),
+            self.slots.len(),
+            std::mem::size_of::<CombinedSerializer>(),
+        )
+    }
+
+    /// a serializer for just the schema at `path`, e.g. a single field, built with this serializer's config,
+    /// see `find_sub_schema` for the segments a path can contain
+    pub fn sub_serializer(&self, py: Python, path: &PyTuple) -> PyResult<Self> {
+        let schema = self.round_trip_schema()?;
+        let sub_schema = find_sub_schema(schema.schema(py), path)?;
+        Self::py_new(py, sub_schema.schema, schema.config(py))
+    }
+
     fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
         self.serializer.py_gc_traverse(&visit)?;
         for slot in self.slots.iter() {
             slot.py_gc_traverse(&visit)?;
         }
+        if let Some(ref round_trip_schema) = self.round_trip_schema {
+            round_trip_schema.py_gc_traverse(&visit)?;
+        }
         Ok(())
     }
 
@@ -164,61 +334,85 @@ impl SchemaSerializer {
         for slot in self.slots.iter_mut() {
             slot.py_gc_clear();
         }
+        self.round_trip_schema = None;
+    }
+}
+
+impl SchemaSerializer {
+    fn round_trip_schema(&self) -> PyResult<&RoundTripSchema> {
+        match self.round_trip_schema {
+            Some(ref round_trip_schema) => Ok(round_trip_schema),
+            None => Err(PydanticSerializationError::new_err(
+                "The serializer has been cleared".to_string(),
+            )),
+        }
     }
 }
 
 #[allow(clippy::too_many_arguments)]
 #[pyfunction]
-#[pyo3(signature = (value, *, indent = None, include = None, exclude = None, exclude_none = false, round_trip = false,
-    timedelta_mode = None, bytes_mode = None, serialize_unknown = false, fallback = None))]
+#[pyo3(signature = (value, *, indent = None, include = None, exclude = None, include_paths = None,
+    exclude_paths = None, by_alias = true, exclude_none = false, round_trip = false, timedelta_mode = None,
+    bytes_mode = None, inf_nan_mode = None, serialize_unknown = false, fallback = None))]
 pub fn to_json(
     py: Python,
     value: &PyAny,
     indent: Option<usize>,
     include: Option<&PyAny>,
     exclude: Option<&PyAny>,
+    include_paths: Option<&PyAny>,
+    exclude_paths: Option<&PyAny>,
+    by_alias: bool,
     exclude_none: bool,
     round_trip: bool,
     timedelta_mode: Option<&str>,
     bytes_mode: Option<&str>,
+    inf_nan_mode: Option<&str>,
     serialize_unknown: bool,
     fallback: Option<&PyAny>,
 ) -> PyResult<PyObject> {
-    let state = SerializationState::new(timedelta_mode, bytes_mode);
+    let include = filter_with_paths(py, include, include_paths, "include")?;
+    let exclude = filter_with_paths(py, exclude, exclude_paths, "exclude")?;
+    let state = SerializationState::new(py, timedelta_mode, bytes_mode, inf_nan_mode)?;
     let extra = state.extra(
         py,
         &SerMode::Json,
-        true,
+        by_alias,
         exclude_none,
         round_trip,
         serialize_unknown,
         fallback,
     );
     let serializer = type_serializers::any::AnySerializer::default().into();
-    let bytes = to_json_bytes(value, &serializer, include, exclude, &extra, indent, 1024)?;
+    let py_bytes = to_json_py_bytes(py, value, &serializer, include, exclude, &extra, indent, 1024)?;
     state.final_check(py)?;
-    let py_bytes = PyBytes::new(py, &bytes);
     Ok(py_bytes.into())
 }
 
 #[allow(clippy::too_many_arguments)]
 #[pyfunction]
-#[pyo3(signature = (value, *, include = None, exclude = None, by_alias = true, exclude_none = false, round_trip = false,
-    timedelta_mode = None, bytes_mode = None, serialize_unknown = false, fallback = None))]
+#[pyo3(signature = (value, *, include = None, exclude = None, include_paths = None, exclude_paths = None,
+    by_alias = true, exclude_none = false, round_trip = false, timedelta_mode = None, bytes_mode = None,
+    inf_nan_mode = None, serialize_unknown = false, fallback = None))]
 pub fn to_jsonable_python(
     py: Python,
     value: &PyAny,
     include: Option<&PyAny>,
     exclude: Option<&PyAny>,
+    include_paths: Option<&PyAny>,
+    exclude_paths: Option<&PyAny>,
     by_alias: bool,
     exclude_none: bool,
     round_trip: bool,
     timedelta_mode: Option<&str>,
     bytes_mode: Option<&str>,
+    inf_nan_mode: Option<&str>,
     serialize_unknown: bool,
     fallback: Option<&PyAny>,
 ) -> PyResult<PyObject> {
-    let state = SerializationState::new(timedelta_mode, bytes_mode);
+    let include = filter_with_paths(py, include, include_paths, "include")?;
+    let exclude = filter_with_paths(py, exclude, exclude_paths, "exclude")?;
+    let state = SerializationState::new(py, timedelta_mode, bytes_mode, inf_nan_mode)?;
     let extra = state.extra(
         py,
         &SerMode::Json,
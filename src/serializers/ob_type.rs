@@ -45,6 +45,10 @@ pub struct ObTypeLookup {
     generator: usize,
     // path
     path: usize,
+    // uuid
+    uuid: usize,
+    // regex pattern
+    pattern: usize,
 }
 
 static TYPE_LOOKUP: GILOnceCell<ObTypeLookup> = GILOnceCell::new();
@@ -83,6 +87,8 @@ impl ObTypeLookup {
             enum_type: py.import("enum").unwrap().getattr("Enum").unwrap().get_type_ptr() as usize,
             generator: py.import("types").unwrap().getattr("GeneratorType").unwrap().as_ptr() as usize,
             path: py.import("pathlib").unwrap().getattr("Path").unwrap().as_ptr() as usize,
+            uuid: py.import("uuid").unwrap().getattr("UUID").unwrap().as_ptr() as usize,
+            pattern: py.import("re").unwrap().getattr("Pattern").unwrap().as_ptr() as usize,
         }
     }
 
@@ -130,6 +136,8 @@ impl ObTypeLookup {
             ObType::Enum => self.enum_type == ob_type,
             ObType::Generator => self.generator == ob_type,
             ObType::Path => self.path == ob_type,
+            ObType::Uuid => self.uuid == ob_type,
+            ObType::Pattern => self.pattern == ob_type,
             ObType::Unknown => false,
         };
 
@@ -218,6 +226,10 @@ impl ObTypeLookup {
             ObType::Generator
         } else if ob_type == self.path {
             ObType::Path
+        } else if ob_type == self.uuid {
+            ObType::Uuid
+        } else if ob_type == self.pattern {
+            ObType::Pattern
         } else {
             // this allows for subtypes of the supported class types,
             // if `ob_type` didn't match any member of self, we try again with the next base type pointer
@@ -314,6 +326,10 @@ pub enum ObType {
     Generator,
     // Path
     Path,
+    // UUID
+    Uuid,
+    // regex pattern, serialized as its source string
+    Pattern,
     // unknown type
     Unknown,
 }
@@ -0,0 +1,173 @@
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict, PyFloat, PyList, PyTuple};
+
+use crate::input::JsonInput;
+use crate::validators::SchemaValidator;
+
+use super::errors::PydanticSerializationError;
+
+/// the schema and config a serializer was built from, used to build a validator for `round_trip_check`
+/// and to build serializers for `sub_serializer`
+#[derive(Debug, Clone)]
+pub(super) struct RoundTripSchema {
+    schema: Py<PyDict>,
+    config: Option<Py<PyDict>>,
+}
+
+impl RoundTripSchema {
+    pub fn new(schema: &PyDict, config: Option<&PyDict>) -> Self {
+        Self {
+            schema: schema.into(),
+            config: config.map(Into::into),
+        }
+    }
+
+    pub fn py_gc_traverse(&self, visit: &pyo3::PyVisit<'_>) -> Result<(), pyo3::PyTraverseError> {
+        visit.call(&self.schema)?;
+        if let Some(ref config) = self.config {
+            visit.call(config)?;
+        }
+        Ok(())
+    }
+
+    pub fn schema<'py>(&'py self, py: Python<'py>) -> &'py PyDict {
+        self.schema.as_ref(py)
+    }
+
+    pub fn config<'py>(&'py self, py: Python<'py>) -> Option<&'py PyDict> {
+        self.config.as_ref().map(|c| c.as_ref(py))
+    }
+
+    fn validator(&self, py: Python) -> PyResult<SchemaValidator> {
+        SchemaValidator::py_new(py, self.schema(py), self.config(py))
+    }
+
+    /// validate `output` from `to_python` and serialize the result again with `serialize`,
+    /// raising an error pointing at the first value which doesn't match the original output
+    pub fn check_python(
+        &self,
+        py: Python,
+        output: &PyAny,
+        serialize: impl FnOnce(&PyAny) -> PyResult<PyObject>,
+    ) -> PyResult<()> {
+        let validated = self
+            .validator(py)?
+            .validate_python(py, output, None, None, None, None, false, None, None)
+            .map_err(|err| invalid_output_err(py, err))?;
+        let reserialized = serialize(validated.as_ref(py))?;
+        check_equal(output, reserialized.as_ref(py))
+    }
+
+    /// like `check_python` but for JSON from `to_json`, the JSON is validated with `validate_json`
+    pub fn check_json(
+        &self,
+        py: Python,
+        output: &[u8],
+        serialize: impl FnOnce(&PyAny) -> PyResult<Vec<u8>>,
+    ) -> PyResult<()> {
+        let validated = self
+            .validator(py)?
+            .validate_json(py, PyBytes::new(py, output), None, None, None, None, false, None, None)
+            .map_err(|err| invalid_output_err(py, err))?;
+        let reserialized = serialize(validated.as_ref(py))?;
+        if reserialized == output {
+            return Ok(());
+        }
+        check_equal(
+            parse_json(py, output)?.as_ref(py),
+            parse_json(py, &reserialized)?.as_ref(py),
+        )
+    }
+}
+
+fn invalid_output_err(py: Python, err: PyErr) -> PyErr {
+    let msg = match err.value(py).str() {
+        Ok(s) => s.to_string_lossy().to_string(),
+        Err(_) => err.to_string(),
+    };
+    PydanticSerializationError::new_err(format!("Round-trip check failed, the output is invalid:\n{msg}"))
+}
+
+fn parse_json(py: Python, json: &[u8]) -> PyResult<PyObject> {
+    match serde_json::from_slice::<JsonInput>(json) {
+        Ok(json_input) => Ok(json_input.to_object(py)),
+        Err(err) => Err(PydanticSerializationError::new_err(format!(
+            "Round-trip check failed, invalid JSON: {err}"
+        ))),
+    }
+}
+
+fn check_equal(output: &PyAny, reserialized: &PyAny) -> PyResult<()> {
+    let mut path: Vec<String> = Vec::new();
+    match first_difference(output, reserialized, &mut path)? {
+        None => Ok(()),
+        Some((original, new)) => {
+            let location = match path.is_empty() {
+                true => String::new(),
+                false => format!(" at `{}`", path.join(".")),
+            };
+            Err(PydanticSerializationError::new_err(format!(
+                "Round-trip check failed{location}, {} was serialized as {} after validating it",
+                original.repr()?,
+                new.repr()?,
+            )))
+        }
+    }
+}
+
+/// find the first value which differs between `a` and `b`, descending into dicts, lists and tuples,
+/// `path` is left as the location of the difference
+fn first_difference<'py>(
+    a: &'py PyAny,
+    b: &'py PyAny,
+    path: &mut Vec<String>,
+) -> PyResult<Option<(&'py PyAny, &'py PyAny)>> {
+    if let (Ok(a_dict), Ok(b_dict)) = (a.downcast::<PyDict>(), b.downcast::<PyDict>()) {
+        if a_dict.len() == b_dict.len() {
+            for (key, a_value) in a_dict {
+                let b_value = match b_dict.get_item(key) {
+                    Some(b_value) => b_value,
+                    None => return Ok(Some((a, b))),
+                };
+                path.push(key.str()?.to_string_lossy().to_string());
+                if let Some(difference) = first_difference(a_value, b_value, path)? {
+                    return Ok(Some(difference));
+                }
+                path.pop();
+            }
+            return Ok(None);
+        }
+    } else if let (Ok(a_list), Ok(b_list)) = (a.downcast::<PyList>(), b.downcast::<PyList>()) {
+        if a_list.len() == b_list.len() {
+            return items_difference(a_list.iter().zip(b_list.iter()), path);
+        }
+    } else if let (Ok(a_tuple), Ok(b_tuple)) = (a.downcast::<PyTuple>(), b.downcast::<PyTuple>()) {
+        if a_tuple.len() == b_tuple.len() {
+            return items_difference(a_tuple.iter().zip(b_tuple.iter()), path);
+        }
+    } else if a.is(b) || a.eq(b)? || is_nan(a) && is_nan(b) {
+        return Ok(None);
+    }
+    Ok(Some((a, b)))
+}
+
+fn items_difference<'py>(
+    items: impl Iterator<Item = (&'py PyAny, &'py PyAny)>,
+    path: &mut Vec<String>,
+) -> PyResult<Option<(&'py PyAny, &'py PyAny)>> {
+    for (index, (a_item, b_item)) in items.enumerate() {
+        path.push(index.to_string());
+        if let Some(difference) = first_difference(a_item, b_item, path)? {
+            return Ok(Some(difference));
+        }
+        path.pop();
+    }
+    Ok(None)
+}
+
+fn is_nan(value: &PyAny) -> bool {
+    match value.downcast::<PyFloat>() {
+        Ok(float) => float.value().is_nan(),
+        Err(_) => false,
+    }
+}
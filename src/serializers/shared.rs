@@ -1,10 +1,12 @@
 use std::borrow::Cow;
 use std::fmt::Debug;
+use std::io;
+use std::ptr;
 
 use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PySet};
-use pyo3::{intern, PyTraverseError, PyVisit};
+use pyo3::types::{PyByteArray, PyBytes, PyDict, PySet};
+use pyo3::{ffi, intern, PyTraverseError, PyVisit};
 
 use enum_dispatch::enum_dispatch;
 use serde::Serialize;
@@ -87,10 +89,23 @@ combined_serializer! {
         super::type_serializers::other::CustomErrorBuilder;
         super::type_serializers::other::CallBuilder;
         super::type_serializers::other::LaxOrStrictBuilder;
+        super::type_serializers::other::JsonOrPythonBuilder;
         super::type_serializers::other::ArgumentsBuilder;
         super::type_serializers::other::IsInstanceBuilder;
         super::type_serializers::other::IsSubclassBuilder;
         super::type_serializers::other::CallableBuilder;
+        super::type_serializers::other::MergeBuilder;
+        super::type_serializers::other::DecimalBuilder;
+        super::type_serializers::other::UuidBuilder;
+        super::type_serializers::other::EnumBuilder;
+        super::type_serializers::other::IpV4AddressBuilder;
+        super::type_serializers::other::IpV6AddressBuilder;
+        super::type_serializers::other::IpV4NetworkBuilder;
+        super::type_serializers::other::IpV6NetworkBuilder;
+        super::type_serializers::other::IpV4InterfaceBuilder;
+        super::type_serializers::other::IpV6InterfaceBuilder;
+        super::type_serializers::other::TypeVarBuilder;
+        super::type_serializers::other::ParametrizedBuilder;
         super::type_serializers::definitions::DefinitionsBuilder;
         super::type_serializers::dataclass::DataclassArgsBuilder;
         super::type_serializers::dataclass::DataclassBuilder;
@@ -106,7 +121,7 @@ combined_serializer! {
         Nullable: super::type_serializers::nullable::NullableSerializer;
         Int: super::type_serializers::simple::IntSerializer;
         Bool: super::type_serializers::simple::BoolSerializer;
-        Float: super::type_serializers::simple::FloatSerializer;
+        Float: super::type_serializers::float::FloatSerializer;
         Str: super::type_serializers::string::StrSerializer;
         Bytes: super::type_serializers::bytes::BytesSerializer;
         Datetime: super::type_serializers::datetime_etc::DatetimeSerializer;
@@ -122,6 +137,10 @@ combined_serializer! {
         Model: super::type_serializers::model::ModelSerializer;
         Url: super::type_serializers::url::UrlSerializer;
         MultiHostUrl: super::type_serializers::url::MultiHostUrlSerializer;
+        Path: super::type_serializers::path::PathSerializer;
+        Timezone: super::type_serializers::timezone::TimezoneSerializer;
+        Fraction: super::type_serializers::fraction::FractionSerializer;
+        Complex: super::type_serializers::complex::ComplexSerializer;
         Any: super::type_serializers::any::AnySerializer;
         Format: super::type_serializers::format::FormatSerializer;
         ToString: super::type_serializers::format::ToStringSerializer;
@@ -210,15 +229,16 @@ impl BuildSerializer for CombinedSerializer {
                 if build_context.ref_already_used(&schema_ref) {
                     return py_err!("Duplicate ref: `{}`", schema_ref);
                 }
+                build_context.check_generic_ref(schema, &schema_ref)?;
 
                 return if build_context.ref_used_within(schema, &schema_ref)? {
                     // the ref is used within itself, so we have to store the serializer in slots
                     // and return a DefinitionRefSerializer
-                    let slot_id = build_context.prepare_slot(schema_ref, None)?;
+                    let slot_id = build_context.prepare_slot(schema_ref.clone(), None)?;
                     let inner_ser = Self::_build(schema, config, build_context)?;
                     build_context.complete_slot(slot_id, inner_ser)?;
                     Ok(super::type_serializers::definitions::DefinitionRefSerializer::from_id(
-                        slot_id,
+                        slot_id, schema_ref,
                     ))
                 } else {
                     // the ref is used elsewhere, so we want to clone it each time it's used
@@ -318,7 +338,6 @@ impl<'py> Serialize for PydanticSerializer<'py> {
     }
 }
 
-#[allow(clippy::too_many_arguments)]
 pub(crate) fn to_json_bytes(
     value: &PyAny,
     serializer: &CombinedSerializer,
@@ -328,10 +347,73 @@ pub(crate) fn to_json_bytes(
     indent: Option<usize>,
     json_size: usize,
 ) -> PyResult<Vec<u8>> {
+    let writer: Vec<u8> = Vec::with_capacity(json_size);
+    serialize_json(value, serializer, include, exclude, extra, indent, writer)
+}
+
+/// like `to_json_bytes` but the JSON is written straight into a python `bytes` object, so it isn't copied
+/// from a rust buffer afterwards
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn to_json_py_bytes<'py>(
+    py: Python<'py>,
+    value: &PyAny,
+    serializer: &CombinedSerializer,
+    include: Option<&PyAny>,
+    exclude: Option<&PyAny>,
+    extra: &Extra,
+    indent: Option<usize>,
+    json_size: usize,
+) -> PyResult<&'py PyBytes> {
+    let writer = PyBytesWriter::new(py, json_size)?;
+    serialize_json(value, serializer, include, exclude, extra, indent, writer)?.finish()
+}
+
+/// the size of the chunks `to_json_into` passes to a writer
+pub(crate) const JSON_CHUNK_SIZE: usize = 64 * 1024;
+
+/// like `to_json_bytes` but the JSON is written to `target`, either a `bytearray` which is extended in place or
+/// any object with a `write(bytes)` method, in chunks of about `JSON_CHUNK_SIZE` bytes collected in `buffer`,
+/// returns the number of bytes written.
+///
+/// If serialization fails, or `target` raises an error, whatever was written before the error stays in `target`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn to_json_into(
+    value: &PyAny,
+    serializer: &CombinedSerializer,
+    include: Option<&PyAny>,
+    exclude: Option<&PyAny>,
+    extra: &Extra,
+    indent: Option<usize>,
+    target: &PyAny,
+    buffer: &mut Vec<u8>,
+) -> PyResult<usize> {
+    buffer.clear();
+    let mut writer = PyChunkWriter {
+        target: JsonTarget::new(target)?,
+        buffer,
+        written: 0,
+        error: None,
+    };
+    if let Err(err) = serialize_json(value, serializer, include, exclude, extra, indent, &mut writer) {
+        // errors from `target` reach us via serde as an `io::Error`, raise the original exception instead
+        return Err(writer.error.take().unwrap_or(err));
+    }
+    writer.write_chunk()?;
+    Ok(writer.written)
+}
+
+fn serialize_json<W: io::Write>(
+    value: &PyAny,
+    serializer: &CombinedSerializer,
+    include: Option<&PyAny>,
+    exclude: Option<&PyAny>,
+    extra: &Extra,
+    indent: Option<usize>,
+    writer: W,
+) -> PyResult<W> {
     let serializer = PydanticSerializer::new(value, serializer, include, exclude, extra);
 
-    let writer: Vec<u8> = Vec::with_capacity(json_size);
-    let bytes = match indent {
+    let writer = match indent {
         Some(indent) => {
             let indent = vec![b' '; indent];
             let formatter = PrettyFormatter::with_indent(&indent);
@@ -345,7 +427,92 @@ pub(crate) fn to_json_bytes(
             ser.into_inner()
         }
     };
-    Ok(bytes)
+    Ok(writer)
+}
+
+/// Writes into the buffer of a new `bytes` object, growing it with `_PyBytes_Resize` which is allowed since
+/// the object isn't shared until `finish` returns it
+struct PyBytesWriter<'py> {
+    py: Python<'py>,
+    // null only if resizing failed, in which case the object has already been freed
+    bytes: *mut ffi::PyObject,
+    // the start of the buffer of `bytes`, which moves when it's resized
+    data: *mut u8,
+    len: usize,
+    capacity: usize,
+}
+
+impl<'py> PyBytesWriter<'py> {
+    fn new(py: Python<'py>, capacity: usize) -> PyResult<Self> {
+        let capacity = capacity.max(64);
+        let bytes = unsafe { ffi::PyBytes_FromStringAndSize(ptr::null(), capacity as ffi::Py_ssize_t) };
+        if bytes.is_null() {
+            return Err(PyErr::fetch(py));
+        }
+        Ok(Self {
+            py,
+            bytes,
+            data: unsafe { ffi::PyBytes_AsString(bytes) as *mut u8 },
+            len: 0,
+            capacity,
+        })
+    }
+
+    fn resize(&mut self, size: usize) -> PyResult<()> {
+        if unsafe { ffi::_PyBytes_Resize(&mut self.bytes, size as ffi::Py_ssize_t) } != 0 {
+            return Err(PyErr::fetch(self.py));
+        }
+        self.data = unsafe { ffi::PyBytes_AsString(self.bytes) as *mut u8 };
+        self.capacity = size;
+        Ok(())
+    }
+
+    fn finish(mut self) -> PyResult<&'py PyBytes> {
+        if self.len != self.capacity {
+            self.resize(self.len)?;
+        }
+        let bytes = std::mem::replace(&mut self.bytes, ptr::null_mut());
+        Ok(unsafe { self.py.from_owned_ptr(bytes) })
+    }
+}
+
+impl<'py> PyBytesWriter<'py> {
+    #[cold]
+    fn grow(&mut self, required: usize) -> io::Result<()> {
+        self.resize(required.max(self.capacity * 2))
+            .map_err(|err| io::Error::new(io::ErrorKind::OutOfMemory, err.to_string()))
+    }
+}
+
+impl<'py> io::Write for PyBytesWriter<'py> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_all(buf)?;
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        let required = self.len + buf.len();
+        if required > self.capacity {
+            self.grow(required)?;
+        }
+        unsafe { ptr::copy_nonoverlapping(buf.as_ptr(), self.data.add(self.len), buf.len()) };
+        self.len = required;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'py> Drop for PyBytesWriter<'py> {
+    fn drop(&mut self) {
+        if !self.bytes.is_null() {
+            unsafe { ffi::Py_DECREF(self.bytes) };
+        }
+    }
 }
 
 pub(super) fn object_to_dict<'py>(value: &'py PyAny, is_model: bool, extra: &Extra) -> PyResult<&'py PyDict> {
@@ -366,3 +533,80 @@ pub(super) fn object_to_dict<'py>(value: &'py PyAny, is_model: bool, extra: &Ext
         Ok(attrs)
     }
 }
+
+enum JsonTarget<'py> {
+    ByteArray(&'py PyByteArray),
+    Writer(&'py PyAny),
+}
+
+impl<'py> JsonTarget<'py> {
+    fn new(target: &'py PyAny) -> PyResult<Self> {
+        if let Ok(byte_array) = target.downcast::<PyByteArray>() {
+            Ok(Self::ByteArray(byte_array))
+        } else if target.hasattr(intern!(target.py(), "write"))? {
+            Ok(Self::Writer(target))
+        } else {
+            let type_name = target.get_type().name().unwrap_or("<unknown type>");
+            Err(PyTypeError::new_err(format!(
+                "`to_json_into` expected a bytearray or an object with a `write` method, got `{type_name}`"
+            )))
+        }
+    }
+}
+
+/// Collects JSON in `buffer` and passes it on to `target` whenever it reaches `JSON_CHUNK_SIZE`
+struct PyChunkWriter<'py, 'b> {
+    target: JsonTarget<'py>,
+    buffer: &'b mut Vec<u8>,
+    written: usize,
+    // the exception raised by `target`, serde only sees an `io::Error`
+    error: Option<PyErr>,
+}
+
+impl<'py, 'b> PyChunkWriter<'py, 'b> {
+    fn write_chunk(&mut self) -> PyResult<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        match self.target {
+            JsonTarget::ByteArray(byte_array) => {
+                let start = byte_array.len();
+                byte_array.resize(start + self.buffer.len())?;
+                // safe since we hold the GIL and nothing else can run between the resize and the copy
+                unsafe { byte_array.as_bytes_mut()[start..].copy_from_slice(self.buffer) };
+            }
+            JsonTarget::Writer(writer) => {
+                let py = writer.py();
+                writer.call_method1(intern!(py, "write"), (PyBytes::new(py, self.buffer),))?;
+            }
+        }
+        self.written += self.buffer.len();
+        self.buffer.clear();
+        Ok(())
+    }
+}
+
+impl<'py, 'b> io::Write for PyChunkWriter<'py, 'b> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_all(buf)?;
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.buffer.extend_from_slice(buf);
+        if self.buffer.len() >= JSON_CHUNK_SIZE {
+            if let Err(err) = self.write_chunk() {
+                let io_error = io::Error::other(err.to_string());
+                self.error = Some(err);
+                return Err(io_error);
+            }
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -1,27 +1,81 @@
 use std::borrow::Cow;
 
+use pyo3::buffer::PyBuffer;
+use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyBytes, PyDict};
+use pyo3::types::{PyByteArray, PyBytes, PyDict};
+use pyo3::{ffi, AsPyPointer};
 
 use crate::build_context::BuildContext;
+use crate::build_tools::SchemaDict;
 
 use super::{
-    infer_json_key, infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, Extra, SerMode,
+    infer_json_key, infer_serialize, infer_to_python, BuildSerializer, BytesMode, CombinedSerializer, Extra, SerMode,
     TypeSerializer,
 };
 
+/// the data of a bytes-like value, memoryviews are read via the buffer protocol so their data isn't copied
+enum BytesData<'py> {
+    Slice(&'py [u8]),
+    Buffer(PyBuffer<u8>),
+}
+
+impl<'py> BytesData<'py> {
+    fn as_slice(&self) -> &[u8] {
+        match self {
+            Self::Slice(bytes) => bytes,
+            // SAFETY: `extract_bytes` only returns C-contiguous buffers, the buffer is held until this is dropped
+            Self::Buffer(buffer) => unsafe {
+                std::slice::from_raw_parts(buffer.buf_ptr() as *const u8, buffer.len_bytes())
+            },
+        }
+    }
+}
+
+/// bytearray and memoryview are accepted as well as bytes since they're commonly used in place of bytes,
+/// and the bytes validator can return a bytearray
+fn extract_bytes(value: &PyAny) -> Option<BytesData<'_>> {
+    if let Ok(py_bytes) = value.downcast::<PyBytes>() {
+        Some(BytesData::Slice(py_bytes.as_bytes()))
+    } else if let Ok(py_byte_array) = value.downcast::<PyByteArray>() {
+        // see https://docs.rs/pyo3/latest/pyo3/types/struct.PyByteArray.html#method.as_bytes
+        // for why this is marked unsafe
+        Some(BytesData::Slice(unsafe { py_byte_array.as_bytes() }))
+    } else if unsafe { ffi::PyMemoryView_Check(value.as_ptr()) } == 1 {
+        match PyBuffer::<u8>::get(value) {
+            Ok(buffer) if buffer.is_c_contiguous() => Some(BytesData::Buffer(buffer)),
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
+
 #[derive(Debug, Clone)]
-pub struct BytesSerializer;
+pub struct BytesSerializer {
+    // `ser_json_bytes` set on the schema, taking precedence over the mode from config or the serialization call
+    bytes_mode: Option<BytesMode>,
+}
 
 impl BuildSerializer for BytesSerializer {
     const EXPECTED_TYPE: &'static str = "bytes";
 
     fn build(
-        _schema: &PyDict,
+        schema: &PyDict,
         _config: Option<&PyDict>,
         _build_context: &mut BuildContext<CombinedSerializer>,
     ) -> PyResult<CombinedSerializer> {
-        Ok(Self {}.into())
+        let bytes_mode = match schema.get_as::<&str>(intern!(schema.py(), "ser_json_bytes"))? {
+            Some(mode) => Some(BytesMode::from_str(Some(mode))?),
+            None => None,
+        };
+        Ok(Self { bytes_mode }.into())
+    }
+}
+
+impl BytesSerializer {
+    fn bytes_mode(&self, extra: &Extra) -> BytesMode {
+        self.bytes_mode.unwrap_or(extra.config.bytes_mode)
     }
 }
 
@@ -34,16 +88,15 @@ impl TypeSerializer for BytesSerializer {
         extra: &Extra,
     ) -> PyResult<PyObject> {
         let py = value.py();
-        match value.downcast::<PyBytes>() {
-            Ok(py_bytes) => match extra.mode {
-                SerMode::Json => extra
-                    .config
-                    .bytes_mode
-                    .bytes_to_string(py, py_bytes.as_bytes())
+        match extract_bytes(value) {
+            Some(bytes) => match extra.mode {
+                SerMode::Json => self
+                    .bytes_mode(extra)
+                    .bytes_to_string(py, bytes.as_slice())
                     .map(|s| s.into_py(py)),
                 _ => Ok(value.into_py(py)),
             },
-            Err(_) => {
+            None => {
                 extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
                 infer_to_python(value, include, exclude, extra)
             }
@@ -51,9 +104,13 @@ impl TypeSerializer for BytesSerializer {
     }
 
     fn json_key<'py>(&self, key: &'py PyAny, extra: &Extra) -> PyResult<Cow<'py, str>> {
-        match key.downcast::<PyBytes>() {
-            Ok(py_bytes) => extra.config.bytes_mode.bytes_to_string(key.py(), py_bytes.as_bytes()),
-            Err(_) => {
+        match extract_bytes(key) {
+            Some(BytesData::Slice(bytes)) => self.bytes_mode(extra).bytes_to_string(key.py(), bytes),
+            Some(buffer) => self
+                .bytes_mode(extra)
+                .bytes_to_string(key.py(), buffer.as_slice())
+                .map(|s| Cow::Owned(s.into_owned())),
+            None => {
                 extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
                 infer_json_key(key, extra)
             }
@@ -68,9 +125,9 @@ impl TypeSerializer for BytesSerializer {
         exclude: Option<&PyAny>,
         extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
-        match value.downcast::<PyBytes>() {
-            Ok(py_bytes) => extra.config.bytes_mode.serialize_bytes(py_bytes.as_bytes(), serializer),
-            Err(_) => {
+        match extract_bytes(value) {
+            Some(bytes) => self.bytes_mode(extra).serialize_bytes(bytes.as_slice(), serializer),
+            None => {
                 extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
                 infer_serialize(value, serializer, include, exclude, extra)
             }
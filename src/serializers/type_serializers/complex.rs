@@ -0,0 +1,123 @@
+use std::borrow::Cow;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyComplex, PyDict, PyList};
+
+use serde::ser::SerializeTuple;
+
+use crate::build_context::BuildContext;
+use crate::build_tools::{py_err, SchemaDict};
+
+use super::{
+    infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
+    SerMode, TypeSerializer,
+};
+
+#[derive(Debug, Clone, Copy)]
+enum ComplexMode {
+    // `"1.5+2j"`
+    Str,
+    // `[real, imag]`
+    Pair,
+}
+
+#[derive(Debug, Clone)]
+pub struct ComplexSerializer {
+    mode: ComplexMode,
+}
+
+impl BuildSerializer for ComplexSerializer {
+    const EXPECTED_TYPE: &'static str = "complex";
+
+    fn build(
+        _schema: &PyDict,
+        config: Option<&PyDict>,
+        _build_context: &mut BuildContext<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        let raw_mode: Option<&str> = match config {
+            Some(c) => c.get_as(intern!(c.py(), "ser_json_complex"))?,
+            None => None,
+        };
+        let mode = match raw_mode {
+            None | Some("str") => ComplexMode::Str,
+            Some("pair") => ComplexMode::Pair,
+            Some(s) => return py_err!("Invalid complex serialization mode: `{}`, expected `str` or `pair`", s),
+        };
+        Ok(Self { mode }.into())
+    }
+}
+
+/// the repr of a complex number without the parentheses python adds when it has a real part,
+/// e.g. `"1.5+2j"`, `"2j"` or `"inf+nanj"`, `complex()` parses all of these back to the same value
+fn complex_str(complex: &PyComplex) -> PyResult<String> {
+    let repr = complex.repr()?.to_str()?;
+    let repr = repr.strip_prefix('(').unwrap_or(repr);
+    Ok(repr.strip_suffix(')').unwrap_or(repr).to_string())
+}
+
+impl TypeSerializer for ComplexSerializer {
+    fn to_python(
+        &self,
+        value: &PyAny,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        let py = value.py();
+        match value.downcast::<PyComplex>() {
+            Ok(complex) => match extra.mode {
+                SerMode::Json => match self.mode {
+                    ComplexMode::Str => Ok(complex_str(complex)?.into_py(py)),
+                    ComplexMode::Pair => Ok(PyList::new(py, [complex.real(), complex.imag()]).into_py(py)),
+                },
+                _ => Ok(value.into_py(py)),
+            },
+            Err(_) => {
+                extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
+                infer_to_python(value, include, exclude, extra)
+            }
+        }
+    }
+
+    fn json_key<'py>(&self, key: &'py PyAny, extra: &Extra) -> PyResult<Cow<'py, str>> {
+        // keys are always strings, whatever the mode
+        match key.downcast::<PyComplex>() {
+            Ok(complex) => Ok(Cow::Owned(complex_str(complex)?)),
+            Err(_) => {
+                extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
+                infer_json_key(key, extra)
+            }
+        }
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &PyAny,
+        serializer: S,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        match value.downcast::<PyComplex>() {
+            Ok(complex) => match self.mode {
+                ComplexMode::Str => serializer.serialize_str(&complex_str(complex).map_err(py_err_se_err)?),
+                // components are written like floats, so infinities and NaNs are `null`
+                ComplexMode::Pair => {
+                    let mut seq = serializer.serialize_tuple(2)?;
+                    seq.serialize_element(&complex.real())?;
+                    seq.serialize_element(&complex.imag())?;
+                    seq.end()
+                }
+            },
+            Err(_) => {
+                extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
+                infer_serialize(value, serializer, include, exclude, extra)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
@@ -43,7 +43,11 @@ impl BuildSerializer for DataclassArgsBuilder {
                     .map_err(|e| py_error_type!("Field `{}`:\n  {}", index, e))?;
 
                 let alias = field_info.get_as(intern!(py, "serialization_alias"))?;
-                fields.insert(name, TypedDictField::new(py, key_py, alias, serializer, true));
+                let serialize_as_any = field_info.get_as(intern!(py, "serialize_as_any"))?.unwrap_or(false);
+                fields.insert(
+                    name,
+                    TypedDictField::new(py, key_py, alias, serializer, true, serialize_as_any),
+                );
             }
         }
 
@@ -37,11 +37,21 @@ impl BuildSerializer for DefinitionsBuilder {
 #[derive(Debug, Clone)]
 pub struct DefinitionRefSerializer {
     serializer_id: usize,
+    schema_ref: String,
 }
 
 impl DefinitionRefSerializer {
-    pub fn from_id(serializer_id: usize) -> CombinedSerializer {
-        Self { serializer_id }.into()
+    pub fn from_id(serializer_id: usize, schema_ref: String) -> CombinedSerializer {
+        Self {
+            serializer_id,
+            schema_ref,
+        }
+        .into()
+    }
+
+    /// the serializer this refers to, for when the value has already been added to the recursion guard
+    pub fn referenced<'a>(&self, slots: &'a [CombinedSerializer]) -> Option<&'a CombinedSerializer> {
+        slots.get(self.serializer_id)
     }
 }
 
@@ -57,7 +67,7 @@ impl BuildSerializer for DefinitionRefSerializer {
 
         match build_context.find(&schema_ref)? {
             ThingOrId::Thing(serializer) => Ok(serializer),
-            ThingOrId::Id(serializer_id) => Ok(Self { serializer_id }.into()),
+            ThingOrId::Id(serializer_id) => Ok(Self::from_id(serializer_id, schema_ref)),
         }
     }
 }
@@ -70,7 +80,7 @@ impl TypeSerializer for DefinitionRefSerializer {
         exclude: Option<&PyAny>,
         extra: &Extra,
     ) -> PyResult<PyObject> {
-        let value_id = extra.rec_guard.add(value)?;
+        let value_id = extra.rec_guard.add(value, Some(&self.schema_ref))?;
         let comb_serializer = unsafe { extra.slots.get_unchecked(self.serializer_id) };
         let r = comb_serializer.to_python(value, include, exclude, extra);
         extra.rec_guard.pop(value_id);
@@ -89,7 +99,10 @@ impl TypeSerializer for DefinitionRefSerializer {
         exclude: Option<&PyAny>,
         extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
-        let value_id = extra.rec_guard.add(value).map_err(py_err_se_err)?;
+        let value_id = extra
+            .rec_guard
+            .add(value, Some(&self.schema_ref))
+            .map_err(py_err_se_err)?;
         let comb_serializer = unsafe { extra.slots.get_unchecked(self.serializer_id) };
         let r = comb_serializer.serde_serialize(value, serializer, include, exclude, extra);
         extra.rec_guard.pop(value_id);
@@ -1,18 +1,21 @@
 use std::borrow::Cow;
 
+use ahash::AHashMap;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
+use pyo3::PyTypeInfo;
 
-use serde::ser::SerializeMap;
+use serde::ser::{Error, SerializeMap};
 
 use crate::build_context::BuildContext;
-use crate::build_tools::SchemaDict;
+use crate::build_tools::{safe_repr, SchemaDict};
+use crate::serializers::errors::SERIALIZATION_ERR_MARKER;
 
 use super::any::AnySerializer;
 use super::{
-    infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra, PydanticSerializer,
-    SchemaFilter, SerMode, TypeSerializer,
+    infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
+    PydanticSerializationError, PydanticSerializer, SchemaFilter, SerMode, TypeSerializer,
 };
 
 #[derive(Debug, Clone)]
@@ -21,6 +24,9 @@ pub struct DictSerializer {
     value_serializer: Box<CombinedSerializer>,
     // isize because we look up include exclude via `.hash()` which returns an isize
     filter: SchemaFilter<isize>,
+    // whether to check that distinct keys don't give the same JSON key, e.g. `Decimal('1.0')` and `'1.0'`,
+    // `str` keys are used as they are so can't collide
+    check_key_collisions: bool,
     name: String,
 }
 
@@ -56,6 +62,7 @@ impl BuildSerializer for DictSerializer {
             value_serializer.get_name()
         );
         Ok(Self {
+            check_key_collisions: !matches!(key_serializer, CombinedSerializer::Str(_)),
             key_serializer: Box::new(key_serializer),
             value_serializer: Box::new(value_serializer),
             filter,
@@ -79,18 +86,32 @@ impl TypeSerializer for DictSerializer {
                 let value_serializer = self.value_serializer.as_ref();
 
                 let new_dict = PyDict::new(py);
+                let mut unchanged = true;
+                let mut json_keys = self.json_keys(py_dict);
                 for (key, value) in py_dict {
                     let op_next = self.filter.key_filter(key, include, exclude)?;
                     if let Some((next_include, next_exclude)) = op_next {
-                        let key = match extra.mode {
-                            SerMode::Json => self.key_serializer.json_key(key, extra)?.into_py(py),
+                        let new_key = match extra.mode {
+                            SerMode::Json => {
+                                let json_key = self.key_serializer.json_key(key, extra)?;
+                                json_keys
+                                    .check(&json_key, key)
+                                    .map_err(PydanticSerializationError::new_err)?;
+                                json_key.into_py(py)
+                            }
                             _ => self.key_serializer.to_python(key, None, None, extra)?,
                         };
-                        let value = value_serializer.to_python(value, next_include, next_exclude, extra)?;
-                        new_dict.set_item(key, value)?;
+                        let new_value = value_serializer.to_python(value, next_include, next_exclude, extra)?;
+                        unchanged &= new_key.is(key) && new_value.is(value);
+                        new_dict.set_item(new_key, new_value)?;
+                    } else {
+                        unchanged = false;
                     }
                 }
-                Ok(new_dict.into_py(py))
+                match extra.share_container(value, PyDict::is_exact_type_of(value), unchanged) {
+                    Some(shared) => Ok(shared),
+                    None => Ok(new_dict.into_py(py)),
+                }
             }
             Err(_) => {
                 extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
@@ -116,11 +137,16 @@ impl TypeSerializer for DictSerializer {
                 let mut map = serializer.serialize_map(Some(py_dict.len()))?;
                 let key_serializer = self.key_serializer.as_ref();
                 let value_serializer = self.value_serializer.as_ref();
+                let mut json_keys = self.json_keys(py_dict);
 
                 for (key, value) in py_dict {
                     let op_next = self.filter.key_filter(key, include, exclude).map_err(py_err_se_err)?;
                     if let Some((next_include, next_exclude)) = op_next {
-                        let key = key_serializer.json_key(key, extra).map_err(py_err_se_err)?;
+                        let json_key = key_serializer.json_key(key, extra).map_err(py_err_se_err)?;
+                        json_keys
+                            .check(&json_key, key)
+                            .map_err(|msg| S::Error::custom(format!("{SERIALIZATION_ERR_MARKER}{msg}")))?;
+                        let key = json_key;
                         let value_serialize =
                             PydanticSerializer::new(value, value_serializer, next_include, next_exclude, extra);
                         map.serialize_entry(&key, &value_serialize)?;
@@ -139,3 +165,31 @@ impl TypeSerializer for DictSerializer {
         &self.name
     }
 }
+
+impl DictSerializer {
+    fn json_keys<'py>(&self, py_dict: &PyDict) -> JsonKeys<'py> {
+        match self.check_key_collisions && py_dict.len() > 1 {
+            true => JsonKeys(Some(AHashMap::with_capacity(py_dict.len()))),
+            false => JsonKeys(None),
+        }
+    }
+}
+
+/// JSON keys already used in a dict, mapped to the key they came from
+struct JsonKeys<'py>(Option<AHashMap<String, &'py PyAny>>);
+
+impl<'py> JsonKeys<'py> {
+    fn check(&mut self, json_key: &str, key: &'py PyAny) -> Result<(), String> {
+        if let Some(ref mut used) = self.0 {
+            if let Some(other_key) = used.insert(json_key.to_owned(), key) {
+                return Err(format!(
+                    "Dict keys {} and {} both serialize to the JSON key \"{}\"",
+                    safe_repr(other_key),
+                    safe_repr(key),
+                    json_key
+                ));
+            }
+        }
+        Ok(())
+    }
+}
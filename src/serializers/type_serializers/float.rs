@@ -0,0 +1,87 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::borrow::Cow;
+
+use crate::build_context::BuildContext;
+
+use super::simple::to_str_json_key;
+use super::{
+    infer_json_key, infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, Extra, IsType, ObType,
+    SerMode, TypeSerializer,
+};
+
+/// like the other simple serializers, except infinite and NaN floats are written in JSON according to
+/// `ser_json_inf_nan`
+#[derive(Debug, Clone)]
+pub struct FloatSerializer;
+
+impl BuildSerializer for FloatSerializer {
+    const EXPECTED_TYPE: &'static str = "float";
+
+    fn build(
+        _schema: &PyDict,
+        _config: Option<&PyDict>,
+        _build_context: &mut BuildContext<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        Ok(Self {}.into())
+    }
+}
+
+impl TypeSerializer for FloatSerializer {
+    fn to_python(
+        &self,
+        value: &PyAny,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        let py = value.py();
+        match extra.ob_type_lookup.is_type(value, ObType::Float) {
+            is_type @ (IsType::Exact | IsType::Subclass) if matches!(extra.mode, SerMode::Json) => {
+                let float: f64 = value.extract()?;
+                match extra.config.inf_nan_mode.float_to_python(py, float) {
+                    Some(non_finite) => Ok(non_finite),
+                    None if matches!(is_type, IsType::Exact) => Ok(value.into_py(py)),
+                    None => Ok(float.into_py(py)),
+                }
+            }
+            IsType::Exact => Ok(value.into_py(py)),
+            IsType::Subclass => infer_to_python(value, include, exclude, extra),
+            IsType::False => {
+                extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
+                infer_to_python(value, include, exclude, extra)
+            }
+        }
+    }
+
+    fn json_key<'py>(&self, key: &'py PyAny, extra: &Extra) -> PyResult<Cow<'py, str>> {
+        match extra.ob_type_lookup.is_type(key, ObType::Float) {
+            IsType::Exact | IsType::Subclass => to_str_json_key(key),
+            IsType::False => {
+                extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
+                infer_json_key(key, extra)
+            }
+        }
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &PyAny,
+        serializer: S,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        match value.extract::<f64>() {
+            Ok(float) => extra.config.inf_nan_mode.serialize_float(float, serializer),
+            Err(_) => {
+                extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
+                infer_serialize(value, serializer, include, exclude, extra)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
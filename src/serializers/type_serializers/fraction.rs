@@ -0,0 +1,135 @@
+use std::borrow::Cow;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use crate::build_context::BuildContext;
+use crate::build_tools::{py_err, SchemaDict};
+
+use super::{
+    infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
+    SerMode, TypeSerializer,
+};
+
+#[derive(Debug, Clone, Copy)]
+enum FractionMode {
+    // `"numerator/denominator"`
+    Str,
+    // `[numerator, denominator]`
+    Pair,
+}
+
+#[derive(Debug, Clone)]
+pub struct FractionSerializer {
+    fraction_class: PyObject,
+    mode: FractionMode,
+}
+
+impl BuildSerializer for FractionSerializer {
+    const EXPECTED_TYPE: &'static str = "fraction";
+
+    fn build(
+        schema: &PyDict,
+        _config: Option<&PyDict>,
+        _build_context: &mut BuildContext<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        let py = schema.py();
+        let mode = match schema.get_as::<&str>(intern!(py, "ser_json_fraction"))? {
+            None | Some("str") => FractionMode::Str,
+            Some("pair") => FractionMode::Pair,
+            Some(s) => return py_err!("Invalid fraction serialization mode: `{}`, expected `str` or `pair`", s),
+        };
+        Ok(Self {
+            fraction_class: py
+                .import(intern!(py, "fractions"))?
+                .getattr(intern!(py, "Fraction"))?
+                .into_py(py),
+            mode,
+        }
+        .into())
+    }
+}
+
+impl FractionSerializer {
+    fn parts<'py>(&self, value: &'py PyAny) -> PyResult<Option<(&'py PyAny, &'py PyAny)>> {
+        let py = value.py();
+        if value.is_instance(self.fraction_class.as_ref(py))? {
+            Ok(Some((
+                value.getattr(intern!(py, "numerator"))?,
+                value.getattr(intern!(py, "denominator"))?,
+            )))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+fn fraction_str(numerator: &PyAny, denominator: &PyAny) -> PyResult<String> {
+    Ok(format!("{}/{}", numerator.str()?, denominator.str()?))
+}
+
+impl TypeSerializer for FractionSerializer {
+    fn to_python(
+        &self,
+        value: &PyAny,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        let py = value.py();
+        match self.parts(value)? {
+            Some((numerator, denominator)) => match extra.mode {
+                SerMode::Json => match self.mode {
+                    FractionMode::Str => Ok(fraction_str(numerator, denominator)?.into_py(py)),
+                    FractionMode::Pair => Ok(PyList::new(py, [numerator, denominator]).into_py(py)),
+                },
+                _ => Ok(value.into_py(py)),
+            },
+            None => {
+                extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
+                infer_to_python(value, include, exclude, extra)
+            }
+        }
+    }
+
+    fn json_key<'py>(&self, key: &'py PyAny, extra: &Extra) -> PyResult<Cow<'py, str>> {
+        // keys are always strings, whatever the mode
+        match self.parts(key)? {
+            Some((numerator, denominator)) => Ok(Cow::Owned(fraction_str(numerator, denominator)?)),
+            None => {
+                extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
+                infer_json_key(key, extra)
+            }
+        }
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &PyAny,
+        serializer: S,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        match self.parts(value).map_err(py_err_se_err)? {
+            Some((numerator, denominator)) => match self.mode {
+                FractionMode::Str => {
+                    serializer.serialize_str(&fraction_str(numerator, denominator).map_err(py_err_se_err)?)
+                }
+                FractionMode::Pair => {
+                    let pair = PyList::new(value.py(), [numerator, denominator]);
+                    infer_serialize(pair, serializer, None, None, extra)
+                }
+            },
+            None => {
+                extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
+                infer_serialize(value, serializer, include, exclude, extra)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
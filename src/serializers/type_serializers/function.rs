@@ -175,7 +175,7 @@ macro_rules! function_type_serializer {
                                 Some(ref ob_type) => infer_to_python_known(ob_type, next_value, None, None, extra),
                                 None => infer_to_python(next_value, None, None, extra),
                             },
-                            _ => Ok(next_value.to_object(py)),
+                            _ => extra.pass_through(next_value),
                         }
                     }
                     Err(err) => match err.value(py).extract::<PydanticSerializationUnexpectedValue>() {
@@ -183,7 +183,7 @@ macro_rules! function_type_serializer {
                             if extra.check.enabled() {
                                 Err(err)
                             } else {
-                                extra.warnings.custom_warning(ser_err.__repr__());
+                                extra.warnings.custom_warning(ser_err.__repr__())?;
                                 infer_to_python(value, include, exclude, extra)
                             }
                         }
@@ -211,7 +211,7 @@ macro_rules! function_type_serializer {
                             if extra.check.enabled() {
                                 Err(err)
                             } else {
-                                extra.warnings.custom_warning(ser_err.__repr__());
+                                extra.warnings.custom_warning(ser_err.__repr__())?;
                                 infer_json_key(key, extra)
                             }
                         }
@@ -249,7 +249,7 @@ macro_rules! function_type_serializer {
                             if extra.check.enabled() {
                                 Err(py_err_se_err(err))
                             } else {
-                                extra.warnings.custom_warning(ser_err.__repr__());
+                                extra.warnings.custom_warning_ser::<S>(ser_err.__repr__())?;
                                 infer_serialize(value, serializer, include, exclude, extra)
                             }
                         }
@@ -475,6 +475,8 @@ struct SerializationInfo {
     #[pyo3(get)]
     round_trip: bool,
     field_name: Option<String>,
+    #[pyo3(get)]
+    context: Option<PyObject>,
 }
 
 impl SerializationInfo {
@@ -498,6 +500,7 @@ impl SerializationInfo {
                         exclude_none: extra.exclude_none,
                         round_trip: extra.round_trip,
                         field_name: Some(field_name.to_string()),
+                        context: extra.context.map(|c| c.into_py(py)),
                     }
                 ),
                 _ => Err(PyRuntimeError::new_err("This serializer expected to be run inside the context of a model field but no model field was found")),
@@ -513,6 +516,7 @@ impl SerializationInfo {
                 exclude_none: extra.exclude_none,
                 round_trip: extra.round_trip,
                 field_name: None,
+                context: extra.context.map(|c| c.into_py(py)),
             })
         }
     }
@@ -544,6 +548,9 @@ impl SerializationInfo {
         d.set_item("exclude_defaults", self.exclude_defaults)?;
         d.set_item("exclude_none", self.exclude_none)?;
         d.set_item("round_trip", self.round_trip)?;
+        if let Some(ref context) = self.context {
+            d.set_item("context", context)?;
+        }
         Ok(d)
     }
 
@@ -3,6 +3,7 @@ use std::borrow::Cow;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
+use pyo3::PyTypeInfo;
 
 use serde::ser::SerializeSeq;
 
@@ -59,13 +60,21 @@ impl TypeSerializer for ListSerializer {
                 let item_serializer = self.item_serializer.as_ref();
 
                 let mut items = Vec::with_capacity(py_list.len());
+                let mut unchanged = true;
                 for (index, element) in py_list.iter().enumerate() {
                     let op_next = self.filter.index_filter(index, include, exclude)?;
                     if let Some((next_include, next_exclude)) = op_next {
-                        items.push(item_serializer.to_python(element, next_include, next_exclude, extra)?);
+                        let item = item_serializer.to_python(element, next_include, next_exclude, extra)?;
+                        unchanged &= item.is(element);
+                        items.push(item);
+                    } else {
+                        unchanged = false;
                     }
                 }
-                Ok(items.into_py(py))
+                match extra.share_container(value, PyList::is_exact_type_of(value), unchanged) {
+                    Some(shared) => Ok(shared),
+                    None => Ok(items.into_py(py)),
+                }
             }
             Err(_) => {
                 extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
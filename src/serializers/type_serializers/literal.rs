@@ -10,6 +10,7 @@ use serde::Serialize;
 use crate::build_context::BuildContext;
 use crate::build_tools::{py_err, SchemaDict};
 
+use super::any::AnySerializer;
 use super::{
     infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
     SerMode, TypeSerializer,
@@ -28,10 +29,14 @@ impl BuildSerializer for LiteralSerializer {
 
     fn build(
         schema: &PyDict,
-        _config: Option<&PyDict>,
-        _build_context: &mut BuildContext<CombinedSerializer>,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext<CombinedSerializer>,
     ) -> PyResult<CombinedSerializer> {
-        let expected: &PyList = schema.get_as_req(intern!(schema.py(), "expected"))?;
+        // the allowed values from `allowed_values_factory` aren't known until validation, so values are inferred
+        let expected: &PyList = match schema.get_as(intern!(schema.py(), "expected"))? {
+            Some(expected) => expected,
+            None => return AnySerializer::build(schema, config, build_context),
+        };
 
         if expected.is_empty() {
             return py_err!("`expected` should have length > 0");
@@ -1,10 +1,13 @@
 pub mod any;
 pub mod bytes;
+pub mod complex;
 pub mod dataclass;
 pub mod datetime_etc;
 pub mod definitions;
 pub mod dict;
+pub mod float;
 pub mod format;
+pub mod fraction;
 pub mod function;
 pub mod generator;
 pub mod json;
@@ -13,17 +16,19 @@ pub mod literal;
 pub mod model;
 pub mod nullable;
 pub mod other;
+pub mod path;
 pub mod set_frozenset;
 pub mod simple;
 pub mod string;
 pub mod timedelta;
+pub mod timezone;
 pub mod tuple;
 pub mod typed_dict;
 pub mod union;
 pub mod url;
 pub mod with_default;
 
-pub(self) use super::config::utf8_py_error;
+pub(self) use super::config::{utf8_py_error, BytesMode};
 pub(self) use super::errors::{py_err_se_err, PydanticSerializationError};
 pub(self) use super::extra::{Extra, ExtraOwned, SerMode};
 pub(self) use super::filter::SchemaFilter;
@@ -2,13 +2,16 @@ use std::borrow::Cow;
 
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyType};
+use pyo3::types::{PyDict, PyString, PyType};
 
 use crate::build_context::BuildContext;
-use crate::build_tools::SchemaDict;
+use crate::build_tools::{apply_alias_generator, py_err, SchemaDict};
+use crate::serializers::computed_fields::ComputedFields;
 use crate::serializers::extra::SerCheck;
 use crate::serializers::infer::{infer_serialize, infer_to_python};
 use crate::serializers::ob_type::ObType;
+use crate::serializers::SchemaSerializer;
+use crate::validators::dataclass_slot_fields;
 
 use super::{
     infer_json_key, infer_json_key_known, object_to_dict, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
@@ -19,6 +22,10 @@ use super::{
 pub struct ModelSerializer {
     class: Py<PyType>,
     serializer: Box<CombinedSerializer>,
+    // whether instances carry `__pydantic_extra__` which should be serialized with the fields
+    has_extra: bool,
+    // for slotted dataclasses, the fields which are read as attributes since there's no `__dict__`
+    slot_fields: Option<Vec<Py<PyString>>>,
     name: String,
 }
 
@@ -32,12 +39,28 @@ impl BuildSerializer for ModelSerializer {
     ) -> PyResult<CombinedSerializer> {
         let py = schema.py();
         let class: &PyType = schema.get_as_req(intern!(py, "cls"))?;
-        let sub_schema: &PyDict = schema.get_as_req(intern!(py, "schema"))?;
-        let serializer = Box::new(CombinedSerializer::build(sub_schema, config, build_context)?);
+        let sub_schema = apply_alias_generator(schema, schema.get_as_req(intern!(py, "schema"))?)?;
+        let mut serializer = Box::new(CombinedSerializer::build(sub_schema, config, build_context)?);
+        if let Some(computed_fields) = ComputedFields::new(schema, config, build_context)? {
+            match *serializer {
+                CombinedSerializer::TypedDict(ref mut fields_serializer) => {
+                    fields_serializer.set_computed_fields(computed_fields);
+                }
+                _ => return py_err!("`computed_fields` require `schema` to be a typed-dict or dataclass-args schema"),
+            }
+        }
+        let has_extra = sub_schema.get_as(intern!(py, "return_extra"))?.unwrap_or(false);
+        // only dataclass schemas have `slots`, they're also serialized with this serializer
+        let slot_fields = match schema.get_as(intern!(py, "slots"))?.unwrap_or(false) {
+            true => Some(dataclass_slot_fields(sub_schema)?),
+            false => None,
+        };
 
         Ok(Self {
             class: class.into(),
             serializer,
+            has_extra,
+            slot_fields,
             name: class.getattr(intern!(py, "__name__"))?.extract()?,
         }
         .into())
@@ -45,13 +68,75 @@ impl BuildSerializer for ModelSerializer {
 }
 
 impl ModelSerializer {
+    /// the `__pydantic_serializer__` defined by the value's own class rather than inherited from `class`
+    fn runtime_serializer(&self, value: &PyAny) -> Option<SchemaSerializer> {
+        let py = value.py();
+        value
+            .get_type()
+            .getattr(intern!(py, "__dict__"))
+            .ok()?
+            .get_item(intern!(py, "__pydantic_serializer__"))
+            .ok()?
+            .extract()
+            .ok()
+    }
+
+    /// with `serialize_as_any`, values which aren't instances of exactly `class` are serialized according to their
+    /// runtime type: with their class's own serializer if it has one, plain objects by inferring the types of their
+    /// attributes, otherwise as usual
+    fn is_runtime_type(&self, value: &PyAny, extra: &Extra) -> PyResult<bool> {
+        Ok(extra.serialize_as_any
+            && !value.get_type().is(self.class.as_ref(value.py()))
+            && self.allow_value(value, extra)?)
+    }
+
     fn allow_value(&self, value: &PyAny, extra: &Extra) -> PyResult<bool> {
         match extra.check {
             SerCheck::Strict => Ok(value.get_type().is(self.class.as_ref(value.py()))),
             SerCheck::Lax => value.is_instance(self.class.as_ref(value.py())),
-            SerCheck::None => value.hasattr(intern!(value.py(), "__dict__")),
+            SerCheck::None => match self.slot_fields {
+                Some(_) => value.hasattr(intern!(value.py(), "__dataclass_fields__")),
+                None => value.hasattr(intern!(value.py(), "__dict__")),
+            },
         }
     }
+
+    fn get_inner_value<'py>(&self, value: &'py PyAny, extra: &Extra) -> PyResult<&'py PyDict> {
+        if let Some(ref fields) = self.slot_fields {
+            let py = value.py();
+            let dict = PyDict::new(py);
+            for field_name in fields {
+                let field_name = field_name.as_ref(py);
+                dict.set_item(field_name, value.getattr(field_name)?)?;
+            }
+            return Ok(dict);
+        }
+        let dict = object_to_dict(value, true, extra)?;
+        if self.has_extra {
+            let py = value.py();
+            if let Ok(model_extra) = value.getattr(intern!(py, "__pydantic_extra__")) {
+                if let Ok(model_extra) = model_extra.downcast::<PyDict>() {
+                    if !model_extra.is_empty() {
+                        let dict = dict.copy()?;
+                        dict.update(model_extra.as_mapping())?;
+                        return Ok(dict);
+                    }
+                }
+            }
+        }
+        Ok(dict)
+    }
+}
+
+/// the serializer of a recursive model is a reference to its definition, it's skipped since the reference would add
+/// the value to the recursion guard again after the reference which led to this model
+fn runtime_root(schema_serializer: &SchemaSerializer) -> &CombinedSerializer {
+    match schema_serializer.serializer {
+        CombinedSerializer::Recursive(ref def_ref) => def_ref
+            .referenced(&schema_serializer.slots)
+            .unwrap_or(&schema_serializer.serializer),
+        ref serializer => serializer,
+    }
 }
 
 impl TypeSerializer for ModelSerializer {
@@ -68,12 +153,27 @@ impl TypeSerializer for ModelSerializer {
         exclude: Option<&PyAny>,
         extra: &Extra,
     ) -> PyResult<PyObject> {
+        if self.is_runtime_type(value, extra)? {
+            if let Some(schema_serializer) = self.runtime_serializer(value) {
+                let extra = Extra {
+                    slots: &schema_serializer.slots,
+                    ..*extra
+                };
+                let value_id = extra.rec_guard.add_as_any(value, &self.name)?;
+                let r = runtime_root(&schema_serializer).to_python(value, include, exclude, &extra);
+                extra.rec_guard.pop(value_id);
+                return r;
+            } else if !value.is_instance(self.class.as_ref(value.py()))? {
+                let dict = object_to_dict(value, false, extra)?;
+                return infer_to_python(dict, include, exclude, extra);
+            }
+        }
         let extra = Extra {
             model: Some(value),
             ..*extra
         };
         if self.allow_value(value, &extra)? {
-            let dict = object_to_dict(value, true, &extra)?;
+            let dict = self.get_inner_value(value, &extra)?;
             self.serializer.to_python(dict, include, exclude, &extra)
         } else {
             extra.warnings.on_fallback_py(self.get_name(), value, &extra)?;
@@ -98,12 +198,30 @@ impl TypeSerializer for ModelSerializer {
         exclude: Option<&PyAny>,
         extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
+        if self.is_runtime_type(value, extra).map_err(py_err_se_err)? {
+            if let Some(schema_serializer) = self.runtime_serializer(value) {
+                let extra = Extra {
+                    slots: &schema_serializer.slots,
+                    ..*extra
+                };
+                let value_id = extra.rec_guard.add_as_any(value, &self.name).map_err(py_err_se_err)?;
+                let r = runtime_root(&schema_serializer).serde_serialize(value, serializer, include, exclude, &extra);
+                extra.rec_guard.pop(value_id);
+                return r;
+            } else if !value
+                .is_instance(self.class.as_ref(value.py()))
+                .map_err(py_err_se_err)?
+            {
+                let dict = object_to_dict(value, false, extra).map_err(py_err_se_err)?;
+                return infer_serialize(dict, serializer, include, exclude, extra);
+            }
+        }
         let extra = Extra {
             model: Some(value),
             ..*extra
         };
         if self.allow_value(value, &extra).map_err(py_err_se_err)? {
-            let dict = object_to_dict(value, true, &extra).map_err(py_err_se_err)?;
+            let dict = self.get_inner_value(value, &extra).map_err(py_err_se_err)?;
             self.serializer
                 .serde_serialize(dict, serializer, include, exclude, &extra)
         } else {
@@ -5,8 +5,10 @@ use pyo3::types::{PyDict, PyList};
 use crate::build_context::BuildContext;
 use crate::build_tools::{py_err, SchemaDict};
 use crate::serializers::shared::CombinedSerializer;
+use crate::validators::chain_output_schema;
 
 use super::any::AnySerializer;
+use super::format::ToStringSerializer;
 use super::BuildSerializer;
 
 pub struct ChainBuilder;
@@ -19,13 +21,8 @@ impl BuildSerializer for ChainBuilder {
         config: Option<&PyDict>,
         build_context: &mut BuildContext<CombinedSerializer>,
     ) -> PyResult<CombinedSerializer> {
-        let last_schema = schema
-            .get_as_req::<&PyList>(intern!(schema.py(), "steps"))?
-            .iter()
-            .last()
-            .unwrap()
-            .downcast()?;
-        CombinedSerializer::build(last_schema, config, build_context)
+        let output_schema = chain_output_schema(schema.get_as_req::<&PyList>(intern!(schema.py(), "steps"))?)?;
+        CombinedSerializer::build(output_schema, config, build_context)
     }
 }
 
@@ -77,6 +74,21 @@ impl BuildSerializer for LaxOrStrictBuilder {
     }
 }
 
+pub struct JsonOrPythonBuilder;
+
+impl BuildSerializer for JsonOrPythonBuilder {
+    const EXPECTED_TYPE: &'static str = "json-or-python";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        let python_schema: &PyDict = schema.get_as_req(intern!(schema.py(), "python_schema"))?;
+        CombinedSerializer::build(python_schema, config, build_context)
+    }
+}
+
 pub struct ArgumentsBuilder;
 
 impl BuildSerializer for ArgumentsBuilder {
@@ -91,6 +103,43 @@ impl BuildSerializer for ArgumentsBuilder {
     }
 }
 
+pub struct TypeVarBuilder;
+
+impl BuildSerializer for TypeVarBuilder {
+    const EXPECTED_TYPE: &'static str = "type-var";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        let py = schema.py();
+        let name: &str = schema.get_as_req(intern!(py, "name"))?;
+        build_context.build_type_var(py, name, |sub_schema, build_context| {
+            CombinedSerializer::build(sub_schema.downcast()?, config, build_context)
+        })
+    }
+}
+
+pub struct ParametrizedBuilder;
+
+impl BuildSerializer for ParametrizedBuilder {
+    const EXPECTED_TYPE: &'static str = "parametrized";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        let py = schema.py();
+        let type_vars: &PyDict = schema.get_as_req(intern!(py, "type_vars"))?;
+        let inner_schema: &PyDict = schema.get_as_req(intern!(py, "schema"))?;
+        build_context.with_type_vars(type_vars, |build_context| {
+            CombinedSerializer::build(inner_schema, config, build_context)
+        })
+    }
+}
+
 macro_rules! any_build_serializer {
     ($struct_name:ident, $expected_type:literal) => {
         pub struct $struct_name;
@@ -111,3 +160,32 @@ macro_rules! any_build_serializer {
 any_build_serializer!(IsInstanceBuilder, "is-instance");
 any_build_serializer!(IsSubclassBuilder, "is-subclass");
 any_build_serializer!(CallableBuilder, "callable");
+any_build_serializer!(MergeBuilder, "merge");
+any_build_serializer!(DecimalBuilder, "decimal");
+any_build_serializer!(UuidBuilder, "uuid");
+any_build_serializer!(EnumBuilder, "enum");
+
+/// the `ipaddress` types are serialized as their canonical string form in JSON mode
+macro_rules! to_string_build_serializer {
+    ($struct_name:ident, $expected_type:literal) => {
+        pub struct $struct_name;
+
+        impl BuildSerializer for $struct_name {
+            const EXPECTED_TYPE: &'static str = $expected_type;
+
+            fn build(
+                schema: &PyDict,
+                config: Option<&PyDict>,
+                build_context: &mut BuildContext<CombinedSerializer>,
+            ) -> PyResult<CombinedSerializer> {
+                ToStringSerializer::build(schema, config, build_context)
+            }
+        }
+    };
+}
+to_string_build_serializer!(IpV4AddressBuilder, "ip-v4-address");
+to_string_build_serializer!(IpV6AddressBuilder, "ip-v6-address");
+to_string_build_serializer!(IpV4NetworkBuilder, "ip-v4-network");
+to_string_build_serializer!(IpV6NetworkBuilder, "ip-v6-network");
+to_string_build_serializer!(IpV4InterfaceBuilder, "ip-v4-interface");
+to_string_build_serializer!(IpV6InterfaceBuilder, "ip-v6-interface");
@@ -0,0 +1,109 @@
+use std::borrow::Cow;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::build_context::BuildContext;
+use crate::build_tools::SchemaDict;
+
+use super::{
+    infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
+    SerMode, TypeSerializer,
+};
+
+#[derive(Debug, Clone)]
+pub struct PathSerializer {
+    pure_path_class: PyObject,
+    // posix paths are always serialized with forward slashes, whatever the type of the value
+    as_posix: bool,
+}
+
+impl BuildSerializer for PathSerializer {
+    const EXPECTED_TYPE: &'static str = "path";
+
+    fn build(
+        schema: &PyDict,
+        _config: Option<&PyDict>,
+        _build_context: &mut BuildContext<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        let py = schema.py();
+        let flavor: Option<&str> = schema.get_as(intern!(py, "flavor"))?;
+        Ok(Self {
+            pure_path_class: py
+                .import(intern!(py, "pathlib"))?
+                .getattr(intern!(py, "PurePath"))?
+                .into_py(py),
+            as_posix: flavor == Some("posix"),
+        }
+        .into())
+    }
+}
+
+impl PathSerializer {
+    fn path_str<'py>(&self, value: &'py PyAny) -> PyResult<Option<&'py PyAny>> {
+        let py = value.py();
+        if value.is_instance(self.pure_path_class.as_ref(py))? {
+            if self.as_posix {
+                value.call_method0(intern!(py, "as_posix")).map(Some)
+            } else {
+                value.str().map(|s| Some(s.as_ref()))
+            }
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl TypeSerializer for PathSerializer {
+    fn to_python(
+        &self,
+        value: &PyAny,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        let py = value.py();
+        match self.path_str(value)? {
+            Some(path_str) => match extra.mode {
+                SerMode::Json => Ok(path_str.into_py(py)),
+                _ => Ok(value.into_py(py)),
+            },
+            None => {
+                extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
+                infer_to_python(value, include, exclude, extra)
+            }
+        }
+    }
+
+    fn json_key<'py>(&self, key: &'py PyAny, extra: &Extra) -> PyResult<Cow<'py, str>> {
+        match self.path_str(key)? {
+            Some(path_str) => Ok(Cow::Owned(path_str.extract()?)),
+            None => {
+                extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
+                infer_json_key(key, extra)
+            }
+        }
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &PyAny,
+        serializer: S,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        match self.path_str(value).map_err(py_err_se_err)? {
+            Some(path_str) => serializer.serialize_str(path_str.extract().map_err(py_err_se_err)?),
+            None => {
+                extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
+                infer_serialize(value, serializer, include, exclude, extra)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
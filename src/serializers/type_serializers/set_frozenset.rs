@@ -3,6 +3,7 @@ use std::borrow::Cow;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyFrozenSet, PyList, PySet};
+use pyo3::PyTypeInfo;
 
 use serde::ser::SerializeSeq;
 
@@ -59,8 +60,16 @@ macro_rules! build_serializer {
                         let item_serializer = self.item_serializer.as_ref();
 
                         let mut items = Vec::with_capacity(py_set.len());
+                        let mut unchanged = true;
                         for element in py_set.iter() {
-                            items.push(item_serializer.to_python(element, include, exclude, extra)?);
+                            let item = item_serializer.to_python(element, include, exclude, extra)?;
+                            unchanged &= item.is(element);
+                            items.push(item);
+                        }
+                        if let Some(shared) =
+                            extra.share_container(value, <$py_type>::is_exact_type_of(value), unchanged)
+                        {
+                            return Ok(shared);
                         }
                         match extra.mode {
                             SerMode::Json => Ok(PyList::new(py, items).into_py(py)),
@@ -0,0 +1,100 @@
+use std::borrow::Cow;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::build_context::BuildContext;
+
+use super::{
+    infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
+    SerMode, TypeSerializer,
+};
+
+#[derive(Debug, Clone)]
+pub struct TimezoneSerializer {
+    tzinfo_class: PyObject,
+}
+
+impl BuildSerializer for TimezoneSerializer {
+    const EXPECTED_TYPE: &'static str = "timezone";
+
+    fn build(
+        schema: &PyDict,
+        _config: Option<&PyDict>,
+        _build_context: &mut BuildContext<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        let py = schema.py();
+        Ok(Self {
+            tzinfo_class: py
+                .import(intern!(py, "datetime"))?
+                .getattr(intern!(py, "tzinfo"))?
+                .into_py(py),
+        }
+        .into())
+    }
+}
+
+impl TimezoneSerializer {
+    /// the name of a timezone, `str()` gives the IANA name for `zoneinfo.ZoneInfo` and `pytz` timezones
+    fn timezone_str<'py>(&self, value: &'py PyAny) -> PyResult<Option<&'py PyAny>> {
+        if value.is_instance(self.tzinfo_class.as_ref(value.py()))? {
+            value.str().map(|s| Some(s.as_ref()))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl TypeSerializer for TimezoneSerializer {
+    fn to_python(
+        &self,
+        value: &PyAny,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        let py = value.py();
+        match self.timezone_str(value)? {
+            Some(timezone_str) => match extra.mode {
+                SerMode::Json => Ok(timezone_str.into_py(py)),
+                _ => Ok(value.into_py(py)),
+            },
+            None => {
+                extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
+                infer_to_python(value, include, exclude, extra)
+            }
+        }
+    }
+
+    fn json_key<'py>(&self, key: &'py PyAny, extra: &Extra) -> PyResult<Cow<'py, str>> {
+        match self.timezone_str(key)? {
+            Some(timezone_str) => Ok(Cow::Owned(timezone_str.extract()?)),
+            None => {
+                extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
+                infer_json_key(key, extra)
+            }
+        }
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &PyAny,
+        serializer: S,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        match self.timezone_str(value).map_err(py_err_se_err)? {
+            Some(timezone_str) => serializer.serialize_str(timezone_str.extract().map_err(py_err_se_err)?),
+            None => {
+                extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
+                infer_serialize(value, serializer, include, exclude, extra)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
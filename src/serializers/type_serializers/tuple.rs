@@ -1,6 +1,7 @@
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyTuple};
+use pyo3::PyTypeInfo;
 use std::borrow::Cow;
 
 use serde::ser::SerializeSeq;
@@ -61,12 +62,20 @@ impl TypeSerializer for TupleVariableSerializer {
                 let item_serializer = self.item_serializer.as_ref();
 
                 let mut items = Vec::with_capacity(py_tuple.len());
+                let mut unchanged = true;
                 for (index, element) in py_tuple.iter().enumerate() {
                     let op_next = self.filter.index_filter(index, include, exclude)?;
                     if let Some((next_include, next_exclude)) = op_next {
-                        items.push(item_serializer.to_python(element, next_include, next_exclude, extra)?);
+                        let item = item_serializer.to_python(element, next_include, next_exclude, extra)?;
+                        unchanged &= item.is(element);
+                        items.push(item);
+                    } else {
+                        unchanged = false;
                     }
                 }
+                if let Some(shared) = extra.share_container(value, PyTuple::is_exact_type_of(value), unchanged) {
+                    return Ok(shared);
+                }
                 match extra.mode {
                     SerMode::Json => Ok(PyList::new(py, items).into_py(py)),
                     _ => Ok(PyTuple::new(py, items).into_py(py)),
@@ -136,10 +145,13 @@ impl TypeSerializer for TupleVariableSerializer {
     }
 }
 
+/// Serializes each item with the serializer for its position. `exclude_none` doesn't drop `None` items since
+/// every later item would then be serialized at the wrong position, it's still applied within nested items.
 #[derive(Debug, Clone)]
 pub struct TuplePositionalSerializer {
     items_serializers: Vec<CombinedSerializer>,
     extra_serializer: Box<CombinedSerializer>,
+    has_extra_schema: bool,
     filter: SchemaFilter<usize>,
     name: String,
 }
@@ -155,7 +167,8 @@ impl BuildSerializer for TuplePositionalSerializer {
         let py = schema.py();
         let items: &PyList = schema.get_as_req(intern!(py, "items_schema"))?;
 
-        let extra_serializer = match schema.get_as::<&PyDict>(intern!(py, "extra_schema"))? {
+        let extra_schema: Option<&PyDict> = schema.get_as(intern!(py, "extra_schema"))?;
+        let extra_serializer = match extra_schema {
             Some(extra_schema) => CombinedSerializer::build(extra_schema, config, build_context)?,
             None => AnySerializer::build(schema, config, build_context)?,
         };
@@ -172,6 +185,7 @@ impl BuildSerializer for TuplePositionalSerializer {
         Ok(Self {
             items_serializers,
             extra_serializer: Box::new(extra_serializer),
+            has_extra_schema: extra_schema.is_some(),
             filter: SchemaFilter::from_schema(schema)?,
             name: format!("tuple[{descr}]"),
         }
@@ -191,28 +205,24 @@ impl TypeSerializer for TuplePositionalSerializer {
             Ok(py_tuple) => {
                 let py = value.py();
 
-                let mut py_tuple_iter = py_tuple.iter();
+                self.check_length(py_tuple, extra)?;
+
                 let mut items = Vec::with_capacity(py_tuple.len());
-                for (index, serializer) in self.items_serializers.iter().enumerate() {
-                    let element = match py_tuple_iter.next() {
-                        Some(value) => value,
-                        None => break,
-                    };
+                let mut unchanged = true;
+                for (index, element, serializer) in self.iter_positions(py_tuple) {
                     let op_next = self.filter.index_filter(index, include, exclude)?;
                     if let Some((next_include, next_exclude)) = op_next {
-                        items.push(serializer.to_python(element, next_include, next_exclude, extra)?);
-                    }
-                }
-                let expected_length = self.items_serializers.len();
-                let extra_serializer = self.extra_serializer.as_ref();
-                for (index2, element) in py_tuple_iter.enumerate() {
-                    let index = index2 + expected_length;
-                    let op_next = self.filter.index_filter(index, include, exclude)?;
-                    if let Some((next_include, next_exclude)) = op_next {
-                        items.push(extra_serializer.to_python(element, next_include, next_exclude, extra)?);
+                        let item = serializer.to_python(element, next_include, next_exclude, extra)?;
+                        unchanged &= item.is(element);
+                        items.push(item);
+                    } else {
+                        unchanged = false;
                     }
                 }
 
+                if let Some(shared) = extra.share_container(value, PyTuple::is_exact_type_of(value), unchanged) {
+                    return Ok(shared);
+                }
                 match extra.mode {
                     SerMode::Json => Ok(PyList::new(py, items).into_py(py)),
                     _ => Ok(PyTuple::new(py, items).into_py(py)),
@@ -228,20 +238,10 @@ impl TypeSerializer for TuplePositionalSerializer {
     fn json_key<'py>(&self, key: &'py PyAny, extra: &Extra) -> PyResult<Cow<'py, str>> {
         match key.downcast::<PyTuple>() {
             Ok(py_tuple) => {
-                let mut py_tuple_iter = py_tuple.iter();
-
                 let mut key_builder = KeyBuilder::new();
-                for serializer in self.items_serializers.iter() {
-                    let element = match py_tuple_iter.next() {
-                        Some(value) => value,
-                        None => break,
-                    };
+                for (_, element, serializer) in self.iter_positions(py_tuple) {
                     key_builder.push(&serializer.json_key(element, extra)?);
                 }
-                let extra_serializer = self.extra_serializer.as_ref();
-                for element in py_tuple_iter {
-                    key_builder.push(&extra_serializer.json_key(element, extra)?);
-                }
                 Ok(Cow::Owned(key_builder.finish()))
             }
             Err(_) => {
@@ -263,13 +263,10 @@ impl TypeSerializer for TuplePositionalSerializer {
             Ok(py_tuple) => {
                 let py_tuple: &PyTuple = py_tuple.downcast().map_err(py_err_se_err)?;
 
-                let mut py_tuple_iter = py_tuple.iter();
+                self.check_length(py_tuple, extra).map_err(py_err_se_err)?;
+
                 let mut seq = serializer.serialize_seq(Some(py_tuple.len()))?;
-                for (index, serializer) in self.items_serializers.iter().enumerate() {
-                    let element = match py_tuple_iter.next() {
-                        Some(value) => value,
-                        None => break,
-                    };
+                for (index, element, serializer) in self.iter_positions(py_tuple) {
                     let op_next = self
                         .filter
                         .index_filter(index, include, exclude)
@@ -281,21 +278,6 @@ impl TypeSerializer for TuplePositionalSerializer {
                     }
                 }
 
-                let expected_length = self.items_serializers.len();
-                let extra_serializer = self.extra_serializer.as_ref();
-                for (index2, element) in py_tuple_iter.enumerate() {
-                    let index = index2 + expected_length;
-                    let op_next = self
-                        .filter
-                        .index_filter(index, include, exclude)
-                        .map_err(py_err_se_err)?;
-                    if let Some((next_include, next_exclude)) = op_next {
-                        let item_serialize =
-                            PydanticSerializer::new(element, extra_serializer, next_include, next_exclude, extra);
-                        seq.serialize_element(&item_serialize)?;
-                    }
-                }
-
                 seq.end()
             }
             Err(_) => {
@@ -310,6 +292,34 @@ impl TypeSerializer for TuplePositionalSerializer {
     }
 }
 
+impl TuplePositionalSerializer {
+    /// Pair each element with the serializer for its position, elements beyond `items_schema` use
+    /// `extra_schema` or fall back to inference. A tuple shorter than the schema simply stops early.
+    fn iter_positions<'s, 'py>(
+        &'s self,
+        py_tuple: &'py PyTuple,
+    ) -> impl Iterator<Item = (usize, &'py PyAny, &'s CombinedSerializer)> {
+        py_tuple.iter().enumerate().map(move |(index, element)| {
+            let serializer = self.items_serializers.get(index).unwrap_or(&self.extra_serializer);
+            (index, element, serializer)
+        })
+    }
+
+    /// Warn when a tuple has more items than the schema declares and there's no `extra_schema` to cover them.
+    fn check_length(&self, py_tuple: &PyTuple, extra: &Extra) -> PyResult<()> {
+        let expected = self.items_serializers.len();
+        if !self.has_extra_schema && py_tuple.len() > expected {
+            extra.warnings.custom_warning(format!(
+                "Expected `{}` with {} items but got {} - extra items serialized by inference",
+                self.name,
+                expected,
+                py_tuple.len()
+            ))?;
+        }
+        Ok(())
+    }
+}
+
 pub(crate) struct KeyBuilder {
     key: String,
     first: bool,
@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 
+use pyo3::exceptions::PyException;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyString};
@@ -9,6 +10,7 @@ use serde::ser::SerializeMap;
 
 use crate::build_context::BuildContext;
 use crate::build_tools::{py_error_type, schema_or_config, ExtraBehavior, SchemaDict};
+use crate::serializers::computed_fields::ComputedFields;
 use crate::PydanticSerializationUnexpectedValue;
 
 use super::{
@@ -23,6 +25,7 @@ pub(super) struct TypedDictField {
     alias_py: Option<Py<PyString>>,
     serializer: CombinedSerializer,
     required: bool,
+    serialize_as_any: bool,
 }
 
 impl TypedDictField {
@@ -32,6 +35,7 @@ impl TypedDictField {
         alias: Option<String>,
         serializer: CombinedSerializer,
         required: bool,
+        serialize_as_any: bool,
     ) -> Self {
         let alias_py = alias.as_ref().map(|alias| PyString::new(py, alias.as_str()).into());
         Self {
@@ -40,6 +44,15 @@ impl TypedDictField {
             alias_py,
             serializer,
             required,
+            serialize_as_any,
+        }
+    }
+
+    /// `serialize_as_any` on the field applies to everything within the field's value
+    fn get_extra<'a>(&self, extra: &Extra<'a>) -> Extra<'a> {
+        Extra {
+            serialize_as_any: extra.serialize_as_any || self.serialize_as_any,
+            ..*extra
         }
     }
 
@@ -68,6 +81,8 @@ pub struct TypedDictSerializer {
     include_extra: bool,
     // isize because we look up include exclude via `.hash()` which returns an isize
     filter: SchemaFilter<isize>,
+    // from the model or dataclass schema this serializer is the fields of, see `ModelSerializer`
+    computed_fields: Option<ComputedFields>,
 }
 
 impl BuildSerializer for TypedDictSerializer {
@@ -115,6 +130,7 @@ impl BuildSerializer for TypedDictSerializer {
                         alias,
                         serializer,
                         field_info.get_as(intern!(py, "required"))?.unwrap_or(total),
+                        field_info.get_as(intern!(py, "serialize_as_any"))?.unwrap_or(false),
                     ),
                 );
             }
@@ -136,14 +152,29 @@ impl TypedDictSerializer {
             fields,
             include_extra,
             filter,
+            computed_fields: None,
         }
     }
 
+    pub(super) fn set_computed_fields(&mut self, computed_fields: ComputedFields) {
+        self.computed_fields = Some(computed_fields);
+    }
+
+    /// a value is only the default if it has the same type, e.g. `True` isn't excluded when the default is `1`
+    /// since it would be serialized differently, a comparison which raises an exception counts as not equal
     fn exclude_default(&self, value: &PyAny, extra: &Extra, field: &TypedDictField) -> PyResult<bool> {
         if extra.exclude_defaults {
-            if let Some(default) = field.serializer.get_default(value.py())? {
-                if value.eq(default)? {
+            let py = value.py();
+            if let Some(default) = field.serializer.get_default(py)? {
+                let default = default.as_ref(py);
+                if value.is(default) {
                     return Ok(true);
+                } else if value.get_type().is(default.get_type()) {
+                    return match value.eq(default) {
+                        Ok(eq) => Ok(eq),
+                        Err(err) if err.is_instance_of::<PyException>(py) => Ok(false),
+                        Err(err) => Err(err),
+                    };
                 }
             }
         }
@@ -192,7 +223,11 @@ impl TypeSerializer for TypedDictSerializer {
                                 if self.exclude_default(value, &extra, field)? {
                                     continue;
                                 }
-                                let value = field.serializer.to_python(value, next_include, next_exclude, &extra)?;
+                                let field_extra = field.get_extra(&extra);
+                                let value =
+                                    field
+                                        .serializer
+                                        .to_python(value, next_include, next_exclude, &field_extra)?;
                                 let output_key = field.get_key_py(py, &extra);
                                 new_dict.set_item(output_key, value)?;
 
@@ -219,6 +254,9 @@ impl TypeSerializer for TypedDictSerializer {
                         return Err(PydanticSerializationUnexpectedValue::new_err(None));
                     }
                 }
+                if let (Some(ref computed_fields), Some(model)) = (&self.computed_fields, extra.model) {
+                    computed_fields.to_python(model, new_dict, &self.filter, include, exclude, &extra)?;
+                }
                 Ok(new_dict.into_py(py))
             }
             Err(_) => {
@@ -252,7 +290,7 @@ impl TypeSerializer for TypedDictSerializer {
                 let expected_len = match self.include_extra {
                     true => py_dict.len(),
                     false => self.fields.len(),
-                };
+                } + self.computed_fields.as_ref().map_or(0, ComputedFields::len);
                 // NOTE! As above, we maintain the order of the input dict assuming that's right
                 // we don't both with `used_fields` here because on unions, `to_python(..., mode='json')` is used
                 let mut map = serializer.serialize_map(Some(expected_len))?;
@@ -275,12 +313,13 @@ impl TypeSerializer for TypedDictSerializer {
                                     continue;
                                 }
                                 let output_key = field.get_key_json(key_str, &extra);
+                                let field_extra = field.get_extra(&extra);
                                 let s = PydanticSerializer::new(
                                     value,
                                     &field.serializer,
                                     next_include,
                                     next_exclude,
-                                    &extra,
+                                    &field_extra,
                                 );
                                 map.serialize_entry(&output_key, &s)?;
                                 continue;
@@ -293,6 +332,9 @@ impl TypeSerializer for TypedDictSerializer {
                         }
                     }
                 }
+                if let (Some(ref computed_fields), Some(model)) = (&self.computed_fields, extra.model) {
+                    computed_fields.serde_serialize(model, &mut map, &self.filter, include, exclude, &extra)?;
+                }
                 map.end()
             }
             Err(_) => {
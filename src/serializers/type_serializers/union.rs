@@ -6,6 +6,7 @@ use std::borrow::Cow;
 use crate::build_context::BuildContext;
 use crate::build_tools::{py_err, SchemaDict};
 use crate::serializers::extra::SerCheck;
+use crate::validators::union_choice_schema;
 use crate::PydanticSerializationUnexpectedValue;
 
 use super::{
@@ -31,7 +32,7 @@ impl BuildSerializer for UnionSerializer {
         let choices: Vec<CombinedSerializer> = schema
             .get_as_req::<&PyList>(intern!(py, "choices"))?
             .iter()
-            .map(|choice| CombinedSerializer::build(choice.downcast()?, config, build_context))
+            .map(|choice| CombinedSerializer::build(union_choice_schema(choice)?, config, build_context))
             .collect::<PyResult<Vec<CombinedSerializer>>>()?;
 
         Self::from_choices(choices)
@@ -5,8 +5,8 @@ use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
 use crate::build_context::BuildContext;
-use crate::build_tools::SchemaDict;
-use crate::validators::DefaultType;
+use crate::build_tools::{schema_or_config_same, SchemaDict};
+use crate::validators::{DefaultType, SchemaValidator};
 
 use super::{BuildSerializer, CombinedSerializer, Extra, TypeSerializer};
 
@@ -14,6 +14,11 @@ use super::{BuildSerializer, CombinedSerializer, Extra, TypeSerializer};
 pub struct WithDefaultSerializer {
     default: DefaultType,
     serializer: Box<CombinedSerializer>,
+    // with `validate_default`, a validator for this schema so `exclude_defaults` compares values with the
+    // validated default, as `WithDefaultValidator` would return it
+    default_validator: Option<Box<SchemaValidator>>,
+    // from `ser_call_default_factory`, whether `exclude_defaults` calls `default_factory`
+    call_default_factory: bool,
 }
 
 impl BuildSerializer for WithDefaultSerializer {
@@ -30,7 +35,27 @@ impl BuildSerializer for WithDefaultSerializer {
         let sub_schema: &PyDict = schema.get_as_req(intern!(py, "schema"))?;
         let serializer = Box::new(CombinedSerializer::build(sub_schema, config, build_context)?);
 
-        Ok(Self { default, serializer }.into())
+        let validate_default = schema_or_config_same(schema, config, intern!(py, "validate_default"))?.unwrap_or(false);
+        let default_validator = match validate_default && !matches!(default, DefaultType::None) && !default.takes_data()
+        {
+            // a schema referring to definitions outside it can't be built alone, the default is then compared
+            // unvalidated
+            true => SchemaValidator::py_new(py, schema, config).ok().map(Box::new),
+            false => None,
+        };
+        let call_default_factory = config
+            .map(|c| c.get_as(intern!(py, "ser_call_default_factory")))
+            .transpose()?
+            .flatten()
+            .unwrap_or(true);
+
+        Ok(Self {
+            default,
+            serializer,
+            default_validator,
+            call_default_factory,
+        }
+        .into())
     }
 }
 
@@ -69,7 +94,18 @@ impl TypeSerializer for WithDefaultSerializer {
         self.serializer.retry_with_lax_check()
     }
 
+    /// the default `exclude_defaults` compares values with, computed each time like `WithDefaultValidator` does
+    /// so `default_factory` is called, unless `ser_call_default_factory` is false
     fn get_default(&self, py: Python) -> PyResult<Option<PyObject>> {
-        self.default.default_value(py)
+        match self.default {
+            // without the rest of the data there's no default to compare with
+            DefaultType::DefaultFactory(_, true) => return Ok(None),
+            DefaultType::DefaultFactory(..) if !self.call_default_factory => return Ok(None),
+            _ => (),
+        }
+        match self.default_validator {
+            Some(ref validator) => validator.default_value(py, None, None),
+            None => self.default.default_value(py, None),
+        }
     }
 }
@@ -0,0 +1,358 @@
+use pyo3::exceptions::PyKeyError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyTuple};
+
+use ahash::{AHashMap, AHashSet};
+
+use crate::build_tools::{py_err, SchemaDict};
+use crate::validators::union_choice_schema;
+
+const ITEM_SEGMENT: &str = "__item__";
+const VALUE_SEGMENT: &str = "__value__";
+
+/// The schema found at a path within a larger schema by `find_sub_schema`
+pub struct SubSchema<'py> {
+    /// the schema at the path, wrapped in a `definitions` schema with every definition it uses
+    pub schema: &'py PyDict,
+    /// model schemas passed through on the way to the schema, in order, their `config` applies to the schema
+    pub models: Vec<&'py PyDict>,
+}
+
+/// Find the schema at `path` within `schema` for `sub_validator` and `sub_serializer`, each segment of the
+/// path is a typed dict or dataclass field name, `'__item__'` for the items of lists, sets and the like,
+/// `'__value__'` for the values of dicts, a tag of a tagged union or the index of a union choice.
+///
+/// Wrapping schemas like `model`, `nullable` and `default` are passed through to find the next segment,
+/// but the schema at the end of the path is returned as is, e.g. a field's `default` schema is kept.
+pub fn find_sub_schema<'py>(schema: &'py PyDict, path: &'py PyTuple) -> PyResult<SubSchema<'py>> {
+    let py = schema.py();
+    let refs = Refs::new(schema)?;
+
+    let mut current = schema;
+    let mut models = Vec::new();
+    for (index, segment) in path.iter().enumerate() {
+        current = unwrap_schema(current, &refs, &mut models)?;
+        current = match step(current, segment)? {
+            Some(next) => next,
+            None => {
+                let valid = valid_segments(current)?;
+                let expected = match valid.is_empty() {
+                    true => format!("`{}` schema has no sub-schemas", schema_type(current)?),
+                    false => format!("expected one of: {}", valid.join(", ")),
+                };
+                return Err(PyKeyError::new_err(format!(
+                    "Invalid segment {} at {}, {expected}",
+                    segment.repr()?,
+                    PyTuple::new(py, &path.as_slice()[..index]).repr()?,
+                )));
+            }
+        };
+    }
+    Ok(SubSchema {
+        schema: with_definitions(current, &refs)?,
+        models,
+    })
+}
+
+/// pass through schemas which wrap a single schema, until one which a segment can be applied to
+fn unwrap_schema<'py>(
+    mut schema: &'py PyDict,
+    refs: &Refs<'py>,
+    models: &mut Vec<&'py PyDict>,
+) -> PyResult<&'py PyDict> {
+    let py = schema.py();
+    // a chain of `definition-ref`s longer than the number of refs must go round in circles
+    let mut ref_steps = 0;
+    loop {
+        schema = match schema_type(schema)? {
+            "definition-ref" => {
+                let schema_ref: &str = schema.get_as_req(intern!(py, "schema_ref"))?;
+                ref_steps += 1;
+                match refs.get(schema_ref) {
+                    Some(definition) if ref_steps <= refs.len() => definition,
+                    Some(_) => return py_err!("Definitions error: ref '{}' only references itself", schema_ref),
+                    None => return py_err!("Definitions error: definition '{}' was never filled", schema_ref),
+                }
+            }
+            "model" => {
+                models.push(schema);
+                schema.get_as_req(intern!(py, "schema"))?
+            }
+            "definitions" | "dataclass" | "default" | "nullable" | "function-before" | "function-after"
+            | "function-wrap" => match schema.get_as(intern!(py, "schema"))? {
+                Some(inner) => inner,
+                None => return Ok(schema),
+            },
+            _ => return Ok(schema),
+        };
+    }
+}
+
+/// the schema which `segment` leads to from `schema`, or `None` if it isn't valid
+fn step<'py>(schema: &'py PyDict, segment: &'py PyAny) -> PyResult<Option<&'py PyDict>> {
+    let py = schema.py();
+    let next = match schema_type(schema)? {
+        "typed-dict" => {
+            let fields: &PyDict = schema.get_as_req(intern!(py, "fields"))?;
+            match fields.get_item(segment) {
+                Some(field) => Some(field.downcast::<PyDict>()?.get_as_req(intern!(py, "schema"))?),
+                None => None,
+            }
+        }
+        "dataclass-args" => {
+            let fields: &PyList = schema.get_as_req(intern!(py, "fields"))?;
+            let mut found = None;
+            for field in fields {
+                let field: &PyDict = field.downcast()?;
+                if field.get_as_req::<&PyAny>(intern!(py, "name"))?.eq(segment)? {
+                    found = Some(field.get_as_req(intern!(py, "schema"))?);
+                    break;
+                }
+            }
+            found
+        }
+        "list" | "set" | "frozenset" | "tuple-variable" | "generator" if segment.eq(ITEM_SEGMENT)? => {
+            Some(schema.get_as(intern!(py, "items_schema"))?.unwrap_or(any_schema(py)?))
+        }
+        "dict" if segment.eq(VALUE_SEGMENT)? => {
+            Some(schema.get_as(intern!(py, "values_schema"))?.unwrap_or(any_schema(py)?))
+        }
+        "tagged-union" => {
+            let choices: &PyDict = schema.get_as_req(intern!(py, "choices"))?;
+            let mut choice = choices.get_item(segment);
+            // a choice can be the tag of another choice, which can't lead back to itself
+            for _ in 0..choices.len() {
+                match choice {
+                    Some(tag) if !tag.is_instance_of::<PyDict>()? => choice = choices.get_item(tag),
+                    _ => break,
+                }
+            }
+            match choice {
+                Some(choice) => Some(choice.downcast::<PyDict>()?),
+                None => None,
+            }
+        }
+        "union" => {
+            let choices: &PyList = schema.get_as_req(intern!(py, "choices"))?;
+            match segment.extract::<usize>() {
+                Ok(index) if index < choices.len() => Some(union_choice_schema(choices.get_item(index)?)?),
+                _ => None,
+            }
+        }
+        _ => None,
+    };
+    Ok(next)
+}
+
+/// the segments which can follow `schema`, used in the error for an invalid segment
+fn valid_segments(schema: &PyDict) -> PyResult<Vec<String>> {
+    let py = schema.py();
+    let segments = match schema_type(schema)? {
+        "typed-dict" => {
+            let fields: &PyDict = schema.get_as_req(intern!(py, "fields"))?;
+            fields.keys().iter().map(repr).collect::<PyResult<_>>()?
+        }
+        "dataclass-args" => {
+            let fields: &PyList = schema.get_as_req(intern!(py, "fields"))?;
+            fields
+                .iter()
+                .map(|field| repr(field.downcast::<PyDict>()?.get_as_req(intern!(py, "name"))?))
+                .collect::<PyResult<_>>()?
+        }
+        "list" | "set" | "frozenset" | "tuple-variable" | "generator" => vec![format!("'{ITEM_SEGMENT}'")],
+        "dict" => vec![format!("'{VALUE_SEGMENT}'")],
+        "tagged-union" => {
+            let choices: &PyDict = schema.get_as_req(intern!(py, "choices"))?;
+            choices.keys().iter().map(repr).collect::<PyResult<_>>()?
+        }
+        "union" => {
+            let choices: &PyList = schema.get_as_req(intern!(py, "choices"))?;
+            (0..choices.len()).map(|index| index.to_string()).collect()
+        }
+        _ => Vec::new(),
+    };
+    Ok(segments)
+}
+
+/// Every schema with a `ref` within a schema, in the order they're built
+#[derive(Default)]
+struct Refs<'py> {
+    schemas: Vec<RefSchema<'py>>,
+    indexes: AHashMap<String, usize>,
+}
+
+struct RefSchema<'py> {
+    schema: &'py PyDict,
+    /// index of the outermost schema with a `ref` which contains this one, or this one's own index
+    outermost: usize,
+}
+
+impl<'py> Refs<'py> {
+    fn new(schema: &'py PyAny) -> PyResult<Self> {
+        let mut refs = Self::default();
+        refs.collect(schema, None)?;
+        Ok(refs)
+    }
+
+    fn collect(&mut self, schema: &'py PyAny, outermost: Option<usize>) -> PyResult<()> {
+        let py = schema.py();
+        if let Ok(dict) = schema.downcast::<PyDict>() {
+            let mut outermost = outermost;
+            if let Some(ref_) = schema_ref(dict)? {
+                let index = self.schemas.len();
+                let outermost = *outermost.get_or_insert(index);
+                self.schemas.push(RefSchema {
+                    schema: dict,
+                    outermost,
+                });
+                self.indexes.insert(ref_, index);
+            }
+            // definitions are built before the schema which uses them
+            if schema_type(dict).ok() == Some("definitions") {
+                self.collect(dict.get_as_req(intern!(py, "definitions"))?, outermost)?;
+                return self.collect(dict.get_as_req(intern!(py, "schema"))?, outermost);
+            }
+            for (key, value) in dict.iter() {
+                if !key.eq(intern!(py, "metadata"))? {
+                    self.collect(value, outermost)?;
+                }
+            }
+        } else if let Ok(list) = schema.downcast::<PyList>() {
+            for item in list.iter() {
+                self.collect(item, outermost)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn len(&self) -> usize {
+        self.schemas.len()
+    }
+
+    fn get(&self, ref_: &str) -> Option<&'py PyDict> {
+        self.indexes.get(ref_).map(|index| self.schemas[*index].schema)
+    }
+
+    fn outermost(&self, ref_: &str) -> Option<usize> {
+        self.indexes.get(ref_).map(|index| self.schemas[*index].outermost)
+    }
+}
+
+/// if `schema` uses definitions from outside itself, wrap it in a `definitions` schema with them,
+/// each definition is the outermost schema with a `ref` containing the one used, kept as is so schemas which
+/// reference each other are built the same way as in the parent, these are also replaced with a `definition-ref`
+/// if they're within `schema` so nothing is defined twice
+fn with_definitions<'py>(schema: &'py PyDict, refs: &Refs<'py>) -> PyResult<&'py PyDict> {
+    let py = schema.py();
+    let defined = Refs::new(schema)?;
+    let mut used = AHashSet::new();
+    collect_used_refs(schema, &mut used)?;
+    let mut pending: Vec<String> = used.into_iter().filter(|r| !defined.indexes.contains_key(r)).collect();
+
+    let mut included = AHashSet::new();
+    while let Some(ref_) = pending.pop() {
+        let outermost = match refs.outermost(&ref_) {
+            Some(outermost) => outermost,
+            None => return py_err!("Definitions error: definition '{}' was never filled", ref_),
+        };
+        if included.insert(outermost) {
+            let mut definition_used = AHashSet::new();
+            collect_used_refs(refs.schemas[outermost].schema, &mut definition_used)?;
+            pending.extend(
+                definition_used
+                    .into_iter()
+                    .filter(|r| refs.outermost(r) != Some(outermost)),
+            );
+        }
+    }
+    if included.is_empty() {
+        return Ok(schema);
+    }
+
+    let mut included: Vec<usize> = included.into_iter().collect();
+    included.sort_unstable();
+    let is_included = |ref_: &str| matches!(refs.outermost(ref_), Some(outermost) if included.contains(&outermost));
+    let definitions: Vec<&PyDict> = included.iter().map(|index| refs.schemas[*index].schema).collect();
+
+    let wrapped = PyDict::new(py);
+    wrapped.set_item(intern!(py, "type"), intern!(py, "definitions"))?;
+    wrapped.set_item(intern!(py, "schema"), replace_refs(schema, &is_included)?)?;
+    wrapped.set_item(intern!(py, "definitions"), PyList::new(py, definitions))?;
+    Ok(wrapped)
+}
+
+/// the refs used by `definition-ref` schemas within `schema`
+fn collect_used_refs(schema: &PyAny, refs: &mut AHashSet<String>) -> PyResult<()> {
+    if let Ok(dict) = schema.downcast::<PyDict>() {
+        if schema_type(dict).ok() == Some("definition-ref") {
+            refs.insert(dict.get_as_req(intern!(schema.py(), "schema_ref"))?);
+        } else {
+            for (key, value) in dict.iter() {
+                if !key.eq(intern!(schema.py(), "metadata"))? {
+                    collect_used_refs(value, refs)?;
+                }
+            }
+        }
+    } else if let Ok(list) = schema.downcast::<PyList>() {
+        for item in list.iter() {
+            collect_used_refs(item, refs)?;
+        }
+    }
+    Ok(())
+}
+
+/// a copy of `schema` with every schema whose `ref` matches `replace` replaced by a `definition-ref` to it
+fn replace_refs(schema: &PyAny, replace: &impl Fn(&str) -> bool) -> PyResult<PyObject> {
+    let py = schema.py();
+    if let Ok(dict) = schema.downcast::<PyDict>() {
+        if let Some(ref_) = schema_ref(dict)? {
+            if replace(&ref_) {
+                let definition_ref = PyDict::new(py);
+                definition_ref.set_item(intern!(py, "type"), intern!(py, "definition-ref"))?;
+                definition_ref.set_item(intern!(py, "schema_ref"), ref_)?;
+                return Ok(definition_ref.into_py(py));
+            }
+        }
+        let copy = PyDict::new(py);
+        for (key, value) in dict.iter() {
+            match key.eq(intern!(py, "metadata"))? {
+                true => copy.set_item(key, value)?,
+                false => copy.set_item(key, replace_refs(value, replace)?)?,
+            }
+        }
+        Ok(copy.into_py(py))
+    } else if let Ok(list) = schema.downcast::<PyList>() {
+        let items = list
+            .iter()
+            .map(|item| replace_refs(item, replace))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(PyList::new(py, items).into_py(py))
+    } else {
+        Ok(schema.into_py(py))
+    }
+}
+
+/// the `ref` of a dict which is a schema, other dicts like config, fields and defaults rarely have
+/// both a `type` and a string `ref`
+fn schema_ref(dict: &PyDict) -> PyResult<Option<String>> {
+    let py = dict.py();
+    match dict.get_item(intern!(py, "type")) {
+        Some(_) => Ok(dict.get_as(intern!(py, "ref")).unwrap_or(None)),
+        None => Ok(None),
+    }
+}
+
+fn schema_type(schema: &PyDict) -> PyResult<&str> {
+    schema.get_as_req(intern!(schema.py(), "type"))
+}
+
+fn any_schema(py: Python<'_>) -> PyResult<&PyDict> {
+    let schema = PyDict::new(py);
+    schema.set_item(intern!(py, "type"), intern!(py, "any"))?;
+    Ok(schema)
+}
+
+fn repr(value: &PyAny) -> PyResult<String> {
+    Ok(value.repr()?.to_str()?.to_string())
+}
@@ -1,7 +1,11 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
 use idna::punycode::decode_to_string;
+use pyo3::basic::CompareOp;
 use pyo3::once_cell::GILOnceCell;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyType};
 use url::Url;
 
 use crate::SchemaValidator;
@@ -37,7 +41,7 @@ impl PyUrl {
     pub fn py_new(py: Python, url: &PyAny) -> PyResult<Self> {
         let schema_obj = SCHEMA_DEFINITION_URL
             .get_or_init(py, || build_schema_validator(py, "url"))
-            .validate_python(py, url, None, None, None)?;
+            .validate_python(py, url, None, None, None, None, false, None, None)?;
         schema_obj.extract(py)
     }
 
@@ -116,6 +120,28 @@ impl PyUrl {
     pub fn __repr__(&self) -> String {
         format!("Url('{}')", self.lib_url)
     }
+
+    // the URL is normalised when it's parsed, so comparing the string form compares each component,
+    // comparison with other types returns `NotImplemented`
+    fn __richcmp__(&self, other: &Self, op: CompareOp) -> bool {
+        op.matches(self.lib_url.as_str().cmp(other.lib_url.as_str()))
+    }
+
+    fn __hash__(&self) -> u64 {
+        hash_str(self.lib_url.as_str())
+    }
+
+    fn __copy__(&self) -> Self {
+        self.clone()
+    }
+
+    fn __deepcopy__(&self, _memo: &PyAny) -> Self {
+        self.clone()
+    }
+
+    fn __reduce__<'py>(&self, py: Python<'py>) -> (&'py PyType, (&str,)) {
+        (py.get_type::<Self>(), (self.__str__(),))
+    }
 }
 
 #[pyclass(name = "MultiHostUrl", module = "pydantic_core._pydantic_core")]
@@ -147,7 +173,7 @@ impl PyMultiHostUrl {
     pub fn py_new(py: Python, url: &PyAny) -> PyResult<Self> {
         let schema_obj = SCHEMA_DEFINITION_MULTI_HOST_URL
             .get_or_init(py, || build_schema_validator(py, "multi-host-url"))
-            .validate_python(py, url, None, None, None)?;
+            .validate_python(py, url, None, None, None, None, false, None, None)?;
         schema_obj.extract(py)
     }
 
@@ -250,6 +276,32 @@ impl PyMultiHostUrl {
     pub fn __repr__(&self) -> String {
         format!("Url('{}')", self.__str__())
     }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp) -> bool {
+        op.matches(self.__str__().cmp(&other.__str__()))
+    }
+
+    fn __hash__(&self) -> u64 {
+        hash_str(&self.__str__())
+    }
+
+    fn __copy__(&self) -> Self {
+        self.clone()
+    }
+
+    fn __deepcopy__(&self, _memo: &PyAny) -> Self {
+        self.clone()
+    }
+
+    fn __reduce__<'py>(&self, py: Python<'py>) -> (&'py PyType, (String,)) {
+        (py.get_type::<Self>(), (self.__str__(),))
+    }
+}
+
+fn hash_str(s: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    s.hash(&mut hasher);
+    hasher.finish()
 }
 
 fn host_to_dict<'a>(py: Python<'a>, lib_url: &Url) -> PyResult<&'a PyDict> {
@@ -1,25 +1,33 @@
+use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
+use crate::build_tools::SchemaDict;
 use crate::errors::ValResult;
 use crate::input::Input;
 use crate::recursion_guard::RecursionGuard;
 
-use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
 
 /// This might seem useless, but it's useful in DictValidator to avoid Option<Validator> a lot
 #[derive(Debug, Clone)]
-pub struct AnyValidator;
+pub struct AnyValidator {
+    // record the type of every input in the coercion report, to find which types are used
+    pub(super) record_types: bool,
+}
 
 impl BuildValidator for AnyValidator {
     const EXPECTED_TYPE: &'static str = "any";
 
     fn build(
-        _schema: &PyDict,
+        schema: &PyDict,
         _config: Option<&PyDict>,
         _build_context: &mut BuildContext<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
-        Ok(Self.into())
+        Ok(Self {
+            record_types: schema.get_as(intern!(schema.py(), "record_types"))?.unwrap_or(false),
+        }
+        .into())
     }
 }
 
@@ -28,12 +36,24 @@ impl Validator for AnyValidator {
         &'s self,
         py: Python<'data>,
         input: &'data impl Input<'data>,
-        _extra: &Extra,
+        extra: &Extra,
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        // Ok(input.clone().into_py(py))
-        Ok(input.to_object(py))
+        if self.record_types {
+            // no coercion is made, but recording the input as a coercion to `any` gives its type and location
+            extra.report_coercion(py, input, Self::EXPECTED_TYPE, || false);
+        }
+        match extra.string_cache {
+            Some(string_cache) => Ok(input.to_object_cached(py, string_cache)),
+            None => Ok(input.to_object(py)),
+        }
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .accepts_always(&["any"])
+            .finish()
     }
 
     fn get_name(&self) -> &str {
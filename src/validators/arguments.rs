@@ -6,11 +6,12 @@ use ahash::AHashSet;
 
 use crate::build_tools::{py_err, schema_or_config_same, SchemaDict};
 use crate::errors::{ErrorType, ValError, ValLineError, ValResult};
-use crate::input::{GenericArguments, Input};
+use crate::input::{GenericArguments, Input, JsonInput, JsonObject};
 use crate::lookup_key::LookupKey;
 use crate::recursion_guard::RecursionGuard;
 
-use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::with_default::WithDefaultValidator;
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
 
 #[derive(Debug, Clone)]
 struct Parameter {
@@ -21,12 +22,35 @@ struct Parameter {
     validator: CombinedValidator,
 }
 
+/// How keyword arguments which don't match a parameter are validated with `var_kwargs_schema`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum VarKwargsMode {
+    /// each value is validated with the schema, like `**kwargs: int`
+    Uniform,
+    /// the keyword arguments are validated together with a typed dict schema, like `**kwargs: Unpack[TD]`
+    UnpackedTypedDict,
+}
+
+impl VarKwargsMode {
+    fn from_schema(schema: &PyDict) -> PyResult<Self> {
+        match schema.get_as::<&str>(intern!(schema.py(), "var_kwargs_mode"))? {
+            None | Some("uniform") => Ok(Self::Uniform),
+            Some("unpacked-typed-dict") => Ok(Self::UnpackedTypedDict),
+            Some(s) => py_err!(
+                "Invalid var_kwargs_mode: `{}`, expected `uniform` or `unpacked-typed-dict`",
+                s
+            ),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ArgumentsValidator {
     parameters: Vec<Parameter>,
     positional_params_count: usize,
     var_args_validator: Option<Box<CombinedValidator>>,
     var_kwargs_validator: Option<Box<CombinedValidator>>,
+    var_kwargs_mode: VarKwargsMode,
     loc_by_alias: bool,
 }
 
@@ -75,7 +99,9 @@ impl BuildValidator for ArgumentsValidator {
 
             let schema: &PyAny = arg.get_as_req(intern!(py, "schema"))?;
 
-            let validator = match build_validator(schema, config, build_context) {
+            let validator = match build_validator(schema, config, build_context)
+                .and_then(|v| WithDefaultValidator::wrap(arg, config, v))
+            {
                 Ok(v) => v,
                 Err(err) => return py_err!("Parameter '{}':\n  {}", name, err),
             };
@@ -104,6 +130,20 @@ impl BuildValidator for ArgumentsValidator {
             });
         }
 
+        let var_kwargs_mode = VarKwargsMode::from_schema(schema)?;
+        let var_kwargs_schema: Option<&PyDict> = schema.get_as(intern!(py, "var_kwargs_schema"))?;
+        if var_kwargs_mode == VarKwargsMode::UnpackedTypedDict {
+            let is_typed_dict = match var_kwargs_schema {
+                Some(v) => v.get_as::<&str>(intern!(py, "type"))? == Some("typed-dict"),
+                None => false,
+            };
+            if !is_typed_dict {
+                return py_err!(
+                    "var_kwargs_schema must be a typed-dict schema with var_kwargs_mode='unpacked-typed-dict'"
+                );
+            }
+        }
+
         Ok(Self {
             parameters,
             positional_params_count,
@@ -111,10 +151,11 @@ impl BuildValidator for ArgumentsValidator {
                 Some(v) => Some(Box::new(build_validator(v, config, build_context)?)),
                 None => None,
             },
-            var_kwargs_validator: match schema.get_item(intern!(py, "var_kwargs_schema")) {
+            var_kwargs_validator: match var_kwargs_schema {
                 Some(v) => Some(Box::new(build_validator(v, config, build_context)?)),
                 None => None,
             },
+            var_kwargs_mode,
             loc_by_alias: config.get_as(intern!(py, "loc_by_alias"))?.unwrap_or(true),
         }
         .into())
@@ -149,6 +190,18 @@ macro_rules! json_slice {
 }
 pub(super) use json_slice;
 
+macro_rules! py_insert_kwarg {
+    ($kwargs:ident, $py:ident, $key:ident, $value:ident) => {
+        $kwargs.set_item($key.as_py_string($py), $value)?
+    };
+}
+
+macro_rules! json_insert_kwarg {
+    ($kwargs:ident, $py:ident, $key:ident, $value:ident) => {{
+        $kwargs.insert($key.as_cow()?.into_owned(), $value.clone());
+    }};
+}
+
 impl Validator for ArgumentsValidator {
     fn validate<'s, 'data>(
         &'s self,
@@ -166,7 +219,7 @@ impl Validator for ArgumentsValidator {
         let mut used_kwargs: AHashSet<&str> = AHashSet::with_capacity(self.parameters.len());
 
         macro_rules! process {
-            ($args:ident, $get_method:ident, $get_macro:ident, $slice_macro:ident) => {{
+            ($args:ident, $get_method:ident, $get_macro:ident, $slice_macro:ident, $unpacked_kwargs:ident, $insert_kwarg:ident) => {{
                 // go through arguments getting the value from args or kwargs and validating it
                 for (index, parameter) in self.parameters.iter().enumerate() {
                     let mut pos_value = None;
@@ -194,6 +247,8 @@ impl Validator for ArgumentsValidator {
                             ));
                         }
                         (Some(pos_value), None) => {
+                            let _loc = extra.coercion_loc(|| [index.into()]);
+                            extra.report_raw_input(py, pos_value);
                             match parameter
                                 .validator
                                 .validate(py, pos_value, extra, slots, recursion_guard)
@@ -206,6 +261,8 @@ impl Validator for ArgumentsValidator {
                             }
                         }
                         (None, Some((lookup_path, kw_value))) => {
+                            let _loc = extra.coercion_loc(|| lookup_path.loc_items(self.loc_by_alias, &parameter.name));
+                            extra.report_raw_input(py, kw_value);
                             match parameter
                                 .validator
                                 .validate(py, kw_value, extra, slots, recursion_guard)
@@ -220,7 +277,18 @@ impl Validator for ArgumentsValidator {
                             }
                         }
                         (None, None) => {
-                            if let Some(value) = parameter.validator.default_value(py, Some(parameter.name.as_str()), extra, slots, recursion_guard)? {
+                            let _loc = extra.coercion_loc(|| [parameter.name.as_str().into()]);
+                            // `data` from an enclosing typed dict isn't data a default factory should see here
+                            let extra = Extra { data: None, ..*extra };
+                            let default = match parameter.validator.default_value(py, Some(parameter.name.as_str()), &extra, slots, recursion_guard) {
+                                Ok(default) => default,
+                                Err(ValError::LineErrors(line_errors)) => {
+                                    errors.extend(line_errors);
+                                    continue;
+                                }
+                                Err(err) => return Err(err),
+                            };
+                            if let Some(value) = default {
                                 if let Some(ref kwarg_key) = parameter.kwarg_key {
                                     output_kwargs.set_item(kwarg_key, value)?;
                                 } else {
@@ -250,6 +318,8 @@ impl Validator for ArgumentsValidator {
                     if len > self.positional_params_count {
                         if let Some(ref validator) = self.var_args_validator {
                             for (index, item) in $slice_macro!(args, self.positional_params_count, len).iter().enumerate() {
+                                let _loc = extra.coercion_loc(|| [(index + self.positional_params_count).into()]);
+                                extra.report_raw_input(py, item);
                                 match validator.validate(py, item, extra, slots, recursion_guard) {
                                     Ok(value) => output_args.push(value),
                                     Err(ValError::LineErrors(line_errors)) => {
@@ -292,15 +362,23 @@ impl Validator for ArgumentsValidator {
                             };
                             if !used_kwargs.contains(either_str.as_cow()?.as_ref()) {
                                 match self.var_kwargs_validator {
-                                    Some(ref validator) => match validator.validate(py, value, extra, slots, recursion_guard) {
-                                        Ok(value) => output_kwargs.set_item(either_str.as_py_string(py), value)?,
-                                        Err(ValError::LineErrors(line_errors)) => {
-                                            for err in line_errors {
-                                                errors.push(err.with_outer_location(raw_key.as_loc_item()));
+                                    // validated together once all keyword arguments have been collected
+                                    Some(_) if self.var_kwargs_mode == VarKwargsMode::UnpackedTypedDict => {
+                                        $insert_kwarg!($unpacked_kwargs, py, either_str, value)
+                                    }
+                                    Some(ref validator) => {
+                                        let _loc = extra.coercion_loc(|| [raw_key.as_loc_item()]);
+                                        extra.report_raw_input(py, value);
+                                        match validator.validate(py, value, extra, slots, recursion_guard) {
+                                            Ok(value) => output_kwargs.set_item(either_str.as_py_string(py), value)?,
+                                            Err(ValError::LineErrors(line_errors)) => {
+                                                for err in line_errors {
+                                                    errors.push(err.with_outer_location(raw_key.as_loc_item()));
+                                                }
                                             }
+                                            Err(err) => return Err(err),
                                         }
-                                        Err(err) => return Err(err),
-                                    },
+                                    }
                                     None => {
                                         errors.push(ValLineError::new_with_loc(
                                             ErrorType::UnexpectedKeywordArgument,
@@ -315,9 +393,28 @@ impl Validator for ArgumentsValidator {
                 }
             }};
         }
+        let unpacked_validator = match self.var_kwargs_mode {
+            VarKwargsMode::UnpackedTypedDict => self.var_kwargs_validator.as_deref(),
+            VarKwargsMode::Uniform => None,
+        };
         match args {
-            GenericArguments::Py(a) => process!(a, py_get_dict_item, py_get, py_slice),
-            GenericArguments::Json(a) => process!(a, json_get, json_get, json_slice),
+            GenericArguments::Py(a) => {
+                let unpacked_kwargs = PyDict::new(py);
+                process!(a, py_get_dict_item, py_get, py_slice, unpacked_kwargs, py_insert_kwarg);
+                if let Some(validator) = unpacked_validator {
+                    let result = validator.validate(py, unpacked_kwargs.as_ref(), extra, slots, recursion_guard);
+                    unpacked_kwargs_result(py, result, input, output_kwargs, &mut errors)?;
+                }
+            }
+            GenericArguments::Json(a) => {
+                let mut unpacked_kwargs = JsonObject::new();
+                process!(a, json_get, json_get, json_slice, unpacked_kwargs, json_insert_kwarg);
+                if let Some(validator) = unpacked_validator {
+                    let unpacked_kwargs = JsonInput::Object(unpacked_kwargs);
+                    let result = validator.validate(py, &unpacked_kwargs, extra, slots, recursion_guard);
+                    unpacked_kwargs_result(py, result, input, output_kwargs, &mut errors)?;
+                }
+            }
         }
         if !errors.is_empty() {
             Err(ValError::LineErrors(errors))
@@ -326,6 +423,28 @@ impl Validator for ArgumentsValidator {
         }
     }
 
+    fn set_strict(&mut self, strict: bool) {
+        self.parameters.iter_mut().for_each(|v| v.validator.set_strict(strict));
+        if let Some(ref mut v) = self.var_args_validator {
+            v.set_strict(strict);
+        }
+        if let Some(ref mut v) = self.var_kwargs_validator {
+            v.set_strict(strict);
+        }
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        let mut description =
+            Description::new(py, Self::EXPECTED_TYPE).accepts_always(&["args_kwargs", "tuple", "list", "dict"]);
+        for parameter in &self.parameters {
+            description = description.child(&parameter.name, &parameter.validator)?;
+        }
+        description
+            .opt_child("var_args_schema", self.var_args_validator.as_deref())?
+            .opt_child("var_kwargs_schema", self.var_kwargs_validator.as_deref())?
+            .finish()
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
@@ -343,3 +462,33 @@ impl Validator for ArgumentsValidator {
         Ok(())
     }
 }
+
+/// add the keyword arguments validated with `var_kwargs_mode='unpacked-typed-dict'` to the output, errors are
+/// located at keywords, required keys which are missing are reported as missing keyword only arguments
+fn unpacked_kwargs_result<'data>(
+    py: Python<'data>,
+    result: ValResult<'_, PyObject>,
+    input: &'data impl Input<'data>,
+    output_kwargs: &PyDict,
+    errors: &mut Vec<ValLineError<'data>>,
+) -> ValResult<'data, ()> {
+    match result {
+        Ok(value) => {
+            let value: &PyDict = value.as_ref(py).downcast()?;
+            output_kwargs.update(value.as_mapping())?;
+        }
+        Err(ValError::LineErrors(line_errors)) => {
+            errors.extend(line_errors.iter().map(|err| {
+                let mut err = err.duplicate(py);
+                if matches!(err.error_type, ErrorType::Missing) && err.location.is_single() {
+                    // report like any other missing keyword argument, with all the arguments as the input
+                    err.error_type = ErrorType::MissingKeywordOnlyArgument;
+                    err.input_value = input.as_error_value();
+                }
+                err
+            }));
+        }
+        Err(err) => return Err(err.duplicate(py)),
+    }
+    Ok(())
+}
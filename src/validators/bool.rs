@@ -1,16 +1,39 @@
+use pyo3::exceptions::{PyException, PyTypeError};
+use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyBool, PyDict};
 
-use crate::build_tools::is_strict;
-use crate::errors::ValResult;
+use crate::build_tools::{is_strict, py_err, SchemaDict};
+use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::Input;
+use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
-use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NumericMode {
+    // only 0 and 1 are valid numbers
+    Strict01,
+    // any number is valid, nonzero numbers are true
+    Truthy,
+}
+
+impl NumericMode {
+    fn from_schema(schema: &PyDict) -> PyResult<Self> {
+        match schema.get_as::<&str>(intern!(schema.py(), "numeric_mode"))? {
+            None | Some("strict01") => Ok(Self::Strict01),
+            Some("truthy") => Ok(Self::Truthy),
+            Some(s) => py_err!("Invalid numeric_mode: `{}`, expected `strict01` or `truthy`", s),
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct BoolValidator {
     strict: bool,
+    coerce: Option<PyObject>,
+    numeric_mode: NumericMode,
 }
 
 impl BuildValidator for BoolValidator {
@@ -23,11 +46,20 @@ impl BuildValidator for BoolValidator {
     ) -> PyResult<CombinedValidator> {
         Ok(Self {
             strict: is_strict(schema, config)?,
+            coerce: schema.get_as(intern!(schema.py(), "coerce"))?,
+            numeric_mode: NumericMode::from_schema(schema)?,
         }
         .into())
     }
 }
 
+impl BoolValidator {
+    /// with `coerce`, the function may return a different bool even for bool inputs
+    pub fn has_coerce(&self) -> bool {
+        self.coerce.is_some()
+    }
+}
+
 impl Validator for BoolValidator {
     fn validate<'s, 'data>(
         &'s self,
@@ -37,16 +69,91 @@ impl Validator for BoolValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
+        let strict = extra.strict.unwrap_or(self.strict);
+        if !strict {
+            if let Some(ref coerce) = self.coerce {
+                if let Some(b) = call_coerce(py, coerce, input)? {
+                    extra.report_coercion(py, input, "bool", || input.strict_bool().is_ok());
+                    return Ok(b.into_py(py));
+                }
+            }
+            if self.numeric_mode == NumericMode::Truthy {
+                if let Some(b) = truthy_number(input)? {
+                    extra.report_coercion(py, input, "bool", || false);
+                    return Ok(b.into_py(py));
+                }
+            }
+        }
         // TODO in theory this could be quicker if we used PyBool rather than going to a bool
         // and back again, might be worth profiling?
-        Ok(input.validate_bool(extra.strict.unwrap_or(self.strict))?.into_py(py))
+        let b = input.validate_bool(strict)?;
+        extra.report_coercion(py, input, "bool", || input.strict_bool().is_ok());
+        Ok(b.into_py(py))
+    }
+
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .strict(self.strict)
+            .accepts(&["bool", "str", "bytes", "int", "float"], &["bool"])
+            .finish()
     }
 
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
 
+    fn ask(&self, question: &Question) -> bool {
+        matches!(question, Question::ReturnsImmutable)
+    }
+
     fn complete(&mut self, _build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
         Ok(())
     }
 }
+
+/// With `numeric_mode='truthy'`, any int or float is valid and nonzero numbers are true, `None` means the input
+/// isn't a number and the default rules should be used
+fn truthy_number<'data>(input: &'data impl Input<'data>) -> ValResult<'data, Option<bool>> {
+    if let Ok(int) = input.strict_int() {
+        Ok(Some(int != 0))
+    } else if let Ok(float) = input.strict_float() {
+        if float.is_nan() {
+            Err(ValError::new(ErrorType::BoolParsing, input))
+        } else {
+            Ok(Some(float != 0.0))
+        }
+    } else {
+        Ok(None)
+    }
+}
+
+/// Call the `coerce` function, `None` means the default rules should be used; exceptions
+/// raised by the function are converted to `bool_parsing` errors
+fn call_coerce<'data>(
+    py: Python<'data>,
+    coerce: &PyObject,
+    input: &'data impl Input<'data>,
+) -> ValResult<'data, Option<bool>> {
+    let result = match coerce.call1(py, (input.to_object(py),)) {
+        Ok(result) => result.into_ref(py),
+        Err(err) if err.is_instance_of::<PyException>(py) => {
+            return Err(ValError::new(ErrorType::BoolParsing, input));
+        }
+        Err(err) => return Err(err.into()),
+    };
+    if result.is_none() {
+        Ok(None)
+    } else if let Ok(py_bool) = result.downcast::<PyBool>() {
+        Ok(Some(py_bool.is_true()))
+    } else {
+        Err(PyTypeError::new_err(format!(
+            "bool `coerce` function must return True, False or None, not {}",
+            result.get_type().name()?
+        ))
+        .into())
+    }
+}
@@ -2,16 +2,69 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use crate::build_tools::{is_strict, SchemaDict};
+use crate::build_tools::{coerce_hook_enabled, is_strict, py_err, SchemaDict};
 use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::Input;
+use crate::input::{with_coerce_hook, EitherBytes, Input};
+use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
+use crate::serializers::BytesMode;
 
-use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
+
+/// The python type returned by the bytes validators
+#[derive(Debug, Clone, Copy)]
+enum BytesOutput {
+    Bytes,
+    Bytearray,
+}
+
+impl BytesOutput {
+    fn from_schema(schema: &PyDict) -> PyResult<Self> {
+        match schema.get_as::<&str>(intern!(schema.py(), "output"))? {
+            Some("bytes") | None => Ok(Self::Bytes),
+            Some("bytearray") => Ok(Self::Bytearray),
+            Some(s) => py_err!("Invalid bytes output: `{}`, expected `bytes` or `bytearray`", s),
+        }
+    }
+
+    fn validate<'data>(
+        self,
+        input: &'data impl Input<'data>,
+        strict: bool,
+        coerce_hook: bool,
+        json_mode: BytesMode,
+    ) -> ValResult<'data, EitherBytes<'data>> {
+        let result = match self {
+            Self::Bytes => input.validate_bytes(strict, json_mode),
+            Self::Bytearray => input.validate_bytearray(strict, json_mode),
+        };
+        // `bytearray` is only distinguished from `bytes` in strict mode, so the hook is always asked for bytes
+        with_coerce_hook(input, "bytes", coerce_hook && !strict, result, |v| {
+            v.lax_bytes(json_mode)
+        })
+    }
+
+    fn target_type(self) -> &'static str {
+        match self {
+            Self::Bytes => "bytes",
+            Self::Bytearray => "bytearray",
+        }
+    }
+
+    fn to_output(self, py: Python, either_bytes: EitherBytes) -> PyObject {
+        match self {
+            Self::Bytes => either_bytes.into_py(py),
+            Self::Bytearray => either_bytes.into_py_bytearray(py),
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct BytesValidator {
     strict: bool,
+    coerce_hook: bool,
+    output: BytesOutput,
+    json_mode: BytesMode,
 }
 
 impl BuildValidator for BytesValidator {
@@ -30,6 +83,9 @@ impl BuildValidator for BytesValidator {
         } else {
             Ok(Self {
                 strict: is_strict(schema, config)?,
+                coerce_hook: coerce_hook_enabled(config)?,
+                output: BytesOutput::from_schema(schema)?,
+                json_mode: BytesMode::from_val_config(config)?,
             }
             .into())
         }
@@ -45,14 +101,37 @@ impl Validator for BytesValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let either_bytes = input.validate_bytes(extra.strict.unwrap_or(self.strict))?;
-        Ok(either_bytes.into_py(py))
+        let either_bytes = self.output.validate(
+            input,
+            extra.strict.unwrap_or(self.strict),
+            self.coerce_hook,
+            self.json_mode,
+        )?;
+        extra.report_coercion(py, input, self.output.target_type(), || {
+            self.output.validate(input, true, false, self.json_mode).is_ok()
+        });
+        Ok(self.output.to_output(py, either_bytes))
+    }
+
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .strict(self.strict)
+            .accepts(&["bytes", "str", "bytearray", "buffer"], &["bytes"])
+            .finish()
     }
 
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
 
+    fn ask(&self, question: &Question) -> bool {
+        *question == Question::ReturnsImmutable && matches!(self.output, BytesOutput::Bytes)
+    }
+
     fn complete(&mut self, _build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
         Ok(())
     }
@@ -61,6 +140,9 @@ impl Validator for BytesValidator {
 #[derive(Debug, Clone)]
 pub struct BytesConstrainedValidator {
     strict: bool,
+    coerce_hook: bool,
+    output: BytesOutput,
+    json_mode: BytesMode,
     max_length: Option<usize>,
     min_length: Option<usize>,
 }
@@ -74,7 +156,13 @@ impl Validator for BytesConstrainedValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let either_bytes = input.validate_bytes(extra.strict.unwrap_or(self.strict))?;
+        let either_bytes = self.output.validate(
+            input,
+            extra.strict.unwrap_or(self.strict),
+            self.coerce_hook,
+            self.json_mode,
+        )?;
+        // for JSON input this is the length after decoding with `val_json_bytes`
         let len = either_bytes.len()?;
 
         if let Some(min_length) = self.min_length {
@@ -88,13 +176,30 @@ impl Validator for BytesConstrainedValidator {
             }
         }
 
-        Ok(either_bytes.into_py(py))
+        Ok(self.output.to_output(py, either_bytes))
+    }
+
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, BytesValidator::EXPECTED_TYPE)
+            .strict(self.strict)
+            .accepts(&["bytes", "str", "bytearray", "buffer"], &["bytes"])
+            .constraint("min_length", self.min_length)?
+            .constraint("max_length", self.max_length)?
+            .finish()
     }
 
     fn get_name(&self) -> &str {
         "constrained-bytes"
     }
 
+    fn ask(&self, question: &Question) -> bool {
+        *question == Question::ReturnsImmutable && matches!(self.output, BytesOutput::Bytes)
+    }
+
     fn complete(&mut self, _build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
         Ok(())
     }
@@ -105,6 +210,9 @@ impl BytesConstrainedValidator {
         let py = schema.py();
         Ok(Self {
             strict: is_strict(schema, config)?,
+            coerce_hook: coerce_hook_enabled(config)?,
+            output: BytesOutput::from_schema(schema)?,
+            json_mode: BytesMode::from_val_config(config)?,
             min_length: schema.get_as(intern!(py, "min_length"))?,
             max_length: schema.get_as(intern!(py, "max_length"))?,
         }
@@ -8,7 +8,7 @@ use crate::errors::ValResult;
 use crate::input::Input;
 use crate::recursion_guard::RecursionGuard;
 
-use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub struct CallValidator {
@@ -73,14 +73,31 @@ impl Validator for CallValidator {
         };
 
         if let Some(return_validator) = &self.return_validator {
+            let return_value = return_value.into_ref(py);
+            let _loc = extra.coercion_loc(|| ["return".into()]);
+            extra.report_raw_input(py, return_value);
             return_validator
-                .validate(py, return_value.into_ref(py), extra, slots, recursion_guard)
+                .validate(py, return_value, extra, slots, recursion_guard)
                 .map_err(|e| e.with_outer_location("return".into()))
         } else {
             Ok(return_value.to_object(py))
         }
     }
 
+    fn set_strict(&mut self, strict: bool) {
+        self.arguments_validator.set_strict(strict);
+        if let Some(ref mut v) = self.return_validator {
+            v.set_strict(strict);
+        }
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .child("arguments_schema", &self.arguments_validator)?
+            .opt_child("return_schema", self.return_validator.as_deref())?
+            .finish()
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -5,7 +5,7 @@ use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::Input;
 use crate::recursion_guard::RecursionGuard;
 
-use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub struct CallableValidator;
@@ -37,6 +37,12 @@ impl Validator for CallableValidator {
         }
     }
 
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .accepts_always(&["callable"])
+            .finish()
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
@@ -2,17 +2,33 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
 
-use crate::build_tools::{py_err, SchemaDict};
-use crate::errors::ValResult;
+use crate::build_tools::{merge_validators_enabled, py_err, SchemaDict};
+use crate::errors::{ChainStep, LocItem, ValError, ValLineError, ValResult};
 use crate::input::Input;
 use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
-use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::int::merge_int_validators;
+use super::string::merge_str_validators;
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
+
+#[derive(Debug, Clone)]
+struct Step {
+    validator: CombinedValidator,
+    label: Option<String>,
+    // the step only checks the value, its output is ignored and the next step gets the same value
+    validate_only: bool,
+    // the step's position in the flattened chain before steps were merged, used as `chain_step` in errors
+    index: usize,
+}
 
 #[derive(Debug, Clone)]
 pub struct ChainValidator {
-    steps: Vec<CombinedValidator>,
+    steps: Vec<Step>,
+    // if false, `validate_only` steps after a failed `validate_only` step still run and their errors are collected
+    short_circuit: bool,
+    // include the type of the value given to the failing step as `chain_step_input_type` in the error context
+    error_input_type: bool,
     name: String,
 }
 
@@ -24,26 +40,42 @@ impl BuildValidator for ChainValidator {
         config: Option<&PyDict>,
         build_context: &mut BuildContext<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
-        let steps: Vec<CombinedValidator> = schema
-            .get_as_req::<&PyList>(intern!(schema.py(), "steps"))?
+        let py = schema.py();
+        let mut steps: Vec<Step> = schema
+            .get_as_req::<&PyList>(intern!(py, "steps"))?
             .iter()
             .map(|step| build_validator_steps(step, config, build_context))
-            .collect::<PyResult<Vec<Vec<CombinedValidator>>>>()?
+            .collect::<PyResult<Vec<Vec<Step>>>>()?
             .into_iter()
             .flatten()
-            .collect::<Vec<CombinedValidator>>();
+            .collect::<Vec<Step>>();
+        steps
+            .iter_mut()
+            .enumerate()
+            .for_each(|(index, step)| step.index = index);
+        if merge_validators_enabled(config)? {
+            steps = merge_steps(steps);
+        }
+        let short_circuit = schema.get_as(intern!(py, "short_circuit"))?.unwrap_or(true);
+        let error_input_type = schema.get_as(intern!(py, "error_input_type"))?.unwrap_or(false);
 
         match steps.len() {
             0 => py_err!("One or more steps are required for a chain validator"),
-            1 => {
+            1 if steps[0].label.is_none() && !steps[0].validate_only && !error_input_type => {
                 let step = steps.into_iter().next().unwrap();
-                Ok(step)
+                Ok(step.validator)
             }
             _ => {
-                let descr = steps.iter().map(|v| v.get_name()).collect::<Vec<_>>().join(",");
+                let descr = steps
+                    .iter()
+                    .map(|step| step.label.as_deref().unwrap_or_else(|| step.validator.get_name()))
+                    .collect::<Vec<_>>()
+                    .join(",");
 
                 Ok(Self {
                     steps,
+                    short_circuit,
+                    error_input_type,
                     name: format!("{}[{descr}]", Self::EXPECTED_TYPE),
                 }
                 .into())
@@ -52,18 +84,102 @@ impl BuildValidator for ChainValidator {
     }
 }
 
-// either a vec of the steps from a nested `ChainValidator`, or a length-1 vec containing the validator
-// to be flattened into `steps` above
+/// A chain step is either a schema, or a dict of the step's `schema` with the options `label` and `validate_only`
+fn chain_step(step: &PyAny) -> PyResult<(&PyDict, Option<String>, bool)> {
+    let py = step.py();
+    let step: &PyDict = step.downcast()?;
+    if step.contains(intern!(py, "type"))? {
+        Ok((step, None, false))
+    } else {
+        Ok((
+            step.get_as_req(intern!(py, "schema"))?,
+            step.get_as(intern!(py, "label"))?,
+            step.get_as(intern!(py, "validate_only"))?.unwrap_or(false),
+        ))
+    }
+}
+
+/// The schema of the chain's output, the last step which isn't `validate_only`, or the last step if they all are
+pub fn chain_output_schema(steps: &PyList) -> PyResult<&PyDict> {
+    let mut output_schema: Option<&PyDict> = None;
+    let mut last_schema: Option<&PyDict> = None;
+    for step in steps {
+        let (schema, _, validate_only) = chain_step(step)?;
+        if !validate_only {
+            output_schema = Some(schema);
+        }
+        last_schema = Some(schema);
+    }
+    match output_schema.or(last_schema) {
+        Some(schema) => Ok(schema),
+        None => py_err!("One or more steps are required for a chain validator"),
+    }
+}
+
+// either a vec of the steps from a nested `ChainValidator`, or a length-1 vec containing the step
+// to be flattened into `steps` above, nested chains are only flattened if their step has no options
+// and they have the default `short_circuit` and `error_input_type`, so their steps behave the same in the outer chain
 fn build_validator_steps<'a>(
     step: &'a PyAny,
     config: Option<&'a PyDict>,
     build_context: &mut BuildContext<CombinedValidator>,
-) -> PyResult<Vec<CombinedValidator>> {
-    let validator = build_validator(step, config, build_context)?;
-    if let CombinedValidator::Chain(chain_validator) = validator {
-        Ok(chain_validator.steps)
-    } else {
-        Ok(vec![validator])
+) -> PyResult<Vec<Step>> {
+    let (schema, label, validate_only) = chain_step(step)?;
+    let validator = build_validator(schema, config, build_context)?;
+    match validator {
+        CombinedValidator::Chain(chain_validator)
+            if label.is_none()
+                && !validate_only
+                && chain_validator.short_circuit
+                && !chain_validator.error_input_type =>
+        {
+            Ok(chain_validator.steps)
+        }
+        validator => Ok(vec![Step {
+            validator,
+            label,
+            validate_only,
+            // set once the chain is flattened
+            index: 0,
+        }]),
+    }
+}
+
+/// merge adjacent steps which only check constraints of the same scalar type, e.g. `str` with `min_length` followed
+/// by `str` with `pattern`, into a single step, steps with a `label` or `validate_only` are never merged;
+/// errors from a merged step report the `chain_step` of the first step it replaces
+fn merge_steps(steps: Vec<Step>) -> Vec<Step> {
+    let mut merged: Vec<Step> = Vec::with_capacity(steps.len());
+    for step in steps {
+        if let Some(last) = merged.last_mut() {
+            if step.label.is_none() && !step.validate_only && last.label.is_none() && !last.validate_only {
+                let combined = merge_str_validators(&last.validator, &step.validator)
+                    .or_else(|| merge_int_validators(&last.validator, &step.validator));
+                if let Some(combined) = combined {
+                    last.validator = combined;
+                    continue;
+                }
+            }
+        }
+        merged.push(step);
+    }
+    merged
+}
+
+impl ChainValidator {
+    /// the step an error occurred in, to be added to the error
+    fn error_step(&self, py: Python, step: &Step, value: PyObject) -> PyResult<ChainStep> {
+        let input_type = match self.error_input_type {
+            true => Some(value.as_ref(py).get_type().name()?.to_string()),
+            false => None,
+        };
+        Ok(ChainStep {
+            step: match step.label {
+                Some(ref label) => LocItem::S(label.clone()),
+                None => step.index.into(),
+            },
+            input_type,
+        })
     }
 }
 
@@ -76,13 +192,60 @@ impl Validator for ChainValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let mut steps_iter = self.steps.iter();
-        let first_step = steps_iter.next().unwrap();
-        let value = first_step.validate(py, input, extra, slots, recursion_guard)?;
+        // the output of the last step which isn't `validate_only`, `None` until there is one
+        let mut value: Option<&'data PyAny> = None;
+        let mut errors: Vec<ValLineError> = Vec::new();
+        for step in &self.steps {
+            // only `validate_only` steps still run after a step has failed, others would get an invalid value
+            if !errors.is_empty() && !step.validate_only {
+                break;
+            }
+            let result = match value {
+                Some(value) => step.validator.validate(py, value, extra, slots, recursion_guard),
+                None => step.validator.validate(py, input, extra, slots, recursion_guard),
+            };
+            match result {
+                Ok(output) => {
+                    if !step.validate_only {
+                        value = Some(output.into_ref(py));
+                    }
+                }
+                Err(ValError::LineErrors(line_errors)) => {
+                    let step_value = match value {
+                        Some(value) => value.into_py(py),
+                        None => input.to_object(py),
+                    };
+                    let error_step = self.error_step(py, step, step_value)?;
+                    errors.extend(line_errors.into_iter().map(|e| e.with_chain_step(&error_step)));
+                    // if a step which transforms the value failed, there's no value for the following steps
+                    if self.short_circuit || !step.validate_only {
+                        break;
+                    }
+                }
+                Err(err) => return Err(err),
+            }
+        }
 
-        steps_iter.try_fold(value, |v, step| {
-            step.validate(py, v.into_ref(py), extra, slots, recursion_guard)
-        })
+        if !errors.is_empty() {
+            Err(ValError::LineErrors(errors))
+        } else {
+            match value {
+                Some(value) => Ok(value.into_py(py)),
+                None => Ok(input.to_object(py)),
+            }
+        }
+    }
+
+    fn set_strict(&mut self, strict: bool) {
+        self.steps.iter_mut().for_each(|step| step.validator.set_strict(strict));
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        let mut description = Description::new(py, Self::EXPECTED_TYPE);
+        for (index, step) in self.steps.iter().enumerate() {
+            description = description.child(index, &step.validator)?;
+        }
+        description.finish()
     }
 
     fn get_name(&self) -> &str {
@@ -92,10 +255,16 @@ impl Validator for ChainValidator {
     fn ask(&self, question: &Question) -> bool {
         // any makes more sense since at the moment we only use ask for "return_fields_set", might need
         // more complex logic in future
-        self.steps.iter().any(|v| v.ask(question))
+        match question {
+            // the value returned is the value returned by the last step
+            Question::ReturnsImmutable => self.steps.last().is_some_and(|step| step.validator.ask(question)),
+            _ => self.steps.iter().any(|step| step.validator.ask(question)),
+        }
     }
 
     fn complete(&mut self, build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
-        self.steps.iter_mut().try_for_each(|v| v.complete(build_context))
+        self.steps
+            .iter_mut()
+            .try_for_each(|step| step.validator.complete(build_context))
     }
 }
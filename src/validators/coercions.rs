@@ -0,0 +1,242 @@
+//! Report of lax coercions made during validation, to find which values would fail in strict mode.
+//! Containers push the location of each item with `Extra::coercion_loc` while it's validated, leaf validators
+//! call `Extra::report_coercion` after lax validation succeeds. With `report_defaults`, `Extra::report_default` also
+//! records fields whose value came from a `default` or `default_factory`.
+//! A report made with `CoercionReport::raw_inputs` instead records the input at every location a container validates
+//! an item at, with `Extra::report_raw_input`, whether or not it was coerced, for `validate_python_with_raw` and
+//! `validate_json_with_raw`.
+//! A report made with `CoercionReport::warnings` instead records only the non-fatal warnings validators emit with
+//! `Extra::report_warning`, for `validate_python_with_warnings` and `validate_json_with_warnings`.
+use std::cell::RefCell;
+use std::fmt;
+
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyTuple};
+
+use crate::errors::LocItem;
+use crate::input::Input;
+
+#[cfg_attr(debug_assertions, derive(Debug))]
+enum CoercionKind {
+    Coerced {
+        input_type: String,
+        target_type: &'static str,
+    },
+    // only recorded with `report_defaults`, a missing value replaced by a `default` or `default_factory`
+    Default {
+        factory: bool,
+    },
+    // only recorded by a `raw_inputs` report, the input before any coercion
+    Raw {
+        input: PyObject,
+    },
+    // only recorded by a `warnings` report
+    Warning {
+        code: String,
+        message: String,
+    },
+}
+
+#[cfg_attr(debug_assertions, derive(Debug))]
+struct Coercion {
+    loc: Vec<LocItem>,
+    kind: CoercionKind,
+}
+
+#[derive(Default)]
+pub struct CoercionReport {
+    // location of the value currently being validated
+    path: RefCell<Vec<LocItem>>,
+    coercions: RefCell<Vec<Coercion>>,
+    report_defaults: bool,
+    raw_inputs: bool,
+    warnings: bool,
+}
+
+// `LocItem` only implements `Debug` in debug builds, so this can't be derived
+impl fmt::Debug for CoercionReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CoercionReport")
+            .field("coercions", &self.coercions.borrow().len())
+            .field("report_defaults", &self.report_defaults)
+            .field("raw_inputs", &self.raw_inputs)
+            .field("warnings", &self.warnings)
+            .finish()
+    }
+}
+
+impl CoercionReport {
+    pub fn new(report_defaults: bool) -> Self {
+        Self {
+            report_defaults,
+            ..Default::default()
+        }
+    }
+
+    pub fn raw_inputs() -> Self {
+        Self {
+            raw_inputs: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn warnings() -> Self {
+        Self {
+            warnings: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn is_raw_inputs(&self) -> bool {
+        self.raw_inputs
+    }
+
+    pub fn is_warnings(&self) -> bool {
+        self.warnings
+    }
+
+    pub fn record<'data>(&self, py: Python<'data>, input: &'data impl Input<'data>, target_type: &'static str) {
+        let input_type = match input.to_object(py).as_ref(py).get_type().name() {
+            Ok(name) => name.to_string(),
+            Err(_) => "unknown".to_string(),
+        };
+        self.push(CoercionKind::Coerced {
+            input_type,
+            target_type,
+        });
+    }
+
+    pub fn record_default(&self, factory: bool) {
+        if self.report_defaults {
+            self.push(CoercionKind::Default { factory });
+        }
+    }
+
+    pub fn record_raw<'data>(&self, py: Python<'data>, input: &'data impl Input<'data>) {
+        self.push(CoercionKind::Raw {
+            input: input.to_object(py),
+        });
+    }
+
+    pub fn record_warning(&self, code: String, message: String) {
+        if self.warnings {
+            self.push(CoercionKind::Warning { code, message });
+        }
+    }
+
+    fn push(&self, kind: CoercionKind) {
+        self.coercions.borrow_mut().push(Coercion {
+            loc: self.path.borrow().clone(),
+            kind,
+        });
+    }
+
+    /// the report as a list of dicts shaped like errors, with `type`, `loc`, `input_type` and `target_type`,
+    /// defaults applied only have `type` (`default` or `default_factory`) and `loc`, warnings have `type` (the
+    /// warning's code), `loc` and `msg`
+    pub fn to_py(&self, py: Python) -> PyResult<Py<PyList>> {
+        let list = PyList::empty(py);
+        for coercion in self.coercions.borrow().iter() {
+            let item = PyDict::new(py);
+            match coercion.kind {
+                CoercionKind::Coerced {
+                    ref input_type,
+                    target_type,
+                } => {
+                    item.set_item("type", format!("{target_type}_from_{input_type}"))?;
+                    item.set_item("loc", PyTuple::new(py, coercion.loc.iter().map(|l| l.to_object(py))))?;
+                    item.set_item("input_type", input_type)?;
+                    item.set_item("target_type", target_type)?;
+                }
+                CoercionKind::Default { factory } => {
+                    item.set_item("type", if factory { "default_factory" } else { "default" })?;
+                    item.set_item("loc", PyTuple::new(py, coercion.loc.iter().map(|l| l.to_object(py))))?;
+                }
+                CoercionKind::Warning { ref code, ref message } => {
+                    item.set_item("type", code)?;
+                    item.set_item("loc", PyTuple::new(py, coercion.loc.iter().map(|l| l.to_object(py))))?;
+                    item.set_item("msg", message)?;
+                }
+                CoercionKind::Raw { .. } => continue,
+            }
+            list.append(item)?;
+        }
+        Ok(list.into())
+    }
+
+    /// raw inputs recorded by a `raw_inputs` report as a dict keyed by location, starting with `input` at `()`
+    pub fn raw_to_py(&self, py: Python, input: PyObject) -> PyResult<Py<PyDict>> {
+        let dict = PyDict::new(py);
+        dict.set_item(PyTuple::empty(py), input)?;
+        for coercion in self.coercions.borrow().iter() {
+            if let CoercionKind::Raw { ref input } = coercion.kind {
+                dict.set_item(PyTuple::new(py, coercion.loc.iter().map(|l| l.to_object(py))), input)?;
+            }
+        }
+        Ok(dict.into())
+    }
+}
+
+/// Coercions recorded after the checkpoint was taken can be forgotten with `rollback`, used when the value
+/// they were made in isn't used in the output, e.g. union choices which failed
+pub struct CoercionCheckpoint<'a> {
+    report: Option<(&'a CoercionReport, usize)>,
+}
+
+impl<'a> CoercionCheckpoint<'a> {
+    pub fn new(report: Option<&'a CoercionReport>) -> Self {
+        Self {
+            report: report.map(|report| (report, report.coercions.borrow().len())),
+        }
+    }
+
+    pub fn rollback(&self) {
+        if let Some((report, len)) = self.report {
+            report.coercions.borrow_mut().truncate(len);
+        }
+    }
+
+    /// remove the coercions recorded since the checkpoint, to be put back with `restore` if the value they were
+    /// recorded for is kept
+    pub fn take(&self) -> TakenCoercions {
+        match self.report {
+            Some((report, len)) => TakenCoercions(report.coercions.borrow_mut().split_off(len)),
+            None => TakenCoercions(Vec::new()),
+        }
+    }
+
+    pub fn restore(&self, taken: TakenCoercions) {
+        if let Some((report, _)) = self.report {
+            report.coercions.borrow_mut().extend(taken.0);
+        }
+    }
+}
+
+/// Coercions removed from a report by `CoercionCheckpoint::take`
+pub struct TakenCoercions(Vec<Coercion>);
+
+/// Location pushed onto a coercion report's path, popped when dropped
+pub struct CoercionLoc<'a> {
+    report: Option<(&'a CoercionReport, usize)>,
+}
+
+impl<'a> CoercionLoc<'a> {
+    pub fn new<I: IntoIterator<Item = LocItem>>(report: Option<&'a CoercionReport>, loc: impl FnOnce() -> I) -> Self {
+        Self {
+            report: report.map(|report| {
+                let mut path = report.path.borrow_mut();
+                let depth = path.len();
+                path.extend(loc());
+                (report, depth)
+            }),
+        }
+    }
+}
+
+impl<'a> Drop for CoercionLoc<'a> {
+    fn drop(&mut self) {
+        if let Some((report, depth)) = self.report {
+            report.path.borrow_mut().truncate(depth);
+        }
+    }
+}
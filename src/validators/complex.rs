@@ -0,0 +1,121 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyComplex, PyDict};
+
+use crate::build_tools::{is_strict, schema_or_config_same};
+use crate::errors::{ErrorType, ValError, ValResult};
+use crate::input::{GenericCollection, Input};
+use crate::recursion_guard::RecursionGuard;
+
+use super::{BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
+
+#[derive(Debug, Clone)]
+pub struct ComplexValidator {
+    strict: bool,
+    allow_inf_nan: bool,
+}
+
+impl BuildValidator for ComplexValidator {
+    const EXPECTED_TYPE: &'static str = "complex";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        _build_context: &mut BuildContext<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            allow_inf_nan: schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(true),
+        }
+        .into())
+    }
+}
+
+impl Validator for ComplexValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        _slots: &'data [CombinedValidator],
+        _recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let complex = self.get_complex(py, input, extra.strict.unwrap_or(self.strict))?;
+        if !self.allow_inf_nan && (!complex.real().is_finite() || !complex.imag().is_finite()) {
+            return Err(ValError::new(ErrorType::FiniteNumber, input));
+        }
+        Ok(complex.into_py(py))
+    }
+
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .strict(self.strict)
+            .accepts(&["complex", "int", "float", "str", "bytes"], &["complex"])
+            .constraint("allow_inf_nan", (!self.allow_inf_nan).then_some(false))?
+            .finish()
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+
+    fn complete(&mut self, _build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
+        Ok(())
+    }
+}
+
+impl ComplexValidator {
+    fn get_complex<'data>(
+        &self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        strict: bool,
+    ) -> ValResult<'data, &'data PyComplex> {
+        if input.is_python() {
+            let value = input.to_object(py).into_ref(py);
+            if let Ok(complex) = value.downcast::<PyComplex>() {
+                // subclasses are converted so the result is always a `complex`
+                return if complex.get_type().is(py.get_type::<PyComplex>()) {
+                    Ok(complex)
+                } else {
+                    Ok(PyComplex::from_doubles(py, complex.real(), complex.imag()))
+                };
+            }
+            // in strict mode only complex numbers are accepted from python
+            if strict || value.is_instance_of::<PyBool>()? {
+                return Err(ValError::new(ErrorType::ComplexType, input));
+            }
+        } else if let Ok(GenericCollection::JsonArray(items)) = input.strict_list() {
+            // JSON has no complex type, so `[real, imag]` pairs are accepted as they're serialized
+            return match items {
+                [real, imag] => match (real.strict_float(), imag.strict_float()) {
+                    (Ok(real), Ok(imag)) => Ok(PyComplex::from_doubles(py, real, imag)),
+                    _ => Err(ValError::new(ErrorType::ComplexType, input)),
+                },
+                _ => Err(ValError::new(ErrorType::ComplexType, input)),
+            };
+        }
+
+        // JSON strings and numbers are accepted in strict mode too
+        if let Ok(either_str) = input.validate_str(strict) {
+            // strings are parsed by `complex()` itself, e.g. `'1+2j'`, `'(1.5-2J)'` or `'nan+infj'`
+            return match py.get_type::<PyComplex>().call1((either_str.as_cow()?.as_ref(),)) {
+                Ok(complex) => Ok(complex.downcast::<PyComplex>()?),
+                Err(err) if err.is_instance_of::<PyValueError>(py) => {
+                    Err(ValError::new(ErrorType::ComplexStrParsing, input))
+                }
+                Err(err) => Err(err.into()),
+            };
+        }
+        match input.strict_float() {
+            Ok(real) => Ok(PyComplex::from_doubles(py, real, 0.0)),
+            Err(_) => Err(ValError::new(ErrorType::ComplexType, input)),
+        }
+    }
+}
@@ -8,7 +8,7 @@ use crate::input::Input;
 use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
-use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub enum CustomError {
@@ -97,6 +97,16 @@ impl Validator for CustomErrorValidator {
             .map_err(|_| self.custom_error.as_val_error(input))
     }
 
+    fn set_strict(&mut self, strict: bool) {
+        self.validator.set_strict(strict);
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .child("schema", &self.validator)?
+            .finish()
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -14,7 +14,7 @@ use crate::validators::function::convert_err;
 
 use super::arguments::{json_get, json_slice, py_get, py_slice};
 use super::model::{create_class, force_setattr, Revalidate};
-use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
 
 #[derive(Debug, Clone)]
 struct Field {
@@ -25,6 +25,7 @@ struct Field {
     lookup_key: LookupKey,
     validator: CombinedValidator,
     frozen: bool,
+    title: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -52,6 +53,8 @@ impl BuildValidator for DataclassArgsValidator {
 
         let extra_behavior = ExtraBehavior::from_schema_or_config(py, schema, config, ExtraBehavior::Ignore)?;
 
+        let error_field_title = config.get_as(intern!(py, "error_field_title"))?.unwrap_or(true);
+
         let fields_schema: &PyList = schema.get_as_req(intern!(py, "fields"))?;
         let mut fields: Vec<Field> = Vec::with_capacity(fields_schema.len());
 
@@ -65,6 +68,9 @@ impl BuildValidator for DataclassArgsValidator {
 
             let lookup_key = match field.get_item(intern!(py, "validation_alias")) {
                 Some(alias) => {
+                    let populate_by_name = field
+                        .get_as(intern!(py, "populate_by_name"))?
+                        .unwrap_or(populate_by_name);
                     let alt_alias = if populate_by_name { Some(name.as_str()) } else { None };
                     LookupKey::from_py(py, alias, alt_alias)?
                 }
@@ -97,6 +103,10 @@ impl BuildValidator for DataclassArgsValidator {
                 validator,
                 init_only: field.get_as(intern!(py, "init_only"))?.unwrap_or(false),
                 frozen: field.get_as::<bool>(intern!(py, "frozen"))?.unwrap_or(false),
+                title: match error_field_title {
+                    true => field.get_as(intern!(py, "title"))?,
+                    false => None,
+                },
             });
         }
 
@@ -182,63 +192,75 @@ impl Validator for DataclassArgsValidator {
                     match (pos_value, kw_value) {
                         // found both positional and keyword arguments, error
                         (Some(_), Some((_, kw_value))) => {
-                            errors.push(ValLineError::new_with_loc(
-                                ErrorType::MultipleArgumentValues,
-                                kw_value,
-                                field.name.clone(),
-                            ));
+                            errors.push(
+                                ValLineError::new_with_loc(
+                                    ErrorType::MultipleArgumentValues,
+                                    kw_value,
+                                    field.name.clone(),
+                                )
+                                .with_field_title(field.title.as_deref()),
+                            );
                         }
                         // found a positional argument, validate it
                         (Some(pos_value), None) => {
+                            let _loc = extra.coercion_loc(|| [index.into()]);
+                            extra.report_raw_input(py, pos_value);
                             match field
                                 .validator
                                 .validate(py, pos_value, &extra, slots, recursion_guard)
                             {
                                 Ok(value) => set_item!(field, value),
                                 Err(ValError::LineErrors(line_errors)) => {
-                                    errors.extend(
-                                        line_errors
-                                            .into_iter()
-                                            .map(|err| err.with_outer_location(index.into())),
-                                    );
+                                    errors.extend(line_errors.into_iter().map(|err| {
+                                        err.with_outer_location(index.into())
+                                            .with_field_title(field.title.as_deref())
+                                    }));
                                 }
                                 Err(err) => return Err(err),
                             }
                         }
                         // found a keyword argument, validate it
                         (None, Some((lookup_path, kw_value))) => {
+                            let _loc = extra.coercion_loc(|| lookup_path.loc_items(self.loc_by_alias, &field.name));
+                            extra.report_raw_input(py, kw_value);
                             match field
                                 .validator
                                 .validate(py, kw_value, &extra, slots, recursion_guard)
                             {
                                 Ok(value) => set_item!(field, value),
                                 Err(ValError::LineErrors(line_errors)) => {
-                                    errors.extend(
-                                        line_errors.into_iter().map(|err| {
-                                            lookup_path.apply_error_loc(err, self.loc_by_alias, &field.name)
-                                        }),
-                                    );
+                                    errors.extend(line_errors.into_iter().map(|err| {
+                                        lookup_path
+                                            .apply_error_loc(err, self.loc_by_alias, &field.name)
+                                            .with_field_title(field.title.as_deref())
+                                    }));
                                 }
                                 Err(err) => return Err(err),
                             }
                         }
                         // found neither, check if there is a default value, otherwise error
                         (None, None) => {
-                            if let Some(value) = field.validator.default_value(
+                            let _loc = extra.coercion_loc(|| [field.name.as_str().into()]);
+                            match field.validator.default_value(
                                 py,
                                 Some(field.name.as_str()),
                                 &extra,
                                 slots,
                                 recursion_guard,
-                            )? {
-                                set_item!(field, value);
-                            } else {
-                                errors.push(field.lookup_key.error(
-                                    ErrorType::Missing,
-                                    input,
-                                    self.loc_by_alias,
-                                    &field.name,
-                                ));
+                            ) {
+                                Ok(Some(value)) => set_item!(field, value),
+                                Ok(None) => errors.push(
+                                    field
+                                        .lookup_key
+                                        .error(ErrorType::Missing, input, self.loc_by_alias, &field.name)
+                                        .with_field_title(field.title.as_deref()),
+                                ),
+                                Err(ValError::LineErrors(line_errors)) => errors.extend(
+                                    line_errors
+                                        .into_iter()
+                                        .map(|err| err.with_field_title(field.title.as_deref())),
+                                ),
+                                Err(err) => return Err(err),
                             }
                         }
                     }
@@ -328,11 +350,10 @@ impl Validator for DataclassArgsValidator {
 
         if let Some(field) = self.fields.iter().find(|f| f.name == field_name) {
             if field.frozen {
-                return Err(ValError::new_with_loc(
-                    ErrorType::FrozenField,
-                    field_value,
-                    field.name.to_string(),
-                ));
+                return Err(
+                    ValError::new_with_loc(ErrorType::FrozenField, field_value, field.name.to_string())
+                        .with_field_title(field.title.as_deref()),
+                );
             }
             // by using dict but removing the field in question, we match V1 behaviour
             let data_dict = dict.copy()?;
@@ -355,7 +376,10 @@ impl Validator for DataclassArgsValidator {
                 Err(ValError::LineErrors(line_errors)) => {
                     let errors = line_errors
                         .into_iter()
-                        .map(|e| e.with_outer_location(field_name.into()))
+                        .map(|e| {
+                            e.with_outer_location(field_name.into())
+                                .with_field_title(field.title.as_deref())
+                        })
                         .collect();
                     Err(ValError::LineErrors(errors))
                 }
@@ -380,6 +404,18 @@ impl Validator for DataclassArgsValidator {
         }
     }
 
+    fn set_strict(&mut self, strict: bool) {
+        self.fields.iter_mut().for_each(|v| v.validator.set_strict(strict));
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        let mut description = Description::new(py, Self::EXPECTED_TYPE).accepts_always(&["dict", "args_kwargs"]);
+        for field in &self.fields {
+            description = description.child(&field.name, &field.validator)?;
+        }
+        description.finish()
+    }
+
     fn get_name(&self) -> &str {
         &self.validator_name
     }
@@ -400,6 +436,8 @@ pub struct DataclassValidator {
     revalidate: Revalidate,
     name: String,
     frozen: bool,
+    // with `slots`, the fields which are set and read as attributes rather than via `__dict__`
+    slot_fields: Option<Vec<Py<PyString>>>,
 }
 
 impl BuildValidator for DataclassValidator {
@@ -416,6 +454,11 @@ impl BuildValidator for DataclassValidator {
         let sub_schema: &PyAny = schema.get_as_req(intern!(py, "schema"))?;
         let validator = build_validator(sub_schema, config, build_context)?;
 
+        let slot_fields = match schema.get_as(intern!(py, "slots"))?.unwrap_or(false) {
+            true => Some(dataclass_slot_fields(sub_schema)?),
+            false => None,
+        };
+
         let post_init = if schema.get_as::<bool>(intern!(py, "post_init"))?.unwrap_or(false) {
             Some(PyString::intern(py, "__post_init__").into_py(py))
         } else {
@@ -436,6 +479,7 @@ impl BuildValidator for DataclassValidator {
             // which is not what we want here
             name: class.getattr(intern!(py, "__name__"))?.extract()?,
             frozen: schema.get_as(intern!(py, "frozen"))?.unwrap_or(false),
+            slot_fields,
         }
         .into())
     }
@@ -459,7 +503,7 @@ impl Validator for DataclassValidator {
         let class = self.class.as_ref(py);
         if input.input_is_instance(class, 0)? {
             if self.revalidate.should_revalidate(input, class) {
-                let input = input.input_get_attr(intern!(py, "__dict__")).unwrap()?;
+                let input: &PyAny = self.dataclass_to_dict(input.to_object(py).into_ref(py))?;
                 let val_output = self.validator.validate(py, input, extra, slots, recursion_guard)?;
                 let dc = create_class(self.class.as_ref(py))?;
                 self.set_dict_call(py, dc.as_ref(py), val_output, input)?;
@@ -495,21 +539,31 @@ impl Validator for DataclassValidator {
         if self.frozen {
             return Err(ValError::new(ErrorType::FrozenInstance, field_value));
         }
-        let dict_py_str = intern!(py, "__dict__");
-        let dict: &PyDict = obj.getattr(dict_py_str)?.downcast()?;
-
-        let new_dict = dict.copy()?;
+        let new_dict = self.dataclass_to_dict(obj)?.copy()?;
         new_dict.set_item(field_name, field_value)?;
 
         let dc_dict =
             self.validator
                 .validate_assignment(py, new_dict, field_name, field_value, extra, slots, recursion_guard)?;
 
-        force_setattr(py, obj, dict_py_str, dc_dict)?;
+        self.set_fields(py, obj, dc_dict.into_ref(py))?;
 
         Ok(obj.to_object(py))
     }
 
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+        self.validator.set_strict(strict);
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .strict(self.strict)
+            .accepts(&["instance", "dict", "args_kwargs"], &["instance"])
+            .child("schema", &self.validator)?
+            .finish()
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -550,7 +604,7 @@ impl DataclassValidator {
         input: &'data impl Input<'data>,
     ) -> ValResult<'data, ()> {
         let (dc_dict, post_init_kwargs): (&PyAny, &PyAny) = val_output.extract(py)?;
-        force_setattr(py, dc, intern!(py, "__dict__"), dc_dict)?;
+        self.set_fields(py, dc, dc_dict)?;
 
         if let Some(ref post_init) = self.post_init {
             let post_init = post_init.as_ref(py);
@@ -564,4 +618,55 @@ impl DataclassValidator {
         }
         Ok(())
     }
+    /// the dataclass's fields, read from `__dict__` unless it's slotted, uninitialized slots are omitted
+    fn dataclass_to_dict<'py>(&self, dc: &'py PyAny) -> PyResult<&'py PyDict> {
+        let py = dc.py();
+        match self.slot_fields {
+            Some(ref fields) => {
+                let dict = PyDict::new(py);
+                for field_name in fields {
+                    let field_name = field_name.as_ref(py);
+                    if let Ok(value) = dc.getattr(field_name) {
+                        dict.set_item(field_name, value)?;
+                    }
+                }
+                Ok(dict)
+            }
+            None => Ok(dc.getattr(intern!(py, "__dict__"))?.downcast()?),
+        }
+    }
+
+    /// set the validated fields, as attributes if the dataclass is slotted, so frozen dataclasses work too
+    fn set_fields(&self, py: Python, dc: &PyAny, dc_dict: &PyAny) -> PyResult<()> {
+        match self.slot_fields {
+            Some(_) => {
+                let dc_dict: &PyDict = dc_dict.downcast()?;
+                for (field_name, value) in dc_dict {
+                    force_setattr(py, dc, field_name, value)?;
+                }
+                Ok(())
+            }
+            None => force_setattr(py, dc, intern!(py, "__dict__"), dc_dict),
+        }
+    }
+}
+
+/// The fields of a slotted dataclass which are stored on instances, from its `dataclass-args` schema,
+/// init-only fields aren't stored
+pub fn dataclass_slot_fields(args_schema: &PyAny) -> PyResult<Vec<Py<PyString>>> {
+    let py = args_schema.py();
+    let args_schema: &PyDict = args_schema.downcast()?;
+    if args_schema.get_as::<&str>(intern!(py, "type"))? != Some(DataclassArgsValidator::EXPECTED_TYPE) {
+        return py_err!("`slots` requires a `dataclass-args` schema");
+    }
+    let fields: &PyList = args_schema.get_as_req(intern!(py, "fields"))?;
+    let mut slot_fields = Vec::with_capacity(fields.len());
+    for field in fields {
+        let field: &PyDict = field.downcast()?;
+        if !field.get_as(intern!(py, "init_only"))?.unwrap_or(false) {
+            let name: &PyString = field.get_as_req(intern!(py, "name"))?;
+            slot_fields.push(name.into_py(py));
+        }
+    }
+    Ok(slot_fields)
 }
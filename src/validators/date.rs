@@ -6,16 +6,18 @@ use strum::EnumMessage;
 
 use crate::build_tools::{is_strict, py_error_type, SchemaDict};
 use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::{EitherDate, Input};
+use crate::input::{EitherDate, Input, MicrosecondsPrecision, TemporalUnit};
+use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 use crate::validators::datetime::{NowConstraint, NowOp};
 
-use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub struct DateValidator {
     strict: bool,
     constraints: Option<DateConstraints>,
+    temporal_unit: TemporalUnit,
 }
 
 impl BuildValidator for DateValidator {
@@ -29,6 +31,7 @@ impl BuildValidator for DateValidator {
         Ok(Self {
             strict: is_strict(schema, config)?,
             constraints: DateConstraints::from_py(schema)?,
+            temporal_unit: TemporalUnit::from_py(schema, config)?,
         }
         .into())
     }
@@ -51,9 +54,10 @@ impl Validator for DateValidator {
                 // if we're in strict mode, we doing try coercing from a date
                 true => return Err(date_err),
                 // otherwise, try creating a date from a datetime input
-                false => date_from_datetime(input, date_err),
+                false => date_from_datetime(input, date_err, self.temporal_unit),
             }?,
         };
+        extra.report_coercion(py, input, "date", || input.strict_date().is_ok());
         if let Some(constraints) = &self.constraints {
             let raw_date = date.as_raw()?;
 
@@ -98,10 +102,33 @@ impl Validator for DateValidator {
         Ok(date.try_into_py(py)?)
     }
 
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        let description = Description::new(py, Self::EXPECTED_TYPE)
+            .strict(self.strict)
+            .accepts(&["date", "datetime", "str", "bytes"], &["date"]);
+        match self.constraints {
+            Some(ref c) => description
+                .constraint("le", c.le.as_ref().map(ToString::to_string))?
+                .constraint("lt", c.lt.as_ref().map(ToString::to_string))?
+                .constraint("ge", c.ge.as_ref().map(ToString::to_string))?
+                .constraint("gt", c.gt.as_ref().map(ToString::to_string))?
+                .finish(),
+            None => description.finish(),
+        }
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
 
+    fn ask(&self, question: &Question) -> bool {
+        matches!(question, Question::ReturnsImmutable)
+    }
+
     fn complete(&mut self, _build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
         Ok(())
     }
@@ -112,8 +139,9 @@ impl Validator for DateValidator {
 fn date_from_datetime<'data>(
     input: &'data impl Input<'data>,
     date_err: ValError<'data>,
+    unit: TemporalUnit,
 ) -> ValResult<'data, EitherDate<'data>> {
-    let either_dt = match input.validate_datetime(false) {
+    let either_dt = match input.validate_datetime(false, unit, MicrosecondsPrecision::default()) {
         Ok(dt) => dt,
         Err(dt_err) => {
             return match dt_err {
@@ -4,19 +4,26 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDateTime, PyDict, PyString};
 use speedate::DateTime;
 use std::cmp::Ordering;
+use std::fmt;
 use strum::EnumMessage;
 
-use crate::build_tools::{is_strict, py_err, py_error_type, SchemaDict};
+use crate::build_tools::{coerce_hook_enabled, is_strict, py_err, py_error_type, SchemaDict};
 use crate::errors::{py_err_string, ErrorType, ValError, ValResult};
-use crate::input::{EitherDateTime, Input};
+use crate::input::{bytes_as_datetime, with_coerce_hook, EitherDateTime, Input, MicrosecondsPrecision, TemporalUnit};
+use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
-use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub struct DateTimeValidator {
     strict: bool,
+    coerce_hook: bool,
+    strict_rfc3339: bool,
     constraints: Option<DateTimeConstraints>,
+    tz_names: Option<TzNames>,
+    temporal_unit: TemporalUnit,
+    microseconds_precision: MicrosecondsPrecision,
 }
 
 impl BuildValidator for DateTimeValidator {
@@ -29,7 +36,12 @@ impl BuildValidator for DateTimeValidator {
     ) -> PyResult<CombinedValidator> {
         Ok(Self {
             strict: is_strict(schema, config)?,
+            coerce_hook: coerce_hook_enabled(config)?,
+            strict_rfc3339: schema.get_as(intern!(schema.py(), "strict_rfc3339"))?.unwrap_or(false),
             constraints: DateTimeConstraints::from_py(schema)?,
+            tz_names: TzNames::from_py(schema)?,
+            temporal_unit: TemporalUnit::from_py(schema, config)?,
+            microseconds_precision: MicrosecondsPrecision::from_py(schema, config)?,
         }
         .into())
     }
@@ -44,7 +56,32 @@ impl Validator for DateTimeValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let datetime = input.validate_datetime(extra.strict.unwrap_or(self.strict))?;
+        let strict = extra.strict.unwrap_or(self.strict);
+        let coerce_hook = self.coerce_hook && !strict;
+        let datetime = match self.validate_zoned(py, input)? {
+            Some(datetime) => datetime,
+            None => with_coerce_hook(
+                input,
+                "datetime",
+                coerce_hook,
+                self.check_rfc3339(
+                    input,
+                    input.validate_datetime(strict, self.temporal_unit, self.microseconds_precision),
+                ),
+                |v| self.check_rfc3339(v, v.lax_datetime(self.temporal_unit, self.microseconds_precision)),
+            )?,
+        };
+        extra.report_coercion(py, input, "datetime", || {
+            input.strict_datetime(self.microseconds_precision).is_ok()
+        });
+        let datetime = match datetime {
+            // timestamps are UTC, with a `tz_constraint` they're checked and returned as aware datetimes
+            EitherDateTime::Raw(mut dt) if dt.offset.is_none() && self.has_tz_constraint() && is_timestamp(input) => {
+                dt.offset = Some(0);
+                EitherDateTime::Raw(dt)
+            }
+            datetime => datetime,
+        };
         if let Some(constraints) = &self.constraints {
             // if we get an error from as_speedate, it's probably because the input datetime was invalid
             // specifically had an invalid tzinfo, hence here we return a validation error
@@ -93,24 +130,260 @@ impl Validator for DateTimeValidator {
                 }
             }
 
-            match (&constraints.tz, speedate_dt.offset) {
-                (Some(TZConstraint::Aware), None) => return Err(ValError::new(ErrorType::DatetimeAware, input)),
-                (Some(TZConstraint::Naive), Some(_)) => return Err(ValError::new(ErrorType::DatetimeNaive, input)),
-                _ => (),
+            if let Some(ref tz_constraint) = constraints.tz {
+                tz_constraint.check(input, speedate_dt.offset)?;
             }
         }
         Ok(datetime.try_into_py(py)?)
     }
 
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        let description = Description::new(py, Self::EXPECTED_TYPE)
+            .strict(self.strict)
+            .accepts(&["datetime", "date", "str", "bytes", "int", "float"], &["datetime"]);
+        match self.constraints {
+            Some(ref c) => description
+                .constraint("le", c.le.as_ref().map(ToString::to_string))?
+                .constraint("lt", c.lt.as_ref().map(ToString::to_string))?
+                .constraint("ge", c.ge.as_ref().map(ToString::to_string))?
+                .constraint("gt", c.gt.as_ref().map(ToString::to_string))?
+                .constraint("tz_constraint", c.tz.as_ref().map(|tz| tz.to_object(py)))?
+                .finish(),
+            None => description.finish(),
+        }
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
 
+    fn ask(&self, question: &Question) -> bool {
+        // with `now_op` whether a value is valid depends on when it's validated
+        matches!(question, Question::ReturnsImmutable) && self.constraints.as_ref().is_none_or(|c| c.now.is_none())
+    }
+
     fn complete(&mut self, _build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
         Ok(())
     }
 }
 
+impl DateTimeValidator {
+    fn has_tz_constraint(&self) -> bool {
+        self.constraints.as_ref().is_some_and(|c| c.tz.is_some())
+    }
+
+    /// with `tz_names`, validate a string ending with a space and an IANA time zone name,
+    /// `None` if the input isn't a string ending with a name so it's validated as usual
+    fn validate_zoned<'data>(
+        &self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+    ) -> ValResult<'data, Option<EitherDateTime<'data>>> {
+        let tz_names = match self.tz_names {
+            Some(ref tz_names) => tz_names,
+            None => return Ok(None),
+        };
+        let either_str = match input.strict_str() {
+            Ok(either_str) => either_str,
+            Err(_) => return Ok(None),
+        };
+        let cow = either_str.as_cow()?;
+        // zone names start with a letter, which distinguishes them from the time after a space separator
+        let (datetime_str, zone) = match cow.rsplit_once(' ') {
+            Some((datetime_str, zone)) if zone.starts_with(|c: char| c.is_ascii_alphabetic()) => (datetime_str, zone),
+            _ => return Ok(None),
+        };
+        match bytes_as_datetime(input, datetime_str.as_bytes(), self.microseconds_precision)?.as_raw()? {
+            datetime if datetime.offset.is_none() => tz_names.localize(py, input, datetime, zone).map(Some),
+            _ => Err(ValError::new(
+                ErrorType::DatetimeParsing {
+                    error: "a time zone name can't be combined with a UTC offset".into(),
+                },
+                input,
+            )),
+        }
+    }
+
+    /// if `strict_rfc3339` is set, check the input a datetime was parsed from is an RFC 3339 string,
+    /// datetime objects aren't checked
+    fn check_rfc3339<'data>(
+        &self,
+        input: &'data impl Input<'data>,
+        result: ValResult<'data, EitherDateTime<'data>>,
+    ) -> ValResult<'data, EitherDateTime<'data>> {
+        let datetime = result?;
+        if !self.strict_rfc3339 {
+            return Ok(datetime);
+        }
+        let speedate_dt = match datetime {
+            EitherDateTime::Py(_) => return Ok(datetime),
+            EitherDateTime::Raw(ref dt) => dt,
+        };
+        let error = match input.lax_str() {
+            Ok(either_str) => rfc3339_error(&either_str.as_cow()?, speedate_dt),
+            Err(_) => Some("RFC 3339 datetimes must be strings"),
+        };
+        match error {
+            Some(error) => Err(ValError::new(ErrorType::DatetimeParsing { error: error.into() }, input)),
+            None => Ok(datetime),
+        }
+    }
+}
+
+/// whether `input` is a unix timestamp, only numbers are parsed as timestamps
+fn is_timestamp<'data>(input: &'data impl Input<'data>) -> bool {
+    input.strict_float().is_ok()
+}
+
+/// Check a string which has already been parsed as a datetime meets the stricter rules of RFC 3339:
+/// `YYYY-MM-DDTHH:MM:SS` followed by optional fractional seconds and a `Z` or `±HH:MM` offset
+fn rfc3339_error(s: &str, dt: &DateTime) -> Option<&'static str> {
+    let bytes = s.as_bytes();
+    let digits_at = |positions: &[usize]| positions.iter().all(|&i| bytes.get(i).is_some_and(u8::is_ascii_digit));
+    if !digits_at(&[0, 1, 2, 3, 5, 6, 8, 9]) || bytes.get(4) != Some(&b'-') || bytes.get(7) != Some(&b'-') {
+        return Some("RFC 3339 requires a date in the format YYYY-MM-DD");
+    }
+    match bytes.get(10) {
+        None => return Some("RFC 3339 requires a time"),
+        Some(b'T' | b't') => (),
+        Some(b' ') => return Some("RFC 3339 requires `T` to separate the date and time, not a space"),
+        Some(_) => return Some("RFC 3339 requires `T` to separate the date and time"),
+    }
+    if !digits_at(&[11, 12, 14, 15, 17, 18]) || bytes.get(13) != Some(&b':') || bytes.get(16) != Some(&b':') {
+        return Some("RFC 3339 requires a time in the format HH:MM:SS");
+    }
+    if dt.offset.is_none() {
+        return Some("RFC 3339 requires a timezone offset, e.g. `Z` or `+01:00`");
+    }
+    let offset_ok = match bytes {
+        [.., b'Z' | b'z'] => true,
+        [.., b'+' | b'-', h1, h2, b':', m1, m2] => [h1, h2, m1, m2].iter().all(|b| b.is_ascii_digit()),
+        _ => false,
+    };
+    if offset_ok {
+        None
+    } else {
+        Some("RFC 3339 requires the timezone offset to be `Z` or in the format ±HH:MM")
+    }
+}
+
+/// How wall times which don't exist or are ambiguous in a time zone because of a DST transition are handled
+#[derive(Debug, Clone, Copy)]
+enum DstPolicy {
+    /// use the earliest of the possible instants
+    Earlier,
+    /// use the latest of the possible instants
+    Later,
+    Error,
+}
+
+impl DstPolicy {
+    fn from_str(s: &str) -> PyResult<Self> {
+        match s {
+            "earlier" => Ok(Self::Earlier),
+            "later" => Ok(Self::Later),
+            "error" => Ok(Self::Error),
+            _ => py_err!("Invalid dst_policy {:?}", s),
+        }
+    }
+}
+
+/// Named time zones, looked up with `zoneinfo.ZoneInfo` which caches zones by name
+#[derive(Debug, Clone)]
+struct TzNames {
+    zone_info: PyObject,
+    dst_policy: DstPolicy,
+}
+
+impl TzNames {
+    fn from_py(schema: &PyDict) -> PyResult<Option<Self>> {
+        let py = schema.py();
+        let dst_policy: Option<&str> = schema.get_as(intern!(py, "dst_policy"))?;
+        if !schema.get_as(intern!(py, "tz_names"))?.unwrap_or(false) {
+            return match dst_policy {
+                Some(_) => py_err!("`dst_policy` can only be used with `tz_names`"),
+                None => Ok(None),
+            };
+        }
+        let zone_info = match py.import(intern!(py, "zoneinfo")) {
+            Ok(module) => module.getattr(intern!(py, "ZoneInfo"))?,
+            Err(_) => return py_err!("`tz_names` requires the `zoneinfo` module, available from Python 3.9"),
+        };
+        Ok(Some(Self {
+            zone_info: zone_info.into_py(py),
+            dst_policy: DstPolicy::from_str(dst_policy.unwrap_or("error"))?,
+        }))
+    }
+
+    /// attach the zone `zone` to the naive `datetime`, applying `dst_policy` to times in a DST gap or overlap
+    fn localize<'data>(
+        &self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        datetime: DateTime,
+        zone: &str,
+    ) -> ValResult<'data, EitherDateTime<'data>> {
+        let tzinfo = match self.zone_info.as_ref(py).call1((zone,)) {
+            Ok(tzinfo) => tzinfo,
+            Err(_) => {
+                let zone = zone.to_string();
+                return Err(ValError::new(ErrorType::DatetimeUnknownZone { zone }, input));
+            }
+        };
+        let naive = EitherDateTime::Raw(datetime).try_into_py(py)?.into_ref(py);
+        let with_fold = |fold: u8| -> PyResult<&PyAny> {
+            let kwargs = PyDict::new(py);
+            kwargs.set_item(intern!(py, "tzinfo"), tzinfo)?;
+            kwargs.set_item(intern!(py, "fold"), fold)?;
+            naive.call_method(intern!(py, "replace"), (), Some(kwargs))
+        };
+        // `fold` only changes the offset of times in a gap or overlap
+        let fold_0 = with_fold(0)?;
+        let fold_1 = with_fold(1)?;
+        let offset_0 = fold_0.call_method0(intern!(py, "utcoffset"))?;
+        if offset_0.eq(fold_1.call_method0(intern!(py, "utcoffset"))?)? {
+            return Ok(EitherDateTime::Py(fold_0.downcast()?));
+        }
+
+        let utc = py
+            .import(intern!(py, "datetime"))?
+            .getattr(intern!(py, "timezone"))?
+            .getattr(intern!(py, "utc"))?;
+        let instant_0 = fold_0.call_method1(intern!(py, "astimezone"), (utc,))?;
+        let instant_1 = fold_1.call_method1(intern!(py, "astimezone"), (utc,))?;
+        let (earlier, later) = match instant_0.lt(instant_1)? {
+            true => (instant_0, instant_1),
+            false => (instant_1, instant_0),
+        };
+        let instant = match self.dst_policy {
+            DstPolicy::Earlier => earlier,
+            DstPolicy::Later => later,
+            DstPolicy::Error => {
+                // wall times in an overlap survive a round trip through UTC, those in a gap don't exist so can't
+                let round_trip = instant_0.call_method1(intern!(py, "astimezone"), (tzinfo,))?;
+                let kwargs = PyDict::new(py);
+                kwargs.set_item(intern!(py, "tzinfo"), py.None())?;
+                let zone = zone.to_string();
+                let error_type = match round_trip
+                    .call_method(intern!(py, "replace"), (), Some(kwargs))?
+                    .eq(naive)?
+                {
+                    true => ErrorType::DatetimeAmbiguous { zone },
+                    false => ErrorType::DatetimeNonexistent { zone },
+                };
+                return Err(ValError::new(error_type, input));
+            }
+        };
+        // converting back from UTC gives a wall time which exists, with `fold` set for times in an overlap
+        let localized = instant.call_method1(intern!(py, "astimezone"), (tzinfo,))?;
+        Ok(EitherDateTime::Py(localized.downcast()?))
+    }
+}
+
 #[derive(Debug, Clone)]
 struct DateTimeConstraints {
     le: Option<DateTime>,
@@ -216,6 +489,8 @@ impl NowConstraint {
 pub enum TZConstraint {
     Aware,
     Naive,
+    /// aware with exactly this UTC offset in seconds
+    Offset(i32),
 }
 
 impl TZConstraint {
@@ -229,9 +504,51 @@ impl TZConstraint {
 
     pub fn from_py(schema: &PyDict) -> PyResult<Option<Self>> {
         let py = schema.py();
-        match schema.get_as(intern!(py, "tz_constraint"))? {
-            Some(kind) => Ok(Some(Self::from_str(kind)?)),
-            None => Ok(None),
+        let tz_constraint = match schema.get_item(intern!(py, "tz_constraint")) {
+            Some(tz_constraint) => tz_constraint,
+            None => return Ok(None),
+        };
+        match tz_constraint.extract::<&str>() {
+            Ok(kind) => Self::from_str(kind).map(Some),
+            // the offset is restricted to -86_400 < offset < 86_400 by the self schema
+            Err(_) => Ok(Some(Self::Offset(tz_constraint.extract()?))),
+        }
+    }
+
+    pub fn check<'data>(&self, input: &'data impl Input<'data>, offset: Option<i32>) -> ValResult<'data, ()> {
+        let error_type = match (self, offset) {
+            (Self::Aware | Self::Offset(_), None) => ErrorType::TimezoneAware {
+                tz_constraint: self.to_string(),
+            },
+            (Self::Naive, Some(_)) => ErrorType::TimezoneNaive {
+                tz_constraint: self.to_string(),
+            },
+            (Self::Offset(tz_expected), Some(tz_actual)) if *tz_expected != tz_actual => ErrorType::TimezoneOffset {
+                tz_expected: *tz_expected,
+                tz_actual,
+            },
+            _ => return Ok(()),
+        };
+        Err(ValError::new(error_type, input))
+    }
+}
+
+impl fmt::Display for TZConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Aware => write!(f, "aware"),
+            Self::Naive => write!(f, "naive"),
+            Self::Offset(offset) => write!(f, "{offset}"),
+        }
+    }
+}
+
+impl ToPyObject for TZConstraint {
+    fn to_object(&self, py: Python) -> PyObject {
+        match self {
+            Self::Aware => "aware".to_object(py),
+            Self::Naive => "naive".to_object(py),
+            Self::Offset(offset) => offset.to_object(py),
         }
     }
 }
@@ -0,0 +1,328 @@
+use pyo3::exceptions::PyArithmeticError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyDict, PyFloat, PyLong, PyString, PyTuple};
+
+use crate::build_tools::{is_strict, py_err, schema_or_config_same, SchemaDict};
+use crate::errors::{ErrorType, Number, ValError, ValResult};
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::{BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
+
+#[derive(Debug, Clone)]
+pub struct DecimalValidator {
+    strict: bool,
+    decimal_class: PyObject,
+    allow_inf_nan: bool,
+    multiple_of: Option<PyObject>,
+    le: Option<PyObject>,
+    lt: Option<PyObject>,
+    ge: Option<PyObject>,
+    gt: Option<PyObject>,
+    max_digits: Option<usize>,
+    decimal_places: Option<usize>,
+}
+
+impl BuildValidator for DecimalValidator {
+    const EXPECTED_TYPE: &'static str = "decimal";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        _build_context: &mut BuildContext<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let decimal_class = py.import(intern!(py, "decimal"))?.getattr(intern!(py, "Decimal"))?;
+        // bounds are converted to decimals so they're compared exactly, floats via their repr as with inputs
+        let bound = |key: &PyString| -> PyResult<Option<PyObject>> {
+            match schema.get_item(key) {
+                Some(value) => Ok(Some(build_decimal(decimal_class, value)?.into_py(py))),
+                None => Ok(None),
+            }
+        };
+        let multiple_of = bound(intern!(py, "multiple_of"))?;
+        if let Some(ref multiple_of) = multiple_of {
+            let multiple_of = multiple_of.as_ref(py);
+            if !is_finite(multiple_of)? || multiple_of.le(0)? {
+                return py_err!("`multiple_of` should be a finite decimal greater than 0");
+            }
+        }
+
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            decimal_class: decimal_class.into_py(py),
+            allow_inf_nan: schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(false),
+            multiple_of,
+            le: bound(intern!(py, "le"))?,
+            lt: bound(intern!(py, "lt"))?,
+            ge: bound(intern!(py, "ge"))?,
+            gt: bound(intern!(py, "gt"))?,
+            max_digits: schema.get_as(intern!(py, "max_digits"))?,
+            decimal_places: schema.get_as(intern!(py, "decimal_places"))?,
+        }
+        .into())
+    }
+}
+
+impl Validator for DecimalValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        _slots: &'data [CombinedValidator],
+        _recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let decimal_object = self.get_decimal(py, input, extra.strict.unwrap_or(self.strict))?;
+        let decimal = decimal_object.as_ref(py);
+
+        if !is_finite(decimal)? {
+            // signalling NaNs raise on any comparison, so they're never accepted
+            if !self.allow_inf_nan || decimal.call_method0(intern!(py, "is_snan"))?.is_true()? {
+                return Err(ValError::new(ErrorType::FiniteNumber, input));
+            }
+            // like float NaNs, decimal NaNs can't be compared with bounds, so only infinities are checked
+            if decimal.call_method0(intern!(py, "is_nan"))?.is_true()? {
+                return Ok(decimal_object);
+            }
+        } else {
+            let (_, digits, exponent): (&PyAny, &PyTuple, i64) =
+                decimal.call_method0(intern!(py, "as_tuple"))?.extract()?;
+            let digits: Vec<u8> = digits.extract()?;
+
+            if let Some(ref multiple_of) = self.multiple_of {
+                if !is_multiple_of(self.decimal_class.as_ref(py), &digits, exponent, multiple_of.as_ref(py))? {
+                    let multiple_of = decimal_number(multiple_of.as_ref(py))?;
+                    return Err(ValError::new(ErrorType::MultipleOf { multiple_of }, input));
+                }
+            }
+            self.check_digits(&digits, exponent, input)?;
+        }
+
+        if let Some(ref le) = self.le {
+            if decimal.gt(le)? {
+                let le = decimal_number(le.as_ref(py))?;
+                return Err(ValError::new(ErrorType::LessThanEqual { le }, input));
+            }
+        }
+        if let Some(ref lt) = self.lt {
+            if decimal.ge(lt)? {
+                let lt = decimal_number(lt.as_ref(py))?;
+                return Err(ValError::new(ErrorType::LessThan { lt }, input));
+            }
+        }
+        if let Some(ref ge) = self.ge {
+            if decimal.lt(ge)? {
+                let ge = decimal_number(ge.as_ref(py))?;
+                return Err(ValError::new(ErrorType::GreaterThanEqual { ge }, input));
+            }
+        }
+        if let Some(ref gt) = self.gt {
+            if decimal.le(gt)? {
+                let gt = decimal_number(gt.as_ref(py))?;
+                return Err(ValError::new(ErrorType::GreaterThan { gt }, input));
+            }
+        }
+        Ok(decimal_object)
+    }
+
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .strict(self.strict)
+            .accepts(&["decimal", "int", "float", "str", "bytes"], &["decimal"])
+            .constraint("allow_inf_nan", self.allow_inf_nan.then_some(true))?
+            .constraint("multiple_of", self.multiple_of.as_ref())?
+            .constraint("le", self.le.as_ref())?
+            .constraint("lt", self.lt.as_ref())?
+            .constraint("ge", self.ge.as_ref())?
+            .constraint("gt", self.gt.as_ref())?
+            .constraint("max_digits", self.max_digits)?
+            .constraint("decimal_places", self.decimal_places)?
+            .finish()
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+
+    fn complete(&mut self, _build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
+        Ok(())
+    }
+}
+
+impl DecimalValidator {
+    fn get_decimal<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        strict: bool,
+    ) -> ValResult<'data, PyObject> {
+        let decimal_class = self.decimal_class.as_ref(py);
+        if input.input_is_instance(decimal_class, 0)? {
+            let value = input.to_object(py);
+            // subclasses are converted so the result is always a `decimal.Decimal`
+            return if value.as_ref(py).get_type().is(decimal_class) {
+                Ok(value)
+            } else {
+                Ok(decimal_class.call1((value,))?.into_py(py))
+            };
+        }
+        // in strict mode only decimals are accepted from python, JSON has no decimal type so strings and
+        // numbers are allowed
+        if strict && input.is_python() {
+            return Err(ValError::new(ErrorType::DecimalType, input));
+        }
+        if let Ok(either_str) = input.validate_str(strict) {
+            return match decimal_class.call1((either_str.as_cow()?.as_ref(),)) {
+                Ok(decimal) => Ok(decimal.into_py(py)),
+                // `decimal.InvalidOperation` is an `ArithmeticError`
+                Err(err) if err.is_instance_of::<PyArithmeticError>(py) => {
+                    Err(ValError::new(ErrorType::DecimalParsing, input))
+                }
+                Err(err) => Err(err.into()),
+            };
+        }
+
+        // JSON numbers are read as they're written, so e.g. `1.000` keeps its precision and trailing zeros
+        if let Some(lexeme) = input.number_lexeme() {
+            return Ok(decimal_class.call1((lexeme,))?.into_py(py));
+        }
+        let value = input.to_object(py).into_ref(py);
+        if value.is_instance_of::<PyBool>()? {
+            Err(ValError::new(ErrorType::DecimalType, input))
+        } else if value.is_instance_of::<PyLong>()? {
+            Ok(decimal_class.call1((value,))?.into_py(py))
+        } else if let Ok(float) = value.downcast::<PyFloat>() {
+            // floats are converted via their shortest repr so e.g. `0.1` is `Decimal('0.1')`, not its binary value
+            Ok(decimal_class.call1((float.repr()?,))?.into_py(py))
+        } else {
+            Err(ValError::new(ErrorType::DecimalType, input))
+        }
+    }
+
+    fn check_digits<'data>(
+        &self,
+        digits: &[u8],
+        exponent: i64,
+        input: &'data impl Input<'data>,
+    ) -> ValResult<'data, ()> {
+        if self.max_digits.is_none() && self.decimal_places.is_none() {
+            return Ok(());
+        }
+        let (total, decimals) = digit_counts(digits, exponent);
+        if let Some(max_digits) = self.max_digits {
+            if total > max_digits as u64 {
+                return Err(ValError::new(ErrorType::DecimalMaxDigits { max_digits }, input));
+            }
+        }
+        if let Some(decimal_places) = self.decimal_places {
+            if decimals > decimal_places as u64 {
+                return Err(ValError::new(ErrorType::DecimalMaxPlaces { decimal_places }, input));
+            }
+            if let Some(max_digits) = self.max_digits {
+                let whole_digits = max_digits.saturating_sub(decimal_places);
+                if total - decimals > whole_digits as u64 {
+                    return Err(ValError::new(ErrorType::DecimalWholeDigits { whole_digits }, input));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// convert a schema value to a decimal, floats via their repr as with inputs
+fn build_decimal<'py>(decimal_class: &'py PyAny, value: &'py PyAny) -> PyResult<&'py PyAny> {
+    match value.downcast::<PyFloat>() {
+        Ok(float) => decimal_class.call1((float.repr()?,)),
+        Err(_) => decimal_class.call1((value,)),
+    }
+}
+
+fn is_finite(decimal: &PyAny) -> PyResult<bool> {
+    decimal.call_method0(intern!(decimal.py(), "is_finite"))?.is_true()
+}
+
+/// the total number of digits and the number of digits after the decimal point of a finite decimal,
+/// trailing zeros after the decimal point aren't counted, e.g. `Decimal('1.500')` has 2 digits and 1 decimal
+/// place while `Decimal('1E+3')` has 4 digits, zero has no digits at all
+fn digit_counts(digits: &[u8], exponent: i64) -> (u64, u64) {
+    let first = match digits.iter().position(|d| *d != 0) {
+        Some(first) => first,
+        None => return (0, 0),
+    };
+    let mut digits = &digits[first..];
+    let mut exponent = exponent;
+    while exponent < 0 && digits.last() == Some(&0) {
+        digits = &digits[..digits.len() - 1];
+        exponent += 1;
+    }
+    let len = digits.len() as u64;
+    if exponent >= 0 {
+        (len.saturating_add(exponent as u64), 0)
+    } else {
+        let decimals = exponent.unsigned_abs();
+        // e.g. `Decimal('0.001')` has 3 digits, all of them decimal places
+        (len.max(decimals), decimals)
+    }
+}
+
+/// whether the finite decimal `coefficient * 10 ** exponent` is an exact multiple of `multiple_of`,
+/// computed with python ints rather than decimal arithmetic which would round to the context's precision
+fn is_multiple_of(decimal_class: &PyAny, digits: &[u8], exponent: i64, multiple_of: &PyAny) -> PyResult<bool> {
+    let py = decimal_class.py();
+    let coefficient = match integer_from_digits(decimal_class, digits)? {
+        Some(coefficient) => coefficient,
+        None => return Ok(true),
+    };
+    let (_, m_digits, m_exponent): (&PyAny, Vec<u8>, i64) =
+        multiple_of.call_method0(intern!(py, "as_tuple"))?.extract()?;
+    // `multiple_of` is checked to be greater than zero when building, so its coefficient isn't zero
+    let m_coefficient = match integer_from_digits(decimal_class, &m_digits)? {
+        Some(m_coefficient) => m_coefficient,
+        None => return Ok(false),
+    };
+    let pow = py.import(intern!(py, "builtins"))?.getattr(intern!(py, "pow"))?;
+
+    let remainder = if exponent >= m_exponent {
+        // `coefficient * 10 ** (exponent - m_exponent) % m_coefficient` without creating the power of ten
+        let scale = pow.call1((10, exponent - m_exponent, m_coefficient))?;
+        coefficient
+            .call_method1(intern!(py, "__mul__"), (scale,))?
+            .call_method1(intern!(py, "__mod__"), (m_coefficient,))?
+    } else {
+        let shift = m_exponent - exponent;
+        // a non-zero coefficient is smaller than `10 ** shift` if it has fewer digits than `shift`
+        if shift.unsigned_abs() > digits.len() as u64 {
+            return Ok(false);
+        }
+        let divisor = m_coefficient.call_method1(intern!(py, "__mul__"), (pow.call1((10, shift))?,))?;
+        coefficient.call_method1(intern!(py, "__mod__"), (divisor,))?
+    };
+    Ok(!remainder.is_true()?)
+}
+
+/// the digits of a decimal as a python int, `None` if they're all zero
+fn integer_from_digits<'py>(decimal_class: &'py PyAny, digits: &[u8]) -> PyResult<Option<&'py PyAny>> {
+    if digits.iter().all(|d| *d == 0) {
+        return Ok(None);
+    }
+    // `int()` of a decimal is exact and, unlike `int()` of a str, isn't limited in length
+    let py = decimal_class.py();
+    let decimal = decimal_class.call1(((0, PyTuple::new(py, digits), 0),))?;
+    let int = py.import(intern!(py, "builtins"))?.getattr(intern!(py, "int"))?;
+    int.call1((decimal,)).map(Some)
+}
+
+/// bounds in error context are ints if they're written as whole numbers, otherwise strings like `'1.5'`
+fn decimal_number(decimal: &PyAny) -> PyResult<Number> {
+    let s = decimal.str()?.to_string();
+    match s.parse::<i64>() {
+        Ok(int) => Ok(Number::Int(int)),
+        Err(_) => Ok(Number::String(s)),
+    }
+}
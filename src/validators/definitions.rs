@@ -9,7 +9,7 @@ use crate::input::Input;
 use crate::questions::{Answers, Question};
 use crate::recursion_guard::RecursionGuard;
 
-use super::{build_validator, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{build_validator, BuildValidator, CombinedValidator, Description, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub struct DefinitionsBuilder;
@@ -39,15 +39,17 @@ impl BuildValidator for DefinitionsBuilder {
 #[derive(Debug, Clone)]
 pub struct DefinitionRefValidator {
     validator_id: usize,
+    schema_ref: String,
     inner_name: String,
     // we have to record the answers to `Question`s as we can't access the validator when `ask()` is called
     answers: Answers,
 }
 
 impl DefinitionRefValidator {
-    pub fn from_id(validator_id: usize, inner_name: String, answers: Answers) -> CombinedValidator {
+    pub fn from_id(validator_id: usize, schema_ref: String, inner_name: String, answers: Answers) -> CombinedValidator {
         Self {
             validator_id,
+            schema_ref,
             inner_name,
             answers,
         }
@@ -71,6 +73,7 @@ impl BuildValidator for DefinitionRefValidator {
                 let answers = build_context.get_slot_answer(validator_id)?;
                 Ok(Self {
                     validator_id,
+                    schema_ref,
                     inner_name: "...".to_string(),
                     answers: answers.unwrap(),
                 }
@@ -89,24 +92,36 @@ impl Validator for DefinitionRefValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        if let Some(id) = input.identity() {
+        let id = input.identity();
+        if let Some(id) = id {
             if recursion_guard.contains_or_insert(id) {
                 // we don't remove id here, we leave that to the validator which originally added id to `recursion_guard`
-                Err(ValError::new(ErrorType::RecursionLoop, input))
-            } else {
-                if recursion_guard.incr_depth() > BACKUP_GUARD_LIMIT {
-                    return Err(ValError::new(ErrorType::RecursionLoop, input));
-                }
-                let output = validate(self.validator_id, py, input, extra, slots, recursion_guard);
-                recursion_guard.remove(&id);
-                recursion_guard.decr_depth();
-                output
+                return Err(self.recursion_error(input));
             }
+        }
+        // the depth is checked whether or not the input has an identity, e.g. for deeply nested JSON
+        let output = match recursion_guard.incr_depth() {
+            true => validate(self.validator_id, py, input, extra, slots, recursion_guard),
+            false => Err(self.recursion_error(input)),
+        };
+        if let Some(id) = id {
+            recursion_guard.remove(&id);
+        }
+        recursion_guard.decr_depth();
+        if recursion_guard.depth() == 0 {
+            // this is the outermost definition, so locations are as long as they'll get within recursion
+            output.map_err(truncate_recursion_locations)
         } else {
-            validate(self.validator_id, py, input, extra, slots, recursion_guard)
+            output
         }
     }
 
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .schema_ref(&self.schema_ref)
+            .finish()
+    }
+
     fn get_name(&self) -> &str {
         &self.inner_name
     }
@@ -123,14 +138,29 @@ impl Validator for DefinitionRefValidator {
     }
 }
 
-// see #143 this is a backup in case the identity check recursion guard fails
-// if a single validator "depth" (how many times it's called inside itself) exceeds the limit,
-// we raise a recursion error.
-const BACKUP_GUARD_LIMIT: u16 = if cfg!(PyPy) || cfg!(target_family = "wasm") {
-    123
-} else {
-    255
-};
+impl DefinitionRefValidator {
+    fn recursion_error<'data>(&self, input: &'data impl Input<'data>) -> ValError<'data> {
+        let schema_ref = Some(self.schema_ref.clone());
+        ValError::new(ErrorType::RecursionLoop { schema_ref }, input)
+    }
+}
+
+// recursion errors can be raised very deep inside the input, only this many location items are shown
+const MAX_RECURSION_LOCATION_LENGTH: usize = 20;
+
+fn truncate_recursion_locations(error: ValError) -> ValError {
+    match error {
+        ValError::LineErrors(mut line_errors) => {
+            for line_error in line_errors.iter_mut() {
+                if matches!(line_error.error_type, ErrorType::RecursionLoop { .. }) {
+                    line_error.location.truncate(MAX_RECURSION_LOCATION_LENGTH);
+                }
+            }
+            ValError::LineErrors(line_errors)
+        }
+        other => other,
+    }
+}
 
 fn validate<'s, 'data>(
     validator_id: usize,
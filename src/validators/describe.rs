@@ -0,0 +1,125 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use super::{CombinedValidator, Validator};
+
+/// Builds one node of the tree returned by `SchemaValidator.describe()`, every node has the same keys in the
+/// same order so tools can rely on the shape:
+/// * `kind` - the schema type, e.g. `'int'`, `'typed-dict'`
+/// * `ref` - for `definition-ref` nodes, the definition the node points to, see `definitions` in `describe()`
+/// * `strict` - the strictness the validator uses when validation doesn't set `strict`, `None` if it has no such setting
+/// * `has_default` - whether the validator can provide a default when the value is missing
+/// * `accepts` - input categories accepted in `lax` and `strict` mode, `'opaque'` when a function decides,
+///   `None` when acceptance is decided by the values of `constraints` or by `children`
+/// * `constraints` - only the constraints which are set, keyed as in the schema
+/// * `children` - descriptions of sub-validators keyed by their role: the schema key they come from, e.g.
+///   `'items_schema'`, a field or parameter name, a tag, or an index for positional items, union choices and chain steps
+pub(crate) struct Description<'py> {
+    py: Python<'py>,
+    kind: String,
+    schema_ref: Option<String>,
+    strict: Option<bool>,
+    has_default: bool,
+    accepts: Accepts,
+    constraints: &'py PyDict,
+    children: &'py PyDict,
+}
+
+enum Accepts {
+    Inputs {
+        lax: &'static [&'static str],
+        strict: &'static [&'static str],
+    },
+    Opaque,
+    Other,
+}
+
+impl<'py> Description<'py> {
+    pub fn new(py: Python<'py>, kind: &str) -> Self {
+        Self {
+            py,
+            kind: kind.to_string(),
+            schema_ref: None,
+            strict: None,
+            has_default: false,
+            accepts: Accepts::Other,
+            constraints: PyDict::new(py),
+            children: PyDict::new(py),
+        }
+    }
+
+    pub fn schema_ref(mut self, schema_ref: &str) -> Self {
+        self.schema_ref = Some(schema_ref.to_string());
+        self
+    }
+
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = Some(strict);
+        self
+    }
+
+    pub fn has_default(mut self, has_default: bool) -> Self {
+        self.has_default = has_default;
+        self
+    }
+
+    /// the same inputs in lax and strict mode
+    pub fn accepts_always(self, inputs: &'static [&'static str]) -> Self {
+        self.accepts(inputs, inputs)
+    }
+
+    pub fn accepts(mut self, lax: &'static [&'static str], strict: &'static [&'static str]) -> Self {
+        self.accepts = Accepts::Inputs { lax, strict };
+        self
+    }
+
+    pub fn opaque(mut self) -> Self {
+        self.accepts = Accepts::Opaque;
+        self
+    }
+
+    /// add a constraint if it's set
+    pub fn constraint(self, key: &str, value: Option<impl ToPyObject>) -> PyResult<Self> {
+        if let Some(value) = value {
+            self.constraints.set_item(key, value)?;
+        }
+        Ok(self)
+    }
+
+    pub fn child(self, role: impl ToPyObject, validator: &CombinedValidator) -> PyResult<Self> {
+        self.children.set_item(role, validator.describe(self.py)?)?;
+        Ok(self)
+    }
+
+    /// add a child if there's a validator for the role, e.g. `items_schema` might be omitted
+    pub fn opt_child(self, role: impl ToPyObject, validator: Option<&CombinedValidator>) -> PyResult<Self> {
+        match validator {
+            Some(validator) => self.child(role, validator),
+            None => Ok(self),
+        }
+    }
+
+    pub fn finish(self) -> PyResult<Py<PyDict>> {
+        let py = self.py;
+        let accepts = match self.accepts {
+            Accepts::Inputs { lax, strict } => {
+                let accepts = PyDict::new(py);
+                accepts.set_item(intern!(py, "lax"), PyList::new(py, lax))?;
+                accepts.set_item(intern!(py, "strict"), PyList::new(py, strict))?;
+                accepts.to_object(py)
+            }
+            Accepts::Opaque => intern!(py, "opaque").to_object(py),
+            Accepts::Other => py.None(),
+        };
+        let node = PyDict::new(py);
+        node.set_item(intern!(py, "kind"), self.kind)?;
+        node.set_item(intern!(py, "ref"), self.schema_ref)?;
+        node.set_item(intern!(py, "strict"), self.strict)?;
+        node.set_item(intern!(py, "has_default"), self.has_default)?;
+        node.set_item(intern!(py, "accepts"), accepts)?;
+        node.set_item(intern!(py, "constraints"), self.constraints)?;
+        node.set_item(intern!(py, "children"), self.children)?;
+        Ok(node.into())
+    }
+}
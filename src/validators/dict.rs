@@ -1,9 +1,10 @@
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyMapping};
+use pyo3::types::{PyDict, PyMapping, PyString};
+use pyo3::PyTypeInfo;
 
-use crate::build_tools::{is_strict, SchemaDict};
-use crate::errors::{ValError, ValLineError, ValResult};
+use crate::build_tools::{force_exact_container_path, is_strict, py_err, SchemaDict};
+use crate::errors::{ErrorType, ValError, ValLineError, ValResult};
 use crate::input::{
     DictGenericIterator, GenericMapping, Input, JsonObject, JsonObjectGenericIterator, MappingGenericIterator,
 };
@@ -11,18 +12,88 @@ use crate::recursion_guard::RecursionGuard;
 
 use super::any::AnyValidator;
 use super::list::length_check;
-use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::set::is_unhashable;
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub struct DictValidator {
     strict: bool,
+    force_exact_container_path: bool,
+    output_preserve_subclass: bool,
     key_validator: Box<CombinedValidator>,
     value_validator: Box<CombinedValidator>,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    // string keys which must be present, each absent key is reported as `missing` at its own location
+    required_keys: Vec<String>,
+    output_type: OutputType,
+    // with `output_type='defaultdict'`, whether strict mode rejects dicts which aren't a `defaultdict`
+    defaultdict_strict: bool,
     name: String,
 }
 
+/// The type of validated dicts, set with `output_type`
+#[derive(Debug, Clone)]
+enum OutputType {
+    Dict,
+    /// `collections.OrderedDict`, the dict is in input iteration order so is the `OrderedDict` built from it
+    OrderedDict(PyObject),
+    /// `collections.defaultdict`, with `default_factory` from the schema, or otherwise from a `defaultdict` input
+    DefaultDict {
+        class: PyObject,
+        default_factory: Option<PyObject>,
+    },
+}
+
+impl OutputType {
+    fn from_schema(schema: &PyDict) -> PyResult<Self> {
+        let py = schema.py();
+        let default_factory: Option<PyObject> = schema.get_as(intern!(py, "default_factory"))?;
+        let output_type = match schema.get_as::<&str>(intern!(py, "output_type"))? {
+            None | Some("dict") => Self::Dict,
+            Some("ordered_dict") => Self::OrderedDict(collections_attr(py, intern!(py, "OrderedDict"))?),
+            Some("defaultdict") => {
+                return Ok(Self::DefaultDict {
+                    class: collections_attr(py, intern!(py, "defaultdict"))?,
+                    default_factory,
+                })
+            }
+            Some(s) => {
+                return py_err!(
+                    "Invalid output_type: `{}`, expected `dict`, `ordered_dict` or `defaultdict`",
+                    s
+                )
+            }
+        };
+        match default_factory {
+            Some(_) => py_err!("`default_factory` can only be used with `output_type='defaultdict'`"),
+            None => Ok(output_type),
+        }
+    }
+
+    /// `output` as this type, `input_dict` is the input when it's a python dict
+    fn convert(&self, py: Python, output: &PyDict, input_dict: Option<&PyDict>) -> PyResult<PyObject> {
+        match self {
+            Self::Dict => Ok(output.into_py(py)),
+            Self::OrderedDict(class) => class.call1(py, (output,)),
+            Self::DefaultDict { class, default_factory } => {
+                let default_factory = match (default_factory, input_dict) {
+                    (Some(default_factory), _) => default_factory.clone_ref(py),
+                    (None, Some(input_dict)) if input_dict.is_instance(class.as_ref(py))? => {
+                        input_dict.getattr(intern!(py, "default_factory"))?.into_py(py)
+                    }
+                    (None, _) => py.None(),
+                };
+                class.call1(py, (default_factory, output))
+            }
+        }
+    }
+}
+
+fn collections_attr(py: Python, name: &PyString) -> PyResult<PyObject> {
+    Ok(py.import(intern!(py, "collections"))?.getattr(name)?.into_py(py))
+}
+
 impl BuildValidator for DictValidator {
     const EXPECTED_TYPE: &'static str = "dict";
 
@@ -48,10 +119,15 @@ impl BuildValidator for DictValidator {
         );
         Ok(Self {
             strict: is_strict(schema, config)?,
+            force_exact_container_path: force_exact_container_path(config)?,
+            output_preserve_subclass: schema.get_as(intern!(py, "output_preserve_subclass"))?.unwrap_or(false),
             key_validator,
             value_validator,
             min_length: schema.get_as(intern!(py, "min_length"))?,
             max_length: schema.get_as(intern!(py, "max_length"))?,
+            required_keys: schema.get_as(intern!(py, "required_keys"))?.unwrap_or_default(),
+            output_type: OutputType::from_schema(schema)?,
+            defaultdict_strict: schema.get_as(intern!(py, "defaultdict_strict"))?.unwrap_or(false),
             name,
         }
         .into())
@@ -67,8 +143,18 @@ impl Validator for DictValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let dict = input.validate_dict(extra.strict.unwrap_or(self.strict))?;
-        match dict {
+        let strict = extra.strict.unwrap_or(self.strict);
+        let dict = input.validate_dict(strict)?;
+        let input_dict = match dict {
+            GenericMapping::PyDict(py_dict) => Some(py_dict),
+            _ => None,
+        };
+        if let (OutputType::DefaultDict { class, .. }, Some(py_dict)) = (&self.output_type, input_dict) {
+            if strict && self.defaultdict_strict && !py_dict.is_instance(class.as_ref(py))? {
+                return Err(ValError::new(ErrorType::DefaultDictType, input));
+            }
+        }
+        let output = match dict.with_subclass_path(self.force_exact_container_path) {
             GenericMapping::PyDict(py_dict) => self.validate_dict(py, input, py_dict, extra, slots, recursion_guard),
             GenericMapping::PyMapping(mapping) => {
                 self.validate_mapping(py, input, mapping, extra, slots, recursion_guard)
@@ -77,9 +163,32 @@ impl Validator for DictValidator {
             GenericMapping::JsonObject(json_object) => {
                 self.validate_json_object(py, input, json_object, extra, slots, recursion_guard)
             }
+        }?;
+        match input_dict {
+            Some(input_dict) if self.output_preserve_subclass && !PyDict::is_exact_type_of(input_dict) => {
+                Ok(preserve_subclass(input_dict, output)?)
+            }
+            _ => Ok(self.output_type.convert(py, output, input_dict)?),
         }
     }
 
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+        self.key_validator.set_strict(strict);
+        self.value_validator.set_strict(strict);
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .strict(self.strict)
+            .accepts(&["dict", "mapping"], &["dict"])
+            .constraint("min_length", self.min_length)?
+            .constraint("max_length", self.max_length)?
+            .child("keys_schema", &self.key_validator)?
+            .child("values_schema", &self.value_validator)?
+            .finish()
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -91,7 +200,7 @@ impl Validator for DictValidator {
 }
 
 macro_rules! build_validate {
-    ($name:ident, $dict_type:ty, $iter:ty) => {
+    ($name:ident, $dict_type:ty, $iter:ty, $check_length_early:literal) => {
         fn $name<'s, 'data>(
             &'s self,
             py: Python<'data>,
@@ -100,15 +209,43 @@ macro_rules! build_validate {
             extra: &Extra,
             slots: &'data [CombinedValidator],
             recursion_guard: &'s mut RecursionGuard,
-        ) -> ValResult<'data, PyObject> {
+        ) -> ValResult<'data, &'data PyDict> {
             let output = PyDict::new(py);
             let mut errors: Vec<ValLineError> = Vec::new();
 
             let key_validator = self.key_validator.as_ref();
             let value_validator = self.value_validator.as_ref();
-            for item_result in <$iter>::new(dict)? {
+            let mut required_seen = vec![false; self.required_keys.len()];
+            // with `allow_partial` only the last value may be incomplete, keys are always complete
+            let partial_key_extra;
+            let key_extra = match extra.is_partial() {
+                true => {
+                    partial_key_extra = extra.partial_item(false);
+                    &partial_key_extra
+                }
+                false => extra,
+            };
+            let mut items = <$iter>::new(dict)?.peekable();
+            while let Some(item_result) = items.next() {
                 let (key, value) = item_result?;
-                let output_key = match key_validator.validate(py, key, extra, slots, recursion_guard) {
+                if !recursion_guard.step() {
+                    errors.push(ValLineError::new_with_loc(
+                        ErrorType::ValidationBudgetExceeded,
+                        value,
+                        key.as_loc_item(),
+                    ));
+                    return Err(ValError::LineErrors(errors));
+                }
+                if !required_seen.is_empty() {
+                    if let Some(index) = self.required_key_index(key) {
+                        required_seen[index] = true;
+                    }
+                }
+                let mark = extra.error_mark();
+                let item_errors_start = errors.len();
+                let key_loc = extra.coercion_loc(|| [key.as_loc_item(), "[key]".into()]);
+                extra.report_raw_input(py, key);
+                let output_key = match key_validator.validate(py, key, key_extra, slots, recursion_guard) {
                     Ok(value) => Some(value),
                     Err(ValError::LineErrors(line_errors)) => {
                         for err in line_errors {
@@ -123,7 +260,18 @@ macro_rules! build_validate {
                     Err(ValError::Omit) => continue,
                     Err(err) => return Err(err),
                 };
-                let output_value = match value_validator.validate(py, value, extra, slots, recursion_guard) {
+                drop(key_loc);
+                let _loc = extra.coercion_loc(|| [key.as_loc_item()]);
+                extra.report_raw_input(py, value);
+                let partial_extra;
+                let value_extra = match extra.is_partial() {
+                    true => {
+                        partial_extra = extra.partial_item(items.peek().is_none());
+                        &partial_extra
+                    }
+                    false => extra,
+                };
+                let output_value = match value_validator.validate(py, value, value_extra, slots, recursion_guard) {
                     Ok(value) => Some(value),
                     Err(ValError::LineErrors(line_errors)) => {
                         for err in line_errors {
@@ -134,14 +282,71 @@ macro_rules! build_validate {
                     Err(ValError::Omit) => continue,
                     Err(err) => return Err(err),
                 };
-                if let (Some(key), Some(value)) = (output_key, output_value) {
-                    output.set_item(key, value)?;
+                let item_errors = errors.len() - item_errors_start;
+                // required keys not seen yet would be missing errors
+                let items_left = || items.peek().is_some() || required_seen.contains(&false);
+                if item_errors > 0 && extra.stop_collecting(mark, item_errors, items_left) {
+                    return Err(ValError::LineErrors(errors));
+                }
+                if let (Some(output_key), Some(value)) = (output_key, output_value) {
+                    let output_len = output.len();
+                    if let Err(err) = output.set_item(&output_key, value) {
+                        if is_unhashable(py, output_key.as_ref(py)) {
+                            // added in reverse order as with key errors above
+                            errors.push(
+                                ValLineError::new_custom_input(ErrorType::Unhashable, output_key.into())
+                                    .with_outer_location("[key]".into())
+                                    .with_outer_location(key.as_loc_item()),
+                            );
+                        } else {
+                            return Err(err.into());
+                        }
+                    } else if output.len() == output_len {
+                        // distinct input keys were validated to the same key, e.g. `'1'` and `1` to `1`
+                        errors.push(
+                            ValLineError::new(ErrorType::DictDuplicateKey, key)
+                                .with_outer_location("[key]".into())
+                                .with_outer_location(key.as_loc_item()),
+                        );
+                    } else if $check_length_early {
+                        // mappings are read lazily and their length isn't known up front, so stop reading once the
+                        // output is too long rather than collecting every item first
+                        if let Some(max_length) = self.max_length {
+                            if output.len() > max_length {
+                                return Err(ValError::new(
+                                    ErrorType::TooLong {
+                                        field_type: "Dictionary".to_string(),
+                                        max_length,
+                                        actual_length: output.len(),
+                                    },
+                                    input,
+                                ));
+                            }
+                        }
+                    }
+                }
+            }
+
+            for (required_key, seen) in self.required_keys.iter().zip(required_seen) {
+                if !seen && !extra.is_partial() {
+                    errors.push(ValLineError::new_with_loc(
+                        ErrorType::Missing,
+                        input,
+                        required_key.as_str(),
+                    ));
                 }
             }
 
             if errors.is_empty() {
-                length_check!(input, "Dictionary", self.min_length, self.max_length, output);
-                Ok(output.into())
+                length_check!(
+                    input,
+                    "Dictionary",
+                    self.min_length,
+                    self.max_length,
+                    output,
+                    extra
+                );
+                Ok(output)
             } else {
                 Err(ValError::LineErrors(errors))
             }
@@ -149,8 +354,29 @@ macro_rules! build_validate {
     };
 }
 
+/// with `output_preserve_subclass`, the validated dict as an instance of the input's dict subclass,
+/// a `defaultdict` keeps its `default_factory`
+fn preserve_subclass(input_dict: &PyDict, output: &PyDict) -> PyResult<PyObject> {
+    let py = input_dict.py();
+    let class = input_dict.get_type();
+    let default_dict = py
+        .import(intern!(py, "collections"))?
+        .getattr(intern!(py, "defaultdict"))?;
+    let output = match input_dict.is_instance(default_dict)? {
+        true => class.call1((input_dict.getattr(intern!(py, "default_factory"))?, output))?,
+        false => class.call1((output,))?,
+    };
+    Ok(output.into_py(py))
+}
+
 impl DictValidator {
-    build_validate!(validate_dict, PyDict, DictGenericIterator);
-    build_validate!(validate_mapping, PyMapping, MappingGenericIterator);
-    build_validate!(validate_json_object, JsonObject, JsonObjectGenericIterator);
+    fn required_key_index<'data>(&self, key: &'data impl Input<'data>) -> Option<usize> {
+        let key_str = key.strict_str().ok()?;
+        let key_str = key_str.as_cow().ok()?;
+        self.required_keys.iter().position(|k| k.as_str() == key_str.as_ref())
+    }
+
+    build_validate!(validate_dict, PyDict, DictGenericIterator, false);
+    build_validate!(validate_mapping, PyMapping, MappingGenericIterator, true);
+    build_validate!(validate_json_object, JsonObject, JsonObjectGenericIterator, false);
 }
@@ -0,0 +1,310 @@
+use pyo3::exceptions::PyTypeError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyLong, PyString, PyType};
+
+use ahash::AHashMap;
+
+use crate::build_tools::{function_name, is_strict, py_err, SchemaDict};
+use crate::errors::{ErrorType, ValError, ValResult};
+use crate::input::Input;
+use crate::questions::Question;
+use crate::recursion_guard::RecursionGuard;
+
+use super::literal::ExpectedRepr;
+use super::{BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
+
+/// how raw values are matched to members, `int` and `str` enums compare values as ints and strings like
+/// `int` and `str` validators, other enums compare by equality as `Enum(value)` does
+#[derive(Debug, Clone, Copy)]
+enum EnumSubType {
+    Int,
+    Str,
+    Plain,
+}
+
+#[derive(Debug, Clone)]
+pub struct EnumValidator {
+    strict: bool,
+    class: Py<PyType>,
+    sub_type: EnumSubType,
+    members: Vec<PyObject>,
+    // indexes into `members` keyed by value
+    int_lookup: AHashMap<i64, usize>,
+    str_lookup: AHashMap<String, usize>,
+    py_lookup: Py<PyDict>,
+    // unhashable values of plain enums which can only be compared one by one, with their indexes into `members`
+    unhashable_values: Vec<(PyObject, usize)>,
+    // with `by_name`, indexes into `members` keyed by name, lower cased with `by_name_case_insensitive`
+    name_lookup: Option<AHashMap<String, usize>>,
+    names_case_insensitive: bool,
+    missing: Option<PyObject>,
+    expected_repr: ExpectedRepr,
+    name: String,
+}
+
+impl BuildValidator for EnumValidator {
+    const EXPECTED_TYPE: &'static str = "enum";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        _build_context: &mut BuildContext<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let class: &PyType = schema.get_as_req(intern!(py, "cls"))?;
+        let members: &PyList = match schema.get_as(intern!(py, "members"))? {
+            Some(members) => members,
+            None => PyList::new(py, class.iter()?.collect::<PyResult<Vec<_>>>()?),
+        };
+        if members.is_empty() {
+            return py_err!("`members` should have length > 0");
+        }
+
+        let sub_type = if class.is_subclass_of::<PyLong>()? {
+            EnumSubType::Int
+        } else if class.is_subclass_of::<PyString>()? {
+            EnumSubType::Str
+        } else {
+            EnumSubType::Plain
+        };
+        let by_name: bool = schema.get_as(intern!(py, "by_name"))?.unwrap_or(false);
+        let names_case_insensitive: bool = schema.get_as(intern!(py, "by_name_case_insensitive"))?.unwrap_or(false);
+        if names_case_insensitive && !by_name {
+            return py_err!("`by_name_case_insensitive` requires `by_name`");
+        }
+        let mut int_lookup = AHashMap::new();
+        let mut str_lookup = AHashMap::new();
+        let py_lookup = PyDict::new(py);
+        let mut unhashable_values = Vec::new();
+        let mut name_lookup: AHashMap<String, usize> = AHashMap::new();
+        let mut repr_args: Vec<String> = Vec::with_capacity(members.len());
+        let mut name_repr_args: Vec<String> = Vec::new();
+        for (index, member) in members.iter().enumerate() {
+            if !member.get_type().is(class) {
+                return py_err!("`members` should all be members of `{}`", class.name()?);
+            }
+            let value = member.getattr(intern!(py, "value"))?;
+            repr_args.push(value.repr()?.extract()?);
+            match sub_type {
+                EnumSubType::Int => {
+                    int_lookup.insert(value.extract::<i64>()?, index);
+                }
+                EnumSubType::Str => {
+                    str_lookup.insert(value.extract::<String>()?, index);
+                }
+                EnumSubType::Plain if value.hash().is_ok() => py_lookup.set_item(value, index)?,
+                EnumSubType::Plain => unhashable_values.push((value.into_py(py), index)),
+            }
+            if by_name {
+                let name = member.getattr(intern!(py, "name"))?;
+                name_repr_args.push(name.repr()?.extract()?);
+                let name: String = name.extract()?;
+                let key = match names_case_insensitive {
+                    true => name.to_lowercase(),
+                    false => name.clone(),
+                };
+                if let Some(other) = name_lookup.insert(key, index) {
+                    let other_name: String = members.get_item(other)?.getattr(intern!(py, "name"))?.extract()?;
+                    return py_err!(
+                        "`by_name_case_insensitive` can't tell `{}` and `{}` apart",
+                        other_name,
+                        name
+                    );
+                }
+            }
+        }
+        // with `by_name`, errors list names after values
+        repr_args.extend(name_repr_args);
+
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            class: class.into(),
+            sub_type,
+            members: members.iter().map(|member| member.into_py(py)).collect(),
+            int_lookup,
+            str_lookup,
+            py_lookup: py_lookup.into(),
+            unhashable_values,
+            name_lookup: by_name.then_some(name_lookup),
+            names_case_insensitive,
+            missing: schema.get_as::<&PyAny>(intern!(py, "missing"))?.map(|f| f.into_py(py)),
+            expected_repr: ExpectedRepr::new(py, &repr_args, " or ", config)?,
+            name: format!("{}[{}]", Self::EXPECTED_TYPE, class.name()?),
+        }
+        .into())
+    }
+}
+
+impl Validator for EnumValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        _slots: &'data [CombinedValidator],
+        _recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let class = self.class.as_ref(py);
+        let strict = extra.strict.unwrap_or(self.strict);
+        if input.input_is_instance(class, 0)? {
+            // members are matched by identity, so members of subclasses aren't accepted
+            let value = input.to_object(py);
+            return match self.find_member(py, value.as_ref(py))? {
+                Some(member) => Ok(member),
+                None => Err(self.enum_error(input)),
+            };
+        }
+        // in strict mode only members are accepted from python, JSON has no enum type so values are allowed
+        if strict && input.is_python() {
+            return Err(self.enum_error(input));
+        }
+
+        let index = match self.sub_type {
+            EnumSubType::Int => match input.validate_int(strict) {
+                Ok(int) => self.int_lookup.get(&int).copied(),
+                Err(_) => None,
+            },
+            EnumSubType::Str => match input.validate_str(strict) {
+                Ok(either_str) => self.str_lookup.get(either_str.as_cow()?.as_ref()).copied(),
+                Err(_) => None,
+            },
+            EnumSubType::Plain => self.find_plain_value(input.to_object(py).as_ref(py))?,
+        };
+        // values are tried before names
+        let index = match index {
+            None => self.find_name(input)?,
+            index => index,
+        };
+        if let Some(index) = index {
+            return Ok(self.members[index].clone_ref(py));
+        }
+
+        if let Some(ref missing) = self.missing {
+            let result = missing.call1(py, (input.to_object(py),))?;
+            let result = result.as_ref(py);
+            if result.is_none() {
+                return Err(self.enum_error(input));
+            }
+            return match self.find_member(py, result)? {
+                Some(member) => Ok(member),
+                None => {
+                    let msg = format!(
+                        "`missing` should return a member of `{}` or None, got {}",
+                        class.name()?,
+                        result.repr()?
+                    );
+                    Err(PyTypeError::new_err(msg).into())
+                }
+            };
+        }
+        Err(self.enum_error(input))
+    }
+
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        let expected: Vec<&PyAny> = self
+            .members
+            .iter()
+            .map(|member| member.as_ref(py).getattr(intern!(py, "value")))
+            .collect::<PyResult<_>>()?;
+        let missing = match self.missing {
+            Some(ref missing) => Some(function_name(missing.as_ref(py))?),
+            None => None,
+        };
+        let description = Description::new(py, Self::EXPECTED_TYPE).strict(self.strict);
+        let description = match self.sub_type {
+            EnumSubType::Int => description.accepts(&["instance", "int", "str", "bytes", "float"], &["instance"]),
+            EnumSubType::Str => description.accepts(&["instance", "str", "bytes", "bytearray"], &["instance"]),
+            // values are compared by equality, so acceptance is decided by `expected`
+            EnumSubType::Plain => description,
+        };
+        description
+            .constraint("expected", Some(expected))?
+            .constraint("by_name", self.name_lookup.is_some().then_some(true))?
+            .constraint("by_name_case_insensitive", self.names_case_insensitive.then_some(true))?
+            .constraint("missing", missing)?
+            .finish()
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn ask(&self, question: &Question) -> bool {
+        matches!(question, Question::ReturnsImmutable)
+    }
+
+    fn complete(&mut self, _build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
+        Ok(())
+    }
+}
+
+impl EnumValidator {
+    /// `value` if it's one of `members`, found by its value and compared by identity
+    fn find_member(&self, py: Python, value: &PyAny) -> PyResult<Option<PyObject>> {
+        if !value.get_type().is(self.class.as_ref(py)) {
+            return Ok(None);
+        }
+        let member_value = value.getattr(intern!(py, "value"))?;
+        let index = match self.sub_type {
+            EnumSubType::Int => member_value
+                .extract::<i64>()
+                .ok()
+                .and_then(|int| self.int_lookup.get(&int).copied()),
+            EnumSubType::Str => member_value
+                .extract::<&str>()
+                .ok()
+                .and_then(|s| self.str_lookup.get(s).copied()),
+            EnumSubType::Plain => self.find_plain_value(member_value)?,
+        };
+        Ok(index
+            .map(|index| &self.members[index])
+            .filter(|member| member.as_ref(py).is(value))
+            .map(|member| member.clone_ref(py)))
+    }
+
+    /// the index of the member of a plain enum with a value equal to `value`
+    fn find_plain_value(&self, value: &PyAny) -> PyResult<Option<usize>> {
+        // unhashable inputs simply aren't found by `get_item`
+        if let Some(index) = self.py_lookup.as_ref(value.py()).get_item(value) {
+            return Ok(Some(index.extract()?));
+        }
+        for (expected, index) in &self.unhashable_values {
+            if value.eq(expected)? {
+                return Ok(Some(*index));
+            }
+        }
+        Ok(None)
+    }
+
+    /// with `by_name`, the index of the member named by a string `input`
+    fn find_name<'data>(&self, input: &'data impl Input<'data>) -> ValResult<'data, Option<usize>> {
+        let name_lookup = match self.name_lookup {
+            Some(ref name_lookup) => name_lookup,
+            None => return Ok(None),
+        };
+        let either_str = match input.validate_str(true) {
+            Ok(either_str) => either_str,
+            Err(_) => return Ok(None),
+        };
+        let name = either_str.as_cow()?;
+        Ok(match self.names_case_insensitive {
+            true => name_lookup.get(&name.to_lowercase()).copied(),
+            false => name_lookup.get(name.as_ref()).copied(),
+        })
+    }
+
+    fn enum_error<'data>(&self, input: &'data impl Input<'data>) -> ValError<'data> {
+        ValError::new(
+            ErrorType::Enum {
+                expected: self.expected_repr.repr().to_string(),
+                expected_full: self.expected_repr.full().map(ToString::to_string),
+            },
+            input,
+        )
+    }
+}
@@ -0,0 +1,55 @@
+//! Limit on the line errors collected in one validation, from `max_errors`.
+//! Collections record each item's errors with `Extra::stop_collecting`, passing the mark from `Extra::error_mark`
+//! taken before validating the item, and stop validating items once the limit is reached. The count is shared by
+//! nested collections, errors already recorded by collections inside the item are only counted once. Since only a
+//! collection which already holds errors stops early, validation still fails, the final `ValidationError` is then
+//! cut to `max_errors` errors. It's only `truncated` if errors were cut or a collection stopped with items left,
+//! so collecting exactly `max_errors` errors from the last items isn't reported as truncated.
+use std::cell::Cell;
+
+#[derive(Debug)]
+pub struct ErrorLimit {
+    max_errors: usize,
+    collected: Cell<usize>,
+    stopped_early: Cell<bool>,
+}
+
+impl ErrorLimit {
+    pub fn new(max_errors: usize) -> Self {
+        Self {
+            max_errors,
+            collected: Cell::new(0),
+            stopped_early: Cell::new(false),
+        }
+    }
+
+    pub fn max_errors(&self) -> usize {
+        self.max_errors
+    }
+
+    /// whether a collection stopped validating before its last item, so there may be errors which weren't collected
+    pub fn stopped_early(&self) -> bool {
+        self.stopped_early.get()
+    }
+
+    pub fn mark(&self) -> usize {
+        self.collected.get()
+    }
+
+    /// record that an item validated since `mark` failed with `item_errors` errors, returns whether the limit
+    /// has been reached, `items_left` is only called then
+    pub fn record(&self, mark: usize, item_errors: usize, items_left: impl FnOnce() -> bool) -> bool {
+        let collected = self.collected.get().max(mark + item_errors);
+        self.collected.set(collected);
+        let reached = collected >= self.max_errors;
+        if reached && items_left() {
+            self.stopped_early.set(true);
+        }
+        reached
+    }
+
+    /// forget errors recorded since `mark` which were discarded, e.g. by union choices which failed
+    pub fn discard(&self, mark: usize) {
+        self.collected.set(mark);
+    }
+}
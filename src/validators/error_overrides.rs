@@ -0,0 +1,117 @@
+use ahash::AHashMap;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::build_tools::{py_err, SchemaDict};
+use crate::errors::{ErrorType, Location, PydanticCustomError, ValError, ValResult};
+use crate::input::Input;
+use crate::questions::Question;
+use crate::recursion_guard::RecursionGuard;
+
+use super::{BuildContext, CombinedValidator, Description, Extra, Validator};
+
+/// Replaces errors raised by a validator itself (not those from items or fields it contains) with custom errors,
+/// set with `error_overrides` on a schema, mapping built-in error types to a custom `type` and `message`
+#[derive(Debug, Clone)]
+pub struct ErrorOverridesValidator {
+    validator: Box<CombinedValidator>,
+    // built-in error type -> (custom error type, message template)
+    overrides: AHashMap<String, (String, String)>,
+}
+
+impl ErrorOverridesValidator {
+    /// wrap `validator` if the schema has `error_overrides`, otherwise return it unchanged
+    pub fn wrap(schema: &PyDict, validator: CombinedValidator) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let overrides_dict: &PyDict = match schema.get_as(intern!(py, "error_overrides"))? {
+            Some(overrides_dict) => overrides_dict,
+            None => return Ok(validator),
+        };
+        let mut overrides = AHashMap::with_capacity(overrides_dict.len());
+        for (key, value) in overrides_dict {
+            let error_type: String = key.extract()?;
+            if !ErrorType::valid_type(py, &error_type) {
+                return py_err!("Invalid error type in error_overrides: '{}'", error_type);
+            }
+            let value: &PyDict = value.downcast()?;
+            overrides.insert(
+                error_type,
+                (
+                    value.get_as_req(intern!(py, "type"))?,
+                    value.get_as_req(intern!(py, "message"))?,
+                ),
+            );
+        }
+        Ok(Self {
+            validator: Box::new(validator),
+            overrides,
+        }
+        .into())
+    }
+
+    fn apply<'data>(&self, py: Python<'data>, result: ValResult<'data, PyObject>) -> ValResult<'data, PyObject> {
+        match result {
+            Err(ValError::LineErrors(mut line_errors)) => {
+                for line_error in line_errors.iter_mut() {
+                    // errors with a location come from items or fields, not this validator
+                    if !matches!(line_error.location, Location::Empty) {
+                        continue;
+                    }
+                    if let Some((error_type, message)) = self.overrides.get(&line_error.error_type.type_string()) {
+                        // the original error's context is kept so it can be used in the message
+                        let context = line_error.error_type.py_dict(py)?;
+                        let custom_error = PydanticCustomError::py_new(
+                            py,
+                            error_type.clone(),
+                            message.clone(),
+                            context.as_ref().map(|c| c.as_ref(py)),
+                        );
+                        line_error.error_type = ErrorType::CustomError { custom_error };
+                    }
+                }
+                Err(ValError::LineErrors(line_errors))
+            }
+            otherwise => otherwise,
+        }
+    }
+}
+
+impl Validator for ErrorOverridesValidator {
+    fn py_gc_traverse(&self, visit: &pyo3::PyVisit<'_>) -> Result<(), pyo3::PyTraverseError> {
+        self.validator.py_gc_traverse(visit)
+    }
+
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        self.apply(py, self.validator.validate(py, input, extra, slots, recursion_guard))
+    }
+
+    fn set_strict(&mut self, strict: bool) {
+        self.validator.set_strict(strict);
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, "error-overrides")
+            .child("schema", &self.validator)?
+            .finish()
+    }
+
+    fn get_name(&self) -> &str {
+        self.validator.get_name()
+    }
+
+    fn ask(&self, question: &Question) -> bool {
+        self.validator.ask(question)
+    }
+
+    fn complete(&mut self, build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
+        self.validator.complete(build_context)
+    }
+}
@@ -2,12 +2,14 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use crate::build_tools::{is_strict, schema_or_config_same, SchemaDict};
+use crate::build_tools::{coerce_hook_enabled, is_strict, py_err, schema_or_config_same, SchemaDict};
 use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::Input;
+use crate::input::{with_coerce_hook, Input};
+use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
-use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::int::MultipleOfMode;
+use super::{BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
 
 pub struct FloatBuilder;
 
@@ -29,7 +31,9 @@ impl BuildValidator for FloatBuilder {
         } else {
             Ok(FloatValidator {
                 strict: is_strict(schema, config)?,
+                coerce_hook: coerce_hook_enabled(config)?,
                 allow_inf_nan: schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(true),
+                number_format: NumberFormat::from_schema(schema)?,
             }
             .into())
         }
@@ -39,7 +43,9 @@ impl BuildValidator for FloatBuilder {
 #[derive(Debug, Clone)]
 pub struct FloatValidator {
     strict: bool,
+    coerce_hook: bool,
     allow_inf_nan: bool,
+    number_format: Option<NumberFormat>,
 }
 
 impl BuildValidator for FloatValidator {
@@ -53,12 +59,20 @@ impl BuildValidator for FloatValidator {
         let py = schema.py();
         Ok(Self {
             strict: is_strict(schema, config)?,
+            coerce_hook: coerce_hook_enabled(config)?,
             allow_inf_nan: schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(true),
+            number_format: NumberFormat::from_schema(schema)?,
         }
         .into())
     }
 }
 
+impl FloatValidator {
+    pub fn allow_inf_nan(&self) -> bool {
+        self.allow_inf_nan
+    }
+}
+
 impl Validator for FloatValidator {
     fn validate<'s, 'data>(
         &'s self,
@@ -68,17 +82,44 @@ impl Validator for FloatValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let float = input.validate_float(extra.strict.unwrap_or(self.strict))?;
+        let strict = extra.strict.unwrap_or(self.strict);
+        let coerce_hook = self.coerce_hook && !strict;
+        let float = with_coerce_hook(
+            input,
+            "float",
+            coerce_hook,
+            validate_float(input, strict, &self.number_format),
+            |v| v.lax_float(),
+        )?;
+        extra.report_coercion(py, input, "float", || input.strict_float().is_ok());
         if !self.allow_inf_nan && !float.is_finite() {
             return Err(ValError::new(ErrorType::FiniteNumber, input));
         }
-        Ok(float.into_py(py))
+        let output = float.into_py(py);
+        extra.report_output_type(input, output.as_ref(py));
+        Ok(output)
+    }
+
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .strict(self.strict)
+            .accepts(&["float", "int", "str", "bytes"], &["float", "int"])
+            .constraint("allow_inf_nan", (!self.allow_inf_nan).then_some(false))?
+            .finish()
     }
 
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
 
+    fn ask(&self, question: &Question) -> bool {
+        matches!(question, Question::ReturnsImmutable)
+    }
+
     fn complete(&mut self, _build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
         Ok(())
     }
@@ -87,8 +128,11 @@ impl Validator for FloatValidator {
 #[derive(Debug, Clone)]
 pub struct ConstrainedFloatValidator {
     strict: bool,
+    coerce_hook: bool,
     allow_inf_nan: bool,
+    number_format: Option<NumberFormat>,
     multiple_of: Option<f64>,
+    multiple_of_mode: MultipleOfMode,
     le: Option<f64>,
     lt: Option<f64>,
     ge: Option<f64>,
@@ -104,7 +148,16 @@ impl Validator for ConstrainedFloatValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let float = input.validate_float(extra.strict.unwrap_or(self.strict))?;
+        let strict = extra.strict.unwrap_or(self.strict);
+        let coerce_hook = self.coerce_hook && !strict;
+        let mut float = with_coerce_hook(
+            input,
+            "float",
+            coerce_hook,
+            validate_float(input, strict, &self.number_format),
+            |v| v.lax_float(),
+        )?;
+        extra.report_coercion(py, input, "float", || input.strict_float().is_ok());
         if !self.allow_inf_nan && !float.is_finite() {
             return Err(ValError::new(ErrorType::FiniteNumber, input));
         }
@@ -112,12 +165,20 @@ impl Validator for ConstrainedFloatValidator {
             let rem = float % multiple_of;
             let threshold = float / 1e9;
             if rem.abs() > threshold && (rem - multiple_of).abs() > threshold {
-                return Err(ValError::new(
-                    ErrorType::MultipleOf {
-                        multiple_of: multiple_of.into(),
-                    },
-                    input,
-                ));
+                let step = multiple_of.abs();
+                float = match self.multiple_of_mode {
+                    MultipleOfMode::Error => {
+                        return Err(ValError::new(
+                            ErrorType::MultipleOf {
+                                multiple_of: multiple_of.into(),
+                            },
+                            input,
+                        ))
+                    }
+                    MultipleOfMode::Round => (float / step).round() * step,
+                    MultipleOfMode::Floor => (float / step).floor() * step,
+                    MultipleOfMode::Ceil => (float / step).ceil() * step,
+                };
             }
         }
         if let Some(le) = self.le {
@@ -140,12 +201,35 @@ impl Validator for ConstrainedFloatValidator {
                 return Err(ValError::new(ErrorType::GreaterThan { gt: gt.into() }, input));
             }
         }
-        Ok(float.into_py(py))
+        let output = float.into_py(py);
+        extra.report_output_type(input, output.as_ref(py));
+        Ok(output)
     }
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .strict(self.strict)
+            .accepts(&["float", "int", "str", "bytes"], &["float", "int"])
+            .constraint("allow_inf_nan", (!self.allow_inf_nan).then_some(false))?
+            .constraint("multiple_of", self.multiple_of)?
+            .constraint("le", self.le)?
+            .constraint("lt", self.lt)?
+            .constraint("ge", self.ge)?
+            .constraint("gt", self.gt)?
+            .finish()
+    }
+
     fn get_name(&self) -> &str {
         "constrained-float"
     }
 
+    fn ask(&self, question: &Question) -> bool {
+        matches!(question, Question::ReturnsImmutable)
+    }
+
     fn complete(&mut self, _build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
         Ok(())
     }
@@ -161,8 +245,11 @@ impl BuildValidator for ConstrainedFloatValidator {
         let py = schema.py();
         Ok(Self {
             strict: is_strict(schema, config)?,
+            coerce_hook: coerce_hook_enabled(config)?,
             allow_inf_nan: schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(true),
+            number_format: NumberFormat::from_schema(schema)?,
             multiple_of: schema.get_as(intern!(py, "multiple_of"))?,
+            multiple_of_mode: MultipleOfMode::from_schema(schema)?,
             le: schema.get_as(intern!(py, "le"))?,
             lt: schema.get_as(intern!(py, "lt"))?,
             ge: schema.get_as(intern!(py, "ge"))?,
@@ -171,3 +258,95 @@ impl BuildValidator for ConstrainedFloatValidator {
         .into())
     }
 }
+
+/// in lax mode strings are parsed with `number_format` if it's set, JSON numbers are unaffected
+fn validate_float<'data>(
+    input: &'data impl Input<'data>,
+    strict: bool,
+    number_format: &Option<NumberFormat>,
+) -> ValResult<'data, f64> {
+    if let (Some(number_format), false) = (number_format, strict) {
+        if let Ok(either_str) = input.strict_str() {
+            return number_format
+                .normalize(&either_str.as_cow()?)
+                .and_then(|s| s.parse::<f64>().ok())
+                .ok_or_else(|| ValError::new(ErrorType::FloatParsing, input));
+        }
+    }
+    input.validate_float(strict)
+}
+
+/// The separators used to parse numbers from strings like `'1.234,56'`, shared with the fraction validator
+#[derive(Debug, Clone)]
+pub struct NumberFormat {
+    thousands_sep: String,
+    decimal_sep: String,
+}
+
+impl NumberFormat {
+    pub fn from_schema(schema: &PyDict) -> PyResult<Option<Self>> {
+        let py = schema.py();
+        let number_format: &PyDict = match schema.get_as(intern!(py, "number_format"))? {
+            Some(number_format) => number_format,
+            None => return Ok(None),
+        };
+        let thousands_sep: String = number_format
+            .get_as(intern!(py, "thousands_sep"))?
+            .unwrap_or_else(|| ",".to_string());
+        let decimal_sep: String = number_format
+            .get_as(intern!(py, "decimal_sep"))?
+            .unwrap_or_else(|| ".".to_string());
+        if thousands_sep.is_empty() || decimal_sep.is_empty() {
+            return py_err!("`number_format` separators cannot be empty");
+        }
+        if thousands_sep == decimal_sep {
+            return py_err!(
+                "`number_format` thousands_sep and decimal_sep must differ, both are `{}`",
+                thousands_sep
+            );
+        }
+        Ok(Some(Self {
+            thousands_sep,
+            decimal_sep,
+        }))
+    }
+
+    /// `s` with thousands separators removed and the decimal separator replaced by `.`, or `None` if a separator
+    /// is in an invalid position: thousands separators may only split the integer part into groups of three digits
+    /// after the first group of one to three, and there can be at most one decimal separator
+    pub fn normalize(&self, s: &str) -> Option<String> {
+        let s = s.trim();
+        let (sign, unsigned) = match s.as_bytes().first() {
+            Some(b'-' | b'+') => s.split_at(1),
+            _ => ("", s),
+        };
+        let (int_part, frac_part) = match unsigned.split_once(self.decimal_sep.as_str()) {
+            Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+            None => (unsigned, None),
+        };
+        let mut normalized = String::with_capacity(s.len());
+        normalized.push_str(sign);
+        if int_part.contains(self.thousands_sep.as_str()) {
+            for (index, group) in int_part.split(self.thousands_sep.as_str()).enumerate() {
+                let valid_len = match index {
+                    0 => (1..=3).contains(&group.len()),
+                    _ => group.len() == 3,
+                };
+                if !valid_len || !group.bytes().all(|b| b.is_ascii_digit()) {
+                    return None;
+                }
+                normalized.push_str(group);
+            }
+        } else {
+            normalized.push_str(int_part);
+        }
+        if let Some(frac_part) = frac_part {
+            if frac_part.contains(self.decimal_sep.as_str()) || frac_part.contains(self.thousands_sep.as_str()) {
+                return None;
+            }
+            normalized.push('.');
+            normalized.push_str(frac_part);
+        }
+        Some(normalized)
+    }
+}
@@ -0,0 +1,269 @@
+use std::borrow::Cow;
+
+use pyo3::exceptions::{PyValueError, PyZeroDivisionError};
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyDict, PyFloat, PyLong, PyString, PyTuple};
+
+use crate::build_tools::{is_strict, py_err, SchemaDict};
+use crate::errors::{ErrorType, Number, ValError, ValResult};
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::float::NumberFormat;
+use super::{BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
+
+/// the most digits a string or decimal input may have, including digits added by its exponent, this matches
+/// python's default `sys.get_int_max_str_digits()` and stops inputs like `'1e999999999'` creating enormous integers
+const MAX_DIGITS: usize = 4300;
+
+#[derive(Debug, Clone, Copy)]
+enum FloatConversion {
+    // the float's exact binary value, e.g. `0.1` is `Fraction(3602879701896397, 36028797018963968)`
+    Exact,
+    // the float's shortest repr, e.g. `0.1` is `Fraction(1, 10)`
+    Repr,
+}
+
+#[derive(Debug, Clone)]
+pub struct FractionValidator {
+    strict: bool,
+    fraction_class: PyObject,
+    decimal_class: PyObject,
+    float_conversion: FloatConversion,
+    number_format: Option<NumberFormat>,
+    // applied to fractions converted from floats
+    limit_denominator: Option<u64>,
+    le: Option<PyObject>,
+    lt: Option<PyObject>,
+    ge: Option<PyObject>,
+    gt: Option<PyObject>,
+}
+
+impl BuildValidator for FractionValidator {
+    const EXPECTED_TYPE: &'static str = "fraction";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        _build_context: &mut BuildContext<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let fraction_class = py.import(intern!(py, "fractions"))?.getattr(intern!(py, "Fraction"))?;
+        // bounds are converted to fractions so they're compared exactly
+        let bound = |key: &PyString| -> PyResult<Option<PyObject>> {
+            match schema.get_item(key) {
+                Some(value) => Ok(Some(fraction_class.call1((value,))?.into_py(py))),
+                None => Ok(None),
+            }
+        };
+        let limit_denominator: Option<u64> = schema.get_as(intern!(py, "limit_denominator"))?;
+        if limit_denominator == Some(0) {
+            return py_err!("`limit_denominator` should be greater than 0");
+        }
+        let float_conversion = match schema.get_as::<&str>(intern!(py, "float_conversion"))? {
+            None | Some("exact") => FloatConversion::Exact,
+            Some("repr") => FloatConversion::Repr,
+            Some(s) => return py_err!("Invalid float_conversion: `{}`, expected `exact` or `repr`", s),
+        };
+
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            fraction_class: fraction_class.into_py(py),
+            decimal_class: py
+                .import(intern!(py, "decimal"))?
+                .getattr(intern!(py, "Decimal"))?
+                .into_py(py),
+            float_conversion,
+            number_format: NumberFormat::from_schema(schema)?,
+            limit_denominator,
+            le: bound(intern!(py, "le"))?,
+            lt: bound(intern!(py, "lt"))?,
+            ge: bound(intern!(py, "ge"))?,
+            gt: bound(intern!(py, "gt"))?,
+        }
+        .into())
+    }
+}
+
+impl Validator for FractionValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        _slots: &'data [CombinedValidator],
+        _recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let fraction_object = self.get_fraction(py, input, extra.strict.unwrap_or(self.strict))?;
+        let fraction = fraction_object.as_ref(py);
+
+        if let Some(ref le) = self.le {
+            if fraction.gt(le)? {
+                let le = fraction_number(le.as_ref(py))?;
+                return Err(ValError::new(ErrorType::LessThanEqual { le }, input));
+            }
+        }
+        if let Some(ref lt) = self.lt {
+            if fraction.ge(lt)? {
+                let lt = fraction_number(lt.as_ref(py))?;
+                return Err(ValError::new(ErrorType::LessThan { lt }, input));
+            }
+        }
+        if let Some(ref ge) = self.ge {
+            if fraction.lt(ge)? {
+                let ge = fraction_number(ge.as_ref(py))?;
+                return Err(ValError::new(ErrorType::GreaterThanEqual { ge }, input));
+            }
+        }
+        if let Some(ref gt) = self.gt {
+            if fraction.le(gt)? {
+                let gt = fraction_number(gt.as_ref(py))?;
+                return Err(ValError::new(ErrorType::GreaterThan { gt }, input));
+            }
+        }
+        Ok(fraction_object)
+    }
+
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .strict(self.strict)
+            .accepts(&["fraction", "int", "float", "decimal", "str", "bytes"], &["fraction"])
+            .constraint("le", self.le.as_ref())?
+            .constraint("lt", self.lt.as_ref())?
+            .constraint("ge", self.ge.as_ref())?
+            .constraint("gt", self.gt.as_ref())?
+            .constraint("limit_denominator", self.limit_denominator)?
+            .finish()
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+
+    fn complete(&mut self, _build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
+        Ok(())
+    }
+}
+
+impl FractionValidator {
+    fn get_fraction<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        strict: bool,
+    ) -> ValResult<'data, PyObject> {
+        let fraction_class = self.fraction_class.as_ref(py);
+        if input.input_is_instance(fraction_class, 0)? {
+            return Ok(input.to_object(py));
+        }
+        // in strict mode only fractions are accepted from python, JSON has no fraction type so strings are allowed
+        if strict && input.is_python() {
+            return Err(ValError::new(ErrorType::FractionType, input));
+        }
+        if let Ok(either_str) = input.validate_str(strict) {
+            return self.parse_str(py, either_str.as_cow()?.as_ref(), input);
+        }
+        if strict {
+            return Err(ValError::new(ErrorType::FractionType, input));
+        }
+
+        let value = input.to_object(py).into_ref(py);
+        if value.is_instance_of::<PyBool>()? {
+            Err(ValError::new(ErrorType::FractionType, input))
+        } else if value.is_instance_of::<PyLong>()? {
+            Ok(fraction_class.call1((value,))?.into_py(py))
+        } else if let Ok(float) = value.downcast::<PyFloat>() {
+            if !float.value().is_finite() {
+                return Err(ValError::new(ErrorType::FiniteNumber, input));
+            }
+            let fraction = match self.float_conversion {
+                FloatConversion::Exact => fraction_class.call1((float,))?,
+                FloatConversion::Repr => fraction_class.call1((float.repr()?,))?,
+            };
+            match self.limit_denominator {
+                Some(max_denominator) => Ok(fraction
+                    .call_method1(intern!(py, "limit_denominator"), (max_denominator,))?
+                    .into_py(py)),
+                None => Ok(fraction.into_py(py)),
+            }
+        } else if value.is_instance(self.decimal_class.as_ref(py))? {
+            if !value.call_method0(intern!(py, "is_finite"))?.is_true()? {
+                return Err(ValError::new(ErrorType::FiniteNumber, input));
+            }
+            let (_, digits, exponent): (&PyAny, &PyTuple, i64) =
+                value.call_method0(intern!(py, "as_tuple"))?.extract()?;
+            if digits.len() > MAX_DIGITS || exponent.unsigned_abs() > MAX_DIGITS as u64 {
+                return Err(too_many_digits(input));
+            }
+            Ok(fraction_class.call1((value,))?.into_py(py))
+        } else {
+            Err(ValError::new(ErrorType::FractionType, input))
+        }
+    }
+
+    fn parse_str<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        s: &str,
+        input: &'data impl Input<'data>,
+    ) -> ValResult<'data, PyObject> {
+        let normalized = match self.number_format {
+            Some(ref number_format) => match number_format.normalize(s) {
+                Some(normalized) => Cow::Owned(normalized),
+                None => return Err(ValError::new(ErrorType::FractionParsing, input)),
+            },
+            None => Cow::Borrowed(s),
+        };
+        let s = normalized.as_ref();
+        if too_long_str(s.trim()) {
+            return Err(too_many_digits(input));
+        }
+        match self.fraction_class.as_ref(py).call1((s,)) {
+            Ok(fraction) => Ok(fraction.into_py(py)),
+            Err(err) if err.is_instance_of::<PyZeroDivisionError>(py) => {
+                Err(ValError::new(ErrorType::FractionZeroDenominator, input))
+            }
+            Err(err) if err.is_instance_of::<PyValueError>(py) => Err(ValError::new(ErrorType::FractionParsing, input)),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+/// whether a string has more than `MAX_DIGITS` digits, or an exponent which would add more than that
+fn too_long_str(s: &str) -> bool {
+    if s.bytes().filter(u8::is_ascii_digit).count() > MAX_DIGITS {
+        return true;
+    }
+    match s.rfind(['e', 'E']) {
+        Some(index) => {
+            let exponent = s[index + 1..].replace('_', "");
+            let digits = exponent.trim_start_matches(['+', '-']);
+            match digits.parse::<u64>() {
+                Ok(exponent) => exponent > MAX_DIGITS as u64,
+                // an exponent too big for a u64 is certainly too big, anything else is a parsing error
+                Err(_) => !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()),
+            }
+        }
+        None => false,
+    }
+}
+
+fn too_many_digits<'data>(input: &'data impl Input<'data>) -> ValError<'data> {
+    ValError::new(ErrorType::FractionTooManyDigits { max_digits: MAX_DIGITS }, input)
+}
+
+/// bounds in error context are ints if they're whole numbers, otherwise strings like `'3/4'`
+fn fraction_number(fraction: &PyAny) -> PyResult<Number> {
+    let py = fraction.py();
+    if fraction.getattr(intern!(py, "denominator"))?.extract::<i64>()? == 1 {
+        if let Ok(int) = fraction.getattr(intern!(py, "numerator"))?.extract::<i64>() {
+            return Ok(Number::Int(int));
+        }
+    }
+    Ok(Number::String(fraction.str()?.to_string()))
+}
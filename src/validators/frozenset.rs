@@ -1,5 +1,5 @@
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyFrozenSet};
+use pyo3::types::{PyDict, PyFrozenSet, PySet};
 
 use crate::build_tools::SchemaDict;
 use crate::errors::ValResult;
@@ -7,8 +7,8 @@ use crate::input::{GenericCollection, Input};
 use crate::recursion_guard::RecursionGuard;
 
 use super::list::{get_items_schema, length_check};
-use super::set::set_build;
-use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::set::{set_build, validate_to_set};
+use super::{BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub struct FrozenSetValidator {
@@ -17,6 +17,7 @@ pub struct FrozenSetValidator {
     min_length: Option<usize>,
     max_length: Option<usize>,
     generator_max_length: Option<usize>,
+    fail_fast: bool,
     name: String,
 }
 
@@ -35,31 +36,62 @@ impl Validator for FrozenSetValidator {
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
         let seq = input.validate_frozenset(extra.strict.unwrap_or(self.strict))?;
-
-        let f_set = match self.item_validator {
-            Some(ref v) => PyFrozenSet::new(
-                py,
-                &seq.validate_to_vec(
+        let f_set = match (seq, &self.item_validator) {
+            (GenericCollection::FrozenSet(f_set), None) => f_set,
+            (seq, item_validator) => {
+                let set = PySet::empty(py)?;
+                validate_to_set(
                     py,
                     input,
-                    self.max_length,
+                    &seq,
+                    set,
                     "Frozenset",
+                    self.max_length,
                     self.generator_max_length,
-                    v,
+                    self.fail_fast,
+                    item_validator.as_deref(),
                     extra,
                     slots,
                     recursion_guard,
-                )?,
-            )?,
-            None => match seq {
-                GenericCollection::FrozenSet(f_set) => f_set,
-                _ => PyFrozenSet::new(py, &seq.to_vec(py, input, "Frozenset", self.generator_max_length)?)?,
-            },
+                )?;
+                PyFrozenSet::new(py, set)?
+            }
         };
-        length_check!(input, "Frozenset", self.min_length, self.max_length, f_set);
+        length_check!(input, "Frozenset", self.min_length, self.max_length, f_set, extra);
         Ok(f_set.into_py(py))
     }
 
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+        if let Some(ref mut v) = self.item_validator {
+            v.set_strict(strict);
+        }
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .strict(self.strict)
+            .accepts(
+                &[
+                    "frozenset",
+                    "set",
+                    "list",
+                    "tuple",
+                    "dict_keys",
+                    "dict_values",
+                    "dict_items",
+                    "iterator",
+                    "deque",
+                    "range",
+                ],
+                &["frozenset"],
+            )
+            .constraint("min_length", self.min_length)?
+            .constraint("max_length", self.max_length)?
+            .opt_child("items_schema", self.item_validator.as_deref())?
+            .finish()
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -1,18 +1,20 @@
 use pyo3::exceptions::{PyAssertionError, PyAttributeError, PyRuntimeError, PyTypeError, PyValueError};
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyAny, PyDict, PyString};
+use pyo3::types::{PyAny, PyDict, PyString, PyType};
 
 use crate::build_tools::{function_name, py_err, SchemaDict};
 use crate::errors::{
-    ErrorType, LocItem, PydanticCustomError, PydanticKnownError, PydanticOmit, ValError, ValResult, ValidationError,
+    ErrorType, LocItem, Location, PydanticCustomError, PydanticKnownError, PydanticOmit, ValError, ValResult,
+    ValidationError,
 };
 use crate::input::Input;
 use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
 use super::generator::InternalValidator;
-use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::model::reset_model_attrs;
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
 
 fn destructure_function_schema(schema: &PyDict) -> PyResult<(bool, bool, &PyAny)> {
     let func_dict: &PyDict = schema.get_as_req(intern!(schema.py(), "function"))?;
@@ -28,7 +30,7 @@ fn destructure_function_schema(schema: &PyDict) -> PyResult<(bool, bool, &PyAny)
 }
 
 macro_rules! impl_build {
-    ($impl_name:ident, $name:literal) => {
+    ($impl_name:ident, $name:literal $(, $extra_field:ident: $extra_build:path)*) => {
         impl BuildValidator for $impl_name {
             const EXPECTED_TYPE: &'static str = $name;
             fn build(
@@ -55,6 +57,7 @@ macro_rules! impl_build {
                     name,
                     is_field_validator,
                     info_arg,
+                    $($extra_field: $extra_build(schema)?,)*
                 }
                 .into())
             }
@@ -94,12 +97,27 @@ macro_rules! impl_validator {
                 self._validate(validate, py, obj, extra)
             }
 
+            fn set_strict(&mut self, strict: bool) {
+                self.validator.set_strict(strict);
+            }
+
+            fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+                Description::new(py, Self::EXPECTED_TYPE)
+                    .opaque()
+                    .child("schema", &self.validator)?
+                    .finish()
+            }
+
             fn get_name(&self) -> &str {
                 &self.name
             }
 
             fn ask(&self, question: &Question) -> bool {
-                self.validator.ask(question)
+                match question {
+                    // the function might not give the same result each time, e.g. if it uses `info.context`
+                    Question::ReturnsImmutable => false,
+                    _ => self.validator.ask(question),
+                }
             }
 
             fn complete(&mut self, build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
@@ -109,6 +127,59 @@ macro_rules! impl_validator {
     };
 }
 
+/// How the locations of errors raised by the inner validator of a before validator are presented
+#[derive(Debug, Clone)]
+enum LocMode {
+    // errors keep the location within the value returned by the function, the default
+    Inner,
+    // errors are collapsed onto the location of the before validator itself
+    Outer,
+    // the function receives the inner location tuple and returns a replacement
+    Function(PyObject),
+}
+
+impl LocMode {
+    fn from_schema(schema: &PyDict) -> PyResult<Self> {
+        let py = schema.py();
+        match schema.get_item(intern!(py, "loc_mode")) {
+            None => Ok(Self::Inner),
+            Some(loc_mode) => match loc_mode.extract::<&str>() {
+                Ok("inner") => Ok(Self::Inner),
+                Ok("outer") => Ok(Self::Outer),
+                Ok(s) => py_err!("Invalid loc_mode: `{}`, expected `inner`, `outer` or a callable", s),
+                Err(_) => Ok(Self::Function(loc_mode.into_py(py))),
+            },
+        }
+    }
+
+    fn apply<'a>(&self, py: Python, error: ValError<'a>) -> ValError<'a> {
+        match (self, error) {
+            (Self::Inner, error) => error,
+            (Self::Outer, ValError::LineErrors(mut line_errors)) => {
+                for line_error in line_errors.iter_mut() {
+                    line_error.location = Location::Empty;
+                }
+                ValError::LineErrors(line_errors)
+            }
+            (Self::Function(function), ValError::LineErrors(mut line_errors)) => {
+                for line_error in line_errors.iter_mut() {
+                    match Self::call_function(py, function, &line_error.location) {
+                        Ok(location) => line_error.location = location,
+                        Err(err) => return ValError::InternalErr(err),
+                    }
+                }
+                ValError::LineErrors(line_errors)
+            }
+            (_, error) => error,
+        }
+    }
+
+    fn call_function(py: Python, function: &PyObject, location: &Location) -> PyResult<Location> {
+        let new_loc = function.call1(py, (location.to_object(py),))?;
+        Location::try_from(Some(new_loc.as_ref(py)))
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct FunctionBeforeValidator {
     validator: Box<CombinedValidator>,
@@ -117,9 +188,10 @@ pub struct FunctionBeforeValidator {
     name: String,
     is_field_validator: bool,
     info_arg: bool,
+    loc_mode: LocMode,
 }
 
-impl_build!(FunctionBeforeValidator, "function-before");
+impl_build!(FunctionBeforeValidator, "function-before", loc_mode: LocMode::from_schema);
 
 impl FunctionBeforeValidator {
     fn _validate<'s, 'data>(
@@ -131,12 +203,12 @@ impl FunctionBeforeValidator {
     ) -> ValResult<'data, PyObject> {
         let r = if self.info_arg {
             let info = ValidationInfo::new(py, extra, &self.config, self.is_field_validator)?;
-            self.func.call1(py, (input.to_object(py), info))
+            info.call(py, extra, |info| self.func.call1(py, (input.to_object(py), info)))
         } else {
             self.func.call1(py, (input.to_object(py),))
         };
         let value = r.map_err(|e| convert_err(py, e, input))?;
-        call(value.into_ref(py), extra)
+        call(value.into_ref(py), extra).map_err(|e| self.loc_mode.apply(py, e))
     }
 }
 
@@ -165,7 +237,7 @@ impl FunctionAfterValidator {
         let v = call(input, extra)?;
         let r = if self.info_arg {
             let info = ValidationInfo::new(py, extra, &self.config, self.is_field_validator)?;
-            self.func.call1(py, (v.to_object(py), info))
+            info.call(py, extra, |info| self.func.call1(py, (v.to_object(py), info)))
         } else {
             self.func.call1(py, (v.to_object(py),))
         };
@@ -219,13 +291,17 @@ impl Validator for FunctionPlainValidator {
     ) -> ValResult<'data, PyObject> {
         let r = if self.info_arg {
             let info = ValidationInfo::new(py, extra, &self.config, self.is_field_validator)?;
-            self.func.call1(py, (input.to_object(py), info))
+            info.call(py, extra, |info| self.func.call1(py, (input.to_object(py), info)))
         } else {
             self.func.call1(py, (input.to_object(py),))
         };
         r.map_err(|e| convert_err(py, e, input))
     }
 
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE).opaque().finish()
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -243,9 +319,20 @@ pub struct FunctionWrapValidator {
     name: String,
     is_field_validator: bool,
     info_arg: bool,
+    model_class: Option<Py<PyType>>,
 }
 
-impl_build!(FunctionWrapValidator, "function-wrap");
+impl_build!(FunctionWrapValidator, "function-wrap", model_class: wrapped_model_class);
+
+/// when the wrapped schema is a model, its class is made available as `info.model_class`
+fn wrapped_model_class(schema: &PyDict) -> PyResult<Option<Py<PyType>>> {
+    let py = schema.py();
+    let sub_schema: &PyDict = schema.get_as_req(intern!(py, "schema"))?;
+    match sub_schema.get_as::<&str>(intern!(py, "type"))? {
+        Some("model") => Ok(Some(sub_schema.get_as_req::<&PyType>(intern!(py, "cls"))?.into())),
+        _ => Ok(None),
+    }
+}
 
 impl FunctionWrapValidator {
     fn _validate<'s, 'data>(
@@ -256,8 +343,11 @@ impl FunctionWrapValidator {
         extra: &Extra,
     ) -> ValResult<'data, PyObject> {
         let r = if self.info_arg {
-            let info = ValidationInfo::new(py, extra, &self.config, self.is_field_validator)?;
-            self.func.call1(py, (input.to_object(py), handler, info))
+            let mut info = ValidationInfo::new(py, extra, &self.config, self.is_field_validator)?;
+            info.model_class = self.model_class.as_ref().map(|c| c.clone_ref(py).into_py(py));
+            info.call(py, extra, |info| {
+                self.func.call1(py, (input.to_object(py), handler, info))
+            })
         } else {
             self.func.call1(py, (input.to_object(py), handler))
         };
@@ -276,13 +366,35 @@ impl Validator for FunctionWrapValidator {
     ) -> ValResult<'data, PyObject> {
         let handler = ValidatorCallable {
             validator: InternalValidator::new(py, "ValidatorCallable", &self.validator, slots, extra, recursion_guard),
+            last_output: None,
         };
-        self._validate(
-            Py::new(py, handler)?.into_ref(py),
-            py,
-            input.to_object(py).into_ref(py),
-            extra,
-        )
+        let handler: &PyCell<ValidatorCallable> = PyCell::new(py, handler)?;
+        let result = self._validate(handler, py, input.to_object(py).into_ref(py), extra);
+        let model_class = match self.model_class {
+            Some(ref model_class) => model_class.as_ref(py),
+            None => return result,
+        };
+        // the handler's output is dropped so the wrap function can't reach it via the handler once we've failed
+        let handler_output = handler.borrow_mut().last_output.take();
+        match (result, extra.self_instance) {
+            (Err(err), Some(self_instance)) if handler_output.is_some() => {
+                // the handler already set attributes on the instance being initialised, undo that
+                reset_model_attrs(py, self_instance)?;
+                Err(err)
+            }
+            (Ok(output), None) => {
+                let output = output.into_ref(py);
+                let from_handler = matches!(handler_output, Some(ref h) if h.is(output));
+                if !from_handler && output.is_instance(model_class)? {
+                    // an instance returned by the wrap function itself, the model validator decides
+                    // whether to revalidate it based on `revalidate_instances`
+                    self.validator.validate(py, output, extra, slots, recursion_guard)
+                } else {
+                    Ok(output.into_py(py))
+                }
+            }
+            (result, _) => result,
+        }
     }
 
     fn validate_assignment<'s, 'data: 's>(
@@ -303,12 +415,26 @@ impl Validator for FunctionWrapValidator {
         self._validate(Py::new(py, handler)?.into_ref(py), py, obj, extra)
     }
 
+    fn set_strict(&mut self, strict: bool) {
+        self.validator.set_strict(strict);
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .opaque()
+            .child("schema", &self.validator)?
+            .finish()
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
 
     fn ask(&self, question: &Question) -> bool {
-        self.validator.ask(question)
+        match question {
+            Question::ReturnsImmutable => false,
+            _ => self.validator.ask(question),
+        }
     }
 
     fn complete(&mut self, build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
@@ -320,6 +446,7 @@ impl Validator for FunctionWrapValidator {
 #[derive(Debug, Clone)]
 struct ValidatorCallable {
     validator: InternalValidator,
+    last_output: Option<PyObject>,
 }
 
 #[pymethods]
@@ -332,7 +459,9 @@ impl ValidatorCallable {
             },
             None => None,
         };
-        self.validator.validate(py, input_value, outer_location)
+        let output = self.validator.validate(py, input_value, outer_location)?;
+        self.last_output = Some(output.clone_ref(py));
+        Ok(output)
     }
 
     fn __repr__(&self) -> String {
@@ -428,6 +557,9 @@ pub struct ValidationInfo {
     context: Option<PyObject>,
     data: Option<Py<PyDict>>,
     field_name: Option<String>,
+    model_class: Option<PyObject>,
+    // `(code, message)` of warnings emitted with `warn()`, `None` unless warnings are being recorded
+    warnings: Option<Vec<(String, String)>>,
 }
 
 impl ValidationInfo {
@@ -440,6 +572,8 @@ impl ValidationInfo {
                         context: extra.context.map(|v| v.into()),
                         field_name: Some(field_name.to_string()),
                         data: extra.data.map(|v| v.into()),
+                        model_class: None,
+                        warnings: extra.reports_warnings().then(Vec::new),
                     }
                 ),
                 _ => Err(PyRuntimeError::new_err("This validator expected to be run inside the context of a model field but no model field was found")),
@@ -448,15 +582,41 @@ impl ValidationInfo {
             Ok(Self {
                 config: config.clone_ref(py),
                 context: extra.context.map(|v| v.into()),
-                field_name: None,
+                field_name: extra.field_name.map(ToString::to_string),
                 data: None,
+                model_class: None,
+                warnings: extra.reports_warnings().then(Vec::new),
             })
         }
     }
+
+    /// call the function with this info, then record the warnings it emitted with `info.warn()`
+    fn call<T>(self, py: Python, extra: &Extra, f: impl FnOnce(Py<Self>) -> PyResult<T>) -> PyResult<T> {
+        if self.warnings.is_none() {
+            return f(Py::new(py, self)?);
+        }
+        let info = Py::new(py, self)?;
+        let result = f(info.clone_ref(py));
+        if let Some(warnings) = info.borrow_mut(py).warnings.take() {
+            for (code, message) in warnings {
+                extra.report_warning(&code, || message);
+            }
+        }
+        result
+    }
 }
 
 #[pymethods]
 impl ValidationInfo {
+    /// emit a warning about the value being validated, it doesn't affect validation and is only recorded
+    /// when validating with `validate_python_with_warnings` or `validate_json_with_warnings`
+    #[pyo3(signature = (message, *, code = "warning"))]
+    fn warn(&mut self, message: String, code: &str) {
+        if let Some(ref mut warnings) = self.warnings {
+            warnings.push((code.to_string(), message));
+        }
+    }
+
     #[getter]
     fn get_data(&self, py: Python) -> PyResult<Py<PyDict>> {
         match self.data {
@@ -465,11 +625,17 @@ impl ValidationInfo {
         }
     }
 
+    /// name of the field being validated, `None` outside a typed dict, model or dataclass field
+    #[getter]
+    fn get_field_name<'py>(&self, py: Python<'py>) -> Option<&'py PyString> {
+        self.field_name.as_ref().map(|field_name| PyString::new(py, field_name))
+    }
+
     #[getter]
-    fn get_field_name<'py>(&self, py: Python<'py>) -> PyResult<&'py PyString> {
-        match self.field_name {
-            Some(ref field_name) => Ok(PyString::new(py, field_name)),
-            None => Err(PyAttributeError::new_err("No attribute named 'field_name'")),
+    fn get_model_class(&self, py: Python) -> PyResult<PyObject> {
+        match self.model_class {
+            Some(ref model_class) => Ok(model_class.clone_ref(py)),
+            None => Err(PyAttributeError::new_err("No attribute named 'model_class'")),
         }
     }
 
@@ -486,6 +652,9 @@ impl ValidationInfo {
         if let Some(ref field_name) = self.field_name {
             s += &format!(", field_name='{field_name}'");
         }
+        if let Some(ref model_class) = self.model_class {
+            s += &format!(", model_class={}", model_class.as_ref(py).repr()?);
+        }
         s += ")";
         Ok(s)
     }
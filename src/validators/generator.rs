@@ -4,14 +4,14 @@ use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
 use crate::build_tools::SchemaDict;
-use crate::errors::{ErrorMode, ErrorType, LocItem, ValError, ValResult};
+use crate::errors::{CustomErrorMessages, ErrorMode, ErrorType, LocItem, ValError, ValResult};
 use crate::input::{GenericIterator, Input};
 use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 use crate::ValidationError;
 
 use super::list::get_items_schema;
-use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub struct GeneratorValidator {
@@ -64,15 +64,35 @@ impl Validator for GeneratorValidator {
             validator,
             min_length: self.min_length,
             max_length: self.max_length,
+            hide_input: extra.hide_input,
+            custom_messages: extra.custom_error_messages.cloned(),
         };
         Ok(v_iterator.into_py(py))
     }
 
+    fn set_strict(&mut self, strict: bool) {
+        if let Some(ref mut v) = self.item_validator {
+            v.set_strict(strict);
+        }
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .accepts_always(&["iterable"])
+            .constraint("min_length", self.min_length)?
+            .constraint("max_length", self.max_length)?
+            .opt_child("items_schema", self.item_validator.as_deref())?
+            .finish()
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
 
     fn ask(&self, question: &Question) -> bool {
+        if *question == Question::ReturnsImmutable {
+            return false;
+        }
         match self.item_validator {
             Some(ref v) => v.ask(question),
             None => false,
@@ -94,6 +114,8 @@ struct ValidatorIterator {
     validator: Option<InternalValidator>,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    hide_input: bool,
+    custom_messages: Option<CustomErrorMessages>,
 }
 
 #[pymethods]
@@ -105,6 +127,8 @@ impl ValidatorIterator {
     fn __next__(mut slf: PyRefMut<'_, Self>, py: Python) -> PyResult<Option<PyObject>> {
         let min_length = slf.min_length;
         let max_length = slf.max_length;
+        let hide_input = slf.hide_input;
+        let custom_messages = slf.custom_messages.clone();
         let Self {
             validator, iterator, ..
         } = &mut *slf;
@@ -129,6 +153,9 @@ impl ValidatorIterator {
                                         ErrorMode::Python,
                                         val_error,
                                         None,
+                                        hide_input,
+                                        custom_messages.clone(),
+                                        None,
                                     ));
                                 }
                             }
@@ -153,6 +180,9 @@ impl ValidatorIterator {
                                     ErrorMode::Python,
                                     val_error,
                                     None,
+                                    hide_input,
+                                    custom_messages.clone(),
+                                    None,
                                 ));
                             }
                         }
@@ -198,6 +228,8 @@ pub struct InternalValidator {
     context: Option<PyObject>,
     self_instance: Option<PyObject>,
     recursion_guard: RecursionGuard,
+    hide_input: bool,
+    custom_messages: Option<CustomErrorMessages>,
 }
 
 impl fmt::Debug for InternalValidator {
@@ -224,6 +256,8 @@ impl InternalValidator {
             context: extra.context.map(|d| d.into_py(py)),
             self_instance: extra.self_instance.map(|d| d.into_py(py)),
             recursion_guard: recursion_guard.clone(),
+            hide_input: extra.hide_input,
+            custom_messages: extra.custom_error_messages.cloned(),
         }
     }
 
@@ -235,13 +269,14 @@ impl InternalValidator {
         field_value: &'data PyAny,
         outer_location: Option<LocItem>,
     ) -> PyResult<PyObject> {
-        let extra = Extra {
-            data: self.data.as_ref().map(|data| data.as_ref(py)),
-            strict: self.strict,
-            context: self.context.as_ref().map(|data| data.as_ref(py)),
-            field_name: None,
-            self_instance: self.self_instance.as_ref().map(|data| data.as_ref(py)),
-        };
+        let extra = Extra::builder()
+            .data(self.data.as_ref().map(|data| data.as_ref(py)))
+            .strict(self.strict)
+            .context(self.context.as_ref().map(|data| data.as_ref(py)))
+            .self_instance(self.self_instance.as_ref().map(|data| data.as_ref(py)))
+            .hide_input(self.hide_input)
+            .custom_error_messages(self.custom_messages.as_ref())
+            .build();
         self.validator
             .validate_assignment(
                 py,
@@ -253,7 +288,17 @@ impl InternalValidator {
                 &mut self.recursion_guard,
             )
             .map_err(|e| {
-                ValidationError::from_val_error(py, self.name.to_object(py), ErrorMode::Python, e, outer_location)
+                let name = self.name.to_object(py);
+                ValidationError::from_val_error(
+                    py,
+                    name,
+                    ErrorMode::Python,
+                    e,
+                    outer_location,
+                    self.hide_input,
+                    self.custom_messages.clone(),
+                    None,
+                )
             })
     }
 
@@ -266,17 +311,28 @@ impl InternalValidator {
     where
         's: 'data,
     {
-        let extra = Extra {
-            data: self.data.as_ref().map(|data| data.as_ref(py)),
-            strict: self.strict,
-            context: self.context.as_ref().map(|data| data.as_ref(py)),
-            field_name: None,
-            self_instance: self.self_instance.as_ref().map(|data| data.as_ref(py)),
-        };
+        let extra = Extra::builder()
+            .data(self.data.as_ref().map(|data| data.as_ref(py)))
+            .strict(self.strict)
+            .context(self.context.as_ref().map(|data| data.as_ref(py)))
+            .self_instance(self.self_instance.as_ref().map(|data| data.as_ref(py)))
+            .hide_input(self.hide_input)
+            .custom_error_messages(self.custom_messages.as_ref())
+            .build();
         self.validator
             .validate(py, input, &extra, &self.slots, &mut self.recursion_guard)
             .map_err(|e| {
-                ValidationError::from_val_error(py, self.name.to_object(py), ErrorMode::Python, e, outer_location)
+                let name = self.name.to_object(py);
+                ValidationError::from_val_error(
+                    py,
+                    name,
+                    ErrorMode::Python,
+                    e,
+                    outer_location,
+                    self.hide_input,
+                    self.custom_messages.clone(),
+                    None,
+                )
             })
     }
 }
@@ -1,17 +1,71 @@
+use std::cmp::Ordering;
+
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use crate::build_tools::{is_strict, SchemaDict};
+use crate::build_tools::{coerce_hook_enabled, is_strict, py_err, SchemaDict};
 use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::Input;
+use crate::input::{with_coerce_hook, Input};
+use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
-use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
+
+/// What to do with a value which isn't a multiple of `multiple_of`, shared with the float validator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MultipleOfMode {
+    Error,
+    Round,
+    Floor,
+    Ceil,
+}
+
+impl MultipleOfMode {
+    pub fn from_schema(schema: &PyDict) -> PyResult<Self> {
+        match schema.get_as::<&str>(intern!(schema.py(), "multiple_of_mode"))? {
+            None | Some("error") => Ok(Self::Error),
+            Some("round") => Ok(Self::Round),
+            Some("floor") => Ok(Self::Floor),
+            Some("ceil") => Ok(Self::Ceil),
+            Some(s) => py_err!(
+                "Invalid multiple_of_mode: `{}`, expected `error`, `round`, `floor` or `ceil`",
+                s
+            ),
+        }
+    }
+
+    /// adjust `int` to a multiple of `multiple_of` using integer arithmetic so the result is exact,
+    /// inputs are `i64`s like everywhere else in int validation, ints too big for one fail with `int_parsing_size`
+    /// before they're adjusted, `i128` is only used since the adjusted value can fall outside the range of `i64`
+    fn adjust_int(self, int: i128, multiple_of: i128) -> Option<i128> {
+        let step = multiple_of.abs();
+        let rem = int.rem_euclid(step);
+        if rem == 0 {
+            return Some(int);
+        }
+        let floor = int - rem;
+        match self {
+            Self::Error => None,
+            Self::Floor => Some(floor),
+            Self::Ceil => Some(floor + step),
+            // ties are rounded away from zero, matching `f64::round` used for floats
+            Self::Round => match (rem * 2).cmp(&step) {
+                Ordering::Less => Some(floor),
+                Ordering::Greater => Some(floor + step),
+                Ordering::Equal if int < 0 => Some(floor),
+                Ordering::Equal => Some(floor + step),
+            },
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct IntValidator {
     strict: bool,
+    coerce_hook: bool,
+    json_strict_numbers: bool,
+    parse_radix_prefixes: bool,
 }
 
 impl BuildValidator for IntValidator {
@@ -27,12 +81,16 @@ impl BuildValidator for IntValidator {
             || schema.get_item(intern!(py, "le")).is_some()
             || schema.get_item(intern!(py, "lt")).is_some()
             || schema.get_item(intern!(py, "ge")).is_some()
-            || schema.get_item(intern!(py, "gt")).is_some();
+            || schema.get_item(intern!(py, "gt")).is_some()
+            || schema.get_item(intern!(py, "scale")).is_some();
         if use_constrained {
             ConstrainedIntValidator::build(schema, config)
         } else {
             Ok(Self {
                 strict: is_strict(schema, config)?,
+                coerce_hook: coerce_hook_enabled(config)?,
+                json_strict_numbers: config.get_as(intern!(py, "json_strict_numbers"))?.unwrap_or(false),
+                parse_radix_prefixes: schema.get_as(intern!(py, "parse_radix_prefixes"))?.unwrap_or(false),
             }
             .into())
         }
@@ -48,13 +106,35 @@ impl Validator for IntValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        Ok(input.validate_int(extra.strict.unwrap_or(self.strict))?.into_py(py))
+        let strict = extra.strict.unwrap_or(self.strict);
+        let coerce_hook = self.coerce_hook && !strict;
+        let result = validate_int(input, strict, self.json_strict_numbers, self.parse_radix_prefixes);
+        let int = with_coerce_hook(input, "int", coerce_hook, result, |v| v.lax_int())?;
+        extra.report_coercion(py, input, "int", || input.strict_int().is_ok());
+        let output = int.into_py(py);
+        extra.report_output_type(input, output.as_ref(py));
+        Ok(output)
+    }
+
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .strict(self.strict)
+            .accepts(&["int", "str", "bytes", "float"], &["int"])
+            .finish()
     }
 
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
 
+    fn ask(&self, question: &Question) -> bool {
+        matches!(question, Question::ReturnsImmutable)
+    }
+
     fn complete(&mut self, _build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
         Ok(())
     }
@@ -63,7 +143,12 @@ impl Validator for IntValidator {
 #[derive(Debug, Clone)]
 pub struct ConstrainedIntValidator {
     strict: bool,
+    coerce_hook: bool,
+    json_strict_numbers: bool,
+    parse_radix_prefixes: bool,
+    scale: Option<u32>,
     multiple_of: Option<i64>,
+    multiple_of_mode: MultipleOfMode,
     le: Option<i64>,
     lt: Option<i64>,
     ge: Option<i64>,
@@ -79,44 +164,80 @@ impl Validator for ConstrainedIntValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let int = input.validate_int(extra.strict.unwrap_or(self.strict))?;
+        let strict = extra.strict.unwrap_or(self.strict);
+        let coerce_hook = self.coerce_hook && !strict;
+        let result = match self.scale {
+            Some(scale) => validate_scaled_int(input, strict, scale),
+            None => validate_int(input, strict, self.json_strict_numbers, self.parse_radix_prefixes).map(i128::from),
+        };
+        let mut int = with_coerce_hook(input, "int", coerce_hook, result, |v| match self.scale {
+            Some(scale) => validate_scaled_int(v, false, scale),
+            None => v.lax_int().map(i128::from),
+        })?;
+        extra.report_coercion(py, input, "int", || input.strict_int().is_ok());
         if let Some(multiple_of) = self.multiple_of {
-            if int % multiple_of != 0 {
-                return Err(ValError::new(
-                    ErrorType::MultipleOf {
-                        multiple_of: multiple_of.into(),
-                    },
-                    input,
-                ));
+            match self.multiple_of_mode.adjust_int(int, multiple_of.into()) {
+                Some(adjusted) => int = adjusted,
+                None => {
+                    return Err(ValError::new(
+                        ErrorType::MultipleOf {
+                            multiple_of: multiple_of.into(),
+                        },
+                        input,
+                    ))
+                }
             }
         }
         if let Some(le) = self.le {
-            if int > le {
+            if int > le.into() {
                 return Err(ValError::new(ErrorType::LessThanEqual { le: le.into() }, input));
             }
         }
         if let Some(lt) = self.lt {
-            if int >= lt {
+            if int >= lt.into() {
                 return Err(ValError::new(ErrorType::LessThan { lt: lt.into() }, input));
             }
         }
         if let Some(ge) = self.ge {
-            if int < ge {
+            if int < ge.into() {
                 return Err(ValError::new(ErrorType::GreaterThanEqual { ge: ge.into() }, input));
             }
         }
         if let Some(gt) = self.gt {
-            if int <= gt {
+            if int <= gt.into() {
                 return Err(ValError::new(ErrorType::GreaterThan { gt: gt.into() }, input));
             }
         }
-        Ok(int.into_py(py))
+        let output = int.into_py(py);
+        extra.report_output_type(input, output.as_ref(py));
+        Ok(output)
+    }
+
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, IntValidator::EXPECTED_TYPE)
+            .strict(self.strict)
+            .accepts(&["int", "str", "bytes", "float"], &["int"])
+            .constraint("multiple_of", self.multiple_of)?
+            .constraint("le", self.le)?
+            .constraint("lt", self.lt)?
+            .constraint("ge", self.ge)?
+            .constraint("gt", self.gt)?
+            .constraint("scale", self.scale)?
+            .finish()
     }
 
     fn get_name(&self) -> &str {
         "constrained-int"
     }
 
+    fn ask(&self, question: &Question) -> bool {
+        matches!(question, Question::ReturnsImmutable)
+    }
+
     fn complete(&mut self, _build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
         Ok(())
     }
@@ -125,9 +246,18 @@ impl Validator for ConstrainedIntValidator {
 impl ConstrainedIntValidator {
     fn build(schema: &PyDict, config: Option<&PyDict>) -> PyResult<CombinedValidator> {
         let py = schema.py();
+        let scale: Option<u32> = schema.get_as(intern!(py, "scale"))?;
+        if matches!(scale, Some(scale) if scale > MAX_SCALE) {
+            return py_err!("`scale` should be at most {}", MAX_SCALE);
+        }
         Ok(Self {
             strict: is_strict(schema, config)?,
+            coerce_hook: coerce_hook_enabled(config)?,
+            json_strict_numbers: config.get_as(intern!(py, "json_strict_numbers"))?.unwrap_or(false),
+            parse_radix_prefixes: schema.get_as(intern!(py, "parse_radix_prefixes"))?.unwrap_or(false),
+            scale,
             multiple_of: schema.get_as(intern!(py, "multiple_of"))?,
+            multiple_of_mode: MultipleOfMode::from_schema(schema)?,
             le: schema.get_as(intern!(py, "le"))?,
             lt: schema.get_as(intern!(py, "lt"))?,
             ge: schema.get_as(intern!(py, "ge"))?,
@@ -135,4 +265,220 @@ impl ConstrainedIntValidator {
         }
         .into())
     }
+
+    fn from_combined(validator: &CombinedValidator) -> Option<Self> {
+        match validator {
+            CombinedValidator::Int(v) => Some(Self {
+                strict: v.strict,
+                coerce_hook: v.coerce_hook,
+                json_strict_numbers: v.json_strict_numbers,
+                parse_radix_prefixes: v.parse_radix_prefixes,
+                scale: None,
+                multiple_of: None,
+                multiple_of_mode: MultipleOfMode::Error,
+                le: None,
+                lt: None,
+                ge: None,
+                gt: None,
+            }),
+            CombinedValidator::ConstrainedInt(v) => Some(v.clone()),
+            _ => None,
+        }
+    }
+
+    fn has_constraints_set(&self) -> bool {
+        self.scale.is_some()
+            || self.multiple_of.is_some()
+            || self.le.is_some()
+            || self.lt.is_some()
+            || self.ge.is_some()
+            || self.gt.is_some()
+    }
+}
+
+/// merge `next`, a chain step validating the output of `first`, into a single validator, `None` if `next` changes the
+/// value with `scale` or `multiple_of`, or merging would change which error some input gets: upper bounds are checked
+/// `le` then `lt` and lower bounds `ge` then `gt`, so `next` can't add a bound which is checked before one from
+/// `first`, and bounds which no int satisfies can't be merged
+pub fn merge_int_validators(first: &CombinedValidator, next: &CombinedValidator) -> Option<CombinedValidator> {
+    let first = ConstrainedIntValidator::from_combined(first)?;
+    let next = ConstrainedIntValidator::from_combined(next)?;
+    if next.scale.is_some() || next.multiple_of.is_some() {
+        return None;
+    }
+    if (first.lt.is_some() && next.le.is_some()) || (first.gt.is_some() && next.ge.is_some()) {
+        return None;
+    }
+    let tightest = |a: Option<i64>, b: Option<i64>, f: fn(i64, i64) -> i64| match (a, b) {
+        (Some(a), Some(b)) => Some(f(a, b)),
+        (a, b) => a.or(b),
+    };
+    let merged = ConstrainedIntValidator {
+        le: tightest(first.le, next.le, i64::min),
+        lt: tightest(first.lt, next.lt, i64::min),
+        ge: tightest(first.ge, next.ge, i64::max),
+        gt: tightest(first.gt, next.gt, i64::max),
+        ..first
+    };
+    let lower = [merged.ge.map(i128::from), merged.gt.map(|gt| i128::from(gt) + 1)];
+    let upper = [merged.le.map(i128::from), merged.lt.map(|lt| i128::from(lt) - 1)];
+    if let (Some(lower), Some(upper)) = (lower.into_iter().flatten().max(), upper.into_iter().flatten().min()) {
+        if lower > upper {
+            return None;
+        }
+    }
+    match merged.has_constraints_set() {
+        true => Some(merged.into()),
+        false => Some(
+            IntValidator {
+                strict: merged.strict,
+                coerce_hook: merged.coerce_hook,
+                json_strict_numbers: merged.json_strict_numbers,
+                parse_radix_prefixes: merged.parse_radix_prefixes,
+            }
+            .into(),
+        ),
+    }
+}
+
+/// in strict mode JSON numbers with a fractional part or exponent are never valid ints, `json_strict_numbers`
+/// applies the same rule in lax mode, `parse_radix_prefixes` allows strings like `0xff` and `1_000` in lax mode
+fn validate_int<'data>(
+    input: &'data impl Input<'data>,
+    strict: bool,
+    json_strict_numbers: bool,
+    parse_radix_prefixes: bool,
+) -> ValResult<'data, i64> {
+    if strict {
+        return input.validate_int(true);
+    }
+    if parse_radix_prefixes {
+        if let Ok(either_str) = input.strict_str() {
+            if let Some(int) = parse_radix_str(&either_str.as_cow()?) {
+                return int.ok_or_else(|| ValError::new(ErrorType::IntParsing, input));
+            }
+        }
+    }
+    if json_strict_numbers {
+        input.lax_int_strict_numbers()
+    } else {
+        input.validate_int(false)
+    }
+}
+
+/// parse a string with a `0x`, `0o` or `0b` prefix or underscores between digits like python's `int(s, 0)`,
+/// `None` if the string has neither so should be parsed as a plain decimal, `Some(None)` if it's invalid
+fn parse_radix_str(s: &str) -> Option<Option<i64>> {
+    let s = s.trim();
+    let (negative, unsigned) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let (radix, digits) = match unsigned.get(..2).map(str::to_ascii_lowercase).as_deref() {
+        Some("0x") => (16, &unsigned[2..]),
+        Some("0o") => (8, &unsigned[2..]),
+        Some("0b") => (2, &unsigned[2..]),
+        _ if unsigned.contains('_') => (10, unsigned),
+        _ => return None,
+    };
+    // after a prefix a single underscore may come before the first digit, e.g. `0x_ff`
+    let digits = match radix {
+        10 => digits,
+        _ => digits.strip_prefix('_').unwrap_or(digits),
+    };
+    if digits.is_empty() || digits.ends_with('_') || digits.starts_with('_') || digits.contains("__") {
+        return Some(None);
+    }
+    let digits: String = digits.chars().filter(|c| *c != '_').collect();
+    if !digits.chars().all(|c| c.is_digit(radix)) {
+        return Some(None);
+    }
+    // as with python, decimals with leading zeros are ambiguous so invalid, unless the value is zero
+    if radix == 10 && digits.starts_with('0') && digits.chars().any(|c| c != '0') {
+        return Some(None);
+    }
+    let digits = if negative { format!("-{digits}") } else { digits };
+    Some(i64::from_str_radix(&digits, radix).ok())
+}
+
+/// the largest `scale` allowed, so any `i64` multiplied by `10 ** scale` fits in an `i128`
+const MAX_SCALE: u32 = 18;
+
+/// validate a decimal number as an integer number of minor units, e.g. `'12.34'` is `1234` with a `scale` of 2;
+/// ints are always valid, in lax mode strings are parsed as decimals and floats are converted via their shortest
+/// representation, so the result is exact
+fn validate_scaled_int<'data>(input: &'data impl Input<'data>, strict: bool, scale: u32) -> ValResult<'data, i128> {
+    let factor = 10_i128.pow(scale);
+    if let Ok(int) = input.strict_int() {
+        return Ok(i128::from(int) * factor);
+    }
+    if strict {
+        return input.validate_int(true).map(|int| i128::from(int) * factor);
+    }
+    let decimal = if let Ok(either_str) = input.strict_str() {
+        parse_scaled_str(&either_str.as_cow()?, scale)
+    } else if let Ok(float) = input.strict_float() {
+        match float.is_finite() {
+            true => parse_scaled_str(&float.to_string(), scale),
+            false => Err(ErrorType::FiniteNumber),
+        }
+    } else {
+        return input.validate_int(false).map(|int| i128::from(int) * factor);
+    };
+    decimal.map_err(|error_type| ValError::new(error_type, input))
+}
+
+/// parse a decimal string like `'-12.34'` or `'1.5e3'` as an integer number of minor units with `scale` decimal
+/// places, trailing zeros after the decimal point are ignored when counting decimal places
+fn parse_scaled_str(s: &str, scale: u32) -> Result<i128, ErrorType> {
+    let s = s.trim();
+    let (negative, unsigned) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+        Some(index) => (&unsigned[..index], Some(&unsigned[index + 1..])),
+        None => (unsigned, None),
+    };
+    let (int_part, frac_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let all_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+    if int_part.is_empty() && frac_part.is_empty() || !all_digits(int_part) || !all_digits(frac_part) {
+        return Err(ErrorType::FloatParsing);
+    }
+    let exponent: i64 = match exponent {
+        Some(e) => {
+            let unsigned_exponent = e.strip_prefix(['+', '-']).unwrap_or(e);
+            if unsigned_exponent.is_empty() || !all_digits(unsigned_exponent) {
+                return Err(ErrorType::FloatParsing);
+            }
+            // an exponent too large for `i64` is saturated, the result is either zero or invalid
+            e.parse()
+                .unwrap_or(if e.starts_with('-') { i64::MIN } else { i64::MAX })
+        }
+        None => 0,
+    };
+
+    let mut digits = format!("{int_part}{frac_part}");
+    let mut decimal_places = (frac_part.len() as i64).saturating_sub(exponent);
+    while decimal_places > 0 && digits.ends_with('0') {
+        digits.pop();
+        decimal_places -= 1;
+    }
+    let digits = digits.trim_start_matches('0');
+    if digits.is_empty() {
+        return Ok(0);
+    }
+    if decimal_places > i64::from(scale) {
+        return Err(ErrorType::DecimalPlaces {
+            decimal_places: scale as usize,
+        });
+    }
+    let value = u32::try_from(i64::from(scale).saturating_sub(decimal_places))
+        .ok()
+        .and_then(|shift| 10_i128.checked_pow(shift))
+        .and_then(|factor| digits.parse::<i128>().ok()?.checked_mul(factor))
+        .ok_or(ErrorType::IntParsing)?;
+    Ok(if negative { -value } else { value })
 }
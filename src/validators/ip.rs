@@ -0,0 +1,307 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use pyo3::exceptions::PyValueError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyBytes, PyDict, PyLong};
+
+use crate::build_tools::is_strict;
+use crate::errors::{ErrorType, ValError, ValResult};
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::{BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IpVersion {
+    V4,
+    V6,
+}
+
+impl IpVersion {
+    fn max_prefix_len(self) -> u8 {
+        match self {
+            Self::V4 => 32,
+            Self::V6 => 128,
+        }
+    }
+
+    fn max_int(self) -> u128 {
+        match self {
+            Self::V4 => u32::MAX as u128,
+            Self::V6 => u128::MAX,
+        }
+    }
+
+    /// all bits of the address set up to `prefix_len`, e.g. `/24` is `255.255.255.0`
+    fn netmask(self, prefix_len: u8) -> u128 {
+        self.max_int() & !self.max_int().checked_shr(prefix_len as u32).unwrap_or(0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IpKind {
+    Address,
+    Network,
+    Interface,
+}
+
+macro_rules! ip_builder {
+    ($struct_name:ident, $expected_type:literal, $version:expr, $kind:expr) => {
+        #[derive(Debug, Clone)]
+        pub struct $struct_name;
+
+        impl BuildValidator for $struct_name {
+            const EXPECTED_TYPE: &'static str = $expected_type;
+
+            fn build(
+                schema: &PyDict,
+                config: Option<&PyDict>,
+                _build_context: &mut BuildContext<CombinedValidator>,
+            ) -> PyResult<CombinedValidator> {
+                IpValidator::build(schema, config, $version, $kind, Self::EXPECTED_TYPE)
+            }
+        }
+    };
+}
+ip_builder!(IpV4AddressBuilder, "ip-v4-address", IpVersion::V4, IpKind::Address);
+ip_builder!(IpV6AddressBuilder, "ip-v6-address", IpVersion::V6, IpKind::Address);
+ip_builder!(IpV4NetworkBuilder, "ip-v4-network", IpVersion::V4, IpKind::Network);
+ip_builder!(IpV6NetworkBuilder, "ip-v6-network", IpVersion::V6, IpKind::Network);
+ip_builder!(
+    IpV4InterfaceBuilder,
+    "ip-v4-interface",
+    IpVersion::V4,
+    IpKind::Interface
+);
+ip_builder!(
+    IpV6InterfaceBuilder,
+    "ip-v6-interface",
+    IpVersion::V6,
+    IpKind::Interface
+);
+
+/// validates the `ipaddress` address, network and interface types, inputs are parsed in rust and the result is
+/// created from its int and prefix length, only IPv6 inputs with a scope ID are created from the string
+#[derive(Debug, Clone)]
+pub struct IpValidator {
+    strict: bool,
+    version: IpVersion,
+    kind: IpKind,
+    // e.g. `ipaddress.IPv4Network` for `ip-v4-network`
+    class: PyObject,
+    // e.g. `ipaddress.IPv4Address`, accepted by network and interface validators in lax mode
+    address_class: PyObject,
+    name: &'static str,
+}
+
+impl IpValidator {
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        version: IpVersion,
+        kind: IpKind,
+        name: &'static str,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let ipaddress = py.import(intern!(py, "ipaddress"))?;
+        let (address_name, class_name) = match (version, kind) {
+            (IpVersion::V4, IpKind::Address) => (intern!(py, "IPv4Address"), intern!(py, "IPv4Address")),
+            (IpVersion::V6, IpKind::Address) => (intern!(py, "IPv6Address"), intern!(py, "IPv6Address")),
+            (IpVersion::V4, IpKind::Network) => (intern!(py, "IPv4Address"), intern!(py, "IPv4Network")),
+            (IpVersion::V6, IpKind::Network) => (intern!(py, "IPv6Address"), intern!(py, "IPv6Network")),
+            (IpVersion::V4, IpKind::Interface) => (intern!(py, "IPv4Address"), intern!(py, "IPv4Interface")),
+            (IpVersion::V6, IpKind::Interface) => (intern!(py, "IPv6Address"), intern!(py, "IPv6Interface")),
+        };
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            version,
+            kind,
+            class: ipaddress.getattr(class_name)?.into_py(py),
+            address_class: ipaddress.getattr(address_name)?.into_py(py),
+            name,
+        }
+        .into())
+    }
+}
+
+impl Validator for IpValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        _slots: &'data [CombinedValidator],
+        _recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        if input.input_is_instance(self.class.as_ref(py), 0)? {
+            return Ok(input.to_object(py));
+        }
+        // in strict mode only instances are accepted from python
+        let strict = extra.strict.unwrap_or(self.strict);
+        if strict && input.is_python() {
+            return Err(self.error(input));
+        }
+
+        let max_prefix_len = self.version.max_prefix_len();
+        let (int, prefix_len) = if let Ok(either_str) = input.strict_str() {
+            let s = either_str.as_cow()?;
+            let (address, prefix) = match self.kind {
+                IpKind::Address => (s.as_ref(), None),
+                _ => match s.split_once('/') {
+                    Some((address, prefix)) => (address, Some(prefix)),
+                    None => (s.as_ref(), None),
+                },
+            };
+            // scope IDs are kept by `ipaddress`, but can't be set from an int so these are created from the string
+            if self.version == IpVersion::V6 && address.contains('%') {
+                return self.create_from_str(py, s.as_ref(), strict, input);
+            }
+            let int = self.parse_address(address).ok_or_else(|| self.error(input))?;
+            let prefix_len = match prefix {
+                Some(prefix) => self.parse_prefix(prefix).ok_or_else(|| self.error(input))?,
+                None => max_prefix_len,
+            };
+            (int, prefix_len)
+        } else if input.is_python() {
+            let value = input.to_object(py).into_ref(py);
+            if let Ok(py_bytes) = value.downcast::<PyBytes>() {
+                // packed addresses as with `ipaddress`, i.e. 4 or 16 big-endian bytes
+                let bytes = py_bytes.as_bytes();
+                let int = match self.version {
+                    IpVersion::V4 => bytes.try_into().map(|bytes| u32::from_be_bytes(bytes) as u128),
+                    IpVersion::V6 => bytes.try_into().map(u128::from_be_bytes),
+                };
+                let int = int.map_err(|_| self.error(input))?;
+                (int, max_prefix_len)
+            } else if value.is_instance_of::<PyLong>()? && !value.is_instance_of::<PyBool>()? {
+                match value.extract::<u128>() {
+                    Ok(int) if int <= self.version.max_int() => (int, max_prefix_len),
+                    _ => return Err(self.error(input)),
+                }
+            } else if self.kind != IpKind::Address && value.is_instance(self.address_class.as_ref(py))? {
+                // interfaces are addresses too, their prefix isn't used
+                (value.call_method0(intern!(py, "__int__"))?.extract()?, max_prefix_len)
+            } else {
+                return Err(self.error(input));
+            }
+        } else {
+            // the int form of addresses is only accepted from JSON in lax mode
+            match input.strict_int() {
+                Ok(int) if !strict && int >= 0 && int as u128 <= self.version.max_int() => {
+                    (int as u128, max_prefix_len)
+                }
+                _ => return Err(self.error(input)),
+            }
+        };
+
+        let netmask = self.version.netmask(prefix_len);
+        let int = if self.kind == IpKind::Network && int & !netmask != 0 {
+            // as with `ipaddress`, host bits are an error in strict mode and are masked otherwise
+            if strict {
+                return Err(ValError::new(ErrorType::IpNetworkHostBits, input));
+            }
+            int & netmask
+        } else {
+            int
+        };
+        Ok(self.create(py, int, prefix_len)?)
+    }
+
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        let description = Description::new(py, self.name).strict(self.strict);
+        match self.kind {
+            IpKind::Address => description.accepts(&["instance", "str", "bytes", "int"], &["instance"]),
+            _ => description.accepts(&["instance", "address", "str", "bytes", "int"], &["instance"]),
+        }
+        .finish()
+    }
+
+    fn get_name(&self) -> &str {
+        self.name
+    }
+
+    fn complete(&mut self, _build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
+        Ok(())
+    }
+}
+
+impl IpValidator {
+    fn error<'data>(&self, input: &'data impl Input<'data>) -> ValError<'data> {
+        let error_type = match (self.version, self.kind) {
+            (IpVersion::V4, IpKind::Address) => ErrorType::IpV4Address,
+            (IpVersion::V6, IpKind::Address) => ErrorType::IpV6Address,
+            (IpVersion::V4, IpKind::Network) => ErrorType::IpV4Network,
+            (IpVersion::V6, IpKind::Network) => ErrorType::IpV6Network,
+            (IpVersion::V4, IpKind::Interface) => ErrorType::IpV4Interface,
+            (IpVersion::V6, IpKind::Interface) => ErrorType::IpV6Interface,
+        };
+        ValError::new(error_type, input)
+    }
+
+    fn parse_address(&self, s: &str) -> Option<u128> {
+        match self.version {
+            IpVersion::V4 => s.parse::<Ipv4Addr>().ok().map(|ip| u32::from(ip) as u128),
+            IpVersion::V6 => s.parse::<Ipv6Addr>().ok().map(u128::from),
+        }
+    }
+
+    /// a prefix length, or for IPv4 a netmask like `255.255.255.0` or hostmask like `0.0.0.255` as with `ipaddress`
+    fn parse_prefix(&self, s: &str) -> Option<u8> {
+        let max_prefix_len = self.version.max_prefix_len();
+        if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) {
+            return s.parse::<u8>().ok().filter(|prefix_len| *prefix_len <= max_prefix_len);
+        }
+        if self.version == IpVersion::V4 {
+            let mask = u32::from(s.parse::<Ipv4Addr>().ok()?);
+            if mask.leading_ones() + mask.trailing_zeros() == 32 {
+                return Some(mask.leading_ones() as u8);
+            }
+            if mask.leading_zeros() + mask.trailing_ones() == 32 {
+                return Some(mask.leading_zeros() as u8);
+            }
+        }
+        None
+    }
+
+    fn create(&self, py: Python, int: u128, prefix_len: u8) -> PyResult<PyObject> {
+        let class = self.class.as_ref(py);
+        let py_ip = match self.kind {
+            IpKind::Address => class.call1((int,))?,
+            _ => class.call1(((int, prefix_len),))?,
+        };
+        Ok(py_ip.into_py(py))
+    }
+
+    fn create_from_str<'data>(
+        &self,
+        py: Python<'data>,
+        s: &str,
+        strict: bool,
+        input: &'data impl Input<'data>,
+    ) -> ValResult<'data, PyObject> {
+        let class = self.class.as_ref(py);
+        let result = match (self.kind, class.call1((s,))) {
+            // `ipaddress` raises for host bits unless `strict=False`, which is the only way they fail once parsed
+            (IpKind::Network, Err(err)) if err.is_instance_of::<PyValueError>(py) => {
+                let kwargs = PyDict::new(py);
+                kwargs.set_item(intern!(py, "strict"), false)?;
+                match class.call((s,), Some(kwargs)) {
+                    Ok(_) if strict => return Err(ValError::new(ErrorType::IpNetworkHostBits, input)),
+                    Ok(network) => Ok(network),
+                    Err(_) => Err(err),
+                }
+            }
+            (_, result) => result,
+        };
+        match result {
+            Ok(py_ip) => Ok(py_ip.into_py(py)),
+            Err(err) if err.is_instance_of::<PyValueError>(py) => Err(self.error(input)),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
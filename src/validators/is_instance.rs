@@ -8,7 +8,7 @@ use crate::input::{Input, JsonType};
 use crate::recursion_guard::RecursionGuard;
 
 use super::function::convert_err;
-use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub struct IsInstanceValidator {
@@ -90,6 +90,13 @@ impl Validator for IsInstanceValidator {
         }
     }
 
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .accepts_always(&["instance"])
+            .constraint("cls", Some(&self.class_repr))?
+            .finish()
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
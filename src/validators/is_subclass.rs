@@ -7,7 +7,7 @@ use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::Input;
 use crate::recursion_guard::RecursionGuard;
 
-use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub struct IsSubclassValidator {
@@ -61,6 +61,13 @@ impl Validator for IsSubclassValidator {
         }
     }
 
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .accepts_always(&["class"])
+            .constraint("cls", Some(&self.class_repr))?
+            .finish()
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
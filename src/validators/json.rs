@@ -1,18 +1,22 @@
+use std::cell::Cell;
+
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
 use crate::build_tools::SchemaDict;
 use crate::errors::ValResult;
-use crate::input::Input;
+use crate::input::{DuplicateKeys, Input};
 use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
-use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub struct JsonValidator {
     validator: Option<Box<CombinedValidator>>,
+    max_str_bytes: Option<usize>,
+    duplicate_keys: DuplicateKeys,
     name: String,
 }
 
@@ -28,7 +32,7 @@ impl BuildValidator for JsonValidator {
             Some(schema) => {
                 let validator = build_validator(schema, config, build_context)?;
                 match validator {
-                    CombinedValidator::Any(_) => None,
+                    CombinedValidator::Any(ref any) if !any.record_types => None,
                     _ => Some(Box::new(validator)),
                 }
             }
@@ -39,7 +43,13 @@ impl BuildValidator for JsonValidator {
             Self::EXPECTED_TYPE,
             validator.as_ref().map(|v| v.get_name()).unwrap_or("any")
         );
-        Ok(Self { validator, name }.into())
+        Ok(Self {
+            validator,
+            max_str_bytes: config.get_as(intern!(schema.py(), "max_str_bytes"))?,
+            duplicate_keys: DuplicateKeys::from_config(config)?,
+            name,
+        }
+        .into())
     }
 }
 
@@ -52,7 +62,12 @@ impl Validator for JsonValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let json_value = input.parse_json()?;
+        let steps_left = Cell::new(recursion_guard.steps_left());
+        // JSON nested within recursive data gets whatever's left of the recursion limit
+        let max_depth = recursion_guard.max_depth().saturating_sub(recursion_guard.depth());
+        let json_value = input.parse_json(self.max_str_bytes, self.duplicate_keys, max_depth, &steps_left);
+        recursion_guard.set_steps_left(steps_left.get());
+        let json_value = json_value?;
         match self.validator {
             Some(ref validator) => match validator.validate(py, &json_value, extra, slots, recursion_guard) {
                 Ok(v) => Ok(v),
@@ -62,6 +77,19 @@ impl Validator for JsonValidator {
         }
     }
 
+    fn set_strict(&mut self, strict: bool) {
+        if let Some(ref mut v) = self.validator {
+            v.set_strict(strict);
+        }
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .accepts_always(&["str", "bytes", "bytearray"])
+            .opt_child("schema", self.validator.as_deref())?
+            .finish()
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
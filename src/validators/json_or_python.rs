@@ -0,0 +1,94 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::build_tools::SchemaDict;
+use crate::errors::ValResult;
+use crate::input::{Input, InputType};
+use crate::questions::Question;
+use crate::recursion_guard::RecursionGuard;
+
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
+
+/// validates JSON input with `json_schema` and python input with `python_schema`, strings validated as JSON
+/// object keys are JSON input
+#[derive(Debug, Clone)]
+pub struct JsonOrPythonValidator {
+    json_validator: Box<CombinedValidator>,
+    python_validator: Box<CombinedValidator>,
+    name: String,
+}
+
+impl BuildValidator for JsonOrPythonValidator {
+    const EXPECTED_TYPE: &'static str = "json-or-python";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let json_schema = schema.get_as_req(intern!(py, "json_schema"))?;
+        let json_validator = Box::new(build_validator(json_schema, config, build_context)?);
+
+        let python_schema = schema.get_as_req(intern!(py, "python_schema"))?;
+        let python_validator = Box::new(build_validator(python_schema, config, build_context)?);
+
+        let name = format!(
+            "{}[json={},python={}]",
+            Self::EXPECTED_TYPE,
+            json_validator.get_name(),
+            python_validator.get_name()
+        );
+        Ok(Self {
+            json_validator,
+            python_validator,
+            name,
+        }
+        .into())
+    }
+}
+
+impl Validator for JsonOrPythonValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        match input.get_type() {
+            InputType::Python => self.python_validator.validate(py, input, extra, slots, recursion_guard),
+            InputType::Json | InputType::String => {
+                self.json_validator.validate(py, input, extra, slots, recursion_guard)
+            }
+        }
+    }
+
+    fn set_strict(&mut self, strict: bool) {
+        self.json_validator.set_strict(strict);
+        self.python_validator.set_strict(strict);
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .child("json_schema", &self.json_validator)?
+            .child("python_schema", &self.python_validator)?
+            .finish()
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    // either branch might be used, so the answer must hold for both
+    fn ask(&self, question: &Question) -> bool {
+        self.json_validator.ask(question) && self.python_validator.ask(question)
+    }
+
+    fn complete(&mut self, build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
+        self.json_validator.complete(build_context)?;
+        self.python_validator.complete(build_context)
+    }
+}
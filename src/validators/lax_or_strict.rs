@@ -7,7 +7,7 @@ use crate::errors::ValResult;
 use crate::input::Input;
 use crate::recursion_guard::RecursionGuard;
 
-use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub struct LaxOrStrictValidator {
@@ -64,6 +64,20 @@ impl Validator for LaxOrStrictValidator {
         }
     }
 
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+        self.lax_validator.set_strict(strict);
+        self.strict_validator.set_strict(strict);
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .strict(self.strict)
+            .child("lax_schema", &self.lax_validator)?
+            .child("strict_schema", &self.strict_validator)?
+            .finish()
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -1,23 +1,49 @@
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyFloat, PyList, PySet};
+use pyo3::PyTypeInfo;
 
-use crate::build_tools::SchemaDict;
-use crate::errors::ValResult;
-use crate::input::{GenericCollection, Input};
+use crate::build_tools::{coerce_hook_enabled, force_exact_container_path, py_err, schema_or_config_same, SchemaDict};
+use crate::errors::{ErrorType, ValError, ValLineError, ValResult};
+use crate::input::{with_coerce_hook, GenericCollection, Input, PrimitiveItems};
 use crate::recursion_guard::RecursionGuard;
 
-use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{build_validator, timer, BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub struct ListValidator {
     strict: bool,
+    coerce_hook: bool,
     allow_any_iter: bool,
+    force_exact_container_path: bool,
+    fail_fast: bool,
+    output_preserve_subclass: bool,
     item_validator: Option<Box<CombinedValidator>>,
+    primitive_items: Option<PrimitiveItems>,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    unique_items: Option<NanEquality>,
     name: String,
 }
 
+/// How NaN floats are compared when checking `unique_items`, all other items are compared with `==`
+#[derive(Debug, Clone, Copy)]
+enum NanEquality {
+    /// NaN is never equal to anything, so NaN items are always unique
+    Ieee,
+    /// NaN is equal to itself, so at most one NaN item is allowed
+    Identity,
+}
+
+impl NanEquality {
+    fn from_schema(schema: &PyDict) -> PyResult<Self> {
+        match schema.get_as::<&str>(pyo3::intern!(schema.py(), "nan_equality"))? {
+            None | Some("ieee") => Ok(Self::Ieee),
+            Some("identity") => Ok(Self::Identity),
+            Some(s) => py_err!("Invalid nan_equality value: `{}`, expected `ieee` or `identity`", s),
+        }
+    }
+}
+
 pub fn get_items_schema(
     schema: &PyDict,
     config: Option<&PyDict>,
@@ -27,7 +53,7 @@ pub fn get_items_schema(
         Some(d) => {
             let validator = build_validator(d, config, build_context)?;
             match validator {
-                CombinedValidator::Any(_) => Ok(None),
+                CombinedValidator::Any(ref any) if !any.record_types => Ok(None),
                 _ => Ok(Some(Box::new(validator))),
             }
         }
@@ -35,10 +61,25 @@ pub fn get_items_schema(
     }
 }
 
+/// whether `item_validator` is a bare `int`, `float`, `str` or `bool` validator, constraints are checked by separate
+/// validators, e.g. `ConstrainedIntValidator`, so only `allow_inf_nan` and bool's `coerce` need checking
+pub(super) fn get_primitive_items(item_validator: Option<&CombinedValidator>) -> Option<PrimitiveItems> {
+    match item_validator? {
+        CombinedValidator::Int(_) => Some(PrimitiveItems::Int),
+        CombinedValidator::Float(v) => Some(PrimitiveItems::Float {
+            allow_inf_nan: v.allow_inf_nan(),
+        }),
+        CombinedValidator::Str(_) => Some(PrimitiveItems::Str),
+        CombinedValidator::Bool(v) if !v.has_coerce() => Some(PrimitiveItems::Bool),
+        _ => None,
+    }
+}
+
+/// `min_length` isn't checked if the input may be incomplete, with `allow_partial`
 macro_rules! length_check {
-    ($input:ident, $field_type:literal, $min_length:expr, $max_length:expr, $obj:ident) => {{
+    ($input:ident, $field_type:literal, $min_length:expr, $max_length:expr, $obj:ident, $extra:ident) => {{
         let mut op_actual_length: Option<usize> = None;
-        if let Some(min_length) = $min_length {
+        if let Some(min_length) = $min_length.filter(|_| !$extra.is_partial()) {
             let actual_length = $obj.len();
             if actual_length < min_length {
                 return Err(crate::errors::ValError::new(
@@ -83,10 +124,21 @@ impl BuildValidator for ListValidator {
         let name = format!("{}[{inner_name}]", Self::EXPECTED_TYPE);
         Ok(Self {
             strict: crate::build_tools::is_strict(schema, config)?,
+            coerce_hook: coerce_hook_enabled(config)?,
             allow_any_iter: schema.get_as(pyo3::intern!(py, "allow_any_iter"))?.unwrap_or(false),
+            force_exact_container_path: force_exact_container_path(config)?,
+            fail_fast: schema_or_config_same(schema, config, pyo3::intern!(py, "fail_fast"))?.unwrap_or(false),
+            output_preserve_subclass: schema
+                .get_as(pyo3::intern!(py, "output_preserve_subclass"))?
+                .unwrap_or(false),
+            primitive_items: get_primitive_items(item_validator.as_deref()),
             item_validator,
             min_length: schema.get_as(pyo3::intern!(py, "min_length"))?,
             max_length: schema.get_as(pyo3::intern!(py, "max_length"))?,
+            unique_items: match schema.get_as(pyo3::intern!(py, "unique_items"))?.unwrap_or(false) {
+                true => Some(NanEquality::from_schema(schema)?),
+                false => None,
+            },
             name,
         }
         .into())
@@ -102,7 +154,23 @@ impl Validator for ListValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let seq = input.validate_list(extra.strict.unwrap_or(self.strict), self.allow_any_iter)?;
+        timer!(self.get_name());
+        let strict = extra.strict.unwrap_or(self.strict);
+        let coerce_hook = self.coerce_hook && !strict;
+        let seq = with_coerce_hook(
+            input,
+            "list",
+            coerce_hook,
+            input.validate_list(strict, self.allow_any_iter),
+            |v| v.lax_list(self.allow_any_iter),
+        )?;
+        let output_class = match seq {
+            GenericCollection::List(list) if self.output_preserve_subclass && !PyList::is_exact_type_of(list) => {
+                Some(list.get_type())
+            }
+            _ => None,
+        };
+        let seq = seq.with_subclass_path(self.force_exact_container_path);
 
         let output = match self.item_validator {
             Some(ref v) => seq.validate_to_vec(
@@ -111,6 +179,8 @@ impl Validator for ListValidator {
                 self.max_length,
                 "List",
                 self.max_length,
+                self.fail_fast,
+                self.primitive_items,
                 v,
                 extra,
                 slots,
@@ -118,14 +188,63 @@ impl Validator for ListValidator {
             )?,
             None => match seq {
                 GenericCollection::List(list) => {
-                    length_check!(input, "List", self.min_length, self.max_length, list);
-                    return Ok(list.into_py(py));
+                    length_check!(input, "List", self.min_length, self.max_length, list, extra);
+                    if let Some(nan_equality) = self.unique_items {
+                        check_unique(py, list.iter(), nan_equality)?;
+                    }
+                    // a subclass only gets here with `force_exact_container_path`, the output is still a plain list
+                    return match (output_class, PyList::is_exact_type_of(list)) {
+                        (None, true) => Ok(list.into_py(py)),
+                        (None, false) => Ok(PyList::new(py, list).into_py(py)),
+                        (Some(class), _) => Ok(class.call1((PyList::new(py, list),))?.into_py(py)),
+                    };
                 }
-                _ => seq.to_vec(py, input, "List", self.max_length)?,
+                _ => seq.to_vec(py, input, self.max_length, "List", self.max_length, extra.string_cache)?,
             },
         };
-        length_check!(input, "List", self.min_length, self.max_length, output);
-        Ok(output.into_py(py))
+        length_check!(input, "List", self.min_length, self.max_length, output, extra);
+        if let Some(nan_equality) = self.unique_items {
+            check_unique(
+                py,
+                output.iter().map(|item| item.clone_ref(py).into_ref(py)),
+                nan_equality,
+            )?;
+        }
+        match output_class {
+            // with `output_preserve_subclass` the list subclass is built from the validated items
+            Some(class) => Ok(class.call1((PyList::new(py, output),))?.into_py(py)),
+            None => Ok(output.into_py(py)),
+        }
+    }
+
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+        if let Some(ref mut v) = self.item_validator {
+            v.set_strict(strict);
+        }
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        let lax: &'static [&'static str] = match self.allow_any_iter {
+            true => &["list", "tuple", "dict_keys", "dict_values", "dict_items", "iterable"],
+            false => &[
+                "list",
+                "tuple",
+                "dict_keys",
+                "dict_values",
+                "dict_items",
+                "iterator",
+                "deque",
+                "range",
+            ],
+        };
+        Description::new(py, Self::EXPECTED_TYPE)
+            .strict(self.strict)
+            .accepts(lax, &["list"])
+            .constraint("min_length", self.min_length)?
+            .constraint("max_length", self.max_length)?
+            .opt_child("items_schema", self.item_validator.as_deref())?
+            .finish()
     }
 
     fn get_name(&self) -> &str {
@@ -141,3 +260,55 @@ impl Validator for ListValidator {
         Ok(())
     }
 }
+
+/// Check validated items are unique, with an error located at each item which equals an earlier item;
+/// hashable items are checked with a set, unhashable items are compared with every earlier unhashable item
+fn check_unique<'data>(
+    py: Python<'data>,
+    items: impl Iterator<Item = &'data PyAny>,
+    nan_equality: NanEquality,
+) -> ValResult<'data, ()> {
+    let hashable_seen = PySet::empty(py)?;
+    let mut unhashable_seen: Vec<&PyAny> = Vec::new();
+    let mut nan_seen = false;
+    let mut errors: Vec<ValLineError> = Vec::new();
+
+    for (index, item) in items.enumerate() {
+        let duplicate = match item.downcast::<PyFloat>() {
+            // NaN is handled here since set lookups would consider the same NaN object equal to itself
+            Ok(float) if float.value().is_nan() => match nan_equality {
+                NanEquality::Ieee => false,
+                NanEquality::Identity => std::mem::replace(&mut nan_seen, true),
+            },
+            _ if item.hash().is_ok() => {
+                let seen = hashable_seen.contains(item)?;
+                if !seen {
+                    hashable_seen.add(item)?;
+                }
+                seen
+            }
+            _ => {
+                let mut seen = false;
+                for other in &unhashable_seen {
+                    if other.eq(item)? {
+                        seen = true;
+                        break;
+                    }
+                }
+                if !seen {
+                    unhashable_seen.push(item);
+                }
+                seen
+            }
+        };
+        if duplicate {
+            errors.push(ValLineError::new_with_loc(ErrorType::ListUniqueItems, item, index));
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ValError::LineErrors(errors))
+    }
+}
@@ -1,16 +1,20 @@
+use pyo3::exceptions::PyTypeError;
 use pyo3::intern;
+use pyo3::once_cell::GILOnceCell;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyString};
+use pyo3::PyTypeInfo;
 
 use ahash::AHashSet;
 
-use crate::build_tools::{py_err, SchemaDict};
+use crate::build_tools::{function_name, py_err, SchemaDict};
 use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::Input;
+use crate::input::{EitherString, Input};
+use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
 use super::none::NoneValidator;
-use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
 
 #[derive(Debug)]
 pub struct LiteralBuilder;
@@ -23,26 +27,38 @@ impl BuildValidator for LiteralBuilder {
         config: Option<&PyDict>,
         build_context: &mut BuildContext<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
-        let expected: &PyList = schema.get_as_req(intern!(schema.py(), "expected"))?;
+        let py = schema.py();
+        let factory: Option<&PyAny> = schema.get_as(intern!(py, "allowed_values_factory"))?;
+        let expected: Option<&PyList> = schema.get_as(intern!(py, "expected"))?;
+        let expected = match (expected, factory) {
+            (Some(expected), None) => expected,
+            (None, Some(factory)) => return Ok(LiteralFactoryValidator::new(factory, config)?.into()),
+            _ => return py_err!(r#"exactly one of "expected" or "allowed_values_factory" should be provided"#),
+        };
         if expected.is_empty() {
             return py_err!(r#""expected" should have length > 0"#);
+        }
+        // enum members are also ints or strings if they mix in those types, but the member itself is returned
+        let enum_type = get_enum_type(py);
+        if expected.iter().any(|item| item.is_instance(enum_type).unwrap_or(false)) {
+            return Ok(LiteralGeneralValidator::new(expected, config)?.into());
         } else if expected.len() == 1 {
             let first = expected.get_item(0)?;
             if let Ok(py_str) = first.downcast::<PyString>() {
-                return Ok(LiteralSingleStringValidator::new(py_str.to_str()?.to_string()).into());
+                return Ok(LiteralSingleStringValidator::new(py, py_str.to_str()?.to_string(), config)?.into());
             } else if let Ok(int) = first.extract::<i64>() {
-                return Ok(LiteralSingleIntValidator::new(int).into());
+                return Ok(LiteralSingleIntValidator::new(py, int, config)?.into());
             } else if first.is_none() {
                 return NoneValidator::build(schema, config, build_context);
             }
         }
 
-        if let Some(v) = LiteralMultipleStringsValidator::new(expected) {
+        if let Some(v) = LiteralMultipleStringsValidator::new(expected, config)? {
             Ok(v.into())
-        } else if let Some(v) = LiteralMultipleIntsValidator::new(expected) {
+        } else if let Some(v) = LiteralMultipleIntsValidator::new(expected, config)? {
             Ok(v.into())
         } else {
-            Ok(LiteralGeneralValidator::new(expected)?.into())
+            Ok(LiteralGeneralValidator::new(expected, config)?.into())
         }
     }
 }
@@ -50,19 +66,19 @@ impl BuildValidator for LiteralBuilder {
 #[derive(Debug, Clone)]
 pub struct LiteralSingleStringValidator {
     expected: String,
-    expected_repr: String,
+    expected_repr: ExpectedRepr,
     name: String,
 }
 
 impl LiteralSingleStringValidator {
-    fn new(expected: String) -> Self {
-        let expected_repr = format!("'{expected}'");
-        let name = format!("literal[{expected_repr}]");
-        Self {
+    fn new(py: Python, expected: String, config: Option<&PyDict>) -> PyResult<Self> {
+        let repr_args = vec![format!("'{expected}'")];
+        let name = format!("literal[{}]", repr_args[0]);
+        Ok(Self {
             expected,
-            expected_repr,
+            expected_repr: ExpectedRepr::new(py, &repr_args, " or ", config)?,
             name,
-        }
+        })
     }
 }
 
@@ -79,19 +95,24 @@ impl Validator for LiteralSingleStringValidator {
         if either_str.as_cow()?.as_ref() == self.expected.as_str() {
             Ok(input.to_object(py))
         } else {
-            Err(ValError::new(
-                ErrorType::LiteralError {
-                    expected: self.expected_repr.clone(),
-                },
-                input,
-            ))
+            Err(ValError::new(self.expected_repr.literal_error(), input))
         }
     }
 
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, LiteralBuilder::EXPECTED_TYPE)
+            .constraint("expected", Some([&self.expected]))?
+            .finish()
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
 
+    fn ask(&self, question: &Question) -> bool {
+        matches!(question, Question::ReturnsImmutable)
+    }
+
     fn complete(&mut self, _build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
         Ok(())
     }
@@ -100,15 +121,17 @@ impl Validator for LiteralSingleStringValidator {
 #[derive(Debug, Clone)]
 pub struct LiteralSingleIntValidator {
     expected: i64,
+    expected_repr: ExpectedRepr,
     name: String,
 }
 
 impl LiteralSingleIntValidator {
-    fn new(expected: i64) -> Self {
-        Self {
+    fn new(py: Python, expected: i64, config: Option<&PyDict>) -> PyResult<Self> {
+        Ok(Self {
             expected,
+            expected_repr: ExpectedRepr::new(py, &[expected.to_string()], " or ", config)?,
             name: format!("literal[{expected}]"),
-        }
+        })
     }
 }
 
@@ -125,19 +148,24 @@ impl Validator for LiteralSingleIntValidator {
         if int == self.expected {
             Ok(input.to_object(py))
         } else {
-            Err(ValError::new(
-                ErrorType::LiteralError {
-                    expected: self.expected.to_string(),
-                },
-                input,
-            ))
+            Err(ValError::new(self.expected_repr.literal_error(), input))
         }
     }
 
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, LiteralBuilder::EXPECTED_TYPE)
+            .constraint("expected", Some([self.expected]))?
+            .finish()
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
 
+    fn ask(&self, question: &Question) -> bool {
+        matches!(question, Question::ReturnsImmutable)
+    }
+
     fn complete(&mut self, _build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
         Ok(())
     }
@@ -146,28 +174,33 @@ impl Validator for LiteralSingleIntValidator {
 #[derive(Debug, Clone)]
 pub struct LiteralMultipleStringsValidator {
     expected: AHashSet<String>,
-    expected_repr: String,
+    // the same values as interned python strings, so `str` inputs from python are checked without copying them
+    expected_py: Py<PyDict>,
+    expected_repr: ExpectedRepr,
     name: String,
 }
 
 impl LiteralMultipleStringsValidator {
-    fn new(expected_list: &PyList) -> Option<Self> {
-        let mut expected: AHashSet<String> = AHashSet::new();
+    fn new(expected_list: &PyList, config: Option<&PyDict>) -> PyResult<Option<Self>> {
+        let py = expected_list.py();
+        let mut expected: AHashSet<String> = AHashSet::with_capacity(expected_list.len());
+        let expected_py = PyDict::new(py);
         let mut repr_args = Vec::new();
         for item in expected_list.iter() {
-            if let Ok(str) = item.extract() {
+            if let Ok(str) = item.extract::<String>() {
                 repr_args.push(format!("'{str}'"));
+                expected_py.set_item(PyString::intern(py, &str), py.None())?;
                 expected.insert(str);
             } else {
-                return None;
+                return Ok(None);
             }
         }
-        let (expected_repr, name) = expected_repr_name(repr_args, "literal");
-        Some(Self {
+        Ok(Some(Self {
             expected,
-            expected_repr,
-            name,
-        })
+            expected_py: expected_py.into(),
+            expected_repr: ExpectedRepr::new(expected_list.py(), &repr_args, " or ", config)?,
+            name: literal_name(&repr_args),
+        }))
     }
 }
 
@@ -181,22 +214,36 @@ impl Validator for LiteralMultipleStringsValidator {
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
         let either_str = input.validate_str(extra.strict.unwrap_or(false))?;
-        if self.expected.contains(either_str.as_cow()?.as_ref()) {
+        let found = match either_str {
+            // only exact strings, subclasses (e.g. str enums) may hash differently to their value
+            EitherString::Py(py_str) if py_str.get_type().is(PyString::type_object(py)) => {
+                self.expected_py.as_ref(py).contains(py_str)?
+            }
+            _ => self.expected.contains(either_str.as_cow()?.as_ref()),
+        };
+        if found {
             Ok(input.to_object(py))
         } else {
-            Err(ValError::new(
-                ErrorType::LiteralError {
-                    expected: self.expected_repr.clone(),
-                },
-                input,
-            ))
+            Err(ValError::new(self.expected_repr.literal_error(), input))
         }
     }
 
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        let mut expected: Vec<&String> = self.expected.iter().collect();
+        expected.sort();
+        Description::new(py, LiteralBuilder::EXPECTED_TYPE)
+            .constraint("expected", Some(expected))?
+            .finish()
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
 
+    fn ask(&self, question: &Question) -> bool {
+        matches!(question, Question::ReturnsImmutable)
+    }
+
     fn complete(&mut self, _build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
         Ok(())
     }
@@ -205,12 +252,12 @@ impl Validator for LiteralMultipleStringsValidator {
 #[derive(Debug, Clone)]
 pub struct LiteralMultipleIntsValidator {
     expected: AHashSet<i64>,
-    expected_repr: String,
+    expected_repr: ExpectedRepr,
     name: String,
 }
 
 impl LiteralMultipleIntsValidator {
-    fn new(expected_list: &PyList) -> Option<Self> {
+    fn new(expected_list: &PyList, config: Option<&PyDict>) -> PyResult<Option<Self>> {
         let mut expected: AHashSet<i64> = AHashSet::with_capacity(expected_list.len());
         let mut repr_args = Vec::new();
         for item in expected_list.iter() {
@@ -218,15 +265,14 @@ impl LiteralMultipleIntsValidator {
                 expected.insert(int);
                 repr_args.push(int.to_string());
             } else {
-                return None;
+                return Ok(None);
             }
         }
-        let (expected_repr, name) = expected_repr_name(repr_args, "literal");
-        Some(Self {
+        Ok(Some(Self {
             expected,
-            expected_repr,
-            name,
-        })
+            expected_repr: ExpectedRepr::new(expected_list.py(), &repr_args, " or ", config)?,
+            name: literal_name(&repr_args),
+        }))
     }
 }
 
@@ -243,19 +289,26 @@ impl Validator for LiteralMultipleIntsValidator {
         if self.expected.contains(&int) {
             Ok(input.to_object(py))
         } else {
-            Err(ValError::new(
-                ErrorType::LiteralError {
-                    expected: self.expected_repr.clone(),
-                },
-                input,
-            ))
+            Err(ValError::new(self.expected_repr.literal_error(), input))
         }
     }
 
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        let mut expected: Vec<i64> = self.expected.iter().copied().collect();
+        expected.sort_unstable();
+        Description::new(py, LiteralBuilder::EXPECTED_TYPE)
+            .constraint("expected", Some(expected))?
+            .finish()
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
 
+    fn ask(&self, question: &Question) -> bool {
+        matches!(question, Question::ReturnsImmutable)
+    }
+
     fn complete(&mut self, _build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
         Ok(())
     }
@@ -265,35 +318,56 @@ impl Validator for LiteralMultipleIntsValidator {
 pub struct LiteralGeneralValidator {
     expected_int: AHashSet<i64>,
     expected_str: AHashSet<String>,
-    expected_py: Py<PyList>,
-    expected_repr: String,
+    // other hashable values, checked with a single lookup
+    expected_py: Py<PyDict>,
+    // enum members, mapped to themselves so they're found by members and by values which compare equal to them
+    expected_enum: Py<PyDict>,
+    // the values of enum members mapped to the member, used in lax mode
+    expected_enum_values: Py<PyDict>,
+    // unhashable values which can only be compared one by one
+    expected_unhashable: Py<PyList>,
+    expected_repr: ExpectedRepr,
     name: String,
 }
 
 impl LiteralGeneralValidator {
-    fn new(expected: &PyList) -> PyResult<Self> {
+    fn new(expected: &PyList, config: Option<&PyDict>) -> PyResult<Self> {
         let mut expected_int = AHashSet::new();
         let mut expected_str = AHashSet::new();
         let py = expected.py();
-        let expected_py = PyList::empty(py);
+        let enum_type = get_enum_type(py);
+        let expected_py = PyDict::new(py);
+        let expected_enum = PyDict::new(py);
+        let expected_enum_values = PyDict::new(py);
+        let expected_unhashable = PyList::empty(py);
         let mut repr_args: Vec<String> = Vec::new();
         for item in expected.iter() {
             repr_args.push(item.repr()?.extract()?);
-            if let Ok(int) = item.extract::<i64>() {
+            if item.is_instance(enum_type)? {
+                expected_enum.set_item(item, item)?;
+                let value = item.getattr(intern!(py, "value"))?;
+                if value.hash().is_ok() {
+                    expected_enum_values.set_item(value, item)?;
+                }
+            } else if let Ok(int) = item.extract::<i64>() {
                 expected_int.insert(int);
             } else if let Ok(py_str) = item.downcast::<PyString>() {
                 expected_str.insert(py_str.to_str()?.to_string());
+            } else if item.hash().is_ok() {
+                expected_py.set_item(item, py.None())?;
             } else {
-                expected_py.append(item)?;
+                expected_unhashable.append(item)?;
             }
         }
-        let (expected_repr, name) = expected_repr_name(repr_args, "literal");
         Ok(Self {
             expected_int,
             expected_str,
-            expected_py: expected_py.into_py(py),
-            expected_repr,
-            name,
+            expected_py: expected_py.into(),
+            expected_enum: expected_enum.into(),
+            expected_enum_values: expected_enum_values.into(),
+            expected_unhashable: expected_unhashable.into(),
+            expected_repr: ExpectedRepr::new(py, &repr_args, " or ", config)?,
+            name: literal_name(&repr_args),
         })
     }
 }
@@ -308,34 +382,143 @@ impl Validator for LiteralGeneralValidator {
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
         let strict = extra.strict.unwrap_or(false);
+        let py_value = input.to_object(py);
+
+        // unhashable inputs simply aren't found by `get_item`
+        let expected_enum = self.expected_enum.as_ref(py);
+        if !expected_enum.is_empty() {
+            if let Some(member) = expected_enum.get_item(&py_value) {
+                // in strict mode only the member itself is accepted, not values which compare equal to it
+                if !strict || member.is(&py_value) {
+                    return Ok(member.into_py(py));
+                }
+            }
+        }
         if !self.expected_int.is_empty() {
             if let Ok(int) = input.validate_int(strict) {
                 if self.expected_int.contains(&int) {
-                    return Ok(input.to_object(py));
+                    return Ok(py_value);
                 }
             }
         }
         if !self.expected_str.is_empty() {
             if let Ok(either_str) = input.validate_str(strict) {
                 if self.expected_str.contains(either_str.as_cow()?.as_ref()) {
-                    return Ok(input.to_object(py));
+                    return Ok(py_value);
                 }
             }
         }
 
-        let py_value = input.to_object(py);
-
         let expected_py = self.expected_py.as_ref(py);
-        if !expected_py.is_empty() && expected_py.contains(&py_value)? {
+        if !expected_py.is_empty() && expected_py.get_item(&py_value).is_some() {
             return Ok(py_value);
         }
+        if !strict {
+            if let Some(member) = self.expected_enum_values.as_ref(py).get_item(&py_value) {
+                return Ok(member.into_py(py));
+            }
+        }
+        let expected_unhashable = self.expected_unhashable.as_ref(py);
+        if !expected_unhashable.is_empty() && expected_unhashable.contains(&py_value)? {
+            return Ok(py_value);
+        }
+
+        Err(ValError::new(self.expected_repr.literal_error(), input))
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        // ints and strings are sorted since they're stored in sets, other values keep the schema's order
+        let mut ints: Vec<i64> = self.expected_int.iter().copied().collect();
+        ints.sort_unstable();
+        let mut strings: Vec<&String> = self.expected_str.iter().collect();
+        strings.sort();
+        let expected = PyList::new(py, ints);
+        for string in strings {
+            expected.append(string)?;
+        }
+        for item in self.expected_py.as_ref(py).keys() {
+            expected.append(item)?;
+        }
+        for item in self.expected_enum.as_ref(py).keys() {
+            expected.append(item)?;
+        }
+        for item in self.expected_unhashable.as_ref(py) {
+            expected.append(item)?;
+        }
+        Description::new(py, LiteralBuilder::EXPECTED_TYPE)
+            .constraint("expected", Some(expected))?
+            .finish()
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn complete(&mut self, _build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
+        Ok(())
+    }
+}
+
+static ENUM_TYPE: GILOnceCell<PyObject> = GILOnceCell::new();
+
+fn get_enum_type(py: Python<'_>) -> &PyAny {
+    ENUM_TYPE
+        .get_or_init(py, || py.import("enum").unwrap().getattr("Enum").unwrap().into_py(py))
+        .as_ref(py)
+}
+
+/// Checks the input against the values returned by `allowed_values_factory`, which is called each time this
+/// validator runs so the allowed values can change between validations, values are compared as they are by
+/// `LiteralGeneralValidator` and errors show the values allowed at the time
+#[derive(Debug, Clone)]
+pub struct LiteralFactoryValidator {
+    factory: PyObject,
+    config: Option<Py<PyDict>>,
+    name: String,
+}
 
-        Err(ValError::new(
-            ErrorType::LiteralError {
-                expected: self.expected_repr.clone(),
-            },
+impl LiteralFactoryValidator {
+    fn new(factory: &PyAny, config: Option<&PyDict>) -> PyResult<Self> {
+        // check the config now rather than on every validation
+        ExpectedRepr::new(factory.py(), &[], " or ", config)?;
+        Ok(Self {
+            factory: factory.into_py(factory.py()),
+            config: config.map(Into::into),
+            name: format!("literal[{}()]", function_name(factory)?),
+        })
+    }
+}
+
+impl Validator for LiteralFactoryValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let allowed_values = self.factory.call0(py)?.into_ref(py);
+        let allowed_values = match allowed_values.iter() {
+            Ok(iter) => iter.collect::<PyResult<Vec<&PyAny>>>()?,
+            Err(_) => {
+                let type_name = allowed_values.get_type().name().unwrap_or("<unknown type>");
+                let msg = format!("`allowed_values_factory` should return an iterable, got `{type_name}`");
+                return Err(PyTypeError::new_err(msg).into());
+            }
+        };
+        let config = self.config.as_ref().map(|config| config.as_ref(py));
+        LiteralGeneralValidator::new(PyList::new(py, allowed_values), config)?.validate(
+            py,
             input,
-        ))
+            extra,
+            slots,
+            recursion_guard,
+        )
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, LiteralBuilder::EXPECTED_TYPE).opaque().finish()
     }
 
     fn get_name(&self) -> &str {
@@ -347,6 +530,85 @@ impl Validator for LiteralGeneralValidator {
     }
 }
 
+/// the name is used in union error locations, so very large literals only include the first values
+fn literal_name(repr_args: &[String]) -> String {
+    if repr_args.len() > DEFAULT_EXPECTED_MAX_ITEMS {
+        format!(
+            "literal[{},…and {} more]",
+            repr_args[..DEFAULT_EXPECTED_MAX_ITEMS].join(","),
+            repr_args.len() - DEFAULT_EXPECTED_MAX_ITEMS
+        )
+    } else {
+        format!("literal[{}]", repr_args.join(","))
+    }
+}
+
+const DEFAULT_EXPECTED_MAX_ITEMS: usize = 20;
+
+/// The expected values shown in errors, in declaration order and de-duplicated, values after
+/// `error_expected_max_items` are replaced with "…and N more"; the full repr is only kept
+/// if `error_expected_full` is set in config
+#[derive(Debug, Clone)]
+pub struct ExpectedRepr {
+    repr: String,
+    full: Option<String>,
+}
+
+impl ExpectedRepr {
+    pub fn new(py: Python, repr_args: &[String], last_separator: &str, config: Option<&PyDict>) -> PyResult<Self> {
+        let mut seen: AHashSet<&str> = AHashSet::with_capacity(repr_args.len());
+        let unique: Vec<&str> = repr_args
+            .iter()
+            .map(String::as_str)
+            .filter(|repr| seen.insert(repr))
+            .collect();
+
+        let max_items: usize = config
+            .get_as(intern!(py, "error_expected_max_items"))?
+            .unwrap_or(DEFAULT_EXPECTED_MAX_ITEMS);
+        if max_items == 0 {
+            return py_err!("`error_expected_max_items` must be greater than 0");
+        }
+        let repr = if unique.len() > max_items {
+            format!(
+                "{} …and {} more",
+                unique[..max_items].join(", "),
+                unique.len() - max_items
+            )
+        } else {
+            join_expected(&unique, last_separator)
+        };
+        let full = match config.get_as(intern!(py, "error_expected_full"))? {
+            Some(true) => Some(join_expected(&unique, last_separator)),
+            _ => None,
+        };
+        Ok(Self { repr, full })
+    }
+
+    pub fn literal_error(&self) -> ErrorType {
+        ErrorType::LiteralError {
+            expected: self.repr.clone(),
+            expected_full: self.full.clone(),
+        }
+    }
+
+    pub fn repr(&self) -> &str {
+        &self.repr
+    }
+
+    pub fn full(&self) -> Option<&str> {
+        self.full.as_deref()
+    }
+}
+
+fn join_expected(reprs: &[&str], last_separator: &str) -> String {
+    match reprs.split_last() {
+        Some((last, [])) => last.to_string(),
+        Some((last, rest)) => format!("{}{last_separator}{last}", rest.join(", ")),
+        None => String::new(),
+    }
+}
+
 pub fn expected_repr_name(mut repr_args: Vec<String>, base_name: &'static str) -> (String, String) {
     let name = format!("{base_name}[{}]", repr_args.join(","));
     // unwrap is okay since we check the length in build at the top of this file
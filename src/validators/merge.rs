@@ -0,0 +1,168 @@
+use pyo3::exceptions::PyTypeError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyTuple};
+
+use crate::build_tools::{py_err, SchemaDict};
+use crate::errors::{ErrorType, ValError, ValLineError, ValResult};
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
+
+/// How to handle a key returned by more than one schema with different values
+#[derive(Debug, Clone, Copy)]
+enum MergeConflict {
+    First,
+    Last,
+    Error,
+}
+
+impl MergeConflict {
+    fn from_schema(schema: &PyDict) -> PyResult<Self> {
+        match schema.get_as::<&str>(intern!(schema.py(), "conflict"))? {
+            Some("first") => Ok(Self::First),
+            Some("last") | None => Ok(Self::Last),
+            Some("error") => Ok(Self::Error),
+            Some(s) => py_err!(
+                "Invalid merge conflict policy: `{}`, expected `first`, `last` or `error`",
+                s
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MergeValidator {
+    validators: Vec<CombinedValidator>,
+    conflict: MergeConflict,
+    name: String,
+}
+
+impl BuildValidator for MergeValidator {
+    const EXPECTED_TYPE: &'static str = "merge";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let validators: Vec<CombinedValidator> = schema
+            .get_as_req::<&PyList>(intern!(py, "schemas"))?
+            .iter()
+            .map(|sub_schema| build_validator(sub_schema, config, build_context))
+            .collect::<PyResult<Vec<CombinedValidator>>>()?;
+        if validators.is_empty() {
+            return py_err!("One or more merge schemas required");
+        }
+
+        let descr = validators.iter().map(|v| v.get_name()).collect::<Vec<_>>().join(",");
+        Ok(Self {
+            validators,
+            conflict: MergeConflict::from_schema(schema)?,
+            name: format!("{}[{descr}]", Self::EXPECTED_TYPE),
+        }
+        .into())
+    }
+}
+
+impl Validator for MergeValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let output_dict = PyDict::new(py);
+        let mut errors: Vec<ValLineError> = Vec::new();
+
+        for validator in &self.validators {
+            let _loc = extra.coercion_loc(|| [validator.get_name().into()]);
+            extra.report_raw_input(py, input);
+            let output = match validator.validate(py, input, extra, slots, recursion_guard) {
+                Ok(output) => output,
+                Err(ValError::LineErrors(line_errors)) => {
+                    errors.extend(
+                        line_errors
+                            .into_iter()
+                            .map(|err| err.with_outer_location(validator.get_name().into())),
+                    );
+                    continue;
+                }
+                Err(err) => return Err(err),
+            };
+            // once there are errors the output won't be used, so there's no need to merge it
+            if !errors.is_empty() {
+                continue;
+            }
+
+            for (key, value) in output_as_dict(py, output.into_ref(py), validator.get_name())? {
+                match (output_dict.get_item(key), self.conflict) {
+                    (None, _) | (Some(_), MergeConflict::Last) => output_dict.set_item(key, value)?,
+                    (Some(_), MergeConflict::First) => (),
+                    (Some(existing), MergeConflict::Error) => {
+                        if !existing.eq(value)? {
+                            let key_str = key.str()?.to_string();
+                            errors.push(ValLineError::new_with_loc(
+                                ErrorType::MergeConflict { key: key_str.clone() },
+                                input,
+                                key_str,
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(output_dict.into_py(py))
+        } else {
+            Err(ValError::LineErrors(errors))
+        }
+    }
+
+    fn set_strict(&mut self, strict: bool) {
+        self.validators.iter_mut().for_each(|v| v.set_strict(strict));
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        let mut description = Description::new(py, Self::EXPECTED_TYPE);
+        for (index, validator) in self.validators.iter().enumerate() {
+            description = description.child(index, validator)?;
+        }
+        description.finish()
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn complete(&mut self, build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
+        self.validators.iter_mut().try_for_each(|v| v.complete(build_context))
+    }
+}
+
+/// the output of sub-validators is generally a dict, but models are merged using their `__dict__`, and
+/// schemas returning a tuple with the fields dict first (e.g. `(fields, fields_set)` from `return_fields_set`
+/// or `(fields, init_only_args)` from dataclass args) are merged using that dict
+fn output_as_dict<'py>(py: Python<'py>, output: &'py PyAny, name: &str) -> PyResult<&'py PyDict> {
+    if let Ok(dict) = output.downcast::<PyDict>() {
+        return Ok(dict);
+    }
+    if let Ok(tuple) = output.downcast::<PyTuple>() {
+        if let Ok(Ok(dict)) = tuple.get_item(0).map(|first| first.downcast::<PyDict>()) {
+            return Ok(dict);
+        }
+    }
+    match output.getattr(intern!(py, "__dict__")) {
+        Ok(dict) => Ok(dict.downcast::<PyDict>()?),
+        Err(_) => Err(PyTypeError::new_err(format!(
+            "merge schema `{name}` must return a dict, a tuple starting with a dict or an object with a `__dict__`, \
+             got `{}`",
+            output.get_type().name()?
+        ))),
+    }
+}
@@ -1,19 +1,25 @@
+use std::cell::Cell;
 use std::fmt::Debug;
 
 use enum_dispatch::enum_dispatch;
 
-use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::once_cell::GILOnceCell;
 use pyo3::prelude::*;
-use pyo3::types::{PyAny, PyDict};
+use pyo3::pyclass::CompareOp;
+use pyo3::types::{PyAny, PyBytes, PyDict, PyList, PyLong, PyString, PyTuple, PyType};
 use pyo3::{intern, PyTraverseError, PyVisit};
 
-use crate::build_context::BuildContext;
-use crate::build_tools::{py_err, py_error_type, SchemaDict, SchemaError};
-use crate::errors::{ErrorMode, LocItem, ValError, ValResult, ValidationError};
-use crate::input::Input;
+use crate::build_context::{BuildContext, Definition, DefinitionKind};
+use crate::build_tools::{check_picklable, py_err, py_error_type, SchemaDict, SchemaError};
+use crate::errors::{CustomErrorMessages, ErrorMode, ErrorType, LocItem, ValError, ValResult, ValidationError};
+use crate::input::{
+    parse_json_utf8, parse_partial_json, strings_as_json, DuplicateKeys, EitherString, Input, InputType, StringCache,
+};
+use crate::memory_report::memory_report;
 use crate::questions::{Answers, Question};
-use crate::recursion_guard::RecursionGuard;
+use crate::recursion_guard::{RecursionGuard, DEFAULT_RECURSION_LIMIT, MAX_RECURSION_LIMIT};
+use crate::sub_schema::find_sub_schema;
 
 mod any;
 mod arguments;
@@ -22,46 +28,91 @@ mod bytes;
 mod call;
 mod callable;
 mod chain;
+mod coercions;
+mod complex;
 mod custom_error;
 mod dataclass;
 mod date;
 mod datetime;
+mod decimal;
 mod definitions;
+mod describe;
 mod dict;
+mod enum_;
+mod error_limit;
+mod error_overrides;
 mod float;
+mod fraction;
 mod frozenset;
 mod function;
 mod generator;
 mod int;
+mod ip;
 mod is_instance;
 mod is_subclass;
 mod json;
+mod json_or_python;
 mod lax_or_strict;
 mod list;
 mod literal;
+mod merge;
 mod model;
 mod none;
 mod nullable;
+mod path;
 mod set;
+mod strict_override;
 mod string;
 mod time;
 mod timedelta;
+mod timezone;
+#[cfg(feature = "timings")]
+mod timings;
 mod tuple;
+mod type_var;
 mod typed_dict;
 mod union;
 mod url;
+mod uuid;
 mod with_default;
 
+pub use chain::chain_output_schema;
+pub use dataclass::dataclass_slot_fields;
+pub use error_limit::ErrorLimit;
+pub use union::union_choice_schema;
 pub use with_default::DefaultType;
 
+use coercions::{CoercionCheckpoint, CoercionLoc, CoercionReport};
+use describe::Description;
+
+#[cfg(feature = "timings")]
+use timings::timer;
+
+// validators aren't timed without the `timings` feature
+#[cfg(not(feature = "timings"))]
+macro_rules! timer {
+    ($name:expr) => {};
+}
+#[cfg(not(feature = "timings"))]
+use timer;
+
 #[pyclass(module = "pydantic_core._pydantic_core")]
 #[derive(Debug, Clone)]
 pub struct SchemaValidator {
     validator: CombinedValidator,
     slots: Vec<CombinedValidator>,
+    definitions: Vec<Definition>,
     schema: PyObject,
+    config: Option<Py<PyDict>>,
     #[pyo3(get)]
     title: PyObject,
+    max_str_bytes: Option<usize>,
+    duplicate_keys: DuplicateKeys,
+    recursion_limit: u16,
+    hide_input_in_errors: bool,
+    custom_error_messages: Option<CustomErrorMessages>,
+    max_errors: Option<usize>,
+    string_cache: StringCache,
 }
 
 #[pymethods]
@@ -75,6 +126,7 @@ impl SchemaValidator {
 
         let mut validator = build_validator(schema, config, &mut build_context)?;
         validator.complete(&build_context)?;
+        let definitions = build_context.definitions();
         let slots = build_context.into_slots_val()?;
         let config_title = match config {
             Some(c) => c.get_item("title"),
@@ -87,18 +139,48 @@ impl SchemaValidator {
         Ok(Self {
             validator,
             slots,
+            definitions,
             schema: schema.into_py(py),
+            config: config.map(Into::into),
             title,
+            max_str_bytes: config.get_as(intern!(py, "max_str_bytes"))?,
+            duplicate_keys: DuplicateKeys::from_config(config)?,
+            recursion_limit: check_recursion_limit(config.get_as(intern!(py, "recursion_limit"))?)?
+                .unwrap_or(DEFAULT_RECURSION_LIMIT),
+            hide_input_in_errors: config.get_as(intern!(py, "hide_input_in_errors"))?.unwrap_or(false),
+            custom_error_messages: CustomErrorMessages::from_config(py, config)?,
+            max_errors: check_max_errors(config.get_as(intern!(py, "max_errors"))?)?,
+            string_cache: StringCache::from_config(config)?,
         })
     }
 
+    /// pickling rebuilds the validator from its schema and config, which fails if anything in them, e.g. a
+    /// function, can't be pickled
     pub fn __reduce__(&self, py: Python) -> PyResult<PyObject> {
-        let args = (self.schema.as_ref(py),);
-        let cls = Py::new(py, self.to_owned())?.getattr(py, "__class__")?;
-        Ok((cls, args).into_py(py))
+        let config = self.config.as_ref().map(|config| config.as_ref(py));
+        check_picklable(py, "SchemaValidator", self.schema.as_ref(py), config)?;
+        let args = (self.schema.as_ref(py), config);
+        Ok((py.get_type::<Self>(), args).into_py(py))
     }
 
-    #[pyo3(signature = (input, *, strict=None, context=None, self_instance=None))]
+    /// validators can't be changed once built, so copies are the same validator
+    fn __copy__(slf: &PyCell<Self>) -> &PyCell<Self> {
+        slf
+    }
+
+    fn __deepcopy__<'py>(slf: &'py PyCell<Self>, _memo: &PyAny) -> &'py PyCell<Self> {
+        slf
+    }
+
+    /// with `allow_partial`, the input is treated as possibly incomplete: missing fields and too few items aren't
+    /// errors, in the input or in the last item or value of each list, tuple and dict, recursively,
+    /// `recursion_limit` overrides the config's limit on how deeply definitions can recurse for this call,
+    /// `max_errors` overrides the config's limit on how many errors are collected before validation stops
+    #[pyo3(signature = (
+        input, *, strict=None, context=None, self_instance=None, max_steps=None, allow_partial=false,
+        recursion_limit=None, max_errors=None
+    ))]
+    #[allow(clippy::too_many_arguments)]
     pub fn validate_python(
         &self,
         py: Python,
@@ -106,9 +188,116 @@ impl SchemaValidator {
         strict: Option<bool>,
         context: Option<&PyAny>,
         self_instance: Option<&PyAny>,
+        max_steps: Option<usize>,
+        allow_partial: bool,
+        recursion_limit: Option<&PyLong>,
+        max_errors: Option<usize>,
     ) -> PyResult<PyObject> {
-        let r = self._validate(py, input, strict, context, self_instance);
-        r.map_err(|e| self.prepare_validation_err(py, e, ErrorMode::Python))
+        let error_limit = self.max_errors(max_errors)?.map(ErrorLimit::new);
+        let recursion_limit = self.recursion_limit(recursion_limit)?;
+        let recursion_guard = &mut self.recursion_guard(max_steps, Some(recursion_limit));
+        let partial_depth = match allow_partial {
+            true => usize::MAX,
+            false => 0,
+        };
+        let r = self._validate(
+            py,
+            input,
+            strict,
+            context,
+            self_instance,
+            partial_depth,
+            error_limit.as_ref(),
+            recursion_guard,
+        );
+        r.map_err(|e| self.prepare_validation_err(py, e, ErrorMode::Python, error_limit.as_ref()))
+    }
+
+    /// like `validate_python`, but also returns the cumulative time spent in model, list and union validators
+    /// keyed by the path of validator names leading to them, only available with the `timings` feature
+    #[cfg(feature = "timings")]
+    #[pyo3(signature = (input, *, strict=None, context=None))]
+    pub fn validate_python_timings(
+        &self,
+        py: Python,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> PyResult<(PyObject, Py<PyDict>)> {
+        let error_limit = self.max_errors.map(ErrorLimit::new);
+        let (r, timings) = timings::collect(py, || {
+            self._validate(
+                py,
+                input,
+                strict,
+                context,
+                None,
+                0,
+                error_limit.as_ref(),
+                &mut self.recursion_guard(None, None),
+            )
+        })?;
+        let output = r.map_err(|e| self.prepare_validation_err(py, e, ErrorMode::Python, error_limit.as_ref()))?;
+        Ok((output, timings))
+    }
+
+    /// like `validate_python`, but also returns a report of every lax coercion made, e.g. `str` to `int`,
+    /// for finding values which would fail validation in strict mode, with `report_defaults` the report
+    /// also includes every value which came from a `default` or `default_factory`
+    #[pyo3(signature = (input, *, strict=None, context=None, report_defaults=false))]
+    pub fn validate_python_with_report(
+        &self,
+        py: Python,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+        report_defaults: bool,
+    ) -> PyResult<(PyObject, Py<PyList>)> {
+        let error_limit = self.max_errors.map(ErrorLimit::new);
+        let report = CoercionReport::new(report_defaults);
+        let extra = Extra::builder()
+            .strict(strict)
+            .context(context)
+            .coercions(Some(&report))
+            .hide_input(self.hide_input_in_errors)
+            .custom_error_messages(self.custom_error_messages.as_ref())
+            .build();
+        let output = self
+            .validator
+            .validate(py, input, &extra, &self.slots, &mut self.recursion_guard(None, None))
+            .map_err(|e| self.prepare_validation_err(py, e, ErrorMode::Python, error_limit.as_ref()))?;
+        Ok((output, report.to_py(py)?))
+    }
+
+    /// like `validate_python`, but returns the non-fatal warnings emitted by validators alongside the output
+    #[pyo3(signature = (input, *, strict=None, context=None))]
+    pub fn validate_python_with_warnings(
+        &self,
+        py: Python,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> PyResult<(PyObject, Py<PyList>)> {
+        let error_limit = self.max_errors.map(ErrorLimit::new);
+        self._validate_with_warnings(py, input, strict, context)
+            .map_err(|e| self.prepare_validation_err(py, e, ErrorMode::Python, error_limit.as_ref()))
+    }
+
+    /// like `validate_python`, but returns the input before coercion alongside the output, with `record_nodes`
+    /// the raw input is a dict of the input at every location validated, containers and their items alike, keyed by
+    /// location and including the whole input at `()`
+    #[pyo3(signature = (input, *, strict=None, context=None, record_nodes=false))]
+    pub fn validate_python_with_raw(
+        &self,
+        py: Python,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+        record_nodes: bool,
+    ) -> PyResult<(PyObject, PyObject)> {
+        let error_limit = self.max_errors.map(ErrorLimit::new);
+        self._validate_with_raw(py, input, strict, context, record_nodes)
+            .map_err(|e| self.prepare_validation_err(py, e, ErrorMode::Python, error_limit.as_ref()))
     }
 
     #[pyo3(signature = (input, *, strict=None, context=None, self_instance=None))]
@@ -120,7 +309,16 @@ impl SchemaValidator {
         context: Option<&PyAny>,
         self_instance: Option<&PyAny>,
     ) -> PyResult<bool> {
-        match self._validate(py, input, strict, context, self_instance) {
+        match self._validate(
+            py,
+            input,
+            strict,
+            context,
+            self_instance,
+            0,
+            self.max_errors.map(ErrorLimit::new).as_ref(),
+            &mut self.recursion_guard(None, None),
+        ) {
             Ok(_) => Ok(true),
             Err(ValError::InternalErr(err)) => Err(err),
             Err(ValError::Omit) => Err(ValidationError::omit_error()),
@@ -128,7 +326,15 @@ impl SchemaValidator {
         }
     }
 
-    #[pyo3(signature = (input, *, strict=None, context=None, self_instance=None))]
+    /// with `max_steps`, parsing counts each JSON value and key against the budget, validation gets what's left,
+    /// with `allow_partial`, JSON which was cut off is completed by dropping the string, number or literal which
+    /// was cut off and closing the arrays and objects left open, which are validated as with `validate_python`,
+    /// `recursion_limit` also limits how deeply arrays and objects can be nested in the JSON
+    #[pyo3(signature = (
+        input, *, strict=None, context=None, self_instance=None, max_steps=None, allow_partial=false,
+        recursion_limit=None, max_errors=None
+    ))]
+    #[allow(clippy::too_many_arguments)]
     pub fn validate_json(
         &self,
         py: Python,
@@ -136,13 +342,151 @@ impl SchemaValidator {
         strict: Option<bool>,
         context: Option<&PyAny>,
         self_instance: Option<&PyAny>,
+        max_steps: Option<usize>,
+        allow_partial: bool,
+        recursion_limit: Option<&PyLong>,
+        max_errors: Option<usize>,
     ) -> PyResult<PyObject> {
-        match input.parse_json() {
-            Ok(input) => {
-                let r = self._validate(py, &input, strict, context, self_instance);
-                r.map_err(|e| self.prepare_validation_err(py, e, ErrorMode::Json))
+        let error_limit = self.max_errors(max_errors)?.map(ErrorLimit::new);
+        let max_depth = self.recursion_limit(recursion_limit)?;
+        let steps_left = Cell::new(max_steps.unwrap_or(usize::MAX));
+        let parsed = match allow_partial {
+            true => parse_partial_json(input, self.max_str_bytes, self.duplicate_keys, max_depth, &steps_left),
+            false => input
+                .parse_json(self.max_str_bytes, self.duplicate_keys, max_depth, &steps_left)
+                .map(|input| (input, 0)),
+        };
+        match parsed {
+            Ok((input, partial_depth)) => {
+                let max_steps = max_steps.map(|_| steps_left.get());
+                let recursion_guard = &mut self.recursion_guard(max_steps, Some(max_depth));
+                let r = self._validate(
+                    py,
+                    &input,
+                    strict,
+                    context,
+                    self_instance,
+                    partial_depth,
+                    error_limit.as_ref(),
+                    recursion_guard,
+                );
+                r.map_err(|e| self.prepare_validation_err(py, e, ErrorMode::Json, error_limit.as_ref()))
             }
-            Err(err) => Err(self.prepare_validation_err(py, err, ErrorMode::Json)),
+            Err(err) => Err(self.prepare_validation_err(py, err, ErrorMode::Json, error_limit.as_ref())),
+        }
+    }
+
+    /// for inputs where every value is a string, e.g. environment variables or query strings, `input` is a `str`
+    /// or a dict or list of them, each string is validated the way a JSON string would be, e.g. `"123"` is a valid
+    /// int except in strict mode
+    #[pyo3(signature = (input, *, strict=None, context=None))]
+    pub fn validate_strings(
+        &self,
+        py: Python,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> PyResult<PyObject> {
+        let error_limit = self.max_errors.map(ErrorLimit::new);
+        match strings_as_json(input) {
+            Ok(input) => self
+                ._validate(
+                    py,
+                    &input,
+                    strict,
+                    context,
+                    None,
+                    0,
+                    error_limit.as_ref(),
+                    &mut self.recursion_guard(None, None),
+                )
+                .map_err(|e| self.prepare_validation_err(py, e, ErrorMode::Json, error_limit.as_ref())),
+            Err(err) => Err(self.prepare_validation_err(py, err, ErrorMode::Json, error_limit.as_ref())),
+        }
+    }
+
+    /// like `validate_json` for UTF-8 encoded bytes, which are parsed directly without decoding them to a `str`,
+    /// `json_invalid` errors include the byte offset where parsing failed as `position`
+    #[pyo3(signature = (input, *, strict=None, context=None, self_instance=None))]
+    pub fn validate_json_bytes(
+        &self,
+        py: Python,
+        input: &PyBytes,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+        self_instance: Option<&PyAny>,
+    ) -> PyResult<PyObject> {
+        let error_limit = self.max_errors.map(ErrorLimit::new);
+        match parse_json_utf8(
+            input.as_bytes(),
+            self.max_str_bytes,
+            self.duplicate_keys,
+            self.recursion_limit,
+            &Cell::new(usize::MAX),
+        ) {
+            Ok(json_input) => self
+                ._validate(
+                    py,
+                    &json_input,
+                    strict,
+                    context,
+                    self_instance,
+                    0,
+                    error_limit.as_ref(),
+                    &mut self.recursion_guard(None, None),
+                )
+                .map_err(|e| self.prepare_validation_err(py, e, ErrorMode::Json, error_limit.as_ref())),
+            Err(error_type) => {
+                let err = ValError::new(error_type, input.as_ref());
+                Err(self.prepare_validation_err(py, err, ErrorMode::Json, error_limit.as_ref()))
+            }
+        }
+    }
+
+    /// like `validate_python_with_raw` for JSON, raw inputs are the values parsed from JSON before coercion
+    #[pyo3(signature = (input, *, strict=None, context=None, record_nodes=false))]
+    pub fn validate_json_with_raw(
+        &self,
+        py: Python,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+        record_nodes: bool,
+    ) -> PyResult<(PyObject, PyObject)> {
+        let error_limit = self.max_errors.map(ErrorLimit::new);
+        match input.parse_json(
+            self.max_str_bytes,
+            self.duplicate_keys,
+            self.recursion_limit,
+            &Cell::new(usize::MAX),
+        ) {
+            Ok(input) => self
+                ._validate_with_raw(py, &input, strict, context, record_nodes)
+                .map_err(|e| self.prepare_validation_err(py, e, ErrorMode::Json, error_limit.as_ref())),
+            Err(err) => Err(self.prepare_validation_err(py, err, ErrorMode::Json, error_limit.as_ref())),
+        }
+    }
+
+    /// like `validate_python_with_warnings` for JSON
+    #[pyo3(signature = (input, *, strict=None, context=None))]
+    pub fn validate_json_with_warnings(
+        &self,
+        py: Python,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> PyResult<(PyObject, Py<PyList>)> {
+        let error_limit = self.max_errors.map(ErrorLimit::new);
+        match input.parse_json(
+            self.max_str_bytes,
+            self.duplicate_keys,
+            self.recursion_limit,
+            &Cell::new(usize::MAX),
+        ) {
+            Ok(input) => self
+                ._validate_with_warnings(py, &input, strict, context)
+                .map_err(|e| self.prepare_validation_err(py, e, ErrorMode::Json, error_limit.as_ref())),
+            Err(err) => Err(self.prepare_validation_err(py, err, ErrorMode::Json, error_limit.as_ref())),
         }
     }
 
@@ -155,8 +499,23 @@ impl SchemaValidator {
         context: Option<&PyAny>,
         self_instance: Option<&PyAny>,
     ) -> PyResult<bool> {
-        match input.parse_json() {
-            Ok(input) => match self._validate(py, &input, strict, context, self_instance) {
+        let error_limit = self.max_errors.map(ErrorLimit::new);
+        match input.parse_json(
+            self.max_str_bytes,
+            self.duplicate_keys,
+            self.recursion_limit,
+            &Cell::new(usize::MAX),
+        ) {
+            Ok(input) => match self._validate(
+                py,
+                &input,
+                strict,
+                context,
+                self_instance,
+                0,
+                error_limit.as_ref(),
+                &mut self.recursion_guard(None, None),
+            ) {
                 Ok(_) => Ok(true),
                 Err(ValError::InternalErr(err)) => Err(err),
                 Err(ValError::Omit) => Err(ValidationError::omit_error()),
@@ -176,18 +535,93 @@ impl SchemaValidator {
         strict: Option<bool>,
         context: Option<&PyAny>,
     ) -> PyResult<PyObject> {
-        let extra = Extra {
-            data: None,
-            strict,
-            context,
-            field_name: None,
-            self_instance: None,
-        };
+        let error_limit = self.max_errors.map(ErrorLimit::new);
+        let extra = Extra::builder()
+            .strict(strict)
+            .context(context)
+            .hide_input(self.hide_input_in_errors)
+            .custom_error_messages(self.custom_error_messages.as_ref())
+            .build();
 
-        let guard = &mut RecursionGuard::default();
+        let guard = &mut self.recursion_guard(None, None);
         self.validator
             .validate_assignment(py, obj, field_name, field_value, &extra, &self.slots, guard)
-            .map_err(|e| self.prepare_validation_err(py, e, ErrorMode::Python))
+            .map_err(|e| self.prepare_validation_err(py, e, ErrorMode::Python, error_limit.as_ref()))
+    }
+
+    /// the default of a `default` schema wrapped in `Some`, or `None` if the schema has no default, `default_factory`
+    /// is called on every call and the default is validated if `validate_default` is set
+    #[pyo3(signature = (*, strict=None, context=None))]
+    pub fn get_default_value(&self, py: Python, strict: Option<bool>, context: Option<&PyAny>) -> PyResult<PyObject> {
+        match self.default_value(py, strict, context)? {
+            Some(value) => Ok(PySome::new(value).into_py(py)),
+            None => Ok(py.None()),
+        }
+    }
+
+    /// every schema with a `ref`, showing which were stored in slots, which were cloned everywhere
+    /// they're used and which weren't used at all, for debugging, this has no effect on validation
+    pub fn definitions_summary(&self, py: Python) -> PyResult<Py<PyList>> {
+        let definitions = self
+            .definitions
+            .iter()
+            .map(|definition| definition.to_py_dict(py))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(PyList::new(py, definitions).into())
+    }
+
+    /// a description of the validator tree for tools, `validator` describes the root and `definitions` maps the ref
+    /// of each definition stored in a slot to its description, `definition-ref` nodes refer to those by `ref`
+    pub fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        let definitions = PyDict::new(py);
+        for (schema_ref, slot_id) in self.definitions.iter().filter_map(Definition::slot) {
+            definitions.set_item(schema_ref, self.slots[slot_id].describe(py)?)?;
+        }
+        let description = PyDict::new(py);
+        description.set_item(intern!(py, "validator"), self.validator.describe(py)?)?;
+        description.set_item(intern!(py, "definitions"), definitions)?;
+        Ok(description.into())
+    }
+
+    /// counts of the schemas this validator was built from and the python objects they hold, see `memory_report`
+    pub fn memory_report(&self, py: Python) -> PyResult<Py<PyDict>> {
+        memory_report(
+            py,
+            self.schema.extract(py)?,
+            self.slots.len(),
+            std::mem::size_of::<CombinedValidator>(),
+        )
+    }
+
+    /// a validator for just the schema at `path`, e.g. a single field, built with this validator's config and
+    /// the config of any models on the way, see `find_sub_schema` for the segments a path can contain
+    pub fn sub_validator(&self, py: Python, path: &PyTuple) -> PyResult<Self> {
+        let sub_schema = find_sub_schema(self.schema.extract(py)?, path)?;
+        let mut config = self.config.as_ref().map(|config| config.as_ref(py));
+        for model in sub_schema.models {
+            config = model::build_config(py, model, config)?;
+        }
+        Self::py_new(py, sub_schema.schema, config)
+    }
+
+    /// a copy of this validator which is strict or lax by default, `strict` passed to validation methods
+    /// still takes precedence
+    pub fn with_strict(&self, py: Python, strict: bool) -> Self {
+        Self {
+            validator: self.validator.with_strict(strict),
+            slots: self.slots.iter().map(|slot| slot.with_strict(strict)).collect(),
+            definitions: self.definitions.clone(),
+            schema: self.schema.clone_ref(py),
+            config: self.config.as_ref().map(|config| config.clone_ref(py)),
+            title: self.title.clone_ref(py),
+            max_str_bytes: self.max_str_bytes,
+            duplicate_keys: self.duplicate_keys,
+            recursion_limit: self.recursion_limit,
+            hide_input_in_errors: self.hide_input_in_errors,
+            custom_error_messages: self.custom_error_messages.clone(),
+            max_errors: self.max_errors,
+            string_cache: self.string_cache.clone(),
+        }
     }
 
     pub fn __repr__(&self, py: Python) -> String {
@@ -202,6 +636,9 @@ impl SchemaValidator {
     fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
         self.validator.py_gc_traverse(&visit)?;
         visit.call(&self.schema)?;
+        if let Some(ref config) = self.config {
+            visit.call(config)?;
+        }
         for slot in self.slots.iter() {
             slot.py_gc_traverse(&visit)?;
         }
@@ -217,6 +654,28 @@ impl SchemaValidator {
 }
 
 impl SchemaValidator {
+    /// the default of a `default` schema, e.g. for `exclude_defaults` when serializing, validated if the schema
+    /// has `validate_default`, `None` if the schema has no default
+    pub(crate) fn default_value(
+        &self,
+        py: Python,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> PyResult<Option<PyObject>> {
+        let error_limit = self.max_errors.map(ErrorLimit::new);
+        let extra = Extra::builder()
+            .strict(strict)
+            .context(context)
+            .hide_input(self.hide_input_in_errors)
+            .custom_error_messages(self.custom_error_messages.as_ref())
+            .build();
+        let recursion_guard = &mut self.recursion_guard(None, None);
+        self.validator
+            .default_value(py, None::<usize>, &extra, &self.slots, recursion_guard)
+            .map_err(|e| self.prepare_validation_err(py, e, ErrorMode::Python, error_limit.as_ref()))
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn _validate<'s, 'data>(
         &'data self,
         py: Python<'data>,
@@ -224,21 +683,210 @@ impl SchemaValidator {
         strict: Option<bool>,
         context: Option<&'data PyAny>,
         self_instance: Option<&PyAny>,
+        partial_depth: usize,
+        error_limit: Option<&ErrorLimit>,
+        recursion_guard: &mut RecursionGuard,
     ) -> ValResult<'data, PyObject>
     where
         's: 'data,
     {
-        self.validator.validate(
+        let extra = Extra::builder()
+            .strict(strict)
+            .context(context)
+            .self_instance(self_instance)
+            .partial_depth(partial_depth)
+            .error_limit(error_limit)
+            .string_cache(Some(&self.string_cache))
+            .hide_input(self.hide_input_in_errors)
+            .custom_error_messages(self.custom_error_messages.as_ref())
+            .build();
+        let result = self.validator.validate(py, input, &extra, &self.slots, recursion_guard);
+        match recursion_guard.budget_exceeded() {
+            true => Err(budget_exceeded_error(result, input)),
+            false => result,
+        }
+    }
+
+    fn _validate_with_raw<'data>(
+        &'data self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        strict: Option<bool>,
+        context: Option<&'data PyAny>,
+        record_nodes: bool,
+    ) -> ValResult<'data, (PyObject, PyObject)> {
+        let report = CoercionReport::raw_inputs();
+        let extra = Extra::builder()
+            .strict(strict)
+            .context(context)
+            .coercions(if record_nodes { Some(&report) } else { None })
+            .hide_input(self.hide_input_in_errors)
+            .custom_error_messages(self.custom_error_messages.as_ref())
+            .build();
+        let output = self
+            .validator
+            .validate(py, input, &extra, &self.slots, &mut self.recursion_guard(None, None))?;
+        let raw = match record_nodes {
+            true => report.raw_to_py(py, input.to_object(py))?.into_py(py),
+            false => input.to_object(py),
+        };
+        Ok((output, raw))
+    }
+
+    fn _validate_with_warnings<'data>(
+        &'data self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        strict: Option<bool>,
+        context: Option<&'data PyAny>,
+    ) -> ValResult<'data, (PyObject, Py<PyList>)> {
+        let report = CoercionReport::warnings();
+        let extra = Extra::builder()
+            .strict(strict)
+            .context(context)
+            .coercions(Some(&report))
+            .hide_input(self.hide_input_in_errors)
+            .custom_error_messages(self.custom_error_messages.as_ref())
+            .build();
+        let output = self
+            .validator
+            .validate(py, input, &extra, &self.slots, &mut self.recursion_guard(None, None))?;
+        Ok((output, report.to_py(py)?))
+    }
+
+    /// a `RecursionGuard` for one validation call, `recursion_limit` overrides the config's for the call
+    fn recursion_guard(&self, max_steps: Option<usize>, recursion_limit: Option<u16>) -> RecursionGuard {
+        RecursionGuard::new(max_steps, recursion_limit.unwrap_or(self.recursion_limit))
+    }
+
+    /// `recursion_limit` for one validation call, overriding the config's
+    fn recursion_limit(&self, recursion_limit: Option<&PyLong>) -> PyResult<u16> {
+        match recursion_limit {
+            Some(limit) => recursion_limit_in_range(limit)?.map_err(PyValueError::new_err),
+            None => Ok(self.recursion_limit),
+        }
+    }
+
+    /// `max_errors` for one validation call, overriding the config's
+    fn max_errors(&self, max_errors: Option<usize>) -> PyResult<Option<usize>> {
+        match max_errors {
+            Some(0) => py_err!(PyValueError; "max_errors must be greater than 0"),
+            Some(max_errors) => Ok(Some(max_errors)),
+            None => Ok(self.max_errors),
+        }
+    }
+
+    fn prepare_validation_err(
+        &self,
+        py: Python,
+        error: ValError,
+        error_mode: ErrorMode,
+        error_limit: Option<&ErrorLimit>,
+    ) -> PyErr {
+        let title = self.title.clone_ref(py);
+        ValidationError::from_val_error(
             py,
-            input,
-            &Extra::new(strict, context, self_instance),
-            &self.slots,
-            &mut RecursionGuard::default(),
+            title,
+            error_mode,
+            error,
+            None,
+            self.hide_input_in_errors,
+            self.custom_error_messages.clone(),
+            error_limit,
         )
     }
+}
+
+fn check_recursion_limit(recursion_limit: Option<&PyLong>) -> PyResult<Option<u16>> {
+    match recursion_limit {
+        Some(limit) => recursion_limit_in_range(limit)?.map(Some).map_err(SchemaError::new_err),
+        None => Ok(None),
+    }
+}
+
+/// `recursion_limit` is read as any int, so an int which doesn't fit a `u16` gets the error for a limit out of
+/// range rather than an `OverflowError`
+fn recursion_limit_in_range(limit: &PyLong) -> PyResult<Result<u16, String>> {
+    if limit.lt(0)? {
+        return Ok(Err("recursion_limit must be at least 0".to_string()));
+    }
+    match limit.extract::<u16>() {
+        Ok(limit) if limit <= MAX_RECURSION_LIMIT => Ok(Ok(limit)),
+        _ => Ok(Err(format!("recursion_limit must be at most {MAX_RECURSION_LIMIT}"))),
+    }
+}
 
-    fn prepare_validation_err(&self, py: Python, error: ValError, error_mode: ErrorMode) -> PyErr {
-        ValidationError::from_val_error(py, self.title.clone_ref(py), error_mode, error, None)
+fn check_max_errors(max_errors: Option<usize>) -> PyResult<Option<usize>> {
+    match max_errors {
+        Some(0) => py_err!("max_errors must be greater than 0"),
+        _ => Ok(max_errors),
+    }
+}
+
+/// wraps the result of `SchemaValidator.get_default_value()` so a default of `None` can be told apart from no default
+#[pyclass(module = "pydantic_core._pydantic_core", name = "Some")]
+#[derive(Debug)]
+pub struct PySome {
+    #[pyo3(get)]
+    value: PyObject,
+}
+
+impl PySome {
+    fn new(value: PyObject) -> Self {
+        Self { value }
+    }
+}
+
+#[pymethods]
+impl PySome {
+    #[new]
+    pub fn py_new(value: PyObject) -> Self {
+        Self::new(value)
+    }
+
+    pub fn __repr__(&self, py: Python) -> PyResult<String> {
+        Ok(format!("Some({})", self.value.as_ref(py).repr()?))
+    }
+
+    fn __richcmp__(&self, py: Python, other: &Self, op: CompareOp) -> PyResult<PyObject> {
+        Ok(self.value.as_ref(py).rich_compare(other.value.as_ref(py), op)?.into())
+    }
+
+    #[classattr]
+    fn __match_args__(py: Python) -> &PyTuple {
+        PyTuple::new(py, [intern!(py, "value")])
+    }
+
+    /// so `Some[int]` can be used in annotations
+    #[classmethod]
+    pub fn __class_getitem__(cls: &PyType, _item: &PyAny) -> Py<PyType> {
+        cls.into()
+    }
+
+    fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
+        visit.call(&self.value)
+    }
+}
+
+/// once the `max_steps` budget is used up, validation fails with just the first `validation_budget_exceeded` error,
+/// it has the location where the budget ran out, other errors collected before validation stopped are dropped
+fn budget_exceeded_error<'data>(
+    result: ValResult<'data, PyObject>,
+    input: &'data impl Input<'data>,
+) -> ValError<'data> {
+    match result {
+        Err(ValError::LineErrors(mut line_errors)) => {
+            match line_errors
+                .iter()
+                .position(|e| matches!(e.error_type, ErrorType::ValidationBudgetExceeded))
+            {
+                Some(index) => ValError::LineErrors(vec![line_errors.swap_remove(index)]),
+                None => ValError::new(ErrorType::ValidationBudgetExceeded, input),
+            }
+        }
+        Err(err @ ValError::InternalErr(_)) => err,
+        // e.g. a function validator caught the error and returned a value
+        _ => ValError::new(ErrorType::ValidationBudgetExceeded, input),
     }
 }
 
@@ -285,8 +933,17 @@ impl<'py> SelfValidator<'py> {
         Ok(SchemaValidator {
             validator,
             slots: build_context.into_slots_val()?,
+            definitions: Vec::new(),
             schema: py.None(),
+            config: None,
             title: "Self Schema".into_py(py),
+            max_str_bytes: None,
+            duplicate_keys: DuplicateKeys::default(),
+            recursion_limit: DEFAULT_RECURSION_LIMIT,
+            hide_input_in_errors: false,
+            custom_error_messages: None,
+            max_errors: None,
+            string_cache: StringCache::from_config(None)?,
         })
     }
 }
@@ -311,39 +968,62 @@ fn build_specific_validator<'a, T: BuildValidator>(
     build_context: &mut BuildContext<CombinedValidator>,
 ) -> PyResult<CombinedValidator> {
     let py = schema_dict.py();
-    if let Some(schema_ref) = schema_dict.get_as::<String>(intern!(py, "ref"))? {
+    let op_schema_ref = schema_dict.get_as::<String>(intern!(py, "ref"))?;
+    if let Some(ref schema_ref) = op_schema_ref {
         // if there's a ref, we **might** want to store the validator in slots and return a DefinitionRefValidator:
         // * if the ref isn't used at all, we just want to return a normal validator, and ignore the ref completely
         // * if the ref is used inside itself, we have to store the validator in slots,
         //   and return a DefinitionRefValidator - two step process with `prepare_slot` and `complete_slot`
         // * if the ref is used elsewhere, we want to clone it each time it's used
-        if build_context.ref_used(&schema_ref) {
+        if build_context.ref_used(schema_ref) {
             // the ref is used somewhere
             // check the ref is unique
-            if build_context.ref_already_used(&schema_ref) {
+            if build_context.ref_already_used(schema_ref) {
                 return py_err!("Duplicate ref: `{}`", schema_ref);
             }
+            build_context.check_generic_ref(schema_dict, schema_ref)?;
 
-            return if build_context.ref_used_within(schema_dict, &schema_ref)? {
+            return if build_context.ref_used_within(schema_dict, schema_ref)? {
                 // the ref is used within itself, so we have to store the validator in slots
                 // and return a DefinitionRefValidator
                 let answers = Answers::new(schema_dict)?;
-                let slot_id = build_context.prepare_slot(schema_ref, Some(answers.clone()))?;
-                let inner_val = T::build(schema_dict, config, build_context)?;
+                let slot_id = build_context.prepare_slot(schema_ref.clone(), Some(answers.clone()))?;
+                let inner_val = build_with_overrides::<T>(schema_dict, config, build_context)?;
                 let name = inner_val.get_name().to_string();
+                build_context.record_definition(schema_ref.clone(), DefinitionKind::Slot(slot_id), &name);
                 build_context.complete_slot(slot_id, inner_val)?;
-                Ok(definitions::DefinitionRefValidator::from_id(slot_id, name, answers))
+                Ok(definitions::DefinitionRefValidator::from_id(
+                    slot_id,
+                    schema_ref.clone(),
+                    name,
+                    answers,
+                ))
             } else {
                 // ref is used, but only out side itself, we want to clone it everywhere it's used
-                let validator = T::build(schema_dict, config, build_context)?;
-                build_context.store_reusable(schema_ref, validator.clone());
+                let validator = build_with_overrides::<T>(schema_dict, config, build_context)?;
+                build_context.record_definition(schema_ref.clone(), DefinitionKind::Reusable, validator.get_name());
+                build_context.store_reusable(schema_ref.clone(), validator.clone());
                 Ok(validator)
             };
         }
     }
 
-    T::build(schema_dict, config, build_context)
-        .map_err(|err| py_error_type!("Error building \"{}\" validator:\n  {}", val_type, err))
+    let validator = build_with_overrides::<T>(schema_dict, config, build_context)
+        .map_err(|err| py_error_type!("Error building \"{}\" validator:\n  {}", val_type, err))?;
+    if let Some(schema_ref) = op_schema_ref {
+        build_context.record_definition(schema_ref, DefinitionKind::Unused, validator.get_name());
+    }
+    Ok(validator)
+}
+
+fn build_with_overrides<'a, T: BuildValidator>(
+    schema_dict: &'a PyDict,
+    config: Option<&'a PyDict>,
+    build_context: &mut BuildContext<CombinedValidator>,
+) -> PyResult<CombinedValidator> {
+    let validator = T::build(schema_dict, config, build_context)?;
+    let validator = strict_override::StrictOverrideValidator::wrap(schema_dict, validator)?;
+    error_overrides::ErrorOverridesValidator::wrap(schema_dict, validator)
 }
 
 // macro to build the match statement for validator selection
@@ -375,6 +1055,8 @@ pub fn build_validator<'a>(
         // unions
         union::UnionValidator,
         union::TaggedUnionValidator,
+        // merge - validate against several schemas and merge the outputs
+        merge::MergeValidator,
         // nullables
         nullable::NullableValidator,
         // model classes
@@ -436,6 +1118,8 @@ pub fn build_validator<'a>(
         chain::ChainValidator,
         // lax or strict
         lax_or_strict::LaxOrStrictValidator,
+        // json or python
+        json_or_python::JsonOrPythonValidator,
         // generator validators
         generator::GeneratorValidator,
         // custom error
@@ -445,9 +1129,33 @@ pub fn build_validator<'a>(
         // url types
         url::UrlValidator,
         url::MultiHostUrlValidator,
+        // path types
+        path::PathValidator,
+        // timezone names
+        timezone::TimezoneValidator,
+        // fractions
+        fraction::FractionValidator,
+        // decimals
+        decimal::DecimalValidator,
+        // uuids
+        uuid::UuidValidator,
+        // enums
+        enum_::EnumValidator,
+        // complex numbers
+        complex::ComplexValidator,
+        // ip addresses, networks and interfaces
+        ip::IpV4AddressBuilder,
+        ip::IpV6AddressBuilder,
+        ip::IpV4NetworkBuilder,
+        ip::IpV6NetworkBuilder,
+        ip::IpV4InterfaceBuilder,
+        ip::IpV6InterfaceBuilder,
         // recursive (self-referencing) models
         definitions::DefinitionRefValidator,
         definitions::DefinitionsBuilder,
+        // generic schemas with type variables substituted at build time
+        type_var::TypeVarBuilder,
+        type_var::ParametrizedBuilder,
     )
 }
 
@@ -466,27 +1174,242 @@ pub struct Extra<'a> {
     pub context: Option<&'a PyAny>,
     /// This is an instance of the model or dataclass being validated, when validation is performed from `__init__`
     self_instance: Option<&'a PyAny>,
+    /// lax coercions are recorded here when validating with `validate_python_with_report`, and warnings
+    /// when validating with `validate_python_with_warnings`
+    pub coercions: Option<&'a CoercionReport>,
+    /// set by smart unions while validating a choice, validators lower it with `report_exactness`
+    /// so the union can pick the choice which matched the input most closely
+    pub exactness: Option<&'a Cell<Exactness>>,
+    /// with `allow_partial`, how many of the input's trailing containers may be incomplete, starting with the input
+    /// itself, so it's `0` unless the input may be incomplete, see `partial_item`
+    pub partial_depth: usize,
+    /// the validator's cache of python strings made from JSON, see `StringCache`
+    pub string_cache: Option<&'a StringCache>,
+    /// from the `hide_input_in_errors` config, for validation errors raised inside validation, e.g. by generators
+    pub hide_input: bool,
+    /// from the `custom_error_messages` config, used like `hide_input`
+    pub custom_error_messages: Option<&'a CustomErrorMessages>,
+    /// with `max_errors`, the errors collected so far, collections stop validating items once it's reached
+    pub error_limit: Option<&'a ErrorLimit>,
+}
+
+/// How closely the input matched a union choice, ordered from worst to best
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Exactness {
+    // a lax coercion was needed, e.g. `'1'` to `int`
+    Lax,
+    // the input is valid in strict mode but its type differs from the output's, e.g. `1` to `float`
+    Strict,
+    // the input already has the output's type, e.g. `1` to `int`
+    Exact,
 }
 
 impl<'a> Extra<'a> {
-    pub fn new(strict: Option<bool>, context: Option<&'a PyAny>, self_instance: Option<&'a PyAny>) -> Self {
-        Extra {
-            strict,
-            context,
-            self_instance,
-            ..Default::default()
-        }
+    pub fn builder() -> ExtraBuilder<'a> {
+        ExtraBuilder::default()
+    }
+}
+
+/// Builds the `Extra` for a validation entry point, anything not set keeps its default, so new fields
+/// on `Extra` only need setting where they're used
+#[derive(Debug, Default)]
+pub struct ExtraBuilder<'a> {
+    extra: Extra<'a>,
+}
+
+impl<'a> ExtraBuilder<'a> {
+    pub fn strict(mut self, strict: Option<bool>) -> Self {
+        self.extra.strict = strict;
+        self
+    }
+
+    pub fn context(mut self, context: Option<&'a PyAny>) -> Self {
+        self.extra.context = context;
+        self
+    }
+
+    pub fn self_instance(mut self, self_instance: Option<&'a PyAny>) -> Self {
+        self.extra.self_instance = self_instance;
+        self
+    }
+
+    pub fn data(mut self, data: Option<&'a PyDict>) -> Self {
+        self.extra.data = data;
+        self
+    }
+
+    pub fn coercions(mut self, coercions: Option<&'a CoercionReport>) -> Self {
+        self.extra.coercions = coercions;
+        self
+    }
+
+    pub fn partial_depth(mut self, partial_depth: usize) -> Self {
+        self.extra.partial_depth = partial_depth;
+        self
+    }
+
+    pub fn string_cache(mut self, string_cache: Option<&'a StringCache>) -> Self {
+        self.extra.string_cache = string_cache;
+        self
+    }
+
+    pub fn hide_input(mut self, hide_input: bool) -> Self {
+        self.extra.hide_input = hide_input;
+        self
+    }
+
+    pub fn custom_error_messages(mut self, custom_error_messages: Option<&'a CustomErrorMessages>) -> Self {
+        self.extra.custom_error_messages = custom_error_messages;
+        self
+    }
+
+    pub fn error_limit(mut self, error_limit: Option<&'a ErrorLimit>) -> Self {
+        self.extra.error_limit = error_limit;
+        self
+    }
+
+    pub fn build(self) -> Extra<'a> {
+        self.extra
     }
 }
 
 impl<'a> Extra<'a> {
     pub fn as_strict(&self) -> Self {
-        Self {
-            data: self.data,
-            strict: Some(true),
-            context: self.context,
-            field_name: self.field_name,
-            self_instance: self.self_instance,
+        self.with_strict(Some(true))
+    }
+
+    /// whether the input may be incomplete, so missing fields and too few items aren't errors
+    pub fn is_partial(&self) -> bool {
+        self.partial_depth > 0
+    }
+
+    /// the `Extra` for an item or value of an incomplete input, only the last one may be incomplete too
+    pub fn partial_item(&self, last: bool) -> Self {
+        let partial_depth = match last {
+            true => self.partial_depth - 1,
+            false => 0,
+        };
+        Self { partial_depth, ..*self }
+    }
+
+    pub fn with_strict(&self, strict: Option<bool>) -> Self {
+        Self { strict, ..*self }
+    }
+
+    /// `either_str` as a python string, strings made from JSON are looked up in the string cache
+    pub fn py_string<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        either_str: &EitherString<'py>,
+    ) -> &'py PyString {
+        match either_str {
+            EitherString::Py(py_string) => py_string,
+            EitherString::Cow(cow) => match (self.string_cache, input.get_type()) {
+                (Some(string_cache), InputType::Json) => string_cache.get(py, cow, false),
+                (Some(string_cache), InputType::String) => string_cache.get(py, cow, true),
+                _ => PyString::new(py, cow),
+            },
+        }
+    }
+
+    /// push `loc` onto the coercion report's path until the returned guard is dropped,
+    /// `loc` is only called if coercions are being reported
+    pub fn coercion_loc<I: IntoIterator<Item = LocItem>>(&self, loc: impl FnOnce() -> I) -> CoercionLoc<'a> {
+        CoercionLoc::new(self.coercions, loc)
+    }
+
+    /// mark to pass to `stop_collecting` once an item has been validated, see `ErrorLimit`
+    pub fn error_mark(&self) -> usize {
+        self.error_limit.map_or(0, ErrorLimit::mark)
+    }
+
+    /// record that an item validated since `mark` failed with `item_errors` errors, returns whether the
+    /// collection should stop validating items because `max_errors` has been reached, only call this once the
+    /// collection holds the item's errors so it still fails, `items_left` is whether the collection has more items
+    pub fn stop_collecting(&self, mark: usize, item_errors: usize, items_left: impl FnOnce() -> bool) -> bool {
+        self.error_limit
+            .is_some_and(|limit| limit.record(mark, item_errors, items_left))
+    }
+
+    /// forget errors recorded since `mark` which were discarded, see `ErrorLimit::discard`
+    pub fn discard_errors(&self, mark: usize) {
+        if let Some(limit) = self.error_limit {
+            limit.discard(mark);
+        }
+    }
+
+    /// checkpoint to roll the coercion report back to if the value being validated is discarded
+    pub fn coercion_checkpoint(&self) -> CoercionCheckpoint<'a> {
+        CoercionCheckpoint::new(self.coercions)
+    }
+
+    /// record `input` as the raw input at the current location when recording raw inputs, containers call this
+    /// for each item once they've pushed its location with `coercion_loc`
+    pub fn report_raw_input<'data>(&self, py: Python<'data>, input: &'data impl Input<'data>) {
+        if let Some(report) = self.coercions {
+            if report.is_raw_inputs() {
+                report.record_raw(py, input);
+            }
+        }
+    }
+
+    /// report `input` as coerced to `target_type` unless `strict_ok`, which is only called if coercions
+    /// are being reported, call this after lax validation has succeeded
+    pub fn report_coercion<'data>(
+        &self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        target_type: &'static str,
+        strict_ok: impl FnOnce() -> bool,
+    ) {
+        let report = match self.coercions {
+            Some(report) if !report.is_warnings() && !report.is_raw_inputs() => Some(report),
+            _ => None,
+        };
+        // a coercion also makes the current union choice a lax match, there can't be one in strict mode
+        let tracked = report.is_some() || self.exactness.is_some();
+        if tracked && self.strict != Some(true) && !strict_ok() {
+            if let Some(report) = report {
+                report.record(py, input, target_type);
+            }
+            self.report_exactness(Exactness::Lax);
+        }
+    }
+
+    /// lower the exactness of the union choice being validated, if there is one
+    pub fn report_exactness(&self, exactness: Exactness) {
+        if let Some(cell) = self.exactness {
+            cell.set(cell.get().min(exactness));
+        }
+    }
+
+    /// a match is strict at best if the output's type isn't the input's, e.g. an `int` validated as a `float`
+    pub fn report_output_type<'data>(&self, input: &'data impl Input<'data>, output: &PyAny) {
+        if self.exactness.is_some() && !input.is_exact_type_of(output) {
+            self.report_exactness(Exactness::Strict);
+        }
+    }
+
+    /// whether warnings emitted with `report_warning` are recorded
+    pub fn reports_warnings(&self) -> bool {
+        self.coercions.is_some_and(CoercionReport::is_warnings)
+    }
+
+    /// emit a non-fatal warning about the value at the current location, this never affects whether validation
+    /// succeeds, `message` is only called if warnings are being recorded
+    pub fn report_warning(&self, code: &str, message: impl FnOnce() -> String) {
+        if let Some(report) = self.coercions {
+            if report.is_warnings() {
+                report.record_warning(code.to_string(), message());
+            }
+        }
+    }
+
+    /// report that the value at the current location came from a `default`, or `default_factory` if `factory`
+    pub fn report_default(&self, factory: bool) {
+        if let Some(report) = self.coercions {
+            report.record_default(factory);
         }
     }
 }
@@ -499,6 +1422,8 @@ pub enum CombinedValidator {
     // unions
     Union(union::UnionValidator),
     TaggedUnion(union::TaggedUnionValidator),
+    // merge - validate against several schemas and merge the outputs
+    Merge(merge::MergeValidator),
     // nullables
     Nullable(nullable::NullableValidator),
     // create new model classes
@@ -541,6 +1466,7 @@ pub enum CombinedValidator {
     LiteralMultipleStrings(literal::LiteralMultipleStringsValidator),
     LiteralMultipleInts(literal::LiteralMultipleIntsValidator),
     LiteralGeneral(literal::LiteralGeneralValidator),
+    LiteralFactory(literal::LiteralFactoryValidator),
     // any
     Any(any::AnyValidator),
     // bytes
@@ -568,19 +1494,52 @@ pub enum CombinedValidator {
     Chain(chain::ChainValidator),
     // lax or strict
     LaxOrStrict(lax_or_strict::LaxOrStrictValidator),
+    // json or python
+    JsonOrPython(json_or_python::JsonOrPythonValidator),
     // generator validators
     Generator(generator::GeneratorValidator),
     // custom error
     CustomError(custom_error::CustomErrorValidator),
+    // errors replaced with `error_overrides`
+    ErrorOverrides(error_overrides::ErrorOverridesValidator),
+    // `strict` on the schema taking precedence over `strict` for the call
+    StrictOverride(strict_override::StrictOverrideValidator),
     // json data
     Json(json::JsonValidator),
     // url types
     Url(url::UrlValidator),
     MultiHostUrl(url::MultiHostUrlValidator),
+    // path types
+    Path(path::PathValidator),
+    // timezone names
+    Timezone(timezone::TimezoneValidator),
+    // fractions
+    Fraction(fraction::FractionValidator),
+    // decimals
+    Decimal(decimal::DecimalValidator),
+    // uuids
+    Uuid(uuid::UuidValidator),
+    // enums
+    Enum(enum_::EnumValidator),
+    // complex numbers
+    Complex(complex::ComplexValidator),
+    // ip addresses, networks and interfaces
+    Ip(ip::IpValidator),
     // reference to definition, useful for recursive (self-referencing) models
     DefinitionRef(definitions::DefinitionRefValidator),
 }
 
+impl CombinedValidator {
+    /// A copy of this validator tree with the strictness `strict` wherever a schema or config set it, without
+    /// rebuilding from the schema. As before, `strict` given for a validation call takes precedence.
+    /// Definitions referenced by `DefinitionRefValidator` live in slots, which need to be changed too.
+    pub fn with_strict(&self, strict: bool) -> Self {
+        let mut validator = self.clone();
+        validator.set_strict(strict);
+        validator
+    }
+}
+
 /// This trait must be implemented by all validators, it allows various validators to be accessed consistently,
 /// validators defined in `build_validator` also need `EXPECTED_TYPE` as a const, but that can't be part of the trait
 #[enum_dispatch(CombinedValidator)]
@@ -628,6 +1587,10 @@ pub trait Validator: Send + Sync + Clone + Debug {
         Err(py_err.into())
     }
 
+    /// set the strictness used when a validation call doesn't set `strict`, this must be implemented for
+    /// validators with a `strict` setting and for any validator which holds references to other validators
+    fn set_strict(&mut self, _strict: bool) {}
+
     /// `get_name` generally returns `Self::EXPECTED_TYPE` or some other clear identifier of the validator
     /// this is used in the error location in unions, and in the top level message in `ValidationError`
     fn get_name(&self) -> &str;
@@ -640,6 +1603,13 @@ pub trait Validator: Send + Sync + Clone + Debug {
         false
     }
 
+    /// structured description of the validator for `SchemaValidator.describe()`, see `Description`, this should be
+    /// implemented for any validator with a `strict` setting or constraints, and must be implemented for any validator
+    /// which holds references to other validators so their descriptions are included as children
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, self.get_name()).finish()
+    }
+
     /// this method must be implemented for any validator which holds references to other validators,
     /// it is used by `DefinitionRefValidator` to set its name
     fn complete(&mut self, _build_context: &BuildContext<CombinedValidator>) -> PyResult<()>;
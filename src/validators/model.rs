@@ -4,17 +4,17 @@ use std::ptr::null_mut;
 use pyo3::conversion::AsPyPointer;
 use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PySet, PyString, PyTuple, PyType};
+use pyo3::types::{PyDict, PyList, PySet, PyString, PyTuple, PyType};
 use pyo3::{ffi, intern};
 
-use crate::build_tools::{py_err, schema_or_config_same, SchemaDict};
-use crate::errors::{ErrorType, ValError, ValResult};
+use crate::build_tools::{apply_alias_generator, py_err, schema_or_config_same, SchemaDict};
+use crate::errors::{ErrorType, ValError, ValLineError, ValResult};
 use crate::input::{py_error_on_minusone, Input};
 use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
 use super::function::convert_err;
-use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{build_validator, timer, BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub(super) enum Revalidate {
@@ -43,6 +43,63 @@ impl Revalidate {
     }
 }
 
+/// a group of fields of which at most one (or exactly one) may be set, checked against the fields set after
+/// validation
+#[derive(Debug, Clone)]
+struct ExclusiveGroup {
+    name: String,
+    fields: Vec<String>,
+    exactly_one: bool,
+}
+
+impl ExclusiveGroup {
+    fn build(group: &PyDict) -> PyResult<Self> {
+        let py = group.py();
+        let fields: Vec<String> = group.get_as_req(intern!(py, "fields"))?;
+        let name = match group.get_as::<String>(intern!(py, "name"))? {
+            Some(name) => name,
+            None => fields.join(", "),
+        };
+        if fields.len() < 2 {
+            return py_err!("Exclusive group '{}' should contain at least two fields", name);
+        }
+        let exactly_one = match group.get_as::<&str>(intern!(py, "mode"))? {
+            Some("exactly_one") => true,
+            Some("at_most_one") | None => false,
+            // schema validation means other values are impossible
+            _ => unreachable!(),
+        };
+        Ok(Self {
+            name,
+            fields,
+            exactly_one,
+        })
+    }
+
+    fn error_type(&self, fields_set: &PyAny) -> PyResult<Option<ErrorType>> {
+        let mut set_fields: Vec<&str> = Vec::new();
+        for field in &self.fields {
+            if fields_set.contains(field.as_str())? {
+                set_fields.push(field);
+            }
+        }
+        Ok(match set_fields.len() {
+            0 if self.exactly_one => Some(ErrorType::ExclusiveGroupMissing {
+                group: self.name.clone(),
+            }),
+            0 | 1 => None,
+            _ => Some(ErrorType::ExclusiveGroupConflict {
+                group: self.name.clone(),
+                fields: set_fields
+                    .iter()
+                    .map(|f| format!("'{f}'"))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            }),
+        })
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ModelValidator {
     strict: bool,
@@ -52,7 +109,10 @@ pub struct ModelValidator {
     post_init: Option<Py<PyString>>,
     name: String,
     expect_fields_set: bool,
+    // whether the inner validator returns extra values, they're set as `__pydantic_extra__`
+    expect_extra: bool,
     frozen: bool,
+    exclusive_groups: Vec<ExclusiveGroup>,
 }
 
 impl BuildValidator for ModelValidator {
@@ -68,10 +128,22 @@ impl BuildValidator for ModelValidator {
         let config = build_config(py, schema, config)?;
 
         let class: &PyType = schema.get_as_req(intern!(py, "cls"))?;
-        let sub_schema: &PyAny = schema.get_as_req(intern!(py, "schema"))?;
+        let sub_schema = apply_alias_generator(schema, schema.get_as_req(intern!(py, "schema"))?)?;
         let validator = build_validator(sub_schema, config, build_context)?;
 
         let expect_fields_set = validator.ask(&Question::ReturnFieldsSet);
+        let expect_extra = validator.ask(&Question::ReturnExtra);
+
+        let exclusive_groups = match schema.get_as::<&PyList>(intern!(py, "exclusive_groups"))? {
+            Some(groups) => groups
+                .iter()
+                .map(|group| ExclusiveGroup::build(group.downcast()?))
+                .collect::<PyResult<Vec<_>>>()?,
+            None => Vec::new(),
+        };
+        if !exclusive_groups.is_empty() && !expect_fields_set {
+            return py_err!("'exclusive_groups' requires a schema which returns the fields set");
+        }
 
         Ok(Self {
             // we don't use is_strict here since we don't want validation to be strict in this case if
@@ -91,7 +163,9 @@ impl BuildValidator for ModelValidator {
             // which is not what we want here
             name: class.getattr(intern!(py, "__name__"))?.extract()?,
             expect_fields_set,
+            expect_extra,
             frozen: schema.get_as(intern!(py, "frozen"))?.unwrap_or(false),
+            exclusive_groups,
         }
         .into())
     }
@@ -112,6 +186,7 @@ impl Validator for ModelValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
+        timer!(self.get_name());
         if let Some(self_instance) = extra.self_instance {
             // in the case that self_instance is Some, we're calling validation from within `BaseModel.__init__`
             return self.validate_init(py, self_instance, input, extra, slots, recursion_guard);
@@ -130,15 +205,26 @@ impl Validator for ModelValidator {
                     None => None,
                 };
                 // get dict here so from_attributes logic doesn't apply
-                let dict = input.input_get_attr(intern!(py, "__dict__")).unwrap()?;
+                let mut dict = input.input_get_attr(intern!(py, "__dict__")).unwrap()?;
+                if self.expect_extra {
+                    // extra values are validated again with the fields
+                    if let Some(Ok(model_extra)) = input.input_get_attr(intern!(py, "__pydantic_extra__")) {
+                        if let Ok(model_extra) = model_extra.downcast::<PyDict>() {
+                            let all_values = dict.downcast::<PyDict>()?.copy()?;
+                            all_values.update(model_extra.as_mapping())?;
+                            dict = all_values;
+                        }
+                    }
+                }
                 let output = self.validator.validate(py, dict, extra, slots, recursion_guard)?;
-                let instance = if self.expect_fields_set {
-                    let (model_dict, validation_fields_set): (&PyAny, &PyAny) = output.extract(py)?;
-                    let fields_set = fields_set.unwrap_or(validation_fields_set);
-                    self.create_class(model_dict, Some(fields_set))?
-                } else {
-                    self.create_class(output.as_ref(py), fields_set)?
-                };
+                let output = self.split_output(output.into_ref(py))?;
+                let fields_set = fields_set.or(output.fields_set);
+                if let Some(fields_set) = fields_set {
+                    if self.expect_fields_set {
+                        self.check_exclusive_groups(fields_set, input)?;
+                    }
+                }
+                let instance = self.create_class(output.model_dict, fields_set, output.extra)?;
                 self.call_post_init(py, instance, input, extra)
             } else {
                 Ok(input.to_object(py))
@@ -152,16 +238,28 @@ impl Validator for ModelValidator {
             ))
         } else {
             let output = self.validator.validate(py, input, extra, slots, recursion_guard)?;
-            let instance = if self.expect_fields_set {
-                let (model_dict, fields_set): (&PyAny, &PyAny) = output.extract(py)?;
-                self.create_class(model_dict, Some(fields_set))?
-            } else {
-                self.create_class(output.as_ref(py), None)?
-            };
+            let output = self.split_output(output.into_ref(py))?;
+            if let Some(fields_set) = output.fields_set {
+                self.check_exclusive_groups(fields_set, input)?;
+            }
+            let instance = self.create_class(output.model_dict, output.fields_set, output.extra)?;
             self.call_post_init(py, instance, input, extra)
         }
     }
 
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+        self.validator.set_strict(strict);
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .strict(self.strict)
+            .accepts(&["instance", "dict", "mapping"], &["instance"])
+            .child("schema", &self.validator)?
+            .finish()
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -192,19 +290,35 @@ impl Validator for ModelValidator {
         let output =
             self.validator
                 .validate_assignment(py, new_dict, field_name, field_value, extra, slots, recursion_guard)?;
-        let output = if self.expect_fields_set {
-            let (output, updated_fields_set): (&PyDict, &PySet) = output.extract(py)?;
+        let output = self.split_output(output.into_ref(py))?;
+        if let Some(updated_fields_set) = output.fields_set {
+            let updated_fields_set: &PySet = updated_fields_set.downcast()?;
             if let Ok(fields_set) = model.input_get_attr(intern!(py, "__pydantic_fields_set__")).unwrap() {
                 let fields_set: &PySet = fields_set.downcast()?;
+                if !self.exclusive_groups.is_empty() {
+                    let new_fields: Vec<&PyAny> = fields_set.iter().chain(updated_fields_set.iter()).collect();
+                    self.check_exclusive_groups(PySet::new(py, &new_fields)?, field_value)?;
+                }
                 for field_name in updated_fields_set {
                     fields_set.add(field_name)?;
                 }
             }
-            output.to_object(py)
-        } else {
-            output
-        };
-        force_setattr(py, model, dict_py_str, output)?;
+        }
+        if let Some(new_extra) = output.extra {
+            // `None` unless an extra value was assigned, in which case it's added to the existing extra values
+            if let Ok(new_extra) = new_extra.downcast::<PyDict>() {
+                let model_extra = intern!(py, "__pydantic_extra__");
+                match model
+                    .getattr(model_extra)
+                    .ok()
+                    .and_then(|e| e.downcast::<PyDict>().ok())
+                {
+                    Some(existing) => existing.update(new_extra.as_mapping())?,
+                    None => force_setattr(py, model, model_extra, new_extra)?,
+                }
+            }
+        }
+        force_setattr(py, model, dict_py_str, output.model_dict)?;
         Ok(model.into_py(py))
     }
 }
@@ -227,12 +341,11 @@ impl ModelValidator {
             ..*extra
         };
         let output = self.validator.validate(py, input, &new_extra, slots, recursion_guard)?;
-        if self.expect_fields_set {
-            let (model_dict, fields_set): (&PyAny, &PyAny) = output.extract(py)?;
-            set_model_attrs(self_instance, model_dict, Some(fields_set))?;
-        } else {
-            set_model_attrs(self_instance, output.as_ref(py), None)?;
-        };
+        let output = self.split_output(output.into_ref(py))?;
+        if let Some(fields_set) = output.fields_set {
+            self.check_exclusive_groups(fields_set, input)?;
+        }
+        set_model_attrs(self_instance, output.model_dict, output.fields_set, output.extra)?;
         self.call_post_init(py, self_instance.into_py(py), input, extra)
     }
 
@@ -251,11 +364,65 @@ impl ModelValidator {
         Ok(instance)
     }
 
-    fn create_class(&self, model_dict: &PyAny, fields_set: Option<&PyAny>) -> PyResult<PyObject> {
+    /// check `exclusive_groups` against the fields set, with an error for each group which isn't satisfied
+    fn check_exclusive_groups<'data>(
+        &self,
+        fields_set: &PyAny,
+        input: &'data impl Input<'data>,
+    ) -> ValResult<'data, ()> {
+        let mut errors: Vec<ValLineError> = Vec::new();
+        for group in &self.exclusive_groups {
+            if let Some(error_type) = group.error_type(fields_set)? {
+                errors.push(ValLineError::new(error_type, input));
+            }
+        }
+        match errors.is_empty() {
+            true => Ok(()),
+            false => Err(ValError::LineErrors(errors)),
+        }
+    }
+
+    fn create_class(
+        &self,
+        model_dict: &PyAny,
+        fields_set: Option<&PyAny>,
+        model_extra: Option<&PyAny>,
+    ) -> PyResult<PyObject> {
         let instance = create_class(self.class.as_ref(model_dict.py()))?;
-        set_model_attrs(instance.as_ref(model_dict.py()), model_dict, fields_set)?;
+        set_model_attrs(instance.as_ref(model_dict.py()), model_dict, fields_set, model_extra)?;
         Ok(instance)
     }
+
+    /// split the inner validator's output, which is a tuple when it returns the fields set or extra values
+    fn split_output<'py>(&self, output: &'py PyAny) -> PyResult<ModelOutput<'py>> {
+        let (model_dict, fields_set, extra) = match (self.expect_fields_set, self.expect_extra) {
+            (true, true) => {
+                let (model_dict, fields_set, extra): (&PyAny, &PyAny, &PyAny) = output.extract()?;
+                (model_dict, Some(fields_set), Some(extra))
+            }
+            (true, false) => {
+                let (model_dict, fields_set): (&PyAny, &PyAny) = output.extract()?;
+                (model_dict, Some(fields_set), None)
+            }
+            (false, true) => {
+                let (model_dict, extra): (&PyAny, &PyAny) = output.extract()?;
+                (model_dict, None, Some(extra))
+            }
+            (false, false) => (output, None, None),
+        };
+        Ok(ModelOutput {
+            model_dict,
+            fields_set,
+            extra,
+        })
+    }
+}
+
+struct ModelOutput<'py> {
+    model_dict: &'py PyAny,
+    fields_set: Option<&'py PyAny>,
+    // `None` (the python object) when extra values aren't allowed
+    extra: Option<&'py PyAny>,
 }
 
 /// based on the following but with the second argument of new_func set to an empty tuple as required
@@ -279,12 +446,40 @@ pub(super) fn create_class(class: &PyType) -> PyResult<PyObject> {
     }
 }
 
-fn set_model_attrs(instance: &PyAny, model_dict: &PyAny, fields_set: Option<&PyAny>) -> PyResult<()> {
+fn set_model_attrs(
+    instance: &PyAny,
+    model_dict: &PyAny,
+    fields_set: Option<&PyAny>,
+    model_extra: Option<&PyAny>,
+) -> PyResult<()> {
     let py = instance.py();
     force_setattr(py, instance, intern!(py, "__dict__"), model_dict)?;
     if let Some(fields_set) = fields_set {
         force_setattr(py, instance, intern!(py, "__pydantic_fields_set__"), fields_set)?;
     }
+    if let Some(model_extra) = model_extra {
+        force_setattr(py, instance, intern!(py, "__pydantic_extra__"), model_extra)?;
+    }
+    Ok(())
+}
+
+/// undo `set_model_attrs`, leaving the instance without fields as if it had just been created
+pub(super) fn reset_model_attrs(py: Python, instance: &PyAny) -> PyResult<()> {
+    force_setattr(py, instance, intern!(py, "__dict__"), PyDict::new(py))?;
+    for attr in [
+        intern!(py, "__pydantic_fields_set__"),
+        intern!(py, "__pydantic_extra__"),
+    ] {
+        if instance.hasattr(attr)? {
+            unsafe {
+                // Safety: a null value deletes the attribute
+                py_error_on_minusone(
+                    py,
+                    ffi::PyObject_GenericSetAttr(instance.as_ptr(), attr.as_ptr(), null_mut()),
+                )?;
+            }
+        }
+    }
     Ok(())
 }
 
@@ -303,7 +498,7 @@ where
     }
 }
 
-fn build_config<'a>(
+pub(super) fn build_config<'a>(
     py: Python<'a>,
     schema: &'a PyDict,
     parent_config: Option<&'a PyDict>,
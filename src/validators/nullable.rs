@@ -2,18 +2,22 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use crate::build_tools::SchemaDict;
+use crate::build_tools::{is_strict, merge_validators_enabled, SchemaDict};
 use crate::errors::ValResult;
 use crate::input::Input;
 use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
-use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub struct NullableValidator {
     validator: Box<CombinedValidator>,
     name: String,
+    strict: bool,
+    // strings which are validated as `None` in lax mode, from `none_strings` in config
+    none_strings: Vec<String>,
+    strip_whitespace: bool,
 }
 
 impl BuildValidator for NullableValidator {
@@ -24,10 +28,44 @@ impl BuildValidator for NullableValidator {
         config: Option<&PyDict>,
         build_context: &mut BuildContext<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
-        let schema: &PyAny = schema.get_as_req(intern!(schema.py(), "schema"))?;
+        let py = schema.py();
+        let strict = is_strict(schema, config)?;
+        let schema: &PyAny = schema.get_as_req(intern!(py, "schema"))?;
         let validator = Box::new(build_validator(schema, config, build_context)?);
         let name = format!("{}[{}]", Self::EXPECTED_TYPE, validator.get_name());
-        Ok(Self { validator, name }.into())
+
+        let mut none_strings: Vec<String> = config.get_as(intern!(py, "none_strings"))?.unwrap_or_default();
+        // for `str` the strings are probably legitimate values, so they're only used if explicitly allowed
+        let inner_is_str = match schema.downcast::<PyDict>() {
+            Ok(schema) => schema.get_as::<&str>(intern!(py, "type"))? == Some("str"),
+            Err(_) => false,
+        };
+        if inner_is_str
+            && !config
+                .get_as(intern!(py, "none_strings_apply_to_str"))?
+                .unwrap_or(false)
+        {
+            none_strings.clear();
+        }
+        let strip_whitespace = config.get_as(intern!(py, "str_strip_whitespace"))?.unwrap_or(false);
+        // a nullable directly inside another which accepts the same values makes the outer one redundant
+        if let CombinedValidator::Nullable(ref inner) = *validator {
+            let same_none_strings = none_strings.is_empty()
+                || (none_strings == inner.none_strings
+                    && strip_whitespace == inner.strip_whitespace
+                    && strict == inner.strict);
+            if same_none_strings && merge_validators_enabled(config)? {
+                return Ok(*validator);
+            }
+        }
+        Ok(Self {
+            validator,
+            name,
+            strict,
+            none_strings,
+            strip_whitespace,
+        }
+        .into())
     }
 }
 
@@ -40,12 +78,24 @@ impl Validator for NullableValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        match input.is_none() {
+        match input.is_none() || self.is_none_string(input, extra)? {
             true => Ok(py.None()),
             false => self.validator.validate(py, input, extra, slots, recursion_guard),
         }
     }
 
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+        self.validator.set_strict(strict);
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .strict(self.strict)
+            .child("schema", &self.validator)?
+            .finish()
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -58,3 +108,23 @@ impl Validator for NullableValidator {
         self.validator.complete(build_context)
     }
 }
+
+impl NullableValidator {
+    /// whether `input` is one of `none_strings`, after stripping whitespace with `str_strip_whitespace`,
+    /// never in strict mode
+    fn is_none_string<'data>(&self, input: &'data impl Input<'data>, extra: &Extra) -> ValResult<'data, bool> {
+        if self.none_strings.is_empty() || extra.strict.unwrap_or(self.strict) {
+            return Ok(false);
+        }
+        let either_str = match input.strict_str() {
+            Ok(either_str) => either_str,
+            Err(_) => return Ok(false),
+        };
+        let cow = either_str.as_cow()?;
+        let s = match self.strip_whitespace {
+            true => cow.trim(),
+            false => cow.as_ref(),
+        };
+        Ok(self.none_strings.iter().any(|none_string| none_string == s))
+    }
+}
@@ -0,0 +1,232 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use ahash::AHashSet;
+
+use crate::build_tools::{is_strict, py_err, SchemaDict};
+use crate::errors::{ErrorType, ValError, ValResult};
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::literal::expected_repr_name;
+use super::{BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
+
+#[derive(Debug, Clone, Copy)]
+enum PathFlavor {
+    Native,
+    Posix,
+    Windows,
+}
+
+impl PathFlavor {
+    fn from_schema(schema: &PyDict) -> PyResult<Self> {
+        match schema.get_as::<&str>(intern!(schema.py(), "flavor"))? {
+            None | Some("native") => Ok(Self::Native),
+            Some("posix") => Ok(Self::Posix),
+            Some("windows") => Ok(Self::Windows),
+            Some(s) => py_err!("Invalid path flavor: `{}`, expected `native`, `posix` or `windows`", s),
+        }
+    }
+
+    fn class_name(self) -> &'static str {
+        match self {
+            Self::Native => "Path",
+            Self::Posix => "PurePosixPath",
+            Self::Windows => "PureWindowsPath",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum MustExist {
+    File,
+    Dir,
+    Any,
+}
+
+impl MustExist {
+    fn from_schema(schema: &PyDict) -> PyResult<Option<Self>> {
+        match schema.get_as::<&str>(intern!(schema.py(), "must_exist"))? {
+            None => Ok(None),
+            Some("file") => Ok(Some(Self::File)),
+            Some("dir") => Ok(Some(Self::Dir)),
+            Some("any") => Ok(Some(Self::Any)),
+            Some(s) => py_err!("Invalid must_exist value: `{}`, expected `file`, `dir` or `any`", s),
+        }
+    }
+}
+
+type AllowedSuffixes = Option<(AHashSet<String>, String)>;
+
+#[derive(Debug, Clone)]
+pub struct PathValidator {
+    strict: bool,
+    class: PyObject,
+    pure_path_class: PyObject,
+    path_like_class: PyObject,
+    absolute: bool,
+    suffix_in: AllowedSuffixes,
+    max_parts: Option<usize>,
+    must_exist: Option<MustExist>,
+    name: String,
+}
+
+impl BuildValidator for PathValidator {
+    const EXPECTED_TYPE: &'static str = "path";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        _build_context: &mut BuildContext<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let pathlib = py.import(intern!(py, "pathlib"))?;
+        let flavor = PathFlavor::from_schema(schema)?;
+        let (suffix_in, name) = get_allowed_suffixes(schema, Self::EXPECTED_TYPE)?;
+
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            class: pathlib.getattr(flavor.class_name())?.into_py(py),
+            pure_path_class: pathlib.getattr(intern!(py, "PurePath"))?.into_py(py),
+            path_like_class: py
+                .import(intern!(py, "os"))?
+                .getattr(intern!(py, "PathLike"))?
+                .into_py(py),
+            absolute: schema.get_as(intern!(py, "absolute"))?.unwrap_or(false),
+            suffix_in,
+            max_parts: schema.get_as(intern!(py, "max_parts"))?,
+            must_exist: MustExist::from_schema(schema)?,
+            name,
+        }
+        .into())
+    }
+}
+
+impl Validator for PathValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        _slots: &'data [CombinedValidator],
+        _recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let path_object = self.get_path(py, input, extra.strict.unwrap_or(self.strict))?;
+        let path = path_object.as_ref(py);
+
+        if self.absolute && !path.call_method0(intern!(py, "is_absolute"))?.is_true()? {
+            return Err(ValError::new(ErrorType::PathNotAbsolute, input));
+        }
+
+        if let Some((ref allowed_suffixes, ref expected_suffixes_repr)) = self.suffix_in {
+            let suffix: &str = path.getattr(intern!(py, "suffix"))?.extract()?;
+            if !allowed_suffixes.contains(suffix) {
+                let expected_suffixes = expected_suffixes_repr.clone();
+                return Err(ValError::new(ErrorType::PathSuffix { expected_suffixes }, input));
+            }
+        }
+
+        if let Some(max_parts) = self.max_parts {
+            if path.getattr(intern!(py, "parts"))?.len()? > max_parts {
+                return Err(ValError::new(ErrorType::PathTooManyParts { max_parts }, input));
+            }
+        }
+
+        if let Some(must_exist) = self.must_exist {
+            // this is the only constraint which performs I/O, `os.path` is used so it also works with pure paths
+            let os_path = py.import(intern!(py, "os.path"))?;
+            let (check, error_type) = match must_exist {
+                MustExist::File => (intern!(py, "isfile"), ErrorType::PathNotFile),
+                MustExist::Dir => (intern!(py, "isdir"), ErrorType::PathNotDirectory),
+                MustExist::Any => (intern!(py, "exists"), ErrorType::PathNotExists),
+            };
+            if !os_path.getattr(check)?.call1((path,))?.is_true()? {
+                return Err(ValError::new(error_type, input));
+            }
+        }
+
+        Ok(path_object)
+    }
+
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .strict(self.strict)
+            .accepts(&["path", "str", "bytes", "bytearray", "path_like"], &["path"])
+            .constraint("max_parts", self.max_parts)?
+            .constraint("absolute", self.absolute.then_some(true))?
+            .finish()
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn complete(&mut self, _build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
+        Ok(())
+    }
+}
+
+impl PathValidator {
+    fn get_path<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        strict: bool,
+    ) -> ValResult<'data, PyObject> {
+        let class = self.class.as_ref(py);
+        if input.input_is_instance(self.pure_path_class.as_ref(py), 0)? {
+            let input_value = input.to_object(py);
+            return if input.input_is_instance(class, 0)? {
+                Ok(input_value)
+            } else {
+                Ok(class.call1((input_value,))?.into_py(py))
+            };
+        }
+        // in strict mode only path instances are accepted from python, JSON has no path type so strings are allowed
+        if strict && input.is_python() {
+            return Err(ValError::new(ErrorType::PathType, input));
+        }
+
+        match input.validate_str(strict) {
+            Ok(either_str) => {
+                let cow = either_str.as_cow()?;
+                Ok(class.call1((cow.as_ref(),))?.into_py(py))
+            }
+            Err(_) => {
+                if input.input_is_instance(self.path_like_class.as_ref(py), 0)? {
+                    let os = py.import(intern!(py, "os"))?;
+                    let path_str = os.call_method1(intern!(py, "fsdecode"), (input.to_object(py),))?;
+                    Ok(class.call1((path_str,))?.into_py(py))
+                } else {
+                    Err(ValError::new(ErrorType::PathType, input))
+                }
+            }
+        }
+    }
+}
+
+fn get_allowed_suffixes(schema: &PyDict, name: &'static str) -> PyResult<(AllowedSuffixes, String)> {
+    match schema.get_as::<&PyList>(intern!(schema.py(), "suffix_in"))? {
+        Some(list) => {
+            if list.is_empty() {
+                return py_err!(r#""suffix_in" should have length > 0"#);
+            }
+
+            let mut expected: AHashSet<String> = AHashSet::new();
+            let mut repr_args = Vec::new();
+            for item in list.iter() {
+                let str = item.extract()?;
+                repr_args.push(format!("'{str}'"));
+                expected.insert(str);
+            }
+            let (repr, name) = expected_repr_name(repr_args, name);
+            Ok((Some((expected, repr)), name))
+        }
+        None => Ok((None, name.to_string())),
+    }
+}
@@ -1,13 +1,14 @@
+use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PySet};
 
 use crate::build_tools::SchemaDict;
-use crate::errors::ValResult;
+use crate::errors::{py_err_string, ErrorType, ValError, ValLineError, ValResult};
 use crate::input::{GenericCollection, Input};
 use crate::recursion_guard::RecursionGuard;
 
 use super::list::{get_items_schema, length_check};
-use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub struct SetValidator {
@@ -16,10 +17,164 @@ pub struct SetValidator {
     min_length: Option<usize>,
     max_length: Option<usize>,
     generator_max_length: Option<usize>,
+    fail_fast: bool,
     name: String,
 }
 pub static MAX_LENGTH_GEN_MULTIPLE: usize = 10;
 
+/// Validate the items of `seq` and add them to `set` one at a time, so iterated inputs like generators are only read
+/// as far as needed: the set is too long as soon as it has more than `max_length` distinct items, duplicates don't
+/// count towards `max_length` but every item read counts towards `generator_max_length`.
+/// Other inputs have a known length, so the caller checks their `max_length` once the set is complete
+#[allow(clippy::too_many_arguments)]
+pub fn validate_to_set<'s, 'data>(
+    py: Python<'data>,
+    input: &'data impl Input<'data>,
+    seq: &GenericCollection<'data>,
+    set: &'data PySet,
+    field_type: &'static str,
+    max_length: Option<usize>,
+    generator_max_length: Option<usize>,
+    fail_fast: bool,
+    validator: Option<&'s CombinedValidator>,
+    extra: &Extra,
+    slots: &'data [CombinedValidator],
+    recursion_guard: &'s mut RecursionGuard,
+) -> ValResult<'data, ()> {
+    seq.check_range_length(input, max_length, field_type)?;
+    macro_rules! add_items {
+        ($iter:expr, $max_length:expr) => {
+            add_items(
+                py,
+                input,
+                $iter,
+                set,
+                field_type,
+                $max_length,
+                fail_fast,
+                validator,
+                extra,
+                slots,
+                recursion_guard,
+            )
+        };
+    }
+    match seq {
+        GenericCollection::List(collection) => add_items!(collection.iter().map(Ok), None),
+        GenericCollection::Tuple(collection) => add_items!(collection.iter().map(Ok), None),
+        GenericCollection::Set(collection) => add_items!(collection.iter().map(Ok), None),
+        GenericCollection::FrozenSet(collection) => add_items!(collection.iter().map(Ok), None),
+        GenericCollection::JsonArray(collection) => add_items!(collection.iter().map(Ok), None),
+        GenericCollection::PyAny(collection) => {
+            let iter = collection.iter()?.enumerate().map(|(index, item_result)| {
+                if let Some(generator_max_length) = generator_max_length {
+                    if index > generator_max_length {
+                        return Err(too_long(input, field_type, generator_max_length, index));
+                    }
+                }
+                item_result.map_err(|err| {
+                    let error = py_err_string(py, err);
+                    ValError::new_with_loc(ErrorType::IterationError { error }, input, index)
+                })
+            });
+            add_items!(iter, max_length)
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn add_items<'s, 'data, I: Input<'data> + 'data>(
+    py: Python<'data>,
+    input: &'data impl Input<'data>,
+    iter: impl Iterator<Item = ValResult<'data, &'data I>>,
+    set: &'data PySet,
+    field_type: &'static str,
+    max_length: Option<usize>,
+    fail_fast: bool,
+    validator: Option<&'s CombinedValidator>,
+    extra: &Extra,
+    slots: &'data [CombinedValidator],
+    recursion_guard: &'s mut RecursionGuard,
+) -> ValResult<'data, ()> {
+    let mut errors: Vec<ValLineError> = Vec::new();
+    let mut iter = iter.enumerate().peekable();
+    while let Some((index, item_result)) = iter.next() {
+        let item = item_result?;
+        let output = match validator {
+            Some(validator) => {
+                if !recursion_guard.step() {
+                    errors.push(ValLineError::new_with_loc(
+                        ErrorType::ValidationBudgetExceeded,
+                        item,
+                        index,
+                    ));
+                    return Err(ValError::LineErrors(errors));
+                }
+                let _loc = extra.coercion_loc(|| [index.into()]);
+                extra.report_raw_input(py, item);
+                let mark = extra.error_mark();
+                match validator.validate(py, item, extra, slots, recursion_guard) {
+                    Ok(output) => output,
+                    Err(ValError::LineErrors(line_errors)) => {
+                        let stop = extra.stop_collecting(mark, line_errors.len(), || iter.peek().is_some());
+                        errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index.into())));
+                        if fail_fast || stop {
+                            return Err(ValError::LineErrors(errors));
+                        }
+                        continue;
+                    }
+                    Err(ValError::Omit) => continue,
+                    Err(err) => return Err(err),
+                }
+            }
+            None => item.to_object(py),
+        };
+        if let Err(err) = set.add(&output) {
+            if !is_unhashable(py, output.as_ref(py)) {
+                return Err(err.into());
+            }
+            errors.push(
+                ValLineError::new_custom_input(ErrorType::Unhashable, output.into()).with_outer_location(index.into()),
+            );
+            if fail_fast {
+                return Err(ValError::LineErrors(errors));
+            }
+        } else if let Some(max_length) = max_length {
+            let actual_length = set.len();
+            if actual_length > max_length {
+                return Err(too_long(input, field_type, max_length, actual_length));
+            }
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ValError::LineErrors(errors))
+    }
+}
+
+fn too_long<'data>(
+    input: &'data impl Input<'data>,
+    field_type: &str,
+    max_length: usize,
+    actual_length: usize,
+) -> ValError<'data> {
+    let field_type = field_type.to_string();
+    ValError::new(
+        ErrorType::TooLong {
+            field_type,
+            max_length,
+            actual_length,
+        },
+        input,
+    )
+}
+
+/// Whether hashing `value` raises a `TypeError`, other exceptions raised by `__hash__` are left to propagate
+pub fn is_unhashable(py: Python, value: &PyAny) -> bool {
+    matches!(value.hash(), Err(err) if err.is_instance_of::<PyTypeError>(py))
+}
+
 macro_rules! set_build {
     () => {
         fn build(
@@ -42,6 +197,8 @@ macro_rules! set_build {
                 min_length: schema.get_as(pyo3::intern!(py, "min_length"))?,
                 max_length,
                 generator_max_length,
+                fail_fast: crate::build_tools::schema_or_config_same(schema, config, pyo3::intern!(py, "fail_fast"))?
+                    .unwrap_or(false),
                 name,
             }
             .into())
@@ -65,31 +222,62 @@ impl Validator for SetValidator {
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
         let seq = input.validate_set(extra.strict.unwrap_or(self.strict))?;
-
-        let set = match self.item_validator {
-            Some(ref v) => PySet::new(
-                py,
-                &seq.validate_to_vec(
+        let set = match (seq, &self.item_validator) {
+            (GenericCollection::Set(set), None) => set,
+            (seq, item_validator) => {
+                let set = PySet::empty(py)?;
+                validate_to_set(
                     py,
                     input,
-                    self.max_length,
+                    &seq,
+                    set,
                     "Set",
+                    self.max_length,
                     self.generator_max_length,
-                    v,
+                    self.fail_fast,
+                    item_validator.as_deref(),
                     extra,
                     slots,
                     recursion_guard,
-                )?,
-            )?,
-            None => match seq {
-                GenericCollection::Set(set) => set,
-                _ => PySet::new(py, &seq.to_vec(py, input, "Set", self.generator_max_length)?)?,
-            },
+                )?;
+                set
+            }
         };
-        length_check!(input, "Set", self.min_length, self.max_length, set);
+        length_check!(input, "Set", self.min_length, self.max_length, set, extra);
         Ok(set.into_py(py))
     }
 
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+        if let Some(ref mut v) = self.item_validator {
+            v.set_strict(strict);
+        }
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .strict(self.strict)
+            .accepts(
+                &[
+                    "set",
+                    "list",
+                    "tuple",
+                    "frozenset",
+                    "dict_keys",
+                    "dict_values",
+                    "dict_items",
+                    "iterator",
+                    "deque",
+                    "range",
+                ],
+                &["set"],
+            )
+            .constraint("min_length", self.min_length)?
+            .constraint("max_length", self.max_length)?
+            .opt_child("items_schema", self.item_validator.as_deref())?
+            .finish()
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
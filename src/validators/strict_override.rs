@@ -0,0 +1,98 @@
+use std::fmt;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::build_tools::SchemaDict;
+use crate::errors::ValResult;
+use crate::input::Input;
+use crate::questions::Question;
+use crate::recursion_guard::RecursionGuard;
+
+use super::{BuildContext, CombinedValidator, Extra, Validator};
+
+/// `strict` set explicitly on a schema takes precedence over `strict` given for the validation call: the call's
+/// `strict` is ignored by the schema and everything inside it, which validate with the strictness from their own
+/// schema or config as if no `strict` was given for the call, the wrapper is left out of reprs and descriptions
+#[derive(Clone)]
+pub struct StrictOverrideValidator {
+    validator: Box<CombinedValidator>,
+}
+
+impl StrictOverrideValidator {
+    /// wrap `validator` if the schema sets `strict`, otherwise return it unchanged
+    pub fn wrap(schema: &PyDict, validator: CombinedValidator) -> PyResult<CombinedValidator> {
+        if schema.get_as::<bool>(intern!(schema.py(), "strict"))?.is_some() {
+            Ok(Self {
+                validator: Box::new(validator),
+            }
+            .into())
+        } else {
+            Ok(validator)
+        }
+    }
+}
+
+impl fmt::Debug for StrictOverrideValidator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.validator.fmt(f)
+    }
+}
+
+impl Validator for StrictOverrideValidator {
+    fn py_gc_traverse(&self, visit: &pyo3::PyVisit<'_>) -> Result<(), pyo3::PyTraverseError> {
+        self.validator.py_gc_traverse(visit)
+    }
+
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        match extra.strict {
+            Some(_) => self
+                .validator
+                .validate(py, input, &extra.with_strict(None), slots, recursion_guard),
+            None => self.validator.validate(py, input, extra, slots, recursion_guard),
+        }
+    }
+
+    fn validate_assignment<'s, 'data: 's>(
+        &'s self,
+        py: Python<'data>,
+        obj: &'data PyAny,
+        field_name: &'data str,
+        field_value: &'data PyAny,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let extra = extra.with_strict(None);
+        self.validator
+            .validate_assignment(py, obj, field_name, field_value, &extra, slots, recursion_guard)
+    }
+
+    fn set_strict(&mut self, strict: bool) {
+        self.validator.set_strict(strict);
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        self.validator.describe(py)
+    }
+
+    fn get_name(&self) -> &str {
+        self.validator.get_name()
+    }
+
+    fn ask(&self, question: &Question) -> bool {
+        self.validator.ask(question)
+    }
+
+    fn complete(&mut self, build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
+        self.validator.complete(build_context)
+    }
+}
@@ -1,18 +1,24 @@
+use pyo3::exceptions::PyTypeError;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyString};
 use regex::Regex;
 
-use crate::build_tools::{is_strict, py_error_type, schema_or_config, SchemaDict};
+use crate::build_tools::{
+    coerce_hook_enabled, is_strict, py_err, py_error_type, schema_or_config, schema_or_config_same, SchemaDict,
+};
 use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::Input;
+use crate::input::{with_coerce_hook, Input};
+use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
-use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::function::convert_err;
+use super::{BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub struct StrValidator {
     strict: bool,
+    coerce_hook: bool,
 }
 
 impl BuildValidator for StrValidator {
@@ -21,15 +27,16 @@ impl BuildValidator for StrValidator {
     fn build(
         schema: &PyDict,
         config: Option<&PyDict>,
-        _build_context: &mut BuildContext<CombinedValidator>,
+        build_context: &mut BuildContext<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
-        let con_str_validator = StrConstrainedValidator::build(schema, config)?;
+        let con_str_validator = StrConstrainedValidator::build(schema, config, build_context)?;
 
         if con_str_validator.has_constraints_set() {
             Ok(con_str_validator.into())
         } else {
             Ok(Self {
                 strict: con_str_validator.strict,
+                coerce_hook: con_str_validator.coerce_hook,
             }
             .into())
         }
@@ -45,13 +52,32 @@ impl Validator for StrValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        Ok(input.validate_str(extra.strict.unwrap_or(self.strict))?.into_py(py))
+        let strict = extra.strict.unwrap_or(self.strict);
+        let coerce_hook = self.coerce_hook && !strict;
+        let either_str = with_coerce_hook(input, "str", coerce_hook, input.validate_str(strict), |v| v.lax_str())?;
+        extra.report_coercion(py, input, "str", || input.strict_str().is_ok());
+        Ok(extra.py_string(py, input, &either_str).into_py(py))
+    }
+
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .strict(self.strict)
+            .accepts(&["str", "bytes", "bytearray"], &["str"])
+            .finish()
     }
 
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
 
+    fn ask(&self, question: &Question) -> bool {
+        matches!(question, Question::ReturnsImmutable)
+    }
+
     fn complete(&mut self, _build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
         Ok(())
     }
@@ -61,12 +87,15 @@ impl Validator for StrValidator {
 #[derive(Debug, Clone, Default)]
 pub struct StrConstrainedValidator {
     strict: bool,
-    pattern: Option<Regex>,
+    coerce_hook: bool,
+    // more than one pattern only when steps of a chain are merged, see `merge_str_validators`
+    patterns: Vec<Pattern>,
     max_length: Option<usize>,
     min_length: Option<usize>,
     strip_whitespace: bool,
     to_lower: bool,
     to_upper: bool,
+    post_validator: Option<PyObject>,
 }
 
 impl Validator for StrConstrainedValidator {
@@ -78,7 +107,10 @@ impl Validator for StrConstrainedValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let either_str = input.validate_str(extra.strict.unwrap_or(self.strict))?;
+        let strict = extra.strict.unwrap_or(self.strict);
+        let coerce_hook = self.coerce_hook && !strict;
+        let either_str = with_coerce_hook(input, "str", coerce_hook, input.validate_str(strict), |v| v.lax_str())?;
+        extra.report_coercion(py, input, "str", || input.strict_str().is_ok());
         let cow = either_str.as_cow()?;
         let mut str = cow.as_ref();
         if self.strip_whitespace {
@@ -95,11 +127,11 @@ impl Validator for StrConstrainedValidator {
                 return Err(ValError::new(ErrorType::StringTooLong { max_length }, input));
             }
         }
-        if let Some(pattern) = &self.pattern {
-            if !pattern.is_match(str) {
+        for pattern in &self.patterns {
+            if !pattern.is_match(py, str)? {
                 return Err(ValError::new(
                     ErrorType::StringPatternMismatch {
-                        pattern: pattern.to_string(),
+                        pattern: pattern.pattern.clone(),
                     },
                     input,
                 ));
@@ -114,26 +146,65 @@ impl Validator for StrConstrainedValidator {
             PyString::new(py, str)
         } else {
             // we haven't modified the string, return the original as it might be a PyString
-            either_str.as_py_string(py)
+            extra.py_string(py, input, &either_str)
+        };
+        match self.post_validator {
+            Some(ref func) => {
+                let output = func.call1(py, (py_string,)).map_err(|e| convert_err(py, e, input))?;
+                if output.as_ref(py).is_instance_of::<PyString>()? {
+                    Ok(output)
+                } else {
+                    let type_name = output.as_ref(py).get_type().name()?;
+                    let msg = format!("post_validator must return a str, got {type_name}");
+                    Err(ValError::InternalErr(PyTypeError::new_err(msg)))
+                }
+            }
+            None => Ok(py_string.into_py(py)),
+        }
+    }
+
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        let patterns = match self.patterns.is_empty() {
+            true => None,
+            false => Some(self.patterns.iter().map(|p| p.pattern.as_str()).collect::<Vec<_>>()),
         };
-        Ok(py_string.into_py(py))
+        Description::new(py, StrValidator::EXPECTED_TYPE)
+            .strict(self.strict)
+            .accepts(&["str", "bytes", "bytearray"], &["str"])
+            .constraint("min_length", self.min_length)?
+            .constraint("max_length", self.max_length)?
+            .constraint("patterns", patterns)?
+            .finish()
     }
 
     fn get_name(&self) -> &str {
         "constrained-str"
     }
 
+    fn ask(&self, question: &Question) -> bool {
+        // `post_validator` is arbitrary python which could return a different value each time
+        matches!(question, Question::ReturnsImmutable) && self.post_validator.is_none()
+    }
+
     fn complete(&mut self, _build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
         Ok(())
     }
 }
 
 impl StrConstrainedValidator {
-    fn build(schema: &PyDict, config: Option<&PyDict>) -> PyResult<Self> {
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext<CombinedValidator>,
+    ) -> PyResult<Self> {
         let py = schema.py();
-        let pattern = match schema.get_as(intern!(py, "pattern"))? {
-            Some(s) => Some(Regex::new(s).map_err(|e| py_error_type!("{}", e))?),
-            None => None,
+        let patterns = match schema.get_item(intern!(py, "pattern")) {
+            Some(pattern) => vec![Pattern::compile(schema, config, pattern, build_context)?],
+            None => Vec::new(),
         };
         let min_length: Option<usize> =
             schema_or_config(schema, config, intern!(py, "min_length"), intern!(py, "str_min_length"))?;
@@ -154,23 +225,153 @@ impl StrConstrainedValidator {
 
         Ok(Self {
             strict: is_strict(schema, config)?,
-            pattern,
+            coerce_hook: coerce_hook_enabled(config)?,
+            patterns,
             min_length,
             max_length,
             strip_whitespace,
             to_lower,
             to_upper,
+            post_validator: schema.get_as(intern!(py, "post_validator"))?,
         })
     }
 
+    fn from_combined(validator: &CombinedValidator) -> Option<Self> {
+        match validator {
+            CombinedValidator::Str(v) => Some(Self {
+                strict: v.strict,
+                coerce_hook: v.coerce_hook,
+                ..Self::default()
+            }),
+            CombinedValidator::StrConstrained(v) => Some(v.clone()),
+            _ => None,
+        }
+    }
+
+    fn has_transforms(&self) -> bool {
+        self.strip_whitespace || self.to_lower || self.to_upper || self.post_validator.is_some()
+    }
+
     // whether any of the constraints/customisations are actually enabled
-    // except strict which can be set on StrValidator
+    // except strict and coerce_hook which can be set on StrValidator
     fn has_constraints_set(&self) -> bool {
-        self.pattern.is_some()
+        !self.patterns.is_empty()
             || self.max_length.is_some()
             || self.min_length.is_some()
             || self.strip_whitespace
             || self.to_lower
             || self.to_upper
+            || self.post_validator.is_some()
+    }
+}
+
+/// A `pattern` constraint compiled with the engine chosen by `regex_engine`, `pattern` is the original pattern
+/// string which is reported in errors whichever engine is used
+#[derive(Debug, Clone)]
+struct Pattern {
+    pattern: String,
+    matcher: Matcher,
+}
+
+#[derive(Debug, Clone)]
+enum Matcher {
+    // the `regex` crate, fast but without lookarounds or backreferences, matches anywhere in the string
+    RustRegex(Regex),
+    // the `match` method of a compiled `re.Pattern`, so the pattern must match at the start of the string
+    PythonRe(PyObject),
+}
+
+impl Pattern {
+    /// `regex_engine` defaults to `'rust-regex'`, or to `'python-re'` if `pattern` is already a compiled `re.Pattern`
+    fn compile(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        pattern: &PyAny,
+        build_context: &mut BuildContext<CombinedValidator>,
+    ) -> PyResult<Self> {
+        let py = schema.py();
+        let re = py.import(intern!(py, "re"))?;
+        let is_compiled = pattern.is_instance(re.getattr(intern!(py, "Pattern"))?)?;
+        let engine: Option<&str> = schema_or_config_same(schema, config, intern!(py, "regex_engine"))?;
+        match (engine, is_compiled) {
+            (Some("rust-regex") | None, false) => {
+                let pattern: String = pattern
+                    .extract()
+                    .map_err(|_| py_error_type!("`pattern` must be a str or a compiled `re.Pattern`"))?;
+                let regex = build_context
+                    .compile_regex(&pattern)
+                    .map_err(|e| py_error_type!("Unable to compile pattern with regex_engine 'rust-regex':\n{}", e))?;
+                Ok(Self {
+                    pattern,
+                    matcher: Matcher::RustRegex(regex),
+                })
+            }
+            (Some("rust-regex"), true) => {
+                py_err!("A compiled `re.Pattern` can only be used with regex_engine 'python-re'")
+            }
+            (Some("python-re"), _) | (None, true) => {
+                // `re.compile` returns a compiled pattern unchanged
+                let compiled = re
+                    .call_method1(intern!(py, "compile"), (pattern,))
+                    .map_err(|e| py_error_type!("Unable to compile pattern with regex_engine 'python-re': {}", e))?;
+                let pattern: String = compiled
+                    .getattr(intern!(py, "pattern"))?
+                    .extract()
+                    .map_err(|_| py_error_type!("`pattern` must be a str pattern, not a bytes pattern"))?;
+                Ok(Self {
+                    pattern,
+                    matcher: Matcher::PythonRe(compiled.getattr(intern!(py, "match"))?.into_py(py)),
+                })
+            }
+            (Some(engine), _) => py_err!(
+                "Invalid regex_engine: `{}`, expected `rust-regex` or `python-re`",
+                engine
+            ),
+        }
+    }
+
+    fn is_match(&self, py: Python, str: &str) -> PyResult<bool> {
+        match self.matcher {
+            Matcher::RustRegex(ref regex) => Ok(regex.is_match(str)),
+            Matcher::PythonRe(ref match_) => Ok(!match_.call1(py, (str,))?.is_none(py)),
+        }
+    }
+}
+
+/// merge `next`, a chain step validating the output of `first`, into a single validator, `None` if either
+/// transforms the string or merging would change which error some input gets: lengths are checked before patterns,
+/// so `next` can't add length constraints after a pattern, and an empty length range can't be merged
+pub fn merge_str_validators(first: &CombinedValidator, next: &CombinedValidator) -> Option<CombinedValidator> {
+    let first = StrConstrainedValidator::from_combined(first)?;
+    let next = StrConstrainedValidator::from_combined(next)?;
+    if first.has_transforms() || next.has_transforms() {
+        return None;
+    }
+    if !first.patterns.is_empty() && (next.min_length.is_some() || next.max_length.is_some()) {
+        return None;
+    }
+    let min_length = first.min_length.max(next.min_length);
+    let max_length = match (first.max_length, next.max_length) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    };
+    if matches!((min_length, max_length), (Some(min), Some(max)) if min > max) {
+        return None;
+    }
+    let merged = StrConstrainedValidator {
+        patterns: first.patterns.into_iter().chain(next.patterns).collect(),
+        min_length,
+        max_length,
+        ..first
+    };
+    match merged.has_constraints_set() {
+        true => Some(merged.into()),
+        false => Some(
+            StrValidator {
+                strict: merged.strict,
+                coerce_hook: merged.coerce_hook,
+            }
+            .into(),
+        ),
     }
 }
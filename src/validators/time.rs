@@ -5,15 +5,17 @@ use speedate::Time;
 
 use crate::build_tools::{is_strict, SchemaDict};
 use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::{EitherTime, Input};
+use crate::input::{EitherTime, Input, MicrosecondsPrecision};
+use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
-use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub struct TimeValidator {
     strict: bool,
     constraints: Option<TimeConstraints>,
+    microseconds_precision: MicrosecondsPrecision,
 }
 
 #[derive(Debug, Clone)]
@@ -49,6 +51,7 @@ impl BuildValidator for TimeValidator {
                 }),
                 false => None,
             },
+            microseconds_precision: MicrosecondsPrecision::from_py(schema, config)?,
         }
         .into())
     }
@@ -63,7 +66,10 @@ impl Validator for TimeValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let time = input.validate_time(extra.strict.unwrap_or(self.strict))?;
+        let time = input.validate_time(extra.strict.unwrap_or(self.strict), self.microseconds_precision)?;
+        extra.report_coercion(py, input, "time", || {
+            input.strict_time(self.microseconds_precision).is_ok()
+        });
         if let Some(constraints) = &self.constraints {
             let raw_time = time.as_raw()?;
 
@@ -90,10 +96,33 @@ impl Validator for TimeValidator {
         Ok(time.try_into_py(py)?)
     }
 
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        let description = Description::new(py, Self::EXPECTED_TYPE)
+            .strict(self.strict)
+            .accepts(&["time", "str", "bytes", "int", "float"], &["time"]);
+        match self.constraints {
+            Some(ref c) => description
+                .constraint("le", c.le.as_ref().map(ToString::to_string))?
+                .constraint("lt", c.lt.as_ref().map(ToString::to_string))?
+                .constraint("ge", c.ge.as_ref().map(ToString::to_string))?
+                .constraint("gt", c.gt.as_ref().map(ToString::to_string))?
+                .finish(),
+            None => description.finish(),
+        }
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
 
+    fn ask(&self, question: &Question) -> bool {
+        matches!(question, Question::ReturnsImmutable)
+    }
+
     fn complete(&mut self, _build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
         Ok(())
     }
@@ -3,17 +3,19 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDelta, PyDict, PyString};
 use speedate::Duration;
 
-use crate::build_tools::{is_strict, SchemaDict};
+use crate::build_tools::{is_strict, py_err, SchemaDict};
 use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::{EitherTimedelta, Input};
+use crate::input::{EitherTimedelta, Input, MicrosecondsPrecision};
+use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
-use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub struct TimeDeltaValidator {
     strict: bool,
     constraints: Option<TimedeltaConstraints>,
+    microseconds_precision: MicrosecondsPrecision,
 }
 
 #[derive(Debug, Clone)]
@@ -22,7 +24,9 @@ struct TimedeltaConstraints {
     lt: Option<Duration>,
     ge: Option<Duration>,
     gt: Option<Duration>,
+    multiple_of: Option<Duration>,
 }
+
 impl BuildValidator for TimeDeltaValidator {
     const EXPECTED_TYPE: &'static str = "timedelta";
 
@@ -35,7 +39,8 @@ impl BuildValidator for TimeDeltaValidator {
         let has_constraints = schema.get_item(intern!(py, "le")).is_some()
             || schema.get_item(intern!(py, "lt")).is_some()
             || schema.get_item(intern!(py, "ge")).is_some()
-            || schema.get_item(intern!(py, "gt")).is_some();
+            || schema.get_item(intern!(py, "gt")).is_some()
+            || schema.get_item(intern!(py, "multiple_of")).is_some();
 
         Ok(Self {
             strict: is_strict(schema, config)?,
@@ -45,9 +50,16 @@ impl BuildValidator for TimeDeltaValidator {
                     lt: py_timedelta_as_timedelta(schema, intern!(py, "lt"))?,
                     ge: py_timedelta_as_timedelta(schema, intern!(py, "ge"))?,
                     gt: py_timedelta_as_timedelta(schema, intern!(py, "gt"))?,
+                    multiple_of: match py_timedelta_as_timedelta(schema, intern!(py, "multiple_of"))? {
+                        Some(multiple_of) if total_microseconds(&multiple_of) == 0 => {
+                            return py_err!("'multiple_of' must not be a zero timedelta")
+                        }
+                        multiple_of => multiple_of,
+                    },
                 }),
                 false => None,
             },
+            microseconds_precision: MicrosecondsPrecision::from_py(schema, config)?,
         }
         .into())
     }
@@ -62,7 +74,10 @@ impl Validator for TimeDeltaValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let timedelta = input.validate_timedelta(extra.strict.unwrap_or(self.strict))?;
+        let timedelta = input.validate_timedelta(extra.strict.unwrap_or(self.strict), self.microseconds_precision)?;
+        extra.report_coercion(py, input, "timedelta", || {
+            input.strict_timedelta(self.microseconds_precision).is_ok()
+        });
         if let Some(constraints) = &self.constraints {
             let raw_timedelta = timedelta.as_raw();
 
@@ -85,19 +100,60 @@ impl Validator for TimeDeltaValidator {
             check_constraint!(lt, LessThan);
             check_constraint!(ge, GreaterThanEqual);
             check_constraint!(gt, GreaterThan);
+
+            if let Some(multiple_of) = &constraints.multiple_of {
+                if total_microseconds(&raw_timedelta) % total_microseconds(multiple_of) != 0 {
+                    return Err(ValError::new(
+                        ErrorType::MultipleOf {
+                            multiple_of: multiple_of.to_string().into(),
+                        },
+                        input,
+                    ));
+                }
+            }
         }
         Ok(timedelta.try_into_py(py)?)
     }
 
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        let description = Description::new(py, Self::EXPECTED_TYPE)
+            .strict(self.strict)
+            .accepts(&["timedelta", "str", "bytes", "int", "float"], &["timedelta"]);
+        match self.constraints {
+            Some(ref c) => description
+                .constraint("le", c.le.as_ref().map(ToString::to_string))?
+                .constraint("lt", c.lt.as_ref().map(ToString::to_string))?
+                .constraint("ge", c.ge.as_ref().map(ToString::to_string))?
+                .constraint("gt", c.gt.as_ref().map(ToString::to_string))?
+                .constraint("multiple_of", c.multiple_of.as_ref().map(ToString::to_string))?
+                .finish(),
+            None => description.finish(),
+        }
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
 
+    fn ask(&self, question: &Question) -> bool {
+        matches!(question, Question::ReturnsImmutable)
+    }
+
     fn complete(&mut self, _build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
         Ok(())
     }
 }
 
+/// exact signed length of the duration in microseconds, used to check `multiple_of` without float rounding
+/// (as `i128` since 999,999,999 days in microseconds overflows `i64`)
+fn total_microseconds(duration: &Duration) -> i128 {
+    duration.signed_total_seconds() as i128 * 1_000_000 + duration.signed_microseconds() as i128
+}
+
 fn py_timedelta_as_timedelta(schema: &PyDict, field: &PyString) -> PyResult<Option<Duration>> {
     match schema.get_as::<&PyDelta>(field)? {
         Some(timedelta) => Ok(Some(EitherTimedelta::Py(timedelta).as_raw())),
@@ -0,0 +1,95 @@
+use pyo3::exceptions::{PyKeyError, PyValueError};
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::build_tools::is_strict;
+use crate::errors::{ErrorType, ValError, ValResult};
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::{BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
+
+#[derive(Debug, Clone)]
+pub struct TimezoneValidator {
+    strict: bool,
+    tzinfo_class: PyObject,
+    zone_info_class: PyObject,
+}
+
+impl BuildValidator for TimezoneValidator {
+    const EXPECTED_TYPE: &'static str = "timezone";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        _build_context: &mut BuildContext<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            tzinfo_class: py
+                .import(intern!(py, "datetime"))?
+                .getattr(intern!(py, "tzinfo"))?
+                .into_py(py),
+            zone_info_class: py
+                .import(intern!(py, "zoneinfo"))?
+                .getattr(intern!(py, "ZoneInfo"))?
+                .into_py(py),
+        }
+        .into())
+    }
+}
+
+impl Validator for TimezoneValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        _slots: &'data [CombinedValidator],
+        _recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        // any tzinfo is accepted as is, including `datetime.timezone` and `pytz` timezones
+        if input.input_is_instance(self.tzinfo_class.as_ref(py), 0)? {
+            return Ok(input.to_object(py));
+        }
+        // in strict mode only tzinfo instances are accepted from python, JSON has no timezone type so strings
+        // are allowed
+        if extra.strict.unwrap_or(self.strict) && input.is_python() {
+            return Err(ValError::new(ErrorType::TimezoneType, input));
+        }
+
+        let either_str = input
+            .validate_str(true)
+            .map_err(|_| ValError::new(ErrorType::TimezoneType, input))?;
+        let name = either_str.as_cow()?;
+        match self.zone_info_class.call1(py, (name.as_ref(),)) {
+            Ok(zone_info) => Ok(zone_info),
+            // `ZoneInfoNotFoundError` is a subclass of `KeyError`, invalid keys like absolute paths raise `ValueError`
+            Err(err) if err.is_instance_of::<PyKeyError>(py) || err.is_instance_of::<PyValueError>(py) => {
+                Err(ValError::new(ErrorType::TimezoneName { name: name.to_string() }, input))
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .strict(self.strict)
+            .accepts(&["tzinfo", "str"], &["tzinfo"])
+            .finish()
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+
+    fn complete(&mut self, _build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
+        Ok(())
+    }
+}
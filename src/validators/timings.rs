@@ -0,0 +1,84 @@
+//! Cumulative timings of validators for profiling schemas, only compiled with the `timings` feature.
+//! Validators which aggregate other validators call `timer!` at the start of `validate`, the timer records
+//! the time until it's dropped against the path of validator names leading to it.
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+
+use indexmap::IndexMap;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+#[derive(Default)]
+struct Timings {
+    path: Vec<String>,
+    totals: IndexMap<String, (Duration, usize)>,
+}
+
+thread_local! {
+    // `None` unless timings are being collected by `collect`
+    static TIMINGS: RefCell<Option<Timings>> = const { RefCell::new(None) };
+}
+
+pub struct Timer {
+    start: Instant,
+    // `None` if timings aren't being collected
+    key: Option<String>,
+}
+
+impl Timer {
+    pub fn start(name: &str) -> Self {
+        let key = TIMINGS.with(|timings| {
+            timings.borrow_mut().as_mut().map(|timings| {
+                timings.path.push(name.to_string());
+                let key = timings.path.join(" > ");
+                // insert the key now so timings are ordered by when validators were first called
+                timings.totals.entry(key.clone()).or_default();
+                key
+            })
+        });
+        Self {
+            start: Instant::now(),
+            key,
+        }
+    }
+}
+
+impl Drop for Timer {
+    fn drop(&mut self) {
+        if let Some(key) = self.key.take() {
+            let elapsed = self.start.elapsed();
+            TIMINGS.with(|timings| {
+                if let Some(timings) = timings.borrow_mut().as_mut() {
+                    timings.path.pop();
+                    let (total, calls) = timings.totals.entry(key).or_default();
+                    *total += elapsed;
+                    *calls += 1;
+                }
+            });
+        }
+    }
+}
+
+/// Call `f` while collecting timings, the timings are returned as a dict of
+/// `{path: {'total': seconds, 'calls': count}}` in the order validators were first called
+pub fn collect<T>(py: Python, f: impl FnOnce() -> T) -> PyResult<(T, Py<PyDict>)> {
+    let previous = TIMINGS.with(|timings| timings.replace(Some(Timings::default())));
+    let result = f();
+    let timings = TIMINGS.with(|timings| timings.replace(previous)).unwrap_or_default();
+
+    let dict = PyDict::new(py);
+    for (path, (total, calls)) in timings.totals {
+        let item = PyDict::new(py);
+        item.set_item("total", total.as_secs_f64())?;
+        item.set_item("calls", calls)?;
+        dict.set_item(path, item)?;
+    }
+    Ok((result, dict.into()))
+}
+
+macro_rules! timer {
+    ($name:expr) => {
+        let _timer = crate::validators::timings::Timer::start($name);
+    };
+}
+pub(crate) use timer;
@@ -1,19 +1,23 @@
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyTuple};
+use pyo3::PyTypeInfo;
 
-use crate::build_tools::{is_strict, SchemaDict};
+use crate::build_tools::{force_exact_container_path, is_strict, schema_or_config_same, SchemaDict};
 use crate::errors::{ErrorType, ValError, ValLineError, ValResult};
-use crate::input::{GenericCollection, Input};
+use crate::input::{GenericCollection, Input, PrimitiveItems};
 use crate::recursion_guard::RecursionGuard;
 
-use super::list::{get_items_schema, length_check};
-use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::list::{get_items_schema, get_primitive_items, length_check};
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub struct TupleVariableValidator {
     strict: bool,
+    force_exact_container_path: bool,
+    fail_fast: bool,
     item_validator: Option<Box<CombinedValidator>>,
+    primitive_items: Option<PrimitiveItems>,
     min_length: Option<usize>,
     max_length: Option<usize>,
     name: String,
@@ -32,6 +36,9 @@ impl BuildValidator for TupleVariableValidator {
         let name = format!("tuple[{inner_name}, ...]");
         Ok(Self {
             strict: crate::build_tools::is_strict(schema, config)?,
+            force_exact_container_path: force_exact_container_path(config)?,
+            fail_fast: schema_or_config_same(schema, config, intern!(py, "fail_fast"))?.unwrap_or(false),
+            primitive_items: get_primitive_items(item_validator.as_deref()),
             item_validator,
             min_length: schema.get_as(pyo3::intern!(py, "min_length"))?,
             max_length: schema.get_as(pyo3::intern!(py, "max_length"))?,
@@ -50,7 +57,9 @@ impl Validator for TupleVariableValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let seq = input.validate_tuple(extra.strict.unwrap_or(self.strict))?;
+        let seq = input
+            .validate_tuple(extra.strict.unwrap_or(self.strict))?
+            .with_subclass_path(self.force_exact_container_path);
 
         let output = match self.item_validator {
             Some(ref v) => seq.validate_to_vec(
@@ -59,6 +68,8 @@ impl Validator for TupleVariableValidator {
                 self.max_length,
                 "Tuple",
                 self.max_length,
+                self.fail_fast,
+                self.primitive_items,
                 v,
                 extra,
                 slots,
@@ -66,16 +77,49 @@ impl Validator for TupleVariableValidator {
             )?,
             None => match seq {
                 GenericCollection::Tuple(tuple) => {
-                    length_check!(input, "Tuple", self.min_length, self.max_length, tuple);
-                    return Ok(tuple.into_py(py));
+                    length_check!(input, "Tuple", self.min_length, self.max_length, tuple, extra);
+                    // a subclass only gets here with `force_exact_container_path`, the output is still a plain tuple
+                    return match PyTuple::is_exact_type_of(tuple) {
+                        true => Ok(tuple.into_py(py)),
+                        false => Ok(PyTuple::new(py, tuple).into_py(py)),
+                    };
                 }
-                _ => seq.to_vec(py, input, "Tuple", self.max_length)?,
+                _ => seq.to_vec(py, input, self.max_length, "Tuple", self.max_length, extra.string_cache)?,
             },
         };
-        length_check!(input, "Tuple", self.min_length, self.max_length, output);
+        length_check!(input, "Tuple", self.min_length, self.max_length, output, extra);
         Ok(PyTuple::new(py, &output).into_py(py))
     }
 
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+        if let Some(ref mut v) = self.item_validator {
+            v.set_strict(strict);
+        }
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .strict(self.strict)
+            .accepts(
+                &[
+                    "tuple",
+                    "list",
+                    "dict_keys",
+                    "dict_values",
+                    "dict_items",
+                    "iterator",
+                    "deque",
+                    "range",
+                ],
+                &["tuple"],
+            )
+            .constraint("min_length", self.min_length)?
+            .constraint("max_length", self.max_length)?
+            .opt_child("items_schema", self.item_validator.as_deref())?
+            .finish()
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -91,8 +135,12 @@ impl Validator for TupleVariableValidator {
 #[derive(Debug, Clone)]
 pub struct TuplePositionalValidator {
     strict: bool,
+    force_exact_container_path: bool,
+    fail_fast: bool,
     items_validators: Vec<CombinedValidator>,
     extra_validator: Option<Box<CombinedValidator>>,
+    // whether missing trailing items are filled from their defaults, otherwise short input is an error
+    fill_missing: bool,
     name: String,
 }
 
@@ -113,11 +161,14 @@ impl BuildValidator for TuplePositionalValidator {
         let descr = validators.iter().map(|v| v.get_name()).collect::<Vec<_>>().join(", ");
         Ok(Self {
             strict: is_strict(schema, config)?,
+            force_exact_container_path: force_exact_container_path(config)?,
+            fail_fast: schema_or_config_same(schema, config, intern!(py, "fail_fast"))?.unwrap_or(false),
             items_validators: validators,
             extra_validator: match schema.get_item(intern!(py, "extra_schema")) {
                 Some(v) => Some(Box::new(build_validator(v, config, build_context)?)),
                 None => None,
             },
+            fill_missing: schema.get_as(intern!(py, "fill_missing"))?.unwrap_or(true),
             name: format!("tuple[{descr}]"),
         }
         .into())
@@ -133,48 +184,118 @@ impl Validator for TuplePositionalValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let collection = input.validate_tuple(extra.strict.unwrap_or(self.strict))?;
+        let collection = input
+            .validate_tuple(extra.strict.unwrap_or(self.strict))?
+            .with_subclass_path(self.force_exact_container_path);
         let expected_length = self.items_validators.len();
+        if self.extra_validator.is_none() {
+            collection.check_range_length(input, Some(expected_length), "Tuple")?;
+        }
 
         let mut output: Vec<PyObject> = Vec::with_capacity(expected_length);
         let mut errors: Vec<ValLineError> = Vec::new();
         macro_rules! iter {
             ($collection_iter:expr) => {{
+                // with `allow_partial`, only the last item may be incomplete
+                let mut collection_iter = $collection_iter.enumerate().peekable();
                 for (index, validator) in self.items_validators.iter().enumerate() {
-                    match $collection_iter.next() {
-                        Some(item) => match validator.validate(py, item, extra, slots, recursion_guard) {
-                            Ok(item) => output.push(item),
-                            Err(ValError::LineErrors(line_errors)) => {
-                                errors.extend(
-                                    line_errors
-                                        .into_iter()
-                                        .map(|err| err.with_outer_location(index.into())),
-                                );
+                    let _loc = extra.coercion_loc(|| [index.into()]);
+                    match collection_iter.next() {
+                        Some((_, item)) => {
+                            extra.report_raw_input(py, item);
+                            let partial_extra;
+                            let item_extra = match extra.is_partial() {
+                                true => {
+                                    partial_extra = extra.partial_item(collection_iter.peek().is_none());
+                                    &partial_extra
+                                }
+                                false => extra,
+                            };
+                            let mark = extra.error_mark();
+                            match validator.validate(py, item, item_extra, slots, recursion_guard) {
+                                Ok(item) => output.push(item),
+                                Err(ValError::LineErrors(line_errors)) => {
+                                    let stop = extra.stop_collecting(mark, line_errors.len(), || {
+                                        index + 1 < self.items_validators.len() || collection_iter.peek().is_some()
+                                    });
+                                    errors.extend(
+                                        line_errors
+                                            .into_iter()
+                                            .map(|err| err.with_outer_location(index.into())),
+                                    );
+                                    if self.fail_fast || stop {
+                                        return Err(ValError::LineErrors(errors));
+                                    }
+                                }
+                                Err(err) => return Err(err),
                             }
-                            Err(err) => return Err(err),
-                        },
+                        }
+                        // the input may have been cut off before this item
+                        None if !self.fill_missing && extra.is_partial() => break,
+                        None if !self.fill_missing => {
+                            errors.push(ValLineError::new(
+                                ErrorType::TooShort {
+                                    field_type: "Tuple".to_string(),
+                                    min_length: expected_length,
+                                    actual_length: collection.generic_len()?,
+                                },
+                                input,
+                            ));
+                            break;
+                        }
                         None => {
-                            if let Some(value) =
-                                validator.default_value(py, Some(index), extra, slots, recursion_guard)?
-                            {
-                                output.push(value);
-                            } else {
-                                errors.push(ValLineError::new_with_loc(ErrorType::Missing, input, index));
+                            let _loc = extra.coercion_loc(|| [index.into()]);
+                            // `data` from an enclosing typed dict isn't data a default factory should see here
+                            let default_extra = Extra {
+                                data: None,
+                                partial_depth: 0,
+                                ..*extra
+                            };
+                            match validator.default_value(py, Some(index), &default_extra, slots, recursion_guard) {
+                                Ok(Some(value)) => output.push(value),
+                                Ok(None) if extra.is_partial() => (),
+                                Ok(None) => errors.push(ValLineError::new_with_loc(ErrorType::Missing, input, index)),
+                                Err(ValError::LineErrors(line_errors)) => errors.extend(line_errors),
+                                Err(err) => return Err(err),
                             }
                         }
                     }
                 }
-                for (index, item) in $collection_iter.enumerate() {
+                while let Some((index, item)) = collection_iter.next() {
+                    let _loc = extra.coercion_loc(|| [index.into()]);
+                    extra.report_raw_input(py, item);
                     match self.extra_validator {
                         Some(ref extra_validator) => {
-                            match extra_validator.validate(py, item, extra, slots, recursion_guard) {
+                            if !recursion_guard.step() {
+                                errors.push(ValLineError::new_with_loc(
+                                    ErrorType::ValidationBudgetExceeded,
+                                    item,
+                                    index,
+                                ));
+                                return Err(ValError::LineErrors(errors));
+                            }
+                            let partial_extra;
+                            let item_extra = match extra.is_partial() {
+                                true => {
+                                    partial_extra = extra.partial_item(collection_iter.peek().is_none());
+                                    &partial_extra
+                                }
+                                false => extra,
+                            };
+                            let mark = extra.error_mark();
+                            match extra_validator.validate(py, item, item_extra, slots, recursion_guard) {
                                 Ok(item) => output.push(item),
                                 Err(ValError::LineErrors(line_errors)) => {
+                                    let stop = extra
+                                        .stop_collecting(mark, line_errors.len(), || collection_iter.peek().is_some());
                                     errors.extend(
                                         line_errors
                                             .into_iter()
-                                            .map(|err| err.with_outer_location((index + expected_length).into())),
+                                            .map(|err| err.with_outer_location(index.into())),
                                     );
+                                    if self.fail_fast || stop {
+                                        return Err(ValError::LineErrors(errors));
+                                    }
                                 }
                                 Err(ValError::Omit) => (),
                                 Err(err) => return Err(err),
@@ -198,21 +319,17 @@ impl Validator for TuplePositionalValidator {
         }
         match collection {
             GenericCollection::List(collection) => {
-                let mut iter = collection.iter();
-                iter!(iter)
+                iter!(collection.iter())
             }
             GenericCollection::Tuple(collection) => {
-                let mut iter = collection.iter();
-                iter!(iter)
+                iter!(collection.iter())
             }
             GenericCollection::PyAny(collection) => {
                 let vec: Vec<&PyAny> = collection.iter()?.collect::<PyResult<_>>()?;
-                let mut iter = vec.into_iter();
-                iter!(iter)
+                iter!(vec.into_iter())
             }
             GenericCollection::JsonArray(collection) => {
-                let mut iter = collection.iter();
-                iter!(iter)
+                iter!(collection.iter())
             }
             _ => unreachable!(),
         }
@@ -223,6 +340,36 @@ impl Validator for TuplePositionalValidator {
         }
     }
 
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+        self.items_validators.iter_mut().for_each(|v| v.set_strict(strict));
+        if let Some(ref mut v) = self.extra_validator {
+            v.set_strict(strict);
+        }
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        let mut description = Description::new(py, Self::EXPECTED_TYPE).strict(self.strict).accepts(
+            &[
+                "tuple",
+                "list",
+                "dict_keys",
+                "dict_values",
+                "dict_items",
+                "iterator",
+                "deque",
+                "range",
+            ],
+            &["tuple"],
+        );
+        for (index, validator) in self.items_validators.iter().enumerate() {
+            description = description.child(index, validator)?;
+        }
+        description
+            .opt_child("extra_schema", self.extra_validator.as_deref())?
+            .finish()
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -0,0 +1,46 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::build_tools::SchemaDict;
+
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator};
+
+#[derive(Debug, Clone)]
+pub struct TypeVarBuilder;
+
+impl BuildValidator for TypeVarBuilder {
+    const EXPECTED_TYPE: &'static str = "type-var";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let name: &str = schema.get_as_req(intern!(py, "name"))?;
+        build_context.build_type_var(py, name, |sub_schema, build_context| {
+            build_validator(sub_schema, config, build_context)
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ParametrizedBuilder;
+
+impl BuildValidator for ParametrizedBuilder {
+    const EXPECTED_TYPE: &'static str = "parametrized";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let type_vars: &PyDict = schema.get_as_req(intern!(py, "type_vars"))?;
+        let inner_schema: &PyAny = schema.get_as_req(intern!(py, "schema"))?;
+        build_context.with_type_vars(type_vars, |build_context| {
+            build_validator(inner_schema, config, build_context)
+        })
+    }
+}
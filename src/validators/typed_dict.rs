@@ -1,22 +1,25 @@
+use std::sync::{Arc, Mutex};
+
 use pyo3::intern;
 use pyo3::prelude::*;
 
-use ahash::AHashSet;
+use ahash::{AHashMap, AHashSet};
 use pyo3::exceptions::PyKeyError;
-use pyo3::types::PyTuple;
-use pyo3::types::{PyDict, PySet, PyString, PyType};
+use pyo3::types::{PyDict, PyList, PySet, PyString, PyType};
 
-use crate::build_tools::{is_strict, py_err, schema_or_config, schema_or_config_same, ExtraBehavior, SchemaDict};
+use crate::build_tools::{
+    force_exact_container_path, is_strict, py_err, schema_or_config, schema_or_config_same, ExtraBehavior, SchemaDict,
+};
 use crate::errors::{py_err_string, ErrorType, ValError, ValLineError, ValResult};
-use crate::input::{
-    AttributesGenericIterator, DictGenericIterator, GenericMapping, Input, JsonObjectGenericIterator,
-    MappingGenericIterator,
+use crate::input::{DictGenericIterator, GenericMapping, Input, JsonObjectGenericIterator, MappingGenericIterator};
+use crate::lookup_key::{
+    instance_dict, slot_descriptors, AttributesMode, AttributesReader, AttributesSource, FromAttributesMode, LookupKey,
+    LookupPath,
 };
-use crate::lookup_key::LookupKey;
 use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
-use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
 
 #[derive(Debug, Clone)]
 struct TypedDictField {
@@ -26,17 +29,126 @@ struct TypedDictField {
     required: bool,
     validator: CombinedValidator,
     frozen: bool,
+    title: Option<String>,
+    // indices of the fields this field depends on, validated before it
+    depends_on: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum FieldGroupMode {
+    RequiredTogether,
+    MutuallyExclusive,
+    AtLeastOne,
+}
+
+/// a constraint on which of a group of fields are provided in the input, fields filled from their default don't count
+#[derive(Debug, Clone)]
+struct FieldGroup {
+    // indices of the group's fields
+    fields: Vec<usize>,
+    mode: FieldGroupMode,
+}
+
+impl FieldGroup {
+    fn build(group: &PyDict, fields: &[TypedDictField]) -> PyResult<Self> {
+        let py = group.py();
+        let names: Vec<String> = group.get_as_req(intern!(py, "fields"))?;
+        if names.len() < 2 {
+            return py_err!("Field group should contain at least two fields");
+        }
+        let fields = names
+            .iter()
+            .map(|name| match fields.iter().position(|f| &f.name == name) {
+                Some(index) => Ok(index),
+                None => py_err!("Field group references unknown field '{}'", name),
+            })
+            .collect::<PyResult<Vec<usize>>>()?;
+        let mode = match group.get_as_req::<&str>(intern!(py, "mode"))? {
+            "required_together" => FieldGroupMode::RequiredTogether,
+            "mutually_exclusive" => FieldGroupMode::MutuallyExclusive,
+            "at_least_one" => FieldGroupMode::AtLeastOne,
+            // schema validation means other values are impossible
+            _ => unreachable!(),
+        };
+        Ok(Self { fields, mode })
+    }
+
+    /// the error if the group isn't satisfied, `provided` is indexed by field
+    fn error_type(&self, provided: &[bool], fields: &[TypedDictField]) -> Option<ErrorType> {
+        let count = self.fields.iter().filter(|&&index| provided[index]).count();
+        let violated = match self.mode {
+            FieldGroupMode::RequiredTogether => count != 0 && count != self.fields.len(),
+            FieldGroupMode::MutuallyExclusive => count > 1,
+            FieldGroupMode::AtLeastOne => count == 0,
+        };
+        if !violated {
+            return None;
+        }
+        let names = self
+            .fields
+            .iter()
+            .map(|&index| format!("'{}'", fields[index].name))
+            .collect::<Vec<_>>()
+            .join(", ");
+        Some(match self.mode {
+            FieldGroupMode::RequiredTogether => ErrorType::GroupRequiredTogether { fields: names },
+            FieldGroupMode::MutuallyExclusive => ErrorType::GroupMutuallyExclusive { fields: names },
+            FieldGroupMode::AtLeastOne => ErrorType::GroupAtLeastOne { fields: names },
+        })
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct TypedDictValidator {
     fields: Vec<TypedDictField>,
+    field_groups: Vec<FieldGroup>,
+    // indices of fields in the order they're validated, fields are validated after the fields they depend on
+    validation_order: Vec<usize>,
+    // whether `validation_order` differs from the order of `fields`, so the output needs reordering
+    reorder_output: bool,
     extra_behavior: ExtraBehavior,
     extra_validator: Option<Box<CombinedValidator>>,
     strict: bool,
+    force_exact_container_path: bool,
     from_attributes: bool,
+    attributes_mode: AttributesMode,
+    from_attributes_mode: FromAttributesMode,
+    slots_cache: SlotsCache,
     return_fields_set: bool,
+    // whether extra values are returned in their own dict after the fields (and fields set), rather than with fields
+    return_extra: bool,
     loc_by_alias: bool,
+    // with `case_insensitive_fields`, lowercased keys mapped to the index of the field they're looked up for
+    case_insensitive_keys: Option<AHashMap<String, usize>>,
+    // keys fields are looked up by, input keys equal to one of these are never matched case-insensitively
+    exact_keys: AHashSet<String>,
+}
+
+/// Member descriptors of the last class validated with `attributes_mode='slots'`, so they're only resolved again
+/// when the class changes
+#[derive(Debug, Default)]
+struct SlotsCache(Mutex<Option<(Py<PyType>, SlotDescriptors)>>);
+
+type SlotDescriptors = Arc<AHashMap<String, PyObject>>;
+
+impl Clone for SlotsCache {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl SlotsCache {
+    fn get(&self, cls: &PyType) -> PyResult<SlotDescriptors> {
+        let mut cached = self.0.lock().unwrap();
+        if let Some((cached_cls, descriptors)) = cached.as_ref() {
+            if cached_cls.as_ref(cls.py()).is(cls) {
+                return Ok(descriptors.clone());
+            }
+        }
+        let descriptors = Arc::new(slot_descriptors(cls)?);
+        *cached = Some((cls.into(), descriptors.clone()));
+        Ok(descriptors)
+    }
 }
 
 impl BuildValidator for TypedDictValidator {
@@ -54,19 +166,33 @@ impl BuildValidator for TypedDictValidator {
             schema_or_config(schema, config, intern!(py, "total"), intern!(py, "typed_dict_total"))?.unwrap_or(true);
         let from_attributes = schema_or_config_same(schema, config, intern!(py, "from_attributes"))?.unwrap_or(false);
         let populate_by_name = schema_or_config_same(schema, config, intern!(py, "populate_by_name"))?.unwrap_or(false);
+        // with `require_alias`, field names are never matched for fields with an alias, even with `populate_by_name`,
+        // so they're treated as extra keys
+        let require_alias = schema_or_config_same(schema, config, intern!(py, "require_alias"))?.unwrap_or(false);
+        let case_insensitive_fields =
+            schema_or_config_same(schema, config, intern!(py, "case_insensitive_fields"))?.unwrap_or(false);
 
         let return_fields_set = schema.get_as(intern!(py, "return_fields_set"))?.unwrap_or(false);
 
         let extra_behavior = ExtraBehavior::from_schema_or_config(py, schema, config, ExtraBehavior::Ignore)?;
 
-        let extra_validator = match (schema.get_item(intern!(py, "extra_validator")), &extra_behavior) {
-            (Some(v), ExtraBehavior::Allow) => Some(Box::new(build_validator(v, config, build_context)?)),
-            (Some(_), _) => return py_err!("extra_validator can only be used if extra_behavior=allow"),
+        let return_extra = schema.get_as(intern!(py, "return_extra"))?.unwrap_or(false);
+
+        // `extra_validator` is the older name of `extras_schema`
+        let extras_schema = ["extras_schema", "extra_validator"]
+            .into_iter()
+            .find_map(|key| schema.get_item(key).map(|v| (key, v)));
+        let extra_validator = match (extras_schema, &extra_behavior) {
+            (Some((_, v)), ExtraBehavior::Allow) => Some(Box::new(build_validator(v, config, build_context)?)),
+            (Some((key, _)), _) => return py_err!("{} can only be used if extra_behavior=allow", key),
             (_, _) => None,
         };
 
+        let error_field_title = config.get_as(intern!(py, "error_field_title"))?.unwrap_or(true);
+
         let fields_dict: &PyDict = schema.get_as_req(intern!(py, "fields"))?;
         let mut fields: Vec<TypedDictField> = Vec::with_capacity(fields_dict.len());
+        let mut dependency_names: Vec<Vec<String>> = Vec::with_capacity(fields_dict.len());
 
         for (key, value) in fields_dict.iter() {
             let field_info: &PyDict = value.downcast()?;
@@ -79,18 +205,17 @@ impl BuildValidator for TypedDictValidator {
                 Err(err) => return py_err!("Field \"{}\":\n  {}", field_name, err),
             };
 
+            // only a `default` schema wrapping the whole field makes it optional, not one within e.g. a union
+            let has_default =
+                matches!(validator, CombinedValidator::WithDefault(_)) && validator.ask(&Question::HasDefault);
             let required = match field_info.get_as::<bool>(intern!(py, "required"))? {
                 Some(required) => {
-                    if required {
-                        if let CombinedValidator::WithDefault(ref val) = validator {
-                            if val.has_default() {
-                                return py_err!("Field '{}': a required field cannot have a default value", field_name);
-                            }
-                        }
+                    if required && has_default {
+                        return py_err!("Field '{}': a required field cannot have a default value", field_name);
                     }
                     required
                 }
-                None => total,
+                None => total && !has_default,
             };
 
             if required {
@@ -106,7 +231,14 @@ impl BuildValidator for TypedDictValidator {
 
             let lookup_key = match field_info.get_item(intern!(py, "validation_alias")) {
                 Some(alias) => {
-                    let alt_alias = if populate_by_name { Some(field_name) } else { None };
+                    let populate_by_name = field_info
+                        .get_as(intern!(py, "populate_by_name"))?
+                        .unwrap_or(populate_by_name);
+                    let alt_alias = if populate_by_name && !require_alias {
+                        Some(field_name)
+                    } else {
+                        None
+                    };
                     LookupKey::from_py(py, alias, alt_alias)?
                 }
                 None => LookupKey::from_string(py, field_name),
@@ -119,22 +251,277 @@ impl BuildValidator for TypedDictValidator {
                 validator,
                 required,
                 frozen: field_info.get_as::<bool>(intern!(py, "frozen"))?.unwrap_or(false),
+                title: match error_field_title {
+                    true => field_info.get_as(intern!(py, "title"))?,
+                    false => None,
+                },
+                depends_on: Vec::new(),
             });
+            dependency_names.push(field_info.get_as(intern!(py, "depends_on"))?.unwrap_or_default());
         }
 
+        for (index, names) in dependency_names.into_iter().enumerate() {
+            for name in names {
+                match fields.iter().position(|f| f.name == name) {
+                    Some(dependency) => fields[index].depends_on.push(dependency),
+                    None => return py_err!("Field '{}': depends on unknown field '{}'", fields[index].name, name),
+                }
+            }
+        }
+        let validation_order = validation_order(&fields)?;
+        let field_groups = match schema.get_as::<&PyList>(intern!(py, "field_groups"))? {
+            Some(groups) => groups
+                .iter()
+                .map(|group| FieldGroup::build(group.downcast()?, &fields))
+                .collect::<PyResult<Vec<_>>>()?,
+            None => Vec::new(),
+        };
+        let reorder_output = validation_order.iter().enumerate().any(|(i, index)| i != *index);
+
+        let mut exact_keys = AHashSet::new();
+        let case_insensitive_keys = match case_insensitive_fields {
+            true => {
+                let mut keys = AHashMap::new();
+                for (index, field) in fields.iter().enumerate() {
+                    for key in field.lookup_key.top_level_keys() {
+                        exact_keys.insert(key.to_string());
+                        // if two fields have the same lowercased key, the first field wins
+                        keys.entry(key.to_lowercase()).or_insert(index);
+                    }
+                }
+                Some(keys)
+            }
+            false => None,
+        };
+
         Ok(Self {
             fields,
+            field_groups,
+            validation_order,
+            reorder_output,
             extra_behavior,
             extra_validator,
             strict,
+            force_exact_container_path: force_exact_container_path(config)?,
             from_attributes,
+            attributes_mode: AttributesMode::from_schema(schema)?,
+            from_attributes_mode: FromAttributesMode::from_schema(schema)?,
+            slots_cache: SlotsCache::default(),
             return_fields_set,
+            return_extra,
             loc_by_alias: config.get_as(intern!(py, "loc_by_alias"))?.unwrap_or(true),
+            case_insensitive_keys,
+            exact_keys,
         }
         .into())
     }
 }
 
+impl TypedDictValidator {
+    /// the first field `field` depends on which isn't in the output, because it was missing or failed validation
+    fn failed_dependency(&self, field: &TypedDictField, output_dict: &PyDict) -> PyResult<Option<&TypedDictField>> {
+        for &index in &field.depends_on {
+            let dependency = &self.fields[index];
+            if !output_dict.contains(&dependency.name_py)? {
+                return Ok(Some(dependency));
+            }
+        }
+        Ok(None)
+    }
+
+    /// move validated fields back into schema order after validating them in dependency order,
+    /// reinserting each field in turn moves it to the end of the dict
+    fn restore_field_order(&self, output_dict: &PyDict) -> PyResult<()> {
+        let py = output_dict.py();
+        for field in &self.fields {
+            let name = field.name_py.as_ref(py);
+            if let Some(value) = output_dict.get_item(name) {
+                output_dict.del_item(name)?;
+                output_dict.set_item(name, value)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// find the first key in the input which matches the field at `index` case-insensitively, keys which exactly
+    /// match any field are skipped, this should only be called if the field's key isn't found exactly
+    fn case_match<'data, K, V, I>(
+        &self,
+        py: Python<'data>,
+        index: usize,
+        items: impl FnOnce() -> ValResult<'data, I>,
+    ) -> ValResult<'data, Option<(LookupPath, V)>>
+    where
+        K: Input<'data> + ?Sized + 'data,
+        I: Iterator<Item = ValResult<'data, (&'data K, V)>>,
+    {
+        let case_insensitive_keys = match self.case_insensitive_keys {
+            Some(ref case_insensitive_keys) => case_insensitive_keys,
+            None => return Ok(None),
+        };
+        for item_result in items()? {
+            let (raw_key, value) = item_result?;
+            let either_str = match raw_key.strict_str() {
+                Ok(either_str) => either_str,
+                Err(_) => continue,
+            };
+            let key = either_str.as_cow()?;
+            if !self.exact_keys.contains(key.as_ref()) && case_insensitive_keys.get(&key.to_lowercase()) == Some(&index)
+            {
+                return Ok(Some((LookupPath::from_str(py, &key, None), value)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// validate keys in the input which aren't used by any field, this isn't part of `validate` to keep its stack
+    /// frame small, since it's called recursively for nested typed dicts
+    #[allow(clippy::too_many_arguments)]
+    fn validate_extra_keys<'s, 'data, K, V>(
+        &'s self,
+        py: Python<'data>,
+        items: impl Iterator<Item = ValResult<'data, (&'data K, &'data V)>>,
+        used_keys: &AHashSet<&str>,
+        extra_dict: &PyDict,
+        fields_set_vec: &mut Option<Vec<Py<PyString>>>,
+        errors: &mut Vec<ValLineError<'data>>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, ()>
+    where
+        K: Input<'data> + ?Sized + 'data,
+        V: Input<'data> + 'data,
+    {
+        let mut items = items.peekable();
+        while let Some(item_result) = items.next() {
+            let (raw_key, value) = item_result?;
+            let either_str = match raw_key.strict_str() {
+                Ok(k) => k,
+                Err(ValError::LineErrors(line_errors)) => {
+                    for err in line_errors {
+                        errors.push(
+                            err.with_outer_location(raw_key.as_loc_item())
+                                .with_type(ErrorType::InvalidKey),
+                        );
+                    }
+                    continue;
+                }
+                Err(err) => return Err(err),
+            };
+            let key = either_str.as_cow()?;
+            if used_keys.contains(key.as_ref()) || self.is_case_match(&key) {
+                continue;
+            }
+
+            // Unknown / extra field
+            match self.extra_behavior {
+                ExtraBehavior::Forbid => {
+                    // the key may still match a field's name when the field is looked up by alias
+                    let title = self
+                        .fields
+                        .iter()
+                        .find(|f| f.name == key)
+                        .and_then(|f| f.title.as_deref());
+                    errors.push(
+                        ValLineError::new_with_loc(ErrorType::ExtraForbidden, value, raw_key.as_loc_item())
+                            .with_field_title(title),
+                    );
+                }
+                ExtraBehavior::Ignore => {}
+                ExtraBehavior::Allow => {
+                    let py_key = extra.py_string(py, raw_key, &either_str);
+                    if let Some(ref validator) = self.extra_validator {
+                        let _loc = extra.coercion_loc(|| [raw_key.as_loc_item()]);
+                        extra.report_raw_input(py, value);
+                        let partial_extra;
+                        let value_extra = match extra.is_partial() {
+                            true => {
+                                partial_extra = extra.partial_item(items.peek().is_none());
+                                &partial_extra
+                            }
+                            false => extra,
+                        };
+                        match validator.validate(py, value, value_extra, slots, recursion_guard) {
+                            Ok(value) => {
+                                extra_dict.set_item(py_key, value)?;
+                                if let Some(ref mut fs) = fields_set_vec {
+                                    fs.push(py_key.into_py(py));
+                                }
+                            }
+                            Err(ValError::LineErrors(line_errors)) => {
+                                for err in line_errors {
+                                    errors.push(err.with_outer_location(raw_key.as_loc_item()));
+                                }
+                            }
+                            Err(err) => return Err(err),
+                        }
+                    } else {
+                        extra_dict.set_item(py_key, value.to_object(py))?;
+                        if let Some(ref mut fs) = fields_set_vec {
+                            fs.push(py_key.into_py(py));
+                        }
+                    };
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// the validated fields, followed by the fields set with `return_fields_set` and the extra values with
+    /// `return_extra`, extra values are `None` unless `extra_behavior` is `allow`
+    fn output(&self, py: Python, fields: &PyDict, fields_set: Option<&PySet>, extra: Option<&PyDict>) -> PyObject {
+        match (fields_set, self.return_extra) {
+            (Some(fields_set), true) => (fields, fields_set, extra).to_object(py),
+            (Some(fields_set), false) => (fields, fields_set).to_object(py),
+            (None, true) => (fields, extra).to_object(py),
+            (None, false) => fields.to_object(py),
+        }
+    }
+
+    /// whether an extra key matches a field case-insensitively, all keys matching a field are ignored when
+    /// looking for extra keys, even if another key was used for the field
+    fn is_case_match(&self, key: &str) -> bool {
+        match self.case_insensitive_keys {
+            Some(ref case_insensitive_keys) => case_insensitive_keys.contains_key(&key.to_lowercase()),
+            None => false,
+        }
+    }
+}
+
+/// order fields so each is validated after the fields it depends on, otherwise keeping the schema's order,
+/// at each step the first field whose dependencies have all been validated is next
+fn validation_order(fields: &[TypedDictField]) -> PyResult<Vec<usize>> {
+    let mut order = Vec::with_capacity(fields.len());
+    let mut done = vec![false; fields.len()];
+    while order.len() < fields.len() {
+        let next = (0..fields.len()).find(|&i| !done[i] && fields[i].depends_on.iter().all(|&d| done[d]));
+        match next {
+            Some(index) => {
+                done[index] = true;
+                order.push(index);
+            }
+            None => {
+                // every remaining field depends on another remaining field, so following dependencies finds a cycle
+                let mut path = vec![(0..fields.len()).find(|&i| !done[i]).unwrap()];
+                loop {
+                    let last = *path.last().unwrap();
+                    let dependency = fields[last].depends_on.iter().copied().find(|&d| !done[d]).unwrap();
+                    if let Some(start) = path.iter().position(|&i| i == dependency) {
+                        path.drain(..start);
+                        path.push(dependency);
+                        break;
+                    }
+                    path.push(dependency);
+                }
+                let cycle: Vec<&str> = path.iter().map(|&i| fields[i].name.as_str()).collect();
+                return py_err!("Field dependencies form a cycle: {}", cycle.join(" -> "));
+            }
+        }
+    }
+    Ok(order)
+}
+
 impl Validator for TypedDictValidator {
     fn validate<'s, 'data>(
         &'s self,
@@ -145,15 +532,28 @@ impl Validator for TypedDictValidator {
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
         let strict = extra.strict.unwrap_or(self.strict);
-        let dict = input.validate_typed_dict(strict, self.from_attributes)?;
+        let dict = input
+            .validate_typed_dict(strict, self.from_attributes)?
+            .with_subclass_path(self.force_exact_container_path);
 
         let output_dict = PyDict::new(py);
+        // with `return_extra`, allowed extra values are kept apart from fields
+        let extra_dict = match (self.return_extra, &self.extra_behavior) {
+            (true, ExtraBehavior::Allow) => Some(PyDict::new(py)),
+            _ => None,
+        };
         let mut errors: Vec<ValLineError> = Vec::with_capacity(self.fields.len());
         let mut fields_set_vec: Option<Vec<Py<PyString>>> = match self.return_fields_set {
             true => Some(Vec::with_capacity(self.fields.len())),
             false => None,
         };
 
+        // which fields were found in the input, only needed to check `field_groups`
+        let mut provided: Vec<bool> = match self.field_groups.is_empty() {
+            true => Vec::new(),
+            false => vec![false; self.fields.len()],
+        };
+
         // we only care about which keys have been used if we're iterating over the object for extra after
         // the first pass
         let mut used_keys: Option<AHashSet<&str>> = match (&self.extra_behavior, &dict) {
@@ -162,33 +562,80 @@ impl Validator for TypedDictValidator {
             _ => None,
         };
 
+        // with `allow_partial`, missing fields aren't errors and only the value of the last key may be incomplete
+        let partial_depth = extra.partial_depth;
+        let partial_last_key = match extra.is_partial() {
+            true => dict.last_key(),
+            false => None,
+        };
+
         macro_rules! process {
-            ($dict:ident, $get_method:ident, $iter:ty $(,$kwargs:ident)?) => {{
-                for field in &self.fields {
-                    let extra = Extra {
+            ($dict:ident, $get_method:ident, $iter:expr $(, $args:expr)*) => {{
+                for &index in &self.validation_order {
+                    let field = &self.fields[index];
+                    let mut extra = Extra {
                         data: Some(output_dict),
                         field_name: Some(&field.name),
+                        partial_depth: 0,
                         ..*extra
                     };
-                    let op_key_value = match field.lookup_key.$get_method($dict $(, $kwargs )? ) {
+                    let op_key_value = match field.lookup_key.$get_method($dict $(, $args)*) {
                         Ok(v) => v,
                         Err(err) => {
-                            errors.push(ValLineError::new_with_loc(
-                                ErrorType::GetAttributeError {
-                                    error: py_err_string(py, err),
-                                },
-                                input,
-                                field.name.clone(),
-                            ));
+                            errors.push(
+                                ValLineError::new_with_loc(
+                                    ErrorType::GetAttributeError {
+                                        error: py_err_string(py, err),
+                                    },
+                                    input,
+                                    field.name.clone(),
+                                )
+                                .with_field_title(field.title.as_deref()),
+                            );
                             continue;
                         }
                     };
+                    let case_match;
+                    let op_key_value = match op_key_value {
+                        Some((lookup_path, value)) => {
+                            if let Some(ref mut used_keys) = used_keys {
+                                // key is "used" whether or not validation passes, since we want to skip this key in
+                                // extra logic either way
+                                used_keys.insert(lookup_path.first_key());
+                            }
+                            Some((lookup_path, value))
+                        }
+                        None => {
+                            case_match = self.case_match(py, index, || $iter($dict))?;
+                            case_match.as_ref().map(|(lookup_path, value)| (lookup_path, *value))
+                        }
+                    };
                     if let Some((lookup_path, value)) = op_key_value {
-                        if let Some(ref mut used_keys) = used_keys {
-                            // key is "used" whether or not validation passes, since we want to skip this key in
-                            // extra logic either way
-                            used_keys.insert(lookup_path.first_key());
+                        if let Some(provided) = provided.get_mut(index) {
+                            *provided = true;
+                        }
+                        if let Some(dependency) = self.failed_dependency(field, output_dict)? {
+                            errors.push(
+                                lookup_path
+                                    .apply_error_loc(
+                                        ValLineError::new(
+                                            ErrorType::FieldDependencyFailed {
+                                                dependency: dependency.name.clone(),
+                                            },
+                                            value,
+                                        ),
+                                        self.loc_by_alias,
+                                        &field.name,
+                                    )
+                                    .with_field_title(field.title.as_deref()),
+                            );
+                            continue;
+                        }
+                        if partial_last_key.is_some_and(|key| key == lookup_path.first_key()) {
+                            extra.partial_depth = partial_depth - 1;
                         }
+                        let _loc = extra.coercion_loc(|| lookup_path.loc_items(self.loc_by_alias, &field.name));
+                        extra.report_raw_input(py, value);
                         match field
                             .validator
                             .validate(py, value, &extra, slots, recursion_guard)
@@ -200,100 +647,97 @@ impl Validator for TypedDictValidator {
                                 }
                             }
                             Err(ValError::Omit) => continue,
-                            Err(ValError::LineErrors(line_errors)) => {
-                                for err in line_errors {
-                                    errors.push(lookup_path.apply_error_loc(err, self.loc_by_alias, &field.name));
-                                }
-                            }
-                            Err(err) => return Err(err),
-                        }
-                        continue;
-                    } else if let Some(value) = field.validator.default_value(py, Some(field.name.as_str()), &extra, slots, recursion_guard)? {
-                        output_dict.set_item(&field.name_py, value)?;
-                    } else if field.required {
-                        errors.push(field.lookup_key.error(
-                            ErrorType::Missing,
-                            input,
-                            self.loc_by_alias,
-                            &field.name
-                        ));
-                    }
-                }
-
-                if let Some(ref mut used_keys) = used_keys {
-                    for item_result in <$iter>::new($dict)? {
-                        let (raw_key, value) = item_result?;
-                        let either_str = match raw_key.strict_str() {
-                            Ok(k) => k,
                             Err(ValError::LineErrors(line_errors)) => {
                                 for err in line_errors {
                                     errors.push(
-                                        err.with_outer_location(raw_key.as_loc_item())
-                                            .with_type(ErrorType::InvalidKey),
+                                        lookup_path
+                                            .apply_error_loc(err, self.loc_by_alias, &field.name)
+                                            .with_field_title(field.title.as_deref()),
                                     );
                                 }
-                                continue;
                             }
                             Err(err) => return Err(err),
-                        };
-                        if used_keys.contains(either_str.as_cow()?.as_ref()) {
-                            continue;
                         }
-
-                        // Unknown / extra field
-                        match self.extra_behavior {
-                            ExtraBehavior::Forbid => {
-                                errors.push(ValLineError::new_with_loc(
-                                    ErrorType::ExtraForbidden,
-                                    value,
-                                    raw_key.as_loc_item(),
-                                ));
-                            }
-                            ExtraBehavior::Ignore => {}
-                            ExtraBehavior::Allow => {
-                            let py_key = either_str.as_py_string(py);
-                                if let Some(ref validator) = self.extra_validator {
-                                    match validator.validate(py, value, &extra, slots, recursion_guard) {
-                                        Ok(value) => {
-                                            output_dict.set_item(py_key, value)?;
-                                            if let Some(ref mut fs) = fields_set_vec {
-                                                fs.push(py_key.into_py(py));
-                                            }
-                                        }
-                                        Err(ValError::LineErrors(line_errors)) => {
-                                            for err in line_errors {
-                                                errors.push(err.with_outer_location(raw_key.as_loc_item()));
-                                            }
-                                        }
-                                        Err(err) => return Err(err),
-                                    }
-                                } else {
-                                    output_dict.set_item(py_key, value.to_object(py))?;
-                                    if let Some(ref mut fs) = fields_set_vec {
-                                        fs.push(py_key.into_py(py));
-                                    }
-                                };
+                        continue;
+                    }
+                    let _loc = extra.coercion_loc(|| [field.name.as_str().into()]);
+                    match field.validator.default_value(py, Some(field.name.as_str()), &extra, slots, recursion_guard) {
+                        Ok(Some(value)) => output_dict.set_item(&field.name_py, value)?,
+                        Ok(None) => {
+                            if field.required && partial_depth == 0 {
+                                errors.push(
+                                    field
+                                        .lookup_key
+                                        .error(ErrorType::Missing, input, self.loc_by_alias, &field.name)
+                                        .with_field_title(field.title.as_deref()),
+                                );
                             }
                         }
+                        // errors from the default are reported alongside errors from other fields
+                        Err(ValError::LineErrors(line_errors)) => errors.extend(
+                            line_errors
+                                .into_iter()
+                                .map(|err| err.with_field_title(field.title.as_deref())),
+                        ),
+                        Err(err) => return Err(err),
                     }
                 }
+
+                if self.reorder_output {
+                    self.restore_field_order(output_dict)?;
+                }
+
+                if let Some(ref used_keys) = used_keys {
+                    self.validate_extra_keys(
+                        py,
+                        $iter($dict)?,
+                        used_keys,
+                        extra_dict.unwrap_or(output_dict),
+                        &mut fields_set_vec,
+                        &mut errors,
+                        extra,
+                        slots,
+                        recursion_guard,
+                    )?;
+                }
             }};
         }
         match dict {
-            GenericMapping::PyDict(d) => process!(d, py_get_dict_item, DictGenericIterator),
-            GenericMapping::PyGetAttr(d, kwargs) => process!(d, py_get_attr, AttributesGenericIterator, kwargs),
-            GenericMapping::PyMapping(d) => process!(d, py_get_mapping_item, MappingGenericIterator),
-            GenericMapping::JsonObject(d) => process!(d, json_get, JsonObjectGenericIterator),
+            GenericMapping::PyDict(d) => process!(d, py_get_dict_item, DictGenericIterator::new),
+            GenericMapping::PyGetAttr(d, kwargs) => {
+                let descriptors;
+                let source = match self.attributes_mode {
+                    AttributesMode::Getattr => AttributesSource::Getattr,
+                    AttributesMode::Dict => match instance_dict(d) {
+                        Some(instance_dict) => AttributesSource::Dict(instance_dict),
+                        None => AttributesSource::Getattr,
+                    },
+                    AttributesMode::Slots => {
+                        descriptors = self.slots_cache.get(d.get_type())?;
+                        AttributesSource::Slots(&descriptors)
+                    }
+                };
+                let reader = AttributesReader::new(d, source, &self.from_attributes_mode);
+                process!(d, py_read_attr, |d| reader.attributes(d), kwargs, &reader)
+            }
+            GenericMapping::PyMapping(d) => process!(d, py_get_mapping_item, MappingGenericIterator::new),
+            GenericMapping::JsonObject(d) => process!(d, json_get, JsonObjectGenericIterator::new),
+        }
+
+        for group in &self.field_groups {
+            if let Some(error_type) = group.error_type(&provided, &self.fields) {
+                errors.push(ValLineError::new(error_type, input));
+            }
         }
 
         if !errors.is_empty() {
-            Err(ValError::LineErrors(errors))
-        } else if let Some(fs) = fields_set_vec {
-            let fields_set = PySet::new(py, &fs)?;
-            Ok((output_dict, fields_set).to_object(py))
-        } else {
-            Ok(output_dict.to_object(py))
+            return Err(ValError::LineErrors(errors));
         }
+        let fields_set = match fields_set_vec {
+            Some(fs) => Some(PySet::new(py, &fs)?),
+            None => None,
+        };
+        Ok(self.output(py, output_dict, fields_set, extra_dict))
     }
 
     fn validate_assignment<'s, 'data: 's>(
@@ -308,13 +752,20 @@ impl Validator for TypedDictValidator {
     ) -> ValResult<'data, PyObject> {
         let dict: &PyDict = obj.downcast()?;
 
+        let field = self.fields.iter().find(|f| f.name == field_name);
+        // reuse the interned name for known fields, so the key is shared with other instances
+        let py_field_name: &PyString = match field {
+            Some(field) => field.name_py.as_ref(py),
+            None => PyString::new(py, field_name),
+        };
+
         let ok = |output: PyObject| {
-            dict.set_item(field_name, output)?;
+            dict.set_item(py_field_name, output)?;
             Ok(dict.to_object(py))
         };
 
-        let prepare_result = |result: ValResult<'data, PyObject>| match result {
-            Ok(output) => ok(output),
+        let with_loc = |result: ValResult<'data, PyObject>| match result {
+            Ok(output) => Ok(output),
             Err(ValError::LineErrors(line_errors)) => {
                 let errors = line_errors
                     .into_iter()
@@ -324,6 +775,8 @@ impl Validator for TypedDictValidator {
             }
             Err(err) => Err(err),
         };
+        let prepare_result =
+            |result: ValResult<'data, PyObject>| -> ValResult<'data, PyObject> { ok(with_loc(result)?) };
 
         // by using dict but removing the field in question, we match V1 behaviour
         let data_dict = dict.copy()?;
@@ -340,19 +793,20 @@ impl Validator for TypedDictValidator {
             ..*extra
         };
 
-        let new_data = if let Some(field) = self.fields.iter().find(|f| f.name == field_name) {
+        let mut extra_dict: Option<&PyDict> = None;
+        if let Some(field) = field {
             if field.frozen {
-                Err(ValError::new_with_loc(
-                    ErrorType::FrozenField,
-                    field_value,
-                    field.name.to_string(),
-                ))
+                Err(
+                    ValError::new_with_loc(ErrorType::FrozenField, field_value, field.name.to_string())
+                        .with_field_title(field.title.as_deref()),
+                )
             } else {
                 prepare_result(
                     field
                         .validator
                         .validate(py, field_value, &extra, slots, recursion_guard),
                 )
+                .map_err(|err| err.with_field_title(field.title.as_deref()))
             }
         } else {
             // Handle extra (unknown) field
@@ -361,12 +815,25 @@ impl Validator for TypedDictValidator {
             // For models / typed dicts we forbid assigning extra attributes
             // unless the user explicitly set extra_behavior to 'allow'
             match self.extra_behavior {
-                ExtraBehavior::Allow => match self.extra_validator {
-                    Some(ref validator) => {
-                        prepare_result(validator.validate(py, field_value, &extra, slots, recursion_guard))
+                ExtraBehavior::Allow => {
+                    let result = match self.extra_validator {
+                        Some(ref validator) => validator.validate(py, field_value, &extra, slots, recursion_guard),
+                        None => Ok(field_value.to_object(py)),
+                    };
+                    if self.return_extra {
+                        // the value is returned with the extra values, so it's removed from the fields
+                        let output = with_loc(result)?;
+                        if dict.contains(py_field_name)? {
+                            dict.del_item(py_field_name)?;
+                        }
+                        let new_extra = PyDict::new(py);
+                        new_extra.set_item(py_field_name, output)?;
+                        extra_dict = Some(new_extra);
+                        Ok(dict.to_object(py))
+                    } else {
+                        prepare_result(result)
                     }
-                    None => ok(field_value.to_object(py)),
-                },
+                }
                 ExtraBehavior::Forbid | ExtraBehavior::Ignore => {
                     return Err(ValError::new_with_loc(
                         ErrorType::NoSuchAttribute {
@@ -378,12 +845,40 @@ impl Validator for TypedDictValidator {
                 }
             }
         }?;
-        if self.return_fields_set {
-            let fields_set: &PySet = PySet::new(py, &[field_name.to_string()])?;
-            Ok(PyTuple::new(py, [new_data, fields_set.to_object(py)]).to_object(py))
-        } else {
-            Ok(new_data)
+        let fields_set = match self.return_fields_set {
+            true => Some(PySet::new(py, &[py_field_name])?),
+            false => None,
+        };
+        Ok(self.output(py, dict, fields_set, extra_dict))
+    }
+
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+        self.fields.iter_mut().for_each(|v| v.validator.set_strict(strict));
+        if let Some(ref mut v) = self.extra_validator {
+            v.set_strict(strict);
+        }
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        let (lax, strict): (&'static [&'static str], &'static [&'static str]) = match self.from_attributes {
+            true => (&["dict", "mapping", "object"], &["dict", "object"]),
+            false => (&["dict", "mapping"], &["dict"]),
+        };
+        let required: Vec<&str> = self
+            .fields
+            .iter()
+            .filter(|field| field.required)
+            .map(|field| field.name.as_str())
+            .collect();
+        let mut description = Description::new(py, Self::EXPECTED_TYPE)
+            .strict(self.strict)
+            .accepts(lax, strict)
+            .constraint("required", (!required.is_empty()).then_some(required))?;
+        for field in &self.fields {
+            description = description.child(&field.name, &field.validator)?;
         }
+        description.finish()
     }
 
     fn get_name(&self) -> &str {
@@ -393,6 +888,9 @@ impl Validator for TypedDictValidator {
     fn ask(&self, question: &Question) -> bool {
         match question {
             Question::ReturnFieldsSet => self.return_fields_set,
+            Question::ReturnExtra => self.return_extra,
+            Question::HasDefault => false,
+            Question::ReturnsImmutable => false,
         }
     }
 
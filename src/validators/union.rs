@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::cell::Cell;
 use std::fmt;
 use std::fmt::Write;
 
@@ -9,7 +10,10 @@ use pyo3::types::{PyDict, PyList, PyString};
 
 use ahash::AHashMap;
 
-use crate::build_tools::{is_strict, py_err, schema_or_config, SchemaDict};
+use crate::build_tools::{
+    force_exact_container_path, function_name, is_strict, py_err, safe_repr, schema_or_config, schema_or_config_same,
+    SchemaDict,
+};
 use crate::errors::{ErrorType, LocItem, ValError, ValLineError, ValResult};
 use crate::input::{GenericMapping, Input};
 use crate::lookup_key::LookupKey;
@@ -17,11 +21,23 @@ use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
 use super::custom_error::CustomError;
-use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::function::convert_err;
+use super::literal::ExpectedRepr;
+use super::{
+    build_validator, timer, BuildContext, BuildValidator, CombinedValidator, Description, Exactness, Extra, Validator,
+};
 
 #[derive(Debug, Clone)]
 pub struct UnionValidator {
+    // in declaration order, which is the order errors are reported in
     choices: Vec<CombinedValidator>,
+    // whether each choice is always validated in strict mode
+    strict_choices: Vec<bool>,
+    // indexes of `choices` in the order they're tried, highest `priority` first
+    order: Vec<usize>,
+    mode: UnionMode,
+    // in smart mode, return the first choice which matches in strict mode without looking for an exact match
+    short_circuit_on_strict: bool,
     custom_error: Option<CustomError>,
     strict: bool,
     name: String,
@@ -36,21 +52,66 @@ impl BuildValidator for UnionValidator {
         build_context: &mut BuildContext<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
         let py = schema.py();
-        let choices: Vec<CombinedValidator> = schema
-            .get_as_req::<&PyList>(intern!(py, "choices"))?
-            .iter()
-            .map(|choice| build_validator(choice, config, build_context))
-            .collect::<PyResult<Vec<CombinedValidator>>>()?;
+        let choices_schema: &PyList = schema.get_as_req(intern!(py, "choices"))?;
+        let mut choices: Vec<CombinedValidator> = Vec::with_capacity(choices_schema.len());
+        let mut strict_choices: Vec<bool> = Vec::with_capacity(choices_schema.len());
+        let mut priorities: Vec<i64> = Vec::with_capacity(choices_schema.len());
+        for choice in choices_schema {
+            let (choice_schema, priority, strict) = union_choice(choice)?;
+            choices.push(build_validator(choice_schema, config, build_context)?);
+            strict_choices.push(strict);
+            priorities.push(priority);
+        }
+        // sort is stable, so choices with the same priority are tried in the order they're declared
+        let mut order: Vec<usize> = (0..choices.len()).collect();
+        order.sort_by_key(|index| -priorities[*index]);
+
+        let mode = match schema.get_as::<&str>(intern!(py, "mode"))? {
+            None | Some("smart") => UnionMode::Smart,
+            Some("left_to_right") => UnionMode::LeftToRight,
+            Some("isinstance") => {
+                let mut isinstance_choices = Vec::new();
+                for (index, choice) in choices_schema.iter().enumerate() {
+                    if let Some(class) = choice_class(union_choice_schema(choice)?)? {
+                        isinstance_choices.push((class.into_py(py), index));
+                    }
+                }
+                UnionMode::Isinstance(isinstance_choices)
+            }
+            Some(s) => {
+                return py_err!(
+                    "Invalid union mode: `{}`, expected `smart`, `left_to_right` or `isinstance`",
+                    s
+                )
+            }
+        };
+        if matches!(mode, UnionMode::LeftToRight) {
+            // in left to right mode a choice with `on_error='omit'` which fails is just a failed choice, not an
+            // omission of the whole union, so it's validated without the `default` wrapper to report its errors
+            choices = choices
+                .into_iter()
+                .map(|choice| match choice {
+                    CombinedValidator::WithDefault(with_default) if with_default.omit_on_error() => {
+                        with_default.into_validator()
+                    }
+                    choice => choice,
+                })
+                .collect();
+        }
 
         let auto_collapse = || schema.get_as_req(intern!(py, "auto_collapse")).unwrap_or(true);
         match choices.len() {
             0 => py_err!("One or more union choices required"),
-            1 if auto_collapse() => Ok(choices.into_iter().next().unwrap()),
+            1 if auto_collapse() && !strict_choices[0] => Ok(choices.into_iter().next().unwrap()),
             _ => {
                 let descr = choices.iter().map(|v| v.get_name()).collect::<Vec<_>>().join(",");
 
                 Ok(Self {
                     choices,
+                    strict_choices,
+                    order,
+                    mode,
+                    short_circuit_on_strict: schema.get_as(intern!(py, "short_circuit_on_strict"))?.unwrap_or(false),
                     custom_error: CustomError::build(schema, config, build_context)?,
                     strict: is_strict(schema, config)?,
                     name: format!("{}[{descr}]", Self::EXPECTED_TYPE),
@@ -61,22 +122,72 @@ impl BuildValidator for UnionValidator {
     }
 }
 
+#[derive(Debug, Clone)]
+enum UnionMode {
+    Smart,
+    // the first choice which succeeds is used, whether or not a later choice would succeed in strict mode
+    LeftToRight,
+    // the `cls` of each choice which has one and the index of that choice
+    Isinstance(Vec<(PyObject, usize)>),
+}
+
+/// A union choice is either a schema, or a dict of the choice's `schema` with the options `priority` and `strict`
+fn union_choice(choice: &PyAny) -> PyResult<(&PyDict, i64, bool)> {
+    let py = choice.py();
+    let choice: &PyDict = choice.downcast()?;
+    if choice.contains(intern!(py, "type"))? {
+        Ok((choice, 0, false))
+    } else {
+        Ok((
+            choice.get_as_req(intern!(py, "schema"))?,
+            choice.get_as(intern!(py, "priority"))?.unwrap_or(0),
+            choice.get_as(intern!(py, "strict"))?.unwrap_or(false),
+        ))
+    }
+}
+
+/// The schema of a union choice, without its options
+pub fn union_choice_schema(choice: &PyAny) -> PyResult<&PyDict> {
+    union_choice(choice).map(|(schema, _, _)| schema)
+}
+
+/// The class instances of which a choice validates, only model, dataclass and is-instance choices have one,
+/// other choices are only tried by the smart mode fallback
+fn choice_class(choice: &PyDict) -> PyResult<Option<&PyAny>> {
+    let py = choice.py();
+    match choice.get_as_req::<&str>(intern!(py, "type"))? {
+        "model" | "dataclass" | "is-instance" => choice.get_as(intern!(py, "cls")),
+        _ => Ok(None),
+    }
+}
+
 impl UnionValidator {
-    fn or_custom_error<'s, 'data>(
+    /// errors from each choice which failed with its index, reported in the order choices were declared
+    fn union_error<'s, 'data>(
         &'s self,
-        errors: Option<Vec<ValLineError<'data>>>,
+        choice_errors: Vec<(usize, Vec<ValLineError<'data>>)>,
         input: &'data impl Input<'data>,
     ) -> ValError<'data> {
-        if let Some(errors) = errors {
-            ValError::LineErrors(errors)
-        } else {
-            self.custom_error.as_ref().unwrap().as_val_error(input)
+        if let Some(ref custom_error) = self.custom_error {
+            return custom_error.as_val_error(input);
         }
+        ValError::LineErrors(self.choice_line_errors(choice_errors))
     }
-}
 
-impl Validator for UnionValidator {
-    fn validate<'s, 'data>(
+    /// `max_steps` was used up before all choices were tried, the errors so far are kept (ignoring `custom_error`)
+    /// since they may include where the budget ran out
+    fn budget_exceeded_error<'s, 'data>(
+        &'s self,
+        choice_errors: Vec<(usize, Vec<ValLineError<'data>>)>,
+        input: &'data impl Input<'data>,
+    ) -> ValError<'data> {
+        let mut line_errors = self.choice_line_errors(choice_errors);
+        line_errors.push(ValLineError::new(ErrorType::ValidationBudgetExceeded, input));
+        ValError::LineErrors(line_errors)
+    }
+
+    /// validates `input` with the choices according to the union's mode
+    fn validate_choices<'s, 'data>(
         &'s self,
         py: Python<'data>,
         input: &'data impl Input<'data>,
@@ -84,65 +195,195 @@ impl Validator for UnionValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        if extra.strict.unwrap_or(self.strict) {
-            let mut errors: Option<Vec<ValLineError>> = match self.custom_error {
-                None => Some(Vec::with_capacity(self.choices.len())),
-                _ => None,
-            };
-            let strict_extra = extra.as_strict();
-
-            for validator in &self.choices {
-                let line_errors = match validator.validate(py, input, &strict_extra, slots, recursion_guard) {
-                    Err(ValError::LineErrors(line_errors)) => line_errors,
-                    otherwise => return otherwise,
-                };
-
-                if let Some(ref mut errors) = errors {
-                    errors.extend(
-                        line_errors
-                            .into_iter()
-                            .map(|err| err.with_outer_location(validator.get_name().into())),
-                    );
+        if let UnionMode::LeftToRight = self.mode {
+            return self.validate_left_to_right(py, input, extra, slots, recursion_guard);
+        }
+        let strict = extra.strict.unwrap_or(self.strict);
+        if let UnionMode::Isinstance(ref isinstance_choices) = self.mode {
+            for &(ref class, index) in isinstance_choices {
+                if input.input_is_instance(class.as_ref(py), 0)? {
+                    // only the matching choice is validated, it's the one used whatever the outcome
+                    if !recursion_guard.step() {
+                        return Err(self.budget_exceeded_error(Vec::new(), input));
+                    }
+                    let choice_strict = strict || self.strict_choices[index];
+                    return match self.validate_choice(py, input, extra, choice_strict, index, slots, recursion_guard) {
+                        (Ok(output), exactness) => {
+                            extra.report_exactness(exactness);
+                            Ok(output)
+                        }
+                        (Err(ValError::LineErrors(line_errors)), _) => {
+                            Err(self.union_error(vec![(index, line_errors)], input))
+                        }
+                        (Err(err), _) => Err(err),
+                    };
+                }
+            }
+        }
+        // each choice is validated once, the first exact match is returned straight away, otherwise the closest
+        // match is used, the first choice tried wins a tie
+        let mut choice_errors = Vec::with_capacity(self.choices.len());
+        let mut best = None;
+        let checkpoint = extra.coercion_checkpoint();
+        for &index in &self.order {
+            if !recursion_guard.step() {
+                if best.is_some() {
+                    break;
+                }
+                return Err(self.budget_exceeded_error(choice_errors, input));
+            }
+            // once there's a match, a later choice can only be better if it matches in strict mode
+            let choice_strict = strict || self.strict_choices[index] || best.is_some();
+            match self.validate_choice(py, input, extra, choice_strict, index, slots, recursion_guard) {
+                // coercions recorded for an earlier match were taken out of the report when it was kept
+                (Ok(output), Exactness::Exact) => return Ok(output),
+                (Ok(output), Exactness::Strict) if self.short_circuit_on_strict => {
+                    extra.report_exactness(Exactness::Strict);
+                    return Ok(output);
+                }
+                (Ok(output), exactness) => match best {
+                    Some((_, best_exactness, _)) if best_exactness >= exactness => checkpoint.rollback(),
+                    // coercions are put back if this choice is used
+                    _ => best = Some((output, exactness, checkpoint.take())),
+                },
+                (Err(ValError::LineErrors(line_errors)), _) => {
+                    // errors are only reported if no choice matches
+                    if best.is_none() {
+                        choice_errors.push((index, line_errors));
+                    }
+                    // coercions made by choices which failed don't apply to the output
+                    checkpoint.rollback();
                 }
+                (Err(err), _) => return Err(err),
             }
+        }
 
-            Err(self.or_custom_error(errors, input))
-        } else {
-            // 1st pass: check if the value is an exact instance of one of the Union types,
-            // e.g. use validate in strict mode
-            let strict_extra = extra.as_strict();
-            if let Some(res) = self
-                .choices
-                .iter()
-                .map(|validator| validator.validate(py, input, &strict_extra, slots, recursion_guard))
-                .find(ValResult::is_ok)
-            {
-                return res;
+        match best {
+            Some((output, exactness, coercions)) => {
+                checkpoint.restore(coercions);
+                extra.report_exactness(exactness);
+                Ok(output)
             }
+            None => Err(self.union_error(choice_errors, input)),
+        }
+    }
 
-            let mut errors: Option<Vec<ValLineError>> = match self.custom_error {
-                None => Some(Vec::with_capacity(self.choices.len())),
-                _ => None,
+    /// tries each choice once in order, returning the first success, choices are validated in strict mode if the
+    /// union or the choice is strict
+    fn validate_left_to_right<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let strict = extra.strict.unwrap_or(self.strict);
+        let mut choice_errors = Vec::with_capacity(self.choices.len());
+        let mut omitted = false;
+        let checkpoint = extra.coercion_checkpoint();
+        for &index in &self.order {
+            if !recursion_guard.step() {
+                return Err(self.budget_exceeded_error(choice_errors, input));
+            }
+            let choice_strict = strict || self.strict_choices[index];
+            match self.validate_choice(py, input, extra, choice_strict, index, slots, recursion_guard) {
+                (Ok(output), exactness) => {
+                    extra.report_exactness(exactness);
+                    return Ok(output);
+                }
+                (Err(ValError::LineErrors(line_errors)), _) => choice_errors.push((index, line_errors)),
+                // omitting from deeper within a choice, e.g. a function raising `PydanticOmit`, fails the choice
+                (Err(ValError::Omit), _) => omitted = true,
+                (Err(err), _) => return Err(err),
             };
+            checkpoint.rollback();
+        }
 
-            // 2nd pass: check if the value can be coerced into one of the Union types, e.g. use validate
-            for validator in &self.choices {
-                let line_errors = match validator.validate(py, input, extra, slots, recursion_guard) {
-                    Err(ValError::LineErrors(line_errors)) => line_errors,
-                    success => return success,
-                };
+        if omitted && choice_errors.is_empty() {
+            // every choice failed without errors to report, `on_error='omit'` choices always have errors
+            Err(ValError::Omit)
+        } else {
+            Err(self.union_error(choice_errors, input))
+        }
+    }
 
-                if let Some(ref mut errors) = errors {
-                    errors.extend(
-                        line_errors
-                            .into_iter()
-                            .map(|err| err.with_outer_location(validator.get_name().into())),
-                    );
-                }
-            }
+    /// validates with one choice, returning how closely the input matched it, which is tracked separately from
+    /// the rest of validation so choices which aren't used don't affect it
+    #[allow(clippy::too_many_arguments)]
+    fn validate_choice<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        strict: bool,
+        index: usize,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> (ValResult<'data, PyObject>, Exactness) {
+        let exactness = Cell::new(Exactness::Exact);
+        let choice_extra = Extra {
+            strict: if strict { Some(true) } else { extra.strict },
+            exactness: Some(&exactness),
+            ..*extra
+        };
+        let validator = &self.choices[index];
+        let _loc = extra.coercion_loc(|| [validator.get_name().into()]);
+        extra.report_raw_input(py, input);
+        let result = validator.validate(py, input, &choice_extra, slots, recursion_guard);
+        if let Ok(ref output) = result {
+            choice_extra.report_output_type(input, output.as_ref(py));
+        }
+        (result, exactness.get())
+    }
 
-            Err(self.or_custom_error(errors, input))
+    fn choice_line_errors<'data>(
+        &self,
+        mut choice_errors: Vec<(usize, Vec<ValLineError<'data>>)>,
+    ) -> Vec<ValLineError<'data>> {
+        choice_errors.sort_by_key(|(index, _)| *index);
+        choice_errors
+            .into_iter()
+            .flat_map(|(index, line_errors)| {
+                let name = self.choices[index].get_name();
+                line_errors
+                    .into_iter()
+                    .map(move |err| err.with_outer_location(name.into()))
+            })
+            .collect()
+    }
+}
+
+impl Validator for UnionValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        timer!(self.get_name());
+        // errors of choices which failed are dropped if another choice succeeds, see `ErrorLimit`
+        let mark = extra.error_mark();
+        let result = self.validate_choices(py, input, extra, slots, recursion_guard);
+        if result.is_ok() {
+            extra.discard_errors(mark);
         }
+        result
+    }
+
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+        self.choices.iter_mut().for_each(|v| v.set_strict(strict));
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        let mut description = Description::new(py, Self::EXPECTED_TYPE).strict(self.strict);
+        for (index, choice) in self.choices.iter().enumerate() {
+            description = description.child(index, choice)?;
+        }
+        description.finish()
     }
 
     fn get_name(&self) -> &str {
@@ -184,14 +425,14 @@ impl Discriminator {
 
     fn to_string_py(&self, py: Python) -> PyResult<String> {
         match self {
-            Self::Function(f) => Ok(format!("{}()", f.getattr(py, "__name__")?)),
+            Self::Function(f) => Ok(format!("{}()", function_name(f.as_ref(py))?)),
             Self::LookupKey(lookup_key) => Ok(lookup_key.to_string()),
             Self::SelfSchema => Ok("self-schema".to_string()),
         }
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 enum ChoiceKey {
     Int(i64),
     Str(String),
@@ -216,6 +457,15 @@ impl ChoiceKey {
     }
 }
 
+impl ToPyObject for ChoiceKey {
+    fn to_object(&self, py: Python<'_>) -> PyObject {
+        match self {
+            Self::Int(i) => i.to_object(py),
+            Self::Str(s) => s.to_object(py),
+        }
+    }
+}
+
 impl fmt::Display for ChoiceKey {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -241,9 +491,12 @@ pub struct TaggedUnionValidator {
     discriminator: Discriminator,
     from_attributes: bool,
     strict: bool,
+    force_exact_container_path: bool,
     custom_error: Option<CustomError>,
-    tags_repr: String,
+    tags_repr: ExpectedRepr,
     discriminator_repr: String,
+    // with `tagged_union_probe_all`, every tag in declaration order, to try when the selected choice fails
+    probe_tags: Option<Vec<ChoiceKey>>,
     name: String,
 }
 
@@ -263,11 +516,15 @@ impl BuildValidator for TaggedUnionValidator {
         let mut choices = AHashMap::with_capacity(schema_choices.len());
         let mut repeat_choices_vec: Vec<(ChoiceKey, ChoiceKey)> = Vec::new();
         let mut first = true;
-        let mut tags_repr = String::with_capacity(50);
+        // tags are shown in errors in the order they're declared, including repeated tags
+        let mut tag_reprs: Vec<String> = Vec::with_capacity(schema_choices.len());
+        let mut all_tags: Vec<ChoiceKey> = Vec::with_capacity(schema_choices.len());
         let mut descr = String::with_capacity(50);
 
         for (key, value) in schema_choices {
             let tag = ChoiceKey::from_py(key)?;
+            tag_reprs.push(tag.repr());
+            all_tags.push(tag.clone());
 
             if let Ok(repeat_tag) = ChoiceKey::from_py(value) {
                 repeat_choices_vec.push((tag, repeat_tag));
@@ -275,13 +532,10 @@ impl BuildValidator for TaggedUnionValidator {
             }
 
             let validator = build_validator(value, config, build_context)?;
-            let tag_repr = tag.repr();
             if first {
                 first = false;
-                write!(tags_repr, "{tag_repr}").unwrap();
                 descr.push_str(validator.get_name());
             } else {
-                write!(tags_repr, ", {tag_repr}").unwrap();
                 // no spaces in get_name() output to make loc easy to read
                 write!(descr, ",{}", validator.get_name()).unwrap();
             }
@@ -295,8 +549,6 @@ impl BuildValidator for TaggedUnionValidator {
             for (tag, repeat_tag) in repeat_choices_vec {
                 match choices.get(&repeat_tag) {
                     Some(validator) => {
-                        let tag_repr = tag.repr();
-                        write!(tags_repr, ", {tag_repr}").unwrap();
                         write!(descr, ",{}", validator.get_name()).unwrap();
                         repeat_choices.insert(tag, repeat_tag);
                     }
@@ -320,15 +572,22 @@ impl BuildValidator for TaggedUnionValidator {
             _ => descr,
         };
 
+        let probe_all = schema_or_config_same(schema, config, intern!(py, "tagged_union_probe_all"))?.unwrap_or(false);
+
         Ok(Self {
             choices,
             repeat_choices,
             discriminator,
             from_attributes,
             strict: is_strict(schema, config)?,
+            force_exact_container_path: force_exact_container_path(config)?,
             custom_error: CustomError::build(schema, config, build_context)?,
-            tags_repr,
+            tags_repr: ExpectedRepr::new(py, &tag_reprs, ", ", config)?,
             discriminator_repr,
+            probe_tags: match probe_all {
+                true => Some(all_tags),
+                false => None,
+            },
             name: format!("{}[{descr}]", Self::EXPECTED_TYPE),
         }
         .into())
@@ -344,6 +603,7 @@ impl Validator for TaggedUnionValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
+        timer!(self.get_name());
         match self.discriminator {
             Discriminator::LookupKey(ref lookup_key) => {
                 macro_rules! find_validator {
@@ -354,15 +614,21 @@ impl Validator for TaggedUnionValidator {
                             Some((_, value)) => {
                                 if let Ok(int) = value.validate_int(self.strict) {
                                     Ok(ChoiceKey::Int(int))
+                                } else if let Ok(either_str) = value.validate_str(self.strict) {
+                                    Ok(ChoiceKey::Str(either_str.as_cow()?.as_ref().to_string()))
                                 } else {
-                                    Ok(ChoiceKey::Str(value.validate_str(self.strict)?.as_cow()?.as_ref().to_string()))
+                                    // values other than ints and strings can't match any choice
+                                    let tag = safe_repr(value.to_object(py).into_ref(py)).to_string();
+                                    Err(self.tag_invalid(tag, input))
                                 }
                             }
                             None => Err(self.tag_not_found(input)),
                         }
                     }};
                 }
-                let dict = input.validate_typed_dict(self.strict, self.from_attributes)?;
+                let dict = input
+                    .validate_typed_dict(self.strict, self.from_attributes)?
+                    .with_subclass_path(self.force_exact_container_path);
                 let tag = match dict {
                     GenericMapping::PyDict(dict) => find_validator!(py_get_dict_item, dict),
                     GenericMapping::PyGetAttr(obj, kwargs) => find_validator!(py_get_attr, obj, kwargs),
@@ -372,12 +638,26 @@ impl Validator for TaggedUnionValidator {
                 self.find_call_validator(py, &tag, input, extra, slots, recursion_guard)
             }
             Discriminator::Function(ref func) => {
-                let tag = func.call1(py, (input.to_object(py),))?;
-                if tag.is_none(py) {
+                // JSON input is passed as the python objects it's loaded as
+                let tag = match func.call1(py, (input.to_object(py),)) {
+                    Ok(tag) => tag.into_ref(py),
+                    // errors raised by the function fail validation as they do in function validators,
+                    // other exceptions mean no tag could be found
+                    Err(err) => {
+                        return Err(match convert_err(py, err, input) {
+                            ValError::InternalErr(_) => self.tag_not_found(input),
+                            err => err,
+                        })
+                    }
+                };
+                if tag.is_none() {
                     Err(self.tag_not_found(input))
                 } else {
-                    let tag: &PyAny = tag.downcast(py)?;
-                    self.find_call_validator(py, &(ChoiceKey::from_py(tag)?), input, extra, slots, recursion_guard)
+                    match ChoiceKey::from_py(tag) {
+                        Ok(tag) => self.find_call_validator(py, &tag, input, extra, slots, recursion_guard),
+                        // tags other than ints and strings can't match any choice
+                        Err(_) => Err(self.tag_invalid(safe_repr(tag).to_string(), input)),
+                    }
                 }
             }
             Discriminator::SelfSchema => self.find_call_validator(
@@ -391,6 +671,31 @@ impl Validator for TaggedUnionValidator {
         }
     }
 
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+        self.choices.values_mut().for_each(|v| v.set_strict(strict));
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        let mut tags: Vec<&ChoiceKey> = self.choices.keys().collect();
+        if let Some(ref repeat_choices) = self.repeat_choices {
+            tags.extend(repeat_choices.keys());
+        }
+        // sorted so the output doesn't depend on hash order, ints before strings
+        tags.sort();
+        let mut description = Description::new(py, Self::EXPECTED_TYPE).strict(self.strict);
+        for tag in tags {
+            let choice_tag = match self.repeat_choices {
+                Some(ref repeat_choices) => repeat_choices.get(tag).unwrap_or(tag),
+                None => tag,
+            };
+            if let Some(validator) = self.choices.get(choice_tag) {
+                description = description.child(tag, validator)?;
+            }
+        }
+        description.finish()
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -461,30 +766,100 @@ impl TaggedUnionValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
+        if !recursion_guard.step() {
+            return Err(ValError::new(ErrorType::ValidationBudgetExceeded, input));
+        }
+        let _loc = extra.coercion_loc(|| [tag.into()]);
+        extra.report_raw_input(py, input);
         if let Some(validator) = self.choices.get(tag) {
             return match validator.validate(py, input, extra, slots, recursion_guard) {
                 Ok(res) => Ok(res),
-                Err(err) => Err(err.with_outer_location(tag.into())),
+                Err(err) => Err(self.probe_other_tags(py, tag, err, input, extra, slots, recursion_guard)),
             };
         } else if let Some(ref repeat_choices) = self.repeat_choices {
             if let Some(choice_tag) = repeat_choices.get(tag) {
                 let validator = &self.choices[choice_tag];
                 return match validator.validate(py, input, extra, slots, recursion_guard) {
                     Ok(res) => Ok(res),
-                    Err(err) => Err(err.with_outer_location(tag.into())),
+                    Err(err) => Err(self.probe_other_tags(py, tag, err, input, extra, slots, recursion_guard)),
                 };
             }
         }
+        Err(self.tag_invalid(tag.to_string(), input))
+    }
+
+    /// the tag in `choices` that `tag` uses, which differs from `tag` for repeated choices
+    fn choice_tag<'a>(&'a self, tag: &'a ChoiceKey) -> &'a ChoiceKey {
+        match self.repeat_choices {
+            Some(ref repeat_choices) => repeat_choices.get(tag).unwrap_or(tag),
+            None => tag,
+        }
+    }
+
+    /// add the location of `tag` to the errors from the selected choice, with `tagged_union_probe_all` also
+    /// try every other choice in strict mode and add an error listing the tags whose schemas accept the input,
+    /// this is purely diagnostic, validation still fails with the selected choice's errors
+    #[allow(clippy::too_many_arguments)]
+    fn probe_other_tags<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        tag: &ChoiceKey,
+        err: ValError<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValError<'data> {
+        let err = err.with_outer_location(tag.into());
+        let (probe_tags, mut line_errors) = match (&self.probe_tags, err) {
+            (Some(probe_tags), ValError::LineErrors(line_errors)) => (probe_tags, line_errors),
+            (_, err) => return err,
+        };
+        let selected_tag = self.choice_tag(tag);
+        let strict_extra = extra.as_strict();
+        let mut matched_other_tags: Vec<String> = Vec::new();
+        for other_tag in probe_tags {
+            let choice_tag = self.choice_tag(other_tag);
+            if choice_tag == selected_tag {
+                continue;
+            }
+            if !recursion_guard.step() {
+                break;
+            }
+            let validator = &self.choices[choice_tag];
+            // the probe result is discarded, so neither are coercions made while validating it
+            let checkpoint = extra.coercion_checkpoint();
+            match validator.validate(py, input, &strict_extra, slots, recursion_guard) {
+                Ok(_) => matched_other_tags.push(other_tag.to_string()),
+                Err(ValError::LineErrors(_)) => (),
+                Err(err) => return err,
+            }
+            checkpoint.rollback();
+        }
+        if !matched_other_tags.is_empty() {
+            line_errors.push(ValLineError::new(
+                ErrorType::UnionTagOtherMatches {
+                    tag: tag.to_string(),
+                    matched_other_tags,
+                },
+                input,
+            ));
+        }
+        ValError::LineErrors(line_errors)
+    }
+
+    fn tag_invalid<'s, 'data>(&'s self, tag: String, input: &'data impl Input<'data>) -> ValError<'data> {
         match self.custom_error {
-            Some(ref custom_error) => Err(custom_error.as_val_error(input)),
-            None => Err(ValError::new(
+            Some(ref custom_error) => custom_error.as_val_error(input),
+            None => ValError::new(
                 ErrorType::UnionTagInvalid {
                     discriminator: self.discriminator_repr.clone(),
-                    tag: tag.to_string(),
-                    expected_tags: self.tags_repr.clone(),
+                    tag,
+                    expected_tags: self.tags_repr.repr().to_string(),
+                    expected_full: self.tags_repr.full().map(ToString::to_string),
                 },
                 input,
-            )),
+            ),
         }
     }
 
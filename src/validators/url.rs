@@ -16,7 +16,7 @@ use crate::recursion_guard::RecursionGuard;
 use crate::url::{schema_is_special, PyMultiHostUrl, PyUrl};
 
 use super::literal::expected_repr_name;
-use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
 
 type AllowedSchemas = Option<(AHashSet<String>, String)>;
 
@@ -86,6 +86,19 @@ impl Validator for UrlValidator {
         }
     }
 
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .strict(self.strict)
+            .accepts(&["url", "str"], &["url"])
+            .constraint("max_length", self.max_length)?
+            .constraint("host_required", self.host_required.then_some(true))?
+            .finish()
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -206,6 +219,19 @@ impl Validator for MultiHostUrlValidator {
         }
     }
 
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .strict(self.strict)
+            .accepts(&["url", "str"], &["url"])
+            .constraint("max_length", self.max_length)?
+            .constraint("host_required", self.host_required.then_some(true))?
+            .finish()
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
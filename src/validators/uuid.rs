@@ -0,0 +1,189 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict};
+use pyo3::{ffi, AsPyPointer};
+
+use crate::build_tools::{is_strict, SchemaDict};
+use crate::errors::{ErrorType, ValError, ValResult};
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::{BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
+
+const URN_PREFIX: &str = "urn:uuid:";
+
+#[derive(Debug, Clone)]
+pub struct UuidValidator {
+    strict: bool,
+    uuid_class: PyObject,
+    // `uuid.SafeUUID.unknown`, set on UUIDs we create as `UUID.__init__` would
+    safe_unknown: PyObject,
+    version: Option<u8>,
+}
+
+impl BuildValidator for UuidValidator {
+    const EXPECTED_TYPE: &'static str = "uuid";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        _build_context: &mut BuildContext<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let uuid_module = py.import(intern!(py, "uuid"))?;
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            uuid_class: uuid_module.getattr(intern!(py, "UUID"))?.into_py(py),
+            safe_unknown: uuid_module
+                .getattr(intern!(py, "SafeUUID"))?
+                .getattr(intern!(py, "unknown"))?
+                .into_py(py),
+            version: schema.get_as(intern!(py, "version"))?,
+        }
+        .into())
+    }
+}
+
+impl Validator for UuidValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        _slots: &'data [CombinedValidator],
+        _recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let strict = extra.strict.unwrap_or(self.strict);
+        let uuid_class = self.uuid_class.as_ref(py);
+        if input.input_is_instance(uuid_class, 0)? {
+            let py_uuid = input.to_object(py);
+            if let Some(version) = self.version {
+                let int: u128 = py_uuid.getattr(py, intern!(py, "int"))?.extract(py)?;
+                self.check_version(int, version, input)?;
+            }
+            return Ok(py_uuid);
+        }
+        // in strict mode only UUIDs are accepted from python, JSON has no UUID type so the hyphenated form is allowed
+        if strict && input.is_python() {
+            return Err(ValError::new(ErrorType::UuidType, input));
+        }
+
+        let int = if let Ok(either_str) = input.strict_str() {
+            parse_uuid(either_str.as_cow()?.as_ref(), strict)
+                .map_err(|error| ValError::new(ErrorType::UuidParsing { error }, input))?
+        } else if let Ok(py_bytes) = input.to_object(py).into_ref(py).downcast::<PyBytes>() {
+            let bytes: [u8; 16] = py_bytes.as_bytes().try_into().map_err(|_| {
+                let error = format!("invalid length: expected 16 bytes, found {}", py_bytes.as_bytes().len());
+                ValError::new(ErrorType::UuidParsing { error }, input)
+            })?;
+            u128::from_be_bytes(bytes)
+        } else {
+            return Err(ValError::new(ErrorType::UuidType, input));
+        };
+        if let Some(version) = self.version {
+            self.check_version(int, version, input)?;
+        }
+        Ok(self.create_py_uuid(py, int)?)
+    }
+
+    fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .strict(self.strict)
+            .accepts(&["uuid", "str", "bytes"], &["uuid"])
+            .constraint("version", self.version)?
+            .finish()
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+
+    fn complete(&mut self, _build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
+        Ok(())
+    }
+}
+
+impl UuidValidator {
+    fn check_version<'data>(&self, int: u128, version: u8, input: &'data impl Input<'data>) -> ValResult<'data, ()> {
+        if uuid_version(int) == Some(version) {
+            Ok(())
+        } else {
+            Err(ValError::new(
+                ErrorType::UuidVersion {
+                    expected_version: version as usize,
+                },
+                input,
+            ))
+        }
+    }
+
+    /// create a `uuid.UUID` without calling `UUID.__init__`, which would check and convert the int again,
+    /// `UUID.__setattr__` raises since UUIDs are immutable so the slots are set as `object.__setattr__` would
+    fn create_py_uuid(&self, py: Python, int: u128) -> PyResult<PyObject> {
+        let uuid_class = self.uuid_class.as_ref(py);
+        let py_uuid = uuid_class.call_method1(intern!(py, "__new__"), (uuid_class,))?;
+        let int = int.into_py(py);
+        for (name, value) in [(intern!(py, "int"), &int), (intern!(py, "is_safe"), &self.safe_unknown)] {
+            if unsafe { ffi::PyObject_GenericSetAttr(py_uuid.as_ptr(), name.as_ptr(), value.as_ptr()) } == -1 {
+                return Err(PyErr::fetch(py));
+            }
+        }
+        Ok(py_uuid.into_py(py))
+    }
+}
+
+/// the version of an RFC 4122 UUID, `None` for other variants as with `UUID.version`
+fn uuid_version(int: u128) -> Option<u8> {
+    if (int >> 62) & 0b11 == 0b10 {
+        Some(((int >> 76) & 0xf) as u8)
+    } else {
+        None
+    }
+}
+
+/// parse the hyphenated form of a UUID, e.g. `'a1b2c3d4-e5f6-4a7b-8c9d-0e1f2a3b4c5d'`, and unless
+/// `hyphenated_only`, the simple form of 32 hex digits and the URN form `'urn:uuid:<hyphenated>'`
+fn parse_uuid(s: &str, hyphenated_only: bool) -> Result<u128, String> {
+    let offset = match s.get(..URN_PREFIX.len()) {
+        Some(prefix) if !hyphenated_only && prefix.eq_ignore_ascii_case(URN_PREFIX) => URN_PREFIX.len(),
+        _ => 0,
+    };
+    let bytes = &s.as_bytes()[offset..];
+    let hyphenated = match bytes.len() {
+        36 => true,
+        32 if !hyphenated_only => false,
+        _ => {
+            let expected = if hyphenated_only { "36" } else { "32 or 36" };
+            let found = s[offset..].chars().count();
+            return Err(format!("invalid length: expected {expected} characters, found {found}"));
+        }
+    };
+
+    let mut int: u128 = 0;
+    for (index, &byte) in bytes.iter().enumerate() {
+        if hyphenated && matches!(index, 8 | 13 | 18 | 23) {
+            if byte != b'-' {
+                return Err(invalid_character(s, offset + index, "`-`"));
+            }
+            continue;
+        }
+        let digit = match byte {
+            b'0'..=b'9' => byte - b'0',
+            b'a'..=b'f' => byte - b'a' + 10,
+            b'A'..=b'F' => byte - b'A' + 10,
+            _ => return Err(invalid_character(s, offset + index, "a hexadecimal digit")),
+        };
+        int = int << 4 | digit as u128;
+    }
+    Ok(int)
+}
+
+fn invalid_character(s: &str, index: usize, expected: &str) -> String {
+    // scanning stops at the first invalid byte, which is always the start of a character
+    let c = s[index..].chars().next().unwrap_or_default();
+    format!("invalid character: expected {expected}, found {c:?} at position {index}")
+}
@@ -1,20 +1,22 @@
+use pyo3::exceptions::{PyException, PyTypeError};
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use crate::build_tools::{py_err, schema_or_config_same, SchemaDict};
-use crate::errors::{LocItem, ValError, ValResult};
+use crate::build_tools::{merge_validators_enabled, py_err, schema_or_config_same, SchemaDict, SchemaError};
+use crate::errors::{py_err_string, ErrorMode, ErrorType, LocItem, ValError, ValLineError, ValResult, ValidationError};
 use crate::input::Input;
 use crate::questions::Question;
 use crate::recursion_guard::RecursionGuard;
 
-use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Description, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub enum DefaultType {
     None,
     Default(PyObject),
-    DefaultFactory(PyObject),
+    // the factory, and whether it takes the data validated so far
+    DefaultFactory(PyObject, bool),
 }
 
 impl DefaultType {
@@ -26,18 +28,38 @@ impl DefaultType {
         ) {
             (Some(_), Some(_)) => py_err!("'default' and 'default_factory' cannot be used together"),
             (Some(default), None) => Ok(Self::Default(default)),
-            (None, Some(default_factory)) => Ok(Self::DefaultFactory(default_factory)),
+            (None, Some(default_factory)) => {
+                let takes_data = schema
+                    .get_as(intern!(py, "default_factory_takes_data"))?
+                    .unwrap_or(false);
+                Ok(Self::DefaultFactory(default_factory, takes_data))
+            }
             (None, None) => Ok(Self::None),
         }
     }
 
-    pub fn default_value(&self, py: Python) -> PyResult<Option<PyObject>> {
+    /// `data` is the data validated so far, passed to the factory as a read-only mapping if it takes data
+    pub fn default_value(&self, py: Python, data: Option<&PyDict>) -> PyResult<Option<PyObject>> {
         match self {
             Self::Default(ref default) => Ok(Some(default.clone_ref(py))),
-            Self::DefaultFactory(ref default_factory) => Ok(Some(default_factory.call0(py)?)),
+            Self::DefaultFactory(ref default_factory, false) => Ok(Some(default_factory.call0(py)?)),
+            Self::DefaultFactory(ref default_factory, true) => match data {
+                Some(data) => {
+                    let data = py.import("types")?.getattr(intern!(py, "MappingProxyType"))?.call1((data,))?;
+                    Ok(Some(default_factory.call1(py, (data,))?))
+                }
+                None => Err(PyTypeError::new_err(
+                    "A `default_factory` which takes data can only be used for fields of a typed dict, model or dataclass",
+                )),
+            },
             Self::None => Ok(None),
         }
     }
+
+    /// whether the default can only be computed from the data validated so far
+    pub fn takes_data(&self) -> bool {
+        matches!(self, Self::DefaultFactory(_, true))
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -45,6 +67,8 @@ enum OnError {
     Raise,
     Omit,
     Default,
+    // the function is called with the input and the validation error, it returns the value to use instead
+    Call(PyObject),
 }
 
 #[derive(Debug, Clone)]
@@ -53,6 +77,8 @@ pub struct WithDefaultValidator {
     on_error: OnError,
     validator: Box<CombinedValidator>,
     validate_default: bool,
+    // a static `default` validated when the validator is built, see `validate_static_default`
+    validated_default: Option<PyObject>,
     name: String,
 }
 
@@ -75,6 +101,10 @@ impl BuildValidator for WithDefaultValidator {
                 }
                 OnError::Default
             }
+            Some("call") => match schema.get_as(intern!(py, "on_error_call"))? {
+                Some(function) => OnError::Call(function),
+                None => return py_err!("'on_error = call' requires an `on_error_call` function"),
+            },
             None => OnError::Raise,
             // schema validation means other values are impossible
             _ => unreachable!(),
@@ -82,6 +112,10 @@ impl BuildValidator for WithDefaultValidator {
 
         let sub_schema: &PyAny = schema.get_as_req(intern!(schema.py(), "schema"))?;
         let validator = Box::new(build_validator(sub_schema, config, build_context)?);
+        // without a default and raising errors unchanged the wrapper does nothing
+        if matches!((&default, &on_error), (DefaultType::None, OnError::Raise)) && merge_validators_enabled(config)? {
+            return Ok(*validator);
+        }
         let name = format!("{}[{}]", Self::EXPECTED_TYPE, validator.get_name());
 
         Ok(Self {
@@ -89,6 +123,7 @@ impl BuildValidator for WithDefaultValidator {
             on_error,
             validator,
             validate_default: schema_or_config_same(schema, config, intern!(py, "validate_default"))?.unwrap_or(false),
+            validated_default: None,
             name,
         }
         .into())
@@ -104,14 +139,47 @@ impl Validator for WithDefaultValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
+        let checkpoint = extra.coercion_checkpoint();
         match self.validator.validate(py, input, extra, slots, recursion_guard) {
             Ok(v) => Ok(v),
             Err(e) => match self.on_error {
                 OnError::Raise => Err(e),
-                OnError::Default => Ok(self
-                    .default_value(py, None::<usize>, extra, slots, recursion_guard)?
-                    .unwrap()),
-                OnError::Omit => Err(ValError::Omit),
+                // the input is replaced or dropped, so coercions made while validating it don't apply
+                OnError::Default => {
+                    checkpoint.rollback();
+                    Ok(self
+                        .default_value(py, None::<usize>, extra, slots, recursion_guard)?
+                        .unwrap())
+                }
+                OnError::Omit => {
+                    checkpoint.rollback();
+                    Err(ValError::Omit)
+                }
+                // omitting from inside the sub-validator isn't an error the function can handle
+                OnError::Call(_) if matches!(e, ValError::Omit) => Err(e),
+                OnError::Call(ref function) => {
+                    checkpoint.rollback();
+                    let error_mode = if input.is_python() {
+                        ErrorMode::Python
+                    } else {
+                        ErrorMode::Json
+                    };
+                    let validation_error = ValidationError::from_val_error(
+                        py,
+                        self.name.to_object(py),
+                        error_mode,
+                        e,
+                        None,
+                        extra.hide_input,
+                        extra.custom_error_messages.cloned(),
+                        None,
+                    );
+                    // internal errors aren't validation errors, they propagate unchanged
+                    if !validation_error.is_instance_of::<ValidationError>(py) {
+                        return Err(validation_error.into());
+                    }
+                    Ok(function.call1(py, (input.to_object(py), validation_error.value(py)))?)
+                }
             },
         }
     }
@@ -124,8 +192,36 @@ impl Validator for WithDefaultValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, Option<PyObject>> {
-        match self.default.default_value(py)? {
+        if let Some(ref validated_default) = self.validated_default {
+            // validating with different strictness or reporting coercions needs the default validated again
+            if extra.strict.is_none() && extra.coercions.is_none() {
+                return Ok(Some(validated_default.clone_ref(py)));
+            }
+        }
+        let default = match self.default.default_value(py, extra.data) {
+            Ok(default) => default,
+            // a factory which takes data used outside a fields context is a mistake in the schema, not invalid input
+            Err(err) if self.default.takes_data() && extra.data.is_none() => return Err(err.into()),
+            // exceptions raised by `default_factory` become validation errors, but not `KeyboardInterrupt` etc.
+            Err(err) if err.is_instance_of::<PyException>(py) => {
+                let cause = err.value(py).into_py(py);
+                let line_error = ValLineError::new(
+                    ErrorType::DefaultFactoryError {
+                        error: py_err_string(py, err),
+                    },
+                    py.None().into_ref(py),
+                );
+                let error = ValError::LineErrors(vec![line_error.with_cause(cause)]);
+                return match outer_loc {
+                    Some(outer_loc) => Err(error.with_outer_location(outer_loc.into())),
+                    None => Err(error),
+                };
+            }
+            Err(err) => return Err(err.into()),
+        };
+        match default {
             Some(dft) => {
+                extra.report_default(matches!(self.default, DefaultType::DefaultFactory(..)));
                 if self.validate_default {
                     match self.validate(py, dft.into_ref(py), extra, slots, recursion_guard) {
                         Ok(v) => Ok(Some(v)),
@@ -145,20 +241,91 @@ impl Validator for WithDefaultValidator {
         }
     }
 
+    fn set_strict(&mut self, strict: bool) {
+        self.validator.set_strict(strict);
+        // the default was validated with the previous strictness
+        self.validated_default = None;
+    }
+
+    fn describe(&self, py: Python) -> PyResult<Py<PyDict>> {
+        Description::new(py, Self::EXPECTED_TYPE)
+            .has_default(self.has_default())
+            .child("schema", &self.validator)?
+            .finish()
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
 
     fn ask(&self, question: &Question) -> bool {
-        self.validator.ask(question)
+        match question {
+            Question::HasDefault => self.has_default(),
+            // with `on_error='default'` or `'call'` the value returned if validation fails might be mutable
+            Question::ReturnsImmutable => {
+                matches!(self.on_error, OnError::Raise | OnError::Omit) && self.validator.ask(question)
+            }
+            _ => self.validator.ask(question),
+        }
     }
 
     fn complete(&mut self, build_context: &BuildContext<CombinedValidator>) -> PyResult<()> {
-        self.validator.complete(build_context)
+        self.validator.complete(build_context)?;
+        self.validate_static_default()
     }
 }
 
 impl WithDefaultValidator {
+    /// wrap `validator` with the `default` or `default_factory` from `schema` (e.g. an arguments parameter),
+    /// if `schema` has neither, `validator` is returned unchanged
+    pub fn wrap(schema: &PyDict, config: Option<&PyDict>, validator: CombinedValidator) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let default = DefaultType::new(schema)?;
+        if matches!(default, DefaultType::None) {
+            return Ok(validator);
+        }
+        if matches!(validator, CombinedValidator::WithDefault(_)) {
+            return py_err!("'default' and 'default_factory' cannot be used with a 'default' schema");
+        }
+        let name = format!("{}[{}]", Self::EXPECTED_TYPE, validator.get_name());
+        Ok(Self {
+            default,
+            on_error: OnError::Raise,
+            validator: Box::new(validator),
+            validate_default: schema_or_config_same(schema, config, intern!(py, "validate_default"))?.unwrap_or(false),
+            validated_default: None,
+            name,
+        }
+        .into())
+    }
+
+    /// a static `default` can't change, so if the validator only returns immutable values the default is
+    /// validated once here instead of each time it's used, an invalid default is then a schema error
+    fn validate_static_default(&mut self) -> PyResult<()> {
+        if !self.validate_default
+            || !matches!(self.on_error, OnError::Raise)
+            || !self.validator.ask(&Question::ReturnsImmutable)
+        {
+            return Ok(());
+        }
+        if let DefaultType::Default(ref default) = self.default {
+            let validated_default = Python::with_gil(|py| {
+                // no slots are needed since definition references never answer `ReturnsImmutable`
+                self.validator
+                    .validate(
+                        py,
+                        default.as_ref(py),
+                        &Extra::default(),
+                        &[],
+                        &mut RecursionGuard::default(),
+                    )
+                    .map_err(|e| SchemaError::from_val_error(py, e))
+            })?;
+            self.validated_default = Some(validated_default);
+        }
+        Ok(())
+    }
+
     pub fn has_default(&self) -> bool {
         !matches!(self.default, DefaultType::None)
     }
@@ -166,4 +333,9 @@ impl WithDefaultValidator {
     pub fn omit_on_error(&self) -> bool {
         matches!(self.on_error, OnError::Omit)
     }
+
+    /// the wrapped validator, without the default
+    pub fn into_validator(self) -> CombinedValidator {
+        *self.validator
+    }
 }
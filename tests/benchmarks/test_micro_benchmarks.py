@@ -4,9 +4,13 @@ Numerous benchmarks of specific functionality.
 import json
 import os
 import platform
+import sys
+import tracemalloc
 from datetime import date, datetime, timedelta, timezone
 from decimal import Decimal
+from ipaddress import IPv4Address, IPv4Network
 from typing import Dict, FrozenSet, List, Optional, Set, Union
+from uuid import UUID, uuid4
 
 import pytest
 
@@ -188,6 +192,56 @@ class TestModelLarge:
         benchmark(core_model_validator.validate_json, json_data)
 
 
+class TestModel20Fields:
+    @pytest.fixture(scope='class')
+    def core_validator_20(self):
+        class CoreModel:
+            __slots__ = '__dict__', '__pydantic_fields_set__'
+
+        return SchemaValidator(
+            core_schema.model_schema(
+                CoreModel,
+                core_schema.typed_dict_schema(
+                    {f'field_{i}': core_schema.typed_dict_field(core_schema.int_schema()) for i in range(20)},
+                    return_fields_set=True,
+                ),
+            )
+        )
+
+    data = {f'field_{i}': i for i in range(20)}
+
+    @pytest.mark.benchmark(group='create model with 20 fields')
+    def test_core_python(self, core_validator_20, benchmark):
+        m = core_validator_20.validate_python(self.data)
+        assert m.field_19 == 19
+        benchmark(core_validator_20.validate_python, self.data)
+
+    @pytest.mark.benchmark(group='create model with 20 fields')
+    def test_core_json(self, core_validator_20, benchmark):
+        json_data = json.dumps(self.data)
+        m = core_validator_20.validate_json(json_data)
+        assert m.field_19 == 19
+        benchmark(core_validator_20.validate_json, json_data)
+
+    def test_core_json_memory(self, core_validator_20):
+        json_data = json.dumps(self.data)
+        core_validator_20.validate_json(json_data)
+
+        tracemalloc.start()
+        try:
+            models = [core_validator_20.validate_json(json_data) for _ in range(1000)]
+            per_instance, _ = tracemalloc.get_traced_memory()
+        finally:
+            tracemalloc.stop()
+        per_instance //= len(models)
+
+        # field names are shared between instances, so they don't add to the size of each instance
+        key_size = sum(sys.getsizeof(k) for k in self.data)
+        dict_size = sys.getsizeof(models[0].__dict__) + sys.getsizeof(models[0].__pydantic_fields_set__)
+        assert per_instance < dict_size + key_size
+        assert all(k1 is k2 for k1, k2 in zip(models[0].__dict__, models[-1].__dict__))
+
+
 bool_cases = [True, False, 0, 1, '0', '1', 'true', 'false', 'True', 'False']
 
 
@@ -265,6 +319,53 @@ def test_small_class_core_model(benchmark):
     benchmark(model_schema_validator.validate_python, small_class_data)
 
 
+class DictAttributes:
+    def __init__(self, **kwargs):
+        self.__dict__.update(kwargs)
+
+
+attributes_field_names = [f'field_{i}' for i in range(30)]
+
+
+class SlotsAttributes:
+    __slots__ = tuple(attributes_field_names)
+
+    def __init__(self, **kwargs):
+        for key, value in kwargs.items():
+            setattr(self, key, value)
+
+
+@pytest.fixture(scope='module', params=['getattr', 'dict', 'slots'])
+def attributes_mode(request):
+    return request.param
+
+
+@pytest.mark.benchmark(group='from attributes')
+def test_from_attributes_dict_class(attributes_mode, benchmark):
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {name: core_schema.typed_dict_field(core_schema.int_schema()) for name in attributes_field_names},
+            from_attributes=True,
+            attributes_mode=attributes_mode,
+        )
+    )
+    obj = DictAttributes(**{name: i for i, name in enumerate(attributes_field_names)})
+    benchmark(v.validate_python, obj)
+
+
+@pytest.mark.benchmark(group='from attributes')
+def test_from_attributes_slots_class(attributes_mode, benchmark):
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {name: core_schema.typed_dict_field(core_schema.int_schema()) for name in attributes_field_names},
+            from_attributes=True,
+            attributes_mode=attributes_mode,
+        )
+    )
+    obj = SlotsAttributes(**{name: i for i, name in enumerate(attributes_field_names)})
+    benchmark(v.validate_python, obj)
+
+
 @pytest.mark.benchmark(group='string')
 def test_core_string_lax(benchmark):
     validator = SchemaValidator(core_schema.str_schema())
@@ -490,6 +591,50 @@ def test_list_of_ints_core_json(benchmark):
         v.validate_json(json_data[1])
 
 
+primitive_list_makers = {
+    'int': lambda i: i,
+    'float': lambda i: i / 2,
+    'str': str,
+    'bool': lambda i: i % 2 == 0,
+}
+
+
+@pytest.fixture(scope='module', params=primitive_list_makers.keys())
+def primitive_list(request):
+    return request.param, [primitive_list_makers[request.param](i) for i in range(1_000_000)]
+
+
+@pytest.mark.benchmark(group='List[primitive] 1M')
+@pytest.mark.parametrize('strict', [False, True], ids=['lax', 'strict'])
+def test_list_of_primitives_1m_core_py(benchmark, primitive_list, strict):
+    item_type, data = primitive_list
+    v = SchemaValidator(core_schema.list_schema({'type': item_type}, strict=strict))
+    benchmark(v.validate_python, data)
+
+
+@pytest.mark.benchmark(group='List[primitive] 1M JSON')
+@pytest.mark.parametrize('strict', [False, True], ids=['lax', 'strict'])
+def test_list_of_primitives_1m_core_json(benchmark, primitive_list, strict):
+    item_type, data = primitive_list
+    v = SchemaValidator(core_schema.list_schema({'type': item_type}, strict=strict))
+    benchmark(v.validate_json, json.dumps(data))
+
+
+@pytest.mark.benchmark(group='Tuple[primitive, ...] 1M')
+def test_tuple_of_primitives_1m_core_py(benchmark, primitive_list):
+    item_type, data = primitive_list
+    v = SchemaValidator(core_schema.tuple_variable_schema({'type': item_type}))
+    benchmark(v.validate_python, tuple(data))
+
+
+@pytest.mark.benchmark(group='List[primitive] 1M')
+def test_list_of_ints_1m_one_invalid_core_py(benchmark):
+    # one item which isn't an exact int is validated by the item validator
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    data = list(range(999_999)) + ['999999']
+    benchmark(v.validate_python, data)
+
+
 @skip_pydantic
 @pytest.mark.benchmark(group='List[Any]')
 def test_list_of_any_pyd_py(benchmark):
@@ -882,6 +1027,35 @@ class TestBenchmarkUnion:
 
         benchmark(PydanticModel.parse_obj, 1)  # will be True
 
+    @pytest.mark.benchmark(group='smart-union-wide')
+    def test_smart_union_wide_strict_match(self, benchmark):
+        choices = [{'type': 'literal', 'expected': [f'choice_{i}']} for i in range(50)]
+        v = SchemaValidator({'type': 'union', 'choices': choices + [{'type': 'int'}]})
+
+        # the strict match returns without trying any choice in lax mode
+        benchmark(v.validate_python, 1)
+
+    @pytest.mark.benchmark(group='smart-union-wide')
+    def test_smart_union_wide_lax_match(self, benchmark):
+        choices = [{'type': 'literal', 'expected': [f'choice_{i}']} for i in range(50)]
+        v = SchemaValidator({'type': 'union', 'choices': choices + [{'type': 'int'}]})
+
+        benchmark(v.validate_python, '1')
+
+    @pytest.mark.benchmark(group='smart-union-wide')
+    def test_smart_union_wide_short_circuit_on_strict(self, benchmark):
+        choices = [{'type': 'literal', 'expected': [f'choice_{i}']} for i in range(50)]
+        v = SchemaValidator(
+            {
+                'type': 'union',
+                'choices': [{'type': 'float'}] + choices + [{'type': 'int'}],
+                'short_circuit_on_strict': True,
+            }
+        )
+
+        # the strict match of `float` returns without trying the other choices for an exact match
+        benchmark(v.validate_python, 1)
+
     @pytest.mark.benchmark(group='strict-union')
     def test_strict_union_core(self, benchmark):
         v = SchemaValidator(
@@ -1298,3 +1472,267 @@ def test_model_instance_abc(benchmark):
     assert validator.isinstance_python(m1)
 
     benchmark(validator.validate_python, m1)
+
+
+@pytest.fixture(scope='module')
+def json_bytes_data():
+    data = [{'name': f'naïve café {i}', 'tags': ['ünïcödé', 'ascii'] * 5, 'score': i / 7} for i in range(1000)]
+    return json.dumps(data, ensure_ascii=False).encode()
+
+
+@pytest.fixture(scope='module')
+def json_bytes_validator():
+    item_schema = core_schema.typed_dict_schema(
+        {
+            'name': core_schema.typed_dict_field(core_schema.str_schema()),
+            'tags': core_schema.typed_dict_field(core_schema.list_schema(core_schema.str_schema())),
+            'score': core_schema.typed_dict_field(core_schema.float_schema()),
+        }
+    )
+    return SchemaValidator(core_schema.list_schema(item_schema))
+
+
+@pytest.mark.benchmark(group='JSON bytes')
+def test_json_bytes_decode_then_validate(json_bytes_validator, json_bytes_data, benchmark):
+    benchmark(lambda: json_bytes_validator.validate_json(json_bytes_data.decode()))
+
+
+@pytest.mark.benchmark(group='JSON bytes')
+def test_json_bytes_validate_bytes(json_bytes_validator, json_bytes_data, benchmark):
+    assert json_bytes_validator.validate_json_bytes(json_bytes_data) == json_bytes_validator.validate_json(
+        json_bytes_data.decode()
+    )
+    benchmark(json_bytes_validator.validate_json_bytes, json_bytes_data)
+
+
+merged_steps_schema = core_schema.chain_schema(
+    [
+        core_schema.str_schema(min_length=1),
+        core_schema.str_schema(max_length=100),
+        core_schema.str_schema(pattern='^[a-z]'),
+        core_schema.str_schema(pattern='[a-z]$'),
+    ]
+)
+
+
+@pytest.mark.benchmark(group='merge validators')
+def test_merge_validators_disabled(benchmark):
+    v = SchemaValidator(merged_steps_schema, {'merge_validators': False})
+    assert v.title == 'chain[constrained-str,constrained-str,constrained-str,constrained-str]'
+    benchmark(v.validate_python, 'hello world')
+
+
+@pytest.mark.benchmark(group='merge validators')
+def test_merge_validators_enabled(benchmark):
+    v = SchemaValidator(merged_steps_schema)
+    assert v.title == 'constrained-str'
+    benchmark(v.validate_python, 'hello world')
+
+
+email_pattern = r'^[a-z0-9._%+-]+@[a-z0-9.-]+\.[a-z]{2,}$'
+
+
+@pytest.mark.benchmark(group='regex engine')
+@pytest.mark.parametrize('regex_engine', ['rust-regex', 'python-re'])
+def test_regex_engine(regex_engine, benchmark):
+    v = SchemaValidator(core_schema.str_schema(pattern=email_pattern, regex_engine=regex_engine))
+    assert v.validate_python('samuel@example.com') == 'samuel@example.com'
+    benchmark(v.validate_python, 'samuel@example.com')
+
+
+@pytest.mark.benchmark(group='regex engine')
+@pytest.mark.parametrize('regex_engine', ['rust-regex', 'python-re'])
+def test_regex_engine_mismatch(regex_engine, benchmark):
+    v = SchemaValidator(core_schema.str_schema(pattern=email_pattern, regex_engine=regex_engine))
+
+    @benchmark
+    def t():
+        try:
+            v.validate_python('not an email address')
+        except ValidationError:
+            pass
+
+
+uuid_strs = [str(uuid4()) for _ in range(10_000)]
+
+
+@pytest.mark.benchmark(group='uuid list')
+def test_uuid_list_core(benchmark):
+    v = SchemaValidator(core_schema.list_schema(core_schema.uuid_schema()))
+    assert v.validate_python(uuid_strs) == [UUID(s) for s in uuid_strs]
+    benchmark(v.validate_python, uuid_strs)
+
+
+@pytest.mark.benchmark(group='uuid list')
+def test_uuid_list_function(benchmark):
+    # how UUIDs were validated before the `uuid` schema, by calling `UUID` on strings
+    v = SchemaValidator(
+        core_schema.list_schema(
+            {
+                'type': 'function-after',
+                'schema': {'type': 'str'},
+                'function': {'type': 'general', 'function': lambda v, info: UUID(v)},
+            }
+        )
+    )
+    assert v.validate_python(uuid_strs) == [UUID(s) for s in uuid_strs]
+    benchmark(v.validate_python, uuid_strs)
+
+
+ipv4_strs = [str(IPv4Address(i * 429_497)) for i in range(10_000)]
+
+
+@pytest.mark.benchmark(group='ipv4 address list')
+def test_ipv4_address_list_core(benchmark):
+    v = SchemaValidator(core_schema.list_schema(core_schema.ip_v4_address_schema()))
+    assert v.validate_python(ipv4_strs) == [IPv4Address(s) for s in ipv4_strs]
+    benchmark(v.validate_python, ipv4_strs)
+
+
+@pytest.mark.benchmark(group='ipv4 address list')
+def test_ipv4_address_list_function(benchmark):
+    # how addresses were validated before the `ip-v4-address` schema, by calling `IPv4Address` on strings
+    v = SchemaValidator(
+        core_schema.list_schema(
+            {
+                'type': 'function-after',
+                'schema': {'type': 'str'},
+                'function': {'type': 'general', 'function': lambda v, info: IPv4Address(v)},
+            }
+        )
+    )
+    assert v.validate_python(ipv4_strs) == [IPv4Address(s) for s in ipv4_strs]
+    benchmark(v.validate_python, ipv4_strs)
+
+
+ipv4_network_strs = [f'{IPv4Address(i * 429_497)}/{i % 33}' for i in range(10_000)]
+
+
+@pytest.mark.benchmark(group='ipv4 network list')
+def test_ipv4_network_list_core(benchmark):
+    v = SchemaValidator(core_schema.list_schema(core_schema.ip_v4_network_schema()))
+    assert v.validate_python(ipv4_network_strs) == [IPv4Network(s, strict=False) for s in ipv4_network_strs]
+    benchmark(v.validate_python, ipv4_network_strs)
+
+
+@pytest.mark.benchmark(group='ipv4 network list')
+def test_ipv4_network_list_function(benchmark):
+    v = SchemaValidator(
+        core_schema.list_schema(
+            {
+                'type': 'function-after',
+                'schema': {'type': 'str'},
+                'function': {'type': 'general', 'function': lambda v, info: IPv4Network(v, strict=False)},
+            }
+        )
+    )
+    assert v.validate_python(ipv4_network_strs) == [IPv4Network(s, strict=False) for s in ipv4_network_strs]
+    benchmark(v.validate_python, ipv4_network_strs)
+
+
+@pytest.mark.benchmark(group='literal str')
+@pytest.mark.parametrize('size', [10, 10_000])
+def test_literal_str(benchmark, size):
+    v = SchemaValidator(core_schema.literal_schema([f'value_{i}' for i in range(size)]))
+    input_value = f'value_{size - 1}'
+    assert v.validate_python(input_value) == input_value
+    benchmark(v.validate_python, input_value)
+
+
+@pytest.mark.benchmark(group='literal int')
+@pytest.mark.parametrize('size', [10, 10_000])
+def test_literal_int(benchmark, size):
+    v = SchemaValidator(core_schema.literal_schema(list(range(size))))
+    assert v.validate_python(size - 1) == size - 1
+    benchmark(v.validate_python, size - 1)
+
+
+@pytest.mark.benchmark(group='literal general')
+@pytest.mark.parametrize('size', [10, 10_000])
+def test_literal_general(benchmark, size):
+    v = SchemaValidator(core_schema.literal_schema([float(i) + 0.5 for i in range(size)] + [None]))
+    input_value = size - 0.5
+    assert v.validate_python(input_value) == input_value
+    benchmark(v.validate_python, input_value)
+
+
+union_list_items = [[1, 'a', 1.5, b'b', None][i % 5] for i in range(100_000)]
+union_list_lax_items = [['1', 2.0, '3.5', 'c', b'd'][i % 5] for i in range(100_000)]
+
+
+@pytest.mark.benchmark(group='smart union list')
+@pytest.mark.parametrize('items', [union_list_items, union_list_lax_items], ids=['exact', 'lax'])
+def test_smart_union_list(benchmark, items):
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.union_schema(
+                [
+                    core_schema.int_schema(),
+                    core_schema.float_schema(),
+                    core_schema.str_schema(),
+                    core_schema.bytes_schema(),
+                    core_schema.none_schema(),
+                ]
+            )
+        )
+    )
+    v.validate_python(items)
+    benchmark(v.validate_python, items)
+
+
+homogeneous_objects_json = json.dumps(
+    [{'name': f'item {i % 50}', 'status': ['active', 'inactive', 'pending'][i % 3], 'count': i} for i in range(100_000)]
+)
+
+
+@pytest.mark.benchmark(group='cache strings')
+@pytest.mark.parametrize('cache_strings', ['all', 'keys', 'none'])
+@pytest.mark.parametrize('typed', [True, False], ids=['typed-dict', 'any'])
+def test_cache_strings(benchmark, cache_strings, typed):
+    if typed:
+        schema = core_schema.list_schema(
+            core_schema.typed_dict_schema(
+                {
+                    'name': core_schema.typed_dict_field(core_schema.str_schema()),
+                    'status': core_schema.typed_dict_field(core_schema.str_schema()),
+                    'count': core_schema.typed_dict_field(core_schema.int_schema()),
+                }
+            )
+        )
+    else:
+        schema = core_schema.any_schema()
+    v = SchemaValidator(schema, {'cache_strings': cache_strings})
+    assert len(v.validate_json(homogeneous_objects_json)) == 100_000
+    benchmark(v.validate_json, homogeneous_objects_json)
+
+
+large_json_payload = json.dumps([{'id': i, 'name': f'item {i}', 'tags': ['a', 'b']} for i in range(1_000_000)]).encode()
+
+
+@pytest.mark.benchmark(group='validate_json buffer')
+@pytest.mark.parametrize('input_type', ['bytes', 'bytearray', 'memoryview', 'memoryview-copied'])
+def test_validate_json_buffer(benchmark, input_type):
+    assert len(large_json_payload) > 50_000_000
+    v = SchemaValidator(core_schema.list_schema(core_schema.dict_schema()))
+    if input_type == 'bytes':
+        benchmark(v.validate_json, large_json_payload)
+    elif input_type == 'bytearray':
+        benchmark(v.validate_json, bytearray(large_json_payload))
+    elif input_type == 'memoryview':
+        benchmark(v.validate_json, memoryview(large_json_payload))
+    else:
+        # what callers had to do before memoryview was supported
+        view = memoryview(large_json_payload)
+        benchmark(lambda: v.validate_json(bytes(view)))
+
+
+def test_validate_json_memoryview_not_copied():
+    v = SchemaValidator(core_schema.int_schema())
+    payload = memoryview(b' ' * 50_000_000 + b'1')
+    tracemalloc.start()
+    try:
+        assert v.validate_json(payload) == 1
+        _, peak = tracemalloc.get_traced_memory()
+    finally:
+        tracemalloc.stop()
+    assert peak < 1_000_000
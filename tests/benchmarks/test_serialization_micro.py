@@ -291,6 +291,30 @@ def test_core_model_json(benchmark, basic_model_serializer):
     benchmark(basic_model_serializer.to_json, m)
 
 
+@pytest.mark.benchmark(group='model-json-100k')
+def test_core_model_json_100k(benchmark, basic_model_serializer):
+    models = [BasicModel(a=i, b=2, c=3, d=4, e=5, f=6, g=7, h=8) for i in range(100_000)]
+
+    @benchmark
+    def r():
+        for m in models:
+            basic_model_serializer.to_json(m)
+
+
+@pytest.mark.benchmark(group='model-json-100k')
+def test_core_model_json_into_100k(benchmark, basic_model_serializer):
+    models = [BasicModel(a=i, b=2, c=3, d=4, e=5, f=6, g=7, h=8) for i in range(100_000)]
+    buffer = bytearray()
+    assert basic_model_serializer.to_json_into(models[1], buffer) == 49
+    assert buffer == b'{"a":1,"b":2,"c":3,"d":4,"e":5,"f":6,"g":7,"h":8}'
+
+    @benchmark
+    def r():
+        for m in models:
+            basic_model_serializer.to_json_into(m, buffer)
+            buffer.clear()
+
+
 class FieldsSetModel:
     __slots__ = '__dict__', '__pydantic_fields_set__'
 
@@ -416,3 +440,46 @@ def test_to_string_direct(benchmark):
     assert s.to_json(123) == b'"123"'
 
     benchmark(s.to_json, 123)
+
+
+@pytest.fixture(scope='module')
+def large_model():
+    schema = core_schema.model_schema(
+        BasicModel,
+        core_schema.typed_dict_schema(
+            {
+                'items': core_schema.typed_dict_field(
+                    core_schema.list_schema(
+                        core_schema.typed_dict_schema(
+                            {
+                                'name': core_schema.typed_dict_field(core_schema.str_schema()),
+                                'value': core_schema.typed_dict_field(core_schema.float_schema()),
+                                'tags': core_schema.typed_dict_field(core_schema.list_schema(core_schema.str_schema())),
+                            }
+                        )
+                    )
+                )
+            }
+        ),
+    )
+    items = [{'name': f'item {i}', 'value': i / 3, 'tags': ['a', 'b', 'c']} for i in range(10_000)]
+    return SchemaSerializer(schema), BasicModel(items=items)
+
+
+@pytest.mark.benchmark(group='large-model-json')
+def test_large_model_json_bytes(benchmark, large_model):
+    s, m = large_model
+    assert s.to_json(m) == json.dumps(s.to_python(m, mode='json'), separators=(',', ':')).encode()
+
+    @benchmark
+    def r():
+        s.to_json(m)
+
+
+@pytest.mark.benchmark(group='large-model-json')
+def test_large_model_json_str_encode(benchmark, large_model):
+    s, m = large_model
+
+    @benchmark
+    def r():
+        json.dumps(s.to_python(m, mode='json'), separators=(',', ':')).encode()
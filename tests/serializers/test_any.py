@@ -1,5 +1,6 @@
 import dataclasses
 import json
+import re
 import sys
 from collections import namedtuple
 from datetime import date, datetime, time, timedelta, timezone
@@ -7,6 +8,7 @@ from decimal import Decimal
 from enum import Enum
 from pathlib import Path
 from typing import ClassVar
+from uuid import UUID
 
 import pytest
 from dirty_equals import HasRepr, IsList
@@ -381,7 +383,7 @@ def test_base64():
 @pytest.mark.parametrize(
     'gen_input,kwargs,expected_json',
     [
-        # (lambda: UUID('ebcdab58-6eb8-46fb-a190-d07a33e9eac8'), '"ebcdab58-6eb8-46fb-a190-d07a33e9eac8"'),
+        (lambda: UUID('ebcdab58-6eb8-46fb-a190-d07a33e9eac8'), {}, b'"ebcdab58-6eb8-46fb-a190-d07a33e9eac8"'),
         (lambda: datetime(2032, 1, 1, 1, 1), {}, b'"2032-01-01T01:01:00"'),
         (lambda: datetime(2032, 1, 1, 1, 1, tzinfo=timezone.utc), {}, b'"2032-01-01T01:01:00Z"'),
         (lambda: datetime(2032, 1, 1, 1, 1, tzinfo=timezone(timedelta(hours=2))), {}, b'"2032-01-01T01:01:00+02:00"'),
@@ -405,7 +407,9 @@ def test_base64():
         (lambda: MyEnum.a, {}, b'1'),
         (lambda: MyEnum.b, {}, b'"b"'),
         (lambda: [MyDataclass(1, 'a', 2), MyModel(a=2, b='b')], {}, b'[{"a":1,"b":"a"},{"a":2,"b":"b"}]'),
-        # # (lambda: re.compile('^regex$'), b'"^regex$"'),
+        (lambda: re.compile('^regex$'), {}, b'"^regex$"'),
+        (lambda: re.compile(b'^regex$'), {}, b'"^regex$"'),
+        (lambda: {re.compile('^regex$'): 1}, {}, b'{"^regex$":1}'),
     ],
 )
 def test_encoding(any_serializer, gen_input, kwargs, expected_json):
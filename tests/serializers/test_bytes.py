@@ -4,7 +4,7 @@ from enum import Enum
 
 import pytest
 
-from pydantic_core import PydanticSerializationError, SchemaSerializer, core_schema
+from pydantic_core import PydanticSerializationError, SchemaError, SchemaSerializer, SchemaValidator, core_schema
 
 
 def test_bytes():
@@ -20,6 +20,13 @@ def test_bytes():
     assert json.loads(json_emoji) == 'emoji 💩'
 
 
+def test_bytearray():
+    s = SchemaSerializer(core_schema.bytes_schema())
+    assert s.to_python(bytearray(b'foobar')) == bytearray(b'foobar')
+    assert s.to_python(bytearray(b'foobar'), mode='json') == 'foobar'
+    assert s.to_json(bytearray(b'foobar')) == b'"foobar"'
+
+
 def test_bytes_invalid_all():
     s = SchemaSerializer(core_schema.bytes_schema())
     assert s.to_python(b'\x81') == b'\x81'
@@ -119,3 +126,132 @@ def test_any_bytes_base64():
     assert s.to_json(b'foobar') == b'"Zm9vYmFy"'
     assert s.to_json({b'foobar': 123}) == b'{"Zm9vYmFy":123}'
     assert s.to_python({b'foobar': 123}, mode='json') == {'Zm9vYmFy': 123}
+
+
+def test_memoryview():
+    s = SchemaSerializer(core_schema.bytes_schema())
+    value = memoryview(b'foobar')
+    assert s.to_python(value) is value
+    assert s.to_python(value, mode='json') == 'foobar'
+    assert s.to_json(value) == b'"foobar"'
+    # slices of network buffers are still contiguous
+    assert s.to_json(memoryview(bytearray(b'foobar'))[1:4]) == b'"oob"'
+
+
+def test_memoryview_dict_key():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.bytes_schema(), core_schema.int_schema()))
+    assert s.to_python({memoryview(b'foobar'): 123}, mode='json') == {'foobar': 123}
+    assert s.to_json({memoryview(b'foobar'): 123}) == b'{"foobar":123}'
+
+
+def test_bytes_hex():
+    s = SchemaSerializer(core_schema.bytes_schema(), {'ser_json_bytes': 'hex'})
+    assert s.to_python(b'foobar') == b'foobar'
+    assert s.to_json(b'\x00\xff foo') == b'"00ff20666f6f"'
+    assert s.to_python(b'\x00\xff foo', mode='json') == '00ff20666f6f'
+    assert s.to_json(bytearray(b'\x01\xab')) == b'"01ab"'
+    assert s.to_json(memoryview(b'\x01\xab')) == b'"01ab"'
+    assert s.to_json(b'') == b'""'
+
+
+def test_bytes_hex_dict_key():
+    s = SchemaSerializer(
+        core_schema.dict_schema(core_schema.bytes_schema(), core_schema.int_schema()), {'ser_json_bytes': 'hex'}
+    )
+    assert s.to_json({b'\xff': 1}) == b'{"ff":1}'
+    assert s.to_python({b'\xff': 1}, mode='json') == {'ff': 1}
+
+
+def test_bytes_base64url():
+    s = SchemaSerializer(core_schema.bytes_schema(), {'ser_json_bytes': 'base64url'})
+    assert s.to_python(b'foo bar') == b'foo bar'
+    # URL-safe alphabet, no padding
+    assert s.to_json(b'\xfb\xff foo bar') == b'"-_8gZm9vIGJhcg"'
+    assert s.to_python(b'\xfb\xff foo bar', mode='json') == '-_8gZm9vIGJhcg'
+    assert s.to_json(bytearray(b'hi')) == b'"aGk"'
+    assert s.to_json(memoryview(b'hi')) == b'"aGk"'
+    assert s.to_json(b'') == b'""'
+
+
+def test_bytes_base64url_dict_key():
+    s = SchemaSerializer(
+        core_schema.dict_schema(core_schema.bytes_schema(), core_schema.int_schema()), {'ser_json_bytes': 'base64url'}
+    )
+    assert s.to_json({b'\xfb\xff': 1}) == b'{"-_8":1}'
+    assert s.to_python({b'\xfb\xff': 1}, mode='json') == {'-_8': 1}
+
+
+def test_any_bytes_base64url_hex():
+    s = SchemaSerializer(core_schema.any_schema(), {'ser_json_bytes': 'base64url'})
+    assert s.to_json({b'\xfb\xff': b'hi'}) == b'{"-_8":"aGk"}'
+    assert s.to_python({b'\xfb\xff': b'hi'}, mode='json') == {'-_8': 'aGk'}
+
+    s = SchemaSerializer(core_schema.any_schema(), {'ser_json_bytes': 'hex'})
+    assert s.to_json({b'\xfb\xff': b'hi'}) == b'{"fbff":"6869"}'
+    assert s.to_python({b'\xfb\xff': b'hi'}, mode='json') == {'fbff': '6869'}
+
+
+@pytest.mark.parametrize('config_mode', [None, 'utf8', 'base64', 'base64url'])
+def test_schema_bytes_mode(config_mode):
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.bytes_schema(ser_json_bytes='hex')),
+                'b': core_schema.typed_dict_field(core_schema.bytes_schema()),
+            }
+        ),
+        {'ser_json_bytes': config_mode} if config_mode else None,
+    )
+    b_json = s.to_python({'a': b'x', 'b': b'foo bar'}, mode='json')['b']
+    assert b_json == {'base64': 'Zm9vIGJhcg==', 'base64url': 'Zm9vIGJhcg'}.get(config_mode, 'foo bar')
+    assert s.to_json({'a': b'foo bar', 'b': b'foo bar'}) == json.dumps(
+        {'a': '666f6f20626172', 'b': b_json}, separators=(',', ':')
+    ).encode()
+
+
+def test_schema_bytes_mode_mixed():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'mac': core_schema.typed_dict_field(core_schema.bytes_schema(ser_json_bytes='hex')),
+                'token': core_schema.typed_dict_field(core_schema.bytes_schema(ser_json_bytes='base64url')),
+                'keys': core_schema.typed_dict_field(
+                    core_schema.dict_schema(core_schema.bytes_schema(ser_json_bytes='hex'), core_schema.int_schema())
+                ),
+            }
+        ),
+        {'ser_json_bytes': 'base64'},
+    )
+    value = {'mac': b'\x00\x1a\x2b\xff', 'token': b'\xfb\xff', 'keys': {b'\xab': 1}}
+    expected = {'mac': '001a2bff', 'token': '-_8', 'keys': {'ab': 1}}
+    assert s.to_python(value, mode='json') == expected
+    assert s.to_json(value) == json.dumps(expected, separators=(',', ':')).encode()
+
+
+def test_invalid_bytes_mode():
+    with pytest.raises(SchemaError, match="Input should be 'utf8', 'base64', 'base64url' or 'hex'"):
+        SchemaSerializer(core_schema.bytes_schema(ser_json_bytes='foo'))
+    msg = 'Invalid bytes serialization mode: `foo`, expected `utf8`, `base64`, `base64url` or `hex`'
+    with pytest.raises(SchemaError, match=msg):
+        SchemaSerializer(core_schema.bytes_schema(), {'ser_json_bytes': 'foo'})
+
+
+@pytest.mark.parametrize(
+    'mode,decode',
+    [
+        ('utf8', str.encode),
+        ('base64', base64.urlsafe_b64decode),
+        ('base64url', lambda s: base64.urlsafe_b64decode(s + '=' * (-len(s) % 4))),
+        ('hex', bytes.fromhex),
+    ],
+)
+@pytest.mark.parametrize('output', ['bytes', 'bytearray'])
+def test_round_trip(mode, decode, output):
+    v = SchemaValidator(core_schema.bytes_schema(output=output))
+    s = SchemaSerializer(core_schema.bytes_schema(output=output, ser_json_bytes=mode))
+    value = v.validate_python(b'foo bar')
+    serialized = json.loads(s.to_json(value))
+    if mode == 'utf8':
+        # the validator only reads JSON strings as utf8
+        assert v.validate_json(s.to_json(value)) == value
+    assert v.validate_python(decode(serialized)) == value
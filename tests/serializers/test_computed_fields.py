@@ -0,0 +1,198 @@
+import dataclasses
+import json
+from datetime import datetime
+
+import pytest
+
+from pydantic_core import PydanticSerializationError, SchemaError, SchemaSerializer, SchemaValidator, core_schema
+
+
+class Rect:
+    def __init__(self, **kwargs):
+        for key, value in kwargs.items():
+            setattr(self, key, value)
+
+    @property
+    def area(self):
+        return self.width * self.height
+
+    @property
+    def created(self):
+        return datetime(2023, 1, 1, 12, 30)
+
+    @property
+    def label(self):
+        return getattr(self, 'name', None)
+
+
+def rect_schema(*computed_fields, **kwargs):
+    return core_schema.model_schema(
+        Rect,
+        core_schema.typed_dict_schema(
+            {
+                'width': core_schema.typed_dict_field(core_schema.int_schema()),
+                'height': core_schema.typed_dict_field(core_schema.int_schema()),
+            }
+        ),
+        computed_fields=list(computed_fields),
+        **kwargs,
+    )
+
+
+def test_computed_fields():
+    s = SchemaSerializer(
+        rect_schema(
+            core_schema.computed_field('area', core_schema.int_schema()),
+            core_schema.computed_field('created', core_schema.datetime_schema()),
+        )
+    )
+    r = Rect(width=2, height=3)
+    assert s.to_python(r) == {'width': 2, 'height': 3, 'area': 6, 'created': datetime(2023, 1, 1, 12, 30)}
+    assert list(s.to_python(r)) == ['width', 'height', 'area', 'created']
+    assert s.to_python(r, mode='json') == {'width': 2, 'height': 3, 'area': 6, 'created': '2023-01-01T12:30:00'}
+    assert s.to_json(r) == b'{"width":2,"height":3,"area":6,"created":"2023-01-01T12:30:00"}'
+
+
+def test_computed_fields_alias():
+    s = SchemaSerializer(rect_schema(core_schema.computed_field('area', core_schema.int_schema(), alias='Area')))
+    r = Rect(width=2, height=3)
+    assert s.to_python(r) == {'width': 2, 'height': 3, 'Area': 6}
+    assert s.to_python(r, by_alias=False) == {'width': 2, 'height': 3, 'area': 6}
+    assert s.to_json(r) == b'{"width":2,"height":3,"Area":6}'
+    assert s.to_json(r, by_alias=False) == b'{"width":2,"height":3,"area":6}'
+
+
+def test_computed_fields_include_exclude():
+    s = SchemaSerializer(
+        rect_schema(
+            core_schema.computed_field('area', core_schema.int_schema(), alias='Area'),
+            core_schema.computed_field('label', core_schema.nullable_schema(core_schema.str_schema())),
+        )
+    )
+    r = Rect(width=2, height=3)
+    assert s.to_python(r, exclude={'area'}) == {'width': 2, 'height': 3, 'label': None}
+    assert s.to_python(r, include={'width', 'area'}) == {'width': 2, 'Area': 6}
+    assert s.to_python(r, exclude_none=True) == {'width': 2, 'height': 3, 'Area': 6}
+    assert s.to_json(r, exclude={'area'}) == b'{"width":2,"height":3,"label":null}'
+    assert s.to_json(r, include={'width', 'area'}) == b'{"width":2,"Area":6}'
+    assert s.to_json(r, exclude_none=True) == b'{"width":2,"height":3,"Area":6}'
+    assert s.to_json(Rect(width=2, height=3, name='x'), exclude_none=True) == (
+        b'{"width":2,"height":3,"Area":6,"label":"x"}'
+    )
+
+
+def test_computed_fields_error():
+    class BadRect(Rect):
+        @property
+        def area(self):
+            raise ValueError('no area')
+
+    s = SchemaSerializer(rect_schema(core_schema.computed_field('area', core_schema.int_schema())))
+    r = BadRect(width=2, height=3)
+    with pytest.raises(PydanticSerializationError, match='Error getting computed field `area`: ValueError: no area'):
+        s.to_python(r)
+    with pytest.raises(PydanticSerializationError, match='Error getting computed field `area`: ValueError: no area'):
+        s.to_python(r, mode='json')
+    with pytest.raises(PydanticSerializationError, match='Error getting computed field `area`: ValueError: no area'):
+        s.to_json(r)
+    # excluded computed fields aren't read
+    assert s.to_python(r, exclude={'area'}) == {'width': 2, 'height': 3}
+
+
+def test_computed_fields_nested():
+    class Outer:
+        def __init__(self, rect, rects):
+            self.rect = rect
+            self.rects = rects
+
+        @property
+        def total_area(self):
+            return sum(r.area for r in [self.rect, *self.rects])
+
+    inner_schema = rect_schema(core_schema.computed_field('area', core_schema.int_schema()))
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            Outer,
+            core_schema.typed_dict_schema(
+                {
+                    'rect': core_schema.typed_dict_field(inner_schema),
+                    'rects': core_schema.typed_dict_field(core_schema.list_schema(inner_schema)),
+                }
+            ),
+            computed_fields=[core_schema.computed_field('total_area', core_schema.int_schema())],
+        )
+    )
+    o = Outer(Rect(width=1, height=2), [Rect(width=3, height=4)])
+    expected = {
+        'rect': {'width': 1, 'height': 2, 'area': 2},
+        'rects': [{'width': 3, 'height': 4, 'area': 12}],
+        'total_area': 14,
+    }
+    assert s.to_python(o) == expected
+    assert s.to_python(o, mode='json') == expected
+    assert json.loads(s.to_json(o)) == expected
+    assert s.to_python(o, include={'rect': {'area'}, 'total_area': True}) == {'rect': {'area': 2}, 'total_area': 14}
+    assert s.to_python(o, exclude={'rects': {0: {'area'}}}) == {
+        'rect': {'width': 1, 'height': 2, 'area': 2},
+        'rects': [{'width': 3, 'height': 4}],
+        'total_area': 14,
+    }
+
+
+def test_computed_fields_dataclass():
+    @dataclasses.dataclass
+    class Point:
+        x: int
+        y: int
+
+        @property
+        def distance(self):
+            return (self.x**2 + self.y**2) ** 0.5
+
+    schema = core_schema.dataclass_schema(
+        Point,
+        core_schema.dataclass_args_schema(
+            'Point',
+            [
+                core_schema.dataclass_field(name='x', schema=core_schema.int_schema()),
+                core_schema.dataclass_field(name='y', schema=core_schema.int_schema()),
+            ],
+        ),
+        computed_fields=[core_schema.computed_field('distance', core_schema.float_schema())],
+    )
+    s = SchemaSerializer(schema)
+    assert s.to_python(Point(3, 4)) == {'x': 3, 'y': 4, 'distance': 5.0}
+    assert s.to_python(Point(3, 4), mode='json') == {'x': 3, 'y': 4, 'distance': 5.0}
+    assert s.to_json(Point(3, 4)) == b'{"x":3,"y":4,"distance":5.0}'
+
+
+def test_computed_fields_not_validated():
+    schema = rect_schema(core_schema.computed_field('area', core_schema.int_schema()))
+    v = SchemaValidator(schema)
+    r = v.validate_python({'width': 2, 'height': 3, 'area': 100})
+    assert r.__dict__ == {'width': 2, 'height': 3}
+    assert r.area == 6
+
+
+def test_computed_fields_model_ser_schema():
+    s = SchemaSerializer(
+        core_schema.any_schema(
+            serialization=core_schema.model_ser_schema(
+                Rect,
+                core_schema.typed_dict_schema({'width': core_schema.typed_dict_field(core_schema.int_schema())}),
+                computed_fields=[core_schema.computed_field('area', core_schema.int_schema())],
+            )
+        )
+    )
+    assert s.to_python(Rect(width=2, height=3)) == {'width': 2, 'area': 6}
+
+
+def test_computed_fields_schema_error():
+    with pytest.raises(SchemaError, match='`computed_fields` require `schema` to be a typed-dict or dataclass-args'):
+        SchemaSerializer(
+            core_schema.model_schema(
+                Rect,
+                core_schema.any_schema(),
+                computed_fields=[core_schema.computed_field('area', core_schema.int_schema())],
+            )
+        )
@@ -0,0 +1,188 @@
+import pytest
+
+from pydantic_core import SchemaSerializer, core_schema
+
+
+class BasicModel:
+    def __init__(self, **kwargs):
+        for key, value in kwargs.items():
+            setattr(self, key, value)
+
+
+class Box:
+    def __init__(self, contents):
+        self.contents = contents
+
+
+@pytest.fixture(scope='module')
+def serializer():
+    return SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.typed_dict_schema(
+                {
+                    'items': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+                    'tags': core_schema.typed_dict_field(
+                        core_schema.dict_schema(core_schema.str_schema(), core_schema.list_schema())
+                    ),
+                    'box': core_schema.typed_dict_field(core_schema.any_schema()),
+                    'child': core_schema.typed_dict_field(core_schema.any_schema()),
+                }
+            ),
+        )
+    )
+
+
+def make_model():
+    child = BasicModel(items=[1])
+    child.__pydantic_fields_set__ = {'items'}
+    return BasicModel(items=[1, 2], tags={'a': [1]}, box=Box([1]), child=child)
+
+
+def test_copy_none(serializer: SchemaSerializer):
+    m = make_model()
+    output = serializer.to_python(m, copy='none')
+    assert output['items'] is m.items
+    assert output['tags'] is m.tags
+    assert output['box'] is m.box
+    assert output['child'] is m.child
+
+    output['items'].append(3)
+    output['tags']['a'].append(2)
+    assert m.items == [1, 2, 3]
+    assert m.tags == {'a': [1, 2]}
+
+
+def test_copy_shallow():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.str_schema(), core_schema.list_schema()))
+    value = {'a': [1], 'b': [2]}
+    output = s.to_python(value, copy='shallow')
+    assert output == value
+    assert output is not value
+    # only the top level is copied, nested containers are shared
+    assert output['a'] is value['a']
+
+    output['c'] = [3]
+    output['a'].append(2)
+    assert value == {'a': [1, 2], 'b': [2]}
+
+    # the top level is copied when it's changed too
+    output = s.to_python(value, copy='shallow', exclude={'b'})
+    assert output == {'a': [1, 2]}
+    assert output['a'] is value['a']
+
+    # immutable containers can't be changed through the output, so are shared
+    s = SchemaSerializer(core_schema.tuple_variable_schema(core_schema.list_schema()))
+    value = ([1], [2])
+    assert s.to_python(value, copy='shallow') is value
+
+
+def test_copy_shallow_model(serializer: SchemaSerializer):
+    m = make_model()
+    output = serializer.to_python(m, copy='shallow')
+    assert output == {'items': [1, 2], 'tags': {'a': [1]}, 'box': m.box, 'child': m.child}
+    assert output['items'] is m.items
+    assert output['tags'] is m.tags
+    output['items'] = [3]
+    assert m.items == [1, 2]
+
+
+def test_copy_default(serializer: SchemaSerializer):
+    m = make_model()
+    output = serializer.to_python(m)
+    assert output == {'items': [1, 2], 'tags': {'a': [1]}, 'box': m.box, 'child': m.child}
+    # containers are rebuilt at every level
+    assert output['items'] is not m.items
+    assert output['tags'] is not m.tags
+    assert output['tags']['a'] is not m.tags['a']
+
+    output['items'].append(3)
+    output['tags']['a'].append(2)
+    assert m.items == [1, 2]
+    assert m.tags == {'a': [1]}
+    # values which aren't serialized are still shared
+    output['box'].contents.append(2)
+    assert m.box.contents == [1, 2]
+
+
+def test_copy_deep(serializer: SchemaSerializer):
+    m = make_model()
+    output = serializer.to_python(m, copy='deep')
+    assert output['items'] == [1, 2]
+    assert output['tags'] == {'a': [1]}
+
+    box = output['box']
+    assert isinstance(box, Box)
+    assert box is not m.box
+    box.contents.append(2)
+    assert m.box.contents == [1]
+
+    child = output['child']
+    assert isinstance(child, BasicModel)
+    assert child is not m.child
+    assert child.__dict__ == m.child.__dict__
+    assert child.__dict__ is not m.child.__dict__
+    assert child.__pydantic_fields_set__ == {'items'}
+    assert child.__pydantic_fields_set__ is not m.child.__pydantic_fields_set__
+    child.items.append(2)
+    assert m.child.items == [1]
+
+
+def test_copy_none_changed():
+    s = SchemaSerializer(core_schema.list_schema(core_schema.list_schema(core_schema.bytes_schema())))
+    value = [[b'a'], [b'b']]
+    output = s.to_python(value, copy='none')
+    assert output is value
+    # excluded items mean a new list is built, unchanged inner lists are still shared
+    output = s.to_python(value, copy='none', exclude={1})
+    assert output == [[b'a']]
+    assert output is not value
+    assert output[0] is value[0]
+    # items serialized to new values mean a new list
+    output = s.to_python(value, mode='json', copy='none')
+    assert output == [['a'], ['b']]
+
+
+def test_copy_none_subclass():
+    class MyList(list):
+        pass
+
+    s = SchemaSerializer(core_schema.list_schema(core_schema.int_schema()))
+    value = MyList([1, 2])
+    output = s.to_python(value, copy='none')
+    assert output == [1, 2]
+    assert type(output) is list
+
+
+@pytest.mark.parametrize(
+    'schema,value',
+    [
+        (core_schema.tuple_variable_schema(core_schema.int_schema()), (1, 2)),
+        (
+            core_schema.tuple_positional_schema([core_schema.int_schema()], extra_schema=core_schema.str_schema()),
+            (1, 'a'),
+        ),
+        (core_schema.set_schema(core_schema.int_schema()), {1, 2}),
+        (core_schema.frozenset_schema(core_schema.int_schema()), frozenset({1, 2})),
+        (core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()), {'a': 1}),
+        (core_schema.any_schema(), [1, (2,), {'a': {3}}]),
+    ],
+)
+def test_copy_none_containers(schema, value):
+    s = SchemaSerializer(schema)
+    assert s.to_python(value, copy='none') is value
+    output = s.to_python(value)
+    assert output == value
+    assert output is not value
+
+
+def test_copy_none_json_mode():
+    s = SchemaSerializer(core_schema.tuple_variable_schema(core_schema.int_schema()))
+    # JSON mode output is always converted
+    assert s.to_python((1, 2), mode='json', copy='none') == [1, 2]
+
+
+def test_copy_invalid():
+    s = SchemaSerializer(core_schema.int_schema())
+    with pytest.raises(ValueError, match="Invalid copy mode: `foobar`, expected `none`, `shallow` or `deep`"):
+        s.to_python(1, copy='foobar')
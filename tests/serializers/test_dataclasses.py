@@ -1,8 +1,11 @@
 import dataclasses
 import json
 import platform
+import sys
 
-from pydantic_core import SchemaSerializer, core_schema
+import pytest
+
+from pydantic_core import SchemaSerializer, SchemaValidator, core_schema
 
 on_pypy = platform.python_implementation() == 'PyPy'
 # pypy doesn't seem to maintain order of `__dict__`
@@ -85,3 +88,74 @@ def test_serialization_alias():
         assert json.loads(j) == {'a': 'hello', 'BAR': 'more'}
     else:
         assert j == b'{"a":"hello","BAR":"more"}'
+
+
+def test_init_only_round_trip():
+    @dataclasses.dataclass
+    class Bar:
+        a: int
+        b: dataclasses.InitVar[str]
+
+        def __post_init__(self, b: str):
+            self.a += len(b)
+
+    schema = core_schema.dataclass_schema(
+        Bar,
+        core_schema.dataclass_args_schema(
+            'Bar',
+            [
+                core_schema.dataclass_field(name='a', schema=core_schema.int_schema()),
+                core_schema.dataclass_field(
+                    name='b',
+                    schema=core_schema.with_default_schema(core_schema.str_schema(), default=''),
+                    init_only=True,
+                ),
+            ],
+            collect_init_only=True,
+        ),
+        post_init=True,
+    )
+    v = SchemaValidator(schema)
+    s = SchemaSerializer(schema)
+    bar = v.validate_python({'a': 1, 'b': 'xx'})
+    assert s.to_python(bar) == {'a': 3}
+    assert s.to_json(bar) == b'{"a":3}'
+    assert dataclasses.asdict(v.validate_json(s.to_json(bar))) == {'a': 3}
+
+
+@pytest.mark.skipif(sys.version_info < (3, 10), reason='requires python3.10 or higher')
+@pytest.mark.parametrize('frozen', [False, True])
+def test_slots_round_trip(frozen: bool):
+    @dataclasses.dataclass(slots=True, frozen=frozen)
+    class Bar:
+        a: int
+        b: bytes
+        c: dataclasses.InitVar[int]
+
+    schema = core_schema.dataclass_schema(
+        Bar,
+        core_schema.dataclass_args_schema(
+            'Bar',
+            [
+                core_schema.dataclass_field(name='a', schema=core_schema.int_schema()),
+                core_schema.dataclass_field(name='b', schema=core_schema.bytes_schema()),
+                core_schema.dataclass_field(
+                    name='c',
+                    schema=core_schema.with_default_schema(core_schema.int_schema(), default=0),
+                    init_only=True,
+                ),
+            ],
+            collect_init_only=True,
+        ),
+        slots=True,
+        frozen=frozen,
+    )
+    v = SchemaValidator(schema)
+    s = SchemaSerializer(schema)
+    bar = v.validate_python({'a': 1, 'b': b'x', 'c': 2})
+    assert s.to_python(bar) == IsStrictDict(a=1, b=b'x')
+    assert s.to_python(bar, mode='json', exclude={'a'}) == {'b': 'x'}
+    j = s.to_json(bar)
+    assert j == b'{"a":1,"b":"x"}'
+    assert v.validate_json(j) == bar
+    assert SchemaSerializer(core_schema.list_schema(schema)).to_json([bar]) == b'[{"a":1,"b":"x"}]'
@@ -1,3 +1,4 @@
+import sys
 from datetime import date, datetime, time, timedelta, timezone
 
 import pytest
@@ -58,6 +59,18 @@ def test_datetime_json(value, expected):
     assert v.to_json(value).decode() == f'"{expected}"'
 
 
+@pytest.mark.skipif(sys.version_info < (3, 9), reason='requires zoneinfo, python3.9 or higher')
+def test_datetime_tz_names():
+    from zoneinfo import ZoneInfo
+
+    v = SchemaSerializer(core_schema.datetime_schema(tz_names=True, dst_policy='later'))
+    value = datetime(2024, 11, 3, 1, 30, fold=1, tzinfo=ZoneInfo('America/New_York'))
+    # the zone name is kept in python mode, JSON uses the UTC offset
+    assert v.to_python(value).tzinfo is ZoneInfo('America/New_York')
+    assert v.to_python(value, mode='json') == '2024-11-03T01:30:00-05:00'
+    assert v.to_json(value) == b'"2024-11-03T01:30:00-05:00"'
+
+
 def test_date():
     v = SchemaSerializer(core_schema.date_schema())
     assert v.to_python(date(2022, 12, 2)) == date(2022, 12, 2)
@@ -1,6 +1,6 @@
 import pytest
 
-from pydantic_core import SchemaSerializer
+from pydantic_core import SchemaSerializer, core_schema
 
 
 def test_branch_nullable():
@@ -51,6 +51,26 @@ def test_cyclic_recursion():
         s.to_json(v)
 
 
+def test_cyclic_recursion_ref():
+    a_ref = core_schema.nullable_schema(core_schema.definition_reference_schema('A'))
+    b_ref = core_schema.nullable_schema(core_schema.definition_reference_schema('B'))
+    b_schema = core_schema.typed_dict_schema(
+        {'a': core_schema.typed_dict_field(a_ref), 'b': core_schema.typed_dict_field(b_ref)}, ref='B'
+    )
+    b_field = core_schema.typed_dict_field(core_schema.nullable_schema(b_schema))
+    s = SchemaSerializer(core_schema.typed_dict_schema({'b': b_field}, ref='A'))
+
+    a = {}
+    a['b'] = {'a': a, 'b': None}
+    with pytest.raises(ValueError, match=r"Circular reference detected \(id repeated\) in 'A'"):
+        s.to_python(a)
+
+    b = {'a': None}
+    b['b'] = b
+    with pytest.raises(ValueError, match=r"Circular reference detected \(id repeated\) in 'B'"):
+        s.to_json({'b': b})
+
+
 def test_custom_ser():
     s = SchemaSerializer(
         {
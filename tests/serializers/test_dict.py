@@ -1,9 +1,15 @@
 import json
+import re
+from collections import defaultdict
+from datetime import date, datetime, time, timezone
+from decimal import Decimal
+from enum import Enum
+from uuid import UUID
 
 import pytest
 from dirty_equals import IsStrictDict
 
-from pydantic_core import SchemaError, SchemaSerializer, core_schema
+from pydantic_core import PydanticSerializationError, SchemaError, SchemaSerializer, SchemaValidator, core_schema
 
 
 def test_dict_str_int():
@@ -156,3 +162,77 @@ def test_filter_runtime_int():
 def test_include_error(include_value, error_msg):
     with pytest.raises(SchemaError, match=error_msg):
         SchemaSerializer(core_schema.dict_schema(serialization=core_schema.filter_dict_schema(include=include_value)))
+
+
+class Color(Enum):
+    red = 'r'
+    blue = 'b'
+
+
+@pytest.mark.parametrize(
+    'key,json_key',
+    [
+        (Decimal('1.50'), '1.50'),
+        (datetime(2022, 1, 1, 12, tzinfo=timezone.utc), '2022-01-01T12:00:00Z'),
+        (date(2022, 1, 1), '2022-01-01'),
+        (time(12, 30), '12:30:00'),
+        (UUID('ebcdab58-6eb8-46fb-a190-d07a33e9eac8'), 'ebcdab58-6eb8-46fb-a190-d07a33e9eac8'),
+        (Color.red, 'r'),
+    ],
+)
+def test_rich_keys(key, json_key):
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.any_schema(), core_schema.int_schema()))
+    assert s.to_python({key: 1}) == {key: 1}
+    assert s.to_python({key: 1}, mode='json') == {json_key: 1}
+    assert s.to_json({key: 1}) == json.dumps({json_key: 1}, separators=(',', ':')).encode()
+
+
+@pytest.mark.parametrize(
+    'keys_schema,key',
+    [
+        (core_schema.datetime_schema(), datetime(2022, 1, 1, 12, 30, tzinfo=timezone.utc)),
+        (core_schema.date_schema(), date(2022, 1, 1)),
+        (core_schema.time_schema(), time(12, 30, 15)),
+        (core_schema.int_schema(), 42),
+        (core_schema.float_schema(), 1.5),
+    ],
+)
+def test_rich_keys_round_trip(keys_schema, key):
+    schema = core_schema.dict_schema(keys_schema, core_schema.int_schema())
+    s = SchemaSerializer(schema)
+    v = SchemaValidator(schema)
+    data = {key: 1}
+    assert v.validate_json(s.to_json(data)) == data
+    assert v.validate_python(s.to_python(data, mode='json')) == data
+
+
+def test_json_key_collision():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.any_schema(), core_schema.int_schema()))
+    # distinct keys in python, but the same JSON key
+    data = {1: 1, '1': 2}
+    assert s.to_python(data) == data
+    msg = re.escape('Dict keys 1 and \'1\' both serialize to the JSON key "1"')
+    with pytest.raises(PydanticSerializationError, match=msg):
+        s.to_json(data)
+    with pytest.raises(PydanticSerializationError, match=msg):
+        s.to_python(data, mode='json')
+
+    msg = re.escape('Dict keys Decimal(\'1.50\') and \'1.50\' both serialize to the JSON key "1.50"')
+    with pytest.raises(PydanticSerializationError, match=msg):
+        s.to_json({Decimal('1.50'): 1, '1.50': 2})
+
+    # keys which were excluded don't collide
+    assert s.to_json(data, exclude={'1'}) == b'{"1":1}'
+
+
+def test_json_key_collision_str_keys():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()))
+    assert s.to_json({'a': 1, 'b': 2}) == b'{"a":1,"b":2}'
+
+
+def test_defaultdict():
+    v = SchemaSerializer(core_schema.dict_schema(core_schema.str_schema(), core_schema.list_schema()))
+    value = defaultdict(list, {'a': [1, 2]})
+    assert v.to_python(value) == IsStrictDict(a=[1, 2])
+    assert v.to_python(value, mode='json') == {'a': [1, 2]}
+    assert v.to_json(value) == b'{"a":[1,2]}'
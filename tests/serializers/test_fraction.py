@@ -0,0 +1,47 @@
+from fractions import Fraction
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaSerializer, core_schema
+
+
+def test_fraction():
+    s = SchemaSerializer(core_schema.fraction_schema())
+    f = Fraction(3, 4)
+    assert s.to_python(f) is f
+    assert s.to_python(f, mode='json') == '3/4'
+    assert s.to_json(f) == b'"3/4"'
+    assert s.to_json(Fraction(-2)) == b'"-2/1"'
+
+    with pytest.warns(UserWarning, match='Expected `fraction` but got `str` - serialized value may not be as expected'):
+        assert s.to_python('3/4', mode='json') == '3/4'
+
+
+def test_pair():
+    s = SchemaSerializer(core_schema.fraction_schema(ser_json_fraction='pair'))
+    f = Fraction(3, 4)
+    assert s.to_python(f) is f
+    assert s.to_python(f, mode='json') == [3, 4]
+    assert s.to_json(f) == b'[3,4]'
+    assert s.to_json(Fraction(-1, 3)) == b'[-1,3]'
+
+
+@pytest.mark.parametrize('mode', ['str', 'pair'])
+def test_dict_key(mode):
+    s = SchemaSerializer(
+        core_schema.dict_schema(core_schema.fraction_schema(ser_json_fraction=mode), core_schema.int_schema())
+    )
+    assert s.to_python({Fraction(1, 2): 1}, mode='json') == {'1/2': 1}
+    assert s.to_json({Fraction(1, 2): 1}) == b'{"1/2":1}'
+
+
+def test_round_trip():
+    s = SchemaSerializer(core_schema.fraction_schema())
+    f = Fraction(-22, 7)
+    assert s.to_json(f, round_trip_check=True) == b'"-22/7"'
+    assert s.to_python(f, mode='json', round_trip_check=True) == '-22/7'
+
+
+def test_invalid_mode():
+    with pytest.raises(SchemaError, match="fraction.ser_json_fraction\n  Input should be 'str' or 'pair'"):
+        SchemaSerializer({'type': 'fraction', 'ser_json_fraction': 'foo'})
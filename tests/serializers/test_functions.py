@@ -566,3 +566,120 @@ def test_wrap_return_type():
     assert s.to_python('foobar') == Path('foobar.new')
     assert s.to_python('foobar', mode='json') == 'foobar.new'
     assert s.to_json('foobar') == b'"foobar.new"'
+
+
+class Account:
+    def __init__(self, **kwargs):
+        self.__dict__.update(kwargs)
+
+
+def redact(value, info):
+    if info.context and info.context.get('external'):
+        return '***'
+    return value
+
+
+def redact_field(_model, value, info):
+    assert info.field_name == 'secret'
+    return redact(value, info)
+
+
+def account_schema():
+    return core_schema.model_schema(
+        Account,
+        core_schema.typed_dict_schema(
+            {
+                'name': core_schema.typed_dict_field(core_schema.str_schema()),
+                'secret': core_schema.typed_dict_field(
+                    core_schema.str_schema(
+                        serialization=core_schema.plain_serializer_function_ser_schema(
+                            redact_field, info_arg=True, is_field_serializer=True
+                        )
+                    )
+                ),
+                'children': core_schema.typed_dict_field(
+                    core_schema.list_schema(core_schema.definition_reference_schema('account'))
+                ),
+            }
+        ),
+        ref='account',
+    )
+
+
+def test_context():
+    s = SchemaSerializer(
+        core_schema.definitions_schema(core_schema.definition_reference_schema('account'), [account_schema()])
+    )
+    value = Account(name='a', secret='s1', children=[Account(name='b', secret='s2', children=[])])
+    assert s.to_python(value) == {
+        'name': 'a',
+        'secret': 's1',
+        'children': [{'name': 'b', 'secret': 's2', 'children': []}],
+    }
+    expected = {'name': 'a', 'secret': '***', 'children': [{'name': 'b', 'secret': '***', 'children': []}]}
+    assert s.to_python(value, context={'external': True}) == expected
+    assert s.to_python(value, mode='json', context={'external': True}) == expected
+    assert json.loads(s.to_json(value, context={'external': True})) == expected
+    assert s.to_python(value, context={'external': False})['secret'] == 's1'
+
+
+def test_context_wrap():
+    contexts = []
+
+    def wrap_function(value, handler, info):
+        contexts.append(info.context)
+        return {k: v for k, v in handler(value).items() if info.context is None or k in info.context['fields']}
+
+    wrapped_account = core_schema.model_schema(
+        Account,
+        core_schema.typed_dict_schema(
+            {
+                'name': core_schema.typed_dict_field(core_schema.str_schema()),
+                'secret': core_schema.typed_dict_field(
+                    core_schema.str_schema(
+                        serialization=core_schema.plain_serializer_function_ser_schema(redact, info_arg=True)
+                    )
+                ),
+                'children': core_schema.typed_dict_field(
+                    core_schema.list_schema(core_schema.definition_reference_schema('account'))
+                ),
+            }
+        ),
+        ref='account',
+        serialization=core_schema.wrap_serializer_function_ser_schema(wrap_function, info_arg=True),
+    )
+    s = SchemaSerializer(
+        core_schema.definitions_schema(core_schema.definition_reference_schema('account'), [wrapped_account])
+    )
+    value = Account(name='a', secret='s1', children=[Account(name='b', secret='s2', children=[])])
+    context = {'external': True, 'fields': {'secret', 'children'}}
+    # the handler serializes the nested models with the same context
+    assert s.to_python(value, context=context) == {'secret': '***', 'children': [{'secret': '***', 'children': []}]}
+    assert contexts == [context, context]
+    assert all(c is context for c in contexts)
+    assert json.loads(s.to_json(value, context=context)) == {
+        'secret': '***',
+        'children': [{'secret': '***', 'children': []}],
+    }
+    contexts.clear()
+    assert s.to_python(value, mode='json')['children'] == [{'name': 'b', 'secret': 's2', 'children': []}]
+    assert contexts == [None, None]
+
+
+def test_context_default():
+    infos = []
+
+    def f(value, info):
+        infos.append(info)
+        return value
+
+    s = SchemaSerializer(
+        core_schema.int_schema(serialization=core_schema.plain_serializer_function_ser_schema(f, info_arg=True))
+    )
+    assert s.to_python(1) == 1
+    assert infos[0].context is None
+    # the context isn't included in the repr
+    assert 'context' not in repr(infos[0])
+    assert s.to_json(1, context=[1, 2]) == b'1'
+    assert infos[1].context == [1, 2]
+    assert infos[1].__dict__['context'] == [1, 2]
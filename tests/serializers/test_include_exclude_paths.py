@@ -0,0 +1,116 @@
+import json
+
+import pytest
+
+from pydantic_core import SchemaSerializer, core_schema, to_json, to_jsonable_python
+
+
+@pytest.fixture(scope='module')
+def order_serializer():
+    item_schema = core_schema.typed_dict_schema(
+        {
+            'name': core_schema.typed_dict_field(core_schema.str_schema()),
+            'internal_id': core_schema.typed_dict_field(core_schema.int_schema()),
+        }
+    )
+    user_schema = core_schema.typed_dict_schema(
+        {
+            'name': core_schema.typed_dict_field(core_schema.str_schema()),
+            'password': core_schema.typed_dict_field(core_schema.str_schema()),
+        }
+    )
+    return SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'user': core_schema.typed_dict_field(user_schema),
+                'items': core_schema.typed_dict_field(core_schema.list_schema(item_schema)),
+            }
+        )
+    )
+
+
+order = {
+    'user': {'name': 'alice', 'password': 'secret'},
+    'items': [{'name': 'apple', 'internal_id': 1}, {'name': 'pear', 'internal_id': 2}],
+}
+
+
+def test_exclude_paths(order_serializer):
+    expected = {'user': {'name': 'alice'}, 'items': [{'name': 'apple'}, {'name': 'pear'}]}
+    exclude_paths = ['user.password', 'items.*.internal_id']
+    assert order_serializer.to_python(order, exclude_paths=exclude_paths) == expected
+    assert json.loads(order_serializer.to_json(order, exclude_paths=exclude_paths)) == expected
+    assert json.loads(to_json(order, exclude_paths=exclude_paths)) == expected
+    assert to_jsonable_python(order, exclude_paths=exclude_paths) == expected
+
+
+def test_include_paths(order_serializer):
+    assert order_serializer.to_python(order, include_paths=['user.name', 'items.1']) == {
+        'user': {'name': 'alice'},
+        'items': [{'name': 'pear', 'internal_id': 2}],
+    }
+    assert order_serializer.to_python(order, include_paths=('items.*.name',)) == {
+        'items': [{'name': 'apple'}, {'name': 'pear'}]
+    }
+
+
+def test_whole_item_takes_precedence(order_serializer):
+    assert order_serializer.to_python(order, include_paths=['user.name', 'user']) == {
+        'user': {'name': 'alice', 'password': 'secret'}
+    }
+    assert order_serializer.to_python(order, exclude_paths=['user', 'user.password', 'items.0']) == {
+        'items': [{'name': 'pear', 'internal_id': 2}]
+    }
+
+
+@pytest.mark.parametrize(
+    'exclude_path,expected',
+    [
+        ('a.b', {'a.b': 1, 'a': {}, '*': 3, '1': 4}),
+        (r'a\.b', {'a': {'b': 2}, '*': 3, '1': 4}),
+        (r'\*', {'a.b': 1, 'a': {'b': 2}, '1': 4}),
+        ('*', {}),
+        (r'\1', {'a.b': 1, 'a': {'b': 2}, '*': 3}),
+        ('1', {'a.b': 1, 'a': {'b': 2}, '*': 3, '1': 4}),
+    ],
+)
+def test_escapes(exclude_path, expected):
+    value = {'a.b': 1, 'a': {'b': 2}, '*': 3, '1': 4}
+    assert to_jsonable_python(value, exclude_paths=[exclude_path]) == expected
+
+
+def test_escaped_backslash():
+    value = {'a\\': {'b': 1}, 'c': 2}
+    assert to_jsonable_python(value, exclude_paths=[r'a\\.b']) == {'a\\': {}, 'c': 2}
+
+
+def test_conflicting_args(order_serializer):
+    with pytest.raises(TypeError, match='`include` and `include_paths` cannot be used together'):
+        order_serializer.to_python(order, include={'user'}, include_paths=['user'])
+    with pytest.raises(TypeError, match='`exclude` and `exclude_paths` cannot be used together'):
+        to_json(order, exclude={'user'}, exclude_paths=['user'])
+    # using different include and exclude styles together is fine
+    assert order_serializer.to_python(order, include={'user'}, exclude_paths=['user.password']) == {
+        'user': {'name': 'alice'}
+    }
+
+
+@pytest.mark.parametrize(
+    'path,error',
+    [
+        ('', 'segments must not be empty'),
+        ('user..name', 'segments must not be empty'),
+        ('user.', 'segments must not be empty'),
+        ('user\\', r'`\\` must be followed by the character to escape'),
+    ],
+)
+def test_invalid_paths(order_serializer, path, error):
+    with pytest.raises(ValueError, match=f"Invalid path in `exclude_paths`: '.*', {error}"):
+        order_serializer.to_python(order, exclude_paths=[path])
+
+
+def test_paths_not_collection(order_serializer):
+    with pytest.raises(TypeError, match='`exclude_paths` must be a collection of strings, not a single string'):
+        order_serializer.to_python(order, exclude_paths='user.password')
+    with pytest.raises(TypeError):
+        order_serializer.to_python(order, exclude_paths=[1])
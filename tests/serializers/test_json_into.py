@@ -0,0 +1,120 @@
+import io
+
+import pytest
+
+from pydantic_core import PydanticSerializationError, SchemaSerializer, core_schema
+
+
+def test_bytearray():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()))
+    buffer = bytearray(b'[')
+    assert s.to_json_into({'a': 1, 'b': 2}, buffer) == 13
+    assert buffer == b'[{"a":1,"b":2}'
+    assert s.to_json_into({'c': 3}, buffer, indent=2) == 12
+    assert buffer == b'[{"a":1,"b":2}{\n  "c": 3\n}'
+
+
+def test_writer():
+    s = SchemaSerializer(core_schema.list_schema(core_schema.int_schema()))
+    f = io.BytesIO()
+    assert s.to_json_into([1, 2, 3], f) == 7
+    assert f.getvalue() == b'[1,2,3]'
+
+
+def test_chunks():
+    class Writer:
+        def __init__(self):
+            self.chunks = []
+
+        def write(self, data):
+            self.chunks.append(data)
+
+    s = SchemaSerializer(core_schema.list_schema(core_schema.str_schema()))
+    value = ['x' * 1000] * 200
+    expected = s.to_json(value)
+    w = Writer()
+    assert s.to_json_into(value, w) == len(expected)
+    assert len(w.chunks) > 1
+    assert all(type(chunk) is bytes for chunk in w.chunks)
+    assert b''.join(w.chunks) == expected
+
+    # the buffer is reused, including after a single big value
+    w = Writer()
+    assert s.to_json_into(['y' * 5_000_000], w) == 5_000_004
+    assert s.to_json_into(value, w) == len(expected)
+    assert b''.join(w.chunks)[5_000_004:] == expected
+
+
+def test_same_as_to_json():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema(), serialization_alias='A'),
+                'b': core_schema.typed_dict_field(core_schema.nullable_schema(core_schema.bytes_schema())),
+            }
+        )
+    )
+    value = {'a': 1, 'b': None}
+    buffer = bytearray()
+    s.to_json_into(value, buffer, exclude_none=True)
+    assert buffer == s.to_json(value, exclude_none=True) == b'{"A":1}'
+    buffer = bytearray()
+    s.to_json_into(value, buffer, by_alias=False)
+    assert buffer == s.to_json(value, by_alias=False) == b'{"a":1,"b":null}'
+
+
+def test_warnings():
+    s = SchemaSerializer(core_schema.int_schema())
+    buffer = bytearray()
+    with pytest.warns(UserWarning, match='Expected `int` but got `str` - serialized value may not be as expected'):
+        assert s.to_json_into('x', buffer) == 3
+    assert buffer == b'"x"'
+
+
+def test_partial_output():
+    def f(value, _info):
+        if value == 'bad':
+            raise ValueError('bad value')
+        return value
+
+    s = SchemaSerializer(
+        core_schema.list_schema(
+            core_schema.any_schema(serialization=core_schema.plain_serializer_function_ser_schema(f, info_arg=True))
+        )
+    )
+    value = ['x' * 100_000, 'bad']
+    buffer = bytearray()
+    with pytest.raises(PydanticSerializationError, match='bad value'):
+        s.to_json_into(value, buffer)
+    # the first chunk has already been written
+    assert buffer.startswith(b'["xxx')
+    assert b'bad' not in buffer
+
+
+def test_writer_error():
+    class Writer:
+        def write(self, data):
+            raise OSError('disk full')
+
+    s = SchemaSerializer(core_schema.str_schema())
+    with pytest.raises(OSError, match='disk full'):
+        s.to_json_into('x', Writer())
+    with pytest.raises(OSError, match='disk full'):
+        s.to_json_into('x' * 100_000, Writer())
+
+
+def test_exported_bytearray():
+    s = SchemaSerializer(core_schema.str_schema())
+    buffer = bytearray()
+    view = memoryview(buffer)
+    with pytest.raises(BufferError):
+        s.to_json_into('x', buffer)
+    view.release()
+    assert s.to_json_into('x', buffer) == 3
+
+
+@pytest.mark.parametrize('buffer', [b'', 'foo', None, [1]])
+def test_invalid_buffer(buffer):
+    s = SchemaSerializer(core_schema.int_schema())
+    with pytest.raises(TypeError, match='`to_json_into` expected a bytearray or an object with a `write` method, got'):
+        s.to_json_into(1, buffer)
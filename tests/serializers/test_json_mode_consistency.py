@@ -0,0 +1,127 @@
+"""
+Check that `to_python(mode='json')` produces exactly the objects which `to_json` encodes, for every combination
+of the config options which change JSON representations.
+"""
+import dataclasses
+import json
+from datetime import date, datetime, time, timedelta, timezone
+
+import pytest
+
+from pydantic_core import SchemaSerializer, SchemaValidator, core_schema
+
+
+@dataclasses.dataclass
+class SubModel:
+    delta: timedelta
+    raw: bytes
+
+
+SubModel.__pydantic_serializer__ = SchemaSerializer(
+    core_schema.dataclass_schema(
+        SubModel,
+        core_schema.dataclass_args_schema(
+            'SubModel',
+            [
+                core_schema.dataclass_field('delta', core_schema.timedelta_schema()),
+                core_schema.dataclass_field('raw', core_schema.bytes_schema(), serialization_alias='RAW'),
+            ],
+        ),
+    )
+)
+
+
+def model_schema():
+    return core_schema.typed_dict_schema(
+        {
+            'delta': core_schema.typed_dict_field(core_schema.timedelta_schema()),
+            'raw': core_schema.typed_dict_field(core_schema.bytes_schema()),
+            'dt': core_schema.typed_dict_field(core_schema.datetime_schema()),
+            'd': core_schema.typed_dict_field(core_schema.date_schema()),
+            't': core_schema.typed_dict_field(core_schema.time_schema()),
+            'url': core_schema.typed_dict_field(core_schema.url_schema()),
+            'multi_host_url': core_schema.typed_dict_field(core_schema.multi_host_url_schema()),
+            'delta_keys': core_schema.typed_dict_field(
+                core_schema.dict_schema(core_schema.timedelta_schema(), core_schema.bytes_schema())
+            ),
+            'delta_list': core_schema.typed_dict_field(core_schema.list_schema(core_schema.timedelta_schema())),
+            'tuple': core_schema.typed_dict_field(
+                core_schema.tuple_positional_schema([core_schema.bytes_schema(), core_schema.timedelta_schema()])
+            ),
+            'union': core_schema.typed_dict_field(
+                core_schema.union_schema([core_schema.int_schema(), core_schema.timedelta_schema()])
+            ),
+            'nullable': core_schema.typed_dict_field(core_schema.nullable_schema(core_schema.timedelta_schema())),
+            'anything': core_schema.typed_dict_field(core_schema.any_schema()),
+        }
+    )
+
+
+def model_value():
+    url = SchemaValidator(core_schema.url_schema()).validate_python('https://example.com/path')
+    multi_host_url = SchemaValidator(core_schema.multi_host_url_schema()).validate_python(
+        'https://example.com,example.org/path'
+    )
+    return {
+        'delta': timedelta(days=1, seconds=3, microseconds=500),
+        'raw': b'hello',
+        'dt': datetime(2022, 1, 2, 12, 13, 14, tzinfo=timezone.utc),
+        'd': date(2022, 1, 2),
+        't': time(12, 13, 14, 123),
+        'url': url,
+        'multi_host_url': multi_host_url,
+        'delta_keys': {timedelta(seconds=1.5): b'x'},
+        'delta_list': [timedelta(minutes=1), timedelta(seconds=-1)],
+        'tuple': (b'y', timedelta(hours=2)),
+        'union': timedelta(seconds=5),
+        'nullable': timedelta(seconds=2),
+        'anything': {
+            'list': [timedelta(hours=1), b'ab', bytearray(b'cd'), datetime(2020, 1, 1), url, multi_host_url],
+            'tuple': (b'z', timedelta(1)),
+            'set': {b'q'},
+            'sub_model': SubModel(timedelta(seconds=10), b'sub'),
+            timedelta(days=2): 1,
+            b'key': 2,
+        },
+    }
+
+
+@pytest.mark.parametrize('ser_json_timedelta', [None, 'iso8601', 'float'])
+@pytest.mark.parametrize('ser_json_bytes', [None, 'utf8', 'base64', 'base64url', 'hex'])
+def test_to_python_json_matches_to_json(ser_json_timedelta, ser_json_bytes):
+    config = {}
+    if ser_json_timedelta is not None:
+        config['ser_json_timedelta'] = ser_json_timedelta
+    if ser_json_bytes is not None:
+        config['ser_json_bytes'] = ser_json_bytes
+    s = SchemaSerializer(model_schema(), config)
+    value = model_value()
+
+    python_json = s.to_python(value, mode='json')
+    assert json.dumps(python_json, separators=(',', ':')).encode() == s.to_json(value)
+    assert python_json == json.loads(s.to_json(value))
+
+
+@pytest.mark.parametrize('ser_json_timedelta', [None, 'iso8601', 'float'])
+@pytest.mark.parametrize('ser_json_bytes', [None, 'base64', 'hex'])
+@pytest.mark.parametrize('kwargs', [{}, {'by_alias': False}, {'exclude_none': True}, {'exclude': {'anything'}}])
+def test_to_json_indent_matches_compact(ser_json_timedelta, ser_json_bytes, kwargs):
+    config = {}
+    if ser_json_timedelta is not None:
+        config['ser_json_timedelta'] = ser_json_timedelta
+    if ser_json_bytes is not None:
+        config['ser_json_bytes'] = ser_json_bytes
+    s = SchemaSerializer(model_schema(), config)
+    value = {**model_value(), 'nullable': None}
+
+    compact = s.to_json(value, **kwargs)
+    pretty = s.to_json(value, indent=2, **kwargs)
+    assert pretty.decode() == json.dumps(json.loads(compact), indent=2)
+    assert pretty != compact
+
+
+def test_inferred_model_uses_own_serializer():
+    s = SchemaSerializer(core_schema.any_schema(), {'ser_json_timedelta': 'float', 'ser_json_bytes': 'base64'})
+    value = SubModel(timedelta(seconds=10), b'sub')
+    assert s.to_python(value, mode='json') == {'delta': 10.0, 'RAW': 'c3Vi'}
+    assert s.to_json(value) == b'{"delta":10.0,"RAW":"c3Vi"}'
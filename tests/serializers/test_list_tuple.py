@@ -1,5 +1,6 @@
 import json
 import re
+from datetime import datetime
 from functools import partial
 
 import pytest
@@ -312,17 +313,118 @@ def test_positional_tuple():
         {'type': 'tuple-positional', 'items_schema': [{'type': 'int'}, {'type': 'bytes'}, {'type': 'float'}]}
     )
     assert s.to_python((1, b'2', 3.0)) == (1, b'2', 3.0)
-    assert s.to_python((1, b'2', 3.0, 123)) == (1, b'2', 3.0, 123)
     assert s.to_python((1, b'2')) == (1, b'2')
 
     assert s.to_python((1, b'2', 3.0), mode='json') == [1, '2', 3.0]
-    assert s.to_python((1, b'2', 3.0, 123), mode='json') == [1, '2', 3.0, 123]
     assert s.to_python((1, b'2'), mode='json') == [1, '2']
 
     assert s.to_json((1, b'2', 3.0)) == b'[1,"2",3.0]'
-    assert s.to_json((1, b'2', 3.0, 123)) == b'[1,"2",3.0,123]'
     assert s.to_json((1, b'2')) == b'[1,"2"]'
 
+    msg = r'Expected `tuple\[int, bytes, float\]` with 3 items but got 4 - extra items serialized by inference'
+    with pytest.warns(UserWarning, match=msg):
+        assert s.to_python((1, b'2', 3.0, 123)) == (1, b'2', 3.0, 123)
+    with pytest.warns(UserWarning, match=msg):
+        assert s.to_python((1, b'2', 3.0, 123), mode='json') == [1, '2', 3.0, 123]
+    with pytest.warns(UserWarning, match=msg):
+        assert s.to_json((1, b'2', 3.0, 123)) == b'[1,"2",3.0,123]'
+    # extra items can't be serialized without a warning, so the warning can be silenced
+    assert s.to_json((1, b'2', 3.0, b'4'), warnings=False) == b'[1,"2",3.0,"4"]'
+
+
+@pytest.fixture(scope='module')
+def heterogeneous_tuple_serializer():
+    return SchemaSerializer(
+        core_schema.tuple_positional_schema(
+            [core_schema.int_schema(), core_schema.str_schema(), core_schema.datetime_schema()]
+        )
+    )
+
+
+@pytest.mark.parametrize(
+    'value,include,exclude',
+    [
+        ((1, 'a', datetime(2022, 1, 2, 3, 4, 5)), None, None),
+        ((1, 'a', datetime(2022, 1, 2, 3, 4, 5)), {0, 2}, None),
+        ((1, 'a', datetime(2022, 1, 2, 3, 4, 5)), None, {1}),
+        ((1, 'a', datetime(2022, 1, 2, 3, 4, 5)), {2: None}, None),
+        ((1, 'a', datetime(2022, 1, 2, 3, 4, 5)), {'__all__'}, {0: ...}),
+        ((1, 'a'), None, None),
+        ((1, 'a'), None, {0}),
+        ((1,), {0, 2}, None),
+    ],
+)
+def test_positional_tuple_json_consistency(heterogeneous_tuple_serializer, value, include, exclude):
+    s = heterogeneous_tuple_serializer
+    json_py = s.to_python(value, include=include, exclude=exclude, mode='json')
+    assert s.to_json(value, include=include, exclude=exclude) == json.dumps(json_py, separators=(',', ':')).encode()
+
+
+def test_positional_tuple_include_exclude(heterogeneous_tuple_serializer):
+    s = heterogeneous_tuple_serializer
+    v = (1, 'a', datetime(2022, 1, 2, 3, 4, 5))
+    assert s.to_python(v, include={0, 2}) == (1, datetime(2022, 1, 2, 3, 4, 5))
+    assert s.to_python(v, include={0, 2}, mode='json') == [1, '2022-01-02T03:04:05']
+    assert s.to_python(v, exclude={1}, mode='json') == [1, '2022-01-02T03:04:05']
+    assert s.to_json(v, exclude={0, 1}) == b'["2022-01-02T03:04:05"]'
+    # shorter tuples keep the serializer for each position
+    assert s.to_python((1, 'a'), exclude={0}, mode='json') == ['a']
+    assert s.to_json((1, 'a'), exclude={0}) == b'["a"]'
+
+
+def test_positional_tuple_extra_schema_no_warning():
+    s = SchemaSerializer(
+        core_schema.tuple_positional_schema([core_schema.int_schema()], extra_schema=core_schema.bytes_schema())
+    )
+    assert s.to_python((1, b'a', b'b'), mode='json') == [1, 'a', 'b']
+    assert s.to_python((1, b'a', b'b'), exclude={1}, mode='json') == [1, 'b']
+    assert s.to_json((1, b'a', b'b'), include={0, 2}) == b'[1,"b"]'
+
+
+def test_positional_tuple_nested_in_typed_dict():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'point': core_schema.typed_dict_field(
+                    core_schema.tuple_positional_schema(
+                        [core_schema.int_schema(), core_schema.str_schema(), core_schema.datetime_schema()]
+                    ),
+                    serialization_alias='pt',
+                ),
+                'name': core_schema.typed_dict_field(core_schema.str_schema()),
+            }
+        )
+    )
+    v = {'point': (1, 'a', datetime(2022, 1, 2)), 'name': 'x'}
+    assert s.to_python(v, by_alias=True, mode='json') == {'pt': [1, 'a', '2022-01-02T00:00:00'], 'name': 'x'}
+    assert s.to_json(v, by_alias=True) == b'{"pt":[1,"a","2022-01-02T00:00:00"],"name":"x"}'
+
+    exclude = {'point': {1}, 'name': ...}
+    assert s.to_python(v, by_alias=True, exclude=exclude) == {'pt': (1, datetime(2022, 1, 2))}
+    assert s.to_python(v, by_alias=True, exclude=exclude, mode='json') == {'pt': [1, '2022-01-02T00:00:00']}
+    assert s.to_json(v, by_alias=True, exclude=exclude) == b'{"pt":[1,"2022-01-02T00:00:00"]}'
+    assert s.to_json(v, by_alias=False, exclude=exclude) == b'{"point":[1,"2022-01-02T00:00:00"]}'
+    assert s.to_json(v, include={'point': {0: ..., 2: ...}}) == b'{"pt":[1,"2022-01-02T00:00:00"]}'
+
+
+def test_positional_tuple_exclude_none():
+    s = SchemaSerializer(
+        core_schema.tuple_positional_schema(
+            [
+                core_schema.nullable_schema(core_schema.int_schema()),
+                core_schema.typed_dict_schema(
+                    {'a': core_schema.typed_dict_field(core_schema.nullable_schema(core_schema.int_schema()))}
+                ),
+                core_schema.str_schema(),
+            ]
+        )
+    )
+    v = (None, {'a': None}, 'x')
+    # None items keep their position, exclude_none only applies to fields of nested items
+    assert s.to_python(v, exclude_none=True) == (None, {}, 'x')
+    assert s.to_python(v, exclude_none=True, mode='json') == [None, {}, 'x']
+    assert s.to_json(v, exclude_none=True) == b'[null,{},"x"]'
+
 
 def test_function_positional_tuple():
     def f(prefix, value, _info):
@@ -61,3 +61,10 @@ def test_other_literal():
 def test_empty_literal():
     with pytest.raises(SchemaError, match='`expected` should have length > 0'):
         SchemaSerializer(core_schema.literal_schema([]))
+
+
+def test_allowed_values_factory():
+    s = SchemaSerializer(core_schema.literal_schema(allowed_values_factory=lambda: ['a', 1]))
+    assert s.to_python('a') == 'a'
+    assert s.to_python(1, mode='json') == 1
+    assert s.to_json('a') == b'"a"'
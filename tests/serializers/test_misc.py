@@ -1,6 +1,8 @@
+import json
+
 import pytest
 
-from pydantic_core import SchemaError, SchemaSerializer, core_schema
+from pydantic_core import PydanticSerializationError, SchemaError, SchemaSerializer, core_schema, to_json
 
 
 @pytest.mark.parametrize(
@@ -13,3 +15,71 @@ from pydantic_core import SchemaError, SchemaSerializer, core_schema
 def test_invalid_ser_schema(ser_schema, msg):
     with pytest.raises(SchemaError, match=msg):
         SchemaSerializer(core_schema.any_schema(serialization=ser_schema))
+
+
+@pytest.mark.parametrize('size', [0, 1, 10, 1000, 100_000])
+def test_to_json_buffer_sizes(size):
+    # the JSON is written straight into a bytes object which is resized as it grows
+    s = SchemaSerializer(core_schema.list_schema(core_schema.str_schema()))
+    value = [f'item {i} 💩' for i in range(size)]
+    expected = json.dumps(value, separators=(',', ':'), ensure_ascii=False).encode()
+    for _ in range(2):
+        # the second call starts with a buffer the size of the previous output
+        assert s.to_json(value) == expected
+    assert s.to_json(value, indent=2) == json.dumps(value, indent=2, ensure_ascii=False).encode()
+    assert to_json(value) == expected
+
+
+def test_to_json_buffer_error():
+    def f(value):
+        if value == 5000:
+            raise ValueError('broken')
+        return value
+
+    s = SchemaSerializer(
+        core_schema.list_schema(
+            core_schema.int_schema(serialization=core_schema.plain_serializer_function_ser_schema(f))
+        )
+    )
+    with pytest.raises(PydanticSerializationError, match='Error calling function `f`: ValueError: broken'):
+        s.to_json(list(range(10_000)))
+    assert s.to_json(list(range(10))) == b'[0,1,2,3,4,5,6,7,8,9]'
+
+
+class IndentModel:
+    def __init__(self, **kwargs):
+        self.__dict__ = kwargs
+
+
+@pytest.mark.parametrize('indent', [None, 0, 2, 4])
+@pytest.mark.parametrize('by_alias', [True, False])
+def test_to_json_indent_nested(indent, by_alias):
+    inner = core_schema.model_schema(
+        IndentModel,
+        core_schema.typed_dict_schema(
+            {'x': core_schema.typed_dict_field(core_schema.int_schema(), serialization_alias='X')}
+        ),
+    )
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'models': core_schema.typed_dict_field(core_schema.list_schema(inner), serialization_alias='Models'),
+                'empty_dict': core_schema.typed_dict_field(core_schema.dict_schema()),
+                'empty_list': core_schema.typed_dict_field(core_schema.list_schema()),
+            }
+        )
+    )
+    value = {'models': [IndentModel(x=1), IndentModel(x=2)], 'empty_dict': {}, 'empty_list': []}
+    expected = s.to_python(value, mode='json', by_alias=by_alias)
+    if indent is None:
+        expected_json = json.dumps(expected, separators=(',', ':'))
+    else:
+        expected_json = json.dumps(expected, indent=indent)
+    assert s.to_json(value, indent=indent, by_alias=by_alias) == expected_json.encode()
+
+
+def test_to_json_indent_zero():
+    s = SchemaSerializer(core_schema.dict_schema())
+    # newlines without any indentation, like `json.dumps(..., indent=0)`
+    assert s.to_json({'a': [1, {}], 'b': []}, indent=0) == b'{\n"a": [\n1,\n{}\n],\n"b": []\n}'
+    assert to_json({'a': [1]}, indent=0) == b'{\n"a": [\n1\n]\n}'
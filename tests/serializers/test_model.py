@@ -125,6 +125,32 @@ def test_model_allow_extra():
         assert j == b'{"bar":"more","foo":1,"c":3}'
 
 
+def test_model_pydantic_extra():
+    class ExtraModel:
+        __slots__ = '__dict__', '__pydantic_extra__'
+
+        def __init__(self, **kwargs):
+            for key, value in kwargs.items():
+                setattr(self, key, value)
+
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            ExtraModel,
+            core_schema.typed_dict_schema(
+                {'foo': core_schema.typed_dict_field(core_schema.int_schema())},
+                extra_behavior='allow',
+                return_extra=True,
+            ),
+        )
+    )
+    m = ExtraModel(foo=1, __pydantic_extra__={'c': b'3'})
+    assert m.__dict__ == {'foo': 1}
+    assert s.to_python(m) == IsStrictDict(foo=1, c=b'3')
+    assert s.to_python(m, mode='json') == {'foo': 1, 'c': '3'}
+    assert s.to_json(m) == b'{"foo":1,"c":"3"}'
+    assert s.to_python(ExtraModel(foo=1, __pydantic_extra__=None)) == {'foo': 1}
+
+
 @pytest.mark.parametrize(
     'params',
     [
@@ -511,3 +537,29 @@ def test_function_wrap_field_serializer_to_json():
         )
     )
     assert json.loads(s.to_json(Model(x=1000))) == {'x': '1_000'}
+
+
+@pytest.mark.parametrize(
+    'alias_generator_serialization,expected',
+    [(None, {'field_a': 1, 'field_b': 2}), (True, {'fieldA': 1, 'FieldB': 2})],
+)
+def test_alias_generator(alias_generator_serialization, expected):
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.typed_dict_schema(
+                {
+                    'field_a': core_schema.typed_dict_field(core_schema.int_schema()),
+                    'field_b': core_schema.typed_dict_field(core_schema.int_schema(), serialization_alias='FieldB'),
+                }
+            ),
+            alias_generator=lambda name: ''.join(w.capitalize() if i else w for i, w in enumerate(name.split('_'))),
+            alias_generator_serialization=alias_generator_serialization,
+        )
+    )
+    m = BasicModel(field_a=1, field_b=2)
+    if alias_generator_serialization:
+        assert s.to_python(m, by_alias=True) == expected
+    else:
+        assert s.to_python(m, by_alias=True) == {'field_a': 1, 'FieldB': 2}
+    assert s.to_python(m, by_alias=False) == {'field_a': 1, 'field_b': 2}
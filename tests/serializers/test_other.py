@@ -1,6 +1,6 @@
 import pytest
 
-from pydantic_core import SchemaSerializer, core_schema
+from pydantic_core import SchemaError, SchemaSerializer, core_schema
 
 from ..conftest import plain_repr
 
@@ -15,6 +15,20 @@ def test_chain():
     assert s.to_json(1) == b'1'
 
 
+def test_chain_validate_only():
+    s = SchemaSerializer(
+        core_schema.chain_schema(
+            [
+                core_schema.str_schema(),
+                core_schema.int_schema(),
+                core_schema.chain_step(core_schema.str_schema(), validate_only=True),
+            ]
+        )
+    )
+    # the last step only validates, so the output is from the step before
+    assert plain_repr(s) == 'SchemaSerializer(serializer=Int(IntSerializer),slots=[])'
+
+
 def test_function_plain():
     s = SchemaSerializer(core_schema.general_plain_validator_function(lambda v, info: v + 1))
     # can't infer the type from plain function validators
@@ -56,3 +70,57 @@ def test_lax_or_strict_custom_ser():
     assert s.to_python('abc') == ' abc '
     assert s.to_python('abc', mode='json') == ' abc '
     assert s.to_json('abc') == b'" abc "'
+
+
+def test_json_or_python():
+    s = SchemaSerializer(core_schema.json_or_python_schema(core_schema.int_schema(), core_schema.str_schema()))
+    # insert_assert(plain_repr(s))
+    assert plain_repr(s) == 'SchemaSerializer(serializer=Str(StrSerializer),slots=[])'
+
+    assert s.to_python('abc') == 'abc'
+    assert s.to_json('abc') == b'"abc"'
+
+
+def test_merge():
+    s = SchemaSerializer(
+        core_schema.merge_schema(
+            [
+                core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.int_schema())}),
+                core_schema.typed_dict_schema({'b': core_schema.typed_dict_field(core_schema.int_schema())}),
+            ]
+        )
+    )
+    # insert_assert(plain_repr(s))
+    assert plain_repr(s) == 'SchemaSerializer(serializer=Any(AnySerializer),slots=[])'
+
+    assert s.to_python({'a': 1, 'b': 2}) == {'a': 1, 'b': 2}
+    assert s.to_json({'a': 1, 'b': 2}) == b'{"a":1,"b":2}'
+
+
+def test_parametrized():
+    schema = core_schema.parametrized_schema(
+        core_schema.list_schema(core_schema.type_var_schema('T')), {'T': core_schema.bytes_schema()}
+    )
+    s = SchemaSerializer(schema)
+    assert s.to_python([b'foo']) == [b'foo']
+    assert s.to_python([b'foo'], mode='json') == ['foo']
+    assert s.to_json([b'foo']) == b'["foo"]'
+
+
+def test_type_var_unsubstituted():
+    with pytest.raises(SchemaError, match='Type variable `T` has not been substituted'):
+        SchemaSerializer(core_schema.list_schema(core_schema.type_var_schema('T')))
+
+
+def test_generic_ref_used_elsewhere():
+    generic_list = core_schema.list_schema(core_schema.type_var_schema('T'), ref='GenericList')
+    schema = core_schema.tuple_positional_schema(
+        [
+            core_schema.parametrized_schema(generic_list, {'T': core_schema.bytes_schema()}),
+            core_schema.parametrized_schema(
+                core_schema.definition_reference_schema('GenericList'), {'T': core_schema.int_schema()}
+            ),
+        ]
+    )
+    with pytest.raises(SchemaError, match='Schema with ref `GenericList` contains type variable `T`'):
+        SchemaSerializer(schema)
@@ -0,0 +1,38 @@
+from pathlib import Path, PurePosixPath, PureWindowsPath
+
+import pytest
+
+from pydantic_core import SchemaSerializer, SchemaValidator, core_schema
+
+
+def test_path():
+    v = SchemaValidator(core_schema.path_schema())
+    s = SchemaSerializer(core_schema.path_schema())
+
+    path = v.validate_python('foo/bar.txt')
+    assert s.to_python(path) == path
+    assert s.to_python(path, mode='json') == str(path)
+    assert s.to_json(path) == f'"{path}"'.encode()
+
+    with pytest.warns(UserWarning, match='Expected `path` but got `str` - serialized value may not be as expected'):
+        assert s.to_python('foo/bar', mode='json') == 'foo/bar'
+
+
+def test_windows_path():
+    s = SchemaSerializer(core_schema.path_schema(flavor='windows'))
+    path = PureWindowsPath('C:/foo/bar.txt')
+    assert s.to_python(path, mode='json') == 'C:\\foo\\bar.txt'
+    assert s.to_json(path) == b'"C:\\\\foo\\\\bar.txt"'
+
+
+def test_posix_forward_slashes():
+    s = SchemaSerializer(core_schema.path_schema(flavor='posix'))
+    assert s.to_python(PurePosixPath('foo/bar'), mode='json') == 'foo/bar'
+    assert s.to_python(PureWindowsPath('foo\\bar'), mode='json') == 'foo/bar'
+    assert s.to_json(PureWindowsPath('foo\\bar')) == b'"foo/bar"'
+
+
+def test_dict_key():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.path_schema(flavor='posix'), core_schema.int_schema()))
+    assert s.to_python({PureWindowsPath('a\\b'): 1}, mode='json') == {'a/b': 1}
+    assert s.to_json({Path('a/b'): 1}) == b'{"a/b":1}'
@@ -0,0 +1,119 @@
+import re
+from datetime import datetime
+
+import pytest
+
+from pydantic_core import PydanticSerializationError, SchemaSerializer, core_schema
+
+
+class BasicModel:
+    def __init__(self, **kwargs):
+        for key, value in kwargs.items():
+            setattr(self, key, value)
+
+
+def model_serializer(fields_schema):
+    return SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.typed_dict_schema(
+                {name: core_schema.typed_dict_field(schema) for name, schema in fields_schema.items()}
+            ),
+        )
+    )
+
+
+def test_round_trip_ok():
+    s = model_serializer(
+        {
+            'id': core_schema.int_schema(),
+            'created': core_schema.datetime_schema(),
+            'tags': core_schema.list_schema(core_schema.str_schema()),
+            'data': core_schema.bytes_schema(),
+        }
+    )
+    m = BasicModel(id=1, created=datetime(2022, 1, 1, 12), tags=['a', 'b'], data=b'x')
+    assert s.to_python(m, round_trip_check=True) == {
+        'id': 1,
+        'created': datetime(2022, 1, 1, 12),
+        'tags': ['a', 'b'],
+        'data': b'x',
+    }
+    assert s.to_python(m, mode='json', round_trip_check=True) == {
+        'id': 1,
+        'created': '2022-01-01T12:00:00',
+        'tags': ['a', 'b'],
+        'data': 'x',
+    }
+    assert s.to_json(m, round_trip_check=True) == (
+        b'{"id":1,"created":"2022-01-01T12:00:00","tags":["a","b"],"data":"x"}'
+    )
+
+
+def test_invalid_output():
+    s = model_serializer(
+        {
+            'id': core_schema.int_schema(),
+            'created': core_schema.datetime_schema(
+                serialization=core_schema.plain_serializer_function_ser_schema(
+                    lambda v: v.strftime('%d/%m/%Y'), when_used='json'
+                )
+            ),
+        }
+    )
+    m = BasicModel(id=1, created=datetime(2022, 1, 31))
+    # no check by default
+    assert s.to_json(m) == b'{"id":1,"created":"31/01/2022"}'
+    assert s.to_python(m, mode='json') == {'id': 1, 'created': '31/01/2022'}
+
+    with pytest.raises(PydanticSerializationError) as exc_info:
+        s.to_json(m, round_trip_check=True)
+    assert str(exc_info.value).startswith('Round-trip check failed, the output is invalid:\n1 validation error for ')
+    assert re.search(r'\ncreated\n  Input should be a valid datetime', str(exc_info.value))
+
+    with pytest.raises(PydanticSerializationError, match=r'\ncreated\n  Input should be a valid datetime'):
+        s.to_python(m, mode='json', round_trip_check=True)
+
+    # python mode isn't affected by the `json` serializer
+    assert s.to_python(m, round_trip_check=True) == {'id': 1, 'created': datetime(2022, 1, 31)}
+
+
+def test_mismatch():
+    s = model_serializer(
+        {
+            'name': core_schema.str_schema(),
+            'scores': core_schema.list_schema(
+                core_schema.int_schema(
+                    serialization=core_schema.plain_serializer_function_ser_schema(lambda v: v * 2)
+                )
+            ),
+        }
+    )
+    m = BasicModel(name='x', scores=[0, 1])
+    assert s.to_python(m) == {'name': 'x', 'scores': [0, 2]}
+    with pytest.raises(
+        PydanticSerializationError,
+        match=r'^Round-trip check failed at `scores.1`, 2 was serialized as 4 after validating it$',
+    ):
+        s.to_python(m, round_trip_check=True)
+    with pytest.raises(
+        PydanticSerializationError,
+        match=r'^Round-trip check failed at `scores.1`, 2 was serialized as 4 after validating it$',
+    ):
+        s.to_json(m, round_trip_check=True)
+
+
+def test_mismatch_top_level():
+    s = SchemaSerializer(
+        core_schema.str_schema(serialization=core_schema.plain_serializer_function_ser_schema(lambda v: v + '!'))
+    )
+    assert s.to_python('a', round_trip_check=False) == 'a!'
+    with pytest.raises(
+        PydanticSerializationError, match=r"^Round-trip check failed, 'a!' was serialized as 'a!!' after validating it$"
+    ):
+        s.to_python('a', round_trip_check=True)
+
+
+def test_nan():
+    s = SchemaSerializer(core_schema.list_schema(core_schema.float_schema()))
+    assert s.to_python([1.5, float('nan')], round_trip_check=True)[0] == 1.5
@@ -0,0 +1,140 @@
+import re
+import warnings
+
+import pytest
+
+from pydantic_core import (
+    PydanticSerializationError,
+    PydanticSerializationUnexpectedValue,
+    SchemaError,
+    SchemaSerializer,
+    core_schema,
+)
+
+
+class Model:
+    def __init__(self, **kwargs):
+        self.__dict__.update(kwargs)
+
+
+def model_schema():
+    return core_schema.model_schema(
+        Model,
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.list_schema(core_schema.str_schema())),
+                'c': core_schema.typed_dict_field(
+                    core_schema.dict_schema(core_schema.bytes_schema(), core_schema.any_schema())
+                ),
+            }
+        ),
+    )
+
+
+def test_warn():
+    s = SchemaSerializer(model_schema())
+    m = Model(a='x', b=['y'], c={})
+    with pytest.warns(UserWarning, match='Expected `int` but got `str` - serialized value may not be as expected'):
+        assert s.to_python(m) == {'a': 'x', 'b': ['y'], 'c': {}}
+
+
+@pytest.mark.parametrize('config,kwargs', [({'ser_warnings': 'none'}, {}), ({}, {'warnings': 'none'})])
+def test_none(config, kwargs):
+    s = SchemaSerializer(model_schema(), config)
+    m = Model(a='x', b=[1], c={'k': 2})
+    with warnings.catch_warnings():
+        warnings.simplefilter('error')
+        assert s.to_python(m, **kwargs) == {'a': 'x', 'b': [1], 'c': {'k': 2}}
+        assert s.to_python(m, mode='json', **kwargs) == {'a': 'x', 'b': [1], 'c': {'k': 2}}
+        assert s.to_json(m, **kwargs) == b'{"a":"x","b":[1],"c":{"k":2}}'
+
+
+@pytest.mark.parametrize(
+    'value,message',
+    [
+        (Model(a='x', b=[], c={}), 'Expected `int` but got `str` in field `a`'),
+        (Model(a=1, b=['y', 2], c={}), 'Expected `str` but got `int` in field `b`'),
+        (Model(a=1, b=[], c={'k': 1}), 'Expected `bytes` but got `str` in field `c`'),
+        ('not a model', 'Expected `Model` but got `str`'),
+    ],
+    ids=['field', 'list-item', 'dict-key', 'model'],
+)
+@pytest.mark.parametrize('config,kwargs', [({'ser_warnings': 'error'}, {}), ({}, {'warnings': 'error'})])
+def test_error(value, message, config, kwargs):
+    s = SchemaSerializer(model_schema(), config)
+    match = f'^{re.escape(message)}$'
+    with pytest.raises(PydanticSerializationError, match=match):
+        s.to_python(value, **kwargs)
+    with pytest.raises(PydanticSerializationError, match=match):
+        s.to_python(value, mode='json', **kwargs)
+    with pytest.raises(PydanticSerializationError, match=match):
+        s.to_json(value, **kwargs)
+
+
+def test_error_valid_values():
+    s = SchemaSerializer(model_schema(), {'ser_warnings': 'error'})
+    m = Model(a=1, b=['y'], c={b'k': 'anything', b'n': None})
+    assert s.to_python(m) == {'a': 1, 'b': ['y'], 'c': {b'k': 'anything', b'n': None}}
+    assert s.to_json(m) == b'{"a":1,"b":["y"],"c":{"k":"anything","n":null}}'
+    # `None` is never reported, e.g. for defaults
+    assert s.to_python(Model(a=None, b=[], c={})) == {'a': None, 'b': [], 'c': {}}
+
+
+def test_error_union():
+    s = SchemaSerializer(
+        core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()]), {'ser_warnings': 'error'}
+    )
+    # union choices are checked without reporting mismatches
+    assert s.to_python('x') == 'x'
+    assert s.to_json(1) == b'1'
+    with pytest.raises(PydanticSerializationError, match=r'^Expected `Union\[int, str\]` but got `bytes`$'):
+        s.to_python(b'x')
+
+
+def test_error_tuple_length():
+    s = SchemaSerializer(
+        core_schema.tuple_positional_schema([core_schema.int_schema()]), {'ser_warnings': 'error'}
+    )
+    msg = r'^Expected `tuple\[int\]` with 1 items but got 2 - extra items serialized by inference$'
+    with pytest.raises(PydanticSerializationError, match=msg):
+        s.to_python((1, 2))
+    with pytest.raises(PydanticSerializationError, match=msg):
+        s.to_json((1, 2))
+
+
+def test_error_function_unexpected_value():
+    def f(value, _info):
+        raise PydanticSerializationUnexpectedValue('bad value')
+
+    s = SchemaSerializer(
+        core_schema.any_schema(serialization=core_schema.plain_serializer_function_ser_schema(f, info_arg=True)),
+        {'ser_warnings': 'error'},
+    )
+    msg = r'^PydanticSerializationUnexpectedValue\(bad value\)$'
+    with pytest.raises(PydanticSerializationError, match=msg):
+        s.to_python(1)
+    with pytest.raises(PydanticSerializationError, match=msg):
+        s.to_json(1)
+    assert s.to_python(1, warnings=False) == 1
+
+
+def test_per_call_override():
+    s = SchemaSerializer(core_schema.int_schema(), {'ser_warnings': 'error'})
+    assert s.to_python('x', warnings=False) == 'x'
+    with pytest.warns(UserWarning, match='Expected `int` but got `str`'):
+        assert s.to_json('x', warnings=True) == b'"x"'
+    with pytest.warns(UserWarning, match='Expected `int` but got `str`'):
+        assert s.to_json('x', warnings='warn') == b'"x"'
+
+    s = SchemaSerializer(core_schema.int_schema())
+    with pytest.raises(PydanticSerializationError, match='^Expected `int` but got `str`$'):
+        s.to_python('x', warnings='error')
+
+
+def test_invalid():
+    with pytest.raises(SchemaError, match='Invalid warnings mode: `foo`, expected `none`, `warn` or `error`'):
+        SchemaSerializer(core_schema.int_schema(), {'ser_warnings': 'foo'})
+    s = SchemaSerializer(core_schema.int_schema())
+    with pytest.raises(ValueError, match="Invalid warnings: 'foo', expected a bool, `'none'`, `'warn'` or `'error'`"):
+        s.to_python(1, warnings='foo')
@@ -0,0 +1,232 @@
+import json
+
+import pytest
+
+from pydantic_core import SchemaSerializer, core_schema
+
+
+class Model:
+    def __init__(self, **kwargs):
+        self.__dict__.update(kwargs)
+
+
+class Parent(Model):
+    pass
+
+
+class Child(Parent):
+    pass
+
+
+class ChildNoSerializer(Parent):
+    pass
+
+
+def parent_fields():
+    return {'x': core_schema.typed_dict_field(core_schema.int_schema(), serialization_alias='X')}
+
+
+Parent.__pydantic_serializer__ = SchemaSerializer(
+    core_schema.model_schema(Parent, core_schema.typed_dict_schema(parent_fields()))
+)
+Child.__pydantic_serializer__ = SchemaSerializer(
+    core_schema.model_schema(
+        Child,
+        core_schema.typed_dict_schema(
+            {
+                **parent_fields(),
+                'y': core_schema.typed_dict_field(core_schema.bytes_schema(), serialization_alias='Y'),
+            }
+        ),
+    )
+)
+
+
+def outer_schema(**field_kwargs):
+    return core_schema.typed_dict_schema(
+        {
+            'inner': core_schema.typed_dict_field(
+                core_schema.model_schema(Parent, core_schema.typed_dict_schema(parent_fields())), **field_kwargs
+            ),
+            'items': core_schema.typed_dict_field(
+                core_schema.list_schema(
+                    core_schema.model_schema(Parent, core_schema.typed_dict_schema(parent_fields()))
+                ),
+                required=False,
+            ),
+        }
+    )
+
+
+def test_off_by_default():
+    s = SchemaSerializer(outer_schema())
+    value = {'inner': Child(x=1, y=b'a')}
+    assert s.to_python(value) == {'inner': {'X': 1}}
+    assert s.to_json(value) == b'{"inner":{"X":1}}'
+
+
+def test_serialize_as_any():
+    s = SchemaSerializer(outer_schema())
+    value = {'inner': Child(x=1, y=b'a'), 'items': [Parent(x=2), Child(x=3, y=b'b')]}
+    assert s.to_python(value, serialize_as_any=True) == {
+        'inner': {'X': 1, 'Y': b'a'},
+        'items': [{'X': 2}, {'X': 3, 'Y': b'b'}],
+    }
+    assert s.to_python(value, serialize_as_any=True, by_alias=False, mode='json') == {
+        'inner': {'x': 1, 'y': 'a'},
+        'items': [{'x': 2}, {'x': 3, 'y': 'b'}],
+    }
+    assert s.to_json(value, serialize_as_any=True) == b'{"inner":{"X":1,"Y":"a"},"items":[{"X":2},{"X":3,"Y":"b"}]}'
+    assert json.loads(s.to_json(value, serialize_as_any=True)) == s.to_python(
+        value, serialize_as_any=True, mode='json'
+    )
+
+
+def test_subclass_without_serializer():
+    s = SchemaSerializer(outer_schema())
+    value = {'inner': ChildNoSerializer(x=1, y=b'a')}
+    # the class doesn't have its own serializer, so the schema's serializer is used as usual
+    assert s.to_python(value, serialize_as_any=True) == {'inner': {'X': 1}}
+    assert s.to_json(value, serialize_as_any=True) == b'{"inner":{"X":1}}'
+
+
+def test_plain_object():
+    class Duck:
+        def __init__(self):
+            self.x = 1
+            self.quack = b'loud'
+
+    s = SchemaSerializer(outer_schema())
+    value = {'inner': Duck()}
+    assert s.to_python(value) == {'inner': {'X': 1}}
+    assert s.to_python(value, serialize_as_any=True) == {'inner': {'x': 1, 'quack': b'loud'}}
+    assert s.to_json(value, serialize_as_any=True) == b'{"inner":{"x":1,"quack":"loud"}}'
+
+
+def test_include_exclude():
+    s = SchemaSerializer(outer_schema())
+    value = {'inner': Child(x=1, y=b'a'), 'items': [Child(x=2, y=b'b'), Child(x=3, y=b'c')]}
+    assert s.to_python(value, serialize_as_any=True, exclude={'inner': {'y'}, 'items': {0: {'x'}}}) == {
+        'inner': {'X': 1},
+        'items': [{'Y': b'b'}, {'X': 3, 'Y': b'c'}],
+    }
+    assert s.to_json(value, serialize_as_any=True, include={'inner': {'y'}}) == b'{"inner":{"Y":"a"}}'
+    assert s.to_python(value, serialize_as_any=True, exclude_paths=['items.*.y'], include_paths=['items']) == {
+        'items': [{'X': 2}, {'X': 3}]
+    }
+
+
+def test_field_serialize_as_any():
+    s = SchemaSerializer(outer_schema(serialize_as_any=True))
+    value = {'inner': Child(x=1, y=b'a'), 'items': [Child(x=2, y=b'b')]}
+    # only the field with `serialize_as_any` set
+    assert s.to_python(value) == {'inner': {'X': 1, 'Y': b'a'}, 'items': [{'X': 2}]}
+    assert s.to_json(value) == b'{"inner":{"X":1,"Y":"a"},"items":[{"X":2}]}'
+    assert s.to_python(value, serialize_as_any=True) == {'inner': {'X': 1, 'Y': b'a'}, 'items': [{'X': 2, 'Y': b'b'}]}
+
+
+def test_dataclass_field_serialize_as_any():
+    s = SchemaSerializer(
+        core_schema.dataclass_args_schema(
+            'Outer',
+            [
+                core_schema.dataclass_field(
+                    'inner',
+                    core_schema.model_schema(Parent, core_schema.typed_dict_schema(parent_fields())),
+                    serialize_as_any=True,
+                )
+            ],
+        )
+    )
+    assert s.to_python({'inner': Child(x=1, y=b'a')}) == {'inner': {'X': 1, 'Y': b'a'}}
+
+
+class Node(Model):
+    pass
+
+
+class SubNode(Node):
+    pass
+
+
+def node_schema(cls, **extra_fields):
+    return core_schema.definitions_schema(
+        core_schema.definition_reference_schema('node'),
+        [
+            core_schema.model_schema(
+                cls,
+                core_schema.typed_dict_schema(
+                    {
+                        'name': core_schema.typed_dict_field(core_schema.str_schema()),
+                        'child': core_schema.typed_dict_field(
+                            core_schema.nullable_schema(core_schema.definition_reference_schema('node'))
+                        ),
+                        **extra_fields,
+                    }
+                ),
+                ref='node',
+            )
+        ],
+    )
+
+
+SubNode.__pydantic_serializer__ = SchemaSerializer(
+    node_schema(SubNode, tag=core_schema.typed_dict_field(core_schema.str_schema()))
+)
+
+
+def test_recursive():
+    s = SchemaSerializer(node_schema(Node))
+    value = Node(name='a', child=SubNode(name='b', tag='t', child=Node(name='c', child=None)))
+    assert s.to_python(value, serialize_as_any=True) == {
+        'name': 'a',
+        'child': {'name': 'b', 'child': {'name': 'c', 'child': None}, 'tag': 't'},
+    }
+    assert s.to_json(value, serialize_as_any=True) == (
+        b'{"name":"a","child":{"name":"b","tag":"t","child":{"name":"c","child":null}}}'
+    )
+
+
+def test_recursive_cycle():
+    s = SchemaSerializer(node_schema(Node))
+    value = SubNode(name='a', tag='t', child=None)
+    value.child = value
+    with pytest.raises(ValueError, match=r'Circular reference detected \(id repeated\)'):
+        s.to_python(value, serialize_as_any=True)
+    with pytest.raises(ValueError, match=r'Circular reference detected \(id repeated\)'):
+        s.to_json(value, serialize_as_any=True)
+
+
+def test_recursive_cycle_inline():
+    # `Parent` isn't recursive, but `SelfChild` contains itself via a `Parent` field
+    class SelfChild(Parent):
+        pass
+
+    SelfChild.__pydantic_serializer__ = SchemaSerializer(
+        core_schema.model_schema(
+            SelfChild,
+            core_schema.typed_dict_schema(
+                {
+                    **parent_fields(),
+                    'other': core_schema.typed_dict_field(
+                        core_schema.model_schema(Parent, core_schema.typed_dict_schema(parent_fields()))
+                    ),
+                }
+            ),
+        )
+    )
+    value = SelfChild(x=1, other=None)
+    value.other = value
+    s = SchemaSerializer(outer_schema())
+    with pytest.raises(ValueError, match=r"Circular reference detected \(id repeated\) in 'Parent'"):
+        s.to_python({'inner': value}, serialize_as_any=True)
+    with pytest.raises(ValueError, match=r"Circular reference detected \(id repeated\) in 'Parent'"):
+        s.to_json({'inner': value}, serialize_as_any=True)
+    # without `serialize_as_any`, the `other` field isn't serialized
+    assert s.to_python({'inner': value}) == {'inner': {'X': 1}}
+
+
+def test_warning_runtime_type():
+    s = SchemaSerializer(outer_schema())
+    with pytest.warns(UserWarning, match='Expected `Parent` but got `int` - serialized value may not be as expected'):
+        assert s.to_python({'inner': 123}, serialize_as_any=True) == {'inner': 123}
@@ -1,9 +1,10 @@
 import json
+from decimal import Decimal
 from enum import IntEnum
 
 import pytest
 
-from pydantic_core import SchemaSerializer
+from pydantic_core import SchemaError, SchemaSerializer, SchemaValidator, core_schema, to_json, to_jsonable_python
 
 
 class IntSubClass(int):
@@ -70,3 +71,107 @@ def test_simple_serializers_fallback(schema_type):
         UserWarning, match=f'Expected `{schema_type}` but got `list` - serialized value may not be as expected'
     ):
         assert s.to_json([1, 2, 3]) == b'[1,2,3]'
+
+
+@pytest.mark.parametrize('schema_type', ['float', 'any'])
+@pytest.mark.parametrize(
+    'mode,expected_python,expected_json',
+    [
+        (None, [None, None, None], b'[null,null,null]'),
+        ('null', [None, None, None], b'[null,null,null]'),
+        ('constants', [float('inf'), float('-inf'), float('nan')], b'[Infinity,-Infinity,NaN]'),
+        ('strings', ['inf', '-inf', 'nan'], b'["inf","-inf","nan"]'),
+    ],
+)
+def test_ser_json_inf_nan(schema_type, mode, expected_python, expected_json):
+    config = {} if mode is None else {'ser_json_inf_nan': mode}
+    s = SchemaSerializer(core_schema.list_schema({'type': schema_type}), config)
+    value = [float('inf'), float('-inf'), float('nan')]
+    assert s.to_json(value) == expected_json
+    # `repr` since NaN isn't equal to itself
+    assert repr(s.to_python(value, mode='json')) == repr(expected_python)
+    assert json.dumps(s.to_python(value, mode='json'), separators=(',', ':')).encode() == expected_json
+    assert repr(s.to_python(value)) == repr(value)
+
+    # finite floats and subclasses are unaffected
+    assert s.to_json([1.5, FloatSubClass(2)]) == b'[1.5,2.0]'
+    assert s.to_json([FloatSubClass('inf')]) == expected_json.split(b',')[0] + b']'
+
+
+def test_ser_json_inf_nan_indent():
+    s = SchemaSerializer(
+        core_schema.dict_schema(values_schema=core_schema.float_schema()), {'ser_json_inf_nan': 'constants'}
+    )
+    assert s.to_json({'a': float('inf')}, indent=2) == b'{\n  "a": Infinity\n}'
+
+
+@pytest.mark.parametrize(
+    'mode,expected_json',
+    [('null', b'{"a":null,"b":[1.5,null]}'), ('constants', b'{"a":NaN,"b":[1.5,-Infinity]}')],
+)
+def test_ser_json_inf_nan_nested(mode, expected_json):
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(
+                    core_schema.union_schema([core_schema.int_schema(), core_schema.float_schema()])
+                ),
+                'b': core_schema.typed_dict_field(core_schema.any_schema()),
+            }
+        ),
+        {'ser_json_inf_nan': mode},
+    )
+    value = {'a': float('nan'), 'b': (1.5, float('-inf'))}
+    assert s.to_json(value) == expected_json
+    assert json.dumps(s.to_python(value, mode='json'), separators=(',', ':')).encode() == expected_json
+
+
+@pytest.mark.parametrize(
+    'mode,expected_json',
+    [
+        ('null', b'["1.5",null,null,null]'),
+        ('constants', b'["1.5",Infinity,-Infinity,NaN]'),
+        ('strings', b'["1.5","inf","-inf","nan"]'),
+    ],
+)
+def test_ser_json_inf_nan_decimal(mode, expected_json):
+    s = SchemaSerializer(core_schema.list_schema(core_schema.decimal_schema()), {'ser_json_inf_nan': mode})
+    value = [Decimal('1.5'), Decimal('Infinity'), Decimal('-Infinity'), Decimal('NaN')]
+    assert s.to_json(value) == expected_json
+    assert json.dumps(s.to_python(value, mode='json'), separators=(',', ':')).encode() == expected_json
+
+
+def test_ser_json_inf_nan_round_trip():
+    s = SchemaSerializer(core_schema.list_schema(core_schema.float_schema()), {'ser_json_inf_nan': 'strings'})
+    v = SchemaValidator(core_schema.list_schema(core_schema.float_schema(allow_inf_nan=True)))
+    value = [1.5, float('inf'), float('-inf'), float('nan')]
+    assert repr(v.validate_json(s.to_json(value))) == repr(value)
+    assert repr(v.validate_python(s.to_python(value, mode='json'))) == repr(value)
+
+    s = SchemaSerializer(core_schema.list_schema(core_schema.decimal_schema()), {'ser_json_inf_nan': 'strings'})
+    v = SchemaValidator(core_schema.list_schema(core_schema.decimal_schema(allow_inf_nan=True)))
+    value = [Decimal('1.5'), Decimal('Infinity'), Decimal('-Infinity')]
+    assert v.validate_json(s.to_json(value)) == value
+    assert v.validate_json(s.to_json([Decimal('NaN')]))[0].is_nan()
+
+
+def test_to_json_inf_nan_mode():
+    assert to_json([float('inf')]) == b'[null]'
+    assert to_json([float('inf')], inf_nan_mode='constants') == b'[Infinity]'
+    assert to_jsonable_python([float('nan')], inf_nan_mode='strings') == ['nan']
+
+
+def test_ser_json_inf_nan_invalid():
+    with pytest.raises(SchemaError, match='Invalid inf_nan serialization mode: `foo`'):
+        SchemaSerializer(core_schema.float_schema(), {'ser_json_inf_nan': 'foo'})
+
+
+def test_to_json_inf_nan_mode_invalid():
+    msg = 'Invalid inf_nan serialization mode: `bogus`, expected `null`, `constants` or `strings`'
+    with pytest.raises(ValueError, match=msg) as exc_info:
+        to_json([float('inf')], inf_nan_mode='bogus')
+    assert not isinstance(exc_info.value, SchemaError)
+    with pytest.raises(ValueError, match=msg):
+        to_jsonable_python([float('inf')], inf_nan_mode='bogus')
+    with pytest.raises(ValueError, match='Invalid timedelta serialization mode: `bogus`'):
+        to_json([1], timedelta_mode='bogus')
@@ -2,7 +2,7 @@ from datetime import timedelta
 
 import pytest
 
-from pydantic_core import SchemaSerializer, core_schema
+from pydantic_core import SchemaSerializer, core_schema, to_json
 
 
 def test_timedelta():
@@ -31,6 +31,25 @@ def test_timedelta_float():
     assert v.to_json(timedelta(seconds=4, microseconds=500_000)) == b'4.5'
 
 
+def test_timedelta_seconds_float():
+    v = SchemaSerializer(core_schema.timedelta_schema(), config={'ser_json_timedelta': 'seconds_float'})
+    assert v.to_python(timedelta(seconds=12, microseconds=500_000), mode='json') == 12.5
+    assert v.to_json(timedelta(seconds=12, microseconds=500_000)) == b'12.5'
+    assert v.to_json(timedelta(days=-1, seconds=86_399, microseconds=999_999)) == b'-1e-6'
+
+    v = SchemaSerializer(
+        core_schema.dict_schema(core_schema.timedelta_schema(), core_schema.int_schema()),
+        config={'ser_json_timedelta': 'seconds_float'},
+    )
+    assert v.to_json({timedelta(minutes=1, microseconds=500): 1}) == b'{"60.0005":1}'
+
+
+def test_timedelta_seconds_float_arg():
+    v = SchemaSerializer(core_schema.any_schema())
+    assert v.to_json(timedelta(seconds=12, microseconds=500_000)) == b'"PT12.5S"'
+    assert to_json(timedelta(seconds=12, microseconds=500_000), timedelta_mode='seconds_float') == b'12.5'
+
+
 def test_timedelta_key():
     v = SchemaSerializer(core_schema.dict_schema(core_schema.timedelta_schema(), core_schema.int_schema()))
     assert v.to_python({timedelta(days=2, hours=3, minutes=4): 1}) == {timedelta(days=2, hours=3, minutes=4): 1}
@@ -0,0 +1,31 @@
+from datetime import timedelta, timezone
+from zoneinfo import ZoneInfo
+
+import pytest
+
+from pydantic_core import SchemaSerializer, SchemaValidator, core_schema
+
+
+def test_timezone():
+    v = SchemaValidator(core_schema.timezone_schema())
+    s = SchemaSerializer(core_schema.timezone_schema())
+
+    tz = v.validate_python('America/New_York')
+    assert s.to_python(tz) is tz
+    assert s.to_python(tz, mode='json') == 'America/New_York'
+    assert s.to_json(tz) == b'"America/New_York"'
+
+    with pytest.warns(UserWarning, match='Expected `timezone` but got `str` - serialized value may not be as expected'):
+        assert s.to_python('UTC', mode='json') == 'UTC'
+
+
+def test_fixed_offset():
+    s = SchemaSerializer(core_schema.timezone_schema())
+    assert s.to_python(timezone.utc, mode='json') == 'UTC'
+    assert s.to_json(timezone(timedelta(hours=2))) == b'"UTC+02:00"'
+
+
+def test_dict_key():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.timezone_schema(), core_schema.int_schema()))
+    assert s.to_python({ZoneInfo('Europe/Paris'): 1}, mode='json') == {'Europe/Paris': 1}
+    assert s.to_json({ZoneInfo('Europe/Paris'): 1}) == b'{"Europe/Paris":1}'
@@ -318,3 +318,103 @@ def test_function_wrap_field_serializer_to_json_no_info():
         )
     )
     assert json.loads(s.to_json(Model(x=1000))) == {'x': '1_000'}
+
+
+def test_exclude_default_factory():
+    calls = []
+
+    def factory():
+        calls.append(1)
+        return [1, 2]
+
+    schema = core_schema.typed_dict_schema(
+        {
+            'foo': core_schema.typed_dict_field(core_schema.int_schema()),
+            'bar': core_schema.typed_dict_field(
+                core_schema.with_default_schema(
+                    core_schema.list_schema(core_schema.int_schema()), default_factory=factory
+                )
+            ),
+        }
+    )
+    v = SchemaSerializer(schema)
+    assert v.to_python({'foo': 1, 'bar': [1, 2]}, exclude_defaults=True) == {'foo': 1}
+    assert v.to_python({'foo': 1, 'bar': [1, 2]}, exclude_defaults=True, mode='json') == {'foo': 1}
+    assert v.to_json({'foo': 1, 'bar': [1, 2]}, exclude_defaults=True) == b'{"foo":1}'
+    assert v.to_python({'foo': 1, 'bar': [1]}, exclude_defaults=True) == {'foo': 1, 'bar': [1]}
+    assert len(calls) == 4
+    # the factory is only called with `exclude_defaults`
+    assert v.to_python({'foo': 1, 'bar': [1, 2]}) == {'foo': 1, 'bar': [1, 2]}
+    assert len(calls) == 4
+
+    v = SchemaSerializer(schema, {'ser_call_default_factory': False})
+    assert v.to_python({'foo': 1, 'bar': [1, 2]}, exclude_defaults=True) == {'foo': 1, 'bar': [1, 2]}
+    assert v.to_json({'foo': 1, 'bar': [1, 2]}, exclude_defaults=True) == b'{"foo":1,"bar":[1,2]}'
+    assert len(calls) == 4
+
+
+def test_exclude_default_validated():
+    schema = core_schema.typed_dict_schema(
+        {
+            'foo': core_schema.typed_dict_field(
+                core_schema.with_default_schema(core_schema.int_schema(), default='1', validate_default=True)
+            ),
+            'bar': core_schema.typed_dict_field(
+                core_schema.with_default_schema(
+                    core_schema.tuple_variable_schema(core_schema.int_schema()),
+                    default_factory=lambda: ['1', '2'],
+                    validate_default=True,
+                )
+            ),
+        }
+    )
+    v = SchemaSerializer(schema)
+    assert v.to_python({'foo': 1, 'bar': (1, 2)}, exclude_defaults=True) == {}
+    assert v.to_python({'foo': 2, 'bar': (1,)}, exclude_defaults=True) == {'foo': 2, 'bar': (1,)}
+    assert v.to_json({'foo': 1, 'bar': (1, 2)}, exclude_defaults=True) == b'{}'
+
+    # without `validate_default` values are compared with the default as it is
+    v = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'foo': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.int_schema(), default='1')
+                )
+            }
+        )
+    )
+    assert v.to_python({'foo': 1}, exclude_defaults=True) == {'foo': 1}
+    assert v.to_python({'foo': '1'}, exclude_defaults=True) == {}
+
+
+def test_exclude_default_type():
+    v = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {'foo': core_schema.typed_dict_field(core_schema.with_default_schema(core_schema.any_schema(), default=1))}
+        )
+    )
+    assert v.to_python({'foo': 1}, exclude_defaults=True) == {}
+    # equal values of a different type serialize differently, so aren't excluded
+    assert v.to_python({'foo': True}, exclude_defaults=True) == {'foo': True}
+    assert v.to_json({'foo': True}, exclude_defaults=True) == b'{"foo":true}'
+    assert v.to_python({'foo': 1.0}, exclude_defaults=True, mode='json') == {'foo': 1.0}
+
+
+def test_exclude_default_eq_error():
+    class BadEq:
+        def __eq__(self, other):
+            raise ValueError('no comparing')
+
+    default = BadEq()
+    v = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'foo': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.any_schema(), default=default)
+                )
+            }
+        )
+    )
+    assert v.to_python({'foo': default}, exclude_defaults=True) == {}
+    value = BadEq()
+    assert v.to_python({'foo': value}, exclude_defaults=True) == {'foo': value}
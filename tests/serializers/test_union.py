@@ -271,3 +271,13 @@ def test_typed_dict_different_fields():
     assert s.to_python(dict(spam=1, ham=2)) == {'spam': 1, 'ham': 2}
     assert s.to_python(dict(spam=1, ham=2), mode='json') == {'spam': 1, 'ham': '0002'}
     assert s.to_json(dict(spam=1, ham=2)) == b'{"spam":1,"ham":"0002"}'
+
+
+def test_union_choice_options():
+    s = SchemaSerializer(
+        core_schema.union_schema(
+            [core_schema.union_choice(core_schema.bytes_schema(), priority=1), core_schema.int_schema()]
+        )
+    )
+    assert s.to_python(b'x', mode='json') == 'x'
+    assert s.to_json(123) == b'123'
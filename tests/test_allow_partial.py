@@ -0,0 +1,182 @@
+import json
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+inner_schema = core_schema.typed_dict_schema(
+    {
+        'name': core_schema.typed_dict_field(core_schema.str_schema()),
+        'tags': core_schema.typed_dict_field(core_schema.list_schema(core_schema.str_schema(), min_length=2)),
+    }
+)
+reference_schema = core_schema.typed_dict_schema(
+    {
+        'id': core_schema.typed_dict_field(core_schema.int_schema()),
+        'score': core_schema.typed_dict_field(core_schema.float_schema()),
+        'active': core_schema.typed_dict_field(core_schema.bool_schema()),
+        'note': core_schema.typed_dict_field(core_schema.nullable_schema(core_schema.str_schema())),
+        'items': core_schema.typed_dict_field(core_schema.list_schema(inner_schema)),
+        'counts': core_schema.typed_dict_field(
+            core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema())
+        ),
+        'pair': core_schema.typed_dict_field(
+            core_schema.tuple_positional_schema([core_schema.int_schema(), core_schema.str_schema()])
+        ),
+    }
+)
+reference = {
+    'id': 12345,
+    'score': -1.5e3,
+    'active': True,
+    'note': None,
+    'items': [{'name': 'first "one"', 'tags': ['a', 'b\\c']}, {'name': 'second', 'tags': ['x', 'y', 'z']}],
+    'counts': {'x': 1, 'yy': 22},
+    'pair': [7, 'seven'],
+}
+
+
+def check_prefix(partial, complete):
+    """
+    `partial` must agree with `complete`, only the last value of a container may itself be incomplete
+    """
+    if isinstance(complete, dict):
+        assert isinstance(partial, dict)
+        keys = list(partial)
+        assert keys == list(complete)[: len(keys)]
+        for key in keys[:-1]:
+            assert partial[key] == complete[key]
+        if keys:
+            check_prefix(partial[keys[-1]], complete[keys[-1]])
+    elif isinstance(complete, (list, tuple)):
+        assert isinstance(partial, type(complete))
+        if partial:
+            assert list(partial[:-1]) == list(complete[: len(partial) - 1])
+            check_prefix(partial[-1], complete[len(partial) - 1])
+    else:
+        # scalars are either dropped or complete
+        assert partial == complete
+
+
+@pytest.mark.parametrize('indent', [None, 2])
+def test_every_prefix(indent):
+    v = SchemaValidator(reference_schema)
+    expected = v.validate_python(reference)
+    doc = json.dumps(reference, indent=indent)
+    for end in range(1, len(doc) + 1):
+        output = v.validate_json(doc[:end], allow_partial=True)
+        check_prefix(output, expected)
+    assert output == expected
+    assert v.validate_json(doc) == expected
+
+
+def test_truncated_scalars_dropped():
+    v = SchemaValidator(core_schema.list_schema(core_schema.any_schema()))
+    assert v.validate_json('[1, 23', allow_partial=True) == [1]
+    assert v.validate_json('[1, 23]', allow_partial=True) == [1, 23]
+    assert v.validate_json('["ab", "cd', allow_partial=True) == ['ab']
+    assert v.validate_json('["ab", tr', allow_partial=True) == ['ab']
+    assert v.validate_json('["ab", true', allow_partial=True) == ['ab', True]
+    assert v.validate_json('["ab", -', allow_partial=True) == ['ab']
+    assert v.validate_json('[[1, [2', allow_partial=True) == [[1, []]]
+
+
+def test_missing_suppressed():
+    v = SchemaValidator(inner_schema)
+    assert v.validate_json('{"name": "x", "ta', allow_partial=True) == {'name': 'x'}
+    assert v.validate_json('{"name": "x", "tags": ["a"', allow_partial=True) == {'name': 'x', 'tags': ['a']}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"name": "x"}')
+    assert exc_info.value.errors() == [
+        {'type': 'missing', 'loc': ('tags',), 'msg': 'Field required', 'input': {'name': 'x'}}
+    ]
+
+
+def test_complete_json_unchanged():
+    v = SchemaValidator(inner_schema)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"name": "x", "tags": ["a"]}', allow_partial=True)
+    assert exc_info.value.errors() == [
+        {
+            'type': 'too_short',
+            'loc': ('tags',),
+            'msg': 'List should have at least 2 items after validation, not 1',
+            'input': ['a'],
+            'ctx': {'field_type': 'List', 'min_length': 2, 'actual_length': 1},
+        }
+    ]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"name": "x"}', allow_partial=True)
+    assert exc_info.value.errors()[0]['type'] == 'missing'
+
+
+def test_only_trailing_position_forgiven():
+    v = SchemaValidator(core_schema.list_schema(inner_schema))
+    # the first item is complete, so its missing field is still an error
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[{"name": "x"}, {"name": "y', allow_partial=True)
+    assert exc_info.value.errors() == [
+        {'type': 'missing', 'loc': (0, 'tags'), 'msg': 'Field required', 'input': {'name': 'x'}}
+    ]
+    # the same goes for a value which isn't the last one in a typed dict
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[{"tags": ["a"], "name": "y', allow_partial=True)
+    assert exc_info.value.errors()[0]['type'] == 'too_short'
+    assert exc_info.value.errors()[0]['loc'] == (0, 'tags')
+
+
+def test_invalid_complete_values():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[1, "x", 3', allow_partial=True)
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': (1,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+
+@pytest.mark.parametrize('input_value', ['', '[1, }', '[1, 2]]', '{"a" 1'])
+def test_invalid_json(input_value):
+    v = SchemaValidator(core_schema.any_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(input_value, allow_partial=True)
+    assert exc_info.value.errors()[0]['type'] == 'json_invalid'
+
+
+def test_dict():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.str_schema(), inner_schema, min_length=2))
+    assert v.validate_json('{"a": {"name": "x", "tags": ["p", "q"]}, "b": {"na', allow_partial=True) == {
+        'a': {'name': 'x', 'tags': ['p', 'q']},
+        'b': {},
+    }
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"a": {"name": "x"}, "b": {"na', allow_partial=True)
+    assert exc_info.value.errors()[0]['loc'] == ('a', 'tags')
+
+
+def test_tuple_positional():
+    v = SchemaValidator(core_schema.tuple_positional_schema([core_schema.int_schema(), core_schema.str_schema()]))
+    assert v.validate_json('[1', allow_partial=True) == ()
+    assert v.validate_json('[1, ', allow_partial=True) == (1,)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[1]', allow_partial=True)
+    assert exc_info.value.errors() == [{'type': 'missing', 'loc': (1,), 'msg': 'Field required', 'input': [1]}]
+
+
+def test_validate_python():
+    v = SchemaValidator(core_schema.list_schema(inner_schema, min_length=3))
+    assert v.validate_python([{'name': 'x', 'tags': ['a', 'b']}, {'name': 'y'}], allow_partial=True) == [
+        {'name': 'x', 'tags': ['a', 'b']},
+        {'name': 'y'},
+    ]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([{'name': 'x'}, {'name': 'y'}], allow_partial=True)
+    assert exc_info.value.errors() == [
+        {'type': 'missing', 'loc': (0, 'tags'), 'msg': 'Field required', 'input': {'name': 'x'}}
+    ]
+    with pytest.raises(ValidationError):
+        v.validate_python([{'name': 'x', 'tags': ['a', 'b']}, {'name': 'y'}])
@@ -0,0 +1,85 @@
+import json
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, core_schema
+
+short = 'short value é'
+long = 'long value ' + 'x' * 60
+payload = json.dumps([{'ключ': short, 'key ' + 'k' * 64: long}] * 3)
+
+
+def outputs_share(output, get):
+    return all(get(item) is get(output[0]) for item in output[1:])
+
+
+def first_key(item):
+    return list(item)[0]
+
+
+def long_key(item):
+    return list(item)[1]
+
+
+@pytest.mark.parametrize(
+    'cache_strings,keys_cached,values_cached',
+    [(None, True, True), ('all', True, True), ('keys', True, False), ('none', False, False)],
+)
+@pytest.mark.parametrize(
+    'item_schema',
+    [
+        core_schema.any_schema(),
+        core_schema.dict_schema(core_schema.str_schema(), core_schema.str_schema()),
+        core_schema.typed_dict_schema({}, extra_behavior='allow', extra_validator=core_schema.str_schema()),
+    ],
+    ids=['any', 'dict', 'typed-dict-extra'],
+)
+def test_cache_strings(cache_strings, keys_cached, values_cached, item_schema):
+    config = {} if cache_strings is None else {'cache_strings': cache_strings}
+    v = SchemaValidator(core_schema.list_schema(item_schema), config)
+    output = v.validate_json(payload)
+    assert output == json.loads(payload)
+
+    assert outputs_share(output, first_key) is keys_cached
+    assert outputs_share(output, lambda item: item['ключ']) is values_cached
+    # strings longer than 64 bytes are never cached
+    assert outputs_share(output, long_key) is False
+    assert outputs_share(output, lambda item: item[long_key(item)]) is False
+
+
+def test_python_input_unchanged():
+    v = SchemaValidator(core_schema.list_schema(core_schema.str_schema()))
+    value = 'foo' * 3
+    output = v.validate_python([value, value])
+    assert output[0] is value and output[1] is value
+    output = v.validate_python([b'foobar', b'foobar'])
+    assert output == ['foobar', 'foobar']
+    assert output[0] is not output[1]
+
+
+def test_cached_across_calls():
+    v = SchemaValidator(core_schema.str_schema())
+    assert v.validate_json('"foobar"') is v.validate_json('"foobar"')
+    v = SchemaValidator(core_schema.str_schema(), {'cache_strings': 'none'})
+    assert v.validate_json('"foobar"') is not v.validate_json('"foobar"')
+
+
+def test_many_strings():
+    # far more distinct strings than the cache holds, each still comes back correctly
+    strings = [f'string {i}' for i in range(50_000)]
+    v = SchemaValidator(core_schema.list_schema(core_schema.str_schema()))
+    assert v.validate_json(json.dumps(strings + strings)) == strings + strings
+
+
+def test_constrained_str():
+    v = SchemaValidator(core_schema.list_schema(core_schema.str_schema(max_length=50)))
+    output = v.validate_json('["foobar", "foobar"]')
+    assert output == ['foobar', 'foobar']
+    assert output[0] is output[1]
+    v = SchemaValidator(core_schema.list_schema(core_schema.str_schema(to_upper=True)))
+    assert v.validate_json('["foobar", "foobar"]') == ['FOOBAR', 'FOOBAR']
+
+
+def test_invalid_config():
+    with pytest.raises(SchemaError, match='Invalid cache_strings: `sometimes`, expected `all`, `keys` or `none`'):
+        SchemaValidator(core_schema.str_schema(), {'cache_strings': 'sometimes'})
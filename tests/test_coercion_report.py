@@ -0,0 +1,232 @@
+from datetime import datetime
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+def test_coercion_report():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'id': core_schema.typed_dict_field(core_schema.int_schema()),
+                'name': core_schema.typed_dict_field(core_schema.str_schema()),
+                'scores': core_schema.typed_dict_field(core_schema.list_schema(core_schema.float_schema())),
+                'created': core_schema.typed_dict_field(core_schema.datetime_schema()),
+            }
+        )
+    )
+    # ints are valid floats in strict mode, so aren't reported
+    output, report = v.validate_python_with_report(
+        {'id': '123', 'name': 'x', 'scores': [1.5, 2, '3'], 'created': '2022-01-01T00:00:00'}
+    )
+    assert output == {'id': 123, 'name': 'x', 'scores': [1.5, 2, 3], 'created': datetime(2022, 1, 1)}
+    assert report == [
+        {'type': 'int_from_str', 'loc': ('id',), 'input_type': 'str', 'target_type': 'int'},
+        {'type': 'float_from_str', 'loc': ('scores', 2), 'input_type': 'str', 'target_type': 'float'},
+        {'type': 'datetime_from_str', 'loc': ('created',), 'input_type': 'str', 'target_type': 'datetime'},
+    ]
+
+
+def test_no_coercions():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    assert v.validate_python_with_report([1, 2, 3]) == ([1, 2, 3], [])
+    # in strict mode there's nothing to report, invalid values raise errors instead
+    assert v.validate_python_with_report([1, 2], strict=True) == ([1, 2], [])
+    with pytest.raises(ValidationError):
+        v.validate_python_with_report(['1'], strict=True)
+
+
+def test_dict_keys():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.int_schema(), core_schema.bool_schema()))
+    assert v.validate_python_with_report({'1': 'yes'}) == (
+        {1: True},
+        [
+            {'type': 'int_from_str', 'loc': ('1', '[key]'), 'input_type': 'str', 'target_type': 'int'},
+            {'type': 'bool_from_str', 'loc': ('1',), 'input_type': 'str', 'target_type': 'bool'},
+        ],
+    )
+
+
+def test_union_losing_choices():
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [
+                core_schema.typed_dict_schema(
+                    {
+                        'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                        'b': core_schema.typed_dict_field(core_schema.int_schema()),
+                    }
+                ),
+                core_schema.typed_dict_schema(
+                    {
+                        'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                        'b': core_schema.typed_dict_field(core_schema.str_schema()),
+                    }
+                ),
+            ]
+        )
+    )
+    # the first choice coerces `a` before failing on `b`, only the second choice's coercion is reported
+    output, report = v.validate_python_with_report({'a': '1', 'b': 'x'})
+    assert output == {'a': 1, 'b': 'x'}
+    assert report == [
+        {'type': 'int_from_str', 'loc': ('typed-dict', 'a'), 'input_type': 'str', 'target_type': 'int'}
+    ]
+
+
+def test_union_exact_match():
+    v = SchemaValidator(core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()]))
+    assert v.validate_python_with_report('1') == ('1', [])
+    assert v.validate_python_with_report(1.0) == (
+        1,
+        [{'type': 'int_from_float', 'loc': ('int',), 'input_type': 'float', 'target_type': 'int'}],
+    )
+
+
+def test_union_closest_match():
+    v = SchemaValidator(core_schema.union_schema([core_schema.bool_schema(), core_schema.float_schema()]))
+    # the coercion made by the lax `bool` match is dropped when the strict `float` match is used
+    assert v.validate_python_with_report(1) == (1.0, [])
+    out, report = v.validate_python_with_report('1')
+    assert out is True
+    assert report == [{'type': 'bool_from_str', 'loc': ('bool',), 'input_type': 'str', 'target_type': 'bool'}]
+
+
+def test_on_error_omit():
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.with_default_schema(
+                core_schema.tuple_positional_schema([core_schema.int_schema(), core_schema.int_schema()]),
+                on_error='omit',
+            )
+        )
+    )
+    output, report = v.validate_python_with_report([('1', 'x'), (2, '3')])
+    assert output == [(2, 3)]
+    assert report == [{'type': 'int_from_str', 'loc': (1, 1), 'input_type': 'str', 'target_type': 'int'}]
+
+
+def test_validation_error():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python_with_report(['1', 'x'])
+
+
+def test_any_record_types():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.any_schema(record_types=True)),
+                'b': core_schema.typed_dict_field(core_schema.list_schema(core_schema.any_schema(record_types=True))),
+                'c': core_schema.typed_dict_field(core_schema.any_schema()),
+            }
+        )
+    )
+    output, report = v.validate_python_with_report({'a': 1, 'b': ['x', None], 'c': 1.5})
+    assert output == {'a': 1, 'b': ['x', None], 'c': 1.5}
+    assert report == [
+        {'type': 'any_from_int', 'loc': ('a',), 'input_type': 'int', 'target_type': 'any'},
+        {'type': 'any_from_str', 'loc': ('b', 0), 'input_type': 'str', 'target_type': 'any'},
+        {'type': 'any_from_NoneType', 'loc': ('b', 1), 'input_type': 'NoneType', 'target_type': 'any'},
+    ]
+    # types are only recorded when validating with a report
+    assert v.validate_python({'a': 1, 'b': [], 'c': 1.5}) == {'a': 1, 'b': [], 'c': 1.5}
+
+
+def test_report_defaults():
+    class Inner:
+        pass
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'host': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.str_schema(), default='localhost')
+                ),
+                'port': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.int_schema(), default=8000)
+                ),
+                'tags': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.list_schema(), default_factory=list)
+                ),
+                'inner': core_schema.typed_dict_field(
+                    core_schema.model_schema(
+                        Inner,
+                        core_schema.typed_dict_schema(
+                            {
+                                'debug': core_schema.typed_dict_field(
+                                    core_schema.with_default_schema(core_schema.bool_schema(), default=False)
+                                ),
+                                'level': core_schema.typed_dict_field(core_schema.int_schema()),
+                            }
+                        ),
+                    )
+                ),
+            }
+        )
+    )
+    output, report = v.validate_python_with_report({'port': '80', 'inner': {'level': 1}}, report_defaults=True)
+    assert output['host'] == 'localhost'
+    assert output['port'] == 80
+    assert output['tags'] == []
+    assert output['inner'].__dict__ == {'debug': False, 'level': 1}
+    assert report == [
+        {'type': 'default', 'loc': ('host',)},
+        {'type': 'int_from_str', 'loc': ('port',), 'input_type': 'str', 'target_type': 'int'},
+        {'type': 'default_factory', 'loc': ('tags',)},
+        {'type': 'default', 'loc': ('inner', 'debug')},
+    ]
+
+    # defaults are only reported when requested
+    output, report = v.validate_python_with_report({'port': '80', 'inner': {'level': 1}})
+    assert report == [{'type': 'int_from_str', 'loc': ('port',), 'input_type': 'str', 'target_type': 'int'}]
+
+
+def test_report_defaults_validate_default():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'a': core_schema.typed_dict_field(core_schema.with_default_schema(core_schema.int_schema(), default='1'))}
+        ),
+        {'validate_default': True},
+    )
+    output, report = v.validate_python_with_report({}, report_defaults=True)
+    assert output == {'a': 1}
+    # coercions made validating the default are reported at the field's location
+    assert report == [
+        {'type': 'default', 'loc': ('a',)},
+        {'type': 'int_from_str', 'loc': ('a',), 'input_type': 'str', 'target_type': 'int'},
+    ]
+
+
+def test_report_defaults_on_error():
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.with_default_schema(core_schema.int_schema(), default=0, on_error='default')
+        )
+    )
+    output, report = v.validate_python_with_report([1, 'x'], report_defaults=True)
+    assert output == [1, 0]
+    assert report == [{'type': 'default', 'loc': (1,)}]
+
+
+def test_report_defaults_union_losing_choice():
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [
+                core_schema.typed_dict_schema(
+                    {
+                        'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                        'b': core_schema.typed_dict_field(
+                            core_schema.with_default_schema(core_schema.int_schema(), default=1)
+                        ),
+                    }
+                ),
+                core_schema.typed_dict_schema({'c': core_schema.typed_dict_field(core_schema.int_schema())}),
+            ]
+        )
+    )
+    # the first choice fails after applying its default, so the default isn't reported
+    output, report = v.validate_python_with_report({'c': 1}, report_defaults=True)
+    assert output == {'c': 1}
+    assert report == []
@@ -0,0 +1,296 @@
+from datetime import date
+
+import pytest
+
+from pydantic_core import SchemaValidator, core_schema
+
+NODE_KEYS = ['kind', 'ref', 'strict', 'has_default', 'accepts', 'constraints', 'children']
+
+
+def f(value):
+    return value
+
+
+def node(kind, *, ref=None, strict=None, has_default=False, accepts=None, constraints=None, children=None):
+    return {
+        'kind': kind,
+        'ref': ref,
+        'strict': strict,
+        'has_default': has_default,
+        'accepts': accepts,
+        'constraints': constraints or {},
+        'children': children or {},
+    }
+
+
+INT_ACCEPTS = {'lax': ['int', 'str', 'bytes', 'float'], 'strict': ['int']}
+STR_ACCEPTS = {'lax': ['str', 'bytes', 'bytearray'], 'strict': ['str']}
+
+
+def walk(description):
+    yield description
+    for child in description['children'].values():
+        yield from walk(child)
+
+
+def test_typed_dict_snapshot():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'id': core_schema.typed_dict_field(core_schema.int_schema(ge=1, strict=True)),
+                'name': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(
+                        core_schema.nullable_schema(core_schema.str_schema(max_length=5)), default=None
+                    ),
+                    required=False,
+                ),
+                'scores': core_schema.typed_dict_field(
+                    core_schema.list_schema(core_schema.no_info_after_validator_function(f, core_schema.float_schema()))
+                ),
+                'tags': core_schema.typed_dict_field(
+                    core_schema.dict_schema(core_schema.str_schema(), core_schema.literal_schema(['b', 'a']))
+                ),
+            }
+        )
+    )
+    assert v.describe() == {
+        'validator': node(
+            'typed-dict',
+            strict=False,
+            accepts={'lax': ['dict', 'mapping'], 'strict': ['dict']},
+            constraints={'required': ['id', 'scores', 'tags']},
+            children={
+                'id': node('int', strict=True, accepts=INT_ACCEPTS, constraints={'ge': 1}),
+                'name': node(
+                    'default',
+                    has_default=True,
+                    children={
+                        'schema': node(
+                            'nullable',
+                            strict=False,
+                            children={
+                                'schema': node(
+                                    'str', strict=False, accepts=STR_ACCEPTS, constraints={'max_length': 5}
+                                )
+                            },
+                        )
+                    },
+                ),
+                'scores': node(
+                    'list',
+                    strict=False,
+                    accepts={
+                        'lax': [
+                            'list',
+                            'tuple',
+                            'dict_keys',
+                            'dict_values',
+                            'dict_items',
+                            'iterator',
+                            'deque',
+                            'range',
+                        ],
+                        'strict': ['list'],
+                    },
+                    children={
+                        'items_schema': node(
+                            'function-after',
+                            accepts='opaque',
+                            children={
+                                'schema': node(
+                                    'float',
+                                    strict=False,
+                                    accepts={'lax': ['float', 'int', 'str', 'bytes'], 'strict': ['float', 'int']},
+                                )
+                            },
+                        )
+                    },
+                ),
+                'tags': node(
+                    'dict',
+                    strict=False,
+                    accepts={'lax': ['dict', 'mapping'], 'strict': ['dict']},
+                    children={
+                        'keys_schema': node('str', strict=False, accepts=STR_ACCEPTS),
+                        'values_schema': node('literal', constraints={'expected': ['a', 'b']}),
+                    },
+                ),
+            },
+        ),
+        'definitions': {},
+    }
+
+
+def test_stable_shape():
+    schema = core_schema.tuple_positional_schema(
+        [
+            core_schema.union_schema([core_schema.int_schema(), core_schema.date_schema(gt=date(2020, 1, 1))]),
+            core_schema.chain_schema([core_schema.str_schema(), core_schema.no_info_plain_validator_function(f)]),
+            core_schema.bytes_schema(min_length=2),
+        ],
+        extra_schema=core_schema.any_schema(),
+    )
+    description = SchemaValidator(schema).describe()
+    assert list(description) == ['validator', 'definitions']
+    for n in walk(description['validator']):
+        assert list(n) == NODE_KEYS
+    # the same schema always gives the same description, in the same order
+    assert repr(SchemaValidator(schema).describe()) == repr(description)
+
+    root = description['validator']
+    assert root['kind'] == 'tuple-positional'
+    assert list(root['children']) == [0, 1, 2, 'extra_schema']
+    union = root['children'][0]
+    assert union['kind'] == 'union'
+    assert union['children'][1]['constraints'] == {'gt': '2020-01-01'}
+    chain = root['children'][1]
+    assert [c['kind'] for c in chain['children'].values()] == ['str', 'function-plain']
+    assert chain['children'][1]['accepts'] == 'opaque'
+    assert root['children'][2]['constraints'] == {'min_length': 2}
+    assert root['children']['extra_schema']['accepts'] == {'lax': ['any'], 'strict': ['any']}
+
+
+def test_tagged_union_sorted():
+    v = SchemaValidator(
+        core_schema.tagged_union_schema(
+            {'b': core_schema.int_schema(), 2: core_schema.str_schema(), 'a': 'b', 1: core_schema.none_schema()},
+            discriminator='kind',
+        )
+    )
+    children = v.describe()['validator']['children']
+    assert list(children) == [1, 2, 'a', 'b']
+    assert children['a'] == children['b'] == node('int', strict=False, accepts=INT_ACCEPTS)
+    assert children[1] == node('none', accepts={'lax': ['none'], 'strict': ['none']})
+
+
+def test_definitions():
+    schema = core_schema.definitions_schema(
+        core_schema.definition_reference_schema('branch'),
+        [
+            core_schema.typed_dict_schema(
+                {
+                    'children': core_schema.typed_dict_field(
+                        core_schema.list_schema(core_schema.definition_reference_schema('branch'))
+                    )
+                },
+                ref='branch',
+            )
+        ],
+    )
+    description = SchemaValidator(schema).describe()
+    assert description['validator'] == node('definition-ref', ref='branch')
+    assert list(description['definitions']) == ['branch']
+    branch = description['definitions']['branch']
+    assert branch['kind'] == 'typed-dict'
+    assert branch['children']['children']['children']['items_schema'] == node('definition-ref', ref='branch')
+
+
+def test_strictness_from_config():
+    schema = core_schema.typed_dict_schema({'x': core_schema.typed_dict_field(core_schema.int_schema())})
+    lax = SchemaValidator(schema).describe()['validator']
+    strict = SchemaValidator(schema, {'strict': True}).describe()['validator']
+    assert lax['strict'] is False
+    assert lax['children']['x']['strict'] is False
+    assert strict['strict'] is True
+    assert strict['children']['x']['strict'] is True
+    assert SchemaValidator(schema).with_strict(True).describe() == {'validator': strict, 'definitions': {}}
+
+
+@pytest.mark.parametrize(
+    'schema,expected',
+    [
+        (core_schema.none_schema(), node('none', accepts={'lax': ['none'], 'strict': ['none']})),
+        (
+            core_schema.bool_schema(),
+            node('bool', strict=False, accepts={'lax': ['bool', 'str', 'bytes', 'int', 'float'], 'strict': ['bool']}),
+        ),
+        (
+            core_schema.float_schema(allow_inf_nan=False, multiple_of=0.5),
+            node(
+                'float',
+                strict=False,
+                accepts={'lax': ['float', 'int', 'str', 'bytes'], 'strict': ['float', 'int']},
+                constraints={'allow_inf_nan': False, 'multiple_of': 0.5},
+            ),
+        ),
+        (
+            core_schema.str_schema(pattern='^a', min_length=1),
+            node('str', strict=False, accepts=STR_ACCEPTS, constraints={'min_length': 1, 'patterns': ['^a']}),
+        ),
+        (core_schema.literal_schema([3, 1, 2]), node('literal', constraints={'expected': [1, 2, 3]})),
+        (
+            core_schema.literal_schema([None, 'b', 2, 'a', 1]),
+            node('literal', constraints={'expected': [1, 2, 'a', 'b', None]}),
+        ),
+        (
+            core_schema.is_instance_schema(int),
+            node('is-instance', accepts={'lax': ['instance'], 'strict': ['instance']}, constraints={'cls': 'int'}),
+        ),
+        (core_schema.no_info_wrap_validator_function(f, core_schema.int_schema()), None),
+        (
+            core_schema.lax_or_strict_schema(
+                lax_schema=core_schema.str_schema(), strict_schema=core_schema.int_schema(), strict=True
+            ),
+            node(
+                'lax-or-strict',
+                strict=True,
+                children={
+                    'lax_schema': node('str', strict=False, accepts=STR_ACCEPTS),
+                    'strict_schema': node('int', strict=False, accepts=INT_ACCEPTS),
+                },
+            ),
+        ),
+        (
+            core_schema.json_or_python_schema(
+                json_schema=core_schema.str_schema(), python_schema=core_schema.int_schema()
+            ),
+            node(
+                'json-or-python',
+                children={
+                    'json_schema': node('str', strict=False, accepts=STR_ACCEPTS),
+                    'python_schema': node('int', strict=False, accepts=INT_ACCEPTS),
+                },
+            ),
+        ),
+        (
+            core_schema.generator_schema(core_schema.int_schema(), max_length=3),
+            node(
+                'generator',
+                accepts={'lax': ['iterable'], 'strict': ['iterable']},
+                constraints={'max_length': 3},
+                children={'items_schema': node('int', strict=False, accepts=INT_ACCEPTS)},
+            ),
+        ),
+    ],
+)
+def test_nodes(schema, expected):
+    description = SchemaValidator(schema).describe()['validator']
+    if expected is None:
+        assert description['kind'] == 'function-wrap'
+        assert description['accepts'] == 'opaque'
+        assert description['children'] == {'schema': node('int', strict=False, accepts=INT_ACCEPTS)}
+    else:
+        assert description == expected
+
+
+def test_arguments():
+    v = SchemaValidator(
+        core_schema.arguments_schema(
+            [
+                core_schema.arguments_parameter('a', core_schema.int_schema()),
+                core_schema.arguments_parameter(
+                    'b', core_schema.with_default_schema(core_schema.str_schema(), default='x'), mode='keyword_only'
+                ),
+            ],
+            var_args_schema=core_schema.int_schema(),
+        )
+    )
+    description = v.describe()['validator']
+    assert description['kind'] == 'arguments'
+    assert description['accepts'] == {
+        'lax': ['args_kwargs', 'tuple', 'list', 'dict'],
+        'strict': ['args_kwargs', 'tuple', 'list', 'dict'],
+    }
+    assert list(description['children']) == ['a', 'b', 'var_args_schema']
+    assert description['children']['b']['has_default'] is True
+    assert description['children']['a']['has_default'] is False
@@ -1,5 +1,6 @@
 import json
 import re
+from datetime import date, datetime
 from decimal import Decimal
 
 import pytest
@@ -9,6 +10,7 @@ from pydantic_core import (
     PydanticCustomError,
     PydanticKnownError,
     PydanticOmit,
+    SchemaError,
     SchemaValidator,
     ValidationError,
     core_schema,
@@ -178,8 +180,21 @@ def test_pydantic_error_type_raise_ctx():
 all_errors = [
     ('no_such_attribute', "Object has no attribute 'wrong_name'", {'attribute': 'wrong_name'}),
     ('json_invalid', 'Invalid JSON: foobar', {'error': 'foobar'}),
+    (
+        'json_invalid',
+        'Invalid JSON: foobar at line 2 column 3',
+        {'error': 'foobar at line 2 column 3', 'position': 5, 'line': 2, 'column': 3, 'expected': 'expected value'},
+    ),
     ('json_type', 'JSON input should be string, bytes or bytearray', None),
+    (
+        'json_string_too_long',
+        'JSON string at byte 10 should have at most 42 bytes',
+        {'max_length': 42, 'position': 10},
+    ),
+    ('json_duplicate_key', "Duplicate key 'a' in JSON object at byte 7", {'key': 'a', 'position': 7}),
     ('recursion_loop', 'Recursion error - cyclic reference detected', None),
+    ('recursion_loop', 'Recursion error - cyclic reference detected', {'ref': 'Node'}),
+    ('validation_budget_exceeded', 'Validation exceeded the maximum number of steps', None),
     ('dict_attributes_type', 'Input should be a valid dictionary or instance to extract fields from', None),
     ('missing', 'Field required', None),
     ('frozen_field', 'Field is frozen', None),
@@ -187,7 +202,38 @@ all_errors = [
     ('extra_forbidden', 'Extra inputs are not permitted', None),
     ('invalid_key', 'Keys should be strings', None),
     ('get_attribute_error', 'Error extracting attribute: foo', {'error': 'foo'}),
+    ('default_factory_error', 'Error calling default_factory: foo', {'error': 'foo'}),
     ('model_class_type', 'Input should be an instance of foo', {'class_name': 'foo'}),
+    (
+        'exclusive_group_conflict',
+        "At most one field of group 'contact' should be set, got 'email', 'phone'",
+        {'group': 'contact', 'fields': "'email', 'phone'"},
+    ),
+    (
+        'exclusive_group_missing',
+        "Exactly one field of group 'contact' should be set, got none",
+        {'group': 'contact'},
+    ),
+    (
+        'field_dependency_failed',
+        "Field depends on 'min', which is missing or failed validation",
+        {'dependency': 'min'},
+    ),
+    (
+        'group_required_together',
+        "Fields 'lat', 'lon' should either all be provided or all be omitted",
+        {'fields': "'lat', 'lon'"},
+    ),
+    (
+        'group_mutually_exclusive',
+        "At most one of fields 'password', 'token' should be provided",
+        {'fields': "'password', 'token'"},
+    ),
+    (
+        'group_at_least_one',
+        "At least one of fields 'email', 'phone' should be provided",
+        {'fields': "'email', 'phone'"},
+    ),
     ('none_required', 'Input should be None', None),
     ('bool', 'Input should be a valid boolean', None),
     ('greater_than', 'Input should be greater than 42.1', {'gt': 42.1}),
@@ -197,6 +243,8 @@ all_errors = [
     ('less_than', 'Input should be less than 42.1', {'lt': 42.1}),
     ('less_than_equal', 'Input should be less than or equal to 42.1', {'le': 42.1}),
     ('finite_number', 'Input should be a finite number', None),
+    ('decimal_places', 'Input should have no more than 2 decimal places', {'decimal_places': 2}),
+    ('decimal_places', 'Input should have no more than 1 decimal place', {'decimal_places': 1}),
     (
         'too_short',
         'Foobar should have at least 42 items after validation, not 40',
@@ -215,15 +263,20 @@ all_errors = [
     ('string_too_long', 'String should have at most 42 characters', {'max_length': 42}),
     ('dict_type', 'Input should be a valid dictionary', None),
     ('mapping_type', 'Input should be a valid mapping, error: foobar', {'error': 'foobar'}),
+    ('dict_duplicate_key', 'Dictionary keys should be unique after validation', None),
+    ('default_dict_type', 'Input should be a valid defaultdict', None),
     ('iterable_type', 'Input should be iterable', None),
     ('iteration_error', 'Error iterating over object, error: foobar', {'error': 'foobar'}),
     ('list_type', 'Input should be a valid list', None),
+    ('list_unique_items', 'List should have unique items', None),
     ('tuple_type', 'Input should be a valid tuple', None),
     ('set_type', 'Input should be a valid set', None),
+    ('unhashable', 'Input should be hashable', None),
     ('bool_type', 'Input should be a valid boolean', None),
     ('bool_parsing', 'Input should be a valid boolean, unable to interpret input', None),
     ('int_type', 'Input should be a valid integer', None),
     ('int_parsing', 'Input should be a valid integer, unable to parse string as an integer', None),
+    ('int_parsing_size', 'Unable to parse input as an integer, exceeded maximum size', None),
     ('int_from_float', 'Input should be a valid integer, got a number with a fractional part', None),
     ('multiple_of', 'Input should be a multiple of 42.1', {'multiple_of': 42.1}),
     ('greater_than', 'Input should be greater than 42.1', {'gt': 42.1}),
@@ -235,10 +288,22 @@ all_errors = [
     ('bytes_type', 'Input should be a valid bytes', None),
     ('bytes_too_short', 'Data should have at least 42 bytes', {'min_length': 42}),
     ('bytes_too_long', 'Data should have at most 42 bytes', {'max_length': 42}),
+    (
+        'bytes_invalid_encoding',
+        'Data should be valid base64: Invalid byte 33, offset 0.',
+        {'encoding': 'base64', 'encoding_error': 'Invalid byte 33, offset 0.'},
+    ),
+    ('bytearray_type', 'Input should be a valid bytearray', None),
+    ('bytes_buffer_invalid', 'Input should be a contiguous one-dimensional buffer of bytes', None),
     ('value_error', 'Value error, foobar', {'error': 'foobar'}),
     ('assertion_error', 'Assertion failed, foobar', {'error': 'foobar'}),
     ('literal_error', 'Input should be foo', {'expected': 'foo'}),
     ('literal_error', 'Input should be foo or bar', {'expected': 'foo or bar'}),
+    (
+        'literal_error',
+        'Input should be foo …and 1 more',
+        {'expected': 'foo …and 1 more', 'expected_full': 'foo or bar'},
+    ),
     ('date_type', 'Input should be a valid date', None),
     ('date_parsing', 'Input should be a valid date in the format YYYY-MM-DD, foobar', {'error': 'foobar'}),
     ('date_from_datetime_parsing', 'Input should be a valid date or datetime, foobar', {'error': 'foobar'}),
@@ -252,8 +317,33 @@ all_errors = [
     ('datetime_object_invalid', 'Invalid datetime object, got foobar', {'error': 'foobar'}),
     ('datetime_past', 'Datetime should be in the past', None),
     ('datetime_future', 'Datetime should be in the future', None),
-    ('datetime_aware', 'Datetime should have timezone info', None),
-    ('datetime_naive', 'Datetime should not have timezone info', None),
+    ('timezone_aware', 'Input should have timezone info', {'tz_constraint': 'aware'}),
+    ('timezone_naive', 'Input should not have timezone info', {'tz_constraint': 'naive'}),
+    (
+        'timezone_offset',
+        'Timezone offset of 3600 required, got -7200',
+        {'tz_expected': 3600, 'tz_actual': -7200},
+    ),
+    (
+        'datetime_unknown_zone',
+        "Input should have a valid IANA time zone name, unknown time zone 'Mars/Base'",
+        {'zone': 'Mars/Base'},
+    ),
+    (
+        'datetime_nonexistent',
+        "Datetime does not exist in time zone 'Europe/Paris', it falls in a DST gap",
+        {'zone': 'Europe/Paris'},
+    ),
+    (
+        'datetime_ambiguous',
+        "Datetime is ambiguous in time zone 'Europe/Paris', it falls in a DST overlap",
+        {'zone': 'Europe/Paris'},
+    ),
+    (
+        'datetime_microseconds_precision',
+        'Input should have at most microsecond precision, got 9 fractional second digits',
+        {'precision': 9},
+    ),
     ('time_delta_type', 'Input should be a valid timedelta', None),
     ('time_delta_parsing', 'Input should be a valid timedelta, foobar', {'error': 'foobar'}),
     ('frozen_set_type', 'Input should be a valid frozenset', None),
@@ -266,6 +356,12 @@ all_errors = [
         {'discriminator': 'bar', 'tag': 'foo', 'expected_tags': 'baz'},
     ),
     ('union_tag_not_found', 'Unable to extract tag using discriminator foo', {'discriminator': 'foo'}),
+    (
+        'union_tag_other_matches',
+        "Input is invalid for tag 'a' but valid for other tags: 'b', 'c'",
+        {'tag': 'a', 'matched_other_tags': ['b', 'c']},
+    ),
+    ('merge_conflict', "Merged schemas returned different values for key 'foo'", {'key': 'foo'}),
     ('arguments_type', 'Arguments must be a tuple, list or a dictionary', None),
     ('missing_argument', 'Missing required argument', None),
     ('unexpected_keyword_argument', 'Unexpected keyword argument', None),
@@ -279,6 +375,58 @@ all_errors = [
     ('url_syntax_violation', 'Input violated strict URL syntax rules, Foobar', {'error': 'Foobar'}),
     ('url_too_long', 'URL should have at most 42 characters', {'max_length': 42}),
     ('url_scheme', 'URL scheme should be "foo", "bar" or "spam"', {'expected_schemes': '"foo", "bar" or "spam"'}),
+    ('path_type', 'Input should be a valid path', None),
+    ('path_not_absolute', 'Path should be absolute', None),
+    ('path_suffix', "Path suffix should be '.txt' or '.csv'", {'expected_suffixes': "'.txt' or '.csv'"}),
+    ('path_too_many_parts', 'Path should have at most 3 parts', {'max_parts': 3}),
+    ('path_not_exists', 'Path does not exist', None),
+    ('path_not_file', 'Path does not point to a file', None),
+    ('path_not_directory', 'Path does not point to a directory', None),
+    ('timezone_type', 'Input should be a valid timezone', None),
+    ('timezone_name', "Unknown timezone name 'Foo/Bar'", {'name': 'Foo/Bar'}),
+    ('fraction_type', 'Input should be a valid fraction', None),
+    ('fraction_parsing', 'Input should be a valid fraction, unable to parse string as a fraction', None),
+    ('fraction_zero_denominator', 'Fraction denominator should not be zero', None),
+    ('fraction_too_many_digits', 'Fraction should have at most 4300 digits', {'max_digits': 4300}),
+    ('decimal_type', 'Decimal input should be an integer, float, string or Decimal object', None),
+    ('decimal_parsing', 'Input should be a valid decimal', None),
+    ('decimal_max_digits', 'Decimal input should have no more than 42 digits in total', {'max_digits': 42}),
+    ('decimal_max_digits', 'Decimal input should have no more than 1 digit in total', {'max_digits': 1}),
+    ('decimal_max_places', 'Decimal input should have no more than 42 decimal places', {'decimal_places': 42}),
+    ('decimal_max_places', 'Decimal input should have no more than 1 decimal place', {'decimal_places': 1}),
+    (
+        'decimal_whole_digits',
+        'Decimal input should have no more than 42 digits before the decimal point',
+        {'whole_digits': 42},
+    ),
+    (
+        'decimal_whole_digits',
+        'Decimal input should have no more than 1 digit before the decimal point',
+        {'whole_digits': 1},
+    ),
+    ('uuid_type', 'UUID input should be a string, bytes or UUID object', None),
+    ('uuid_parsing', 'Input should be a valid UUID, Foobar', {'error': 'Foobar'}),
+    ('uuid_version', 'UUID version 4 expected', {'expected_version': 4}),
+    (
+        'complex_type',
+        'Input should be a valid python complex object, a number, or a valid complex string following the rules at '
+        'https://docs.python.org/3/library/functions.html#complex',
+        None,
+    ),
+    (
+        'complex_str_parsing',
+        'Input should be a valid complex string following the rules at '
+        'https://docs.python.org/3/library/functions.html#complex',
+        None,
+    ),
+    ('ip_v4_address', 'Input is not a valid IPv4 address', None),
+    ('ip_v6_address', 'Input is not a valid IPv6 address', None),
+    ('ip_v4_network', 'Input is not a valid IPv4 network', None),
+    ('ip_v6_network', 'Input is not a valid IPv6 network', None),
+    ('ip_v4_interface', 'Input is not a valid IPv4 interface', None),
+    ('ip_v6_interface', 'Input is not a valid IPv6 interface', None),
+    ('ip_network_host_bits', 'Input network should not have host bits set', None),
+    ('enum', "Input should be 'a' or 'b'", {'expected': "'a' or 'b'"}),
 ]
 
 
@@ -380,10 +528,10 @@ def test_all_errors():
             'example_context': None,
         },
         {
-            'type': 'recursion_loop',
-            'message_template_python': 'Recursion error - cyclic reference detected',
-            'example_message_python': 'Recursion error - cyclic reference detected',
-            'example_context': None,
+            'type': 'json_string_too_long',
+            'message_template_python': 'JSON string at byte {position} should have at most {max_length} bytes',
+            'example_message_python': 'JSON string at byte 0 should have at most 0 bytes',
+            'example_context': {'max_length': 0, 'position': 0},
         },
     ]
 
@@ -464,6 +612,170 @@ def test_error_json():
     assert exc_info.value.json(indent=2).startswith('[\n  {\n    "type": "string_too_short",')
 
 
+def test_errors_include_input_context():
+    s = SchemaValidator(core_schema.list_schema(core_schema.str_schema(min_length=3)))
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_python(['12', 'secret'])
+    assert exc_info.value.errors(include_input=False) == [
+        {
+            'type': 'string_too_short',
+            'loc': (0,),
+            'msg': 'String should have at least 3 characters',
+            'ctx': {'min_length': 3},
+        }
+    ]
+    assert exc_info.value.errors(include_context=False, include_input=False) == [
+        {'type': 'string_too_short', 'loc': (0,), 'msg': 'String should have at least 3 characters'}
+    ]
+    assert json.loads(exc_info.value.json(include_input=False)) == [
+        {
+            'type': 'string_too_short',
+            'loc': [0],
+            'msg': 'String should have at least 3 characters',
+            'ctx': {'min_length': 3},
+        }
+    ]
+    assert exc_info.value.json(include_context=False, include_input=False) == (
+        '[{"type":"string_too_short","loc":[0],"msg":"String should have at least 3 characters"}]'
+    )
+    # the input is still displayed
+    assert "input_value='12'" in str(exc_info.value)
+
+
+def test_hide_input_in_errors():
+    s = SchemaValidator(core_schema.str_schema(min_length=3), {'hide_input_in_errors': True})
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_python('12')
+    assert str(exc_info.value) == (
+        '1 validation error for constrained-str\n  String should have at least 3 characters [type=string_too_short]'
+    )
+    assert repr(exc_info.value) == str(exc_info.value)
+    # errors() is controlled separately
+    assert exc_info.value.errors() == [
+        {
+            'type': 'string_too_short',
+            'loc': (),
+            'msg': 'String should have at least 3 characters',
+            'input': '12',
+            'ctx': {'min_length': 3},
+        }
+    ]
+    assert 'input' not in exc_info.value.errors(include_input=False)[0]
+
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_json('"12"')
+    assert '12' not in str(exc_info.value)
+
+
+def test_hide_input_in_errors_generator():
+    s = SchemaValidator(
+        core_schema.generator_schema(core_schema.int_schema(), max_length=1), {'hide_input_in_errors': True}
+    )
+    gen = s.validate_python(['a', 2])
+    with pytest.raises(ValidationError) as exc_info:
+        next(gen)
+    assert str(exc_info.value) == (
+        '1 validation error for ValidatorIterator\n'
+        '0\n'
+        '  Input should be a valid integer, unable to parse string as an integer [type=int_parsing]'
+    )
+
+
+def test_custom_error_messages():
+    s = SchemaValidator(
+        core_schema.list_schema(core_schema.str_schema(min_length=3)),
+        {
+            'custom_error_messages': {
+                'string_too_short': 'at least {min_length} chars please',
+                'list_type': 'not a list',
+            }
+        },
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_python(['ab'])
+    # only `msg` changes
+    assert exc_info.value.errors() == [
+        {
+            'type': 'string_too_short',
+            'loc': (0,),
+            'msg': 'at least 3 chars please',
+            'input': 'ab',
+            'ctx': {'min_length': 3},
+        }
+    ]
+    assert json.loads(exc_info.value.json())[0]['msg'] == 'at least 3 chars please'
+    assert str(exc_info.value) == (
+        '1 validation error for list[constrained-str]\n'
+        '0\n'
+        "  at least 3 chars please [type=string_too_short, input_value='ab', input_type=str]"
+    )
+
+    # the replacement is used for JSON input too
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_json('1')
+    assert exc_info.value.errors()[0]['msg'] == 'not a list'
+
+
+def test_custom_error_messages_derived_placeholder():
+    s = SchemaValidator(
+        core_schema.str_schema(min_length=1), {'custom_error_messages': {'string_too_short': 'min {min_length}'}}
+    )
+    assert s.validate_python('a') == 'a'
+    v = SchemaValidator(
+        core_schema.list_schema(min_length=2),
+        {'custom_error_messages': {'too_short': 'need {min_length} item{expected_plural}'}},
+    )
+    with pytest.raises(ValidationError, match='need 2 items'):
+        v.validate_python([1])
+
+
+def test_custom_error_messages_precedence():
+    config = {'custom_error_messages': {'int_parsing': 'config message'}}
+    s = SchemaValidator(
+        core_schema.custom_error_schema(core_schema.int_schema(), 'my_error', custom_error_message='custom_error wins'),
+        config,
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_python('x')
+    assert exc_info.value.errors()[0]['msg'] == 'custom_error wins'
+
+    # custom errors are never replaced, even with the same type as a built-in error
+    s = SchemaValidator(
+        core_schema.int_schema(error_overrides={'int_parsing': {'type': 'int_parsing', 'message': 'override wins'}}),
+        config,
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_python('x')
+    assert exc_info.value.errors()[0]['msg'] == 'override wins'
+
+
+def test_custom_error_messages_generator():
+    s = SchemaValidator(
+        core_schema.generator_schema(core_schema.int_schema()), {'custom_error_messages': {'int_parsing': 'no int'}}
+    )
+    gen = s.validate_python(['a'])
+    with pytest.raises(ValidationError) as exc_info:
+        next(gen)
+    assert exc_info.value.errors()[0]['msg'] == 'no int'
+
+
+@pytest.mark.parametrize(
+    'messages,error',
+    [
+        ({'not_an_error': 'x'}, "Invalid error type in custom_error_messages: 'not_an_error'"),
+        (
+            {'string_too_short': 'at least {max_length}'},
+            "Invalid placeholder '{max_length}' in custom_error_messages for 'string_too_short'",
+        ),
+        # only placeholders which the error renders in its own messages are allowed
+        ({'json_invalid': 'at {position}'}, "Invalid placeholder '{position}' in custom_error_messages"),
+    ],
+)
+def test_custom_error_messages_invalid(messages, error):
+    with pytest.raises(SchemaError, match=re.escape(error)):
+        SchemaValidator(core_schema.str_schema(), {'custom_error_messages': messages})
+
+
 def test_error_json_cycle():
     s = SchemaValidator({'type': 'str', 'min_length': 3})
     cycle = []
@@ -541,13 +853,55 @@ def test_error_json_loc():
     ]
 
 
+def test_error_json_snapshot():
+    s = SchemaValidator(core_schema.list_schema(core_schema.int_schema(gt=1)))
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_python(['x', 0])
+
+    assert exc_info.value.json() == (
+        '[{"type":"int_parsing","loc":[0],'
+        '"msg":"Input should be a valid integer, unable to parse string as an integer","input":"x"},'
+        '{"type":"greater_than","loc":[1],"msg":"Input should be greater than 1","input":0,"ctx":{"gt":1}}]'
+    )
+    # the output is stable between calls
+    assert exc_info.value.json() == exc_info.value.json()
+
+
+@pytest.mark.parametrize('indent', [0, 2, 4])
+def test_error_json_indent_matches_dumps(indent):
+    s = SchemaValidator(core_schema.list_schema(core_schema.int_schema(gt=1)))
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_python(['x', 0])
+
+    error_json = exc_info.value.json(indent=indent)
+    assert error_json == json.dumps(json.loads(error_json), indent=indent)
+
+
+def test_error_json_input_fallbacks():
+    s = SchemaValidator(core_schema.int_schema())
+    errors = []
+    for input_value in (b'\xff\xfe', b'foo', datetime(2023, 1, 2, 3, 4, 5), Decimal('1.5'), float('nan'), Foobar()):
+        with pytest.raises(ValidationError) as exc_info:
+            s.validate_python(input_value, strict=True)
+        errors.append(exc_info.value)
+
+    assert [json.loads(e.json())[0]['input'] for e in errors] == [
+        "b'\\xff\\xfe'",
+        'foo',
+        '2023-01-02T03:04:05',
+        '1.5',
+        None,
+        IsStr(regex='<.+.test_errors.Foobar object at 0x[a-f0-9]{5,}>', regex_flags=re.I),
+    ]
+
+
 def test_raise_validation_error():
     with pytest.raises(ValidationError, match='1 validation error for Foobar\n') as exc_info:
         raise ValidationError('Foobar', [{'type': 'greater_than', 'loc': ('a', 2), 'input': 4, 'ctx': {'gt': 5}}])
 
     # insert_assert(exc_info.value.errors())
     assert exc_info.value.errors() == [
-        {'type': 'greater_than', 'loc': (2, 'a'), 'msg': 'Input should be greater than 5', 'input': 4, 'ctx': {'gt': 5}}
+        {'type': 'greater_than', 'loc': ('a', 2), 'msg': 'Input should be greater than 5', 'input': 4, 'ctx': {'gt': 5}}
     ]
     with pytest.raises(TypeError, match='GreaterThan requires context: {gt: Number}'):
         raise ValidationError('Foobar', [{'type': 'greater_than', 'loc': ('a', 2), 'input': 4}])
@@ -590,6 +944,89 @@ def test_raise_validation_error_custom():
     ]
 
 
+def test_from_exception_data():
+    exc = ValidationError.from_exception_data(
+        'Foobar', [{'type': 'greater_than', 'loc': ('a', 2), 'input': 4, 'ctx': {'gt': 5}}]
+    )
+    assert isinstance(exc, ValidationError)
+    assert exc.title == 'Foobar'
+    assert exc.errors() == [
+        {'type': 'greater_than', 'loc': ('a', 2), 'msg': 'Input should be greater than 5', 'input': 4, 'ctx': {'gt': 5}}
+    ]
+    assert str(exc) == (
+        '1 validation error for Foobar\n'
+        'a.2\n'
+        '  Input should be greater than 5 [type=greater_than, input_value=4, input_type=int]'
+    )
+
+
+def test_from_exception_data_options():
+    exc = ValidationError.from_exception_data(
+        'Foobar', [{'type': 'none_required', 'input': 'x'}], input_type='json', hide_input=True
+    )
+    assert exc.errors() == [{'type': 'none_required', 'loc': (), 'msg': 'Input should be null', 'input': 'x'}]
+    assert str(exc) == '1 validation error for Foobar\n  Input should be null [type=none_required]'
+
+
+def test_from_exception_data_custom():
+    exc = ValidationError.from_exception_data(
+        'Foobar', [{'type': 'my_error', 'loc': ('a',), 'msg': 'custom {foo}', 'input': 1, 'ctx': {'foo': 'X'}}]
+    )
+    assert exc.errors() == [{'type': 'my_error', 'loc': ('a',), 'msg': 'custom X', 'input': 1, 'ctx': {'foo': 'X'}}]
+
+    # the message of known error types always comes from their template
+    exc = ValidationError.from_exception_data('Foobar', [{'type': 'int_type', 'msg': 'ignored', 'input': 'x'}])
+    assert exc.errors()[0]['msg'] == 'Input should be a valid integer'
+
+
+def test_from_exception_data_invalid_type():
+    with pytest.raises(KeyError, match="'int_parse', custom error types require a `msg`, did you mean 'int_parsing'"):
+        ValidationError.from_exception_data('Foobar', [{'type': 'int_parse', 'input': 'x'}])
+    with pytest.raises(KeyError, match=r"Invalid error type: 'xxxxxxxxxx', custom error types require a `msg`\"$"):
+        ValidationError.from_exception_data('Foobar', [{'type': 'xxxxxxxxxx', 'input': 'x'}])
+
+
+def raise_custom(value):
+    raise PydanticCustomError('my_error', 'custom {foo}', {'foo': value})
+
+
+@pytest.mark.parametrize(
+    'input_value',
+    [
+        {'a': 1, 'b': 1, 'c': ['xxx', 1], 'e': 'x'},
+        '{"a": "x", "b": 1, "c": ["xxx"], "e": 2}',
+    ],
+)
+def test_from_exception_data_round_trip(input_value):
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema(gt=3)),
+                'b': core_schema.typed_dict_field(
+                    core_schema.general_plain_validator_function(lambda v, info: raise_custom(v))
+                ),
+                'c': core_schema.typed_dict_field(core_schema.list_schema(core_schema.str_schema(max_length=2))),
+                'd': core_schema.typed_dict_field(core_schema.str_schema()),
+                'e': core_schema.typed_dict_field(
+                    core_schema.chain_schema([core_schema.str_schema(), core_schema.int_schema()])
+                ),
+            }
+        )
+    )
+    input_type = 'python' if isinstance(input_value, dict) else 'json'
+    with pytest.raises(ValidationError) as exc_info:
+        if input_type == 'python':
+            v.validate_python(input_value)
+        else:
+            v.validate_json(input_value)
+    exc = exc_info.value
+
+    rebuilt = ValidationError.from_exception_data(exc.title, exc.errors(), input_type=input_type)
+    assert rebuilt.errors() == exc.errors()
+    assert rebuilt.json() == exc.json()
+    assert str(rebuilt) == str(exc)
+
+
 def test_loc_with_dots():
     v = SchemaValidator(
         core_schema.typed_dict_schema(
@@ -620,3 +1057,149 @@ def test_loc_with_dots():
         "  Input should be a valid integer, unable to parse string as an integer "
         "[type=int_parsing, input_value='x', input_type=str]"
     )
+
+
+@pytest.fixture(scope='module')
+def error_order_validator():
+    return SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'b': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'c': core_schema.typed_dict_field(
+                    core_schema.union_schema([core_schema.int_schema(), core_schema.bool_schema()])
+                ),
+            },
+            extra_behavior='forbid',
+        )
+    )
+
+
+def test_error_order(error_order_validator):
+    input_value = {'z': 1, 'c': 'x', 'b': ['x', 1, 'y'], 'y': 2}
+    with pytest.raises(ValidationError) as exc_info:
+        error_order_validator.validate_python(input_value)
+    # fields in declaration order, with missing fields in place, items in input order, union choices in
+    # declaration order, then extra keys in input order
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('int_parsing', ('b', 0)),
+        ('int_parsing', ('b', 2)),
+        ('missing', ('a',)),
+        ('int_parsing', ('c', 'int')),
+        ('bool_parsing', ('c', 'bool')),
+        ('extra_forbidden', ('z',)),
+        ('extra_forbidden', ('y',)),
+    ]
+    assert [e['loc'] for e in exc_info.value.errors(sort_errors='loc')] == [
+        ('a',),
+        ('b', 0),
+        ('b', 2),
+        ('c', 'bool'),
+        ('c', 'int'),
+        ('y',),
+        ('z',),
+    ]
+    # the same input always gives errors in the same order
+    for _ in range(5):
+        with pytest.raises(ValidationError) as exc_info2:
+            error_order_validator.validate_python(input_value)
+        assert exc_info2.value.errors() == exc_info.value.errors()
+
+
+def test_sort_errors_loc():
+    v = SchemaValidator(
+        core_schema.dict_schema(
+            core_schema.int_schema(), core_schema.list_schema(core_schema.int_schema(), max_length=1)
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'x': [1], 3: ['a', 'b'], 1: 'y', 2: [1, 2]})
+    # ints are ordered numerically before strings
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors(sort_errors='loc')] == [
+        ('list_type', (1,)),
+        ('too_long', (2,)),
+        ('int_parsing', (3, 0)),
+        ('int_parsing', (3, 1)),
+        ('int_parsing', ('x', '[key]')),
+    ]
+    assert exc_info.value.errors(sort_errors='input') == exc_info.value.errors()
+
+
+def test_sort_errors_invalid():
+    with pytest.raises(ValidationError) as exc_info:
+        SchemaValidator(core_schema.int_schema()).validate_python('x')
+    with pytest.raises(ValueError, match="Invalid sort_errors: `foobar`, expected 'input' or 'loc'"):
+        exc_info.value.errors(sort_errors='foobar')
+
+
+def raise_validation_error(error):
+    def f(input_value, info):
+        raise error
+
+    v = SchemaValidator(core_schema.general_plain_validator_function(f))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x')
+    return exc_info.value
+
+
+def is_json_primitive(value):
+    if isinstance(value, list):
+        return all(is_json_primitive(v) for v in value)
+    return value is None or type(value) in (str, int, float, bool)
+
+
+@pytest.mark.parametrize('error_type, message, context', all_errors)
+def test_error_type_json(error_type, message, context):
+    error = raise_validation_error(PydanticKnownError(error_type, context))
+    [details] = json.loads(error.json())
+    assert details['type'] == error_type
+    assert details['msg'] == message
+    assert details.get('ctx') == context
+    assert all(is_json_primitive(v) for v in (context or {}).values())
+    assert error.errors(context_mode='json') == error.errors()
+
+
+def test_constraint_context_json():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'd': core_schema.typed_dict_field(core_schema.date_schema(gt=date(2020, 1, 1))),
+                's': core_schema.typed_dict_field(core_schema.str_schema(pattern='^a+$')),
+                'f': core_schema.typed_dict_field(core_schema.float_schema(le=1.5)),
+            }
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'d': '2019-01-01', 's': 'b', 'f': 2})
+    # bounds and patterns are stored as JSON-safe values
+    assert [e['ctx'] for e in exc_info.value.errors()] == [{'gt': '2020-01-01'}, {'pattern': '^a+$'}, {'le': 1.5}]
+    assert [e['ctx'] for e in json.loads(exc_info.value.json())] == [e['ctx'] for e in exc_info.value.errors()]
+
+
+def test_context_mode():
+    context = {
+        'decimal': Decimal('1.5'),
+        'datetime': datetime(2020, 1, 1, 12),
+        'pattern': re.compile('^a+$'),
+        'tuple': (1, 2),
+        'int': 3,
+    }
+    error = raise_validation_error(PydanticCustomError('my_error', 'my message', context))
+    # by default context values are returned as they are
+    assert error.errors()[0]['ctx'] == context
+    assert error.errors(context_mode='python') == error.errors()
+    json_context = {
+        'decimal': '1.5',
+        'datetime': '2020-01-01T12:00:00',
+        'pattern': '^a+$',
+        'tuple': [1, 2],
+        'int': 3,
+    }
+    assert error.errors(context_mode='json')[0]['ctx'] == json_context
+    assert json.loads(error.json())[0]['ctx'] == json_context
+
+
+def test_context_mode_invalid():
+    error = raise_validation_error(PydanticKnownError('finite_number'))
+    with pytest.raises(ValueError, match="Invalid context_mode: `foobar`, expected 'python' or 'json'"):
+        error.errors(context_mode='foobar')
@@ -9,7 +9,7 @@ from dirty_equals import AnyThing, IsBytes, IsStr, IsTuple
 from hypothesis import given, strategies
 from typing_extensions import TypedDict
 
-from pydantic_core import SchemaSerializer, SchemaValidator, ValidationError
+from pydantic_core import SchemaSerializer, SchemaValidator, ValidationError, core_schema
 
 
 @pytest.fixture(scope='module')
@@ -116,6 +116,7 @@ def test_definition_cycles(definition_schema, data):
                 'loc': IsTuple(length=(1, None)),
                 'msg': 'Recursion error - cyclic reference detected',
                 'input': AnyThing(),
+                'ctx': {'ref': 'Branch'},
             }
         ]
 
@@ -163,3 +164,46 @@ def str_serializer():
 def test_serialize_string(str_serializer: SchemaSerializer, data):
     assert str_serializer.to_python(data) == data
     assert json.loads(str_serializer.to_json(data)) == data
+
+
+@pytest.fixture(scope='module')
+def error_order_validator():
+    return SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+                'b': core_schema.typed_dict_field(
+                    core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema())
+                ),
+                'c': core_schema.typed_dict_field(
+                    core_schema.union_schema([core_schema.int_schema(), core_schema.bool_schema()])
+                ),
+            },
+            extra_behavior='forbid',
+        )
+    )
+
+
+def loc_sort_key(error):
+    return [(0, item) if isinstance(item, int) else (1, item) for item in error['loc']]
+
+
+error_order_values = strategies.one_of(
+    strategies.integers(),
+    strategies.text(max_size=3),
+    strategies.lists(strategies.one_of(strategies.integers(), strategies.text(max_size=3)), max_size=4),
+    strategies.dictionaries(strategies.text(max_size=3), strategies.one_of(strategies.integers(), strategies.text())),
+)
+
+
+@given(strategies.dictionaries(strategies.sampled_from(['a', 'b', 'c', 'x', 'y']), error_order_values))
+def test_error_order_stable(error_order_validator, data):
+    try:
+        error_order_validator.validate_python(data)
+    except ValidationError as exc:
+        errors = exc.errors()
+        for _ in range(3):
+            with pytest.raises(ValidationError) as exc_info:
+                error_order_validator.validate_python(data)
+            assert exc_info.value.errors() == errors
+        assert exc.errors(sort_errors='loc') == sorted(errors, key=loc_sort_key)
@@ -1,10 +1,12 @@
 import re
+from datetime import date
 
 import pytest
 from dirty_equals import IsList
 
 from pydantic_core import (
     PydanticSerializationError,
+    SchemaError,
     SchemaSerializer,
     SchemaValidator,
     ValidationError,
@@ -25,10 +27,61 @@ def test_bool(input_value, output_value):
     assert v.validate_json(input_value) == output_value
 
 
-@pytest.mark.parametrize('input_value', ['[1, 2, 3]', b'[1, 2, 3]', bytearray(b'[1, 2, 3]')])
+@pytest.mark.parametrize(
+    'input_value',
+    [
+        '[1, 2, 3]',
+        b'[1, 2, 3]',
+        bytearray(b'[1, 2, 3]'),
+        memoryview(b'[1, 2, 3]'),
+        memoryview(bytearray(b'[1, 2, 3]')),
+        memoryview(b'xx[1, 2, 3]xx')[2:-2],
+    ],
+)
 def test_input_types(input_value):
     v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'int'}})
     assert v.validate_json(input_value) == [1, 2, 3]
+    assert v.validate_json(input_value, allow_partial=True) == [1, 2, 3]
+    assert v.isinstance_json(input_value) is True
+
+
+def test_input_buffer_not_contiguous():
+    v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'int'}})
+    input_value = memoryview(b'[x1x]')[::2]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(input_value)
+    # insert_assert(exc_info.value.errors())
+    assert exc_info.value.errors() == [
+        {
+            'type': 'json_invalid',
+            'loc': (),
+            'msg': 'Invalid JSON: buffer should be contiguous UTF-8 encoded bytes',
+            'input': input_value,
+            'ctx': {'error': 'buffer should be contiguous UTF-8 encoded bytes'},
+        }
+    ]
+
+
+def test_input_buffer_not_utf8():
+    v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'str'}})
+    with pytest.raises(ValidationError, match=r'Invalid JSON: invalid unicode code point at line 1 column 11'):
+        v.validate_json(memoryview(b'["abc", "\xff"]'))
+
+
+def test_input_buffer_mutated_by_validator():
+    data = bytearray(b'[{"a": 1}, {"a": 2}]')
+
+    def f(input_value):
+        # parsing has finished, the buffer was released and values were copied out of it
+        data.clear()
+        data.extend(b'garbage')
+        return input_value
+
+    dict_schema = core_schema.dict_schema(values_schema=core_schema.int_schema())
+    v = SchemaValidator(core_schema.list_schema(core_schema.no_info_after_validator_function(f, dict_schema)))
+    assert v.validate_json(data) == [{'a': 1}, {'a': 2}]
+    assert data == bytearray(b'garbage')
+    assert v.validate_json(memoryview(bytearray(b'[{"b": 3}]'))) == [{'b': 3}]
 
 
 def test_input_type_invalid():
@@ -158,6 +211,21 @@ def test_dict_any_value():
     assert v.validate_json('{"1": 1, "2": "a", "3": null}') == {'1': 1, '2': 'a', '3': None}
 
 
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('{"$serde_json::private::Number": "abc"}', {'$serde_json::private::Number': 'abc'}),
+        ('{"$serde_json::private::Number": "1"}', {'$serde_json::private::Number': '1'}),
+        ('{"$serde_json::private::Number": 1.5, "a": [1]}', {'$serde_json::private::Number': 1.5, 'a': [1]}),
+        ('[{"$serde_json::private::Number": null}, 1e3]', [{'$serde_json::private::Number': None}, 1000.0]),
+    ],
+)
+def test_serde_number_key(input_value, expected):
+    """serde_json passes big numbers as an object with this key, a JSON object with the same key is still an object"""
+    v = SchemaValidator({'type': 'any'})
+    assert v.validate_json(input_value) == expected
+
+
 def test_json_invalid():
     v = SchemaValidator({'type': 'bool'})
 
@@ -169,7 +237,13 @@ def test_json_invalid():
             'loc': (),
             'msg': 'Invalid JSON: EOF while parsing a string at line 1 column 7',
             'input': '"foobar',
-            'ctx': {'error': 'EOF while parsing a string at line 1 column 7'},
+            'ctx': {
+                'error': 'EOF while parsing a string at line 1 column 7',
+                'position': 7,
+                'line': 1,
+                'column': 7,
+                'expected': 'expected `"`',
+            },
         }
     ]
     with pytest.raises(ValidationError) as exc_info:
@@ -180,9 +254,211 @@ def test_json_invalid():
             'loc': (),
             'msg': 'Invalid JSON: trailing comma at line 3 column 3',
             'input': '[1,\n2,\n3,]',
-            'ctx': {'error': 'trailing comma at line 3 column 3'},
+            'ctx': {
+                'error': 'trailing comma at line 3 column 3',
+                'position': 9,
+                'line': 3,
+                'column': 3,
+                'expected': 'expected value',
+            },
+        }
+    ]
+
+
+@pytest.mark.parametrize(
+    'input_value,position,line,column,expected',
+    [
+        ('{"a": 1 "b": 2}', 8, 1, 9, 'expected `,` or `}`'),
+        ('[1 2]', 3, 1, 4, 'expected `,` or `]`'),
+        ('{"a" 1}', 5, 1, 6, 'expected `:`'),
+        ('{"a": 1,\n "b": [1,\n', 19, 3, 0, 'expected value'),
+        ('[1', 2, 1, 2, 'expected the rest of the array'),
+        ('{"a": 1', 7, 1, 7, 'expected the rest of the object'),
+        ('"\\x"', 2, 1, 3, None),
+        ('[1.e]', 3, 1, 4, None),
+    ],
+)
+@pytest.mark.parametrize('as_bytes', [False, True], ids=['str', 'bytes'])
+def test_json_invalid_location(input_value, position, line, column, expected, as_bytes):
+    v = SchemaValidator(core_schema.any_schema())
+    if as_bytes:
+        input_value = input_value.encode()
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(input_value)
+    ctx = exc_info.value.errors()[0]['ctx']
+    assert ctx['error'].endswith(f' at line {line} column {column}')
+    assert (ctx['position'], ctx['line'], ctx['column'], ctx.get('expected')) == (position, line, column, expected)
+    assert f'at line {line} column {column} [type=json_invalid' in str(exc_info.value)
+    assert repr(exc_info.value) == str(exc_info.value)
+
+
+@pytest.mark.parametrize('input_value', ['{"é": é}', '{"é": é}'.encode()], ids=['str', 'bytes'])
+def test_json_invalid_column_counts_bytes(input_value):
+    v = SchemaValidator(core_schema.any_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(input_value)
+    # `é` is two bytes in UTF-8, so the `é` value starts at byte 7 and column 8 rather than character 6
+    assert exc_info.value.errors()[0]['ctx'] == {
+        'error': 'expected value at line 1 column 8',
+        'position': 7,
+        'line': 1,
+        'column': 8,
+        'expected': 'expected value',
+    }
+
+
+@pytest.mark.parametrize(
+    'input_value,position',
+    [
+        ('"abcdef"', 0),
+        ('["abc", "abcdef"]', 8),
+        ('{"a": 1,\n "b": "abc\\"def"}', 15),
+        ('{"abcdef": 1}', 1),
+        (b'[1, "abcdef"]', 4),
+        (bytearray(b'[1, "abcdef"]'), 4),
+    ],
+)
+def test_max_str_bytes(input_value, position):
+    v = SchemaValidator(core_schema.any_schema(), {'max_str_bytes': 5})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(input_value)
+    # insert_assert(exc_info.value.errors())
+    assert exc_info.value.errors() == [
+        {
+            'type': 'json_string_too_long',
+            'loc': (),
+            'msg': f'JSON string at byte {position} should have at most 5 bytes',
+            'input': input_value,
+            'ctx': {'max_length': 5, 'position': position},
         }
     ]
+    assert v.isinstance_json(input_value) is False
+
+
+def test_max_str_bytes_valid():
+    v = SchemaValidator(core_schema.any_schema(), {'max_str_bytes': 5})
+    assert v.validate_json('{"abc": ["abcde", "\\u00e9\\u00e9"]}') == {'abc': ['abcde', 'éé']}
+    # the limit is in bytes, not characters
+    with pytest.raises(ValidationError, match='JSON string at byte 0 should have at most 5 bytes'):
+        v.validate_json('"ééé"')
+    # still a normal error for invalid JSON
+    with pytest.raises(ValidationError, match='Invalid JSON: EOF while parsing a string'):
+        v.validate_json('"abc')
+
+
+def test_max_str_bytes_unlimited():
+    v = SchemaValidator(core_schema.str_schema())
+    assert v.validate_json('"' + 'x' * 10_000 + '"') == 'x' * 10_000
+
+
+def test_max_str_bytes_json_schema():
+    v = SchemaValidator(core_schema.json_schema(core_schema.list_schema()), {'max_str_bytes': 3})
+    assert v.validate_python('["abc"]') == ['abc']
+    with pytest.raises(ValidationError, match='JSON string at byte 1 should have at most 3 bytes'):
+        v.validate_python('["abcd"]')
+
+
+@pytest.mark.parametrize('input_type', [str, bytes])
+@pytest.mark.parametrize(
+    'mode,expected',
+    [
+        (None, {'a': 2, 'b': {'c': 4}}),
+        ('last', {'a': 2, 'b': {'c': 4}}),
+        ('first', {'a': 1, 'b': {'c': 3}}),
+    ],
+)
+def test_json_duplicate_keys(input_type, mode, expected):
+    config = {} if mode is None else {'json_duplicate_keys': mode}
+    v = SchemaValidator(core_schema.any_schema(), config)
+    input_value = '{"a": 1, "b": {"c": 3, "c": 4}, "\\u0061": 2}'
+    if input_type is bytes:
+        input_value = input_value.encode()
+    assert v.validate_json(input_value) == expected
+
+
+@pytest.mark.parametrize('input_type', [str, bytes])
+@pytest.mark.parametrize(
+    'input_value,key,position',
+    [
+        ('{"a": 1, "a": 2}', 'a', 9),
+        ('{"a": 1, "\\u0061": 2}', 'a', 9),
+        ('[{"x": {"a": [], "b": 1}}, {"x": {"b": 1,\n "b": 2}}]', 'b', 43),
+        ('{"a": {"a": 1}, "b": 1, "b": {"c": 1}}', 'b', 24),
+    ],
+)
+def test_json_duplicate_keys_error(input_type, input_value, key, position):
+    v = SchemaValidator(core_schema.any_schema(), {'json_duplicate_keys': 'error'})
+    if input_type is bytes:
+        input_value = input_value.encode()
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(input_value)
+    # insert_assert(exc_info.value.errors())
+    assert exc_info.value.errors() == [
+        {
+            'type': 'json_duplicate_key',
+            'loc': (),
+            'msg': f"Duplicate key '{key}' in JSON object at byte {position}",
+            'input': input_value,
+            'ctx': {'key': key, 'position': position},
+        }
+    ]
+    assert v.isinstance_json(input_value) is False
+
+
+def test_json_duplicate_keys_error_valid():
+    v = SchemaValidator(core_schema.any_schema(), {'json_duplicate_keys': 'error'})
+    # the same key in different objects isn't a duplicate
+    assert v.validate_json('[{"a": 1}, {"a": 2, "b": {"a": 3}}]') == [{'a': 1}, {'a': 2, 'b': {'a': 3}}]
+    assert v.validate_json('{"a": 1, "A": 2, "a ": 3}') == {'a': 1, 'A': 2, 'a ': 3}
+
+
+def test_json_duplicate_keys_json_schema():
+    v = SchemaValidator(core_schema.json_schema(), {'json_duplicate_keys': 'error'})
+    with pytest.raises(ValidationError, match="Duplicate key 'a' in JSON object at byte 9"):
+        v.validate_python('{"a": 1, "a": 2}')
+
+
+def test_json_duplicate_keys_invalid_config():
+    with pytest.raises(SchemaError, match='Invalid json_duplicate_keys: `both`, expected `last`, `first` or `error`'):
+        SchemaValidator(core_schema.any_schema(), {'json_duplicate_keys': 'both'})
+
+
+def test_validate_json_bytes():
+    v = SchemaValidator(core_schema.list_schema(core_schema.str_schema()))
+    assert v.validate_json_bytes('["abc", "café", "\\u00e9"]'.encode()) == ['abc', 'café', 'é']
+    with pytest.raises(ValidationError, match='Input should be a valid string'):
+        v.validate_json_bytes(b'["abc", 1]')
+    with pytest.raises(TypeError, match="'str' object cannot be converted to 'PyBytes'"):
+        v.validate_json_bytes('["abc"]')
+
+
+@pytest.mark.parametrize(
+    'input_value,error,position,line,column,expected',
+    [
+        (b'["abc", "\xff"]', 'invalid unicode code point at line 1 column 11', 9, 1, 11, None),
+        (b'["abc", "d\xe9f"]', 'invalid unicode code point at line 1 column 13', 10, 1, 13, None),
+        (b'[1,', 'EOF while parsing a value at line 1 column 3', 3, 1, 3, 'expected value'),
+        (b'', 'EOF while parsing a value at line 1 column 0', 0, 1, 0, 'expected value'),
+        (b'{\n  "a": x}', 'expected value at line 2 column 8', 9, 2, 8, 'expected value'),
+        (b'["a"] x', 'trailing characters at line 1 column 7', 6, 1, 7, 'expected end of input'),
+    ],
+)
+def test_validate_json_bytes_invalid(input_value, error, position, line, column, expected):
+    v = SchemaValidator(core_schema.any_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json_bytes(input_value)
+    ctx = {'error': error, 'position': position, 'line': line, 'column': column}
+    if expected is not None:
+        ctx['expected'] = expected
+    assert exc_info.value.errors() == [
+        {'type': 'json_invalid', 'loc': (), 'msg': f'Invalid JSON: {error}', 'input': input_value, 'ctx': ctx}
+    ]
+
+
+def test_validate_json_bytes_max_str_bytes():
+    v = SchemaValidator(core_schema.any_schema(), {'max_str_bytes': 5})
+    with pytest.raises(ValidationError, match='JSON string at byte 4 should have at most 5 bytes'):
+        v.validate_json_bytes(b'[1, "abcdef"]')
 
 
 class Foobar:
@@ -253,13 +529,8 @@ def test_to_jsonable_python_schema_serializer():
     instance = Foobar(my_foo=1, my_bar='a')
     assert to_jsonable_python(instance) == {'myFoo': 1, 'myBar': 'a'}
     assert to_jsonable_python(instance, by_alias=False) == {'my_foo': 1, 'my_bar': 'a'}
-
-    # Just including this to document the behavior. Note that trying to get `to_json` to respect aliases
-    # by putting the by_alias field into the `extra` in `to_json` won't work because it always uses an
-    # AnySchema for serialization. If you want any python-introspection to happen during the json serialization
-    # process for the purpose of respecting objects' own serialization schemas, you need to call
-    # to_jsonable_python and serialize the result yourself.
-    assert to_json(instance) == b'{"my_foo":1,"my_bar":"a"}'
+    assert to_json(instance) == b'{"myFoo":1,"myBar":"a"}'
+    assert to_json(instance, by_alias=False) == b'{"my_foo":1,"my_bar":"a"}'
 
 
 def test_cycle_same():
@@ -288,6 +559,31 @@ def test_cycle_change():
         to_json(f, fallback=fallback_func_change_id)
 
 
+def test_cycle_error():
+    a = [1]
+    a.append(a)
+    d = {'a': a}
+    d['d'] = d
+    for value in a, d:
+        with pytest.raises(PydanticSerializationError, match=r'^Circular reference detected \(id repeated\)$'):
+            to_jsonable_python(value)
+        with pytest.raises(PydanticSerializationError, match=r'^Circular reference detected \(id repeated\)$'):
+            to_json(value)
+
+
+def test_fallback_recursive():
+    def fallback(obj):
+        # the returned value is serialized too, including by calling `fallback` again
+        if isinstance(obj, Foobar):
+            return {'date': date(2020, 1, 1), 'items': {b'x'}, 'hash': FoobarHash()}
+        return str(obj)
+
+    assert to_jsonable_python([Foobar()], fallback=fallback) == [
+        {'date': '2020-01-01', 'items': ['x'], 'hash': 'Foobar.__str__'}
+    ]
+    assert to_json([Foobar()], fallback=fallback) == b'[{"date":"2020-01-01","items":["x"],"hash":"Foobar.__str__"}]'
+
+
 class FoobarHash:
     def __str__(self):
         return 'Foobar.__str__'
@@ -0,0 +1,164 @@
+import json
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
+
+
+def int_type_error(loc, input_value):
+    return {'type': 'int_type', 'loc': loc, 'msg': 'Input should be a valid integer', 'input': input_value}
+
+
+@pytest.mark.parametrize('config,kwargs', [({'max_errors': 3}, {}), ({}, {'max_errors': 3})])
+def test_list(config, kwargs):
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()), config)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([None] * 1000, **kwargs)
+    exc = exc_info.value
+    assert exc.errors() == [int_type_error((0,), None), int_type_error((1,), None), int_type_error((2,), None)]
+    # error_count() is the number of errors in the exception
+    assert exc.error_count() == 3
+    assert exc.truncated is True
+    assert str(exc).endswith('\n[validation stopped at max_errors, there may be more errors]')
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(json.dumps([None] * 1000), **kwargs)
+    assert exc_info.value.error_count() == 3
+    assert exc_info.value.truncated is True
+
+
+def test_stops_validating_items():
+    calls = []
+
+    def f(input_value, info):
+        calls.append(input_value)
+        raise ValueError('bad')
+
+    v = SchemaValidator(core_schema.list_schema(core_schema.general_plain_validator_function(f)), {'max_errors': 2})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(list(range(100)))
+    assert exc_info.value.error_count() == 2
+    assert calls == [0, 1]
+
+
+def test_under_limit():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()), {'max_errors': 3})
+    assert v.validate_python([1, 2]) == [1, 2]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, None, None])
+    assert exc_info.value.error_count() == 2
+    assert exc_info.value.truncated is False
+    assert 'max_errors' not in str(exc_info.value)
+
+
+@pytest.mark.parametrize(
+    'input_value,truncated',
+    [([None] * 3, False), ([1, None, None, None], False), ([None] * 3 + [1], True), ([None] * 4, True)],
+)
+def test_exactly_max_errors(input_value, truncated):
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()), {'max_errors': 3})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert exc_info.value.error_count() == 3
+    # only truncated if validation stopped before the last item
+    assert exc_info.value.truncated is truncated
+
+
+@pytest.mark.parametrize(
+    'input_value,truncated',
+    [([[None, None], [None]], False), ([[None, None], [None, 1]], True), ([[None, None], [None], []], True)],
+)
+def test_exactly_max_errors_nested(input_value, truncated):
+    v = SchemaValidator(core_schema.list_schema(core_schema.list_schema(core_schema.int_schema())), {'max_errors': 3})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert exc_info.value.error_count() == 3
+    assert exc_info.value.truncated is truncated
+
+
+def test_call_overrides_config():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()), {'max_errors': 3})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([None] * 10, max_errors=5)
+    assert exc_info.value.error_count() == 5
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([None] * 10)
+    assert exc_info.value.error_count() == 3
+
+
+def test_no_limit():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([None] * 1000)
+    assert exc_info.value.error_count() == 1000
+    assert exc_info.value.truncated is False
+
+
+def test_shared_across_nested():
+    v = SchemaValidator(
+        core_schema.dict_schema(core_schema.str_schema(), core_schema.list_schema(core_schema.int_schema())),
+        {'max_errors': 4},
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': [1, None, None], 'b': [None, None, None], 'c': [None] * 100})
+    # the limit applies to all errors, not to each list
+    assert exc_info.value.errors() == [
+        int_type_error(('a', 1), None),
+        int_type_error(('a', 2), None),
+        int_type_error(('b', 0), None),
+        int_type_error(('b', 1), None),
+    ]
+    assert exc_info.value.truncated is True
+
+
+@pytest.mark.parametrize(
+    'schema,input_value',
+    [
+        (core_schema.tuple_variable_schema(core_schema.int_schema()), (None,) * 10),
+        (core_schema.set_schema(core_schema.int_schema()), ['a', 'b', 'c', 'd', 'e']),
+        (core_schema.frozenset_schema(core_schema.int_schema()), ['a', 'b', 'c', 'd', 'e']),
+        (core_schema.dict_schema(core_schema.int_schema(), core_schema.int_schema()), {str(i): 'x' for i in range(9)}),
+    ],
+)
+def test_collections(schema, input_value):
+    v = SchemaValidator(core_schema.list_schema(schema), {'max_errors': 2})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([input_value])
+    assert exc_info.value.error_count() == 2
+    assert exc_info.value.truncated is True
+
+
+def test_typed_dict_capped():
+    # validators without an early stop are still cut to `max_errors`
+    v = SchemaValidator(
+        core_schema.typed_dict_schema({k: core_schema.typed_dict_field(core_schema.int_schema()) for k in 'abcde'}),
+        {'max_errors': 2},
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({})
+    assert [e['loc'] for e in exc_info.value.errors()] == [('a',), ('b',)]
+    assert exc_info.value.truncated is True
+
+
+def test_union_discards_failed_choices():
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.union_schema(
+                [core_schema.list_schema(core_schema.int_schema()), core_schema.list_schema(core_schema.str_schema())]
+            )
+        ),
+        {'max_errors': 3},
+    )
+    # each item fails the first choice with 3 errors but matches the second
+    assert v.validate_python([['a', 'b', 'c']] * 5) == [['a', 'b', 'c']] * 5
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([['a', 'b', 'c'], [1, 'x', None], [None]])
+    assert exc_info.value.error_count() == 3
+
+
+def test_invalid():
+    with pytest.raises(SchemaError, match='max_errors must be greater than 0'):
+        SchemaValidator(core_schema.int_schema(), {'max_errors': 0})
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(ValueError, match='max_errors must be greater than 0'):
+        v.validate_python(1, max_errors=0)
@@ -0,0 +1,117 @@
+import re
+import time
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+def budget_error(loc, input_value):
+    return {
+        'type': 'validation_budget_exceeded',
+        'loc': loc,
+        'msg': 'Validation exceeded the maximum number of steps',
+        'input': input_value,
+    }
+
+
+def test_unlimited():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    assert v.validate_python([1, 2, 3]) == [1, 2, 3]
+    assert v.validate_python([1, 2, 3], max_steps=None) == [1, 2, 3]
+    assert v.validate_python([1, 2, 3], max_steps=3) == [1, 2, 3]
+
+
+def test_list():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 2, 3, 4], max_steps=3)
+    assert exc_info.value.errors() == [budget_error((3,), 4)]
+
+
+def test_nested_loc():
+    v = SchemaValidator(core_schema.list_schema(core_schema.list_schema(core_schema.int_schema())))
+    # one step for each outer and each inner item
+    assert v.validate_python([[1, 2], [3, 4]], max_steps=6) == [[1, 2], [3, 4]]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([['x', 2], [3, 4]], max_steps=5)
+    # other errors are dropped, the error is where the budget ran out
+    assert exc_info.value.errors() == [budget_error((1, 1), 4)]
+
+
+def test_dict():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1, 'b': 2, 'c': 3}, max_steps=2)
+    assert exc_info.value.errors() == [budget_error(('c',), 3)]
+
+
+def test_union():
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.union_schema(
+                [core_schema.int_schema(strict=True), core_schema.float_schema(strict=True), core_schema.str_schema()]
+            )
+        )
+    )
+    # each item is a step, as is each union choice tried: 1 for `1` and 3 for `'x'`
+    assert v.validate_python([1, 'x'], max_steps=6) == [1, 'x']
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 'x'], max_steps=5)
+    assert exc_info.value.errors() == [budget_error((1,), 'x')]
+
+
+def test_adversarial_fails_fast():
+    choices = [
+        core_schema.typed_dict_schema(
+            {f'field_{i}': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema()))}
+        )
+        for i in range(20)
+    ]
+    v = SchemaValidator(core_schema.list_schema(core_schema.union_schema(choices)))
+    # every item fails every choice, after trying the whole list of ints in each
+    input_value = [{'field_19': list(range(1000)) + ['x']} for _ in range(1000)]
+
+    start = time.perf_counter()
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value, max_steps=10_000)
+    duration = time.perf_counter() - start
+
+    assert exc_info.value.errors() == [budget_error((9, 'typed-dict', 'field_19', 781), 781)]
+    # without a budget this is ~40 million steps
+    assert duration < 1
+
+
+def test_json_parse_phase():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    # parsing uses a step for each value, so 4 for the list and its items
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[1, 2, 3]', max_steps=3)
+    assert exc_info.value.errors() == [budget_error((), '[1, 2, 3]')]
+
+    # the rest of the budget is used by validation
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[1, 2, 3]', max_steps=6)
+    assert exc_info.value.errors() == [budget_error((2,), 3)]
+
+    assert v.validate_json('[1, 2, 3]', max_steps=7) == [1, 2, 3]
+    assert v.validate_json('[1, 2, 3]') == [1, 2, 3]
+
+
+def test_json_keys():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()))
+    # the object, its key and its value
+    with pytest.raises(ValidationError, match=re.escape('Validation exceeded the maximum number of steps')):
+        v.validate_json('{"a": 1}', max_steps=2)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"a": 1}', max_steps=3)
+    assert exc_info.value.errors() == [budget_error(('a',), 1)]
+    assert v.validate_json('{"a": 1}', max_steps=4) == {'a': 1}
+
+
+def test_nested_json():
+    v = SchemaValidator(core_schema.list_schema(core_schema.json_schema(core_schema.list_schema())))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['[1, 2]', '[3, 4]'], max_steps=5)
+    assert exc_info.value.errors() == [budget_error((1,), '[3, 4]')]
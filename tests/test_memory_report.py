@@ -0,0 +1,92 @@
+from pydantic_core import SchemaSerializer, SchemaValidator, core_schema
+
+
+def f(value):
+    return value
+
+
+def test_memory_report():
+    schema = core_schema.typed_dict_schema(
+        {
+            'a': core_schema.typed_dict_field(core_schema.str_schema(pattern='a+')),
+            'b': core_schema.typed_dict_field(
+                core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()])
+            ),
+            'c': core_schema.typed_dict_field(
+                core_schema.no_info_after_validator_function(f, core_schema.str_schema())
+            ),
+            'd': core_schema.typed_dict_field(core_schema.with_default_schema(core_schema.int_schema(), default=1)),
+            'e': core_schema.typed_dict_field(core_schema.is_instance_schema(int), metadata={'ignored': object()}),
+        }
+    )
+    report = SchemaValidator(schema).memory_report()
+    assert report == {
+        'slots': 0,
+        'nodes': 15,
+        'kinds': {
+            'default': 1,
+            'function-after': 1,
+            'int': 2,
+            'is-instance': 1,
+            'str': 3,
+            'typed-dict': 1,
+            'typed-dict-field': 5,
+            'union': 1,
+        },
+        # the function, the default and the class
+        'py_refs': 3,
+        'estimated_bytes': report['estimated_bytes'],
+    }
+    assert report['estimated_bytes'] > 15
+
+    ser_report = SchemaSerializer(schema).memory_report()
+    assert {k: v for k, v in ser_report.items() if k != 'estimated_bytes'} == {
+        k: v for k, v in report.items() if k != 'estimated_bytes'
+    }
+    assert ser_report['estimated_bytes'] > 15
+
+
+def test_more_fields_more_bytes():
+    def report(fields):
+        schema = core_schema.typed_dict_schema(
+            {f'field_{i}': core_schema.typed_dict_field(core_schema.int_schema()) for i in range(fields)}
+        )
+        return SchemaValidator(schema).memory_report()
+
+    assert report(1)['nodes'] == 3
+    assert report(10)['nodes'] == 21
+    assert report(10)['estimated_bytes'] > report(1)['estimated_bytes']
+
+
+def test_recursive():
+    schema = core_schema.definitions_schema(
+        core_schema.definition_reference_schema('node'),
+        [
+            core_schema.typed_dict_schema(
+                {
+                    'value': core_schema.typed_dict_field(core_schema.int_schema()),
+                    'next': core_schema.typed_dict_field(
+                        core_schema.nullable_schema(core_schema.definition_reference_schema('node'))
+                    ),
+                },
+                ref='node',
+            )
+        ],
+    )
+    v = SchemaValidator(schema)
+    assert v.memory_report() == {
+        'slots': 1,
+        'nodes': 8,
+        'kinds': {
+            'definition-ref': 2,
+            'definitions': 1,
+            'int': 1,
+            'nullable': 1,
+            'typed-dict': 1,
+            'typed-dict-field': 2,
+        },
+        'py_refs': 0,
+        'estimated_bytes': v.memory_report()['estimated_bytes'],
+    }
+    assert SchemaSerializer(schema).memory_report()['slots'] == 1
+
@@ -0,0 +1,119 @@
+import copy
+import pickle
+import subprocess
+import sys
+from pathlib import Path
+
+import pytest
+
+from pydantic_core import SchemaSerializer, SchemaValidator, ValidationError, core_schema
+
+ROOT = Path(__file__).parent.parent
+
+
+def double(value):
+    return value * 2
+
+
+def default_tags():
+    return ['a']
+
+
+def model_schema():
+    return core_schema.typed_dict_schema(
+        {
+            'name': core_schema.typed_dict_field(core_schema.str_schema(max_length=5)),
+            'age': core_schema.typed_dict_field(
+                core_schema.no_info_after_validator_function(double, core_schema.int_schema())
+            ),
+            'tags': core_schema.typed_dict_field(
+                core_schema.with_default_schema(
+                    core_schema.list_schema(core_schema.str_schema()), default_factory=default_tags
+                ),
+                required=False,
+            ),
+        }
+    )
+
+
+INPUTS = [{'name': 'Sam', 'age': 21}, {'name': 'Samantha', 'age': 'x', 'tags': [1]}, 'not a dict']
+
+
+def outputs(v):
+    results = []
+    for input_value in INPUTS:
+        try:
+            results.append(['ok', v.validate_python(input_value)])
+        except ValidationError as e:
+            results.append(['error', e.json()])
+    return results
+
+
+@pytest.mark.parametrize('pickle_protocol', range(1, pickle.HIGHEST_PROTOCOL + 1))
+def test_pickle_validator(pickle_protocol: int):
+    v = SchemaValidator(model_schema(), {'str_to_upper': True})
+    v2 = pickle.loads(pickle.dumps(v, protocol=pickle_protocol))
+    assert v2 is not v
+    assert outputs(v2) == outputs(v)
+    assert v2.validate_python({'name': 'sam', 'age': 1}) == {'name': 'SAM', 'age': 2, 'tags': ['a']}
+
+
+@pytest.mark.parametrize('pickle_protocol', range(1, pickle.HIGHEST_PROTOCOL + 1))
+def test_pickle_serializer(pickle_protocol: int):
+    s = SchemaSerializer(core_schema.list_schema(core_schema.bytes_schema()), {'ser_json_bytes': 'base64'})
+    s2 = pickle.loads(pickle.dumps(s, protocol=pickle_protocol))
+    assert s2 is not s
+    assert s2.to_json([b'foo']) == s.to_json([b'foo']) == b'["Zm9v"]'
+
+
+def test_copy():
+    v = SchemaValidator(model_schema())
+    s = SchemaSerializer(core_schema.int_schema())
+    assert copy.copy(v) is v
+    assert copy.deepcopy(v) is v
+    assert copy.deepcopy({'v': v})['v'] is v
+    assert outputs(copy.deepcopy(v)) == outputs(v)
+    assert copy.copy(s) is s
+    assert copy.deepcopy(s) is s
+    assert copy.deepcopy(s).to_python(1) == 1
+
+
+def test_pickle_lambda():
+    schema = core_schema.typed_dict_schema(
+        {
+            'a': core_schema.typed_dict_field(
+                core_schema.no_info_plain_validator_function(lambda value: value),
+            )
+        }
+    )
+    v = SchemaValidator(schema)
+    with pytest.raises(pickle.PicklingError) as exc_info:
+        pickle.dumps(v)
+    assert str(exc_info.value).startswith(
+        "Can't pickle SchemaValidator, schema['fields']['a']['schema']['function']['function'] "
+        "in the 'function-plain' schema can't be pickled: "
+    )
+    assert exc_info.value.__cause__ is not None
+
+    with pytest.raises(pickle.PicklingError, match=r"^Can't pickle SchemaSerializer, schema\['fields'\]"):
+        pickle.dumps(SchemaSerializer(schema))
+
+
+def test_pickle_default_factory_lambda():
+    v = SchemaValidator(core_schema.with_default_schema(core_schema.int_schema(), default_factory=lambda: 1))
+    with pytest.raises(pickle.PicklingError, match=r"schema\['default_factory'\] in the 'default' schema"):
+        pickle.dumps(v)
+
+
+def test_pickle_subprocess():
+    v = SchemaValidator(model_schema(), {'str_to_upper': True})
+    code = (
+        'import pickle, sys\n'
+        'from tests.test_pickle import outputs\n'
+        'v = pickle.load(sys.stdin.buffer)\n'
+        'pickle.dump(outputs(v), sys.stdout.buffer)\n'
+    )
+    result = subprocess.run(
+        [sys.executable, '-c', code], input=pickle.dumps(v), cwd=ROOT, capture_output=True, check=True
+    )
+    assert pickle.loads(result.stdout) == outputs(v)
@@ -0,0 +1,134 @@
+from datetime import date
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+@pytest.fixture(scope='module')
+def validator():
+    return SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'id': core_schema.typed_dict_field(core_schema.int_schema()),
+                'tags': core_schema.typed_dict_field(core_schema.list_schema(core_schema.str_schema())),
+                'created': core_schema.typed_dict_field(core_schema.date_schema()),
+            }
+        )
+    )
+
+
+def test_top_level(validator):
+    input_value = {'id': '123', 'tags': ['a', b'b'], 'created': '2022-01-01'}
+    output, raw = validator.validate_python_with_raw(input_value)
+    assert output == {'id': 123, 'tags': ['a', 'b'], 'created': date(2022, 1, 1)}
+    assert raw is input_value
+
+
+def test_record_nodes(validator):
+    input_value = {'id': '123', 'tags': ['a', b'b'], 'created': '2022-01-01'}
+    output, raw = validator.validate_python_with_raw(input_value, record_nodes=True)
+    assert output == {'id': 123, 'tags': ['a', 'b'], 'created': date(2022, 1, 1)}
+    # values which weren't coerced are recorded too, containers as well as their items
+    assert raw == {
+        (): input_value,
+        ('id',): '123',
+        ('tags',): ['a', b'b'],
+        ('tags', 0): 'a',
+        ('tags', 1): b'b',
+        ('created',): '2022-01-01',
+    }
+
+
+def test_json(validator):
+    output, raw = validator.validate_json_with_raw('{"id": "123", "tags": ["a"], "created": "2022-01-01"}')
+    assert output == {'id': 123, 'tags': ['a'], 'created': date(2022, 1, 1)}
+    assert raw == {'id': '123', 'tags': ['a'], 'created': '2022-01-01'}
+
+    output, raw = validator.validate_json_with_raw('{"id": 1.0, "tags": [], "created": 0}', record_nodes=True)
+    assert output == {'id': 1, 'tags': [], 'created': date(1970, 1, 1)}
+    assert raw == {(): {'id': 1.0, 'tags': [], 'created': 0}, ('id',): 1.0, ('tags',): [], ('created',): 0}
+    assert type(raw[('id',)]) == float
+
+
+def test_union_losing_choices():
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [
+                core_schema.typed_dict_schema(
+                    {
+                        'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                        'b': core_schema.typed_dict_field(core_schema.int_schema()),
+                    }
+                ),
+                core_schema.typed_dict_schema(
+                    {
+                        'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                        'b': core_schema.typed_dict_field(core_schema.str_schema()),
+                    }
+                ),
+            ]
+        )
+    )
+    # only inputs to the choice used in the output are recorded
+    assert v.validate_python_with_raw({'a': '1', 'b': 'x'}, record_nodes=True) == (
+        {'a': 1, 'b': 'x'},
+        {
+            (): {'a': '1', 'b': 'x'},
+            ('typed-dict',): {'a': '1', 'b': 'x'},
+            ('typed-dict', 'a'): '1',
+            ('typed-dict', 'b'): 'x',
+        },
+    )
+
+
+def test_record_every_node():
+    class Foo:
+        pass
+
+    foo = Foo()
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'literal': core_schema.typed_dict_field(core_schema.literal_schema(['a'])),
+                'none': core_schema.typed_dict_field(core_schema.none_schema()),
+                'instance': core_schema.typed_dict_field(core_schema.is_instance_schema(Foo)),
+                'tuple': core_schema.typed_dict_field(core_schema.tuple_variable_schema(core_schema.int_schema())),
+                'dict': core_schema.typed_dict_field(
+                    core_schema.dict_schema(core_schema.int_schema(), core_schema.list_schema(core_schema.int_schema()))
+                ),
+            }
+        )
+    )
+    input_value = {'literal': 'a', 'none': None, 'instance': foo, 'tuple': ['1', 2], 'dict': {'3': (4,)}}
+    output, raw = v.validate_python_with_raw(input_value, record_nodes=True)
+    assert output == {'literal': 'a', 'none': None, 'instance': foo, 'tuple': (1, 2), 'dict': {3: [4]}}
+    # leaves which never coerce and containers coerced to another type are recorded like any other value
+    assert raw == {
+        (): input_value,
+        ('literal',): 'a',
+        ('none',): None,
+        ('instance',): foo,
+        ('tuple',): ['1', 2],
+        ('tuple', 0): '1',
+        ('tuple', 1): 2,
+        ('dict',): {'3': (4,)},
+        ('dict', '3', '[key]'): '3',
+        ('dict', '3'): (4,),
+        ('dict', '3', 0): 4,
+    }
+    assert type(raw[('tuple',)]) is list
+
+
+def test_strict(validator):
+    with pytest.raises(ValidationError, match=r'id\n  Input should be a valid integer \[type=int_type'):
+        validator.validate_python_with_raw({'id': '123', 'tags': [], 'created': date(2022, 1, 1)}, strict=True)
+    assert validator.validate_json_with_raw('{"id": 1, "tags": [], "created": "2022-01-01"}', strict=True) == (
+        {'id': 1, 'tags': [], 'created': date(2022, 1, 1)},
+        {'id': 1, 'tags': [], 'created': '2022-01-01'},
+    )
+
+
+def test_invalid_json(validator):
+    with pytest.raises(ValidationError, match=r'Invalid JSON: EOF while parsing an object'):
+        validator.validate_json_with_raw('{"id": 1')
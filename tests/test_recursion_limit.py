@@ -0,0 +1,162 @@
+import json
+import platform
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
+
+DEFAULT_RECURSION_LIMIT = 123 if platform.python_implementation() == 'PyPy' else 255
+MAX_RECURSION_LIMIT = 250 if platform.python_implementation() == 'PyPy' else 500
+
+
+@pytest.fixture(scope='module')
+def nested_list_schema():
+    return core_schema.definitions_schema(
+        core_schema.definition_reference_schema('Nested'),
+        [core_schema.list_schema(core_schema.definition_reference_schema('Nested'), ref='Nested')],
+    )
+
+
+def nested_list(depth):
+    data = []
+    for _ in range(depth - 1):
+        data = [data]
+    return data
+
+
+def recursion_error(loc, input_value):
+    return {
+        'type': 'recursion_loop',
+        'loc': loc,
+        'msg': 'Recursion error - cyclic reference detected',
+        'input': input_value,
+        'ctx': {'ref': 'Nested'},
+    }
+
+
+def test_default(nested_list_schema):
+    v = SchemaValidator(nested_list_schema)
+    data = nested_list(DEFAULT_RECURSION_LIMIT)
+    assert v.validate_python(data) == data
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([data])
+    errors = exc_info.value.errors()
+    assert len(errors) == 1
+    assert errors[0]['type'] == 'recursion_loop'
+    # locations of recursion errors are truncated
+    assert errors[0]['loc'] == (0,) * 20 + ('...',)
+
+
+@pytest.mark.parametrize('config,kwargs', [({'recursion_limit': 10}, {}), ({}, {'recursion_limit': 10})])
+def test_python_boundary(nested_list_schema, config, kwargs):
+    v = SchemaValidator(nested_list_schema, config)
+    assert v.validate_python(nested_list(10), **kwargs) == nested_list(10)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(nested_list(11), **kwargs)
+    # the limit is hit at the innermost list
+    assert exc_info.value.errors() == [recursion_error((0,) * 10, [])]
+
+
+def test_call_overrides_config(nested_list_schema):
+    v = SchemaValidator(nested_list_schema, {'recursion_limit': 10})
+    data = nested_list(300)
+    assert v.validate_python(data, recursion_limit=300) == data
+    with pytest.raises(ValidationError, match='Recursion error'):
+        v.validate_python(nested_list(5), recursion_limit=4)
+    # the config's limit is used again afterwards
+    with pytest.raises(ValidationError, match='Recursion error'):
+        v.validate_python(data)
+
+
+def test_cycle_independent_of_limit(nested_list_schema):
+    v = SchemaValidator(nested_list_schema, {'recursion_limit': MAX_RECURSION_LIMIT})
+    data = []
+    data.append(data)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(data)
+    # found by identity straight away, not by reaching the limit
+    assert exc_info.value.errors() == [recursion_error((0,), data)]
+
+
+def test_json_boundary(nested_list_schema):
+    v = SchemaValidator(nested_list_schema, {'recursion_limit': 10})
+    assert v.validate_json(json.dumps(nested_list(10))) == nested_list(10)
+    input_value = json.dumps(nested_list(11))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(input_value)
+    # insert_assert(exc_info.value.errors())
+    assert exc_info.value.errors() == [
+        {
+            'type': 'json_invalid',
+            'loc': (),
+            'msg': 'Invalid JSON: recursion limit exceeded at line 1 column 12',
+            'input': input_value,
+            'ctx': {'error': 'recursion limit exceeded at line 1 column 12', 'position': 11, 'line': 1, 'column': 12},
+        }
+    ]
+
+
+@pytest.mark.parametrize('input_value', [b'[[[[[[]]]]]]', b'{"a": [{"b": [[[1]]]}]}'])
+def test_json_any_boundary(input_value):
+    v = SchemaValidator(core_schema.any_schema())
+    assert v.validate_json(input_value, recursion_limit=6) == json.loads(input_value)
+    with pytest.raises(ValidationError, match='Invalid JSON: recursion limit exceeded at line 1 column'):
+        v.validate_json(input_value, recursion_limit=5)
+    with pytest.raises(ValidationError, match='Invalid JSON: recursion limit exceeded at line 1 column'):
+        v.validate_json(input_value, recursion_limit=5, allow_partial=True)
+
+
+def test_json_deeper_than_default():
+    v = SchemaValidator(core_schema.any_schema(), {'recursion_limit': 500})
+    data = nested_list(300)
+    assert v.validate_json(json.dumps(data)) == data
+    with pytest.raises(ValidationError, match='Invalid JSON: recursion limit exceeded'):
+        SchemaValidator(core_schema.any_schema()).validate_json(json.dumps(data))
+
+
+def test_json_schema_nested():
+    v = SchemaValidator(core_schema.list_schema(core_schema.json_schema()), {'recursion_limit': 3})
+    assert v.validate_python(['[[[]]]']) == [[[[]]]]
+    with pytest.raises(ValidationError, match='Invalid JSON: recursion limit exceeded'):
+        v.validate_python(['[[[[]]]]'])
+
+
+@pytest.mark.parametrize(
+    'config,kwargs', [({'recursion_limit': MAX_RECURSION_LIMIT}, {}), ({}, {'recursion_limit': MAX_RECURSION_LIMIT})]
+)
+def test_max_limit(nested_list_schema, config, kwargs):
+    v = SchemaValidator(nested_list_schema, config)
+    data = nested_list(MAX_RECURSION_LIMIT)
+    assert v.validate_python(data, **kwargs) == data
+    assert v.validate_json(json.dumps(data), **kwargs) == data
+    # far deeper input is an error, not a stack overflow
+    deep = nested_list(20_000)
+    with pytest.raises(ValidationError, match='Recursion error - cyclic reference detected'):
+        v.validate_python(deep, **kwargs)
+    with pytest.raises(ValidationError, match='Invalid JSON: recursion limit exceeded'):
+        v.validate_json('[' * 20_000 + ']' * 20_000, **kwargs)
+    any_validator = SchemaValidator(core_schema.any_schema(), config)
+    with pytest.raises(ValidationError, match='Invalid JSON: recursion limit exceeded'):
+        any_validator.validate_json('[' * 20_000 + ']' * 20_000, **kwargs)
+
+
+@pytest.mark.parametrize('limit', [MAX_RECURSION_LIMIT + 1, 2**16, 10**9, 2**100])
+def test_limit_too_high(nested_list_schema, limit):
+    with pytest.raises(SchemaError, match=f'recursion_limit must be at most {MAX_RECURSION_LIMIT}'):
+        SchemaValidator(nested_list_schema, {'recursion_limit': limit})
+    v = SchemaValidator(nested_list_schema)
+    with pytest.raises(ValueError, match=f'recursion_limit must be at most {MAX_RECURSION_LIMIT}'):
+        v.validate_python([], recursion_limit=limit)
+    with pytest.raises(ValueError, match=f'recursion_limit must be at most {MAX_RECURSION_LIMIT}'):
+        v.validate_json('[]', recursion_limit=limit)
+
+
+@pytest.mark.parametrize('limit', [-1, -(2**100)])
+def test_limit_negative(nested_list_schema, limit):
+    with pytest.raises(SchemaError, match='recursion_limit must be at least 0'):
+        SchemaValidator(nested_list_schema, {'recursion_limit': limit})
+    v = SchemaValidator(nested_list_schema)
+    with pytest.raises(ValueError, match='recursion_limit must be at least 0'):
+        v.validate_python([], recursion_limit=limit)
+    with pytest.raises(ValueError, match='recursion_limit must be at least 0'):
+        v.validate_json('[]', recursion_limit=limit)
@@ -1,5 +1,6 @@
 import re
 from datetime import date
+from enum import Enum
 from typing import Any
 
 import pytest
@@ -19,6 +20,10 @@ class MyModel:
     __slots__ = '__dict__', '__pydantic_fields_set__'
 
 
+class MyEnum(Enum):
+    a = 1
+
+
 def ids_function(val):
     if callable(val):
         return val.__name__
@@ -43,16 +48,58 @@ all_schema_functions = [
     (core_schema.int_schema, args(multiple_of=5, gt=10, lt=20), {'type': 'int', 'multiple_of': 5, 'gt': 10, 'lt': 20}),
     (core_schema.float_schema, args(), {'type': 'float'}),
     (core_schema.float_schema, args(multiple_of=5, gt=1.2), {'type': 'float', 'multiple_of': 5, 'gt': 1.2}),
+    (
+        core_schema.float_schema,
+        args(number_format={'thousands_sep': '.', 'decimal_sep': ','}),
+        {'type': 'float', 'number_format': {'thousands_sep': '.', 'decimal_sep': ','}},
+    ),
     (core_schema.str_schema, args(), {'type': 'str'}),
     (core_schema.str_schema, args(min_length=5, max_length=10), {'type': 'str', 'min_length': 5, 'max_length': 10}),
+    (core_schema.str_schema, args(post_validator=val_function), {'type': 'str', 'post_validator': val_function}),
+    (
+        core_schema.str_schema,
+        args(pattern='^a', regex_engine='python-re'),
+        {'type': 'str', 'pattern': '^a', 'regex_engine': 'python-re'},
+    ),
     (core_schema.bytes_schema, args(), {'type': 'bytes'}),
     (core_schema.bytes_schema, args(min_length=5, ref='xx'), {'type': 'bytes', 'min_length': 5, 'ref': 'xx'}),
     (core_schema.date_schema, args(), {'type': 'date'}),
     (core_schema.date_schema, args(gt=date(2020, 1, 1)), {'type': 'date', 'gt': date(2020, 1, 1)}),
+    (
+        core_schema.date_schema,
+        args(val_temporal_unit='milliseconds'),
+        {'type': 'date', 'val_temporal_unit': 'milliseconds'},
+    ),
     (core_schema.time_schema, args(), {'type': 'time'}),
+    (
+        core_schema.time_schema,
+        args(microseconds_precision='error'),
+        {'type': 'time', 'microseconds_precision': 'error'},
+    ),
     (core_schema.datetime_schema, args(), {'type': 'datetime'}),
+    (core_schema.datetime_schema, args(tz_constraint=3600), {'type': 'datetime', 'tz_constraint': 3600}),
+    (
+        core_schema.datetime_schema,
+        args(tz_names=True, dst_policy='later'),
+        {'type': 'datetime', 'tz_names': True, 'dst_policy': 'later'},
+    ),
+    (
+        core_schema.datetime_schema,
+        args(val_temporal_unit='seconds'),
+        {'type': 'datetime', 'val_temporal_unit': 'seconds'},
+    ),
     (core_schema.timedelta_schema, args(), {'type': 'timedelta'}),
+    (
+        core_schema.timedelta_schema,
+        args(microseconds_precision='truncate'),
+        {'type': 'timedelta', 'microseconds_precision': 'truncate'},
+    ),
     (core_schema.literal_schema, args(['a', 'b']), {'type': 'literal', 'expected': ['a', 'b']}),
+    (
+        core_schema.literal_schema,
+        args(allowed_values_factory=list),
+        {'type': 'literal', 'allowed_values_factory': list},
+    ),
     (core_schema.is_instance_schema, args(int), {'type': 'is-instance', 'cls': int}),
     (core_schema.callable_schema, args(), {'type': 'callable'}),
     (core_schema.list_schema, args(), {'type': 'list'}),
@@ -128,6 +175,11 @@ all_schema_functions = [
         args({'type': 'int'}, default_factory=make_5),
         {'type': 'default', 'schema': {'type': 'int'}, 'default_factory': make_5},
     ),
+    (
+        core_schema.with_default_schema,
+        args({'type': 'int'}, default_factory=make_5, default_factory_takes_data=True),
+        {'type': 'default', 'schema': {'type': 'int'}, 'default_factory': make_5, 'default_factory_takes_data': True},
+    ),
     (core_schema.nullable_schema, args({'type': 'int'}), {'type': 'nullable', 'schema': {'type': 'int'}}),
     (
         core_schema.union_schema,
@@ -149,6 +201,11 @@ all_schema_functions = [
         args({'foo': {'type': 'int'}, 'bar': {'type': 'str'}}, 'foo'),
         {'type': 'tagged-union', 'choices': {'foo': {'type': 'int'}, 'bar': {'type': 'str'}}, 'discriminator': 'foo'},
     ),
+    (
+        core_schema.merge_schema,
+        args([{'type': 'typed-dict', 'fields': {}}], conflict='error'),
+        {'type': 'merge', 'schemas': [{'type': 'typed-dict', 'fields': {}}], 'conflict': 'error'},
+    ),
     (
         core_schema.chain_schema,
         args([{'type': 'int'}, {'type': 'str'}]),
@@ -159,11 +216,42 @@ all_schema_functions = [
         args({'type': 'int'}, required=True),
         {'type': 'typed-dict-field', 'schema': {'type': 'int'}, 'required': True},
     ),
+    (
+        core_schema.typed_dict_field,
+        args({'type': 'int'}, depends_on=['foo']),
+        {'type': 'typed-dict-field', 'schema': {'type': 'int'}, 'depends_on': ['foo']},
+    ),
     (
         core_schema.typed_dict_schema,
         args({'foo': core_schema.typed_dict_field({'type': 'int'})}),
         {'type': 'typed-dict', 'fields': {'foo': {'type': 'typed-dict-field', 'schema': {'type': 'int'}}}},
     ),
+    (
+        core_schema.typed_dict_schema,
+        args(
+            {'a': core_schema.typed_dict_field({'type': 'int'}), 'b': core_schema.typed_dict_field({'type': 'int'})},
+            field_groups=[core_schema.field_group(['a', 'b'], 'at_least_one')],
+        ),
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'a': {'type': 'typed-dict-field', 'schema': {'type': 'int'}},
+                'b': {'type': 'typed-dict-field', 'schema': {'type': 'int'}},
+            },
+            'field_groups': [{'fields': ['a', 'b'], 'mode': 'at_least_one'}],
+        },
+    ),
+    (
+        core_schema.typed_dict_schema,
+        args({}, from_attributes=True, from_attributes_mode='explicit', readable_attributes=['foo']),
+        {
+            'type': 'typed-dict',
+            'fields': {},
+            'from_attributes': True,
+            'from_attributes_mode': 'explicit',
+            'readable_attributes': ['foo'],
+        },
+    ),
     (
         core_schema.model_schema,
         args(MyModel, {'type': 'int'}),
@@ -210,11 +298,74 @@ all_schema_functions = [
     (core_schema.json_schema, args({'type': 'int'}), {'type': 'json', 'schema': {'type': 'int'}}),
     (core_schema.url_schema, args(), {'type': 'url'}),
     (core_schema.multi_host_url_schema, args(), {'type': 'multi-host-url'}),
+    (core_schema.path_schema, args(), {'type': 'path'}),
+    (
+        core_schema.path_schema,
+        args(flavor='posix', suffix_in=['.txt'], max_parts=3),
+        {'type': 'path', 'flavor': 'posix', 'suffix_in': ['.txt'], 'max_parts': 3},
+    ),
+    (core_schema.timezone_schema, args(), {'type': 'timezone'}),
+    (core_schema.fraction_schema, args(), {'type': 'fraction'}),
+    (
+        core_schema.fraction_schema,
+        args(number_format={'thousands_sep': ' ', 'decimal_sep': ','}),
+        {'type': 'fraction', 'number_format': {'thousands_sep': ' ', 'decimal_sep': ','}},
+    ),
+    (
+        core_schema.fraction_schema,
+        args(ge=0, lt='1/2', float_conversion='repr', limit_denominator=100, ser_json_fraction='pair'),
+        {
+            'type': 'fraction',
+            'ge': 0,
+            'lt': '1/2',
+            'float_conversion': 'repr',
+            'limit_denominator': 100,
+            'ser_json_fraction': 'pair',
+        },
+    ),
+    (core_schema.decimal_schema, args(), {'type': 'decimal'}),
+    (
+        core_schema.decimal_schema,
+        args(allow_inf_nan=True, multiple_of='0.5', le=10, max_digits=4, decimal_places=1),
+        {
+            'type': 'decimal',
+            'allow_inf_nan': True,
+            'multiple_of': '0.5',
+            'le': 10,
+            'max_digits': 4,
+            'decimal_places': 1,
+        },
+    ),
+    (core_schema.uuid_schema, args(), {'type': 'uuid'}),
+    (core_schema.uuid_schema, args(version=4, strict=True), {'type': 'uuid', 'version': 4, 'strict': True}),
+    (core_schema.complex_schema, args(), {'type': 'complex'}),
+    (
+        core_schema.complex_schema,
+        args(allow_inf_nan=False, strict=True),
+        {'type': 'complex', 'allow_inf_nan': False, 'strict': True},
+    ),
+    (core_schema.ip_v4_address_schema, args(), {'type': 'ip-v4-address'}),
+    (core_schema.ip_v6_address_schema, args(strict=True), {'type': 'ip-v6-address', 'strict': True}),
+    (core_schema.ip_v4_network_schema, args(), {'type': 'ip-v4-network'}),
+    (core_schema.ip_v6_network_schema, args(ref='net'), {'type': 'ip-v6-network', 'ref': 'net'}),
+    (core_schema.ip_v4_interface_schema, args(), {'type': 'ip-v4-interface'}),
+    (core_schema.ip_v6_interface_schema, args(strict=False), {'type': 'ip-v6-interface', 'strict': False}),
+    (core_schema.enum_schema, args(MyEnum), {'type': 'enum', 'cls': MyEnum}),
+    (
+        core_schema.enum_schema,
+        args(MyEnum, [MyEnum.a], missing=make_5, strict=True),
+        {'type': 'enum', 'cls': MyEnum, 'members': [MyEnum.a], 'missing': make_5, 'strict': True},
+    ),
     (
         core_schema.lax_or_strict_schema,
         args({'type': 'int'}, {'type': 'int'}),
         {'type': 'lax-or-strict', 'lax_schema': {'type': 'int'}, 'strict_schema': {'type': 'int'}},
     ),
+    (
+        core_schema.json_or_python_schema,
+        args({'type': 'int'}, {'type': 'str'}),
+        {'type': 'json-or-python', 'json_schema': {'type': 'int'}, 'python_schema': {'type': 'str'}},
+    ),
     (core_schema.is_subclass_schema, args(MyModel), {'type': 'is-subclass', 'cls': MyModel}),
     (
         core_schema.definitions_schema,
@@ -222,6 +373,16 @@ all_schema_functions = [
         {'type': 'definitions', 'schema': {'type': 'int'}, 'definitions': [{'type': 'int'}]},
     ),
     (core_schema.definition_reference_schema, args('foo'), {'type': 'definition-ref', 'schema_ref': 'foo'}),
+    (core_schema.type_var_schema, args('T'), {'type': 'type-var', 'name': 'T'}),
+    (
+        core_schema.parametrized_schema,
+        args({'type': 'list', 'items_schema': {'type': 'type-var', 'name': 'T'}}, {'T': {'type': 'int'}}),
+        {
+            'type': 'parametrized',
+            'schema': {'type': 'list', 'items_schema': {'type': 'type-var', 'name': 'T'}},
+            'type_vars': {'T': {'type': 'int'}},
+        },
+    ),
     (
         core_schema.dataclass_args_schema,
         args('Foo', [{'name': 'foo', 'type': 'dataclass-field', 'schema': {'type': 'int'}}]),
@@ -245,7 +406,7 @@ def test_schema_functions(function, args_kwargs, expected_schema):
     args, kwargs = args_kwargs
     schema = function(*args, **kwargs)
     assert schema == expected_schema
-    if schema.get('type') in {None, 'definition-ref', 'typed-dict-field'}:
+    if schema.get('type') in {None, 'definition-ref', 'type-var', 'typed-dict-field'}:
         return
 
     v = SchemaValidator(schema)
@@ -1,11 +1,13 @@
 from __future__ import annotations
 
 import re
+from datetime import datetime
+from types import MappingProxyType
 from typing import Any
 
 import pytest
 
-from pydantic_core import ValidationError
+from pydantic_core import SchemaValidator, ValidationError, core_schema
 
 from .conftest import Err, PyAndJson
 
@@ -18,9 +20,9 @@ from .conftest import Err, PyAndJson
         (None, False, 123, 123),
         (None, False, '123', 123),
         (True, False, 123, 123),
-        (True, False, '123', Err('Input should be a valid integer [type=int_type')),
+        (True, False, '123', 123),
         (False, True, 123, 123),
-        (False, True, '123', 123),
+        (False, True, '123', Err('Input should be a valid integer [type=int_type')),
         (None, True, 123, 123),
         (None, True, '123', Err('Input should be a valid integer [type=int_type')),
         (True, True, 123, 123),
@@ -47,3 +49,284 @@ def test_int_strict_argument(
     else:
         assert v.isinstance_test(input_value, strict_to_validator) is True
         assert v.validate_test(input_value, strict_to_validator) == expected
+
+
+def test_with_strict():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema(strict=False))),
+                'c': core_schema.typed_dict_field(core_schema.int_schema(strict=True)),
+            }
+        )
+    )
+    strict_v = v.with_strict(True)
+    lax_v = strict_v.with_strict(False)
+
+    assert v.validate_python({'a': '1', 'b': ['2'], 'c': 3}) == {'a': 1, 'b': [2], 'c': 3}
+    with pytest.raises(ValidationError) as exc_info:
+        strict_v.validate_python({'a': '1', 'b': ['2'], 'c': 3})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_type', ('a',)), ('int_type', ('b', 0))]
+    # strict set in the schema is replaced too
+    assert lax_v.validate_python({'a': '1', 'b': ['2'], 'c': '3'}) == {'a': 1, 'b': [2], 'c': 3}
+    # the original validator is unchanged
+    assert v.validate_python({'a': '1', 'b': ['2'], 'c': 3}) == {'a': 1, 'b': [2], 'c': 3}
+    with pytest.raises(ValidationError, match=r'c\n  Input should be a valid integer \[type=int_type'):
+        v.validate_python({'a': 1, 'b': [], 'c': '3'})
+
+    # strict given to validation methods only applies to schemas which don't set `strict`, replaced or not
+    with pytest.raises(ValidationError) as exc_info:
+        strict_v.validate_python({'a': '1', 'b': ['2'], 'c': '3'}, strict=False)
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_type', ('b', 0)), ('int_type', ('c',))]
+    with pytest.raises(ValidationError) as exc_info:
+        lax_v.validate_json('{"a": "1", "b": ["2"], "c": "3"}', strict=True)
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_type', ('a',))]
+
+
+def test_with_strict_definitions():
+    # a recursive schema, so the definition is stored in a slot
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'value': core_schema.typed_dict_field(core_schema.int_schema()),
+                'sub': core_schema.typed_dict_field(
+                    core_schema.nullable_schema(core_schema.definition_reference_schema('node')),
+                    required=False,
+                ),
+            },
+            ref='node',
+        )
+    )
+    assert v.validate_python({'value': '1', 'sub': {'value': '2'}}) == {'value': 1, 'sub': {'value': 2}}
+    strict_v = v.with_strict(True)
+    with pytest.raises(ValidationError) as exc_info:
+        strict_v.validate_python({'value': 1, 'sub': {'value': '2'}})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_type', ('sub', 'value'))]
+
+
+item_schemas = [
+    pytest.param(core_schema.int_schema, '1', 1, id='int'),
+    pytest.param(core_schema.str_schema, b'x', 'x', id='str'),
+    pytest.param(core_schema.datetime_schema, '2022-01-01T00:00:00', datetime(2022, 1, 1), id='datetime'),
+]
+containers = [
+    pytest.param(core_schema.list_schema, lambda item: (item,), lambda item: [item], (0,), id='list'),
+    pytest.param(
+        lambda items_schema, **kwargs: core_schema.dict_schema(values_schema=items_schema, **kwargs),
+        lambda item: MappingProxyType({'a': item}),
+        lambda item: {'a': item},
+        ('a',),
+        id='dict',
+    ),
+    pytest.param(core_schema.tuple_variable_schema, lambda item: [item], lambda item: (item,), (0,), id='tuple'),
+]
+
+
+@pytest.mark.parametrize('container_schema,lax_container,output_container,item_loc', containers)
+@pytest.mark.parametrize('item_schema,lax_item,item_output', item_schemas)
+@pytest.mark.parametrize(
+    'strict_to_validator,container_strict,item_strict,container_ok,item_ok',
+    [
+        # strict set on an item schema isn't replaced by the lax default for the container
+        (None, None, True, True, False),
+        (None, False, True, True, False),
+        # or inherited from a strict container
+        (None, True, None, False, True),
+        (None, True, False, False, True),
+        # strict set on a schema takes precedence over strict given for the call
+        (False, None, True, True, False),
+        (True, None, False, False, True),
+        # and the call's strict is ignored inside it too
+        (True, False, False, True, True),
+        (True, False, None, True, True),
+        (False, True, True, False, False),
+        (False, True, None, False, True),
+        # schemas without strict follow the call
+        (True, None, None, False, False),
+        (False, None, None, True, True),
+    ],
+)
+def test_container_item_strict(
+    container_schema,
+    lax_container,
+    output_container,
+    item_loc,
+    item_schema,
+    lax_item,
+    item_output,
+    strict_to_validator,
+    container_strict,
+    item_strict,
+    container_ok,
+    item_ok,
+):
+    v = SchemaValidator(container_schema(item_schema(strict=item_strict), strict=container_strict))
+    lax_input = lax_container(lax_item)
+    if container_ok and item_ok:
+        assert v.validate_python(lax_input, strict=strict_to_validator) == output_container(item_output)
+    else:
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(lax_input, strict=strict_to_validator)
+        # a strict container rejects the input before its items are validated
+        assert [e['loc'] for e in exc_info.value.errors()] == [item_loc if container_ok else ()]
+
+    # with a strict container input only the item's strictness matters
+    strict_input = output_container(lax_item)
+    if item_ok:
+        assert v.validate_python(strict_input, strict=strict_to_validator) == output_container(item_output)
+    else:
+        with pytest.raises(ValidationError):
+            v.validate_python(strict_input, strict=strict_to_validator)
+
+
+def test_schema_strict_assignment():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__'
+
+    def model_validator(strict: bool):
+        return SchemaValidator(
+            core_schema.model_schema(
+                MyModel,
+                core_schema.typed_dict_schema(
+                    {'a': core_schema.typed_dict_field(core_schema.int_schema())}, return_fields_set=True
+                ),
+                strict=strict,
+            ),
+            {'strict': strict},
+        )
+
+    # `strict=True` for the call doesn't tighten a lax model
+    v = model_validator(False)
+    m = v.validate_python({'a': '1'}, strict=True)
+    assert m.a == 1
+    v.validate_assignment(m, 'a', '2', strict=True)
+    assert m.a == 2
+
+    # nor does `strict=False` relax a strict one
+    v = model_validator(True)
+    with pytest.raises(ValidationError, match=r'a\n  Input should be a valid integer \[type=int_type'):
+        v.validate_assignment(m, 'a', '3', strict=False)
+    assert m.a == 2
+
+
+class LeafModel:
+    __slots__ = '__dict__', '__pydantic_fields_set__'
+
+
+def leaf_fields(field_strict: bool | None = None) -> dict[str, core_schema.TypedDictField]:
+    return {
+        's': core_schema.typed_dict_field(core_schema.str_schema()),
+        'i': core_schema.typed_dict_field(core_schema.int_schema()),
+        'dt': core_schema.typed_dict_field(core_schema.datetime_schema()),
+        'f': core_schema.typed_dict_field(core_schema.int_schema(strict=field_strict)),
+    }
+
+
+def leaf_validator(config: core_schema.CoreConfig | None = None, field_strict: bool | None = None):
+    model_schema = core_schema.model_schema(
+        LeafModel,
+        core_schema.typed_dict_schema(
+            {'a': core_schema.typed_dict_field(core_schema.int_schema())}, return_fields_set=True
+        ),
+    )
+    fields = {**leaf_fields(field_strict), 'm': core_schema.typed_dict_field(model_schema)}
+    return SchemaValidator(core_schema.typed_dict_schema(fields), config)
+
+
+def leaf_model_validator(config: core_schema.CoreConfig | None = None):
+    return SchemaValidator(
+        core_schema.model_schema(LeafModel, core_schema.typed_dict_schema(leaf_fields(), return_fields_set=True)),
+        config,
+    )
+
+
+lax_leaf_input = {'s': b'x', 'i': '1', 'dt': '2022-01-01T00:00:00', 'f': 2.0, 'm': {'a': '3'}}
+lax_leaf_json = '{"s": "x", "i": "1", "dt": "2022-01-01T00:00:00", "f": 2.0, "m": {"a": "3"}}'
+lax_leaf_errors = [
+    ('string_type', ('s',)),
+    ('int_type', ('i',)),
+    ('datetime_type', ('dt',)),
+    ('int_type', ('f',)),
+    ('model_class_type', ('m',)),
+]
+
+
+def check_leaves(output):
+    m = output.pop('m')
+    assert output == {'s': 'x', 'i': 1, 'dt': datetime(2022, 1, 1), 'f': 2}
+    assert isinstance(m, LeafModel)
+    assert m.__dict__ == {'a': 3}
+
+
+def test_call_strict_reaches_leaves():
+    v = leaf_validator()
+    check_leaves(v.validate_python(lax_leaf_input))
+    check_leaves(v.validate_json(lax_leaf_json))
+    assert v.isinstance_python(lax_leaf_input) is True
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(lax_leaf_input, strict=True)
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == lax_leaf_errors
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(lax_leaf_json, strict=True)
+    # strings are valid for datetimes, and objects for models, in strict JSON
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('int_type', ('i',)),
+        ('int_type', ('f',)),
+        ('int_type', ('m', 'a')),
+    ]
+    assert v.isinstance_python(lax_leaf_input, strict=True) is False
+
+
+def test_call_lax_reaches_leaves():
+    v = leaf_validator({'strict': True})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(lax_leaf_input)
+    # models aren't made strict by `config.strict`, only by their schema or the call
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == lax_leaf_errors[:-1] + [('int_type', ('m', 'a'))]
+    assert v.isinstance_python(lax_leaf_input) is False
+
+    check_leaves(v.validate_python(lax_leaf_input, strict=False))
+    check_leaves(v.validate_json(lax_leaf_json, strict=False))
+    assert v.isinstance_python(lax_leaf_input, strict=False) is True
+
+
+def test_call_strict_assignment():
+    v = leaf_model_validator()
+    m = v.validate_python({'s': 'x', 'i': 1, 'dt': datetime(2022, 1, 1), 'f': 2})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment(m, 'dt', '2022-01-02T00:00:00', strict=True)
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('datetime_type', ('dt',))]
+    v.validate_assignment(m, 'dt', '2022-01-02T00:00:00')
+    assert m.dt == datetime(2022, 1, 2)
+
+    v = leaf_model_validator({'strict': True})
+    with pytest.raises(ValidationError, match=r'i\n  Input should be a valid integer \[type=int_type'):
+        v.validate_assignment(m, 'i', '3')
+    v.validate_assignment(m, 'i', '3', strict=False)
+    assert m.i == 3
+
+
+@pytest.mark.parametrize(
+    'call_strict,field_strict,f_ok',
+    [
+        # strict set on a field wins over the call
+        (False, True, False),
+        (True, False, True),
+        # fields without strict set follow the call
+        (True, None, False),
+        (False, None, True),
+    ],
+)
+def test_call_strict_field_override(call_strict, field_strict, f_ok):
+    v = leaf_validator(field_strict=field_strict)
+    value = {'s': 'x', 'i': 1, 'dt': datetime(2022, 1, 1), 'f': '2', 'm': LeafModel()}
+    if f_ok:
+        assert v.validate_python(value, strict=call_strict)['f'] == 2
+        assert v.isinstance_python(value, strict=call_strict) is True
+    else:
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(value, strict=call_strict)
+        assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_type', ('f',))]
+        assert v.isinstance_python(value, strict=call_strict) is False
@@ -0,0 +1,279 @@
+import re
+from datetime import timedelta
+
+import pytest
+
+from pydantic_core import SchemaSerializer, SchemaValidator, ValidationError, core_schema
+
+
+class User:
+    __slots__ = '__dict__', '__pydantic_fields_set__'
+
+
+class Address:
+    __slots__ = '__dict__', '__pydantic_fields_set__'
+
+
+address_schema = core_schema.model_schema(
+    Address,
+    core_schema.typed_dict_schema(
+        {
+            'city': core_schema.typed_dict_field(core_schema.str_schema()),
+            'zip': core_schema.typed_dict_field(
+                core_schema.with_default_schema(core_schema.int_schema(), default=0, on_error='default')
+            ),
+        },
+        return_fields_set=True,
+    ),
+)
+user_schema = core_schema.model_schema(
+    User,
+    core_schema.typed_dict_schema(
+        {
+            'name': core_schema.typed_dict_field(core_schema.str_schema()),
+            'address': core_schema.typed_dict_field(address_schema),
+            'tags': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+            'scores': core_schema.typed_dict_field(
+                core_schema.dict_schema(core_schema.str_schema(), core_schema.nullable_schema(core_schema.int_schema()))
+            ),
+        },
+        return_fields_set=True,
+    ),
+)
+
+
+@pytest.fixture(scope='module')
+def validator():
+    return SchemaValidator(core_schema.typed_dict_schema({'user': core_schema.typed_dict_field(user_schema)}))
+
+
+def test_field(validator):
+    v = validator.sub_validator(('user', 'address', 'city'))
+    assert isinstance(v, SchemaValidator)
+    assert v.validate_python('London') == 'London'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(123)
+    # the location is relative to the field
+    assert exc_info.value.errors() == [
+        {'type': 'string_type', 'loc': (), 'msg': 'Input should be a valid string', 'input': 123}
+    ]
+
+
+def test_model(validator):
+    v = validator.sub_validator(('user', 'address'))
+    address = v.validate_python({'city': 'Paris', 'zip': '75001'})
+    assert isinstance(address, Address)
+    assert address.__dict__ == {'city': 'Paris', 'zip': 75001}
+
+
+def test_default(validator):
+    v = validator.sub_validator(('user', 'address', 'zip'))
+    assert v.validate_python('123') == 123
+    # the `default` schema is included, so `on_error='default'` still applies
+    assert v.validate_python('wrong') == 0
+
+
+def test_item_and_value(validator):
+    assert validator.sub_validator(('user', 'tags')).validate_python(['1', 2]) == [1, 2]
+    assert validator.sub_validator(('user', 'tags', '__item__')).validate_python('3') == 3
+    v = validator.sub_validator(('user', 'scores', '__value__'))
+    assert v.validate_python('4') == 4
+    assert v.validate_python(None) is None
+
+
+def test_empty_path(validator):
+    v = validator.sub_validator(())
+    output = v.validate_python({'user': {'name': 'x', 'address': {'city': 'y'}, 'tags': [], 'scores': {}}})
+    assert output['user'].name == 'x'
+
+
+def test_config():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.str_schema())}),
+        config=core_schema.CoreConfig(str_max_length=3),
+    )
+    sub_v = v.sub_validator(('a',))
+    assert sub_v.validate_python('abc') == 'abc'
+    assert not sub_v.isinstance_python('abcd')
+
+
+def test_model_config():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__'
+
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.model_schema(
+                MyModel,
+                core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.str_schema())}),
+                config=core_schema.CoreConfig(str_to_upper=True),
+            )
+        )
+    )
+    assert v.sub_validator(('__item__', 'a')).validate_python('abc') == 'ABC'
+
+
+def test_tagged_union():
+    v = SchemaValidator(
+        core_schema.tagged_union_schema(
+            {
+                'apple': core_schema.typed_dict_schema(
+                    {
+                        'kind': core_schema.typed_dict_field(core_schema.str_schema()),
+                        'seeds': core_schema.typed_dict_field(core_schema.int_schema()),
+                    }
+                ),
+                'pear': 'apple',
+                'banana': core_schema.typed_dict_schema(
+                    {'kind': core_schema.typed_dict_field(core_schema.str_schema())}
+                ),
+            },
+            discriminator='kind',
+        )
+    )
+    assert v.sub_validator(('apple', 'seeds')).validate_python('3') == 3
+    # a choice which refers to another choice by its tag
+    assert v.sub_validator(('pear', 'seeds')).validate_python('4') == 4
+    assert v.sub_validator(('banana',)).validate_python({'kind': 'banana'}) == {'kind': 'banana'}
+
+
+def test_union():
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [
+                core_schema.list_schema(core_schema.int_schema()),
+                core_schema.union_choice(core_schema.dict_schema(values_schema=core_schema.str_schema()), priority=1),
+            ]
+        )
+    )
+    assert v.sub_validator((0, '__item__')).validate_python('1') == 1
+    assert v.sub_validator((1, '__value__')).validate_python('x') == 'x'
+
+
+def test_dataclass():
+    v = SchemaValidator(
+        core_schema.dataclass_schema(
+            dict,
+            core_schema.dataclass_args_schema(
+                'MyDataclass', [core_schema.dataclass_field('a', core_schema.int_schema())]
+            ),
+        )
+    )
+    assert v.sub_validator(('a',)).validate_python('1') == 1
+
+
+def test_recursive():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'name': core_schema.typed_dict_field(core_schema.str_schema()),
+                'children': core_schema.typed_dict_field(
+                    core_schema.list_schema(core_schema.definition_reference_schema('Branch'))
+                ),
+            },
+            ref='Branch',
+        )
+    )
+    sub_v = v.sub_validator(('children', '__item__', 'children', '__item__'))
+    assert sub_v.validate_python({'name': 'a', 'children': [{'name': 'b', 'children': []}]}) == {
+        'name': 'a',
+        'children': [{'name': 'b', 'children': []}],
+    }
+    assert v.sub_validator(('children', '__item__', 'name')).validate_python('c') == 'c'
+
+
+def test_definitions():
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.typed_dict_schema(
+                {
+                    'a': core_schema.typed_dict_field(core_schema.definition_reference_schema('Point')),
+                    'b': core_schema.typed_dict_field(
+                        core_schema.list_schema(core_schema.definition_reference_schema('Point'))
+                    ),
+                }
+            ),
+            [
+                core_schema.typed_dict_schema(
+                    {
+                        'x': core_schema.typed_dict_field(core_schema.int_schema(ref='coordinate')),
+                        'y': core_schema.typed_dict_field(core_schema.definition_reference_schema('coordinate')),
+                    },
+                    ref='Point',
+                )
+            ],
+        )
+    )
+    assert v.sub_validator(('a',)).validate_python({'x': '1', 'y': '2'}) == {'x': 1, 'y': 2}
+    assert v.sub_validator(('b', '__item__', 'y')).validate_python('3') == 3
+    # the sub-schema contains the inline `coordinate` definition, which isn't defined twice
+    assert v.sub_validator(('b', '__item__', 'x')).validate_python('4') == 4
+
+
+def test_mutual_recursion():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'b': core_schema.typed_dict_field(
+                    core_schema.nullable_schema(
+                        core_schema.typed_dict_schema(
+                            {
+                                'a': core_schema.typed_dict_field(
+                                    core_schema.nullable_schema(core_schema.definition_reference_schema('A'))
+                                ),
+                                'n': core_schema.typed_dict_field(core_schema.int_schema()),
+                            },
+                            ref='B',
+                        )
+                    )
+                )
+            },
+            ref='A',
+        )
+    )
+    sub_v = v.sub_validator(('b',))
+    assert sub_v.validate_python({'a': {'b': {'a': None, 'n': '1'}}, 'n': '2'}) == {
+        'a': {'b': {'a': None, 'n': 1}},
+        'n': 2,
+    }
+    assert v.sub_validator(('b', 'a', 'b', 'n')).validate_python('3') == 3
+
+
+def test_invalid_segment(validator):
+    msg = "Invalid segment 'street' at ('user', 'address'), expected one of: 'city', 'zip'"
+    with pytest.raises(KeyError, match=re.escape(msg)):
+        validator.sub_validator(('user', 'address', 'street'))
+    msg = "Invalid segment 0 at ('user',), expected one of: 'name', 'address', 'tags', 'scores'"
+    with pytest.raises(KeyError, match=re.escape(msg)):
+        validator.sub_validator(('user', 0))
+    msg = "Invalid segment 'x' at ('user', 'tags'), expected one of: '__item__'"
+    with pytest.raises(KeyError, match=re.escape(msg)):
+        validator.sub_validator(('user', 'tags', 'x'))
+    msg = "Invalid segment 'x' at ('user', 'name'), `str` schema has no sub-schemas"
+    with pytest.raises(KeyError, match=re.escape(msg)):
+        validator.sub_validator(('user', 'name', 'x'))
+
+
+def test_sub_serializer():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'user': core_schema.typed_dict_field(user_schema),
+                'durations': core_schema.typed_dict_field(core_schema.list_schema(core_schema.timedelta_schema())),
+            }
+        ),
+        config=core_schema.CoreConfig(ser_json_timedelta='float'),
+    )
+    sub_s = s.sub_serializer(('durations', '__item__'))
+    assert isinstance(sub_s, SchemaSerializer)
+    # serialized with the parent's config
+    assert sub_s.to_json(timedelta(seconds=1.5)) == b'1.5'
+    assert s.sub_serializer(('user', 'tags')).to_json([1, 2]) == b'[1,2]'
+
+    address = Address()
+    address.__dict__ = {'city': 'Rome', 'zip': 123}
+    address.__pydantic_fields_set__ = {'city'}
+    assert s.sub_serializer(('user', 'address')).to_python(address, exclude_unset=True) == {'city': 'Rome'}
+
+    with pytest.raises(KeyError, match=re.escape("Invalid segment 'x' at (), expected one of: 'user', 'durations'")):
+        s.sub_serializer(('x',))
@@ -0,0 +1,60 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+pytestmark = pytest.mark.skipif(
+    not hasattr(SchemaValidator, 'validate_python_timings'), reason='built without the `timings` feature'
+)
+
+
+class MyModel:
+    __slots__ = '__dict__', '__fields_set__'
+
+
+def test_timings():
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.typed_dict_schema(
+                {
+                    'items': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+                    'choice': core_schema.typed_dict_field(
+                        core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()])
+                    ),
+                }
+            ),
+        )
+    )
+    m, timings = v.validate_python_timings({'items': [1, '2'], 'choice': 'x'})
+    assert isinstance(m, MyModel)
+    assert m.__dict__ == {'items': [1, 2], 'choice': 'x'}
+    assert list(timings) == ['MyModel', 'MyModel > list[int]', 'MyModel > union[int,str]']
+    for timing in timings.values():
+        assert timing['calls'] == 1
+        assert timing['total'] >= 0
+    assert timings['MyModel']['total'] >= timings['MyModel > list[int]']['total']
+
+
+def test_timings_calls():
+    v = SchemaValidator(core_schema.list_schema(core_schema.list_schema(core_schema.int_schema())))
+    output, timings = v.validate_python_timings([[1], [2, 3], []])
+    assert output == [[1], [2, 3], []]
+    assert {path: timing['calls'] for path, timing in timings.items()} == {
+        'list[list[int]]': 1,
+        'list[list[int]] > list[int]': 3,
+    }
+
+
+def test_timings_not_collected():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    assert v.validate_python([1]) == [1]
+    assert v.validate_python_timings([]) == ([], {'list[int]': {'total': pytest.approx(0, abs=1), 'calls': 1}})
+
+
+def test_timings_error():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python_timings(['x'])
+    # timings from the failed call aren't leaked into the next call
+    _, timings = v.validate_python_timings([1])
+    assert timings['list[int]']['calls'] == 1
@@ -0,0 +1,105 @@
+from datetime import date, datetime, timedelta
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+@pytest.mark.parametrize(
+    'schema,input_value,expected',
+    [
+        (core_schema.int_schema(), '123', 123),
+        (core_schema.float_schema(), '1.5', 1.5),
+        (core_schema.bool_schema(), 'true', True),
+        (core_schema.bool_schema(), 'false', False),
+        (core_schema.str_schema(), 'foobar', 'foobar'),
+        (core_schema.bytes_schema(), 'foobar', b'foobar'),
+        (core_schema.date_schema(), '2022-01-01', date(2022, 1, 1)),
+        (core_schema.datetime_schema(), '2022-01-01T12:13:14', datetime(2022, 1, 1, 12, 13, 14)),
+        (core_schema.timedelta_schema(), 'PT1H', timedelta(hours=1)),
+        (core_schema.nullable_schema(core_schema.int_schema()), '1', 1),
+    ],
+)
+def test_leaves(schema, input_value, expected):
+    v = SchemaValidator(schema)
+    assert v.validate_strings(input_value) == expected
+
+
+def test_nested():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'port': core_schema.typed_dict_field(core_schema.int_schema()),
+                'debug': core_schema.typed_dict_field(core_schema.bool_schema()),
+                'db': core_schema.typed_dict_field(
+                    core_schema.typed_dict_schema(
+                        {
+                            'timeout': core_schema.typed_dict_field(core_schema.float_schema()),
+                            'hosts': core_schema.typed_dict_field(core_schema.list_schema(core_schema.str_schema())),
+                        }
+                    )
+                ),
+            }
+        )
+    )
+    assert v.validate_strings({'port': '8000', 'debug': 'false', 'db': {'timeout': '2.5', 'hosts': ['a', 'b']}}) == {
+        'port': 8000,
+        'debug': False,
+        'db': {'timeout': 2.5, 'hosts': ['a', 'b']},
+    }
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_strings({'port': 'x', 'debug': 'false', 'db': {'timeout': 'soon', 'hosts': []}})
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': ('port',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+        {
+            'type': 'float_parsing',
+            'loc': ('db', 'timeout'),
+            'msg': 'Input should be a valid number, unable to parse string as an number',
+            'input': 'soon',
+        },
+    ]
+
+
+def test_strict():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()))
+    assert v.validate_strings({'a': '1'}) == {'a': 1}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_strings({'a': '1'}, strict=True)
+    assert exc_info.value.errors() == [
+        {'type': 'int_type', 'loc': ('a',), 'msg': 'Input should be a valid integer', 'input': '1'}
+    ]
+
+    v = SchemaValidator(core_schema.int_schema(strict=True))
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_strings('1')
+
+
+def test_non_string_leaves():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.str_schema(), core_schema.any_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_strings({'a': 1, 'b': ['x', None], 2: 'y'})
+    assert exc_info.value.errors() == [
+        {'type': 'string_type', 'loc': ('a',), 'msg': 'Input should be a valid string', 'input': 1},
+        {'type': 'string_type', 'loc': ('b', 1), 'msg': 'Input should be a valid string', 'input': None},
+        {'type': 'string_type', 'loc': (2, '[key]'), 'msg': 'Input should be a valid string', 'input': 2},
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_strings(b'foobar')
+    assert exc_info.value.errors() == [
+        {'type': 'string_type', 'loc': (), 'msg': 'Input should be a valid string', 'input': b'foobar'}
+    ]
+
+
+def test_context():
+    def f(input_value, info):
+        return input_value + info.context['suffix']
+
+    v = SchemaValidator(core_schema.general_after_validator_function(f, core_schema.str_schema()))
+    assert v.validate_strings('foo', context={'suffix': '!'}) == 'foo!'
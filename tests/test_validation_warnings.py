@@ -0,0 +1,119 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+def deprecated_status(value, info):
+    if value == 'legacy':
+        info.warn(f'{value!r} is deprecated', code='deprecated_value')
+    return value
+
+
+def test_warnings():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'status': core_schema.typed_dict_field(
+                    core_schema.general_after_validator_function(deprecated_status, core_schema.str_schema())
+                ),
+                'tags': core_schema.typed_dict_field(
+                    core_schema.list_schema(
+                        core_schema.general_plain_validator_function(deprecated_status),
+                    )
+                ),
+            }
+        )
+    )
+    output, warnings = v.validate_python_with_warnings({'status': 'legacy', 'tags': ['new', 'legacy']})
+    assert output == {'status': 'legacy', 'tags': ['new', 'legacy']}
+    assert warnings == [
+        {'type': 'deprecated_value', 'loc': ('status',), 'msg': "'legacy' is deprecated"},
+        {'type': 'deprecated_value', 'loc': ('tags', 1), 'msg': "'legacy' is deprecated"},
+    ]
+
+    assert v.validate_json_with_warnings('{"status": "ok", "tags": ["legacy"]}') == (
+        {'status': 'ok', 'tags': ['legacy']},
+        [{'type': 'deprecated_value', 'loc': ('tags', 0), 'msg': "'legacy' is deprecated"}],
+    )
+    # warnings aren't collected by the other entry points, so `warn()` is a no-op
+    assert v.validate_python({'status': 'legacy', 'tags': []}) == {'status': 'legacy', 'tags': []}
+    output, report = v.validate_python_with_report({'status': 'legacy', 'tags': []})
+    assert report == []
+
+
+def test_default_code():
+    def f(value, info):
+        info.warn('odd value')
+        info.warn('very odd value', code='very_odd')
+        return value
+
+    v = SchemaValidator(core_schema.general_before_validator_function(f, core_schema.int_schema()))
+    assert v.validate_python_with_warnings('1') == (
+        1,
+        [{'type': 'warning', 'loc': (), 'msg': 'odd value'}, {'type': 'very_odd', 'loc': (), 'msg': 'very odd value'}],
+    )
+
+
+def test_coercions_not_reported():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    assert v.validate_python_with_warnings(['1', 2]) == ([1, 2], [])
+
+
+def test_wrap_validator():
+    def f(value, handler, info):
+        info.warn('wrapped')
+        return handler(value)
+
+    v = SchemaValidator(
+        core_schema.dict_schema(
+            core_schema.str_schema(), core_schema.general_wrap_validator_function(f, core_schema.int_schema())
+        )
+    )
+    assert v.validate_python_with_warnings({'a': 1}) == (
+        {'a': 1},
+        [{'type': 'warning', 'loc': ('a',), 'msg': 'wrapped'}],
+    )
+
+
+def test_warnings_dont_affect_validation():
+    def f(value, info):
+        info.warn('checked')
+        if value < 0:
+            raise ValueError('negative')
+        return value
+
+    v = SchemaValidator(
+        core_schema.list_schema(core_schema.general_after_validator_function(f, core_schema.int_schema()))
+    )
+    with pytest.raises(ValidationError, match='Value error, negative'):
+        v.validate_python_with_warnings([1, -1])
+    output, warnings = v.validate_python_with_warnings([1, 2])
+    assert output == [1, 2]
+    assert [w['loc'] for w in warnings] == [(0,), (1,)]
+
+
+def test_union_losing_choices():
+    def warn(msg):
+        def f(value, info):
+            info.warn(msg)
+            return value
+
+        return f
+
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [
+                core_schema.general_before_validator_function(warn('str'), core_schema.str_schema(strict=True)),
+                core_schema.general_before_validator_function(warn('int'), core_schema.int_schema()),
+            ]
+        )
+    )
+    # only warnings from the choice which succeeded are kept, locations include the choice like errors
+    assert v.validate_python_with_warnings(1) == (
+        1,
+        [{'type': 'warning', 'loc': ('function-before[f(), int]',), 'msg': 'int'}],
+    )
+    assert v.validate_python_with_warnings('a') == (
+        'a',
+        [{'type': 'warning', 'loc': ('function-before[f(), str]',), 'msg': 'str'}],
+    )
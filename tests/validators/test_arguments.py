@@ -1019,3 +1019,266 @@ def test_error_display():
         '[{"type":"missing_argument","loc":["b"],"msg":"Missing required argument",'
         '"input":"ArgsKwargs((), {\'a\': 1})"}]'
     )
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        [ArgsKwargs((1, 2, 3, 4)), ((1, 2, 3, 4), {'c': 20})],
+        [ArgsKwargs((1, 2, 3)), ((1, 2, 3), {'b': 11, 'c': 20})],
+        [ArgsKwargs((1, 2)), ((1, 2), {'a': 10, 'b': 11, 'c': 20})],
+        [ArgsKwargs((1,)), ((1, 0), {'a': 10, 'b': 11, 'c': 20})],
+        [ArgsKwargs((1,), {'b': 5, 'c': 6}), ((1, 0), {'a': 10, 'b': 5, 'c': 6})],
+        [
+            ArgsKwargs(()),
+            Err(
+                'Missing required positional only argument',
+                [
+                    {
+                        'type': 'missing_positional_only_argument',
+                        'loc': (0,),
+                        'msg': 'Missing required positional only argument',
+                        'input': ArgsKwargs(()),
+                    }
+                ],
+            ),
+        ],
+    ],
+    ids=repr,
+)
+def test_parameter_default(input_value, expected):
+    v = SchemaValidator(
+        core_schema.arguments_schema(
+            [
+                core_schema.arguments_parameter('x', core_schema.int_schema(), mode='positional_only'),
+                core_schema.arguments_parameter('y', core_schema.int_schema(), mode='positional_only', default=0),
+                core_schema.arguments_parameter('a', core_schema.int_schema(), default=10),
+                core_schema.arguments_parameter('b', core_schema.int_schema(), default_factory=lambda: 11),
+                core_schema.arguments_parameter('c', core_schema.int_schema(), mode='keyword_only', default=20),
+            ]
+        )
+    )
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)) as exc_info:
+            v.validate_python(input_value)
+        assert exc_info.value.errors() == expected.errors
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_parameter_default_validate():
+    v = SchemaValidator(
+        core_schema.arguments_schema(
+            [
+                core_schema.arguments_parameter('a', core_schema.int_schema(), default='1', validate_default=True),
+                core_schema.arguments_parameter(
+                    'b', core_schema.int_schema(), default_factory=lambda: 'x', validate_default=True
+                ),
+                core_schema.arguments_parameter('c', core_schema.int_schema(), default='2'),
+            ]
+        )
+    )
+    assert v.validate_python(ArgsKwargs((), {'b': 3})) == ((), {'a': 1, 'b': 3, 'c': '2'})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(ArgsKwargs(()))
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': ('b',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+
+def test_parameter_default_validate_config():
+    v = SchemaValidator(
+        core_schema.arguments_schema([core_schema.arguments_parameter('a', core_schema.int_schema(), default='1')]),
+        {'validate_default': True},
+    )
+    assert v.validate_python(ArgsKwargs(())) == ((), {'a': 1})
+
+
+def test_parameter_default_factory_error():
+    def broken():
+        raise RuntimeError('broken')
+
+    v = SchemaValidator(
+        core_schema.arguments_schema(
+            [core_schema.arguments_parameter('a', core_schema.int_schema(), default_factory=broken)]
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(ArgsKwargs(()))
+    assert exc_info.value.errors() == [
+        {
+            'type': 'default_factory_error',
+            'loc': ('a',),
+            'msg': 'Error calling default_factory: RuntimeError: broken',
+            'input': None,
+            'ctx': {'error': 'RuntimeError: broken'},
+        }
+    ]
+
+
+def test_parameter_default_factory_error_other_errors():
+    def broken():
+        raise RuntimeError('broken')
+
+    v = SchemaValidator(
+        core_schema.arguments_schema(
+            [
+                core_schema.arguments_parameter('a', core_schema.int_schema()),
+                core_schema.arguments_parameter('b', core_schema.int_schema(), default_factory=broken),
+            ]
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(ArgsKwargs(('x',)))
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('int_parsing', (0,)),
+        ('default_factory_error', ('b',)),
+    ]
+    assert isinstance(exc_info.value.__cause__, RuntimeError)
+
+
+def test_parameter_non_default_follows_default():
+    with pytest.raises(SchemaError, match="Non-default argument 'b' follows default argument"):
+        SchemaValidator(
+            core_schema.arguments_schema(
+                [
+                    core_schema.arguments_parameter('a', core_schema.int_schema(), default=1),
+                    core_schema.arguments_parameter('b', core_schema.int_schema()),
+                ]
+            )
+        )
+
+
+def test_parameter_default_with_default_schema():
+    with pytest.raises(SchemaError, match="'default' and 'default_factory' cannot be used with a 'default' schema"):
+        SchemaValidator(
+            core_schema.arguments_schema(
+                [
+                    core_schema.arguments_parameter(
+                        'a', core_schema.with_default_schema(core_schema.int_schema(), default=1), default=2
+                    )
+                ]
+            )
+        )
+
+
+@pytest.fixture(scope='module')
+def unpacked_kwargs_schema():
+    return core_schema.arguments_schema(
+        [
+            core_schema.arguments_parameter('a', core_schema.int_schema()),
+            core_schema.arguments_parameter('b', core_schema.int_schema(), mode='keyword_only'),
+        ],
+        var_kwargs_schema=core_schema.typed_dict_schema(
+            {
+                'x': core_schema.typed_dict_field(core_schema.int_schema()),
+                'y': core_schema.typed_dict_field(core_schema.str_schema(), required=False, validation_alias='Y'),
+            },
+            extra_behavior='forbid',
+        ),
+        var_kwargs_mode='unpacked-typed-dict',
+    )
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        [ArgsKwargs((1,), {'b': 2, 'x': '3'}), ((1,), {'b': 2, 'x': 3})],
+        [ArgsKwargs((1,), {'b': 2, 'x': 3, 'Y': 'z'}), ((1,), {'b': 2, 'x': 3, 'y': 'z'})],
+        [ArgsKwargs((), {'a': 1, 'b': 2, 'x': 3}), ((), {'a': 1, 'b': 2, 'x': 3})],
+        [{'a': 1, 'b': 2, 'x': 3}, ((), {'a': 1, 'b': 2, 'x': 3})],
+    ],
+    ids=repr,
+)
+def test_unpacked_kwargs(py_and_json: PyAndJson, unpacked_kwargs_schema, input_value, expected):
+    v = py_and_json(unpacked_kwargs_schema)
+    assert v.validate_test(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'input_value,expected_errors',
+    [
+        [
+            ArgsKwargs((1,), {'b': 2}),
+            [
+                {
+                    'type': 'missing_keyword_only_argument',
+                    'loc': ('x',),
+                    'msg': 'Missing required keyword only argument',
+                    'input': ArgsKwargs((1,), {'b': 2}),
+                }
+            ],
+        ],
+        [
+            ArgsKwargs((1,), {'x': 'q'}),
+            [
+                {
+                    'type': 'missing_keyword_only_argument',
+                    'loc': ('b',),
+                    'msg': 'Missing required keyword only argument',
+                    'input': ArgsKwargs((1,), {'x': 'q'}),
+                },
+                {
+                    'type': 'int_parsing',
+                    'loc': ('x',),
+                    'msg': 'Input should be a valid integer, unable to parse string as an integer',
+                    'input': 'q',
+                },
+            ],
+        ],
+        [
+            ArgsKwargs((1,), {'b': 2, 'x': 3, 'z': 4}),
+            [{'type': 'extra_forbidden', 'loc': ('z',), 'msg': 'Extra inputs are not permitted', 'input': 4}],
+        ],
+    ],
+    ids=repr,
+)
+def test_unpacked_kwargs_errors(unpacked_kwargs_schema, input_value, expected_errors):
+    v = SchemaValidator(unpacked_kwargs_schema)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert exc_info.value.errors() == expected_errors
+
+
+def test_unpacked_kwargs_errors_json(unpacked_kwargs_schema):
+    v = SchemaValidator(unpacked_kwargs_schema)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"a": 1, "b": 2, "x": "q", "z": 1}')
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('int_parsing', ('x',)),
+        ('extra_forbidden', ('z',)),
+    ]
+
+
+@pytest.mark.parametrize(
+    'extra_behavior,expected',
+    [('ignore', ((), {'x': 1})), ('allow', ((), {'x': 1, 'z': '2'}))],
+)
+def test_unpacked_kwargs_extra_behavior(extra_behavior, expected):
+    v = SchemaValidator(
+        core_schema.arguments_schema(
+            [],
+            var_kwargs_schema=core_schema.typed_dict_schema(
+                {'x': core_schema.typed_dict_field(core_schema.int_schema())}, extra_behavior=extra_behavior
+            ),
+            var_kwargs_mode='unpacked-typed-dict',
+        )
+    )
+    assert v.validate_python(ArgsKwargs((), {'x': '1', 'z': '2'})) == expected
+
+
+def test_unpacked_kwargs_invalid_schema():
+    with pytest.raises(SchemaError, match="var_kwargs_schema must be a typed-dict schema with var_kwargs_mode="):
+        SchemaValidator(
+            core_schema.arguments_schema(
+                [], var_kwargs_schema=core_schema.int_schema(), var_kwargs_mode='unpacked-typed-dict'
+            )
+        )
+    with pytest.raises(SchemaError, match="Input should be 'uniform' or 'unpacked-typed-dict'"):
+        SchemaValidator({'type': 'arguments', 'arguments_schema': [], 'var_kwargs_mode': 'other'})
@@ -2,7 +2,7 @@ import re
 
 import pytest
 
-from pydantic_core import SchemaValidator, ValidationError, core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, plain_repr
 
@@ -32,6 +32,10 @@ from ..conftest import Err, PyAndJson, plain_repr
         (1.1, Err('Input should be a valid boolean [type=bool_type, input_value=1.1, input_type=float]')),
         (2, Err('unable to interpret input [type=bool_parsing, input_value=2, input_type=int]')),
         (2.0, Err('unable to interpret input [type=bool_parsing, input_value=2.0, input_type=float]')),
+        (
+            2**64,
+            Err('Input should be a valid boolean [type=bool_type, input_value=18446744073709551616, input_type=int]'),
+        ),
     ],
 )
 def test_bool(py_and_json: PyAndJson, input_value, expected):
@@ -76,9 +80,15 @@ def test_bool_error():
 
 def test_bool_repr():
     v = SchemaValidator({'type': 'bool'})
-    assert plain_repr(v) == 'SchemaValidator(title="bool",validator=Bool(BoolValidator{strict:false}),slots=[])'
+    assert plain_repr(v) == (
+        'SchemaValidator(title="bool",'
+        'validator=Bool(BoolValidator{strict:false,coerce:None,numeric_mode:Strict01}),slots=[])'
+    )
     v = SchemaValidator({'type': 'bool', 'strict': True})
-    assert plain_repr(v) == 'SchemaValidator(title="bool",validator=Bool(BoolValidator{strict:true}),slots=[])'
+    assert plain_repr(v) == (
+        'SchemaValidator(title="bool",'
+        'validator=StrictOverride(Bool(BoolValidator{strict:true,coerce:None,numeric_mode:Strict01})),slots=[])'
+    )
 
 
 def test_bool_key(py_and_json: PyAndJson):
@@ -99,3 +109,127 @@ def test_validate_assignment_not_supported() -> None:
     v = SchemaValidator(core_schema.bool_schema())
     with pytest.raises(TypeError, match='validate_assignment is not supported for bool'):
         v.validate_assignment(False, 'foo', True)
+
+
+def yes_no(value):
+    if value in ('Y', 'N'):
+        return value == 'Y'
+    elif isinstance(value, int) and not isinstance(value, bool) and value >= 10:
+        return value < 100
+    else:
+        return None
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('Y', True),
+        ('N', False),
+        (50, True),
+        (100, False),
+        (1, True),
+        ('true', True),
+        ('off', False),
+        (False, False),
+        ('X', Err('Input should be a valid boolean, unable to interpret input [type=bool_parsing')),
+    ],
+)
+def test_bool_coerce(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.bool_schema(coerce=yes_no))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) is expected
+
+
+def test_bool_coerce_strict():
+    calls = []
+
+    def coerce(value):
+        calls.append(value)
+        return True
+
+    v = SchemaValidator(core_schema.bool_schema(strict=True, coerce=coerce))
+    assert v.validate_python(False) is False
+    with pytest.raises(ValidationError, match='Input should be a valid boolean'):
+        v.validate_python('Y')
+    v = SchemaValidator(core_schema.bool_schema(coerce=coerce))
+    assert v.validate_python(False, strict=True) is False
+    assert calls == []
+    assert v.validate_python(False) is True
+    assert calls == [False]
+
+
+def test_bool_coerce_error():
+    def coerce(value):
+        raise ValueError('broken')
+
+    v = SchemaValidator(core_schema.bool_schema(coerce=coerce))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('Y')
+    # insert_assert(exc_info.value.errors())
+    assert exc_info.value.errors() == [
+        {
+            'type': 'bool_parsing',
+            'loc': (),
+            'msg': 'Input should be a valid boolean, unable to interpret input',
+            'input': 'Y',
+        }
+    ]
+
+
+def test_bool_coerce_invalid_return():
+    v = SchemaValidator(core_schema.bool_schema(coerce=lambda v: 'yes'))
+    with pytest.raises(TypeError, match='bool `coerce` function must return True, False or None, not str'):
+        v.validate_python('Y')
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (0, False),
+        (1, True),
+        (2, True),
+        (-1, True),
+        (2**64, True),
+        (0.0, False),
+        (2.5, True),
+        (float('inf'), True),
+        (float('nan'), Err('Input should be a valid boolean, unable to interpret input [type=bool_parsing')),
+        ('yes', True),
+        ('2', Err('Input should be a valid boolean, unable to interpret input [type=bool_parsing')),
+        (True, True),
+    ],
+)
+def test_bool_numeric_truthy(input_value, expected):
+    v = SchemaValidator(core_schema.bool_schema(numeric_mode='truthy'))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) is expected
+
+
+def test_bool_numeric_truthy_json():
+    v = SchemaValidator(core_schema.bool_schema(numeric_mode='truthy'))
+    assert v.validate_json('0') is False
+    assert v.validate_json('42') is True
+    assert v.validate_json('0.0') is False
+    assert v.validate_json('-0.5') is True
+    with pytest.raises(ValidationError, match='type=bool_type'):
+        v.validate_json('42', strict=True)
+
+
+def test_bool_numeric_strict01():
+    v = SchemaValidator(core_schema.bool_schema(numeric_mode='strict01'))
+    assert v.validate_python(1) is True
+    with pytest.raises(ValidationError, match='type=bool_parsing'):
+        v.validate_python(2)
+    with pytest.raises(ValidationError, match='type=bool_type'):
+        v.validate_python(2.5)
+
+
+def test_bool_numeric_mode_invalid():
+    with pytest.raises(SchemaError, match="bool.numeric_mode\n  Input should be 'strict01' or 'truthy'"):
+        SchemaValidator(core_schema.bool_schema(numeric_mode='foobar'))
@@ -1,9 +1,10 @@
 import re
+from array import array
 from typing import Any, Dict
 
 import pytest
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaSerializer, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson
 
@@ -121,3 +122,194 @@ def test_length_ctx():
             'ctx': {'max_length': 3},
         }
     ]
+
+
+def test_bytearray_output():
+    v = SchemaValidator({'type': 'bytes', 'output': 'bytearray'})
+
+    for input_value in (b'foo', bytearray(b'foo'), 'foo'):
+        output = v.validate_python(input_value)
+        assert output == bytearray(b'foo')
+        assert type(output) is bytearray
+
+    output = v.validate_json('"foo"')
+    assert output == bytearray(b'foo')
+    assert type(output) is bytearray
+
+
+def test_bytearray_output_is_a_copy():
+    v = SchemaValidator({'type': 'bytes', 'output': 'bytearray'})
+    input_value = bytearray(b'foo')
+    output = v.validate_python(input_value)
+    output.extend(b'bar')
+    assert input_value == bytearray(b'foo')
+
+
+def test_bytes_output_from_bytearray():
+    v = SchemaValidator(core_schema.bytes_schema(output='bytes'))
+    output = v.validate_python(bytearray(b'foo'))
+    assert output == b'foo'
+    assert type(output) is bytes
+
+
+def test_strict_bytearray_output():
+    v = SchemaValidator(core_schema.bytes_schema(output='bytearray', strict=True))
+
+    assert v.validate_python(bytearray(b'foo')) == bytearray(b'foo')
+    assert v.validate_json('"foo"') == bytearray(b'foo')
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(b'foo')
+    assert exc_info.value.errors() == [
+        {'type': 'bytearray_type', 'loc': (), 'msg': 'Input should be a valid bytearray', 'input': b'foo'}
+    ]
+    with pytest.raises(ValidationError, match='Input should be a valid bytearray'):
+        v.validate_python('foo')
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (bytearray(b'foo'), bytearray(b'foo')),
+        (b'foo', bytearray(b'foo')),
+        ('foo', bytearray(b'foo')),
+        (bytearray(b'f'), Err('Data should have at least 2 bytes')),
+        (b'foobar', Err('Data should have at most 5 bytes')),
+    ],
+)
+def test_constrained_bytearray_output(input_value, expected):
+    v = SchemaValidator(core_schema.bytes_schema(output='bytearray', min_length=2, max_length=5))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert type(output) is bytearray
+
+
+def test_invalid_output():
+    with pytest.raises(SchemaError, match="Input should be 'bytes' or 'bytearray'"):
+        SchemaValidator({'type': 'bytes', 'output': 'memoryview'})
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (memoryview(b'foo'), b'foo'),
+        (memoryview(bytearray(b'foobar'))[1:4], b'oob'),
+        (array('B', [1, 2, 3]), b'\x01\x02\x03'),
+        (memoryview(b'f'), Err('Data should have at least 2 bytes [type=bytes_too_short')),
+        (memoryview(b'foobar'), Err('Data should have at most 5 bytes [type=bytes_too_long')),
+        (memoryview(b'foobar')[::2], Err('buffer of bytes [type=bytes_buffer_invalid')),
+        (memoryview(b'foobar').cast('B', (2, 3)), Err('buffer of bytes [type=bytes_buffer_invalid')),
+        (array('i', [1, 2]), Err('buffer of bytes [type=bytes_buffer_invalid')),
+    ],
+)
+@pytest.mark.parametrize('output', ['bytes', 'bytearray'])
+def test_buffer(input_value, expected, output):
+    v = SchemaValidator(core_schema.bytes_schema(output=output, min_length=2, max_length=5))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        result = v.validate_python(input_value)
+        assert result == expected
+        assert type(result) is (bytes if output == 'bytes' else bytearray)
+
+
+def test_buffer_strict():
+    v = SchemaValidator(core_schema.bytes_schema(strict=True))
+    with pytest.raises(ValidationError, match=r'Input should be a valid bytes \[type=bytes_type'):
+        v.validate_python(memoryview(b'foo'))
+    assert SchemaValidator(core_schema.bytes_schema()).validate_python(memoryview(b'foo'), strict=False) == b'foo'
+
+
+@pytest.mark.parametrize(
+    'mode,input_value,expected',
+    [
+        ('utf8', 'aGk=', b'aGk='),
+        ('base64', 'aGk=', b'hi'),
+        ('base64', 'aGk', b'hi'),
+        ('base64', '+/8=', b'\xfb\xff'),
+        ('base64', '-_8', b'\xfb\xff'),
+        ('base64', '', b''),
+        (
+            'base64',
+            '!!',
+            Err('Data should be valid base64: Invalid byte 33, offset 0. [type=bytes_invalid_encoding'),
+        ),
+        ('base64', 'a', Err('Data should be valid base64: Encoded text cannot have a 6-bit remainder.')),
+        ('base64url', '-_8', b'\xfb\xff'),
+        ('base64url', '-_8=', b'\xfb\xff'),
+        ('base64url', '', b''),
+        ('base64url', '+/8', Err('Data should be valid base64url: Invalid byte 43, offset 0.')),
+        ('hex', 'DEADbeef', b'\xde\xad\xbe\xef'),
+        ('hex', 'abc', Err('Data should be valid hex: Odd number of digits [type=bytes_invalid_encoding')),
+        ('hex', 'zz', Err("Data should be valid hex: Invalid character 'z' at position 0")),
+    ],
+)
+def test_val_json_bytes(mode, input_value, expected):
+    v = SchemaValidator(core_schema.bytes_schema(), {'val_json_bytes': mode})
+    json_input = f'"{input_value}"'
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_json(json_input)
+    else:
+        assert v.validate_json(json_input) == expected
+        # python inputs are unaffected
+        assert v.validate_python(input_value) == input_value.encode()
+
+
+def test_val_json_bytes_length():
+    v = SchemaValidator(core_schema.bytes_schema(min_length=2, max_length=3), {'val_json_bytes': 'base64'})
+    # 4 characters, but 2 bytes after decoding
+    assert v.validate_json('"aGk="') == b'hi'
+    with pytest.raises(ValidationError, match=r'Data should have at most 3 bytes \[type=bytes_too_long'):
+        v.validate_json('"aGVsbG8="')
+    with pytest.raises(ValidationError, match=r'Data should have at least 2 bytes \[type=bytes_too_short'):
+        v.validate_json('"aA=="')
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('"a"')
+    assert exc_info.value.errors() == [
+        {
+            'type': 'bytes_invalid_encoding',
+            'loc': (),
+            'msg': 'Data should be valid base64: Encoded text cannot have a 6-bit remainder.',
+            'input': 'a',
+            'ctx': {'encoding': 'base64', 'encoding_error': 'Encoded text cannot have a 6-bit remainder.'},
+        }
+    ]
+
+
+@pytest.mark.parametrize('mode', ['utf8', 'base64', 'base64url', 'hex'])
+def test_val_json_bytes_round_trip(mode):
+    schema = core_schema.bytes_schema()
+    s = SchemaSerializer(schema, {'ser_json_bytes': mode})
+    v = SchemaValidator(schema, {'val_json_bytes': mode})
+    value = b'hello world' if mode == 'utf8' else b'\x00\xfb\xff binary \xde\xad'
+    assert v.validate_json(s.to_json(value)) == value
+
+
+@pytest.mark.parametrize('mode', ['base64', 'base64url', 'hex'])
+@pytest.mark.parametrize('value', [b'', bytes(range(256)) * 20], ids=['empty', 'large'])
+def test_val_json_bytes_round_trip_sizes(mode, value):
+    schema = core_schema.dict_schema(core_schema.bytes_schema(), core_schema.bytes_schema())
+    s = SchemaSerializer(schema, {'ser_json_bytes': mode})
+    v = SchemaValidator(schema, {'val_json_bytes': mode})
+    assert v.validate_json(s.to_json({value: value})) == {value: value}
+    if mode == 'hex':
+        assert s.to_json({value: value}) == s.to_json({value: value}).lower()
+
+
+def test_val_json_bytes_dict_key():
+    v = SchemaValidator(
+        core_schema.dict_schema(core_schema.bytes_schema(), core_schema.int_schema()), {'val_json_bytes': 'hex'}
+    )
+    assert v.validate_json('{"6869": 1}') == {b'hi': 1}
+
+
+def test_val_json_bytes_invalid():
+    with pytest.raises(SchemaError, match='Invalid bytes validation mode: `base32`, expected `utf8`, `base64`'):
+        SchemaValidator(core_schema.bytes_schema(), {'val_json_bytes': 'base32'})
@@ -4,7 +4,7 @@ from collections import namedtuple
 
 import pytest
 
-from pydantic_core import ArgsKwargs, SchemaValidator, ValidationError
+from pydantic_core import ArgsKwargs, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, plain_repr
 
@@ -178,3 +178,39 @@ def test_named_tuple():
     assert isinstance(d, Point)
     assert d.x == 1.1
     assert d.y == 2.2
+
+
+def test_parameter_defaults():
+    def my_function(a, b=2, /, c=3, *args, d=4, **kwargs):
+        return a, b, c, args, d, kwargs
+
+    v = SchemaValidator(
+        core_schema.call_schema(
+            core_schema.arguments_schema(
+                [
+                    core_schema.arguments_parameter('a', core_schema.int_schema(), mode='positional_only'),
+                    core_schema.arguments_parameter('b', core_schema.int_schema(), mode='positional_only', default=20),
+                    core_schema.arguments_parameter('c', core_schema.int_schema(), default_factory=lambda: 30),
+                    core_schema.arguments_parameter('d', core_schema.int_schema(), mode='keyword_only', default=40),
+                ],
+                var_args_schema=core_schema.int_schema(),
+                var_kwargs_schema=core_schema.int_schema(),
+            ),
+            my_function,
+        )
+    )
+    assert v.validate_python(ArgsKwargs((1,))) == (1, 20, 30, (), 40, {})
+    assert v.validate_python(ArgsKwargs((1, 2))) == (1, 2, 30, (), 40, {})
+    assert v.validate_python(ArgsKwargs((1, 2, 3, 4, 5), {'e': 6})) == (1, 2, 3, (4, 5), 40, {'e': 6})
+    assert v.validate_python(ArgsKwargs((1,), {'c': '3', 'd': '4'})) == (1, 20, 3, (), 4, {})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(ArgsKwargs((), {'c': 3}))
+    assert exc_info.value.errors() == [
+        {
+            'type': 'missing_positional_only_argument',
+            'loc': (0,),
+            'msg': 'Missing required positional only argument',
+            'input': ArgsKwargs((), {'c': 3}),
+        }
+    ]
@@ -3,7 +3,7 @@ from decimal import Decimal
 
 import pytest
 
-from pydantic_core import SchemaError, SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import PyAndJson, plain_repr
 
@@ -54,6 +54,7 @@ def test_chain_error():
             'loc': (),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'abc',
+            'ctx': {'chain_step': 1},
         }
     ]
 
@@ -155,3 +156,297 @@ def test_ask():
     assert m.__pydantic_fields_set__ == {'field_a'}
     # insert_assert(calls)
     assert calls == [({'field_a': 'abc'}, {'field_a'})]
+
+
+def check_positive(v, info):
+    if v <= 0:
+        raise ValueError('must be positive')
+    return v
+
+
+def check_even(v, info):
+    if v % 2:
+        raise ValueError('must be even')
+    return v
+
+
+def test_labels():
+    v = SchemaValidator(
+        core_schema.chain_schema(
+            [
+                core_schema.chain_step(core_schema.str_schema(), label='text'),
+                core_schema.int_schema(),
+                core_schema.chain_step(core_schema.general_plain_validator_function(check_positive), label='positive'),
+            ]
+        )
+    )
+    assert v.title == 'chain[text,int,positive]'
+    assert v.validate_python('3') == 3
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(3)
+    assert exc_info.value.errors()[0]['ctx'] == {'chain_step': 'text'}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x')
+    # steps without a label are identified by their index
+    assert exc_info.value.errors()[0]['ctx'] == {'chain_step': 1}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('-1')
+    assert exc_info.value.errors()[0]['ctx'] == {'error': 'must be positive', 'chain_step': 'positive'}
+
+
+def test_chain_one_labelled():
+    v = SchemaValidator(core_schema.chain_schema([core_schema.chain_step(core_schema.int_schema(), label='number')]))
+    assert v.title == 'chain[number]'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x')
+    assert exc_info.value.errors()[0]['ctx'] == {'chain_step': 'number'}
+
+
+def test_error_input_type():
+    v = SchemaValidator(
+        core_schema.chain_schema(
+            [
+                core_schema.str_schema(),
+                core_schema.int_schema(),
+                core_schema.general_plain_validator_function(check_positive),
+            ],
+            error_input_type=True,
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(b'x')
+    assert exc_info.value.errors()[0]['ctx'] == {'chain_step': 1, 'chain_step_input_type': 'str'}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(b'-1')
+    assert exc_info.value.errors()[0]['ctx'] == {
+        'error': 'must be positive',
+        'chain_step': 2,
+        'chain_step_input_type': 'int',
+    }
+
+
+def test_validate_only():
+    calls = []
+
+    def record(v, info):
+        calls.append(v)
+        return 'ignored'
+
+    v = SchemaValidator(
+        core_schema.chain_schema(
+            [
+                core_schema.int_schema(),
+                core_schema.chain_step(core_schema.general_plain_validator_function(record), validate_only=True),
+                core_schema.general_plain_validator_function(lambda v, info: v * 2),
+            ]
+        )
+    )
+    # the output of validate-only steps is ignored
+    assert v.validate_python('3') == 6
+    assert calls == [3]
+
+
+def short_circuit_validator(short_circuit):
+    return SchemaValidator(
+        core_schema.chain_schema(
+            [
+                core_schema.int_schema(),
+                core_schema.chain_step(
+                    core_schema.general_plain_validator_function(check_positive), label='positive', validate_only=True
+                ),
+                core_schema.chain_step(
+                    core_schema.general_plain_validator_function(check_even), label='even', validate_only=True
+                ),
+                core_schema.general_plain_validator_function(lambda v, info: v * 10),
+            ],
+            short_circuit=short_circuit,
+        )
+    )
+
+
+def test_short_circuit():
+    v = short_circuit_validator(None)
+    assert v.validate_python('2') == 20
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('-1')
+    # validation stops at the first step which fails
+    assert [e['ctx'] for e in exc_info.value.errors()] == [{'error': 'must be positive', 'chain_step': 'positive'}]
+
+
+def test_no_short_circuit():
+    v = short_circuit_validator(False)
+    assert v.validate_python('2') == 20
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('-1')
+    # all validate-only steps run and their errors are collected
+    assert [e['ctx'] for e in exc_info.value.errors()] == [
+        {'error': 'must be positive', 'chain_step': 'positive'},
+        {'error': 'must be even', 'chain_step': 'even'},
+    ]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x')
+    # steps which transform the value don't run after a step has failed
+    assert [e['type'] for e in exc_info.value.errors()] == ['int_parsing']
+
+
+def test_nested_chain_step():
+    inner = core_schema.chain_schema(
+        [
+            core_schema.int_schema(),
+            core_schema.chain_step(core_schema.general_plain_validator_function(check_positive), label='positive'),
+        ]
+    )
+    v = SchemaValidator(core_schema.chain_schema([core_schema.str_schema(), inner]))
+    # the inner chain is flattened, labels are kept
+    assert v.title == 'chain[str,int,positive]'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x')
+    assert exc_info.value.errors()[0]['ctx'] == {'chain_step': 1}
+
+    v = SchemaValidator(
+        core_schema.chain_schema([core_schema.str_schema(), core_schema.chain_step(inner, label='number')])
+    )
+    # the inner chain has a label so isn't flattened, the innermost step is used in errors
+    assert v.title == 'chain[str,number]'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('-1')
+    assert exc_info.value.errors()[0]['ctx'] == {'error': 'must be positive', 'chain_step': 'positive'}
+
+
+def test_chain_step_context_roundtrip():
+    v = SchemaValidator(core_schema.chain_schema([core_schema.str_schema(), core_schema.int_schema()]))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x')
+    errors = exc_info.value.errors()
+    assert ValidationError('Foobar', errors).errors() == errors
+
+
+merge_cases = [
+    (
+        [core_schema.str_schema(min_length=2), core_schema.str_schema(max_length=5), core_schema.str_schema()],
+        ['', 'a', 'ab', 'abcde', 'abcdef', b'abc', 1],
+    ),
+    (
+        [core_schema.str_schema(max_length=5), core_schema.str_schema(min_length=3)],
+        ['ab', 'abc', 'abcdef'],
+    ),
+    (
+        [
+            core_schema.str_schema(min_length=2),
+            core_schema.str_schema(min_length=3, pattern='^a'),
+            core_schema.str_schema(pattern='z$'),
+        ],
+        ['a', 'az', 'abz', 'bbz', 'abc', 'xyz'],
+    ),
+    (
+        [core_schema.str_schema(pattern='^a'), core_schema.str_schema(min_length=3)],
+        ['b', 'ab', 'abc', 'bcd'],
+    ),
+    (
+        [core_schema.int_schema(ge=0), core_schema.int_schema(le=10), core_schema.int_schema(gt=2, lt=9)],
+        [-1, 0, 2, 3, 8, 9, 10, 11, '5', 'x', 1.5],
+    ),
+    (
+        [core_schema.int_schema(lt=5), core_schema.int_schema(le=10)],
+        [4, 5, 10, 11],
+    ),
+    (
+        [core_schema.int_schema(multiple_of=5, multiple_of_mode='round'), core_schema.int_schema(le=10)],
+        [7, 12, 13, -3],
+    ),
+    (
+        [core_schema.int_schema(ge=5), core_schema.int_schema(le=3)],
+        [2, 4, 6],
+    ),
+]
+
+
+def error_types(validator, input_value):
+    try:
+        return validator.validate_python(input_value)
+    except ValidationError as e:
+        return [(error['type'], error['loc']) for error in e.errors()]
+
+
+@pytest.mark.parametrize('steps,inputs', merge_cases)
+def test_merge_equivalent(steps, inputs):
+    merged = SchemaValidator(core_schema.chain_schema(steps))
+    unmerged = SchemaValidator(core_schema.chain_schema(steps), {'merge_validators': False})
+    for input_value in inputs:
+        assert error_types(merged, input_value) == error_types(unmerged, input_value), input_value
+
+
+def test_merge_str():
+    v = SchemaValidator(
+        core_schema.chain_schema([core_schema.str_schema(min_length=2), core_schema.str_schema(pattern='^a')])
+    )
+    assert v.title == 'constrained-str'
+    assert v.validate_python('ab') == 'ab'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('ba')
+    assert exc_info.value.errors() == [
+        {
+            'type': 'string_pattern_mismatch',
+            'loc': (),
+            'msg': "String should match pattern '^a'",
+            'input': 'ba',
+            'ctx': {'pattern': '^a'},
+        }
+    ]
+
+    v = SchemaValidator(
+        core_schema.chain_schema([core_schema.str_schema(min_length=2), core_schema.str_schema(pattern='^a')]),
+        {'merge_validators': False},
+    )
+    assert v.title == 'chain[constrained-str,constrained-str]'
+
+
+def test_merge_tightest_bounds():
+    v = SchemaValidator(core_schema.chain_schema([core_schema.int_schema(le=10), core_schema.int_schema(le=5)]))
+    assert v.title == 'constrained-int'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(7)
+    assert exc_info.value.errors()[0]['ctx'] == {'le': 5}
+
+
+def test_merge_not_possible():
+    # a length check after a pattern would change which error is raised
+    v = SchemaValidator(
+        core_schema.chain_schema([core_schema.str_schema(pattern='^a'), core_schema.str_schema(min_length=3)])
+    )
+    assert v.title == 'chain[constrained-str,constrained-str]'
+    # transforms aren't merged
+    v = SchemaValidator(
+        core_schema.chain_schema([core_schema.str_schema(to_lower=True), core_schema.str_schema(max_length=3)])
+    )
+    assert v.title == 'chain[constrained-str,constrained-str]'
+    # labelled steps keep their label in errors
+    v = SchemaValidator(
+        core_schema.chain_schema(
+            [core_schema.int_schema(ge=0), core_schema.chain_step(core_schema.int_schema(le=5), label='max')]
+        )
+    )
+    assert v.title == 'chain[constrained-int,max]'
+
+
+def test_merge_chain_step_index():
+    v = SchemaValidator(
+        core_schema.chain_schema(
+            [
+                core_schema.general_plain_validator_function(lambda v, info: v),
+                core_schema.int_schema(ge=0),
+                core_schema.int_schema(le=5),
+                core_schema.general_plain_validator_function(lambda v, info: v * 2),
+                core_schema.int_schema(lt=8),
+            ]
+        )
+    )
+    assert v.title == 'chain[function-plain[<lambda>()],constrained-int,function-plain[<lambda>()],constrained-int]'
+    assert v.validate_python(3) == 6
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(6)
+    assert exc_info.value.errors()[0]['ctx'] == {'le': 5, 'chain_step': 1}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(4)
+    assert exc_info.value.errors()[0]['ctx'] == {'lt': 8, 'chain_step': 4}
@@ -0,0 +1,170 @@
+import gc
+import weakref
+from datetime import datetime
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+class Money:
+    def __init__(self, pence: int):
+        self.pence = pence
+
+    def __pydantic_core_coerce__(self, target: str):
+        if target == 'int':
+            return self.pence
+        elif target == 'float':
+            return self.pence / 100
+        elif target == 'str':
+            return f'£{self.pence / 100:.2f}'
+        elif target == 'bytes':
+            return str(self.pence).encode()
+        else:
+            return NotImplemented
+
+
+class Timestamp:
+    def __init__(self, value: str):
+        self.value = value
+
+    def __pydantic_core_coerce__(self, target: str):
+        if target == 'datetime':
+            return self.value
+        elif target == 'list':
+            return self.value.split('T')[0].split('-')
+        else:
+            return NotImplemented
+
+
+@pytest.mark.parametrize(
+    'schema,expected',
+    [
+        (core_schema.int_schema(), 123),
+        (core_schema.int_schema(ge=100), 123),
+        (core_schema.float_schema(), 1.23),
+        (core_schema.float_schema(lt=2), 1.23),
+        (core_schema.str_schema(), '£1.23'),
+        (core_schema.str_schema(to_upper=True), '£1.23'),
+        (core_schema.bytes_schema(), b'123'),
+        (core_schema.bytes_schema(max_length=5), b'123'),
+        (core_schema.bytes_schema(output='bytearray'), bytearray(b'123')),
+    ],
+)
+def test_coerce_hook(schema, expected):
+    v = SchemaValidator(schema)
+    assert v.validate_python(Money(123)) == expected
+
+
+def test_datetime_and_list():
+    v = SchemaValidator(core_schema.datetime_schema())
+    assert v.validate_python(Timestamp('2022-06-08T12:30')) == datetime(2022, 6, 8, 12, 30)
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    assert v.validate_python(Timestamp('2022-06-08T12:30')) == [2022, 6, 8]
+
+
+def test_not_implemented():
+    v = SchemaValidator(core_schema.datetime_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(Money(123))
+    errors = exc_info.value.errors()
+    assert len(errors) == 1
+    assert errors[0]['type'] == 'datetime_type'
+    assert isinstance(errors[0]['input'], Money)
+
+
+def test_coerced_value_invalid():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(Money(1.5))
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_from_float',
+            'loc': (),
+            'msg': 'Input should be a valid integer, got a number with a fractional part',
+            'input': 1.5,
+        }
+    ]
+
+
+def test_constraints_apply_to_coerced_value():
+    v = SchemaValidator(core_schema.int_schema(lt=100))
+    with pytest.raises(ValidationError, match='Input should be less than 100'):
+        v.validate_python(Money(123))
+
+
+def test_valid_input_skips_hook():
+    class MyStr(str):
+        def __pydantic_core_coerce__(self, target: str):
+            raise AssertionError('hook should not be called')
+
+    assert SchemaValidator(core_schema.str_schema()).validate_python(MyStr('foo')) == 'foo'
+
+
+def test_strict():
+    v = SchemaValidator(core_schema.int_schema(strict=True))
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python(Money(123))
+
+    v = SchemaValidator(core_schema.int_schema())
+    assert v.validate_python(Money(123)) == 123
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python(Money(123), strict=True)
+
+
+def test_config_disabled():
+    v = SchemaValidator(core_schema.int_schema(), {'coerce_hook': False})
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python(Money(123))
+
+
+def test_hook_error():
+    class Broken:
+        def __pydantic_core_coerce__(self, target: str):
+            raise RuntimeError(f'cannot coerce to {target}')
+
+    v = SchemaValidator(core_schema.float_schema())
+    with pytest.raises(RuntimeError, match='cannot coerce to float'):
+        v.validate_python(Broken())
+
+
+def test_hook_called_per_instance():
+    class Celsius:
+        def __init__(self, degrees: float):
+            self.degrees = degrees
+
+        def __pydantic_core_coerce__(self, target: str):
+            return self.degrees + 273.15
+
+    v = SchemaValidator(core_schema.float_schema())
+    assert v.validate_python(Celsius(0)) == 273.15
+    assert v.validate_python(Celsius(100)) == 373.15
+
+
+def test_hook_added_later():
+    class Later:
+        pass
+
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(ValidationError, match='type=int_type'):
+        v.validate_python(Later())
+
+    Later.__pydantic_core_coerce__ = lambda self, target: 42 if target == 'int' else NotImplemented
+    assert v.validate_python(Later()) == 42
+
+    del Later.__pydantic_core_coerce__
+    with pytest.raises(ValidationError, match='type=int_type'):
+        v.validate_python(Later())
+
+
+def test_types_not_kept_alive():
+    class Temporary:
+        pass
+
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(ValidationError):
+        v.validate_python(Temporary())
+    ref = weakref.ref(Temporary)
+    del Temporary, v
+    gc.collect()
+    assert ref() is None
@@ -0,0 +1,173 @@
+import json
+import math
+import re
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaSerializer, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
+
+COMPLEX_RULES = 'following the rules at https://docs.python.org/3/library/functions.html#complex'
+
+
+class ComplexSubclass(complex):
+    pass
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (1 + 2j, 1 + 2j),
+        (ComplexSubclass(1, 2), 1 + 2j),
+        (3, complex(3, 0)),
+        (2.5, complex(2.5, 0)),
+        ('1.5+2j', complex(1.5, 2)),
+        ('(1-2J)', complex(1, -2)),
+        (' 3j ', 3j),
+        ('1_000+1e3j', complex(1000, 1000)),
+        (b'1+1j', 1 + 1j),
+        ('x', Err(f'Input should be a valid complex string {COMPLEX_RULES} [type=complex_str_parsing, input_value=')),
+        ('1+2', Err('[type=complex_str_parsing,')),
+        ('', Err('[type=complex_str_parsing,')),
+        (
+            True,
+            Err(f'Input should be a valid python complex object, a number, or a valid complex string {COMPLEX_RULES}'),
+        ),
+        (None, Err('[type=complex_type, input_value=None, input_type=NoneType]')),
+        ([1, 2], Err('[type=complex_type,')),
+        (10**400, Err('[type=complex_type,')),
+    ],
+)
+def test_complex(input_value, expected):
+    v = SchemaValidator(core_schema.complex_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert type(output) is complex
+
+
+def test_complex_passthrough():
+    v = SchemaValidator(core_schema.complex_schema(strict=True))
+    c = complex(1, 2)
+    assert v.validate_python(c) is c
+    assert type(v.validate_python(ComplexSubclass(1, 2))) is complex
+
+
+@pytest.mark.parametrize('input_value', [1, 1.5, '1+2j', b'1+2j'])
+def test_strict(input_value):
+    v = SchemaValidator(core_schema.complex_schema(strict=True))
+    with pytest.raises(ValidationError, match=re.escape('[type=complex_type,')):
+        v.validate_python(input_value)
+
+
+def test_json(py_and_json: PyAndJson):
+    v = py_and_json(core_schema.complex_schema())
+    assert v.validate_test('1.5+2j') == complex(1.5, 2)
+    assert v.validate_test(2) == complex(2, 0)
+    assert v.validate_test(0.5) == complex(0.5, 0)
+    with pytest.raises(ValidationError, match=re.escape('[type=complex_str_parsing,')):
+        v.validate_test('1+2')
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('[1.5, 2]', complex(1.5, 2)),
+        ('[0, -1]', -1j),
+        ('[1]', Err('[type=complex_type,')),
+        ('[1, 2, 3]', Err('[type=complex_type,')),
+        ('[1, "2"]', Err('[type=complex_type,')),
+        ('[true, 1]', Err('[type=complex_type,')),
+        ('true', Err('[type=complex_type,')),
+        ('null', Err('[type=complex_type,')),
+    ],
+)
+def test_json_pair(input_value, expected):
+    v = SchemaValidator(core_schema.complex_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_json(input_value)
+    else:
+        assert v.validate_json(input_value) == expected
+
+
+def test_json_strict():
+    v = SchemaValidator(core_schema.complex_schema(strict=True))
+    # JSON has no complex type, so strings, numbers and pairs are accepted in strict mode
+    assert v.validate_json('"1+2j"') == 1 + 2j
+    assert v.validate_json('3') == complex(3, 0)
+    assert v.validate_json('[1, 2]') == 1 + 2j
+
+
+def test_allow_inf_nan():
+    v = SchemaValidator(core_schema.complex_schema())
+    assert v.validate_python('inf-infj') == complex(math.inf, -math.inf)
+    assert math.isnan(v.validate_python('nanj').imag)
+
+    v = SchemaValidator(core_schema.complex_schema(allow_inf_nan=False))
+    assert v.validate_python('1+2j') == 1 + 2j
+    for input_value in ['inf', '1+nanj', complex(1, math.inf), math.nan]:
+        with pytest.raises(ValidationError, match=re.escape('Input should be a finite number [type=finite_number,')):
+            v.validate_python(input_value)
+
+    v = SchemaValidator(core_schema.complex_schema(), {'allow_inf_nan': False})
+    with pytest.raises(ValidationError, match=re.escape('[type=finite_number,')):
+        v.validate_json('"infj"')
+
+
+@pytest.mark.parametrize(
+    'value,expected_str,expected_pair',
+    [
+        (complex(1.5, 2), '1.5+2j', [1.5, 2.0]),
+        (2j, '2j', [0.0, 2.0]),
+        (complex(1, 0), '1+0j', [1.0, 0.0]),
+        (complex(-0.0, -1), '-0-1j', [-0.0, -1.0]),
+        (complex(1e300, -1e-300), '1e+300-1e-300j', [1e300, -1e-300]),
+    ],
+)
+def test_serialization(value, expected_str, expected_pair):
+    s = SchemaSerializer(core_schema.complex_schema())
+    assert s.to_python(value) is value
+    assert s.to_python(value, mode='json') == expected_str
+    assert s.to_json(value) == f'"{expected_str}"'.encode()
+
+    s = SchemaSerializer(core_schema.complex_schema(), {'ser_json_complex': 'pair'})
+    assert s.to_python(value, mode='json') == expected_pair
+    assert json.loads(s.to_json(value)) == expected_pair
+
+    # both forms are read back as the same value
+    v = SchemaValidator(core_schema.complex_schema())
+    assert v.validate_json(f'"{expected_str}"') == value
+    assert v.validate_json(str(expected_pair)) == value
+
+
+def test_serialization_inf_nan():
+    value = complex(math.inf, math.nan)
+    s = SchemaSerializer(core_schema.complex_schema())
+    assert s.to_json(value) == b'"inf+nanj"'
+    # as with floats, infinite and NaN components are written as `null` in pairs
+    s = SchemaSerializer(core_schema.complex_schema(), {'ser_json_complex': 'pair'})
+    assert s.to_json(value) == b'[null,null]'
+
+
+def test_serialization_key():
+    s = SchemaSerializer(
+        core_schema.dict_schema(core_schema.complex_schema(), core_schema.int_schema()), {'ser_json_complex': 'pair'}
+    )
+    assert s.to_json({1 + 2j: 1}) == b'{"1+2j":1}'
+    assert s.to_python({1 + 2j: 1}, mode='json') == {'1+2j': 1}
+
+
+def test_serialization_fallback():
+    s = SchemaSerializer(core_schema.complex_schema())
+    with pytest.warns(UserWarning, match='Expected `complex` but got `int` - serialized value may not be as expected'):
+        assert s.to_json(1) == b'1'
+
+
+def test_invalid_ser_json_complex():
+    with pytest.raises(SchemaError, match='Invalid complex serialization mode: `foo`, expected `str` or `pair`'):
+        SchemaSerializer(core_schema.complex_schema(), {'ser_json_complex': 'foo'})
@@ -0,0 +1,185 @@
+from collections import OrderedDict, defaultdict, namedtuple
+
+import pytest
+
+from pydantic_core import SchemaValidator, core_schema
+
+
+class UpperDict(dict):
+    """dict subclass whose `__getitem__` and `items` upper-case string values"""
+
+    def __getitem__(self, key):
+        value = super().__getitem__(key)
+        return value.upper() if isinstance(value, str) else value
+
+    def items(self):
+        return [(k, self[k]) for k in self]
+
+
+class ReversedList(list):
+    def __iter__(self):
+        return reversed(list(super().__iter__()))
+
+
+class MyTuple(tuple):
+    def __iter__(self):
+        return iter(['x', 'y'])
+
+
+def typed_dict_validator(config=None):
+    return SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.str_schema(), validation_alias=[['alias_a'], ['a']]),
+                'b': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.str_schema(), default='default')
+                ),
+            }
+        ),
+        config=config,
+    )
+
+
+def test_typed_dict_getitem_override():
+    v = typed_dict_validator()
+    assert v.validate_python(UpperDict(a='x', b='y')) == {'a': 'X', 'b': 'Y'}
+    # plain dicts are unaffected
+    assert v.validate_python({'a': 'x', 'b': 'y'}) == {'a': 'x', 'b': 'y'}
+
+
+def test_typed_dict_force_exact():
+    v = typed_dict_validator(core_schema.CoreConfig(force_exact_container_path=True))
+    # overrides are ignored and the dict is read directly
+    assert v.validate_python(UpperDict(a='x', b='y')) == {'a': 'x', 'b': 'y'}
+
+
+@pytest.mark.parametrize('config', [None, core_schema.CoreConfig(force_exact_container_path=True)])
+def test_typed_dict_defaultdict(config):
+    v = typed_dict_validator(config)
+    input_value = defaultdict(lambda: 'missing', a='x')
+    # looking up `alias_a` and `b` mustn't call `__missing__`, which would insert them
+    assert v.validate_python(input_value) == {'a': 'x', 'b': 'default'}
+    assert dict(input_value) == {'a': 'x'}
+
+
+def test_typed_dict_ordered_dict():
+    v = typed_dict_validator()
+    assert v.validate_python(OrderedDict([('b', 'y'), ('a', 'x')])) == {'a': 'x', 'b': 'y'}
+
+
+def test_dict_subclass_input():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.str_schema(), core_schema.str_schema()))
+    output = v.validate_python(UpperDict(a='x'))
+    assert output == {'a': 'X'}
+    assert type(output) is dict
+
+    output = v.validate_python(OrderedDict([('b', 'y'), ('a', 'x')]))
+    assert output == {'b': 'y', 'a': 'x'}
+    assert list(output) == ['b', 'a']
+    assert type(output) is dict
+
+    input_value = defaultdict(list, a='x')
+    output = v.validate_python(input_value)
+    assert output == {'a': 'x'}
+    assert type(output) is dict
+
+
+def test_dict_force_exact():
+    v = SchemaValidator(
+        core_schema.dict_schema(core_schema.str_schema(), core_schema.str_schema()),
+        config=core_schema.CoreConfig(force_exact_container_path=True),
+    )
+    output = v.validate_python(UpperDict(a='x'))
+    assert output == {'a': 'x'}
+    assert type(output) is dict
+
+
+@pytest.mark.parametrize('config', [None, core_schema.CoreConfig(force_exact_container_path=True)])
+def test_dict_preserve_subclass(config):
+    v = SchemaValidator(
+        core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema(), output_preserve_subclass=True),
+        config=config,
+    )
+    output = v.validate_python(OrderedDict([('b', '2'), ('a', '1')]))
+    assert output == OrderedDict([('b', 2), ('a', 1)])
+    assert type(output) is OrderedDict
+
+    output = v.validate_python(defaultdict(int, a='1'))
+    assert type(output) is defaultdict
+    assert output == {'a': 1}
+    # `default_factory` is kept
+    assert output['z'] == 0
+
+    # plain dicts and other mappings still give a plain dict
+    assert type(v.validate_python({'a': '1'})) is dict
+    assert v.validate_json('{"a": 1}') == {'a': 1}
+
+
+def test_dict_preserve_subclass_ordered_dict_output():
+    v = SchemaValidator(core_schema.dict_schema(output_type='ordered_dict', output_preserve_subclass=True))
+    assert type(v.validate_python({'a': 1})) is OrderedDict
+    assert type(v.validate_python(defaultdict(int, a=1))) is defaultdict
+
+
+@pytest.mark.parametrize('items_schema', [None, core_schema.int_schema()])
+def test_list_subclass_input(items_schema):
+    v = SchemaValidator(core_schema.list_schema(items_schema))
+    output = v.validate_python(ReversedList([1, 2, 3]))
+    # `__iter__` is used
+    assert output == [3, 2, 1]
+    assert type(output) is list
+
+
+@pytest.mark.parametrize('items_schema', [None, core_schema.int_schema()])
+def test_list_force_exact(items_schema):
+    v = SchemaValidator(
+        core_schema.list_schema(items_schema), config=core_schema.CoreConfig(force_exact_container_path=True)
+    )
+    output = v.validate_python(ReversedList([1, 2, 3]))
+    assert output == [1, 2, 3]
+    assert type(output) is list
+
+
+@pytest.mark.parametrize('config', [None, core_schema.CoreConfig(force_exact_container_path=True)])
+@pytest.mark.parametrize('items_schema', [None, core_schema.int_schema()])
+def test_list_preserve_subclass(config, items_schema):
+    class MyList(list):
+        pass
+
+    v = SchemaValidator(core_schema.list_schema(items_schema, output_preserve_subclass=True), config=config)
+    output = v.validate_python(MyList([1, 2]))
+    assert output == [1, 2]
+    assert type(output) is MyList
+    assert type(v.validate_python([1, 2])) is list
+    assert type(v.validate_python((1, 2))) is list
+
+
+def test_list_subclass_strict():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), strict=True))
+    assert v.validate_python(ReversedList([1, 2])) == [2, 1]
+
+
+def test_tuple_subclass():
+    v = SchemaValidator(core_schema.tuple_variable_schema())
+    output = v.validate_python(MyTuple((1, 2)))
+    assert output == ('x', 'y')
+    assert type(output) is tuple
+
+    config = core_schema.CoreConfig(force_exact_container_path=True)
+    v = SchemaValidator(core_schema.tuple_variable_schema(), config=config)
+    output = v.validate_python(MyTuple((1, 2)))
+    assert output == (1, 2)
+    assert type(output) is tuple
+
+
+def test_tuple_positional_subclass():
+    v = SchemaValidator(core_schema.tuple_positional_schema([core_schema.str_schema(), core_schema.str_schema()]))
+    assert v.validate_python(MyTuple((1, 2))) == ('x', 'y')
+
+
+def test_namedtuple():
+    Point = namedtuple('Point', 'x y')
+    v = SchemaValidator(core_schema.tuple_variable_schema(core_schema.int_schema()))
+    output = v.validate_python(Point('1', 2))
+    assert output == (1, 2)
+    assert type(output) is tuple
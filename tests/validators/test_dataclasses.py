@@ -1,11 +1,12 @@
 import dataclasses
 import re
+import sys
 from typing import Any, Dict, Union
 
 import pytest
 from dirty_equals import IsListOrTuple, IsStr
 
-from pydantic_core import ArgsKwargs, SchemaValidator, ValidationError, core_schema
+from pydantic_core import ArgsKwargs, SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson
 
@@ -185,6 +186,50 @@ def test_aliases(py_and_json: PyAndJson):
     )
 
 
+def test_alias_alternatives(py_and_json: PyAndJson):
+    schema = core_schema.dataclass_args_schema(
+        'MyDataclass',
+        [
+            core_schema.dataclass_field(
+                name='a', schema=core_schema.int_schema(), validation_alias=[['Apple', 'v'], 'apple', ['Apples', -1]]
+            )
+        ],
+    )
+    v = py_and_json(schema)
+    assert v.validate_test({'Apple': {'v': 1}, 'apple': 2}) == ({'a': 1}, None)
+    assert v.validate_test({'Apple': 'x', 'apple': 2}) == ({'a': 2}, None)
+    assert v.validate_test({'Apples': [4, 3]}) == ({'a': 3}, None)
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'a': 1})
+    assert exc_info.value.errors() == [
+        {'type': 'missing', 'loc': ('Apple', 'v'), 'msg': 'Field required', 'input': {'a': 1}}
+    ]
+
+
+def test_field_populate_by_name(py_and_json: PyAndJson):
+    schema = core_schema.dataclass_args_schema(
+        'MyDataclass',
+        [
+            core_schema.dataclass_field(
+                name='a', schema=core_schema.int_schema(), validation_alias='A', populate_by_name=False
+            ),
+            core_schema.dataclass_field(name='b', schema=core_schema.int_schema(), validation_alias='B'),
+        ],
+        populate_by_name=True,
+    )
+    v = py_and_json(schema)
+    assert v.validate_test({'A': 1, 'b': 2}) == ({'a': 1, 'b': 2}, None)
+    assert v.validate_test({'A': 1, 'B': 2, 'b': 3}) == ({'a': 1, 'b': 2}, None)
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'a': 1})
+    assert exc_info.value.errors() == [
+        {'type': 'missing', 'loc': ('A',), 'msg': 'Field required', 'input': {'a': 1}},
+        {'type': 'missing', 'loc': ('b',), 'msg': 'Field required', 'input': {'a': 1}},
+    ]
+
+
 @dataclasses.dataclass
 class FooDataclass:
     a: str
@@ -1039,3 +1084,148 @@ def test_extra_behavior_allow(config: Union[core_schema.CoreConfig, None], schem
 
     v.validate_assignment(m, 'not_f', '123')
     assert getattr(m, 'not_f') == '123'
+
+
+def test_field_title():
+    @dataclasses.dataclass
+    class MyModel:
+        a: int
+        b: str
+
+    v = SchemaValidator(
+        core_schema.dataclass_schema(
+            MyModel,
+            core_schema.dataclass_args_schema(
+                'MyModel',
+                [
+                    core_schema.dataclass_field('a', core_schema.int_schema(), kw_only=False, title='Field A'),
+                    core_schema.dataclass_field('b', core_schema.str_schema(), title='Field B', frozen=True),
+                ],
+            ),
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(ArgsKwargs(('x',)))
+    assert [(e['type'], e['loc'], e['ctx']) for e in exc_info.value.errors()] == [
+        ('int_parsing', (0,), {'field_title': 'Field A'}),
+        ('missing', ('b',), {'field_title': 'Field B'}),
+    ]
+
+    m = v.validate_python({'a': 1, 'b': 'x'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment(m, 'a', 'x')
+    assert exc_info.value.errors()[0]['ctx'] == {'field_title': 'Field A'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment(m, 'b', 'y')
+    assert exc_info.value.errors() == [
+        {
+            'type': 'frozen_field',
+            'loc': ('b',),
+            'msg': 'Field is frozen',
+            'input': 'y',
+            'ctx': {'field_title': 'Field B'},
+        }
+    ]
+
+
+@pytest.mark.skipif(sys.version_info < (3, 10), reason='requires python3.10 or higher')
+@pytest.mark.parametrize('frozen', [False, True])
+def test_slots(frozen: bool):
+    post_init_calls = []
+
+    @dataclasses.dataclass(slots=True, frozen=frozen)
+    class Foo:
+        a: int
+        b: str
+        c: dataclasses.InitVar[int]
+
+        def __post_init__(self, c: int):
+            post_init_calls.append(c)
+            if c < 0:
+                raise ValueError('c should be positive')
+
+    v = SchemaValidator(
+        core_schema.dataclass_schema(
+            Foo,
+            core_schema.dataclass_args_schema(
+                'Foo',
+                [
+                    core_schema.dataclass_field(name='a', schema=core_schema.int_schema()),
+                    core_schema.dataclass_field(name='b', schema=core_schema.str_schema()),
+                    core_schema.dataclass_field(name='c', schema=core_schema.int_schema(), init_only=True),
+                ],
+                collect_init_only=True,
+            ),
+            post_init=True,
+            slots=True,
+            frozen=frozen,
+        )
+    )
+    foo = v.validate_python({'a': '1', 'b': 'x', 'c': '2'})
+    assert dataclasses.asdict(foo) == {'a': 1, 'b': 'x'}
+    assert not hasattr(foo, '__dict__')
+    assert post_init_calls == [2]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"a": 1, "b": "x", "c": -1}')
+    assert exc_info.value.errors() == [
+        {
+            'type': 'value_error',
+            'loc': (),
+            'msg': 'Value error, c should be positive',
+            'input': {'a': 1, 'b': 'x', 'c': -1},
+            'ctx': {'error': 'c should be positive'},
+        }
+    ]
+    assert post_init_calls == [2, -1]
+
+
+@pytest.mark.skipif(sys.version_info < (3, 10), reason='requires python3.10 or higher')
+def test_slots_assignment_and_revalidate():
+    @dataclasses.dataclass(slots=True)
+    class Foo:
+        a: int
+        b: str = 'default'
+
+    v = SchemaValidator(
+        core_schema.dataclass_schema(
+            Foo,
+            core_schema.dataclass_args_schema(
+                'Foo',
+                [
+                    core_schema.dataclass_field(name='a', schema=core_schema.int_schema()),
+                    core_schema.dataclass_field(
+                        name='b', schema=core_schema.with_default_schema(core_schema.str_schema(), default='default')
+                    ),
+                ],
+            ),
+            slots=True,
+            revalidate_instances='always',
+        )
+    )
+    foo = v.validate_python({'a': 1})
+    assert foo == Foo(1)
+
+    v.validate_assignment(foo, 'a', '2')
+    assert foo == Foo(2)
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_assignment(foo, 'a', 'x')
+    assert foo == Foo(2)
+
+    foo.b = 123
+    with pytest.raises(ValidationError, match='Input should be a valid string'):
+        v.validate_python(foo)
+    foo.b = 'x'
+    foo2 = v.validate_python(foo)
+    assert foo2 is not foo
+    assert foo2 == Foo(2, 'x')
+
+
+def test_slots_invalid():
+    @dataclasses.dataclass
+    class Foo:
+        a: int
+
+    with pytest.raises(SchemaError, match='`slots` requires a `dataclass-args` schema'):
+        SchemaValidator(core_schema.dataclass_schema(Foo, core_schema.any_schema(), slots=True))
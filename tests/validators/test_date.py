@@ -283,3 +283,46 @@ def test_date_past_future_today():
 def test_offset_too_large():
     with pytest.raises(SchemaError, match=r'Input should be less than 86400 \[type=less_than,'):
         SchemaValidator(core_schema.date_schema(now_op='past', now_utc_offset=24 * 3600))
+
+
+@pytest.mark.parametrize(
+    'unit,input_value,expected',
+    [
+        ('infer', 1609459200000, date(2021, 1, 1)),
+        ('seconds', 1609459200, date(2021, 1, 1)),
+        (
+            'seconds',
+            1609459200000,
+            Err(
+                'Input should be a valid date or datetime, dates after 9999 are not supported as unix timestamps '
+                'in seconds [type=date_from_datetime_parsing,'
+            ),
+        ),
+        ('milliseconds', 1609459200000, date(2021, 1, 1)),
+        ('milliseconds', 1609459200000.0, date(2021, 1, 1)),
+        ('milliseconds', 0, date(1970, 1, 1)),
+        (
+            'milliseconds',
+            1609459200001,
+            Err('should have zero time - e.g. be exact dates [type=date_from_datetime_inexact,'),
+        ),
+        (
+            'milliseconds',
+            1609459200,
+            Err('should have zero time - e.g. be exact dates [type=date_from_datetime_inexact,'),
+        ),
+    ],
+)
+def test_val_temporal_unit(py_and_json: PyAndJson, unit, input_value, expected):
+    v = py_and_json(core_schema.date_schema(val_temporal_unit=unit))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_val_temporal_unit_config():
+    v = SchemaValidator(core_schema.date_schema(), {'val_temporal_unit': 'milliseconds'})
+    assert v.validate_python(1609459200000) == date(2021, 1, 1)
+    assert v.validate_json('1609459200000') == date(2021, 1, 1)
@@ -1,6 +1,7 @@
 import json
 import platform
 import re
+import sys
 from datetime import date, datetime, time, timedelta, timezone, tzinfo
 from decimal import Decimal
 
@@ -117,6 +118,8 @@ def test_keep_tz_bound():
         (True, Err('Input should be a valid datetime [type=datetime_type')),
         (None, Err('Input should be a valid datetime [type=datetime_type')),
         ([1, 2, 3], Err('Input should be a valid datetime [type=datetime_type')),
+        (2**64, Err('dates after 9999 are not supported as unix timestamps [type=datetime_parsing')),
+        (-(2**64), Err('dates before 1600 are not supported as unix timestamps [type=datetime_parsing')),
     ],
 )
 def test_datetime_json(py_and_json: PyAndJson, input_value, expected):
@@ -387,7 +390,7 @@ def test_can_validate_aware_value():
 def test_raises_validation_error_when_aware_given_naive():
     aware_validator = SchemaValidator(core_schema.datetime_schema(tz_constraint='aware'))
     value = datetime.now()
-    with pytest.raises(ValidationError, match=r'Datetime should have timezone info'):
+    with pytest.raises(ValidationError, match=r'Input should have timezone info'):
         assert aware_validator.validate_python(value)
 
 
@@ -400,5 +403,344 @@ def test_can_validate_naive_value():
 def test_raises_validation_error_when_naive_given_aware():
     naive_validator = SchemaValidator(core_schema.datetime_schema(tz_constraint='naive'))
     value = datetime.now(tz=timezone.utc)
-    with pytest.raises(ValidationError, match=r'Datetime should not have timezone info'):
+    with pytest.raises(ValidationError, match=r'Input should not have timezone info'):
         assert naive_validator.validate_python(value)
+
+
+@pytest.mark.parametrize(
+    'tz_constraint,input_value,expected',
+    [
+        ('aware', '2022-06-08T12:13:14Z', datetime(2022, 6, 8, 12, 13, 14, tzinfo=timezone.utc)),
+        ('aware', '2022-06-08T12:13:14', Err('Input should have timezone info [type=timezone_aware')),
+        ('naive', '2022-06-08T12:13:14', datetime(2022, 6, 8, 12, 13, 14)),
+        ('naive', '2022-06-08T12:13:14+01:00', Err('Input should not have timezone info [type=timezone_naive')),
+        (3600, '2022-06-08T12:13:14+01:00', datetime(2022, 6, 8, 12, 13, 14, tzinfo=timezone(timedelta(hours=1)))),
+        (3600, '2022-06-08T12:13:14+02:00', Err('Timezone offset of 3600 required, got 7200 [type=timezone_offset')),
+        (3600, '2022-06-08T12:13:14', Err('Input should have timezone info [type=timezone_aware')),
+        (-1800, '2022-06-08T12:13:14-00:30', datetime(2022, 6, 8, 12, 13, 14, tzinfo=timezone(timedelta(minutes=-30)))),
+        # timestamps are treated as UTC
+        ('aware', 1654646400, datetime(2022, 6, 8, tzinfo=timezone.utc)),
+        ('naive', 1654646400, Err('Input should not have timezone info [type=timezone_naive')),
+        (0, 1654646400.5, datetime(2022, 6, 8, 0, 0, 0, 500000, tzinfo=timezone.utc)),
+        (3600, 1654646400, Err('Timezone offset of 3600 required, got 0 [type=timezone_offset')),
+    ],
+)
+def test_tz_constraint(py_and_json: PyAndJson, tz_constraint, input_value, expected):
+    v = py_and_json(core_schema.datetime_schema(tz_constraint=tz_constraint))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+        assert output.utcoffset() == expected.utcoffset()
+
+
+@pytest.mark.parametrize(
+    'tz_constraint,input_value,expected_type,expected_ctx',
+    [
+        ('aware', datetime(2022, 6, 8, 12), 'timezone_aware', {'tz_constraint': 'aware'}),
+        (3600, datetime(2022, 6, 8, 12), 'timezone_aware', {'tz_constraint': '3600'}),
+        ('naive', datetime(2022, 6, 8, 12, tzinfo=timezone.utc), 'timezone_naive', {'tz_constraint': 'naive'}),
+    ],
+)
+def test_tz_constraint_ctx(tz_constraint, input_value, expected_type, expected_ctx):
+    v = SchemaValidator(core_schema.datetime_schema(tz_constraint=tz_constraint))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    error = exc_info.value.errors()[0]
+    assert error['type'] == expected_type
+    assert error['ctx'] == expected_ctx
+
+
+def test_tz_constraint_offset_python():
+    v = SchemaValidator(core_schema.datetime_schema(tz_constraint=3600))
+    value = datetime(2022, 6, 8, 12, tzinfo=timezone(timedelta(hours=1)))
+    assert v.validate_python(value) is value
+    assert v.validate_python(value, strict=True) is value
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(datetime(2022, 6, 8, 12, tzinfo=timezone.utc), strict=True)
+    # insert_assert(exc_info.value.errors())
+    assert exc_info.value.errors() == [
+        {
+            'type': 'timezone_offset',
+            'loc': (),
+            'msg': 'Timezone offset of 3600 required, got 0',
+            'input': datetime(2022, 6, 8, 12, tzinfo=timezone.utc),
+            'ctx': {'tz_expected': 3600, 'tz_actual': 0},
+        }
+    ]
+    with pytest.raises(ValidationError, match='Input should have timezone info'):
+        v.validate_python(datetime(2022, 6, 8, 12), strict=True)
+
+
+def test_tz_constraint_timestamps_without_constraint():
+    # timestamps stay naive without a tz_constraint
+    v = SchemaValidator(core_schema.datetime_schema())
+    assert v.validate_python(1654646400) == datetime(2022, 6, 8)
+
+
+@pytest.mark.parametrize('tz_constraint', [86_400, -86_400])
+def test_tz_constraint_offset_out_of_range(tz_constraint):
+    with pytest.raises(SchemaError, match='datetime.tz_constraint.constrained-int'):
+        SchemaValidator(core_schema.datetime_schema(tz_constraint=tz_constraint))
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('2022-06-08T12:13:14Z', datetime(2022, 6, 8, 12, 13, 14, tzinfo=timezone.utc)),
+        ('2022-06-08t12:13:14z', datetime(2022, 6, 8, 12, 13, 14, tzinfo=timezone.utc)),
+        (
+            '2022-06-08T12:13:14.123+01:00',
+            datetime(2022, 6, 8, 12, 13, 14, 123000, tzinfo=timezone(timedelta(hours=1))),
+        ),
+        ('2022-06-08 12:13:14Z', Err('RFC 3339 requires `T` to separate the date and time, not a space')),
+        ('2022-06-08T12:13:14', Err('RFC 3339 requires a timezone offset, e.g. `Z` or `+01:00`')),
+        ('2022-06-08T12:13Z', Err('RFC 3339 requires a time in the format HH:MM:SS')),
+        ('2022-06-08T12:13:14+0100', Err('RFC 3339 requires the timezone offset to be `Z` or in the format ±HH:MM')),
+        (
+            1655205632,
+            Err('Input should be a valid datetime, RFC 3339 datetimes must be strings [type=datetime_parsing'),
+        ),
+        ('foobar', Err('Input should be a valid datetime, input is too short [type=datetime_parsing')),
+    ],
+)
+def test_strict_rfc3339(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.datetime_schema(strict_rfc3339=True))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_strict_rfc3339_error():
+    v = SchemaValidator(core_schema.datetime_schema(strict_rfc3339=True))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('"2022-06-08 12:13:14"')
+    assert exc_info.value.errors() == [
+        {
+            'type': 'datetime_parsing',
+            'loc': (),
+            'msg': 'Input should be a valid datetime, RFC 3339 requires `T` to separate the date and time, not a space',
+            'input': '2022-06-08 12:13:14',
+            'ctx': {'error': 'RFC 3339 requires `T` to separate the date and time, not a space'},
+        }
+    ]
+
+
+def test_strict_rfc3339_datetime_object():
+    v = SchemaValidator(core_schema.datetime_schema(strict_rfc3339=True))
+    value = datetime(2022, 6, 8, 12, 13, 14)
+    assert v.validate_python(value) is value
+
+
+requires_zoneinfo = pytest.mark.skipif(sys.version_info < (3, 9), reason='requires zoneinfo, python3.9 or higher')
+
+
+@requires_zoneinfo
+@pytest.mark.parametrize(
+    'input_value,expected_iso',
+    [
+        ('2024-07-01 12:00:00 Europe/London', '2024-07-01T12:00:00+01:00'),
+        ('2024-01-01T12:00:00 Europe/London', '2024-01-01T12:00:00+00:00'),
+        ('2024-03-10 01:59:59.5 America/New_York', '2024-03-10T01:59:59.500000-05:00'),
+        ('2024-03-10 03:00:00 America/New_York', '2024-03-10T03:00:00-04:00'),
+        ('2024-07-01 12:00:00 UTC', '2024-07-01T12:00:00+00:00'),
+    ],
+)
+def test_tz_names(py_and_json: PyAndJson, input_value, expected_iso):
+    from zoneinfo import ZoneInfo
+
+    v = py_and_json(core_schema.datetime_schema(tz_names=True))
+    output = v.validate_test(input_value)
+    assert output.isoformat() == expected_iso
+    assert output.tzinfo is ZoneInfo(input_value.rsplit(' ', 1)[1])
+
+
+@requires_zoneinfo
+def test_tz_names_other_inputs():
+    v = SchemaValidator(core_schema.datetime_schema(tz_names=True, tz_constraint='aware'))
+    # strings without a zone name and other inputs are validated as usual
+    assert v.validate_python('2024-07-01 12:00:00+02:00') == datetime(
+        2024, 7, 1, 12, tzinfo=timezone(timedelta(hours=2))
+    )
+    assert v.validate_python(datetime(2024, 7, 1, 12, tzinfo=timezone.utc)) == datetime(
+        2024, 7, 1, 12, tzinfo=timezone.utc
+    )
+    with pytest.raises(ValidationError, match='Input should have timezone info'):
+        v.validate_python('2024-07-01 12:00:00')
+
+
+@requires_zoneinfo
+@pytest.mark.parametrize(
+    'dst_policy,input_value,expected_iso,fold',
+    [
+        # spring forward, 02:30 doesn't exist
+        ('earlier', '2024-03-10 02:30:00 America/New_York', '2024-03-10T01:30:00-05:00', 0),
+        ('later', '2024-03-10 02:30:00 America/New_York', '2024-03-10T03:30:00-04:00', 0),
+        # fall back, 01:30 happens twice
+        ('earlier', '2024-11-03 01:30:00 America/New_York', '2024-11-03T01:30:00-04:00', 0),
+        ('later', '2024-11-03 01:30:00 America/New_York', '2024-11-03T01:30:00-05:00', 1),
+        # Lord Howe island moves its clocks by half an hour
+        ('earlier', '2024-04-07 01:45:00 Australia/Lord_Howe', '2024-04-07T01:45:00+11:00', 0),
+        ('later', '2024-04-07 01:45:00 Australia/Lord_Howe', '2024-04-07T01:45:00+10:30', 1),
+    ],
+)
+def test_tz_names_dst_policy(dst_policy, input_value, expected_iso, fold):
+    v = SchemaValidator(core_schema.datetime_schema(tz_names=True, dst_policy=dst_policy))
+    output = v.validate_python(input_value)
+    assert output.isoformat() == expected_iso
+    assert output.fold == fold
+
+
+@requires_zoneinfo
+@pytest.mark.parametrize(
+    'input_value,error_type,msg',
+    [
+        (
+            '2024-03-10 02:30:00 America/New_York',
+            'datetime_nonexistent',
+            "Datetime does not exist in time zone 'America/New_York', it falls in a DST gap",
+        ),
+        (
+            '2024-11-03 01:30:00 America/New_York',
+            'datetime_ambiguous',
+            "Datetime is ambiguous in time zone 'America/New_York', it falls in a DST overlap",
+        ),
+    ],
+)
+def test_tz_names_dst_error(py_and_json: PyAndJson, input_value, error_type, msg):
+    v = py_and_json(core_schema.datetime_schema(tz_names=True))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test(input_value)
+    assert exc_info.value.errors() == [
+        {'type': error_type, 'loc': (), 'msg': msg, 'input': input_value, 'ctx': {'zone': 'America/New_York'}}
+    ]
+
+
+@requires_zoneinfo
+def test_tz_names_invalid():
+    v = SchemaValidator(core_schema.datetime_schema(tz_names=True))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('2024-07-01 12:00:00 Mars/Olympus_Mons')
+    assert exc_info.value.errors() == [
+        {
+            'type': 'datetime_unknown_zone',
+            'loc': (),
+            'msg': "Input should have a valid IANA time zone name, unknown time zone 'Mars/Olympus_Mons'",
+            'input': '2024-07-01 12:00:00 Mars/Olympus_Mons',
+            'ctx': {'zone': 'Mars/Olympus_Mons'},
+        }
+    ]
+    with pytest.raises(ValidationError, match="a time zone name can't be combined with a UTC offset"):
+        v.validate_python('2024-07-01 12:00:00+01:00 Europe/London')
+    with pytest.raises(ValidationError, match='Input should be a valid datetime, input is too short'):
+        v.validate_python('2024 Europe/London')
+
+
+def test_dst_policy_without_tz_names():
+    with pytest.raises(SchemaError, match='`dst_policy` can only be used with `tz_names`'):
+        SchemaValidator(core_schema.datetime_schema(dst_policy='later'))
+
+
+@pytest.mark.parametrize(
+    'unit,input_value,expected',
+    [
+        ('infer', 1609459200, datetime(2021, 1, 1)),
+        ('infer', 1609459200000, datetime(2021, 1, 1)),
+        ('seconds', 1609459200, datetime(2021, 1, 1)),
+        ('seconds', 1609459200.5, datetime(2021, 1, 1, 0, 0, 0, 500000)),
+        ('seconds', -1.25, datetime(1969, 12, 31, 23, 59, 58, 750000)),
+        ('seconds', 30_000_000_000, datetime(2920, 8, 30, 5, 20)),
+        ('seconds', 253_402_300_799, datetime(9999, 12, 31, 23, 59, 59)),
+        ('seconds', -11_676_096_000, datetime(1600, 1, 1)),
+        (
+            'seconds',
+            253_402_300_800,
+            Err('dates after 9999 are not supported as unix timestamps in seconds [type=datetime_parsing,'),
+        ),
+        ('seconds', 1609459200000, Err('dates after 9999 are not supported as unix timestamps in seconds')),
+        ('milliseconds', 1609459200000, datetime(2021, 1, 1)),
+        ('milliseconds', 1609459200, datetime(1970, 1, 19, 15, 4, 19, 200000)),
+        ('milliseconds', 1609459200123.5, datetime(2021, 1, 1, 0, 0, 0, 123500)),
+        ('milliseconds', -1, datetime(1969, 12, 31, 23, 59, 59, 999000)),
+        ('milliseconds', -1.5, datetime(1969, 12, 31, 23, 59, 59, 998500)),
+        (
+            'milliseconds',
+            -11_676_096_000_001,
+            Err('dates before 1600 are not supported as unix timestamps in milliseconds [type=datetime_parsing,'),
+        ),
+    ],
+)
+def test_val_temporal_unit(py_and_json: PyAndJson, unit, input_value, expected):
+    v = py_and_json(core_schema.datetime_schema(val_temporal_unit=unit))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_val_temporal_unit_config():
+    v = SchemaValidator(core_schema.datetime_schema(), {'val_temporal_unit': 'milliseconds'})
+    assert v.validate_python(1609459200000) == datetime(2021, 1, 1)
+    assert v.validate_json('1609459200000') == datetime(2021, 1, 1)
+    assert v.validate_python('2021-01-01T00:00:00') == datetime(2021, 1, 1)
+
+    v = SchemaValidator(core_schema.datetime_schema(val_temporal_unit='seconds'), {'val_temporal_unit': 'milliseconds'})
+    assert v.validate_python(1609459200) == datetime(2021, 1, 1)
+
+
+def test_val_temporal_unit_invalid():
+    with pytest.raises(SchemaError, match="Input should be 'seconds', 'milliseconds' or 'infer'"):
+        SchemaValidator(core_schema.datetime_schema(val_temporal_unit='minutes'))
+
+
+@pytest.mark.parametrize(
+    'microseconds_precision,input_value,expected',
+    [
+        (
+            'truncate',
+            '2023-01-01T12:00:00.123456789Z',
+            datetime(2023, 1, 1, 12, 0, 0, 123_456, tzinfo=timezone.utc),
+        ),
+        ('truncate', '2023-01-01T12:00:00.9999999', datetime(2023, 1, 1, 12, 0, 0, 999_999)),
+        (
+            'truncate',
+            '2023-01-01T12:00:00.1234567+01:00',
+            datetime(2023, 1, 1, 12, 0, 0, 123_456, tzinfo=timezone(timedelta(hours=1))),
+        ),
+        ('error', '2023-01-01T12:00:00.123456Z', datetime(2023, 1, 1, 12, 0, 0, 123_456, tzinfo=timezone.utc)),
+        ('error', '2023-01-01T12:00:00.123456789Z', Err('got 9 fractional second digits')),
+    ],
+)
+def test_microseconds_precision(py_and_json: PyAndJson, microseconds_precision, input_value, expected):
+    v = py_and_json(core_schema.datetime_schema(microseconds_precision=microseconds_precision))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_microseconds_precision_error():
+    v = SchemaValidator(core_schema.datetime_schema(), {'microseconds_precision': 'error'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('"2023-01-01T12:00:00.123456789Z"')
+    assert exc_info.value.errors() == [
+        {
+            'type': 'datetime_microseconds_precision',
+            'loc': (),
+            'msg': 'Input should have at most microsecond precision, got 9 fractional second digits',
+            'input': '2023-01-01T12:00:00.123456789Z',
+            'ctx': {'precision': 9},
+        }
+    ]
+    # other parsing errors take priority
+    with pytest.raises(ValidationError, match=r'Input should be a valid datetime, .* \[type=datetime_parsing'):
+        v.validate_python('2023-13-01T12:00:00.123456789Z')
+
+    with pytest.raises(SchemaError, match="Input should be 'truncate' or 'error'"):
+        SchemaValidator(core_schema.datetime_schema(microseconds_precision='round'))
@@ -0,0 +1,228 @@
+import re
+from decimal import Decimal
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaSerializer, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
+
+
+class DecimalSubclass(Decimal):
+    pass
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (Decimal('1.5'), Decimal('1.5')),
+        (DecimalSubclass('2.5'), Decimal('2.5')),
+        (3, Decimal(3)),
+        (10**100, Decimal(10**100)),
+        ('1.50', Decimal('1.50')),
+        (' -1_000.5 ', Decimal('-1000.5')),
+        ('1e3', Decimal('1E+3')),
+        (b'42', Decimal(42)),
+        # floats are converted from their repr
+        (0.1, Decimal('0.1')),
+        (1e300, Decimal('1E+300')),
+        ('x', Err('Input should be a valid decimal [type=decimal_parsing, input_value=\'x\', input_type=str]')),
+        ('1/2', Err('Input should be a valid decimal [type=decimal_parsing,')),
+        ('inf', Err('Input should be a finite number [type=finite_number,')),
+        (float('nan'), Err('Input should be a finite number [type=finite_number,')),
+        (Decimal('-Infinity'), Err('Input should be a finite number [type=finite_number,')),
+        (Decimal('sNaN'), Err('Input should be a finite number [type=finite_number,')),
+        (True, Err('Decimal input should be an integer, float, string or Decimal object [type=decimal_type,')),
+        (None, Err('Decimal input should be an integer, float, string or Decimal object [type=decimal_type,')),
+        ([1, 2], Err('Decimal input should be an integer, float, string or Decimal object [type=decimal_type,')),
+    ],
+)
+def test_decimal(input_value, expected):
+    v = SchemaValidator(core_schema.decimal_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert type(output) is Decimal
+
+
+def test_decimal_passthrough():
+    v = SchemaValidator(core_schema.decimal_schema(strict=True))
+    d = Decimal('1.5')
+    assert v.validate_python(d) is d
+    assert type(v.validate_python(DecimalSubclass('1.5'))) is Decimal
+
+
+@pytest.mark.parametrize('input_value', [1, '1.5', 0.5, b'1'])
+def test_strict(input_value):
+    v = SchemaValidator(core_schema.decimal_schema(strict=True))
+    with pytest.raises(ValidationError, match=re.escape('[type=decimal_type,')):
+        v.validate_python(input_value)
+
+
+def test_json(py_and_json: PyAndJson):
+    v = py_and_json(core_schema.decimal_schema())
+    assert v.validate_test('1.25') == Decimal('1.25')
+    assert v.validate_test(2) == Decimal(2)
+    assert v.validate_test(0.1) == Decimal('0.1')
+    with pytest.raises(ValidationError, match=re.escape('[type=decimal_type,')):
+        v.validate_test([1])
+
+
+def test_json_strict():
+    v = SchemaValidator(core_schema.decimal_schema(strict=True))
+    # JSON has no decimal type, so numbers and strings are accepted in strict mode
+    assert v.validate_json('"1.5"') == Decimal('1.5')
+    assert v.validate_json('0.1') == Decimal('0.1')
+    assert v.validate_json('-7') == Decimal(-7)
+    with pytest.raises(ValidationError, match=re.escape('[type=decimal_type,')):
+        v.validate_json('true')
+
+
+def test_json_big_int():
+    v = SchemaValidator(core_schema.decimal_schema())
+    assert v.validate_json('12345678901234567890') == Decimal('12345678901234567890')
+    assert v.validate_json('-123456789012345678901234567890') == Decimal('-123456789012345678901234567890')
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('0.12345678901234567890123', '0.12345678901234567890123'),
+        ('1.000', '1.000'),
+        ('-0.0', '-0.0'),
+        ('1E+2', '1E+2'),
+        ('1.5e-30', '1.5E-30'),
+        ('1e400', '1E+400'),
+    ],
+)
+def test_json_exact(input_value, expected):
+    """JSON numbers are read as they're written, not through a float"""
+    v = SchemaValidator(core_schema.decimal_schema())
+    output = v.validate_json(input_value)
+    assert str(output) == expected
+    assert output.as_tuple() == Decimal(expected).as_tuple()
+
+
+def test_json_exact_constraints():
+    v = SchemaValidator(core_schema.decimal_schema(max_digits=20))
+    # 17 significant digits survive a float, the rest don't
+    with pytest.raises(ValidationError, match=re.escape('[type=decimal_max_digits,')):
+        v.validate_json('0.12345678901234567890123')
+
+
+@pytest.mark.parametrize(
+    'kwargs,input_value,expected',
+    [
+        ({'le': 1}, '1', Decimal(1)),
+        (
+            {'le': 1},
+            '1.000000000000000000000000000001',
+            Err('Input should be less than or equal to 1 [type=less_than_equal,'),
+        ),
+        ({'lt': '0.5'}, '0.4999', Decimal('0.4999')),
+        ({'lt': '0.5'}, '0.5', Err("Input should be less than 0.5 [type=less_than, input_value='0.5'")),
+        ({'ge': Decimal('-1.5')}, -1.5, Decimal('-1.5')),
+        (
+            {'ge': Decimal('-1.5')},
+            '-1.51',
+            Err('Input should be greater than or equal to -1.5 [type=greater_than_equal,'),
+        ),
+        ({'gt': 0}, 0, Err('Input should be greater than 0 [type=greater_than,')),
+        ({'gt': 0.1}, '0.1', Err('Input should be greater than 0.1 [type=greater_than,')),
+        ({'multiple_of': '0.1'}, '0.3', Decimal('0.3')),
+        ({'multiple_of': '0.1'}, '-0.70', Decimal('-0.70')),
+        ({'multiple_of': '0.1'}, '1e100', Decimal('1E+100')),
+        ({'multiple_of': '0.1'}, '0', Decimal(0)),
+        ({'multiple_of': '0.1'}, '0.35', Err('Input should be a multiple of 0.1 [type=multiple_of,')),
+        ({'multiple_of': '0.1'}, '1e-100', Err('Input should be a multiple of 0.1 [type=multiple_of,')),
+        ({'multiple_of': 3}, '1e999999999', Err('Input should be a multiple of 3 [type=multiple_of,')),
+        ({'multiple_of': 5}, '1e999999999', Decimal('1e999999999')),
+        ({'multiple_of': '1e-30'}, '1.000000000000000000000000000001', Decimal('1.000000000000000000000000000001')),
+    ],
+)
+def test_constraints(kwargs, input_value, expected):
+    v = SchemaValidator(core_schema.decimal_schema(**kwargs))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('123.45', Decimal('123.45')),
+        # trailing zeros after the decimal point aren't counted
+        ('1.500', Decimal('1.500')),
+        ('0.01', Decimal('0.01')),
+        ('0.000', Decimal(0)),
+        ('12.345', Err('Decimal input should have no more than 2 decimal places [type=decimal_max_places,')),
+        ('0.001', Err('Decimal input should have no more than 2 decimal places [type=decimal_max_places,')),
+        ('999.999', Err('Decimal input should have no more than 5 digits in total [type=decimal_max_digits,')),
+        (
+            '1234.5',
+            Err('Decimal input should have no more than 3 digits before the decimal point [type=decimal_whole_digits,'),
+        ),
+        ('1e3', Err('Decimal input should have no more than 3 digits before the decimal point')),
+    ],
+)
+def test_digits(input_value, expected):
+    v = SchemaValidator(core_schema.decimal_schema(max_digits=5, decimal_places=2))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_digits_context():
+    v = SchemaValidator(core_schema.decimal_schema(max_digits=3))
+    assert v.validate_python('1.23') == Decimal('1.23')
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('1.234')
+    assert exc_info.value.errors() == [
+        {
+            'type': 'decimal_max_digits',
+            'loc': (),
+            'msg': 'Decimal input should have no more than 3 digits in total',
+            'input': '1.234',
+            'ctx': {'max_digits': 3},
+        }
+    ]
+
+
+def test_allow_inf_nan():
+    v = SchemaValidator(core_schema.decimal_schema(allow_inf_nan=True, le=5, max_digits=2))
+    assert v.validate_python('NaN').is_nan()
+    assert v.validate_python(float('-inf')) == Decimal('-Infinity')
+    with pytest.raises(ValidationError, match=re.escape('Input should be less than or equal to 5')):
+        v.validate_python('inf')
+    # signalling NaNs can't be compared, so they're always rejected
+    with pytest.raises(ValidationError, match=re.escape('Input should be a finite number [type=finite_number,')):
+        v.validate_python('sNaN')
+
+
+def test_allow_inf_nan_config():
+    v = SchemaValidator(core_schema.decimal_schema(), {'allow_inf_nan': True})
+    assert v.validate_python('Infinity') == Decimal('Infinity')
+    v = SchemaValidator(core_schema.decimal_schema(allow_inf_nan=False), {'allow_inf_nan': True})
+    with pytest.raises(ValidationError, match=re.escape('[type=finite_number,')):
+        v.validate_python('Infinity')
+
+
+@pytest.mark.parametrize('multiple_of', [0, '-0.1', 'NaN'])
+def test_invalid_multiple_of(multiple_of):
+    with pytest.raises(SchemaError):
+        SchemaValidator(core_schema.decimal_schema(multiple_of=multiple_of))
+
+
+def test_serialization():
+    v = SchemaValidator(core_schema.decimal_schema())
+    s = SchemaSerializer(core_schema.decimal_schema())
+    d = v.validate_python('1.10')
+    assert s.to_python(d) is d
+    assert s.to_json(d) == b'"1.10"'
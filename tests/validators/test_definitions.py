@@ -140,3 +140,45 @@ def test_use_after():
         )
     )
     assert v.validate_python(['1', '2']) == (1, 2)
+
+
+def test_definitions_summary():
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.typed_dict_schema(
+                {
+                    'a': core_schema.typed_dict_field(core_schema.definition_reference_schema('int-def')),
+                    'b': core_schema.typed_dict_field(core_schema.definition_reference_schema('int-def')),
+                    'branch': core_schema.typed_dict_field(core_schema.definition_reference_schema('branch-def')),
+                }
+            ),
+            [
+                core_schema.int_schema(ref='int-def'),
+                core_schema.str_schema(ref='unused-def'),
+                core_schema.typed_dict_schema(
+                    {
+                        'sub_branch': core_schema.typed_dict_field(
+                            core_schema.nullable_schema(core_schema.definition_reference_schema('branch-def'))
+                        )
+                    },
+                    ref='branch-def',
+                ),
+            ],
+        )
+    )
+    assert v.definitions_summary() == [
+        {'ref': 'int-def', 'kind': 'reusable', 'slot_id': None, 'name': 'int', 'references': 2},
+        {'ref': 'unused-def', 'kind': 'unused', 'slot_id': None, 'name': 'str', 'references': 0},
+        {'ref': 'branch-def', 'kind': 'slot', 'slot_id': 0, 'name': 'typed-dict', 'references': 2},
+    ]
+    # introspection doesn't affect validation
+    assert v.validate_python({'a': 1, 'b': '2', 'branch': {'sub_branch': {'sub_branch': None}}}) == {
+        'a': 1,
+        'b': 2,
+        'branch': {'sub_branch': {'sub_branch': None}},
+    }
+
+
+def test_definitions_summary_no_refs():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    assert v.definitions_summary() == []
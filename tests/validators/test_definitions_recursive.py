@@ -1,3 +1,4 @@
+import dataclasses
 from typing import Optional
 
 import pytest
@@ -377,6 +378,7 @@ def test_recursion_branch():
             'type': 'recursion_loop',
             'loc': ('branch',),
             'msg': 'Recursion error - cyclic reference detected',
+            'ctx': {'ref': 'Branch'},
             'input': {'name': 'recursive', 'branch': IsPartialDict(name='recursive')},
         }
     ]
@@ -390,6 +392,7 @@ def test_recursion_branch():
             'type': 'recursion_loop',
             'loc': ('branch',),
             'msg': 'Recursion error - cyclic reference detected',
+            'ctx': {'ref': 'Branch'},
             'input': HasAttributes(name='root', branch=AnyThing()),
         }
     ]
@@ -413,6 +416,7 @@ def test_definition_list():
             'type': 'recursion_loop',
             'loc': (0,),
             'msg': 'Recursion error - cyclic reference detected',
+            'ctx': {'ref': 'the-list'},
             'input': [IsList(length=1)],
         }
     ]
@@ -489,12 +493,14 @@ def test_multiple_tuple_recursion(multiple_tuple_schema: SchemaValidator):
             'type': 'recursion_loop',
             'loc': ('f1', 1),
             'msg': 'Recursion error - cyclic reference detected',
+            'ctx': {'ref': 't'},
             'input': [1, IsList(length=2)],
         },
         {
             'type': 'recursion_loop',
             'loc': ('f2', 1),
             'msg': 'Recursion error - cyclic reference detected',
+            'ctx': {'ref': 't'},
             'input': [1, IsList(length=2)],
         },
     ]
@@ -511,12 +517,14 @@ def test_multiple_tuple_recursion_once(multiple_tuple_schema: SchemaValidator):
             'type': 'recursion_loop',
             'loc': ('f1', 1),
             'msg': 'Recursion error - cyclic reference detected',
+            'ctx': {'ref': 't'},
             'input': [1, IsList(length=2)],
         },
         {
             'type': 'recursion_loop',
             'loc': ('f2', 1),
             'msg': 'Recursion error - cyclic reference detected',
+            'ctx': {'ref': 't'},
             'input': [1, IsList(length=2)],
         },
     ]
@@ -551,6 +559,7 @@ def test_definition_wrap():
             'type': 'recursion_loop',
             'loc': (1,),
             'msg': 'Recursion error - cyclic reference detected',
+            'ctx': {'ref': 'wrapper'},
             'input': IsList(positions={0: 1}, length=2),
         }
     ]
@@ -644,6 +653,7 @@ def test_union_cycle(strict: bool):
             'type': 'recursion_loop',
             'loc': ('typed-dict', 'foobar', 0),
             'msg': 'Recursion error - cyclic reference detected',
+            'ctx': {'ref': 'root-schema'},
             'input': {'foobar': [{'foobar': IsList(length=1)}]},
         }
     ]
@@ -678,6 +688,7 @@ def test_function_name():
             'type': 'recursion_loop',
             'loc': ('function-after[f(), ...]',),
             'msg': 'Recursion error - cyclic reference detected',
+            'ctx': {'ref': 'root-schema'},
             'input': 'input value',
         },
         {
@@ -719,6 +730,7 @@ def test_function_change_id(strict: bool):
             'type': 'recursion_loop',
             'loc': IsTuple(length=(1, 255)),
             'msg': 'Recursion error - cyclic reference detected',
+            'ctx': {'ref': 'root-schema'},
             'input': IsStr(regex=r'f-\d+'),
         }
     ]
@@ -830,3 +842,180 @@ def test_model_td_recursive():
     assert f.x == 2
     assert f.y is None
     assert f.__pydantic_fields_set__ == {'x'}
+
+
+@pytest.fixture(scope='module')
+def mutual_recursion_schema():
+    """
+    like:
+    class A:
+        b: Optional['B']
+    class B:
+        a: Optional[A]
+        b: Optional['B']
+    """
+    a_ref = core_schema.nullable_schema(core_schema.definition_reference_schema('A'))
+    b_ref = core_schema.nullable_schema(core_schema.definition_reference_schema('B'))
+    b_schema = core_schema.typed_dict_schema(
+        {'a': core_schema.typed_dict_field(a_ref), 'b': core_schema.typed_dict_field(b_ref)}, ref='B'
+    )
+    b_field = core_schema.typed_dict_field(core_schema.nullable_schema(b_schema))
+    return core_schema.typed_dict_schema({'b': b_field}, ref='A')
+
+
+def test_mutual_recursion_ref(mutual_recursion_schema):
+    v = SchemaValidator(mutual_recursion_schema)
+
+    a = {}
+    a['b'] = {'a': a, 'b': None}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(a)
+    assert exc_info.value.errors() == [
+        {
+            'type': 'recursion_loop',
+            'loc': ('b', 'a'),
+            'msg': 'Recursion error - cyclic reference detected',
+            'input': {'b': {'a': AnyThing(), 'b': None}},
+            'ctx': {'ref': 'A'},
+        }
+    ]
+
+    b = {'a': None}
+    b['b'] = b
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'b': b})
+    assert exc_info.value.errors() == [
+        {
+            'type': 'recursion_loop',
+            'loc': ('b', 'b'),
+            'msg': 'Recursion error - cyclic reference detected',
+            'input': {'a': None, 'b': AnyThing()},
+            'ctx': {'ref': 'B'},
+        }
+    ]
+
+
+def test_recursion_loc_truncated(mutual_recursion_schema):
+    v = SchemaValidator(mutual_recursion_schema)
+
+    # no cycle here, but the input is deep enough to trip the backup depth guard
+    data = None
+    for _ in range(300):
+        data = {'a': None, 'b': data}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'b': data})
+    errors = exc_info.value.errors()
+    assert len(errors) == 1
+    assert errors[0]['type'] == 'recursion_loop'
+    assert errors[0]['ctx'] == {'ref': 'B'}
+    assert errors[0]['loc'] == ('b',) * 20 + ('...',)
+
+
+@pytest.fixture(name='revalidate_branch_schema')
+def revalidate_branch_schema_fixture():
+    class Branch:
+        __slots__ = '__dict__', '__pydantic_fields_set__'
+
+    return core_schema.definitions_schema(
+        core_schema.definition_reference_schema('Branch'),
+        [
+            core_schema.model_schema(
+                Branch,
+                core_schema.typed_dict_schema(
+                    {
+                        'width': core_schema.typed_dict_field(core_schema.int_schema()),
+                        'branch': core_schema.typed_dict_field(
+                            core_schema.with_default_schema(
+                                core_schema.nullable_schema(core_schema.definition_reference_schema('Branch')),
+                                default=None,
+                            )
+                        ),
+                    },
+                    return_fields_set=True,
+                ),
+                revalidate_instances='always',
+                ref='Branch',
+            )
+        ],
+    )
+
+
+def test_model_revalidate(revalidate_branch_schema):
+    v = SchemaValidator(revalidate_branch_schema)
+    m1 = v.validate_python({'width': 1, 'branch': {'width': 2}})
+    m1.branch.width = '3'
+
+    m2 = v.validate_python(m1)
+    assert m2 is not m1
+    assert m2.branch is not m1.branch
+    assert m2.branch.width == 3
+    assert m2.__pydantic_fields_set__ == {'width', 'branch'}
+    assert m2.branch.__pydantic_fields_set__ == {'width'}
+
+
+def test_model_revalidate_cycle(revalidate_branch_schema):
+    v = SchemaValidator(revalidate_branch_schema)
+    m = v.validate_python({'width': 1, 'branch': {'width': 2}})
+    m.branch.branch = m
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(m)
+    assert exc_info.value.errors() == [
+        {
+            'type': 'recursion_loop',
+            'loc': ('branch', 'branch'),
+            'msg': 'Recursion error - cyclic reference detected',
+            'input': m,
+            'ctx': {'ref': 'Branch'},
+        }
+    ]
+
+
+def test_dataclass_revalidate_cycle():
+    @dataclasses.dataclass
+    class Branch:
+        width: int
+        branch: Optional['Branch'] = None
+
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.definition_reference_schema('Branch'),
+            [
+                core_schema.dataclass_schema(
+                    Branch,
+                    core_schema.dataclass_args_schema(
+                        'Branch',
+                        [
+                            core_schema.dataclass_field('width', core_schema.int_schema()),
+                            core_schema.dataclass_field(
+                                'branch',
+                                core_schema.with_default_schema(
+                                    core_schema.nullable_schema(core_schema.definition_reference_schema('Branch')),
+                                    default=None,
+                                ),
+                            ),
+                        ],
+                    ),
+                    revalidate_instances='always',
+                    ref='Branch',
+                )
+            ],
+        )
+    )
+    b = Branch(1, Branch('2'))
+    b2 = v.validate_python(b)
+    assert b2 == Branch(1, Branch(2))
+    assert b2.branch is not b.branch
+
+    b.branch.branch = b
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(b)
+    assert exc_info.value.errors() == [
+        {
+            'type': 'recursion_loop',
+            'loc': ('branch', 'branch'),
+            'msg': 'Recursion error - cyclic reference detected',
+            'input': b,
+            'ctx': {'ref': 'Branch'},
+        }
+    ]
@@ -1,12 +1,13 @@
 import re
-from collections import OrderedDict
+from collections import OrderedDict, defaultdict
+from datetime import datetime, timedelta, timezone
 from collections.abc import Mapping
 from typing import Any, Dict
 
 import pytest
 from dirty_equals import HasRepr, IsStr
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson
 
@@ -225,3 +226,224 @@ def test_dict_length_constraints(kwargs: Dict[str, Any], input_value, expected):
             v.validate_python(input_value)
     else:
         assert v.validate_python(input_value) == expected
+
+
+def test_unhashable_keys():
+    v = SchemaValidator(
+        {
+            'type': 'dict',
+            'keys_schema': {'type': 'list', 'items_schema': {'type': 'int'}},
+            'values_schema': {'type': 'int'},
+        }
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({(1, 2): 1, (3,): 'x'})
+    assert exc_info.value.errors() == [
+        {'type': 'unhashable', 'loc': ('(1, 2)', '[key]'), 'msg': 'Input should be hashable', 'input': [1, 2]},
+        {
+            'type': 'int_parsing',
+            'loc': ('(3,)',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+    ]
+
+
+def test_ordered_dict_output():
+    v = SchemaValidator(
+        {
+            'type': 'dict',
+            'keys_schema': {'type': 'str'},
+            'values_schema': {'type': 'int'},
+            'output_type': 'ordered_dict',
+        }
+    )
+    output = v.validate_python({'z': '1', 'a': 2, 'm': 3})
+    assert type(output) is OrderedDict
+    assert list(output.items()) == [('z', 1), ('a', 2), ('m', 3)]
+
+    output = v.validate_json('{"z": "1", "a": 2, "m": 3}')
+    assert type(output) is OrderedDict
+    assert list(output.items()) == [('z', 1), ('a', 2), ('m', 3)]
+
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python({'z': 'x'})
+
+
+def test_ordered_dict_output_invalid():
+    msg = "dict.output_type\n  Input should be 'dict', 'ordered_dict' or 'defaultdict'"
+    with pytest.raises(SchemaError, match=msg):
+        SchemaValidator({'type': 'dict', 'output_type': 'list'})
+
+
+def test_defaultdict_output(py_and_json: PyAndJson):
+    v = py_and_json(
+        core_schema.dict_schema(
+            core_schema.str_schema(),
+            core_schema.list_schema(core_schema.int_schema()),
+            output_type='defaultdict',
+            default_factory=list,
+        )
+    )
+    output = v.validate_test({'a': [1, '2']})
+    assert type(output) is defaultdict
+    assert output == {'a': [1, 2]}
+    assert output.default_factory is list
+    assert output['b'] == []
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'a': ['x']})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', ('a', 0))]
+
+
+@pytest.mark.parametrize(
+    'default_factory,input_value,expected_factory',
+    [
+        (None, defaultdict(int, a=1), int),
+        (None, {'a': 1}, None),
+        (list, defaultdict(int, a=1), list),
+    ],
+)
+def test_defaultdict_default_factory(default_factory, input_value, expected_factory):
+    v = SchemaValidator(core_schema.dict_schema(output_type='defaultdict', default_factory=default_factory))
+    output = v.validate_python(input_value)
+    assert type(output) is defaultdict
+    assert output == {'a': 1}
+    assert output.default_factory is expected_factory
+    assert output is not input_value
+
+
+def test_defaultdict_length():
+    v = SchemaValidator(
+        core_schema.dict_schema(output_type='defaultdict', default_factory=int, min_length=1, max_length=2)
+    )
+    assert v.validate_python(defaultdict(int, a=1)) == {'a': 1}
+    with pytest.raises(ValidationError, match='type=too_short,'):
+        v.validate_python(defaultdict(int))
+    with pytest.raises(ValidationError, match='type=too_long,'):
+        v.validate_python({'a': 1, 'b': 2, 'c': 3})
+
+
+@pytest.mark.parametrize('defaultdict_strict', [None, False, True])
+def test_defaultdict_strict(defaultdict_strict):
+    v = SchemaValidator(
+        core_schema.dict_schema(
+            output_type='defaultdict', default_factory=int, strict=True, defaultdict_strict=defaultdict_strict
+        )
+    )
+    assert v.validate_python(defaultdict(int, a=1)) == {'a': 1}
+    # JSON objects are accepted either way
+    assert type(v.validate_json('{"a": 1}')) is defaultdict
+    # in lax mode any dict is accepted
+    lax_v = SchemaValidator(
+        core_schema.dict_schema(output_type='defaultdict', default_factory=int, defaultdict_strict=defaultdict_strict)
+    )
+    assert lax_v.validate_python({'a': 1}) == {'a': 1}
+    if defaultdict_strict:
+        # `strict=False` for the call doesn't relax the schema's `strict`
+        for strict in (None, False):
+            with pytest.raises(ValidationError) as exc_info:
+                v.validate_python({'a': 1}, strict=strict)
+            assert exc_info.value.errors() == [
+                {
+                    'type': 'default_dict_type',
+                    'loc': (),
+                    'msg': 'Input should be a valid defaultdict',
+                    'input': {'a': 1},
+                }
+            ]
+    else:
+        assert type(v.validate_python({'a': 1})) is defaultdict
+
+
+def test_default_factory_without_defaultdict():
+    with pytest.raises(SchemaError, match="`default_factory` can only be used with `output_type='defaultdict'`"):
+        SchemaValidator(core_schema.dict_schema(default_factory=list))
+
+
+def test_required_keys(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'dict', 'values_schema': {'type': 'int'}, 'required_keys': ['a', 'b']})
+    assert v.validate_test({'a': 1, 'b': '2'}) == {'a': 1, 'b': 2}
+    assert v.validate_test({'a': 1, 'b': 2, 'c': '3'}) == {'a': 1, 'b': 2, 'c': 3}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'c': 'x'})
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': ('c',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+        {'type': 'missing', 'loc': ('a',), 'msg': 'Field required', 'input': {'c': 'x'}},
+        {'type': 'missing', 'loc': ('b',), 'msg': 'Field required', 'input': {'c': 'x'}},
+    ]
+
+
+def test_required_keys_invalid_value():
+    v = SchemaValidator({'type': 'dict', 'values_schema': {'type': 'int'}, 'required_keys': ['a']})
+    # the key is present, so only the value error is reported
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x'})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', ('a',))]
+
+
+def test_required_keys_non_str_key():
+    v = SchemaValidator({'type': 'dict', 'required_keys': ['1']})
+    assert v.validate_python({'1': 1}) == {'1': 1}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({1: 1})
+    assert exc_info.value.errors() == [{'type': 'missing', 'loc': ('1',), 'msg': 'Field required', 'input': {1: 1}}]
+
+
+def test_duplicate_keys_after_validation():
+    v = SchemaValidator({'type': 'dict', 'keys_schema': {'type': 'int'}, 'values_schema': {'type': 'str'}})
+    assert v.validate_python({1: 'a', '2': 'b'}) == {1: 'a', 2: 'b'}
+    # distinct input keys aren't silently merged
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({1: 'a', '1': 'b'})
+    assert exc_info.value.errors() == [
+        {
+            'type': 'dict_duplicate_key',
+            'loc': ('1', '[key]'),
+            'msg': 'Dictionary keys should be unique after validation',
+            'input': '1',
+        }
+    ]
+
+
+def test_duplicate_datetime_keys():
+    v = SchemaValidator({'type': 'dict', 'keys_schema': {'type': 'datetime'}})
+    assert v.validate_json('{"2022-01-01T00:00:00Z": 1, "2022-01-01T00:00:00+01:00": 2}') == {
+        datetime(2022, 1, 1, tzinfo=timezone.utc): 1,
+        datetime(2022, 1, 1, tzinfo=timezone(timedelta(hours=1))): 2,
+    }
+    # the same instant in different timezones gives equal datetimes
+    with pytest.raises(ValidationError, match=r'\[key\]\n  Dictionary keys should be unique after validation'):
+        v.validate_json('{"2022-01-01T00:00:00Z": 1, "2022-01-01T01:00:00+01:00": 2}')
+
+
+def test_mapping_read_until_too_long():
+    class EndlessMapping(Mapping):
+        def __init__(self):
+            self.read = 0
+
+        def __getitem__(self, key):
+            return key * 2
+
+        def __iter__(self):
+            i = 0
+            while True:
+                self.read += 1
+                yield i
+                i += 1
+
+        def __len__(self):
+            raise TypeError('unknown length')
+
+    v = SchemaValidator({'type': 'dict', 'keys_schema': {'type': 'int'}, 'max_length': 3})
+    mapping = EndlessMapping()
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(mapping)
+    assert exc_info.value.errors()[0]['ctx'] == {'field_type': 'Dictionary', 'max_length': 3, 'actual_length': 4}
+    assert mapping.read == 4
@@ -0,0 +1,251 @@
+import re
+from enum import Enum, IntEnum
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaSerializer, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
+
+
+class Color(Enum):
+    RED = 'red'
+    GREEN = 'green'
+    ONE = 1
+
+
+class Number(IntEnum):
+    ONE = 1
+    TWO = 2
+
+
+class Language(str, Enum):
+    PYTHON = 'python'
+    RUST = 'rust'
+
+
+class OtherColor(Enum):
+    RED = 'red'
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (Color.RED, Color.RED),
+        ('green', Color.GREEN),
+        (1, Color.ONE),
+        # values are compared by equality, as `Color(True)` does
+        (True, Color.ONE),
+        ('1', Err("Input should be 'red', 'green' or 1 [type=enum, input_value='1', input_type=str]")),
+        (OtherColor.RED, Err("Input should be 'red', 'green' or 1 [type=enum,")),
+        ('blue', Err("Input should be 'red', 'green' or 1 [type=enum,")),
+        ([1], Err("Input should be 'red', 'green' or 1 [type=enum,")),
+    ],
+)
+def test_plain_enum(input_value, expected):
+    v = SchemaValidator(core_schema.enum_schema(Color))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) is expected
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (Number.ONE, Number.ONE),
+        (2, Number.TWO),
+        ('2', Number.TWO),
+        (1.0, Number.ONE),
+        (3, Err('Input should be 1 or 2 [type=enum, input_value=3, input_type=int]')),
+        ('x', Err('Input should be 1 or 2 [type=enum,')),
+    ],
+)
+def test_int_enum(input_value, expected):
+    v = SchemaValidator(core_schema.enum_schema(Number))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) is expected
+
+
+def test_str_enum():
+    v = SchemaValidator(core_schema.enum_schema(Language))
+    assert v.validate_python('rust') is Language.RUST
+    assert v.validate_python(b'python') is Language.PYTHON
+    assert v.validate_python(Language.PYTHON) is Language.PYTHON
+    with pytest.raises(ValidationError, match=re.escape("Input should be 'python' or 'rust' [type=enum,")):
+        v.validate_python('Python')
+
+
+@pytest.mark.parametrize('input_value', ['red', 1, OtherColor.RED])
+def test_strict(input_value):
+    v = SchemaValidator(core_schema.enum_schema(Color, strict=True))
+    assert v.validate_python(Color.RED) is Color.RED
+    with pytest.raises(ValidationError, match=re.escape("Input should be 'red', 'green' or 1 [type=enum,")):
+        v.validate_python(input_value)
+
+
+def test_json(py_and_json: PyAndJson):
+    v = py_and_json(core_schema.enum_schema(Color))
+    assert v.validate_test('red') is Color.RED
+    assert v.validate_test(1) is Color.ONE
+    with pytest.raises(ValidationError, match=re.escape('[type=enum,')):
+        v.validate_test('blue')
+
+
+def test_json_strict():
+    # JSON has no enum type, so values are accepted in strict mode
+    v = SchemaValidator(core_schema.enum_schema(Number, strict=True))
+    assert v.validate_json('2') is Number.TWO
+    with pytest.raises(ValidationError, match=re.escape('Input should be 1 or 2 [type=enum,')):
+        v.validate_json('"2"')
+    v = SchemaValidator(core_schema.enum_schema(Language, strict=True))
+    assert v.validate_json('"rust"') is Language.RUST
+
+
+def test_members():
+    v = SchemaValidator(core_schema.enum_schema(Color, [Color.RED, Color.ONE]))
+    assert v.validate_python('red') is Color.RED
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(Color.GREEN)
+    assert exc_info.value.errors() == [
+        {
+            'type': 'enum',
+            'loc': (),
+            'msg': "Input should be 'red' or 1",
+            'input': Color.GREEN,
+            'ctx': {'expected': "'red' or 1"},
+        }
+    ]
+
+
+def test_missing():
+    def case_insensitive(value):
+        if isinstance(value, str):
+            for member in Language:
+                if member.value == value.lower():
+                    return member
+        return None
+
+    v = SchemaValidator(core_schema.enum_schema(Language, missing=case_insensitive))
+    assert v.validate_python('Python') is Language.PYTHON
+    assert v.validate_json('"RUST"') is Language.RUST
+    with pytest.raises(ValidationError, match=re.escape("Input should be 'python' or 'rust' [type=enum,")):
+        v.validate_python('go')
+
+
+def test_missing_not_called_strict():
+    calls = []
+
+    def missing(value):
+        calls.append(value)
+
+    v = SchemaValidator(core_schema.enum_schema(Color, missing=missing, strict=True))
+    with pytest.raises(ValidationError):
+        v.validate_python('blue')
+    assert calls == []
+
+
+@pytest.mark.parametrize('result', ['python', Color.RED])
+def test_missing_invalid_result(result):
+    v = SchemaValidator(core_schema.enum_schema(Language, missing=lambda value: result))
+    msg = f'`missing` should return a member of `Language` or None, got {result!r}'
+    with pytest.raises(TypeError, match=re.escape(msg)):
+        v.validate_python('go')
+
+
+def test_invalid_members():
+    with pytest.raises(SchemaError, match='`members` should all be members of `Color`'):
+        SchemaValidator(core_schema.enum_schema(Color, [Color.RED, OtherColor.RED]))
+    with pytest.raises(SchemaError, match='`members` should have length > 0'):
+        SchemaValidator(core_schema.enum_schema(Color, []))
+
+
+def test_unhashable_values():
+    class Shape(Enum):
+        POINT = [0]
+        LINE = [0, 1]
+        SQUARE = 4
+
+    v = SchemaValidator(core_schema.enum_schema(Shape))
+    assert v.validate_python(Shape.LINE) is Shape.LINE
+    assert v.validate_python([0, 1]) is Shape.LINE
+    assert v.validate_python(4) is Shape.SQUARE
+    assert v.validate_json('[0]') is Shape.POINT
+    with pytest.raises(ValidationError, match=re.escape('Input should be [0], [0, 1] or 4 [type=enum,')):
+        v.validate_python([1])
+
+
+def test_serialization():
+    s = SchemaSerializer(core_schema.enum_schema(Color))
+    assert s.to_python(Color.RED) is Color.RED
+    assert s.to_python(Color.RED, mode='json') == 'red'
+    assert s.to_json(Color.ONE) == b'1'
+
+
+class Shade(Enum):
+    RED = 'GREEN'
+    GREEN = 'green'
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('red', Color.RED),
+        ('RED', Color.RED),
+        ('ONE', Color.ONE),
+        (Color.GREEN, Color.GREEN),
+        ('Red', Err("Input should be 'red', 'green', 1, 'RED', 'GREEN' or 'ONE' [type=enum, input_value='Red'")),
+        (b'RED', Err("Input should be 'red', 'green', 1, 'RED', 'GREEN' or 'ONE' [type=enum,")),
+    ],
+)
+def test_by_name(input_value, expected):
+    v = SchemaValidator(core_schema.enum_schema(Color, by_name=True))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) is expected
+
+
+def test_by_name_value_first():
+    v = SchemaValidator(core_schema.enum_schema(Shade, by_name=True))
+    # 'GREEN' is both the value of `RED` and the name of `GREEN`, values are tried first
+    assert v.validate_python('GREEN') is Shade.RED
+    assert v.validate_python('RED') is Shade.RED
+    assert v.validate_python('green') is Shade.GREEN
+
+
+def test_by_name_case_insensitive():
+    v = SchemaValidator(core_schema.enum_schema(Number, by_name=True, by_name_case_insensitive=True))
+    assert v.validate_python('two') is Number.TWO
+    assert v.validate_json('"One"') is Number.ONE
+    assert v.validate_python('2') is Number.TWO
+    with pytest.raises(ValidationError, match=re.escape("Input should be 1, 2, 'ONE' or 'TWO' [type=enum,")):
+        v.validate_python('three')
+
+
+def test_by_name_members_strict():
+    v = SchemaValidator(core_schema.enum_schema(Color, [Color.RED], by_name=True, strict=True))
+    # only the names of `members` are accepted, and like values only from JSON in strict mode
+    assert v.validate_json('"RED"') is Color.RED
+    with pytest.raises(ValidationError, match=re.escape("Input should be 'red' or 'RED' [type=enum,")):
+        v.validate_json('"GREEN"')
+    with pytest.raises(ValidationError, match=re.escape("Input should be 'red' or 'RED' [type=enum,")):
+        v.validate_python('RED')
+
+
+def test_by_name_invalid():
+    with pytest.raises(SchemaError, match='`by_name_case_insensitive` requires `by_name`'):
+        SchemaValidator(core_schema.enum_schema(Color, by_name_case_insensitive=True))
+
+    class Clash(Enum):
+        ONE = 1
+        one = 2
+
+    with pytest.raises(SchemaError, match="`by_name_case_insensitive` can't tell `ONE` and `one` apart"):
+        SchemaValidator(core_schema.enum_schema(Clash, by_name=True, by_name_case_insensitive=True))
+    assert SchemaValidator(core_schema.enum_schema(Clash, by_name=True)).validate_python('one') is Clash.one
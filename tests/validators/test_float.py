@@ -6,7 +6,7 @@ from typing import Any, Dict
 import pytest
 from dirty_equals import FunctionCheck, IsStr
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, plain_repr
 
@@ -177,12 +177,14 @@ def test_float_repr():
     v = SchemaValidator({'type': 'float'})
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:false,allow_inf_nan:true}),slots=[])'
+        == 'SchemaValidator(title="float",validator=Float(FloatValidator{'
+        'strict:false,coerce_hook:true,allow_inf_nan:true,number_format:None}),slots=[])'
     )
     v = SchemaValidator({'type': 'float', 'strict': True})
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:true,allow_inf_nan:true}),slots=[])'
+        == 'SchemaValidator(title="float",validator=StrictOverride(Float(FloatValidator{'
+        'strict:true,coerce_hook:true,allow_inf_nan:true,number_format:None})),slots=[])'
     )
     v = SchemaValidator({'type': 'float', 'multiple_of': 7})
     assert plain_repr(v).startswith('SchemaValidator(title="constrained-float",validator=ConstrainedFloat(')
@@ -323,3 +325,81 @@ def test_non_finite_constrained_float_values(input_value, allow_inf_nan, expecte
             v.validate_python(input_value)
     else:
         assert v.validate_python(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'mode,multiple_of,input_value,expected',
+    [
+        ('error', 0.5, 1.5, 1.5),
+        ('error', 0.5, 1.6, Err('Input should be a multiple of 0.5')),
+        ('round', 0.5, 1.6, 1.5),
+        ('round', 0.5, 1.8, 2.0),
+        ('round', 0.5, '-1.6', -1.5),
+        ('floor', 0.5, 1.9, 1.5),
+        ('floor', 0.5, -1.6, -2.0),
+        ('ceil', 0.5, 1.6, 2.0),
+        ('ceil', 0.5, -1.9, -1.5),
+        ('ceil', 0.5, 2.0, 2.0),
+        ('round', 5, 12.0, 10.0),
+        ('floor', -5, 14.0, 10.0),
+    ],
+)
+def test_float_multiple_of_mode(py_and_json: PyAndJson, mode, multiple_of, input_value, expected):
+    v = py_and_json({'type': 'float', 'multiple_of': multiple_of, 'multiple_of_mode': mode})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_float_multiple_of_mode_then_constraints():
+    v = SchemaValidator({'type': 'float', 'multiple_of': 0.5, 'multiple_of_mode': 'ceil', 'lt': 2})
+    assert v.validate_python(1.2) == 1.5
+    with pytest.raises(ValidationError, match='Input should be less than 2'):
+        v.validate_python(1.6)
+
+
+@pytest.mark.parametrize(
+    'number_format,input_value,expected',
+    [
+        ({}, '1,234.56', 1234.56),
+        ({}, '-1,234,567.5', -1234567.5),
+        ({}, '1234.5', 1234.5),
+        ({}, 1234.5, 1234.5),
+        ({'thousands_sep': '.', 'decimal_sep': ','}, '1.234,56', 1234.56),
+        ({'thousands_sep': '.', 'decimal_sep': ','}, '12,5', 12.5),
+        ({'thousands_sep': '.', 'decimal_sep': ','}, 12.5, 12.5),
+        ({'thousands_sep': ' ', 'decimal_sep': ','}, '1 000 000,25', 1000000.25),
+        ({}, '1,23.4', Err('[type=float_parsing')),
+        ({}, '1234,567.0', Err('[type=float_parsing')),
+        ({}, ',123', Err('[type=float_parsing')),
+        ({}, '1.234,5', Err('[type=float_parsing')),
+        ({'thousands_sep': '.', 'decimal_sep': ','}, '1,234.5', Err('[type=float_parsing')),
+        ({'thousands_sep': '.', 'decimal_sep': ','}, '1,2,3', Err('[type=float_parsing')),
+    ],
+)
+def test_number_format(py_and_json: PyAndJson, number_format, input_value, expected):
+    v = py_and_json({'type': 'float', 'number_format': number_format})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)) as exc_info:
+            v.validate_test(input_value)
+        assert exc_info.value.errors()[0]['input'] == input_value
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_number_format_constrained():
+    v = SchemaValidator(core_schema.float_schema(number_format={'thousands_sep': '.', 'decimal_sep': ','}, le=2000))
+    assert v.validate_python('1.999,5') == 1999.5
+    with pytest.raises(ValidationError, match='Input should be less than or equal to 2000'):
+        v.validate_python('2.000,5')
+    with pytest.raises(ValidationError, match=r'Input should be a valid number \[type=float_type'):
+        v.validate_python('1.999,5', strict=True)
+
+
+def test_number_format_invalid():
+    with pytest.raises(SchemaError, match='`number_format` thousands_sep and decimal_sep must differ, both are `.`'):
+        SchemaValidator(core_schema.float_schema(number_format={'thousands_sep': '.'}))
+    with pytest.raises(SchemaError, match='`number_format` separators cannot be empty'):
+        SchemaValidator(core_schema.float_schema(number_format={'decimal_sep': ''}))
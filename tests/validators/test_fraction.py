@@ -0,0 +1,207 @@
+import re
+from decimal import Decimal
+from fractions import Fraction
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (Fraction(3, 4), Fraction(3, 4)),
+        (3, Fraction(3)),
+        (10**100, Fraction(10**100)),
+        ('3/4', Fraction(3, 4)),
+        (' -3/4 ', Fraction(-3, 4)),
+        ('1.5', Fraction(3, 2)),
+        ('1e3', Fraction(1000)),
+        (b'1/3', Fraction(1, 3)),
+        (Decimal('0.1'), Fraction(1, 10)),
+        (Decimal('-1.25'), Fraction(-5, 4)),
+        (0.5, Fraction(1, 2)),
+        # floats are converted exactly
+        (0.1, Fraction(3602879701896397, 36028797018963968)),
+        ('1/0', Err('Fraction denominator should not be zero [type=fraction_zero_denominator, input_value=\'1/0\'')),
+        ('x', Err('Input should be a valid fraction, unable to parse string as a fraction [type=fraction_parsing,')),
+        ('1/2/3', Err('unable to parse string as a fraction [type=fraction_parsing,')),
+        ('inf', Err('unable to parse string as a fraction [type=fraction_parsing,')),
+        (float('inf'), Err('Input should be a finite number [type=finite_number,')),
+        (float('nan'), Err('Input should be a finite number [type=finite_number,')),
+        (Decimal('NaN'), Err('Input should be a finite number [type=finite_number,')),
+        (Decimal('-Infinity'), Err('Input should be a finite number [type=finite_number,')),
+        (True, Err('Input should be a valid fraction [type=fraction_type, input_value=True, input_type=bool]')),
+        (None, Err('Input should be a valid fraction [type=fraction_type,')),
+        ([1, 2], Err('Input should be a valid fraction [type=fraction_type,')),
+    ],
+)
+def test_fraction(input_value, expected):
+    v = SchemaValidator(core_schema.fraction_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert type(output) is Fraction
+
+
+def test_fraction_passthrough():
+    v = SchemaValidator(core_schema.fraction_schema(strict=True))
+    f = Fraction(1, 3)
+    assert v.validate_python(f) is f
+
+
+@pytest.mark.parametrize('input_value', [1, '1/3', 0.5, Decimal('0.5')])
+def test_strict(input_value):
+    v = SchemaValidator(core_schema.fraction_schema(strict=True))
+    with pytest.raises(ValidationError, match=re.escape('Input should be a valid fraction [type=fraction_type,')):
+        v.validate_python(input_value)
+
+
+def test_json(py_and_json: PyAndJson):
+    v = py_and_json(core_schema.fraction_schema())
+    assert v.validate_test('3/4') == Fraction(3, 4)
+    assert v.validate_test(2) == Fraction(2)
+    assert v.validate_test(1.5) == Fraction(3, 2)
+    with pytest.raises(ValidationError, match=re.escape('Input should be a valid fraction [type=fraction_type,')):
+        v.validate_test([3, 4])
+
+
+def test_json_strict():
+    v = SchemaValidator(core_schema.fraction_schema(strict=True))
+    # JSON has no fraction type, so strings are accepted in strict mode
+    assert v.validate_json('"3/4"') == Fraction(3, 4)
+    with pytest.raises(ValidationError, match=re.escape('Input should be a valid fraction [type=fraction_type,')):
+        v.validate_json('1')
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (0.1, Fraction(1, 10)),
+        (1.5, Fraction(3, 2)),
+        (-2.0, Fraction(-2)),
+        (1e20, Fraction(10**20)),
+        (1e-7, Fraction(1, 10**7)),
+    ],
+)
+def test_float_conversion_repr(input_value, expected):
+    v = SchemaValidator(core_schema.fraction_schema(float_conversion='repr'))
+    assert v.validate_python(input_value) == expected
+    assert v.validate_json(str(input_value)) == expected
+
+
+def test_float_conversion_repr_bounds():
+    # with the float's exact value, 0.1 is just greater than 1/10
+    v = SchemaValidator(core_schema.fraction_schema(le='1/10'))
+    with pytest.raises(ValidationError, match=re.escape('[type=less_than_equal,')):
+        v.validate_python(0.1)
+    v = SchemaValidator(core_schema.fraction_schema(le='1/10', float_conversion='repr'))
+    assert v.validate_python(0.1) == Fraction(1, 10)
+
+
+def test_limit_denominator():
+    v = SchemaValidator(core_schema.fraction_schema(limit_denominator=1000))
+    assert v.validate_python(0.1) == Fraction(1, 10)
+    assert v.validate_python(3.14159265) == Fraction(355, 113)
+    # only floats are approximated
+    assert v.validate_python('1/1001') == Fraction(1, 1001)
+
+    with pytest.raises(SchemaError, match='`limit_denominator` should be greater than 0'):
+        SchemaValidator(core_schema.fraction_schema(limit_denominator=0))
+
+    v = SchemaValidator(core_schema.fraction_schema(float_conversion='repr', limit_denominator=10))
+    assert v.validate_python(0.25) == Fraction(1, 4)
+    assert v.validate_python(0.123) == Fraction(1, 8)
+
+
+@pytest.mark.parametrize(
+    'input_value',
+    ['1' * 4301, '1/' + '1' * 4301, '1e4301', '1e-4301', '1e999999999999999999999', Decimal('1e4301')],
+    ids=['digits', 'denominator', 'exponent', 'negative-exponent', 'huge-exponent', 'decimal'],
+)
+def test_too_many_digits(input_value):
+    v = SchemaValidator(core_schema.fraction_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert exc_info.value.errors() == [
+        {
+            'type': 'fraction_too_many_digits',
+            'loc': (),
+            'msg': 'Fraction should have at most 4300 digits',
+            'input': input_value,
+            'ctx': {'max_digits': 4300},
+        }
+    ]
+
+
+def test_max_digits():
+    v = SchemaValidator(core_schema.fraction_schema())
+    assert v.validate_python('1' * 4300) == Fraction(int('1' * 4300))
+    assert v.validate_python('1e4300') == Fraction(10**4300)
+    assert v.validate_python(Decimal('1e-4300')) == Fraction(1, 10**4300)
+
+
+@pytest.mark.parametrize(
+    'kwargs,input_value,expected',
+    [
+        ({'gt': '1/3'}, '1/2', Fraction(1, 2)),
+        ({'gt': '1/3'}, '1/3', Err('Input should be greater than 1/3 [type=greater_than,')),
+        # 0.3333333333333333 is just less than 1/3
+        ({'ge': '1/3'}, 0.3333333333333333, Err('greater than or equal to 1/3 [type=greater_than_equal,')),
+        ({'ge': '1/3'}, '1/3', Fraction(1, 3)),
+        ({'lt': 1}, '999999999999/1000000000000', Fraction(999999999999, 1000000000000)),
+        ({'lt': 1}, 1, Err('Input should be less than 1 [type=less_than,')),
+        ({'le': Fraction(1, 10)}, '0.1', Fraction(1, 10)),
+        # floats are compared exactly, 0.1 is just greater than 1/10
+        ({'le': Fraction(1, 10)}, 0.1, Err('Input should be less than or equal to 1/10 [type=less_than_equal,')),
+        ({'le': 0.5}, '1/2', Fraction(1, 2)),
+        ({'ge': Decimal('-0.25')}, '-1/4', Fraction(-1, 4)),
+    ],
+)
+def test_constraints(kwargs, input_value, expected):
+    v = SchemaValidator(core_schema.fraction_schema(**kwargs))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_constraint_context():
+    v = SchemaValidator(core_schema.fraction_schema(gt=2, lt='5/2'))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(2)
+    assert exc_info.value.errors()[0]['ctx'] == {'gt': 2}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(3)
+    assert exc_info.value.errors()[0]['ctx'] == {'lt': '5/2'}
+
+
+def test_invalid_bound():
+    with pytest.raises(SchemaError, match='Invalid Schema:\nfraction.ge\n  Input should be a valid fraction'):
+        SchemaValidator(core_schema.fraction_schema(ge='x'))
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('1.234,56', Fraction(123456, 100)),
+        ('-1.000.000', Fraction(-1000000)),
+        (1.5, Fraction(3, 2)),
+        ('1,234.5', Err('Input should be a valid fraction, unable to parse string')),
+        ('12.34,5', Err('Input should be a valid fraction, unable to parse string')),
+    ],
+)
+def test_number_format(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.fraction_schema(number_format={'thousands_sep': '.', 'decimal_sep': ','}))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)) as exc_info:
+            v.validate_test(input_value)
+        assert exc_info.value.errors()[0]['input'] == input_value
+    else:
+        assert v.validate_test(input_value) == expected
@@ -1,5 +1,6 @@
 import re
 from collections import deque
+from itertools import cycle
 from typing import Any, Dict
 
 import pytest
@@ -153,10 +154,17 @@ def generate_repeats():
         # length check after set creation
         ({'max_length': 3}, [1, 1, 2, 2, 3, 3], {1, 2, 3}),
         ({'max_length': 3}, generate_repeats(), {1, 2, 3}),
-        # because of default max_length * 10
+        # the length is checked as items are added, so a generator of distinct items stops at max_length
         (
             {'max_length': 3},
             infinite_generator(),
+            Err('Frozenset should have at most 3 items after validation, not 4 [type=too_long,'),
+        ),
+        # duplicates don't count towards max_length, but every item read counts towards the default
+        # generator_max_length of max_length * 10
+        (
+            {'max_length': 3},
+            cycle([1, 2, 3]),
             Err('Frozenset should have at most 30 items after validation, not 31 [type=too_long,'),
         ),
         (
@@ -247,10 +255,10 @@ def test_repr():
     assert plain_repr(v) == (
         'SchemaValidator('
         'title="frozenset[any]",'
-        'validator=FrozenSet(FrozenSetValidator{'
-        'strict:true,item_validator:None,min_length:Some(42),max_length:None,generator_max_length:None,'
+        'validator=StrictOverride(FrozenSet(FrozenSetValidator{'
+        'strict:true,item_validator:None,min_length:Some(42),max_length:None,generator_max_length:None,fail_fast:false,'
         'name:"frozenset[any]"'
-        '}),slots=[])'
+        '})),slots=[])'
     )
 
 
@@ -272,6 +280,13 @@ def test_generator_error():
         v.validate_python(gen(True))
 
 
+def test_range():
+    v = SchemaValidator({'type': 'frozenset', 'items_schema': {'type': 'int'}, 'max_length': 3})
+    assert v.validate_python(range(3)) == frozenset({0, 1, 2})
+    with pytest.raises(ValidationError, match='type=too_long,'):
+        v.validate_python(range(10**12))
+
+
 @pytest.mark.parametrize(
     'input_value,items_schema,expected',
     [
@@ -295,3 +310,46 @@ def test_frozenset_from_dict_items(input_value, items_schema, expected):
     output = v.validate_python(input_value)
     assert isinstance(output, frozenset)
     assert output == expected
+
+
+def test_unhashable_items():
+    v = SchemaValidator({'type': 'frozenset'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, [2]])
+    assert exc_info.value.errors() == [
+        {'type': 'unhashable', 'loc': (1,), 'msg': 'Input should be hashable', 'input': [2]}
+    ]
+
+
+def test_unhashable_after_omitted_items():
+    v = SchemaValidator(
+        {
+            'type': 'frozenset',
+            'items_schema': {
+                'type': 'default',
+                'schema': {'type': 'list', 'items_schema': {'type': 'int'}},
+                'on_error': 'omit',
+            },
+        }
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(iter([['x'], [1], (2,)]))
+    assert exc_info.value.errors() == [
+        {'type': 'unhashable', 'loc': (1,), 'msg': 'Input should be hashable', 'input': [1]},
+        {'type': 'unhashable', 'loc': (2,), 'msg': 'Input should be hashable', 'input': [2]},
+    ]
+
+
+def test_fail_fast(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'frozenset', 'items_schema': {'type': 'int'}, 'fail_fast': True})
+    assert v.validate_test([1, '2', 1]) == frozenset({1, 2})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([1, 'x', 'y'])
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', (1,))]
+
+
+def test_infinite_generator_items_schema():
+    v = SchemaValidator({'type': 'frozenset', 'items_schema': {'type': 'int'}, 'max_length': 3})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(infinite_generator())
+    assert exc_info.value.errors()[0]['ctx'] == {'field_type': 'Frozenset', 'max_length': 3, 'actual_length': 4}
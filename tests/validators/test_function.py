@@ -1,3 +1,4 @@
+import dataclasses
 import platform
 import re
 from copy import deepcopy
@@ -121,6 +122,77 @@ def test_function_before_error_model():
     ]
 
 
+def split_csv(input_value: str):
+    return input_value.split(',')
+
+
+def tags_validator(**kwargs) -> SchemaValidator:
+    return SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'tags': core_schema.typed_dict_field(
+                    core_schema.no_info_before_validator_function(
+                        split_csv, core_schema.list_schema(core_schema.int_schema()), **kwargs
+                    )
+                )
+            }
+        )
+    )
+
+
+@pytest.mark.parametrize(
+    'kwargs,expected_loc',
+    [
+        ({}, ('tags', 3)),
+        ({'loc_mode': 'inner'}, ('tags', 3)),
+        ({'loc_mode': 'outer'}, ('tags',)),
+        ({'loc_mode': lambda loc: ('csv_item', loc[0] + 1)}, ('tags', 'csv_item', 4)),
+        ({'loc_mode': lambda loc: ()}, ('tags',)),
+    ],
+)
+def test_function_before_loc_mode(kwargs, expected_loc):
+    v = tags_validator(**kwargs)
+    assert v.validate_python({'tags': '1,2,3'}) == {'tags': [1, 2, 3]}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'tags': '1,2,3,x'})
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': expected_loc,
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+
+def test_function_before_loc_mode_outer_multiple():
+    v = tags_validator(loc_mode='outer')
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'tags': 'x,1,y'})
+    assert [(e['loc'], e['input']) for e in exc_info.value.errors()] == [(('tags',), 'x'), (('tags',), 'y')]
+
+
+def test_function_before_loc_mode_function_error():
+    def loc_mode(loc):
+        raise RuntimeError('broken loc_mode')
+
+    v = tags_validator(loc_mode=loc_mode)
+    with pytest.raises(RuntimeError, match='broken loc_mode'):
+        v.validate_python({'tags': '1,x'})
+
+
+def test_function_before_loc_mode_function_invalid():
+    v = tags_validator(loc_mode=lambda loc: 123)
+    with pytest.raises(TypeError, match='Location must be a list or tuple of strings and ints'):
+        v.validate_python({'tags': '1,x'})
+
+
+def test_function_before_loc_mode_invalid():
+    with pytest.raises(SchemaError, match="Input should be 'inner' or 'outer'"):
+        tags_validator(loc_mode='middle')
+
+
 @pytest.mark.parametrize(
     'config,kwargs,expected_repr',
     [
@@ -174,7 +246,7 @@ def test_function_wrap_repr():
         {'type': 'function-wrap', 'function': {'type': 'general', 'function': f}, 'schema': {'type': 'str'}}
     )
 
-    assert v.validate_python('input value') == 'ValidatorCallable(Str(StrValidator{strict:false}))'
+    assert v.validate_python('input value') == 'ValidatorCallable(Str(StrValidator{strict:false,coerce_hook:true}))'
 
 
 def test_function_wrap_str():
@@ -185,7 +257,7 @@ def test_function_wrap_str():
         {'type': 'function-wrap', 'function': {'type': 'general', 'function': f}, 'schema': {'type': 'str'}}
     )
 
-    assert v.validate_python('input value') == 'ValidatorCallable(Str(StrValidator{strict:false}))'
+    assert v.validate_python('input value') == 'ValidatorCallable(Str(StrValidator{strict:false,coerce_hook:true}))'
 
 
 def test_function_wrap_not_callable():
@@ -647,12 +719,11 @@ def test_model_field_wrap_validator() -> None:
 
 
 def check_that_info_has_no_model_data(info: core_schema.ValidationInfo) -> None:
-    with pytest.raises(AttributeError, match="No attribute named 'field_name'"):
-        info.field_name  # type: ignore[attr-defined]
+    assert info.field_name == 'x'
     with pytest.raises(AttributeError, match="No attribute named 'data'"):
         info.data  # type: ignore[attr-defined]
-    assert not hasattr(info, 'field_name')
     assert not hasattr(info, 'data')
+    assert repr(info) == "ValidationInfo(config=None, context=None, field_name='x')"
 
 
 def test_non_model_field_before_validator_tries_to_access_field_info() -> None:
@@ -761,6 +832,102 @@ def test_non_model_field_wrap_validator_tries_to_access_field_info() -> None:
     assert v.validate_python({'x': b'foo'}).x == 'input: foo'
 
 
+def field_name_validator_function(mode: str, field_names: list) -> core_schema.CoreSchema:
+    def f(input_value: Any, info: core_schema.ValidationInfo) -> Any:
+        field_names.append(info.field_name)
+        return input_value
+
+    def f_wrap(input_value: Any, handler: core_schema.ValidatorFunctionWrapHandler, info: core_schema.ValidationInfo):
+        field_names.append(info.field_name)
+        return handler(input_value)
+
+    if mode == 'before':
+        return core_schema.general_before_validator_function(f, core_schema.int_schema())
+    elif mode == 'after':
+        return core_schema.general_after_validator_function(f, core_schema.int_schema())
+    elif mode == 'wrap':
+        return core_schema.general_wrap_validator_function(f_wrap, core_schema.int_schema())
+    else:
+        return core_schema.general_plain_validator_function(f)
+
+
+@pytest.mark.parametrize('mode', ['before', 'after', 'wrap', 'plain'])
+def test_field_name(mode):
+    field_names = []
+    check = field_name_validator_function(mode, field_names)
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(check),
+                'items': core_schema.typed_dict_field(core_schema.list_schema(check)),
+            }
+        )
+    )
+    assert v.validate_python({'a': 1, 'items': [2, 3]}) == {'a': 1, 'items': [2, 3]}
+    assert field_names == ['a', 'items', 'items']
+
+
+@pytest.mark.parametrize('mode', ['before', 'after', 'wrap', 'plain'])
+def test_field_name_none(mode):
+    field_names = []
+    check = field_name_validator_function(mode, field_names)
+
+    assert SchemaValidator(check).validate_python(1) == 1
+    assert SchemaValidator(core_schema.list_schema(check)).validate_python([1, 2]) == [1, 2]
+    assert SchemaValidator(core_schema.dict_schema(check, check)).validate_python({1: 2}) == {1: 2}
+    assert field_names == [None, None, None, None, None]
+
+
+def test_field_name_dataclass():
+    field_names = []
+
+    @dataclasses.dataclass
+    class MyDataclass:
+        a: int
+        b: int
+
+    v = SchemaValidator(
+        core_schema.dataclass_schema(
+            MyDataclass,
+            core_schema.dataclass_args_schema(
+                'MyDataclass',
+                [
+                    core_schema.dataclass_field('a', field_name_validator_function('before', field_names)),
+                    core_schema.dataclass_field('b', field_name_validator_function('wrap', field_names)),
+                ],
+            ),
+        )
+    )
+    assert dataclasses.asdict(v.validate_python({'a': 1, 'b': 2})) == {'a': 1, 'b': 2}
+    assert field_names == ['a', 'b']
+
+
+def test_field_name_nested_model():
+    field_names = []
+
+    class Model:
+        def __init__(self, **kwargs: Any) -> None:
+            self.__dict__.update(kwargs)
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'sub': core_schema.typed_dict_field(
+                    core_schema.model_schema(
+                        Model,
+                        core_schema.typed_dict_schema(
+                            {'x': core_schema.typed_dict_field(field_name_validator_function('after', field_names))}
+                        ),
+                    )
+                ),
+                'y': core_schema.typed_dict_field(field_name_validator_function('plain', field_names)),
+            }
+        )
+    )
+    assert v.validate_python({'sub': {'x': 1}, 'y': 2})['y'] == 2
+    assert field_names == ['x', 'y']
+
+
 def test_method_function_no_model():
     def f(*args: Any, **kwargs: Any) -> Any:  # pragma: no cover
         raise AssertionError('Should not be called')
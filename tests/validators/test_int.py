@@ -1,3 +1,4 @@
+import json
 import re
 from decimal import Decimal
 from typing import Any, Dict
@@ -5,7 +6,7 @@ from typing import Any, Dict
 import pytest
 from dirty_equals import IsStr
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, plain_repr
 
@@ -59,8 +60,16 @@ def test_int_py_and_json(py_and_json: PyAndJson, input_value, expected):
         (Decimal('1'), 1),
         (Decimal('1.0'), 1),
         (i64_max, i64_max),
-        (i64_max + 1, i64_max),
-        (i64_max * 2, i64_max),
+        pytest.param(
+            i64_max + 1,
+            Err('Unable to parse input as an integer, exceeded maximum size [type=int_parsing_size'),
+            id='too-big',
+        ),
+        pytest.param(
+            -i64_max * 2,
+            Err('Unable to parse input as an integer, exceeded maximum size [type=int_parsing_size'),
+            id='too-small',
+        ),
         pytest.param(
             Decimal('1.001'),
             Err(
@@ -205,13 +214,91 @@ def test_union_int_simple(py_and_json: PyAndJson):
 
 def test_int_repr():
     v = SchemaValidator({'type': 'int'})
-    assert plain_repr(v) == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:false}),slots=[])'
+    assert plain_repr(v) == (
+        'SchemaValidator(title="int",validator=Int(IntValidator{'
+        'strict:false,coerce_hook:true,json_strict_numbers:false,parse_radix_prefixes:false}),slots=[])'
+    )
     v = SchemaValidator({'type': 'int', 'strict': True})
-    assert plain_repr(v) == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:true}),slots=[])'
+    assert plain_repr(v) == (
+        'SchemaValidator(title="int",validator=StrictOverride(Int(IntValidator{'
+        'strict:true,coerce_hook:true,json_strict_numbers:false,parse_radix_prefixes:false})),slots=[])'
+    )
     v = SchemaValidator({'type': 'int', 'multiple_of': 7})
     assert plain_repr(v).startswith('SchemaValidator(title="constrained-int",validator=ConstrainedInt(')
 
 
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('1', 1),
+        ('-1', -1),
+        ('"1"', 1),
+        ('true', 1),
+        ('1.0', Err('Input should be a valid integer [type=int_type, input_value=1.0, input_type=float]')),
+        ('1e2', Err('Input should be a valid integer [type=int_type, input_value=100.0, input_type=float]')),
+        (
+            '1.5',
+            Err(
+                'Input should be a valid integer, got a number with a fractional part '
+                '[type=int_from_float, input_value=1.5, input_type=float]'
+            ),
+        ),
+    ],
+)
+@pytest.mark.parametrize(
+    'schema', [core_schema.int_schema(), core_schema.int_schema(le=10)], ids=['int', 'constrained']
+)
+def test_json_strict_numbers(schema, input_value, expected):
+    v = SchemaValidator(schema, {'json_strict_numbers': True})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_json(input_value)
+    else:
+        assert v.validate_json(input_value) == expected
+
+
+def test_json_strict_numbers_other_inputs():
+    v = SchemaValidator(core_schema.int_schema(), {'json_strict_numbers': True})
+    # python floats aren't affected
+    assert v.validate_python(1.0) == 1
+
+    # float fields accept JSON numbers with or without a fractional part
+    v = SchemaValidator(core_schema.float_schema(strict=True), {'json_strict_numbers': True})
+    assert v.validate_json('1') == 1.0
+    assert v.validate_json('1.0') == 1.0
+
+
+def test_json_strict_int_float_token():
+    v = SchemaValidator(core_schema.int_schema())
+    assert v.validate_json('1.0') == 1
+    with pytest.raises(ValidationError, match=r'Input should be a valid integer \[type=int_type'):
+        v.validate_json('1.0', strict=True)
+
+
+@pytest.mark.parametrize('input_value', ['9223372036854775808', '18446744073709551617', '-9223372036854775809', '1e20'])
+def test_json_int_too_big(input_value):
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(input_value)
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing_size',
+            'loc': (),
+            'msg': 'Unable to parse input as an integer, exceeded maximum size',
+            'input': json.loads(input_value),
+        }
+    ]
+
+
+def test_json_big_int_any():
+    """ints too big for an i64 are kept exactly, they don't saturate or become floats"""
+    v = SchemaValidator(core_schema.any_schema())
+    assert v.validate_json('18446744073709551617') == 18446744073709551617
+    assert v.validate_json('[-123456789012345678901234567890]') == [-123456789012345678901234567890]
+    big = '9' * 5000
+    assert v.validate_json(big) == int(Decimal(big))
+
+
 def test_long_int(py_and_json: PyAndJson):
     v = py_and_json({'type': 'int'})
 
@@ -244,3 +331,264 @@ def test_int_key(py_and_json: PyAndJson):
     assert v.validate_test({'1': 1, '2': 2}) == {1: 1, 2: 2}
     with pytest.raises(ValidationError, match='Input should be a valid integer'):
         v.validate_test({'1': 1, '2': 2}, strict=True)
+
+
+@pytest.mark.parametrize(
+    'mode,input_value,expected',
+    [
+        ('error', 15, 15),
+        ('error', 16, Err('Input should be a multiple of 5')),
+        ('round', 15, 15),
+        ('round', 16, 15),
+        ('round', 18, 20),
+        ('round', '-16', -15),
+        ('round', -18, -20),
+        ('floor', 19, 15),
+        ('floor', -16, -20),
+        ('ceil', 16, 20),
+        ('ceil', -19, -15),
+        ('ceil', 0, 0),
+    ],
+)
+def test_multiple_of_mode(py_and_json: PyAndJson, mode, input_value, expected):
+    v = py_and_json({'type': 'int', 'multiple_of': 5, 'multiple_of_mode': mode})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+        assert isinstance(output, int)
+
+
+def test_multiple_of_mode_ties():
+    v = SchemaValidator({'type': 'int', 'multiple_of': 10, 'multiple_of_mode': 'round'})
+    assert v.validate_python(5) == 10
+    assert v.validate_python(15) == 20
+    assert v.validate_python(-5) == -10
+    assert v.validate_python(-15) == -20
+
+
+def test_multiple_of_mode_negative_multiple():
+    v = SchemaValidator({'type': 'int', 'multiple_of': -5, 'multiple_of_mode': 'floor'})
+    assert v.validate_python(19) == 15
+    assert v.validate_python(-16) == -20
+
+
+def test_multiple_of_mode_exact_large():
+    v = SchemaValidator({'type': 'int', 'multiple_of': 1000, 'multiple_of_mode': 'ceil'})
+    assert v.validate_python(i64_max) == 9_223_372_036_854_776_000
+    assert v.validate_python(i64_max - 1_000_000) == 9_223_372_036_853_776_000
+
+    v = SchemaValidator({'type': 'int', 'multiple_of': 3, 'multiple_of_mode': 'floor'})
+    assert v.validate_python(i64_max - 1) == i64_max - 1 - (i64_max - 1) % 3
+
+
+@pytest.mark.parametrize('mode', ['round', 'floor', 'ceil'])
+def test_multiple_of_mode_big_int(mode):
+    # ints too big for 64 bits aren't supported by int validation, adjusting them included
+    v = SchemaValidator({'type': 'int', 'multiple_of': 1000, 'multiple_of_mode': mode})
+    with pytest.raises(ValidationError, match=r'exceeded maximum size \[type=int_parsing_size'):
+        v.validate_python(i64_max + 1)
+    with pytest.raises(ValidationError, match=r'exceeded maximum size \[type=int_parsing_size'):
+        v.validate_json(str(10**20))
+
+
+def test_multiple_of_mode_then_constraints():
+    v = SchemaValidator({'type': 'int', 'multiple_of': 5, 'multiple_of_mode': 'ceil', 'le': 20})
+    assert v.validate_python(16) == 20
+    with pytest.raises(ValidationError, match='Input should be less than or equal to 20'):
+        v.validate_python(21)
+
+
+def test_multiple_of_mode_invalid():
+    with pytest.raises(SchemaError, match="Input should be 'error', 'round', 'floor' or 'ceil'"):
+        SchemaValidator({'type': 'int', 'multiple_of': 5, 'multiple_of_mode': 'truncate'})
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('0xFF', 255),
+        ('0Xff', 255),
+        ('-0x10', -16),
+        ('0o17', 15),
+        ('0b1010', 10),
+        ('+0b1', 1),
+        ('0x_ff', 255),
+        ('1_000', 1000),
+        ('0b1_0', 2),
+        (' 0x10 ', 16),
+        ('0_0', 0),
+        ('0x7fffffffffffffff', 9223372036854775807),
+        ('-0x8000000000000000', -9223372036854775808),
+        # plain decimals are parsed as before
+        ('123', 123),
+        ('010', 10),
+        ('1.0', 1),
+        (1, 1),
+        ('0x', Err('int_parsing')),
+        ('0xfg', Err('int_parsing')),
+        ('0b102', Err('int_parsing')),
+        ('1__000', Err('int_parsing')),
+        ('1_000_', Err('int_parsing')),
+        ('_1000', Err('int_parsing')),
+        ('0x__ff', Err('int_parsing')),
+        ('0_1', Err('int_parsing')),
+        ('1_0.5', Err('int_parsing')),
+        ('0x+5', Err('int_parsing')),
+        ('0x8000000000000000', Err('int_parsing')),
+        ('abc', Err('int_parsing')),
+    ],
+)
+@pytest.mark.parametrize(
+    'schema',
+    [core_schema.int_schema(parse_radix_prefixes=True), core_schema.int_schema(parse_radix_prefixes=True, le=i64_max)],
+    ids=['int', 'constrained'],
+)
+def test_parse_radix_prefixes(py_and_json: PyAndJson, schema, input_value, expected):
+    v = py_and_json(schema)
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_test(input_value)
+        assert exc_info.value.errors() == [
+            {
+                'type': 'int_parsing',
+                'loc': (),
+                'msg': 'Input should be a valid integer, unable to parse string as an integer',
+                'input': input_value,
+            }
+        ]
+    else:
+        assert v.validate_test(input_value) == expected
+        # python's `int(x, 0)` agrees, except for plain decimals it rejects
+        if isinstance(input_value, str) and input_value not in ('010', '1.0'):
+            assert int(input_value, 0) == expected
+
+
+def test_parse_radix_prefixes_strict():
+    v = SchemaValidator(core_schema.int_schema(parse_radix_prefixes=True))
+    with pytest.raises(ValidationError, match=r'Input should be a valid integer \[type=int_type'):
+        v.validate_python('0xff', strict=True)
+    with pytest.raises(ValidationError, match=r'Input should be a valid integer \[type=int_type'):
+        v.validate_python('123', strict=True)
+
+
+def test_parse_radix_prefixes_off():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(ValidationError, match='int_parsing'):
+        v.validate_python('0xff')
+    with pytest.raises(ValidationError, match='int_parsing'):
+        v.validate_python('1_000')
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('12.34', 1234),
+        ('12.3', 1230),
+        ('12', 1200),
+        ('-0.01', -1),
+        ('+1.5', 150),
+        ('.5', 50),
+        ('5.', 500),
+        (' 12.34 ', 1234),
+        # trailing zeros aren't counted as decimal places
+        ('12.34000', 1234),
+        ('1.5e1', 1500),
+        ('1.2345E2', 12345),
+        ('1e-2', 1),
+        ('0.000e-10', 0),
+        (12, 1200),
+        (12.34, 1234),
+        (0.1, 10),
+        (-0.07, -7),
+        # larger than a float can represent exactly
+        ('12345678901234567.89', 1234567890123456789),
+        ('12.345', Err('Input should have no more than 2 decimal places [type=decimal_places')),
+        ('1e-3', Err('Input should have no more than 2 decimal places [type=decimal_places')),
+        (12.345, Err('Input should have no more than 2 decimal places [type=decimal_places')),
+        ('abc', Err('Input should be a valid number, unable to parse string as an number [type=float_parsing')),
+        ('1.2.3', Err('float_parsing')),
+        ('1e', Err('float_parsing')),
+        ('.', Err('float_parsing')),
+        ('1_000', Err('float_parsing')),
+        ('1e99999999999999999999', Err('int_parsing')),
+    ],
+)
+def test_scale(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.int_schema(scale=2))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+        assert type(output) == int
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (Decimal('12.34'), 1234),
+        (True, 100),
+        (float('inf'), Err('Input should be a finite number [type=finite_number')),
+        (None, Err('Input should be a valid integer [type=int_type')),
+    ],
+)
+def test_scale_python(input_value, expected):
+    v = SchemaValidator(core_schema.int_schema(scale=2))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_scale_decimal_places_error():
+    v = SchemaValidator(core_schema.int_schema(scale=1))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('1.25')
+    assert exc_info.value.errors() == [
+        {
+            'type': 'decimal_places',
+            'loc': (),
+            'msg': 'Input should have no more than 1 decimal place',
+            'input': '1.25',
+            'ctx': {'decimal_places': 1},
+        }
+    ]
+
+
+def test_scale_strict():
+    v = SchemaValidator(core_schema.int_schema(scale=2, strict=True))
+    assert v.validate_python(12) == 1200
+    with pytest.raises(ValidationError, match=r'Input should be a valid integer \[type=int_type'):
+        v.validate_python('12.34')
+    with pytest.raises(ValidationError, match=r'Input should be a valid integer \[type=int_type'):
+        v.validate_python(12.34)
+
+
+def test_scale_constraints():
+    # constraints apply to the number of minor units
+    v = SchemaValidator(core_schema.int_schema(scale=2, ge=0, le=10_000, multiple_of=5))
+    assert v.validate_python('100.00') == 10_000
+    assert v.validate_python('0.05') == 5
+    with pytest.raises(ValidationError, match=r'Input should be less than or equal to 10000 \[type=less_than_equal'):
+        v.validate_python('100.05')
+    with pytest.raises(ValidationError, match=r'Input should be greater than or equal to 0 \[type=greater_than_equal'):
+        v.validate_python('-0.05')
+    with pytest.raises(ValidationError, match=r'Input should be a multiple of 5 \[type=multiple_of'):
+        v.validate_python('0.01')
+
+
+def test_scale_zero():
+    v = SchemaValidator(core_schema.int_schema(scale=0))
+    assert v.validate_python('12.0') == 12
+    with pytest.raises(ValidationError, match=r'Input should have no more than 0 decimal places \[type=decimal_places'):
+        v.validate_python('12.5')
+
+
+def test_scale_too_large():
+    with pytest.raises(SchemaError, match='`scale` should be at most 18'):
+        SchemaValidator(core_schema.int_schema(scale=19))
@@ -0,0 +1,278 @@
+import re
+import sys
+from ipaddress import IPv4Address, IPv4Interface, IPv4Network, IPv6Address, IPv6Interface, IPv6Network
+
+import pytest
+
+from pydantic_core import SchemaSerializer, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (IPv4Address('192.168.0.1'), IPv4Address('192.168.0.1')),
+        ('192.168.0.1', IPv4Address('192.168.0.1')),
+        ('0.0.0.0', IPv4Address(0)),
+        ('255.255.255.255', IPv4Address(2**32 - 1)),
+        (3232235521, IPv4Address('192.168.0.1')),
+        (b'\xc0\xa8\x00\x01', IPv4Address('192.168.0.1')),
+        ('192.168.0', Err("Input is not a valid IPv4 address [type=ip_v4_address, input_value='192.168.0',")),
+        ('192.168.0.256', Err('[type=ip_v4_address,')),
+        # leading zeros are ambiguous, so they're rejected as by `ipaddress`
+        ('192.168.0.01', Err('[type=ip_v4_address,')),
+        (' 192.168.0.1', Err('[type=ip_v4_address,')),
+        ('::1', Err('[type=ip_v4_address,')),
+        (2**32, Err('[type=ip_v4_address,')),
+        (-1, Err('[type=ip_v4_address,')),
+        (True, Err('[type=ip_v4_address,')),
+        (b'\x00' * 16, Err('[type=ip_v4_address,')),
+        (IPv6Address('::1'), Err('[type=ip_v4_address,')),
+        (None, Err('[type=ip_v4_address, input_value=None, input_type=NoneType]')),
+    ],
+)
+def test_ip_v4_address(input_value, expected):
+    v = SchemaValidator(core_schema.ip_v4_address_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert type(output) is IPv4Address
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (IPv6Address('2001:db8::1'), IPv6Address('2001:db8::1')),
+        ('2001:db8::1', IPv6Address('2001:db8::1')),
+        ('2001:0DB8:0000:0000:0000:0000:0000:0001', IPv6Address('2001:db8::1')),
+        ('::ffff:192.168.0.1', IPv6Address('::ffff:c0a8:1')),
+        ('::', IPv6Address(0)),
+        (2**128 - 1, IPv6Address('ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff')),
+        (b'\x20\x01\x0d\xb8' + b'\x00' * 11 + b'\x01', IPv6Address('2001:db8::1')),
+        ('2001:db8::1::1', Err("Input is not a valid IPv6 address [type=ip_v6_address, input_value='2001:db8::1::1'")),
+        ('2001:db8:::1', Err('[type=ip_v6_address,')),
+        ('192.168.0.1', Err('[type=ip_v6_address,')),
+        (2**128, Err('[type=ip_v6_address,')),
+        (b'\x00' * 4, Err('[type=ip_v6_address,')),
+        (IPv4Address('192.168.0.1'), Err('[type=ip_v6_address,')),
+    ],
+)
+def test_ip_v6_address(input_value, expected):
+    v = SchemaValidator(core_schema.ip_v6_address_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert type(output) is IPv6Address
+
+
+@pytest.mark.skipif(sys.version_info < (3, 9), reason='scope IDs need python 3.9')
+def test_ip_v6_address_scope_id():
+    v = SchemaValidator(core_schema.ip_v6_address_schema())
+    output = v.validate_python('fe80::1%eth0')
+    assert output == IPv6Address('fe80::1%eth0')
+    assert output.scope_id == 'eth0'
+    with pytest.raises(ValidationError, match=re.escape('[type=ip_v6_address,')):
+        v.validate_python('fe80::1%')
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (IPv4Network('192.168.0.0/24'), IPv4Network('192.168.0.0/24')),
+        ('192.168.0.0/24', IPv4Network('192.168.0.0/24')),
+        ('192.168.0.0/255.255.255.0', IPv4Network('192.168.0.0/24')),
+        ('192.168.0.0/0.0.0.255', IPv4Network('192.168.0.0/24')),
+        ('192.168.0.1', IPv4Network('192.168.0.1/32')),
+        ('0.0.0.0/0', IPv4Network('0.0.0.0/0')),
+        # host bits are masked in lax mode
+        ('192.168.0.1/24', IPv4Network('192.168.0.0/24')),
+        (IPv4Address('192.168.0.1'), IPv4Network('192.168.0.1/32')),
+        (IPv4Interface('192.168.0.1/24'), IPv4Network('192.168.0.1/32')),
+        (3232235521, IPv4Network('192.168.0.1/32')),
+        (b'\xc0\xa8\x00\x01', IPv4Network('192.168.0.1/32')),
+        ('192.168.0.0/33', Err("Input is not a valid IPv4 network [type=ip_v4_network, input_value='192.168.0.0/3")),
+        ('192.168.0.0/', Err('[type=ip_v4_network,')),
+        ('192.168.0.0/-1', Err('[type=ip_v4_network,')),
+        ('192.168.0.0/255.0.255.0', Err('[type=ip_v4_network,')),
+        ('192.168.0.0/24/24', Err('[type=ip_v4_network,')),
+        ('2001:db8::/32', Err('[type=ip_v4_network,')),
+        (IPv6Network('2001:db8::/32'), Err('[type=ip_v4_network,')),
+    ],
+)
+def test_ip_v4_network(input_value, expected):
+    v = SchemaValidator(core_schema.ip_v4_network_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert type(output) is IPv4Network
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (IPv6Network('2001:db8::/32'), IPv6Network('2001:db8::/32')),
+        ('2001:db8::/32', IPv6Network('2001:db8::/32')),
+        ('2001:db8::1/32', IPv6Network('2001:db8::/32')),
+        ('::/0', IPv6Network('::/0')),
+        ('::1', IPv6Network('::1/128')),
+        (IPv6Address('::1'), IPv6Network('::1/128')),
+        ('2001:db8::/129', Err("Input is not a valid IPv6 network [type=ip_v6_network, input_value='2001:db8::/129'")),
+        # netmasks are only accepted for IPv4 networks, as with `ipaddress`
+        ('2001:db8::/ffff:ffff::', Err('[type=ip_v6_network,')),
+        ('192.168.0.0/24', Err('[type=ip_v6_network,')),
+    ],
+)
+def test_ip_v6_network(input_value, expected):
+    v = SchemaValidator(core_schema.ip_v6_network_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert type(output) is IPv6Network
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (IPv4Interface('192.168.0.1/24'), IPv4Interface('192.168.0.1/24')),
+        ('192.168.0.1/24', IPv4Interface('192.168.0.1/24')),
+        ('192.168.0.1/255.255.255.0', IPv4Interface('192.168.0.1/24')),
+        ('192.168.0.1', IPv4Interface('192.168.0.1/32')),
+        (IPv4Address('192.168.0.1'), IPv4Interface('192.168.0.1/32')),
+        (3232235521, IPv4Interface('192.168.0.1/32')),
+        ('192.168.0.1/33', Err('Input is not a valid IPv4 interface [type=ip_v4_interface, input_value=')),
+        ('x', Err('[type=ip_v4_interface,')),
+    ],
+)
+def test_ip_v4_interface(input_value, expected):
+    v = SchemaValidator(core_schema.ip_v4_interface_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert type(output) is IPv4Interface
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (IPv6Interface('2001:db8::1/32'), IPv6Interface('2001:db8::1/32')),
+        ('2001:db8::1/32', IPv6Interface('2001:db8::1/32')),
+        ('2001:db8::1', IPv6Interface('2001:db8::1/128')),
+        ('2001:db8::1/x', Err('Input is not a valid IPv6 interface [type=ip_v6_interface, input_value=')),
+    ],
+)
+def test_ip_v6_interface(input_value, expected):
+    v = SchemaValidator(core_schema.ip_v6_interface_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert type(output) is IPv6Interface
+
+
+@pytest.mark.parametrize(
+    'schema,input_value',
+    [
+        (core_schema.ip_v4_address_schema, '192.168.0.1'),
+        (core_schema.ip_v4_address_schema, 3232235521),
+        (core_schema.ip_v6_address_schema, b'\x00' * 16),
+        (core_schema.ip_v4_network_schema, IPv4Address('192.168.0.1')),
+        (core_schema.ip_v6_network_schema, '2001:db8::/32'),
+        (core_schema.ip_v4_interface_schema, '192.168.0.1/24'),
+    ],
+)
+def test_strict(schema, input_value):
+    v = SchemaValidator(schema(strict=True))
+    with pytest.raises(ValidationError, match=re.escape('Input is not a valid IP')):
+        v.validate_python(input_value)
+
+
+def test_passthrough():
+    v = SchemaValidator(core_schema.ip_v4_address_schema(strict=True))
+    address = IPv4Address('192.168.0.1')
+    assert v.validate_python(address) is address
+    # interfaces are addresses too
+    interface = IPv4Interface('192.168.0.1/24')
+    assert v.validate_python(interface) is interface
+
+    v = SchemaValidator(core_schema.ip_v6_network_schema(strict=True))
+    network = IPv6Network('2001:db8::/32')
+    assert v.validate_python(network) is network
+
+
+def test_json(py_and_json: PyAndJson):
+    v = py_and_json(core_schema.ip_v4_address_schema())
+    assert v.validate_test('192.168.0.1') == IPv4Address('192.168.0.1')
+    assert v.validate_test(3232235521) == IPv4Address('192.168.0.1')
+    with pytest.raises(ValidationError, match=re.escape('[type=ip_v4_address,')):
+        v.validate_test(-1)
+    with pytest.raises(ValidationError, match=re.escape('[type=ip_v4_address,')):
+        v.validate_test(1.5)
+
+
+def test_json_strict():
+    v = SchemaValidator(core_schema.ip_v4_address_schema(strict=True))
+    # JSON has no address type, so strings are accepted in strict mode, but not the int form
+    assert v.validate_json('"192.168.0.1"') == IPv4Address('192.168.0.1')
+    with pytest.raises(ValidationError, match=re.escape('[type=ip_v4_address, input_value=3232235521,')):
+        v.validate_json('3232235521')
+
+    v = SchemaValidator(core_schema.ip_v6_interface_schema(strict=True))
+    assert v.validate_json('"2001:db8::1/32"') == IPv6Interface('2001:db8::1/32')
+
+
+def test_network_host_bits_strict():
+    v = SchemaValidator(core_schema.ip_v4_network_schema(strict=True))
+    assert v.validate_json('"192.168.0.0/24"') == IPv4Network('192.168.0.0/24')
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('"192.168.0.1/24"')
+    assert exc_info.value.errors() == [
+        {
+            'type': 'ip_network_host_bits',
+            'loc': (),
+            'msg': 'Input network should not have host bits set',
+            'input': '192.168.0.1/24',
+        }
+    ]
+
+    v = SchemaValidator(core_schema.ip_v6_network_schema())
+    assert v.validate_json('"2001:db8::1/32"', strict=False) == IPv6Network('2001:db8::/32')
+    with pytest.raises(ValidationError, match=re.escape('[type=ip_network_host_bits,')):
+        v.validate_json('"2001:db8::1/32"', strict=True)
+
+
+@pytest.mark.parametrize(
+    'schema,value,expected',
+    [
+        (core_schema.ip_v4_address_schema, IPv4Address('192.168.0.1'), '192.168.0.1'),
+        (core_schema.ip_v6_address_schema, IPv6Address('2001:0db8:0000::0001'), '2001:db8::1'),
+        (core_schema.ip_v4_network_schema, IPv4Network('192.168.0.0/24'), '192.168.0.0/24'),
+        (core_schema.ip_v6_network_schema, IPv6Network('2001:db8::/32'), '2001:db8::/32'),
+        (core_schema.ip_v4_interface_schema, IPv4Interface('192.168.0.1/24'), '192.168.0.1/24'),
+        (core_schema.ip_v6_interface_schema, IPv6Interface('::1/64'), '::1/64'),
+    ],
+)
+def test_serialization(schema, value, expected):
+    s = SchemaSerializer(schema())
+    assert s.to_python(value) is value
+    assert s.to_python(value, mode='json') == expected
+    assert s.to_json(value) == f'"{expected}"'.encode()
+    # the JSON form is validated back to the same value
+    assert SchemaValidator(schema(strict=True)).validate_json(s.to_json(value)) == value
@@ -24,7 +24,13 @@ from ..conftest import Err, PyAndJson, plain_repr
                         'loc': (),
                         'msg': 'Invalid JSON: key must be a string at line 1 column 2',
                         'input': '{1: 2}',
-                        'ctx': {'error': 'key must be a string at line 1 column 2'},
+                        'ctx': {
+                            'error': 'key must be a string at line 1 column 2',
+                            'position': 1,
+                            'line': 1,
+                            'column': 2,
+                            'expected': 'expected `"`',
+                        },
                     }
                 ],
             ),
@@ -136,7 +142,13 @@ def test_dict_key(py_and_json: PyAndJson):
             'loc': ('x', '[key]'),
             'msg': 'Invalid JSON: expected value at line 1 column 1',
             'input': 'x',
-            'ctx': {'error': 'expected value at line 1 column 1'},
+            'ctx': {
+                'error': 'expected value at line 1 column 1',
+                'position': 0,
+                'line': 1,
+                'column': 1,
+                'expected': 'expected value',
+            },
         }
     ]
 
@@ -0,0 +1,139 @@
+from enum import Enum
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+class Color(Enum):
+    red = 'r'
+    blue = 'b'
+
+
+@pytest.fixture(scope='module')
+def color_schema():
+    return core_schema.json_or_python_schema(
+        json_schema=core_schema.no_info_after_validator_function(Color, core_schema.str_schema()),
+        python_schema=core_schema.is_instance_schema(Color),
+    )
+
+
+def test_json_or_python(color_schema):
+    v = SchemaValidator(color_schema)
+    assert v.validate_json('"r"') is Color.red
+    assert v.validate_python(Color.red) is Color.red
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('r')
+    # location is not changed
+    # insert_assert(exc_info.value.errors())
+    assert exc_info.value.errors() == [
+        {
+            'type': 'is_instance_of',
+            'loc': (),
+            'msg': 'Input should be an instance of Color',
+            'input': 'r',
+            'ctx': {'class': 'Color'},
+        }
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('"x"')
+    assert exc_info.value.errors() == [
+        {
+            'type': 'value_error',
+            'loc': (),
+            'msg': "Value error, 'x' is not a valid Color",
+            'input': 'x',
+            'ctx': {'error': "'x' is not a valid Color"},
+        }
+    ]
+
+
+def test_name(color_schema):
+    v = SchemaValidator(color_schema)
+    assert v.title == 'json-or-python[json=function-after[Color(), str],python=is-instance[Color]]'
+
+
+def test_dict_keys():
+    v = SchemaValidator(
+        core_schema.dict_schema(
+            core_schema.json_or_python_schema(core_schema.int_schema(), core_schema.str_schema()),
+            core_schema.int_schema(),
+        )
+    )
+    # JSON object keys use the JSON schema
+    assert v.validate_json('{"1": 2}') == {1: 2}
+    assert v.validate_python({'1': 2}) == {'1': 2}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({1: 2})
+    assert exc_info.value.errors() == [
+        {'type': 'string_type', 'loc': (1, '[key]'), 'msg': 'Input should be a valid string', 'input': 1}
+    ]
+
+
+def test_union(color_schema):
+    v = SchemaValidator(
+        core_schema.union_schema([color_schema, core_schema.int_schema(strict=True)], mode='left_to_right')
+    )
+    assert v.validate_json('"b"') is Color.blue
+    assert v.validate_json('1') == 1
+    assert v.validate_python(Color.blue) is Color.blue
+    assert v.validate_python(1) == 1
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('b')
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('is_instance_of', ('json-or-python[json=function-after[Color(), str],python=is-instance[Color]]',)),
+        ('int_type', ('int',)),
+    ]
+
+
+def test_default(color_schema):
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'color': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(color_schema, default=Color.red, validate_default=True),
+                    required=False,
+                )
+            }
+        )
+    )
+    assert v.validate_json('{}') == {'color': Color.red}
+    assert v.validate_python({}) == {'color': Color.red}
+    assert v.validate_json('{"color": "b"}') == {'color': Color.blue}
+    assert v.validate_python({'color': Color.blue}) == {'color': Color.blue}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'color': 'b'})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('is_instance_of', ('color',))]
+
+
+def test_model_fields_set():
+    class Model:
+        def __init__(self, **kwargs):
+            self.__dict__.update(kwargs)
+
+    fields_schema = core_schema.typed_dict_schema(
+        {'a': core_schema.typed_dict_field(core_schema.int_schema())}, return_fields_set=True
+    )
+    v = SchemaValidator(
+        core_schema.model_schema(Model, core_schema.json_or_python_schema(fields_schema, fields_schema))
+    )
+    m = v.validate_json('{"a": 1}')
+    assert m.a == 1
+    assert m.__pydantic_fields_set__ == {'a'}
+    m = v.validate_python({'a': 1})
+    assert m.a == 1
+    assert m.__pydantic_fields_set__ == {'a'}
+
+
+def test_strict():
+    v = SchemaValidator(core_schema.json_or_python_schema(core_schema.int_schema(), core_schema.int_schema()))
+    assert v.validate_json('"1"') == 1
+    assert v.validate_python('1') == 1
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_json('"1"', strict=True)
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python('1', strict=True)
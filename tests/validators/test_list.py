@@ -6,7 +6,7 @@ from typing import Any, Dict
 import pytest
 from dirty_equals import HasRepr, IsInstance, IsStr
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, infinite_generator
 
@@ -278,6 +278,33 @@ def test_generator_error():
     ]
 
 
+def test_range():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    assert v.validate_python(range(3)) == [0, 1, 2]
+    assert v.validate_python(range(0)) == []
+
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), strict=True))
+    with pytest.raises(ValidationError, match='type=list_type,'):
+        v.validate_python(range(3))
+
+
+def test_range_too_long():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), max_length=3))
+    assert v.validate_python(range(3)) == [0, 1, 2]
+    # the range's length is checked without iterating over it
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(range(10**12))
+    assert exc_info.value.errors() == [
+        {
+            'type': 'too_long',
+            'loc': (),
+            'msg': 'List should have at most 3 items after validation, not 1000000000000',
+            'input': range(10**12),
+            'ctx': {'field_type': 'List', 'max_length': 3, 'actual_length': 10**12},
+        }
+    ]
+
+
 @pytest.mark.parametrize(
     'input_value,items_schema,expected',
     [
@@ -401,3 +428,259 @@ def test_bad_iter(items_schema):
             'ctx': {'error': 'RuntimeError: broken'},
         }
     ]
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ([1, 2, 3], [1, 2, 3]),
+        ([], []),
+        ([1, '1'], Err('1\n  List should have unique items [type=list_unique_items, input_value=1, input_type=int]')),
+        ([1, 2, 1, 1], Err('2 validation errors')),
+    ],
+)
+def test_unique_items(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.list_schema(core_schema.int_schema(), unique_items=True))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_unique_items_errors():
+    v = SchemaValidator(core_schema.list_schema(unique_items=True))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([[1], (1,), [1], 'a', 'a'])
+    # insert_assert(exc_info.value.errors())
+    assert exc_info.value.errors() == [
+        {'type': 'list_unique_items', 'loc': (2,), 'msg': 'List should have unique items', 'input': [1]},
+        {'type': 'list_unique_items', 'loc': (4,), 'msg': 'List should have unique items', 'input': 'a'},
+    ]
+
+
+@pytest.mark.parametrize('nan_equality', [None, 'ieee'])
+def test_unique_items_nan_ieee(nan_equality):
+    v = SchemaValidator(core_schema.list_schema(unique_items=True, nan_equality=nan_equality))
+    nan = float('nan')
+    output = v.validate_python([nan, nan, 1.0])
+    # the same NaN object is still considered distinct, and the items are unchanged
+    assert len(output) == 3
+    assert output[0] is nan and output[1] is nan
+    with pytest.raises(ValidationError, match='List should have unique items'):
+        v.validate_python([nan, 1.0, 1.0])
+
+
+def test_unique_items_nan_identity():
+    v = SchemaValidator(
+        core_schema.list_schema(core_schema.float_schema(), unique_items=True, nan_equality='identity')
+    )
+    assert len(v.validate_python([float('nan'), 1.0])) == 2
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([float('nan'), 1.0, float('nan')])
+    assert exc_info.value.errors()[0]['type'] == 'list_unique_items'
+    assert exc_info.value.errors()[0]['loc'] == (2,)
+
+
+def test_nan_equality_invalid():
+    with pytest.raises(SchemaError, match="Input should be 'ieee' or 'identity'"):
+        SchemaValidator(core_schema.list_schema(unique_items=True, nan_equality='other'))
+
+
+def test_error_overrides_items_unchanged():
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.int_schema(),
+            min_length=2,
+            error_overrides={
+                'too_short': {'type': 'need_more', 'message': 'need {min_length} items, got {actual_length}'},
+                'int_parsing': {'type': 'not_used', 'message': 'not used'},
+            },
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['x'])
+    # errors from items aren't overridden, only errors from the list itself
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', (0,))]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1])
+    # insert_assert(exc_info.value.errors())
+    assert exc_info.value.errors() == [
+        {
+            'type': 'need_more',
+            'loc': (),
+            'msg': 'need 2 items, got 1',
+            'input': [1],
+            'ctx': {'field_type': 'List', 'min_length': 2, 'actual_length': 1},
+        }
+    ]
+
+
+def test_fail_fast(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'list', 'items_schema': {'type': 'int'}, 'fail_fast': True})
+    assert v.validate_test([1, '2']) == [1, 2]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([1, 'x', 'y', 4, 'z'])
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': (1,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+
+def test_fail_fast_config():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()), {'fail_fast': True})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['a', 'b'])
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', (0,))]
+
+    # the schema takes precedence over config
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), fail_fast=False), {'fail_fast': True})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['a', 'b'])
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', (0,)), ('int_parsing', (1,))]
+
+
+def test_fail_fast_generator():
+    consumed = []
+
+    def gen():
+        for item in [1, 'x', 3, 'y']:
+            consumed.append(item)
+            yield item
+
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), fail_fast=True))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(gen())
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', (1,))]
+    # the generator isn't consumed past the first error
+    assert consumed == [1, 'x']
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        # an invalid item before the list is too long
+        ([1, 'x', 3, 4], ('int_parsing', (1,))),
+        # the list is too long before an invalid item
+        ([1, 2, 3, 4, 'x'], ('too_long', ())),
+    ],
+)
+def test_fail_fast_max_length(input_value, expected):
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), max_length=2, fail_fast=True))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(iter(input_value))
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [expected]
+
+
+class IntSubclass(int):
+    pass
+
+
+class StrSubclass(str):
+    pass
+
+
+def validate_items_separately(items_schema, items, strict):
+    """
+    the output and errors from validating each item on its own, for comparison with validating them in a list
+    """
+    item_validator = SchemaValidator(items_schema)
+    output, errors = [], []
+    for index, item in enumerate(items):
+        try:
+            output.append(item_validator.validate_python(item, strict=strict))
+        except ValidationError as e:
+            errors.extend({**error, 'loc': (index, *error['loc'])} for error in e.errors())
+    return output, errors
+
+
+@pytest.mark.parametrize(
+    'items_schema,items',
+    [
+        (core_schema.int_schema(), [1, -2, 2**63 - 1]),
+        (core_schema.int_schema(), [1, 2**64, True, IntSubclass(3), '4', 5.0, 5.5, None, 6]),
+        (core_schema.float_schema(), [1.5, -0.0, float('inf'), float('nan')]),
+        (core_schema.float_schema(), [1.5, 2, True, '3.5', 'x', None, 4.5]),
+        (core_schema.float_schema(allow_inf_nan=False), [1.5, float('inf'), -float('inf'), float('nan'), 2]),
+        (core_schema.str_schema(), ['a', '', 'b']),
+        (core_schema.str_schema(), ['a', StrSubclass('b'), b'c', 1, None, 'd']),
+        (core_schema.bool_schema(), [True, False]),
+        (core_schema.bool_schema(), [True, 1, 0, 'yes', 2, None, False]),
+        (core_schema.bool_schema(coerce=lambda v: not v if isinstance(v, bool) else None), [True, False]),
+    ],
+)
+@pytest.mark.parametrize('strict', [False, True])
+def test_primitive_items(items_schema, items, strict):
+    # items which already have the output type skip the item validator, the output and errors are unchanged
+    expected_output, expected_errors = validate_items_separately(items_schema, items, strict)
+    for schema, output_type in (
+        (core_schema.list_schema(items_schema), list),
+        (core_schema.tuple_variable_schema(items_schema), tuple),
+    ):
+        v = SchemaValidator(schema)
+        if expected_errors:
+            with pytest.raises(ValidationError) as exc_info:
+                v.validate_python(output_type(items), strict=strict)
+            assert exc_info.value.errors() == expected_errors
+        else:
+            output = v.validate_python(output_type(items), strict=strict)
+            assert type(output) is output_type
+            # compared by repr since NaN isn't equal to itself
+            assert repr(list(output)) == repr(expected_output)
+            assert [type(item) for item in output] == [type(item) for item in expected_output]
+
+
+@pytest.mark.parametrize(
+    'items_schema,input_json,expected',
+    [
+        (core_schema.int_schema(), '[1, -2, 9223372036854775807]', [1, -2, 9223372036854775807]),
+        (core_schema.float_schema(), '[1.5, 2, -0.0]', [1.5, 2.0, -0.0]),
+        (core_schema.str_schema(), '["a", "", "b"]', ['a', '', 'b']),
+        (core_schema.bool_schema(), '[true, false]', [True, False]),
+        (core_schema.int_schema(), '[1, 2.0, "3", true]', [1, 2, 3, 1]),
+        (core_schema.bool_schema(), '[true, 0, "yes"]', [True, False, True]),
+        (
+            core_schema.int_schema(),
+            '[1, 2.5, "x", 4]',
+            Err(
+                '2 validation errors for list[int]',
+                [
+                    {
+                        'type': 'int_from_float',
+                        'loc': (1,),
+                        'msg': 'Input should be a valid integer, got a number with a fractional part',
+                        'input': 2.5,
+                    },
+                    {
+                        'type': 'int_parsing',
+                        'loc': (2,),
+                        'msg': 'Input should be a valid integer, unable to parse string as an integer',
+                        'input': 'x',
+                    },
+                ],
+            ),
+        ),
+        (
+            core_schema.str_schema(strict=True),
+            '["a", 1]',
+            Err(
+                '1 validation error for list[str]',
+                [{'type': 'string_type', 'loc': (1,), 'msg': 'Input should be a valid string', 'input': 1}],
+            ),
+        ),
+    ],
+)
+def test_primitive_items_json(items_schema, input_json, expected):
+    v = SchemaValidator(core_schema.list_schema(items_schema))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)) as exc_info:
+            v.validate_json(input_json)
+        assert exc_info.value.errors() == expected.errors
+    else:
+        output = v.validate_json(input_json)
+        assert output == expected
+        assert [type(item) for item in output] == [type(item) for item in expected]
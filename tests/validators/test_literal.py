@@ -1,5 +1,5 @@
 import re
-from enum import Enum
+from enum import Enum, IntEnum
 
 import pytest
 
@@ -200,9 +200,12 @@ def test_enum():
         foo = 'foo_value'
 
     v = SchemaValidator(core_schema.literal_schema([FooEnum.foo]))
-    assert v.validate_python(FooEnum.foo) == FooEnum.foo
+    assert v.validate_python(FooEnum.foo) is FooEnum.foo
+    # the member's value is accepted in lax mode, the member itself is returned
+    assert v.validate_python('foo_value') is FooEnum.foo
+    assert v.validate_json('"foo_value"') is FooEnum.foo
     with pytest.raises(ValidationError) as exc_info:
-        v.validate_python('foo_value')
+        v.validate_python('foo_value', strict=True)
     # insert_assert(exc_info.value.errors())
     assert exc_info.value.errors() == [
         {
@@ -213,3 +216,231 @@ def test_enum():
             'ctx': {'expected': "<FooEnum.foo: 'foo_value'>"},
         }
     ]
+    with pytest.raises(ValidationError, match='literal_error'):
+        v.validate_python('foo')
+
+
+def test_int_enum():
+    class IntFoo(IntEnum):
+        a = 1
+        b = 2
+
+    v = SchemaValidator(core_schema.literal_schema([IntFoo.a, 3]))
+    assert v.validate_python(IntFoo.a) is IntFoo.a
+    assert v.validate_python(1) is IntFoo.a
+    assert v.validate_python(3) == 3
+    assert v.validate_python(IntFoo.a, strict=True) is IntFoo.a
+    for input_value in (1, IntFoo.b, 2):
+        with pytest.raises(ValidationError, match='literal_error'):
+            v.validate_python(input_value, strict=True)
+
+
+def test_str_enum():
+    class StrFoo(str, Enum):
+        a = 'a_value'
+
+    v = SchemaValidator(core_schema.literal_schema([StrFoo.a, 'b']))
+    assert v.validate_python(StrFoo.a) is StrFoo.a
+    assert v.validate_python('a_value') is StrFoo.a
+    assert v.validate_python('b') == 'b'
+    with pytest.raises(ValidationError, match=re.escape("Input should be <StrFoo.a: 'a_value'> or 'b'")):
+        v.validate_python('a_value', strict=True)
+
+
+def test_str_subclass():
+    class StrFoo(str, Enum):
+        a = 'a'
+
+    # str subclasses are compared by value, not by their hash
+    v = SchemaValidator(core_schema.literal_schema(['a', 'b']))
+    assert v.validate_python(StrFoo.a) is StrFoo.a
+
+
+def test_general_hashable():
+    v = SchemaValidator(core_schema.literal_schema([1.5, (1, 2), b'x', None, [3]]))
+    assert v.validate_python(1.5) == 1.5
+    assert v.validate_python((1, 2)) == (1, 2)
+    assert v.validate_python(b'x') == b'x'
+    assert v.validate_python(None) is None
+    # unhashable values are still compared by equality
+    assert v.validate_python([3]) == [3]
+    for input_value in (2.5, (1, 3), {}, [4]):
+        with pytest.raises(ValidationError, match='literal_error'):
+            v.validate_python(input_value)
+
+
+def test_large_literal():
+    expected = [f'value_{i}' for i in range(10_000)]
+    v = SchemaValidator(core_schema.literal_schema(expected))
+    assert v.validate_python('value_9999') == 'value_9999'
+    assert v.validate_json('"value_5000"') == 'value_5000'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('value_10000')
+    assert exc_info.value.errors()[0]['ctx'] == {
+        'expected': ', '.join(f"'value_{i}'" for i in range(20)) + ' …and 9980 more'
+    }
+
+    v = SchemaValidator(
+        core_schema.union_schema([core_schema.literal_schema(list(range(100))), core_schema.none_schema()])
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(100)
+    assert exc_info.value.errors()[0]['loc'] == (f"literal[{','.join(map(str, range(20)))},…and 80 more]",)
+
+
+def test_expected_dedupe():
+    v = SchemaValidator(core_schema.literal_schema(['c', 'a', 'c', 'b', 'a']))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x')
+    # insert_assert(exc_info.value.errors())
+    assert exc_info.value.errors() == [
+        {
+            'type': 'literal_error',
+            'loc': (),
+            'msg': "Input should be 'c', 'a' or 'b'",
+            'input': 'x',
+            'ctx': {'expected': "'c', 'a' or 'b'"},
+        }
+    ]
+
+
+@pytest.mark.parametrize(
+    'expected,config,message',
+    [
+        (
+            list(range(20)),
+            None,
+            'Input should be 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18 or 19',
+        ),
+        (
+            list(range(25)),
+            None,
+            'Input should be 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19 …and 5 more',
+        ),
+        (list(range(5)), {'error_expected_max_items': 2}, 'Input should be 0, 1 …and 3 more'),
+        ([3, 2, 3, 1], {'error_expected_max_items': 3}, 'Input should be 3, 2 or 1'),
+        (['a', 'b', 'c', None], {'error_expected_max_items': 1}, "Input should be 'a' …and 3 more"),
+        ([1, 'a', None], {'error_expected_max_items': 2}, "Input should be 1, 'a' …and 1 more"),
+    ],
+)
+def test_expected_truncated(expected, config, message):
+    v = SchemaValidator(core_schema.literal_schema(expected), config)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(100)
+    assert exc_info.value.errors()[0]['msg'] == message
+    assert 'expected_full' not in exc_info.value.errors()[0]['ctx']
+
+
+def test_expected_full():
+    v = SchemaValidator(
+        core_schema.literal_schema(['a', 'b', 'c', 'a']), {'error_expected_max_items': 1, 'error_expected_full': True}
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x')
+    # insert_assert(exc_info.value.errors())
+    assert exc_info.value.errors() == [
+        {
+            'type': 'literal_error',
+            'loc': (),
+            'msg': "Input should be 'a' …and 2 more",
+            'input': 'x',
+            'ctx': {'expected': "'a' …and 2 more", 'expected_full': "'a', 'b' or 'c'"},
+        }
+    ]
+
+
+def test_expected_max_items_invalid():
+    with pytest.raises(SchemaError, match='`error_expected_max_items` must be greater than 0'):
+        SchemaValidator(core_schema.literal_schema(['a', 'b']), {'error_expected_max_items': 0})
+
+
+def test_allowed_values_factory():
+    allowed = {'a', 'b'}
+    calls = []
+
+    def factory():
+        calls.append(1)
+        return sorted(allowed)
+
+    v = SchemaValidator(core_schema.literal_schema(allowed_values_factory=factory))
+    assert v.validate_python('a') == 'a'
+    assert v.validate_json('"b"') == 'b'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('c')
+    # insert_assert(exc_info.value.errors())
+    assert exc_info.value.errors() == [
+        {
+            'type': 'literal_error',
+            'loc': (),
+            'msg': "Input should be 'a' or 'b'",
+            'input': 'c',
+            'ctx': {'expected': "'a' or 'b'"},
+        }
+    ]
+    assert len(calls) == 3
+
+    # the allowed values are checked each time
+    allowed.add('c')
+    assert v.validate_python('c') == 'c'
+    allowed.clear()
+    with pytest.raises(ValidationError, match=re.escape("Input should be  [type=literal_error, input_value='a'")):
+        v.validate_python('a')
+
+
+@pytest.mark.parametrize(
+    'allowed,input_value,expected',
+    [
+        ((1, 2), 2, 2),
+        ((1, 2), '2', '2'),
+        ((1, 'x', None), None, None),
+        ((1, 'x', None), 'x', 'x'),
+        (
+            iter([1, 'x']),
+            3,
+            Err("Input should be 1 or 'x' [type=literal_error, input_value=3, input_type=int]"),
+        ),
+        ({'x': 1}, 'x', 'x'),
+    ],
+)
+def test_allowed_values_factory_iterables(allowed, input_value, expected):
+    v = SchemaValidator(core_schema.literal_schema(allowed_values_factory=lambda: allowed))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_allowed_values_factory_errors():
+    v = SchemaValidator(core_schema.literal_schema(allowed_values_factory=lambda: 42))
+    with pytest.raises(TypeError, match='`allowed_values_factory` should return an iterable, got `int`'):
+        v.validate_python(1)
+
+    def factory():
+        raise RuntimeError('flags unavailable')
+
+    v = SchemaValidator(core_schema.literal_schema(allowed_values_factory=factory))
+    with pytest.raises(RuntimeError, match='flags unavailable'):
+        v.validate_python(1)
+
+
+def test_allowed_values_factory_in_list():
+    v = SchemaValidator(
+        core_schema.list_schema(core_schema.literal_schema(allowed_values_factory=lambda: ['on', 'off'])),
+        {'error_expected_max_items': 1},
+    )
+    assert v.validate_python(['on', 'off']) == ['on', 'off']
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['on', 'maybe'])
+    assert exc_info.value.errors(include_context=False) == [
+        {'type': 'literal_error', 'loc': (1,), 'msg': "Input should be 'on' …and 1 more", 'input': 'maybe'}
+    ]
+
+
+def test_allowed_values_factory_build_errors():
+    with pytest.raises(SchemaError, match='exactly one of "expected" or "allowed_values_factory" should be provided'):
+        SchemaValidator({'type': 'literal'})
+    with pytest.raises(SchemaError, match='exactly one of "expected" or "allowed_values_factory" should be provided'):
+        SchemaValidator(core_schema.literal_schema(['a'], allowed_values_factory=lambda: ['a']))
+    with pytest.raises(SchemaError, match='`error_expected_max_items` must be greater than 0'):
+        SchemaValidator(core_schema.literal_schema(allowed_values_factory=list), {'error_expected_max_items': 0})
@@ -0,0 +1,142 @@
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import plain_repr
+
+name_schema = core_schema.typed_dict_schema(
+    {
+        'name': core_schema.typed_dict_field(core_schema.str_schema()),
+        'kind': core_schema.typed_dict_field(core_schema.str_schema(to_upper=True)),
+    },
+    extra_behavior='ignore',
+)
+age_schema = core_schema.typed_dict_schema(
+    {
+        'age': core_schema.typed_dict_field(core_schema.int_schema()),
+        'kind': core_schema.typed_dict_field(core_schema.str_schema()),
+    },
+    extra_behavior='ignore',
+)
+
+
+def test_merge(py_and_json):
+    v = py_and_json(core_schema.merge_schema([name_schema, age_schema]))
+    assert v.validate_test({'name': 'Alice', 'age': '42', 'kind': 'cat'}) == {'name': 'Alice', 'age': 42, 'kind': 'cat'}
+
+
+@pytest.mark.parametrize('conflict,expected_kind', [(None, 'cat'), ('last', 'cat'), ('first', 'CAT')])
+def test_conflict(conflict, expected_kind):
+    v = SchemaValidator(core_schema.merge_schema([name_schema, age_schema], conflict=conflict))
+    assert v.validate_python({'name': 'Alice', 'age': 42, 'kind': 'cat'}) == {
+        'name': 'Alice',
+        'age': 42,
+        'kind': expected_kind,
+    }
+
+
+def test_conflict_error():
+    v = SchemaValidator(core_schema.merge_schema([name_schema, age_schema], conflict='error'))
+    # equal values don't conflict
+    assert v.validate_python({'name': 'Alice', 'age': 42, 'kind': 'CAT'}) == {'name': 'Alice', 'age': 42, 'kind': 'CAT'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'name': 'Alice', 'age': 42, 'kind': 'cat'})
+    assert exc_info.value.errors() == [
+        {
+            'type': 'merge_conflict',
+            'loc': ('kind',),
+            'msg': "Merged schemas returned different values for key 'kind'",
+            'input': {'name': 'Alice', 'age': 42, 'kind': 'cat'},
+            'ctx': {'key': 'kind'},
+        }
+    ]
+
+
+def test_errors_aggregated():
+    v = SchemaValidator(core_schema.merge_schema([name_schema, core_schema.int_schema(), age_schema]))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'name': 'Alice', 'age': 'x'})
+    assert exc_info.value.errors() == [
+        {
+            'type': 'missing',
+            'loc': ('typed-dict', 'kind'),
+            'msg': 'Field required',
+            'input': {'name': 'Alice', 'age': 'x'},
+        },
+        {
+            'type': 'int_type',
+            'loc': ('int',),
+            'msg': 'Input should be a valid integer',
+            'input': {'name': 'Alice', 'age': 'x'},
+        },
+        {
+            'type': 'int_parsing',
+            'loc': ('typed-dict', 'age'),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+        {
+            'type': 'missing',
+            'loc': ('typed-dict', 'kind'),
+            'msg': 'Field required',
+            'input': {'name': 'Alice', 'age': 'x'},
+        },
+    ]
+
+
+def test_models():
+    class Named:
+        pass
+
+    class Aged:
+        pass
+
+    v = SchemaValidator(
+        core_schema.merge_schema(
+            [core_schema.model_schema(Named, name_schema), core_schema.model_schema(Aged, age_schema)]
+        )
+    )
+    assert v.validate_python({'name': 'Alice', 'age': 42, 'kind': 'cat'}) == {'name': 'Alice', 'age': 42, 'kind': 'cat'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'name': 'Alice', 'kind': 'cat'})
+    assert exc_info.value.errors() == [
+        {'type': 'missing', 'loc': ('Aged', 'age'), 'msg': 'Field required', 'input': {'name': 'Alice', 'kind': 'cat'}}
+    ]
+
+
+def test_tuple_output():
+    fields_set_schema = core_schema.typed_dict_schema(
+        {'age': core_schema.typed_dict_field(core_schema.int_schema())}, extra_behavior='ignore', return_fields_set=True
+    )
+    args_schema = core_schema.dataclass_args_schema(
+        'Foo', [core_schema.dataclass_field('kind', core_schema.str_schema())]
+    )
+    v = SchemaValidator(core_schema.merge_schema([name_schema, fields_set_schema, args_schema]))
+    assert v.validate_python({'name': 'Alice', 'age': '42', 'kind': 'cat'}) == {
+        'name': 'Alice',
+        'age': 42,
+        'kind': 'cat',
+    }
+
+
+def test_invalid_output():
+    v = SchemaValidator(core_schema.merge_schema([core_schema.int_schema(), name_schema]))
+    with pytest.raises(TypeError, match='merge schema `int` must return a dict, a tuple starting with a dict or an'):
+        v.validate_python(1)
+
+
+def test_repr():
+    v = SchemaValidator(core_schema.merge_schema([name_schema, age_schema]))
+    assert plain_repr(v).startswith('SchemaValidator(title="merge[typed-dict,typed-dict]"')
+
+
+def test_no_schemas():
+    with pytest.raises(SchemaError, match='One or more merge schemas required'):
+        SchemaValidator(core_schema.merge_schema([]))
+
+
+def test_invalid_conflict():
+    with pytest.raises(SchemaError, match="Input should be 'first', 'last' or 'error'"):
+        SchemaValidator({'type': 'merge', 'schemas': [name_schema], 'conflict': 'other'})
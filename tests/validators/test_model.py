@@ -1007,6 +1007,36 @@ def test_validate_assignment():
     assert m.__pydantic_fields_set__ == {'field_a', 'field_b'}
 
 
+def test_field_names_shared():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__'
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.typed_dict_schema(
+                {
+                    'field_a': core_schema.typed_dict_field(core_schema.str_schema()),
+                    'field_b': core_schema.typed_dict_field(core_schema.int_schema()),
+                },
+                return_fields_set=True,
+            ),
+        )
+    )
+
+    m1 = v.validate_python({'field_a': 'a', 'field_b': 1})
+    m2 = v.validate_json('{"field_a": "b", "field_b": 2}')
+    assert m1.__dict__ == {'field_a': 'a', 'field_b': 1}
+    assert m2.__dict__ == {'field_a': 'b', 'field_b': 2}
+    # the field names are created once when building the validator and reused for every instance
+    assert all(k1 is k2 for k1, k2 in zip(m1.__dict__, m2.__dict__))
+    assert {id(k) for k in m1.__pydantic_fields_set__} == {id(k) for k in m2.__dict__}
+
+    v.validate_assignment(m1, ''.join(['field', '_b']), '3')
+    assert m1.__dict__ == {'field_a': 'a', 'field_b': 3}
+    assert all(k1 is k2 for k1, k2 in zip(m1.__dict__, m2.__dict__))
+
+
 def test_validate_assignment_function():
     class MyModel:
         # this is not required, but it avoids `__pydantic_fields_set__` being included in `__dict__`
@@ -1190,3 +1220,523 @@ def test_validate_assignment_model_validator_function(function_schema: Any, call
     assert m.b == 2
     assert m.__pydantic_fields_set__ == {'a', 'b'}
     assert calls == [call1, call2]
+
+
+def test_wrap_model_class_info():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__'
+
+    calls = []
+
+    def f(input_value, handler, info: core_schema.ModelValidationInfo):
+        calls.append(info.model_class)
+        if input_value == 'cached':
+            return cached
+        return handler(input_value)
+
+    v = SchemaValidator(
+        core_schema.general_wrap_validator_function(
+            f,
+            core_schema.model_schema(
+                MyModel,
+                core_schema.typed_dict_schema(
+                    {'a': core_schema.typed_dict_field(core_schema.int_schema())},
+                    return_fields_set=True,
+                    extra_behavior='allow',
+                ),
+            ),
+        )
+    )
+    m = v.validate_python({'a': '1', 'b': 2})
+    assert isinstance(m, MyModel)
+    assert m.__dict__ == {'a': 1, 'b': 2}
+    assert m.__pydantic_fields_set__ == {'a', 'b'}
+    assert calls == [MyModel]
+
+    # returning an instance of the class skips field validation
+    cached = MyModel()
+    cached.__dict__.update(a='not an int')
+    assert v.validate_python('cached') is cached
+
+
+def test_wrap_model_class_info_not_model():
+    def f(input_value, handler, info):
+        with pytest.raises(AttributeError, match="No attribute named 'model_class'"):
+            info.model_class
+        return handler(input_value)
+
+    v = SchemaValidator(core_schema.general_wrap_validator_function(f, core_schema.int_schema()))
+    assert v.validate_python('1') == 1
+
+
+def test_wrap_model_returned_instance_revalidate():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__'
+
+    def f(input_value, handler, info):
+        if isinstance(input_value, dict):
+            return handler(input_value)
+        instance = MyModel()
+        instance.__dict__.update(a=input_value)
+        instance.__pydantic_fields_set__ = {'a'}
+        return instance
+
+    v = SchemaValidator(
+        core_schema.general_wrap_validator_function(
+            f,
+            core_schema.model_schema(
+                MyModel,
+                core_schema.typed_dict_schema(
+                    {'a': core_schema.typed_dict_field(core_schema.int_schema())}, return_fields_set=True
+                ),
+                revalidate_instances='always',
+            ),
+        )
+    )
+    assert v.validate_python({'a': '1'}).__dict__ == {'a': 1}
+    # instances returned by the wrap function are revalidated since `revalidate_instances='always'`
+    m = v.validate_python('2')
+    assert m.__dict__ == {'a': 2}
+    assert m.__pydantic_fields_set__ == {'a'}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x')
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': ('a',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+
+def test_wrap_model_error_after_handler():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__'
+
+    handlers = []
+
+    def f(input_value, handler, info):
+        handlers.append(handler)
+        handler(input_value)
+        raise ValueError('rejected')
+
+    v = SchemaValidator(
+        core_schema.general_wrap_validator_function(
+            f,
+            core_schema.model_schema(
+                MyModel,
+                core_schema.typed_dict_schema(
+                    {'a': core_schema.typed_dict_field(core_schema.int_schema())}, return_fields_set=True
+                ),
+            ),
+        )
+    )
+    m = MyModel()
+    with pytest.raises(ValidationError, match='Value error, rejected'):
+        v.validate_python({'a': 1}, self_instance=m)
+    # attributes set by the handler are removed again
+    assert m.__dict__ == {}
+    assert not hasattr(m, '__pydantic_fields_set__')
+
+    with pytest.raises(ValidationError, match='Value error, rejected'):
+        v.validate_python({'a': 1})
+    assert len(handlers) == 2
+
+
+def exclusive_groups_validator(*groups: core_schema.ExclusiveGroup) -> SchemaValidator:
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__'
+
+    fields = {
+        name: core_schema.typed_dict_field(core_schema.with_default_schema(core_schema.str_schema(), default=None))
+        for name in ('email', 'phone', 'fax', 'pager')
+    }
+    return SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.typed_dict_schema(fields, return_fields_set=True),
+            exclusive_groups=list(groups),
+        )
+    )
+
+
+def test_exclusive_groups_at_most_one():
+    v = exclusive_groups_validator(core_schema.exclusive_group(['email', 'phone'], name='contact'))
+    assert v.validate_python({}).__pydantic_fields_set__ == set()
+    assert v.validate_python({'email': 'a@b.c', 'fax': '1'}).__pydantic_fields_set__ == {'email', 'fax'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'email': 'a@b.c', 'phone': '123'})
+    assert exc_info.value.errors() == [
+        {
+            'type': 'exclusive_group_conflict',
+            'loc': (),
+            'msg': "At most one field of group 'contact' should be set, got 'email', 'phone'",
+            'input': {'email': 'a@b.c', 'phone': '123'},
+            'ctx': {'group': 'contact', 'fields': "'email', 'phone'"},
+        }
+    ]
+
+
+def test_exclusive_groups_exactly_one():
+    v = exclusive_groups_validator(
+        core_schema.exclusive_group(['email', 'phone'], mode='exactly_one'),
+        core_schema.exclusive_group(['fax', 'pager'], mode='exactly_one', name='legacy'),
+    )
+    m = v.validate_python({'phone': '123', 'pager': '456'})
+    assert m.__dict__ == {'email': None, 'phone': '123', 'fax': None, 'pager': '456'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        # an empty value still counts as set
+        v.validate_python({'email': '', 'phone': '123', 'fax': '1', 'pager': '2'})
+    assert exc_info.value.errors() == [
+        {
+            'type': 'exclusive_group_conflict',
+            'loc': (),
+            'msg': "At most one field of group 'email, phone' should be set, got 'email', 'phone'",
+            'input': {'email': '', 'phone': '123', 'fax': '1', 'pager': '2'},
+            'ctx': {'group': 'email, phone', 'fields': "'email', 'phone'"},
+        },
+        {
+            'type': 'exclusive_group_conflict',
+            'loc': (),
+            'msg': "At most one field of group 'legacy' should be set, got 'fax', 'pager'",
+            'input': {'email': '', 'phone': '123', 'fax': '1', 'pager': '2'},
+            'ctx': {'group': 'legacy', 'fields': "'fax', 'pager'"},
+        },
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'fax': '1'})
+    assert exc_info.value.errors() == [
+        {
+            'type': 'exclusive_group_missing',
+            'loc': (),
+            'msg': "Exactly one field of group 'email, phone' should be set, got none",
+            'input': {'fax': '1'},
+            'ctx': {'group': 'email, phone'},
+        }
+    ]
+
+
+def test_exclusive_groups_field_errors_first():
+    v = exclusive_groups_validator(core_schema.exclusive_group(['email', 'phone'], mode='exactly_one'))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'fax': 1})
+    # groups are only checked once the fields are valid
+    assert [e['type'] for e in exc_info.value.errors()] == ['string_type']
+
+
+def test_exclusive_groups_init_and_assignment():
+    v = exclusive_groups_validator(core_schema.exclusive_group(['email', 'phone'], name='contact'))
+    m = v.validate_python({'email': 'a@b.c'})
+
+    v.validate_assignment(m, 'fax', '1')
+    assert m.__pydantic_fields_set__ == {'email', 'fax'}
+
+    with pytest.raises(ValidationError, match="group 'contact' should be set, got 'email', 'phone'"):
+        v.validate_assignment(m, 'phone', '123')
+    assert m.phone is None
+    assert m.__pydantic_fields_set__ == {'email', 'fax'}
+
+    m2 = v.validate_python({'phone': '123'})
+    with pytest.raises(ValidationError, match="got 'email', 'phone'"):
+        v.validate_python({'email': 'a@b.c', 'phone': '123'}, self_instance=m2)
+
+
+def test_exclusive_groups_invalid():
+    class MyModel:
+        pass
+
+    with pytest.raises(SchemaError, match="'exclusive_groups' requires a schema which returns the fields set"):
+        SchemaValidator(
+            core_schema.model_schema(
+                MyModel,
+                core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.int_schema())}),
+                exclusive_groups=[core_schema.exclusive_group(['a', 'b'])],
+            )
+        )
+    with pytest.raises(SchemaError, match="Exclusive group 'a' should contain at least two fields"):
+        exclusive_groups_validator(core_schema.exclusive_group(['a']))
+
+
+class ExtraModel:
+    __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__'
+
+
+@pytest.fixture(name='extras_validator')
+def extras_validator_fixture():
+    return SchemaValidator(
+        core_schema.model_schema(
+            ExtraModel,
+            core_schema.typed_dict_schema(
+                {'field_a': core_schema.typed_dict_field(core_schema.str_schema(), validation_alias='FieldA')},
+                extra_behavior='allow',
+                extras_schema=core_schema.int_schema(),
+                return_fields_set=True,
+                return_extra=True,
+                populate_by_name=True,
+            ),
+        )
+    )
+
+
+def test_model_extra(extras_validator: SchemaValidator):
+    m = extras_validator.validate_python({'FieldA': 'x', 'b': '1'})
+    assert m.__dict__ == {'field_a': 'x'}
+    assert m.__pydantic_extra__ == {'b': 1}
+    assert m.__pydantic_fields_set__ == {'field_a', 'b'}
+
+    m = extras_validator.validate_json('{"field_a": "x", "c": 2}')
+    assert m.__dict__ == {'field_a': 'x'}
+    assert m.__pydantic_extra__ == {'c': 2}
+    assert m.__pydantic_fields_set__ == {'field_a', 'c'}
+
+
+def test_model_extra_errors(extras_validator: SchemaValidator):
+    with pytest.raises(ValidationError) as exc_info:
+        extras_validator.validate_json('{"FieldA": "x", "b": "wrong"}')
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': ('b',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'wrong',
+        }
+    ]
+
+
+def test_model_extra_assignment(extras_validator: SchemaValidator):
+    m = extras_validator.validate_python({'field_a': 'x', 'b': 1})
+
+    extras_validator.validate_assignment(m, 'c', '2')
+    assert m.__dict__ == {'field_a': 'x'}
+    assert m.__pydantic_extra__ == {'b': 1, 'c': 2}
+    assert m.__pydantic_fields_set__ == {'field_a', 'b', 'c'}
+
+    extras_validator.validate_assignment(m, 'field_a', 'y')
+    assert m.__dict__ == {'field_a': 'y'}
+    assert m.__pydantic_extra__ == {'b': 1, 'c': 2}
+
+    with pytest.raises(ValidationError) as exc_info:
+        extras_validator.validate_assignment(m, 'd', 'wrong')
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': ('d',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'wrong',
+        }
+    ]
+    assert m.__pydantic_extra__ == {'b': 1, 'c': 2}
+
+
+def test_model_extra_init_and_revalidate():
+    v = SchemaValidator(
+        core_schema.model_schema(
+            ExtraModel,
+            core_schema.typed_dict_schema(
+                {'field_a': core_schema.typed_dict_field(core_schema.str_schema())},
+                extra_behavior='allow',
+                return_fields_set=True,
+                return_extra=True,
+            ),
+            revalidate_instances='always',
+        )
+    )
+    m = ExtraModel()
+    v.validate_python({'field_a': 'x', 'b': 1}, self_instance=m)
+    assert m.__dict__ == {'field_a': 'x'}
+    assert m.__pydantic_extra__ == {'b': 1}
+
+    m2 = v.validate_python(m)
+    assert m2 is not m
+    assert m2.__dict__ == {'field_a': 'x'}
+    assert m2.__pydantic_extra__ == {'b': 1}
+    assert m2.__pydantic_fields_set__ == {'field_a', 'b'}
+
+
+def test_model_extra_ignored():
+    v = SchemaValidator(
+        core_schema.model_schema(
+            ExtraModel,
+            core_schema.typed_dict_schema(
+                {'field_a': core_schema.typed_dict_field(core_schema.str_schema())},
+                return_fields_set=True,
+                return_extra=True,
+            ),
+        )
+    )
+    m = v.validate_python({'field_a': 'x', 'b': 1})
+    assert m.__dict__ == {'field_a': 'x'}
+    assert m.__pydantic_extra__ is None
+
+
+def assignment_validator(extra_behavior: str) -> SchemaValidator:
+    return SchemaValidator(
+        core_schema.model_schema(
+            ExtraModel,
+            core_schema.typed_dict_schema(
+                {
+                    'field_a': core_schema.typed_dict_field(core_schema.int_schema(), frozen=True),
+                    'field_b': core_schema.typed_dict_field(core_schema.int_schema(), required=False),
+                },
+                extra_behavior=extra_behavior,
+                extras_schema=core_schema.int_schema() if extra_behavior == 'allow' else None,
+                return_fields_set=True,
+                return_extra=True,
+            ),
+        )
+    )
+
+
+@pytest.mark.parametrize('extra_behavior', ['allow', 'forbid', 'ignore'])
+def test_model_assignment_frozen_field(extra_behavior: str):
+    v = assignment_validator(extra_behavior)
+    m = v.validate_python({'field_a': 1})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment(m, 'field_a', 2)
+    assert exc_info.value.errors() == [
+        {'type': 'frozen_field', 'loc': ('field_a',), 'msg': 'Field is frozen', 'input': 2}
+    ]
+    assert m.__dict__ == {'field_a': 1}
+    assert m.__pydantic_fields_set__ == {'field_a'}
+
+    v.validate_assignment(m, 'field_b', '3')
+    assert m.__dict__ == {'field_a': 1, 'field_b': 3}
+    assert m.__pydantic_fields_set__ == {'field_a', 'field_b'}
+
+
+@pytest.mark.parametrize('extra_behavior', ['forbid', 'ignore'])
+def test_model_assignment_no_such_attribute(extra_behavior: str):
+    v = assignment_validator(extra_behavior)
+    m = v.validate_python({'field_a': 1, 'field_b': 2})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment(m, 'field_c', 3)
+    assert exc_info.value.errors() == [
+        {
+            'type': 'no_such_attribute',
+            'loc': ('field_c',),
+            'msg': "Object has no attribute 'field_c'",
+            'input': 3,
+            'ctx': {'attribute': 'field_c'},
+        }
+    ]
+    assert m.__dict__ == {'field_a': 1, 'field_b': 2}
+    assert m.__pydantic_fields_set__ == {'field_a', 'field_b'}
+    assert m.__pydantic_extra__ is None
+
+
+def test_model_assignment_extra():
+    v = assignment_validator('allow')
+    m = v.validate_python({'field_a': 1, 'x': 1, 'y': 2})
+    assert m.__pydantic_fields_set__ == {'field_a', 'x', 'y'}
+
+    v.validate_assignment(m, 'x', '3')
+    assert m.__dict__ == {'field_a': 1}
+    assert m.__pydantic_extra__ == {'x': 3, 'y': 2}
+
+    v.validate_assignment(m, 'z', '4')
+    assert m.__pydantic_extra__ == {'x': 3, 'y': 2, 'z': 4}
+    assert m.__pydantic_fields_set__ == {'field_a', 'x', 'y', 'z'}
+
+    # instances created without extra values get them on assignment
+    m2 = ExtraModel()
+    m2.__dict__.update(field_a=1)
+    m2.__pydantic_fields_set__ = {'field_a'}
+    m2.__pydantic_extra__ = None
+    v.validate_assignment(m2, 'z', '5')
+    assert m2.__dict__ == {'field_a': 1}
+    assert m2.__pydantic_extra__ == {'z': 5}
+    assert m2.__pydantic_fields_set__ == {'field_a', 'z'}
+
+
+def to_camel(name: str) -> str:
+    first, *rest = name.split('_')
+    return first + ''.join(word.capitalize() for word in rest)
+
+
+class AliasModel:
+    pass
+
+
+def test_alias_generator():
+    calls = []
+
+    def alias_generator(name: str) -> str:
+        calls.append(name)
+        return to_camel(name)
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            AliasModel,
+            core_schema.typed_dict_schema(
+                {
+                    'field_a': core_schema.typed_dict_field(core_schema.int_schema()),
+                    'field_b': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias='FieldB'),
+                }
+            ),
+            alias_generator=alias_generator,
+        )
+    )
+    assert calls == ['field_a']
+    m = v.validate_python({'fieldA': 1, 'FieldB': 2})
+    assert m.__dict__ == {'field_a': 1, 'field_b': 2}
+    m = v.validate_json('{"fieldA": 3, "FieldB": 4}')
+    assert m.__dict__ == {'field_a': 3, 'field_b': 4}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'field_a': 1, 'FieldB': 2})
+    assert exc_info.value.errors() == [
+        {'type': 'missing', 'loc': ('fieldA',), 'msg': 'Field required', 'input': {'field_a': 1, 'FieldB': 2}}
+    ]
+    assert calls == ['field_a']
+
+
+def test_alias_generator_populate_by_name():
+    v = SchemaValidator(
+        core_schema.model_schema(
+            AliasModel,
+            core_schema.typed_dict_schema(
+                {'field_a': core_schema.typed_dict_field(core_schema.int_schema())}, populate_by_name=True
+            ),
+            alias_generator=to_camel,
+        )
+    )
+    assert v.validate_python({'fieldA': 1}).__dict__ == {'field_a': 1}
+    assert v.validate_python({'field_a': 2}).__dict__ == {'field_a': 2}
+
+
+def test_alias_generator_collision():
+    with pytest.raises(SchemaError, match="Alias 'fieldA' of field 'fieldA' is also the alias of field 'field_a'"):
+        SchemaValidator(
+            core_schema.model_schema(
+                AliasModel,
+                core_schema.typed_dict_schema(
+                    {
+                        'field_a': core_schema.typed_dict_field(core_schema.int_schema()),
+                        'fieldA': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias='fieldA'),
+                    }
+                ),
+                alias_generator=to_camel,
+            )
+        )
+
+
+def test_alias_generator_not_str():
+    with pytest.raises(SchemaError, match="`alias_generator` must return a str, got `int` for field 'field_a'"):
+        SchemaValidator(
+            core_schema.model_schema(
+                AliasModel,
+                core_schema.typed_dict_schema({'field_a': core_schema.typed_dict_field(core_schema.int_schema())}),
+                alias_generator=lambda name: 1,
+            )
+        )
+
+
+def test_alias_generator_not_typed_dict():
+    with pytest.raises(SchemaError, match='`alias_generator` requires `schema` to be a typed-dict schema'):
+        SchemaValidator(core_schema.model_schema(AliasModel, core_schema.any_schema(), alias_generator=to_camel))
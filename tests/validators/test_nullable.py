@@ -1,6 +1,8 @@
 import pytest
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+from ..conftest import PyAndJson
 
 
 def test_nullable():
@@ -33,3 +35,75 @@ def test_union_nullable_bool_int():
     assert v.validate_python(None) is None
     assert v.validate_python(True) is True
     assert v.validate_python(1) == 1
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [('null', None), ('NULL', None), ('', None), ('123', 123), (' null', 'error'), ('None', 'error'), (None, None)],
+)
+def test_none_strings(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.nullable_schema(core_schema.int_schema()), {'none_strings': ['null', 'NULL', '']})
+    if expected == 'error':
+        with pytest.raises(ValidationError, match='int_parsing'):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_none_strings_strip_whitespace():
+    v = SchemaValidator(
+        core_schema.nullable_schema(core_schema.int_schema()),
+        {'none_strings': ['null', ''], 'str_strip_whitespace': True},
+    )
+    assert v.validate_python(' null ') is None
+    assert v.validate_python('  ') is None
+    assert v.validate_python('1') == 1
+
+
+def test_none_strings_strict():
+    v = SchemaValidator(core_schema.nullable_schema(core_schema.int_schema()), {'none_strings': ['null']})
+    assert v.validate_python(None, strict=True) is None
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python('null', strict=True)
+
+    v = SchemaValidator(core_schema.nullable_schema(core_schema.int_schema(), strict=True), {'none_strings': ['null']})
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python('null')
+
+
+def test_none_strings_str():
+    schema = core_schema.typed_dict_schema(
+        {
+            'name': core_schema.typed_dict_field(core_schema.nullable_schema(core_schema.str_schema())),
+            'age': core_schema.typed_dict_field(core_schema.nullable_schema(core_schema.int_schema())),
+        }
+    )
+    v = SchemaValidator(schema, {'none_strings': ['null']})
+    assert v.validate_python({'name': 'null', 'age': 'null'}) == {'name': 'null', 'age': None}
+
+    v = SchemaValidator(schema, {'none_strings': ['null'], 'none_strings_apply_to_str': True})
+    assert v.validate_python({'name': 'null', 'age': 'null'}) == {'name': None, 'age': None}
+
+
+def test_none_strings_not_set():
+    v = SchemaValidator(core_schema.nullable_schema(core_schema.int_schema()))
+    with pytest.raises(ValidationError, match='int_parsing'):
+        v.validate_python('')
+    v = SchemaValidator(core_schema.nullable_schema(core_schema.int_schema()), {'none_strings_apply_to_str': True})
+    with pytest.raises(ValidationError, match='int_parsing'):
+        v.validate_python('')
+
+
+def test_nested_nullable_merged():
+    schema = core_schema.nullable_schema(core_schema.nullable_schema(core_schema.int_schema()))
+    v = SchemaValidator(schema)
+    assert v.title == 'nullable[int]'
+    assert v.validate_python(None) is None
+    assert v.validate_python('1') == 1
+
+    v = SchemaValidator(schema, {'merge_validators': False})
+    assert v.title == 'nullable[nullable[int]]'
+
+    v = SchemaValidator(schema, {'none_strings': ['null']})
+    assert v.title == 'nullable[int]'
+    assert v.validate_python('null') is None
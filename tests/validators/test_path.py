@@ -0,0 +1,195 @@
+import os
+import re
+from pathlib import Path, PurePath, PurePosixPath, PureWindowsPath
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
+
+
+class MyPathLike:
+    def __init__(self, path):
+        self.path = path
+
+    def __fspath__(self):
+        return self.path
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('/foo/bar.txt', Path('/foo/bar.txt')),
+        (b'/foo/bar.txt', Path('/foo/bar.txt')),
+        (Path('foo/bar'), Path('foo/bar')),
+        (PurePosixPath('foo/bar'), Path('foo/bar')),
+        (MyPathLike('foo/bar'), Path('foo/bar')),
+        (MyPathLike(b'foo/bar'), Path('foo/bar')),
+        (123, Err('Input should be a valid path [type=path_type, input_value=123, input_type=int]')),
+        (b'\x81', Err('Input should be a valid path [type=path_type,')),
+    ],
+)
+def test_path(input_value, expected):
+    v = SchemaValidator(core_schema.path_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert isinstance(output, Path)
+
+
+def test_path_json():
+    v = SchemaValidator(core_schema.path_schema())
+    assert v.validate_json('"/foo/bar.txt"') == Path('/foo/bar.txt')
+    with pytest.raises(ValidationError, match='Input should be a valid path'):
+        v.validate_json('123')
+
+
+def test_path_instance_unchanged():
+    v = SchemaValidator(core_schema.path_schema())
+    p = Path('foo/bar')
+    assert v.validate_python(p) is p
+
+
+@pytest.mark.parametrize(
+    'flavor,expected_type',
+    [(None, Path), ('native', Path), ('posix', PurePosixPath), ('windows', PureWindowsPath)],
+)
+def test_flavor(flavor, expected_type):
+    v = SchemaValidator(core_schema.path_schema(flavor=flavor))
+    output = v.validate_python('foo/bar')
+    assert type(output) is type(expected_type('foo/bar'))
+    assert output.parts == ('foo', 'bar')
+
+
+def test_windows_flavor():
+    v = SchemaValidator(core_schema.path_schema(flavor='windows'))
+    output = v.validate_python('C:\\foo\\bar.txt')
+    assert output == PureWindowsPath('C:/foo/bar.txt')
+    assert output.parts == ('C:\\', 'foo', 'bar.txt')
+
+
+def test_strict():
+    v = SchemaValidator(core_schema.path_schema(flavor='posix', strict=True))
+    assert v.validate_python(PurePosixPath('foo/bar')) == PurePosixPath('foo/bar')
+    assert v.validate_python(Path('foo/bar')) == PurePosixPath('foo/bar')
+    assert v.validate_json('"foo/bar"') == PurePosixPath('foo/bar')
+
+    for input_value in ('foo/bar', b'foo/bar', MyPathLike('foo/bar')):
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(input_value)
+        assert exc_info.value.errors() == [
+            {'type': 'path_type', 'loc': (), 'msg': 'Input should be a valid path', 'input': input_value}
+        ]
+
+
+def test_strict_from_validate():
+    v = SchemaValidator(core_schema.path_schema())
+    assert v.validate_python('foo') == Path('foo')
+    with pytest.raises(ValidationError, match='Input should be a valid path'):
+        v.validate_python('foo', strict=True)
+
+
+@pytest.mark.parametrize(
+    'kwargs,input_value,expected',
+    [
+        ({'absolute': True}, '/foo/bar', PurePosixPath('/foo/bar')),
+        ({'absolute': True}, 'foo/bar', Err('Path should be absolute [type=path_not_absolute,')),
+        ({'absolute': False}, 'foo/bar', PurePosixPath('foo/bar')),
+        ({'suffix_in': ['.txt', '.csv']}, 'foo/bar.csv', PurePosixPath('foo/bar.csv')),
+        ({'suffix_in': ['.txt', '.csv']}, 'foo/bar.tar.txt', PurePosixPath('foo/bar.tar.txt')),
+        (
+            {'suffix_in': ['.txt', '.csv']},
+            'foo/bar.json',
+            Err("Path suffix should be '.txt' or '.csv' [type=path_suffix,"),
+        ),
+        ({'suffix_in': ['.txt', '']}, 'foo/bar', PurePosixPath('foo/bar')),
+        ({'max_parts': 3}, '/foo/bar', PurePosixPath('/foo/bar')),
+        ({'max_parts': 2}, '/foo/bar', Err('Path should have at most 2 parts [type=path_too_many_parts,')),
+    ],
+)
+def test_constraints(py_and_json: PyAndJson, kwargs, input_value, expected):
+    v = py_and_json(core_schema.path_schema(flavor='posix', **kwargs))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_constraint_ctx():
+    v = SchemaValidator(core_schema.path_schema(suffix_in=['.txt'], max_parts=2))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('foo.csv')
+    assert exc_info.value.errors() == [
+        {
+            'type': 'path_suffix',
+            'loc': (),
+            'msg': "Path suffix should be '.txt'",
+            'input': 'foo.csv',
+            'ctx': {'expected_suffixes': "'.txt'"},
+        }
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('foo/bar/spam.txt')
+    assert exc_info.value.errors() == [
+        {
+            'type': 'path_too_many_parts',
+            'loc': (),
+            'msg': 'Path should have at most 2 parts',
+            'input': 'foo/bar/spam.txt',
+            'ctx': {'max_parts': 2},
+        }
+    ]
+
+
+def test_must_exist(tmp_path: Path):
+    file_path = tmp_path / 'foo.txt'
+    file_path.write_text('hello')
+    missing_path = tmp_path / 'missing'
+
+    v = SchemaValidator(core_schema.path_schema(must_exist='file'))
+    assert v.validate_python(str(file_path)) == file_path
+    with pytest.raises(ValidationError, match=r'Path does not point to a file \[type=path_not_file,'):
+        v.validate_python(tmp_path)
+
+    v = SchemaValidator(core_schema.path_schema(must_exist='dir'))
+    assert v.validate_python(str(tmp_path)) == tmp_path
+    with pytest.raises(ValidationError, match=r'Path does not point to a directory \[type=path_not_directory,'):
+        v.validate_python(file_path)
+
+    v = SchemaValidator(core_schema.path_schema(must_exist='any'))
+    assert v.validate_python(file_path) == file_path
+    assert v.validate_python(tmp_path) == tmp_path
+    with pytest.raises(ValidationError, match=r'Path does not exist \[type=path_not_exists,'):
+        v.validate_python(missing_path)
+
+
+def test_must_exist_pure_path(tmp_path: Path):
+    v = SchemaValidator(core_schema.path_schema(flavor='posix', must_exist='dir'))
+    output = v.validate_python(os.fspath(tmp_path))
+    assert isinstance(output, PurePath)
+    assert not isinstance(output, Path)
+
+
+def test_name():
+    assert repr(SchemaValidator(core_schema.path_schema())).startswith('SchemaValidator(title="path"')
+    v = SchemaValidator(core_schema.path_schema(suffix_in=['.txt', '.csv']))
+    assert repr(v).startswith('SchemaValidator(title="path[\'.txt\',\'.csv\']"')
+
+
+@pytest.mark.parametrize(
+    'schema,error',
+    [
+        ({'type': 'path', 'flavor': 'mac'}, "Input should be 'native', 'posix' or 'windows'"),
+        ({'type': 'path', 'must_exist': 'socket'}, "Input should be 'file', 'dir' or 'any'"),
+        ({'type': 'path', 'suffix_in': []}, '"suffix_in" should have length > 0'),
+    ],
+)
+def test_invalid_schema(schema, error):
+    with pytest.raises(SchemaError, match=re.escape(error)):
+        SchemaValidator(schema)
@@ -1,5 +1,6 @@
 import re
 from collections import deque
+from itertools import cycle
 from typing import Any, Dict
 
 import pytest
@@ -104,6 +105,22 @@ def test_set_multiple_errors():
     ]
 
 
+@pytest.mark.parametrize('input_value', [[1, 1, 'x', 2], (1, 1, 'x', 2), deque([1, 1, 'x', 2]), iter([1, 1, 'x', 2])])
+def test_set_error_location(input_value):
+    # the location is the index in the input, duplicates before the bad item don't change it
+    v = SchemaValidator({'type': 'set', 'items_schema': {'type': 'int'}})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': (2,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+
 def generate_repeats():
     for i in 1, 2, 3:
         yield i
@@ -138,10 +155,17 @@ def generate_repeats():
         # length check after set creation
         ({'max_length': 3}, [1, 1, 2, 2, 3, 3], {1, 2, 3}),
         ({'max_length': 3}, generate_repeats(), {1, 2, 3}),
-        # because of default max_length * 10
+        # the length is checked as items are added, so a generator of distinct items stops at max_length
         (
             {'max_length': 3},
             infinite_generator(),
+            Err('Set should have at most 3 items after validation, not 4 [type=too_long,'),
+        ),
+        # duplicates don't count towards max_length, but every item read counts towards the default
+        # generator_max_length of max_length * 10
+        (
+            {'max_length': 3},
+            cycle([1, 2, 3]),
             Err('Set should have at most 30 items after validation, not 31 [type=too_long,'),
         ),
         (
@@ -243,6 +267,20 @@ def test_generator_error():
         v.validate_python(gen(True))
 
 
+def test_range():
+    v = SchemaValidator({'type': 'set', 'items_schema': {'type': 'int'}})
+    assert v.validate_python(range(3)) == {0, 1, 2}
+
+    v = SchemaValidator({'type': 'set', 'strict': True})
+    with pytest.raises(ValidationError, match='type=set_type,'):
+        v.validate_python(range(3))
+
+    v = SchemaValidator({'type': 'set', 'max_length': 3})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(range(10**12))
+    assert exc_info.value.errors()[0]['ctx'] == {'field_type': 'Set', 'max_length': 3, 'actual_length': 10**12}
+
+
 @pytest.mark.parametrize(
     'input_value,items_schema,expected',
     [
@@ -283,3 +321,95 @@ def test_set_any(input_value, expected):
     output = v.validate_python(input_value)
     assert output == expected
     assert isinstance(output, set)
+
+
+def test_unhashable_items():
+    v = SchemaValidator({'type': 'set'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, [2], 3, {'a': 4}])
+    assert exc_info.value.errors() == [
+        {'type': 'unhashable', 'loc': (1,), 'msg': 'Input should be hashable', 'input': [2]},
+        {'type': 'unhashable', 'loc': (3,), 'msg': 'Input should be hashable', 'input': {'a': 4}},
+    ]
+
+
+def test_unhashable_validated_items():
+    v = SchemaValidator({'type': 'set', 'items_schema': {'type': 'list', 'items_schema': {'type': 'int'}}})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({(1, 2)})
+    assert exc_info.value.errors() == [
+        {'type': 'unhashable', 'loc': (0,), 'msg': 'Input should be hashable', 'input': [1, 2]}
+    ]
+
+
+def test_unhashable_after_omitted_items():
+    v = SchemaValidator(
+        {
+            'type': 'set',
+            'items_schema': {
+                'type': 'default',
+                'schema': {'type': 'union', 'choices': [{'type': 'int'}, {'type': 'list'}]},
+                'on_error': 'omit',
+            },
+        }
+    )
+    assert v.validate_python(['x', 1, 'y', 2]) == {1, 2}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['x', 1, 'y', [2]])
+    # the location is the index in the input, not the position after omitting items
+    assert exc_info.value.errors() == [
+        {'type': 'unhashable', 'loc': (3,), 'msg': 'Input should be hashable', 'input': [2]}
+    ]
+
+
+def test_hash_error():
+    class BadHash:
+        def __hash__(self):
+            raise ValueError('bad hash')
+
+    v = SchemaValidator({'type': 'set'})
+    # only TypeErrors mean a value is unhashable, other errors are raised as they are
+    with pytest.raises(ValueError, match='bad hash'):
+        v.validate_python([BadHash()])
+
+
+def test_fail_fast(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'set', 'items_schema': {'type': 'int'}, 'fail_fast': True})
+    assert v.validate_test([1, '2', 1]) == {1, 2}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([1, 'x', 'y'])
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', (1,))]
+
+
+def test_infinite_generator_items_schema():
+    v = SchemaValidator({'type': 'set', 'items_schema': {'type': 'int'}, 'max_length': 3})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(infinite_generator())
+    assert exc_info.value.errors()[0]['ctx'] == {'field_type': 'Set', 'max_length': 3, 'actual_length': 4}
+
+
+def test_generator_read_until_too_long():
+    consumed = []
+
+    def gen():
+        for item in [1, 1, '1', 2, 2, 3, 4, 5]:
+            consumed.append(item)
+            yield item
+
+    v = SchemaValidator({'type': 'set', 'items_schema': {'type': 'int'}, 'max_length': 2})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(gen())
+    assert exc_info.value.errors()[0]['ctx'] == {'field_type': 'Set', 'max_length': 2, 'actual_length': 3}
+    # duplicates after validation don't count, reading stops at the first item which makes the set too long
+    assert consumed == [1, 1, '1', 2, 2, 3]
+
+
+def test_validated_generator_input():
+    # the lazily validated generator returned by a generator schema is read incrementally too
+    items = SchemaValidator({'type': 'generator', 'items_schema': {'type': 'int'}}).validate_python(
+        infinite_generator()
+    )
+    v = SchemaValidator({'type': 'set', 'items_schema': {'type': 'int'}, 'max_length': 5})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(items)
+    assert exc_info.value.errors()[0]['ctx'] == {'field_type': 'Set', 'max_length': 5, 'actual_length': 6}
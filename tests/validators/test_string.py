@@ -151,17 +151,17 @@ def test_str_constrained_config():
 
 
 def test_invalid_regex():
-    # TODO uncomment and fix once #150 is done
-    # with pytest.raises(SchemaError) as exc_info:
-    #     SchemaValidator({'type': 'str', 'pattern': 123})
-    # assert exc_info.value.args[0] == (
-    #     'Error building "str" validator:\n  TypeError: \'int\' object cannot be converted to \'PyString\''
-    # )
+    with pytest.raises(SchemaError) as exc_info:
+        SchemaValidator({'type': 'str', 'pattern': 123})
+    assert exc_info.value.args[0] == (
+        'Error building "str" validator:\n  SchemaError: `pattern` must be a str or a compiled `re.Pattern`'
+    )
     with pytest.raises(SchemaError) as exc_info:
         SchemaValidator({'type': 'str', 'pattern': '(abc'})
     assert exc_info.value.args[0] == (
         'Error building "str" validator:\n'
-        '  SchemaError: regex parse error:\n'
+        "  SchemaError: Unable to compile pattern with regex_engine 'rust-regex':\n"
+        'regex parse error:\n'
         '    (abc\n'
         '    ^\n'
         'error: unclosed group'
@@ -183,9 +183,114 @@ def test_regex_error():
     ]
 
 
+def test_repeated_pattern():
+    # identical patterns share one compiled regex
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.str_schema(pattern=r'^\d+$')),
+                'b': core_schema.typed_dict_field(core_schema.str_schema(pattern=r'^\d+$', to_upper=True)),
+                'c': core_schema.typed_dict_field(core_schema.str_schema(pattern=r'^[a-z]+$')),
+            }
+        )
+    )
+    assert v.validate_python({'a': '1', 'b': '2', 'c': 'x'}) == {'a': '1', 'b': '2', 'c': 'x'}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x', 'b': 'y', 'c': '1'})
+    assert [(e['loc'], e['ctx']['pattern']) for e in exc_info.value.errors()] == [
+        (('a',), r'^\d+$'),
+        (('b',), r'^\d+$'),
+        (('c',), '^[a-z]+$'),
+    ]
+
+
+def test_regex_engine_rust_unsupported():
+    with pytest.raises(SchemaError, match="Unable to compile pattern with regex_engine 'rust-regex'") as exc_info:
+        SchemaValidator(core_schema.str_schema(pattern=r'(\w)\1'))
+    assert 'backreferences are not supported' in exc_info.value.args[0]
+
+
+@pytest.mark.parametrize('config', [None, {'regex_engine': 'python-re'}])
+def test_regex_engine_python_re(config):
+    schema = core_schema.str_schema(pattern=r'(?!x)(\w)\1')
+    if config is None:
+        schema['regex_engine'] = 'python-re'
+    v = SchemaValidator(schema, config)
+    assert v.validate_python('aab') == 'aab'
+    assert v.validate_python(b'bb') == 'bb'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('xx')
+    # insert_assert(exc_info.value.errors())
+    assert exc_info.value.errors() == [
+        {
+            'type': 'string_pattern_mismatch',
+            'loc': (),
+            'msg': "String should match pattern '(?!x)(\\w)\\1'",
+            'input': 'xx',
+            'ctx': {'pattern': r'(?!x)(\w)\1'},
+        }
+    ]
+
+
+def test_regex_engine_match_semantics():
+    # `re.Pattern.match` only matches at the start of the string, the rust engine matches anywhere
+    assert SchemaValidator(core_schema.str_schema(pattern=r'\d')).validate_python('a1') == 'a1'
+    v = SchemaValidator(core_schema.str_schema(pattern=r'\d', regex_engine='python-re'))
+    with pytest.raises(ValidationError, match='String should match pattern'):
+        v.validate_python('a1')
+
+
+def test_regex_engine_schema_over_config():
+    config = {'regex_engine': 'python-re'}
+    v = SchemaValidator(core_schema.str_schema(pattern='^a', regex_engine='rust-regex'), config)
+    assert v.validate_python('abc') == 'abc'
+    with pytest.raises(SchemaError, match="regex_engine 'rust-regex'"):
+        SchemaValidator(core_schema.str_schema(pattern='(?<=a)b', regex_engine='rust-regex'), config)
+
+
+def test_compiled_pattern():
+    v = SchemaValidator(core_schema.str_schema(pattern=re.compile('abc', re.IGNORECASE)))
+    assert v.validate_python('ABCd') == 'ABCd'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('xyz')
+    assert exc_info.value.errors()[0]['ctx'] == {'pattern': 'abc'}
+
+    v = SchemaValidator(core_schema.str_schema(pattern=re.compile(r'^\d+$'), regex_engine='python-re'))
+    assert v.validate_python('123') == '123'
+
+
+@pytest.mark.parametrize(
+    'schema,message',
+    [
+        (
+            core_schema.str_schema(pattern=re.compile('a'), regex_engine='rust-regex'),
+            "A compiled `re.Pattern` can only be used with regex_engine 'python-re'",
+        ),
+        (core_schema.str_schema(pattern=re.compile(b'a')), '`pattern` must be a str pattern, not a bytes pattern'),
+        (
+            core_schema.str_schema(pattern='(abc', regex_engine='python-re'),
+            "Unable to compile pattern with regex_engine 'python-re': error: missing \\), unterminated subpattern",
+        ),
+    ],
+)
+def test_regex_engine_schema_errors(schema, message):
+    with pytest.raises(SchemaError, match=message):
+        SchemaValidator(schema)
+
+
+def test_regex_engine_invalid():
+    with pytest.raises(SchemaError, match="Input should be 'rust-regex' or 'python-re'"):
+        SchemaValidator(core_schema.str_schema(pattern='a', regex_engine='pcre'))
+    with pytest.raises(SchemaError, match='Invalid regex_engine: `pcre`, expected `rust-regex` or `python-re`'):
+        SchemaValidator(core_schema.str_schema(pattern='a'), {'regex_engine': 'pcre'})
+
 def test_default_validator():
     v = SchemaValidator(core_schema.str_schema(strict=True, to_lower=False), {'str_strip_whitespace': False})
-    assert plain_repr(v) == 'SchemaValidator(title="str",validator=Str(StrValidator{strict:true}),slots=[])'
+    assert (
+        plain_repr(v)
+        == 'SchemaValidator(title="str",validator=StrictOverride(Str(StrValidator{'
+        'strict:true,coerce_hook:true})),slots=[])'
+    )
 
 
 @pytest.fixture(scope='session', name='FruitEnum')
@@ -227,3 +332,82 @@ def test_lax_subclass(FruitEnum, kwargs):
     assert p == 'pear'
     assert type(p) is str
     assert repr(p) == "'pear'"
+
+
+def test_error_overrides():
+    v = SchemaValidator(
+        core_schema.str_schema(
+            min_length=3,
+            max_length=5,
+            error_overrides={
+                'string_too_short': {'type': 'username_too_short', 'message': 'at least {min_length} characters'}
+            },
+        )
+    )
+    assert v.validate_python('abcd') == 'abcd'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('ab')
+    # insert_assert(exc_info.value.errors())
+    assert exc_info.value.errors() == [
+        {
+            'type': 'username_too_short',
+            'loc': (),
+            'msg': 'at least 3 characters',
+            'input': 'ab',
+            'ctx': {'min_length': 3},
+        }
+    ]
+    # errors without an override are unchanged
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('abcdef')
+    assert exc_info.value.errors()[0]['type'] == 'string_too_long'
+
+
+def test_error_overrides_unknown_type():
+    with pytest.raises(SchemaError, match="Invalid error type in error_overrides: 'not_an_error'"):
+        SchemaValidator(core_schema.str_schema(error_overrides={'not_an_error': {'type': 'foo', 'message': 'bar'}}))
+
+
+def normalize_phone(value: str) -> str:
+    digits = re.sub(r'[\s\-().]', '', value)
+    if not re.fullmatch(r'\+?\d{7,15}', digits):
+        raise ValueError('not a valid phone number')
+    return digits
+
+
+def test_post_validator(py_and_json: PyAndJson):
+    v = py_and_json(core_schema.str_schema(strip_whitespace=True, post_validator=normalize_phone))
+    assert v.validate_test(' +44 (20) 7946-0958 ') == '+442079460958'
+    assert v.validate_test('555.123.4567') == '5551234567'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test('call me')
+    # insert_assert(exc_info.value.errors())
+    assert exc_info.value.errors() == [
+        {
+            'type': 'value_error',
+            'loc': (),
+            'msg': 'Value error, not a valid phone number',
+            'input': 'call me',
+            'ctx': {'error': 'not a valid phone number'},
+        }
+    ]
+
+
+def test_post_validator_after_checks():
+    calls = []
+
+    def post_validator(value: str) -> str:
+        calls.append(value)
+        return value
+
+    v = SchemaValidator(core_schema.str_schema(strip_whitespace=True, max_length=5, post_validator=post_validator))
+    assert v.validate_python('  abc  ') == 'abc'
+    with pytest.raises(ValidationError, match='String should have at most 5 characters'):
+        v.validate_python('abcdefg')
+    assert calls == ['abc']
+
+
+def test_post_validator_invalid_return():
+    v = SchemaValidator(core_schema.str_schema(post_validator=lambda s: 123))
+    with pytest.raises(TypeError, match='post_validator must return a str, got int'):
+        v.validate_python('abc')
@@ -2,7 +2,7 @@ from enum import Enum
 
 import pytest
 
-from pydantic_core import SchemaError, SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson
 from .test_typed_dict import Cls
@@ -277,6 +277,97 @@ def test_discriminator_path(py_and_json: PyAndJson):
     ]
 
 
+@pytest.fixture(scope='module')
+def nested_path_schema():
+    return {
+        'type': 'tagged-union',
+        'discriminator': [['payload', 'meta', 'kind'], ['type'], ['items', 0]],
+        'choices': {
+            'cat': {
+                'type': 'typed-dict',
+                'fields': {'name': {'type': 'typed-dict-field', 'schema': {'type': 'str'}}},
+            },
+            'dog': {
+                'type': 'typed-dict',
+                'fields': {'bark': {'type': 'typed-dict-field', 'schema': {'type': 'bool'}}},
+            },
+        },
+    }
+
+
+def test_discriminator_nested_path(py_and_json: PyAndJson, nested_path_schema):
+    v = py_and_json(nested_path_schema)
+    input_value = {'payload': {'meta': {'kind': 'cat'}}, 'name': 'Tom'}
+    assert v.validate_test(input_value) == {'name': 'Tom'}
+    assert input_value == {'payload': {'meta': {'kind': 'cat'}}, 'name': 'Tom'}
+    assert v.validate_test({'type': 'dog', 'bark': 'true'}) == {'bark': True}
+    assert v.validate_test({'items': ['dog', 'cat'], 'bark': False}) == {'bark': False}
+    # a missing intermediate key falls through to the next path
+    assert v.validate_test({'payload': {}, 'type': 'cat', 'name': 'Tom'}) == {'name': 'Tom'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'payload': {'meta': {}}, 'items': []})
+    assert exc_info.value.errors() == [
+        {
+            'type': 'union_tag_not_found',
+            'loc': (),
+            'msg': (
+                "Unable to extract tag using discriminator 'payload'.'meta'.'kind' | 'type' | 'items'.0"
+            ),
+            'input': {'payload': {'meta': {}}, 'items': []},
+            'ctx': {'discriminator': "'payload'.'meta'.'kind' | 'type' | 'items'.0"},
+        }
+    ]
+
+
+@pytest.mark.parametrize(
+    'tag,tag_repr', [([1], '[1]'), (None, 'None'), (1.5, '1.5'), ({'a': 1}, "{'a': 1}")], ids=repr
+)
+def test_discriminator_path_invalid_tag(py_and_json: PyAndJson, nested_path_schema, tag, tag_repr):
+    v = py_and_json(nested_path_schema)
+    input_value = {'payload': {'meta': {'kind': tag}}}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test(input_value)
+    assert exc_info.value.errors() == [
+        {
+            'type': 'union_tag_invalid',
+            'loc': (),
+            'msg': (
+                f"Input tag '{tag_repr}' found using 'payload'.'meta'.'kind' | 'type' | 'items'.0 "
+                "does not match any of the expected tags: 'cat', 'dog'"
+            ),
+            'input': input_value,
+            'ctx': {
+                'discriminator': "'payload'.'meta'.'kind' | 'type' | 'items'.0",
+                'tag': tag_repr,
+                'expected_tags': "'cat', 'dog'",
+            },
+        }
+    ]
+
+
+def test_discriminator_path_attributes(nested_path_schema):
+    v = SchemaValidator(nested_path_schema, {'from_attributes': True})
+
+    class Meta:
+        kind = 'dog'
+
+    class Payload:
+        meta = Meta()
+
+    class Pet:
+        payload = Payload()
+        bark = 'yes'
+
+    assert v.validate_python(Pet()) == {'bark': True}
+
+    class ListPet:
+        items = ('cat',)
+        name = 'Tom'
+
+    assert v.validate_python(ListPet()) == {'name': 'Tom'}
+
+
 @pytest.mark.parametrize(
     'input_value,expected',
     [
@@ -611,13 +702,13 @@ def test_custom_error_type():
                         'loc': (),
                         'msg': (
                             "Input tag 'wrong' found using 'food' does not match any of the expected tags: "
-                            "'apple', 'banana', 1, 'cherry', 'durian', 2"
+                            "'apple', 'banana', 'cherry', 'durian', 1, 2"
                         ),
                         'input': {'food': 'wrong'},
                         'ctx': {
                             'discriminator': "'food'",
                             'tag': 'wrong',
-                            'expected_tags': "'apple', 'banana', 1, 'cherry', 'durian', 2",
+                            'expected_tags': "'apple', 'banana', 'cherry', 'durian', 1, 2",
                         },
                     }
                 ],
@@ -687,3 +778,228 @@ def test_tag_repeated_invalid():
                 },
             }
         )
+
+
+def test_expected_tags_truncated():
+    v = SchemaValidator(
+        core_schema.tagged_union_schema(
+            choices={
+                'c': core_schema.typed_dict_schema({}),
+                'a': core_schema.typed_dict_schema({}),
+                'b': 'a',
+                'd': 'c',
+            },
+            discriminator='kind',
+        ),
+        {'error_expected_max_items': 2, 'error_expected_full': True},
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'kind': 'x'})
+    # insert_assert(exc_info.value.errors())
+    assert exc_info.value.errors() == [
+        {
+            'type': 'union_tag_invalid',
+            'loc': (),
+            'msg': "Input tag 'x' found using 'kind' does not match any of the expected tags: 'c', 'a' …and 2 more",
+            'input': {'kind': 'x'},
+            'ctx': {
+                'discriminator': "'kind'",
+                'tag': 'x',
+                'expected_tags': "'c', 'a' …and 2 more",
+                'expected_full': "'c', 'a', 'b', 'd'",
+            },
+        }
+    ]
+
+
+def pets_schema(**kwargs):
+    return core_schema.tagged_union_schema(
+        discriminator='pet_type',
+        choices={
+            'cat': core_schema.typed_dict_schema(
+                {
+                    'pet_type': core_schema.typed_dict_field(core_schema.str_schema()),
+                    'meows': core_schema.typed_dict_field(core_schema.int_schema()),
+                }
+            ),
+            'kitten': 'cat',
+            'dog': core_schema.typed_dict_schema(
+                {
+                    'pet_type': core_schema.typed_dict_field(core_schema.str_schema()),
+                    'barks': core_schema.typed_dict_field(core_schema.float_schema()),
+                }
+            ),
+            'puppy': 'dog',
+            'lizard': core_schema.typed_dict_schema(
+                {'pet_type': core_schema.typed_dict_field(core_schema.str_schema())}
+            ),
+        },
+        **kwargs,
+    )
+
+
+@pytest.mark.parametrize('config', [None, {'tagged_union_probe_all': False}])
+def test_probe_all_off(config):
+    v = SchemaValidator(pets_schema(), config)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'pet_type': 'cat', 'barks': 1.5})
+    assert exc_info.value.errors() == [
+        {
+            'type': 'missing',
+            'loc': ('cat', 'meows'),
+            'msg': 'Field required',
+            'input': {'pet_type': 'cat', 'barks': 1.5},
+        }
+    ]
+
+
+def test_probe_all(py_and_json: PyAndJson):
+    v = py_and_json(pets_schema(tagged_union_probe_all=True))
+    assert v.validate_test({'pet_type': 'cat', 'meows': '3'}) == {'pet_type': 'cat', 'meows': 3}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'pet_type': 'cat', 'barks': 1.5})
+    # 'kitten' uses the same schema as 'cat' so isn't tried, 'lizard' ignores the extra field
+    assert exc_info.value.errors() == [
+        {
+            'type': 'missing',
+            'loc': ('cat', 'meows'),
+            'msg': 'Field required',
+            'input': {'pet_type': 'cat', 'barks': 1.5},
+        },
+        {
+            'type': 'union_tag_other_matches',
+            'loc': (),
+            'msg': "Input is invalid for tag 'cat' but valid for other tags: 'dog', 'puppy', 'lizard'",
+            'input': {'pet_type': 'cat', 'barks': 1.5},
+            'ctx': {'tag': 'cat', 'matched_other_tags': ['dog', 'puppy', 'lizard']},
+        },
+    ]
+
+
+def test_probe_all_strict():
+    v = SchemaValidator(pets_schema(), {'tagged_union_probe_all': True, 'extra_fields_behavior': 'forbid'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'pet_type': 'dog', 'meows': '3'})
+    # other choices are tried in strict mode, so `'3'` isn't a valid int for 'cat'
+    assert [e['type'] for e in exc_info.value.errors()] == ['missing', 'extra_forbidden']
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'pet_type': 'kitten', 'barks': 1})
+    assert exc_info.value.errors()[-1]['ctx'] == {'tag': 'kitten', 'matched_other_tags': ['dog', 'puppy']}
+
+
+def version_discriminator(obj):
+    # the variant is implied by the keys present, or the range of `version`
+    if 'legacy_id' in obj:
+        return 'legacy'
+    version = obj.get('version')
+    if version is None:
+        return None
+    return 'v1' if version < 10 else 'v2'
+
+
+def versioned_schema():
+    return core_schema.tagged_union_schema(
+        choices={
+            'legacy': core_schema.typed_dict_schema(
+                {'legacy_id': core_schema.typed_dict_field(core_schema.int_schema())}
+            ),
+            'v1': core_schema.typed_dict_schema({'version': core_schema.typed_dict_field(core_schema.int_schema())}),
+            'v2': core_schema.typed_dict_schema(
+                {
+                    'version': core_schema.typed_dict_field(core_schema.int_schema()),
+                    'name': core_schema.typed_dict_field(core_schema.str_schema()),
+                }
+            ),
+        },
+        discriminator=version_discriminator,
+    )
+
+
+def test_discriminator_function_implicit(py_and_json: PyAndJson):
+    v = py_and_json(versioned_schema())
+    assert v.validate_test({'legacy_id': '1'}) == {'legacy_id': 1}
+    assert v.validate_test({'version': 3}) == {'version': 3}
+    assert v.validate_test({'version': 12, 'name': 'x'}) == {'version': 12, 'name': 'x'}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'version': 12})
+    assert exc_info.value.errors() == [
+        {'type': 'missing', 'loc': ('v2', 'name'), 'msg': 'Field required', 'input': {'version': 12}}
+    ]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({})
+    assert exc_info.value.errors() == [
+        {
+            'type': 'union_tag_not_found',
+            'loc': (),
+            'msg': 'Unable to extract tag using discriminator version_discriminator()',
+            'input': {},
+            'ctx': {'discriminator': 'version_discriminator()'},
+        }
+    ]
+
+
+def test_discriminator_function_raises():
+    def discriminator(obj):
+        if obj == 'value':
+            raise ValueError('bad input')
+        if obj == 'assert':
+            assert False, 'bad assert'
+        return obj['kind']
+
+    v = SchemaValidator(
+        core_schema.tagged_union_schema(choices={'a': core_schema.dict_schema()}, discriminator=discriminator)
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('value')
+    assert exc_info.value.errors() == [
+        {
+            'type': 'value_error',
+            'loc': (),
+            'msg': 'Value error, bad input',
+            'input': 'value',
+            'ctx': {'error': 'bad input'},
+        }
+    ]
+    with pytest.raises(ValidationError, match='Assertion failed, bad assert'):
+        v.validate_python('assert')
+    # other exceptions, e.g. the `TypeError` from indexing an int, mean there's no tag
+    for input_value in (1, {}):
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(input_value)
+        assert exc_info.value.errors()[0]['type'] == 'union_tag_not_found'
+
+
+def test_discriminator_function_invalid_tag():
+    v = SchemaValidator(
+        core_schema.tagged_union_schema(choices={'a': core_schema.any_schema()}, discriminator=lambda obj: obj)
+    )
+    assert v.validate_python('a') == 'a'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(1.5)
+    assert exc_info.value.errors() == [
+        {
+            'type': 'union_tag_invalid',
+            'loc': (),
+            'msg': "Input tag '1.5' found using <lambda>() does not match any of the expected tags: 'a'",
+            'input': 1.5,
+            'ctx': {'discriminator': '<lambda>()', 'tag': '1.5', 'expected_tags': "'a'"},
+        }
+    ]
+
+
+def test_discriminator_callable_object():
+    class Discriminator:
+        def __call__(self, obj):
+            return obj['kind']
+
+        def __repr__(self):
+            return 'Discriminator'
+
+    v = SchemaValidator(
+        core_schema.tagged_union_schema(choices={'a': core_schema.dict_schema()}, discriminator=Discriminator())
+    )
+    assert v.validate_python({'kind': 'a'}) == {'kind': 'a'}
+    with pytest.raises(ValidationError, match=r'Unable to extract tag using discriminator Discriminator\(\)'):
+        v.validate_python({})
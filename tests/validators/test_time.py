@@ -5,7 +5,7 @@ from typing import Any, Dict
 
 import pytest
 
-from pydantic_core import SchemaError, SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson
 
@@ -47,14 +47,7 @@ def test_time(input_value, expected):
         pytest.param('12:13:14.123', time(12, 13, 14, 123_000), id='str-micro'),
         pytest.param('12:13:14.123456', time(12, 13, 14, 123_456), id='str-micro-6dig'),
         pytest.param('12:13:14.123456', time(12, 13, 14, 123_456), id='str-micro-6dig'),
-        pytest.param(
-            '12:13:14.1234561',
-            Err(
-                'Input should be in a valid time format, '
-                'second fraction value is more than 6 digits long [type=time_parsing'
-            ),
-            id='str-micro-7dig',
-        ),
+        pytest.param('12:13:14.1234567', time(12, 13, 14, 123_456), id='str-micro-7dig'),
         pytest.param(123, time(0, 2, 3), id='int'),
         pytest.param(123.4, time(0, 2, 3, 400_000), id='float'),
         pytest.param(123.0, time(0, 2, 3), id='float.0'),
@@ -197,3 +190,30 @@ def test_union():
     v = SchemaValidator({'type': 'union', 'choices': [{'type': 'time'}, {'type': 'str'}]})
     assert v.validate_python('12:01:02') == '12:01:02'
     assert v.validate_python(time(12, 1, 2)) == time(12, 1, 2)
+
+
+@pytest.mark.parametrize(
+    'microseconds_precision,input_value,expected',
+    [
+        ('truncate', '12:13:14.123456789', time(12, 13, 14, 123_456)),
+        ('truncate', '12:13:14.9999999', time(12, 13, 14, 999_999)),
+        ('truncate', '12:13:14,1234567', time(12, 13, 14, 123_456)),
+        ('error', '12:13:14.123456', time(12, 13, 14, 123_456)),
+        (
+            'error',
+            '12:13:14.123456789',
+            Err(
+                'Input should have at most microsecond precision, got 9 fractional second digits '
+                '[type=datetime_microseconds_precision'
+            ),
+        ),
+        ('error', '12:13:1x.123456789', Err('Input should be in a valid time format')),
+    ],
+)
+def test_microseconds_precision(py_and_json: PyAndJson, microseconds_precision, input_value, expected):
+    v = py_and_json(core_schema.time_schema(microseconds_precision=microseconds_precision))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
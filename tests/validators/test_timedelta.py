@@ -5,7 +5,7 @@ from typing import Any, Dict
 
 import pytest
 
-from pydantic_core import SchemaError, SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaSerializer, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson
 
@@ -40,8 +40,8 @@ from ..conftest import Err, PyAndJson
         (Decimal('-3601.2222222'), timedelta(hours=-2, seconds=3598, microseconds=777778)),
         (Decimal('-3601.2222227'), timedelta(hours=-2, seconds=3598, microseconds=777777)),
         (float('nan'), Err('Input should be a valid timedelta, NaN values not permitted')),
-        (float('inf'), Err('Input should be a valid timedelta, durations may not exceed 999,999,999 days')),
-        (float('-inf'), Err('Input should be a valid timedelta, durations may not exceed 999,999,999 days')),
+        (float('inf'), Err('Input should be a valid timedelta, infinite values not permitted')),
+        (float('-inf'), Err('Input should be a valid timedelta, infinite values not permitted')),
         (timedelta.max, timedelta.max),
     ],
     ids=repr,
@@ -68,6 +68,8 @@ def test_timedelta(input_value, expected):
         ('-3601.222222', timedelta(hours=-2, seconds=3598, microseconds=777778)),
         ('-3601.2222222', timedelta(hours=-2, seconds=3598, microseconds=777778)),
         ('3600.999999', timedelta(seconds=3600, microseconds=999999)),
+        ('18446744073709551616', Err('durations may not exceed 999,999,999 days [type=time_delta_parsing')),
+        ('-18446744073709551616', Err('durations may not exceed 999,999,999 days [type=time_delta_parsing')),
     ],
     ids=repr,
 )
@@ -90,6 +92,8 @@ def test_timedelta_json(input_value, expected):
         ),
         ('P0Y0M3D2WT1H2M3.5S', Err('Input should be a valid timedelta [type=time_delta_type')),
         (b'P0Y0M3D2WT1H2M3.5S', Err('Input should be a valid timedelta [type=time_delta_type')),
+        (12, Err('Input should be a valid timedelta [type=time_delta_type')),
+        (12.5, Err('Input should be a valid timedelta [type=time_delta_type')),
     ],
 )
 def test_timedelta_strict(input_value, expected):
@@ -108,6 +112,8 @@ def test_timedelta_strict(input_value, expected):
         ('"P0Y0M3D2WT1H2M3.5S"', timedelta(days=3, weeks=2, hours=1, minutes=2, seconds=3, milliseconds=500)),
         ('"12345"', Err('Input should be a valid timedelta')),
         ('true', Err('Input should be a valid timedelta [type=time_delta_type')),
+        ('12', Err('Input should be a valid timedelta [type=time_delta_type')),
+        ('12.5', Err('Input should be a valid timedelta [type=time_delta_type')),
     ],
 )
 def test_timedelta_strict_json(input_value, expected):
@@ -148,6 +154,30 @@ def test_timedelta_strict_json(input_value, expected):
             '-PT86400.124S',
             Err('Input should be greater than -P1DT0.123S [type=greater_than'),
         ),
+        ({'ge': 'PT1H', 'le': 'P30D'}, 'PT1H', timedelta(hours=1)),
+        ({'ge': 'PT1H', 'le': 'P30D'}, timedelta(days=30), timedelta(days=30)),
+        ({'ge': 'PT1H', 'le': 'P30D'}, 'PT59M', Err('Input should be greater than or equal to PT3600S')),
+        ({'ge': 'PT1H', 'le': 'P30D'}, 'P30DT1S', Err('Input should be less than or equal to P30D')),
+        ({'lt': 60}, 59.999999, timedelta(seconds=59.999999)),
+        ({'lt': 60}, 60, Err('Input should be less than PT60S')),
+        ({'gt': -0.000001}, timedelta(0), timedelta(0)),
+        ({'gt': -0.000001}, '-PT0.000001S', Err('Input should be greater than -PT0.000001S')),
+        ({'multiple_of': timedelta(minutes=1)}, 'PT3M', timedelta(minutes=3)),
+        ({'multiple_of': timedelta(minutes=1)}, timedelta(minutes=-3), timedelta(minutes=-3)),
+        (
+            {'multiple_of': timedelta(minutes=1)},
+            'PT3M0.000001S',
+            Err('Input should be a multiple of PT60S [type=multiple_of'),
+        ),
+        ({'multiple_of': 'PT1M'}, timedelta(minutes=-3, microseconds=1), Err('Input should be a multiple of PT60S')),
+        ({'multiple_of': 0.000002}, timedelta(microseconds=-4), timedelta(microseconds=-4)),
+        ({'multiple_of': 0.000002}, '-PT0.000003S', Err('Input should be a multiple of PT0.000002S')),
+        (
+            {'multiple_of': timedelta(microseconds=7)},
+            timedelta(days=999_999_999, microseconds=7),
+            Err('Input should be a multiple of PT0.000007S'),
+        ),
+        ({'multiple_of': timedelta(days=3)}, timedelta(days=999_999_999), timedelta(days=999_999_999)),
     ],
     ids=repr,
 )
@@ -175,6 +205,27 @@ def test_invalid_constraint():
         SchemaValidator({'type': 'timedelta', 'le': 'foobar'})
 
 
+def test_multiple_of_ctx():
+    v = SchemaValidator(core_schema.timedelta_schema(multiple_of='PT15M'))
+    assert v.validate_python('PT45M') == timedelta(minutes=45)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('PT50M')
+    assert exc_info.value.errors() == [
+        {
+            'type': 'multiple_of',
+            'loc': (),
+            'msg': 'Input should be a multiple of PT900S',
+            'input': 'PT50M',
+            'ctx': {'multiple_of': 'PT900S'},
+        }
+    ]
+
+
+def test_multiple_of_zero():
+    with pytest.raises(SchemaError, match="'multiple_of' must not be a zero timedelta"):
+        SchemaValidator({'type': 'timedelta', 'multiple_of': timedelta(0)})
+
+
 def test_dict_py():
     v = SchemaValidator({'type': 'dict', 'keys_schema': {'type': 'timedelta'}, 'values_schema': {'type': 'int'}})
     assert v.validate_python({timedelta(days=2, hours=1): 2, timedelta(days=2, hours=2): 4}) == {
@@ -252,3 +303,56 @@ def test_large_value():
     assert v.validate_python(f'{999_999_999}days, 12:34') == timedelta(days=999_999_999, hours=12, minutes=34)
     with pytest.raises(ValidationError, match='should be a valid timedelta, durations may not exceed 999,999,999 days'):
         v.validate_python(f'{999_999_999 + 1}days, 12:34')
+
+
+@pytest.mark.parametrize(
+    'microseconds_precision,input_value,expected',
+    [
+        ('truncate', 'PT1.123456789S', timedelta(seconds=1, microseconds=123_456)),
+        ('truncate', 'PT0.9999999S', timedelta(microseconds=999_999)),
+        ('truncate', '12:00:00.1234567', timedelta(hours=12, microseconds=123_456)),
+        ('error', 'PT1.123456S', timedelta(seconds=1, microseconds=123_456)),
+        ('error', 'PT1.123456789S', Err('got 9 fractional second digits [type=datetime_microseconds_precision')),
+        ('error', '12:00:00.1234567', Err('got 7 fractional second digits [type=datetime_microseconds_precision')),
+    ],
+)
+def test_microseconds_precision(py_and_json: PyAndJson, microseconds_precision, input_value, expected):
+    v = py_and_json(core_schema.timedelta_schema(microseconds_precision=microseconds_precision))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('12.5', timedelta(seconds=12, microseconds=500_000)),
+        ('-0.5', timedelta(microseconds=-500_000)),
+        ('0.000001', timedelta(microseconds=1)),
+        ('1036834.000056', timedelta(days=12, seconds=34, microseconds=56)),
+    ],
+)
+def test_timedelta_float_seconds_json(input_value, expected):
+    v = SchemaValidator(core_schema.timedelta_schema())
+    assert v.validate_json(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'value',
+    [
+        timedelta(seconds=12, microseconds=500_000),
+        timedelta(microseconds=1),
+        timedelta(microseconds=-1),
+        timedelta(days=-3, seconds=5, microseconds=999_999),
+        timedelta(days=36_500, seconds=86_399, microseconds=123_457),
+        timedelta(days=-36_500, microseconds=999_999),
+    ],
+    ids=repr,
+)
+def test_seconds_float_round_trip(value):
+    s = SchemaSerializer(core_schema.timedelta_schema(), {'ser_json_timedelta': 'seconds_float'})
+    v = SchemaValidator(core_schema.timedelta_schema())
+    assert v.validate_json(s.to_json(value)) == value
+    assert v.validate_python(s.to_python(value, mode='json')) == value
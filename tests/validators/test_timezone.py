@@ -0,0 +1,76 @@
+import re
+from datetime import timedelta, timezone
+from zoneinfo import ZoneInfo
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('America/New_York', ZoneInfo('America/New_York')),
+        ('UTC', ZoneInfo('UTC')),
+        (timezone.utc, timezone.utc),
+        (timezone(timedelta(hours=2)), timezone(timedelta(hours=2))),
+        (ZoneInfo('Europe/London'), ZoneInfo('Europe/London')),
+        ('Foo/Bar', Err("Unknown timezone name 'Foo/Bar' [type=timezone_name, input_value='Foo/Bar', input_type=str]")),
+        ('/etc/passwd', Err("Unknown timezone name '/etc/passwd' [type=timezone_name,")),
+        (123, Err('Input should be a valid timezone [type=timezone_type, input_value=123, input_type=int]')),
+        (None, Err('Input should be a valid timezone [type=timezone_type,')),
+    ],
+)
+def test_timezone(input_value, expected):
+    v = SchemaValidator(core_schema.timezone_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+
+
+def test_timezone_passthrough():
+    v = SchemaValidator(core_schema.timezone_schema())
+    tz = timezone(timedelta(hours=-5), 'EST')
+    assert v.validate_python(tz) is tz
+
+
+def test_timezone_name_error():
+    v = SchemaValidator(core_schema.timezone_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('Mars/Olympus_Mons')
+    assert exc_info.value.errors() == [
+        {
+            'type': 'timezone_name',
+            'loc': (),
+            'msg': "Unknown timezone name 'Mars/Olympus_Mons'",
+            'input': 'Mars/Olympus_Mons',
+            'ctx': {'name': 'Mars/Olympus_Mons'},
+        }
+    ]
+
+
+def test_timezone_json(py_and_json: PyAndJson):
+    v = py_and_json(core_schema.timezone_schema())
+    assert v.validate_test('Asia/Tokyo') == ZoneInfo('Asia/Tokyo')
+    with pytest.raises(ValidationError, match=re.escape('Input should be a valid timezone [type=timezone_type,')):
+        v.validate_test(1)
+
+
+def test_timezone_strict():
+    v = SchemaValidator(core_schema.timezone_schema(strict=True))
+    assert v.validate_python(timezone.utc) is timezone.utc
+    with pytest.raises(ValidationError, match=re.escape('Input should be a valid timezone [type=timezone_type,')):
+        v.validate_python('UTC')
+    # JSON has no timezone type, so names are allowed in strict mode
+    assert v.validate_json('"UTC"') == ZoneInfo('UTC')
+
+
+def test_timezone_strict_override():
+    v = SchemaValidator(core_schema.timezone_schema())
+    with pytest.raises(ValidationError, match='type=timezone_type'):
+        v.validate_python('UTC', strict=True)
@@ -450,6 +450,31 @@ def test_generator_error():
         v.validate_python(gen(True))
 
 
+def test_range():
+    v = SchemaValidator({'type': 'tuple-variable', 'items_schema': {'type': 'int'}})
+    output = v.validate_python(range(3))
+    assert output == (0, 1, 2)
+    assert type(output) is tuple
+
+    v = SchemaValidator({'type': 'tuple-variable', 'strict': True})
+    with pytest.raises(ValidationError, match='type=tuple_type,'):
+        v.validate_python(range(3))
+
+
+def test_range_too_long():
+    v = SchemaValidator({'type': 'tuple-variable', 'max_length': 3})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(range(10**12))
+    assert exc_info.value.errors()[0]['ctx'] == {'field_type': 'Tuple', 'max_length': 3, 'actual_length': 10**12}
+
+    v = SchemaValidator({'type': 'tuple-positional', 'items_schema': [{'type': 'int'}, {'type': 'int'}]})
+    assert v.validate_python(range(2)) == (0, 1)
+    # without `extra_schema`, the number of positions is the maximum length
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(range(10**12))
+    assert exc_info.value.errors()[0]['ctx'] == {'field_type': 'Tuple', 'max_length': 2, 'actual_length': 10**12}
+
+
 @pytest.mark.parametrize(
     'input_value,items_schema,expected',
     [
@@ -496,3 +521,85 @@ def test_length_constraints_omit(input_value, expected):
             v.validate_python(input_value)
     else:
         assert v.validate_python(input_value) == expected
+
+
+@pytest.mark.parametrize('fill_missing', [None, True])
+def test_positional_fill_missing(py_and_json: PyAndJson, fill_missing):
+    schema = {
+        'type': 'tuple-positional',
+        'items_schema': [
+            {'type': 'str'},
+            {'type': 'int'},
+            {'type': 'default', 'schema': {'type': 'int'}, 'default': 0},
+            {'type': 'default', 'schema': {'type': 'str'}, 'default': ''},
+        ],
+    }
+    if fill_missing is not None:
+        schema['fill_missing'] = fill_missing
+    v = py_and_json(schema)
+    assert v.validate_test(['a', '1', '2', 'x']) == ('a', 1, 2, 'x')
+    assert v.validate_test(['a', '1', '2']) == ('a', 1, 2, '')
+    assert v.validate_test(['a', '1']) == ('a', 1, 0, '')
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test(['a'])
+    assert exc_info.value.errors() == [{'type': 'missing', 'loc': (1,), 'msg': 'Field required', 'input': ['a']}]
+    with pytest.raises(ValidationError, match=r'Tuple should have at most 4 items after validation, not 5'):
+        v.validate_test(['a', '1', '2', 'x', 'y'])
+
+
+def test_positional_fill_missing_extra(py_and_json: PyAndJson):
+    v = py_and_json(
+        {
+            'type': 'tuple-positional',
+            'items_schema': [{'type': 'int'}, {'type': 'default', 'schema': {'type': 'int'}, 'default': 0}],
+            'extra_schema': {'type': 'str'},
+        }
+    )
+    assert v.validate_test([1]) == (1, 0)
+    assert v.validate_test([1, 2, 'x', 'y']) == (1, 2, 'x', 'y')
+
+
+def test_positional_no_fill_missing(py_and_json: PyAndJson):
+    v = py_and_json(
+        {
+            'type': 'tuple-positional',
+            'items_schema': [{'type': 'int'}, {'type': 'default', 'schema': {'type': 'int'}, 'default': 0}],
+            'fill_missing': False,
+        }
+    )
+    assert v.validate_test([1, 2]) == (1, 2)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([1])
+    assert exc_info.value.errors() == [
+        {
+            'type': 'too_short',
+            'loc': (),
+            'msg': 'Tuple should have at least 2 items after validation, not 1',
+            'input': [1],
+            'ctx': {'field_type': 'Tuple', 'min_length': 2, 'actual_length': 1},
+        }
+    ]
+
+
+@pytest.mark.parametrize(
+    'schema',
+    [
+        {'type': 'tuple-variable', 'items_schema': {'type': 'int'}, 'fail_fast': True},
+        {
+            'type': 'tuple-positional',
+            'items_schema': [{'type': 'int'}, {'type': 'int'}],
+            'extra_schema': {'type': 'int'},
+            'fail_fast': True,
+        },
+    ],
+    ids=['variable', 'positional'],
+)
+@pytest.mark.parametrize(
+    'input_value,loc', [([1, 'x', 'y', 'z'], (1,)), ([1, 2, 3, 'x', 'y'], (3,))], ids=['items', 'extra']
+)
+def test_fail_fast(py_and_json: PyAndJson, schema, input_value, loc):
+    v = py_and_json(schema)
+    assert v.validate_test([1, '2', 3]) == (1, 2, 3)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test(input_value)
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', loc)]
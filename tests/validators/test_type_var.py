@@ -0,0 +1,146 @@
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import plain_repr
+
+# like `class Box(Generic[T]): content: T`
+box_schema = core_schema.typed_dict_schema(
+    {'content': core_schema.typed_dict_field(core_schema.type_var_schema('T'))}
+)
+
+
+def test_substitution():
+    v = SchemaValidator(core_schema.parametrized_schema(box_schema, {'T': core_schema.int_schema()}))
+    assert v.validate_python({'content': '1'}) == {'content': 1}
+    assert 'type-var' not in plain_repr(v)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'content': 'x'})
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': ('content',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+
+def test_reuse_generic_schema():
+    int_box = SchemaValidator(core_schema.parametrized_schema(box_schema, {'T': core_schema.int_schema()}))
+    str_box = SchemaValidator(core_schema.parametrized_schema(box_schema, {'T': core_schema.str_schema()}))
+    assert int_box.validate_python({'content': '1'}) == {'content': 1}
+    assert str_box.validate_python({'content': '1'}) == {'content': '1'}
+    with pytest.raises(ValidationError, match='Input should be a valid string'):
+        str_box.validate_python({'content': 1})
+
+
+def test_nested_substitution():
+    # like `class Pair(Generic[T]): items: List[Box[T]]` parametrized as `Pair[int]`
+    pair_schema = core_schema.typed_dict_schema(
+        {
+            'items': core_schema.typed_dict_field(
+                core_schema.list_schema(
+                    core_schema.parametrized_schema(box_schema, {'T': core_schema.type_var_schema('T')})
+                )
+            )
+        }
+    )
+    v = SchemaValidator(core_schema.parametrized_schema(pair_schema, {'T': core_schema.int_schema()}))
+    assert v.validate_python({'items': [{'content': '1'}, {'content': 2}]}) == {
+        'items': [{'content': 1}, {'content': 2}]
+    }
+
+
+def test_inner_type_vars_not_visible_to_substitutions():
+    schema = core_schema.parametrized_schema(
+        core_schema.parametrized_schema(
+            core_schema.tuple_positional_schema([core_schema.type_var_schema('T'), core_schema.type_var_schema('U')]),
+            {'T': core_schema.type_var_schema('U'), 'U': core_schema.str_schema()},
+        ),
+        {'U': core_schema.int_schema()},
+    )
+    v = SchemaValidator(schema)
+    assert v.validate_python(('1', 'x')) == (1, 'x')
+
+
+def test_recursive_generic():
+    # like `class Node(Generic[T]): value: T; children: List['Node[T]']`
+    node_schema = core_schema.typed_dict_schema(
+        {
+            'value': core_schema.typed_dict_field(core_schema.type_var_schema('T')),
+            'children': core_schema.typed_dict_field(
+                core_schema.list_schema(core_schema.definition_reference_schema('Node'))
+            ),
+        },
+        ref='Node',
+    )
+    v = SchemaValidator(core_schema.parametrized_schema(node_schema, {'T': core_schema.int_schema()}))
+    assert v.validate_python({'value': '1', 'children': [{'value': 2, 'children': []}]}) == {
+        'value': 1,
+        'children': [{'value': 2, 'children': []}],
+    }
+
+
+def test_unsubstituted():
+    with pytest.raises(SchemaError, match='Type variable `T` has not been substituted'):
+        SchemaValidator(box_schema)
+
+    schema = core_schema.parametrized_schema(box_schema, {'U': core_schema.int_schema()})
+    with pytest.raises(SchemaError, match='Type variable `T` has not been substituted'):
+        SchemaValidator(schema)
+
+
+def test_generic_ref_used_elsewhere():
+    generic_list = core_schema.list_schema(core_schema.type_var_schema('T'), ref='GenericList')
+    schema = core_schema.tuple_positional_schema(
+        [
+            core_schema.parametrized_schema(generic_list, {'T': core_schema.int_schema()}),
+            core_schema.parametrized_schema(
+                core_schema.definition_reference_schema('GenericList'), {'T': core_schema.str_schema()}
+            ),
+        ]
+    )
+    with pytest.raises(
+        SchemaError,
+        match='Schema with ref `GenericList` contains type variable `T`, it can only be referenced within itself',
+    ):
+        SchemaValidator(schema)
+
+
+def test_generic_definition():
+    node_schema = core_schema.typed_dict_schema(
+        {
+            'value': core_schema.typed_dict_field(core_schema.type_var_schema('T')),
+            'children': core_schema.typed_dict_field(
+                core_schema.list_schema(core_schema.definition_reference_schema('Node'))
+            ),
+        },
+        ref='Node',
+    )
+    schema = core_schema.definitions_schema(
+        core_schema.parametrized_schema(
+            core_schema.definition_reference_schema('Node'), {'T': core_schema.int_schema()}
+        ),
+        [node_schema],
+    )
+    with pytest.raises(SchemaError, match='Schema with ref `Node` contains type variable `T`'):
+        SchemaValidator(schema)
+
+
+def test_substituted_ref_used_elsewhere():
+    # type variables substituted within the schema don't depend on where it's used
+    int_list = core_schema.list_schema(
+        core_schema.parametrized_schema(core_schema.type_var_schema('T'), {'T': core_schema.int_schema()}),
+        ref='IntList',
+    )
+    schema = core_schema.tuple_positional_schema(
+        [
+            core_schema.parametrized_schema(int_list, {'T': core_schema.str_schema()}),
+            core_schema.parametrized_schema(
+                core_schema.definition_reference_schema('IntList'), {'T': core_schema.str_schema()}
+            ),
+        ]
+    )
+    v = SchemaValidator(schema)
+    assert v.validate_python((['1'], [2])) == ([1], [2])
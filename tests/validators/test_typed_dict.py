@@ -2,13 +2,20 @@ import math
 import re
 import sys
 from dataclasses import dataclass
-from datetime import datetime
+from datetime import date, datetime
 from typing import Any, Dict, List, Mapping, Union
 
 import pytest
 from dirty_equals import FunctionCheck, HasRepr, IsStr
 
-from pydantic_core import CoreConfig, SchemaError, SchemaValidator, ValidationError, core_schema
+from pydantic_core import (
+    CoreConfig,
+    PydanticCustomError,
+    SchemaError,
+    SchemaValidator,
+    ValidationError,
+    core_schema,
+)
 
 from ..conftest import Err, PyAndJson
 
@@ -201,6 +208,63 @@ def test_allow_extra_invalid():
         )
 
 
+def test_extras_schema_invalid():
+    with pytest.raises(SchemaError, match='extras_schema can only be used if extra_behavior=allow'):
+        SchemaValidator({'type': 'typed-dict', 'fields': {}, 'extras_schema': {'type': 'int'}})
+
+
+def test_extras_schema(py_and_json: PyAndJson):
+    v = py_and_json(
+        core_schema.typed_dict_schema(
+            {'field_a': core_schema.typed_dict_field(core_schema.str_schema())},
+            extra_behavior='allow',
+            extras_schema=core_schema.int_schema(),
+        )
+    )
+    assert v.validate_test({'field_a': 'x', 'b': '1', 'c': 2}) == {'field_a': 'x', 'b': 1, 'c': 2}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'field_a': 'x', 'b': 'wrong'})
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': ('b',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'wrong',
+        }
+    ]
+
+
+@pytest.mark.parametrize('return_fields_set', [False, True])
+def test_return_extra(py_and_json: PyAndJson, return_fields_set: bool):
+    v = py_and_json(
+        core_schema.typed_dict_schema(
+            {'field_a': core_schema.typed_dict_field(core_schema.str_schema(), validation_alias='FieldA')},
+            extra_behavior='allow',
+            extras_schema=core_schema.int_schema(),
+            return_fields_set=return_fields_set,
+            return_extra=True,
+            populate_by_name=True,
+        )
+    )
+    output = v.validate_test({'FieldA': 'x', 'b': '1'})
+    if return_fields_set:
+        assert output == ({'field_a': 'x'}, {'field_a', 'b'}, {'b': 1})
+    else:
+        assert output == ({'field_a': 'x'}, {'b': 1})
+    # with `populate_by_name` the field name isn't an extra key
+    assert v.validate_test({'field_a': 'x'})[-1] == {}
+
+
+def test_return_extra_not_allowed():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'field_a': core_schema.typed_dict_field(core_schema.str_schema())}, return_extra=True
+        )
+    )
+    assert v.validate_python({'field_a': 'x', 'b': 1}) == ({'field_a': 'x'}, None)
+
+
 def test_allow_extra_wrong():
     with pytest.raises(SchemaError, match='Invalid extra_behavior: `wrong`'):
         SchemaValidator({'type': 'typed-dict', 'fields': {}}, {'extra_fields_behavior': 'wrong'})
@@ -542,6 +606,45 @@ def test_field_required_and_default():
         )
 
 
+@pytest.mark.parametrize(
+    'field_schema',
+    [
+        core_schema.nullable_schema(core_schema.with_default_schema(core_schema.str_schema(), default='pika')),
+        core_schema.no_info_after_validator_function(
+            lambda v: v, core_schema.with_default_schema(core_schema.str_schema(), default='pika')
+        ),
+        core_schema.union_schema(
+            [
+                core_schema.with_default_schema(core_schema.str_schema(), default='pika'),
+                core_schema.with_default_schema(core_schema.int_schema(), default=1),
+            ]
+        ),
+    ],
+)
+def test_field_wrapped_default(field_schema):
+    """only a `default` field schema provides the field's default, a wrapped default can't be used for the field"""
+    v = SchemaValidator(core_schema.typed_dict_schema({'x': core_schema.typed_dict_field(field_schema)}))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({})
+    assert exc_info.value.errors() == [{'type': 'missing', 'loc': ('x',), 'msg': 'Field required', 'input': {}}]
+    assert v.validate_python({'x': 'foo'}) == {'x': 'foo'}
+
+    v = SchemaValidator(core_schema.typed_dict_schema({'x': core_schema.typed_dict_field(field_schema, required=True)}))
+    with pytest.raises(ValidationError, match='Field required'):
+        v.validate_python({})
+
+
+def test_field_union_partial_default():
+    """a union where only some choices have a default doesn't provide a default, so the field is required"""
+    field_schema = core_schema.union_schema(
+        [core_schema.with_default_schema(core_schema.str_schema(), default='pika'), core_schema.int_schema()]
+    )
+    v = SchemaValidator(core_schema.typed_dict_schema({'x': core_schema.typed_dict_field(field_schema)}))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({})
+    assert exc_info.value.errors() == [{'type': 'missing', 'loc': ('x',), 'msg': 'Field required', 'input': {}}]
+
+
 def test_alias(py_and_json: PyAndJson):
     v = py_and_json(
         {
@@ -597,10 +700,169 @@ def test_alias_allow_pop(py_and_json: PyAndJson):
     assert v.validate_test({'FieldA': '123'}) == ({'field_a': 123}, {'field_a'})
     assert v.validate_test({'field_a': '123'}) == ({'field_a': 123}, {'field_a'})
     assert v.validate_test({'FieldA': '1', 'field_a': '2'}) == ({'field_a': 1}, {'field_a'})
-    with pytest.raises(ValidationError, match=r'FieldA\n +Field required \[type=missing,'):
+    # the field can be populated by name, so that's where it's missing
+    with pytest.raises(ValidationError, match=r'field_a\n +Field required \[type=missing,'):
         assert v.validate_test({'foobar': '123'})
 
 
+@pytest.mark.parametrize('schema_populate_by_name', [None, False, True])
+def test_field_populate_by_name(py_and_json: PyAndJson, schema_populate_by_name):
+    v = py_and_json(
+        core_schema.typed_dict_schema(
+            {
+                'field_a': core_schema.typed_dict_field(
+                    core_schema.int_schema(), validation_alias='FieldA', populate_by_name=True
+                ),
+                'field_b': core_schema.typed_dict_field(
+                    core_schema.int_schema(), validation_alias='field_a', populate_by_name=False
+                ),
+            },
+            populate_by_name=schema_populate_by_name,
+        )
+    )
+    assert v.validate_test({'FieldA': 1, 'field_a': 2}) == {'field_a': 1, 'field_b': 2}
+    # `field_b` can't be populated by name, `field_a` uses its alias if both are present
+    assert v.validate_test({'field_a': 2}) == {'field_a': 2, 'field_b': 2}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'field_b': 3})
+    assert exc_info.value.errors() == [
+        {'type': 'missing', 'loc': ('field_a',), 'msg': 'Field required', 'input': {'field_b': 3}},
+        {'type': 'missing', 'loc': ('field_a',), 'msg': 'Field required', 'input': {'field_b': 3}},
+    ]
+
+
+def test_field_populate_by_name_missing_loc(py_and_json: PyAndJson):
+    v = py_and_json(
+        core_schema.typed_dict_schema(
+            {
+                'field_a': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias='FieldA'),
+                'field_b': core_schema.typed_dict_field(
+                    core_schema.int_schema(), validation_alias='FieldB', populate_by_name=False
+                ),
+            },
+            extra_behavior='forbid',
+            populate_by_name=True,
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'field_b': 1})
+    assert exc_info.value.errors() == [
+        {'type': 'missing', 'loc': ('field_a',), 'msg': 'Field required', 'input': {'field_b': 1}},
+        {'type': 'missing', 'loc': ('FieldB',), 'msg': 'Field required', 'input': {'field_b': 1}},
+        {'type': 'extra_forbidden', 'loc': ('field_b',), 'msg': 'Extra inputs are not permitted', 'input': 1},
+    ]
+
+
+def test_field_populate_by_name_assignment():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'field_a': core_schema.typed_dict_field(
+                    core_schema.int_schema(), validation_alias='FieldA', populate_by_name=True
+                ),
+                'field_b': core_schema.typed_dict_field(
+                    core_schema.int_schema(), validation_alias='FieldB', populate_by_name=False
+                ),
+            }
+        )
+    )
+    data = v.validate_python({'field_a': 1, 'FieldB': 2})
+    # attributes are always assigned by field name, whichever keys the field is populated by
+    assert v.validate_assignment(data, 'field_a', '3') == {'field_a': 3, 'field_b': 2}
+    assert v.validate_assignment(data, 'field_b', '4') == {'field_a': 3, 'field_b': 4}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment(data, 'field_b', 'x')
+    assert exc_info.value.errors()[0]['loc'] == ('field_b',)
+
+
+def test_require_alias():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'field_a': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias='FieldA'),
+                'field_b': core_schema.typed_dict_field(core_schema.int_schema()),
+            },
+            extra_behavior='forbid',
+            populate_by_name=True,
+            require_alias=True,
+        )
+    )
+    assert v.validate_python({'FieldA': '1', 'field_b': '2'}) == {'field_a': 1, 'field_b': 2}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'field_a': '1', 'field_b': '2'})
+    # insert_assert(exc_info.value.errors())
+    assert exc_info.value.errors() == [
+        {'type': 'missing', 'loc': ('FieldA',), 'msg': 'Field required', 'input': {'field_a': '1', 'field_b': '2'}},
+        {'type': 'extra_forbidden', 'loc': ('field_a',), 'msg': 'Extra inputs are not permitted', 'input': '1'},
+    ]
+
+
+def test_require_alias_config():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'field_a': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias='FieldA')}
+        ),
+        {'populate_by_name': True, 'require_alias': True},
+    )
+    assert v.validate_python({'FieldA': '1'}) == {'field_a': 1}
+    # extra keys are ignored by default
+    with pytest.raises(ValidationError, match=r'FieldA\n +Field required \[type=missing,'):
+        v.validate_python({'field_a': '1'})
+
+
+def test_case_insensitive_fields():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'username': core_schema.typed_dict_field(core_schema.str_schema()),
+                'user_id': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias='UserID'),
+            },
+            extra_behavior='forbid',
+            populate_by_name=True,
+            case_insensitive_fields=True,
+        )
+    )
+    assert v.validate_python({'UserName': 'x', 'userid': '1'}) == {'username': 'x', 'user_id': 1}
+    assert v.validate_python({'USERNAME': 'x', 'User_Id': '1'}) == {'username': 'x', 'user_id': 1}
+    assert v.validate_json('{"userName": "x", "USERID": 1}') == {'username': 'x', 'user_id': 1}
+    # an exact match wins, otherwise the first key in the input, other keys matching the field aren't extra
+    assert v.validate_python({'USERNAME': 'a', 'username': 'b', 'UserID': 1}) == {'username': 'b', 'user_id': 1}
+    assert v.validate_python({'USERNAME': 'a', 'UserName': 'b', 'UserID': 1}) == {'username': 'a', 'user_id': 1}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'UserName': 'x', 'UserID': 'x', 'other': 1})
+    # insert_assert(exc_info.value.errors())
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': ('UserID',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+        {'type': 'extra_forbidden', 'loc': ('other',), 'msg': 'Extra inputs are not permitted', 'input': 1},
+    ]
+    # errors are located by the key in the input
+    with pytest.raises(ValidationError, match=r'USERID\n +Input should be a valid integer'):
+        v.validate_python({'username': 'x', 'USERID': 'x'})
+
+
+def test_case_insensitive_fields_attributes():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'username': core_schema.typed_dict_field(core_schema.str_schema())}, from_attributes=True
+        ),
+        {'case_insensitive_fields': True},
+    )
+
+    @dataclass
+    class User:
+        UserName: str
+
+    assert v.validate_python(User('x')) == {'username': 'x'}
+    with pytest.raises(ValidationError, match=r'username\n +Field required'):
+        v.validate_python({'user_name': 'x'})
+
+
 @pytest.mark.parametrize(
     'input_value,expected',
     [
@@ -800,10 +1062,81 @@ def test_paths_allow_by_name(py_and_json: PyAndJson, input_value):
     assert v.validate_test(input_value) == {'field_a': 42}
 
 
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ({'user': {'profile': {'name': 'a'}}}, {'name': 'a'}),
+        ({'username': 'b'}, {'name': 'b'}),
+        ({'users': ['c', 'x']}, {'name': 'c'}),
+        # the first alternative which matches is used
+        ({'username': 'b', 'user': {'profile': {'name': 'a'}}}, {'name': 'a'}),
+        # paths into values which aren't containers are missing rather than an error
+        ({'user': 42, 'username': 'd'}, {'name': 'd'}),
+        ({'user': {'profile': 'x'}, 'users': []}, Err(r'user.profile.name\n +Field required \[type=missing,')),
+    ],
+    ids=repr,
+)
+def test_alias_alternatives(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(
+        core_schema.typed_dict_schema(
+            {
+                'name': core_schema.typed_dict_field(
+                    core_schema.str_schema(), validation_alias=[['user', 'profile', 'name'], 'username', ['users', 0]]
+                )
+            }
+        )
+    )
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=expected.message) as exc_info:
+            v.validate_test(input_value)
+        assert exc_info.value.errors()[0]['loc'] == ('user', 'profile', 'name')
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_alias_alternatives_attributes():
+    @dataclass
+    class Profile:
+        name: str
+
+    @dataclass
+    class User:
+        profile: Profile
+
+    class Payload:
+        def __init__(self, **kwargs):
+            self.__dict__.update(kwargs)
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'name': core_schema.typed_dict_field(
+                    core_schema.str_schema(), validation_alias=[['user', 'profile', 'name'], 'username']
+                )
+            },
+            from_attributes=True,
+        )
+    )
+    assert v.validate_python(Payload(user=User(Profile('a')), username='b')) == {'name': 'a'}
+    assert v.validate_python(Payload(user=42, username='b')) == {'name': 'b'}
+    assert v.validate_python(Payload(user={'profile': {'name': 'c'}})) == {'name': 'c'}
+    payload = Payload(user=User(Profile(None)), other='x')
+    # the first alternative matches, so its value is validated even though it isn't valid
+    with pytest.raises(ValidationError, match='Input should be a valid string'):
+        v.validate_python(payload)
+
+    payload = Payload(user=User(42))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(payload)
+    assert exc_info.value.errors() == [
+        {'type': 'missing', 'loc': ('user', 'profile', 'name'), 'msg': 'Field required', 'input': payload}
+    ]
+
+
 @pytest.mark.parametrize(
     'alias_schema,error',
     [
-        ({'validation_alias': ['foo', ['bar']]}, 'Input should be a valid string'),
+        ({'validation_alias': ['foo', [['bar']]]}, 'Input should be a valid string'),
         ({'validation_alias': []}, 'Lookup paths should have at least one element'),
         ({'validation_alias': [[]]}, 'Each alias path should have at least one element'),
         ({'validation_alias': [123]}, "TypeError: 'int' object cannot be converted to 'PyList'"),
@@ -911,6 +1244,92 @@ def test_alias_error_loc_field_names(py_and_json: PyAndJson):
     assert exc_info.value.errors() == [{'type': 'missing', 'loc': ('field_a',), 'msg': 'Field required', 'input': {}}]
 
 
+def test_alias_error_loc_nested():
+    class Inner:
+        __slots__ = '__dict__', '__pydantic_fields_set__'
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'outer_a': core_schema.typed_dict_field(
+                    core_schema.list_schema(
+                        core_schema.model_schema(
+                            Inner,
+                            core_schema.typed_dict_schema(
+                                {
+                                    'inner_a': core_schema.typed_dict_field(
+                                        core_schema.int_schema(), validation_alias=[['x'], ['y', 'z']]
+                                    ),
+                                    'inner_b': core_schema.typed_dict_field(core_schema.int_schema()),
+                                    'inner_c': core_schema.typed_dict_field(
+                                        core_schema.int_schema(), validation_alias='InnerC'
+                                    ),
+                                }
+                            ),
+                            # the inner model uses its own config
+                            config={'loc_by_alias': False},
+                        )
+                    ),
+                    validation_alias='OuterA',
+                ),
+                'outer_b': core_schema.typed_dict_field(core_schema.int_schema()),
+            }
+        )
+    )
+    input_value = {'OuterA': [{'y': {'z': 'a'}, 'inner_b': 'b'}], 'outer_b': 'c'}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    # by default each level uses its own `loc_by_alias`
+    assert [e['loc'] for e in exc_info.value.errors()] == [
+        ('OuterA', 0, 'inner_a'),
+        ('OuterA', 0, 'inner_b'),
+        ('OuterA', 0, 'inner_c'),
+        ('outer_b',),
+    ]
+    # the alias path which matched the input is used, missing fields use the first alias
+    assert [e['loc'] for e in exc_info.value.errors(by_alias=True)] == [
+        ('OuterA', 0, 'y', 'z'),
+        ('OuterA', 0, 'inner_b'),
+        ('OuterA', 0, 'InnerC'),
+        ('outer_b',),
+    ]
+    assert [e['loc'] for e in exc_info.value.errors(by_alias=False)] == [
+        ('outer_a', 0, 'inner_a'),
+        ('outer_a', 0, 'inner_b'),
+        ('outer_a', 0, 'inner_c'),
+        ('outer_b',),
+    ]
+    assert exc_info.value.errors(by_alias=None) == exc_info.value.errors()
+    assert 'OuterA.0.inner_a\n' in str(exc_info.value)
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"OuterA": [{"x": "a", "inner_b": 1, "InnerC": "c"}], "outer_b": 1}')
+    assert [e['loc'] for e in exc_info.value.errors(by_alias=True)] == [('OuterA', 0, 'x'), ('OuterA', 0, 'InnerC')]
+    assert [e['loc'] for e in exc_info.value.errors(by_alias=False)] == [
+        ('outer_a', 0, 'inner_a'),
+        ('outer_a', 0, 'inner_c'),
+    ]
+
+
+def test_alias_error_loc_choices():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'field_a': core_schema.typed_dict_field(
+                    core_schema.int_schema(), validation_alias=[['foo'], ['bar', 1, -1]]
+                ),
+                'field_b': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias='FieldB'),
+            }
+        ),
+        {'loc_by_alias': False},
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'bar': ['x', [1, 'a']]})
+    assert [e['loc'] for e in exc_info.value.errors()] == [('field_a',), ('field_b',)]
+    assert [e['loc'] for e in exc_info.value.errors(by_alias=True)] == [('bar', 1, -1), ('FieldB',)]
+    assert exc_info.value.errors(by_alias=True, sort_errors='loc')[0]['loc'] == ('FieldB',)
+
+
 def test_empty_model():
     v = SchemaValidator({'type': 'typed-dict', 'fields': {}, 'return_fields_set': True})
     assert v.validate_python({}) == ({}, set())
@@ -1139,6 +1558,277 @@ def test_from_attributes_error():
     ]
 
 
+class SlotsBase:
+    __slots__ = ('a',)
+
+    def __init__(self, a):
+        self.a = a
+
+
+class SlotsWithAttributes(SlotsBase):
+    __slots__ = ('b',)
+
+    def __init__(self, a, b=None):
+        super().__init__(a)
+        if b is not None:
+            self.b = b
+
+    @property
+    def c(self):
+        return 'ham'
+
+
+def attributes_mode_validator(attributes_mode):
+    fields = {
+        'a': {'type': 'typed-dict-field', 'schema': {'type': 'int'}},
+        'b': {'type': 'typed-dict-field', 'schema': {'type': 'int'}},
+        'c': {'type': 'typed-dict-field', 'schema': {'type': 'str'}},
+    }
+    return SchemaValidator(
+        {'type': 'typed-dict', 'fields': fields, 'from_attributes': True, 'attributes_mode': attributes_mode}
+    )
+
+
+@pytest.mark.parametrize('attributes_mode', ['getattr', 'dict', 'slots'])
+@pytest.mark.parametrize(
+    'input_value',
+    [ClassWithAttributes(), MyDataclass(), SlotsWithAttributes(1, 2), (SlotsWithAttributes(1), dict(b=2))],
+    ids=repr,
+)
+def test_from_attributes_mode(attributes_mode, input_value):
+    v = attributes_mode_validator(attributes_mode)
+    assert v.validate_python(input_value) == {'a': 1, 'b': 2, 'c': 'ham'}
+
+
+@pytest.mark.parametrize('attributes_mode', ['getattr', 'dict', 'slots'])
+def test_from_attributes_mode_missing(attributes_mode):
+    v = attributes_mode_validator(attributes_mode)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(SlotsWithAttributes(1))
+    assert exc_info.value.errors() == [
+        {
+            'type': 'missing',
+            'loc': ('b',),
+            'msg': 'Field required',
+            'input': HasRepr(IsStr(regex='.+SlotsWithAttributes object at.+')),
+        }
+    ]
+
+
+@pytest.mark.parametrize('attributes_mode', ['dict', 'slots'])
+def test_from_attributes_mode_fallback(attributes_mode):
+    class Fallback(SlotsBase):
+        __slots__ = ('__dict__',)
+
+        def __getattr__(self, name):
+            if name == 'b':
+                return 2
+            raise AttributeError(name)
+
+        @property
+        def c(self):
+            return 'ham'
+
+    v = attributes_mode_validator(attributes_mode)
+    assert v.validate_python(Fallback(1)) == {'a': 1, 'b': 2, 'c': 'ham'}
+
+
+def test_from_attributes_mode_dict_shadowed():
+    class Shadowed:
+        def __init__(self):
+            self.a = 1
+            self.b = 2
+            self.__dict__['c'] = 'from dict'
+
+        @property
+        def c(self):
+            return 'from property'
+
+    # `__dict__` is read directly, so it takes priority over data descriptors unlike `getattr`
+    assert attributes_mode_validator('getattr').validate_python(Shadowed())['c'] == 'from property'
+    assert attributes_mode_validator('dict').validate_python(Shadowed())['c'] == 'from dict'
+
+
+def test_from_attributes_mode_slots_shadowed():
+    class Shadowed(SlotsWithAttributes):
+        __slots__ = ()
+
+        def __init__(self):
+            # the slot is set, but shadowed by the property below
+            SlotsBase.a.__set__(self, 1)
+            self.b = 2
+
+        @property
+        def a(self):
+            return 42
+
+    v = attributes_mode_validator('slots')
+    assert v.validate_python(Shadowed()) == {'a': 42, 'b': 2, 'c': 'ham'}
+
+
+def test_from_attributes_mode_slots_classes():
+    class Other:
+        __slots__ = ('a', 'b', 'c')
+
+        def __init__(self):
+            self.a = 3
+            self.b = 4
+            self.c = 'spam'
+
+    v = attributes_mode_validator('slots')
+    # member descriptors are cached per class, so switching classes must resolve them again
+    for _ in range(2):
+        assert v.validate_python(SlotsWithAttributes(1, 2)) == {'a': 1, 'b': 2, 'c': 'ham'}
+        assert v.validate_python(Other()) == {'a': 3, 'b': 4, 'c': 'spam'}
+
+
+@pytest.mark.parametrize('attributes_mode', ['dict', 'slots'])
+def test_from_attributes_mode_error(attributes_mode):
+    class Foobar:
+        def __init__(self):
+            self.a = 1
+            self.c = 'ham'
+
+        @property
+        def b(self):
+            raise RuntimeError('intentional error')
+
+    v = attributes_mode_validator(attributes_mode)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(Foobar())
+    assert exc_info.value.errors() == [
+        {
+            'type': 'get_attribute_error',
+            'loc': ('b',),
+            'msg': 'Error extracting attribute: RuntimeError: intentional error',
+            'input': HasRepr(IsStr(regex='.+Foobar object at.+')),
+            'ctx': {'error': 'RuntimeError: intentional error'},
+        }
+    ]
+
+
+def test_from_attributes_mode_invalid():
+    with pytest.raises(SchemaError, match="typed-dict.attributes_mode\n  Input should be 'getattr', 'dict' or 'slots'"):
+        attributes_mode_validator('foobar')
+
+
+class LazyRecord:
+    """Like an ORM proxy, returns a lazily loaded value for any attribute which isn't set"""
+
+    def __init__(self):
+        self.id = 1
+        self.name = 'Alice'
+        self.loaded = []
+
+    def __getattr__(self, name):
+        self.loaded.append(name)
+        return f'<lazy {name}>'
+
+
+def lazy_record_validator(**kwargs):
+    fields = {
+        'id': core_schema.typed_dict_field(core_schema.int_schema()),
+        'name': core_schema.typed_dict_field(core_schema.str_schema()),
+        'nickname': core_schema.typed_dict_field(core_schema.str_schema()),
+    }
+    return SchemaValidator(core_schema.typed_dict_schema(fields, from_attributes=True, **kwargs))
+
+
+def test_from_attributes_mode_getattr_any_attribute():
+    record = LazyRecord()
+    v = lazy_record_validator(from_attributes_mode='getattr')
+    assert v.validate_python(record) == {'id': 1, 'name': 'Alice', 'nickname': '<lazy nickname>'}
+    assert record.loaded == ['nickname']
+
+
+@pytest.mark.parametrize(
+    'kwargs',
+    [
+        dict(from_attributes_mode='dir'),
+        dict(from_attributes_mode='explicit', readable_attributes=['id', 'name']),
+    ],
+    ids=repr,
+)
+def test_from_attributes_mode_restricted(kwargs):
+    record = LazyRecord()
+    v = lazy_record_validator(**kwargs)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(record)
+    assert exc_info.value.errors() == [
+        {
+            'type': 'missing',
+            'loc': ('nickname',),
+            'msg': 'Field required',
+            'input': HasRepr(IsStr(regex='.+LazyRecord object at.+')),
+        }
+    ]
+    assert record.loaded == []
+
+
+def test_from_attributes_mode_explicit_alias_paths():
+    record = LazyRecord()
+    fields = {
+        'nickname': core_schema.typed_dict_field(
+            core_schema.str_schema(), validation_alias=[['profile', 'nickname'], ['nickname'], ['name']]
+        )
+    }
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            fields, from_attributes=True, from_attributes_mode='explicit', readable_attributes=['name']
+        )
+    )
+    assert v.validate_python(record) == {'nickname': 'Alice'}
+    # paths starting with attributes which aren't readable aren't probed
+    assert record.loaded == []
+
+
+def test_from_attributes_mode_explicit_getattr_fallback():
+    # readable attributes are still read with `getattr`, so `__getattr__` is used for them
+    record = LazyRecord()
+    v = lazy_record_validator(from_attributes_mode='explicit', readable_attributes=['id', 'name', 'nickname'])
+    assert v.validate_python(record) == {'id': 1, 'name': 'Alice', 'nickname': '<lazy nickname>'}
+    assert record.loaded == ['nickname']
+
+
+def test_from_attributes_mode_case_insensitive():
+    record = LazyRecord()
+    record.NickName = 'Al'
+    v = lazy_record_validator(
+        from_attributes_mode='explicit', readable_attributes=['id', 'name', 'NickName'], case_insensitive_fields=True
+    )
+    assert v.validate_python(record) == {'id': 1, 'name': 'Alice', 'nickname': 'Al'}
+    assert record.loaded == []
+
+
+def test_from_attributes_mode_dict_input():
+    # the mode only applies to reading attributes
+    v = lazy_record_validator(from_attributes_mode='explicit', readable_attributes=['id'])
+    input_value = {'id': 1, 'name': 'Alice', 'nickname': 'Al'}
+    assert v.validate_python(input_value) == input_value
+
+
+@pytest.mark.parametrize(
+    'kwargs,message',
+    [
+        (
+            dict(from_attributes_mode='explicit'),
+            "`readable_attributes` is required with `from_attributes_mode='explicit'`",
+        ),
+        (
+            dict(readable_attributes=['id']),
+            "`readable_attributes` can only be used with `from_attributes_mode='explicit'`",
+        ),
+        (
+            dict(from_attributes_mode='dir', readable_attributes=['id']),
+            "`readable_attributes` can only be used with `from_attributes_mode='explicit'`",
+        ),
+    ],
+)
+def test_from_attributes_mode_readable_attributes_invalid(kwargs, message):
+    with pytest.raises(SchemaError, match=re.escape(message)):
+        lazy_record_validator(**kwargs)
+
+
 def test_from_attributes_extra():
     def another_function(x):
         return x
@@ -1530,8 +2220,17 @@ def test_bad_default_factory(default_factory, error_message):
             },
         }
     )
-    with pytest.raises(TypeError, match=re.escape(error_message)):
+    with pytest.raises(ValidationError) as exc_info:
         v.validate_python({})
+    assert exc_info.value.errors() == [
+        {
+            'type': 'default_factory_error',
+            'loc': ('x',),
+            'msg': f'Error calling default_factory: TypeError: {error_message}',
+            'input': None,
+            'ctx': {'error': f'TypeError: {error_message}'},
+        }
+    ]
 
 
 class TestOnError:
@@ -1868,3 +2567,368 @@ def test_extra_behavior_ignore(config: Union[core_schema.CoreConfig, None], sche
         }
     ]
     assert 'not_f' not in m
+
+
+def test_field_title():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'dob': core_schema.typed_dict_field(core_schema.date_schema(), title='Date of birth'),
+                'name': core_schema.typed_dict_field(core_schema.str_schema()),
+                'tags': core_schema.typed_dict_field(
+                    core_schema.list_schema(core_schema.int_schema()), title='Tags', required=False
+                ),
+            },
+            extra_behavior='forbid',
+        )
+    )
+    assert v.validate_python({'dob': '2000-01-01', 'name': 'x'}) == {'dob': date(2000, 1, 1), 'name': 'x'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'tags': [1, 'x']})
+    assert exc_info.value.errors() == [
+        {
+            'type': 'missing',
+            'loc': ('dob',),
+            'msg': 'Field required',
+            'input': {'tags': [1, 'x']},
+            'ctx': {'field_title': 'Date of birth'},
+        },
+        {'type': 'missing', 'loc': ('name',), 'msg': 'Field required', 'input': {'tags': [1, 'x']}},
+        {
+            'type': 'int_parsing',
+            'loc': ('tags', 1),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+            'ctx': {'field_title': 'Tags'},
+        },
+    ]
+    assert '"ctx":{"field_title":"Date of birth"}' in exc_info.value.json()
+    # the title is kept when the error is rebuilt from its dict
+    rebuilt = ValidationError('Foo', exc_info.value.errors())
+    assert [e.get('ctx') for e in rebuilt.errors()] == [{'field_title': 'Date of birth'}, None, {'field_title': 'Tags'}]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'dob': '2000-01-01', 'name': 'x', 'other': 1})
+    assert exc_info.value.errors() == [
+        {'type': 'extra_forbidden', 'loc': ('other',), 'msg': 'Extra inputs are not permitted', 'input': 1}
+    ]
+
+
+def test_field_title_extra_forbidden():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'dob': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias='DOB', title='DOB')},
+            extra_behavior='forbid',
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'DOB': 1, 'dob': 2})
+    assert exc_info.value.errors() == [
+        {
+            'type': 'extra_forbidden',
+            'loc': ('dob',),
+            'msg': 'Extra inputs are not permitted',
+            'input': 2,
+            'ctx': {'field_title': 'DOB'},
+        }
+    ]
+
+
+def test_field_title_nested():
+    inner = core_schema.typed_dict_schema({'x': core_schema.typed_dict_field(core_schema.int_schema(), title='X')})
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(inner, title='A'),
+                'b': core_schema.typed_dict_field(
+                    core_schema.typed_dict_schema({'y': core_schema.typed_dict_field(core_schema.int_schema())}),
+                    title='B',
+                ),
+            }
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': {'x': 'x'}, 'b': {'y': 'y'}})
+    assert [(e['loc'], e['ctx']) for e in exc_info.value.errors()] == [
+        (('a', 'x'), {'field_title': 'X'}),
+        (('b', 'y'), {'field_title': 'B'}),
+    ]
+
+
+def test_field_title_custom_error_context():
+    def f(input_value, info):
+        raise PydanticCustomError('my_error', 'my message {foo}', {'foo': 'bar'})
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.general_plain_validator_function(f), title='A')
+            }
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1})
+    assert exc_info.value.errors() == [
+        {
+            'type': 'my_error',
+            'loc': ('a',),
+            'msg': 'my message bar',
+            'input': 1,
+            'ctx': {'foo': 'bar', 'field_title': 'A'},
+        }
+    ]
+
+
+def test_field_title_disabled():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.int_schema(), title='A')}),
+        config=CoreConfig(error_field_title=False),
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({})
+    assert exc_info.value.errors() == [{'type': 'missing', 'loc': ('a',), 'msg': 'Field required', 'input': {}}]
+
+
+def check_max(value, info):
+    if value < info.data['min']:
+        raise ValueError(f'max should be at least min ({info.data["min"]})')
+    return value
+
+
+def depends_on_schema(min_required=True):
+    return core_schema.typed_dict_schema(
+        {
+            'max': core_schema.typed_dict_field(
+                core_schema.field_after_validator_function(check_max, core_schema.int_schema()), depends_on=['min']
+            ),
+            'min': core_schema.typed_dict_field(core_schema.int_schema(), required=min_required),
+            'name': core_schema.typed_dict_field(core_schema.str_schema()),
+        }
+    )
+
+
+def test_depends_on(py_and_json: PyAndJson):
+    v = py_and_json(depends_on_schema())
+    output = v.validate_test({'name': 'x', 'min': 5, 'max': 10})
+    assert output == {'max': 10, 'min': 5, 'name': 'x'}
+    # fields are validated in dependency order, but the output keeps the schema's order
+    assert list(output) == ['max', 'min', 'name']
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'max': 1, 'min': 5, 'name': 'x'})
+    assert exc_info.value.errors() == [
+        {
+            'type': 'value_error',
+            'loc': ('max',),
+            'msg': 'Value error, max should be at least min (5)',
+            'input': 1,
+            'ctx': {'error': 'max should be at least min (5)'},
+        }
+    ]
+
+
+def test_depends_on_failed(py_and_json: PyAndJson):
+    v = py_and_json(depends_on_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'max': 10, 'min': 'x', 'name': 'x'})
+    # insert_assert(exc_info.value.errors())
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': ('min',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+        {
+            'type': 'field_dependency_failed',
+            'loc': ('max',),
+            'msg': "Field depends on 'min', which is missing or failed validation",
+            'input': 10,
+            'ctx': {'dependency': 'min'},
+        },
+    ]
+
+
+def test_depends_on_missing():
+    v = SchemaValidator(depends_on_schema(min_required=False))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'max': 10, 'name': 'x'})
+    assert exc_info.value.errors() == [
+        {
+            'type': 'field_dependency_failed',
+            'loc': ('max',),
+            'msg': "Field depends on 'min', which is missing or failed validation",
+            'input': 10,
+            'ctx': {'dependency': 'min'},
+        }
+    ]
+    # dependencies only matter once the dependent field is found
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'name': 'x'})
+    assert [e['type'] for e in exc_info.value.errors()] == ['missing']
+
+
+def test_depends_on_chain():
+    calls = []
+
+    def record(value, info):
+        calls.append((info.field_name, dict(info.data)))
+        return value
+
+    def field(*depends_on):
+        schema = core_schema.field_after_validator_function(record, core_schema.int_schema())
+        return core_schema.typed_dict_field(schema, depends_on=list(depends_on) or None)
+
+    v = SchemaValidator(core_schema.typed_dict_schema({'c': field('b'), 'b': field('a'), 'a': field(), 'd': field()}))
+    assert v.validate_python({'a': 1, 'b': 2, 'c': 3, 'd': 4}) == {'c': 3, 'b': 2, 'a': 1, 'd': 4}
+    assert calls == [('a', {}), ('b', {'a': 1}), ('c', {'a': 1, 'b': 2}), ('d', {'a': 1, 'b': 2, 'c': 3})]
+
+
+@pytest.mark.parametrize(
+    'fields,message',
+    [
+        (
+            {'a': ['b'], 'b': ['c'], 'c': ['a']},
+            'Field dependencies form a cycle: a -> b -> c -> a',
+        ),
+        ({'a': [], 'b': ['b']}, 'Field dependencies form a cycle: b -> b'),
+        ({'a': ['b'], 'b': ['c'], 'c': ['b']}, 'Field dependencies form a cycle: b -> c -> b'),
+        ({'a': ['missing']}, "Field 'a': depends on unknown field 'missing'"),
+    ],
+)
+def test_depends_on_invalid(fields, message):
+    schema = core_schema.typed_dict_schema(
+        {
+            name: core_schema.typed_dict_field(core_schema.int_schema(), depends_on=depends_on)
+            for name, depends_on in fields.items()
+        }
+    )
+    with pytest.raises(SchemaError, match=re.escape(message)):
+        SchemaValidator(schema)
+
+
+def field_groups_schema():
+    optional_int = core_schema.with_default_schema(core_schema.int_schema(), default=None)
+    return core_schema.typed_dict_schema(
+        {
+            'lat': core_schema.typed_dict_field(optional_int, validation_alias='latitude'),
+            'lon': core_schema.typed_dict_field(optional_int),
+            'password': core_schema.typed_dict_field(core_schema.str_schema(), required=False),
+            'token': core_schema.typed_dict_field(core_schema.str_schema(), required=False),
+        },
+        field_groups=[
+            core_schema.field_group(['lat', 'lon'], 'required_together'),
+            core_schema.field_group(['password', 'token'], 'mutually_exclusive'),
+            core_schema.field_group(['password', 'token'], 'at_least_one'),
+        ],
+    )
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ({'token': 'x'}, {'lat': None, 'lon': None, 'token': 'x'}),
+        ({'latitude': 1, 'lon': 2, 'password': 'x'}, {'lat': 1, 'lon': 2, 'password': 'x'}),
+        (
+            {'latitude': 1, 'token': 'x'},
+            [
+                {
+                    'type': 'group_required_together',
+                    'loc': (),
+                    'msg': "Fields 'lat', 'lon' should either all be provided or all be omitted",
+                    'ctx': {'fields': "'lat', 'lon'"},
+                }
+            ],
+        ),
+        (
+            {'password': 'x', 'token': 'y'},
+            [
+                {
+                    'type': 'group_mutually_exclusive',
+                    'loc': (),
+                    'msg': "At most one of fields 'password', 'token' should be provided",
+                    'ctx': {'fields': "'password', 'token'"},
+                }
+            ],
+        ),
+        (
+            {'lon': 2},
+            [
+                {
+                    'type': 'group_required_together',
+                    'loc': (),
+                    'msg': "Fields 'lat', 'lon' should either all be provided or all be omitted",
+                    'ctx': {'fields': "'lat', 'lon'"},
+                },
+                {
+                    'type': 'group_at_least_one',
+                    'loc': (),
+                    'msg': "At least one of fields 'password', 'token' should be provided",
+                    'ctx': {'fields': "'password', 'token'"},
+                },
+            ],
+        ),
+    ],
+)
+def test_field_groups(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(field_groups_schema())
+    if isinstance(expected, list):
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_test(input_value)
+        errors = [{k: e[k] for k in ('type', 'loc', 'msg', 'ctx')} for e in exc_info.value.errors()]
+        assert errors == expected
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_field_groups_invalid_field_provided():
+    v = SchemaValidator(field_groups_schema())
+    # a field which fails validation was still provided, so both its own error and the group's are raised
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'latitude': 'x', 'token': 'y'})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('int_parsing', ('latitude',)),
+        ('group_required_together', ()),
+    ]
+
+
+def test_field_groups_model():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__'
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.typed_dict_schema(
+                {
+                    'a': core_schema.typed_dict_field(core_schema.int_schema(), required=False),
+                    'b': core_schema.typed_dict_field(core_schema.int_schema(), required=False),
+                },
+                return_fields_set=True,
+                field_groups=[core_schema.field_group(['a', 'b'], 'at_least_one')],
+            ),
+        )
+    )
+    m = v.validate_python({'b': 1})
+    assert m.__dict__ == {'b': 1}
+    assert m.__pydantic_fields_set__ == {'b'}
+    with pytest.raises(ValidationError, match=r"At least one of fields 'a', 'b' should be provided \[type=group_"):
+        v.validate_python({})
+
+
+@pytest.mark.parametrize(
+    'group,message',
+    [
+        (
+            core_schema.field_group(['lat', 'latitude'], 'at_least_one'),
+            "Field group references unknown field 'latitude'",
+        ),
+        (core_schema.field_group(['lat'], 'at_least_one'), 'Field group should contain at least two fields'),
+    ],
+)
+def test_field_groups_invalid(group, message):
+    schema = field_groups_schema()
+    schema['field_groups'] = [group]
+    with pytest.raises(SchemaError, match=re.escape(message)):
+        SchemaValidator(schema)
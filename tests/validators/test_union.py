@@ -1,6 +1,8 @@
+from enum import IntEnum
+
 import pytest
 
-from pydantic_core import SchemaError, SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import plain_repr
 
@@ -258,7 +260,10 @@ def test_empty_choices():
 
 def test_one_choice():
     v = SchemaValidator({'type': 'union', 'choices': [{'type': 'str'}]})
-    assert plain_repr(v) == 'SchemaValidator(title="str",validator=Str(StrValidator{strict:false}),slots=[])'
+    assert (
+        plain_repr(v)
+        == 'SchemaValidator(title="str",validator=Str(StrValidator{strict:false,coerce_hook:true}),slots=[])'
+    )
     assert v.validate_python('hello') == 'hello'
 
 
@@ -326,3 +331,453 @@ def test_custom_error_type_context():
     assert exc_info.value.errors() == [
         {'type': 'less_than', 'loc': (), 'msg': 'Input should be less than 42', 'input': 123, 'ctx': {'lt': 42.0}}
     ]
+
+
+class Cat:
+    pass
+
+
+class Dog:
+    pass
+
+
+class Puppy(Dog):
+    pass
+
+
+def isinstance_union_schema(**kwargs):
+    return core_schema.union_schema(
+        [
+            core_schema.model_schema(
+                Cat,
+                core_schema.typed_dict_schema({'meow': core_schema.typed_dict_field(core_schema.int_schema())}),
+                revalidate_instances='always',
+            ),
+            core_schema.model_schema(
+                Dog,
+                core_schema.typed_dict_schema({'bark': core_schema.typed_dict_field(core_schema.int_schema())}),
+                revalidate_instances='always',
+            ),
+            core_schema.str_schema(),
+        ],
+        mode='isinstance',
+        **kwargs,
+    )
+
+
+def test_isinstance_mode():
+    v = SchemaValidator(isinstance_union_schema())
+
+    cat = Cat()
+    cat.__dict__ = {'meow': '1'}
+    m = v.validate_python(cat)
+    assert isinstance(m, Cat)
+    assert m.__dict__ == {'meow': 1}
+
+    puppy = Puppy()
+    puppy.__dict__ = {'bark': 2}
+    # subclasses match too
+    m = v.validate_python(puppy)
+    assert isinstance(m, Dog)
+    assert m.__dict__ == {'bark': 2}
+
+    # no type matches, smart mode is used
+    assert v.validate_python('hello') == 'hello'
+    m = v.validate_python({'bark': '3'})
+    assert isinstance(m, Dog)
+    assert m.__dict__ == {'bark': 3}
+    assert v.validate_json('"hello"') == 'hello'
+
+
+def test_isinstance_mode_errors():
+    v = SchemaValidator(isinstance_union_schema())
+
+    # only the matching choice is tried, so only its errors are shown
+    dog = Dog()
+    dog.__dict__ = {'meow': 1}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(dog)
+    assert exc_info.value.errors() == [
+        {'type': 'missing', 'loc': ('Dog', 'bark'), 'msg': 'Field required', 'input': {'meow': 1}}
+    ]
+
+
+def test_isinstance_mode_custom_error():
+    v = SchemaValidator(isinstance_union_schema(custom_error_type='animal', custom_error_message='Not an animal'))
+    dog = Dog()
+    dog.__dict__ = {}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(dog)
+    assert exc_info.value.errors() == [{'type': 'animal', 'loc': (), 'msg': 'Not an animal', 'input': dog}]
+
+
+def test_isinstance_mode_shared_checks():
+    v = SchemaValidator(isinstance_union_schema())
+    cat = Cat()
+    cat.__dict__ = {'meow': '1'}
+
+    # the matching choice is a step, and its coercions are reported under its name like in smart mode
+    assert v.validate_python(cat, max_steps=1).__dict__ == {'meow': 1}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(cat, max_steps=0)
+    assert [e['type'] for e in exc_info.value.errors()] == ['validation_budget_exceeded']
+    _, report = v.validate_python_with_report(cat)
+    assert report == [{'type': 'int_from_str', 'loc': ('Cat', 'meow'), 'input_type': 'str', 'target_type': 'int'}]
+
+    # and an enclosing smart union sees it was a lax match, so prefers an exact one
+    exact_cat = core_schema.model_schema(
+        Cat,
+        core_schema.typed_dict_schema({'meow': core_schema.typed_dict_field(core_schema.str_schema())}),
+        revalidate_instances='always',
+    )
+    outer = SchemaValidator(core_schema.union_schema([isinstance_union_schema(), exact_cat]))
+    assert outer.validate_python(cat).__dict__ == {'meow': '1'}
+
+
+def test_isinstance_mode_invalid():
+    with pytest.raises(SchemaError, match="Input should be 'smart', 'left_to_right' or 'isinstance'"):
+        SchemaValidator(core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()], mode='foo'))
+
+
+def test_choice_priority():
+    # str accepts bytes-like input in lax mode, so swallows bytearrays meant for bytes
+    v = SchemaValidator(core_schema.union_schema([core_schema.str_schema(), core_schema.bytes_schema()]))
+    assert v.validate_python(bytearray(b'x')) == 'x'
+
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [core_schema.str_schema(), core_schema.union_choice(core_schema.bytes_schema(), priority=1)]
+        )
+    )
+    assert v.validate_python(bytearray(b'x')) == b'x'
+    # exact matches still win over lax ones
+    assert v.validate_python('x') == 'x'
+    assert v.validate_python(b'x') == b'x'
+
+
+def test_choice_priority_same():
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [
+                core_schema.union_choice(core_schema.float_schema(), priority=-1),
+                core_schema.int_schema(),
+                core_schema.union_choice(core_schema.bool_schema(), priority=1),
+                core_schema.union_choice(core_schema.none_schema(), priority=1),
+            ]
+        )
+    )
+    # in lax mode, choices are tried in order bool, none, int, float
+    assert v.validate_python('1') is True
+    assert v.validate_python('2') == 2
+    assert v.validate_python('2.5') == 2.5
+
+
+def test_choice_strict():
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [core_schema.union_choice(core_schema.int_schema(), strict=True), core_schema.float_schema()]
+        )
+    )
+    assert v.validate_python(1) == 1
+    assert v.validate_python('1') == 1.0
+    assert v.validate_json('"1"') == 1.0
+
+
+def test_smart_strict_match_short_circuits():
+    calls = []
+
+    def f(input_value, _info):
+        calls.append(input_value)
+        return input_value
+
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [core_schema.str_schema(), core_schema.int_schema()]
+            + [core_schema.general_after_validator_function(f, core_schema.float_schema()) for _ in range(20)]
+        )
+    )
+    # `int` matches in strict mode, so no later choice is tried, in strict or lax mode
+    assert v.validate_python(1) == 1
+    assert calls == []
+    # a strict match wins over an earlier lax match
+    assert v.validate_python(1.5) == 1.5
+    assert calls == [1.5]
+
+
+@pytest.mark.parametrize(
+    'choices,input_value,expected',
+    [
+        # an exact type match wins over an earlier strict match
+        ([core_schema.float_schema(), core_schema.int_schema()], 1, 1),
+        ([core_schema.int_schema(), core_schema.bool_schema()], True, True),
+        ([core_schema.int_schema(), core_schema.float_schema()], 1.0, 1.0),
+        # a strict match wins over an earlier lax match
+        ([core_schema.bool_schema(), core_schema.float_schema()], 1, 1.0),
+        # lax matches tie, so the first choice wins
+        ([core_schema.int_schema(), core_schema.float_schema()], '1', 1),
+        ([core_schema.float_schema(), core_schema.int_schema()], '1', 1.0),
+        # items are scored too
+        (
+            [core_schema.list_schema(core_schema.float_schema()), core_schema.list_schema(core_schema.int_schema())],
+            [1, 2],
+            [1, 2],
+        ),
+        (
+            [core_schema.list_schema(core_schema.int_schema()), core_schema.list_schema(core_schema.str_schema())],
+            ['1'],
+            ['1'],
+        ),
+        # nested unions report their closest match
+        (
+            [
+                core_schema.union_schema([core_schema.bool_schema(), core_schema.float_schema()]),
+                core_schema.int_schema(),
+            ],
+            1,
+            1,
+        ),
+    ],
+)
+def test_smart_closest_match(choices, input_value, expected):
+    v = SchemaValidator(core_schema.union_schema(choices))
+    output = v.validate_python(input_value)
+    assert output == expected
+    assert type(output) is type(expected)
+
+
+def test_smart_closest_match_json():
+    v = SchemaValidator(core_schema.union_schema([core_schema.float_schema(), core_schema.int_schema()]))
+    assert type(v.validate_json('1')) is int
+    assert type(v.validate_json('1.0')) is float
+
+    v = SchemaValidator(core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()]))
+    assert v.validate_json('"1"') == '1'
+
+
+def test_smart_closest_match_strict():
+    v = SchemaValidator(core_schema.union_schema([core_schema.float_schema(), core_schema.int_schema()]))
+    assert type(v.validate_python(1, strict=True)) is int
+    assert type(v.validate_python(1.5, strict=True)) is float
+
+
+def test_smart_int_enum():
+    class MyEnum(IntEnum):
+        a = 1
+
+    v = SchemaValidator(core_schema.union_schema([core_schema.int_schema(), core_schema.is_instance_schema(MyEnum)]))
+    assert v.validate_python(MyEnum.a) is MyEnum.a
+    assert type(v.validate_python(1)) is int
+
+
+def test_short_circuit_on_strict():
+    calls = []
+
+    def f(input_value, _info):
+        calls.append(input_value)
+        return input_value
+
+    choices = [core_schema.float_schema(), core_schema.general_after_validator_function(f, core_schema.int_schema())]
+    # without the option the remaining choices are tried for an exact match
+    v = SchemaValidator(core_schema.union_schema(choices))
+    assert v.validate_python(1) == 1
+    assert calls == [1]
+
+    calls.clear()
+    v = SchemaValidator(core_schema.union_schema(choices, short_circuit_on_strict=True))
+    output = v.validate_python(1)
+    assert output == 1.0
+    assert isinstance(output, float)
+    assert calls == []
+
+    # a lax match doesn't short circuit, a later strict match is still used
+    v = SchemaValidator(
+        core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()], short_circuit_on_strict=True)
+    )
+    assert v.validate_python('1') == '1'
+
+
+def test_choice_priority_dict_to_typed_dict():
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [
+                core_schema.str_schema(),
+                core_schema.union_choice(
+                    core_schema.typed_dict_schema({'x': core_schema.typed_dict_field(core_schema.int_schema())}),
+                    priority=1,
+                    strict=True,
+                ),
+            ]
+        )
+    )
+    assert v.validate_python({'x': 1}) == {'x': 1}
+    assert v.validate_python('hello') == 'hello'
+    assert v.validate_python(b'hello') == 'hello'
+
+
+def test_choice_errors_declaration_order():
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [
+                core_schema.int_schema(),
+                core_schema.union_choice(core_schema.bool_schema(), priority=1, strict=True),
+                core_schema.union_choice(core_schema.list_schema(), priority=2),
+            ]
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x')
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('int_parsing', ('int',)),
+        ('bool_type', ('bool',)),
+        ('list_type', ('list[any]',)),
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x', strict=True)
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('int_type', ('int',)),
+        ('bool_type', ('bool',)),
+        ('list_type', ('list[any]',)),
+    ]
+
+
+def test_choice_invalid():
+    with pytest.raises(SchemaError, match='union.choices.0.typed-dict.priority\n  Input should be a valid integer'):
+        SchemaValidator(core_schema.union_schema([{'schema': core_schema.int_schema(), 'priority': 'x'}]))
+
+
+def test_left_to_right():
+    choices = [core_schema.int_schema(), core_schema.str_schema()]
+    # smart mode prefers the exact type
+    v = SchemaValidator(core_schema.union_schema(choices))
+    assert v.validate_python('1') == '1'
+
+    v = SchemaValidator(core_schema.union_schema(choices, mode='left_to_right'))
+    assert v.validate_python('1') == 1
+    assert v.validate_python('x') == 'x'
+    assert v.validate_json('"1"') == 1
+    # in strict mode the first choice which accepts the input in strict mode is used
+    assert v.validate_python('1', strict=True) == '1'
+    assert v.validate_python(1, strict=True) == 1
+
+    v = SchemaValidator(core_schema.union_schema(choices, mode='left_to_right', strict=True))
+    assert v.validate_python('1') == '1'
+
+
+def test_left_to_right_choice_strict():
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [core_schema.union_choice(core_schema.int_schema(), strict=True), core_schema.float_schema()],
+            mode='left_to_right',
+        )
+    )
+    assert v.validate_python(1) == 1
+    assert v.validate_python('1') == 1.0
+
+
+def test_left_to_right_errors():
+    v = SchemaValidator(
+        core_schema.union_schema([core_schema.int_schema(), core_schema.bool_schema()], mode='left_to_right')
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x')
+    # insert_assert(exc_info.value.errors())
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': ('int',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+        {
+            'type': 'bool_parsing',
+            'loc': ('bool',),
+            'msg': 'Input should be a valid boolean, unable to interpret input',
+            'input': 'x',
+        },
+    ]
+
+
+def test_left_to_right_omit():
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [
+                core_schema.with_default_schema(core_schema.int_schema(), on_error='omit'),
+                core_schema.str_schema(),
+            ],
+            mode='left_to_right',
+        )
+    )
+    # the omitted choice is a failure, later choices are still tried
+    assert v.validate_python('1') == 1
+    assert v.validate_python('x') == 'x'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(1.5)
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_from_float',
+            'loc': ('int',),
+            'msg': 'Input should be a valid integer, got a number with a fractional part',
+            'input': 1.5,
+        },
+        {'type': 'string_type', 'loc': ('str',), 'msg': 'Input should be a valid string', 'input': 1.5},
+    ]
+
+    # if every choice fails, the union fails rather than being omitted
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.union_schema(
+                [
+                    core_schema.with_default_schema(core_schema.int_schema(), on_error='omit'),
+                    core_schema.with_default_schema(core_schema.bool_schema(), on_error='omit'),
+                ],
+                mode='left_to_right',
+            )
+        )
+    )
+    assert v.validate_python([1, 'true']) == [1, True]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([[], 1, 'a'])
+    assert exc_info.value.errors() == [
+        {'type': 'int_type', 'loc': (0, 'int'), 'msg': 'Input should be a valid integer', 'input': []},
+        {'type': 'bool_type', 'loc': (0, 'bool'), 'msg': 'Input should be a valid boolean', 'input': []},
+        {
+            'type': 'int_parsing',
+            'loc': (2, 'int'),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'a',
+        },
+        {
+            'type': 'bool_parsing',
+            'loc': (2, 'bool'),
+            'msg': 'Input should be a valid boolean, unable to interpret input',
+            'input': 'a',
+        },
+    ]
+
+
+def test_left_to_right_validate_assignment():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__'
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.typed_dict_schema(
+                {
+                    'field': core_schema.typed_dict_field(
+                        core_schema.union_schema(
+                            [core_schema.int_schema(), core_schema.str_schema()], mode='left_to_right'
+                        )
+                    )
+                },
+                return_fields_set=True,
+            ),
+        )
+    )
+    m = v.validate_python({'field': 'x'})
+    assert m.field == 'x'
+    v.validate_assignment(m, 'field', '1')
+    assert m.field == 1
+    v.validate_assignment(m, 'field', 'y')
+    assert m.field == 'y'
@@ -0,0 +1,141 @@
+import copy
+import pickle
+import re
+from uuid import NAMESPACE_DNS, UUID, SafeUUID, uuid1, uuid3, uuid4, uuid5
+
+import pytest
+
+from pydantic_core import SchemaSerializer, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
+
+UUID_STR = '12345678-1234-4678-9234-567812345678'
+UUID_V4 = UUID(UUID_STR)
+
+
+class UuidSubclass(UUID):
+    pass
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (UUID_V4, UUID_V4),
+        (UUID_STR, UUID_V4),
+        (UUID_STR.upper(), UUID_V4),
+        ('12345678123446789234567812345678', UUID_V4),
+        (f'urn:uuid:{UUID_STR}', UUID_V4),
+        ('URN:UUID:12345678123446789234567812345678', UUID_V4),
+        (UUID_V4.bytes, UUID_V4),
+        ('00000000-0000-0000-0000-000000000000', UUID(int=0)),
+        ('ffffffff-ffff-ffff-ffff-ffffffffffff', UUID(int=2**128 - 1)),
+        ('1234', Err('Input should be a valid UUID, invalid length: expected 32 or 36 characters, found 4')),
+        (f'{{{UUID_STR}}}', Err('invalid length: expected 32 or 36 characters, found 38 [type=uuid_parsing,')),
+        (f'{UUID_STR[:-1]}é', Err('invalid length: expected 32 or 36 characters, found 36 [type=uuid_parsing,')),
+        (
+            UUID_STR.replace('-', '_'),
+            Err("Input should be a valid UUID, invalid character: expected `-`, found '_' at position 8"),
+        ),
+        (f'{UUID_STR[:-1]}g', Err("expected a hexadecimal digit, found 'g' at position 35 [type=uuid_parsing,")),
+        (f'urn:uuid:{UUID_STR[:-1]}x', Err("expected a hexadecimal digit, found 'x' at position 44")),
+        (b'x' * 15, Err('Input should be a valid UUID, invalid length: expected 16 bytes, found 15')),
+        (UUID_STR.encode(), Err('invalid length: expected 16 bytes, found 36 [type=uuid_parsing,')),
+        (123, Err('UUID input should be a string, bytes or UUID object [type=uuid_type, input_value=123,')),
+        (None, Err('UUID input should be a string, bytes or UUID object [type=uuid_type,')),
+    ],
+)
+def test_uuid(input_value, expected):
+    v = SchemaValidator(core_schema.uuid_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert type(output) is UUID
+
+
+def test_created_uuid():
+    v = SchemaValidator(core_schema.uuid_schema())
+    output = v.validate_python(UUID_STR)
+    assert output.int == UUID_V4.int
+    assert output.is_safe is SafeUUID.unknown
+    assert output.version == 4
+    assert hash(output) == hash(UUID_V4)
+    assert str(output) == UUID_STR
+    assert pickle.loads(pickle.dumps(output)) == UUID_V4
+    assert copy.deepcopy(output) == UUID_V4
+    with pytest.raises(TypeError, match='UUID objects are immutable'):
+        output.int = 1
+
+
+def test_uuid_passthrough():
+    v = SchemaValidator(core_schema.uuid_schema(strict=True))
+    assert v.validate_python(UUID_V4) is UUID_V4
+    sub = UuidSubclass(UUID_STR)
+    assert v.validate_python(sub) is sub
+
+
+@pytest.mark.parametrize('input_value', [UUID_STR, UUID_V4.bytes, UUID_V4.int])
+def test_strict(input_value):
+    v = SchemaValidator(core_schema.uuid_schema(strict=True))
+    with pytest.raises(ValidationError, match=re.escape('[type=uuid_type,')):
+        v.validate_python(input_value)
+
+
+def test_json(py_and_json: PyAndJson):
+    v = py_and_json(core_schema.uuid_schema())
+    assert v.validate_test(UUID_STR) == UUID_V4
+    assert v.validate_test(UUID_V4.hex) == UUID_V4
+    assert v.validate_test(f'urn:uuid:{UUID_STR}') == UUID_V4
+    with pytest.raises(ValidationError, match=re.escape('[type=uuid_type,')):
+        v.validate_test(1)
+
+
+def test_json_strict():
+    v = SchemaValidator(core_schema.uuid_schema(strict=True))
+    # JSON has no UUID type, so the hyphenated form is accepted in strict mode
+    assert v.validate_json(f'"{UUID_STR}"') == UUID_V4
+    with pytest.raises(ValidationError, match=re.escape('invalid length: expected 36 characters, found 32')):
+        v.validate_json(f'"{UUID_V4.hex}"')
+    with pytest.raises(ValidationError, match=re.escape('invalid length: expected 36 characters, found 45')):
+        v.validate_json(f'"urn:uuid:{UUID_STR}"')
+
+
+@pytest.mark.parametrize(
+    'version,input_value,expected',
+    [
+        (1, uuid1(), True),
+        (3, uuid3(NAMESPACE_DNS, 'example.com'), True),
+        (4, uuid4(), True),
+        (5, str(uuid5(NAMESPACE_DNS, 'example.com')), True),
+        (4, uuid5(NAMESPACE_DNS, 'example.com').bytes, False),
+        (5, uuid3(NAMESPACE_DNS, 'example.com'), False),
+        # not an RFC 4122 UUID, so it has no version
+        (4, '12345678-1234-4678-1234-567812345678', False),
+        (1, UUID(int=0), False),
+    ],
+)
+def test_version(version, input_value, expected):
+    v = SchemaValidator(core_schema.uuid_schema(version=version))
+    if expected:
+        assert v.validate_python(input_value).version == version
+    else:
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(input_value)
+        assert exc_info.value.errors() == [
+            {
+                'type': 'uuid_version',
+                'loc': (),
+                'msg': f'UUID version {version} expected',
+                'input': input_value,
+                'ctx': {'expected_version': version},
+            }
+        ]
+
+
+def test_serialization():
+    s = SchemaSerializer(core_schema.uuid_schema())
+    assert s.to_python(UUID_V4) is UUID_V4
+    assert s.to_python(UUID_V4, mode='json') == UUID_STR
+    assert s.to_json(UUID_V4) == f'"{UUID_STR}"'.encode()
@@ -2,7 +2,16 @@ from collections import deque
 
 import pytest
 
-from pydantic_core import ArgsKwargs, SchemaError, SchemaValidator, ValidationError, core_schema
+from pydantic_core import (
+    ArgsKwargs,
+    PydanticOmit,
+    SchemaError,
+    SchemaSerializer,
+    SchemaValidator,
+    Some,
+    ValidationError,
+    core_schema,
+)
 
 from ..conftest import PyAndJson, plain_repr
 
@@ -202,8 +211,18 @@ def test_factory_runtime_error():
     )
     assert v.validate_python(42) == 42
     assert v.validate_python('42') == 42
-    with pytest.raises(RuntimeError, match='this is broken'):
+    with pytest.raises(ValidationError) as exc_info:
         v.validate_python('wrong')
+    # insert_assert(exc_info.value.errors())
+    assert exc_info.value.errors() == [
+        {
+            'type': 'default_factory_error',
+            'loc': (),
+            'msg': 'Error calling default_factory: RuntimeError: this is broken',
+            'input': None,
+            'ctx': {'error': 'RuntimeError: this is broken'},
+        }
+    ]
 
 
 def test_factory_type_error():
@@ -215,7 +234,7 @@ def test_factory_type_error():
     )
     assert v.validate_python(42) == 42
     assert v.validate_python('42') == 42
-    with pytest.raises(TypeError, match=r"broken\(\) missing 1 required positional argument: 'x'"):
+    with pytest.raises(ValidationError, match=r"broken\(\) missing 1 required positional argument: 'x'"):
         v.validate_python('wrong')
 
 
@@ -233,8 +252,62 @@ def test_typed_dict_error():
         }
     )
     assert v.validate_python({'x': 'x', 'y': 'y'}) == {'x': 'x', 'y': 'y'}
-    with pytest.raises(TypeError, match=r"<lambda>\(\) missing 1 required positional argument: 'y'"):
+    with pytest.raises(ValidationError) as exc_info:
         v.validate_python({'x': 'x'})
+    errors = exc_info.value.errors()
+    assert [(e['type'], e['loc'], e['input']) for e in errors] == [('default_factory_error', ('y',), None)]
+    assert errors[0]['msg'].startswith('Error calling default_factory: TypeError: ')
+    assert errors[0]['msg'].endswith("<lambda>() missing 1 required positional argument: 'y'")
+
+
+def test_factory_error_cause():
+    error = KeyError('MY_ENV_VAR')
+
+    def broken():
+        raise error
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.str_schema(), default_factory=broken)
+                ),
+            }
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x'})
+    # the factory's exception is kept as the cause, alongside other errors
+    assert exc_info.value.__cause__ is error
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('int_parsing', ('a',)),
+        ('default_factory_error', ('b',)),
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x', 'b': 'y'})
+    assert exc_info.value.__cause__ is None
+
+
+@pytest.mark.parametrize('exception', [KeyboardInterrupt, SystemExit])
+def test_factory_base_exception(exception):
+    def interrupted():
+        raise exception()
+
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'x': {
+                    'type': 'typed-dict-field',
+                    'schema': {'type': 'default', 'schema': {'type': 'int'}, 'default_factory': interrupted},
+                }
+            },
+        }
+    )
+    with pytest.raises(exception):
+        v.validate_python({})
 
 
 def test_on_error_default_not_int():
@@ -269,6 +342,99 @@ def test_on_error_wrong():
         SchemaValidator({'type': 'default', 'schema': {'type': 'int'}, 'on_error': 'default'})
 
 
+def test_on_error_call():
+    calls = []
+
+    def on_error(input_value, error):
+        calls.append((input_value, error))
+        return max(min(int(input_value), 10), 0) if isinstance(input_value, (int, float)) else 0
+
+    v = SchemaValidator(
+        core_schema.with_default_schema(core_schema.int_schema(ge=0, le=10), on_error='call', on_error_call=on_error)
+    )
+    assert v.validate_python(5) == 5
+    assert calls == []
+    assert v.validate_python(42) == 10
+    assert v.validate_python('wrong') == 0
+    assert v.validate_json('-3') == 0
+    assert [c[0] for c in calls] == [42, 'wrong', -3]
+
+    input_value, error = calls[0]
+    assert isinstance(error, ValidationError)
+    assert error.title == 'default[constrained-int]'
+    assert error.errors() == [
+        {
+            'type': 'less_than_equal',
+            'loc': (),
+            'msg': 'Input should be less than or equal to 10',
+            'input': 42,
+            'ctx': {'le': 10},
+        }
+    ]
+
+
+def test_on_error_call_typed_dict():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(
+                        core_schema.int_schema(), on_error='call', on_error_call=lambda v, e: [v, e.error_count()]
+                    )
+                )
+            }
+        )
+    )
+    assert v.validate_python({'a': 'x'}) == {'a': ['x', 1]}
+    assert v.validate_python({'a': '1'}) == {'a': 1}
+
+
+def test_on_error_call_raises():
+    def on_error(input_value, error):
+        raise RuntimeError(f'bad input {input_value!r}')
+
+    v = SchemaValidator(
+        core_schema.with_default_schema(core_schema.int_schema(), on_error='call', on_error_call=on_error)
+    )
+    with pytest.raises(RuntimeError, match="bad input 'x'"):
+        v.validate_python('x')
+
+    def reraise(input_value, error):
+        raise error
+
+    v = SchemaValidator(
+        core_schema.with_default_schema(core_schema.int_schema(), on_error='call', on_error_call=reraise)
+    )
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python('x')
+
+
+def test_on_error_call_omit():
+    calls = []
+
+    def omit(input_value):
+        if input_value == 'omit':
+            raise PydanticOmit
+        return int(input_value)
+
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.with_default_schema(
+                core_schema.no_info_plain_validator_function(omit),
+                on_error='call',
+                on_error_call=lambda v, e: calls.append(v) or -1,
+            )
+        )
+    )
+    assert v.validate_python(['1', 'omit', 'x', '2']) == [1, -1, 2]
+    assert calls == ['x']
+
+
+def test_on_error_call_missing():
+    with pytest.raises(SchemaError, match="'on_error = call' requires an `on_error_call` function"):
+        SchemaValidator({'type': 'default', 'schema': {'type': 'int'}, 'on_error': 'call'})
+
+
 def test_build_default_and_default_factory():
     with pytest.raises(SchemaError, match="'default' and 'default_factory' cannot be used together"):
         SchemaValidator({'type': 'default', 'schema': {'type': 'int'}, 'default_factory': lambda: 1, 'default': 2})
@@ -340,7 +506,11 @@ def test_validate_default_factory():
 def test_validate_default_error_tuple():
     v = SchemaValidator(
         core_schema.tuple_positional_schema(
-            [core_schema.with_default_schema(core_schema.int_schema(), default='wrong', validate_default=True)]
+            [
+                core_schema.with_default_schema(
+                    core_schema.int_schema(), default_factory=lambda: 'wrong', validate_default=True
+                )
+            ]
         )
     )
     assert v.validate_python(('2',)) == (2,)
@@ -363,7 +533,9 @@ def test_validate_default_error_typed_dict():
         core_schema.typed_dict_schema(
             {
                 'x': core_schema.typed_dict_field(
-                    core_schema.with_default_schema(core_schema.int_schema(), default='xx', validate_default=True)
+                    core_schema.with_default_schema(
+                        core_schema.int_schema(), default_factory=lambda: 'xx', validate_default=True
+                    )
                 )
             }
         )
@@ -381,3 +553,350 @@ def test_validate_default_error_typed_dict():
             'input': 'xx',
         }
     ]
+
+
+def test_validate_default_static_schema_error():
+    schema = core_schema.typed_dict_schema(
+        {
+            'x': core_schema.typed_dict_field(
+                core_schema.with_default_schema(core_schema.int_schema(), default='xx', validate_default=True)
+            )
+        }
+    )
+    with pytest.raises(SchemaError, match='Input should be a valid integer, unable to parse string as an integer'):
+        SchemaValidator(schema)
+
+
+def default_field_validator(schema, default):
+    return SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'x': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(schema, default=default, validate_default=True)
+                )
+            }
+        )
+    )
+
+
+@pytest.mark.parametrize(
+    'schema',
+    [
+        core_schema.int_schema(),
+        core_schema.int_schema(ge=0),
+        core_schema.nullable_schema(core_schema.int_schema()),
+        core_schema.union_schema([core_schema.int_schema(), core_schema.float_schema()]),
+        core_schema.chain_schema([core_schema.str_schema(), core_schema.int_schema()]),
+    ],
+)
+def test_validate_default_static_reused(schema):
+    v = default_field_validator(schema, '1234567890123')
+    first = v.validate_python({})['x']
+    assert first == 1234567890123
+    # the default was validated once when the validator was built
+    assert v.validate_python({})['x'] is first
+
+    # validating with different strictness validates the default again
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python({}, strict=True)
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.with_strict(True).validate_python({})
+
+
+def test_validate_default_static_function():
+    calls = []
+
+    def f(value):
+        calls.append(value)
+        return value
+
+    v = default_field_validator(core_schema.no_info_before_validator_function(f, core_schema.int_schema()), '1')
+    assert v.validate_python({}) == {'x': 1}
+    assert v.validate_python({}) == {'x': 1}
+    assert calls == ['1', '1']
+
+
+def test_validate_default_static_post_validator():
+    calls = []
+
+    def f(value):
+        calls.append(value)
+        return value
+
+    v = default_field_validator(core_schema.str_schema(post_validator=f), 'foo')
+    assert v.validate_python({}) == {'x': 'foo'}
+    assert v.validate_python({}) == {'x': 'foo'}
+    assert calls == ['foo', 'foo']
+
+
+def test_validate_default_static_now_op():
+    # whether the default is valid depends on when it's validated, so it isn't validated once when building
+    v = default_field_validator(core_schema.datetime_schema(now_op='future'), '2100-01-01T00:00:00Z')
+    first = v.validate_python({})['x']
+    assert first.year == 2100
+    assert v.validate_python({})['x'] is not first
+
+
+def test_validate_default_static_mutable():
+    v = default_field_validator(core_schema.list_schema(core_schema.int_schema()), ['1'])
+    first = v.validate_python({})['x']
+    assert first == [1]
+    first.append(2)
+    assert v.validate_python({}) == {'x': [1]}
+
+
+def test_no_default_removed():
+    v = SchemaValidator(core_schema.with_default_schema(core_schema.int_schema()))
+    assert v.title == 'int'
+    assert v.validate_python('1') == 1
+
+    v = SchemaValidator(core_schema.with_default_schema(core_schema.int_schema()), {'merge_validators': False})
+    assert v.title == 'default[int]'
+
+    v = SchemaValidator(core_schema.with_default_schema(core_schema.int_schema(), on_error='omit'))
+    assert v.title == 'default[int]'
+
+
+def slugify(data):
+    return data['name'].lower().replace(' ', '-')
+
+
+def test_default_factory_takes_data(py_and_json: PyAndJson):
+    calls = []
+
+    def factory(data):
+        calls.append(dict(data))
+        with pytest.raises(TypeError):
+            data['x'] = 1
+        return slugify(data)
+
+    v = py_and_json(
+        core_schema.typed_dict_schema(
+            {
+                'name': core_schema.typed_dict_field(core_schema.str_schema()),
+                'slug': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(
+                        core_schema.str_schema(), default_factory=factory, default_factory_takes_data=True
+                    )
+                ),
+                'later': core_schema.typed_dict_field(core_schema.int_schema()),
+            }
+        )
+    )
+    assert v.validate_test({'name': 'Hello World', 'later': 1}) == {
+        'name': 'Hello World',
+        'slug': 'hello-world',
+        'later': 1,
+    }
+    # only fields before the one with the default are visible
+    assert calls == [{'name': 'Hello World'}]
+    assert v.validate_test({'name': 'A', 'slug': 'x', 'later': 1}) == {'name': 'A', 'slug': 'x', 'later': 1}
+    assert len(calls) == 1
+
+    # when `name` is invalid it isn't in the data, so the factory fails
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'name': 1, 'later': 1})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('string_type', ('name',)),
+        ('default_factory_error', ('slug',)),
+    ]
+
+
+def test_default_factory_takes_data_validate_default(py_and_json: PyAndJson):
+    v = py_and_json(
+        core_schema.typed_dict_schema(
+            {
+                'name': core_schema.typed_dict_field(core_schema.str_schema()),
+                'slug': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(
+                        core_schema.str_schema(max_length=5),
+                        default_factory=slugify,
+                        default_factory_takes_data=True,
+                        validate_default=True,
+                    )
+                ),
+            }
+        )
+    )
+    assert v.validate_test({'name': 'A B'}) == {'name': 'A B', 'slug': 'a-b'}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'name': 'Hello World'})
+    # insert_assert(exc_info.value.errors())
+    assert exc_info.value.errors() == [
+        {
+            'type': 'string_too_long',
+            'loc': ('slug',),
+            'msg': 'String should have at most 5 characters',
+            'input': 'hello-world',
+            'ctx': {'max_length': 5},
+        }
+    ]
+
+
+def test_default_factory_takes_data_model():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__'
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.typed_dict_schema(
+                {
+                    'name': core_schema.typed_dict_field(core_schema.str_schema()),
+                    'slug': core_schema.typed_dict_field(
+                        core_schema.with_default_schema(
+                            core_schema.str_schema(), default_factory=slugify, default_factory_takes_data=True
+                        )
+                    ),
+                },
+                return_fields_set=True,
+            ),
+        )
+    )
+    m = v.validate_python({'name': 'Big Cat'})
+    assert m.__dict__ == {'name': 'Big Cat', 'slug': 'big-cat'}
+    assert m.__pydantic_fields_set__ == {'name'}
+    m = v.validate_json('{"name": "Big Dog"}')
+    assert m.slug == 'big-dog'
+
+
+def test_default_factory_takes_data_dataclass_args():
+    v = SchemaValidator(
+        core_schema.dataclass_args_schema(
+            'MyDataclass',
+            [
+                core_schema.dataclass_field(name='name', schema=core_schema.str_schema(), kw_only=False),
+                core_schema.dataclass_field(
+                    name='slug',
+                    schema=core_schema.with_default_schema(
+                        core_schema.str_schema(), default_factory=slugify, default_factory_takes_data=True
+                    ),
+                ),
+            ],
+        )
+    )
+    assert v.validate_python(ArgsKwargs(('Red Fox',))) == ({'name': 'Red Fox', 'slug': 'red-fox'}, None)
+    assert v.validate_json('{"name": "Red Fox"}') == ({'name': 'Red Fox', 'slug': 'red-fox'}, None)
+
+
+def test_default_factory_takes_data_outside_fields():
+    schema = core_schema.with_default_schema(
+        core_schema.int_schema(), default_factory=lambda data: 1, default_factory_takes_data=True, on_error='default'
+    )
+    msg = 'A `default_factory` which takes data can only be used for fields of a typed dict, model or dataclass'
+    v = SchemaValidator(schema)
+    with pytest.raises(TypeError, match=msg):
+        v.validate_python('x')
+
+    # a positional tuple item inside a typed dict doesn't see the typed dict's data
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(
+                    core_schema.tuple_positional_schema([core_schema.int_schema(), schema])
+                ),
+            }
+        )
+    )
+    assert v.validate_python({'a': 1, 'b': (1, 2)}) == {'a': 1, 'b': (1, 2)}
+    with pytest.raises(TypeError, match=msg):
+        v.validate_python({'a': 1, 'b': (1,)})
+
+
+def test_default_factory_takes_data_serializer():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'name': core_schema.typed_dict_field(core_schema.str_schema()),
+                'slug': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(
+                        core_schema.str_schema(), default_factory=slugify, default_factory_takes_data=True
+                    )
+                ),
+            }
+        )
+    )
+    # there's no default to compare with, so the value is never excluded
+    assert s.to_python({'name': 'A B', 'slug': 'a-b'}, exclude_defaults=True) == {'name': 'A B', 'slug': 'a-b'}
+
+
+def test_get_default_value():
+    assert SchemaValidator(core_schema.int_schema()).get_default_value() is None
+    v = SchemaValidator(core_schema.with_default_schema(core_schema.int_schema(), on_error='omit'))
+    assert v.get_default_value() is None
+
+    v = SchemaValidator(core_schema.with_default_schema(core_schema.int_schema(), default=None))
+    default = v.get_default_value()
+    assert isinstance(default, Some)
+    assert default.value is None
+    assert repr(default) == 'Some(None)'
+    assert default == Some(None)
+    assert default != Some(1)
+
+    assert Some.__match_args__ == ('value',)
+    assert Some[int] is Some
+
+
+def test_get_default_value_factory():
+    calls = []
+
+    def factory():
+        calls.append(1)
+        return []
+
+    v = SchemaValidator(core_schema.with_default_schema(core_schema.list_schema(), default_factory=factory))
+    first = v.get_default_value()
+    second = v.get_default_value()
+    assert first == second == Some([])
+    assert first.value is not second.value
+    assert len(calls) == 2
+
+
+def test_get_default_value_validate_default():
+    v = SchemaValidator(core_schema.with_default_schema(core_schema.int_schema(), default='1', validate_default=True))
+    assert v.get_default_value() == Some(1)
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.get_default_value(strict=True)
+
+    v = SchemaValidator(
+        core_schema.with_default_schema(core_schema.int_schema(), default_factory=lambda: 'x', validate_default=True)
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.get_default_value()
+    # insert_assert(exc_info.value.errors())
+    assert exc_info.value.errors() == [
+        {
+            'type': 'int_parsing',
+            'loc': (),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+    v = SchemaValidator(core_schema.with_default_schema(core_schema.int_schema(), default='x'))
+    assert v.get_default_value() == Some('x')
+
+
+def test_get_default_value_context():
+    def f(value, info):
+        return value * info.context['multiplier']
+
+    v = SchemaValidator(
+        core_schema.with_default_schema(
+            core_schema.general_after_validator_function(f, core_schema.int_schema()),
+            default=2,
+            validate_default=True,
+        )
+    )
+    assert v.get_default_value(context={'multiplier': 3}) == Some(6)
+
+
+def test_get_default_value_factory_takes_data():
+    v = SchemaValidator(
+        core_schema.with_default_schema(
+            core_schema.int_schema(), default_factory=lambda data: 1, default_factory_takes_data=True
+        )
+    )
+    with pytest.raises(TypeError, match='which takes data can only be used for fields'):
+        v.get_default_value()